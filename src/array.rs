@@ -0,0 +1,316 @@
+//! ELF address array functionality.
+//!
+//! This module provides the [AddrArray] type, which acts as a wrapper
+//! around the ELF initialization and finalization function arrays
+//! (`.init_array`, `.fini_array`, and `.preinit_array`).  Each entry
+//! in these sections is a single address.
+//!
+//! # Examples
+//!
+//! An `AddrArray` can be created from any slice containing binary
+//! data that contains a whole number of addresses:
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use elf_utils::Elf64;
+//! use elf_utils::array::AddrArray;
+//!
+//! const INIT_ARRAY: [u8; 16] = [
+//!     0x30, 0x11, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x90, 0x12, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00
+//! ];
+//!
+//! let addrs: AddrArray<'_, LittleEndian, Elf64> =
+//!     AddrArray::try_from(&INIT_ARRAY[0..]).expect("Expected success");
+//! let mut iter = addrs.iter();
+//!
+//! assert_eq!(iter.next(), Some(0x201130));
+//! assert_eq!(iter.next(), Some(0x201290));
+//! assert_eq!(iter.next(), None);
+//! ```
+
+use byteorder::ByteOrder;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::iter::Iterator;
+use core::marker::PhantomData;
+use crate::elf::ElfClass;
+
+/// In-place read-only ELF address array.
+///
+/// An address array (the `.init_array`, `.fini_array`, and
+/// `.preinit_array` sections) contains the addresses of functions to
+/// be called at program startup or shutdown.  Each entry is an
+/// address of the size given by the ELF class.
+///
+/// An `AddrArray` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance.  New `AddrArray`s can
+/// be created from an iterator over addresses with
+/// [create](AddrArray::create) or [create_split](AddrArray::create_split).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::Elf32;
+/// use elf_utils::array::AddrArray;
+///
+/// const FINI_ARRAY: [u8; 8] = [
+///     0x40, 0x12, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00
+/// ];
+///
+/// let addrs: AddrArray<'_, LittleEndian, Elf32> =
+///     AddrArray::try_from(&FINI_ARRAY[0..]).expect("Expected success");
+///
+/// assert_eq!(addrs.num_addrs(), 2);
+/// assert_eq!(addrs.idx(0), Some(0x08001240));
+/// assert_eq!(addrs.idx(1), Some(0));
+/// assert_eq!(addrs.idx(2), None);
+/// ```
+#[derive(Copy, Clone)]
+pub struct AddrArray<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8]
+}
+
+/// Iterator over the entries of an [AddrArray].
+#[derive(Copy, Clone)]
+pub struct AddrArrayIter<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8],
+    idx: usize
+}
+
+/// Errors that can occur creating an [AddrArray].
+///
+/// The only error that can occur is if the data is not a multiple of
+/// the size of an address.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AddrArrayError {
+    BadSize(usize)
+}
+
+/// Calculate the number of bytes required to represent all of the
+/// addresses in `addrs`.
+#[inline]
+pub fn required_bytes<I, Class>(addrs: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<Class::Addr>,
+          Class: ElfClass {
+    addrs.count() * Class::ADDR_SIZE
+}
+
+impl<'a, B, Class> AddrArray<'a, B, Class>
+    where B: ByteOrder,
+          Class: ElfClass {
+    /// Attempt to create an `AddrArray` in `buf` containing the
+    /// addresses in `addrs`.
+    ///
+    /// This will write the addresses into the buffer in the ELF
+    /// format.  Returns both the `AddrArray` and the remaining space
+    /// if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the addresses don't fit
+    /// into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::array::AddrArray;
+    ///
+    /// const ADDRS: [u64; 2] = [ 0x201130, 0x201290 ];
+    ///
+    /// let mut buf = [0; 20];
+    /// let (addrs, rest): (AddrArray<'_, LittleEndian, Elf64>, _) =
+    ///     AddrArray::create_split(&mut buf[0..], ADDRS.iter())
+    ///     .expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 4);
+    ///
+    /// let mut iter = addrs.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(ADDRS[0]));
+    /// assert_eq!(iter.next(), Some(ADDRS[1]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn create_split<I>(buf: &'a mut [u8], addrs: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<Class::Addr> {
+        let mut idx = 0;
+
+        for addr in addrs {
+            if buf.len() - idx < Class::ADDR_SIZE {
+                return Err(())
+            }
+
+            Class::write_addr::<B>(&mut buf[idx .. idx + Class::ADDR_SIZE],
+                                   *addr.borrow());
+            idx += Class::ADDR_SIZE;
+        }
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((AddrArray { byteorder: PhantomData, class: PhantomData,
+                        data: data }, rest))
+    }
+
+    /// Attempt to create an `AddrArray` in `buf` containing the
+    /// addresses in `addrs` (see
+    /// [create_split](AddrArray::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the addresses don't fit
+    /// into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], addrs: I) -> Result<Self, ()>
+        where I: Iterator,
+              I::Item: Borrow<Class::Addr> {
+        match Self::create_split(buf, addrs) {
+            Ok((addrs, _)) => Ok(addrs),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the address at index `idx`.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<Class::Addr> {
+        let start = idx * Class::ADDR_SIZE;
+
+        if start < self.data.len() {
+            let end = start + Class::ADDR_SIZE;
+
+            Some(Class::read_addr::<B>(&self.data[start .. end]))
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of addresses in this `AddrArray`.
+    #[inline]
+    pub fn num_addrs(&self) -> usize {
+        self.data.len() / Class::ADDR_SIZE
+    }
+
+    /// Get an iterator over this `AddrArray`.
+    #[inline]
+    pub fn iter(&self) -> AddrArrayIter<'a, B, Class> {
+        AddrArrayIter { byteorder: PhantomData, class: PhantomData,
+                        data: self.data, idx: 0 }
+    }
+}
+
+impl<'a, B, Class> TryFrom<&'a [u8]> for AddrArray<'a, B, Class>
+    where B: ByteOrder,
+          Class: ElfClass {
+    type Error = AddrArrayError;
+
+    /// Create an `AddrArray` from the data buffer.  This will check
+    /// that the data buffer is a multiple of the address size.
+    #[inline]
+    fn try_from(data: &'a [u8]) ->
+        Result<AddrArray<'a, B, Class>, Self::Error> {
+        if data.len() % Class::ADDR_SIZE == 0 {
+            Ok(AddrArray { byteorder: PhantomData, class: PhantomData,
+                           data: data })
+        } else {
+            Err(AddrArrayError::BadSize(data.len()))
+        }
+    }
+}
+
+impl<'a, B, Class> TryFrom<&'a mut [u8]> for AddrArray<'a, B, Class>
+    where B: ByteOrder,
+          Class: ElfClass {
+    type Error = AddrArrayError;
+
+    /// Create an `AddrArray` from the data buffer.  This will check
+    /// that the data buffer is a multiple of the address size.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) ->
+        Result<AddrArray<'a, B, Class>, Self::Error> {
+        AddrArray::try_from(&*data)
+    }
+}
+
+impl<'a, B, Class> Iterator for AddrArrayIter<'a, B, Class>
+    where B: ByteOrder,
+          Class: ElfClass {
+    type Item = Class::Addr;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.len();
+
+        (size, Some(size))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let start = (self.idx + n) * Class::ADDR_SIZE;
+
+        if start < self.data.len() {
+            let end = start + Class::ADDR_SIZE;
+
+            self.idx += n + 1;
+
+            Some(Class::read_addr::<B>(&self.data[start .. end]))
+        } else {
+            self.idx = self.data.len() / Class::ADDR_SIZE;
+
+            None
+        }
+    }
+}
+
+impl<'a, B, Class> FusedIterator for AddrArrayIter<'a, B, Class>
+    where B: ByteOrder,
+          Class: ElfClass {}
+
+impl<'a, B, Class> ExactSizeIterator for AddrArrayIter<'a, B, Class>
+    where B: ByteOrder,
+          Class: ElfClass {
+    #[inline]
+    fn len(&self) -> usize {
+        (self.data.len() / Class::ADDR_SIZE) - self.idx
+    }
+}
+
+impl Display for AddrArrayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AddrArrayError::BadSize(size) =>
+                write!(f, "bad address array size {}", size)
+        }
+    }
+}
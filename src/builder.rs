@@ -0,0 +1,1144 @@
+//! Whole-file ELF creation.
+//!
+//! This module provides the [ElfBuilder] type, which creates an
+//! entire ELF file from a symbolic description of its sections,
+//! segments, and symbols.  Unlike [Elf::create],
+//! [ProgHdrs::create](crate::prog_hdr::ProgHdrs::create),
+//! [SectionHdrs::create](crate::section_hdr::SectionHdrs::create),
+//! etc., which each create a single structure at a given location, an
+//! `ElfBuilder` computes the layout of the whole file:
+//!
+//! - Sections are placed in order after the ELF header and program
+//!   header table, with each one aligned as requested.  Sections in a
+//!   loadable segment are placed so that their offsets in the file
+//!   match their addresses in memory.
+//! - The symbol table (`.symtab`) and its string table (`.strtab`)
+//!   are built from the symbols, with local symbols moved ahead of
+//!   the others.  The section header string table (`.shstrtab`) is
+//!   built from the section names.
+//! - The `sh_link` and `sh_info` fields of relocation sections and
+//!   the symbol table are filled in, and the symbol references in
+//!   relocations are translated into the final symbol table.
+//!
+//! Sections are referred to by their index in
+//! [sections](ElfBuilder::sections), which becomes index `i + 1` in
+//! the section header table (after the null section header).
+//! Symbols are referred to in relocations by their index in
+//! [syms](ElfBuilder::syms) plus one, with `0` referring to the null
+//! symbol, exactly as if the symbols had been written out in the
+//! order given.
+//!
+//! As no allocation is performed, the file is written into a buffer
+//! provided by the caller.  The size of the buffer needed can be
+//! obtained with [required_bytes](ElfBuilder::required_bytes).
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryInto;
+//! use core::marker::PhantomData;
+//! use elf_utils::Elf;
+//! use elf_utils::Elf64;
+//! use elf_utils::ElfABI;
+//! use elf_utils::ElfArch;
+//! use elf_utils::ElfHdrDataRaw;
+//! use elf_utils::ElfKind;
+//! use elf_utils::builder::BuilderContent;
+//! use elf_utils::builder::BuilderSection;
+//! use elf_utils::builder::BuilderSym;
+//! use elf_utils::builder::ElfBuilder;
+//! use elf_utils::reloc::RelaData;
+//! use elf_utils::section_hdr::SectionFlags;
+//! use elf_utils::symtab::SymBase;
+//! use elf_utils::symtab::SymBind;
+//! use elf_utils::symtab::SymData;
+//! use elf_utils::symtab::SymKind;
+//! use elf_utils::symtab::SymVisibility;
+//!
+//! const TEXT: [u8; 8] = [0xe8, 0, 0, 0, 0, 0x31, 0xc0, 0xc3];
+//! const RELAS: [RelaData<u32, Elf64>; 1] = [
+//!     RelaData { offset: 1, sym: 1, kind: 4, addend: -4 }
+//! ];
+//! const SECTIONS: [BuilderSection<'static, Elf64>; 2] = [
+//!     BuilderSection {
+//!         name: ".text", addr: 0, align: 16,
+//!         flags: SectionFlags { bits: 0x6 },
+//!         content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+//!     },
+//!     BuilderSection {
+//!         name: ".rela.text", addr: 0, align: 8,
+//!         flags: SectionFlags { bits: 0x40 },
+//!         content: BuilderContent::Rela { relas: &RELAS, target: 0 }
+//!     }
+//! ];
+//! const SYMS: [BuilderSym<'static, Elf64>; 2] = [
+//!     SymData { name: Some("main"), value: 0, size: 8,
+//!               kind: SymKind::Function, bind: SymBind::Global,
+//!               visibility: SymVisibility::Default, other: 0,
+//!               section: SymBase::Index(0) },
+//!     SymData { name: Some("main.c"), value: 0, size: 0,
+//!               kind: SymKind::File, bind: SymBind::Local,
+//!               visibility: SymVisibility::Default, other: 0,
+//!               section: SymBase::Absolute }
+//! ];
+//!
+//! let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+//!     byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+//!     kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+//!     flags: 0, sections: &SECTIONS, segments: &[], syms: &SYMS
+//! };
+//! let size = builder.required_bytes().expect("Expected success");
+//! let mut buf = vec![0; size];
+//! let elf: Elf<'_, LittleEndian, Elf64> =
+//!     builder.create(&mut buf[0..]).expect("Expected success");
+//! let hdr: ElfHdrDataRaw<LittleEndian, Elf64> =
+//!     elf.try_into().expect("Expected success");
+//!
+//! assert_eq!(hdr.kind, ElfKind::Relocatable);
+//! assert_eq!(hdr.section_hdrs.num_ents, 6);
+//! assert_eq!(hdr.section_hdr_strtab, 5);
+//! ```
+
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::once;
+use core::marker::PhantomData;
+use crate::elf::Elf;
+use crate::elf::ElfABI;
+use crate::elf::ElfArch;
+use crate::elf::ElfByteOrder;
+use crate::elf::ElfClass;
+use crate::elf::ElfError;
+use crate::elf::ElfHdrData;
+use crate::elf::ElfHdrDataRaw;
+use crate::elf::ElfHdrOffsets;
+use crate::elf::ElfKind;
+use crate::elf::ElfTable;
+use crate::elf::PN_XNUM;
+use crate::note::pad;
+use crate::prog_hdr::ProgHdrData;
+use crate::prog_hdr::ProgHdrDataRaw;
+use crate::prog_hdr::ProgHdrs;
+use crate::prog_hdr::Segment;
+use crate::reloc::RelClass;
+use crate::reloc::RelData;
+use crate::reloc::RelDataRaw;
+use crate::reloc::Rels;
+use crate::reloc::RelaData;
+use crate::reloc::RelaDataRaw;
+use crate::reloc::RelaOffsets;
+use crate::reloc::Relas;
+use crate::section_hdr::SectionFlags;
+use crate::section_hdr::SectionHdrData;
+use crate::section_hdr::SectionHdrDataRaw;
+use crate::section_hdr::SectionHdrs;
+use crate::section_hdr::SectionPos;
+use crate::strtab::Strtab;
+use crate::symtab::SymBase;
+use crate::symtab::SymBind;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymKind;
+use crate::symtab::Symtab;
+use crate::symtab::SymVisibility;
+
+const SHN_LORESERVE: usize = 0xff00;
+
+const SYMTAB_NAME: &str = ".symtab";
+const STRTAB_NAME: &str = ".strtab";
+const SHSTRTAB_NAME: &str = ".shstrtab";
+
+/// Description of an entire ELF file, to be created with
+/// [create](ElfBuilder::create).
+///
+/// This holds the ELF header information that is not derived from
+/// the layout, along with the sections, segments, and symbols that
+/// make up the file.  See the [module documentation](crate::builder)
+/// for how these refer to each other.
+#[derive(Copy, Clone)]
+pub struct ElfBuilder<'a, B: ByteOrder, Class: ElfHdrOffsets> {
+    /// `PhantomData` for the byte order.
+    pub byteorder: PhantomData<B>,
+    /// OS ABI for the ELF file.
+    pub abi: ElfABI,
+    /// OS ABI version for the ELF file.
+    pub abi_version: u8,
+    /// ELF file kind.
+    pub kind: ElfKind,
+    /// Processor architecture.
+    pub arch: ElfArch,
+    /// Entry point for execution.
+    pub entry: Class::Addr,
+    /// Processor-specific flags.
+    pub flags: Class::Word,
+    /// Sections, in the order they will appear in the file.
+    pub sections: &'a [BuilderSection<'a, Class>],
+    /// Segments, described in terms of the sections they contain.
+    pub segments: &'a [BuilderSegment<Class>],
+    /// Symbols to put in the symbol table.
+    ///
+    /// The symbol table (and its string table) is only created if
+    /// there are symbols or relocation sections.
+    pub syms: &'a [BuilderSym<'a, Class>]
+}
+
+/// A section to be created by an [ElfBuilder].
+#[derive(Copy, Clone)]
+pub struct BuilderSection<'a, Class: RelClass> {
+    /// Name of the section.
+    pub name: &'a str,
+    /// Starting address of the section.
+    pub addr: Class::Addr,
+    /// Alignment of the section.
+    pub align: Class::Offset,
+    /// Flags for the section.
+    pub flags: SectionFlags<Class>,
+    /// Contents of the section.
+    pub content: BuilderContent<'a, Class>
+}
+
+/// Contents of a section to be created by an [ElfBuilder].
+#[derive(Copy, Clone)]
+pub enum BuilderContent<'a, Class: RelClass> {
+    /// Program data (`SHT_PROGBITS`).
+    ProgBits {
+        /// Contents of the section.
+        data: &'a [u8],
+        /// Size of the entries in the section, if it holds a table of
+        /// fixed-size entries (such as a mergeable section).
+        ent_size: Class::Offset
+    },
+    /// Space that takes up memory, but no space in the file
+    /// (`SHT_NOBITS`).
+    Nobits {
+        /// Size of the section in memory.
+        size: Class::Offset
+    },
+    /// Notes (`SHT_NOTE`), which can be created with
+    /// [Notes::create](crate::note::Notes::create).
+    Note {
+        /// Contents of the section.
+        data: &'a [u8]
+    },
+    /// Relocations without explicit addends (`SHT_REL`).
+    Rel {
+        /// Relocations, with symbols given as indexes into
+        /// [syms](ElfBuilder::syms) plus one.
+        rels: &'a [RelDataRaw<Class>],
+        /// Index of the section to which the relocations apply.
+        target: usize
+    },
+    /// Relocations with explicit addends (`SHT_RELA`).
+    Rela {
+        /// Relocations, with symbols given as indexes into
+        /// [syms](ElfBuilder::syms) plus one.
+        relas: &'a [RelaDataRaw<Class>],
+        /// Index of the section to which the relocations apply.
+        target: usize
+    }
+}
+
+/// A segment to be created by an [ElfBuilder].
+///
+/// A segment covers a contiguous range of sections, and its offset,
+/// address, and sizes are derived from them.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct BuilderSegment<Class: ElfClass> {
+    /// Kind of the segment.
+    pub kind: BuilderSegmentKind<Class>,
+    /// Index of the first section in the segment.
+    pub first: usize,
+    /// Number of sections in the segment.
+    ///
+    /// This is ignored for [GnuStack](BuilderSegmentKind::GnuStack)
+    /// segments.
+    pub num: usize
+}
+
+/// Kind of a segment to be created by an [ElfBuilder].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum BuilderSegmentKind<Class: ElfClass> {
+    /// Loadable segment (`PT_LOAD`).
+    ///
+    /// The sections in a loadable segment are placed in the file so
+    /// that their offsets modulo `align` match their addresses, and
+    /// the distances between them match the distances between their
+    /// addresses.
+    Load {
+        /// Memory alignment (typically the page size).
+        align: Class::Offset,
+        /// Whether the segment has read permissions.
+        read: bool,
+        /// Whether the segment has write permissions.
+        write: bool,
+        /// Whether the segment has execute permissions.
+        exec: bool
+    },
+    /// Dynamic linking information (`PT_DYNAMIC`).
+    Dynamic,
+    /// Interpreter path (`PT_INTERP`).
+    Interp,
+    /// Notes (`PT_NOTE`).
+    Note,
+    /// Thread-local storage template (`PT_TLS`).
+    Tls {
+        /// Memory alignment.
+        align: Class::Offset
+    },
+    /// Memory to be made read-only after relocation
+    /// (`PT_GNU_RELRO`).
+    GnuRelro,
+    /// Stack permissions (`PT_GNU_STACK`).
+    GnuStack {
+        /// Whether the stack has read permissions.
+        read: bool,
+        /// Whether the stack has write permissions.
+        write: bool,
+        /// Whether the stack has execute permissions.
+        exec: bool
+    }
+}
+
+/// Type synonym for [SymData] as given to an [ElfBuilder].
+///
+/// Symbols have their names given directly, and are defined relative
+/// to sections given as indexes into [sections](ElfBuilder::sections).
+pub type BuilderSym<'a, Class> =
+    SymData<&'a str, usize, Class>;
+
+/// Errors that can occur when creating an ELF file with an
+/// [ElfBuilder].
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ElfBuilderError {
+    /// The file does not fit into the provided memory.
+    TooSmall,
+    /// The file is too large to be represented in its ELF class.
+    TooLarge,
+    /// A relocation section has a target that is out of range.
+    BadTarget(usize),
+    /// A relocation in a section refers to a symbol that is out of
+    /// range.
+    BadRelocSym(usize),
+    /// A symbol refers to a section that is out of range, or would
+    /// need an extended section index.
+    BadSymSection(usize),
+    /// A segment covers sections that are out of range, or no
+    /// sections at all.
+    BadSegment(usize),
+    /// A section in a loadable segment has an address lower than the
+    /// end of the previous section.
+    BadSectionAddr(usize),
+    /// The created ELF header was rejected.
+    BadElf(ElfError)
+}
+
+/// Sizes and offsets of the parts of an ELF file.
+struct Layout {
+    /// Offset of the program header table.
+    prog_hdrs_start: usize,
+    /// Number of program headers.
+    num_prog_hdrs: usize,
+    /// Number of local symbols.
+    num_locals: usize,
+    /// Position of the symbol table, if there is one.
+    symtab: Option<(usize, usize)>,
+    /// Position of the string table, if there is one.
+    strtab: Option<(usize, usize)>,
+    /// Position of the section header string table.
+    shstrtab: (usize, usize),
+    /// Offset of the section header table.
+    section_hdrs_start: usize,
+    /// Number of section headers.
+    num_section_hdrs: usize,
+    /// Total size.
+    size: usize
+}
+
+/// Iterator over the offsets of the sections in an [ElfBuilder].
+struct SectionOffsets<'a, Class: RelaOffsets> {
+    sections: &'a [BuilderSection<'a, Class>],
+    segments: &'a [BuilderSegment<Class>],
+    /// Index of the next section.
+    idx: usize,
+    /// End of the previous section.
+    pos: usize,
+    /// End index, starting offset, and starting address of the
+    /// current loadable segment.
+    load: Option<(usize, usize, usize)>
+}
+
+/// Get the number of bytes a section takes up in the file.
+#[inline]
+fn content_size<Class>(content: &BuilderContent<'_, Class>) -> usize
+    where Class: RelaOffsets {
+    match content {
+        BuilderContent::ProgBits { data, .. } => data.len(),
+        BuilderContent::Nobits { .. } => 0,
+        BuilderContent::Note { data } => data.len(),
+        BuilderContent::Rel { rels, .. } => rels.len() * Class::REL_SIZE,
+        BuilderContent::Rela { relas, .. } => relas.len() * Class::RELA_SIZE
+    }
+}
+
+/// Get the number of bytes a section takes up in memory.
+#[inline]
+fn mem_size<Class>(content: &BuilderContent<'_, Class>) ->
+    Result<usize, ElfBuilderError>
+    where Class: RelaOffsets {
+    match content {
+        BuilderContent::Nobits { size } => to_usize(*size),
+        content => Ok(content_size(content))
+    }
+}
+
+/// Get the file alignment of a section.
+#[inline]
+fn section_align<Class>(section: &BuilderSection<'_, Class>) ->
+    Result<usize, ElfBuilderError>
+    where Class: RelaOffsets {
+    let align = to_usize(section.align)?.max(1);
+
+    match section.content {
+        BuilderContent::Rel { .. } | BuilderContent::Rela { .. } =>
+            Ok(align.max(Class::ADDR_SIZE)),
+        _ => Ok(align)
+    }
+}
+
+#[inline]
+fn to_usize<T>(val: T) -> Result<usize, ElfBuilderError>
+    where T: TryInto<usize> {
+    val.try_into().map_err(|_| ElfBuilderError::TooLarge)
+}
+
+#[inline]
+fn from_usize<T>(val: usize) -> Result<T, ElfBuilderError>
+    where T: TryFrom<usize> {
+    T::try_from(val).map_err(|_| ElfBuilderError::TooLarge)
+}
+
+/// Get the index in the final symbol table of the symbol at `idx`
+/// plus one in `syms`.
+fn sym_idx<Class>(syms: &[BuilderSym<'_, Class>], idx: usize) ->
+    Option<usize>
+    where Class: ElfClass {
+    if idx == 0 {
+        Some(0)
+    } else if idx <= syms.len() {
+        let local = syms[idx - 1].bind == SymBind::Local;
+        let before = syms[.. idx - 1].iter()
+            .filter(|sym| (sym.bind == SymBind::Local) == local)
+            .count();
+
+        if local {
+            Some(before + 1)
+        } else {
+            let locals = syms.iter()
+                .filter(|sym| sym.bind == SymBind::Local)
+                .count();
+
+            Some(locals + before + 1)
+        }
+    } else {
+        None
+    }
+}
+
+/// Get the symbols in the order of the final symbol table.
+#[inline]
+fn ordered_syms<'a, 'b, Class>(syms: &'b [BuilderSym<'a, Class>]) ->
+    impl Iterator<Item = &'b BuilderSym<'a, Class>> + Clone
+    where Class: ElfClass {
+    syms.iter().filter(|sym| sym.bind == SymBind::Local)
+        .chain(syms.iter().filter(|sym| sym.bind != SymBind::Local))
+}
+
+/// Get the string table index of each name in `names`, in order.
+#[inline]
+fn str_idxs<'a, I>(names: I) -> impl 'a + Iterator<Item = usize> + Clone
+    where I: 'a + Iterator<Item = &'a str> + Clone {
+    names.scan(1, |idx, name| {
+        let out = *idx;
+
+        *idx += name.len() + 1;
+
+        Some(out)
+    })
+}
+
+/// Convert a symbol to the form written into the symbol table.
+fn raw_sym<Class>(sym: &BuilderSym<'_, Class>, name: Option<usize>,
+                  num_sections: usize, idx: usize) ->
+    Result<SymDataRaw<Class>, ElfBuilderError>
+    where Class: ElfClass {
+    let section = match sym.section {
+        SymBase::Undef => SymBase::Undef,
+        SymBase::Absolute => SymBase::Absolute,
+        SymBase::Common => SymBase::Common,
+        SymBase::Index(section) if section < num_sections &&
+                                   section + 1 < SHN_LORESERVE =>
+            SymBase::Index(Class::Half::from((section + 1) as u16)),
+        SymBase::ArchSpecific(code) => SymBase::ArchSpecific(code),
+        SymBase::OSSpecific(code) => SymBase::OSSpecific(code),
+        _ => return Err(ElfBuilderError::BadSymSection(idx))
+    };
+    let name = match name {
+        Some(name) => Some(from_usize(name)?),
+        None => None
+    };
+
+    Ok(SymData { name: name, value: sym.value, size: sym.size,
+                 kind: sym.kind, bind: sym.bind,
+                 visibility: sym.visibility, other: sym.other,
+                 section: section })
+}
+
+impl<'a, Class> SectionOffsets<'a, Class>
+    where Class: RelaOffsets {
+    #[inline]
+    fn new(sections: &'a [BuilderSection<'a, Class>],
+           segments: &'a [BuilderSegment<Class>], start: usize) -> Self {
+        SectionOffsets { sections: sections, segments: segments, idx: 0,
+                         pos: start, load: None }
+    }
+
+    /// Get the offset of the next section.
+    fn next_offset(&mut self) -> Result<usize, ElfBuilderError> {
+        let idx = self.idx;
+        let section = &self.sections[idx];
+        let addr: usize = to_usize(section.addr)?;
+        let start = pad(self.pos, section_align(section)?);
+
+        if let Some((end, _, _)) = self.load {
+            if idx >= end {
+                self.load = None
+            }
+        }
+
+        let offset = match self.load {
+            // Keep the same distances as the addresses.
+            Some((_, load_offset, load_addr)) => {
+                match addr.checked_sub(load_addr) {
+                    Some(diff) if load_offset + diff >= self.pos =>
+                        load_offset + diff,
+                    _ => return Err(ElfBuilderError::BadSectionAddr(idx))
+                }
+            },
+            None => {
+                let load = self.segments.iter().find_map(|seg| match seg {
+                    BuilderSegment { kind: BuilderSegmentKind::Load {
+                                         align, ..
+                                     },
+                                     first, num } if *first == idx =>
+                        Some((align, first + num)),
+                    _ => None
+                });
+
+                match load {
+                    // Match the address modulo the segment alignment.
+                    Some((align, end)) => {
+                        let align = to_usize(*align)?.max(1);
+                        let offset = start +
+                                     ((addr % align) + align - (start % align))
+                                     % align;
+
+                        self.load = Some((end, offset, addr));
+
+                        offset
+                    },
+                    None => start
+                }
+            }
+        };
+
+        self.pos = offset + content_size(&section.content);
+        self.idx += 1;
+
+        Ok(offset)
+    }
+}
+
+impl<'a, Class> Iterator for SectionOffsets<'a, Class>
+    where Class: RelaOffsets {
+    type Item = Result<usize, ElfBuilderError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx < self.sections.len() {
+            Some(self.next_offset())
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, B, Class> ElfBuilder<'a, B, Class>
+    where Class: ElfHdrOffsets,
+          B: ElfByteOrder {
+    /// Whether a symbol table will be created.
+    #[inline]
+    fn has_symtab(&self) -> bool {
+        !self.syms.is_empty() ||
+        self.sections.iter().any(|section| {
+            matches!(section.content, BuilderContent::Rel { .. } |
+                                      BuilderContent::Rela { .. })
+        })
+    }
+
+    /// Get the section names, in the order they appear in the section
+    /// header string table.
+    #[inline]
+    fn section_names(&self) -> impl Iterator<Item = &'a str> + Clone {
+        let extra: &'static [&'static str] = if self.has_symtab() {
+            &[SYMTAB_NAME, STRTAB_NAME, SHSTRTAB_NAME]
+        } else {
+            &[SHSTRTAB_NAME]
+        };
+
+        self.sections.iter().map(|section| section.name)
+            .chain(extra.iter().cloned())
+    }
+
+    fn layout(&self) -> Result<Layout, ElfBuilderError> {
+        let num_prog_hdrs = self.segments.len();
+        let prog_hdrs_start = Class::ELF_HDR_SIZE;
+        let mut pos = prog_hdrs_start + (num_prog_hdrs * Class::PROG_HDR_SIZE);
+        let offsets = SectionOffsets::new(self.sections, self.segments, pos);
+
+        for (section, offset) in self.sections.iter().zip(offsets) {
+            pos = offset? + content_size(&section.content);
+        }
+
+        let num_locals = self.syms.iter()
+            .filter(|sym| sym.bind == SymBind::Local)
+            .count();
+        let (symtab, strtab) = if self.has_symtab() {
+            let symtab_start = pad(pos, Class::ADDR_SIZE);
+            let symtab_size = (self.syms.len() + 1) * Class::ST_ENT_SIZE;
+            let strtab_start = symtab_start + symtab_size;
+            let strtab_size = Strtab::required_bytes(
+                self.syms.iter().filter_map(|sym| sym.name)
+            );
+
+            pos = strtab_start + strtab_size;
+
+            (Some((symtab_start, symtab_size)),
+             Some((strtab_start, strtab_size)))
+        } else {
+            (None, None)
+        };
+        let shstrtab_size = Strtab::required_bytes(self.section_names());
+        let shstrtab = (pos, shstrtab_size);
+        let section_hdrs_start = pad(pos + shstrtab_size, Class::ADDR_SIZE);
+        let num_section_hdrs = self.section_names().count() + 1;
+        let size = section_hdrs_start +
+                   (num_section_hdrs * Class::SECTION_HDR_SIZE);
+
+        // Everything else is smaller, so this checks all offsets.
+        // String table indexes must also fit in a word.
+        from_usize::<Class::Offset>(size)?;
+        from_usize::<Class::Word>(size)?;
+
+        Ok(Layout { prog_hdrs_start: prog_hdrs_start,
+                    num_prog_hdrs: num_prog_hdrs, num_locals: num_locals,
+                    symtab: symtab, strtab: strtab, shstrtab: shstrtab,
+                    section_hdrs_start: section_hdrs_start,
+                    num_section_hdrs: num_section_hdrs, size: size })
+    }
+
+    /// Get the program header for the segment at `idx`.
+    fn prog_hdr(&self, layout: &Layout, idx: usize) ->
+        Result<ProgHdrDataRaw<Class>, ElfBuilderError> {
+        let BuilderSegment { kind, first, num } = self.segments[idx];
+
+        if let BuilderSegmentKind::GnuStack { read, write, exec } = kind {
+            return Ok(ProgHdrData::GnuStack { size: (0 as u8).into(),
+                                              align: (0 as u8).into(),
+                                              read: read, write: write,
+                                              exec: exec })
+        }
+
+        let end = match first.checked_add(num) {
+            Some(end) if num > 0 && end <= self.sections.len() => end,
+            _ => return Err(ElfBuilderError::BadSegment(idx))
+        };
+        let data_start = layout.prog_hdrs_start +
+                         (layout.num_prog_hdrs * Class::PROG_HDR_SIZE);
+        let offsets = SectionOffsets::new(self.sections, self.segments,
+                                          data_start);
+        let addr = self.sections[first].addr;
+        let start_addr: usize = to_usize(addr)?;
+        let mut start = 0;
+        let mut file_end = 0;
+        let mut mem_end = start_addr;
+
+        for (i, offset) in offsets.enumerate().take(end).skip(first) {
+            let offset = offset?;
+            let section = &self.sections[i];
+            let section_addr: usize = to_usize(section.addr)?;
+            let size = content_size(&section.content);
+
+            if i == first {
+                start = offset;
+                file_end = offset;
+            }
+
+            if size > 0 {
+                file_end = offset + size;
+            }
+
+            mem_end = mem_end.max(section_addr + mem_size(&section.content)?);
+        }
+
+        let content = Segment { offset: from_usize(start)?,
+                                size: from_usize(file_end - start)? };
+        let mem_size = from_usize(mem_end - start_addr)?;
+
+        Ok(match kind {
+            BuilderSegmentKind::Load { align, read, write, exec } =>
+                ProgHdrData::Load { virt_addr: addr, phys_addr: addr,
+                                    mem_size: mem_size, align: align,
+                                    read: read, write: write, exec: exec,
+                                    content: content },
+            BuilderSegmentKind::Dynamic =>
+                ProgHdrData::Dynamic { virt_addr: addr, phys_addr: addr,
+                                       content: content },
+            BuilderSegmentKind::Interp =>
+                ProgHdrData::Interp { virt_addr: addr, phys_addr: addr,
+                                      str: content },
+            BuilderSegmentKind::Note =>
+                ProgHdrData::Note { virt_addr: addr, phys_addr: addr,
+                                    content: content },
+            BuilderSegmentKind::Tls { align } =>
+                ProgHdrData::Tls { virt_addr: addr, phys_addr: addr,
+                                   mem_size: mem_size, align: align,
+                                   read: true, write: false, exec: false,
+                                   content: content },
+            BuilderSegmentKind::GnuRelro =>
+                ProgHdrData::GnuRelro { virt_addr: addr, phys_addr: addr,
+                                        mem_size: content.size,
+                                        align: (1 as u8).into(),
+                                        read: true, write: false,
+                                        exec: false, content: content },
+            BuilderSegmentKind::GnuStack { read, write, exec } =>
+                ProgHdrData::GnuStack { size: (0 as u8).into(),
+                                        align: (0 as u8).into(), read: read,
+                                        write: write, exec: exec }
+        })
+    }
+
+    /// Get the section header for the section at `idx`.
+    fn section_hdr(&self, idx: usize, name: usize, offset: usize) ->
+        Result<SectionHdrDataRaw<Class>, ElfBuilderError> {
+        let BuilderSection { addr, align, flags, content, .. } =
+            self.sections[idx];
+        let name = from_usize(name)?;
+        let pos = SectionPos { offset: from_usize(offset)?,
+                               size: from_usize(content_size(&content))? };
+        let symtab = from_usize(self.sections.len() + 1)?;
+        let target = |target: usize| {
+            if target < self.sections.len() {
+                from_usize(target + 1)
+            } else {
+                Err(ElfBuilderError::BadTarget(idx))
+            }
+        };
+
+        Ok(match content {
+            BuilderContent::ProgBits { ent_size, .. } =>
+                SectionHdrData::ProgBits { name: name, addr: addr,
+                                           align: align, data: pos,
+                                           ent_size: ent_size, flags: flags },
+            BuilderContent::Nobits { size } =>
+                SectionHdrData::Nobits { name: name, addr: addr, align: align,
+                                         offset: pos.offset, size: size,
+                                         flags: flags },
+            BuilderContent::Note { .. } =>
+                SectionHdrData::Note { name: name, addr: addr, align: align,
+                                       note: pos, flags: flags },
+            BuilderContent::Rel { target: rel_target, .. } =>
+                SectionHdrData::Rel { name: name, addr: addr, align: align,
+                                      rels: pos, symtab: symtab,
+                                      target: target(rel_target)?,
+                                      flags: flags },
+            BuilderContent::Rela { target: rela_target, .. } =>
+                SectionHdrData::Rela { name: name, addr: addr, align: align,
+                                       relas: pos, symtab: symtab,
+                                       target: target(rela_target)?,
+                                       flags: flags }
+        })
+    }
+
+    /// Write the contents of the section at `idx` into `buf`.
+    fn write_section(&self, buf: &mut [u8], idx: usize) ->
+        Result<(), ElfBuilderError> {
+        let bad_sym = ElfBuilderError::BadRelocSym(idx);
+
+        match self.sections[idx].content {
+            BuilderContent::ProgBits { data, .. } |
+            BuilderContent::Note { data } => buf.clone_from_slice(data),
+            BuilderContent::Nobits { .. } => {},
+            BuilderContent::Rel { rels, .. } => {
+                for rel in rels {
+                    sym_idx(self.syms, rel.sym.into() as usize)
+                        .ok_or(bad_sym)?;
+                }
+
+                let rels = rels.iter().map(|rel| RelData {
+                    offset: rel.offset, kind: rel.kind,
+                    sym: sym_idx(self.syms, rel.sym.into() as usize)
+                        .map_or(rel.sym, |sym| Class::Word::from(sym as u32))
+                });
+                let res: Result<Rels<'_, B, Class>, ()> =
+                    Rels::create(buf, rels);
+
+                res.map_err(|_| ElfBuilderError::TooSmall)?;
+            },
+            BuilderContent::Rela { relas, .. } => {
+                for rela in relas {
+                    sym_idx(self.syms, rela.sym.into() as usize)
+                        .ok_or(bad_sym)?;
+                }
+
+                let relas = relas.iter().map(|rela| RelaData {
+                    offset: rela.offset, kind: rela.kind,
+                    addend: rela.addend,
+                    sym: sym_idx(self.syms, rela.sym.into() as usize)
+                        .map_or(rela.sym, |sym| Class::Word::from(sym as u32))
+                });
+                let res: Result<Relas<'_, B, Class>, ()> =
+                    Relas::create(buf, relas);
+
+                res.map_err(|_| ElfBuilderError::TooSmall)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Calculate the number of bytes required to create the ELF file.
+    ///
+    /// # Errors
+    ///
+    /// Errors can occur if the file can't be laid out (see
+    /// [ElfBuilderError]).
+    #[inline]
+    pub fn required_bytes(&self) -> Result<usize, ElfBuilderError> {
+        Ok(self.layout()?.size)
+    }
+
+    /// Attempt to create the ELF file in `buf`, and return it along
+    /// with the unused space.
+    ///
+    /// The sections are placed after the ELF header and program
+    /// header table, followed by the symbol table and its string
+    /// table (if any), the section header string table, and finally
+    /// the section header table.  Any space between them is filled
+    /// with zeros.
+    ///
+    /// # Errors
+    ///
+    /// Errors can occur if the file doesn't fit into the provided
+    /// memory, or if the file can't be laid out (see
+    /// [ElfBuilderError]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryInto;
+    /// use core::marker::PhantomData;
+    /// use elf_utils::Elf;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::ElfABI;
+    /// use elf_utils::ElfArch;
+    /// use elf_utils::ElfHdrDataRaw;
+    /// use elf_utils::ElfKind;
+    /// use elf_utils::ElfTable;
+    /// use elf_utils::builder::BuilderContent;
+    /// use elf_utils::builder::BuilderSection;
+    /// use elf_utils::builder::BuilderSegment;
+    /// use elf_utils::builder::BuilderSegmentKind;
+    /// use elf_utils::builder::ElfBuilder;
+    /// use elf_utils::section_hdr::SectionFlags;
+    ///
+    /// const TEXT: [u8; 4] = [0x31, 0xc0, 0xc3, 0x90];
+    /// const SECTIONS: [BuilderSection<'static, Elf64>; 2] = [
+    ///     BuilderSection {
+    ///         name: ".text", addr: 0x401000, align: 16,
+    ///         flags: SectionFlags { bits: 0x6 },
+    ///         content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+    ///     },
+    ///     BuilderSection {
+    ///         name: ".bss", addr: 0x401010, align: 16,
+    ///         flags: SectionFlags { bits: 0x3 },
+    ///         content: BuilderContent::Nobits { size: 0x100 }
+    ///     }
+    /// ];
+    /// const SEGMENTS: [BuilderSegment<Elf64>; 1] = [
+    ///     BuilderSegment {
+    ///         kind: BuilderSegmentKind::Load { align: 0x1000, read: true,
+    ///                                          write: true, exec: true },
+    ///         first: 0, num: 2
+    ///     }
+    /// ];
+    ///
+    /// let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+    ///     byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+    ///     kind: ElfKind::Executable, arch: ElfArch::X86_64,
+    ///     entry: 0x401000, flags: 0, sections: &SECTIONS,
+    ///     segments: &SEGMENTS, syms: &[]
+    /// };
+    /// let mut buf = [0; 0x2000];
+    /// let (elf, rest): (Elf<'_, LittleEndian, Elf64>, &'_ mut [u8]) =
+    ///     builder.create_split(&mut buf[0..]).expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 0x2000 - builder.required_bytes().unwrap());
+    ///
+    /// let hdr: ElfHdrDataRaw<LittleEndian, Elf64> =
+    ///     elf.try_into().expect("Expected success");
+    ///
+    /// assert_eq!(hdr.entry, 0x401000);
+    /// assert_eq!(hdr.prog_hdrs, Some(ElfTable { offset: 64, num_ents: 1 }));
+    /// ```
+    pub fn create_split<'b>(&self, buf: &'b mut [u8]) ->
+        Result<(Elf<'b, B, Class>, &'b mut [u8]), ElfBuilderError> {
+        let layout = self.layout()?;
+
+        if buf.len() < layout.size {
+            return Err(ElfBuilderError::TooSmall)
+        }
+
+        let (data, out) = buf.split_at_mut(layout.size);
+        let num_sections = self.sections.len();
+        let shstrtab_idx = layout.num_section_hdrs - 1;
+        let ext = layout.num_section_hdrs >= SHN_LORESERVE ||
+                  layout.num_prog_hdrs >= PN_XNUM as usize;
+        let prog_hdrs = if layout.num_prog_hdrs > 0 {
+            Some(ElfTable { offset: from_usize(layout.prog_hdrs_start)?,
+                            num_ents: from_usize(layout.num_prog_hdrs)? })
+        } else {
+            None
+        };
+        let hdr: ElfHdrDataRaw<B, Class> = ElfHdrData {
+            byteorder: PhantomData, abi: self.abi,
+            abi_version: self.abi_version, kind: self.kind, arch: self.arch,
+            entry: self.entry, flags: self.flags, prog_hdrs: prog_hdrs,
+            section_hdrs: ElfTable {
+                offset: from_usize(layout.section_hdrs_start)?,
+                num_ents: from_usize(layout.num_section_hdrs)?
+            },
+            section_hdr_strtab: from_usize(shstrtab_idx)?
+        };
+
+        for byte in data.iter_mut() {
+            *byte = 0;
+        }
+
+        let elf: Result<Elf<'_, B, Class>, ()> =
+            Elf::create(&mut data[.. Class::ELF_HDR_SIZE], hdr);
+
+        elf.map_err(|_| ElfBuilderError::TooSmall)?;
+
+        // Program headers.
+        for i in 0 .. layout.num_prog_hdrs {
+            let start = layout.prog_hdrs_start + (i * Class::PROG_HDR_SIZE);
+            let end = start + Class::PROG_HDR_SIZE;
+            let hdr = self.prog_hdr(&layout, i)?;
+            let hdrs: Result<ProgHdrs<'_, B, Class>, ()> =
+                ProgHdrs::create(&mut data[start .. end], once(hdr));
+
+            hdrs.map_err(|_| ElfBuilderError::TooSmall)?;
+        }
+
+        // Section contents and headers.
+        let data_start = layout.prog_hdrs_start +
+                         (layout.num_prog_hdrs * Class::PROG_HDR_SIZE);
+        let offsets = SectionOffsets::new(self.sections, self.segments,
+                                          data_start);
+        let names = str_idxs(self.section_names());
+        let null_hdr = if ext {
+            let num_section_hdrs = if layout.num_section_hdrs >=
+                                      SHN_LORESERVE {
+                from_usize(layout.num_section_hdrs)?
+            } else {
+                (0 as u8).into()
+            };
+            let section_hdr_strtab = if shstrtab_idx >= SHN_LORESERVE {
+                from_usize(shstrtab_idx)?
+            } else {
+                (0 as u8).into()
+            };
+            let num_prog_hdrs = if layout.num_prog_hdrs >= PN_XNUM as usize {
+                from_usize(layout.num_prog_hdrs)?
+            } else {
+                (0 as u8).into()
+            };
+
+            SectionHdrData::ExtNull { num_section_hdrs: num_section_hdrs,
+                                      section_hdr_strtab: section_hdr_strtab,
+                                      num_prog_hdrs: num_prog_hdrs }
+        } else {
+            SectionHdrData::Null
+        };
+
+        self.write_section_hdr(data, &layout, 0, null_hdr)?;
+
+        for (i, (offset, name)) in offsets.zip(names.clone()).enumerate() {
+            let offset = offset?;
+            let end = offset + content_size(&self.sections[i].content);
+            let hdr = self.section_hdr(i, name, offset)?;
+
+            self.write_section(&mut data[offset .. end], i)?;
+            self.write_section_hdr(data, &layout, i + 1, hdr)?;
+        }
+
+        let mut names = names.skip(num_sections);
+        let zero_flags = SectionFlags { bits: (0 as u8).into() };
+
+        // Symbol table and its string table.
+        if let (Some((symtab_start, symtab_size)),
+                Some((strtab_start, strtab_size))) =
+            (layout.symtab, layout.strtab) {
+            let sym_names = ordered_syms(self.syms)
+                .filter_map(|sym| sym.name);
+            let null = SymData {
+                name: None, value: (0 as u8).into(), size: (0 as u8).into(),
+                kind: SymKind::None, bind: SymBind::Local,
+                visibility: SymVisibility::Default, other: 0,
+                section: SymBase::Undef
+            };
+            let symtab_end = symtab_start + symtab_size;
+            let strtab_end = strtab_start + strtab_size;
+            let symtab_idx = num_sections + 1;
+
+            for (i, sym) in self.syms.iter().enumerate() {
+                raw_sym(sym, None, num_sections, i)?;
+            }
+
+            let mut name_idxs = str_idxs(sym_names.clone());
+            let syms = ordered_syms(self.syms).map(|sym| {
+                let name = sym.name.and_then(|_| name_idxs.next());
+
+                raw_sym(sym, name, num_sections, 0).unwrap_or(null.clone())
+            });
+            let res: Result<Symtab<'_, B, Class>, ()> =
+                Symtab::create(&mut data[symtab_start .. symtab_end],
+                               once(null.clone()).chain(syms));
+
+            res.map_err(|_| ElfBuilderError::TooSmall)?;
+
+            let res: Result<Strtab<'_>, ()> =
+                Strtab::create(&mut data[strtab_start .. strtab_end],
+                               sym_names);
+
+            res.map_err(|_| ElfBuilderError::TooSmall)?;
+
+            let symtab_hdr = SectionHdrData::Symtab {
+                name: from_usize(names.next().unwrap_or(0))?,
+                addr: (0 as u8).into(), align: from_usize(Class::ADDR_SIZE)?,
+                syms: SectionPos { offset: from_usize(symtab_start)?,
+                                   size: from_usize(symtab_size)? },
+                strtab: from_usize(symtab_idx + 1)?,
+                local_end: from_usize(layout.num_locals + 1)?,
+                flags: zero_flags
+            };
+            let strtab_hdr = SectionHdrData::Strtab {
+                name: from_usize(names.next().unwrap_or(0))?,
+                addr: (0 as u8).into(), align: (1 as u8).into(),
+                strs: SectionPos { offset: from_usize(strtab_start)?,
+                                   size: from_usize(strtab_size)? },
+                flags: zero_flags
+            };
+
+            self.write_section_hdr(data, &layout, symtab_idx, symtab_hdr)?;
+            self.write_section_hdr(data, &layout, symtab_idx + 1,
+                                   strtab_hdr)?;
+        }
+
+        // Section header string table.
+        let (shstrtab_start, shstrtab_size) = layout.shstrtab;
+        let res: Result<Strtab<'_>, ()> =
+            Strtab::create(&mut data[shstrtab_start ..
+                                     shstrtab_start + shstrtab_size],
+                           self.section_names());
+
+        res.map_err(|_| ElfBuilderError::TooSmall)?;
+
+        let shstrtab_hdr = SectionHdrData::Strtab {
+            name: from_usize(names.next().unwrap_or(0))?,
+            addr: (0 as u8).into(), align: (1 as u8).into(),
+            strs: SectionPos { offset: from_usize(shstrtab_start)?,
+                               size: from_usize(shstrtab_size)? },
+            flags: zero_flags
+        };
+
+        self.write_section_hdr(data, &layout, shstrtab_idx, shstrtab_hdr)?;
+
+        match Elf::try_from(&*data) {
+            Ok(elf) => Ok((elf, out)),
+            Err(err) => Err(ElfBuilderError::BadElf(err))
+        }
+    }
+
+    /// Attempt to create the ELF file in `buf` (see
+    /// [create_split](ElfBuilder::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// Errors can occur if the file doesn't fit into the provided
+    /// memory, or if the file can't be laid out (see
+    /// [ElfBuilderError]).
+    #[inline]
+    pub fn create<'b>(&self, buf: &'b mut [u8]) ->
+        Result<Elf<'b, B, Class>, ElfBuilderError> {
+        match self.create_split(buf) {
+            Ok((elf, _)) => Ok(elf),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Write the section header at `idx`.
+    #[inline]
+    fn write_section_hdr(&self, data: &mut [u8], layout: &Layout,
+                         idx: usize, hdr: SectionHdrDataRaw<Class>) ->
+        Result<(), ElfBuilderError> {
+        let start = layout.section_hdrs_start + (idx * Class::SECTION_HDR_SIZE);
+        let end = start + Class::SECTION_HDR_SIZE;
+        let hdrs: Result<SectionHdrs<'_, B, Class>, ()> =
+            SectionHdrs::create(&mut data[start .. end], once(hdr));
+
+        hdrs.map(|_| ()).map_err(|_| ElfBuilderError::TooSmall)
+    }
+}
+
+impl Display for ElfBuilderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ElfBuilderError::TooSmall => write!(f, "buffer too small"),
+            ElfBuilderError::TooLarge =>
+                write!(f, "file too large for ELF class"),
+            ElfBuilderError::BadTarget(idx) =>
+                write!(f, "bad relocation target in section {}", idx),
+            ElfBuilderError::BadRelocSym(idx) =>
+                write!(f, "bad relocation symbol in section {}", idx),
+            ElfBuilderError::BadSymSection(idx) =>
+                write!(f, "bad section for symbol {}", idx),
+            ElfBuilderError::BadSegment(idx) =>
+                write!(f, "bad section range for segment {}", idx),
+            ElfBuilderError::BadSectionAddr(idx) =>
+                write!(f, "address of section {} overlaps previous section",
+                       idx),
+            ElfBuilderError::BadElf(err) =>
+                write!(f, "bad ELF header: {}", err)
+        }
+    }
+}
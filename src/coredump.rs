@@ -0,0 +1,2215 @@
+//! ELF core dump functionality.
+//!
+//! This module provides a [Core] type which acts as a wrapper around
+//! the contents of an ELF core dump (an ELF file with kind
+//! [Core](crate::ElfKind::Core)).  A core dump records the state of a
+//! process: its memory is stored in `PT_LOAD` segments, and its
+//! threads, registers, and other information are stored as notes in
+//! `PT_NOTE` segments.
+//!
+//! The notes in a core dump can be iterated over with
+//! [notes](Core::notes), and the notes written by Linux can be
+//! decoded into a [CoreNote] using the
+//! [TryFrom](core::convert::TryFrom) instance on [CoreNoteRaw].  The
+//! memory in a core dump can be iterated over with
+//! [mem_ranges](Core::mem_ranges), or read by address using
+//! [read](Core::read).
+//!
+//! A core dump can be created from a set of notes and memory ranges
+//! using [create](Core::create).  The notes are given as
+//! [CoreNoteRefs], which allows register sets, auxiliary vectors, and
+//! mapped file tables to be built from ordinary values and slices.
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use elf_utils::Elf64;
+//! use elf_utils::ElfArch;
+//! use elf_utils::coredump::AuxvEnt;
+//! use elf_utils::coredump::Core;
+//! use elf_utils::coredump::CoreMemData;
+//! use elf_utils::coredump::CoreNote;
+//! use elf_utils::coredump::CoreNoteRaw;
+//!
+//! const CORE: [u8; 236] = [
+//!     0x7f, 0x45, 0x4c, 0x46, 0x02, 0x01, 0x01, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x04, 0x00, 0x3e, 0x00, 0x01, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x38, 0x00,
+//!     0x02, 0x00, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0xb0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x01, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+//!     0xe4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x10, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x05, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+//!     0x06, 0x00, 0x00, 0x00, 0x43, 0x4f, 0x52, 0x45,
+//!     0x00, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04,
+//!     0x05, 0x06, 0x07, 0x08
+//! ];
+//!
+//! let core: Core<'_, LittleEndian, Elf64> =
+//!     Core::try_from(&CORE[0..]).unwrap();
+//!
+//! assert_eq!(core.arch(), ElfArch::X86_64);
+//!
+//! let note = core.notes().next().unwrap();
+//! let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+//!     CoreNote::try_from(note).unwrap();
+//!
+//! match note {
+//!     CoreNote::Auxv { auxv } => {
+//!         let mut iter = auxv.iter();
+//!
+//!         assert_eq!(iter.next(), Some(AuxvEnt::PageSize { size: 0x1000 }));
+//!         assert_eq!(iter.next(), Some(AuxvEnt::Null));
+//!         assert_eq!(iter.next(), None);
+//!     },
+//!     _ => panic!("Expected auxv note")
+//! }
+//!
+//! let mem: CoreMemData<'_, Elf64> = core.mem_ranges().next().unwrap();
+//!
+//! assert_eq!(mem.addr, 0x401000);
+//! assert_eq!(mem.size, 0x1000);
+//! assert_eq!(mem.data.len(), 8);
+//! assert_eq!(core.read(0x401002, 2), Some(&[0x03, 0x04][0..]));
+//! assert_eq!(core.read(0x401006, 4), None);
+//! ```
+
+use byteorder::ByteOrder;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::iter::Iterator;
+use core::iter::once;
+use core::marker::PhantomData;
+use crate::elf::Elf;
+use crate::elf::ElfABI;
+use crate::elf::ElfArch;
+use crate::elf::ElfByteOrder;
+use crate::elf::ElfClass;
+use crate::elf::ElfError;
+use crate::elf::ElfHdrData;
+use crate::elf::ElfHdrDataError;
+use crate::elf::ElfHdrDataRaw;
+use crate::elf::ElfHdrOffsets;
+use crate::elf::ElfKind;
+use crate::elf::ElfTable;
+use crate::elf::PN_XNUM;
+use crate::elf::WithElfData;
+use crate::note::note_size;
+use crate::note::pad;
+use crate::note::write_note;
+use crate::note::NoteData;
+use crate::note::Notes;
+use crate::note::NotesError;
+use crate::note::NotesIter;
+use crate::prog_hdr::ProgHdrData;
+use crate::prog_hdr::ProgHdrDataBufs;
+use crate::prog_hdr::ProgHdrDataRaw;
+use crate::prog_hdr::ProgHdrError;
+use crate::prog_hdr::ProgHdrIter;
+use crate::prog_hdr::ProgHdrOffsets;
+use crate::prog_hdr::ProgHdrs;
+use crate::prog_hdr::ProgHdrsError;
+use crate::prog_hdr::Segment;
+use crate::section_hdr::SectionHdrData;
+use crate::section_hdr::SectionHdrs;
+
+const NOTE_NAME_CORE: &[u8] = b"CORE\0";
+
+const NT_PRSTATUS: u32 = 1;
+const NT_PRPSINFO: u32 = 3;
+const NT_AUXV: u32 = 6;
+const NT_SIGINFO: u32 = 0x53494749;
+const NT_FILE: u32 = 0x46494c45;
+
+const AT_NULL: usize = 0;
+const AT_EXECFD: usize = 2;
+const AT_PHDR: usize = 3;
+const AT_PHENT: usize = 4;
+const AT_PHNUM: usize = 5;
+const AT_PAGESZ: usize = 6;
+const AT_BASE: usize = 7;
+const AT_FLAGS: usize = 8;
+const AT_ENTRY: usize = 9;
+const AT_UID: usize = 11;
+const AT_EUID: usize = 12;
+const AT_GID: usize = 13;
+const AT_EGID: usize = 14;
+const AT_PLATFORM: usize = 15;
+const AT_HWCAP: usize = 16;
+const AT_CLKTCK: usize = 17;
+const AT_SECURE: usize = 23;
+const AT_BASE_PLATFORM: usize = 24;
+const AT_RANDOM: usize = 25;
+const AT_HWCAP2: usize = 26;
+const AT_EXECFN: usize = 31;
+const AT_SYSINFO_EHDR: usize = 33;
+const AT_MINSIGSTKSZ: usize = 51;
+
+const SIGILL: i32 = 4;
+const SIGTRAP: i32 = 5;
+const SIGBUS: i32 = 7;
+const SIGFPE: i32 = 8;
+const SIGSEGV: i32 = 11;
+const SIGCHLD: i32 = 17;
+
+const SIGINFO_SIZE: usize = 128;
+
+const PRSTATUS_SIGPEND_START: usize = 16;
+
+const PRPSINFO_FNAME_SIZE: usize = 16;
+const PRPSINFO_PSARGS_SIZE: usize = 80;
+const PRPSINFO_FIXED_SIZE: usize = 16 + PRPSINFO_FNAME_SIZE +
+                                   PRPSINFO_PSARGS_SIZE;
+
+const X86_64_NUM_REGS: usize = 27;
+const AARCH64_NUM_REGS: usize = 34;
+
+/// In-place read-only ELF core dump.
+///
+/// A `Core` is essentially a 'handle' for the raw data of an ELF
+/// core dump.  It can be created from the complete data using the
+/// [TryFrom](core::convert::TryFrom) instance, which will check that
+/// the data is a core dump, and that its `PT_LOAD` and `PT_NOTE`
+/// segments are well-formed.
+///
+/// The notes in the core dump can be iterated over with
+/// [notes](Core::notes), and the memory can be accessed with
+/// [mem_ranges](Core::mem_ranges) and [read](Core::read).  A new
+/// core dump can be written with [create](Core::create).
+#[derive(Copy, Clone)]
+pub struct Core<'a, B: ByteOrder, Class: ProgHdrOffsets> {
+    byteorder: PhantomData<B>,
+    arch: ElfArch,
+    hdrs: ProgHdrs<'a, B, Class>,
+    data: &'a [u8]
+}
+
+/// Iterator over the notes in all of the `PT_NOTE` segments of a
+/// [Core].
+#[derive(Clone)]
+pub struct CoreNotesIter<'a, B: ByteOrder, Class: ProgHdrOffsets> {
+    hdrs: ProgHdrIter<'a, B, Class>,
+    notes: Option<NotesIter<'a, B>>,
+    data: &'a [u8]
+}
+
+/// Iterator over the memory ranges in the `PT_LOAD` segments of a
+/// [Core].
+#[derive(Clone)]
+pub struct CoreMemIter<'a, B: ByteOrder, Class: ProgHdrOffsets> {
+    hdrs: ProgHdrIter<'a, B, Class>,
+    data: &'a [u8]
+}
+
+/// A range of process memory stored in a [Core].
+///
+/// This is produced from a `PT_LOAD` segment by
+/// [mem_ranges](Core::mem_ranges).  Memory which was not dumped
+/// (such as read-only file mappings) will have less `data` than its
+/// `size`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CoreMemData<'a, Class: ElfClass> {
+    /// Start address of the range.
+    pub addr: Class::Addr,
+    /// Size of the range in memory.
+    pub size: Class::Offset,
+    /// Whether the range is readable.
+    pub read: bool,
+    /// Whether the range is writable.
+    pub write: bool,
+    /// Whether the range is executable.
+    pub exec: bool,
+    /// Contents of the range present in the core dump.
+    pub data: &'a [u8]
+}
+
+/// Errors that can occur creating a [Core].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CoreError<Class: ProgHdrOffsets> {
+    /// The ELF header was malformed.
+    BadElf(ElfError),
+    /// The ELF header could not be projected.
+    BadHdr(ElfHdrDataError<Class>),
+    /// The ELF data is not a core dump.
+    NotCore(ElfKind),
+    /// The ELF data has no program header table.
+    NoProgHdrs,
+    /// The program header table is out of bounds.
+    ProgHdrsOutOfBounds(Class::Offset),
+    /// The program header table was malformed.
+    BadProgHdrs(ProgHdrsError),
+    /// A program header was malformed.
+    BadProgHdr(ProgHdrError<Class>),
+    /// A `PT_NOTE` segment was malformed.
+    BadNotes(NotesError)
+}
+
+/// Raw register set from a `NT_PRSTATUS` note.
+///
+/// The layout of the registers depends on the architecture, which is
+/// not recorded in the note itself.  The registers can be decoded
+/// using [regs](PrStatusRegs::regs) with the architecture of the
+/// [Core].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PrStatusRegs<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8]
+}
+
+/// General-purpose registers on x86-64.
+///
+/// This is the layout of `struct user_regs_struct`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct X86_64Regs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    /// Value of `rax` on entry to a system call.
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64
+}
+
+/// General-purpose registers on AArch64.
+///
+/// This is the layout of `struct user_pt_regs`.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AArch64Regs {
+    /// Registers `x0` through `x30`.
+    pub regs: [u64; 31],
+    pub sp: u64,
+    pub pc: u64,
+    pub pstate: u64
+}
+
+/// Register set decoded for a specific architecture.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CoreRegs<'a> {
+    /// x86-64 registers.
+    X86_64(X86_64Regs),
+    /// AArch64 registers.
+    AArch64(AArch64Regs),
+    /// Registers for any other architecture, or with an unexpected
+    /// size.
+    Other(&'a [u8])
+}
+
+/// Time value from a `NT_PRSTATUS` note.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TimeVal<Word> {
+    /// Seconds.
+    pub sec: Word,
+    /// Microseconds.
+    pub usec: Word
+}
+
+/// Thread status from a `NT_PRSTATUS` note.
+///
+/// A core dump contains one of these for each thread, with the
+/// thread that caused the dump first.  The `Regs` parameter is the
+/// representation of the register set, which will be a
+/// [PrStatusRegs] when decoded from existing data.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PrStatus<Class: ElfClass, Regs> {
+    /// Signal number from `pr_info`.
+    pub signo: i32,
+    /// Signal code from `pr_info`.
+    pub code: i32,
+    /// Error number from `pr_info`.
+    pub errno: i32,
+    /// Current signal.
+    pub cursig: u16,
+    /// Set of pending signals.
+    pub sigpend: Class::Addr,
+    /// Set of held signals.
+    pub sighold: Class::Addr,
+    /// Thread ID.
+    pub pid: i32,
+    /// Parent process ID.
+    pub ppid: i32,
+    /// Process group ID.
+    pub pgrp: i32,
+    /// Session ID.
+    pub sid: i32,
+    /// User time.
+    pub utime: TimeVal<Class::Addr>,
+    /// System time.
+    pub stime: TimeVal<Class::Addr>,
+    /// Cumulative user time.
+    pub cutime: TimeVal<Class::Addr>,
+    /// Cumulative system time.
+    pub cstime: TimeVal<Class::Addr>,
+    /// General-purpose registers.
+    pub regs: Regs,
+    /// Whether floating point registers are present.
+    pub fp_valid: bool
+}
+
+/// Process information from a `NT_PRPSINFO` note.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PrPsInfo<'a, Class: ElfClass> {
+    /// Numeric process state.
+    pub state: u8,
+    /// Character representing the process state.
+    pub sname: u8,
+    /// Whether the process is a zombie.
+    pub zombie: bool,
+    /// Nice value.
+    pub nice: i8,
+    /// Process flags.
+    pub flags: Class::Addr,
+    /// User ID.
+    pub uid: u32,
+    /// Group ID.
+    pub gid: u32,
+    /// Process ID.
+    pub pid: i32,
+    /// Parent process ID.
+    pub ppid: i32,
+    /// Process group ID.
+    pub pgrp: i32,
+    /// Session ID.
+    pub sid: i32,
+    /// Name of the executable, without the terminating `NUL`.
+    pub fname: &'a str,
+    /// Initial part of the argument list, without the terminating
+    /// `NUL`.
+    pub psargs: &'a str
+}
+
+/// Signal-specific fields from a `NT_SIGINFO` note.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SigFields<'a, Class: ElfClass> {
+    /// Signal sent by a process.
+    Kill {
+        /// Sending process ID.
+        pid: i32,
+        /// Sending user ID.
+        uid: u32
+    },
+    /// Signal generated by a fault.
+    Fault {
+        /// Faulting address.
+        addr: Class::Addr
+    },
+    /// Child process status change.
+    Child {
+        /// Child process ID.
+        pid: i32,
+        /// Child user ID.
+        uid: u32,
+        /// Exit status or signal.
+        status: i32
+    },
+    /// Any other signal, giving the raw union data.
+    Other(&'a [u8])
+}
+
+/// Signal information from a `NT_SIGINFO` note.
+///
+/// This is the `siginfo_t` for the signal that caused the dump.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SigInfo<'a, Class: ElfClass> {
+    /// Signal number.
+    pub signo: i32,
+    /// Error number.
+    pub errno: i32,
+    /// Signal code.
+    pub code: i32,
+    /// Signal-specific fields.
+    pub fields: SigFields<'a, Class>
+}
+
+/// In-place read-only auxiliary vector from a `NT_AUXV` note.
+///
+/// This can be created from the note descriptor using the
+/// [TryFrom](core::convert::TryFrom) instance, and iterated over with
+/// [iter](Auxv::iter).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Auxv<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8]
+}
+
+/// Iterator over the entries in an [Auxv].
+#[derive(Copy, Clone)]
+pub struct AuxvIter<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8],
+    idx: usize
+}
+
+/// Projected auxiliary vector entry.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum AuxvEnt<Class: ElfClass> {
+    /// End of the vector (`AT_NULL`).
+    Null,
+    /// File descriptor of the program (`AT_EXECFD`).
+    ExecFd {
+        /// File descriptor.
+        fd: Class::Addr
+    },
+    /// Address of the program headers (`AT_PHDR`).
+    Phdr {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Size of a program header (`AT_PHENT`).
+    PhEnt {
+        /// Size.
+        size: Class::Addr
+    },
+    /// Number of program headers (`AT_PHNUM`).
+    PhNum {
+        /// Number of headers.
+        num: Class::Addr
+    },
+    /// System page size (`AT_PAGESZ`).
+    PageSize {
+        /// Page size.
+        size: Class::Addr
+    },
+    /// Base address of the interpreter (`AT_BASE`).
+    Base {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Flags (`AT_FLAGS`).
+    Flags {
+        /// Flags.
+        flags: Class::Addr
+    },
+    /// Entry point of the program (`AT_ENTRY`).
+    Entry {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Real user ID (`AT_UID`).
+    Uid {
+        /// User ID.
+        id: Class::Addr
+    },
+    /// Effective user ID (`AT_EUID`).
+    EUid {
+        /// User ID.
+        id: Class::Addr
+    },
+    /// Real group ID (`AT_GID`).
+    Gid {
+        /// Group ID.
+        id: Class::Addr
+    },
+    /// Effective group ID (`AT_EGID`).
+    EGid {
+        /// Group ID.
+        id: Class::Addr
+    },
+    /// Address of the platform string (`AT_PLATFORM`).
+    Platform {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Hardware capabilities (`AT_HWCAP`).
+    HwCap {
+        /// Capability bits.
+        bits: Class::Addr
+    },
+    /// Frequency of `times()` (`AT_CLKTCK`).
+    ClockTick {
+        /// Ticks per second.
+        hz: Class::Addr
+    },
+    /// Whether the program is running in secure mode (`AT_SECURE`).
+    Secure {
+        /// Secure mode.
+        secure: bool
+    },
+    /// Address of the base platform string (`AT_BASE_PLATFORM`).
+    BasePlatform {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Address of 16 random bytes (`AT_RANDOM`).
+    Random {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Extended hardware capabilities (`AT_HWCAP2`).
+    HwCap2 {
+        /// Capability bits.
+        bits: Class::Addr
+    },
+    /// Address of the program file name (`AT_EXECFN`).
+    ExecFn {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Address of the vDSO ELF header (`AT_SYSINFO_EHDR`).
+    SysInfoEhdr {
+        /// Address.
+        addr: Class::Addr
+    },
+    /// Minimum signal stack size (`AT_MINSIGSTKSZ`).
+    MinSigStackSize {
+        /// Size.
+        size: Class::Addr
+    },
+    /// Any other entry.
+    Unknown {
+        /// Entry type.
+        tag: Class::Addr,
+        /// Entry value.
+        val: Class::Addr
+    }
+}
+
+/// Errors that can occur creating an [Auxv].
+///
+/// The only error that can occur is if the data is not a multiple of
+/// the size of an entry.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AuxvError {
+    BadSize(usize)
+}
+
+/// In-place read-only mapped file table from a `NT_FILE` note.
+///
+/// This can be created from the note descriptor using the
+/// [TryFrom](core::convert::TryFrom) instance, which checks that all
+/// file names are present and valid UTF-8.  It can be iterated over
+/// with [iter](MappedFiles::iter).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MappedFiles<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8],
+    num: usize
+}
+
+/// Iterator over the entries in a [MappedFiles].
+#[derive(Copy, Clone)]
+pub struct MappedFilesIter<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8],
+    num: usize,
+    idx: usize,
+    name_idx: usize
+}
+
+/// Projected mapped file table entry.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MappedFileData<'a, Class: ElfClass> {
+    /// Start address of the mapping.
+    pub start: Class::Addr,
+    /// End address of the mapping.
+    pub end: Class::Addr,
+    /// Offset into the file, in units of the table's page size.
+    pub page_offset: Class::Addr,
+    /// Path to the file.
+    pub name: &'a str
+}
+
+/// Errors that can occur creating a [MappedFiles].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MappedFilesError {
+    /// The table or file names ran past the end of the data.
+    TooShort,
+    /// A file name was not valid UTF-8.
+    BadStr
+}
+
+/// Semantic contents of a note in a core dump.
+///
+/// This is a typed interpretation of a [NoteData] written by Linux
+/// with the name `CORE`.  A `CoreNote` is obtained from a `NoteData`
+/// using the [TryFrom](core::convert::TryFrom) instance on
+/// [CoreNoteRaw]; notes that are not recognized are preserved as
+/// [Other](CoreNote::Other).
+///
+/// The `Regs`, `Ents`, and `Files` parameters are the representation
+/// of the register set, auxiliary vector, and mapped file table.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CoreNote<'a, Class: ElfClass, Regs, Ents, Files> {
+    /// Thread status (`NT_PRSTATUS`).
+    PrStatus(PrStatus<Class, Regs>),
+    /// Process information (`NT_PRPSINFO`).
+    PrPsInfo(PrPsInfo<'a, Class>),
+    /// Auxiliary vector (`NT_AUXV`).
+    Auxv {
+        /// Auxiliary vector entries.
+        auxv: Ents
+    },
+    /// Mapped file table (`NT_FILE`).
+    File {
+        /// Page size used for file offsets.
+        page_size: Class::Addr,
+        /// Mapped files.
+        files: Files
+    },
+    /// Signal information (`NT_SIGINFO`).
+    SigInfo(SigInfo<'a, Class>),
+    /// Any other note.
+    Other(NoteData<'a>)
+}
+
+/// Type synonym for [CoreNote] as decoded from a [NoteData].
+pub type CoreNoteRaw<'a, B, Class> =
+    CoreNote<'a, Class, PrStatusRegs<'a, B>, Auxv<'a, B, Class>,
+             MappedFiles<'a, B, Class>>;
+
+/// Type synonym for [CoreNote] with the register set, auxiliary
+/// vector, and mapped file table given directly.
+///
+/// This is the form used to create new core dumps with
+/// [create](Core::create).
+pub type CoreNoteRefs<'a, Class> =
+    CoreNote<'a, Class, CoreRegs<'a>, &'a [AuxvEnt<Class>],
+             &'a [MappedFileData<'a, Class>]>;
+
+/// Errors that can occur decoding a [NoteData] into a [CoreNote].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum CoreNoteError {
+    /// The descriptor was the wrong size for the note type.
+    BadDescSize {
+        /// Note type.
+        kind: u32,
+        /// Descriptor size.
+        size: usize
+    },
+    /// A string field was not valid UTF-8.
+    BadStr,
+    /// The mapped file table in a `NT_FILE` note was malformed.
+    BadFiles(MappedFilesError)
+}
+
+/// Read an address-sized word.
+#[inline]
+fn read_addr<B, Class>(data: &[u8], idx: usize) -> Class::Addr
+    where Class: ElfClass,
+          B: ByteOrder {
+    Class::read_addr::<B>(&data[idx .. idx + Class::ADDR_SIZE])
+}
+
+/// Convert a `NUL`-padded string field.
+#[inline]
+fn field_str<'a>(data: &'a [u8]) -> Result<&'a str, CoreNoteError> {
+    let len = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+
+    match core::str::from_utf8(&data[.. len]) {
+        Ok(str) => Ok(str),
+        Err(_) => Err(CoreNoteError::BadStr)
+    }
+}
+
+/// Offset of the process IDs in a `NT_PRSTATUS` note.
+#[inline]
+fn prstatus_pid_start<Class>() -> usize
+    where Class: ElfClass {
+    PRSTATUS_SIGPEND_START + (2 * Class::ADDR_SIZE)
+}
+
+/// Offset of the times in a `NT_PRSTATUS` note.
+#[inline]
+fn prstatus_times_start<Class>() -> usize
+    where Class: ElfClass {
+    prstatus_pid_start::<Class>() + 16
+}
+
+/// Offset of the registers in a `NT_PRSTATUS` note.
+#[inline]
+fn prstatus_regs_start<Class>() -> usize
+    where Class: ElfClass {
+    prstatus_times_start::<Class>() + (8 * Class::ADDR_SIZE)
+}
+
+/// Size of the `pr_fpvalid` field and its padding.
+#[inline]
+fn prstatus_fpvalid_size<Class>() -> usize
+    where Class: ElfClass {
+    if Class::ADDR_SIZE > 4 { Class::ADDR_SIZE } else { 4 }
+}
+
+/// Offset of the signal-specific fields in a `siginfo_t`.
+#[inline]
+fn siginfo_fields_start<Class>() -> usize
+    where Class: ElfClass {
+    if Class::ADDR_SIZE > 4 { 16 } else { 12 }
+}
+
+#[inline]
+fn project_prstatus<'a, B, Class>(desc: &'a [u8]) ->
+    Result<PrStatus<Class, PrStatusRegs<'a, B>>, CoreNoteError>
+    where Class: ElfClass,
+          B: ByteOrder {
+    let addr_size = Class::ADDR_SIZE;
+    let regs_start = prstatus_regs_start::<Class>();
+    let fpvalid_size = prstatus_fpvalid_size::<Class>();
+
+    if desc.len() >= regs_start + fpvalid_size {
+        let regs_end = desc.len() - fpvalid_size;
+        let pid_start = prstatus_pid_start::<Class>();
+        let times_start = prstatus_times_start::<Class>();
+        let mut times = [TimeVal { sec: (0 as u8).into(),
+                                   usec: (0 as u8).into() }; 4];
+
+        for (i, time) in times.iter_mut().enumerate() {
+            let start = times_start + (2 * i * addr_size);
+
+            *time = TimeVal {
+                sec: read_addr::<B, Class>(desc, start),
+                usec: read_addr::<B, Class>(desc, start + addr_size)
+            };
+        }
+
+        Ok(PrStatus {
+            signo: B::read_i32(&desc[0 .. 4]),
+            code: B::read_i32(&desc[4 .. 8]),
+            errno: B::read_i32(&desc[8 .. 12]),
+            cursig: B::read_u16(&desc[12 .. 14]),
+            sigpend: read_addr::<B, Class>(desc, PRSTATUS_SIGPEND_START),
+            sighold: read_addr::<B, Class>(desc, PRSTATUS_SIGPEND_START +
+                                                 addr_size),
+            pid: B::read_i32(&desc[pid_start .. pid_start + 4]),
+            ppid: B::read_i32(&desc[pid_start + 4 .. pid_start + 8]),
+            pgrp: B::read_i32(&desc[pid_start + 8 .. pid_start + 12]),
+            sid: B::read_i32(&desc[pid_start + 12 .. pid_start + 16]),
+            utime: times[0],
+            stime: times[1],
+            cutime: times[2],
+            cstime: times[3],
+            regs: PrStatusRegs { byteorder: PhantomData,
+                                 data: &desc[regs_start .. regs_end] },
+            fp_valid: B::read_u32(&desc[regs_end .. regs_end + 4]) != 0
+        })
+    } else {
+        Err(CoreNoteError::BadDescSize { kind: NT_PRSTATUS,
+                                         size: desc.len() })
+    }
+}
+
+#[inline]
+fn project_prpsinfo<'a, B, Class>(desc: &'a [u8]) ->
+    Result<PrPsInfo<'a, Class>, CoreNoteError>
+    where Class: ElfClass,
+          B: ByteOrder {
+    let ids_start = 2 * Class::ADDR_SIZE;
+    let id_size = match desc.len().checked_sub(ids_start +
+                                               PRPSINFO_FIXED_SIZE) {
+        Some(4) => 2,
+        Some(8) => 4,
+        _ => return Err(CoreNoteError::BadDescSize { kind: NT_PRPSINFO,
+                                                     size: desc.len() })
+    };
+    let (uid, gid) = if id_size == 2 {
+        (B::read_u16(&desc[ids_start .. ids_start + 2]) as u32,
+         B::read_u16(&desc[ids_start + 2 .. ids_start + 4]) as u32)
+    } else {
+        (B::read_u32(&desc[ids_start .. ids_start + 4]),
+         B::read_u32(&desc[ids_start + 4 .. ids_start + 8]))
+    };
+    let pid_start = ids_start + (2 * id_size);
+    let fname_start = pid_start + 16;
+    let psargs_start = fname_start + PRPSINFO_FNAME_SIZE;
+    let psargs_end = psargs_start + PRPSINFO_PSARGS_SIZE;
+
+    Ok(PrPsInfo {
+        state: desc[0],
+        sname: desc[1],
+        zombie: desc[2] != 0,
+        nice: desc[3] as i8,
+        flags: read_addr::<B, Class>(desc, Class::ADDR_SIZE),
+        uid: uid,
+        gid: gid,
+        pid: B::read_i32(&desc[pid_start .. pid_start + 4]),
+        ppid: B::read_i32(&desc[pid_start + 4 .. pid_start + 8]),
+        pgrp: B::read_i32(&desc[pid_start + 8 .. pid_start + 12]),
+        sid: B::read_i32(&desc[pid_start + 12 .. pid_start + 16]),
+        fname: field_str(&desc[fname_start .. psargs_start])?,
+        psargs: field_str(&desc[psargs_start .. psargs_end])?
+    })
+}
+
+#[inline]
+fn project_siginfo<'a, B, Class>(desc: &'a [u8]) ->
+    Result<SigInfo<'a, Class>, CoreNoteError>
+    where Class: ElfClass,
+          B: ByteOrder {
+    if desc.len() == SIGINFO_SIZE {
+        let signo = B::read_i32(&desc[0 .. 4]);
+        let code = B::read_i32(&desc[8 .. 12]);
+        let start = siginfo_fields_start::<Class>();
+        let fields = if code <= 0 {
+            SigFields::Kill {
+                pid: B::read_i32(&desc[start .. start + 4]),
+                uid: B::read_u32(&desc[start + 4 .. start + 8])
+            }
+        } else {
+            match signo {
+                SIGILL | SIGTRAP | SIGBUS | SIGFPE | SIGSEGV =>
+                    SigFields::Fault {
+                        addr: read_addr::<B, Class>(desc, start)
+                    },
+                SIGCHLD => SigFields::Child {
+                    pid: B::read_i32(&desc[start .. start + 4]),
+                    uid: B::read_u32(&desc[start + 4 .. start + 8]),
+                    status: B::read_i32(&desc[start + 8 .. start + 12])
+                },
+                _ => SigFields::Other(&desc[start ..])
+            }
+        };
+
+        Ok(SigInfo {
+            signo: signo,
+            errno: B::read_i32(&desc[4 .. 8]),
+            code: code,
+            fields: fields
+        })
+    } else {
+        Err(CoreNoteError::BadDescSize { kind: NT_SIGINFO,
+                                         size: desc.len() })
+    }
+}
+
+#[inline]
+fn project_auxv<Class>(tag: Class::Addr, val: Class::Addr) -> AuxvEnt<Class>
+    where Class: ElfClass {
+    match tag.try_into() {
+        Ok(AT_NULL) => AuxvEnt::Null,
+        Ok(AT_EXECFD) => AuxvEnt::ExecFd { fd: val },
+        Ok(AT_PHDR) => AuxvEnt::Phdr { addr: val },
+        Ok(AT_PHENT) => AuxvEnt::PhEnt { size: val },
+        Ok(AT_PHNUM) => AuxvEnt::PhNum { num: val },
+        Ok(AT_PAGESZ) => AuxvEnt::PageSize { size: val },
+        Ok(AT_BASE) => AuxvEnt::Base { addr: val },
+        Ok(AT_FLAGS) => AuxvEnt::Flags { flags: val },
+        Ok(AT_ENTRY) => AuxvEnt::Entry { addr: val },
+        Ok(AT_UID) => AuxvEnt::Uid { id: val },
+        Ok(AT_EUID) => AuxvEnt::EUid { id: val },
+        Ok(AT_GID) => AuxvEnt::Gid { id: val },
+        Ok(AT_EGID) => AuxvEnt::EGid { id: val },
+        Ok(AT_PLATFORM) => AuxvEnt::Platform { addr: val },
+        Ok(AT_HWCAP) => AuxvEnt::HwCap { bits: val },
+        Ok(AT_CLKTCK) => AuxvEnt::ClockTick { hz: val },
+        Ok(AT_SECURE) =>
+            AuxvEnt::Secure { secure: val != (0 as u8).into() },
+        Ok(AT_BASE_PLATFORM) => AuxvEnt::BasePlatform { addr: val },
+        Ok(AT_RANDOM) => AuxvEnt::Random { addr: val },
+        Ok(AT_HWCAP2) => AuxvEnt::HwCap2 { bits: val },
+        Ok(AT_EXECFN) => AuxvEnt::ExecFn { addr: val },
+        Ok(AT_SYSINFO_EHDR) => AuxvEnt::SysInfoEhdr { addr: val },
+        Ok(AT_MINSIGSTKSZ) => AuxvEnt::MinSigStackSize { size: val },
+        _ => AuxvEnt::Unknown { tag: tag, val: val }
+    }
+}
+
+/// Check the mapped file table, returning the number of entries.
+fn check_files<B, Class>(data: &[u8]) -> Result<usize, MappedFilesError>
+    where Class: ElfClass,
+          B: ByteOrder {
+    let addr_size = Class::ADDR_SIZE;
+
+    if data.len() >= 2 * addr_size {
+        let num = match read_addr::<B, Class>(data, 0).try_into() {
+            Ok(num) => num,
+            Err(_) => return Err(MappedFilesError::TooShort)
+        };
+        let names_start = num.checked_mul(3 * addr_size)
+            .and_then(|size| size.checked_add(2 * addr_size));
+        let mut idx = match names_start {
+            Some(start) if start <= data.len() => start,
+            _ => return Err(MappedFilesError::TooShort)
+        };
+
+        for _ in 0 .. num {
+            match data[idx ..].iter().position(|b| *b == 0) {
+                Some(len) => {
+                    if core::str::from_utf8(&data[idx .. idx + len])
+                        .is_err() {
+                        return Err(MappedFilesError::BadStr)
+                    }
+
+                    idx += len + 1
+                },
+                None => return Err(MappedFilesError::TooShort)
+            }
+        }
+
+        Ok(num)
+    } else {
+        Err(MappedFilesError::TooShort)
+    }
+}
+
+/// Write an address-sized word.
+#[inline]
+fn write_addr<B, Class>(data: &mut [u8], idx: usize, val: Class::Addr)
+    where Class: ElfClass,
+          B: ByteOrder {
+    Class::write_addr::<B>(&mut data[idx .. idx + Class::ADDR_SIZE], val)
+}
+
+/// Convert a size or count to an address-sized word.
+#[inline]
+fn to_addr<Class>(val: usize) -> Class::Addr
+    where Class: ElfClass {
+    Class::Addr::try_from(val).unwrap_or_else(|_| (0 as u8).into())
+}
+
+/// Size of the user and group IDs in a `NT_PRPSINFO` note created
+/// by [create](Core::create).
+///
+/// 32-bit notes use the 16-bit IDs of i386 and 32-bit ARM.
+#[inline]
+fn prpsinfo_id_size<Class>() -> usize
+    where Class: ElfClass {
+    if Class::ADDR_SIZE > 4 { 4 } else { 2 }
+}
+
+/// Get the size of a register set.
+#[inline]
+fn regs_size(regs: &CoreRegs<'_>) -> usize {
+    match regs {
+        CoreRegs::X86_64(_) => X86_64_NUM_REGS * 8,
+        CoreRegs::AArch64(_) => AARCH64_NUM_REGS * 8,
+        CoreRegs::Other(data) => data.len()
+    }
+}
+
+#[inline]
+fn write_regs<B>(buf: &mut [u8], regs: &CoreRegs<'_>)
+    where B: ByteOrder {
+    match regs {
+        CoreRegs::X86_64(regs) => {
+            let vals = [
+                regs.r15, regs.r14, regs.r13, regs.r12, regs.rbp, regs.rbx,
+                regs.r11, regs.r10, regs.r9, regs.r8, regs.rax, regs.rcx,
+                regs.rdx, regs.rsi, regs.rdi, regs.orig_rax, regs.rip,
+                regs.cs, regs.eflags, regs.rsp, regs.ss, regs.fs_base,
+                regs.gs_base, regs.ds, regs.es, regs.fs, regs.gs
+            ];
+
+            for (i, val) in vals.iter().enumerate() {
+                B::write_u64(&mut buf[i * 8 .. (i + 1) * 8], *val);
+            }
+        },
+        CoreRegs::AArch64(regs) => {
+            let rest = [regs.sp, regs.pc, regs.pstate];
+
+            for (i, val) in regs.regs.iter().chain(rest.iter()).enumerate() {
+                B::write_u64(&mut buf[i * 8 .. (i + 1) * 8], *val);
+            }
+        },
+        CoreRegs::Other(data) => buf.clone_from_slice(data)
+    }
+}
+
+#[inline]
+fn write_prstatus<B, Class>(buf: &mut [u8],
+                            status: &PrStatus<Class, CoreRegs<'_>>)
+    where Class: ElfClass,
+          B: ByteOrder {
+    let addr_size = Class::ADDR_SIZE;
+    let pid_start = prstatus_pid_start::<Class>();
+    let times_start = prstatus_times_start::<Class>();
+    let regs_start = prstatus_regs_start::<Class>();
+    let regs_end = regs_start + regs_size(&status.regs);
+    let times = [status.utime, status.stime, status.cutime, status.cstime];
+
+    B::write_i32(&mut buf[0 .. 4], status.signo);
+    B::write_i32(&mut buf[4 .. 8], status.code);
+    B::write_i32(&mut buf[8 .. 12], status.errno);
+    B::write_u16(&mut buf[12 .. 14], status.cursig);
+    write_addr::<B, Class>(buf, PRSTATUS_SIGPEND_START, status.sigpend);
+    write_addr::<B, Class>(buf, PRSTATUS_SIGPEND_START + addr_size,
+                           status.sighold);
+    B::write_i32(&mut buf[pid_start .. pid_start + 4], status.pid);
+    B::write_i32(&mut buf[pid_start + 4 .. pid_start + 8], status.ppid);
+    B::write_i32(&mut buf[pid_start + 8 .. pid_start + 12], status.pgrp);
+    B::write_i32(&mut buf[pid_start + 12 .. pid_start + 16], status.sid);
+
+    for (i, time) in times.iter().enumerate() {
+        let start = times_start + (2 * i * addr_size);
+
+        write_addr::<B, Class>(buf, start, time.sec);
+        write_addr::<B, Class>(buf, start + addr_size, time.usec);
+    }
+
+    write_regs::<B>(&mut buf[regs_start .. regs_end], &status.regs);
+    B::write_u32(&mut buf[regs_end .. regs_end + 4], status.fp_valid as u32);
+}
+
+#[inline]
+fn write_prpsinfo<B, Class>(buf: &mut [u8], info: &PrPsInfo<'_, Class>)
+    where Class: ElfClass,
+          B: ByteOrder {
+    let ids_start = 2 * Class::ADDR_SIZE;
+    let id_size = prpsinfo_id_size::<Class>();
+    let pid_start = ids_start + (2 * id_size);
+    let fname_start = pid_start + 16;
+    let psargs_start = fname_start + PRPSINFO_FNAME_SIZE;
+    let fname = info.fname.as_bytes();
+    let fname = &fname[.. fname.len().min(PRPSINFO_FNAME_SIZE)];
+    let psargs = info.psargs.as_bytes();
+    let psargs = &psargs[.. psargs.len().min(PRPSINFO_PSARGS_SIZE)];
+
+    buf[0] = info.state;
+    buf[1] = info.sname;
+    buf[2] = info.zombie as u8;
+    buf[3] = info.nice as u8;
+    write_addr::<B, Class>(buf, Class::ADDR_SIZE, info.flags);
+
+    if id_size == 2 {
+        B::write_u16(&mut buf[ids_start .. ids_start + 2], info.uid as u16);
+        B::write_u16(&mut buf[ids_start + 2 .. ids_start + 4],
+                     info.gid as u16);
+    } else {
+        B::write_u32(&mut buf[ids_start .. ids_start + 4], info.uid);
+        B::write_u32(&mut buf[ids_start + 4 .. ids_start + 8], info.gid);
+    }
+
+    B::write_i32(&mut buf[pid_start .. pid_start + 4], info.pid);
+    B::write_i32(&mut buf[pid_start + 4 .. pid_start + 8], info.ppid);
+    B::write_i32(&mut buf[pid_start + 8 .. pid_start + 12], info.pgrp);
+    B::write_i32(&mut buf[pid_start + 12 .. pid_start + 16], info.sid);
+    buf[fname_start .. fname_start + fname.len()].clone_from_slice(fname);
+    buf[psargs_start .. psargs_start + psargs.len()]
+        .clone_from_slice(psargs);
+}
+
+#[inline]
+fn write_siginfo<B, Class>(buf: &mut [u8], info: &SigInfo<'_, Class>)
+    where Class: ElfClass,
+          B: ByteOrder {
+    let start = siginfo_fields_start::<Class>();
+
+    B::write_i32(&mut buf[0 .. 4], info.signo);
+    B::write_i32(&mut buf[4 .. 8], info.errno);
+    B::write_i32(&mut buf[8 .. 12], info.code);
+
+    match info.fields {
+        SigFields::Kill { pid, uid } => {
+            B::write_i32(&mut buf[start .. start + 4], pid);
+            B::write_u32(&mut buf[start + 4 .. start + 8], uid);
+        },
+        SigFields::Fault { addr } => write_addr::<B, Class>(buf, start, addr),
+        SigFields::Child { pid, uid, status } => {
+            B::write_i32(&mut buf[start .. start + 4], pid);
+            B::write_u32(&mut buf[start + 4 .. start + 8], uid);
+            B::write_i32(&mut buf[start + 8 .. start + 12], status);
+        },
+        SigFields::Other(data) => {
+            let len = data.len().min(SIGINFO_SIZE - start);
+
+            buf[start .. start + len].clone_from_slice(&data[.. len]);
+        }
+    }
+}
+
+/// Get the type and value of an auxiliary vector entry.
+#[inline]
+fn auxv_tag_val<Class>(ent: &AuxvEnt<Class>) -> (Class::Addr, Class::Addr)
+    where Class: ElfClass {
+    let zero: Class::Addr = (0 as u8).into();
+    let (tag, val) = match *ent {
+        AuxvEnt::Null => (AT_NULL, zero),
+        AuxvEnt::ExecFd { fd } => (AT_EXECFD, fd),
+        AuxvEnt::Phdr { addr } => (AT_PHDR, addr),
+        AuxvEnt::PhEnt { size } => (AT_PHENT, size),
+        AuxvEnt::PhNum { num } => (AT_PHNUM, num),
+        AuxvEnt::PageSize { size } => (AT_PAGESZ, size),
+        AuxvEnt::Base { addr } => (AT_BASE, addr),
+        AuxvEnt::Flags { flags } => (AT_FLAGS, flags),
+        AuxvEnt::Entry { addr } => (AT_ENTRY, addr),
+        AuxvEnt::Uid { id } => (AT_UID, id),
+        AuxvEnt::EUid { id } => (AT_EUID, id),
+        AuxvEnt::Gid { id } => (AT_GID, id),
+        AuxvEnt::EGid { id } => (AT_EGID, id),
+        AuxvEnt::Platform { addr } => (AT_PLATFORM, addr),
+        AuxvEnt::HwCap { bits } => (AT_HWCAP, bits),
+        AuxvEnt::ClockTick { hz } => (AT_CLKTCK, hz),
+        AuxvEnt::Secure { secure } =>
+            (AT_SECURE, (secure as u8).into()),
+        AuxvEnt::BasePlatform { addr } => (AT_BASE_PLATFORM, addr),
+        AuxvEnt::Random { addr } => (AT_RANDOM, addr),
+        AuxvEnt::HwCap2 { bits } => (AT_HWCAP2, bits),
+        AuxvEnt::ExecFn { addr } => (AT_EXECFN, addr),
+        AuxvEnt::SysInfoEhdr { addr } => (AT_SYSINFO_EHDR, addr),
+        AuxvEnt::MinSigStackSize { size } => (AT_MINSIGSTKSZ, size),
+        AuxvEnt::Unknown { tag, val } => return (tag, val)
+    };
+
+    (to_addr::<Class>(tag), val)
+}
+
+/// Get the name, type, and descriptor size of a [CoreNote].
+#[inline]
+fn core_note_info<'a, Class>(note: &CoreNoteRefs<'a, Class>) ->
+    (&'a [u8], u32, usize)
+    where Class: ElfClass {
+    let addr_size = Class::ADDR_SIZE;
+
+    match note {
+        CoreNote::PrStatus(status) => {
+            let size = prstatus_regs_start::<Class>() +
+                       regs_size(&status.regs) +
+                       prstatus_fpvalid_size::<Class>();
+
+            (NOTE_NAME_CORE, NT_PRSTATUS, size)
+        },
+        CoreNote::PrPsInfo(_) => {
+            let size = (2 * addr_size) + (2 * prpsinfo_id_size::<Class>()) +
+                       PRPSINFO_FIXED_SIZE;
+
+            (NOTE_NAME_CORE, NT_PRPSINFO, size)
+        },
+        CoreNote::Auxv { auxv } =>
+            (NOTE_NAME_CORE, NT_AUXV, auxv.len() * 2 * addr_size),
+        CoreNote::File { files, .. } => {
+            let names = files.iter()
+                .fold(0, |acc, file| acc + file.name.len() + 1);
+            let size = ((2 + (3 * files.len())) * addr_size) + names;
+
+            (NOTE_NAME_CORE, NT_FILE, size)
+        },
+        CoreNote::SigInfo(_) => (NOTE_NAME_CORE, NT_SIGINFO, SIGINFO_SIZE),
+        CoreNote::Other(NoteData { kind, name, desc }) =>
+            (name, *kind, desc.len())
+    }
+}
+
+/// Write the descriptor of a [CoreNote] into `buf`, which must be
+/// exactly the size of the descriptor and filled with zeros.
+#[inline]
+fn write_core_desc<'a, B, Class>(buf: &mut [u8],
+                                 note: &CoreNoteRefs<'a, Class>)
+    where Class: ElfClass,
+          B: ByteOrder {
+    let addr_size = Class::ADDR_SIZE;
+
+    match note {
+        CoreNote::PrStatus(status) => write_prstatus::<B, Class>(buf, status),
+        CoreNote::PrPsInfo(info) => write_prpsinfo::<B, Class>(buf, info),
+        CoreNote::Auxv { auxv } => {
+            for (i, ent) in auxv.iter().enumerate() {
+                let start = 2 * i * addr_size;
+                let (tag, val) = auxv_tag_val(ent);
+
+                write_addr::<B, Class>(buf, start, tag);
+                write_addr::<B, Class>(buf, start + addr_size, val);
+            }
+        },
+        CoreNote::File { page_size, files } => {
+            let mut name_idx = (2 + (3 * files.len())) * addr_size;
+
+            write_addr::<B, Class>(buf, 0, to_addr::<Class>(files.len()));
+            write_addr::<B, Class>(buf, addr_size, *page_size);
+
+            for (i, file) in files.iter().enumerate() {
+                let start = (2 + (3 * i)) * addr_size;
+                let name = file.name.as_bytes();
+
+                write_addr::<B, Class>(buf, start, file.start);
+                write_addr::<B, Class>(buf, start + addr_size, file.end);
+                write_addr::<B, Class>(buf, start + (2 * addr_size),
+                                       file.page_offset);
+                buf[name_idx .. name_idx + name.len()].clone_from_slice(name);
+                name_idx += name.len() + 1;
+            }
+        },
+        CoreNote::SigInfo(info) => write_siginfo::<B, Class>(buf, info),
+        CoreNote::Other(NoteData { desc, .. }) => buf.clone_from_slice(desc)
+    }
+}
+
+/// Offsets of the parts of a core dump created by
+/// [create](Core::create).
+struct CoreLayout {
+    /// Number of program headers.
+    num_hdrs: usize,
+    /// Offset of the notes.
+    notes_start: usize,
+    /// Size of the notes.
+    notes_size: usize,
+    /// Offset of the section header holding the number of program
+    /// headers, if there are too many to fit in the ELF header.
+    ext_hdr: Option<usize>,
+    /// Total size.
+    size: usize
+}
+
+fn layout<'a, I, J, Class>(notes: I, mem: J) -> CoreLayout
+    where I: Iterator,
+          I::Item: Borrow<CoreNoteRefs<'a, Class>>,
+          J: Iterator,
+          J::Item: Borrow<CoreMemData<'a, Class>>,
+          Class: 'a + ElfHdrOffsets {
+    let notes_size = notes.fold(0, |acc, note| {
+        let (name, _, descsize) = core_note_info(note.borrow());
+
+        acc + note_size(name.len(), descsize)
+    });
+    let mut num_hdrs = 1;
+    let notes_start = Class::ELF_HDR_SIZE;
+    let mut idx = notes_start + notes_size;
+
+    for mem in mem {
+        num_hdrs += 1;
+        idx = pad(idx, Class::ADDR_SIZE) + mem.borrow().data.len();
+    }
+
+    let hdrs_size = num_hdrs * Class::PROG_HDR_SIZE;
+    let notes_start = notes_start + hdrs_size;
+    let idx = idx + hdrs_size;
+    let (ext_hdr, size) = if num_hdrs >= PN_XNUM as usize {
+        let start = pad(idx, Class::ADDR_SIZE);
+
+        (Some(start), start + Class::SECTION_HDR_SIZE)
+    } else {
+        (None, idx)
+    };
+
+    CoreLayout { num_hdrs: num_hdrs, notes_start: notes_start,
+                 notes_size: notes_size, ext_hdr: ext_hdr, size: size }
+}
+
+/// Calculate the number of bytes required to represent a core dump
+/// containing `notes` and the memory ranges in `mem`, as created by
+/// [create](Core::create).
+#[inline]
+pub fn required_bytes<'a, I, J, Class>(notes: I, mem: J) -> usize
+    where I: Iterator,
+          I::Item: Borrow<CoreNoteRefs<'a, Class>>,
+          J: Iterator,
+          J::Item: Borrow<CoreMemData<'a, Class>>,
+          Class: 'a + ElfHdrOffsets {
+    layout(notes, mem).size
+}
+
+/// Get the data for a segment, or the error for the program header.
+#[inline]
+fn segment_data<'a, Class>(hdr: ProgHdrDataRaw<Class>, data: &'a [u8]) ->
+    Result<ProgHdrDataBufs<'a, Class>, CoreError<Class>>
+    where Class: ProgHdrOffsets {
+    match hdr.with_elf_data(data) {
+        Ok(hdr) => Ok(hdr),
+        Err(err) => Err(CoreError::BadProgHdr(err))
+    }
+}
+
+impl<'a, B, Class> Core<'a, B, Class>
+    where Class: ProgHdrOffsets,
+          B: ByteOrder {
+    /// Get the architecture of the process that was dumped.
+    ///
+    /// This is used to decode the register sets in `NT_PRSTATUS`
+    /// notes with [regs](PrStatusRegs::regs).
+    #[inline]
+    pub fn arch(&self) -> ElfArch {
+        self.arch
+    }
+
+    /// Get the program header table of the core dump.
+    #[inline]
+    pub fn prog_hdrs(&self) -> ProgHdrs<'a, B, Class> {
+        self.hdrs
+    }
+
+    /// Get an iterator over the notes in all `PT_NOTE` segments.
+    #[inline]
+    pub fn notes(&self) -> CoreNotesIter<'a, B, Class> {
+        CoreNotesIter { hdrs: self.hdrs.iter(), notes: None, data: self.data }
+    }
+
+    /// Get an iterator over the memory ranges in all `PT_LOAD`
+    /// segments.
+    #[inline]
+    pub fn mem_ranges(&self) -> CoreMemIter<'a, B, Class> {
+        CoreMemIter { hdrs: self.hdrs.iter(), data: self.data }
+    }
+
+    /// Get the `len` bytes of memory starting at `addr`.
+    ///
+    /// This will return `None` if the memory is not contained in a
+    /// single `PT_LOAD` segment, or was not present in the core dump.
+    pub fn read(&self, addr: Class::Addr, len: usize) -> Option<&'a [u8]> {
+        let addr: usize = addr.try_into().ok()?;
+
+        for mem in self.mem_ranges() {
+            if let Ok(start) = mem.addr.try_into() {
+                let start: usize = start;
+
+                if addr >= start {
+                    let offset = addr - start;
+
+                    if offset.checked_add(len)? <= mem.data.len() {
+                        return Some(&mem.data[offset .. offset + len])
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<'a, B, Class> Core<'a, B, Class>
+    where Class: ElfHdrOffsets,
+          B: ElfByteOrder {
+    /// Attempt to create a `Core` in `buf` for a process running on
+    /// `arch`, containing the notes in `notes` and the memory ranges
+    /// in `mem`, and return the unused space.
+    ///
+    /// The notes are stored in a single `PT_NOTE` segment, followed
+    /// by a `PT_LOAD` segment for each memory range.  A thread is
+    /// typically described by a [PrStatus](CoreNote::PrStatus) note
+    /// holding its registers, and the process by a
+    /// [PrPsInfo](CoreNote::PrPsInfo) note and an
+    /// [Auxv](CoreNote::Auxv) note.  Linux expects the
+    /// `NT_PRSTATUS` note of the thread that caused the dump to come
+    /// first.
+    ///
+    /// `NT_PRPSINFO` notes use 32-bit user and group IDs for 64-bit
+    /// classes, and 16-bit IDs for 32-bit classes (as on i386 and
+    /// 32-bit ARM).  The command name and arguments are truncated to
+    /// fit in the note.
+    ///
+    /// If there are too many memory ranges for the number of program
+    /// headers to fit in the ELF header, it is stored in a single
+    /// [ExtNull](crate::section_hdr::SectionHdrData::ExtNull) section
+    /// header at the end of the file.
+    ///
+    /// The number of bytes required can be obtained with
+    /// [required_bytes].
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the core dump doesn't fit
+    /// into the provided memory, or its offsets can't be represented
+    /// in the ELF class.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryFrom;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::ElfArch;
+    /// use elf_utils::coredump::AuxvEnt;
+    /// use elf_utils::coredump::Core;
+    /// use elf_utils::coredump::CoreMemData;
+    /// use elf_utils::coredump::CoreNote;
+    /// use elf_utils::coredump::CoreNoteRaw;
+    /// use elf_utils::coredump::CoreNoteRefs;
+    ///
+    /// const AUXV: [AuxvEnt<Elf64>; 2] = [
+    ///     AuxvEnt::PageSize { size: 0x1000 },
+    ///     AuxvEnt::Null
+    /// ];
+    /// const NOTES: [CoreNoteRefs<'static, Elf64>; 1] = [
+    ///     CoreNote::Auxv { auxv: &AUXV }
+    /// ];
+    /// const MEM: [CoreMemData<'static, Elf64>; 1] = [
+    ///     CoreMemData { addr: 0x401000, size: 0x1000, read: true,
+    ///                   write: false, exec: true,
+    ///                   data: &[1, 2, 3, 4, 5, 6, 7, 8] }
+    /// ];
+    ///
+    /// let mut buf = [0; 256];
+    /// let res: Result<(Core<'_, LittleEndian, Elf64>, &'_ mut [u8]), ()> =
+    ///     Core::create_split(&mut buf[0..], ElfArch::X86_64,
+    ///                        NOTES.iter(), MEM.iter());
+    /// let (core, buf) = res.unwrap();
+    ///
+    /// assert_eq!(buf.len(), 16);
+    /// assert_eq!(core.arch(), ElfArch::X86_64);
+    ///
+    /// let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+    ///     CoreNote::try_from(core.notes().next().unwrap()).unwrap();
+    ///
+    /// match note {
+    ///     CoreNote::Auxv { auxv } => {
+    ///         assert!(auxv.iter().eq(AUXV.iter().cloned()));
+    ///     },
+    ///     _ => panic!("Expected auxv note")
+    /// }
+    ///
+    /// assert_eq!(core.read(0x401002, 2), Some(&[0x03, 0x04][0..]));
+    /// ```
+    pub fn create_split<'c, I, J>(buf: &'a mut [u8], arch: ElfArch,
+                                  notes: I, mem: J) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator + Clone,
+              I::Item: Borrow<CoreNoteRefs<'c, Class>>,
+              J: Iterator + Clone,
+              J::Item: Borrow<CoreMemData<'c, Class>>,
+              Class: 'c {
+        let CoreLayout { num_hdrs, notes_start, notes_size, ext_hdr, size } =
+            layout(notes.clone(), mem.clone());
+
+        // Everything else is smaller, so this checks all offsets.
+        if buf.len() < size || Class::Offset::try_from(size).is_err() {
+            return Err(())
+        }
+
+        let offset = |val: usize| {
+            Class::Offset::try_from(val)
+                .unwrap_or_else(|_| (0 as u8).into())
+        };
+        let num_ents = match Class::Word::try_from(num_hdrs) {
+            Ok(num_ents) => num_ents,
+            Err(_) => return Err(())
+        };
+        let zero: Class::Addr = (0 as u8).into();
+        let (data, out) = buf.split_at_mut(size);
+        let section_hdrs = match ext_hdr {
+            Some(start) => ElfTable { offset: offset(start),
+                                      num_ents: (1 as u8).into() },
+            None => ElfTable { offset: (0 as u8).into(),
+                               num_ents: (0 as u8).into() }
+        };
+        let hdr: ElfHdrDataRaw<B, Class> = ElfHdrData {
+            byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+            kind: ElfKind::Core, arch: arch, entry: zero,
+            flags: (0 as u8).into(), section_hdr_strtab: (0 as u8).into(),
+            prog_hdrs: Some(ElfTable { offset: offset(Class::ELF_HDR_SIZE),
+                                       num_ents: num_ents }),
+            section_hdrs: section_hdrs
+        };
+
+        for byte in data.iter_mut() {
+            *byte = 0;
+        }
+
+        let elf: Result<Elf<'_, B, Class>, ()> =
+            Elf::create(&mut data[.. Class::ELF_HDR_SIZE], hdr);
+
+        elf?;
+
+        let note_hdr = ProgHdrData::Note {
+            virt_addr: zero, phys_addr: zero,
+            content: Segment { offset: offset(notes_start),
+                               size: offset(notes_size) }
+        };
+        let mut idx = notes_start + notes_size;
+        let load_hdrs = mem.clone().map(|mem| {
+            let mem = mem.borrow();
+            let start = pad(idx, Class::ADDR_SIZE);
+
+            idx = start + mem.data.len();
+
+            ProgHdrData::Load {
+                virt_addr: mem.addr, phys_addr: zero, mem_size: mem.size,
+                align: (1 as u8).into(), read: mem.read, write: mem.write,
+                exec: mem.exec,
+                content: Segment { offset: offset(start),
+                                   size: offset(mem.data.len()) }
+            }
+        });
+        let hdrs: Result<ProgHdrs<'_, B, Class>, ()> =
+            ProgHdrs::create(&mut data[Class::ELF_HDR_SIZE .. notes_start],
+                             once(note_hdr).chain(load_hdrs));
+
+        hdrs?;
+
+        let mut idx = notes_start;
+
+        for note in notes {
+            let note = note.borrow();
+            let (name, kind, descsize) = core_note_info(note);
+            let end = idx + note_size(name.len(), descsize);
+            let desc = write_note::<B>(&mut data[idx .. end], name, kind,
+                                       descsize);
+
+            write_core_desc::<B, Class>(desc, note);
+            idx = end;
+        }
+
+        for mem in mem {
+            let mem = mem.borrow();
+            let start = pad(idx, Class::ADDR_SIZE);
+
+            idx = start + mem.data.len();
+            data[start .. idx].clone_from_slice(mem.data);
+        }
+
+        if let Some(start) = ext_hdr {
+            let ent = SectionHdrData::ExtNull {
+                num_section_hdrs: (0 as u8).into(),
+                section_hdr_strtab: (0 as u8).into(),
+                num_prog_hdrs: num_ents
+            };
+            let end = start + Class::SECTION_HDR_SIZE;
+            let hdrs: Result<SectionHdrs<'_, B, Class>, ()> =
+                SectionHdrs::create(&mut data[start .. end], once(ent));
+
+            hdrs?;
+        }
+
+        match Core::try_from(&*data) {
+            Ok(core) => Ok((core, out)),
+            Err(_) => Err(())
+        }
+    }
+
+    /// Attempt to create a `Core` in `buf` for a process running on
+    /// `arch`, containing the notes in `notes` and the memory ranges
+    /// in `mem` (see [create_split](Core::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the core dump doesn't fit
+    /// into the provided memory, or its offsets can't be represented
+    /// in the ELF class.
+    #[inline]
+    pub fn create<'c, I, J>(buf: &'a mut [u8], arch: ElfArch, notes: I,
+                            mem: J) -> Result<Self, ()>
+        where I: Iterator + Clone,
+              I::Item: Borrow<CoreNoteRefs<'c, Class>>,
+              J: Iterator + Clone,
+              J::Item: Borrow<CoreMemData<'c, Class>>,
+              Class: 'c {
+        match Self::create_split(buf, arch, notes, mem) {
+            Ok((core, _)) => Ok(core),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a, B, Class> TryFrom<&'a [u8]> for Core<'a, B, Class>
+    where Class: ElfHdrOffsets,
+          B: ElfByteOrder {
+    type Error = CoreError<Class>;
+
+    fn try_from(data: &'a [u8]) -> Result<Core<'a, B, Class>, Self::Error> {
+        let elf: Elf<'a, B, Class> = match Elf::try_from(data) {
+            Ok(elf) => elf,
+            Err(err) => return Err(CoreError::BadElf(err))
+        };
+        let hdr: ElfHdrDataRaw<B, Class> = match ElfHdrData::try_from(elf) {
+            Ok(hdr) => hdr,
+            Err(err) => return Err(CoreError::BadHdr(err))
+        };
+
+        if hdr.kind != ElfKind::Core {
+            return Err(CoreError::NotCore(hdr.kind))
+        }
+
+        let hdrs = match hdr.prog_hdrs {
+            Some(ElfTable { offset, num_ents }) => {
+                let size = (num_ents.into() as usize)
+                    .checked_mul(Class::PROG_HDR_SIZE);
+                let start: Option<usize> = offset.try_into().ok();
+                let end = match (start, size) {
+                    (Some(start), Some(size)) => start.checked_add(size),
+                    _ => None
+                };
+
+                match (start, end) {
+                    (Some(start), Some(end)) if end <= data.len() => {
+                        match ProgHdrs::try_from(&data[start .. end]) {
+                            Ok(hdrs) => hdrs,
+                            Err(err) => return Err(CoreError::BadProgHdrs(err))
+                        }
+                    },
+                    _ => return Err(CoreError::ProgHdrsOutOfBounds(offset))
+                }
+            },
+            None => return Err(CoreError::NoProgHdrs)
+        };
+        let hdrs: ProgHdrs<'a, B, Class> = hdrs;
+
+        for hdr in hdrs.iter() {
+            let hdr: ProgHdrDataRaw<Class> = match hdr.try_into() {
+                Ok(hdr) => hdr,
+                Err(err) => return Err(CoreError::BadProgHdr(err))
+            };
+
+            if let ProgHdrData::Note { content, .. } =
+                segment_data(hdr, data)? {
+                let notes: Result<Notes<'a, B>, NotesError> =
+                    Notes::try_from(content);
+
+                if let Err(err) = notes {
+                    return Err(CoreError::BadNotes(err))
+                }
+            }
+        }
+
+        Ok(Core { byteorder: PhantomData, arch: hdr.arch, hdrs: hdrs,
+                  data: data })
+    }
+}
+
+impl<'a, B, Class> Iterator for CoreNotesIter<'a, B, Class>
+    where Class: ProgHdrOffsets,
+          B: ByteOrder {
+    type Item = NoteData<'a>;
+
+    fn next(&mut self) -> Option<NoteData<'a>> {
+        loop {
+            if let Some(note) = self.notes.as_mut().and_then(|n| n.next()) {
+                return Some(note)
+            }
+
+            let hdr = self.hdrs.next()?;
+            let hdr: ProgHdrDataRaw<Class> = hdr.try_into().ok()?;
+
+            if let Ok(ProgHdrData::Note { content, .. }) =
+                hdr.with_elf_data(self.data) {
+                let notes: Notes<'a, B> = Notes::try_from(content).ok()?;
+
+                self.notes = Some(notes.iter());
+            }
+        }
+    }
+}
+
+impl<'a, B, Class> FusedIterator for CoreNotesIter<'a, B, Class>
+    where Class: ProgHdrOffsets,
+          B: ByteOrder {}
+
+impl<'a, B, Class> Iterator for CoreMemIter<'a, B, Class>
+    where Class: ProgHdrOffsets,
+          B: ByteOrder {
+    type Item = CoreMemData<'a, Class>;
+
+    fn next(&mut self) -> Option<CoreMemData<'a, Class>> {
+        loop {
+            let hdr = self.hdrs.next()?;
+            let hdr: ProgHdrDataRaw<Class> = hdr.try_into().ok()?;
+
+            if let Ok(ProgHdrData::Load { virt_addr, mem_size, read, write,
+                                          exec, content, .. }) =
+                hdr.with_elf_data(self.data) {
+                return Some(CoreMemData { addr: virt_addr, size: mem_size,
+                                          read: read, write: write,
+                                          exec: exec, data: content })
+            }
+        }
+    }
+}
+
+impl<'a, B, Class> FusedIterator for CoreMemIter<'a, B, Class>
+    where Class: ProgHdrOffsets,
+          B: ByteOrder {}
+
+impl<'a, B> PrStatusRegs<'a, B>
+    where B: ByteOrder {
+    /// Get the raw register data.
+    #[inline]
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Decode the registers for the architecture `arch`.
+    ///
+    /// This will produce [Other](CoreRegs::Other) if the architecture
+    /// is not recognized, or if the size of the register set does not
+    /// match the architecture.
+    pub fn regs(&self, arch: ElfArch) -> CoreRegs<'a> {
+        let data = self.data;
+        let num = data.len() / 8;
+        let reg = |i: usize| B::read_u64(&data[i * 8 .. (i + 1) * 8]);
+
+        match arch {
+            ElfArch::X86_64 if data.len() == X86_64_NUM_REGS * 8 =>
+                CoreRegs::X86_64(X86_64Regs {
+                    r15: reg(0), r14: reg(1), r13: reg(2), r12: reg(3),
+                    rbp: reg(4), rbx: reg(5), r11: reg(6), r10: reg(7),
+                    r9: reg(8), r8: reg(9), rax: reg(10), rcx: reg(11),
+                    rdx: reg(12), rsi: reg(13), rdi: reg(14),
+                    orig_rax: reg(15), rip: reg(16), cs: reg(17),
+                    eflags: reg(18), rsp: reg(19), ss: reg(20),
+                    fs_base: reg(21), gs_base: reg(22), ds: reg(23),
+                    es: reg(24), fs: reg(25), gs: reg(26)
+                }),
+            ElfArch::AArch64 if data.len() == AARCH64_NUM_REGS * 8 => {
+                let mut regs = [0; 31];
+
+                for (i, ent) in regs.iter_mut().enumerate() {
+                    *ent = reg(i);
+                }
+
+                CoreRegs::AArch64(AArch64Regs { regs: regs, sp: reg(num - 3),
+                                                pc: reg(num - 2),
+                                                pstate: reg(num - 1) })
+            },
+            _ => CoreRegs::Other(data)
+        }
+    }
+}
+
+impl<'a, B, Class> Auxv<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    /// Get the number of entries in this `Auxv`.
+    #[inline]
+    pub fn num_ents(&self) -> usize {
+        self.data.len() / (2 * Class::ADDR_SIZE)
+    }
+
+    /// Get an iterator over this `Auxv`.
+    #[inline]
+    pub fn iter(&self) -> AuxvIter<'a, B, Class> {
+        AuxvIter { byteorder: PhantomData, class: PhantomData,
+                   data: self.data, idx: 0 }
+    }
+}
+
+impl<'a, B, Class> TryFrom<&'a [u8]> for Auxv<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Error = AuxvError;
+
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<Auxv<'a, B, Class>, Self::Error> {
+        if data.len() % (2 * Class::ADDR_SIZE) == 0 {
+            Ok(Auxv { byteorder: PhantomData, class: PhantomData, data: data })
+        } else {
+            Err(AuxvError::BadSize(data.len()))
+        }
+    }
+}
+
+impl<'a, B, Class> Iterator for AuxvIter<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Item = AuxvEnt<Class>;
+
+    fn next(&mut self) -> Option<AuxvEnt<Class>> {
+        let start = self.idx;
+        let end = start + (2 * Class::ADDR_SIZE);
+
+        if end <= self.data.len() {
+            let tag = read_addr::<B, Class>(self.data, start);
+            let val = read_addr::<B, Class>(self.data,
+                                            start + Class::ADDR_SIZE);
+
+            self.idx = end;
+
+            Some(project_auxv(tag, val))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, B, Class> FusedIterator for AuxvIter<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {}
+
+impl<'a, B, Class> MappedFiles<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    /// Get the page size used for file offsets.
+    #[inline]
+    pub fn page_size(&self) -> Class::Addr {
+        read_addr::<B, Class>(self.data, Class::ADDR_SIZE)
+    }
+
+    /// Get the number of mapped files.
+    #[inline]
+    pub fn num_files(&self) -> usize {
+        self.num
+    }
+
+    /// Get an iterator over this `MappedFiles`.
+    #[inline]
+    pub fn iter(&self) -> MappedFilesIter<'a, B, Class> {
+        let names = (2 + (3 * self.num)) * Class::ADDR_SIZE;
+
+        MappedFilesIter { byteorder: PhantomData, class: PhantomData,
+                          data: self.data, num: self.num, idx: 0,
+                          name_idx: names }
+    }
+}
+
+impl<'a, B, Class> TryFrom<&'a [u8]> for MappedFiles<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Error = MappedFilesError;
+
+    #[inline]
+    fn try_from(data: &'a [u8]) ->
+        Result<MappedFiles<'a, B, Class>, Self::Error> {
+        let num = check_files::<B, Class>(data)?;
+
+        Ok(MappedFiles { byteorder: PhantomData, class: PhantomData,
+                         data: data, num: num })
+    }
+}
+
+impl<'a, B, Class> Iterator for MappedFilesIter<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Item = MappedFileData<'a, Class>;
+
+    fn next(&mut self) -> Option<MappedFileData<'a, Class>> {
+        if self.idx < self.num {
+            let addr_size = Class::ADDR_SIZE;
+            let start = (2 + (3 * self.idx)) * addr_size;
+            let name = &self.data[self.name_idx ..];
+            let len = name.iter().position(|b| *b == 0)?;
+
+            self.idx += 1;
+            self.name_idx += len + 1;
+
+            Some(MappedFileData {
+                start: read_addr::<B, Class>(self.data, start),
+                end: read_addr::<B, Class>(self.data, start + addr_size),
+                page_offset: read_addr::<B, Class>(self.data,
+                                                   start + (2 * addr_size)),
+                name: core::str::from_utf8(&name[.. len]).ok()?
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, B, Class> FusedIterator for MappedFilesIter<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {}
+
+impl<'a, B, Class> TryFrom<NoteData<'a>> for CoreNoteRaw<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Error = CoreNoteError;
+
+    #[inline]
+    fn try_from(note: NoteData<'a>) ->
+        Result<CoreNoteRaw<'a, B, Class>, Self::Error> {
+        let NoteData { kind, name, desc } = note;
+
+        if name != NOTE_NAME_CORE {
+            return Ok(CoreNote::Other(note))
+        }
+
+        match kind {
+            NT_PRSTATUS =>
+                Ok(CoreNote::PrStatus(project_prstatus::<B, Class>(desc)?)),
+            NT_PRPSINFO =>
+                Ok(CoreNote::PrPsInfo(project_prpsinfo::<B, Class>(desc)?)),
+            NT_AUXV => match Auxv::try_from(desc) {
+                Ok(auxv) => Ok(CoreNote::Auxv { auxv: auxv }),
+                Err(_) => Err(CoreNoteError::BadDescSize { kind: kind,
+                                                           size: desc.len() })
+            },
+            NT_FILE => match MappedFiles::try_from(desc) {
+                Ok(files) => Ok(CoreNote::File {
+                    page_size: files.page_size(),
+                    files: files
+                }),
+                Err(err) => Err(CoreNoteError::BadFiles(err))
+            },
+            NT_SIGINFO =>
+                Ok(CoreNote::SigInfo(project_siginfo::<B, Class>(desc)?)),
+            _ => Ok(CoreNote::Other(note))
+        }
+    }
+}
+
+impl<Class> Display for CoreError<Class>
+    where Class: ProgHdrOffsets {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CoreError::BadElf(err) => write!(f, "bad ELF header: {}", err),
+            CoreError::BadHdr(err) => write!(f, "bad ELF header: {}", err),
+            CoreError::NotCore(kind) =>
+                write!(f, "ELF data is {}, not core image", kind),
+            CoreError::NoProgHdrs => write!(f, "no program header table"),
+            CoreError::ProgHdrsOutOfBounds(offset) =>
+                write!(f, "program header table offset 0x{:x} out of bounds",
+                       offset),
+            CoreError::BadProgHdrs(err) =>
+                write!(f, "bad program header table: {}", err),
+            CoreError::BadProgHdr(err) =>
+                write!(f, "bad program header: {}", err),
+            CoreError::BadNotes(err) => write!(f, "bad note segment: {}", err)
+        }
+    }
+}
+
+impl<'a, Class> Display for CoreMemData<'a, Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "0x{:x} - 0x{:x} {}{}{} (0x{:x} bytes present)",
+               self.addr, self.size,
+               if self.read { "r" } else { "-" },
+               if self.write { "w" } else { "-" },
+               if self.exec { "x" } else { "-" },
+               self.data.len())
+    }
+}
+
+impl Display for X86_64Regs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, concat!("    rax: 0x{:016x} rbx: 0x{:016x} ",
+                          "rcx: 0x{:016x}\n",
+                          "    rdx: 0x{:016x} rsi: 0x{:016x} ",
+                          "rdi: 0x{:016x}\n",
+                          "    rbp: 0x{:016x} rsp: 0x{:016x} ",
+                          "rip: 0x{:016x}\n",
+                          "    r8:  0x{:016x} r9:  0x{:016x} ",
+                          "r10: 0x{:016x}\n",
+                          "    r11: 0x{:016x} r12: 0x{:016x} ",
+                          "r13: 0x{:016x}\n",
+                          "    r14: 0x{:016x} r15: 0x{:016x} ",
+                          "eflags: 0x{:x}"),
+               self.rax, self.rbx, self.rcx, self.rdx, self.rsi, self.rdi,
+               self.rbp, self.rsp, self.rip, self.r8, self.r9, self.r10,
+               self.r11, self.r12, self.r13, self.r14, self.r15, self.eflags)
+    }
+}
+
+impl Display for AArch64Regs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        for i in 0 .. self.regs.len() {
+            if i % 3 == 0 {
+                write!(f, "    ")?;
+            }
+
+            write!(f, "x{:<2}: 0x{:016x}", i, self.regs[i])?;
+
+            if i % 3 == 2 {
+                writeln!(f)?;
+            } else {
+                write!(f, " ")?;
+            }
+        }
+
+        write!(f, "sp:  0x{:016x}\n    pc:  0x{:016x} pstate: 0x{:x}",
+               self.sp, self.pc, self.pstate)
+    }
+}
+
+impl<'a> Display for CoreRegs<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CoreRegs::X86_64(regs) => write!(f, "{}", regs),
+            CoreRegs::AArch64(regs) => write!(f, "{}", regs),
+            CoreRegs::Other(data) => {
+                write!(f, "    [")?;
+
+                for byte in data.iter() {
+                    write!(f, " {:02x}", byte)?;
+                }
+
+                write!(f, " ]")
+            }
+        }
+    }
+}
+
+impl<Class, Regs> Display for PrStatus<Class, Regs>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, concat!("  Thread status: pid {}, ppid {}, pgrp {}, ",
+                          "sid {}, signal {}"),
+               self.pid, self.ppid, self.pgrp, self.sid, self.cursig)
+    }
+}
+
+impl<'a, Class> Display for PrPsInfo<'a, Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, concat!("  Process info: {} ({}), pid {}, ppid {}, ",
+                          "uid {}, gid {}, state {}"),
+               self.fname, self.psargs, self.pid, self.ppid, self.uid,
+               self.gid, self.sname as char)
+    }
+}
+
+impl<'a, Class> Display for SigInfo<'a, Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "  Signal info: signal {}, errno {}, code {}",
+               self.signo, self.errno, self.code)?;
+
+        match self.fields {
+            SigFields::Kill { pid, uid } =>
+                write!(f, ", sent by pid {}, uid {}", pid, uid),
+            SigFields::Fault { addr } =>
+                write!(f, ", fault address 0x{:x}", addr),
+            SigFields::Child { pid, uid, status } =>
+                write!(f, ", child pid {}, uid {}, status {}",
+                       pid, uid, status),
+            SigFields::Other(_) => Ok(())
+        }
+    }
+}
+
+impl<Class> Display for AuxvEnt<Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AuxvEnt::Null => write!(f, "AT_NULL"),
+            AuxvEnt::ExecFd { fd } => write!(f, "AT_EXECFD: {}", fd),
+            AuxvEnt::Phdr { addr } => write!(f, "AT_PHDR: 0x{:x}", addr),
+            AuxvEnt::PhEnt { size } => write!(f, "AT_PHENT: {}", size),
+            AuxvEnt::PhNum { num } => write!(f, "AT_PHNUM: {}", num),
+            AuxvEnt::PageSize { size } => write!(f, "AT_PAGESZ: {}", size),
+            AuxvEnt::Base { addr } => write!(f, "AT_BASE: 0x{:x}", addr),
+            AuxvEnt::Flags { flags } => write!(f, "AT_FLAGS: 0x{:x}", flags),
+            AuxvEnt::Entry { addr } => write!(f, "AT_ENTRY: 0x{:x}", addr),
+            AuxvEnt::Uid { id } => write!(f, "AT_UID: {}", id),
+            AuxvEnt::EUid { id } => write!(f, "AT_EUID: {}", id),
+            AuxvEnt::Gid { id } => write!(f, "AT_GID: {}", id),
+            AuxvEnt::EGid { id } => write!(f, "AT_EGID: {}", id),
+            AuxvEnt::Platform { addr } =>
+                write!(f, "AT_PLATFORM: 0x{:x}", addr),
+            AuxvEnt::HwCap { bits } => write!(f, "AT_HWCAP: 0x{:x}", bits),
+            AuxvEnt::ClockTick { hz } => write!(f, "AT_CLKTCK: {}", hz),
+            AuxvEnt::Secure { secure } => write!(f, "AT_SECURE: {}", secure),
+            AuxvEnt::BasePlatform { addr } =>
+                write!(f, "AT_BASE_PLATFORM: 0x{:x}", addr),
+            AuxvEnt::Random { addr } => write!(f, "AT_RANDOM: 0x{:x}", addr),
+            AuxvEnt::HwCap2 { bits } => write!(f, "AT_HWCAP2: 0x{:x}", bits),
+            AuxvEnt::ExecFn { addr } => write!(f, "AT_EXECFN: 0x{:x}", addr),
+            AuxvEnt::SysInfoEhdr { addr } =>
+                write!(f, "AT_SYSINFO_EHDR: 0x{:x}", addr),
+            AuxvEnt::MinSigStackSize { size } =>
+                write!(f, "AT_MINSIGSTKSZ: {}", size),
+            AuxvEnt::Unknown { tag, val } =>
+                write!(f, "auxv entry {}: 0x{:x}", tag, val)
+        }
+    }
+}
+
+impl<'a, B, Class> Display for Auxv<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        for ent in self.iter() {
+            write!(f, "\n    {}", ent)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Class> Display for MappedFileData<'a, Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "0x{:x} - 0x{:x} at page 0x{:x}: {}",
+               self.start, self.end, self.page_offset, self.name)
+    }
+}
+
+impl<'a, B, Class> Display for MappedFiles<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        for file in self.iter() {
+            write!(f, "\n    {}", file)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Class, Regs, Ents, Files> Display
+    for CoreNote<'a, Class, Regs, Ents, Files>
+    where Class: ElfClass,
+          Ents: Display,
+          Files: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CoreNote::PrStatus(status) => write!(f, "{}", status),
+            CoreNote::PrPsInfo(info) => write!(f, "{}", info),
+            CoreNote::Auxv { auxv } =>
+                write!(f, "  Auxiliary vector:{}", auxv),
+            CoreNote::File { page_size, files } =>
+                write!(f, "  Mapped files (page size {}):{}",
+                       page_size, files),
+            CoreNote::SigInfo(info) => write!(f, "{}", info),
+            CoreNote::Other(note) => write!(f, "{}", note)
+        }
+    }
+}
+
+impl Display for AuxvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AuxvError::BadSize(size) =>
+                write!(f, "bad auxiliary vector size {}", size)
+        }
+    }
+}
+
+impl Display for MappedFilesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            MappedFilesError::TooShort =>
+                write!(f, "buffer too short for mapped file table"),
+            MappedFilesError::BadStr =>
+                write!(f, "mapped file name is not UTF-8")
+        }
+    }
+}
+
+impl Display for CoreNoteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            CoreNoteError::BadDescSize { kind, size } =>
+                write!(f, "bad descriptor size {} for note type {}",
+                       size, kind),
+            CoreNoteError::BadStr => write!(f, "note string is not UTF-8"),
+            CoreNoteError::BadFiles(err) =>
+                write!(f, "bad mapped file note: {}", err)
+        }
+    }
+}
@@ -124,6 +124,61 @@ use crate::strtab::Strtab;
 use crate::strtab::StrtabIdxError;
 use crate::strtab::WithStrtab;
 
+const DT_GNU_HASH: usize = 0x6ffffef5;
+const DT_VERSYM: usize = 0x6ffffff0;
+const DT_RELACOUNT: usize = 0x6ffffff9;
+const DT_RELCOUNT: usize = 0x6ffffffa;
+const DT_FLAGS_1: usize = 0x6ffffffb;
+const DT_VERDEF: usize = 0x6ffffffc;
+const DT_VERDEFNUM: usize = 0x6ffffffd;
+const DT_VERNEED: usize = 0x6ffffffe;
+const DT_VERNEEDNUM: usize = 0x6fffffff;
+
+const DF_ORIGIN: u32 = 0x1;
+const DF_SYMBOLIC: u32 = 0x2;
+const DF_TEXTREL: u32 = 0x4;
+const DF_BIND_NOW: u32 = 0x8;
+const DF_STATIC_TLS: u32 = 0x10;
+
+const DYNAMIC_FLAGS: [DynamicFlag; 5] = [
+    DynamicFlag::Origin,
+    DynamicFlag::Symbolic,
+    DynamicFlag::TextRel,
+    DynamicFlag::BindNow,
+    DynamicFlag::StaticTLS
+];
+
+const DYNAMIC_FLAGS_1: [DynamicFlag1; 28] = [
+    DynamicFlag1::Now,
+    DynamicFlag1::Global,
+    DynamicFlag1::Group,
+    DynamicFlag1::NoDelete,
+    DynamicFlag1::LoadFilter,
+    DynamicFlag1::InitFirst,
+    DynamicFlag1::NoOpen,
+    DynamicFlag1::Origin,
+    DynamicFlag1::Direct,
+    DynamicFlag1::Trans,
+    DynamicFlag1::Interpose,
+    DynamicFlag1::NoDefaultLib,
+    DynamicFlag1::NoDump,
+    DynamicFlag1::ConfAlt,
+    DynamicFlag1::EndFiltee,
+    DynamicFlag1::DispRelDone,
+    DynamicFlag1::DispRelPending,
+    DynamicFlag1::NoDirect,
+    DynamicFlag1::IgnoreMultiDef,
+    DynamicFlag1::NoKSyms,
+    DynamicFlag1::NoHdr,
+    DynamicFlag1::Edited,
+    DynamicFlag1::NoReloc,
+    DynamicFlag1::SymInterpose,
+    DynamicFlag1::GlobalAudit,
+    DynamicFlag1::Singleton,
+    DynamicFlag1::Stub,
+    DynamicFlag1::PIE
+];
+
 /// Offsets for ELF dynamic linking table entries.
 ///
 /// This contains the various offsets for fields in an ELF dynamic
@@ -187,6 +242,7 @@ pub trait DynamicOffsets: ElfClass {
 /// use elf_utils::dynamic::Dynamic;
 /// use elf_utils::dynamic::DynamicEntData;
 /// use elf_utils::dynamic::DynamicEntDataRaw;
+/// use elf_utils::dynamic::DynamicFlags;
 ///
 /// const DYNAMIC: [u8; 96] = [
 ///     0x1e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
@@ -203,7 +259,7 @@ pub trait DynamicOffsets: ElfClass {
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
 /// ];
 /// const DYNAMIC_ENTS: [DynamicEntDataRaw<Elf32>; 12] = [
-///     DynamicEntData::Flags { flags: 0x2 },
+///     DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
 ///     DynamicEntData::Rel { tab: 0x7f4 },
 ///     DynamicEntData::RelSize { size: 1512 },
 ///     DynamicEntData::RelEntSize { size: 8 },
@@ -288,6 +344,68 @@ pub struct DynamicEnt<'a, B: ByteOrder, Offsets: DynamicOffsets> {
     data: &'a [u8]
 }
 
+/// In-place mutable ELF dynamic linking table.
+///
+/// This is the mutable counterpart to [Dynamic].  It can be created
+/// from raw data using the [TryFrom](core::convert::TryFrom)
+/// instance, and provides [DynamicEntMut]s through
+/// [idx_mut](DynamicMut::idx_mut), which can be used to patch dynamic
+/// linking entries in place.
+pub struct DynamicMut<'a, B: ByteOrder, Offsets: DynamicOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a mut [u8]
+}
+
+/// In-place mutable ELF dynamic linking table entry.
+///
+/// This is obtained from a [DynamicMut] using
+/// [idx_mut](DynamicMut::idx_mut).  The tag and value can be changed
+/// individually, or the entire entry can be replaced with
+/// [set](DynamicEntMut::set).  All values are written in the byte
+/// order of the underlying data.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::Elf32;
+/// use elf_utils::dynamic::DynamicEntData;
+/// use elf_utils::dynamic::DynamicEntDataRaw;
+/// use elf_utils::dynamic::DynamicMut;
+///
+/// let mut buf: [u8; 16] = [
+///     0x11, 0x00, 0x00, 0x00, 0xf4, 0x07, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+/// let mut dynamic: DynamicMut<'_, LittleEndian, Elf32> =
+///     DynamicMut::try_from(&mut buf[0..]).unwrap();
+/// let mut ent = dynamic.idx_mut(0).unwrap();
+///
+/// ent.set_ptr(0x800);
+///
+/// let data: DynamicEntDataRaw<Elf32> = ent.try_into().unwrap();
+///
+/// assert_eq!(data, DynamicEntData::Rel { tab: 0x800 });
+///
+/// let mut ent = dynamic.idx_mut(1).unwrap();
+///
+/// ent.set(&DynamicEntData::RelSize { size: 0x40 });
+///
+/// let data: DynamicEntDataRaw<Elf32> = ent.try_into().unwrap();
+///
+/// assert_eq!(data, DynamicEntData::RelSize { size: 0x40 });
+/// ```
+pub struct DynamicEntMut<'a, B: ByteOrder, Offsets: DynamicOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a mut [u8]
+}
+
 /// Iterator for [Dynamic]s.
 ///
 /// This iterator produces [DynamicEnt]s referenceding the dynamic
@@ -305,6 +423,7 @@ pub struct DynamicEnt<'a, B: ByteOrder, Offsets: DynamicOffsets> {
 /// use elf_utils::dynamic::Dynamic;
 /// use elf_utils::dynamic::DynamicEntData;
 /// use elf_utils::dynamic::DynamicEntDataRaw;
+/// use elf_utils::dynamic::DynamicFlags;
 ///
 /// const DYNAMIC: [u8; 96] = [
 ///     0x1e, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
@@ -321,7 +440,7 @@ pub struct DynamicEnt<'a, B: ByteOrder, Offsets: DynamicOffsets> {
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
 /// ];
 /// const DYNAMIC_ENTS: [DynamicEntDataRaw<Elf32>; 12] = [
-///     DynamicEntData::Flags { flags: 0x2 },
+///     DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
 ///     DynamicEntData::Rel { tab: 0x7f4 },
 ///     DynamicEntData::RelSize { size: 1512 },
 ///     DynamicEntData::RelEntSize { size: 8 },
@@ -392,6 +511,144 @@ pub enum DynamicEntStrsError<Class: ElfClass> {
     BadName(Class::Offset),
 }
 
+/// Individual flags that can appear in a `DT_FLAGS` entry.
+///
+/// These are the values recognized by [DynamicFlags].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DynamicFlag {
+    /// The object may use `$ORIGIN` substitution (`DF_ORIGIN`).
+    Origin,
+    /// Symbol resolution starts in the object itself (`DF_SYMBOLIC`).
+    Symbolic,
+    /// Relocations may modify non-writable segments (`DF_TEXTREL`).
+    TextRel,
+    /// All relocations must be processed at load time (`DF_BIND_NOW`).
+    BindNow,
+    /// The object uses the static TLS model (`DF_STATIC_TLS`).
+    StaticTLS
+}
+
+/// Individual flags that can appear in a `DT_FLAGS_1` entry.
+///
+/// These are the values recognized by [DynamicFlags1].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DynamicFlag1 {
+    /// Bind all symbols at load time (`DF_1_NOW`).
+    Now,
+    /// Make symbols globally available (`DF_1_GLOBAL`).
+    Global,
+    /// The object is a member of a group (`DF_1_GROUP`).
+    Group,
+    /// The object cannot be unloaded (`DF_1_NODELETE`).
+    NoDelete,
+    /// Filtees are loaded immediately (`DF_1_LOADFLTR`).
+    LoadFilter,
+    /// Run this object's initializers first (`DF_1_INITFIRST`).
+    InitFirst,
+    /// The object cannot be opened with `dlopen` (`DF_1_NOOPEN`).
+    NoOpen,
+    /// The object may use `$ORIGIN` substitution (`DF_1_ORIGIN`).
+    Origin,
+    /// Direct binding is enabled (`DF_1_DIRECT`).
+    Direct,
+    /// Reserved (`DF_1_TRANS`).
+    Trans,
+    /// The object interposes on other objects (`DF_1_INTERPOSE`).
+    Interpose,
+    /// Ignore the default library search path (`DF_1_NODEFLIB`).
+    NoDefaultLib,
+    /// The object cannot be dumped with `dldump` (`DF_1_NODUMP`).
+    NoDump,
+    /// The object is a configuration alternative (`DF_1_CONFALT`).
+    ConfAlt,
+    /// Filtee terminates the filter search (`DF_1_ENDFILTEE`).
+    EndFiltee,
+    /// Displacement relocations have been done (`DF_1_DISPRELDNE`).
+    DispRelDone,
+    /// Displacement relocations are pending (`DF_1_DISPRELPND`).
+    DispRelPending,
+    /// The object has no direct bindings (`DF_1_NODIRECT`).
+    NoDirect,
+    /// Ignore multiple definitions (`DF_1_IGNMULDEF`).
+    IgnoreMultiDef,
+    /// Do not export kernel symbols (`DF_1_NOKSYMS`).
+    NoKSyms,
+    /// The object has no ELF header (`DF_1_NOHDR`).
+    NoHdr,
+    /// The object has been modified since linking (`DF_1_EDITED`).
+    Edited,
+    /// The object has no relocations (`DF_1_NORELOC`).
+    NoReloc,
+    /// Individual symbols may interpose (`DF_1_SYMINTPOSE`).
+    SymInterpose,
+    /// Global auditing is required (`DF_1_GLOBAUDIT`).
+    GlobalAudit,
+    /// The object defines singleton symbols (`DF_1_SINGLETON`).
+    Singleton,
+    /// The object is a stub (`DF_1_STUB`).
+    Stub,
+    /// The object is a position-independent executable (`DF_1_PIE`).
+    PIE
+}
+
+/// Decoded contents of a `DT_FLAGS` entry.
+///
+/// This is a bitset over the [DynamicFlag] values.  All bits of the
+/// original entry are kept, including any that do not correspond to
+/// a known flag, so that projecting a [DynamicEnt] and then writing
+/// it out again with [create](Dynamic::create) produces the same
+/// entry.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use elf_utils::Elf32;
+/// use elf_utils::dynamic::DynamicFlag;
+/// use elf_utils::dynamic::DynamicFlags;
+///
+/// let mut flags: DynamicFlags<Elf32> = DynamicFlags { bits: 0x2 };
+///
+/// assert!(flags.contains(DynamicFlag::Symbolic));
+/// assert!(!flags.contains(DynamicFlag::BindNow));
+///
+/// flags.insert(DynamicFlag::BindNow);
+///
+/// assert_eq!(flags.bits, 0xa);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DynamicFlags<Class: ElfClass> {
+    /// The raw flag bits.
+    pub bits: Class::Offset
+}
+
+/// Decoded contents of a `DT_FLAGS_1` entry.
+///
+/// This is a bitset over the [DynamicFlag1] values.  As with
+/// [DynamicFlags], unknown bits are preserved.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use elf_utils::Elf64;
+/// use elf_utils::dynamic::DynamicFlag1;
+/// use elf_utils::dynamic::DynamicFlags1;
+///
+/// let flags: DynamicFlags1<Elf64> = DynamicFlags1 { bits: 0x8000001 };
+///
+/// assert!(flags.contains(DynamicFlag1::Now));
+/// assert!(flags.contains(DynamicFlag1::PIE));
+/// assert_eq!(flags.unknown(), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DynamicFlags1<Class: ElfClass> {
+    /// The raw flag bits.
+    pub bits: Class::Offset
+}
+
 /// Projected ELF dynamic linking data.
 ///
 /// This is a representation of an ELF dynamic linking entry projected
@@ -476,10 +733,21 @@ pub enum DynamicEntData<Name, Idx, Class: ElfClass> {
         name: Name
     },
     /// Indicates the search path for shared objects.
+    ///
+    /// This is the older `DT_RPATH` form, which is searched before
+    /// the `LD_LIBRARY_PATH` environment variable.
     RPath {
         /// Search path for shared objects.
         path: Name
     },
+    /// Indicates the search path for shared objects.
+    ///
+    /// This is the `DT_RUNPATH` form, which is searched after the
+    /// `LD_LIBRARY_PATH` environment variable.
+    RunPath {
+        /// Search path for shared objects.
+        path: Name
+    },
     /// Indicates that the search for symbols should start in the
     /// shared object itself, not the executable.
     Symbolic,
@@ -544,7 +812,7 @@ pub enum DynamicEntData<Name, Idx, Class: ElfClass> {
     /// Provides the flags.
     Flags {
         /// The flags field.
-        flags: Class::Offset
+        flags: DynamicFlags<Class>
     },
     /// Provides the pre-initialization function array.
     PreInitArray {
@@ -561,6 +829,68 @@ pub enum DynamicEntData<Name, Idx, Class: ElfClass> {
         /// Section header index of the symbol table.
         idx: Idx
     },
+    /// Provides the size of the compact relative relocation table.
+    RelrSize {
+        /// Size of the compact relative relocation table.
+        size: Class::Offset
+    },
+    /// Provides the compact relative relocation table.
+    Relr {
+        /// Address of the compact relative relocation table.
+        tab: Class::Addr
+    },
+    /// Provides the compact relative relocation table entry size.
+    RelrEntSize {
+        /// Size of compact relative relocation table entries.
+        size: Class::Offset
+    },
+    /// Provides the GNU-style symbol hash table.
+    GnuHash {
+        /// Address of the GNU hash table.
+        tab: Class::Addr
+    },
+    /// Provides the symbol version table.
+    Versym {
+        /// Address of the symbol version table.
+        tab: Class::Addr
+    },
+    /// Provides the number of relative relocations in the relocation
+    /// table with explicit addends.
+    RelaCount {
+        /// Number of relative relocations.
+        count: Class::Offset
+    },
+    /// Provides the number of relative relocations in the relocation
+    /// table with implicit addends.
+    RelCount {
+        /// Number of relative relocations.
+        count: Class::Offset
+    },
+    /// Provides the extended flags.
+    Flags1 {
+        /// The extended flags field.
+        flags: DynamicFlags1<Class>
+    },
+    /// Provides the version definition table.
+    Verdef {
+        /// Address of the version definition table.
+        tab: Class::Addr
+    },
+    /// Provides the number of entries in the version definition table.
+    VerdefNum {
+        /// Number of version definitions.
+        num: Class::Offset
+    },
+    /// Provides the version requirement table.
+    Verneed {
+        /// Address of the version requirement table.
+        tab: Class::Addr
+    },
+    /// Provides the number of entries in the version requirement table.
+    VerneedNum {
+        /// Number of version requirements.
+        num: Class::Offset
+    },
     /// Unknown dynamic information type.
     Unknown {
         /// The tag for this information.
@@ -742,7 +1072,7 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
 
             Ok(DynamicEntData::JumpRel { tab: ptr })
         },
-        Ok(24) => Ok(DynamicEntData::TextRel),
+        Ok(24) => Ok(DynamicEntData::BindNow),
         Ok(25) => {
             let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
                                                     Offsets::D_PTR_END]);
@@ -771,13 +1101,13 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
             let path = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
                                                        Offsets::D_VAL_END]);
 
-            Ok(DynamicEntData::RPath { path: path })
+            Ok(DynamicEntData::RunPath { path: path })
         },
         Ok(30) => {
-            let val = Offsets::read_offset::<B>(&data[Offsets::D_PTR_START ..
-                                                      Offsets::D_PTR_END]);
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
 
-            Ok(DynamicEntData::Flags { flags: val })
+            Ok(DynamicEntData::Flags { flags: DynamicFlags { bits: val } })
         },
         Ok(32) => {
             let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
@@ -797,6 +1127,78 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
 
             Ok(DynamicEntData::SymtabIdx { idx: idx })
         },
+        Ok(35) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::RelrSize { size: val })
+        },
+        Ok(36) => {
+            let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
+                                                    Offsets::D_PTR_END]);
+
+            Ok(DynamicEntData::Relr { tab: ptr })
+        },
+        Ok(37) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::RelrEntSize { size: val })
+        },
+        Ok(DT_GNU_HASH) => {
+            let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
+                                                    Offsets::D_PTR_END]);
+
+            Ok(DynamicEntData::GnuHash { tab: ptr })
+        },
+        Ok(DT_VERSYM) => {
+            let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
+                                                    Offsets::D_PTR_END]);
+
+            Ok(DynamicEntData::Versym { tab: ptr })
+        },
+        Ok(DT_RELACOUNT) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::RelaCount { count: val })
+        },
+        Ok(DT_RELCOUNT) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::RelCount { count: val })
+        },
+        Ok(DT_FLAGS_1) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::Flags1 { flags: DynamicFlags1 { bits: val } })
+        },
+        Ok(DT_VERDEF) => {
+            let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
+                                                    Offsets::D_PTR_END]);
+
+            Ok(DynamicEntData::Verdef { tab: ptr })
+        },
+        Ok(DT_VERDEFNUM) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::VerdefNum { num: val })
+        },
+        Ok(DT_VERNEED) => {
+            let ptr = Offsets::read_addr::<B>(&data[Offsets::D_PTR_START ..
+                                                    Offsets::D_PTR_END]);
+
+            Ok(DynamicEntData::Verneed { tab: ptr })
+        },
+        Ok(DT_VERNEEDNUM) => {
+            let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
+                                                      Offsets::D_VAL_END]);
+
+            Ok(DynamicEntData::VerneedNum { num: val })
+        },
         Ok(_) => {
             let val = Offsets::read_offset::<B>(&data[Offsets::D_VAL_START ..
                                                       Offsets::D_VAL_END]);
@@ -807,6 +1209,398 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
     }
 }
 
+#[inline]
+fn write<B, Offsets>(data: &mut [u8], ent: &DynamicEntDataRaw<Offsets>)
+    where Offsets: DynamicOffsets,
+          B: ByteOrder {
+    match ent {
+        DynamicEntData::None => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (0 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       (0 as u8).into());
+        },
+        DynamicEntData::Needed { name } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (1 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *name);
+        },
+        DynamicEntData::PLTRelSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (2 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::PLTGOT { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (3 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::Hash { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (4 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::Strtab { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (5 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::Symtab { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (6 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::Rela { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (7 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::RelaSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (8 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::RelaEntSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (9 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::StrtabSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (10 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::SymtabEntSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (11 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::Init { func } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (12 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *func);
+        },
+        DynamicEntData::Fini { func } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (13 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *func);
+        },
+        DynamicEntData::Name { name } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (14 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_PTR_START ..
+                                                 Offsets::D_PTR_END],
+                                       *name);
+        },
+        DynamicEntData::RPath { path } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (15 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_PTR_START ..
+                                                 Offsets::D_PTR_END],
+                                       *path);
+        },
+        DynamicEntData::RunPath { path } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (29 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *path);
+        },
+        DynamicEntData::Symbolic => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (16 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       (0 as u8).into());
+        },
+        DynamicEntData::Rel { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (17 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::RelSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (18 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::RelEntSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (19 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::PLTRela { rela: true } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (20 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       (7 as u8).into());
+        },
+        DynamicEntData::PLTRela { rela: false } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (20 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       (17 as u8).into());
+        },
+        DynamicEntData::Debug { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (21 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::TextRel => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (22 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       (0 as u8).into());
+        },
+        DynamicEntData::JumpRel { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (23 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::BindNow => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (24 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       (0 as u8).into());
+        },
+        DynamicEntData::InitArray { arr } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (25 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *arr);
+        },
+        DynamicEntData::FiniArray { arr } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (26 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *arr);
+        },
+        DynamicEntData::InitArraySize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (27 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::FiniArraySize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (28 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::Flags { flags } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (30 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       flags.bits);
+        },
+        DynamicEntData::PreInitArray { arr } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (32 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *arr);
+        },
+        DynamicEntData::PreInitArraySize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (33 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::SymtabIdx { idx } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (34 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *idx);
+        },
+        DynamicEntData::RelrSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (35 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::Relr { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (36 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::RelrEntSize { size } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (37 as u8).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *size);
+        },
+        DynamicEntData::GnuHash { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_GNU_HASH as u32).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::Versym { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_VERSYM as u32).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::RelaCount { count } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_RELACOUNT as u32).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *count);
+        },
+        DynamicEntData::RelCount { count } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_RELCOUNT as u32).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *count);
+        },
+        DynamicEntData::Flags1 { flags } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_FLAGS_1 as u32).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       flags.bits);
+        },
+        DynamicEntData::Verdef { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_VERDEF as u32).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::VerdefNum { num } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_VERDEFNUM as u32).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *num);
+        },
+        DynamicEntData::Verneed { tab } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_VERNEED as u32).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
+                                               Offsets::D_PTR_END],
+                                     *tab);
+        },
+        DynamicEntData::VerneedNum { num } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       (DT_VERNEEDNUM as u32).into());
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *num);
+        },
+        DynamicEntData::Unknown { tag, info } => {
+            Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
+                                                 Offsets::D_TAG_END],
+                                       *tag);
+            Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
+                                                 Offsets::D_VAL_END],
+                                       *info);
+        }
+    }
+}
+
 fn create<'a, B, I, Offsets>(buf: &'a mut [u8], ents: I) ->
     Result<(&'a mut [u8], &'a mut [u8]), ()>
     where I: Iterator,
@@ -823,288 +1617,7 @@ fn create<'a, B, I, Offsets>(buf: &'a mut [u8], ents: I) ->
         if idx + Offsets::DYNAMIC_SIZE <= len {
             let data = &mut buf[idx .. idx + Offsets::DYNAMIC_SIZE];
 
-            match ent {
-                DynamicEntData::None => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (0 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               (0 as u8).into());
-                },
-                DynamicEntData::Needed { name } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (1 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *name);
-                },
-                DynamicEntData::PLTRelSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (2 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::PLTGOT { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (3 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::Hash { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (4 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::Strtab { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (5 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::Symtab { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (6 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::Rela { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (7 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::RelaSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (8 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::RelaEntSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (9 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::StrtabSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (10 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::SymtabEntSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (11 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::Init { func } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (12 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *func);
-                },
-                DynamicEntData::Fini { func } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (13 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *func);
-                },
-                DynamicEntData::Name { name } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (14 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_PTR_START ..
-                                                         Offsets::D_PTR_END],
-                                               *name);
-                },
-                DynamicEntData::RPath { path } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (29 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_PTR_START ..
-                                                         Offsets::D_PTR_END],
-                                               *path);
-                },
-                DynamicEntData::Symbolic => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (16 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               (0 as u8).into());
-                },
-                DynamicEntData::Rel { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (17 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::RelSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (18 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::RelEntSize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (19 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::PLTRela { rela: true } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (20 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               (7 as u8).into());
-                },
-                DynamicEntData::PLTRela { rela: false } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (20 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               (17 as u8).into());
-                },
-                DynamicEntData::Debug { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (21 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::TextRel => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (22 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               (0 as u8).into());
-                },
-                DynamicEntData::JumpRel { tab } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (23 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *tab);
-                },
-                DynamicEntData::BindNow => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (24 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               (0 as u8).into());
-                },
-                DynamicEntData::InitArray { arr } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (25 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *arr);
-                },
-                DynamicEntData::FiniArray { arr } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (26 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *arr);
-                },
-                DynamicEntData::InitArraySize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (27 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::FiniArraySize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (28 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::Flags { flags } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (30 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *flags);
-                },
-                DynamicEntData::PreInitArray { arr } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (32 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::D_PTR_START ..
-                                                       Offsets::D_PTR_END],
-                                             *arr);
-                },
-                DynamicEntData::PreInitArraySize { size } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (33 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *size);
-                },
-                DynamicEntData::SymtabIdx { idx } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               (34 as u8).into());
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *idx);
-                },
-                DynamicEntData::Unknown { tag, info } => {
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_TAG_START ..
-                                                         Offsets::D_TAG_END],
-                                               *tag);
-                    Offsets::write_offset::<B>(&mut data[Offsets::D_VAL_START ..
-                                                         Offsets::D_VAL_END],
-                                               *info);
-                }
-            }
+            write::<B, Offsets>(data, ent);
 
             idx += Offsets::DYNAMIC_SIZE;
         } else {
@@ -1125,6 +1638,92 @@ impl DynamicOffsets for Elf64 {
     const DYNAMIC_SIZE_OFFSET: Self::Offset = Self::DYNAMIC_SIZE as u64;
 }
 
+impl DynamicFlag {
+    /// Get the bit in a `DT_FLAGS` entry corresponding to this flag.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        match self {
+            DynamicFlag::Origin => DF_ORIGIN,
+            DynamicFlag::Symbolic => DF_SYMBOLIC,
+            DynamicFlag::TextRel => DF_TEXTREL,
+            DynamicFlag::BindNow => DF_BIND_NOW,
+            DynamicFlag::StaticTLS => DF_STATIC_TLS
+        }
+    }
+}
+
+impl DynamicFlag1 {
+    /// Get the bit in a `DT_FLAGS_1` entry corresponding to this flag.
+    ///
+    /// The `DF_1_*` values are assigned sequentially, so this is
+    /// simply the position of the flag in the enumeration.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        1 << (*self as u32)
+    }
+}
+
+impl<Class: ElfClass> DynamicFlags<Class> {
+    /// Check whether `flag` is set.
+    #[inline]
+    pub fn contains(&self, flag: DynamicFlag) -> bool {
+        let mask: Class::Offset = flag.mask().into();
+
+        self.bits & mask == mask
+    }
+
+    /// Set `flag`.
+    #[inline]
+    pub fn insert(&mut self, flag: DynamicFlag) {
+        self.bits = self.bits | flag.mask().into();
+    }
+
+    /// Clear `flag`.
+    #[inline]
+    pub fn remove(&mut self, flag: DynamicFlag) {
+        self.bits = self.bits & !Class::Offset::from(flag.mask());
+    }
+
+    /// Get any set bits that do not correspond to a [DynamicFlag].
+    #[inline]
+    pub fn unknown(&self) -> Class::Offset {
+        let known = DYNAMIC_FLAGS.iter().fold(0, |acc, flag| acc | flag.mask());
+
+        self.bits & !Class::Offset::from(known)
+    }
+}
+
+impl<Class: ElfClass> DynamicFlags1<Class> {
+    /// Check whether `flag` is set.
+    #[inline]
+    pub fn contains(&self, flag: DynamicFlag1) -> bool {
+        let mask: Class::Offset = flag.mask().into();
+
+        self.bits & mask == mask
+    }
+
+    /// Set `flag`.
+    #[inline]
+    pub fn insert(&mut self, flag: DynamicFlag1) {
+        self.bits = self.bits | flag.mask().into();
+    }
+
+    /// Clear `flag`.
+    #[inline]
+    pub fn remove(&mut self, flag: DynamicFlag1) {
+        self.bits = self.bits & !Class::Offset::from(flag.mask());
+    }
+
+    /// Get any set bits that do not correspond to a [DynamicFlag1].
+    #[inline]
+    pub fn unknown(&self) -> Class::Offset {
+        let known = DYNAMIC_FLAGS_1.iter()
+                                   .fold(0, |acc, flag| acc | flag.mask());
+
+        self.bits & !Class::Offset::from(known)
+    }
+}
+
 impl<'a, B, Offsets: DynamicOffsets> TryFrom<&'a [u8]>
     for Dynamic<'a, B, Offsets>
     where B: ByteOrder {
@@ -1170,6 +1769,28 @@ impl<'a, B, Offsets: DynamicOffsets> TryFrom<&'a mut [u8]>
     }
 }
 
+impl<'a, B, Offsets: DynamicOffsets> TryFrom<&'a mut [u8]>
+    for DynamicMut<'a, B, Offsets>
+    where B: ByteOrder {
+    type Error = DynamicError;
+
+    /// Create a `DynamicMut` from the data buffer.  This will check
+    /// that the data buffer is a multiple of the dynamic linking
+    /// entry size.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) ->
+        Result<DynamicMut<'a, B, Offsets>, Self::Error> {
+        let len = data.len();
+
+        if data.len() % Offsets::DYNAMIC_SIZE == 0 {
+            Ok(DynamicMut { byteorder: PhantomData, offsets: PhantomData,
+                            data: data })
+        } else {
+            Err(DynamicError::BadSize(len))
+        }
+    }
+}
+
 /// Calculate the number of bytes required to represent the dynamic
 /// linking table containing `ents`.
 ///
@@ -1185,10 +1806,11 @@ impl<'a, B, Offsets: DynamicOffsets> TryFrom<&'a mut [u8]>
 /// use elf_utils::dynamic::Dynamic;
 /// use elf_utils::dynamic::DynamicEntData;
 /// use elf_utils::dynamic::DynamicEntDataRaw;
+/// use elf_utils::dynamic::DynamicFlags;
 /// use elf_utils::dynamic;
 ///
 /// const DYNAMIC_ENTS: [DynamicEntDataRaw<Elf32>; 12] = [
-///     DynamicEntData::Flags { flags: 0x2 },
+///     DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
 ///     DynamicEntData::Rel { tab: 0x7f4 },
 ///     DynamicEntData::RelSize { size: 1512 },
 ///     DynamicEntData::RelEntSize { size: 8 },
@@ -1239,10 +1861,11 @@ impl<'a, B, Offsets> Dynamic<'a, B, Offsets>
     /// use elf_utils::dynamic::Dynamic;
     /// use elf_utils::dynamic::DynamicEntData;
     /// use elf_utils::dynamic::DynamicEntDataRaw;
+/// use elf_utils::dynamic::DynamicFlags;
     /// use elf_utils::dynamic;
     ///
     /// const DYNAMIC_ENTS: [DynamicEntDataRaw<Elf32>; 12] = [
-    ///     DynamicEntData::Flags { flags: 0x2 },
+    ///     DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
     ///     DynamicEntData::Rel { tab: 0x7f4 },
     ///     DynamicEntData::RelSize { size: 1512 },
     ///     DynamicEntData::RelEntSize { size: 8 },
@@ -1315,10 +1938,11 @@ impl<'a, B, Offsets> Dynamic<'a, B, Offsets>
     /// use elf_utils::dynamic::Dynamic;
     /// use elf_utils::dynamic::DynamicEntData;
     /// use elf_utils::dynamic::DynamicEntDataRaw;
+/// use elf_utils::dynamic::DynamicFlags;
     /// use elf_utils::dynamic;
     ///
     /// const DYNAMIC_ENTS: [DynamicEntDataRaw<Elf32>; 12] = [
-    ///     DynamicEntData::Flags { flags: 0x2 },
+    ///     DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
     ///     DynamicEntData::Rel { tab: 0x7f4 },
     ///     DynamicEntData::RelSize { size: 1512 },
     ///     DynamicEntData::RelEntSize { size: 8 },
@@ -1393,6 +2017,109 @@ impl<'a, B, Offsets> Dynamic<'a, B, Offsets>
 }
 
 
+impl<'a, B, Offsets> DynamicMut<'a, B, Offsets>
+    where Offsets: DynamicOffsets,
+          B: ByteOrder {
+    /// Get a [DynamicEnt] for the dynamic linking entry at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<DynamicEnt<'_, B, Offsets>> {
+        let len = self.data.len();
+        let start = idx * Offsets::DYNAMIC_SIZE;
+
+        if start < len {
+            let end = start + Offsets::DYNAMIC_SIZE;
+
+            Some(DynamicEnt { byteorder: PhantomData, offsets: PhantomData,
+                              data: &self.data[start .. end ] })
+        } else {
+            None
+        }
+    }
+
+    /// Get a [DynamicEntMut] for the dynamic linking entry at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx_mut(&mut self, idx: usize) ->
+        Option<DynamicEntMut<'_, B, Offsets>> {
+        let len = self.data.len();
+        let start = idx * Offsets::DYNAMIC_SIZE;
+
+        if start < len {
+            let end = start + Offsets::DYNAMIC_SIZE;
+
+            Some(DynamicEntMut { byteorder: PhantomData, offsets: PhantomData,
+                                 data: &mut self.data[start .. end ] })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of dynamic linking entries in this `DynamicMut`.
+    #[inline]
+    pub fn num_ents(&self) -> usize {
+        self.data.len() / Offsets::DYNAMIC_SIZE
+    }
+}
+
+impl<'a, B, Offsets> DynamicEntMut<'a, B, Offsets>
+    where Offsets: DynamicOffsets,
+          B: ByteOrder {
+    /// Replace this dynamic linking entry with `ent`.
+    #[inline]
+    pub fn set(&mut self, ent: &DynamicEntDataRaw<Offsets>) {
+        write::<B, Offsets>(self.data, ent)
+    }
+
+    /// Set the tag (`d_tag`) of this entry to `tag`, leaving the
+    /// value unchanged.
+    #[inline]
+    pub fn set_tag(&mut self, tag: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.data[Offsets::D_TAG_START ..
+                                                  Offsets::D_TAG_END],
+                                   tag)
+    }
+
+    /// Set the value (`d_val`) of this entry to `val`.
+    #[inline]
+    pub fn set_val(&mut self, val: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.data[Offsets::D_VAL_START ..
+                                                  Offsets::D_VAL_END],
+                                   val)
+    }
+
+    /// Set the pointer (`d_ptr`) of this entry to `ptr`.
+    ///
+    /// This occupies the same space as the value, and is used by
+    /// entries that refer to addresses.
+    #[inline]
+    pub fn set_ptr(&mut self, ptr: Offsets::Addr) {
+        Offsets::write_addr::<B>(&mut self.data[Offsets::D_PTR_START ..
+                                                Offsets::D_PTR_END],
+                                 ptr)
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<DynamicEntMut<'a, B, Offsets>>
+    for DynamicEntDataRaw<Offsets>
+    where Offsets: DynamicOffsets,
+          B: ByteOrder {
+    type Error = DynamicEntDataError<Offsets>;
+
+    #[inline]
+    fn try_from(ent: DynamicEntMut<'a, B, Offsets>) ->
+        Result<DynamicEntData<Offsets::Offset, Offsets::Offset, Offsets>,
+               Self::Error> {
+        project::<B, Offsets>(ent.data)
+    }
+}
+
 impl<'a, B, Offsets> TryFrom<DynamicEnt<'a, B, Offsets>>
     for DynamicEntDataRaw<Offsets>
     where Offsets: DynamicOffsets,
@@ -1462,6 +2189,12 @@ impl<'a, B, Offsets> WithStrtab<'a> for DynamicEnt<'a, B, Offsets>
                     Ok(DynamicEntData::RPath { path: Err(data) }),
                 _ => Err(DynamicEntStrsError::BadName(path))
             },
+            Ok(DynamicEntData::RunPath { path }) => match strtab.idx(path) {
+                Ok(path) => Ok(DynamicEntData::RunPath { path: Ok(path) }),
+                Err(StrtabIdxError::UTF8Decode(data)) =>
+                    Ok(DynamicEntData::RunPath { path: Err(data) }),
+                _ => Err(DynamicEntStrsError::BadName(path))
+            },
             Ok(DynamicEntData::Symbolic) =>
                 Ok(DynamicEntData::Symbolic),
             Ok(DynamicEntData::Rel { tab }) =>
@@ -1494,6 +2227,30 @@ impl<'a, B, Offsets> WithStrtab<'a> for DynamicEnt<'a, B, Offsets>
                 Ok(DynamicEntData::PreInitArraySize { size: size }),
             Ok(DynamicEntData::SymtabIdx { idx }) =>
                 Ok(DynamicEntData::SymtabIdx { idx: idx }),
+            Ok(DynamicEntData::RelrSize { size }) =>
+                Ok(DynamicEntData::RelrSize { size: size }),
+            Ok(DynamicEntData::Relr { tab }) =>
+                Ok(DynamicEntData::Relr { tab: tab }),
+            Ok(DynamicEntData::RelrEntSize { size }) =>
+                Ok(DynamicEntData::RelrEntSize { size: size }),
+            Ok(DynamicEntData::GnuHash { tab }) =>
+                Ok(DynamicEntData::GnuHash { tab: tab }),
+            Ok(DynamicEntData::Versym { tab }) =>
+                Ok(DynamicEntData::Versym { tab: tab }),
+            Ok(DynamicEntData::RelaCount { count }) =>
+                Ok(DynamicEntData::RelaCount { count: count }),
+            Ok(DynamicEntData::RelCount { count }) =>
+                Ok(DynamicEntData::RelCount { count: count }),
+            Ok(DynamicEntData::Flags1 { flags }) =>
+                Ok(DynamicEntData::Flags1 { flags: flags }),
+            Ok(DynamicEntData::Verdef { tab }) =>
+                Ok(DynamicEntData::Verdef { tab: tab }),
+            Ok(DynamicEntData::VerdefNum { num }) =>
+                Ok(DynamicEntData::VerdefNum { num: num }),
+            Ok(DynamicEntData::Verneed { tab }) =>
+                Ok(DynamicEntData::Verneed { tab: tab }),
+            Ok(DynamicEntData::VerneedNum { num }) =>
+                Ok(DynamicEntData::VerneedNum { num: num }),
             Ok(DynamicEntData::Unknown { tag, info }) =>
                 Ok(DynamicEntData::Unknown { tag: tag, info: info }),
             Err(DynamicEntDataError::BadRelocs(reloc)) =>
@@ -1559,6 +2316,12 @@ impl<'a, Name, Idx, Class> WithStrtab<'a>
                     Ok(DynamicEntData::RPath { path: Err(data) }),
                 _ => Err(path)
             },
+            DynamicEntData::RunPath { path } => match strtab.idx(path) {
+                Ok(path) => Ok(DynamicEntData::RunPath { path: Ok(path) }),
+                Err(StrtabIdxError::UTF8Decode(data)) =>
+                    Ok(DynamicEntData::RunPath { path: Err(data) }),
+                _ => Err(path)
+            },
             DynamicEntData::Symbolic =>
                 Ok(DynamicEntData::Symbolic),
             DynamicEntData::Rel { tab } =>
@@ -1591,6 +2354,30 @@ impl<'a, Name, Idx, Class> WithStrtab<'a>
                 Ok(DynamicEntData::PreInitArraySize { size: size }),
             DynamicEntData::SymtabIdx { idx } =>
                 Ok(DynamicEntData::SymtabIdx { idx: idx }),
+            DynamicEntData::RelrSize { size } =>
+                Ok(DynamicEntData::RelrSize { size: size }),
+            DynamicEntData::Relr { tab } =>
+                Ok(DynamicEntData::Relr { tab: tab }),
+            DynamicEntData::RelrEntSize { size } =>
+                Ok(DynamicEntData::RelrEntSize { size: size }),
+            DynamicEntData::GnuHash { tab } =>
+                Ok(DynamicEntData::GnuHash { tab: tab }),
+            DynamicEntData::Versym { tab } =>
+                Ok(DynamicEntData::Versym { tab: tab }),
+            DynamicEntData::RelaCount { count } =>
+                Ok(DynamicEntData::RelaCount { count: count }),
+            DynamicEntData::RelCount { count } =>
+                Ok(DynamicEntData::RelCount { count: count }),
+            DynamicEntData::Flags1 { flags } =>
+                Ok(DynamicEntData::Flags1 { flags: flags }),
+            DynamicEntData::Verdef { tab } =>
+                Ok(DynamicEntData::Verdef { tab: tab }),
+            DynamicEntData::VerdefNum { num } =>
+                Ok(DynamicEntData::VerdefNum { num: num }),
+            DynamicEntData::Verneed { tab } =>
+                Ok(DynamicEntData::Verneed { tab: tab }),
+            DynamicEntData::VerneedNum { num } =>
+                Ok(DynamicEntData::VerneedNum { num: num }),
             DynamicEntData::Unknown { tag, info } =>
                 Ok(DynamicEntData::Unknown { tag: tag, info: info }),
         }
@@ -1643,6 +2430,9 @@ impl<'a, Idx, Class> TryFrom<DynamicEntData<Result<&'a str, &'a [u8]>,
             DynamicEntData::RPath { path: Ok(path) } =>
                 Ok(DynamicEntData::RPath { path: path }),
             DynamicEntData::RPath { path: Err(err) } => Err(err),
+            DynamicEntData::RunPath { path: Ok(path) } =>
+                Ok(DynamicEntData::RunPath { path: path }),
+            DynamicEntData::RunPath { path: Err(err) } => Err(err),
             DynamicEntData::Symbolic =>
                 Ok(DynamicEntData::Symbolic),
             DynamicEntData::Rel { tab } =>
@@ -1675,6 +2465,30 @@ impl<'a, Idx, Class> TryFrom<DynamicEntData<Result<&'a str, &'a [u8]>,
                 Ok(DynamicEntData::PreInitArraySize { size: size }),
             DynamicEntData::SymtabIdx { idx } =>
                 Ok(DynamicEntData::SymtabIdx { idx: idx }),
+            DynamicEntData::RelrSize { size } =>
+                Ok(DynamicEntData::RelrSize { size: size }),
+            DynamicEntData::Relr { tab } =>
+                Ok(DynamicEntData::Relr { tab: tab }),
+            DynamicEntData::RelrEntSize { size } =>
+                Ok(DynamicEntData::RelrEntSize { size: size }),
+            DynamicEntData::GnuHash { tab } =>
+                Ok(DynamicEntData::GnuHash { tab: tab }),
+            DynamicEntData::Versym { tab } =>
+                Ok(DynamicEntData::Versym { tab: tab }),
+            DynamicEntData::RelaCount { count } =>
+                Ok(DynamicEntData::RelaCount { count: count }),
+            DynamicEntData::RelCount { count } =>
+                Ok(DynamicEntData::RelCount { count: count }),
+            DynamicEntData::Flags1 { flags } =>
+                Ok(DynamicEntData::Flags1 { flags: flags }),
+            DynamicEntData::Verdef { tab } =>
+                Ok(DynamicEntData::Verdef { tab: tab }),
+            DynamicEntData::VerdefNum { num } =>
+                Ok(DynamicEntData::VerdefNum { num: num }),
+            DynamicEntData::Verneed { tab } =>
+                Ok(DynamicEntData::Verneed { tab: tab }),
+            DynamicEntData::VerneedNum { num } =>
+                Ok(DynamicEntData::VerneedNum { num: num }),
             DynamicEntData::Unknown { tag, info } =>
                 Ok(DynamicEntData::Unknown { tag: tag, info: info }),
         }
@@ -1766,6 +2580,121 @@ impl<Offsets> Display for DynamicEntStrsError<Offsets>
     }
 }
 
+impl Display for DynamicFlag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            DynamicFlag::Origin => write!(f, "origin"),
+            DynamicFlag::Symbolic => write!(f, "symbolic"),
+            DynamicFlag::TextRel => write!(f, "text relocations"),
+            DynamicFlag::BindNow => write!(f, "bind now"),
+            DynamicFlag::StaticTLS => write!(f, "static TLS")
+        }
+    }
+}
+
+impl Display for DynamicFlag1 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            DynamicFlag1::Now => write!(f, "now"),
+            DynamicFlag1::Global => write!(f, "global"),
+            DynamicFlag1::Group => write!(f, "group"),
+            DynamicFlag1::NoDelete => write!(f, "no delete"),
+            DynamicFlag1::LoadFilter => write!(f, "load filter"),
+            DynamicFlag1::InitFirst => write!(f, "init first"),
+            DynamicFlag1::NoOpen => write!(f, "no open"),
+            DynamicFlag1::Origin => write!(f, "origin"),
+            DynamicFlag1::Direct => write!(f, "direct"),
+            DynamicFlag1::Trans => write!(f, "trans"),
+            DynamicFlag1::Interpose => write!(f, "interpose"),
+            DynamicFlag1::NoDefaultLib => write!(f, "no default lib"),
+            DynamicFlag1::NoDump => write!(f, "no dump"),
+            DynamicFlag1::ConfAlt => write!(f, "configuration alternative"),
+            DynamicFlag1::EndFiltee => write!(f, "end filtee"),
+            DynamicFlag1::DispRelDone => write!(f, "displacement relocs done"),
+            DynamicFlag1::DispRelPending =>
+                write!(f, "displacement relocs pending"),
+            DynamicFlag1::NoDirect => write!(f, "no direct"),
+            DynamicFlag1::IgnoreMultiDef => write!(f, "ignore multiple defs"),
+            DynamicFlag1::NoKSyms => write!(f, "no kernel symbols"),
+            DynamicFlag1::NoHdr => write!(f, "no header"),
+            DynamicFlag1::Edited => write!(f, "edited"),
+            DynamicFlag1::NoReloc => write!(f, "no relocations"),
+            DynamicFlag1::SymInterpose => write!(f, "symbol interpose"),
+            DynamicFlag1::GlobalAudit => write!(f, "global audit"),
+            DynamicFlag1::Singleton => write!(f, "singleton"),
+            DynamicFlag1::Stub => write!(f, "stub"),
+            DynamicFlag1::PIE => write!(f, "PIE")
+        }
+    }
+}
+
+impl<Class> Display for DynamicFlags<Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let mut first = true;
+
+        for flag in DYNAMIC_FLAGS.iter().filter(|flag| self.contains(**flag)) {
+            if !first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", flag)?;
+            first = false;
+        }
+
+        let unknown = self.unknown();
+
+        if unknown != Class::Offset::from(0 as u8) {
+            if !first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "0x{:x}", unknown)?;
+            first = false;
+        }
+
+        if first {
+            write!(f, "none")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Class> Display for DynamicFlags1<Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let mut first = true;
+
+        for flag in DYNAMIC_FLAGS_1.iter()
+                                   .filter(|flag| self.contains(**flag)) {
+            if !first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", flag)?;
+            first = false;
+        }
+
+        let unknown = self.unknown();
+
+        if unknown != Class::Offset::from(0 as u8) {
+            if !first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "0x{:x}", unknown)?;
+            first = false;
+        }
+
+        if first {
+            write!(f, "none")?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<Name, Idx, Offsets> Display for DynamicEntData<Name, Idx, Offsets>
     where Offsets: DynamicOffsets,
           Name: Display,
@@ -1818,6 +2747,9 @@ impl<Name, Idx, Offsets> Display for DynamicEntData<Name, Idx, Offsets>
             DynamicEntData::RPath { path } => {
                 write!(f, "Dynamic linking path: {}", path)
             }
+            DynamicEntData::RunPath { path } => {
+                write!(f, "Dynamic linking run path: {}", path)
+            }
             DynamicEntData::Symbolic => write!(f, "Symbolic linking"),
             DynamicEntData::Rel { tab } => {
                 write!(f, "Relocation table: 0x{:x}", tab)
@@ -1856,7 +2788,7 @@ impl<Name, Idx, Offsets> Display for DynamicEntData<Name, Idx, Offsets>
                 write!(f, "Initializer array size: 0x{:x}", size)
             },
             DynamicEntData::Flags { flags } => {
-                write!(f, "Flags: 0x{:x}", flags)
+                write!(f, "Flags: {}", flags)
             },
             DynamicEntData::PreInitArray { arr } => {
                 write!(f, "Pre-initializer array: 0x{:x}", arr)
@@ -1867,6 +2799,44 @@ impl<Name, Idx, Offsets> Display for DynamicEntData<Name, Idx, Offsets>
             DynamicEntData::SymtabIdx { idx } => {
                 write!(f, "Symbol table: {}", idx)
             },
+            DynamicEntData::RelrSize { size } => {
+                write!(f, "Relative relocation table size: 0x{:x}", size)
+            },
+            DynamicEntData::Relr { tab } => {
+                write!(f, "Relative relocation table: 0x{:x}", tab)
+            },
+            DynamicEntData::RelrEntSize { size } => {
+                write!(f, "Relative relocation table entry size: 0x{:x}",
+                       size)
+            },
+            DynamicEntData::GnuHash { tab } => {
+                write!(f, "GNU symbol hash table: 0x{:x}", tab)
+            },
+            DynamicEntData::Versym { tab } => {
+                write!(f, "Symbol version table: 0x{:x}", tab)
+            },
+            DynamicEntData::RelaCount { count } => {
+                write!(f, "Relative relocations (explicit addends): {}",
+                       count)
+            },
+            DynamicEntData::RelCount { count } => {
+                write!(f, "Relative relocations: {}", count)
+            },
+            DynamicEntData::Flags1 { flags } => {
+                write!(f, "Extended flags: {}", flags)
+            },
+            DynamicEntData::Verdef { tab } => {
+                write!(f, "Version definition table: 0x{:x}", tab)
+            },
+            DynamicEntData::VerdefNum { num } => {
+                write!(f, "Version definition count: {}", num)
+            },
+            DynamicEntData::Verneed { tab } => {
+                write!(f, "Version requirement table: 0x{:x}", tab)
+            },
+            DynamicEntData::VerneedNum { num } => {
+                write!(f, "Version requirement count: {}", num)
+            },
             DynamicEntData::Unknown { tag, info } => {
                 write!(f, "Unknown type 0x{:x}: 0x{:x}", tag, info)
             }
@@ -11,7 +11,9 @@ use core::hash::Hash;
 use core::marker::PhantomData;
 use core::ops::Add;
 use core::ops::BitAnd;
+use core::ops::BitOr;
 use core::ops::Mul;
+use core::ops::Not;
 use crate::prog_hdr::ProgHdrs;
 use crate::prog_hdr::ProgHdrsError;
 use crate::prog_hdr::ProgHdrOffsets;
@@ -29,6 +31,10 @@ const ELF_IDENT_END: usize = 0x10;
 
 const ELF_VERSION: u8 = 1;
 
+pub(crate) const PN_XNUM: u32 = 0xffff;
+const SHN_LORESERVE: u32 = 0xff00;
+const SHN_XINDEX: u32 = 0xffff;
+
 /// Trait for ELF byte orderings.
 pub trait ElfByteOrder: ByteOrder {
     /// Code identifying the byte order.
@@ -51,9 +57,11 @@ pub trait ElfClass: Copy + PartialEq + PartialOrd {
                TryInto<usize>;
     /// An offset (into the file or memory).
     type Offset: Add<Output = Self::Offset> + BitAnd<Output = Self::Offset> +
-                 Copy + Debug + Display + Eq + From<u8> + Hash + LowerHex +
-                 Mul<Output = Self::Offset> + Ord + PartialEq + PartialOrd +
-                 TryFrom<usize> + TryInto<usize>;
+                 BitOr<Output = Self::Offset> + Copy + Debug + Display + Eq +
+                 From<u8> + From<u32> + Hash + LowerHex +
+                 Mul<Output = Self::Offset> + Not<Output = Self::Offset> +
+                 Ord + PartialEq + PartialOrd + TryFrom<usize> +
+                 TryInto<usize>;
     /// An addend (a signed offset).
     type Addend: Copy + Debug + Display + Eq + From<u8> + Hash + LowerHex +
                  Ord + PartialEq + PartialOrd + TryInto<usize>;
@@ -706,6 +714,8 @@ pub enum ElfArch {
     BPF,
     /// NEC SX-Aurora VE.
     SXAuroraVE,
+    /// LoongArch.
+    LoongArch,
     /// Unknown code.
     Other(u16)
 }
@@ -732,7 +742,12 @@ pub struct ElfTable<Class: ElfClass> {
     /// Offset into the ELF data of the table.
     pub offset: Class::Offset,
     /// Number of entries in the table.
-    pub num_ents: Class::Half
+    ///
+    /// This may be larger than can be stored in the ELF header, in
+    /// which case the actual value is stored in the first section
+    /// header (see
+    /// [ExtNull](crate::section_hdr::SectionHdrData::ExtNull)).
+    pub num_ents: Class::Word
 }
 
 /// Contents of the ELF data projected into a usable form.
@@ -740,7 +755,7 @@ pub struct ElfTable<Class: ElfClass> {
 /// The easiest way to obtain this type is to use the [TryFrom]
 /// instance on an [Elf].  This will produce an instance having
 /// [ElfTable] as teh program and section header table
-/// representations, and [Offsets::Half](ElfClass::Half) as the
+/// representations, and [Offsets::Word](ElfClass::Word) as the
 /// section header string table representation.
 ///
 /// The projected representation can be converted to one using
@@ -769,6 +784,9 @@ pub struct ElfHdrData<B: ByteOrder, Class: ElfClass, P, S, T> {
     /// Section header table.
     pub section_hdrs: S,
     /// Section header string table.
+    ///
+    /// As with [ElfTable::num_ents], this may be stored in the first
+    /// section header if it is too large to fit in the ELF header.
     pub section_hdr_strtab: T
 }
 
@@ -778,7 +796,7 @@ pub struct ElfHdrData<B: ByteOrder, Class: ElfClass, P, S, T> {
 /// [Elf].  This is also used in [Elf::create] and [Elf::create_split].
 pub type ElfHdrDataRaw<B, Class> =
     ElfHdrData<B, Class, ElfTable<Class>, ElfTable<Class>,
-               <Class as ElfClass>::Half>;
+               <Class as ElfClass>::Word>;
 
 /// Type alias for [ElfHdrData] with `&[u8]` buffers for section and
 /// program header tables.
@@ -786,7 +804,7 @@ pub type ElfHdrDataRaw<B, Class> =
 /// This is produced from an [ElfHdrDataRaw] using the [WithElfData]
 /// instance.
 pub type ElfHdrDataBufs<'a, B, Class> =
-    ElfHdrData<B, Class, &'a [u8], &'a [u8], <Class as ElfClass>::Half>;
+    ElfHdrData<B, Class, &'a [u8], &'a [u8], <Class as ElfClass>::Word>;
 
 /// Type alias for [ElfHdrData] with
 /// [ProgHdrs](crate::prog_hdr::ProgHdrs) and
@@ -796,7 +814,7 @@ pub type ElfHdrDataBufs<'a, B, Class> =
 /// This is produced from an [ElfHdrDataBufs] with the [TryInto] instance.
 pub type ElfHdrDataHdrs<'a, B, Class> =
     ElfHdrData<B, Class, ProgHdrs<'a, B, Class>,
-               SectionHdrs<'a, B, Class>, <Class as ElfClass>::Half>;
+               SectionHdrs<'a, B, Class>, <Class as ElfClass>::Word>;
 
 pub struct ElfHdrMut<'a, B: ByteOrder, Offsets: ElfHdrOffsets> {
     byte_ord: PhantomData<B>,
@@ -901,7 +919,13 @@ pub enum ElfHdrDataError<Class: ElfClass> {
     /// Section header size was incorrect.
     BadSectionHdrEntSize(Class::Half),
     /// Elf type code was not recognized.
-    BadKind(Class::Half)
+    BadKind(Class::Half),
+    /// The first section header, which holds extended ELF header
+    /// values, is outside the data.
+    ExtHdrOutOfBounds(Class::Offset),
+    /// The number of section headers held in the first section
+    /// header is too large.
+    BadSectionHdrNum(Class::Offset)
 }
 
 /// Errors that can occur when converting an `ElfHdrData` with raw
@@ -927,7 +951,7 @@ pub enum ElfHdrTableError {
 
 fn project<'a, B, Offsets>(data: &'a [u8]) ->
     Result<ElfHdrData<B, Offsets, ElfTable<Offsets>,
-                      ElfTable<Offsets>, Offsets::Half>,
+                      ElfTable<Offsets>, Offsets::Word>,
            ElfHdrDataError<Offsets>>
     where Offsets: ElfHdrOffsets,
           B: ByteOrder {
@@ -938,15 +962,11 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
     let ph_num = Offsets::read_half::<B>(&data[Offsets::E_PHNUM_START ..
                                                Offsets::E_PHNUM_END]);
 
-    let prog_hdrs = if ph_offset == (0 as u8).into() &&
-                       ph_num == (0 as u8).into() {
-        None
-    } else if ph_entsize != (0 as u8).into() &&
-              ph_entsize.into() as usize != Offsets::PROG_HDR_SIZE {
+    if ph_entsize != (0 as u8).into() &&
+       ph_entsize.into() as usize != Offsets::PROG_HDR_SIZE &&
+       !(ph_offset == (0 as u8).into() && ph_num == (0 as u8).into()) {
         return Err(ElfHdrDataError::BadProgHdrEntSize(ph_entsize))
-    } else {
-        Some(ElfTable { offset: ph_offset, num_ents: ph_num })
-    };
+    }
 
     let sh_offset = Offsets::read_offset::<B>(&data[Offsets::E_SHOFF_START ..
                                                     Offsets::E_SHOFF_END]);
@@ -954,13 +974,67 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
                                                    Offsets::E_SHENTSIZE_END]);
     let sh_num = Offsets::read_half::<B>(&data[Offsets::E_SHNUM_START ..
                                                Offsets::E_SHNUM_END]);
+    let strtab = Offsets::read_half::<B>(&data[Offsets::E_SHSTRTAB_START ..
+                                               Offsets::E_SHSTRTAB_END]);
 
-    let section_hdrs = if sh_entsize.into() as usize !=
-                          Offsets::SECTION_HDR_SIZE {
+    if sh_entsize.into() as usize != Offsets::SECTION_HDR_SIZE {
         return Err(ElfHdrDataError::BadSectionHdrEntSize(sh_entsize))
+    }
+
+    let ph_num: u32 = ph_num.into().into();
+    let sh_num: u32 = sh_num.into().into();
+    let strtab: u32 = strtab.into().into();
+    let sh_ext = sh_num == 0 && sh_offset != (0 as u8).into();
+
+    // Values that don't fit in the ELF header are stored in the
+    // first section header.
+    let (ph_num, sh_num, strtab) =
+        if ph_num == PN_XNUM || strtab == SHN_XINDEX || sh_ext {
+            let ent = match sh_offset.try_into() {
+                Ok(offset) if offset + Offsets::SECTION_HDR_SIZE <=
+                              data.len() =>
+                    &data[offset .. offset + Offsets::SECTION_HDR_SIZE],
+                _ => return Err(ElfHdrDataError::ExtHdrOutOfBounds(sh_offset))
+            };
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let link = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                    Offsets::SH_LINK_END]);
+            let info = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                    Offsets::SH_INFO_END]);
+            let ph_num = if ph_num == PN_XNUM {
+                info
+            } else {
+                ph_num.into()
+            };
+            let sh_num = if sh_ext {
+                match size.try_into().ok().and_then(
+                    |size: usize| Offsets::Word::try_from(size).ok()
+                ) {
+                    Some(size) => size,
+                    None => return Err(ElfHdrDataError::BadSectionHdrNum(size))
+                }
+            } else {
+                sh_num.into()
+            };
+            let strtab = if strtab == SHN_XINDEX {
+                link
+            } else {
+                strtab.into()
+            };
+
+            (ph_num, sh_num, strtab)
+        } else {
+            (ph_num.into(), sh_num.into(), strtab.into())
+        };
+
+    let prog_hdrs = if ph_offset == (0 as u8).into() &&
+                       ph_num == (0 as u8).into() {
+        None
     } else {
-        ElfTable { offset: sh_offset, num_ents: sh_num }
+        Some(ElfTable { offset: ph_offset, num_ents: ph_num })
     };
+    let section_hdrs = ElfTable { offset: sh_offset, num_ents: sh_num };
 
     let kind = Offsets::read_half::<B>(&data[Offsets::E_TYPE_START ..
                                              Offsets::E_TYPE_END]);
@@ -976,10 +1050,6 @@ fn project<'a, B, Offsets>(data: &'a [u8]) ->
                                                       Offsets::E_ENTRY_END]);
             let flags = Offsets::read_word::<B>(&data[Offsets::E_FLAGS_START ..
                                                       Offsets::E_FLAGS_END]);
-            let strtab = Offsets::read_half::<B>(
-                &data[Offsets::E_SHSTRTAB_START .. Offsets::E_SHSTRTAB_END]
-            );
-
             Ok(ElfHdrData { abi: abi, abi_version: abi_version, kind: kind,
                             arch: arch.into(), entry: entry, flags: flags,
                             byteorder: PhantomData, section_hdrs: section_hdrs,
@@ -1151,7 +1221,7 @@ fn check_known<'a, B, Offsets>(data: &'a [u8], _byteorder: PhantomData<B>,
 #[inline]
 fn create<'a, B, Offsets>(buf: &'a mut [u8],
                           hdr: ElfHdrData<B, Offsets, ElfTable<Offsets>,
-                                          ElfTable<Offsets>, Offsets::Half>,
+                                          ElfTable<Offsets>, Offsets::Word>,
                           _offsets: PhantomData<Offsets>) ->
     Result<(&'a mut [u8], &'a mut [u8]), ()>
     where Offsets: ElfHdrOffsets,
@@ -1204,15 +1274,27 @@ fn create<'a, B, Offsets>(buf: &'a mut [u8],
         Offsets::write_half::<B>(&mut buf[Offsets::E_SHENTSIZE_START ..
                                           Offsets::E_SHENTSIZE_END],
                                  Offsets::SECTION_HDR_SIZE_HALF);
+
+        // Values that don't fit are escaped, and must be stored in
+        // the first section header.
+        let sh_ents: u32 = sh_ents.into();
+        let sh_ents = if sh_ents >= SHN_LORESERVE { 0 } else { sh_ents };
+        let strtab: u32 = section_hdr_strtab.into();
+        let strtab = if strtab >= SHN_LORESERVE { SHN_XINDEX } else { strtab };
+
         Offsets::write_half::<B>(&mut buf[Offsets::E_SHNUM_START ..
                                           Offsets::E_SHNUM_END],
-                                 sh_ents);
+                                 (sh_ents as u16).into());
         Offsets::write_half::<B>(&mut buf[Offsets::E_SHSTRTAB_START ..
                                           Offsets::E_SHSTRTAB_END],
-                                 section_hdr_strtab);
+                                 (strtab as u16).into());
 
         match prog_hdrs {
             Some(ElfTable { offset: ph_offset, num_ents: ph_ents }) => {
+                let ph_ents: u32 = ph_ents.into();
+                let ph_ents = if ph_ents >= PN_XNUM { PN_XNUM }
+                              else { ph_ents };
+
                 Offsets::write_offset::<B>(&mut buf[Offsets::E_PHOFF_START ..
                                                     Offsets::E_PHOFF_END],
                                            ph_offset);
@@ -1221,7 +1303,7 @@ fn create<'a, B, Offsets>(buf: &'a mut [u8],
                                          Offsets::PROG_HDR_SIZE_HALF);
                 Offsets::write_half::<B>(&mut buf[Offsets::E_PHNUM_START ..
                                                   Offsets::E_PHNUM_END],
-                                         ph_ents);
+                                         (ph_ents as u16).into());
             },
             None => {
                 Offsets::write_offset::<B>(&mut buf[Offsets::E_PHOFF_START ..
@@ -1336,6 +1418,10 @@ impl<Class: ElfClass> Display for ElfHdrDataError<Class> {
                 write!(f, "bad section header entry size ({})", size),
             ElfHdrDataError::BadKind(kind) =>
                 write!(f, "bad ELF type ({})", kind),
+            ElfHdrDataError::ExtHdrOutOfBounds(offset) =>
+                write!(f, "extended header at {} is outside data", offset),
+            ElfHdrDataError::BadSectionHdrNum(num) =>
+                write!(f, "bad number of section headers ({})", num),
         }
     }
 }
@@ -1626,6 +1712,12 @@ impl<'a, B, Offsets> Elf<'a, B, Offsets>
     /// proper format for the ELF class and byte order.  Returns both
     /// the `Elf` and the remaining space if successful.
     ///
+    /// If the number of section headers, the section header string
+    /// table index, or the number of program headers is too large to
+    /// fit in the ELF header, it will be escaped.  The actual value
+    /// must then be stored in the first section header (see
+    /// [ExtNull](crate::section_hdr::SectionHdrData::ExtNull)).
+    ///
     /// # Errors
     ///
     /// The only error that can occur is if the ELF header doesn't fit
@@ -1738,19 +1830,26 @@ impl<'a, B, Offsets> Elf<'a, B, Offsets>
             Err(err) => Err(err)
         }
     }
+
+    /// Get the entire ELF data for this `Elf`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.data
+    }
 }
 
 
 impl<'a, B, Offsets> TryFrom<Elf<'a, B, Offsets>>
     for ElfHdrData<B, Offsets, ElfTable<Offsets>,
-                   ElfTable<Offsets>, Offsets::Half>
+                   ElfTable<Offsets>, Offsets::Word>
     where Offsets: ElfHdrOffsets,
           B: ElfByteOrder {
     type Error = ElfHdrDataError<Offsets>;
 
     fn try_from(elf: Elf<'a, B, Offsets>) ->
         Result<ElfHdrData<B, Offsets, ElfTable<Offsets>,
-                          ElfTable<Offsets>, Offsets::Half>,
+                          ElfTable<Offsets>, Offsets::Word>,
                Self::Error> {
         project::<B, Offsets>(elf.data)
     }
@@ -1758,14 +1857,14 @@ impl<'a, B, Offsets> TryFrom<Elf<'a, B, Offsets>>
 
 impl<'a, B, Offsets> TryFrom<ElfMut<'a, B, Offsets>>
     for ElfHdrData<B, Offsets, ElfTable<Offsets>,
-                   ElfTable<Offsets>, Offsets::Half>
+                   ElfTable<Offsets>, Offsets::Word>
     where Offsets: ElfHdrOffsets,
           B: ElfByteOrder {
     type Error = ElfHdrDataError<Offsets>;
 
     fn try_from(elf: ElfMut<'a, B, Offsets>) ->
         Result<ElfHdrData<B, Offsets, ElfTable<Offsets>,
-                          ElfTable<Offsets>, Offsets::Half>,
+                          ElfTable<Offsets>, Offsets::Word>,
                Self::Error> {
         project::<B, Offsets>(elf.data)
     }
@@ -2212,6 +2311,7 @@ impl Display for ElfArch {
             ElfArch::Lanai => write!(f, "Lanai processor"),
             ElfArch::BPF => write!(f, "Berkeley Packet Filter virtual machine"),
             ElfArch::SXAuroraVE => write!(f, "SX-Aurora VE"),
+            ElfArch::LoongArch => write!(f, "LoongArch"),
             ElfArch::Other(code) => write!(f, "Other ({:02x})", code),
         }
     }
@@ -2400,6 +2500,7 @@ impl From<ElfArch> for u16 {
             ElfArch::Lanai => 244,
             ElfArch::BPF => 247,
             ElfArch::SXAuroraVE => 251,
+            ElfArch::LoongArch => 258,
             ElfArch::Other(code) => code
         }
     }
@@ -2588,6 +2689,7 @@ impl From<u16> for ElfArch {
             244 => ElfArch::Lanai,
             247 => ElfArch::BPF,
             251 => ElfArch::SXAuroraVE,
+            258 => ElfArch::LoongArch,
             code => ElfArch::Other(code)
         }
     }
@@ -0,0 +1,341 @@
+//! ELF section group functionality.
+//!
+//! This module provides the [Group] type, which acts as a wrapper
+//! around ELF section group data (the `SHT_GROUP` sections, usually
+//! named `.group`).  A section group consists of a flags word,
+//! followed by the indexes of the sections that are members of the
+//! group.  The most common kind of group is a COMDAT group, which the
+//! linker will deduplicate across object files.
+//!
+//! # Examples
+//!
+//! A `Group` can be created from any slice containing binary data
+//! that contains a flags word and a whole number of section indexes:
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use elf_utils::group::Group;
+//!
+//! const GROUP: [u8; 12] = [
+//!     0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+//!     0x06, 0x00, 0x00, 0x00
+//! ];
+//!
+//! let group: Group<'_, LittleEndian> =
+//!     Group::try_from(&GROUP[0..]).expect("Expected success");
+//!
+//! assert!(group.comdat());
+//!
+//! let mut iter = group.iter();
+//!
+//! assert_eq!(iter.next(), Some(5));
+//! assert_eq!(iter.next(), Some(6));
+//! assert_eq!(iter.next(), None);
+//! ```
+
+use byteorder::ByteOrder;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::iter::Iterator;
+use core::marker::PhantomData;
+
+/// Flag indicating a COMDAT group.
+pub const GRP_COMDAT: u32 = 0x1;
+
+const GROUP_ENT_SIZE: usize = 4;
+
+const GROUP_FLAGS_START: usize = 0;
+const GROUP_FLAGS_SIZE: usize = GROUP_ENT_SIZE;
+const GROUP_FLAGS_END: usize = GROUP_FLAGS_START + GROUP_FLAGS_SIZE;
+
+const GROUP_MEMBERS_START: usize = GROUP_FLAGS_END;
+
+/// In-place read-only ELF section group.
+///
+/// A section group contains a flags word (indicating whether the
+/// group is a COMDAT group), followed by the section header indexes
+/// of each member of the group.  The signature symbol identifying the
+/// group is given by the section header for the group itself.
+///
+/// A `Group` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance.  New `Group`s can be
+/// created from an iterator over section indexes with
+/// [create](Group::create) or [create_split](Group::create_split).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::group::Group;
+///
+/// const GROUP: [u8; 8] = [
+///     0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00
+/// ];
+///
+/// let group: Group<'_, LittleEndian> =
+///     Group::try_from(&GROUP[0..]).expect("Expected success");
+///
+/// assert!(!group.comdat());
+/// assert_eq!(group.num_members(), 1);
+/// assert_eq!(group.idx(0), Some(7));
+/// assert_eq!(group.idx(1), None);
+/// ```
+#[derive(Copy, Clone)]
+pub struct Group<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8]
+}
+
+/// Iterator over the member section indexes of a [Group].
+#[derive(Copy, Clone)]
+pub struct GroupIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    idx: usize
+}
+
+/// Errors that can occur creating a [Group].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GroupError {
+    /// The data is too short to hold the flags word.
+    TooShort,
+    /// The data is not a multiple of the size of a section index.
+    BadSize(usize)
+}
+
+/// Calculate the number of bytes required to represent a group with
+/// all of the members in `members`.
+#[inline]
+pub fn required_bytes<I>(members: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<u32> {
+    GROUP_MEMBERS_START + (members.count() * GROUP_ENT_SIZE)
+}
+
+impl<'a, B> Group<'a, B>
+    where B: ByteOrder {
+    /// Attempt to create a `Group` in `buf` containing the section
+    /// indexes in `members`, with the COMDAT flag set if `comdat` is
+    /// `true`.
+    ///
+    /// This will write the flags word and the section indexes into
+    /// the buffer in the ELF format.  Returns both the `Group` and
+    /// the remaining space if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the group doesn't fit
+    /// into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::group::Group;
+    ///
+    /// const MEMBERS: [u32; 2] = [ 5, 6 ];
+    ///
+    /// let mut buf = [0; 16];
+    /// let (group, rest): (Group<'_, LittleEndian>, _) =
+    ///     Group::create_split(&mut buf[0..], true, MEMBERS.iter())
+    ///     .expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 4);
+    /// assert!(group.comdat());
+    ///
+    /// let mut iter = group.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(MEMBERS[0]));
+    /// assert_eq!(iter.next(), Some(MEMBERS[1]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn create_split<I>(buf: &'a mut [u8], comdat: bool, members: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<u32> {
+        if buf.len() < GROUP_MEMBERS_START {
+            return Err(())
+        }
+
+        let flags = if comdat { GRP_COMDAT } else { 0 };
+        let mut idx = GROUP_MEMBERS_START;
+
+        B::write_u32(&mut buf[GROUP_FLAGS_START .. GROUP_FLAGS_END], flags);
+
+        for member in members {
+            if buf.len() - idx < GROUP_ENT_SIZE {
+                return Err(())
+            }
+
+            B::write_u32(&mut buf[idx .. idx + GROUP_ENT_SIZE],
+                         *member.borrow());
+            idx += GROUP_ENT_SIZE;
+        }
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((Group { byteorder: PhantomData, data: data }, rest))
+    }
+
+    /// Attempt to create a `Group` in `buf` containing the section
+    /// indexes in `members` (see [create_split](Group::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the group doesn't fit
+    /// into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], comdat: bool, members: I) ->
+        Result<Self, ()>
+        where I: Iterator,
+              I::Item: Borrow<u32> {
+        match Self::create_split(buf, comdat, members) {
+            Ok((group, _)) => Ok(group),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the raw flags word for this `Group`.
+    #[inline]
+    pub fn flags(&self) -> u32 {
+        B::read_u32(&self.data[GROUP_FLAGS_START .. GROUP_FLAGS_END])
+    }
+
+    /// Check whether this `Group` is a COMDAT group.
+    #[inline]
+    pub fn comdat(&self) -> bool {
+        self.flags() & GRP_COMDAT != 0
+    }
+
+    /// Get the section index of the member at index `idx`.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<u32> {
+        let start = GROUP_MEMBERS_START + (idx * GROUP_ENT_SIZE);
+
+        if start < self.data.len() {
+            Some(B::read_u32(&self.data[start .. start + GROUP_ENT_SIZE]))
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of members in this `Group`.
+    #[inline]
+    pub fn num_members(&self) -> usize {
+        (self.data.len() - GROUP_MEMBERS_START) / GROUP_ENT_SIZE
+    }
+
+    /// Get an iterator over the member section indexes in this
+    /// `Group`.
+    #[inline]
+    pub fn iter(&self) -> GroupIter<'a, B> {
+        GroupIter { byteorder: PhantomData,
+                    data: &self.data[GROUP_MEMBERS_START ..], idx: 0 }
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for Group<'a, B>
+    where B: ByteOrder {
+    type Error = GroupError;
+
+    /// Create a `Group` from the data buffer.  This will check that
+    /// the data buffer holds a flags word and is a multiple of the
+    /// section index size.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<Group<'a, B>, Self::Error> {
+        if data.len() < GROUP_MEMBERS_START {
+            Err(GroupError::TooShort)
+        } else if data.len() % GROUP_ENT_SIZE != 0 {
+            Err(GroupError::BadSize(data.len()))
+        } else {
+            Ok(Group { byteorder: PhantomData, data: data })
+        }
+    }
+}
+
+impl<'a, B> TryFrom<&'a mut [u8]> for Group<'a, B>
+    where B: ByteOrder {
+    type Error = GroupError;
+
+    /// Create a `Group` from the data buffer.  This will check that
+    /// the data buffer holds a flags word and is a multiple of the
+    /// section index size.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<Group<'a, B>, Self::Error> {
+        Group::try_from(&*data)
+    }
+}
+
+impl<'a, B> Iterator for GroupIter<'a, B>
+    where B: ByteOrder {
+    type Item = u32;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.len();
+
+        (size, Some(size))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let start = (self.idx + n) * GROUP_ENT_SIZE;
+
+        if start < self.data.len() {
+            let value = B::read_u32(&self.data[start ..
+                                               start + GROUP_ENT_SIZE]);
+
+            self.idx += n + 1;
+
+            Some(value)
+        } else {
+            self.idx = self.data.len() / GROUP_ENT_SIZE;
+
+            None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for GroupIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> ExactSizeIterator for GroupIter<'a, B>
+    where B: ByteOrder {
+    #[inline]
+    fn len(&self) -> usize {
+        (self.data.len() / GROUP_ENT_SIZE) - self.idx
+    }
+}
+
+impl Display for GroupError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            GroupError::TooShort => write!(f, "section group too short"),
+            GroupError::BadSize(size) =>
+                write!(f, "bad section group size {}", size)
+        }
+    }
+}
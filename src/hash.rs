@@ -1,7 +1,9 @@
 //! ELF hash table section functionality.
 //!
 //! This module provides a [Hashtab] type which acts as a wrapper
-//! around ELF hash table section data.
+//! around ELF hash table section data, as well as a [GnuHashtab]
+//! type which does the same for GNU-style hash table section data.
+//! Both provide the same interface for looking up symbols.
 //!
 //! # Examples
 //!
@@ -81,6 +83,7 @@
 //! use elf_utils::symtab::SymBind;
 //! use elf_utils::symtab::SymData;
 //! use elf_utils::symtab::SymKind;
+//! use elf_utils::symtab::SymVisibility;
 //!
 //! const SYMTAB: [u8; 120] = [
 //!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -132,6 +135,7 @@
 //! assert_eq!(data, SymData { name: Some(Ok("finalizer")), value: 560,
 //!                            size: 90, kind: SymKind::Function,
 //!                            bind: SymBind::Local,
+//!                            visibility: SymVisibility::Default, other: 0,
 //!                            section: SymBase::Index(1) });
 //! ```
 //!
@@ -190,6 +194,7 @@ use crate::strtab::Strtab;
 use crate::strtab::WithStrtab;
 use crate::symtab::Sym;
 use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
 use crate::symtab::Symtab;
 use crate::symtab::SymOffsets;
 
@@ -204,6 +209,39 @@ const ELF_HASH_NCHAINS_END: usize = ELF_HASH_NCHAINS_START +
                                     ELF_HASH_NCHAINS_SIZE;
 const ELF_HASH_HASHES_START: usize = ELF_HASH_NCHAINS_END;
 
+const GNU_HASH_NBUCKETS_START: usize = 0;
+const GNU_HASH_NBUCKETS_SIZE: usize = ELF_HASH_WORD_SIZE;
+const GNU_HASH_NBUCKETS_END: usize = GNU_HASH_NBUCKETS_START +
+                                     GNU_HASH_NBUCKETS_SIZE;
+const GNU_HASH_SYMOFFSET_START: usize = GNU_HASH_NBUCKETS_END;
+const GNU_HASH_SYMOFFSET_SIZE: usize = ELF_HASH_WORD_SIZE;
+const GNU_HASH_SYMOFFSET_END: usize = GNU_HASH_SYMOFFSET_START +
+                                      GNU_HASH_SYMOFFSET_SIZE;
+const GNU_HASH_BLOOM_SIZE_START: usize = GNU_HASH_SYMOFFSET_END;
+const GNU_HASH_BLOOM_SIZE_SIZE: usize = ELF_HASH_WORD_SIZE;
+const GNU_HASH_BLOOM_SIZE_END: usize = GNU_HASH_BLOOM_SIZE_START +
+                                       GNU_HASH_BLOOM_SIZE_SIZE;
+const GNU_HASH_BLOOM_SHIFT_START: usize = GNU_HASH_BLOOM_SIZE_END;
+const GNU_HASH_BLOOM_SHIFT_SIZE: usize = ELF_HASH_WORD_SIZE;
+const GNU_HASH_BLOOM_SHIFT_END: usize = GNU_HASH_BLOOM_SHIFT_START +
+                                        GNU_HASH_BLOOM_SHIFT_SIZE;
+const GNU_HASH_BLOOM_START: usize = GNU_HASH_BLOOM_SHIFT_END;
+
+// Shift used for the second bloom filter bit when creating tables.
+// This is the same value used by most linkers.
+const GNU_HASH_DEFAULT_SHIFT: u32 = 26;
+
+/// Compute the hash used by GNU-style hash tables for `name`.
+fn gnu_hash(name: &[u8]) -> u32 {
+    let mut h: u32 = 5381;
+
+    for byte in name {
+        h = h.wrapping_mul(33).wrapping_add(*byte as u32);
+    }
+
+    h
+}
+
 /// Trait for datatypes that can be hashed according to the ELF standard.
 ///
 /// The ELF standard defines its own hash function, which is likely
@@ -216,6 +254,26 @@ pub trait ElfName {
     /// Compute the hash according to the ELF standard.
     fn hash_name(&self) -> u32;
 
+    /// Get the bytes making up this name, if they are available.
+    ///
+    /// The default implementation returns `None`.
+    #[inline]
+    fn name_bytes(&self) -> Option<&[u8]> {
+        None
+    }
+
+    /// Compute the hash used by GNU-style hash tables.
+    ///
+    /// The default implementation hashes the bytes obtained from
+    /// [name_bytes](ElfName::name_bytes).  If this returns `None`,
+    /// so does this, and lookups in GNU-style hash tables will fall
+    /// back to searching the symbol table using
+    /// [cmp_name](ElfName::cmp_name).
+    #[inline]
+    fn gnu_hash_name(&self) -> Option<u32> {
+        self.name_bytes().map(gnu_hash)
+    }
+
     /// Compare this name against a `str` or a `[u8]` that failed
     /// UTF-8 verification.
     fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool;
@@ -257,6 +315,7 @@ pub trait ElfName {
 /// use elf_utils::symtab::SymBind;
 /// use elf_utils::symtab::SymData;
 /// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
 ///
 /// const SYMTAB: [u8; 120] = [
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -303,18 +362,21 @@ pub trait ElfName {
 ///                .with_strtab(strtab).unwrap(),
 ///            SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
 ///                      kind: SymKind::File, bind: SymBind::Local,
+///                      visibility: SymVisibility::Default, other: 0,
 ///                      section: SymBase::Absolute });
 /// assert_eq!(hash.lookup("finalizer").unwrap().unwrap()
 ///                .with_strtab(strtab).unwrap(),
-///            SymData { name: Some(Ok("finalizer")), value: 560,
-///                            size: 90, kind: SymKind::Function,
-///                            bind: SymBind::Local,
-///                            section: SymBase::Index(1) });
+///            SymData { name: Some(Ok("finalizer")), value: 560, size: 90,
+///                      kind: SymKind::Function, bind: SymBind::Local,
+///                      visibility: SymVisibility::Default, other: 0,
+///                      section: SymBase::Index(1) });
 /// assert_eq!(hash.lookup("handle_static_init").unwrap().unwrap()
 ///                .with_strtab(strtab).unwrap(),
 ///            SymData { name: Some(Ok("handle_static_init")), value: 272,
 ///                      size: 282, kind: SymKind::Function,
-///                      bind: SymBind::Local, section: SymBase::Index(1) });
+///                      bind: SymBind::Local,
+///                      visibility: SymVisibility::Default, other: 0,
+///                      section: SymBase::Index(1) });
 /// ```
 #[derive(Copy, Clone)]
 pub struct Hashtab<'a, B: ByteOrder, Offsets: SymOffsets> {
@@ -348,6 +410,156 @@ pub enum HashtabError {
     BadHashes
 }
 
+/// In-place read-only GNU-style ELF symbol hash table.
+///
+/// A GNU-style hash table serves the same purpose as a [Hashtab],
+/// but adds a bloom filter to quickly reject names that aren't
+/// present, and stores its chains as an array of hash values
+/// parallel to the symbol table.  Most modern binaries provide only
+/// this kind of hash table, in a `.gnu.hash` section.
+///
+/// Only the symbols at or after the table's
+/// [symoffset](GnuHashtab::symoffset) are present in the table;
+/// the symbols before it (typically undefined symbols) cannot be
+/// looked up.  The hashed symbols must also be grouped by their
+/// bucket, which can be arranged using [sort_gnu_syms].
+///
+/// A `GnuHashtab` can be created from a slice containing the raw ELF
+/// data and the associated `Symtab` and `Strtab` using the
+/// [from_slice](GnuHashtab::from_slice) function.
+///
+/// New `GnuHashtab`s can be created from a `Symtab` and associated
+/// `Strtab` using the [create](GnuHashtab::create) and
+/// [create_split](GnuHashtab::create_split) functions.
+///
+/// # Examples
+///
+/// ```
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::Elf64;
+/// use elf_utils::hash::GnuHashtab;
+/// use elf_utils::strtab::Strtab;
+/// use elf_utils::strtab::WithStrtab;
+/// use elf_utils::symtab::Symtab;
+/// use elf_utils::symtab::SymBase;
+/// use elf_utils::symtab::SymBind;
+/// use elf_utils::symtab::SymData;
+/// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
+///
+/// const SYMTAB: [u8; 120] = [
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0xf1, 0xff,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x0a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+///     0x30, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x14, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+///     0x10, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x1a, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+/// ];
+///
+/// const STRTAB: [u8; 39] = [
+///     0x00, 0x63, 0x72, 0x74, 0x31, 0x5f, 0x63, 0x2e,
+///     0x63, 0x00, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69,
+///     0x7a, 0x65, 0x72, 0x00, 0x68, 0x61, 0x6e, 0x64,
+///     0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x69,
+///     0x63, 0x5f, 0x69, 0x6e, 0x69, 0x74, 0x00
+/// ];
+///
+/// const GNU_HASHTAB: [u8; 48] = [
+///     0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+///     0x01, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+///     0x21, 0x10, 0x05, 0x00, 0x00, 0x0e, 0x00, 0x00,
+///     0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+///     0x13, 0x88, 0xda, 0x32, 0xe8, 0x5f, 0x5a, 0x40,
+///     0x6a, 0x56, 0xc6, 0xab, 0x05, 0x15, 0x00, 0x00
+/// ];
+///
+/// let strtab: Strtab<'_> =
+///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+/// let symtab: Symtab<'_, LittleEndian, Elf64> =
+///     Symtab::try_from(&SYMTAB[0..]).unwrap();
+/// let hash = GnuHashtab::from_slice(&GNU_HASHTAB[0..], strtab,
+///                                   symtab).unwrap();
+///
+/// assert_eq!(hash.lookup("crt1_c.c").unwrap().unwrap()
+///                .with_strtab(strtab).unwrap(),
+///            SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
+///                      kind: SymKind::File, bind: SymBind::Local,
+///                      visibility: SymVisibility::Default, other: 0,
+///                      section: SymBase::Absolute });
+/// assert_eq!(hash.lookup("finalizer").unwrap().unwrap()
+///                .with_strtab(strtab).unwrap(),
+///            SymData { name: Some(Ok("finalizer")), value: 560, size: 90,
+///                      kind: SymKind::Function, bind: SymBind::Local,
+///                      visibility: SymVisibility::Default, other: 0,
+///                      section: SymBase::Index(1) });
+/// assert_eq!(hash.lookup("handle_static_init").unwrap().unwrap()
+///                .with_strtab(strtab).unwrap(),
+///            SymData { name: Some(Ok("handle_static_init")), value: 272,
+///                      size: 282, kind: SymKind::Function,
+///                      bind: SymBind::Local,
+///                      visibility: SymVisibility::Default, other: 0,
+///                      section: SymBase::Index(1) });
+/// assert!(hash.lookup("not present").unwrap().is_none());
+/// ```
+#[derive(Copy, Clone)]
+pub struct GnuHashtab<'a, B: ByteOrder, Offsets: SymOffsets> {
+    symtab: Symtab<'a, B, Offsets>,
+    strtab: Strtab<'a>,
+    symoffset: usize,
+    shift: u32,
+    bloom: &'a [u8],
+    buckets: &'a [u8],
+    chains: &'a [u8]
+}
+
+/// In-place mutable GNU-style ELF symbol hash table.
+///
+/// This is the mutable counterpart of [GnuHashtab], and is created in
+/// the same way, using [from_slice](GnuHashtabMut::from_slice),
+/// [create](GnuHashtabMut::create), or
+/// [create_split](GnuHashtabMut::create_split).  As with
+/// `GnuHashtab`, only the symbols at or after the table's
+/// [symoffset](GnuHashtabMut::symoffset) can be looked up.
+pub struct GnuHashtabMut<'a, B: ByteOrder, Offsets: SymOffsets> {
+    symtab: Symtab<'a, B, Offsets>,
+    strtab: Strtab<'a>,
+    symoffset: usize,
+    shift: u32,
+    bloom: &'a mut [u8],
+    buckets: &'a mut [u8],
+    chains: &'a mut [u8]
+}
+
+/// Errors that can occur when creating `GnuHashtab`s from raw data.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GnuHashtabError {
+    /// The buffer was too short.
+    TooShort,
+    /// The symbol offset is past the end of the symbol table.
+    BadSymOffset {
+        /// Number of symbols in the symbol table.
+        nsyms: u32,
+        /// Symbol offset in the hash data.
+        symoffset: u32
+    },
+    /// Bad number of hash buckets (currently, anything other than 0
+    /// is supported)
+    BadBuckets,
+    /// Bad bloom filter parameters (the number of words must be a
+    /// power of two, and the shift must be less than 32)
+    BadBloom
+}
+
 /// Create an empty hash table from the sizes of the two tables.
 #[inline]
 fn create_split_empty<'a, B>(buf: &'a mut [u8], nhashes: usize,
@@ -616,6 +828,7 @@ impl<'a, B, Offsets> Hashtab<'a, B, Offsets>
     /// use elf_utils::symtab::SymBind;
     /// use elf_utils::symtab::SymData;
     /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
     ///
     /// const SYMTAB: [u8; 120] = [
     ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -657,18 +870,20 @@ impl<'a, B, Offsets> Hashtab<'a, B, Offsets>
     ///                .with_strtab(strtab).unwrap(),
     ///            SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
     ///                      kind: SymKind::File, bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
     ///                      section: SymBase::Absolute });
     /// assert_eq!(hash.lookup("finalizer").unwrap().unwrap()
     ///                .with_strtab(strtab).unwrap(),
-    ///            SymData { name: Some(Ok("finalizer")), value: 560,
-    ///                            size: 90, kind: SymKind::Function,
-    ///                            bind: SymBind::Local,
-    ///                            section: SymBase::Index(1) });
+    ///            SymData { name: Some(Ok("finalizer")), value: 560, size: 90,
+    ///                      kind: SymKind::Function, bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
+    ///                      section: SymBase::Index(1) });
     /// assert_eq!(hash.lookup("handle_static_init").unwrap().unwrap()
     ///                .with_strtab(strtab).unwrap(),
     ///            SymData { name: Some(Ok("handle_static_init")), value: 272,
     ///                      size: 282, kind: SymKind::Function,
     ///                      bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
     ///                      section: SymBase::Index(1) });
     /// assert!(hash.lookup("not present").unwrap().is_none());
     /// ```
@@ -710,6 +925,7 @@ impl<'a, B, Offsets> Hashtab<'a, B, Offsets>
     /// use elf_utils::symtab::SymBind;
     /// use elf_utils::symtab::SymData;
     /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
     ///
     /// const SYMTAB: [u8; 120] = [
     ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -749,18 +965,20 @@ impl<'a, B, Offsets> Hashtab<'a, B, Offsets>
     ///                .with_strtab(strtab).unwrap(),
     ///            SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
     ///                      kind: SymKind::File, bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
     ///                      section: SymBase::Absolute });
     /// assert_eq!(hash.lookup("finalizer").unwrap().unwrap()
     ///                .with_strtab(strtab).unwrap(),
-    ///            SymData { name: Some(Ok("finalizer")), value: 560,
-    ///                            size: 90, kind: SymKind::Function,
-    ///                            bind: SymBind::Local,
-    ///                            section: SymBase::Index(1) });
+    ///            SymData { name: Some(Ok("finalizer")), value: 560, size: 90,
+    ///                      kind: SymKind::Function, bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
+    ///                      section: SymBase::Index(1) });
     /// assert_eq!(hash.lookup("handle_static_init").unwrap().unwrap()
     ///                .with_strtab(strtab).unwrap(),
     ///            SymData { name: Some(Ok("handle_static_init")), value: 272,
     ///                      size: 282, kind: SymKind::Function,
     ///                      bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
     ///                      section: SymBase::Index(1) });
     /// ```
     #[inline]
@@ -863,81 +1081,921 @@ impl<'a, B, Offsets> HashtabMut<'a, B, Offsets>
     }
 }
 
-impl ElfName for [u8] {
-    fn hash_name(&self) -> u32 {
-        let mut h = 0;
+/// Get the number of bloom filter words to use when creating a
+/// GNU-style hash table with `nhashed` symbols.
+#[inline]
+fn gnu_bloom_words<Offsets>(nhashed: usize) -> usize
+    where Offsets: SymOffsets {
+    let bits = Offsets::ADDR_SIZE * 8;
 
-        for byte in self {
-            h = (h << 4) + (*byte as u32);
+    // Allow about 12 bits per symbol, which is what most linkers do.
+    (nhashed * 12 / bits + 1).next_power_of_two()
+}
 
-            let g = h & 0xf0000000;
+/// Calculate the number of bytes required to create a GNU-style hash
+/// table for `nsyms` symbols, with hashing beginning at `symoffset`
+/// and with `nbuckets` buckets.
+///
+/// This is the size of the table that will be produced by
+/// [GnuHashtab::create].
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::Elf32;
+/// use elf_utils::Elf64;
+/// use elf_utils::hash;
+///
+/// assert_eq!(hash::gnu_required_bytes::<Elf64>(5, 1, 2), 48);
+/// assert_eq!(hash::gnu_required_bytes::<Elf32>(27, 1, 6), 208);
+/// assert_eq!(hash::gnu_required_bytes::<Elf64>(26, 1, 6), 204);
+/// ```
+#[inline]
+pub fn gnu_required_bytes<Offsets>(nsyms: usize, symoffset: usize,
+                                   nbuckets: usize) -> usize
+    where Offsets: SymOffsets {
+    let nhashed = nsyms.saturating_sub(symoffset);
+    let nbloom = gnu_bloom_words::<Offsets>(nhashed);
 
-            if g != 0 {
-                h ^= g >> 24;
-                h &= !g;
+    GNU_HASH_BLOOM_START + (nbloom * Offsets::ADDR_SIZE) +
+    (ELF_HASH_WORD_SIZE * (nbuckets + nhashed))
+}
+
+/// Sort `syms` so that a GNU-style hash table with `nbuckets`
+/// buckets can be created from them.
+///
+/// GNU-style hash tables require all hashed symbols with the same
+/// bucket to be adjacent in the symbol table.  This sorts all the
+/// symbols at or after `symoffset` by their bucket, leaving the ones
+/// before it in place.  Names are looked up in `strtab`; symbols
+/// without a name are treated as having an empty name.
+///
+/// Note that this changes symbol indexes, so it must be done before
+/// anything refers to the symbols by index.
+///
+/// # Errors
+///
+/// An error occurs if `symoffset` is past the end of `syms`,
+/// `nbuckets` is zero, or a symbol name isn't present in `strtab`.
+/// In this case, `syms` is left unmodified.
+///
+/// # Examples
+///
+/// ```
+/// use core::convert::TryFrom;
+/// use elf_utils::Elf64;
+/// use elf_utils::hash;
+/// use elf_utils::strtab::Strtab;
+/// use elf_utils::symtab::SymBase;
+/// use elf_utils::symtab::SymBind;
+/// use elf_utils::symtab::SymData;
+/// use elf_utils::symtab::SymDataRaw;
+/// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
+///
+/// const STRTAB: [u8; 39] = [
+///     0x00, 0x63, 0x72, 0x74, 0x31, 0x5f, 0x63, 0x2e,
+///     0x63, 0x00, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69,
+///     0x7a, 0x65, 0x72, 0x00, 0x68, 0x61, 0x6e, 0x64,
+///     0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x69,
+///     0x63, 0x5f, 0x69, 0x6e, 0x69, 0x74, 0x00
+/// ];
+///
+/// let strtab: Strtab<'_> =
+///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+/// let mut syms: [SymDataRaw<Elf64>; 4] = [
+///     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
+///               bind: SymBind::Local, visibility: SymVisibility::Default,
+///               other: 0, section: SymBase::Undef },
+///     SymData { name: Some(10), value: 0x230, size: 0x5a,
+///               kind: SymKind::Function, bind: SymBind::Global,
+///               visibility: SymVisibility::Default, other: 0,
+///               section: SymBase::Index(1) },
+///     SymData { name: Some(1), value: 0x100, size: 0x10,
+///               kind: SymKind::Object, bind: SymBind::Global,
+///               visibility: SymVisibility::Default, other: 0,
+///               section: SymBase::Index(2) },
+///     SymData { name: Some(20), value: 0x110, size: 0x11a,
+///               kind: SymKind::Function, bind: SymBind::Global,
+///               visibility: SymVisibility::Default, other: 0,
+///               section: SymBase::Index(1) }
+/// ];
+///
+/// assert!(hash::sort_gnu_syms(&mut syms[0..], strtab, 1, 2).is_ok());
+/// assert_eq!(syms[0].name, None);
+/// assert_eq!(syms[1].name, Some(1));
+/// ```
+pub fn sort_gnu_syms<'a, Offsets>(syms: &mut [SymDataRaw<Offsets>],
+                                  strtab: Strtab<'a>, symoffset: usize,
+                                  nbuckets: usize) -> Result<(), ()>
+    where Offsets: SymOffsets {
+    if symoffset <= syms.len() && nbuckets != 0 {
+        let hashed = &mut syms[symoffset..];
+
+        // Check all the names first, so that we don't fail partway
+        // through sorting.
+        for sym in hashed.iter() {
+            if sym.clone().with_strtab(strtab).is_err() {
+                return Err(())
             }
         }
 
-        h
+        hashed.sort_unstable_by_key(|sym| {
+            let hash = match sym.clone().with_strtab(strtab) {
+                Ok(SymData { name: Some(Ok(name)), .. }) =>
+                    gnu_hash(name.as_bytes()),
+                Ok(SymData { name: Some(Err(name)), .. }) => gnu_hash(name),
+                _ => gnu_hash(&[])
+            };
+
+            hash as usize % nbuckets
+        });
+
+        Ok(())
+    } else {
+        Err(())
     }
+}
 
-    #[inline]
-    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
-        match other {
-            Ok(str) => str.as_bytes() == self,
-            Err(bytes) => *bytes == self
+/// Check the header of a GNU-style hash table, and get the symbol
+/// offset, the bloom filter shift, and the sizes of the bloom filter,
+/// the buckets, and the chains.
+fn gnu_hash_hdr<B, Offsets>(data: &[u8], nsyms: usize) ->
+    Result<(usize, u32, usize, usize, usize), GnuHashtabError>
+    where B: ByteOrder,
+          Offsets: SymOffsets {
+    if data.len() >= GNU_HASH_BLOOM_START {
+        let nbuckets = B::read_u32(&data[GNU_HASH_NBUCKETS_START ..
+                                         GNU_HASH_NBUCKETS_END]) as usize;
+        let symoffset = B::read_u32(&data[GNU_HASH_SYMOFFSET_START ..
+                                          GNU_HASH_SYMOFFSET_END]) as usize;
+        let nbloom = B::read_u32(&data[GNU_HASH_BLOOM_SIZE_START ..
+                                       GNU_HASH_BLOOM_SIZE_END]) as usize;
+        let shift = B::read_u32(&data[GNU_HASH_BLOOM_SHIFT_START ..
+                                      GNU_HASH_BLOOM_SHIFT_END]);
+
+        if nbuckets == 0 {
+            Err(GnuHashtabError::BadBuckets)
+        } else if !nbloom.is_power_of_two() || shift >= 32 {
+            Err(GnuHashtabError::BadBloom)
+        } else if symoffset > nsyms {
+            Err(GnuHashtabError::BadSymOffset {
+                nsyms: nsyms as u32,
+                symoffset: symoffset as u32
+            })
+        } else {
+            let bloom_size = nbloom * Offsets::ADDR_SIZE;
+            let buckets_size = nbuckets * ELF_HASH_WORD_SIZE;
+            let chains_size = (nsyms - symoffset) * ELF_HASH_WORD_SIZE;
+            let size = GNU_HASH_BLOOM_START + bloom_size + buckets_size +
+                       chains_size;
+
+            if data.len() < size {
+                Err(GnuHashtabError::TooShort)
+            } else {
+                Ok((symoffset, shift, bloom_size, buckets_size, chains_size))
+            }
         }
+    } else {
+        Err(GnuHashtabError::TooShort)
     }
 }
 
-impl ElfName for str {
-    #[inline]
-    fn hash_name(&self) -> u32 {
-        self.as_bytes().hash_name()
+fn create_split_gnu_filled<'a, B, Offsets>(buf: &'a mut [u8],
+                                           strtab: Strtab<'a>,
+                                           symtab: Symtab<'a, B, Offsets>,
+                                           symoffset: usize,
+                                           nbuckets: usize) ->
+    Result<(&'a mut [u8], &'a mut [u8], &'a mut [u8], &'a mut [u8]), ()>
+    where Sym<'a, B, Offsets>: TryInto<SymData<Offsets::Word, Offsets::Half,
+                                               Offsets>>,
+          B: 'a + ByteOrder,
+          Offsets: 'a + SymOffsets {
+    let nsyms = symtab.num_syms();
+
+    // The null symbol can't be hashed, as a zero bucket indicates an
+    // empty bucket.
+    if symoffset == 0 || symoffset > nsyms || nbuckets == 0 {
+        return Err(())
     }
 
-    #[inline]
-    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
-        match other {
-            Ok(str) => *str == self,
-            Err(_) => false
-        }
+    let nhashed = nsyms - symoffset;
+    let nbloom = gnu_bloom_words::<Offsets>(nhashed);
+    let size = gnu_required_bytes::<Offsets>(nsyms, symoffset, nbuckets);
+
+    if buf.len() < size {
+        return Err(())
     }
-}
 
-impl<'a> ElfName for &'a str {
-    #[inline]
-    fn hash_name(&self) -> u32 {
-        self.as_bytes().hash_name()
+    let (data, rest) = buf.split_at_mut(size);
+
+    for byte in data.iter_mut() {
+        *byte = 0;
     }
 
-    #[inline]
-    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
-        match other {
-            Ok(str) => *str == *self,
-            Err(_) => false
+    B::write_u32(&mut data[GNU_HASH_NBUCKETS_START .. GNU_HASH_NBUCKETS_END],
+                 nbuckets as u32);
+    B::write_u32(&mut data[GNU_HASH_SYMOFFSET_START ..
+                           GNU_HASH_SYMOFFSET_END], symoffset as u32);
+    B::write_u32(&mut data[GNU_HASH_BLOOM_SIZE_START ..
+                           GNU_HASH_BLOOM_SIZE_END], nbloom as u32);
+    B::write_u32(&mut data[GNU_HASH_BLOOM_SHIFT_START ..
+                           GNU_HASH_BLOOM_SHIFT_END], GNU_HASH_DEFAULT_SHIFT);
+
+    let tabs = &mut data[GNU_HASH_BLOOM_START..];
+    let (bloom, tabs) = tabs.split_at_mut(nbloom * Offsets::ADDR_SIZE);
+    let (buckets, chains) = tabs.split_at_mut(nbuckets * ELF_HASH_WORD_SIZE);
+    let bits = Offsets::ADDR_SIZE * 8;
+    let mut prev = None;
+
+    for i in symoffset .. nsyms {
+        let hash = match symtab.idx(i) {
+            Some(sym) => match sym.try_into() {
+                Ok(_) => match sym.with_strtab(strtab) {
+                    Ok(SymData { name: Some(Ok(name)), .. }) => {
+                        gnu_hash(name.as_bytes())
+                    },
+                    Ok(SymData { name: Some(Err(name)), .. }) => {
+                        gnu_hash(name)
+                    },
+                    // This symbol has no name; hash it as an empty name.
+                    Ok(_) => gnu_hash(&[]),
+                    Err(_) => return Err(())
+                },
+                Err(_) => return Err(())
+            },
+            None => return Err(())
+        };
+
+        // Set the two bits in the bloom filter.
+        let word = ((hash as usize) / bits) % nbloom;
+        let offset = word * Offsets::ADDR_SIZE;
+        let end = offset + Offsets::ADDR_SIZE;
+        let mask = (1 << ((hash as usize) % bits)) |
+                   (1 << ((hash >> GNU_HASH_DEFAULT_SHIFT) as usize % bits));
+        let old = B::read_uint(&bloom[offset .. end], Offsets::ADDR_SIZE);
+
+        B::write_uint(&mut bloom[offset .. end], old | mask,
+                      Offsets::ADDR_SIZE);
+
+        let bucket = (hash as usize) % nbuckets;
+        let chainidx = i - symoffset;
+
+        if prev != Some(bucket) {
+            let offset = bucket * ELF_HASH_WORD_SIZE;
+            let end = offset + ELF_HASH_WORD_SIZE;
+
+            // If this bucket was already started, then the symbols
+            // aren't grouped by bucket.
+            if B::read_u32(&buckets[offset .. end]) != 0 {
+                return Err(())
+            }
+
+            B::write_u32(&mut buckets[offset .. end], i as u32);
+
+            // Mark the end of the previous chain.
+            if chainidx != 0 {
+                let offset = (chainidx - 1) * ELF_HASH_WORD_SIZE;
+                let end = offset + ELF_HASH_WORD_SIZE;
+                let old = B::read_u32(&chains[offset .. end]);
+
+                B::write_u32(&mut chains[offset .. end], old | 1);
+            }
         }
+
+        let offset = chainidx * ELF_HASH_WORD_SIZE;
+        let end = offset + ELF_HASH_WORD_SIZE;
+
+        B::write_u32(&mut chains[offset .. end], hash & !1);
+        prev = Some(bucket);
     }
-}
-/*
-impl ElfName for String {
-    #[inline]
-    fn hash_name(&self) -> u32 {
-        self.as_str().hash_name()
+
+    // Mark the end of the last chain.
+    if nhashed != 0 {
+        let offset = (nhashed - 1) * ELF_HASH_WORD_SIZE;
+        let end = offset + ELF_HASH_WORD_SIZE;
+        let old = B::read_u32(&chains[offset .. end]);
+
+        B::write_u32(&mut chains[offset .. end], old | 1);
     }
 
-    #[inline]
-    fn cmp_name(&self, other: Result<&str, &[u8]>) -> bool {
-        self.as_str().cmp_name(other)
+    Ok((bloom, buckets, chains, rest))
+}
+
+/// Search the symbols covered by a GNU-style hash table for `name`
+/// without using the hash table, giving `Ok(None)` if no such symbol
+/// is found.
+fn scan_gnu_syms<'a, B, Offsets, Name>(symtab: Symtab<'a, B, Offsets>,
+                                       strtab: Strtab<'a>, symoffset: usize,
+                                       name: Name) ->
+    Result<Option<Sym<'a, B, Offsets>>, ()>
+    where Sym<'a, B, Offsets>: TryInto<SymData<Offsets::Word, Offsets::Half,
+                                               Offsets>>,
+          B: 'a + ByteOrder,
+          Offsets: 'a + SymOffsets,
+          Name: ElfName {
+    for symidx in symoffset .. symtab.num_syms() {
+        let sym = match symtab.idx(symidx) {
+            Some(sym) => sym,
+            None => return Err(())
+        };
+        let raw: SymData<Offsets::Word, Offsets::Half, Offsets> =
+            match sym.try_into() {
+                Ok(raw) => raw,
+                Err(_) => return Err(())
+            };
+
+        match raw.with_strtab(strtab) {
+            Ok(SymData { name: Some(symname), .. })
+                if name.cmp_name(&symname) => return Ok(Some(sym)),
+            Ok(_) => {},
+            Err(_) => return Err(())
+        }
     }
+
+    Ok(None)
 }
-*/
-impl<'a> ElfName for Result<&'a str, &'a [u8]> {
-    #[inline]
-    fn hash_name(&self) -> u32 {
-        match self {
-            Ok(str) => str.hash_name(),
-            Err(str) => str.hash_name()
+
+/// Lookup the index of the symbol with `name` in a GNU-style hash
+/// table, giving `Ok(None)` if no such symbol is found.
+fn lookup_gnu_sym<'a, B, Offsets, Name>(hashtab: &GnuHashtab<'a, B, Offsets>,
+                                        name: Name) ->
+    Result<Option<Sym<'a, B, Offsets>>, ()>
+    where Sym<'a, B, Offsets>: TryInto<SymData<Offsets::Word, Offsets::Half,
+                                               Offsets>>,
+          B: 'a + ByteOrder,
+          Offsets: 'a + SymOffsets,
+          Name: ElfName {
+    let GnuHashtab { symtab, strtab, symoffset, shift,
+                     bloom, buckets, chains } = *hashtab;
+    let bits = Offsets::ADDR_SIZE * 8;
+    let nbloom = bloom.len() / Offsets::ADDR_SIZE;
+    let nbuckets = buckets.len() / ELF_HASH_WORD_SIZE;
+    let nchains = chains.len() / ELF_HASH_WORD_SIZE;
+    let hash = match name.gnu_hash_name() {
+        Some(hash) => hash,
+        // The hash can't be computed, so search every hashed symbol.
+        None => return scan_gnu_syms(symtab, strtab, symoffset, name)
+    };
+
+    // Check the bloom filter first; if either bit is clear, the
+    // name definitely isn't present.
+    let word = ((hash as usize) / bits) % nbloom;
+    let offset = word * Offsets::ADDR_SIZE;
+    let end = offset + Offsets::ADDR_SIZE;
+    let mask = (1 << ((hash as usize) % bits)) |
+               (1 << ((hash >> shift) as usize % bits));
+
+    if B::read_uint(&bloom[offset .. end], Offsets::ADDR_SIZE) & mask != mask {
+        return Ok(None)
+    }
+
+    let bucket = (hash as usize) % nbuckets;
+    let offset = bucket * ELF_HASH_WORD_SIZE;
+    let end = offset + ELF_HASH_WORD_SIZE;
+    let mut symidx = B::read_u32(&buckets[offset .. end]) as usize;
+
+    // A zero index means the bucket is empty.
+    if symidx == 0 {
+        return Ok(None)
+    }
+
+    // As with the SysV hash table, bound the number of iterations to
+    // the size of the chain array to defeat pathological tables.
+    for _ in 0 .. nchains {
+        if symidx >= symoffset && symidx - symoffset < nchains {
+            let offset = (symidx - symoffset) * ELF_HASH_WORD_SIZE;
+            let end = offset + ELF_HASH_WORD_SIZE;
+            let chainhash = B::read_u32(&chains[offset .. end]);
+
+            // Only check the name if the hashes match (ignoring the
+            // low bit, which marks the end of the chain).
+            if (hash | 1) == (chainhash | 1) {
+                let sym = match symtab.idx(symidx) {
+                    Some(sym) => sym,
+                    None => return Err(())
+                };
+                let raw: SymData<Offsets::Word, Offsets::Half, Offsets> =
+                    match sym.try_into() {
+                        Ok(raw) => raw,
+                        Err(_) => return Err(())
+                    };
+                let symdata = match raw.with_strtab(strtab) {
+                    Ok(symdata) => symdata,
+                    Err(_) => return Err(())
+                };
+
+                match symdata.name {
+                    Some(symname) if name.cmp_name(&symname) => {
+                        return Ok(Some(sym))
+                    },
+                    _ => {}
+                }
+            }
+
+            if chainhash & 1 != 0 {
+                // We're at the end of the chain.
+                break
+            }
+
+            symidx += 1;
+        } else {
+            return Err(())
+        }
+    }
+
+    Ok(None)
+}
+
+impl<'a, B, Offsets> GnuHashtab<'a, B, Offsets>
+    where Sym<'a, B, Offsets>: TryInto<SymData<Offsets::Word, Offsets::Half,
+                                               Offsets>> + WithStrtab<'a>,
+          B: 'a + ByteOrder,
+          Offsets: 'a + SymOffsets {
+    /// Create a `GnuHashtab` from a slice containing data, along with
+    /// the associated `Strtab` and `Symtab`.
+    ///
+    /// # Errors
+    ///
+    /// Any error in [GnuHashtabError] can occur if bad hash table
+    /// data is provided.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryFrom;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::hash::GnuHashtab;
+    /// use elf_utils::hash::GnuHashtabError;
+    /// use elf_utils::strtab::Strtab;
+    /// use elf_utils::symtab::Symtab;
+    ///
+    /// const SYMTAB: [u8; 120] = [
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0xf1, 0xff,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x0a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+    ///     0x30, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x14, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+    ///     0x10, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x1a, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    /// ];
+    ///
+    /// const STRTAB: [u8; 39] = [
+    ///     0x00, 0x63, 0x72, 0x74, 0x31, 0x5f, 0x63, 0x2e,
+    ///     0x63, 0x00, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69,
+    ///     0x7a, 0x65, 0x72, 0x00, 0x68, 0x61, 0x6e, 0x64,
+    ///     0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x69,
+    ///     0x63, 0x5f, 0x69, 0x6e, 0x69, 0x74, 0x00
+    /// ];
+    ///
+    /// const GNU_HASHTAB: [u8; 48] = [
+    ///     0x02, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    ///     0x01, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+    ///     0x21, 0x10, 0x05, 0x00, 0x00, 0x0e, 0x00, 0x00,
+    ///     0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    ///     0x13, 0x88, 0xda, 0x32, 0xe8, 0x5f, 0x5a, 0x40,
+    ///     0x6a, 0x56, 0xc6, 0xab, 0x05, 0x15, 0x00, 0x00
+    /// ];
+    ///
+    /// let strtab: Strtab<'_> =
+    ///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+    /// let symtab: Symtab<'_, LittleEndian, Elf64> =
+    ///     Symtab::try_from(&SYMTAB[0..]).unwrap();
+    /// let hash = GnuHashtab::from_slice(&GNU_HASHTAB[0..], strtab, symtab);
+    ///
+    /// assert!(hash.is_ok());
+    ///
+    /// let hash = GnuHashtab::from_slice(&GNU_HASHTAB[0..40], strtab, symtab);
+    ///
+    /// assert_eq!(hash.err(), Some(GnuHashtabError::TooShort));
+    /// ```
+    pub fn from_slice(data: &'a [u8], strtab: Strtab<'a>,
+                      symtab: Symtab<'a, B, Offsets>) ->
+        Result<GnuHashtab<'a, B, Offsets>, GnuHashtabError> {
+        match gnu_hash_hdr::<B, Offsets>(data, symtab.num_syms()) {
+            Ok((symoffset, shift, bloom_size, buckets_size, chains_size)) => {
+                let end = GNU_HASH_BLOOM_START + bloom_size + buckets_size +
+                          chains_size;
+                let tabs = &data[GNU_HASH_BLOOM_START .. end];
+                let (bloom, tabs) = tabs.split_at(bloom_size);
+                let (buckets, chains) = tabs.split_at(buckets_size);
+
+                Ok(GnuHashtab { symtab: symtab, strtab: strtab,
+                                symoffset: symoffset, shift: shift,
+                                bloom: bloom, buckets: buckets,
+                                chains: chains })
+            },
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Attempt to create a `GnuHashtab` by filling in `buf` with data
+    /// from `strtab` and `symtab`.
+    ///
+    /// This will write the GNU-style hash table data into the buffer
+    /// in the proper ELF format, hashing all symbols at or after
+    /// `symoffset` into `nbuckets` buckets.  The size of the buffer
+    /// required can be obtained from [gnu_required_bytes].  Returns
+    /// both the `GnuHashtab` and the remaining space if successful.
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if the hash table doesn't fit into the
+    /// provided buffer, if `symoffset` is zero or past the end of
+    /// `symtab`, if `nbuckets` is zero, or if the hashed symbols
+    /// aren't grouped by bucket (see [sort_gnu_syms]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryFrom;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::hash::GnuHashtab;
+    /// use elf_utils::strtab::Strtab;
+    /// use elf_utils::strtab::WithStrtab;
+    /// use elf_utils::symtab::Symtab;
+    /// use elf_utils::symtab::SymBase;
+    /// use elf_utils::symtab::SymBind;
+    /// use elf_utils::symtab::SymData;
+    /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
+    ///
+    /// const SYMTAB: [u8; 120] = [
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0xf1, 0xff,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x0a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+    ///     0x30, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x14, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+    ///     0x10, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x1a, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    /// ];
+    ///
+    /// const STRTAB: [u8; 39] = [
+    ///     0x00, 0x63, 0x72, 0x74, 0x31, 0x5f, 0x63, 0x2e,
+    ///     0x63, 0x00, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69,
+    ///     0x7a, 0x65, 0x72, 0x00, 0x68, 0x61, 0x6e, 0x64,
+    ///     0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x69,
+    ///     0x63, 0x5f, 0x69, 0x6e, 0x69, 0x74, 0x00
+    /// ];
+    ///
+    /// let strtab: Strtab<'_> =
+    ///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+    /// let symtab: Symtab<'_, LittleEndian, Elf64> =
+    ///     Symtab::try_from(&SYMTAB[0..]).unwrap();
+    /// let mut buf = [0; 60];
+    /// let (hash, rest) = GnuHashtab::create_split(&mut buf[0..], strtab,
+    ///                                             symtab, 1, 2).unwrap();
+    ///
+    /// assert_eq!(rest.len(), 12);
+    ///
+    /// assert_eq!(hash.lookup("finalizer").unwrap().unwrap()
+    ///                .with_strtab(strtab).unwrap(),
+    ///            SymData { name: Some(Ok("finalizer")), value: 560, size: 90,
+    ///                      kind: SymKind::Function, bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
+    ///                      section: SymBase::Index(1) });
+    /// assert!(hash.lookup("not present").unwrap().is_none());
+    /// ```
+    #[inline]
+    pub fn create_split(buf: &'a mut [u8], strtab: Strtab<'a>,
+                        symtab: Symtab<'a, B, Offsets>, symoffset: usize,
+                        nbuckets: usize) ->
+        Result<(GnuHashtab<'a, B, Offsets>, &'a mut [u8]), ()> {
+        match create_split_gnu_filled(buf, strtab, symtab, symoffset,
+                                      nbuckets) {
+            Ok((bloom, buckets, chains, rest)) => {
+                Ok((GnuHashtab { symtab: symtab, strtab: strtab,
+                                 symoffset: symoffset,
+                                 shift: GNU_HASH_DEFAULT_SHIFT,
+                                 bloom: bloom, buckets: buckets,
+                                 chains: chains }, rest))
+            },
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Attempt to create a `GnuHashtab` by filling in `buf` with data
+    /// from `strtab` and `symtab`.
+    ///
+    /// This will write the GNU-style hash table data into the buffer
+    /// in the proper ELF format, hashing all symbols at or after
+    /// `symoffset` into `nbuckets` buckets.  The size of the buffer
+    /// required can be obtained from [gnu_required_bytes].
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if the hash table doesn't fit into the
+    /// provided buffer, if `symoffset` is zero or past the end of
+    /// `symtab`, if `nbuckets` is zero, or if the hashed symbols
+    /// aren't grouped by bucket (see [sort_gnu_syms]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryFrom;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::hash;
+    /// use elf_utils::hash::GnuHashtab;
+    /// use elf_utils::strtab::Strtab;
+    /// use elf_utils::strtab::WithStrtab;
+    /// use elf_utils::symtab::Symtab;
+    /// use elf_utils::symtab::SymBase;
+    /// use elf_utils::symtab::SymBind;
+    /// use elf_utils::symtab::SymData;
+    /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
+    ///
+    /// const SYMTAB: [u8; 120] = [
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0xf1, 0xff,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x0a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+    ///     0x30, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x14, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+    ///     0x10, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x1a, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x01, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    /// ];
+    ///
+    /// const STRTAB: [u8; 39] = [
+    ///     0x00, 0x63, 0x72, 0x74, 0x31, 0x5f, 0x63, 0x2e,
+    ///     0x63, 0x00, 0x66, 0x69, 0x6e, 0x61, 0x6c, 0x69,
+    ///     0x7a, 0x65, 0x72, 0x00, 0x68, 0x61, 0x6e, 0x64,
+    ///     0x6c, 0x65, 0x5f, 0x73, 0x74, 0x61, 0x74, 0x69,
+    ///     0x63, 0x5f, 0x69, 0x6e, 0x69, 0x74, 0x00
+    /// ];
+    ///
+    /// let strtab: Strtab<'_> =
+    ///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+    /// let symtab: Symtab<'_, LittleEndian, Elf64> =
+    ///     Symtab::try_from(&SYMTAB[0..]).unwrap();
+    /// let mut buf = [0; 48];
+    ///
+    /// assert_eq!(hash::gnu_required_bytes::<Elf64>(symtab.num_syms(), 1, 2),
+    ///            buf.len());
+    ///
+    /// let hash = GnuHashtab::create(&mut buf[0..], strtab, symtab,
+    ///                               1, 2).unwrap();
+    ///
+    /// assert_eq!(hash.lookup("crt1_c.c").unwrap().unwrap()
+    ///                .with_strtab(strtab).unwrap(),
+    ///            SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
+    ///                      kind: SymKind::File, bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
+    ///                      section: SymBase::Absolute });
+    /// assert_eq!(hash.lookup("handle_static_init").unwrap().unwrap()
+    ///                .with_strtab(strtab).unwrap(),
+    ///            SymData { name: Some(Ok("handle_static_init")), value: 272,
+    ///                      size: 282, kind: SymKind::Function,
+    ///                      bind: SymBind::Local,
+    ///                      visibility: SymVisibility::Default, other: 0,
+    ///                      section: SymBase::Index(1) });
+    /// ```
+    #[inline]
+    pub fn create(buf: &'a mut [u8], strtab: Strtab<'a>,
+                  symtab: Symtab<'a, B, Offsets>, symoffset: usize,
+                  nbuckets: usize) ->
+        Result<GnuHashtab<'a, B, Offsets>, ()> {
+        match Self::create_split(buf, strtab, symtab, symoffset, nbuckets) {
+            Ok((hashtab, _)) => Ok(hashtab),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the index of the first symbol present in the hash table.
+    ///
+    /// Symbols with indexes below this cannot be looked up.
+    #[inline]
+    pub fn symoffset(&self) -> usize {
+        self.symoffset
+    }
+
+    /// Look up the [Sym](crate::symtab::Sym) by `name`.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the internal hash table
+    /// formatting is bad.  If `name` does not reference any symbol,
+    /// `Ok(None)` will be returned.
+    #[inline]
+    pub fn lookup<Name>(&self, name: Name) ->
+        Result<Option<Sym<'a, B, Offsets>>, ()>
+        where Name: ElfName {
+        lookup_gnu_sym(self, name)
+    }
+}
+
+impl<'a, B, Offsets> GnuHashtabMut<'a, B, Offsets>
+    where Sym<'a, B, Offsets>: TryInto<SymData<Offsets::Word, Offsets::Half,
+                                               Offsets>> +
+                               WithStrtab<'a>,
+          B: 'a + ByteOrder,
+          Offsets: 'a + SymOffsets {
+    /// Create a `GnuHashtabMut` from a slice containing data, along
+    /// with the associated `Strtab` and `Symtab`.
+    pub fn from_slice(data: &'a mut [u8], strtab: Strtab<'a>,
+                      symtab: Symtab<'a, B, Offsets>) ->
+        Result<GnuHashtabMut<'a, B, Offsets>, GnuHashtabError> {
+        match gnu_hash_hdr::<B, Offsets>(data, symtab.num_syms()) {
+            Ok((symoffset, shift, bloom_size, buckets_size, chains_size)) => {
+                let end = GNU_HASH_BLOOM_START + bloom_size + buckets_size +
+                          chains_size;
+                let tabs = &mut data[GNU_HASH_BLOOM_START .. end];
+                let (bloom, tabs) = tabs.split_at_mut(bloom_size);
+                let (buckets, chains) = tabs.split_at_mut(buckets_size);
+
+                Ok(GnuHashtabMut { symtab: symtab, strtab: strtab,
+                                   symoffset: symoffset, shift: shift,
+                                   bloom: bloom, buckets: buckets,
+                                   chains: chains })
+            },
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Create a `GnuHashtabMut` by filling in `buf` with data from
+    /// `strtab` and `symtab`.  Return the rest of the buffer.
+    #[inline]
+    pub fn create_split(buf: &'a mut [u8], strtab: Strtab<'a>,
+                        symtab: Symtab<'a, B, Offsets>, symoffset: usize,
+                        nbuckets: usize) ->
+        Result<(GnuHashtabMut<'a, B, Offsets>, &'a mut [u8]), ()> {
+        match create_split_gnu_filled(buf, strtab, symtab, symoffset,
+                                      nbuckets) {
+            Ok((bloom, buckets, chains, rest)) => {
+                Ok((GnuHashtabMut { symtab: symtab, strtab: strtab,
+                                    symoffset: symoffset,
+                                    shift: GNU_HASH_DEFAULT_SHIFT,
+                                    bloom: bloom, buckets: buckets,
+                                    chains: chains }, rest))
+            },
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Create a `GnuHashtabMut` by filling in `buf` with data from
+    /// `strtab` and `symtab`.
+    #[inline]
+    pub fn create(buf: &'a mut [u8], strtab: Strtab<'a>,
+                  symtab: Symtab<'a, B, Offsets>, symoffset: usize,
+                  nbuckets: usize) ->
+        Result<GnuHashtabMut<'a, B, Offsets>, ()> {
+        match Self::create_split(buf, strtab, symtab, symoffset, nbuckets) {
+            Ok((hashtab, _)) => Ok(hashtab),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the index of the first symbol present in the hash table.
+    #[inline]
+    pub fn symoffset(&self) -> usize {
+        self.symoffset
+    }
+
+    /// Look up the [Sym](crate::symtab::Sym) by `name`.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the internal hash table
+    /// formatting is bad.  If `name` does not reference any symbol,
+    /// `Ok(None)` will be returned.
+    #[inline]
+    pub fn lookup<Name>(&'a self, name: Name) ->
+        Result<Option<Sym<'a, B, Offsets>>, ()>
+        where Name: ElfName {
+        let hashtab = GnuHashtab { symtab: self.symtab, strtab: self.strtab,
+                                   symoffset: self.symoffset,
+                                   shift: self.shift, bloom: self.bloom,
+                                   buckets: self.buckets,
+                                   chains: self.chains };
+
+        lookup_gnu_sym(&hashtab, name)
+    }
+}
+
+impl ElfName for [u8] {
+    fn hash_name(&self) -> u32 {
+        let mut h = 0;
+
+        for byte in self {
+            h = (h << 4) + (*byte as u32);
+
+            let g = h & 0xf0000000;
+
+            if g != 0 {
+                h ^= g >> 24;
+                h &= !g;
+            }
+        }
+
+        h
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Option<&[u8]> {
+        Some(self)
+    }
+
+    #[inline]
+    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
+        match other {
+            Ok(str) => str.as_bytes() == self,
+            Err(bytes) => *bytes == self
+        }
+    }
+}
+
+impl ElfName for str {
+    #[inline]
+    fn hash_name(&self) -> u32 {
+        self.as_bytes().hash_name()
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+
+    #[inline]
+    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
+        match other {
+            Ok(str) => *str == self,
+            Err(_) => false
+        }
+    }
+}
+
+impl<'a> ElfName for &'a str {
+    #[inline]
+    fn hash_name(&self) -> u32 {
+        self.as_bytes().hash_name()
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Option<&[u8]> {
+        Some(self.as_bytes())
+    }
+
+    #[inline]
+    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
+        match other {
+            Ok(str) => *str == *self,
+            Err(_) => false
+        }
+    }
+}
+/*
+impl ElfName for String {
+    #[inline]
+    fn hash_name(&self) -> u32 {
+        self.as_str().hash_name()
+    }
+
+    #[inline]
+    fn cmp_name(&self, other: Result<&str, &[u8]>) -> bool {
+        self.as_str().cmp_name(other)
+    }
+}
+*/
+impl<'a> ElfName for Result<&'a str, &'a [u8]> {
+    #[inline]
+    fn hash_name(&self) -> u32 {
+        match self {
+            Ok(str) => str.hash_name(),
+            Err(str) => str.hash_name()
+        }
+    }
+
+    #[inline]
+    fn name_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Ok(str) => Some(str.as_bytes()),
+            Err(str) => Some(str)
         }
     }
 
@@ -20,21 +20,38 @@
 //! [WithElfData](crate::elf::WithElfData),
 //! [WithStrtab](crate::strtab::WithStrtab),
 //! [WithSymtab](crate::symtab::WithSymtab), etc.
+//!
+//! # Owned Data
+//!
+//! The crate does not allocate by default.  With the `alloc` feature
+//! enabled, the `owned` module provides owned counterparts to some
+//! of the borrowed views, which can outlive the data from which they
+//! were created and can be grown incrementally.
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 extern crate byteorder;
 
 mod elf;
 
+pub mod array;
+pub mod builder;
+pub mod coredump;
 pub mod dynamic;
+pub mod group;
 pub mod hash;
 pub mod note;
+#[cfg(feature = "alloc")]
+pub mod owned;
 pub mod prog_hdr;
 pub mod reloc;
 pub mod section_hdr;
+pub mod shndx;
 pub mod strtab;
 pub mod symtab;
+pub mod version;
 
 pub use elf::Elf;
 pub use elf::Elf32;
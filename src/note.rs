@@ -223,9 +223,12 @@ use core::fmt::Formatter;
 use core::iter::FusedIterator;
 use core::iter::Iterator;
 use core::marker::PhantomData;
+use crate::elf::ElfClass;
 
 const ELF_NOTE_WORD_SIZE: usize = 4;
 
+const ELF_NOTE_ALIGN: usize = 4;
+
 const ELF_NOTE_NAME_SIZE_START: usize = 0;
 const ELF_NOTE_NAME_SIZE_SIZE: usize = ELF_NOTE_WORD_SIZE;
 const ELF_NOTE_NAME_SIZE_END: usize = ELF_NOTE_NAME_SIZE_START +
@@ -240,6 +243,74 @@ const ELF_NOTE_TYPE_START: usize = ELF_NOTE_DESC_SIZE_END;
 const ELF_NOTE_TYPE_SIZE: usize = ELF_NOTE_WORD_SIZE;
 const ELF_NOTE_TYPE_END: usize = ELF_NOTE_TYPE_START + ELF_NOTE_TYPE_SIZE;
 
+const NOTE_NAME_GNU: &[u8] = b"GNU\0";
+const NOTE_NAME_FREEBSD: &[u8] = b"FreeBSD\0";
+
+const NT_GNU_ABI_TAG: u32 = 1;
+const NT_GNU_BUILD_ID: u32 = 3;
+const NT_GNU_GOLD_VERSION: u32 = 4;
+const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+const NT_FREEBSD_ABI_TAG: u32 = 1;
+const NT_FREEBSD_NOINIT_TAG: u32 = 2;
+const NT_FREEBSD_ARCH_TAG: u32 = 3;
+const NT_FREEBSD_FEATURE_CTL: u32 = 4;
+
+const GNU_ABI_TAG_SIZE: usize = ELF_NOTE_WORD_SIZE * 4;
+
+const GNU_PROPERTY_HDR_SIZE: usize = 8;
+
+const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+
+const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 0x1;
+const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 0x2;
+
+const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 0x1;
+const GNU_PROPERTY_X86_ISA_1_V2: u32 = 0x2;
+const GNU_PROPERTY_X86_ISA_1_V3: u32 = 0x4;
+const GNU_PROPERTY_X86_ISA_1_V4: u32 = 0x8;
+
+const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 0x1;
+const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 0x2;
+
+const NT_FREEBSD_FCTL_ASLR_DISABLE: u32 = 0x1;
+const NT_FREEBSD_FCTL_PROTMAX_DISABLE: u32 = 0x2;
+const NT_FREEBSD_FCTL_STKGAP_DISABLE: u32 = 0x4;
+const NT_FREEBSD_FCTL_WXNEEDED: u32 = 0x8;
+const NT_FREEBSD_FCTL_LA48: u32 = 0x10;
+const NT_FREEBSD_FCTL_ASG_DISABLE: u32 = 0x20;
+
+const X86_FEATURES: [X86Feature; 2] = [
+    X86Feature::IBT,
+    X86Feature::SHSTK
+];
+
+const X86_ISA_LEVELS: [X86IsaLevel; 4] = [
+    X86IsaLevel::Baseline,
+    X86IsaLevel::V2,
+    X86IsaLevel::V3,
+    X86IsaLevel::V4
+];
+
+const AARCH64_FEATURES: [AArch64Feature; 2] = [
+    AArch64Feature::BTI,
+    AArch64Feature::PAC
+];
+
+const FREEBSD_FEATURES: [FreeBSDFeature; 6] = [
+    FreeBSDFeature::ASLRDisable,
+    FreeBSDFeature::ProtMaxDisable,
+    FreeBSDFeature::StackGapDisable,
+    FreeBSDFeature::WXNeeded,
+    FreeBSDFeature::LA48,
+    FreeBSDFeature::ASGDisable
+];
+
 /// In-place read-only ELF notes section.
 ///
 /// ELF note sections contain one or more simple data objects
@@ -387,19 +458,425 @@ pub enum NotesError {
     TooShort
 }
 
+/// Operating systems that can appear in a `NT_GNU_ABI_TAG` note.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GnuAbiOS {
+    /// Linux (`GNU_ABI_TAG_LINUX`).
+    Linux,
+    /// GNU Hurd (`GNU_ABI_TAG_HURD`).
+    Hurd,
+    /// Solaris (`GNU_ABI_TAG_SOLARIS`).
+    Solaris,
+    /// FreeBSD (`GNU_ABI_TAG_FREEBSD`).
+    FreeBSD,
+    /// NetBSD (`GNU_ABI_TAG_NETBSD`).
+    NetBSD,
+    /// Syllable (`GNU_ABI_TAG_SYLLABLE`).
+    Syllable,
+    /// NaCl (`GNU_ABI_TAG_NACL`).
+    NaCl,
+    /// Unknown operating system code.
+    Unknown(u32)
+}
+
+/// Individual x86 feature bits from a
+/// `GNU_PROPERTY_X86_FEATURE_1_AND` property.
+///
+/// These are the values recognized by [X86Features].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum X86Feature {
+    /// Indirect branch tracking (`GNU_PROPERTY_X86_FEATURE_1_IBT`).
+    IBT,
+    /// Shadow stack (`GNU_PROPERTY_X86_FEATURE_1_SHSTK`).
+    SHSTK
+}
+
+/// Individual x86 ISA levels from a `GNU_PROPERTY_X86_ISA_1_NEEDED`
+/// or `GNU_PROPERTY_X86_ISA_1_USED` property.
+///
+/// These are the values recognized by [X86IsaLevels].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum X86IsaLevel {
+    /// Baseline x86-64 (`GNU_PROPERTY_X86_ISA_1_BASELINE`).
+    Baseline,
+    /// x86-64-v2 (`GNU_PROPERTY_X86_ISA_1_V2`).
+    V2,
+    /// x86-64-v3 (`GNU_PROPERTY_X86_ISA_1_V3`).
+    V3,
+    /// x86-64-v4 (`GNU_PROPERTY_X86_ISA_1_V4`).
+    V4
+}
+
+/// Individual AArch64 feature bits from a
+/// `GNU_PROPERTY_AARCH64_FEATURE_1_AND` property.
+///
+/// These are the values recognized by [AArch64Features].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AArch64Feature {
+    /// Branch target identification
+    /// (`GNU_PROPERTY_AARCH64_FEATURE_1_BTI`).
+    BTI,
+    /// Pointer authentication (`GNU_PROPERTY_AARCH64_FEATURE_1_PAC`).
+    PAC
+}
+
+/// Individual flags that can appear in a FreeBSD `NT_FREEBSD_FEATURE_CTL`
+/// note.
+///
+/// These are the values recognized by [FreeBSDFeatures].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum FreeBSDFeature {
+    /// Disable address space layout randomization
+    /// (`NT_FREEBSD_FCTL_ASLR_DISABLE`).
+    ASLRDisable,
+    /// Disable implicit `PROT_MAX` (`NT_FREEBSD_FCTL_PROTMAX_DISABLE`).
+    ProtMaxDisable,
+    /// Disable the stack gap (`NT_FREEBSD_FCTL_STKGAP_DISABLE`).
+    StackGapDisable,
+    /// The program needs writable and executable mappings
+    /// (`NT_FREEBSD_FCTL_WXNEEDED`).
+    WXNeeded,
+    /// Limit user virtual addresses to 48 bits
+    /// (`NT_FREEBSD_FCTL_LA48`).
+    LA48,
+    /// Disable stack address randomization
+    /// (`NT_FREEBSD_FCTL_ASG_DISABLE`).
+    ASGDisable
+}
+
+/// Decoded contents of a `GNU_PROPERTY_X86_FEATURE_1_AND` property.
+///
+/// This is a bitset over the [X86Feature] values.  All bits are kept,
+/// including any that do not correspond to a known feature.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct X86Features {
+    /// Raw value of the property.
+    pub bits: u32
+}
+
+/// Decoded contents of a `GNU_PROPERTY_X86_ISA_1_NEEDED` or
+/// `GNU_PROPERTY_X86_ISA_1_USED` property.
+///
+/// This is a bitset over the [X86IsaLevel] values.  All bits are
+/// kept, including any that do not correspond to a known level.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct X86IsaLevels {
+    /// Raw value of the property.
+    pub bits: u32
+}
+
+/// Decoded contents of a `GNU_PROPERTY_AARCH64_FEATURE_1_AND`
+/// property.
+///
+/// This is a bitset over the [AArch64Feature] values.  All bits are
+/// kept, including any that do not correspond to a known feature.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AArch64Features {
+    /// Raw value of the property.
+    pub bits: u32
+}
+
+/// Decoded contents of a FreeBSD `NT_FREEBSD_FEATURE_CTL` note.
+///
+/// This is a bitset over the [FreeBSDFeature] values.  All bits are
+/// kept, including any that do not correspond to a known flag.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct FreeBSDFeatures {
+    /// Raw value of the note descriptor.
+    pub bits: u32
+}
+
+/// In-place read-only array of GNU properties.
+///
+/// This is the descriptor of a `NT_GNU_PROPERTY_TYPE_0` note, which
+/// holds an array of properties, each padded out to the address size
+/// of the ELF class.  It can be iterated over with
+/// [iter](GnuProperties::iter), which produces [GnuPropertyData].
+///
+/// A `GnuProperties` is usually obtained by decoding a [NoteData]
+/// into a [NoteKind]; however, it can also be created directly from
+/// raw data using the [TryFrom](core::convert::TryFrom) instance,
+/// which will check that each property fits within the data and
+/// that known properties have the correct size.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::Elf64;
+/// use elf_utils::note::GnuProperties;
+/// use elf_utils::note::GnuPropertyData;
+/// use elf_utils::note::X86Feature;
+///
+/// const PROPS: [u8; 16] = [
+///     0x02, 0x00, 0x00, 0xc0, 0x04, 0x00, 0x00, 0x00,
+///     0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+///
+/// let props: GnuProperties<'_, LittleEndian, Elf64> =
+///     GnuProperties::try_from(&PROPS[0..]).unwrap();
+/// let mut iter = props.iter();
+///
+/// match iter.next() {
+///     Some(GnuPropertyData::X86Feature1And { features }) => {
+///         assert!(features.contains(X86Feature::IBT));
+///         assert!(features.contains(X86Feature::SHSTK));
+///     },
+///     _ => panic!("Expected X86 feature property")
+/// }
+///
+/// assert!(iter.next().is_none());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct GnuProperties<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8]
+}
+
+/// Iterator over the properties in a [GnuProperties].
+#[derive(Copy, Clone)]
+pub struct GnuPropertiesIter<'a, B: ByteOrder, Class: ElfClass> {
+    byteorder: PhantomData<B>,
+    class: PhantomData<Class>,
+    data: &'a [u8],
+    idx: usize
+}
+
+/// Projected GNU property.
+///
+/// This is a single property from a `NT_GNU_PROPERTY_TYPE_0` note.
+/// Properties are produced by iterating over a [GnuProperties], and
+/// can be used to create a property note using
+/// [create_kinds](Notes::create_kinds).
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GnuPropertyData<'a, Class: ElfClass> {
+    /// Stack size required by the program
+    /// (`GNU_PROPERTY_STACK_SIZE`).
+    StackSize {
+        /// Required stack size.
+        size: Class::Addr
+    },
+    /// Copy relocations are not allowed against protected symbols
+    /// (`GNU_PROPERTY_NO_COPY_ON_PROTECTED`).
+    NoCopyOnProtected,
+    /// x86 ISA levels needed to run the program
+    /// (`GNU_PROPERTY_X86_ISA_1_NEEDED`).
+    X86IsaNeeded {
+        /// ISA levels.
+        isa: X86IsaLevels
+    },
+    /// x86 ISA levels used by the program
+    /// (`GNU_PROPERTY_X86_ISA_1_USED`).
+    X86IsaUsed {
+        /// ISA levels.
+        isa: X86IsaLevels
+    },
+    /// x86 features supported by every input object
+    /// (`GNU_PROPERTY_X86_FEATURE_1_AND`).
+    X86Feature1And {
+        /// Supported features.
+        features: X86Features
+    },
+    /// AArch64 features supported by every input object
+    /// (`GNU_PROPERTY_AARCH64_FEATURE_1_AND`).
+    AArch64Feature1And {
+        /// Supported features.
+        features: AArch64Features
+    },
+    /// Any other property.
+    Unknown {
+        /// Property type (`pr_type`).
+        kind: u32,
+        /// Property data, without padding.
+        data: &'a [u8]
+    }
+}
+
+/// Semantic contents of an ELF note.
+///
+/// This is a typed interpretation of a [NoteData], for notes whose
+/// meaning is given by their name and type.  A `NoteKind` is obtained
+/// from a `NoteData` using the [TryFrom](core::convert::TryFrom)
+/// instance on [NoteKindRaw]; notes that are not recognized are
+/// preserved as [Other](NoteKind::Other).
+///
+/// The `Props` parameter is the representation of the properties in
+/// a `NT_GNU_PROPERTY_TYPE_0` note.  This will be a [GnuProperties]
+/// when decoded from existing data, and a slice of [GnuPropertyData]
+/// (see [NoteKindProps]) when creating new notes with
+/// [create_kinds](Notes::create_kinds).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::Elf64;
+/// use elf_utils::note::NoteKind;
+/// use elf_utils::note::NoteKindRaw;
+/// use elf_utils::note::Notes;
+///
+/// const ELF_NOTES: [u8; 48] = [
+///     0x08, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+///     0x01, 0x00, 0x00, 0x00, 0x46, 0x72, 0x65, 0x65,
+///     0x42, 0x53, 0x44, 0x00, 0x92, 0xd6, 0x13, 0x00,
+///     0x04, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+///     0x03, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00,
+///     0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67
+/// ];
+/// const BUILD_ID: [u8; 8] = [
+///     0xde, 0xad, 0xbe, 0xef, 0x01, 0x23, 0x45, 0x67
+/// ];
+///
+/// let notes: Notes<'_, LittleEndian> =
+///     Notes::try_from(&ELF_NOTES[0..]).unwrap();
+/// let mut iter = notes.iter();
+/// let kind: NoteKindRaw<'_, LittleEndian, Elf64> =
+///     iter.next().unwrap().try_into().unwrap();
+///
+/// assert_eq!(kind, NoteKind::FreeBSDAbiTag { version: 1300114 });
+///
+/// let kind: NoteKindRaw<'_, LittleEndian, Elf64> =
+///     iter.next().unwrap().try_into().unwrap();
+///
+/// assert_eq!(kind, NoteKind::GnuBuildId { id: &BUILD_ID });
+/// assert_eq!(format!("{}", kind), "  GNU build ID: deadbeef01234567");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum NoteKind<'a, Props> {
+    /// Operating system ABI required by the object
+    /// (`NT_GNU_ABI_TAG`).
+    GnuAbiTag {
+        /// Operating system.
+        os: GnuAbiOS,
+        /// Earliest compatible kernel major version.
+        major: u32,
+        /// Earliest compatible kernel minor version.
+        minor: u32,
+        /// Earliest compatible kernel subminor version.
+        subminor: u32
+    },
+    /// Unique build ID (`NT_GNU_BUILD_ID`).
+    GnuBuildId {
+        /// Build ID bytes.
+        id: &'a [u8]
+    },
+    /// Version of the gold linker used (`NT_GNU_GOLD_VERSION`).
+    GnuGoldVersion {
+        /// Version string, without the terminating `NUL`.
+        version: &'a str
+    },
+    /// Program properties (`NT_GNU_PROPERTY_TYPE_0`).
+    GnuProperty {
+        /// Properties.
+        props: Props
+    },
+    /// FreeBSD version the object was built for
+    /// (`NT_FREEBSD_ABI_TAG`).
+    FreeBSDAbiTag {
+        /// Value of `__FreeBSD_version`.
+        version: u32
+    },
+    /// Object was linked without `crtbegin`/`crtend`
+    /// (`NT_FREEBSD_NOINIT_TAG`).
+    FreeBSDNoInit,
+    /// Architecture the object was built for (`NT_FREEBSD_ARCH_TAG`).
+    FreeBSDArchTag {
+        /// Architecture name, without the terminating `NUL`.
+        arch: &'a str
+    },
+    /// FreeBSD feature control flags (`NT_FREEBSD_FEATURE_CTL`).
+    FreeBSDFeatureCtl {
+        /// Feature control flags.
+        features: FreeBSDFeatures
+    },
+    /// Any other note.
+    Other(NoteData<'a>)
+}
+
+/// Type synonym for [NoteKind] as decoded from a [NoteData].
+pub type NoteKindRaw<'a, B, Class> = NoteKind<'a, GnuProperties<'a, B, Class>>;
+
+/// Type synonym for [NoteKind] with properties given as a slice of
+/// [GnuPropertyData].
+///
+/// This is the form used to create new notes with
+/// [create_kinds](Notes::create_kinds).
+pub type NoteKindProps<'a, Class> =
+    NoteKind<'a, &'a [GnuPropertyData<'a, Class>]>;
+
+/// Errors that can occur creating a [GnuProperties].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum GnuPropertiesError {
+    /// A property ran past the end of the data.
+    TooShort,
+    /// A known property had the wrong data size.
+    BadDataSize {
+        /// Property type (`pr_type`).
+        kind: u32,
+        /// Data size (`pr_datasz`).
+        size: usize
+    }
+}
+
+/// Errors that can occur decoding a [NoteData] into a [NoteKind].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NoteKindError {
+    /// The descriptor was the wrong size for the note type.
+    BadDescSize {
+        /// Note type.
+        kind: u32,
+        /// Descriptor size.
+        size: usize
+    },
+    /// A string descriptor was not valid UTF-8.
+    BadStr,
+    /// The property array in a `NT_GNU_PROPERTY_TYPE_0` note was
+    /// malformed.
+    BadProps(GnuPropertiesError)
+}
+
+/// Round `size` up to a multiple of `align`, which must be a power
+/// of two.
+#[inline]
+pub(crate) fn pad(size: usize, align: usize) -> usize {
+    (size + align - 1) & !(align - 1)
+}
+
+/// Calculate the size of a single ELF note, with the name and
+/// descriptor padded out to the note alignment.
+#[inline]
+pub(crate) fn note_size(namesize: usize, descsize: usize) -> usize {
+    (ELF_NOTE_WORD_SIZE * 3) + pad(namesize, ELF_NOTE_ALIGN) +
+    pad(descsize, ELF_NOTE_ALIGN)
+}
+
 /// Calculate the size of a single ELF note.
+///
+/// The padding after the descriptor of the last note may be absent.
 #[inline]
 fn get_size<'a, B>(data: &'a [u8]) -> Option<usize>
     where B: ByteOrder {
-    if data.len() >= ELF_NOTE_WORD_SIZE * 2 {
+    if data.len() >= ELF_NOTE_WORD_SIZE * 3 {
         let namesize = B::read_u32(&data[ELF_NOTE_NAME_SIZE_START ..
                                          ELF_NOTE_NAME_SIZE_END]) as usize;
         let descsize = B::read_u32(&data[ELF_NOTE_DESC_SIZE_START ..
                                          ELF_NOTE_DESC_SIZE_END]) as usize;
-        let size = (ELF_NOTE_WORD_SIZE * 3) + namesize + descsize;
+        let min = (ELF_NOTE_WORD_SIZE * 3) + pad(namesize, ELF_NOTE_ALIGN) +
+                  descsize;
+        let size = note_size(namesize, descsize);
 
         if data.len() >= size {
             Some(size)
+        } else if data.len() >= min {
+            Some(data.len())
         } else {
             None
         }
@@ -437,7 +914,7 @@ fn project<'a, B>(data: &'a [u8], _byteorder: PhantomData<B>) -> NoteData<'a>
     let name_start = ELF_NOTE_TYPE_END;
     let name_end = name_start + name_size;
     let name = &data[name_start .. name_end];
-    let desc_start = name_end;
+    let desc_start = name_start + pad(name_size, ELF_NOTE_ALIGN);
     let desc_end = desc_start + desc_size;
     let desc = &data[desc_start .. desc_end];
 
@@ -455,14 +932,18 @@ fn create_split_raw<'a, 'b, B, I>(buf: &'b mut [u8], notes: I) ->
         let note = note.borrow();
         let namesize = note.name.len();
         let descsize = note.desc.len();
-        let size = namesize + descsize + (ELF_NOTE_WORD_SIZE * 3);
+        let size = note_size(namesize, descsize);
 
         if buf.len() >= size + idx {
             let name_start = ELF_NOTE_TYPE_END;
             let name_end = name_start + namesize;
-            let desc_start = name_end;
+            let desc_start = name_start + pad(namesize, ELF_NOTE_ALIGN);
             let desc_end = desc_start + descsize;
 
+            for byte in &mut buf[idx .. idx + size] {
+                *byte = 0;
+            }
+
             B::write_u32(&mut buf[idx + ELF_NOTE_NAME_SIZE_START ..
                                   idx + ELF_NOTE_NAME_SIZE_END],
                          namesize as u32);
@@ -486,6 +967,306 @@ fn create_split_raw<'a, 'b, B, I>(buf: &'b mut [u8], notes: I) ->
     Ok(buf.split_at_mut(idx))
 }
 
+/// Get the data size a known GNU property must have, if any.
+#[inline]
+fn prop_data_size<Class>(kind: u32) -> Option<usize>
+    where Class: ElfClass {
+    match kind {
+        GNU_PROPERTY_STACK_SIZE => Some(Class::ADDR_SIZE),
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED => Some(0),
+        GNU_PROPERTY_AARCH64_FEATURE_1_AND |
+        GNU_PROPERTY_X86_FEATURE_1_AND |
+        GNU_PROPERTY_X86_ISA_1_NEEDED |
+        GNU_PROPERTY_X86_ISA_1_USED => Some(ELF_NOTE_WORD_SIZE),
+        _ => None
+    }
+}
+
+/// Calculate the size of a single GNU property, including padding.
+///
+/// The padding after the last property may be absent.
+#[inline]
+fn get_prop_size<'a, B, Class>(data: &'a [u8]) ->
+    Result<usize, GnuPropertiesError>
+    where Class: ElfClass,
+          B: ByteOrder {
+    if data.len() >= GNU_PROPERTY_HDR_SIZE {
+        let kind = B::read_u32(&data[0 .. 4]);
+        let datasize = B::read_u32(&data[4 .. 8]) as usize;
+        let min = GNU_PROPERTY_HDR_SIZE + datasize;
+        let size = pad(min, Class::ADDR_SIZE);
+
+        match prop_data_size::<Class>(kind) {
+            Some(expected) if expected != datasize =>
+                Err(GnuPropertiesError::BadDataSize { kind: kind,
+                                                      size: datasize }),
+            _ => if data.len() >= size {
+                Ok(size)
+            } else if data.len() >= min {
+                Ok(data.len())
+            } else {
+                Err(GnuPropertiesError::TooShort)
+            }
+        }
+    } else {
+        Err(GnuPropertiesError::TooShort)
+    }
+}
+
+/// Check the internal formatting of a GNU property array.
+#[inline]
+fn check_props<'a, B, Class>(data: &'a [u8]) -> Result<(), GnuPropertiesError>
+    where Class: ElfClass,
+          B: ByteOrder {
+    let mut idx = 0;
+
+    while idx < data.len() {
+        idx += get_prop_size::<B, Class>(&data[idx..])?;
+    }
+
+    Ok(())
+}
+
+#[inline]
+fn project_prop<'a, B, Class>(data: &'a [u8]) -> GnuPropertyData<'a, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    let kind = B::read_u32(&data[0 .. 4]);
+    let datasize = B::read_u32(&data[4 .. 8]) as usize;
+    let data = &data[GNU_PROPERTY_HDR_SIZE ..
+                     GNU_PROPERTY_HDR_SIZE + datasize];
+
+    match kind {
+        GNU_PROPERTY_STACK_SIZE =>
+            GnuPropertyData::StackSize { size: Class::read_addr::<B>(data) },
+        GNU_PROPERTY_NO_COPY_ON_PROTECTED =>
+            GnuPropertyData::NoCopyOnProtected,
+        GNU_PROPERTY_X86_ISA_1_NEEDED => {
+            let isa = X86IsaLevels { bits: B::read_u32(data) };
+
+            GnuPropertyData::X86IsaNeeded { isa: isa }
+        },
+        GNU_PROPERTY_X86_ISA_1_USED => {
+            let isa = X86IsaLevels { bits: B::read_u32(data) };
+
+            GnuPropertyData::X86IsaUsed { isa: isa }
+        },
+        GNU_PROPERTY_X86_FEATURE_1_AND => {
+            let features = X86Features { bits: B::read_u32(data) };
+
+            GnuPropertyData::X86Feature1And { features: features }
+        },
+        GNU_PROPERTY_AARCH64_FEATURE_1_AND => {
+            let features = AArch64Features { bits: B::read_u32(data) };
+
+            GnuPropertyData::AArch64Feature1And { features: features }
+        },
+        _ => GnuPropertyData::Unknown { kind: kind, data: data }
+    }
+}
+
+/// Get the type and data size of a GNU property.
+#[inline]
+fn prop_info<'a, Class>(prop: &GnuPropertyData<'a, Class>) -> (u32, usize)
+    where Class: ElfClass {
+    match prop {
+        GnuPropertyData::StackSize { .. } =>
+            (GNU_PROPERTY_STACK_SIZE, Class::ADDR_SIZE),
+        GnuPropertyData::NoCopyOnProtected =>
+            (GNU_PROPERTY_NO_COPY_ON_PROTECTED, 0),
+        GnuPropertyData::X86IsaNeeded { .. } =>
+            (GNU_PROPERTY_X86_ISA_1_NEEDED, ELF_NOTE_WORD_SIZE),
+        GnuPropertyData::X86IsaUsed { .. } =>
+            (GNU_PROPERTY_X86_ISA_1_USED, ELF_NOTE_WORD_SIZE),
+        GnuPropertyData::X86Feature1And { .. } =>
+            (GNU_PROPERTY_X86_FEATURE_1_AND, ELF_NOTE_WORD_SIZE),
+        GnuPropertyData::AArch64Feature1And { .. } =>
+            (GNU_PROPERTY_AARCH64_FEATURE_1_AND, ELF_NOTE_WORD_SIZE),
+        GnuPropertyData::Unknown { kind, data } => (*kind, data.len())
+    }
+}
+
+/// Write a GNU property into `buf`, which must be exactly the padded
+/// size of the property.
+#[inline]
+fn write_prop<'a, B, Class>(buf: &mut [u8], prop: &GnuPropertyData<'a, Class>)
+    where Class: ElfClass,
+          B: ByteOrder {
+    let (kind, datasize) = prop_info(prop);
+    let data = &mut buf[GNU_PROPERTY_HDR_SIZE ..
+                        GNU_PROPERTY_HDR_SIZE + datasize];
+
+    match prop {
+        GnuPropertyData::StackSize { size } =>
+            Class::write_addr::<B>(data, *size),
+        GnuPropertyData::NoCopyOnProtected => {},
+        GnuPropertyData::X86IsaNeeded { isa } =>
+            B::write_u32(data, isa.bits),
+        GnuPropertyData::X86IsaUsed { isa } =>
+            B::write_u32(data, isa.bits),
+        GnuPropertyData::X86Feature1And { features } =>
+            B::write_u32(data, features.bits),
+        GnuPropertyData::AArch64Feature1And { features } =>
+            B::write_u32(data, features.bits),
+        GnuPropertyData::Unknown { data: bytes, .. } =>
+            data.clone_from_slice(bytes)
+    }
+
+    B::write_u32(&mut buf[0 .. 4], kind);
+    B::write_u32(&mut buf[4 .. 8], datasize as u32);
+}
+
+/// Get the contents of a string descriptor, without the terminating
+/// `NUL`.
+#[inline]
+fn desc_str<'a>(desc: &'a [u8]) -> Result<&'a str, NoteKindError> {
+    let len = desc.iter().position(|b| *b == 0).unwrap_or(desc.len());
+
+    match core::str::from_utf8(&desc[.. len]) {
+        Ok(str) => Ok(str),
+        Err(_) => Err(NoteKindError::BadStr)
+    }
+}
+
+/// Get the descriptor of a note that holds a single word.
+#[inline]
+fn desc_word<'a, B>(kind: u32, desc: &'a [u8]) -> Result<u32, NoteKindError>
+    where B: ByteOrder {
+    if desc.len() == ELF_NOTE_WORD_SIZE {
+        Ok(B::read_u32(desc))
+    } else {
+        Err(NoteKindError::BadDescSize { kind: kind, size: desc.len() })
+    }
+}
+
+/// Get the name, type, and descriptor size of a [NoteKind].
+#[inline]
+fn kind_info<'a, Class>(note: &NoteKindProps<'a, Class>) ->
+    (&'a [u8], u32, usize)
+    where Class: ElfClass {
+    match note {
+        NoteKind::GnuAbiTag { .. } =>
+            (NOTE_NAME_GNU, NT_GNU_ABI_TAG, GNU_ABI_TAG_SIZE),
+        NoteKind::GnuBuildId { id } =>
+            (NOTE_NAME_GNU, NT_GNU_BUILD_ID, id.len()),
+        NoteKind::GnuGoldVersion { version } =>
+            (NOTE_NAME_GNU, NT_GNU_GOLD_VERSION, version.len() + 1),
+        NoteKind::GnuProperty { props } => {
+            let size = props.iter().fold(0, |acc, prop| {
+                let (_, datasize) = prop_info(prop);
+
+                acc + pad(GNU_PROPERTY_HDR_SIZE + datasize,
+                          Class::ADDR_SIZE)
+            });
+
+            (NOTE_NAME_GNU, NT_GNU_PROPERTY_TYPE_0, size)
+        },
+        NoteKind::FreeBSDAbiTag { .. } =>
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_ABI_TAG, ELF_NOTE_WORD_SIZE),
+        NoteKind::FreeBSDNoInit =>
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_NOINIT_TAG, ELF_NOTE_WORD_SIZE),
+        NoteKind::FreeBSDArchTag { arch } =>
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_ARCH_TAG, arch.len() + 1),
+        NoteKind::FreeBSDFeatureCtl { .. } =>
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_FEATURE_CTL, ELF_NOTE_WORD_SIZE),
+        NoteKind::Other(NoteData { kind, name, desc }) =>
+            (name, *kind, desc.len())
+    }
+}
+
+/// Write the descriptor of a [NoteKind] into `buf`, which must be
+/// exactly the size of the descriptor and filled with zeros.
+#[inline]
+fn write_desc<'a, B, Class>(buf: &mut [u8], note: &NoteKindProps<'a, Class>)
+    where Class: ElfClass,
+          B: ByteOrder {
+    match note {
+        NoteKind::GnuAbiTag { os, major, minor, subminor } => {
+            B::write_u32(&mut buf[0 .. 4], (*os).into());
+            B::write_u32(&mut buf[4 .. 8], *major);
+            B::write_u32(&mut buf[8 .. 12], *minor);
+            B::write_u32(&mut buf[12 .. 16], *subminor);
+        },
+        NoteKind::GnuBuildId { id } => buf.clone_from_slice(id),
+        NoteKind::GnuGoldVersion { version: str } |
+        NoteKind::FreeBSDArchTag { arch: str } =>
+            buf[.. str.len()].clone_from_slice(str.as_bytes()),
+        NoteKind::GnuProperty { props } => {
+            let mut idx = 0;
+
+            for prop in props.iter() {
+                let (_, datasize) = prop_info(prop);
+                let size = pad(GNU_PROPERTY_HDR_SIZE + datasize,
+                               Class::ADDR_SIZE);
+
+                write_prop::<B, Class>(&mut buf[idx .. idx + size], prop);
+                idx += size;
+            }
+        },
+        NoteKind::FreeBSDAbiTag { version } => B::write_u32(buf, *version),
+        NoteKind::FreeBSDNoInit => {},
+        NoteKind::FreeBSDFeatureCtl { features } =>
+            B::write_u32(buf, features.bits),
+        NoteKind::Other(NoteData { desc, .. }) => buf.clone_from_slice(desc)
+    }
+}
+
+/// Write the header and name of a note into `buf`, which must be
+/// exactly the size of the note, and zero the rest of it.
+///
+/// Returns the space for the descriptor, without padding.
+pub(crate) fn write_note<'b, B>(buf: &'b mut [u8], name: &[u8], kind: u32,
+                                descsize: usize) -> &'b mut [u8]
+    where B: ByteOrder {
+    let namesize = name.len();
+    let name_start = ELF_NOTE_TYPE_END;
+    let name_end = name_start + namesize;
+    let desc_start = name_start + pad(namesize, ELF_NOTE_ALIGN);
+    let desc_end = desc_start + descsize;
+
+    for byte in buf.iter_mut() {
+        *byte = 0;
+    }
+
+    B::write_u32(&mut buf[ELF_NOTE_NAME_SIZE_START .. ELF_NOTE_NAME_SIZE_END],
+                 namesize as u32);
+    B::write_u32(&mut buf[ELF_NOTE_DESC_SIZE_START .. ELF_NOTE_DESC_SIZE_END],
+                 descsize as u32);
+    B::write_u32(&mut buf[ELF_NOTE_TYPE_START .. ELF_NOTE_TYPE_END], kind);
+    buf[name_start .. name_end].clone_from_slice(name);
+
+    &mut buf[desc_start .. desc_end]
+}
+
+fn create_kinds_split_raw<'a, 'b, B, I, Class>(buf: &'b mut [u8], notes: I) ->
+    Result<(&'b mut [u8], &'b mut [u8]), ()>
+    where I: Iterator,
+          I::Item: Borrow<NoteKindProps<'a, Class>>,
+          Class: 'a + ElfClass,
+          B: ByteOrder {
+    let mut idx = 0;
+
+    for note in notes {
+        let note = note.borrow();
+        let (name, kind, descsize) = kind_info(note);
+        let namesize = name.len();
+        let size = note_size(namesize, descsize);
+
+        if buf.len() >= size + idx {
+            let desc = write_note::<B>(&mut buf[idx .. idx + size], name,
+                                       kind, descsize);
+
+            write_desc::<B, Class>(desc, note);
+
+            idx += size
+        } else {
+            return Err(())
+        }
+    }
+
+    Ok(buf.split_at_mut(idx))
+}
+
 
 /// Calculate the number of bytes required to represent all of the
 /// note objects in `notes`.
@@ -498,12 +1279,44 @@ pub fn required_bytes<'a, I>(notes: I) -> usize
         let namesize = name.len();
         let descsize = desc.len();
 
-        size += (ELF_NOTE_WORD_SIZE * 3) + namesize + descsize;
+        size += note_size(namesize, descsize);
     }
 
     size
 }
 
+/// Calculate the number of bytes required to represent all of the
+/// notes in `notes`, as created by [create_kinds](Notes::create_kinds).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use elf_utils::Elf64;
+/// use elf_utils::note::required_bytes_kinds;
+/// use elf_utils::note::NoteKind;
+/// use elf_utils::note::NoteKindProps;
+///
+/// const NOTES: [NoteKindProps<'static, Elf64>; 2] = [
+///     NoteKind::FreeBSDAbiTag { version: 1300114 },
+///     NoteKind::GnuGoldVersion { version: "gold 1.16" }
+/// ];
+///
+/// assert_eq!(required_bytes_kinds(NOTES.iter()), 52);
+/// ```
+#[inline]
+pub fn required_bytes_kinds<'a, I, Class>(notes: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<NoteKindProps<'a, Class>>,
+          Class: 'a + ElfClass {
+    notes.fold(0, |acc, note| {
+        let (name, _, descsize) = kind_info(note.borrow());
+
+        acc + note_size(name.len(), descsize)
+    })
+}
+
 impl<'a, B> Notes<'a, B>
     where B: ByteOrder {
     /// Attempt to create a `Notes` in `buf` containing the note objects
@@ -655,25 +1468,121 @@ impl<'a, B> Notes<'a, B>
         }
     }
 
-    /// Get an iterator over this `Notes`.
-    #[inline]
-    pub fn iter(&self) -> NotesIter<'a, B> {
-        NotesIter { byteorder: PhantomData, data: self.data, idx: 0 }
-    }
-}
-
-impl<'a, B> NotesMut<'a, B>
-    where B: ByteOrder {
-    /// Attempt to create a `NotesMut` in `buf` containing the note objects
-    /// in `notes`.  Returns both the `Notes` and the remaining space
-    /// if successful.
+    /// Attempt to create a `Notes` in `buf` containing the typed notes
+    /// in `notes`.
+    ///
+    /// This will encode each [NoteKind] with its standard name and
+    /// type, and write it into the buffer in the ELF format.  Returns
+    /// both the `Notes` and the remaining space if successful.
     ///
     /// # Errors
     ///
     /// The only error that can occur is if the notes don't fit into
     /// the provided memory.
-    #[inline]
-    pub fn create_split<I>(buf: &'a mut [u8], notes: I) ->
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryInto;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::note::GnuPropertyData;
+    /// use elf_utils::note::NoteKind;
+    /// use elf_utils::note::NoteKindProps;
+    /// use elf_utils::note::NoteKindRaw;
+    /// use elf_utils::note::Notes;
+    /// use elf_utils::note::X86Features;
+    ///
+    /// const PROPS: [GnuPropertyData<'static, Elf64>; 1] = [
+    ///     GnuPropertyData::X86Feature1And {
+    ///         features: X86Features { bits: 0x3 }
+    ///     }
+    /// ];
+    /// const NOTES: [NoteKindProps<'static, Elf64>; 2] = [
+    ///     NoteKind::GnuProperty { props: &PROPS },
+    ///     NoteKind::GnuGoldVersion { version: "gold 1.16" }
+    /// ];
+    ///
+    /// let mut buf = [0; 80];
+    /// let res: Result<(Notes<'_, LittleEndian>, &'_ mut [u8]), ()> =
+    ///     Notes::create_kinds_split(&mut buf[0..], NOTES.iter());
+    /// let (notes, buf) = res.unwrap();
+    ///
+    /// assert_eq!(buf.len(), 20);
+    ///
+    /// let mut iter = notes.iter();
+    /// let kind: NoteKindRaw<'_, LittleEndian, Elf64> =
+    ///     iter.next().unwrap().try_into().unwrap();
+    ///
+    /// match kind {
+    ///     NoteKind::GnuProperty { props } => {
+    ///         assert_eq!(props.iter().next(), Some(PROPS[0]));
+    ///     },
+    ///     _ => panic!("Expected property note")
+    /// }
+    ///
+    /// let kind: NoteKindRaw<'_, LittleEndian, Elf64> =
+    ///     iter.next().unwrap().try_into().unwrap();
+    ///
+    /// assert_eq!(kind, NoteKind::GnuGoldVersion { version: "gold 1.16" });
+    /// ```
+    #[inline]
+    pub fn create_kinds_split<'b, 'c, I, Class>(buf: &'b mut [u8], notes: I) ->
+        Result<(Notes<'b, B>, &'b mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<NoteKindProps<'c, Class>>,
+              Class: 'c + ElfClass,
+              B: ByteOrder {
+        let byteorder: PhantomData<B> = PhantomData;
+
+        match create_kinds_split_raw::<B, I, Class>(buf, notes) {
+            Ok((note, rest)) => Ok((Notes { byteorder: byteorder,
+                                            data: note }, rest)),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Attempt to create a `Notes` in `buf` containing the typed notes
+    /// in `notes` (see [create_kinds_split](Notes::create_kinds_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the notes don't fit into
+    /// the provided memory.
+    #[inline]
+    pub fn create_kinds<'b, 'c, I, Class>(buf: &'b mut [u8], notes: I) ->
+        Result<Notes<'b, B>, ()>
+        where I: Iterator,
+              I::Item: Borrow<NoteKindProps<'c, Class>>,
+              Class: 'c + ElfClass,
+              B: ByteOrder {
+        match Self::create_kinds_split(buf, notes) {
+            Ok((notes, _)) => Ok(notes),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get an iterator over this `Notes`.
+    #[inline]
+    pub fn iter(&self) -> NotesIter<'a, B> {
+        NotesIter { byteorder: PhantomData, data: self.data, idx: 0 }
+    }
+}
+
+impl<'a, B> NotesMut<'a, B>
+    where B: ByteOrder {
+    /// Attempt to create a `NotesMut` in `buf` containing the note objects
+    /// in `notes`.  Returns both the `Notes` and the remaining space
+    /// if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the notes don't fit into
+    /// the provided memory.
+    #[inline]
+    pub fn create_split<I>(buf: &'a mut [u8], notes: I) ->
         Result<(NotesMut<'a, B>, &'a mut [u8]), ()>
         where I: Iterator<Item = &'a NoteData<'a>>,
               B: ByteOrder {
@@ -776,6 +1685,319 @@ impl<'a, B> Iterator for NotesIter<'a, B>
 
 impl<'a, B> FusedIterator for NotesIter<'a, B> where B: ByteOrder {}
 
+impl X86Feature {
+    /// Get the bit in a `GNU_PROPERTY_X86_FEATURE_1_AND` property
+    /// corresponding to this feature.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        match self {
+            X86Feature::IBT => GNU_PROPERTY_X86_FEATURE_1_IBT,
+            X86Feature::SHSTK => GNU_PROPERTY_X86_FEATURE_1_SHSTK
+        }
+    }
+}
+
+impl X86IsaLevel {
+    /// Get the bit in a `GNU_PROPERTY_X86_ISA_1_NEEDED` or
+    /// `GNU_PROPERTY_X86_ISA_1_USED` property corresponding to this
+    /// level.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        match self {
+            X86IsaLevel::Baseline => GNU_PROPERTY_X86_ISA_1_BASELINE,
+            X86IsaLevel::V2 => GNU_PROPERTY_X86_ISA_1_V2,
+            X86IsaLevel::V3 => GNU_PROPERTY_X86_ISA_1_V3,
+            X86IsaLevel::V4 => GNU_PROPERTY_X86_ISA_1_V4
+        }
+    }
+}
+
+impl AArch64Feature {
+    /// Get the bit in a `GNU_PROPERTY_AARCH64_FEATURE_1_AND` property
+    /// corresponding to this feature.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        match self {
+            AArch64Feature::BTI => GNU_PROPERTY_AARCH64_FEATURE_1_BTI,
+            AArch64Feature::PAC => GNU_PROPERTY_AARCH64_FEATURE_1_PAC
+        }
+    }
+}
+
+impl FreeBSDFeature {
+    /// Get the bit in a `NT_FREEBSD_FEATURE_CTL` note corresponding to
+    /// this flag.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        match self {
+            FreeBSDFeature::ASLRDisable => NT_FREEBSD_FCTL_ASLR_DISABLE,
+            FreeBSDFeature::ProtMaxDisable => NT_FREEBSD_FCTL_PROTMAX_DISABLE,
+            FreeBSDFeature::StackGapDisable => NT_FREEBSD_FCTL_STKGAP_DISABLE,
+            FreeBSDFeature::WXNeeded => NT_FREEBSD_FCTL_WXNEEDED,
+            FreeBSDFeature::LA48 => NT_FREEBSD_FCTL_LA48,
+            FreeBSDFeature::ASGDisable => NT_FREEBSD_FCTL_ASG_DISABLE
+        }
+    }
+}
+
+impl X86Features {
+    /// Check whether `feature` is set.
+    #[inline]
+    pub fn contains(&self, feature: X86Feature) -> bool {
+        self.bits & feature.mask() == feature.mask()
+    }
+
+    /// Set `feature`.
+    #[inline]
+    pub fn insert(&mut self, feature: X86Feature) {
+        self.bits |= feature.mask();
+    }
+
+    /// Clear `feature`.
+    #[inline]
+    pub fn remove(&mut self, feature: X86Feature) {
+        self.bits &= !feature.mask();
+    }
+
+    /// Get any set bits that do not correspond to an [X86Feature].
+    #[inline]
+    pub fn unknown(&self) -> u32 {
+        let known = X86_FEATURES.iter()
+                                .fold(0, |acc, feature| acc | feature.mask());
+
+        self.bits & !known
+    }
+}
+
+impl X86IsaLevels {
+    /// Check whether `level` is set.
+    #[inline]
+    pub fn contains(&self, level: X86IsaLevel) -> bool {
+        self.bits & level.mask() == level.mask()
+    }
+
+    /// Set `level`.
+    #[inline]
+    pub fn insert(&mut self, level: X86IsaLevel) {
+        self.bits |= level.mask();
+    }
+
+    /// Clear `level`.
+    #[inline]
+    pub fn remove(&mut self, level: X86IsaLevel) {
+        self.bits &= !level.mask();
+    }
+
+    /// Get any set bits that do not correspond to an [X86IsaLevel].
+    #[inline]
+    pub fn unknown(&self) -> u32 {
+        let known = X86_ISA_LEVELS.iter()
+                                  .fold(0, |acc, level| acc | level.mask());
+
+        self.bits & !known
+    }
+}
+
+impl AArch64Features {
+    /// Check whether `feature` is set.
+    #[inline]
+    pub fn contains(&self, feature: AArch64Feature) -> bool {
+        self.bits & feature.mask() == feature.mask()
+    }
+
+    /// Set `feature`.
+    #[inline]
+    pub fn insert(&mut self, feature: AArch64Feature) {
+        self.bits |= feature.mask();
+    }
+
+    /// Clear `feature`.
+    #[inline]
+    pub fn remove(&mut self, feature: AArch64Feature) {
+        self.bits &= !feature.mask();
+    }
+
+    /// Get any set bits that do not correspond to an [AArch64Feature].
+    #[inline]
+    pub fn unknown(&self) -> u32 {
+        let known = AARCH64_FEATURES.iter()
+                                    .fold(0, |acc, feature| {
+                                        acc | feature.mask()
+                                    });
+
+        self.bits & !known
+    }
+}
+
+impl FreeBSDFeatures {
+    /// Check whether `flag` is set.
+    #[inline]
+    pub fn contains(&self, flag: FreeBSDFeature) -> bool {
+        self.bits & flag.mask() == flag.mask()
+    }
+
+    /// Set `flag`.
+    #[inline]
+    pub fn insert(&mut self, flag: FreeBSDFeature) {
+        self.bits |= flag.mask();
+    }
+
+    /// Clear `flag`.
+    #[inline]
+    pub fn remove(&mut self, flag: FreeBSDFeature) {
+        self.bits &= !flag.mask();
+    }
+
+    /// Get any set bits that do not correspond to a [FreeBSDFeature].
+    #[inline]
+    pub fn unknown(&self) -> u32 {
+        let known = FREEBSD_FEATURES.iter()
+                                    .fold(0, |acc, flag| acc | flag.mask());
+
+        self.bits & !known
+    }
+}
+
+impl From<u32> for GnuAbiOS {
+    #[inline]
+    fn from(os: u32) -> GnuAbiOS {
+        match os {
+            0 => GnuAbiOS::Linux,
+            1 => GnuAbiOS::Hurd,
+            2 => GnuAbiOS::Solaris,
+            3 => GnuAbiOS::FreeBSD,
+            4 => GnuAbiOS::NetBSD,
+            5 => GnuAbiOS::Syllable,
+            6 => GnuAbiOS::NaCl,
+            _ => GnuAbiOS::Unknown(os)
+        }
+    }
+}
+
+impl From<GnuAbiOS> for u32 {
+    #[inline]
+    fn from(os: GnuAbiOS) -> u32 {
+        match os {
+            GnuAbiOS::Linux => 0,
+            GnuAbiOS::Hurd => 1,
+            GnuAbiOS::Solaris => 2,
+            GnuAbiOS::FreeBSD => 3,
+            GnuAbiOS::NetBSD => 4,
+            GnuAbiOS::Syllable => 5,
+            GnuAbiOS::NaCl => 6,
+            GnuAbiOS::Unknown(os) => os
+        }
+    }
+}
+
+impl<'a, B, Class> GnuProperties<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    /// Get an iterator over this `GnuProperties`.
+    #[inline]
+    pub fn iter(&self) -> GnuPropertiesIter<'a, B, Class> {
+        GnuPropertiesIter { byteorder: PhantomData, class: PhantomData,
+                            data: self.data, idx: 0 }
+    }
+}
+
+impl<'a, B, Class> TryFrom<&'a [u8]> for GnuProperties<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Error = GnuPropertiesError;
+
+    #[inline]
+    fn try_from(data: &'a [u8]) ->
+        Result<GnuProperties<'a, B, Class>, Self::Error> {
+        check_props::<B, Class>(data)?;
+
+        Ok(GnuProperties { byteorder: PhantomData, class: PhantomData,
+                           data: data })
+    }
+}
+
+impl<'a, B, Class> Iterator for GnuPropertiesIter<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Item = GnuPropertyData<'a, Class>;
+
+    fn next(&mut self) -> Option<GnuPropertyData<'a, Class>> {
+        let start = self.idx;
+
+        match get_prop_size::<B, Class>(&self.data[start..]) {
+            Ok(size) => {
+                let buf = &self.data[start .. start + size];
+
+                self.idx += size;
+
+                Some(project_prop::<B, Class>(buf))
+            },
+            Err(_) => None
+        }
+    }
+}
+
+impl<'a, B, Class> FusedIterator for GnuPropertiesIter<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {}
+
+impl<'a, B, Class> TryFrom<NoteData<'a>> for NoteKindRaw<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    type Error = NoteKindError;
+
+    #[inline]
+    fn try_from(note: NoteData<'a>) ->
+        Result<NoteKindRaw<'a, B, Class>, Self::Error> {
+        let NoteData { kind, name, desc } = note;
+
+        match (name, kind) {
+            (NOTE_NAME_GNU, NT_GNU_ABI_TAG) => {
+                if desc.len() == GNU_ABI_TAG_SIZE {
+                    let os = B::read_u32(&desc[0 .. 4]);
+
+                    Ok(NoteKind::GnuAbiTag {
+                        os: GnuAbiOS::from(os),
+                        major: B::read_u32(&desc[4 .. 8]),
+                        minor: B::read_u32(&desc[8 .. 12]),
+                        subminor: B::read_u32(&desc[12 .. 16])
+                    })
+                } else {
+                    Err(NoteKindError::BadDescSize { kind: kind,
+                                                     size: desc.len() })
+                }
+            },
+            (NOTE_NAME_GNU, NT_GNU_BUILD_ID) =>
+                Ok(NoteKind::GnuBuildId { id: desc }),
+            (NOTE_NAME_GNU, NT_GNU_GOLD_VERSION) =>
+                Ok(NoteKind::GnuGoldVersion { version: desc_str(desc)? }),
+            (NOTE_NAME_GNU, NT_GNU_PROPERTY_TYPE_0) => {
+                match GnuProperties::try_from(desc) {
+                    Ok(props) => Ok(NoteKind::GnuProperty { props: props }),
+                    Err(err) => Err(NoteKindError::BadProps(err))
+                }
+            },
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_ABI_TAG) => {
+                let version = desc_word::<B>(kind, desc)?;
+
+                Ok(NoteKind::FreeBSDAbiTag { version: version })
+            },
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_NOINIT_TAG) =>
+                Ok(NoteKind::FreeBSDNoInit),
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_ARCH_TAG) =>
+                Ok(NoteKind::FreeBSDArchTag { arch: desc_str(desc)? }),
+            (NOTE_NAME_FREEBSD, NT_FREEBSD_FEATURE_CTL) => {
+                let bits = desc_word::<B>(kind, desc)?;
+
+                Ok(NoteKind::FreeBSDFeatureCtl {
+                    features: FreeBSDFeatures { bits: bits }
+                })
+            },
+            _ => Ok(NoteKind::Other(note))
+        }
+    }
+}
+
 impl<'a> Display for NoteData<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         let NoteData { kind, name, desc } = self;
@@ -814,3 +2036,218 @@ impl Display for NotesError {
         }
     }
 }
+
+impl Display for GnuAbiOS {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            GnuAbiOS::Linux => write!(f, "Linux"),
+            GnuAbiOS::Hurd => write!(f, "Hurd"),
+            GnuAbiOS::Solaris => write!(f, "Solaris"),
+            GnuAbiOS::FreeBSD => write!(f, "FreeBSD"),
+            GnuAbiOS::NetBSD => write!(f, "NetBSD"),
+            GnuAbiOS::Syllable => write!(f, "Syllable"),
+            GnuAbiOS::NaCl => write!(f, "NaCl"),
+            GnuAbiOS::Unknown(os) => write!(f, "unknown OS {}", os)
+        }
+    }
+}
+
+impl Display for X86Feature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            X86Feature::IBT => write!(f, "IBT"),
+            X86Feature::SHSTK => write!(f, "SHSTK")
+        }
+    }
+}
+
+impl Display for X86IsaLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            X86IsaLevel::Baseline => write!(f, "x86-64-baseline"),
+            X86IsaLevel::V2 => write!(f, "x86-64-v2"),
+            X86IsaLevel::V3 => write!(f, "x86-64-v3"),
+            X86IsaLevel::V4 => write!(f, "x86-64-v4")
+        }
+    }
+}
+
+impl Display for AArch64Feature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AArch64Feature::BTI => write!(f, "BTI"),
+            AArch64Feature::PAC => write!(f, "PAC")
+        }
+    }
+}
+
+impl Display for FreeBSDFeature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            FreeBSDFeature::ASLRDisable => write!(f, "ASLR disabled"),
+            FreeBSDFeature::ProtMaxDisable => write!(f, "PROT_MAX disabled"),
+            FreeBSDFeature::StackGapDisable =>
+                write!(f, "stack gap disabled"),
+            FreeBSDFeature::WXNeeded => write!(f, "W+X needed"),
+            FreeBSDFeature::LA48 => write!(f, "48-bit addresses"),
+            FreeBSDFeature::ASGDisable =>
+                write!(f, "stack randomization disabled")
+        }
+    }
+}
+
+/// Write the names of the set bits in `bits`, followed by any unknown
+/// bits.
+fn fmt_bits<T: Display>(f: &mut Formatter<'_>, bits: u32, known: &[T],
+                        mask: fn(&T) -> u32) ->
+    Result<(), core::fmt::Error> {
+    let mut first = true;
+    let mut unknown = bits;
+
+    for flag in known.iter().filter(|flag| bits & mask(flag) != 0) {
+        if !first {
+            write!(f, ", ")?;
+        }
+
+        write!(f, "{}", flag)?;
+        unknown &= !mask(flag);
+        first = false;
+    }
+
+    if unknown != 0 {
+        if !first {
+            write!(f, ", ")?;
+        }
+
+        write!(f, "0x{:x}", unknown)?;
+        first = false;
+    }
+
+    if first {
+        write!(f, "none")?;
+    }
+
+    Ok(())
+}
+
+impl Display for X86Features {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        fmt_bits(f, self.bits, &X86_FEATURES, X86Feature::mask)
+    }
+}
+
+impl Display for X86IsaLevels {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        fmt_bits(f, self.bits, &X86_ISA_LEVELS, X86IsaLevel::mask)
+    }
+}
+
+impl Display for AArch64Features {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        fmt_bits(f, self.bits, &AARCH64_FEATURES, AArch64Feature::mask)
+    }
+}
+
+impl Display for FreeBSDFeatures {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        fmt_bits(f, self.bits, &FREEBSD_FEATURES, FreeBSDFeature::mask)
+    }
+}
+
+impl<'a, Class> Display for GnuPropertyData<'a, Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            GnuPropertyData::StackSize { size } =>
+                write!(f, "stack size: 0x{:x}", size),
+            GnuPropertyData::NoCopyOnProtected =>
+                write!(f, "no copy on protected"),
+            GnuPropertyData::X86IsaNeeded { isa } =>
+                write!(f, "x86 ISA needed: {}", isa),
+            GnuPropertyData::X86IsaUsed { isa } =>
+                write!(f, "x86 ISA used: {}", isa),
+            GnuPropertyData::X86Feature1And { features } =>
+                write!(f, "x86 feature: {}", features),
+            GnuPropertyData::AArch64Feature1And { features } =>
+                write!(f, "AArch64 feature: {}", features),
+            GnuPropertyData::Unknown { kind, data } => {
+                write!(f, "property 0x{:x}: [", kind)?;
+
+                for byte in data.iter() {
+                    write!(f, " {:02x}", byte)?;
+                }
+
+                write!(f, " ]")
+            }
+        }
+    }
+}
+
+impl<'a, B, Class> Display for GnuProperties<'a, B, Class>
+    where Class: ElfClass,
+          B: ByteOrder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        for prop in self.iter() {
+            write!(f, "\n    {}", prop)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Props> Display for NoteKind<'a, Props>
+    where Props: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            NoteKind::GnuAbiTag { os, major, minor, subminor } =>
+                write!(f, "  GNU ABI tag: {} {}.{}.{}",
+                       os, major, minor, subminor),
+            NoteKind::GnuBuildId { id } => {
+                write!(f, "  GNU build ID: ")?;
+
+                for byte in id.iter() {
+                    write!(f, "{:02x}", byte)?;
+                }
+
+                Ok(())
+            },
+            NoteKind::GnuGoldVersion { version } =>
+                write!(f, "  GNU gold version: {}", version),
+            NoteKind::GnuProperty { props } =>
+                write!(f, "  GNU properties:{}", props),
+            NoteKind::FreeBSDAbiTag { version } =>
+                write!(f, "  FreeBSD ABI tag: {}", version),
+            NoteKind::FreeBSDNoInit =>
+                write!(f, "  FreeBSD no-init tag"),
+            NoteKind::FreeBSDArchTag { arch } =>
+                write!(f, "  FreeBSD arch tag: {}", arch),
+            NoteKind::FreeBSDFeatureCtl { features } =>
+                write!(f, "  FreeBSD feature control: {}", features),
+            NoteKind::Other(note) => write!(f, "{}", note)
+        }
+    }
+}
+
+impl Display for GnuPropertiesError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            GnuPropertiesError::TooShort =>
+                write!(f, "buffer too short for property data"),
+            GnuPropertiesError::BadDataSize { kind, size } =>
+                write!(f, "bad data size {} for property 0x{:x}", size, kind)
+        }
+    }
+}
+
+impl Display for NoteKindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            NoteKindError::BadDescSize { kind, size } =>
+                write!(f, "bad descriptor size {} for note type {}",
+                       size, kind),
+            NoteKindError::BadStr => write!(f, "note string is not UTF-8"),
+            NoteKindError::BadProps(err) =>
+                write!(f, "bad property note: {}", err)
+        }
+    }
+}
@@ -0,0 +1,840 @@
+//! Owned ELF data.
+//!
+//! This module is only available with the `alloc` feature.
+//!
+//! The other types in this crate are views of borrowed data, which
+//! means they cannot outlive the buffer from which they were
+//! obtained, and they cannot grow.  The types in this module own
+//! their data in a [Vec], stored in the same binary format as the
+//! borrowed views:
+//!
+//! - [OwnedStrtab] is an owned [Strtab], to which strings can be
+//!   added.
+//! - [OwnedSymtab] is an owned [Symtab], to which symbols can be
+//!   added.
+//! - [OwnedSectionHdrs] is an owned [SectionHdrs], to which section
+//!   headers can be added.
+//! - [ElfFile] is an owned copy of an entire ELF file.
+//!
+//! Each of these can be created from its borrowed counterpart with
+//! the [From] instance, which copies the data, or from a `Vec<u8>`
+//! with the [TryFrom] instance, which performs the same checks as the
+//! borrowed view.  The borrowed view can be obtained at any time, and
+//! the data can be turned back into a `Vec<u8>` with the [From]
+//! instance or `into_bytes`.
+//!
+//! A [StrtabBuilder] is also provided, which creates an
+//! [OwnedStrtab] with duplicate strings and common suffixes merged.
+//!
+//! # Examples
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryInto;
+//! use elf_utils::Elf64;
+//! use elf_utils::owned::OwnedStrtab;
+//! use elf_utils::owned::OwnedSymtab;
+//! use elf_utils::strtab::WithStrtab;
+//! use elf_utils::symtab::SymBase;
+//! use elf_utils::symtab::SymBind;
+//! use elf_utils::symtab::SymData;
+//! use elf_utils::symtab::SymDataRaw;
+//! use elf_utils::symtab::SymDataStr;
+//! use elf_utils::symtab::SymKind;
+//! use elf_utils::symtab::SymVisibility;
+//!
+//! let mut strtab = OwnedStrtab::new();
+//! let mut symtab: OwnedSymtab<LittleEndian, Elf64> = OwnedSymtab::new();
+//!
+//! symtab.push(&SymData { name: None, value: 0, size: 0,
+//!                        kind: SymKind::None, bind: SymBind::Local,
+//!                        visibility: SymVisibility::Default, other: 0,
+//!                        section: SymBase::Undef });
+//!
+//! let name = strtab.push("main");
+//!
+//! symtab.push(&SymData { name: Some(name as u32), value: 0x1000,
+//!                        size: 0x20, kind: SymKind::Function,
+//!                        bind: SymBind::Global,
+//!                        visibility: SymVisibility::Default, other: 0,
+//!                        section: SymBase::Index(1) });
+//!
+//! let sym: SymDataRaw<Elf64> = symtab.symtab().idx(1).unwrap()
+//!     .try_into().unwrap();
+//! let sym: SymDataStr<'_, Elf64> = sym.with_strtab(strtab.strtab())
+//!     .unwrap().try_into().unwrap();
+//!
+//! assert_eq!(sym.name, Some("main"));
+//! assert_eq!(symtab.into_bytes().len(), 48);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use byteorder::ByteOrder;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::iter::FromIterator;
+use core::iter::once;
+use core::marker::PhantomData;
+use crate::builder::ElfBuilder;
+use crate::builder::ElfBuilderError;
+use crate::elf::Elf;
+use crate::elf::ElfByteOrder;
+use crate::elf::ElfError;
+use crate::elf::ElfHdrOffsets;
+use crate::elf::ElfMut;
+use crate::section_hdr::SectionHdrDataRaw;
+use crate::section_hdr::SectionHdrOffsets;
+use crate::section_hdr::SectionHdrs;
+use crate::section_hdr::SectionHdrsError;
+use crate::section_hdr::SectionHdrsMut;
+use crate::strtab::Strtab;
+use crate::strtab::StrtabError;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymOffsets;
+use crate::symtab::Symtab;
+use crate::symtab::SymtabError;
+use crate::symtab::SymtabMut;
+
+/// Owned ELF string table.
+///
+/// This holds string table data in the same format as a [Strtab],
+/// which can be obtained with [strtab](OwnedStrtab::strtab).  New
+/// strings can be added with [push](OwnedStrtab::push).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use core::convert::TryFrom;
+/// use elf_utils::owned::OwnedStrtab;
+/// use elf_utils::strtab::Strtab;
+///
+/// const STRTAB_DATA: [u8; 7] = [
+///     0, 'n' as u8, 'a' as u8, 'm' as u8, 'e' as u8, '.' as u8, 0
+/// ];
+///
+/// let strtab: Strtab<'_> = Strtab::try_from(&STRTAB_DATA[0..]).unwrap();
+/// let mut owned = OwnedStrtab::from(strtab);
+///
+/// assert_eq!(owned.push("xx"), 7);
+/// assert_eq!(owned.strtab().idx(1), Ok("name."));
+/// assert_eq!(owned.strtab().idx(7), Ok("xx"));
+/// assert_eq!(owned.into_bytes().len(), 10);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OwnedStrtab {
+    data: Vec<u8>
+}
+
+/// Builder for string tables with merged strings.
+///
+/// [Strtab::create] writes each string as given.  A `StrtabBuilder`
+/// instead collects strings with [insert](StrtabBuilder::insert),
+/// then creates a string table with [build](StrtabBuilder::build)
+/// where each distinct string is stored only once, and any string
+/// that is a suffix of another (such as `".text"` and
+/// `".rela.text"`) is stored as part of the longer one.  The empty
+/// string is always at index `0`.
+///
+/// Strings are referred to by the index returned from
+/// [insert](StrtabBuilder::insert), which is the order of insertion.
+/// [build](StrtabBuilder::build) returns the offset in the string
+/// table of each string, in the same order.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use elf_utils::owned::StrtabBuilder;
+///
+/// let mut builder = StrtabBuilder::new();
+/// let rela_text = builder.insert(".rela.text");
+/// let text = builder.insert(".text");
+/// let data = builder.insert(".data");
+/// let text_again = builder.insert(".text");
+/// let (strtab, offsets) = builder.build();
+///
+/// assert_eq!(strtab.len(), 18);
+/// assert_eq!(strtab.strtab().idx(offsets[rela_text]), Ok(".rela.text"));
+/// assert_eq!(strtab.strtab().idx(offsets[text]), Ok(".text"));
+/// assert_eq!(strtab.strtab().idx(offsets[data]), Ok(".data"));
+/// assert_eq!(offsets[text], offsets[rela_text] + 5);
+/// assert_eq!(offsets[text_again], offsets[text]);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StrtabBuilder<'a> {
+    strs: Vec<&'a str>
+}
+
+/// Owned ELF symbol table.
+///
+/// This holds symbol table data in the same format as a [Symtab],
+/// which can be obtained with [symtab](OwnedSymtab::symtab).  New
+/// symbols can be added with [push](OwnedSymtab::push), and existing
+/// symbols can be modified through the [SymtabMut] obtained with
+/// [symtab_mut](OwnedSymtab::symtab_mut).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnedSymtab<B: ByteOrder, Offsets: SymOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: Vec<u8>
+}
+
+/// Owned ELF section header table.
+///
+/// This holds section header table data in the same format as a
+/// [SectionHdrs], which can be obtained with
+/// [section_hdrs](OwnedSectionHdrs::section_hdrs).  New section
+/// headers can be added with [push](OwnedSectionHdrs::push), and
+/// existing section headers can be modified through the
+/// [SectionHdrsMut] obtained with
+/// [section_hdrs_mut](OwnedSectionHdrs::section_hdrs_mut).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnedSectionHdrs<B: ByteOrder, Offsets: SectionHdrOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: Vec<u8>
+}
+
+/// Owned ELF file.
+///
+/// This holds the entire data of an ELF file, which must begin with a
+/// valid ELF header.  An [Elf] can be obtained with
+/// [elf](ElfFile::elf), and used to traverse the rest of the data as
+/// usual.  An `ElfFile` can also be created directly from an
+/// [ElfBuilder] with [build](ElfFile::build).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use core::marker::PhantomData;
+/// use elf_utils::Elf;
+/// use elf_utils::Elf64;
+/// use elf_utils::ElfABI;
+/// use elf_utils::ElfArch;
+/// use elf_utils::ElfHdrDataRaw;
+/// use elf_utils::ElfKind;
+/// use elf_utils::builder::BuilderContent;
+/// use elf_utils::builder::BuilderSection;
+/// use elf_utils::builder::ElfBuilder;
+/// use elf_utils::owned::ElfFile;
+/// use elf_utils::section_hdr::SectionFlags;
+///
+/// const TEXT: [u8; 4] = [0x31, 0xc0, 0xc3, 0x90];
+///
+/// let sections: [BuilderSection<'_, Elf64>; 1] = [
+///     BuilderSection {
+///         name: ".text", addr: 0, align: 16,
+///         flags: SectionFlags { bits: 0x6 },
+///         content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+///     }
+/// ];
+/// let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+///     byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+///     kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+///     flags: 0, sections: &sections, segments: &[], syms: &[]
+/// };
+/// let file = ElfFile::build(&builder).expect("Expected success");
+/// let bytes: Vec<u8> = file.into();
+///
+/// // The file outlives the builder and can be parsed again later.
+/// let file: ElfFile<LittleEndian, Elf64> =
+///     ElfFile::try_from(bytes).expect("Expected success");
+/// let hdr: ElfHdrDataRaw<LittleEndian, Elf64> =
+///     file.elf().expect("Expected success")
+///     .try_into().expect("Expected success");
+///
+/// assert_eq!(hdr.kind, ElfKind::Relocatable);
+/// assert_eq!(hdr.section_hdrs.num_ents, 3);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElfFile<B: ByteOrder, Offsets: ElfHdrOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: Vec<u8>
+}
+
+impl OwnedStrtab {
+    /// Create an empty `OwnedStrtab`.
+    ///
+    /// This will contain only the leading null character, so the
+    /// only valid index is `0`, which is the empty string.
+    #[inline]
+    pub fn new() -> Self {
+        OwnedStrtab { data: vec![0] }
+    }
+
+    /// Add `str` to the end of this `OwnedStrtab`, returning its
+    /// index.
+    ///
+    /// This does not check whether `str` is already present.
+    #[inline]
+    pub fn push(&mut self, str: &str) -> usize {
+        let idx = self.data.len();
+
+        self.data.extend_from_slice(str.as_bytes());
+        self.data.push(0);
+
+        idx
+    }
+
+    /// Get a [Strtab] for this `OwnedStrtab`.
+    #[inline]
+    pub fn strtab(&self) -> Strtab<'_> {
+        match Strtab::try_from(&self.data[0..]) {
+            Ok(strtab) => strtab,
+            Err(_) => panic!("String table data should be valid")
+        }
+    }
+
+    /// Get the length of the underlying data in this `OwnedStrtab`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check whether the underlying data in this `OwnedStrtab` is
+    /// empty.
+    ///
+    /// This is always `false` for an `OwnedStrtab`, which contains at
+    /// least the empty string at index `0`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get the underlying data in this `OwnedStrtab`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Convert this `OwnedStrtab` into its underlying data.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl Default for OwnedStrtab {
+    #[inline]
+    fn default() -> Self {
+        OwnedStrtab::new()
+    }
+}
+
+impl<'a> From<Strtab<'a>> for OwnedStrtab {
+    #[inline]
+    fn from(strtab: Strtab<'a>) -> OwnedStrtab {
+        OwnedStrtab { data: strtab.data().to_vec() }
+    }
+}
+
+impl<'a> FromIterator<&'a str> for OwnedStrtab {
+    #[inline]
+    fn from_iter<I>(strs: I) -> OwnedStrtab
+        where I: IntoIterator<Item = &'a str> {
+        let mut out = OwnedStrtab::new();
+
+        for str in strs {
+            out.push(str);
+        }
+
+        out
+    }
+}
+
+impl TryFrom<Vec<u8>> for OwnedStrtab {
+    type Error = StrtabError;
+
+    /// Check that the first and last bytes are 0, as per the ELF
+    /// standard.
+    #[inline]
+    fn try_from(data: Vec<u8>) -> Result<OwnedStrtab, Self::Error> {
+        if data.is_empty() {
+            Err(StrtabError::BadFirst)
+        } else {
+            Strtab::try_from(&data[0..])?;
+
+            Ok(OwnedStrtab { data: data })
+        }
+    }
+}
+
+impl From<OwnedStrtab> for Vec<u8> {
+    #[inline]
+    fn from(strtab: OwnedStrtab) -> Vec<u8> {
+        strtab.data
+    }
+}
+
+impl<'a> StrtabBuilder<'a> {
+    /// Create an empty `StrtabBuilder`.
+    #[inline]
+    pub fn new() -> Self {
+        StrtabBuilder { strs: Vec::new() }
+    }
+
+    /// Add `str` to this `StrtabBuilder`, returning its index.
+    ///
+    /// The index can be used to look up the offset of `str` in the
+    /// result of [build](StrtabBuilder::build).
+    #[inline]
+    pub fn insert(&mut self, str: &'a str) -> usize {
+        let idx = self.strs.len();
+
+        self.strs.push(str);
+
+        idx
+    }
+
+    /// Get the number of strings added to this `StrtabBuilder`.
+    #[inline]
+    pub fn num_strs(&self) -> usize {
+        self.strs.len()
+    }
+
+    /// Create the string table, and get the offset in it of each
+    /// string, in the order they were inserted.
+    ///
+    /// The strings are sorted by their reversed bytes, so that any
+    /// string that is a suffix of another comes immediately after
+    /// it when traversed in descending order.  Each string is then
+    /// either a suffix of the last string that was written, or is
+    /// written itself.
+    pub fn build(&self) -> (OwnedStrtab, Vec<usize>) {
+        let mut order: Vec<usize> = (0 .. self.strs.len()).collect();
+        let mut offsets = vec![0; self.strs.len()];
+        let mut out = OwnedStrtab::new();
+        let mut last: Option<(&str, usize)> = None;
+
+        order.sort_unstable_by(|a, b| {
+            self.strs[*b].bytes().rev().cmp(self.strs[*a].bytes().rev())
+        });
+
+        for idx in order {
+            let str = self.strs[idx];
+
+            offsets[idx] = match last {
+                _ if str.is_empty() => 0,
+                Some((prev, offset)) if prev.ends_with(str) =>
+                    offset + (prev.len() - str.len()),
+                _ => {
+                    let offset = out.push(str);
+
+                    last = Some((str, offset));
+
+                    offset
+                }
+            }
+        }
+
+        (out, offsets)
+    }
+}
+
+impl<'a> Default for StrtabBuilder<'a> {
+    #[inline]
+    fn default() -> Self {
+        StrtabBuilder::new()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for StrtabBuilder<'a> {
+    #[inline]
+    fn from_iter<I>(strs: I) -> StrtabBuilder<'a>
+        where I: IntoIterator<Item = &'a str> {
+        StrtabBuilder { strs: strs.into_iter().collect() }
+    }
+}
+
+impl<B, Offsets> OwnedSymtab<B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    /// Create an empty `OwnedSymtab`.
+    ///
+    /// Note that the ELF standard requires the first symbol to be a
+    /// null symbol; this is not added automatically.
+    #[inline]
+    pub fn new() -> Self {
+        OwnedSymtab { byteorder: PhantomData, offsets: PhantomData,
+                      data: Vec::new() }
+    }
+
+    /// Add `sym` to the end of this `OwnedSymtab`, returning its
+    /// index.
+    #[inline]
+    pub fn push(&mut self, sym: &SymDataRaw<Offsets>) -> usize {
+        let idx = self.num_syms();
+        let len = self.data.len();
+
+        self.data.resize(len + Offsets::ST_ENT_SIZE, 0);
+
+        match Symtab::<B, Offsets>::create(&mut self.data[len ..],
+                                           once(sym)) {
+            Ok(_) => idx,
+            Err(_) => panic!("Symbol creation should not fail")
+        }
+    }
+
+    /// Get a [Symtab] for this `OwnedSymtab`.
+    #[inline]
+    pub fn symtab(&self) -> Symtab<'_, B, Offsets> {
+        match Symtab::try_from(&self.data[0..]) {
+            Ok(symtab) => symtab,
+            Err(_) => panic!("Symbol table data should be valid")
+        }
+    }
+
+    /// Get a [SymtabMut] for this `OwnedSymtab`.
+    #[inline]
+    pub fn symtab_mut(&mut self) -> SymtabMut<'_, B, Offsets> {
+        match SymtabMut::try_from(&mut self.data[0..]) {
+            Ok(symtab) => symtab,
+            Err(_) => panic!("Symbol table data should be valid")
+        }
+    }
+
+    /// Get the number of symbols in this `OwnedSymtab`.
+    #[inline]
+    pub fn num_syms(&self) -> usize {
+        self.data.len() / Offsets::ST_ENT_SIZE
+    }
+
+    /// Get the underlying data in this `OwnedSymtab`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Convert this `OwnedSymtab` into its underlying data.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<B, Offsets> Default for OwnedSymtab<B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn default() -> Self {
+        OwnedSymtab::new()
+    }
+}
+
+impl<'a, B, Offsets> From<Symtab<'a, B, Offsets>> for OwnedSymtab<B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn from(symtab: Symtab<'a, B, Offsets>) -> OwnedSymtab<B, Offsets> {
+        OwnedSymtab { byteorder: PhantomData, offsets: PhantomData,
+                      data: symtab.data().to_vec() }
+    }
+}
+
+impl<B, Offsets, S> FromIterator<S> for OwnedSymtab<B, Offsets>
+    where S: Borrow<SymDataRaw<Offsets>>,
+          Offsets: SymOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn from_iter<I>(syms: I) -> OwnedSymtab<B, Offsets>
+        where I: IntoIterator<Item = S> {
+        let mut out = OwnedSymtab::new();
+
+        for sym in syms {
+            out.push(sym.borrow());
+        }
+
+        out
+    }
+}
+
+impl<B, Offsets> TryFrom<Vec<u8>> for OwnedSymtab<B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    type Error = SymtabError;
+
+    /// Check that the data is a multiple of the symbol size.
+    #[inline]
+    fn try_from(data: Vec<u8>) -> Result<OwnedSymtab<B, Offsets>,
+                                         Self::Error> {
+        Symtab::<B, Offsets>::try_from(&data[0..])?;
+
+        Ok(OwnedSymtab { byteorder: PhantomData, offsets: PhantomData,
+                         data: data })
+    }
+}
+
+impl<B, Offsets> From<OwnedSymtab<B, Offsets>> for Vec<u8>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn from(symtab: OwnedSymtab<B, Offsets>) -> Vec<u8> {
+        symtab.data
+    }
+}
+
+impl<B, Offsets> OwnedSectionHdrs<B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    /// Create an empty `OwnedSectionHdrs`.
+    ///
+    /// Note that the ELF standard requires the first section header
+    /// to be a null section header; this is not added automatically.
+    #[inline]
+    pub fn new() -> Self {
+        OwnedSectionHdrs { byteorder: PhantomData, offsets: PhantomData,
+                           data: Vec::new() }
+    }
+
+    /// Add `hdr` to the end of this `OwnedSectionHdrs`, returning its
+    /// index.
+    #[inline]
+    pub fn push(&mut self, hdr: SectionHdrDataRaw<Offsets>) -> usize {
+        let idx = self.num_hdrs();
+        let len = self.data.len();
+
+        self.data.resize(len + Offsets::SECTION_HDR_SIZE, 0);
+
+        match SectionHdrs::<B, Offsets>::create(&mut self.data[len ..],
+                                                once(hdr)) {
+            Ok(_) => idx,
+            Err(_) => panic!("Section header creation should not fail")
+        }
+    }
+
+    /// Get a [SectionHdrs] for this `OwnedSectionHdrs`.
+    #[inline]
+    pub fn section_hdrs(&self) -> SectionHdrs<'_, B, Offsets> {
+        match SectionHdrs::try_from(&self.data[0..]) {
+            Ok(hdrs) => hdrs,
+            Err(_) => panic!("Section header data should be valid")
+        }
+    }
+
+    /// Get a [SectionHdrsMut] for this `OwnedSectionHdrs`.
+    #[inline]
+    pub fn section_hdrs_mut(&mut self) -> SectionHdrsMut<'_, B, Offsets> {
+        match SectionHdrsMut::try_from(&mut self.data[0..]) {
+            Ok(hdrs) => hdrs,
+            Err(_) => panic!("Section header data should be valid")
+        }
+    }
+
+    /// Get the number of section headers in this `OwnedSectionHdrs`.
+    #[inline]
+    pub fn num_hdrs(&self) -> usize {
+        self.data.len() / Offsets::SECTION_HDR_SIZE
+    }
+
+    /// Get the underlying data in this `OwnedSectionHdrs`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Convert this `OwnedSectionHdrs` into its underlying data.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<B, Offsets> Default for OwnedSectionHdrs<B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn default() -> Self {
+        OwnedSectionHdrs::new()
+    }
+}
+
+impl<'a, B, Offsets> From<SectionHdrs<'a, B, Offsets>>
+    for OwnedSectionHdrs<B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn from(hdrs: SectionHdrs<'a, B, Offsets>) ->
+        OwnedSectionHdrs<B, Offsets> {
+        OwnedSectionHdrs { byteorder: PhantomData, offsets: PhantomData,
+                           data: hdrs.data().to_vec() }
+    }
+}
+
+impl<B, Offsets> FromIterator<SectionHdrDataRaw<Offsets>>
+    for OwnedSectionHdrs<B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn from_iter<I>(hdrs: I) -> OwnedSectionHdrs<B, Offsets>
+        where I: IntoIterator<Item = SectionHdrDataRaw<Offsets>> {
+        let mut out = OwnedSectionHdrs::new();
+
+        for hdr in hdrs {
+            out.push(hdr);
+        }
+
+        out
+    }
+}
+
+impl<B, Offsets> TryFrom<Vec<u8>> for OwnedSectionHdrs<B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    type Error = SectionHdrsError;
+
+    /// Check that the data is a multiple of the section header size.
+    #[inline]
+    fn try_from(data: Vec<u8>) -> Result<OwnedSectionHdrs<B, Offsets>,
+                                         Self::Error> {
+        SectionHdrs::<B, Offsets>::try_from(&data[0..])?;
+
+        Ok(OwnedSectionHdrs { byteorder: PhantomData, offsets: PhantomData,
+                              data: data })
+    }
+}
+
+impl<B, Offsets> From<OwnedSectionHdrs<B, Offsets>> for Vec<u8>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn from(hdrs: OwnedSectionHdrs<B, Offsets>) -> Vec<u8> {
+        hdrs.data
+    }
+}
+
+impl<B, Offsets> ElfFile<B, Offsets>
+    where Offsets: ElfHdrOffsets,
+          B: ElfByteOrder {
+    /// Create an `ElfFile` containing the ELF file described by
+    /// `builder`.
+    ///
+    /// This allocates a buffer of the size given by
+    /// [required_bytes](ElfBuilder::required_bytes) and creates the
+    /// file in it.
+    ///
+    /// # Errors
+    ///
+    /// Any error from [ElfBuilder::create] other than
+    /// [TooSmall](ElfBuilderError::TooSmall) can occur.
+    #[inline]
+    pub fn build(builder: &ElfBuilder<'_, B, Offsets>) ->
+        Result<Self, ElfBuilderError> {
+        let mut data = vec![0; builder.required_bytes()?];
+
+        builder.create(&mut data[0..])?;
+
+        Ok(ElfFile { byteorder: PhantomData, offsets: PhantomData,
+                     data: data })
+    }
+
+    /// Get an [Elf] for this `ElfFile`.
+    ///
+    /// # Errors
+    ///
+    /// Since the data can be modified with
+    /// [as_bytes_mut](ElfFile::as_bytes_mut), the ELF header is
+    /// checked again, and any error from the [TryFrom] instance for
+    /// [Elf] can occur.
+    #[inline]
+    pub fn elf(&self) -> Result<Elf<'_, B, Offsets>, ElfError> {
+        Elf::try_from(&self.data[0..])
+    }
+
+    /// Get an [ElfMut] for this `ElfFile`.
+    ///
+    /// # Errors
+    ///
+    /// Since the data can be modified with
+    /// [as_bytes_mut](ElfFile::as_bytes_mut), the ELF header is
+    /// checked again, and any error from the [TryFrom] instance for
+    /// [ElfMut] can occur.
+    #[inline]
+    pub fn elf_mut(&mut self) -> Result<ElfMut<'_, B, Offsets>, ElfError> {
+        ElfMut::try_from(&mut self.data[0..])
+    }
+
+    /// Get the length of the data in this `ElfFile`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Check whether the data in this `ElfFile` is empty.
+    ///
+    /// This is always `false` for an `ElfFile`, which contains at
+    /// least an ELF header.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get the underlying data in this `ElfFile`.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get the underlying data in this `ElfFile` for modification.
+    ///
+    /// This can be used to obtain mutable views such as
+    /// [SectionHdrsMut] on parts of the file.  If the ELF header is
+    /// modified so that it is no longer valid, [elf](ElfFile::elf)
+    /// and [elf_mut](ElfFile::elf_mut) will report an error.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Convert this `ElfFile` into its underlying data.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.data
+    }
+}
+
+impl<'a, B, Offsets> From<Elf<'a, B, Offsets>> for ElfFile<B, Offsets>
+    where Offsets: ElfHdrOffsets,
+          B: ElfByteOrder {
+    #[inline]
+    fn from(elf: Elf<'a, B, Offsets>) -> ElfFile<B, Offsets> {
+        ElfFile { byteorder: PhantomData, offsets: PhantomData,
+                  data: elf.data().to_vec() }
+    }
+}
+
+impl<B, Offsets> TryFrom<Vec<u8>> for ElfFile<B, Offsets>
+    where Offsets: ElfHdrOffsets,
+          B: ElfByteOrder {
+    type Error = ElfError;
+
+    /// Do some basic checks on the data to make sure it actually
+    /// contains an ELF header.
+    #[inline]
+    fn try_from(data: Vec<u8>) -> Result<ElfFile<B, Offsets>, Self::Error> {
+        Elf::<B, Offsets>::try_from(&data[0..])?;
+
+        Ok(ElfFile { byteorder: PhantomData, offsets: PhantomData,
+                     data: data })
+    }
+}
+
+impl<B, Offsets> From<ElfFile<B, Offsets>> for Vec<u8>
+    where Offsets: ElfHdrOffsets,
+          B: ElfByteOrder {
+    #[inline]
+    fn from(file: ElfFile<B, Offsets>) -> Vec<u8> {
+        file.data
+    }
+}
@@ -409,6 +409,62 @@ pub struct ProgHdr<'a, B: ByteOrder, Offsets: ProgHdrOffsets> {
     ent: &'a [u8]
 }
 
+/// In-place mutable ELF program header table.
+///
+/// This is the mutable counterpart to [ProgHdrs].  It can be created
+/// from raw data using the [TryFrom](core::convert::TryFrom)
+/// instance, and provides [ProgHdrMut]s through
+/// [idx_mut](ProgHdrsMut::idx_mut), which can be used to patch
+/// program header table entries in place.
+pub struct ProgHdrsMut<'a, B, Offsets: ProgHdrOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    hdrs: &'a mut [u8]
+}
+
+/// In-place mutable ELF program header table entry.
+///
+/// This is obtained from a [ProgHdrsMut] using
+/// [idx_mut](ProgHdrsMut::idx_mut).  Individual fields can be changed
+/// with the setter functions, or the entire entry can be replaced
+/// with [set](ProgHdrMut::set).  All values are written in the byte
+/// order of the underlying data.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::Elf32;
+/// use elf_utils::prog_hdr::ProgHdrData;
+/// use elf_utils::prog_hdr::ProgHdrDataRaw;
+/// use elf_utils::prog_hdr::ProgHdrsMut;
+/// use elf_utils::prog_hdr::Segment;
+///
+/// let mut buf: [u8; 32] = [
+///     0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0xbc, 0x46, 0x00, 0x00, 0xbc, 0x46, 0x00, 0x00,
+///     0x05, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00
+/// ];
+/// let mut hdrs: ProgHdrsMut<'_, LittleEndian, Elf32> =
+///     ProgHdrsMut::try_from(&mut buf[0..]).unwrap();
+/// let mut ent = hdrs.idx_mut(0).unwrap();
+///
+/// ent.set_exec(false);
+/// ent.set_write(true);
+///
+/// let data: ProgHdrDataRaw<Elf32> = ent.try_into().unwrap();
+///
+/// assert_eq!(data, ProgHdrData::Load { virt_addr: 0, phys_addr: 0,
+///                                      mem_size: 0x46bc, align: 0x1000,
+///                                      read: true, write: true, exec: false,
+///                                      content: Segment { offset: 0,
+///                                                         size: 0x46bc } });
+/// ```
 pub struct ProgHdrMut<'a, B: ByteOrder, Offsets: ProgHdrOffsets> {
     byteorder: PhantomData<B>,
     offsets: PhantomData<Offsets>,
@@ -593,6 +649,100 @@ pub enum ProgHdrData<Offsets: ElfClass, Data, Str, Dyn> {
         /// Content from the file.
         content: Data
     },
+    /// Thread-local storage template.
+    ///
+    /// The file content is the initialization image for the
+    /// thread-local data, and the memory size includes any
+    /// zero-initialized thread-local data following it.
+    Tls {
+        /// Runtime virtual address.
+        virt_addr: Offsets::Addr,
+        /// Runtime physical address.
+        phys_addr: Offsets::Addr,
+        /// Memory size (may be larger than the actual data).
+        mem_size: Offsets::Offset,
+        /// Memory alignment.
+        align: Offsets::Offset,
+        /// Whether the segment has read permissions.
+        read: bool,
+        /// Whether the segment has write permissions.
+        write: bool,
+        /// Whether the segment has execute permissions.
+        exec: bool,
+        /// Content from the file.
+        content: Data
+    },
+    /// Location of the exception handling frame header
+    /// (`.eh_frame_hdr`).
+    GnuEhFrame {
+        /// Runtime virtual address.
+        virt_addr: Offsets::Addr,
+        /// Runtime physical address.
+        phys_addr: Offsets::Addr,
+        /// Memory alignment.
+        align: Offsets::Offset,
+        /// Whether the segment has read permissions.
+        read: bool,
+        /// Whether the segment has write permissions.
+        write: bool,
+        /// Whether the segment has execute permissions.
+        exec: bool,
+        /// Content from the file.
+        content: Data
+    },
+    /// Permissions for the process stack.
+    ///
+    /// This has no content, and is used only to indicate whether the
+    /// stack should be executable.
+    GnuStack {
+        /// Requested stack size, or zero to use the default.
+        size: Offsets::Offset,
+        /// Memory alignment.
+        align: Offsets::Offset,
+        /// Whether the stack has read permissions.
+        read: bool,
+        /// Whether the stack has write permissions.
+        write: bool,
+        /// Whether the stack has execute permissions.
+        exec: bool
+    },
+    /// Region to be made read-only after relocation.
+    GnuRelro {
+        /// Runtime virtual address.
+        virt_addr: Offsets::Addr,
+        /// Runtime physical address.
+        phys_addr: Offsets::Addr,
+        /// Memory size (some linkers round this up to a page
+        /// boundary).
+        mem_size: Offsets::Offset,
+        /// Memory alignment.
+        align: Offsets::Offset,
+        /// Whether the segment has read permissions.
+        read: bool,
+        /// Whether the segment has write permissions.
+        write: bool,
+        /// Whether the segment has execute permissions.
+        exec: bool,
+        /// Content from the file.
+        content: Data
+    },
+    /// Location of the GNU program property note.
+    GnuProperty {
+        /// Runtime virtual address.
+        virt_addr: Offsets::Addr,
+        /// Runtime physical address.
+        phys_addr: Offsets::Addr,
+        /// Memory alignment.
+        align: Offsets::Offset,
+        /// Whether the segment has read permissions.
+        read: bool,
+        /// Whether the segment has write permissions.
+        write: bool,
+        /// Whether the segment has execute permissions.
+        exec: bool,
+        /// Content from the file.
+        content: Data
+    },
     /// Unknown program header type.
     Unknown {
         /// Type tag.
@@ -795,6 +945,136 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<ProgHdrDataRaw<Offsets>,
                                       phys_addr: phys_addr,
                                       content: pos })
         },
+        7 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::P_OFFSET_START .. Offsets::P_OFFSET_END]
+            );
+            let size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_FILE_SIZE_START .. Offsets::P_FILE_SIZE_END]
+            );
+            let virt_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_VADDR_START .. Offsets::P_VADDR_END]
+            );
+            let phys_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_PADDR_START .. Offsets::P_PADDR_END]
+            );
+            let mem_size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_MEM_SIZE_START .. Offsets::P_MEM_SIZE_END]
+            );
+            let align = Offsets::read_offset::<B>(&ent[Offsets::P_ALIGN_START ..
+                                                       Offsets::P_ALIGN_END]);
+            let flags = Offsets::read_word::<B>(&ent[Offsets::P_FLAGS_START ..
+                                                     Offsets::P_FLAGS_END]);
+            let pos = Segment { offset: offset, size: size };
+
+            Ok(ProgHdrData::Tls {
+                virt_addr: virt_addr, phys_addr: phys_addr,
+                mem_size: mem_size, align: align, content: pos,
+                read: flags & (0x4 as u8).into() == (0x4 as u8).into(),
+                write: flags & (0x2 as u8).into() == (0x2 as u8).into(),
+                exec: flags & (0x1 as u8).into() == (0x1 as u8).into()
+            })
+        },
+        0x6474e550 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::P_OFFSET_START .. Offsets::P_OFFSET_END]
+            );
+            let size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_FILE_SIZE_START .. Offsets::P_FILE_SIZE_END]
+            );
+            let virt_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_VADDR_START .. Offsets::P_VADDR_END]
+            );
+            let phys_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_PADDR_START .. Offsets::P_PADDR_END]
+            );
+            let align = Offsets::read_offset::<B>(&ent[Offsets::P_ALIGN_START ..
+                                                       Offsets::P_ALIGN_END]);
+            let flags = Offsets::read_word::<B>(&ent[Offsets::P_FLAGS_START ..
+                                                     Offsets::P_FLAGS_END]);
+            let pos = Segment { offset: offset, size: size };
+
+            Ok(ProgHdrData::GnuEhFrame {
+                virt_addr: virt_addr, phys_addr: phys_addr,
+                align: align, content: pos,
+                read: flags & (0x4 as u8).into() == (0x4 as u8).into(),
+                write: flags & (0x2 as u8).into() == (0x2 as u8).into(),
+                exec: flags & (0x1 as u8).into() == (0x1 as u8).into()
+            })
+        },
+        0x6474e551 => {
+            let size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_MEM_SIZE_START .. Offsets::P_MEM_SIZE_END]
+            );
+            let align = Offsets::read_offset::<B>(&ent[Offsets::P_ALIGN_START ..
+                                                       Offsets::P_ALIGN_END]);
+            let flags = Offsets::read_word::<B>(&ent[Offsets::P_FLAGS_START ..
+                                                     Offsets::P_FLAGS_END]);
+
+            Ok(ProgHdrData::GnuStack {
+                size: size, align: align,
+                read: flags & (0x4 as u8).into() == (0x4 as u8).into(),
+                write: flags & (0x2 as u8).into() == (0x2 as u8).into(),
+                exec: flags & (0x1 as u8).into() == (0x1 as u8).into()
+            })
+        },
+        0x6474e552 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::P_OFFSET_START .. Offsets::P_OFFSET_END]
+            );
+            let size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_FILE_SIZE_START .. Offsets::P_FILE_SIZE_END]
+            );
+            let virt_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_VADDR_START .. Offsets::P_VADDR_END]
+            );
+            let phys_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_PADDR_START .. Offsets::P_PADDR_END]
+            );
+            let mem_size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_MEM_SIZE_START .. Offsets::P_MEM_SIZE_END]
+            );
+            let align = Offsets::read_offset::<B>(&ent[Offsets::P_ALIGN_START ..
+                                                       Offsets::P_ALIGN_END]);
+            let flags = Offsets::read_word::<B>(&ent[Offsets::P_FLAGS_START ..
+                                                     Offsets::P_FLAGS_END]);
+            let pos = Segment { offset: offset, size: size };
+
+            Ok(ProgHdrData::GnuRelro {
+                virt_addr: virt_addr, phys_addr: phys_addr,
+                mem_size: mem_size, align: align, content: pos,
+                read: flags & (0x4 as u8).into() == (0x4 as u8).into(),
+                write: flags & (0x2 as u8).into() == (0x2 as u8).into(),
+                exec: flags & (0x1 as u8).into() == (0x1 as u8).into()
+            })
+        },
+        0x6474e553 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::P_OFFSET_START .. Offsets::P_OFFSET_END]
+            );
+            let size = Offsets::read_offset::<B>(
+                &ent[Offsets::P_FILE_SIZE_START .. Offsets::P_FILE_SIZE_END]
+            );
+            let virt_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_VADDR_START .. Offsets::P_VADDR_END]
+            );
+            let phys_addr = Offsets::read_addr::<B>(
+                &ent[Offsets::P_PADDR_START .. Offsets::P_PADDR_END]
+            );
+            let align = Offsets::read_offset::<B>(&ent[Offsets::P_ALIGN_START ..
+                                                       Offsets::P_ALIGN_END]);
+            let flags = Offsets::read_word::<B>(&ent[Offsets::P_FLAGS_START ..
+                                                     Offsets::P_FLAGS_END]);
+            let pos = Segment { offset: offset, size: size };
+
+            Ok(ProgHdrData::GnuProperty {
+                virt_addr: virt_addr, phys_addr: phys_addr,
+                align: align, content: pos,
+                read: flags & (0x4 as u8).into() == (0x4 as u8).into(),
+                write: flags & (0x2 as u8).into() == (0x2 as u8).into(),
+                exec: flags & (0x1 as u8).into() == (0x1 as u8).into()
+            })
+        },
         _ => {
             let offset = Offsets::read_offset::<B>(
                 &ent[Offsets::P_OFFSET_START .. Offsets::P_OFFSET_END]
@@ -825,6 +1105,494 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<ProgHdrDataRaw<Offsets>,
     }
 }
 
+#[inline]
+fn write<B, Offsets>(data: &mut [u8], ent: ProgHdrDataRaw<Offsets>)
+    where Offsets: ProgHdrOffsets,
+          B: ByteOrder {
+    match ent {
+        ProgHdrData::Null => {
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                (0 as u8).into()
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                (0 as u8).into()
+            );
+        },
+        ProgHdrData::Load { virt_addr, phys_addr, mem_size,
+                            align, read, write, exec, content } => {
+            let flags: u8 = if read { 0x4 } else { 0 } |
+                            if write { 0x2 } else { 0 } |
+                            if exec { 0x1 } else { 0 };
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (1 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                mem_size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        },
+        ProgHdrData::Dynamic { virt_addr, phys_addr, content } => {
+            let flags: u8 = 0x6;
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (2 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                Offsets::OFFSET_ALIGN
+            );
+        },
+        ProgHdrData::Interp { virt_addr, phys_addr, str } => {
+            let flags: u8 = 0x4;
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (3 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                str.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                str.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                str.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                (1 as u8).into()
+            );
+        },
+        ProgHdrData::Note { virt_addr, phys_addr, content } => {
+            let flags: u8 = 0x4;
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (4 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                Offsets::WORD_ALIGN
+            );
+        },
+        ProgHdrData::Shlib => {
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (5 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                (0 as u8).into()
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                (0 as u8).into()
+            );
+        },
+        ProgHdrData::ProgHdr { virt_addr, phys_addr, content } => {
+            let flags: u8 = 0x4;
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (6 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                Offsets::OFFSET_ALIGN
+            );
+        },
+        ProgHdrData::Tls { virt_addr, phys_addr, mem_size, align, read, write,
+                           exec, content } => {
+            let flags: u8 = if read { 0x4 } else { 0 } |
+                            if write { 0x2 } else { 0 } |
+                            if exec { 0x1 } else { 0 };
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (7 as u8).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                mem_size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        },
+        ProgHdrData::GnuEhFrame { virt_addr, phys_addr, align, read, write,
+                                  exec, content } => {
+            let flags: u8 = if read { 0x4 } else { 0 } |
+                            if write { 0x2 } else { 0 } |
+                            if exec { 0x1 } else { 0 };
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (0x6474e550 as u32).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        },
+        ProgHdrData::GnuStack { size, align, read, write, exec } => {
+            let flags: u8 = if read { 0x4 } else { 0 } |
+                            if write { 0x2 } else { 0 } |
+                            if exec { 0x1 } else { 0 };
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (0x6474e551 as u32).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                (0 as u8).into()
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        },
+        ProgHdrData::GnuRelro { virt_addr, phys_addr, mem_size, align, read,
+                                write, exec, content } => {
+            let flags: u8 = if read { 0x4 } else { 0 } |
+                            if write { 0x2 } else { 0 } |
+                            if exec { 0x1 } else { 0 };
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (0x6474e552 as u32).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                mem_size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        },
+        ProgHdrData::GnuProperty { virt_addr, phys_addr, align, read, write,
+                                   exec, content } => {
+            let flags: u8 = if read { 0x4 } else { 0 } |
+                            if write { 0x2 } else { 0 } |
+                            if exec { 0x1 } else { 0 };
+
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     (0x6474e553 as u32).into());
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags.into());
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                content.offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                content.size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        },
+        ProgHdrData::Unknown { tag, flags, offset, file_size, mem_size,
+                               phys_addr, virt_addr, align } => {
+            Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                     tag);
+            Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                     flags);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_OFFSET_START ..
+                          Offsets::P_OFFSET_END],
+                offset
+            );
+            Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                     virt_addr);
+            Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                     phys_addr);
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_FILE_SIZE_START ..
+                          Offsets::P_FILE_SIZE_END],
+                file_size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_MEM_SIZE_START ..
+                          Offsets::P_MEM_SIZE_END],
+                mem_size
+            );
+            Offsets::write_offset::<B>(
+                &mut data[Offsets::P_ALIGN_START ..
+                          Offsets::P_ALIGN_END],
+                align
+            );
+        }
+    }
+}
+
 fn create<'a, B, I, Offsets>(buf: &'a mut [u8], ents: I) ->
     Result<(&'a mut [u8], &'a mut [u8]), ()>
     where I: Iterator<Item = ProgHdrDataRaw<Offsets>>,
@@ -837,294 +1605,7 @@ fn create<'a, B, I, Offsets>(buf: &'a mut [u8], ents: I) ->
         if idx + Offsets::PROG_HDR_SIZE <= len {
             let data = &mut buf[idx .. idx + Offsets::PROG_HDR_SIZE];
 
-            match ent {
-                ProgHdrData::Null => {
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             (0 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        (0 as u8).into()
-                    );
-                },
-                ProgHdrData::Load { virt_addr, phys_addr, mem_size,
-                                    align, read, write, exec, content } => {
-                    let flags: u8 = if read { 0x4 } else { 0 } |
-                                    if write { 0x2 } else { 0 } |
-                                    if exec { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (1 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             flags.into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        content.offset
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             virt_addr);
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             phys_addr);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        mem_size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        align
-                    );
-                },
-                ProgHdrData::Dynamic { virt_addr, phys_addr, content } => {
-                    let flags: u8 = 0x6;
-
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (2 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             flags.into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        content.offset
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             virt_addr);
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             phys_addr);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        Offsets::OFFSET_ALIGN
-                    );
-                },
-                ProgHdrData::Interp { virt_addr, phys_addr, str } => {
-                    let flags: u8 = 0x4;
-
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (3 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             flags.into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        str.offset
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             virt_addr);
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             phys_addr);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        str.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        str.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        (1 as u8).into()
-                    );
-                },
-                ProgHdrData::Note { virt_addr, phys_addr, content } => {
-                    let flags: u8 = 0x4;
-
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (4 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             flags.into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        content.offset
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             virt_addr);
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             phys_addr);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        Offsets::WORD_ALIGN
-                    );
-                },
-                ProgHdrData::Shlib => {
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (5 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             (0 as u8).into());
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        (0 as u8).into()
-                    );
-                },
-                ProgHdrData::ProgHdr { virt_addr, phys_addr, content } => {
-                    let flags: u8 = 0x4;
-
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             (6 as u8).into());
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             flags.into());
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        content.offset
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             virt_addr);
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             phys_addr);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        content.size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        Offsets::OFFSET_ALIGN
-                    );
-                },
-                ProgHdrData::Unknown { tag, flags, offset, file_size, mem_size,
-                                       phys_addr, virt_addr, align } => {
-                    Offsets::write_word::<B>(&mut data[Offsets::P_KIND_START ..
-                                                       Offsets::P_KIND_END],
-                                             tag);
-                    Offsets::write_word::<B>(&mut data[Offsets::P_FLAGS_START ..
-                                                       Offsets::P_FLAGS_END],
-                                             flags);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_OFFSET_START ..
-                                  Offsets::P_OFFSET_END],
-                        offset
-                    );
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_VADDR_START ..
-                                                       Offsets::P_VADDR_END],
-                                             virt_addr);
-                    Offsets::write_addr::<B>(&mut data[Offsets::P_PADDR_START ..
-                                                       Offsets::P_PADDR_END],
-                                             phys_addr);
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_FILE_SIZE_START ..
-                                  Offsets::P_FILE_SIZE_END],
-                        file_size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_MEM_SIZE_START ..
-                                  Offsets::P_MEM_SIZE_END],
-                        mem_size
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut data[Offsets::P_ALIGN_START ..
-                                  Offsets::P_ALIGN_END],
-                        align
-                    );
-                }
-            }
+            write::<B, Offsets>(data, ent);
 
             idx += Offsets::PROG_HDR_SIZE;
         } else {
@@ -1413,6 +1894,158 @@ impl<'a, B, Offsets: ProgHdrOffsets> ProgHdrs<'a, B, Offsets>
     }
 }
 
+impl<'a, B, Offsets: ProgHdrOffsets> ProgHdrsMut<'a, B, Offsets>
+    where B: ByteOrder {
+    /// Get a [ProgHdr] for the program header table entry at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<ProgHdr<'_, B, Offsets>> {
+        let len = self.hdrs.len();
+        let start = idx * Offsets::PROG_HDR_SIZE;
+
+        if start < len {
+            let end = start + Offsets::PROG_HDR_SIZE;
+
+            Some(ProgHdr { byteorder: PhantomData, offsets: PhantomData,
+                           ent: &self.hdrs[start .. end ] })
+        } else {
+            None
+        }
+    }
+
+    /// Get a [ProgHdrMut] for the program header table entry at
+    /// `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx_mut(&mut self, idx: usize) ->
+        Option<ProgHdrMut<'_, B, Offsets>> {
+        let len = self.hdrs.len();
+        let start = idx * Offsets::PROG_HDR_SIZE;
+
+        if start < len {
+            let end = start + Offsets::PROG_HDR_SIZE;
+
+            Some(ProgHdrMut { byteorder: PhantomData, offsets: PhantomData,
+                              ent: &mut self.hdrs[start .. end ] })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of program header table entries.
+    #[inline]
+    pub fn num_hdrs(&self) -> usize {
+        self.hdrs.len() / Offsets::PROG_HDR_SIZE
+    }
+}
+
+impl<'a, B, Offsets: ProgHdrOffsets> ProgHdrMut<'a, B, Offsets>
+    where B: ByteOrder {
+    /// Replace this entry with `data`.
+    #[inline]
+    pub fn set(&mut self, data: ProgHdrDataRaw<Offsets>) {
+        write::<B, Offsets>(self.ent, data)
+    }
+
+    /// Set the type tag (`p_type`) for this entry to `kind`.
+    #[inline]
+    pub fn set_kind(&mut self, kind: Offsets::Word) {
+        Offsets::write_word::<B>(&mut self.ent[Offsets::P_KIND_START ..
+                                               Offsets::P_KIND_END],
+                                 kind)
+    }
+
+    /// Set the offset of the segment in the file to `offset`.
+    #[inline]
+    pub fn set_offset(&mut self, offset: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::P_OFFSET_START ..
+                                                 Offsets::P_OFFSET_END],
+                                   offset)
+    }
+
+    /// Set the virtual address of the segment to `addr`.
+    #[inline]
+    pub fn set_virt_addr(&mut self, addr: Offsets::Addr) {
+        Offsets::write_addr::<B>(&mut self.ent[Offsets::P_VADDR_START ..
+                                               Offsets::P_VADDR_END],
+                                 addr)
+    }
+
+    /// Set the physical address of the segment to `addr`.
+    #[inline]
+    pub fn set_phys_addr(&mut self, addr: Offsets::Addr) {
+        Offsets::write_addr::<B>(&mut self.ent[Offsets::P_PADDR_START ..
+                                               Offsets::P_PADDR_END],
+                                 addr)
+    }
+
+    /// Set the size of the segment in the file to `size`.
+    #[inline]
+    pub fn set_file_size(&mut self, size: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::P_FILE_SIZE_START ..
+                                                 Offsets::P_FILE_SIZE_END],
+                                   size)
+    }
+
+    /// Set the size of the segment in memory to `size`.
+    #[inline]
+    pub fn set_mem_size(&mut self, size: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::P_MEM_SIZE_START ..
+                                                 Offsets::P_MEM_SIZE_END],
+                                   size)
+    }
+
+    /// Set the alignment of the segment to `align`.
+    #[inline]
+    pub fn set_align(&mut self, align: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::P_ALIGN_START ..
+                                                 Offsets::P_ALIGN_END],
+                                   align)
+    }
+
+    /// Set the raw flags (`p_flags`) for this entry to `flags`.
+    #[inline]
+    pub fn set_flags(&mut self, flags: Offsets::Word) {
+        Offsets::write_word::<B>(&mut self.ent[Offsets::P_FLAGS_START ..
+                                               Offsets::P_FLAGS_END],
+                                 flags)
+    }
+
+    /// Set whether the segment is readable.
+    #[inline]
+    pub fn set_read(&mut self, read: bool) {
+        self.set_flag(0x4, read)
+    }
+
+    /// Set whether the segment is writable.
+    #[inline]
+    pub fn set_write(&mut self, write: bool) {
+        self.set_flag(0x2, write)
+    }
+
+    /// Set whether the segment is executable.
+    #[inline]
+    pub fn set_exec(&mut self, exec: bool) {
+        self.set_flag(0x1, exec)
+    }
+
+    #[inline]
+    fn set_flag(&mut self, mask: u32, value: bool) {
+        let flags: u32 = Offsets::read_word::<B>(
+            &self.ent[Offsets::P_FLAGS_START .. Offsets::P_FLAGS_END]
+        ).into();
+        let flags = if value { flags | mask } else { flags & !mask };
+
+        self.set_flags(flags.into())
+    }
+}
+
 impl<'a, Offsets> WithElfData<'a>
     for ProgHdrDataRaw<Offsets>
     where Offsets: ProgHdrOffsets {
@@ -1428,7 +2061,9 @@ impl<'a, Offsets> WithElfData<'a>
                                 virt_addr, phys_addr, mem_size,
                                 align, read, write, exec } => {
                 match (offset.try_into(), size.try_into()) {
-                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
                         Ok(ProgHdrData::Load {
                             virt_addr: virt_addr, phys_addr: phys_addr,
                             mem_size: mem_size, align: align, read: read,
@@ -1442,7 +2077,9 @@ impl<'a, Offsets> WithElfData<'a>
             ProgHdrData::Dynamic { content: Segment { offset, size },
                                    virt_addr, phys_addr } => {
                 match (offset.try_into(), size.try_into()) {
-                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
                         Ok(ProgHdrData::Dynamic {
                             virt_addr: virt_addr, phys_addr: phys_addr,
                             content: &data[offset .. offset + size]
@@ -1454,7 +2091,9 @@ impl<'a, Offsets> WithElfData<'a>
             ProgHdrData::Interp { str: Segment { offset, size },
                                   virt_addr, phys_addr } => {
                 match (offset.try_into(), size.try_into()) {
-                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
                         Ok(ProgHdrData::Interp {
                             virt_addr: virt_addr, phys_addr: phys_addr,
                             str: &data[offset .. offset + size]
@@ -1466,7 +2105,9 @@ impl<'a, Offsets> WithElfData<'a>
             ProgHdrData::Note { content: Segment { offset, size },
                                 virt_addr, phys_addr } => {
                 match (offset.try_into(), size.try_into()) {
-                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
                         Ok(ProgHdrData::Note {
                             virt_addr: virt_addr, phys_addr: phys_addr,
                             content: &data[offset .. offset + size]
@@ -1479,7 +2120,9 @@ impl<'a, Offsets> WithElfData<'a>
             ProgHdrData::ProgHdr { content: Segment { offset, size },
                                    virt_addr, phys_addr } => {
                 match (offset.try_into(), size.try_into()) {
-                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
                         Ok(ProgHdrData::ProgHdr {
                             virt_addr: virt_addr, phys_addr: phys_addr,
                             content: &data[offset .. offset + size]
@@ -1488,6 +2131,78 @@ impl<'a, Offsets> WithElfData<'a>
                                                              size: size })
                 }
             },
+            ProgHdrData::Tls { content: Segment { offset, size },
+                               virt_addr, phys_addr, mem_size, align, read,
+                               write, exec } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
+                        Ok(ProgHdrData::Tls {
+                            virt_addr: virt_addr, phys_addr: phys_addr,
+                            mem_size: mem_size, align: align, read: read,
+                            write: write, exec: exec,
+                            content: &data[offset .. offset + size]
+                        }),
+                    _ => Err(ProgHdrError::DataOutOfBounds { offset: offset,
+                                                             size: size })
+                }
+            },
+            ProgHdrData::GnuEhFrame { content: Segment { offset, size },
+                                      virt_addr, phys_addr, align, read,
+                                      write, exec } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
+                        Ok(ProgHdrData::GnuEhFrame {
+                            virt_addr: virt_addr, phys_addr: phys_addr,
+                            align: align, read: read, write: write,
+                            exec: exec,
+                            content: &data[offset .. offset + size]
+                        }),
+                    _ => Err(ProgHdrError::DataOutOfBounds { offset: offset,
+                                                             size: size })
+                }
+            },
+            ProgHdrData::GnuStack { size, align, read, write, exec } =>
+                Ok(ProgHdrData::GnuStack { size: size, align: align,
+                                           read: read, write: write,
+                                           exec: exec }),
+            ProgHdrData::GnuRelro { content: Segment { offset, size },
+                                    virt_addr, phys_addr, mem_size, align,
+                                    read, write, exec } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
+                        Ok(ProgHdrData::GnuRelro {
+                            virt_addr: virt_addr, phys_addr: phys_addr,
+                            mem_size: mem_size, align: align, read: read,
+                            write: write, exec: exec,
+                            content: &data[offset .. offset + size]
+                        }),
+                    _ => Err(ProgHdrError::DataOutOfBounds { offset: offset,
+                                                             size: size })
+                }
+            },
+            ProgHdrData::GnuProperty { content: Segment { offset, size },
+                                       virt_addr, phys_addr, align, read,
+                                       write, exec } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size))
+                        if matches!(offset.checked_add(size),
+                                    Some(end) if end <= data.len()) =>
+                        Ok(ProgHdrData::GnuProperty {
+                            virt_addr: virt_addr, phys_addr: phys_addr,
+                            align: align, read: read, write: write,
+                            exec: exec,
+                            content: &data[offset .. offset + size]
+                        }),
+                    _ => Err(ProgHdrError::DataOutOfBounds { offset: offset,
+                                                             size: size })
+                }
+            },
             ProgHdrData::Unknown { tag, flags, offset, file_size, mem_size,
                                    phys_addr, virt_addr, align } => {
                 Ok(ProgHdrData::Unknown {
@@ -1539,6 +2254,25 @@ impl<'a, B, Offsets> TryFrom<&'a mut [u8]> for ProgHdrs<'a, B, Offsets>
     }
 }
 
+impl<'a, B, Offsets> TryFrom<&'a mut [u8]> for ProgHdrsMut<'a, B, Offsets>
+    where Offsets: ProgHdrOffsets,
+          B: ByteOrder {
+    type Error = ProgHdrsError;
+
+    #[inline]
+    fn try_from(hdrs: &'a mut [u8]) -> Result<ProgHdrsMut<'a, B, Offsets>,
+                                              ProgHdrsError> {
+        let len = hdrs.len();
+
+        if hdrs.len() % Offsets::PROG_HDR_SIZE == 0 {
+            Ok(ProgHdrsMut { byteorder: PhantomData, offsets: PhantomData,
+                             hdrs: hdrs })
+        } else {
+            Err(ProgHdrsError::BadSize(len))
+        }
+    }
+}
+
 impl<'a, B, Offsets> TryFrom<ProgHdr<'a, B, Offsets>>
     for ProgHdrDataRaw<Offsets>
     where Offsets: ProgHdrOffsets,
@@ -1598,6 +2332,25 @@ impl<'a, B, Offsets, Data, Str> TryFrom<ProgHdrData<Offsets, Data,
             ProgHdrData::Shlib => Ok(ProgHdrData::Shlib),
             ProgHdrData::ProgHdr { content, virt_addr, phys_addr } =>
                 Ok(ProgHdrData::ProgHdr { content, virt_addr, phys_addr }),
+            ProgHdrData::Tls { content, virt_addr, phys_addr, mem_size,
+                               align, read, write, exec } =>
+                Ok(ProgHdrData::Tls { content, virt_addr, phys_addr, mem_size,
+                                      align, read, write, exec }),
+            ProgHdrData::GnuEhFrame { content, virt_addr, phys_addr, align,
+                                      read, write, exec } =>
+                Ok(ProgHdrData::GnuEhFrame { content, virt_addr, phys_addr,
+                                             align, read, write, exec }),
+            ProgHdrData::GnuStack { size, align, read, write, exec } =>
+                Ok(ProgHdrData::GnuStack { size, align, read, write, exec }),
+            ProgHdrData::GnuRelro { content, virt_addr, phys_addr, mem_size,
+                                    align, read, write, exec } =>
+                Ok(ProgHdrData::GnuRelro { content, virt_addr, phys_addr,
+                                           mem_size, align, read, write,
+                                           exec }),
+            ProgHdrData::GnuProperty { content, virt_addr, phys_addr, align,
+                                       read, write, exec } =>
+                Ok(ProgHdrData::GnuProperty { content, virt_addr, phys_addr,
+                                              align, read, write, exec }),
             ProgHdrData::Unknown { tag, flags, offset, file_size, mem_size,
                                    phys_addr, virt_addr, align } =>
                 Ok(ProgHdrData::Unknown { tag, flags, offset, file_size,
@@ -1635,6 +2388,25 @@ impl<'a, Offsets, Data, Dyn> TryFrom<ProgHdrData<Offsets, Data, &'a [u8], Dyn>>
             ProgHdrData::Shlib => Ok(ProgHdrData::Shlib),
             ProgHdrData::ProgHdr { content, virt_addr, phys_addr } =>
                 Ok(ProgHdrData::ProgHdr { content, virt_addr, phys_addr }),
+            ProgHdrData::Tls { content, virt_addr, phys_addr, mem_size,
+                               align, read, write, exec } =>
+                Ok(ProgHdrData::Tls { content, virt_addr, phys_addr, mem_size,
+                                      align, read, write, exec }),
+            ProgHdrData::GnuEhFrame { content, virt_addr, phys_addr, align,
+                                      read, write, exec } =>
+                Ok(ProgHdrData::GnuEhFrame { content, virt_addr, phys_addr,
+                                             align, read, write, exec }),
+            ProgHdrData::GnuStack { size, align, read, write, exec } =>
+                Ok(ProgHdrData::GnuStack { size, align, read, write, exec }),
+            ProgHdrData::GnuRelro { content, virt_addr, phys_addr, mem_size,
+                                    align, read, write, exec } =>
+                Ok(ProgHdrData::GnuRelro { content, virt_addr, phys_addr,
+                                           mem_size, align, read, write,
+                                           exec }),
+            ProgHdrData::GnuProperty { content, virt_addr, phys_addr, align,
+                                       read, write, exec } =>
+                Ok(ProgHdrData::GnuProperty { content, virt_addr, phys_addr,
+                                              align, read, write, exec }),
             ProgHdrData::Unknown { tag, flags, offset, file_size, mem_size,
                                    phys_addr, virt_addr, align } =>
                 Ok(ProgHdrData::Unknown { tag, flags, offset, file_size,
@@ -1751,6 +2523,64 @@ impl<Offsets, Data, Str, Dyn> Display for ProgHdrData<Offsets, Data, Str, Dyn>
                                   "    Physical address: 0x{:x}\n",
                                   "    Content: {}"),
                        virt_addr, phys_addr, content),
+            ProgHdrData::Tls { content, virt_addr, phys_addr, mem_size,
+                               align, read, write, exec } =>
+                write!(f, concat!("  Thread-local storage\n",
+                                  "    Virtual address: 0x{:x}\n",
+                                  "    Physical address: 0x{:x}\n",
+                                  "    Memory size: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Readable: {}\n",
+                                  "    Writable: {}\n",
+                                  "    Executable: {}\n",
+                                  "    Content: {}"),
+                       virt_addr, phys_addr, mem_size, align,
+                       read, write, exec, content),
+            ProgHdrData::GnuEhFrame { content, virt_addr, phys_addr, align,
+                                      read, write, exec } =>
+                write!(f, concat!("  Exception handling frame header\n",
+                                  "    Virtual address: 0x{:x}\n",
+                                  "    Physical address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Readable: {}\n",
+                                  "    Writable: {}\n",
+                                  "    Executable: {}\n",
+                                  "    Content: {}"),
+                       virt_addr, phys_addr, align, read, write, exec,
+                       content),
+            ProgHdrData::GnuStack { size, align, read, write, exec } =>
+                write!(f, concat!("  Stack\n",
+                                  "    Size: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Readable: {}\n",
+                                  "    Writable: {}\n",
+                                  "    Executable: {}"),
+                       size, align, read, write, exec),
+            ProgHdrData::GnuRelro { content, virt_addr, phys_addr, mem_size,
+                                    align, read, write, exec } =>
+                write!(f, concat!("  Read-only after relocation\n",
+                                  "    Virtual address: 0x{:x}\n",
+                                  "    Physical address: 0x{:x}\n",
+                                  "    Memory size: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Readable: {}\n",
+                                  "    Writable: {}\n",
+                                  "    Executable: {}\n",
+                                  "    Content: {}"),
+                       virt_addr, phys_addr, mem_size, align,
+                       read, write, exec, content),
+            ProgHdrData::GnuProperty { content, virt_addr, phys_addr, align,
+                                       read, write, exec } =>
+                write!(f, concat!("  Program properties\n",
+                                  "    Virtual address: 0x{:x}\n",
+                                  "    Physical address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Readable: {}\n",
+                                  "    Writable: {}\n",
+                                  "    Executable: {}\n",
+                                  "    Content: {}"),
+                       virt_addr, phys_addr, align, read, write, exec,
+                       content),
             ProgHdrData::Unknown { tag, flags, offset, file_size, mem_size,
                                    phys_addr, virt_addr, align } =>
                 write!(f, concat!("  Unknown type 0x{:x}\n",
@@ -1776,3 +2606,16 @@ impl<Offsets, Data, Str, Dyn> Display for ProgHdrData<Offsets, Data, Str, Dyn>
         }
     }
 }
+
+impl<Offsets> Display for ProgHdrError<Offsets>
+    where Offsets: ProgHdrOffsets {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ProgHdrError::DataOutOfBounds { offset, size } =>
+                write!(f, "segment data at 0x{:x} of size 0x{:x} out of bounds",
+                       offset, size),
+            ProgHdrError::EntryOutOfBounds { idx } =>
+                write!(f, "program header index {} out of bounds", idx)
+        }
+    }
+}
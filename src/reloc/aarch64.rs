@@ -0,0 +1,9127 @@
+//! Relocation types for 64-bit ARM architecture.
+//!
+//! This module provides the `AArch64Reloc` type, which describes the
+//! relocation entries for the AArch64 (aka. ARM64) architecture.
+//! These can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
+//! the [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for 64-bit ARM architectures (aka. AArch64,
+/// ARM64).
+///
+/// This datatype provides a semantic-level presentation of the
+/// AArch64 relocation entries, covering the static, Global Offset
+/// Table, thread-local storage, and dynamic relocations defined by
+/// the AArch64 ELF ABI.  These can be converted to and from
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
+/// the [ElfClass](crate::ElfClass) type argument using the
+/// [TryFrom](core::convert::TryFrom) instances for easier handling.
+///
+/// The ABI defines relocations in terms of explicit addends, so these
+/// will generally be found in [Relas](crate::reloc::Relas).  Note
+/// that AArch64 is bi-endian; the
+/// [WithSymtab](crate::symtab::WithSymtab) instance works with any
+/// byte order.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum AArch64Reloc<Name> {
+    /// Null relocation.
+    None,
+    /// 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`.
+    Abs64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Abs32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset` to `sym + addend`.
+    Abs16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the offset or address
+    /// of the target word from `sym + addend`).
+    PRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the offset or address
+    /// of the target word from `sym + addend`).
+    PRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative offset.
+    ///
+    /// Set the 2-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the offset or address
+    /// of the target word from `sym + addend`).
+    PRel16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 0-15.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of `sym + addend`, checking for
+    /// overflow.
+    MovWUAbsG0 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of `sym + addend`, without checking for
+    /// overflow.
+    MovWUAbsG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 16-31 of `sym + addend`, checking for
+    /// overflow.
+    MovWUAbsG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 16-31, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 16-31 of `sym + addend`, without checking for
+    /// overflow.
+    MovWUAbsG1NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 32-47.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 32-47 of `sym + addend`, checking for
+    /// overflow.
+    MovWUAbsG2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 32-47, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 32-47 of `sym + addend`, without checking for
+    /// overflow.
+    MovWUAbsG2NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unsigned absolute MOVW, bits 48-63.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 48-63 of `sym + addend`, checking for
+    /// overflow.
+    MovWUAbsG3 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Signed absolute MOVW, bits 0-15.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 0-15 of `sym + addend`, checking for
+    /// overflow.  The instruction becomes a MOVN or MOVZ depending on
+    /// the sign of the value.
+    MovWSAbsG0 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Signed absolute MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of `sym + addend`, checking for
+    /// overflow.  The instruction becomes a MOVN or MOVZ depending on
+    /// the sign of the value.
+    MovWSAbsG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Signed absolute MOVW, bits 32-47.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 32-47 of `sym + addend`, checking for
+    /// overflow.  The instruction becomes a MOVN or MOVZ depending on
+    /// the sign of the value.
+    MovWSAbsG2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative load literal offset.
+    ///
+    /// Set the 19-bit literal field of the LDR instruction at
+    /// `offset` to bits 2-20 of the relative address of
+    /// `sym + addend`.
+    LdPRelLo19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 21-bit PC-relative ADR offset.
+    ///
+    /// Set the immediate field of the ADR instruction at `offset` to
+    /// the relative address of `sym + addend`.
+    AdrPRelLo21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative ADRP page offset.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing
+    /// `sym + addend` and the target instruction, checking for
+    /// overflow.
+    AdrPRelPgHi21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative ADRP page offset, no overflow check.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing
+    /// `sym + addend` and the target instruction, without checking
+    /// for overflow.
+    AdrPRelPgHi21NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADD absolute low 12 bits.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of `sym + addend`.
+    AddAbsLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit load/store offset for `sym + addend`, low 12 bits, no
+    /// overflow check.
+    ///
+    /// Set the offset field of the 8-bit load or store instruction at
+    /// `offset` to bits 0-11 of `sym + addend`, without checking for
+    /// overflow.
+    LdSt8AbsNC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 14-bit PC-relative test and branch offset.
+    ///
+    /// Set the offset field of the TBZ or TBNZ instruction at
+    /// `offset` to bits 2-15 of the relative address of
+    /// `sym + addend`.
+    TstBr14 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative conditional branch offset.
+    ///
+    /// Set the offset field of the conditional branch instruction at
+    /// `offset` to bits 2-20 of the relative address of
+    /// `sym + addend`.
+    CondBr19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 26-bit PC-relative jump offset.
+    ///
+    /// Set the offset field of the B instruction at `offset` to bits
+    /// 2-27 of the relative address of `sym + addend`.
+    Jump26 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 26-bit PC-relative call offset.
+    ///
+    /// Set the offset field of the BL instruction at `offset` to bits
+    /// 2-27 of the relative address of `sym + addend`.
+    Call26 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit load/store offset for `sym + addend`, low 12 bits, no
+    /// overflow check.
+    ///
+    /// Set the offset field of the 16-bit load or store instruction
+    /// at `offset` to bits 1-11 of `sym + addend`, without checking
+    /// for overflow.
+    LdSt16AbsNC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit load/store offset for `sym + addend`, low 12 bits, no
+    /// overflow check.
+    ///
+    /// Set the offset field of the 32-bit load or store instruction
+    /// at `offset` to bits 2-11 of `sym + addend`, without checking
+    /// for overflow.
+    LdSt32AbsNC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit load/store offset for `sym + addend`, low 12 bits, no
+    /// overflow check.
+    ///
+    /// Set the offset field of the 64-bit load or store instruction
+    /// at `offset` to bits 3-11 of `sym + addend`, without checking
+    /// for overflow.
+    LdSt64AbsNC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 0-15.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 0-15 of the relative address of
+    /// `sym + addend`, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    MovWPRelG0 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the relative address of
+    /// `sym + addend`, without checking for overflow.
+    MovWPRelG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the relative address of
+    /// `sym + addend`, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    MovWPRelG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 16-31, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 16-31 of the relative address of
+    /// `sym + addend`, without checking for overflow.
+    MovWPRelG1NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 32-47.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 32-47 of the relative address of
+    /// `sym + addend`, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    MovWPRelG2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 32-47, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 32-47 of the relative address of
+    /// `sym + addend`, without checking for overflow.
+    MovWPRelG2NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative MOVW, bits 48-63.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 48-63 of the relative address of
+    /// `sym + addend`, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    MovWPRelG3 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 128-bit load/store offset for `sym + addend`, low 12 bits, no
+    /// overflow check.
+    ///
+    /// Set the offset field of the 128-bit load or store instruction
+    /// at `offset` to bits 4-11 of `sym + addend`, without checking
+    /// for overflow.
+    LdSt128AbsNC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 0-15.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 0-15 of the offset of the Global Offset Table
+    /// entry for `sym + addend` from the Global Offset Table,
+    /// checking for overflow.  The instruction becomes a MOVN or MOVZ
+    /// depending on the sign of the value.
+    MovWGOTOffG0 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of the Global Offset Table
+    /// entry for `sym + addend` from the Global Offset Table, without
+    /// checking for overflow.
+    MovWGOTOffG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of the Global Offset
+    /// Table entry for `sym + addend` from the Global Offset Table,
+    /// checking for overflow.  The instruction becomes a MOVN or MOVZ
+    /// depending on the sign of the value.
+    MovWGOTOffG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 16-31, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 16-31 of the offset of the Global Offset
+    /// Table entry for `sym + addend` from the Global Offset Table,
+    /// without checking for overflow.
+    MovWGOTOffG1NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 32-47.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 32-47 of the offset of the Global Offset
+    /// Table entry for `sym + addend` from the Global Offset Table,
+    /// checking for overflow.  The instruction becomes a MOVN or MOVZ
+    /// depending on the sign of the value.
+    MovWGOTOffG2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 32-47, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 32-47 of the offset of the Global Offset
+    /// Table entry for `sym + addend` from the Global Offset Table,
+    /// without checking for overflow.
+    MovWGOTOffG2NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GOT-relative MOVW, bits 48-63.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 48-63 of the offset of the Global Offset
+    /// Table entry for `sym + addend` from the Global Offset Table,
+    /// checking for overflow.  The instruction becomes a MOVN or MOVZ
+    /// depending on the sign of the value.
+    MovWGOTOffG3 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit offset from the Global Offset Table.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of
+    /// `sym + addend` from the Global Offset Table.
+    GOTRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit offset from the Global Offset Table.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` from the Global Offset Table.
+    GOTRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative load of a Global Offset Table entry.
+    ///
+    /// Set the 19-bit literal field of the LDR instruction at
+    /// `offset` to bits 2-20 of the relative address of the Global
+    /// Offset Table entry for `sym + addend`.
+    GOTLdPRel19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Global Offset Table entry offset for a 64-bit load.
+    ///
+    /// Set the offset field of the 64-bit LDR instruction at `offset`
+    /// to bits 3-14 of the offset of the Global Offset Table entry
+    /// for `sym + addend` from the Global Offset Table.
+    Ld64GOTOffLo15 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADRP page offset of a Global Offset Table entry.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing the Global
+    /// Offset Table entry for `sym + addend` and the target
+    /// instruction.
+    AdrGOTPage {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a Global Offset Table entry for a 64-bit load.
+    ///
+    /// Set the offset field of the 64-bit LDR instruction at `offset`
+    /// to bits 3-11 of the address of the Global Offset Table entry
+    /// for `sym + addend`, without checking for overflow.
+    Ld64GOTLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Global Offset Table entry page offset for a 64-bit load.
+    ///
+    /// Set the offset field of the 64-bit LDR instruction at `offset`
+    /// to bits 3-14 of the offset of the Global Offset Table entry
+    /// for `sym + addend` from the page containing the Global Offset
+    /// Table.
+    Ld64GOTPageLo15 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset to a Procedure Linkage Table entry.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend`, which may be resolved to a Procedure Linkage
+    /// Table entry.
+    PLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADR offset to the general dynamic `tls_index` entry.
+    ///
+    /// Set the immediate field of the ADR instruction at `offset` to
+    /// the relative address of the Global Offset Table entry for the
+    /// general dynamic `tls_index` of `sym + addend`.
+    TLSGDAdrPRel21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADRP page offset of the general dynamic `tls_index` entry.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing the Global
+    /// Offset Table entry for the general dynamic `tls_index` of
+    /// `sym + addend` and the target instruction.
+    TLSGDAdrPage21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of the general dynamic `tls_index` entry.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the address of the Global Offset Table
+    /// entry for the general dynamic `tls_index` of `sym + addend`.
+    TLSGDAddLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// General dynamic `tls_index` MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of the Global Offset
+    /// Table entry for the general dynamic `tls_index` of
+    /// `sym + addend` from the Global Offset Table, checking for
+    /// overflow.  The instruction becomes a MOVN or MOVZ depending on
+    /// the sign of the value.
+    TLSGDMovWG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// General dynamic `tls_index` MOVW, bits 0-15, no overflow
+    /// check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of the Global Offset Table
+    /// entry for the general dynamic `tls_index` of `sym + addend`
+    /// from the Global Offset Table, without checking for overflow.
+    TLSGDMovWG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADR offset to the local dynamic `tls_index` entry.
+    ///
+    /// Set the immediate field of the ADR instruction at `offset` to
+    /// the relative address of the Global Offset Table entry for the
+    /// local dynamic `tls_index` of `sym + addend`.
+    TLSLDAdrPRel21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADRP page offset of the local dynamic `tls_index` entry.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing the Global
+    /// Offset Table entry for the local dynamic `tls_index` of
+    /// `sym + addend` and the target instruction.
+    TLSLDAdrPage21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of the local dynamic `tls_index` entry.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the address of the Global Offset Table
+    /// entry for the local dynamic `tls_index` of `sym + addend`.
+    TLSLDAddLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic `tls_index` MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of the Global Offset
+    /// Table entry for the local dynamic `tls_index` of
+    /// `sym + addend` from the Global Offset Table, checking for
+    /// overflow.  The instruction becomes a MOVN or MOVZ depending on
+    /// the sign of the value.
+    TLSLDMovWG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic `tls_index` MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of the Global Offset Table
+    /// entry for the local dynamic `tls_index` of `sym + addend` from
+    /// the Global Offset Table, without checking for overflow.
+    TLSLDMovWG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative load of the local dynamic `tls_index`
+    /// entry.
+    ///
+    /// Set the 19-bit literal field of the LDR instruction at
+    /// `offset` to bits 2-20 of the relative address of the Global
+    /// Offset Table entry for the local dynamic `tls_index` of
+    /// `sym + addend`.
+    TLSLDLdPRel19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local block offset MOVW, bits 32-47.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 32-47 of the offset of `sym + addend` in its
+    /// thread-local storage block, checking for overflow.  The
+    /// instruction becomes a MOVN or MOVZ depending on the sign of
+    /// the value.
+    TLSLDMovWDTPRelG2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local block offset MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of `sym + addend` in its
+    /// thread-local storage block, checking for overflow.  The
+    /// instruction becomes a MOVN or MOVZ depending on the sign of
+    /// the value.
+    TLSLDMovWDTPRelG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local block offset MOVW, bits 16-31, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 16-31 of the offset of `sym + addend` in its
+    /// thread-local storage block, without checking for overflow.
+    TLSLDMovWDTPRelG1NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local block offset MOVW, bits 0-15.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 0-15 of the offset of `sym + addend` in its
+    /// thread-local storage block, checking for overflow.  The
+    /// instruction becomes a MOVN or MOVZ depending on the sign of
+    /// the value.
+    TLSLDMovWDTPRelG0 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local block offset MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of `sym + addend` in its
+    /// thread-local storage block, without checking for overflow.
+    TLSLDMovWDTPRelG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 12 bits of a thread-local block offset.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// bits 12-23 of the offset of `sym + addend` in its thread-local
+    /// storage block, checking for overflow.
+    TLSLDAddDTPRelHi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a thread-local block offset.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the offset of `sym + addend` in its
+    /// thread-local storage block, checking for overflow.
+    TLSLDAddDTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a thread-local block offset, no overflow check.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the offset of `sym + addend` in its
+    /// thread-local storage block, without checking for overflow.
+    TLSLDAddDTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits.
+    ///
+    /// Set the offset field of the 8-bit load or store instruction at
+    /// `offset` to bits 0-11 of the offset of `sym + addend` in its
+    /// thread-local storage block, checking for overflow.
+    TLSLDLdSt8DTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits, no overflow
+    /// check.
+    ///
+    /// Set the offset field of the 8-bit load or store instruction at
+    /// `offset` to bits 0-11 of the offset of `sym + addend` in its
+    /// thread-local storage block, without checking for overflow.
+    TLSLDLdSt8DTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits.
+    ///
+    /// Set the offset field of the 16-bit load or store instruction
+    /// at `offset` to bits 1-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, checking for overflow.
+    TLSLDLdSt16DTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits, no overflow
+    /// check.
+    ///
+    /// Set the offset field of the 16-bit load or store instruction
+    /// at `offset` to bits 1-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, without checking for overflow.
+    TLSLDLdSt16DTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits.
+    ///
+    /// Set the offset field of the 32-bit load or store instruction
+    /// at `offset` to bits 2-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, checking for overflow.
+    TLSLDLdSt32DTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits, no overflow
+    /// check.
+    ///
+    /// Set the offset field of the 32-bit load or store instruction
+    /// at `offset` to bits 2-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, without checking for overflow.
+    TLSLDLdSt32DTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits.
+    ///
+    /// Set the offset field of the 64-bit load or store instruction
+    /// at `offset` to bits 3-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, checking for overflow.
+    TLSLDLdSt64DTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits, no overflow
+    /// check.
+    ///
+    /// Set the offset field of the 64-bit load or store instruction
+    /// at `offset` to bits 3-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, without checking for overflow.
+    TLSLDLdSt64DTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Initial execution GOT offset MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of the Global Offset
+    /// Table entry for the thread-pointer offset of `sym + addend`
+    /// from the Global Offset Table, checking for overflow.  The
+    /// instruction becomes a MOVN or MOVZ depending on the sign of
+    /// the value.
+    TLSIEMovWGOTTPRelG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Initial execution GOT offset MOVW, bits 0-15, no overflow
+    /// check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of the Global Offset Table
+    /// entry for the thread-pointer offset of `sym + addend` from the
+    /// Global Offset Table, without checking for overflow.
+    TLSIEMovWGOTTPRelG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADRP page offset of an initial execution GOT entry.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing the Global
+    /// Offset Table entry for the thread-pointer offset of
+    /// `sym + addend` and the target instruction.
+    TLSIEAdrGOTTPRelPage21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an initial execution GOT entry for a 64-bit
+    /// load.
+    ///
+    /// Set the offset field of the 64-bit LDR instruction at `offset`
+    /// to bits 3-11 of the address of the Global Offset Table entry
+    /// for the thread-pointer offset of `sym + addend`.
+    TLSIELd64GOTTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative load of an initial execution GOT entry.
+    ///
+    /// Set the 19-bit literal field of the LDR instruction at
+    /// `offset` to bits 2-20 of the relative address of the Global
+    /// Offset Table entry for the thread-pointer offset of
+    /// `sym + addend`.
+    TLSIELdGOTTPRelPRel19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset MOVW, bits 32-47.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 32-47 of the offset of `sym + addend` from
+    /// the thread pointer, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    TLSLEMovWTPRelG2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of `sym + addend` from
+    /// the thread pointer, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    TLSLEMovWTPRelG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset MOVW, bits 16-31, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 16-31 of the offset of `sym + addend` from
+    /// the thread pointer, without checking for overflow.
+    TLSLEMovWTPRelG1NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset MOVW, bits 0-15.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 0-15 of the offset of `sym + addend` from the
+    /// thread pointer, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    TLSLEMovWTPRelG0 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of `sym + addend` from the
+    /// thread pointer, without checking for overflow.
+    TLSLEMovWTPRelG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 12 bits of a thread pointer offset.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// bits 12-23 of the offset of `sym + addend` from the thread
+    /// pointer, checking for overflow.
+    TLSLEAddTPRelHi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a thread pointer offset.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the offset of `sym + addend` from the
+    /// thread pointer, checking for overflow.
+    TLSLEAddTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a thread pointer offset, no overflow check.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the offset of `sym + addend` from the
+    /// thread pointer, without checking for overflow.
+    TLSLEAddTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits.
+    ///
+    /// Set the offset field of the 8-bit load or store instruction at
+    /// `offset` to bits 0-11 of the offset of `sym + addend` from the
+    /// thread pointer, checking for overflow.
+    TLSLELdSt8TPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits, no overflow check.
+    ///
+    /// Set the offset field of the 8-bit load or store instruction at
+    /// `offset` to bits 0-11 of the offset of `sym + addend` from the
+    /// thread pointer, without checking for overflow.
+    TLSLELdSt8TPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits.
+    ///
+    /// Set the offset field of the 16-bit load or store instruction
+    /// at `offset` to bits 1-11 of the offset of `sym + addend` from
+    /// the thread pointer, checking for overflow.
+    TLSLELdSt16TPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits, no overflow check.
+    ///
+    /// Set the offset field of the 16-bit load or store instruction
+    /// at `offset` to bits 1-11 of the offset of `sym + addend` from
+    /// the thread pointer, without checking for overflow.
+    TLSLELdSt16TPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits.
+    ///
+    /// Set the offset field of the 32-bit load or store instruction
+    /// at `offset` to bits 2-11 of the offset of `sym + addend` from
+    /// the thread pointer, checking for overflow.
+    TLSLELdSt32TPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits, no overflow check.
+    ///
+    /// Set the offset field of the 32-bit load or store instruction
+    /// at `offset` to bits 2-11 of the offset of `sym + addend` from
+    /// the thread pointer, without checking for overflow.
+    TLSLELdSt32TPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits.
+    ///
+    /// Set the offset field of the 64-bit load or store instruction
+    /// at `offset` to bits 3-11 of the offset of `sym + addend` from
+    /// the thread pointer, checking for overflow.
+    TLSLELdSt64TPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit load/store offset for the offset of `sym + addend` from
+    /// the thread pointer, low 12 bits, no overflow check.
+    ///
+    /// Set the offset field of the 64-bit load or store instruction
+    /// at `offset` to bits 3-11 of the offset of `sym + addend` from
+    /// the thread pointer, without checking for overflow.
+    TLSLELdSt64TPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative load of a TLS descriptor.
+    ///
+    /// Set the 19-bit literal field of the LDR instruction at
+    /// `offset` to bits 2-20 of the relative address of the Global
+    /// Offset Table entry for the TLS descriptor of `sym + addend`.
+    TLSDescLdPRel19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADR offset to a TLS descriptor.
+    ///
+    /// Set the immediate field of the ADR instruction at `offset` to
+    /// the relative address of the Global Offset Table entry for the
+    /// TLS descriptor of `sym + addend`.
+    TLSDescAdrPRel21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ADRP page offset of a TLS descriptor.
+    ///
+    /// Set the immediate field of the ADRP instruction at `offset` to
+    /// the difference between the 4KiB pages containing the Global
+    /// Offset Table entry for the TLS descriptor of `sym + addend`
+    /// and the target instruction.
+    TLSDescAdrPage21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a TLS descriptor for a 64-bit load.
+    ///
+    /// Set the offset field of the 64-bit LDR instruction at `offset`
+    /// to bits 3-11 of the address of the Global Offset Table entry
+    /// for the TLS descriptor of `sym + addend`.
+    TLSDescLd64Lo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a TLS descriptor.
+    ///
+    /// Set the immediate field of the ADD instruction at `offset` to
+    /// the low 12 bits of the address of the Global Offset Table
+    /// entry for the TLS descriptor of `sym + addend`.
+    TLSDescAddLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// TLS descriptor offset MOVW, bits 16-31.
+    ///
+    /// Set the immediate field of the MOVN or MOVZ instruction at
+    /// `offset` to bits 16-31 of the offset of the Global Offset
+    /// Table entry for the TLS descriptor of `sym + addend` from the
+    /// Global Offset Table, checking for overflow.  The instruction
+    /// becomes a MOVN or MOVZ depending on the sign of the value.
+    TLSDescOffG1 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// TLS descriptor offset MOVW, bits 0-15, no overflow check.
+    ///
+    /// Set the immediate field of the MOVZ or MOVK instruction at
+    /// `offset` to bits 0-15 of the offset of the Global Offset Table
+    /// entry for the TLS descriptor of `sym + addend` from the Global
+    /// Offset Table, without checking for overflow.
+    TLSDescOffG0NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// TLS descriptor load marker.
+    ///
+    /// Marks the LDR instruction at `offset` as loading the TLS
+    /// descriptor for `sym`, allowing the linker to relax the
+    /// sequence.
+    TLSDescLdr {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// TLS descriptor add marker.
+    ///
+    /// Marks the ADD instruction at `offset` as computing the address
+    /// of the TLS descriptor for `sym`, allowing the linker to relax
+    /// the sequence.
+    TLSDescAdd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// TLS descriptor call marker.
+    ///
+    /// Marks the BLR instruction at `offset` as calling the TLS
+    /// descriptor resolver for `sym`, allowing the linker to relax
+    /// the sequence.
+    TLSDescCall {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 128-bit load/store offset for the offset of `sym + addend`
+    /// from the thread pointer, low 12 bits.
+    ///
+    /// Set the offset field of the 128-bit load or store instruction
+    /// at `offset` to bits 4-11 of the offset of `sym + addend` from
+    /// the thread pointer, checking for overflow.
+    TLSLELdSt128TPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 128-bit load/store offset for the offset of `sym + addend`
+    /// from the thread pointer, low 12 bits, no overflow check.
+    ///
+    /// Set the offset field of the 128-bit load or store instruction
+    /// at `offset` to bits 4-11 of the offset of `sym + addend` from
+    /// the thread pointer, without checking for overflow.
+    TLSLELdSt128TPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 128-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits.
+    ///
+    /// Set the offset field of the 128-bit load or store instruction
+    /// at `offset` to bits 4-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, checking for overflow.
+    TLSLDLdSt128DTPRelLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 128-bit load/store offset for the offset of `sym + addend` in
+    /// its thread-local storage block, low 12 bits, no overflow
+    /// check.
+    ///
+    /// Set the offset field of the 128-bit load or store instruction
+    /// at `offset` to bits 4-11 of the offset of `sym + addend` in
+    /// its thread-local storage block, without checking for overflow.
+    TLSLDLdSt128DTPRelLo12NC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Global Offset Table entry fill.
+    ///
+    /// Set a Global Offset Table entry to the address of
+    /// `sym + addend`.
+    GlobalData {
+        /// Offset in the GOT.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the PLT.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit offset relative to the image base.
+    ///
+    /// Set the 8-byte word at `offset` to the sum of the base address
+    /// and `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local storage module index.
+    ///
+    /// Set the 8-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thread-local storage block offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    TLSDTPRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TLSTPRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// TLS descriptor fill.
+    ///
+    /// Set the 16-byte TLS descriptor at `offset` to describe the
+    /// thread-local `sym + addend`.
+    TLSDesc {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the result of calling the
+    /// resolver function at the sum of the base address and `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    }
+}
+
+/// Type synonym for [AArch64Reloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type AArch64RelocRaw = AArch64Reloc<u32>;
+
+/// Type synonym for [AArch64Reloc] with [SymDataRaw] as the symbol
+/// type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [AArch64RelocRaw].
+pub type AArch64RelocRawSym = AArch64Reloc<SymDataRaw<Elf64>>;
+
+/// Type synonym for [AArch64Reloc] with [SymDataStrData] as the
+/// symbol type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [AArch64RelocRawSym].
+pub type AArch64RelocStrDataSym<'a> = AArch64Reloc<SymDataStrData<'a, Elf64>>;
+
+/// Type synonym for [AArch64Reloc] with [SymDataStr] as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [AArch64RelocStrDataSym].
+pub type AArch64RelocStrData<'a> =
+    AArch64Reloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [AArch64Reloc] with UTF-8 decoded string data as
+/// the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [AArch64RelocStrDataSym].
+pub type AArch64RelocStrSym<'a> = AArch64Reloc<SymDataStr<'a, Elf64>>;
+
+/// Type synonym for [AArch64Reloc] with a `&'a str`s as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [AArch64RelocStrSym].
+pub type AArch64RelocStr<'a> = AArch64Reloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [AArch64Reloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// At present, this can only happen with a non-zero addend.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AArch64ToRelError {
+    /// Non-zero addend.
+    BadAddend(i64)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [AArch64Reloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AArch64RelocError {
+    /// Unknown tag value.
+    BadTag(u32)
+}
+
+impl<Name> Display for AArch64Reloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AArch64Reloc::None => write!(f, "none"),
+            AArch64Reloc::Abs64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            AArch64Reloc::Abs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::Abs16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            AArch64Reloc::PRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 8, sym, addend, offset),
+            AArch64Reloc::PRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::PRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            AArch64Reloc::MovWUAbsG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWUAbsG0NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWUAbsG1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 16) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWUAbsG1NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 16) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWUAbsG2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 32) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWUAbsG2NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 32) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWUAbsG3 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 48) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWSAbsG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWSAbsG1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 16) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWSAbsG2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 32) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::LdPRelLo19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::AdrPRelLo21 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::AdrPRelPgHi21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (page((&{} + {})) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::AdrPRelPgHi21NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (page((&{} + {})) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::AddAbsLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::LdSt8AbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TstBr14 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::CondBr19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::Jump26 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::Call26 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::LdSt16AbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) & 0xfff) >> 1",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::LdSt32AbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) & 0xfff) >> 2",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::LdSt64AbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) & 0xfff) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWPRelG0 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::MovWPRelG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::MovWPRelG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::MovWPRelG1NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::MovWPRelG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 32) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::MovWPRelG2NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 32) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::MovWPRelG3 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 48) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::LdSt128AbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) & 0xfff) >> 4",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG0 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "&.got) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "&.got) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}] - ",
+                                  "&.got) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG1NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}] - ",
+                                  "&.got) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}] - ",
+                                  "&.got) >> 32) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG2NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}] - ",
+                                  "&.got) >> 32) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::MovWGOTOffG3 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}] - ",
+                                  "&.got) >> 48) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::GOTRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 8, sym, addend),
+            AArch64Reloc::GOTRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::GOTLdPRel19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::Ld64GOTOffLo15 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}] - &.got) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::AdrGOTPage { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (page(&.got[&{} + {}]) ",
+                                  "- page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::Ld64GOTLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}] & 0xfff) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::Ld64GOTPageLo15 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "page(&.got)) >> 3"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::PLT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSGDAdrPRel21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] - (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSGDAdrPage21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tls_index(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSGDAddLo12NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSGDMovWG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tls_index(&{} ",
+                                  "+ {})] - &.got) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSGDMovWG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] - &.got) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDAdrPRel21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] - (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSLDAdrPage21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tls_index(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSLDAddLo12NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDMovWG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tls_index(&{} ",
+                                  "+ {})] - &.got) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDMovWG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] - &.got) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdPRel19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] - (&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSLDMovWDTPRelG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (dtprel(&{} + {}) >> ",
+                                  "32) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDMovWDTPRelG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (dtprel(&{} + {}) >> ",
+                                  "16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (dtprel(&{} + {}) >> ",
+                                  "16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDMovWDTPRelG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDAddDTPRelHi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (dtprel(&{} + {}) >> ",
+                                  "12) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDAddDTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 1",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 1",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 2",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 2",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tprel(&{} + ",
+                                  "{})] - &.got) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tprel(&{} + ",
+                                  "{})] - &.got) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (page(&.got[tprel(&{} ",
+                                  "+ {})]) - page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tprel(&{} + ",
+                                  "{})] & 0xfff) >> 3"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tprel(&{} + ",
+                                  "{})] - (&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSLEMovWTPRelG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (tprel(&{} + {}) >> ",
+                                  "32) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEMovWTPRelG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (tprel(&{} + {}) >> ",
+                                  "16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEMovWTPRelG1NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (tprel(&{} + {}) >> ",
+                                  "16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEMovWTPRelG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEMovWTPRelG0NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEAddTPRelHi12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) >> 12) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEAddTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLEAddTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 1",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 1",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 2",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 2",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 3",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSDescLdPRel19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tlsdesc(&{} + ",
+                                  "{})] - (&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSDescAdrPRel21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tlsdesc(&{} + ",
+                                  "{})] - (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSDescAdrPage21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tlsdesc(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            AArch64Reloc::TLSDescLd64Lo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tlsdesc(&{} + ",
+                                  "{})] & 0xfff) >> 3"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSDescAddLo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tlsdesc(&{} + ",
+                                  "{})] & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSDescOffG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tlsdesc(&{} + ",
+                                  "{})] - &.got) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSDescOffG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tlsdesc(&{} + ",
+                                  "{})] - &.got) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSDescLdr { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc load for {}",
+                       offset, offset + 4, sym),
+            AArch64Reloc::TLSDescAdd { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc add for {}",
+                       offset, offset + 4, sym),
+            AArch64Reloc::TLSDescCall { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc call for {}",
+                       offset, offset + 4, sym),
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 4",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (tprel(&{} + {}) & 0xfff) >> 4",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 4",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (dtprel(&{} + {}) & 0xfff) >> 4",
+                       offset, offset + 4, sym, addend),
+            AArch64Reloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            AArch64Reloc::GlobalData { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            AArch64Reloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            AArch64Reloc::Relative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 8, addend),
+            AArch64Reloc::TLSDTPMod { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 8, sym),
+            AArch64Reloc::TLSDTPRel { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 8, sym, addend),
+            AArch64Reloc::TLSTPRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 8, sym, addend),
+            AArch64Reloc::TLSDesc { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- tls descriptor for {} + {}",
+                       offset, offset + 16, sym, addend),
+            AArch64Reloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- (&base + {})()",
+                       offset, offset + 8, addend)
+        }
+    }
+}
+
+impl Display for AArch64RelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AArch64RelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for AArch64ToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AArch64ToRelError::BadAddend(addend) =>
+                write!(f, "non-zero addend value {}", addend)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<AArch64Reloc<Name>, AArch64RelocError> {
+    match kind {
+        0 => Ok(AArch64Reloc::None),
+        257 => Ok(AArch64Reloc::Abs64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        258 => Ok(AArch64Reloc::Abs32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        259 => Ok(AArch64Reloc::Abs16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        260 => Ok(AArch64Reloc::PRel64 { offset: offset, sym: sym,
+                                         addend: addend }),
+        261 => Ok(AArch64Reloc::PRel32 { offset: offset, sym: sym,
+                                         addend: addend }),
+        262 => Ok(AArch64Reloc::PRel16 { offset: offset, sym: sym,
+                                         addend: addend }),
+        263 => Ok(AArch64Reloc::MovWUAbsG0 { offset: offset, sym: sym,
+                                             addend: addend }),
+        264 => Ok(AArch64Reloc::MovWUAbsG0NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        265 => Ok(AArch64Reloc::MovWUAbsG1 { offset: offset, sym: sym,
+                                             addend: addend }),
+        266 => Ok(AArch64Reloc::MovWUAbsG1NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        267 => Ok(AArch64Reloc::MovWUAbsG2 { offset: offset, sym: sym,
+                                             addend: addend }),
+        268 => Ok(AArch64Reloc::MovWUAbsG2NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        269 => Ok(AArch64Reloc::MovWUAbsG3 { offset: offset, sym: sym,
+                                             addend: addend }),
+        270 => Ok(AArch64Reloc::MovWSAbsG0 { offset: offset, sym: sym,
+                                             addend: addend }),
+        271 => Ok(AArch64Reloc::MovWSAbsG1 { offset: offset, sym: sym,
+                                             addend: addend }),
+        272 => Ok(AArch64Reloc::MovWSAbsG2 { offset: offset, sym: sym,
+                                             addend: addend }),
+        273 => Ok(AArch64Reloc::LdPRelLo19 { offset: offset, sym: sym,
+                                             addend: addend }),
+        274 => Ok(AArch64Reloc::AdrPRelLo21 { offset: offset, sym: sym,
+                                              addend: addend }),
+        275 => Ok(AArch64Reloc::AdrPRelPgHi21 { offset: offset, sym: sym,
+                                                addend: addend }),
+        276 => Ok(AArch64Reloc::AdrPRelPgHi21NC { offset: offset, sym: sym,
+                                                  addend: addend }),
+        277 => Ok(AArch64Reloc::AddAbsLo12NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        278 => Ok(AArch64Reloc::LdSt8AbsNC { offset: offset, sym: sym,
+                                             addend: addend }),
+        279 => Ok(AArch64Reloc::TstBr14 { offset: offset, sym: sym,
+                                          addend: addend }),
+        280 => Ok(AArch64Reloc::CondBr19 { offset: offset, sym: sym,
+                                           addend: addend }),
+        282 => Ok(AArch64Reloc::Jump26 { offset: offset, sym: sym,
+                                         addend: addend }),
+        283 => Ok(AArch64Reloc::Call26 { offset: offset, sym: sym,
+                                         addend: addend }),
+        284 => Ok(AArch64Reloc::LdSt16AbsNC { offset: offset, sym: sym,
+                                              addend: addend }),
+        285 => Ok(AArch64Reloc::LdSt32AbsNC { offset: offset, sym: sym,
+                                              addend: addend }),
+        286 => Ok(AArch64Reloc::LdSt64AbsNC { offset: offset, sym: sym,
+                                              addend: addend }),
+        287 => Ok(AArch64Reloc::MovWPRelG0 { offset: offset, sym: sym,
+                                             addend: addend }),
+        288 => Ok(AArch64Reloc::MovWPRelG0NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        289 => Ok(AArch64Reloc::MovWPRelG1 { offset: offset, sym: sym,
+                                             addend: addend }),
+        290 => Ok(AArch64Reloc::MovWPRelG1NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        291 => Ok(AArch64Reloc::MovWPRelG2 { offset: offset, sym: sym,
+                                             addend: addend }),
+        292 => Ok(AArch64Reloc::MovWPRelG2NC { offset: offset, sym: sym,
+                                               addend: addend }),
+        293 => Ok(AArch64Reloc::MovWPRelG3 { offset: offset, sym: sym,
+                                             addend: addend }),
+        299 => Ok(AArch64Reloc::LdSt128AbsNC { offset: offset, sym: sym,
+                                               addend: addend }),
+        300 => Ok(AArch64Reloc::MovWGOTOffG0 { offset: offset, sym: sym,
+                                               addend: addend }),
+        301 => Ok(AArch64Reloc::MovWGOTOffG0NC { offset: offset, sym: sym,
+                                                 addend: addend }),
+        302 => Ok(AArch64Reloc::MovWGOTOffG1 { offset: offset, sym: sym,
+                                               addend: addend }),
+        303 => Ok(AArch64Reloc::MovWGOTOffG1NC { offset: offset, sym: sym,
+                                                 addend: addend }),
+        304 => Ok(AArch64Reloc::MovWGOTOffG2 { offset: offset, sym: sym,
+                                               addend: addend }),
+        305 => Ok(AArch64Reloc::MovWGOTOffG2NC { offset: offset, sym: sym,
+                                                 addend: addend }),
+        306 => Ok(AArch64Reloc::MovWGOTOffG3 { offset: offset, sym: sym,
+                                               addend: addend }),
+        307 => Ok(AArch64Reloc::GOTRel64 { offset: offset, sym: sym,
+                                           addend: addend }),
+        308 => Ok(AArch64Reloc::GOTRel32 { offset: offset, sym: sym,
+                                           addend: addend }),
+        309 => Ok(AArch64Reloc::GOTLdPRel19 { offset: offset, sym: sym,
+                                              addend: addend }),
+        310 => Ok(AArch64Reloc::Ld64GOTOffLo15 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        311 => Ok(AArch64Reloc::AdrGOTPage { offset: offset, sym: sym,
+                                             addend: addend }),
+        312 => Ok(AArch64Reloc::Ld64GOTLo12NC { offset: offset, sym: sym,
+                                                addend: addend }),
+        313 => Ok(AArch64Reloc::Ld64GOTPageLo15 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        314 => Ok(AArch64Reloc::PLT32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        512 => Ok(AArch64Reloc::TLSGDAdrPRel21 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        513 => Ok(AArch64Reloc::TLSGDAdrPage21 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        514 => Ok(AArch64Reloc::TLSGDAddLo12NC { offset: offset, sym: sym,
+                                                 addend: addend }),
+        515 => Ok(AArch64Reloc::TLSGDMovWG1 { offset: offset, sym: sym,
+                                              addend: addend }),
+        516 => Ok(AArch64Reloc::TLSGDMovWG0NC { offset: offset, sym: sym,
+                                                addend: addend }),
+        517 => Ok(AArch64Reloc::TLSLDAdrPRel21 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        518 => Ok(AArch64Reloc::TLSLDAdrPage21 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        519 => Ok(AArch64Reloc::TLSLDAddLo12NC { offset: offset, sym: sym,
+                                                 addend: addend }),
+        520 => Ok(AArch64Reloc::TLSLDMovWG1 { offset: offset, sym: sym,
+                                              addend: addend }),
+        521 => Ok(AArch64Reloc::TLSLDMovWG0NC { offset: offset, sym: sym,
+                                                addend: addend }),
+        522 => Ok(AArch64Reloc::TLSLDLdPRel19 { offset: offset, sym: sym,
+                                                addend: addend }),
+        523 => Ok(AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        524 => Ok(AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        525 => Ok(AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset, sym: sym,
+                                                      addend: addend }),
+        526 => Ok(AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        527 => Ok(AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset, sym: sym,
+                                                      addend: addend }),
+        528 => Ok(AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset, sym: sym,
+                                                     addend: addend }),
+        529 => Ok(AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset, sym: sym,
+                                                     addend: addend }),
+        530 => Ok(AArch64Reloc::TLSLDAddDTPRelLo12NC { offset: offset, sym: sym,
+                                                       addend: addend }),
+        531 => Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset: offset, sym: sym,
+                                                       addend: addend }),
+        532 => Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset: offset,
+                                                         sym: sym,
+                                                         addend: addend }),
+        533 => Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        534 => Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset: offset,
+                                                          sym: sym,
+                                                          addend: addend }),
+        535 => Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        536 => Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset: offset,
+                                                          sym: sym,
+                                                          addend: addend }),
+        537 => Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        538 => Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset: offset,
+                                                          sym: sym,
+                                                          addend: addend }),
+        539 => Ok(AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset, sym: sym,
+                                                      addend: addend }),
+        540 => Ok(AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        541 => Ok(AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset: offset,
+                                                         sym: sym,
+                                                         addend: addend }),
+        542 => Ok(AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset: offset,
+                                                          sym: sym,
+                                                          addend: addend }),
+        543 => Ok(AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        544 => Ok(AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        545 => Ok(AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        546 => Ok(AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset, sym: sym,
+                                                     addend: addend }),
+        547 => Ok(AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        548 => Ok(AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset, sym: sym,
+                                                     addend: addend }),
+        549 => Ok(AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        550 => Ok(AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        551 => Ok(AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset, sym: sym,
+                                                      addend: addend }),
+        552 => Ok(AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset, sym: sym,
+                                                      addend: addend }),
+        553 => Ok(AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        554 => Ok(AArch64Reloc::TLSLELdSt16TPRelLo12 { offset: offset, sym: sym,
+                                                       addend: addend }),
+        555 => Ok(AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset: offset,
+                                                         sym: sym,
+                                                         addend: addend }),
+        556 => Ok(AArch64Reloc::TLSLELdSt32TPRelLo12 { offset: offset, sym: sym,
+                                                       addend: addend }),
+        557 => Ok(AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset: offset,
+                                                         sym: sym,
+                                                         addend: addend }),
+        558 => Ok(AArch64Reloc::TLSLELdSt64TPRelLo12 { offset: offset, sym: sym,
+                                                       addend: addend }),
+        559 => Ok(AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset: offset,
+                                                         sym: sym,
+                                                         addend: addend }),
+        560 => Ok(AArch64Reloc::TLSDescLdPRel19 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        561 => Ok(AArch64Reloc::TLSDescAdrPRel21 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        562 => Ok(AArch64Reloc::TLSDescAdrPage21 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        563 => Ok(AArch64Reloc::TLSDescLd64Lo12 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        564 => Ok(AArch64Reloc::TLSDescAddLo12 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        565 => Ok(AArch64Reloc::TLSDescOffG1 { offset: offset, sym: sym,
+                                               addend: addend }),
+        566 => Ok(AArch64Reloc::TLSDescOffG0NC { offset: offset, sym: sym,
+                                                 addend: addend }),
+        567 => Ok(AArch64Reloc::TLSDescLdr { offset: offset, sym: sym }),
+        568 => Ok(AArch64Reloc::TLSDescAdd { offset: offset, sym: sym }),
+        569 => Ok(AArch64Reloc::TLSDescCall { offset: offset, sym: sym }),
+        570 => Ok(AArch64Reloc::TLSLELdSt128TPRelLo12 { offset: offset,
+                                                        sym: sym,
+                                                        addend: addend }),
+        571 => Ok(AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset: offset,
+                                                          sym: sym,
+                                                          addend: addend }),
+        572 => Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset: offset,
+                                                         sym: sym,
+                                                         addend: addend }),
+        573 => Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset: offset,
+                                                           sym: sym,
+                                                           addend: addend }),
+        1024 => Ok(AArch64Reloc::Copy { offset: offset, sym: sym }),
+        1025 => Ok(AArch64Reloc::GlobalData { offset: offset, sym: sym,
+                                              addend: addend }),
+        1026 => Ok(AArch64Reloc::JumpSlot { offset: offset, sym: sym,
+                                            addend: addend }),
+        1027 => Ok(AArch64Reloc::Relative { offset: offset, addend: addend }),
+        1028 => Ok(AArch64Reloc::TLSDTPMod { offset: offset, sym: sym }),
+        1029 => Ok(AArch64Reloc::TLSDTPRel { offset: offset, sym: sym,
+                                             addend: addend }),
+        1030 => Ok(AArch64Reloc::TLSTPRel { offset: offset, sym: sym,
+                                            addend: addend }),
+        1031 => Ok(AArch64Reloc::TLSDesc { offset: offset, sym: sym,
+                                           addend: addend }),
+        1032 => Ok(AArch64Reloc::IRelative { offset: offset, addend: addend }),
+        tag => Err(AArch64RelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf64>> for AArch64Reloc<Name> {
+    type Error = AArch64RelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf64>) -> Result<AArch64Reloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<AArch64Reloc<u32>> for RelData<u32, Elf64> {
+    type Error = AArch64ToRelError;
+
+    #[inline]
+    fn try_from(rel: AArch64Reloc<u32>) -> Result<RelData<u32, Elf64>,
+                                                  Self::Error> {
+        match rel {
+            AArch64Reloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            AArch64Reloc::Abs64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 257 }),
+            AArch64Reloc::Abs64 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Abs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 258 }),
+            AArch64Reloc::Abs32 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Abs16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 259 }),
+            AArch64Reloc::Abs16 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::PRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 260 }),
+            AArch64Reloc::PRel64 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::PRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 261 }),
+            AArch64Reloc::PRel32 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::PRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 262 }),
+            AArch64Reloc::PRel16 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 263 }),
+            AArch64Reloc::MovWUAbsG0 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 264 }),
+            AArch64Reloc::MovWUAbsG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 265 }),
+            AArch64Reloc::MovWUAbsG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 266 }),
+            AArch64Reloc::MovWUAbsG1NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 267 }),
+            AArch64Reloc::MovWUAbsG2 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG2NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 268 }),
+            AArch64Reloc::MovWUAbsG2NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWUAbsG3 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 269 }),
+            AArch64Reloc::MovWUAbsG3 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWSAbsG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 270 }),
+            AArch64Reloc::MovWSAbsG0 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWSAbsG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 271 }),
+            AArch64Reloc::MovWSAbsG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWSAbsG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 272 }),
+            AArch64Reloc::MovWSAbsG2 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::LdPRelLo19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 273 }),
+            AArch64Reloc::LdPRelLo19 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::AdrPRelLo21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 274 }),
+            AArch64Reloc::AdrPRelLo21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::AdrPRelPgHi21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 275 }),
+            AArch64Reloc::AdrPRelPgHi21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::AdrPRelPgHi21NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 276 }),
+            AArch64Reloc::AdrPRelPgHi21NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::AddAbsLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 277 }),
+            AArch64Reloc::AddAbsLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::LdSt8AbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 278 }),
+            AArch64Reloc::LdSt8AbsNC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TstBr14 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 279 }),
+            AArch64Reloc::TstBr14 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::CondBr19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 280 }),
+            AArch64Reloc::CondBr19 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Jump26 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 282 }),
+            AArch64Reloc::Jump26 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Call26 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 283 }),
+            AArch64Reloc::Call26 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::LdSt16AbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 284 }),
+            AArch64Reloc::LdSt16AbsNC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::LdSt32AbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 285 }),
+            AArch64Reloc::LdSt32AbsNC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::LdSt64AbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 286 }),
+            AArch64Reloc::LdSt64AbsNC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 287 }),
+            AArch64Reloc::MovWPRelG0 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 288 }),
+            AArch64Reloc::MovWPRelG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 289 }),
+            AArch64Reloc::MovWPRelG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 290 }),
+            AArch64Reloc::MovWPRelG1NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 291 }),
+            AArch64Reloc::MovWPRelG2 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG2NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 292 }),
+            AArch64Reloc::MovWPRelG2NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWPRelG3 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 293 }),
+            AArch64Reloc::MovWPRelG3 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::LdSt128AbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 299 }),
+            AArch64Reloc::LdSt128AbsNC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 300 }),
+            AArch64Reloc::MovWGOTOffG0 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 301 }),
+            AArch64Reloc::MovWGOTOffG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 302 }),
+            AArch64Reloc::MovWGOTOffG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 303 }),
+            AArch64Reloc::MovWGOTOffG1NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 304 }),
+            AArch64Reloc::MovWGOTOffG2 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG2NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 305 }),
+            AArch64Reloc::MovWGOTOffG2NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::MovWGOTOffG3 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 306 }),
+            AArch64Reloc::MovWGOTOffG3 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::GOTRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 307 }),
+            AArch64Reloc::GOTRel64 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::GOTRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 308 }),
+            AArch64Reloc::GOTRel32 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::GOTLdPRel19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 309 }),
+            AArch64Reloc::GOTLdPRel19 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Ld64GOTOffLo15 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 310 }),
+            AArch64Reloc::Ld64GOTOffLo15 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::AdrGOTPage { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 311 }),
+            AArch64Reloc::AdrGOTPage { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Ld64GOTLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 312 }),
+            AArch64Reloc::Ld64GOTLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Ld64GOTPageLo15 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 313 }),
+            AArch64Reloc::Ld64GOTPageLo15 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 314 }),
+            AArch64Reloc::PLT32 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSGDAdrPRel21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 512 }),
+            AArch64Reloc::TLSGDAdrPRel21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSGDAdrPage21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 513 }),
+            AArch64Reloc::TLSGDAdrPage21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSGDAddLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 514 }),
+            AArch64Reloc::TLSGDAddLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSGDMovWG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 515 }),
+            AArch64Reloc::TLSGDMovWG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSGDMovWG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 516 }),
+            AArch64Reloc::TLSGDMovWG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDAdrPRel21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 517 }),
+            AArch64Reloc::TLSLDAdrPRel21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDAdrPage21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 518 }),
+            AArch64Reloc::TLSLDAdrPage21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDAddLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 519 }),
+            AArch64Reloc::TLSLDAddLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 520 }),
+            AArch64Reloc::TLSLDMovWG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 521 }),
+            AArch64Reloc::TLSLDMovWG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdPRel19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 522 }),
+            AArch64Reloc::TLSLDLdPRel19 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWDTPRelG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 523 }),
+            AArch64Reloc::TLSLDMovWDTPRelG2 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWDTPRelG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 524 }),
+            AArch64Reloc::TLSLDMovWDTPRelG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 525 }),
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWDTPRelG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 526 }),
+            AArch64Reloc::TLSLDMovWDTPRelG0 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 527 }),
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDAddDTPRelHi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 528 }),
+            AArch64Reloc::TLSLDAddDTPRelHi12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDAddDTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 529 }),
+            AArch64Reloc::TLSLDAddDTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 530 }),
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 531 }),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 532 }),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 533 }),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 534 }),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 535 }),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 536 }),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 537 }),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 538 }),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 539 }),
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 540 }),
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 541 }),
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 542 }),
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 543 }),
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEMovWTPRelG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 544 }),
+            AArch64Reloc::TLSLEMovWTPRelG2 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEMovWTPRelG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 545 }),
+            AArch64Reloc::TLSLEMovWTPRelG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEMovWTPRelG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 546 }),
+            AArch64Reloc::TLSLEMovWTPRelG1NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEMovWTPRelG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 547 }),
+            AArch64Reloc::TLSLEMovWTPRelG0 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEMovWTPRelG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 548 }),
+            AArch64Reloc::TLSLEMovWTPRelG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEAddTPRelHi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 549 }),
+            AArch64Reloc::TLSLEAddTPRelHi12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEAddTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 550 }),
+            AArch64Reloc::TLSLEAddTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLEAddTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 551 }),
+            AArch64Reloc::TLSLEAddTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 552 }),
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 553 }),
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 554 }),
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 555 }),
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 556 }),
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 557 }),
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 558 }),
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 559 }),
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescLdPRel19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 560 }),
+            AArch64Reloc::TLSDescLdPRel19 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescAdrPRel21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 561 }),
+            AArch64Reloc::TLSDescAdrPRel21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescAdrPage21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 562 }),
+            AArch64Reloc::TLSDescAdrPage21 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescLd64Lo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 563 }),
+            AArch64Reloc::TLSDescLd64Lo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescAddLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 564 }),
+            AArch64Reloc::TLSDescAddLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescOffG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 565 }),
+            AArch64Reloc::TLSDescOffG1 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescOffG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 566 }),
+            AArch64Reloc::TLSDescOffG0NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDescLdr { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 567 }),
+            AArch64Reloc::TLSDescAdd { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 568 }),
+            AArch64Reloc::TLSDescCall { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 569 }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 570 }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 571 }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 572 }),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 573 }),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1024 }),
+            AArch64Reloc::GlobalData { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1025 }),
+            AArch64Reloc::GlobalData { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1026 }),
+            AArch64Reloc::JumpSlot { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 1027 }),
+            AArch64Reloc::Relative { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDTPMod { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1028 }),
+            AArch64Reloc::TLSDTPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1029 }),
+            AArch64Reloc::TLSDTPRel { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSTPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1030 }),
+            AArch64Reloc::TLSTPRel { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::TLSDesc { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1031 }),
+            AArch64Reloc::TLSDesc { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend)),
+            AArch64Reloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 1032 }),
+            AArch64Reloc::IRelative { addend, .. } =>
+                Err(AArch64ToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf64>> for AArch64Reloc<Name> {
+    type Error = AArch64RelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf64>) -> Result<AArch64Reloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<AArch64Reloc<u32>> for RelaData<u32, Elf64> {
+    #[inline]
+    fn from(rel: AArch64Reloc<u32>) -> RelaData<u32, Elf64> {
+        match rel {
+            AArch64Reloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            AArch64Reloc::Abs64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 257,
+                           addend: addend },
+            AArch64Reloc::Abs32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 258,
+                           addend: addend },
+            AArch64Reloc::Abs16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 259,
+                           addend: addend },
+            AArch64Reloc::PRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 260,
+                           addend: addend },
+            AArch64Reloc::PRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 261,
+                           addend: addend },
+            AArch64Reloc::PRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 262,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 263,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 264,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 265,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 266,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 267,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG2NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 268,
+                           addend: addend },
+            AArch64Reloc::MovWUAbsG3 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 269,
+                           addend: addend },
+            AArch64Reloc::MovWSAbsG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 270,
+                           addend: addend },
+            AArch64Reloc::MovWSAbsG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 271,
+                           addend: addend },
+            AArch64Reloc::MovWSAbsG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 272,
+                           addend: addend },
+            AArch64Reloc::LdPRelLo19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 273,
+                           addend: addend },
+            AArch64Reloc::AdrPRelLo21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 274,
+                           addend: addend },
+            AArch64Reloc::AdrPRelPgHi21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 275,
+                           addend: addend },
+            AArch64Reloc::AdrPRelPgHi21NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 276,
+                           addend: addend },
+            AArch64Reloc::AddAbsLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 277,
+                           addend: addend },
+            AArch64Reloc::LdSt8AbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 278,
+                           addend: addend },
+            AArch64Reloc::TstBr14 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 279,
+                           addend: addend },
+            AArch64Reloc::CondBr19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 280,
+                           addend: addend },
+            AArch64Reloc::Jump26 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 282,
+                           addend: addend },
+            AArch64Reloc::Call26 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 283,
+                           addend: addend },
+            AArch64Reloc::LdSt16AbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 284,
+                           addend: addend },
+            AArch64Reloc::LdSt32AbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 285,
+                           addend: addend },
+            AArch64Reloc::LdSt64AbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 286,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 287,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 288,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 289,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 290,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 291,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG2NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 292,
+                           addend: addend },
+            AArch64Reloc::MovWPRelG3 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 293,
+                           addend: addend },
+            AArch64Reloc::LdSt128AbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 299,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 300,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 301,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 302,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 303,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 304,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG2NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 305,
+                           addend: addend },
+            AArch64Reloc::MovWGOTOffG3 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 306,
+                           addend: addend },
+            AArch64Reloc::GOTRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 307,
+                           addend: addend },
+            AArch64Reloc::GOTRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 308,
+                           addend: addend },
+            AArch64Reloc::GOTLdPRel19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 309,
+                           addend: addend },
+            AArch64Reloc::Ld64GOTOffLo15 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 310,
+                           addend: addend },
+            AArch64Reloc::AdrGOTPage { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 311,
+                           addend: addend },
+            AArch64Reloc::Ld64GOTLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 312,
+                           addend: addend },
+            AArch64Reloc::Ld64GOTPageLo15 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 313,
+                           addend: addend },
+            AArch64Reloc::PLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 314,
+                           addend: addend },
+            AArch64Reloc::TLSGDAdrPRel21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 512,
+                           addend: addend },
+            AArch64Reloc::TLSGDAdrPage21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 513,
+                           addend: addend },
+            AArch64Reloc::TLSGDAddLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 514,
+                           addend: addend },
+            AArch64Reloc::TLSGDMovWG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 515,
+                           addend: addend },
+            AArch64Reloc::TLSGDMovWG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 516,
+                           addend: addend },
+            AArch64Reloc::TLSLDAdrPRel21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 517,
+                           addend: addend },
+            AArch64Reloc::TLSLDAdrPage21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 518,
+                           addend: addend },
+            AArch64Reloc::TLSLDAddLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 519,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 520,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 521,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdPRel19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 522,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 523,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 524,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 525,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 526,
+                           addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 527,
+                           addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelHi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 528,
+                           addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 529,
+                           addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 530,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 531,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 532,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 533,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 534,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 535,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 536,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 537,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 538,
+                           addend: addend },
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 539,
+                           addend: addend },
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 540,
+                           addend: addend },
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 541,
+                           addend: addend },
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 542,
+                           addend: addend },
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 543,
+                           addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 544,
+                           addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 545,
+                           addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 546,
+                           addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 547,
+                           addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 548,
+                           addend: addend },
+            AArch64Reloc::TLSLEAddTPRelHi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 549,
+                           addend: addend },
+            AArch64Reloc::TLSLEAddTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 550,
+                           addend: addend },
+            AArch64Reloc::TLSLEAddTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 551,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 552,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 553,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 554,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 555,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 556,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 557,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 558,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 559,
+                           addend: addend },
+            AArch64Reloc::TLSDescLdPRel19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 560,
+                           addend: addend },
+            AArch64Reloc::TLSDescAdrPRel21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 561,
+                           addend: addend },
+            AArch64Reloc::TLSDescAdrPage21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 562,
+                           addend: addend },
+            AArch64Reloc::TLSDescLd64Lo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 563,
+                           addend: addend },
+            AArch64Reloc::TLSDescAddLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 564,
+                           addend: addend },
+            AArch64Reloc::TLSDescOffG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 565,
+                           addend: addend },
+            AArch64Reloc::TLSDescOffG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 566,
+                           addend: addend },
+            AArch64Reloc::TLSDescLdr { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 567, addend: 0 },
+            AArch64Reloc::TLSDescAdd { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 568, addend: 0 },
+            AArch64Reloc::TLSDescCall { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 569, addend: 0 },
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 570,
+                           addend: addend },
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 571,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 572,
+                           addend: addend },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 573,
+                           addend: addend },
+            AArch64Reloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 1024, addend: 0 },
+            AArch64Reloc::GlobalData { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1025,
+                           addend: addend },
+            AArch64Reloc::JumpSlot { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1026,
+                           addend: addend },
+            AArch64Reloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 1027, addend: addend },
+            AArch64Reloc::TLSDTPMod { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 1028, addend: 0 },
+            AArch64Reloc::TLSDTPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1029,
+                           addend: addend },
+            AArch64Reloc::TLSTPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1030,
+                           addend: addend },
+            AArch64Reloc::TLSDesc { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1031,
+                           addend: addend },
+            AArch64Reloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 1032, addend: addend }
+        }
+    }
+}
+
+impl<'a, B> WithSymtab<'a, B, Elf64> for AArch64RelocRaw
+    where B: ByteOrder {
+    type Result = AArch64RelocRawSym;
+    type Error = RelocSymtabError<Elf64>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Elf64>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            AArch64Reloc::None => Ok(AArch64Reloc::None),
+            AArch64Reloc::Abs64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Abs64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Abs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Abs32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Abs16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Abs16 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::PRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::PRel64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::PRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::PRel32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::PRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::PRel16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG0 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG0NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG1 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG1NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG2 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG2NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG2NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWUAbsG3 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWUAbsG3 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWSAbsG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWSAbsG0 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWSAbsG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWSAbsG1 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWSAbsG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWSAbsG2 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::LdPRelLo19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::LdPRelLo19 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::AdrPRelLo21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::AdrPRelLo21 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::AdrPRelPgHi21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::AdrPRelPgHi21 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::AdrPRelPgHi21NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::AdrPRelPgHi21NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::AddAbsLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::AddAbsLo12NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::LdSt8AbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::LdSt8AbsNC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TstBr14 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TstBr14 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::CondBr19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::CondBr19 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Jump26 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Jump26 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Call26 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Call26 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::LdSt16AbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::LdSt16AbsNC { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::LdSt32AbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::LdSt32AbsNC { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::LdSt64AbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::LdSt64AbsNC { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG0 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG0NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG1 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG1NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG2 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG2NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG2NC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWPRelG3 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWPRelG3 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::LdSt128AbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::LdSt128AbsNC { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG0 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG0NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG1 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG1NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG2NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG2NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::MovWGOTOffG3 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::MovWGOTOffG3 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::GOTRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::GOTRel64 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::GOTRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::GOTRel32 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::GOTLdPRel19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::GOTLdPRel19 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Ld64GOTOffLo15 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Ld64GOTOffLo15 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::AdrGOTPage { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::AdrGOTPage { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Ld64GOTLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Ld64GOTLo12NC { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Ld64GOTPageLo15 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Ld64GOTPageLo15 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::PLT32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSGDAdrPRel21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSGDAdrPRel21 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSGDAdrPage21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSGDAdrPage21 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSGDAddLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSGDAddLo12NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSGDMovWG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSGDMovWG1 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSGDMovWG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSGDMovWG0NC { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDAdrPRel21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDAdrPRel21 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDAdrPage21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDAdrPage21 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDAddLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDAddLo12NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWG1 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWG0NC { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdPRel19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdPRel19 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWDTPRelG2 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWDTPRelG1 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWDTPRelG1NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWDTPRelG0 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDMovWDTPRelG0NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDAddDTPRelHi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDAddDTPRelHi12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDAddDTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDAddDTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDAddDTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSIEMovWGOTTPRelG1 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSIEMovWGOTTPRelG0NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSIEAdrGOTTPRelPage21 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSIELd64GOTTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSIELdGOTTPRelPRel19 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEMovWTPRelG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEMovWTPRelG2 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEMovWTPRelG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEMovWTPRelG1 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEMovWTPRelG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEMovWTPRelG1NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEMovWTPRelG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEMovWTPRelG0 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEMovWTPRelG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEMovWTPRelG0NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEAddTPRelHi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEAddTPRelHi12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEAddTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEAddTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLEAddTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLEAddTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt8TPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt8TPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt16TPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt16TPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt32TPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt32TPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt64TPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt64TPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescLdPRel19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescLdPRel19 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescAdrPRel21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescAdrPRel21 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescAdrPage21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescAdrPage21 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescLd64Lo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescLd64Lo12 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescAddLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescAddLo12 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescOffG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescOffG1 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescOffG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescOffG0NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescLdr { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescLdr { offset: offset,
+                                                          sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescAdd { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescAdd { offset: offset,
+                                                          sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDescCall { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDescCall { offset: offset,
+                                                           sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt128TPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLELdSt128TPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12NC {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::Copy { offset: offset,
+                                                    sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::GlobalData { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::GlobalData { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::JumpSlot { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::Relative { offset, addend } =>
+                Ok(AArch64Reloc::Relative { offset: offset, addend: addend }),
+            AArch64Reloc::TLSDTPMod { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDTPMod { offset: offset,
+                                                         sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDTPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDTPRel { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSTPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSTPRel { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::TLSDesc { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(AArch64Reloc::TLSDesc { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            AArch64Reloc::IRelative { offset, addend } =>
+                Ok(AArch64Reloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> WithStrtab<'a> for AArch64RelocRawSym {
+    type Result = AArch64RelocStrDataSym<'a>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            AArch64Reloc::None => Ok(AArch64Reloc::None),
+            AArch64Reloc::Abs64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Abs64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Abs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Abs32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Abs16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Abs16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PRel64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PRel32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PRel16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG0NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG1 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG1NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG2 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG2NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG2NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG3 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG3 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWSAbsG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWSAbsG0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWSAbsG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWSAbsG1 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWSAbsG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWSAbsG2 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdPRelLo19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdPRelLo19 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrPRelLo21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrPRelLo21 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrPRelPgHi21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrPRelPgHi21 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrPRelPgHi21NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrPRelPgHi21NC { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AddAbsLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AddAbsLo12NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt8AbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt8AbsNC { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TstBr14 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TstBr14 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::CondBr19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::CondBr19 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Jump26 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Jump26 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Call26 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Call26 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt16AbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt16AbsNC { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt32AbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt32AbsNC { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt64AbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt64AbsNC { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG0NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG1 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG1NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG2 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG2NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG2NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG3 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG3 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt128AbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt128AbsNC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG0 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG0NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG1 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG1NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG2 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG2NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG2NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG3 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG3 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GOTRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GOTRel64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GOTRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GOTRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GOTLdPRel19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GOTLdPRel19 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Ld64GOTOffLo15 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Ld64GOTOffLo15 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrGOTPage { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrGOTPage { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Ld64GOTLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Ld64GOTLo12NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Ld64GOTPageLo15 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Ld64GOTPageLo15 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PLT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDAdrPRel21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDAdrPRel21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDAdrPage21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDAdrPage21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDAddLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDAddLo12NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDMovWG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDMovWG1 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDMovWG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDMovWG0NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAdrPRel21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAdrPRel21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAdrPage21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAdrPage21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddLo12NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWG1 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWG0NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdPRel19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdPRel19 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddDTPRelHi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddDTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddDTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIEMovWGOTTPRelG0NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIEAdrGOTTPRelPage21 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIELd64GOTTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIELdGOTTPRelPRel19 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEAddTPRelHi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEAddTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEAddTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt8TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt16TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt16TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt32TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt32TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt64TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt64TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescLdPRel19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescLdPRel19 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAdrPRel21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAdrPRel21 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAdrPage21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAdrPage21 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescLd64Lo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescLd64Lo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAddLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAddLo12 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescOffG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescOffG1 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescOffG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescOffG0NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescLdr { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescLdr { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAdd { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAdd { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescCall { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescCall { offset: offset,
+                                                       sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt128TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt128TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GlobalData { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GlobalData { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::JumpSlot { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Relative { offset, addend } =>
+                Ok(AArch64Reloc::Relative { offset: offset, addend: addend }),
+            AArch64Reloc::TLSDTPMod { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDTPMod { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDTPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDTPRel { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSTPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSTPRel { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDesc { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDesc { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::IRelative { offset, addend } =>
+                Ok(AArch64Reloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> TryFrom<AArch64RelocStrDataSym<'a>> for AArch64RelocStrSym<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: AArch64RelocStrDataSym<'a>) ->
+        Result<AArch64RelocStrSym<'a>, Self::Error> {
+        match reloc {
+            AArch64Reloc::None => Ok(AArch64Reloc::None),
+            AArch64Reloc::Abs64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Abs64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Abs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Abs32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Abs16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Abs16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PRel64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PRel32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PRel16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG0NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG1 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG1NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG2 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG2NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG2NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWUAbsG3 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWUAbsG3 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWSAbsG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWSAbsG0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWSAbsG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWSAbsG1 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWSAbsG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWSAbsG2 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdPRelLo19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdPRelLo19 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrPRelLo21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrPRelLo21 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrPRelPgHi21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrPRelPgHi21 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrPRelPgHi21NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrPRelPgHi21NC { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AddAbsLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AddAbsLo12NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt8AbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt8AbsNC { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TstBr14 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TstBr14 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::CondBr19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::CondBr19 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Jump26 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Jump26 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Call26 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Call26 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt16AbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt16AbsNC { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt32AbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt32AbsNC { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt64AbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt64AbsNC { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG0NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG1 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG1NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG2 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG2NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG2NC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWPRelG3 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWPRelG3 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::LdSt128AbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::LdSt128AbsNC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG0 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG0NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG1 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG1NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG2 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG2NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG2NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::MovWGOTOffG3 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::MovWGOTOffG3 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GOTRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GOTRel64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GOTRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GOTRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GOTLdPRel19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GOTLdPRel19 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Ld64GOTOffLo15 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Ld64GOTOffLo15 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::AdrGOTPage { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::AdrGOTPage { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Ld64GOTLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Ld64GOTLo12NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Ld64GOTPageLo15 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Ld64GOTPageLo15 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::PLT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDAdrPRel21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDAdrPRel21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDAdrPage21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDAdrPage21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDAddLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDAddLo12NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDMovWG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDMovWG1 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSGDMovWG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSGDMovWG0NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAdrPRel21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAdrPRel21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAdrPage21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAdrPage21 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddLo12NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWG1 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWG0NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdPRel19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdPRel19 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddDTPRelHi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddDTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDAddDTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIEMovWGOTTPRelG0NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIEAdrGOTTPRelPage21 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIELd64GOTTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSIELdGOTTPRelPRel19 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEMovWTPRelG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEAddTPRelHi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEAddTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLEAddTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt8TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt16TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt16TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt32TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt32TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt64TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt64TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescLdPRel19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescLdPRel19 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAdrPRel21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAdrPRel21 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAdrPage21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAdrPage21 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescLd64Lo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescLd64Lo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAddLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAddLo12 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescOffG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescOffG1 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescOffG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescOffG0NC { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescLdr { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescLdr { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescAdd { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescAdd { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDescCall { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDescCall { offset: offset,
+                                                       sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt128TPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLELdSt128TPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12 {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12NC {
+                            offset: offset, sym: symdata, addend: addend
+                        })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::GlobalData { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::GlobalData { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::JumpSlot { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::Relative { offset, addend } =>
+                Ok(AArch64Reloc::Relative { offset: offset, addend: addend }),
+            AArch64Reloc::TLSDTPMod { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDTPMod { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDTPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDTPRel { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSTPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSTPRel { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::TLSDesc { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(AArch64Reloc::TLSDesc { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            AArch64Reloc::IRelative { offset, addend } =>
+                Ok(AArch64Reloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> From<AArch64RelocStrDataSym<'a>> for AArch64RelocStrData<'a> {
+    #[inline]
+    fn from(reloc: AArch64RelocStrDataSym<'a>) -> AArch64RelocStrData<'a> {
+        match reloc {
+            AArch64Reloc::None => AArch64Reloc::None,
+            AArch64Reloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::Abs64 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::Abs32 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::Abs16 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::PRel64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::PRel64 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::PRel32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::PRel32 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::PRel16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::PRel16 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::MovWUAbsG0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG0 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWUAbsG0NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWUAbsG0NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWUAbsG1 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG1 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWUAbsG1NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWUAbsG1NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWUAbsG2 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG2 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWUAbsG2NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWUAbsG2NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWUAbsG3 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG3 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWSAbsG0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWSAbsG0 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWSAbsG1 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWSAbsG1 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWSAbsG2 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWSAbsG2 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::LdPRelLo19 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::LdPRelLo19 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::AdrPRelLo21 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::AdrPRelLo21 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::AdrPRelPgHi21 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::AdrPRelPgHi21 { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::AdrPRelPgHi21NC { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::AdrPRelPgHi21NC { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::AddAbsLo12NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::AddAbsLo12NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::LdSt8AbsNC { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::LdSt8AbsNC { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::TstBr14 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                AArch64Reloc::TstBr14 { offset: offset, sym: name,
+                                        addend: addend },
+            AArch64Reloc::CondBr19 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::CondBr19 { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::Jump26 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::Jump26 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::Call26 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::Call26 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::LdSt16AbsNC { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::LdSt16AbsNC { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::LdSt32AbsNC { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::LdSt32AbsNC { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::LdSt64AbsNC { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::LdSt64AbsNC { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::MovWPRelG0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG0 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWPRelG0NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWPRelG0NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWPRelG1 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG1 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWPRelG1NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWPRelG1NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWPRelG2 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG2 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWPRelG2NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWPRelG2NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWPRelG3 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG3 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::LdSt128AbsNC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::LdSt128AbsNC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG0 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG0 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG0NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::MovWGOTOffG0NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::MovWGOTOffG1 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG1 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG1NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::MovWGOTOffG1NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::MovWGOTOffG2 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG2 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG2NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::MovWGOTOffG2NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::MovWGOTOffG3 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG3 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::GOTRel64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::GOTRel64 { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::GOTRel32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::GOTRel32 { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::GOTLdPRel19 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::GOTLdPRel19 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::Ld64GOTOffLo15 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::Ld64GOTOffLo15 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::AdrGOTPage { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::AdrGOTPage { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::Ld64GOTLo12NC { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::Ld64GOTLo12NC { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::Ld64GOTPageLo15 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::Ld64GOTPageLo15 { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::PLT32 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::TLSGDAdrPRel21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSGDAdrPRel21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSGDAdrPage21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSGDAdrPage21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSGDAddLo12NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSGDAddLo12NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSGDMovWG1 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::TLSGDMovWG1 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::TLSGDMovWG0NC { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::TLSGDMovWG0NC { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::TLSLDAdrPRel21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSLDAdrPRel21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSLDAdrPage21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSLDAdrPage21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSLDAddLo12NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSLDAddLo12NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSLDMovWG1 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::TLSLDMovWG1 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::TLSLDMovWG0NC { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::TLSLDMovWG0NC { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::TLSLDLdPRel19 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::TLSLDLdPRel19 { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG2 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG1 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG0 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelHi12 { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelLo12 { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLDAddDTPRelLo12NC { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG2 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG1 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG1NC { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG0 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG0NC { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLEAddTPRelHi12 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLEAddTPRelLo12 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLEAddTPRelLo12NC { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLELdSt16TPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLELdSt32TPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLELdSt64TPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSDescLdPRel19 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::TLSDescLdPRel19 { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::TLSDescAdrPRel21 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSDescAdrPRel21 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSDescAdrPage21 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSDescAdrPage21 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSDescLd64Lo12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::TLSDescLd64Lo12 { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::TLSDescAddLo12 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSDescAddLo12 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSDescOffG1 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::TLSDescOffG1 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::TLSDescOffG0NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSDescOffG0NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSDescLdr { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDescLdr { offset: offset, sym: name },
+            AArch64Reloc::TLSDescAdd { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDescAdd { offset: offset, sym: name },
+            AArch64Reloc::TLSDescCall { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDescCall { offset: offset, sym: name },
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLELdSt128TPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset: offset,
+                                                         sym: name,
+                                                         addend: addend },
+            AArch64Reloc::Copy { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::Copy { offset: offset, sym: name },
+            AArch64Reloc::GlobalData { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::GlobalData { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::JumpSlot { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::Relative { offset, addend } =>
+                AArch64Reloc::Relative { offset: offset, addend: addend },
+            AArch64Reloc::TLSDTPMod { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDTPMod { offset: offset, sym: name },
+            AArch64Reloc::TLSDTPRel { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                AArch64Reloc::TLSDTPRel { offset: offset, sym: name,
+                                          addend: addend },
+            AArch64Reloc::TLSTPRel { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::TLSTPRel { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::TLSDesc { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                AArch64Reloc::TLSDesc { offset: offset, sym: name,
+                                        addend: addend },
+            AArch64Reloc::IRelative { offset, addend } =>
+                AArch64Reloc::IRelative { offset: offset, addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<AArch64RelocStrData<'a>> for AArch64RelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: AArch64RelocStrData<'a>) ->
+        Result<AArch64RelocStr<'a>, Self::Error> {
+        match reloc {
+            AArch64Reloc::None => Ok(AArch64Reloc::None),
+            AArch64Reloc::Abs64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::Abs64 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            AArch64Reloc::Abs64 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Abs64 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Abs64 { offset: offset, sym: None,
+                                         addend: addend }),
+            AArch64Reloc::Abs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::Abs32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            AArch64Reloc::Abs32 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Abs32 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Abs32 { offset: offset, sym: None,
+                                         addend: addend }),
+            AArch64Reloc::Abs16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::Abs16 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            AArch64Reloc::Abs16 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Abs16 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Abs16 { offset: offset, sym: None,
+                                         addend: addend }),
+            AArch64Reloc::PRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::PRel64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            AArch64Reloc::PRel64 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::PRel64 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::PRel64 { offset: offset, sym: None,
+                                          addend: addend }),
+            AArch64Reloc::PRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::PRel32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            AArch64Reloc::PRel32 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::PRel32 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::PRel32 { offset: offset, sym: None,
+                                          addend: addend }),
+            AArch64Reloc::PRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::PRel16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            AArch64Reloc::PRel16 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::PRel16 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::PRel16 { offset: offset, sym: None,
+                                          addend: addend }),
+            AArch64Reloc::MovWUAbsG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG0 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG0 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG0 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG0 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG0NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWUAbsG0NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWUAbsG0NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG0NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWUAbsG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG1 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG1 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG1NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWUAbsG1NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWUAbsG1NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG1NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG1NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWUAbsG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG2 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG2 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG2 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG2 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG2NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWUAbsG2NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWUAbsG2NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG2NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG2NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWUAbsG3 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG3 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWUAbsG3 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWUAbsG3 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWUAbsG3 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWSAbsG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWSAbsG0 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWSAbsG0 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWSAbsG0 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWSAbsG0 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWSAbsG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWSAbsG1 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWSAbsG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWSAbsG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWSAbsG1 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWSAbsG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWSAbsG2 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWSAbsG2 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWSAbsG2 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWSAbsG2 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::LdPRelLo19 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::LdPRelLo19 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::LdPRelLo19 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::LdPRelLo19 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::LdPRelLo19 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::AdrPRelLo21 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::AdrPRelLo21 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::AdrPRelLo21 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::AdrPRelLo21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::AdrPRelLo21 { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::AdrPRelPgHi21 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(AArch64Reloc::AdrPRelPgHi21 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            AArch64Reloc::AdrPRelPgHi21 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::AdrPRelPgHi21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::AdrPRelPgHi21 { offset: offset, sym: None,
+                                                 addend: addend }),
+            AArch64Reloc::AdrPRelPgHi21NC { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(AArch64Reloc::AdrPRelPgHi21NC { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            AArch64Reloc::AdrPRelPgHi21NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::AdrPRelPgHi21NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::AdrPRelPgHi21NC { offset: offset, sym: None,
+                                                   addend: addend }),
+            AArch64Reloc::AddAbsLo12NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::AddAbsLo12NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::AddAbsLo12NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::AddAbsLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::AddAbsLo12NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::LdSt8AbsNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::LdSt8AbsNC { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::LdSt8AbsNC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::LdSt8AbsNC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::LdSt8AbsNC { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::TstBr14 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::TstBr14 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            AArch64Reloc::TstBr14 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TstBr14 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TstBr14 { offset: offset, sym: None,
+                                           addend: addend }),
+            AArch64Reloc::CondBr19 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::CondBr19 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            AArch64Reloc::CondBr19 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::CondBr19 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::CondBr19 { offset: offset, sym: None,
+                                            addend: addend }),
+            AArch64Reloc::Jump26 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::Jump26 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            AArch64Reloc::Jump26 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Jump26 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Jump26 { offset: offset, sym: None,
+                                          addend: addend }),
+            AArch64Reloc::Call26 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::Call26 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            AArch64Reloc::Call26 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Call26 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Call26 { offset: offset, sym: None,
+                                          addend: addend }),
+            AArch64Reloc::LdSt16AbsNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::LdSt16AbsNC { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::LdSt16AbsNC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::LdSt16AbsNC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::LdSt16AbsNC { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::LdSt32AbsNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::LdSt32AbsNC { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::LdSt32AbsNC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::LdSt32AbsNC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::LdSt32AbsNC { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::LdSt64AbsNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::LdSt64AbsNC { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::LdSt64AbsNC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::LdSt64AbsNC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::LdSt64AbsNC { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::MovWPRelG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG0 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG0 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG0 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG0 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG0NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWPRelG0NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWPRelG0NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG0NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWPRelG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG1 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG1 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG1NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWPRelG1NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWPRelG1NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG1NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG1NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWPRelG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG2 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG2 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG2 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG2 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG2NC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWPRelG2NC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWPRelG2NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG2NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG2NC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWPRelG3 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG3 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::MovWPRelG3 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWPRelG3 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWPRelG3 { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::LdSt128AbsNC { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::LdSt128AbsNC { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::LdSt128AbsNC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::LdSt128AbsNC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::LdSt128AbsNC { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG0 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG0 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG0 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWGOTOffG0 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG0 { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG0NC { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG0NC { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::MovWGOTOffG0NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::MovWGOTOffG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG0NC { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::MovWGOTOffG1 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG1 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWGOTOffG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG1 { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG1NC { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG1NC { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::MovWGOTOffG1NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::MovWGOTOffG1NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG1NC { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::MovWGOTOffG2 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG2 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG2 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWGOTOffG2 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG2 { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG2NC { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG2NC { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::MovWGOTOffG2NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::MovWGOTOffG2NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG2NC { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::MovWGOTOffG3 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG3 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::MovWGOTOffG3 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::MovWGOTOffG3 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::MovWGOTOffG3 { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::GOTRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::GOTRel64 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            AArch64Reloc::GOTRel64 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::GOTRel64 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::GOTRel64 { offset: offset, sym: None,
+                                            addend: addend }),
+            AArch64Reloc::GOTRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::GOTRel32 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            AArch64Reloc::GOTRel32 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::GOTRel32 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::GOTRel32 { offset: offset, sym: None,
+                                            addend: addend }),
+            AArch64Reloc::GOTLdPRel19 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::GOTLdPRel19 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::GOTLdPRel19 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::GOTLdPRel19 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::GOTLdPRel19 { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::Ld64GOTOffLo15 { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::Ld64GOTOffLo15 { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::Ld64GOTOffLo15 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::Ld64GOTOffLo15 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Ld64GOTOffLo15 { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::AdrGOTPage { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::AdrGOTPage { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::AdrGOTPage { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::AdrGOTPage { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::AdrGOTPage { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::Ld64GOTLo12NC { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(AArch64Reloc::Ld64GOTLo12NC { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            AArch64Reloc::Ld64GOTLo12NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Ld64GOTLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Ld64GOTLo12NC { offset: offset, sym: None,
+                                                 addend: addend }),
+            AArch64Reloc::Ld64GOTPageLo15 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(AArch64Reloc::Ld64GOTPageLo15 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            AArch64Reloc::Ld64GOTPageLo15 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::Ld64GOTPageLo15 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::Ld64GOTPageLo15 { offset: offset, sym: None,
+                                                   addend: addend }),
+            AArch64Reloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::PLT32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            AArch64Reloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::PLT32 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::PLT32 { offset: offset, sym: None,
+                                         addend: addend }),
+            AArch64Reloc::TLSGDAdrPRel21 { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSGDAdrPRel21 { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSGDAdrPRel21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSGDAdrPRel21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSGDAdrPRel21 { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSGDAdrPage21 { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSGDAdrPage21 { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSGDAdrPage21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSGDAdrPage21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSGDAdrPage21 { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSGDAddLo12NC { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSGDAddLo12NC { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSGDAddLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSGDAddLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSGDAddLo12NC { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSGDMovWG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::TLSGDMovWG1 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::TLSGDMovWG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSGDMovWG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSGDMovWG1 { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::TLSGDMovWG0NC { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(AArch64Reloc::TLSGDMovWG0NC { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            AArch64Reloc::TLSGDMovWG0NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSGDMovWG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSGDMovWG0NC { offset: offset, sym: None,
+                                                 addend: addend }),
+            AArch64Reloc::TLSLDAdrPRel21 { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSLDAdrPRel21 { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSLDAdrPRel21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDAdrPRel21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDAdrPRel21 { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSLDAdrPage21 { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSLDAdrPage21 { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSLDAdrPage21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDAdrPage21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDAdrPage21 { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSLDAddLo12NC { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSLDAddLo12NC { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSLDAddLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDAddLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDAddLo12NC { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSLDMovWG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWG1 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            AArch64Reloc::TLSLDMovWG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSLDMovWG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWG1 { offset: offset, sym: None,
+                                               addend: addend }),
+            AArch64Reloc::TLSLDMovWG0NC { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(AArch64Reloc::TLSLDMovWG0NC { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            AArch64Reloc::TLSLDMovWG0NC { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSLDMovWG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWG0NC { offset: offset, sym: None,
+                                                 addend: addend }),
+            AArch64Reloc::TLSLDLdPRel19 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(AArch64Reloc::TLSLDLdPRel19 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            AArch64Reloc::TLSLDLdPRel19 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSLDLdPRel19 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDLdPRel19 { offset: offset, sym: None,
+                                                 addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG2 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG2 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDMovWDTPRelG2 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset, sym: None,
+                                                     addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG1 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG1 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDMovWDTPRelG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset, sym: None,
+                                                     addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { sym: Some(Ok(name)), offset,
+                                                addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset,
+                                                       sym: Some(name),
+                                                       addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset,
+                                                       sym: None,
+                                                       addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG0 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG0 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDMovWDTPRelG0 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset, sym: None,
+                                                     addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { sym: Some(Ok(name)), offset,
+                                                addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset,
+                                                       sym: Some(name),
+                                                       addend: addend }),
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset,
+                                                       sym: None,
+                                                       addend: addend }),
+            AArch64Reloc::TLSLDAddDTPRelHi12 { sym: Some(Ok(name)), offset,
+                                               addend } =>
+                Ok(AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset,
+                                                      sym: Some(name),
+                                                      addend: addend }),
+            AArch64Reloc::TLSLDAddDTPRelHi12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDAddDTPRelHi12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset, sym: None,
+                                                      addend: addend }),
+            AArch64Reloc::TLSLDAddDTPRelLo12 { sym: Some(Ok(name)), offset,
+                                               addend } =>
+                Ok(AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset,
+                                                      sym: Some(name),
+                                                      addend: addend }),
+            AArch64Reloc::TLSLDAddDTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDAddDTPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset, sym: None,
+                                                      addend: addend }),
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                 addend } =>
+                Ok(AArch64Reloc::TLSLDAddDTPRelLo12NC { offset: offset,
+                                                        sym: Some(name),
+                                                        addend: addend }),
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDAddDTPRelLo12NC { offset: offset,
+                                                        sym: None,
+                                                        addend: addend }),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { sym: Some(Ok(name)), offset,
+                                                 addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset: offset,
+                                                        sym: Some(name),
+                                                        addend: addend }),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset: offset,
+                                                        sym: None,
+                                                        addend: addend }),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset: offset,
+                                                          sym: Some(name),
+                                                          addend: addend }),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { sym: None, offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset: offset,
+                                                          sym: None,
+                                                          addend: addend }),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset: offset,
+                                                           sym: Some(name),
+                                                           addend: addend }),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { sym: None, offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset: offset,
+                                                           sym: None,
+                                                           addend: addend }),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset: offset,
+                                                           sym: Some(name),
+                                                           addend: addend }),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { sym: None, offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset: offset,
+                                                           sym: None,
+                                                           addend: addend }),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset: offset,
+                                                           sym: Some(name),
+                                                           addend: addend }),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { sym: None, offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset: offset,
+                                                           sym: None,
+                                                           addend: addend }),
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { sym: Some(Ok(name)), offset,
+                                                addend } =>
+                Ok(AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset,
+                                                       sym: Some(name),
+                                                       addend: addend }),
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset,
+                                                       sym: None,
+                                                       addend: addend }),
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { sym: Some(Ok(name)), offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset: offset,
+                                                          sym: Some(name),
+                                                          addend: addend }),
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 { sym: None, offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset: offset,
+                                                          sym: None,
+                                                          addend: addend }),
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset: offset,
+                                                           sym: Some(name),
+                                                           addend: addend }),
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC { sym: None, offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset: offset,
+                                                           sym: None,
+                                                           addend: addend }),
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG2 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG2 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEMovWTPRelG2 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset, sym: None,
+                                                    addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG1 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG1 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEMovWTPRelG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset, sym: None,
+                                                    addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG1NC { sym: Some(Ok(name)), offset,
+                                               addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset,
+                                                      sym: Some(name),
+                                                      addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG1NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEMovWTPRelG1NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset, sym: None,
+                                                      addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG0 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG0 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEMovWTPRelG0 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset, sym: None,
+                                                    addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG0NC { sym: Some(Ok(name)), offset,
+                                               addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset,
+                                                      sym: Some(name),
+                                                      addend: addend }),
+            AArch64Reloc::TLSLEMovWTPRelG0NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEMovWTPRelG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset, sym: None,
+                                                      addend: addend }),
+            AArch64Reloc::TLSLEAddTPRelHi12 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            AArch64Reloc::TLSLEAddTPRelHi12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEAddTPRelHi12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset, sym: None,
+                                                     addend: addend }),
+            AArch64Reloc::TLSLEAddTPRelLo12 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            AArch64Reloc::TLSLEAddTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEAddTPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset, sym: None,
+                                                     addend: addend }),
+            AArch64Reloc::TLSLEAddTPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                addend } =>
+                Ok(AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset,
+                                                       sym: Some(name),
+                                                       addend: addend }),
+            AArch64Reloc::TLSLEAddTPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLEAddTPRelLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset,
+                                                       sym: None,
+                                                       addend: addend }),
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { sym: Some(Ok(name)), offset,
+                                                addend } =>
+                Ok(AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset,
+                                                       sym: Some(name),
+                                                       addend: addend }),
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset,
+                                                       sym: None,
+                                                       addend: addend }),
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { sym: Some(Ok(name)), offset,
+                                                 addend } =>
+                Ok(AArch64Reloc::TLSLELdSt16TPRelLo12 { offset: offset,
+                                                        sym: Some(name),
+                                                        addend: addend }),
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLELdSt16TPRelLo12 { offset: offset,
+                                                        sym: None,
+                                                        addend: addend }),
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset: offset,
+                                                          sym: Some(name),
+                                                          addend: addend }),
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC { sym: None, offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset: offset,
+                                                          sym: None,
+                                                          addend: addend }),
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { sym: Some(Ok(name)), offset,
+                                                 addend } =>
+                Ok(AArch64Reloc::TLSLELdSt32TPRelLo12 { offset: offset,
+                                                        sym: Some(name),
+                                                        addend: addend }),
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLELdSt32TPRelLo12 { offset: offset,
+                                                        sym: None,
+                                                        addend: addend }),
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset: offset,
+                                                          sym: Some(name),
+                                                          addend: addend }),
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC { sym: None, offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset: offset,
+                                                          sym: None,
+                                                          addend: addend }),
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { sym: Some(Ok(name)), offset,
+                                                 addend } =>
+                Ok(AArch64Reloc::TLSLELdSt64TPRelLo12 { offset: offset,
+                                                        sym: Some(name),
+                                                        addend: addend }),
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLELdSt64TPRelLo12 { offset: offset,
+                                                        sym: None,
+                                                        addend: addend }),
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset: offset,
+                                                          sym: Some(name),
+                                                          addend: addend }),
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC { sym: None, offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset: offset,
+                                                          sym: None,
+                                                          addend: addend }),
+            AArch64Reloc::TLSDescLdPRel19 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(AArch64Reloc::TLSDescLdPRel19 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            AArch64Reloc::TLSDescLdPRel19 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSDescLdPRel19 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescLdPRel19 { offset: offset, sym: None,
+                                                   addend: addend }),
+            AArch64Reloc::TLSDescAdrPRel21 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(AArch64Reloc::TLSDescAdrPRel21 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            AArch64Reloc::TLSDescAdrPRel21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSDescAdrPRel21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescAdrPRel21 { offset: offset, sym: None,
+                                                    addend: addend }),
+            AArch64Reloc::TLSDescAdrPage21 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(AArch64Reloc::TLSDescAdrPage21 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            AArch64Reloc::TLSDescAdrPage21 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSDescAdrPage21 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescAdrPage21 { offset: offset, sym: None,
+                                                    addend: addend }),
+            AArch64Reloc::TLSDescLd64Lo12 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(AArch64Reloc::TLSDescLd64Lo12 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            AArch64Reloc::TLSDescLd64Lo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSDescLd64Lo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescLd64Lo12 { offset: offset, sym: None,
+                                                   addend: addend }),
+            AArch64Reloc::TLSDescAddLo12 { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSDescAddLo12 { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSDescAddLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSDescAddLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescAddLo12 { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSDescOffG1 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(AArch64Reloc::TLSDescOffG1 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            AArch64Reloc::TLSDescOffG1 { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDescOffG1 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescOffG1 { offset: offset, sym: None,
+                                                addend: addend }),
+            AArch64Reloc::TLSDescOffG0NC { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(AArch64Reloc::TLSDescOffG0NC { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            AArch64Reloc::TLSDescOffG0NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSDescOffG0NC { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDescOffG0NC { offset: offset, sym: None,
+                                                  addend: addend }),
+            AArch64Reloc::TLSDescLdr { sym: Some(Ok(name)), offset } =>
+                Ok(AArch64Reloc::TLSDescLdr { offset: offset,
+                                              sym: Some(name) }),
+            AArch64Reloc::TLSDescLdr { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDescLdr { sym: None, offset } =>
+                Ok(AArch64Reloc::TLSDescLdr { offset: offset, sym: None }),
+            AArch64Reloc::TLSDescAdd { sym: Some(Ok(name)), offset } =>
+                Ok(AArch64Reloc::TLSDescAdd { offset: offset,
+                                              sym: Some(name) }),
+            AArch64Reloc::TLSDescAdd { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDescAdd { sym: None, offset } =>
+                Ok(AArch64Reloc::TLSDescAdd { offset: offset, sym: None }),
+            AArch64Reloc::TLSDescCall { sym: Some(Ok(name)), offset } =>
+                Ok(AArch64Reloc::TLSDescCall { offset: offset,
+                                               sym: Some(name) }),
+            AArch64Reloc::TLSDescCall { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDescCall { sym: None, offset } =>
+                Ok(AArch64Reloc::TLSDescCall { offset: offset, sym: None }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { sym: Some(Ok(name)), offset,
+                                                  addend } =>
+                Ok(AArch64Reloc::TLSLELdSt128TPRelLo12 { offset: offset,
+                                                         sym: Some(name),
+                                                         addend: addend }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSLELdSt128TPRelLo12 { offset: offset,
+                                                         sym: None,
+                                                         addend: addend }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { sym: Some(Ok(name)), offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset: offset,
+                                                           sym: Some(name),
+                                                           addend: addend }),
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC { sym: None, offset,
+                                                    addend } =>
+                Ok(AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset: offset,
+                                                           sym: None,
+                                                           addend: addend }),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { sym: Some(Ok(name)), offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset: offset,
+                                                          sym: Some(name),
+                                                          addend: addend }),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { sym: Some(Err(err)), .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 { sym: None, offset,
+                                                   addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset: offset,
+                                                          sym: None,
+                                                          addend: addend }),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { sym: Some(Ok(name)),
+                                                     offset, addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset: offset,
+                                                            sym: Some(name),
+                                                            addend: addend }),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { sym: Some(Err(err)),
+                                                     .. } =>
+                Err(err),
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { sym: None, offset,
+                                                     addend } =>
+                Ok(AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset: offset,
+                                                            sym: None,
+                                                            addend: addend }),
+            AArch64Reloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(AArch64Reloc::Copy { offset: offset, sym: Some(name) }),
+            AArch64Reloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::Copy { sym: None, offset } =>
+                Ok(AArch64Reloc::Copy { offset: offset, sym: None }),
+            AArch64Reloc::GlobalData { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::GlobalData { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            AArch64Reloc::GlobalData { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::GlobalData { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::GlobalData { offset: offset, sym: None,
+                                              addend: addend }),
+            AArch64Reloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::JumpSlot { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            AArch64Reloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::JumpSlot { offset: offset, sym: None,
+                                            addend: addend }),
+            AArch64Reloc::Relative { offset, addend } =>
+                Ok(AArch64Reloc::Relative { offset: offset, addend: addend }),
+            AArch64Reloc::TLSDTPMod { sym: Some(Ok(name)), offset } =>
+                Ok(AArch64Reloc::TLSDTPMod { offset: offset, sym: Some(name) }),
+            AArch64Reloc::TLSDTPMod { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDTPMod { sym: None, offset } =>
+                Ok(AArch64Reloc::TLSDTPMod { offset: offset, sym: None }),
+            AArch64Reloc::TLSDTPRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::TLSDTPRel { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            AArch64Reloc::TLSDTPRel { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDTPRel { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDTPRel { offset: offset, sym: None,
+                                             addend: addend }),
+            AArch64Reloc::TLSTPRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::TLSTPRel { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            AArch64Reloc::TLSTPRel { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSTPRel { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSTPRel { offset: offset, sym: None,
+                                            addend: addend }),
+            AArch64Reloc::TLSDesc { sym: Some(Ok(name)), offset, addend } =>
+                Ok(AArch64Reloc::TLSDesc { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            AArch64Reloc::TLSDesc { sym: Some(Err(err)), .. } => Err(err),
+            AArch64Reloc::TLSDesc { sym: None, offset, addend } =>
+                Ok(AArch64Reloc::TLSDesc { offset: offset, sym: None,
+                                           addend: addend }),
+            AArch64Reloc::IRelative { offset, addend } =>
+                Ok(AArch64Reloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> From<AArch64RelocStrSym<'a>> for AArch64RelocStr<'a> {
+    #[inline]
+    fn from(reloc: AArch64RelocStrSym<'a>) -> AArch64RelocStr<'a> {
+        match reloc {
+            AArch64Reloc::None => AArch64Reloc::None,
+            AArch64Reloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::Abs64 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::Abs32 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::Abs16 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::PRel64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::PRel64 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::PRel32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::PRel32 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::PRel16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::PRel16 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::MovWUAbsG0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG0 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWUAbsG0NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWUAbsG0NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWUAbsG1 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG1 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWUAbsG1NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWUAbsG1NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWUAbsG2 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG2 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWUAbsG2NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWUAbsG2NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWUAbsG3 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWUAbsG3 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWSAbsG0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWSAbsG0 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWSAbsG1 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWSAbsG1 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWSAbsG2 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWSAbsG2 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::LdPRelLo19 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::LdPRelLo19 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::AdrPRelLo21 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::AdrPRelLo21 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::AdrPRelPgHi21 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::AdrPRelPgHi21 { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::AdrPRelPgHi21NC { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::AdrPRelPgHi21NC { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::AddAbsLo12NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::AddAbsLo12NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::LdSt8AbsNC { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::LdSt8AbsNC { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::TstBr14 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                AArch64Reloc::TstBr14 { offset: offset, sym: name,
+                                        addend: addend },
+            AArch64Reloc::CondBr19 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::CondBr19 { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::Jump26 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::Jump26 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::Call26 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                AArch64Reloc::Call26 { offset: offset, sym: name,
+                                       addend: addend },
+            AArch64Reloc::LdSt16AbsNC { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::LdSt16AbsNC { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::LdSt32AbsNC { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::LdSt32AbsNC { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::LdSt64AbsNC { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::LdSt64AbsNC { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::MovWPRelG0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG0 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWPRelG0NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWPRelG0NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWPRelG1 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG1 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWPRelG1NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWPRelG1NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWPRelG2 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG2 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::MovWPRelG2NC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWPRelG2NC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWPRelG3 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::MovWPRelG3 { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::LdSt128AbsNC { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::LdSt128AbsNC { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG0 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG0 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG0NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::MovWGOTOffG0NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::MovWGOTOffG1 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG1 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG1NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::MovWGOTOffG1NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::MovWGOTOffG2 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG2 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::MovWGOTOffG2NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::MovWGOTOffG2NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::MovWGOTOffG3 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::MovWGOTOffG3 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::GOTRel64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::GOTRel64 { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::GOTRel32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::GOTRel32 { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::GOTLdPRel19 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::GOTLdPRel19 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::Ld64GOTOffLo15 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::Ld64GOTOffLo15 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::AdrGOTPage { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::AdrGOTPage { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::Ld64GOTLo12NC { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::Ld64GOTLo12NC { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::Ld64GOTPageLo15 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::Ld64GOTPageLo15 { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                AArch64Reloc::PLT32 { offset: offset, sym: name,
+                                      addend: addend },
+            AArch64Reloc::TLSGDAdrPRel21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSGDAdrPRel21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSGDAdrPage21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSGDAdrPage21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSGDAddLo12NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSGDAddLo12NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSGDMovWG1 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::TLSGDMovWG1 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::TLSGDMovWG0NC { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::TLSGDMovWG0NC { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::TLSLDAdrPRel21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSLDAdrPRel21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSLDAdrPage21 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSLDAdrPage21 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSLDAddLo12NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSLDAddLo12NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSLDMovWG1 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                AArch64Reloc::TLSLDMovWG1 { offset: offset, sym: name,
+                                            addend: addend },
+            AArch64Reloc::TLSLDMovWG0NC { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::TLSLDMovWG0NC { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::TLSLDLdPRel19 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                AArch64Reloc::TLSLDLdPRel19 { offset: offset, sym: name,
+                                              addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG2 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG2 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG1 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG1 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG1NC { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG1NC { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG0 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG0 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLDMovWDTPRelG0NC { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLDMovWDTPRelG0NC { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelHi12 { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLDAddDTPRelHi12 { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelLo12 { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLDAddDTPRelLo12 { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLDAddDTPRelLo12NC { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLDAddDTPRelLo12NC { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLDLdSt8DTPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLDLdSt8DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt8DTPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLDLdSt16DTPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLDLdSt16DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt16DTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLDLdSt32DTPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLDLdSt32DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt32DTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLDLdSt64DTPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLDLdSt64DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt64DTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSIEMovWGOTTPRelG1 { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSIEMovWGOTTPRelG1 { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSIEMovWGOTTPRelG0NC { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSIEMovWGOTTPRelG0NC { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSIEAdrGOTTPRelPage21 {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSIEAdrGOTTPRelPage21 { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSIELd64GOTTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSIELd64GOTTPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSIELdGOTTPRelPRel19 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSIELdGOTTPRelPRel19 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG2 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG2 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG1 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG1 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG1NC { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG1NC { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG0 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG0 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSLEMovWTPRelG0NC { sym: SymData { name, .. },
+                                               offset, addend } =>
+                AArch64Reloc::TLSLEMovWTPRelG0NC { offset: offset, sym: name,
+                                                   addend: addend },
+            AArch64Reloc::TLSLEAddTPRelHi12 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLEAddTPRelHi12 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLEAddTPRelLo12 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                AArch64Reloc::TLSLEAddTPRelLo12 { offset: offset, sym: name,
+                                                  addend: addend },
+            AArch64Reloc::TLSLEAddTPRelLo12NC { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLEAddTPRelLo12NC { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLELdSt8TPRelLo12 { sym: SymData { name, .. },
+                                                offset, addend } =>
+                AArch64Reloc::TLSLELdSt8TPRelLo12 { offset: offset, sym: name,
+                                                    addend: addend },
+            AArch64Reloc::TLSLELdSt8TPRelLo12NC { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLELdSt8TPRelLo12NC { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLELdSt16TPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLELdSt16TPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLELdSt16TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt16TPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLELdSt32TPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLELdSt32TPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLELdSt32TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt32TPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLELdSt64TPRelLo12 { sym: SymData { name, .. },
+                                                 offset, addend } =>
+                AArch64Reloc::TLSLELdSt64TPRelLo12 { offset: offset, sym: name,
+                                                     addend: addend },
+            AArch64Reloc::TLSLELdSt64TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt64TPRelLo12NC { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSDescLdPRel19 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::TLSDescLdPRel19 { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::TLSDescAdrPRel21 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSDescAdrPRel21 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSDescAdrPage21 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                AArch64Reloc::TLSDescAdrPage21 { offset: offset, sym: name,
+                                                 addend: addend },
+            AArch64Reloc::TLSDescLd64Lo12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                AArch64Reloc::TLSDescLd64Lo12 { offset: offset, sym: name,
+                                                addend: addend },
+            AArch64Reloc::TLSDescAddLo12 { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSDescAddLo12 { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSDescOffG1 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                AArch64Reloc::TLSDescOffG1 { offset: offset, sym: name,
+                                             addend: addend },
+            AArch64Reloc::TLSDescOffG0NC { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                AArch64Reloc::TLSDescOffG0NC { offset: offset, sym: name,
+                                               addend: addend },
+            AArch64Reloc::TLSDescLdr { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDescLdr { offset: offset, sym: name },
+            AArch64Reloc::TLSDescAdd { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDescAdd { offset: offset, sym: name },
+            AArch64Reloc::TLSDescCall { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDescCall { offset: offset, sym: name },
+            AArch64Reloc::TLSLELdSt128TPRelLo12 { sym: SymData { name, .. },
+                                                  offset, addend } =>
+                AArch64Reloc::TLSLELdSt128TPRelLo12 { offset: offset, sym: name,
+                                                      addend: addend },
+            AArch64Reloc::TLSLELdSt128TPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLELdSt128TPRelLo12NC { offset: offset,
+                                                        sym: name,
+                                                        addend: addend },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12 {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt128DTPRelLo12 { offset: offset,
+                                                       sym: name,
+                                                       addend: addend },
+            AArch64Reloc::TLSLDLdSt128DTPRelLo12NC {
+                sym: SymData { name, .. }, offset, addend
+            } =>
+                AArch64Reloc::TLSLDLdSt128DTPRelLo12NC { offset: offset,
+                                                         sym: name,
+                                                         addend: addend },
+            AArch64Reloc::Copy { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::Copy { offset: offset, sym: name },
+            AArch64Reloc::GlobalData { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                AArch64Reloc::GlobalData { offset: offset, sym: name,
+                                           addend: addend },
+            AArch64Reloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::JumpSlot { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::Relative { offset, addend } =>
+                AArch64Reloc::Relative { offset: offset, addend: addend },
+            AArch64Reloc::TLSDTPMod { sym: SymData { name, .. }, offset } =>
+                AArch64Reloc::TLSDTPMod { offset: offset, sym: name },
+            AArch64Reloc::TLSDTPRel { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                AArch64Reloc::TLSDTPRel { offset: offset, sym: name,
+                                          addend: addend },
+            AArch64Reloc::TLSTPRel { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                AArch64Reloc::TLSTPRel { offset: offset, sym: name,
+                                         addend: addend },
+            AArch64Reloc::TLSDesc { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                AArch64Reloc::TLSDesc { offset: offset, sym: name,
+                                        addend: addend },
+            AArch64Reloc::IRelative { offset, addend } =>
+                AArch64Reloc::IRelative { offset: offset, addend: addend }
+        }
+    }
+}
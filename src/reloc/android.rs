@@ -0,0 +1,1081 @@
+//! Android packed relocation tables.
+//!
+//! This module provides the [AndroidRels] and [AndroidRelas] types,
+//! which act as a wrapper around Android packed relocation table data
+//! (`SHT_ANDROID_REL` and `SHT_ANDROID_RELA` sections, referenced by
+//! the `DT_ANDROID_REL` and `DT_ANDROID_RELA` dynamic entries).
+//!
+//! A packed relocation table consists of the magic string `APS2`,
+//! followed by a sequence of signed LEB128 values.  The first two of
+//! these are the number of relocations and the initial offset.  The
+//! remainder describe groups of relocations.  Each group begins with
+//! its size and a set of flags indicating which fields are shared by
+//! every relocation in the group; those fields are given once in the
+//! group header, and the rest are given for each relocation.  Offsets
+//! and addends are stored as deltas from the previous relocation.
+//!
+//! Decoding a packed table produces the same
+//! [RelData](crate::reloc::RelData) and
+//! [RelaData](crate::reloc::RelaData) values as
+//! [Rels](crate::reloc::Rels) and [Relas](crate::reloc::Relas), so
+//! they can be further resolved and converted to
+//! architecture-specific relocation types in the same way.
+//!
+//! # Examples
+//!
+//! An `AndroidRelas` can be created from a packed relocation table
+//! using the [TryFrom](core::convert::TryFrom) instances, and iterated
+//! over to obtain the decoded relocations:
+//!
+//! ```
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use elf_utils::Elf64;
+//! use elf_utils::reloc::RelaData;
+//! use elf_utils::reloc::android::AndroidRelas;
+//!
+//! const PACKED: [u8; 45] = [
+//!     0x41, 0x50, 0x53, 0x32, 0x07, 0x00, 0x01, 0x0c,
+//!     0xb0, 0x22, 0xe0, 0xfb, 0x00, 0x08, 0x03, 0x0b,
+//!     0x08, 0x08, 0x10, 0x10, 0x10, 0x03, 0x08, 0xe0,
+//!     0x03, 0x86, 0x80, 0x80, 0x80, 0x30, 0xa0, 0x5d,
+//!     0x08, 0x86, 0x80, 0x80, 0x80, 0xc0, 0x00, 0x00,
+//!     0x28, 0x08, 0x88, 0x80, 0x01
+//! ];
+//!
+//! let relas: AndroidRelas<'_, LittleEndian, Elf64> =
+//!     AndroidRelas::try_from(&PACKED[0..]).unwrap();
+//!
+//! assert_eq!(relas.num_relocs(), 7);
+//!
+//! let mut iter = relas.iter();
+//!
+//! assert_eq!(iter.next(), Some(RelaData { offset: 0x3de0, sym: 0,
+//!                                         kind: 8, addend: 0x1130 }));
+//! assert_eq!(iter.next(), Some(RelaData { offset: 0x3de8, sym: 0,
+//!                                         kind: 8, addend: 0x1140 }));
+//! ```
+use byteorder::ByteOrder;
+use byteorder::LittleEndian;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use crate::elf::Elf32;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelDataRaw;
+use crate::reloc::RelaData;
+use crate::reloc::RelaDataRaw;
+use crate::reloc::RelaOffsets;
+
+/// Magic string at the start of a packed relocation table.
+const APS2_MAGIC: [u8; 4] = [b'A', b'P', b'S', b'2'];
+
+/// Group flag indicating all relocations share an info value.
+const GROUPED_BY_INFO: u64 = 0x1;
+/// Group flag indicating all relocations share an offset delta.
+const GROUPED_BY_OFFSET_DELTA: u64 = 0x2;
+/// Group flag indicating all relocations share an addend.
+const GROUPED_BY_ADDEND: u64 = 0x4;
+/// Group flag indicating the relocations have addends.
+const GROUP_HAS_ADDEND: u64 = 0x8;
+
+/// Mask of all known group flags.
+const GROUP_FLAGS_MASK: u64 = GROUPED_BY_INFO | GROUPED_BY_OFFSET_DELTA |
+                              GROUPED_BY_ADDEND | GROUP_HAS_ADDEND;
+
+/// Minimum number of relocations sharing an offset delta and info
+/// value that will be packed into their own group.
+const MIN_GROUP_SIZE: usize = 3;
+
+/// Maximum number of relocations in a group that shares its offset
+/// delta, info value, and addend, and so takes no input to decode.
+///
+/// Larger groups are rejected, as a tiny malformed table could
+/// otherwise take practically forever to decode.
+const MAX_GROUPED_SIZE: usize = 1 << 20;
+
+/// Conversions for Android packed relocation table values.
+///
+/// Values in a packed relocation table are signed LEB128 numbers, and
+/// arithmetic on them wraps at the word size of the ELF class.  This
+/// provides the conversions between these and the relocation fields
+/// for a given ELF class.
+pub trait AndroidRelOffsets: RelaOffsets {
+    /// Convert an offset into a packed value.
+    fn to_packed_offset(offset: Self::Offset) -> u64;
+
+    /// Convert a packed value into an offset.
+    fn from_packed_offset(val: u64) -> Self::Offset;
+
+    /// Convert an addend into a packed value.
+    fn to_packed_addend(addend: Self::Addend) -> i64;
+
+    /// Convert a packed value into an addend.
+    fn from_packed_addend(val: i64) -> Self::Addend;
+
+    /// Truncate a value to the word size of the ELF class and
+    /// sign-extend it, giving the value that is actually encoded.
+    fn packed_value(val: u64) -> i64;
+
+    /// Combine a symbol index and kind tag into a packed info value.
+    #[inline]
+    fn to_packed_info(kind: Self::RelKind, sym: Self::Word) -> u64 {
+        let mut buf = [0; 8];
+
+        Self::write_info::<LittleEndian>(&mut buf[0 .. Self::R_INFO_SIZE],
+                                         kind, sym);
+
+        LittleEndian::read_u64(&buf)
+    }
+
+    /// Split a packed info value into a kind tag and a symbol index.
+    #[inline]
+    fn from_packed_info(val: u64) -> (Self::RelKind, Self::Word) {
+        let mut buf = [0; 8];
+
+        LittleEndian::write_u64(&mut buf, val);
+
+        Self::read_info::<LittleEndian>(&buf[0 .. Self::R_INFO_SIZE])
+    }
+}
+
+/// In-place read-only Android packed relocation table with implicit
+/// addends.
+///
+/// An Android packed relocation table (`SHT_ANDROID_REL`) holds the
+/// same information as an ELF relocation table, compressed as
+/// described in the [module documentation](crate::reloc::android).
+///
+/// An `AndroidRels` is essentially a 'handle' for raw ELF data.  The
+/// table is checked when the `AndroidRels` is created using the
+/// [TryFrom](core::convert::TryFrom) instance, after which it can be
+/// iterated over with [iter](AndroidRels::iter) to obtain
+/// [RelData](crate::reloc::RelData) for each relocation.
+///
+/// New `AndroidRels` can be created from an iterator over
+/// [RelData](crate::reloc::RelData) with
+/// [create](AndroidRels::create) or
+/// [create_split](AndroidRels::create_split).
+#[derive(Copy, Clone)]
+pub struct AndroidRels<'a, B: ByteOrder, Offsets: AndroidRelOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a [u8],
+    num_relocs: usize
+}
+
+/// In-place read-only Android packed relocation table with explicit
+/// addends.
+///
+/// An Android packed relocation table (`SHT_ANDROID_RELA`) holds the
+/// same information as an ELF relocation table, compressed as
+/// described in the [module documentation](crate::reloc::android).
+///
+/// An `AndroidRelas` is essentially a 'handle' for raw ELF data.  The
+/// table is checked when the `AndroidRelas` is created using the
+/// [TryFrom](core::convert::TryFrom) instance, after which it can be
+/// iterated over with [iter](AndroidRelas::iter) to obtain
+/// [RelaData](crate::reloc::RelaData) for each relocation.
+///
+/// New `AndroidRelas` can be created from an iterator over
+/// [RelaData](crate::reloc::RelaData) with
+/// [create](AndroidRelas::create) or
+/// [create_split](AndroidRelas::create_split).
+#[derive(Copy, Clone)]
+pub struct AndroidRelas<'a, B: ByteOrder, Offsets: AndroidRelOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a [u8],
+    num_relocs: usize
+}
+
+/// Iterator for [AndroidRels].
+///
+/// This iterator produces [RelData](crate::reloc::RelData)s decoded
+/// from an underlying `AndroidRels`.
+#[derive(Clone)]
+pub struct AndroidRelIter<'a, B: ByteOrder, Offsets: AndroidRelOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a [u8],
+    decoder: Decoder
+}
+
+/// Iterator for [AndroidRelas].
+///
+/// This iterator produces [RelaData](crate::reloc::RelaData)s decoded
+/// from an underlying `AndroidRelas`.
+#[derive(Clone)]
+pub struct AndroidRelaIter<'a, B: ByteOrder, Offsets: AndroidRelOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a [u8],
+    decoder: Decoder
+}
+
+/// Errors that can occur creating an [AndroidRels] or [AndroidRelas].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum AndroidRelocsError {
+    /// The data does not begin with the `APS2` magic string.
+    BadMagic,
+    /// The data ended in the middle of the table.
+    TooShort,
+    /// The number of relocations is negative, or more than the table
+    /// could encode.
+    BadCount(i64),
+    /// A group size is negative, larger than the number of remaining
+    /// relocations, or more than the table could encode.
+    BadGroupSize(i64),
+    /// A group has unknown flags, or has addends in a table with
+    /// implicit addends.
+    BadFlags(i64)
+}
+
+/// Decoding state for a packed relocation table.
+#[derive(Clone)]
+struct Decoder {
+    /// Index of the next byte to decode.
+    idx: usize,
+    /// Number of relocations remaining in the table.
+    remaining: u64,
+    /// Number of relocations remaining in the current group.
+    group_left: u64,
+    /// Flags for the current group.
+    flags: u64,
+    /// Offset delta for the current group.
+    delta: u64,
+    /// Offset of the previous relocation.
+    offset: u64,
+    /// Info value of the previous relocation.
+    info: u64,
+    /// Addend of the previous relocation.
+    addend: i64
+}
+
+fn read_sleb(data: &[u8], idx: usize) ->
+    Result<(i64, usize), AndroidRelocsError> {
+    let mut idx = idx;
+    let mut shift = 0;
+    let mut val: i64 = 0;
+
+    loop {
+        match data.get(idx) {
+            Some(byte) => {
+                if shift < 64 {
+                    val |= ((byte & 0x7f) as i64) << shift;
+                }
+
+                shift += 7;
+                idx += 1;
+
+                if byte & 0x80 == 0 {
+                    if shift < 64 && byte & 0x40 != 0 {
+                        val |= -1 << shift;
+                    }
+
+                    return Ok((val, idx))
+                }
+            },
+            None => return Err(AndroidRelocsError::TooShort)
+        }
+    }
+}
+
+fn write_sleb<F>(val: i64, emit: &mut F) -> Result<(), ()>
+    where F: FnMut(u8) -> Result<(), ()> {
+    let mut val = val;
+
+    loop {
+        let byte = (val & 0x7f) as u8;
+
+        val >>= 7;
+
+        if (val == 0 && byte & 0x40 == 0) || (val == -1 && byte & 0x40 != 0) {
+            return emit(byte)
+        } else {
+            emit(byte | 0x80)?;
+        }
+    }
+}
+
+impl Decoder {
+    fn new(data: &[u8]) -> Result<Decoder, AndroidRelocsError> {
+        if data.len() < APS2_MAGIC.len() ||
+           data[0 .. APS2_MAGIC.len()] != APS2_MAGIC {
+            return Err(AndroidRelocsError::BadMagic)
+        }
+
+        let (count, idx) = read_sleb(data, APS2_MAGIC.len())?;
+        let (offset, idx) = read_sleb(data, idx)?;
+
+        // Each relocation takes at least a byte to decode, unless it
+        // is in a group of at most MAX_GROUPED_SIZE relocations.
+        let max = ((data.len() - idx) as u64)
+            .saturating_mul(MAX_GROUPED_SIZE as u64);
+
+        if count < 0 || count as u64 > max {
+            return Err(AndroidRelocsError::BadCount(count))
+        }
+
+        Ok(Decoder { idx: idx, remaining: count as u64, group_left: 0,
+                     flags: 0, delta: 0, offset: offset as u64, info: 0,
+                     addend: 0 })
+    }
+
+    /// Decode the next relocation, giving its offset, info value, and
+    /// addend.
+    fn next(&mut self, data: &[u8], rela: bool) ->
+        Result<Option<(u64, u64, i64)>, AndroidRelocsError> {
+        if self.remaining == 0 {
+            return Ok(None)
+        }
+
+        while self.group_left == 0 {
+            let (size, idx) = read_sleb(data, self.idx)?;
+            let (flags, mut idx) = read_sleb(data, idx)?;
+
+            if size < 0 || size as u64 > self.remaining {
+                return Err(AndroidRelocsError::BadGroupSize(size))
+            }
+
+            if flags as u64 & !GROUP_FLAGS_MASK != 0 ||
+               (!rela && flags as u64 & GROUP_HAS_ADDEND != 0) {
+                return Err(AndroidRelocsError::BadFlags(flags))
+            }
+
+            let flags = flags as u64;
+            let grouped = GROUPED_BY_INFO | GROUPED_BY_OFFSET_DELTA;
+            let limit = if flags & grouped == grouped &&
+                           (flags & GROUP_HAS_ADDEND == 0 ||
+                            flags & GROUPED_BY_ADDEND != 0) {
+                MAX_GROUPED_SIZE
+            } else {
+                data.len() - idx
+            };
+
+            if size as u64 > limit as u64 {
+                return Err(AndroidRelocsError::BadGroupSize(size))
+            }
+
+            if flags & GROUPED_BY_OFFSET_DELTA != 0 {
+                let (delta, next) = read_sleb(data, idx)?;
+
+                self.delta = delta as u64;
+                idx = next;
+            }
+
+            if flags & GROUPED_BY_INFO != 0 {
+                let (info, next) = read_sleb(data, idx)?;
+
+                self.info = info as u64;
+                idx = next;
+            }
+
+            if flags & GROUP_HAS_ADDEND == 0 {
+                self.addend = 0;
+            } else if flags & GROUPED_BY_ADDEND != 0 {
+                let (addend, next) = read_sleb(data, idx)?;
+
+                self.addend = self.addend.wrapping_add(addend);
+                idx = next;
+            }
+
+            self.idx = idx;
+            self.flags = flags;
+            self.group_left = size as u64;
+        }
+
+        if self.flags & GROUPED_BY_OFFSET_DELTA != 0 {
+            self.offset = self.offset.wrapping_add(self.delta);
+        } else {
+            let (delta, idx) = read_sleb(data, self.idx)?;
+
+            self.offset = self.offset.wrapping_add(delta as u64);
+            self.idx = idx;
+        }
+
+        if self.flags & GROUPED_BY_INFO == 0 {
+            let (info, idx) = read_sleb(data, self.idx)?;
+
+            self.info = info as u64;
+            self.idx = idx;
+        }
+
+        if self.flags & GROUP_HAS_ADDEND != 0 &&
+           self.flags & GROUPED_BY_ADDEND == 0 {
+            let (addend, idx) = read_sleb(data, self.idx)?;
+
+            self.addend = self.addend.wrapping_add(addend);
+            self.idx = idx;
+        }
+
+        self.group_left -= 1;
+        self.remaining -= 1;
+
+        Ok(Some((self.offset, self.info, self.addend)))
+    }
+}
+
+/// Check the whole table, and get the number of relocations.
+fn check_relocs(data: &[u8], rela: bool) -> Result<usize, AndroidRelocsError> {
+    let mut decoder = Decoder::new(data)?;
+    let mut count = 0;
+
+    while decoder.next(data, rela)?.is_some() {
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+/// Get the length of the run of relocations sharing an offset delta
+/// and info value, starting at the head of `relocs`, up to `limit`.
+fn run_len<I>(relocs: I, prev: u64, limit: usize) -> usize
+    where I: Iterator<Item = (u64, u64, i64)> {
+    let mut relocs = relocs;
+
+    match relocs.next() {
+        Some((offset, info, _)) => {
+            let delta = offset.wrapping_sub(prev);
+            let mut last = offset;
+            let mut len = 1;
+
+            for (offset, next_info, _) in relocs {
+                if len >= limit || next_info != info ||
+                   offset.wrapping_sub(last) != delta {
+                    break;
+                }
+
+                last = offset;
+                len += 1;
+            }
+
+            len
+        },
+        None => 0
+    }
+}
+
+/// Get the length of the run of relocations at the head of `relocs`
+/// that won't be packed into their own groups.
+fn batch_len<I>(relocs: I, prev: u64) -> usize
+    where I: Iterator<Item = (u64, u64, i64)> + Clone {
+    let mut relocs = relocs;
+    let mut prev = prev;
+    let mut len = 0;
+
+    loop {
+        if len > 0 && run_len(relocs.clone(), prev,
+                              MIN_GROUP_SIZE) >= MIN_GROUP_SIZE {
+            return len
+        }
+
+        match relocs.next() {
+            Some((offset, _, _)) => {
+                prev = offset;
+                len += 1;
+            },
+            None => return len
+        }
+    }
+}
+
+fn encode_relocs<I, Offsets, F>(relocs: I, rela: bool, mut emit: F) ->
+    Result<(), ()>
+    where I: Iterator<Item = (u64, u64, i64)> + Clone,
+          Offsets: AndroidRelOffsets,
+          F: FnMut(u8) -> Result<(), ()> {
+    let mut relocs = relocs;
+    let mut offset: u64 = 0;
+    let mut addend: i64 = 0;
+
+    for byte in APS2_MAGIC.iter() {
+        emit(*byte)?;
+    }
+
+    write_sleb(relocs.clone().count() as i64, &mut emit)?;
+    write_sleb(0, &mut emit)?;
+
+    loop {
+        let run = run_len(relocs.clone(), offset, MAX_GROUPED_SIZE);
+        let (size, grouped) = if run >= MIN_GROUP_SIZE {
+            (run, true)
+        } else {
+            (batch_len(relocs.clone(), offset), false)
+        };
+
+        if size == 0 {
+            return Ok(())
+        }
+
+        let group = relocs.clone().take(size);
+        let mut addends = group.clone().map(|(_, _, addend)| addend);
+        let first = addends.next().unwrap_or(0);
+        let (has_addend, by_addend) = if !rela {
+            (false, false)
+        } else if addends.clone().all(|addend| addend == first) {
+            (first != 0, first != 0)
+        } else {
+            (true, false)
+        };
+        let mut flags = 0;
+
+        if grouped {
+            flags |= GROUPED_BY_INFO | GROUPED_BY_OFFSET_DELTA;
+        }
+
+        if has_addend {
+            flags |= GROUP_HAS_ADDEND;
+        }
+
+        if by_addend {
+            flags |= GROUPED_BY_ADDEND;
+        }
+
+        write_sleb(size as i64, &mut emit)?;
+        write_sleb(flags as i64, &mut emit)?;
+
+        if grouped {
+            if let Some((first_offset, info, _)) = group.clone().next() {
+                let delta = first_offset.wrapping_sub(offset);
+
+                write_sleb(Offsets::packed_value(delta), &mut emit)?;
+                write_sleb(Offsets::packed_value(info), &mut emit)?;
+            }
+        }
+
+        if by_addend {
+            let delta = first.wrapping_sub(addend) as u64;
+
+            write_sleb(Offsets::packed_value(delta), &mut emit)?;
+            addend = first;
+        } else if !has_addend {
+            addend = 0;
+        }
+
+        for (next_offset, info, next_addend) in group {
+            if !grouped {
+                let delta = next_offset.wrapping_sub(offset);
+
+                write_sleb(Offsets::packed_value(delta), &mut emit)?;
+                write_sleb(Offsets::packed_value(info), &mut emit)?;
+            }
+
+            offset = next_offset;
+
+            if has_addend && !by_addend {
+                let delta = next_addend.wrapping_sub(addend) as u64;
+
+                write_sleb(Offsets::packed_value(delta), &mut emit)?;
+                addend = next_addend;
+            }
+        }
+
+        relocs.nth(size - 1);
+    }
+}
+
+fn create_packed<'a, I, Offsets>(buf: &'a mut [u8], relocs: I, rela: bool) ->
+    Result<(&'a [u8], &'a mut [u8]), ()>
+    where I: Iterator<Item = (u64, u64, i64)> + Clone,
+          Offsets: AndroidRelOffsets {
+    let len = buf.len();
+    let mut idx = 0;
+
+    encode_relocs::<I, Offsets, _>(relocs, rela, |byte| {
+        if idx < len {
+            buf[idx] = byte;
+            idx += 1;
+
+            Ok(())
+        } else {
+            Err(())
+        }
+    })?;
+
+    let (data, rest) = buf.split_at_mut(idx);
+
+    Ok((data, rest))
+}
+
+fn packed_len<I, Offsets>(relocs: I, rela: bool) -> usize
+    where I: Iterator<Item = (u64, u64, i64)> + Clone,
+          Offsets: AndroidRelOffsets {
+    let mut len = 0;
+
+    // Counting can't fail.
+    let _ = encode_relocs::<I, Offsets, _>(relocs, rela, |_| {
+        len += 1;
+
+        Ok(())
+    });
+
+    len
+}
+
+fn rel_value<R, Offsets>(rel: R) -> (u64, u64, i64)
+    where R: Borrow<RelDataRaw<Offsets>>,
+          Offsets: AndroidRelOffsets {
+    let rel = rel.borrow();
+
+    (Offsets::to_packed_offset(rel.offset),
+     Offsets::to_packed_info(rel.kind, rel.sym), 0)
+}
+
+fn rela_value<R, Offsets>(rela: R) -> (u64, u64, i64)
+    where R: Borrow<RelaDataRaw<Offsets>>,
+          Offsets: AndroidRelOffsets {
+    let rela = rela.borrow();
+
+    (Offsets::to_packed_offset(rela.offset),
+     Offsets::to_packed_info(rela.kind, rela.sym),
+     Offsets::to_packed_addend(rela.addend))
+}
+
+impl AndroidRelOffsets for Elf32 {
+    #[inline]
+    fn to_packed_offset(offset: Self::Offset) -> u64 {
+        offset as u64
+    }
+
+    #[inline]
+    fn from_packed_offset(val: u64) -> Self::Offset {
+        val as u32
+    }
+
+    #[inline]
+    fn to_packed_addend(addend: Self::Addend) -> i64 {
+        addend as i64
+    }
+
+    #[inline]
+    fn from_packed_addend(val: i64) -> Self::Addend {
+        val as i32
+    }
+
+    #[inline]
+    fn packed_value(val: u64) -> i64 {
+        val as u32 as i32 as i64
+    }
+}
+
+impl AndroidRelOffsets for Elf64 {
+    #[inline]
+    fn to_packed_offset(offset: Self::Offset) -> u64 {
+        offset
+    }
+
+    #[inline]
+    fn from_packed_offset(val: u64) -> Self::Offset {
+        val
+    }
+
+    #[inline]
+    fn to_packed_addend(addend: Self::Addend) -> i64 {
+        addend
+    }
+
+    #[inline]
+    fn from_packed_addend(val: i64) -> Self::Addend {
+        val
+    }
+
+    #[inline]
+    fn packed_value(val: u64) -> i64 {
+        val as i64
+    }
+}
+
+/// Calculate the number of bytes required to represent the packed
+/// relocation table containing `rels`.
+pub fn rels_required_bytes<I, Offsets>(rels: I) -> usize
+    where I: Iterator + Clone,
+          I::Item: Borrow<RelDataRaw<Offsets>>,
+          Offsets: AndroidRelOffsets {
+    packed_len::<_, Offsets>(rels.map(rel_value::<I::Item, Offsets>), false)
+}
+
+/// Calculate the number of bytes required to represent the packed
+/// relocation table containing `relas`.
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::Elf64;
+/// use elf_utils::reloc::RelaData;
+/// use elf_utils::reloc::RelaDataRaw;
+/// use elf_utils::reloc::android;
+///
+/// const RELAS: [RelaDataRaw<Elf64>; 4] = [
+///     RelaData { offset: 0x3de0, sym: 0, kind: 8, addend: 0x1130 },
+///     RelaData { offset: 0x3de8, sym: 0, kind: 8, addend: 0x1140 },
+///     RelaData { offset: 0x3df0, sym: 0, kind: 8, addend: 0x1150 },
+///     RelaData { offset: 0x3df8, sym: 0, kind: 8, addend: 0x1160 }
+/// ];
+///
+/// assert_eq!(android::relas_required_bytes(RELAS.iter()), 21);
+/// ```
+pub fn relas_required_bytes<I, Offsets>(relas: I) -> usize
+    where I: Iterator + Clone,
+          I::Item: Borrow<RelaDataRaw<Offsets>>,
+          Offsets: AndroidRelOffsets {
+    packed_len::<_, Offsets>(relas.map(rela_value::<I::Item, Offsets>), true)
+}
+
+impl<'a, B, Offsets> AndroidRels<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    /// Attempt to create an `AndroidRels` in `buf` containing the
+    /// relocations in `rels`.
+    ///
+    /// This will pack the relocations into groups and write the table
+    /// into the buffer.  Relocations are packed in the order in which
+    /// they are given; sorting them by offset will generally give a
+    /// smaller table.  Returns both the `AndroidRels` and the
+    /// remaining space if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the relocation table doesn't
+    /// fit into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::Elf32;
+    /// use elf_utils::reloc::RelData;
+    /// use elf_utils::reloc::RelDataRaw;
+    /// use elf_utils::reloc::android::AndroidRels;
+    ///
+    /// const RELS: [RelDataRaw<Elf32>; 5] = [
+    ///     RelData { offset: 0x1f00, sym: 0, kind: 8 },
+    ///     RelData { offset: 0x1f04, sym: 0, kind: 8 },
+    ///     RelData { offset: 0x1f08, sym: 0, kind: 8 },
+    ///     RelData { offset: 0x1f0c, sym: 0, kind: 8 },
+    ///     RelData { offset: 0x1ff0, sym: 3, kind: 6 }
+    /// ];
+    ///
+    /// let mut buf = [0; 32];
+    /// let res: Result<(AndroidRels<'_, LittleEndian, Elf32>,
+    ///                  &'_ mut [u8]), ()> =
+    ///     AndroidRels::create_split(&mut buf[0..], RELS.iter());
+    /// let (rels, rest) = res.unwrap();
+    ///
+    /// assert_eq!(rels.num_relocs(), 5);
+    ///
+    /// let mut iter = rels.iter();
+    ///
+    /// for i in 0 .. 5 {
+    ///     assert_eq!(iter.next(), Some(RELS[i].clone()));
+    /// }
+    ///
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn create_split<I>(buf: &'a mut [u8], rels: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator + Clone,
+              I::Item: Borrow<RelDataRaw<Offsets>> {
+        let num_relocs = rels.clone().count();
+        let relocs = rels.map(rel_value::<I::Item, Offsets>);
+        let (data, rest) = create_packed::<_, Offsets>(buf, relocs, false)?;
+
+        Ok((AndroidRels { byteorder: PhantomData, offsets: PhantomData,
+                          data: data, num_relocs: num_relocs }, rest))
+    }
+
+    /// Attempt to create an `AndroidRels` in `buf` containing the
+    /// relocations in `rels` (see
+    /// [create_split](AndroidRels::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the relocation table doesn't
+    /// fit into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], rels: I) -> Result<Self, ()>
+        where I: Iterator + Clone,
+              I::Item: Borrow<RelDataRaw<Offsets>> {
+        match Self::create_split(buf, rels) {
+            Ok((out, _)) => Ok(out),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the number of relocations in this `AndroidRels`.
+    #[inline]
+    pub fn num_relocs(&self) -> usize {
+        self.num_relocs
+    }
+
+    /// Get an iterator over this `AndroidRels`.
+    #[inline]
+    pub fn iter(&self) -> AndroidRelIter<'a, B, Offsets> {
+        match Decoder::new(self.data) {
+            Ok(decoder) => AndroidRelIter {
+                byteorder: PhantomData, offsets: PhantomData,
+                data: self.data, decoder: decoder
+            },
+            Err(_) => unreachable!("table was checked on creation")
+        }
+    }
+}
+
+impl<'a, B, Offsets> AndroidRelas<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    /// Attempt to create an `AndroidRelas` in `buf` containing the
+    /// relocations in `relas`.
+    ///
+    /// This will pack the relocations into groups and write the table
+    /// into the buffer.  Relocations are packed in the order in which
+    /// they are given; sorting them by offset will generally give a
+    /// smaller table.  Returns both the `AndroidRelas` and the
+    /// remaining space if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the relocation table doesn't
+    /// fit into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::reloc::RelaData;
+    /// use elf_utils::reloc::RelaDataRaw;
+    /// use elf_utils::reloc::android::AndroidRelas;
+    ///
+    /// const RELAS: [RelaDataRaw<Elf64>; 7] = [
+    ///     RelaData { offset: 0x3de0, sym: 0, kind: 8, addend: 0x1130 },
+    ///     RelaData { offset: 0x3de8, sym: 0, kind: 8, addend: 0x1140 },
+    ///     RelaData { offset: 0x3df0, sym: 0, kind: 8, addend: 0x1150 },
+    ///     RelaData { offset: 0x3df8, sym: 0, kind: 8, addend: 0x1160 },
+    ///     RelaData { offset: 0x3fd8, sym: 3, kind: 6, addend: 0 },
+    ///     RelaData { offset: 0x3fe0, sym: 4, kind: 6, addend: 0 },
+    ///     RelaData { offset: 0x4008, sym: 0, kind: 8, addend: 0x4008 }
+    /// ];
+    ///
+    /// let mut buf = [0; 64];
+    /// let res: Result<(AndroidRelas<'_, LittleEndian, Elf64>,
+    ///                  &'_ mut [u8]), ()> =
+    ///     AndroidRelas::create_split(&mut buf[0..], RELAS.iter());
+    /// let (relas, rest) = res.unwrap();
+    ///
+    /// assert_eq!(rest.len(), 19);
+    ///
+    /// let mut iter = relas.iter();
+    ///
+    /// for i in 0 .. 7 {
+    ///     assert_eq!(iter.next(), Some(RELAS[i].clone()));
+    /// }
+    ///
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn create_split<I>(buf: &'a mut [u8], relas: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator + Clone,
+              I::Item: Borrow<RelaDataRaw<Offsets>> {
+        let num_relocs = relas.clone().count();
+        let relocs = relas.map(rela_value::<I::Item, Offsets>);
+        let (data, rest) = create_packed::<_, Offsets>(buf, relocs, true)?;
+
+        Ok((AndroidRelas { byteorder: PhantomData, offsets: PhantomData,
+                           data: data, num_relocs: num_relocs }, rest))
+    }
+
+    /// Attempt to create an `AndroidRelas` in `buf` containing the
+    /// relocations in `relas` (see
+    /// [create_split](AndroidRelas::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the relocation table doesn't
+    /// fit into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], relas: I) -> Result<Self, ()>
+        where I: Iterator + Clone,
+              I::Item: Borrow<RelaDataRaw<Offsets>> {
+        match Self::create_split(buf, relas) {
+            Ok((out, _)) => Ok(out),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the number of relocations in this `AndroidRelas`.
+    #[inline]
+    pub fn num_relocs(&self) -> usize {
+        self.num_relocs
+    }
+
+    /// Get an iterator over this `AndroidRelas`.
+    #[inline]
+    pub fn iter(&self) -> AndroidRelaIter<'a, B, Offsets> {
+        match Decoder::new(self.data) {
+            Ok(decoder) => AndroidRelaIter {
+                byteorder: PhantomData, offsets: PhantomData,
+                data: self.data, decoder: decoder
+            },
+            Err(_) => unreachable!("table was checked on creation")
+        }
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a [u8]> for AndroidRels<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    type Error = AndroidRelocsError;
+
+    /// Create an `AndroidRels` from the data buffer.  This will decode
+    /// the entire table to check that it is well-formed.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<AndroidRels<'a, B, Offsets>,
+                                          Self::Error> {
+        let num_relocs = check_relocs(data, false)?;
+
+        Ok(AndroidRels { byteorder: PhantomData, offsets: PhantomData,
+                         data: data, num_relocs: num_relocs })
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a mut [u8]> for AndroidRels<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    type Error = AndroidRelocsError;
+
+    /// Create an `AndroidRels` from the data buffer.  This will decode
+    /// the entire table to check that it is well-formed.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<AndroidRels<'a, B, Offsets>,
+                                              Self::Error> {
+        AndroidRels::try_from(&*data)
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a [u8]> for AndroidRelas<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    type Error = AndroidRelocsError;
+
+    /// Create an `AndroidRelas` from the data buffer.  This will decode
+    /// the entire table to check that it is well-formed.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<AndroidRelas<'a, B, Offsets>,
+                                          Self::Error> {
+        let num_relocs = check_relocs(data, true)?;
+
+        Ok(AndroidRelas { byteorder: PhantomData, offsets: PhantomData,
+                          data: data, num_relocs: num_relocs })
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a mut [u8]> for AndroidRelas<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    type Error = AndroidRelocsError;
+
+    /// Create an `AndroidRelas` from the data buffer.  This will decode
+    /// the entire table to check that it is well-formed.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<AndroidRelas<'a, B, Offsets>,
+                                              Self::Error> {
+        AndroidRelas::try_from(&*data)
+    }
+}
+
+impl<'a, B, Offsets> Iterator for AndroidRelIter<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    type Item = RelDataRaw<Offsets>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.decoder.remaining as usize;
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.decoder.remaining as usize
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.decoder.next(self.data, false) {
+            Ok(Some((offset, info, _))) => {
+                let (kind, sym) = Offsets::from_packed_info(info);
+
+                Some(RelData { offset: Offsets::from_packed_offset(offset),
+                               sym: sym, kind: kind })
+            },
+            _ => None
+        }
+    }
+}
+
+impl<'a, B, Offsets> FusedIterator for AndroidRelIter<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {}
+
+impl<'a, B, Offsets> ExactSizeIterator for AndroidRelIter<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {}
+
+impl<'a, B, Offsets> Iterator for AndroidRelaIter<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {
+    type Item = RelaDataRaw<Offsets>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.decoder.remaining as usize;
+
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.decoder.remaining as usize
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.decoder.next(self.data, true) {
+            Ok(Some((offset, info, addend))) => {
+                let (kind, sym) = Offsets::from_packed_info(info);
+
+                Some(RelaData { offset: Offsets::from_packed_offset(offset),
+                                sym: sym, kind: kind,
+                                addend: Offsets::from_packed_addend(addend) })
+            },
+            _ => None
+        }
+    }
+}
+
+impl<'a, B, Offsets> FusedIterator for AndroidRelaIter<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {}
+
+impl<'a, B, Offsets> ExactSizeIterator for AndroidRelaIter<'a, B, Offsets>
+    where Offsets: AndroidRelOffsets,
+          B: ByteOrder {}
+
+impl Display for AndroidRelocsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            AndroidRelocsError::BadMagic => write!(f, "bad magic"),
+            AndroidRelocsError::TooShort => write!(f, "data too short"),
+            AndroidRelocsError::BadCount(count) =>
+                write!(f, "bad relocation count {}", count),
+            AndroidRelocsError::BadGroupSize(size) =>
+                write!(f, "bad relocation group size {}", size),
+            AndroidRelocsError::BadFlags(flags) =>
+                write!(f, "bad relocation group flags {:x}", flags)
+        }
+    }
+}
@@ -0,0 +1,843 @@
+//! Application of relocations to section contents.
+//!
+//! This module provides the [Apply] trait, which carries out the
+//! effect of a relocation on the contents of a section, as described
+//! by the `Display` instances of the relocation types.  This is the
+//! core operation of a static linker or a loader.
+//!
+//! Applying a relocation requires the section contents as a mutable
+//! buffer, along with an [ApplyEnv] giving the address at which the
+//! section will be loaded and any Global Offset Table, Procedure
+//! Linkage Table, and thread-local storage addresses, and a resolver
+//! function that gives the address and size of a symbol, along with
+//! its Procedure Linkage Table and Global Offset Table entries, as a
+//! [SymAddr].  Relocations from x32 objects are applied by wrapping
+//! them in an [X32Reloc](crate::reloc::x86_64::X32Reloc).
+//!
+//! Values that do not fit in the relocated field are reported as
+//! errors, as are dynamic relocations applied to misaligned words.
+//! Nothing is ever silently truncated.
+//!
+//! # Examples
+//!
+//! ```
+//! use elf_utils::reloc::apply::Apply;
+//! use elf_utils::reloc::apply::ApplyEnv;
+//! use elf_utils::reloc::apply::ApplyError;
+//! use elf_utils::reloc::apply::SymAddr;
+//! use elf_utils::reloc::x86_64::X86_64Reloc;
+//!
+//! let mut text = [0xe8, 0x00, 0x00, 0x00, 0x00];
+//! let env = ApplyEnv { section: 0x401000, base: 0, got: None,
+//!                      plt: None, tls: None };
+//! let call: X86_64Reloc<&str> =
+//!     X86_64Reloc::PC32 { offset: 1, sym: "main", addend: -4 };
+//!
+//! call.apply(&mut text[0..], &env, |name| match *name {
+//!     "main" => Some(SymAddr { addr: 0x401100, size: 0x20, plt: None,
+//!                              got: None }),
+//!     _ => None
+//! }).expect("Expected success");
+//!
+//! assert_eq!(text, [0xe8, 0xfb, 0x00, 0x00, 0x00]);
+//!
+//! let far: X86_64Reloc<&str> =
+//!     X86_64Reloc::PC32 { offset: 1, sym: "far", addend: -4 };
+//! let res = far.apply(&mut text[0..], &env, |_| {
+//!     Some(SymAddr { addr: 0x100000000000, size: 0, plt: None, got: None })
+//! });
+//!
+//! assert_eq!(res, Err(ApplyError::Overflow(1)));
+//! ```
+use byteorder::ByteOrder;
+use byteorder::LittleEndian;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::reloc::x86::X86Reloc;
+use crate::reloc::x86_64::X32Reloc;
+use crate::reloc::x86_64::X86_64Reloc;
+
+/// Trait for relocations that can be applied to section contents.
+///
+/// The relocation's `offset` is taken as an offset into the section
+/// buffer, and the address of the relocated field is computed by
+/// adding it to [section](ApplyEnv::section).
+pub trait Apply {
+    /// Type of symbol references.
+    type Name;
+    /// Type of addresses.
+    type Addr;
+
+    /// Apply this relocation to `section`, using only the explicit
+    /// addend.
+    ///
+    /// This is the behavior for relocations obtained from a
+    /// [Rela](crate::reloc::Rela).  Symbols are looked up with
+    /// `resolve`, which should return `None` if a symbol cannot be
+    /// resolved.
+    fn apply<F>(&self, section: &mut [u8], env: &ApplyEnv<Self::Addr>,
+                resolve: F) -> Result<(), ApplyError<Self::Addr>>
+        where F: FnMut(&Self::Name) -> Option<SymAddr<Self::Addr>>;
+
+    /// Apply this relocation to `section`, adding the current
+    /// contents of the relocated field to the addend.
+    ///
+    /// This is the behavior for relocations obtained from a
+    /// [Rel](crate::reloc::Rel), which store the addend in the
+    /// relocated field itself.
+    /// Relocations whose value does not involve an addend, such as
+    /// Global Offset Table and Procedure Linkage Table slots, simply
+    /// overwrite the field.
+    fn apply_implicit<F>(&self, section: &mut [u8],
+                         env: &ApplyEnv<Self::Addr>, resolve: F) ->
+        Result<(), ApplyError<Self::Addr>>
+        where F: FnMut(&Self::Name) -> Option<SymAddr<Self::Addr>>;
+}
+
+/// Addresses used to compute thread-local storage relocations.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TLSBases<Addr> {
+    /// Module index of the object being relocated.
+    pub module: Addr,
+    /// Address of the thread-local storage block for the module.
+    pub block: Addr,
+    /// Value of the thread pointer.
+    pub thread_ptr: Addr
+}
+
+/// Addresses needed to apply relocations to a section.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ApplyEnv<Addr> {
+    /// Address at which the section is loaded.
+    pub section: Addr,
+    /// Base address of the loaded image.
+    pub base: Addr,
+    /// Address of the Global Offset Table, if there is one.
+    pub got: Option<Addr>,
+    /// Address of the Procedure Linkage Table, if there is one.
+    pub plt: Option<Addr>,
+    /// Thread-local storage addresses, if available.
+    pub tls: Option<TLSBases<Addr>>
+}
+
+/// Resolved symbol information, as returned by a resolver function.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SymAddr<Addr> {
+    /// Address of the symbol.
+    pub addr: Addr,
+    /// Size of the symbol.
+    pub size: Addr,
+    /// Address of the symbol's Procedure Linkage Table entry, if it
+    /// has one.
+    pub plt: Option<Addr>,
+    /// Offset of the symbol's Global Offset Table entry from the
+    /// start of the Global Offset Table, if it has one.
+    pub got: Option<Addr>
+}
+
+/// Errors that can occur applying a relocation.
+///
+/// Most errors carry the offset of the relocated field.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ApplyError<Offset> {
+    /// The relocated field lies outside the section.
+    OutOfBounds(Offset),
+    /// The relocated field is not aligned to its size.
+    Misaligned(Offset),
+    /// The computed value does not fit in the relocated field.
+    Overflow(Offset),
+    /// The symbol could not be resolved.
+    Unresolved(Offset),
+    /// The Global Offset Table address or the symbol's Global Offset
+    /// Table entry is needed, but was not given.
+    NoGOT(Offset),
+    /// The symbol's Procedure Linkage Table entry is needed, but was
+    /// not given.
+    NoPLT(Offset),
+    /// Thread-local storage addresses are needed, but were not given.
+    NoTLS(Offset),
+    /// The relocation cannot be carried out by patching the section
+    /// contents, and must be handled by a linker.
+    Unsupported
+}
+
+/// How to check a computed value against the width of its field.
+#[derive(Clone, Copy)]
+enum Check {
+    /// The value must fit as a signed integer.
+    Signed,
+    /// The value must fit as an unsigned integer.
+    Unsigned,
+    /// The value must fit as either a signed or unsigned integer.
+    Bitfield,
+    /// The value is truncated to the field width.
+    Wrap
+}
+
+/// A computed patch to a section.
+struct Patch {
+    /// Offset of the field.
+    offset: u64,
+    /// Width of the field in bytes.
+    width: usize,
+    /// Overflow check.
+    check: Check,
+    /// Whether the field must be naturally aligned.
+    aligned: bool,
+    /// Whether the value is computed from the addend, so that an
+    /// implicit addend must be added to it.
+    addend: bool,
+    /// Value to store, before adding any implicit addend.
+    value: i128
+}
+
+impl<Offset> Display for ApplyError<Offset>
+    where Offset: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ApplyError::OutOfBounds(offset) =>
+                write!(f, "relocation at {} out of bounds", offset),
+            ApplyError::Misaligned(offset) =>
+                write!(f, "misaligned relocation at {}", offset),
+            ApplyError::Overflow(offset) =>
+                write!(f, "relocation at {} overflows its field", offset),
+            ApplyError::Unresolved(offset) =>
+                write!(f, "unresolved symbol for relocation at {}", offset),
+            ApplyError::NoGOT(offset) =>
+                write!(f, "no GOT address for relocation at {}", offset),
+            ApplyError::NoPLT(offset) =>
+                write!(f, "no PLT address for relocation at {}", offset),
+            ApplyError::NoTLS(offset) =>
+                write!(f, "no TLS addresses for relocation at {}", offset),
+            ApplyError::Unsupported => write!(f, "unsupported relocation")
+        }
+    }
+}
+
+impl Patch {
+    #[inline]
+    fn new(offset: u64, width: usize, check: Check, value: i128) -> Patch {
+        Patch { offset: offset, width: width, check: check, aligned: false,
+                addend: true, value: value }
+    }
+
+    #[inline]
+    fn aligned(offset: u64, width: usize, value: i128) -> Patch {
+        Patch { offset: offset, width: width, check: Check::Wrap,
+                aligned: true, addend: true, value: value }
+    }
+
+    /// A naturally aligned field whose value does not involve the
+    /// addend, such as a Global Offset Table slot.
+    #[inline]
+    fn slot(offset: u64, width: usize, value: i128) -> Patch {
+        Patch { offset: offset, width: width, check: Check::Wrap,
+                aligned: true, addend: false, value: value }
+    }
+}
+
+/// Check that `value` fits in `width` bytes according to `check`.
+#[inline]
+fn fits(value: i128, width: usize, check: Check) -> bool {
+    let bits = width * 8;
+    let smin = -(1i128 << (bits - 1));
+    let smax = (1i128 << (bits - 1)) - 1;
+    let umax = (1i128 << bits) - 1;
+
+    match check {
+        Check::Signed => smin <= value && value <= smax,
+        Check::Unsigned => 0 <= value && value <= umax,
+        Check::Bitfield => smin <= value && value <= umax,
+        Check::Wrap => true
+    }
+}
+
+/// Read the current contents of a field as a sign-extended value.
+#[inline]
+fn read_field(field: &[u8], check: Check) -> i128 {
+    match (field.len(), check) {
+        (1, Check::Unsigned) => field[0] as i128,
+        (1, _) => field[0] as i8 as i128,
+        (2, Check::Unsigned) => LittleEndian::read_u16(field) as i128,
+        (2, _) => LittleEndian::read_i16(field) as i128,
+        (4, Check::Unsigned) => LittleEndian::read_u32(field) as i128,
+        (4, _) => LittleEndian::read_i32(field) as i128,
+        (_, Check::Unsigned) => LittleEndian::read_u64(field) as i128,
+        _ => LittleEndian::read_i64(field) as i128
+    }
+}
+
+/// Write a patch into a section.
+fn write_patch(section: &mut [u8], addr: u64, patch: Patch,
+               implicit: bool) -> Result<(), ApplyError<u64>> {
+    let Patch { offset, width, check, aligned, addend, value } = patch;
+    let start: usize = match offset.try_into() {
+        Ok(start) => start,
+        Err(_) => return Err(ApplyError::OutOfBounds(offset))
+    };
+    let field = match start.checked_add(width) {
+        Some(end) if end <= section.len() => &mut section[start .. end],
+        _ => return Err(ApplyError::OutOfBounds(offset))
+    };
+
+    if aligned && (addr.wrapping_add(offset) % width as u64 != 0) {
+        return Err(ApplyError::Misaligned(offset))
+    }
+
+    let value = if implicit && addend {
+        value + read_field(field, check)
+    } else {
+        value
+    };
+
+    if !fits(value, width, check) {
+        return Err(ApplyError::Overflow(offset))
+    }
+
+    match width {
+        1 => field[0] = value as u8,
+        2 => LittleEndian::write_u16(field, value as u16),
+        4 => LittleEndian::write_u32(field, value as u32),
+        _ => LittleEndian::write_u64(field, value as u64)
+    }
+
+    Ok(())
+}
+
+/// Look up a symbol, reporting failures at `offset`.
+#[inline]
+fn lookup<Name, F>(resolve: &mut F, sym: &Name, offset: u64) ->
+    Result<(i128, i128), ApplyError<u64>>
+    where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+    match resolve(sym) {
+        Some(SymAddr { addr, size, .. }) => Ok((addr as i128, size as i128)),
+        None => Err(ApplyError::Unresolved(offset))
+    }
+}
+
+/// Get the GOT address, reporting failures at `offset`.
+#[inline]
+fn got(env: &ApplyEnv<u64>, offset: u64) -> Result<i128, ApplyError<u64>> {
+    match env.got {
+        Some(got) => Ok(got as i128),
+        None => Err(ApplyError::NoGOT(offset))
+    }
+}
+
+/// Look up the address of a symbol's PLT entry, reporting failures
+/// at `offset`.
+#[inline]
+fn plt_entry<Name, F>(resolve: &mut F, sym: &Name, offset: u64) ->
+    Result<i128, ApplyError<u64>>
+    where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+    match resolve(sym) {
+        Some(SymAddr { plt: Some(plt), .. }) => Ok(plt as i128),
+        Some(_) => Err(ApplyError::NoPLT(offset)),
+        None => Err(ApplyError::Unresolved(offset))
+    }
+}
+
+/// Look up the offset of a symbol's GOT entry, reporting failures at
+/// `offset`.
+#[inline]
+fn got_entry<Name, F>(resolve: &mut F, sym: &Name, offset: u64) ->
+    Result<i128, ApplyError<u64>>
+    where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+    match resolve(sym) {
+        Some(SymAddr { got: Some(got), .. }) => Ok(got as i128),
+        Some(_) => Err(ApplyError::NoGOT(offset)),
+        None => Err(ApplyError::Unresolved(offset))
+    }
+}
+
+/// Get the TLS addresses, reporting failures at `offset`.
+#[inline]
+fn tls(env: &ApplyEnv<u64>, offset: u64) ->
+    Result<TLSBases<u64>, ApplyError<u64>> {
+    match env.tls {
+        Some(tls) => Ok(tls),
+        None => Err(ApplyError::NoTLS(offset))
+    }
+}
+
+/// Get the address of the field at `offset`.
+#[inline]
+fn place(env: &ApplyEnv<u64>, offset: u64) -> i128 {
+    env.section as i128 + offset as i128
+}
+
+fn x86_64_patch<Name, F>(reloc: &X86_64Reloc<Name>, env: &ApplyEnv<u64>,
+                         mut resolve: F) ->
+    Result<Option<Patch>, ApplyError<u64>>
+    where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+    match reloc {
+        X86_64Reloc::None => Ok(None),
+        X86_64Reloc::Abs64 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 8, Check::Wrap,
+                               s + *addend as i128)))
+        },
+        X86_64Reloc::PC32 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               s + *addend as i128 - p)))
+        },
+        X86_64Reloc::GOT32 { offset, sym, addend } => {
+            let g = got_entry(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               g + *addend as i128)))
+        },
+        X86_64Reloc::PLTRel { offset, sym, addend } => {
+            let l = plt_entry(&mut resolve, sym, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               l + *addend as i128 - p)))
+        },
+        X86_64Reloc::Copy { .. } => Err(ApplyError::Unsupported),
+        X86_64Reloc::GlobalData { offset, sym } |
+        X86_64Reloc::JumpSlot { offset, sym } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 8, s)))
+        },
+        X86_64Reloc::Relative { offset, addend } |
+        X86_64Reloc::Relative64 { offset, addend } =>
+            Ok(Some(Patch::aligned(*offset, 8,
+                                   env.base as i128 + *addend as i128))),
+        X86_64Reloc::GOTPC { offset, sym, addend } |
+        X86_64Reloc::GOTPCRelX { offset, sym, addend } |
+        X86_64Reloc::RexGOTPCRelX { offset, sym, addend } => {
+            let got = got(env, *offset)?;
+            let g = got_entry(&mut resolve, sym, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               g + got + *addend as i128 - p)))
+        },
+        X86_64Reloc::GOTPC32 { offset, addend, .. } => {
+            let got = got(env, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               got + *addend as i128 - p)))
+        },
+        X86_64Reloc::Abs32 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 4, Check::Unsigned,
+                               s + *addend as i128)))
+        },
+        X86_64Reloc::Abs32Signed { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               s + *addend as i128)))
+        },
+        X86_64Reloc::Abs16 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 2, Check::Bitfield,
+                               s + *addend as i128)))
+        },
+        X86_64Reloc::PC16 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 2, Check::Signed,
+                               s + *addend as i128 - p)))
+        },
+        X86_64Reloc::Abs8 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 1, Check::Bitfield,
+                               s + *addend as i128)))
+        },
+        X86_64Reloc::PC8 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 1, Check::Signed,
+                               s + *addend as i128 - p)))
+        },
+        X86_64Reloc::DTPMod { offset, .. } => {
+            let tls = tls(env, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 8, tls.module as i128)))
+        },
+        X86_64Reloc::DTPOff { offset, sym } => {
+            let tls = tls(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 8, s - tls.block as i128)))
+        },
+        X86_64Reloc::TPOff { offset, sym } => {
+            let tls = tls(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 8, s - tls.thread_ptr as i128)))
+        },
+        X86_64Reloc::TLSGD { .. } | X86_64Reloc::TLSLD { .. } |
+        X86_64Reloc::GOTTPOff { .. } => Err(ApplyError::Unsupported),
+        X86_64Reloc::DTPOff32 { offset, sym } => {
+            let tls = tls(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               s - tls.block as i128)))
+        },
+        X86_64Reloc::TPOff32 { offset, sym } => {
+            let tls = tls(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 4, Check::Signed,
+                               s - tls.thread_ptr as i128)))
+        },
+        X86_64Reloc::PC64 { offset, sym, addend } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+            let p = place(env, *offset);
+
+            Ok(Some(Patch::new(*offset, 8, Check::Wrap,
+                               s + *addend as i128 - p)))
+        },
+        X86_64Reloc::GOTRel { offset, sym, addend } => {
+            let got = got(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 8, Check::Wrap,
+                               s + *addend as i128 - got)))
+        },
+        X86_64Reloc::Size32 { offset, sym, addend } => {
+            let (_, z) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 4, Check::Unsigned,
+                               z + *addend as i128)))
+        },
+        X86_64Reloc::Size { offset, sym, addend } => {
+            let (_, z) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::new(*offset, 8, Check::Wrap,
+                               z + *addend as i128)))
+        }
+    }
+}
+
+/// Widen a 32-bit environment to 64 bits.
+#[inline]
+fn widen_env(env: &ApplyEnv<u32>) -> ApplyEnv<u64> {
+    ApplyEnv {
+        section: env.section.into(),
+        base: env.base.into(),
+        got: env.got.map(|got| got.into()),
+        plt: env.plt.map(|plt| plt.into()),
+        tls: env.tls.map(|TLSBases { module, block, thread_ptr }| {
+            TLSBases { module: module.into(), block: block.into(),
+                       thread_ptr: thread_ptr.into() }
+        })
+    }
+}
+
+/// Narrow the offset in an error back to 32 bits.
+#[inline]
+fn narrow_err(err: ApplyError<u64>) -> ApplyError<u32> {
+    match err {
+        ApplyError::OutOfBounds(offset) =>
+            ApplyError::OutOfBounds(offset as u32),
+        ApplyError::Misaligned(offset) => ApplyError::Misaligned(offset as u32),
+        ApplyError::Overflow(offset) => ApplyError::Overflow(offset as u32),
+        ApplyError::Unresolved(offset) => ApplyError::Unresolved(offset as u32),
+        ApplyError::NoGOT(offset) => ApplyError::NoGOT(offset as u32),
+        ApplyError::NoPLT(offset) => ApplyError::NoPLT(offset as u32),
+        ApplyError::NoTLS(offset) => ApplyError::NoTLS(offset as u32),
+        ApplyError::Unsupported => ApplyError::Unsupported
+    }
+}
+
+fn x32_patch<Name, F>(reloc: &X86_64Reloc<Name>, env: &ApplyEnv<u64>,
+                      mut resolve: F) ->
+    Result<Option<Patch>, ApplyError<u64>>
+    where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+    // Pointer-sized fields are 4 bytes; everything else is as x86-64.
+    match reloc {
+        X86_64Reloc::GlobalData { offset, sym } |
+        X86_64Reloc::JumpSlot { offset, sym } => {
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 4, s)))
+        },
+        X86_64Reloc::Relative { offset, addend } =>
+            Ok(Some(Patch::aligned(*offset, 4,
+                                   env.base as i128 + *addend as i128))),
+        X86_64Reloc::DTPMod { offset, .. } => {
+            let tls = tls(env, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 4, tls.module as i128)))
+        },
+        X86_64Reloc::DTPOff { offset, sym } => {
+            let tls = tls(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 4, s - tls.block as i128)))
+        },
+        X86_64Reloc::TPOff { offset, sym } => {
+            let tls = tls(env, *offset)?;
+            let (s, _) = lookup(&mut resolve, sym, *offset)?;
+
+            Ok(Some(Patch::slot(*offset, 4, s - tls.thread_ptr as i128)))
+        },
+        X86_64Reloc::PC64 { .. } | X86_64Reloc::GOTRel { .. } =>
+            Err(ApplyError::Unsupported),
+        _ => x86_64_patch(reloc, env, resolve)
+    }
+}
+
+fn x86_patch<Name, F>(reloc: &X86Reloc<Name>, env: &ApplyEnv<u64>,
+                      mut resolve: F) ->
+    Result<Option<Patch>, ApplyError<u64>>
+    where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+    // All 32-bit fields wrap, as the address space is 32 bits wide.
+    match reloc {
+        X86Reloc::None => Ok(None),
+        X86Reloc::Abs32 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               s + *addend as i128)))
+        },
+        X86Reloc::PC32 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+            let p = place(env, offset);
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               s + *addend as i128 - p)))
+        },
+        X86Reloc::Abs16 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 2, Check::Bitfield,
+                               s + *addend as i128)))
+        },
+        X86Reloc::PC16 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+            let p = place(env, offset);
+
+            Ok(Some(Patch::new(offset, 2, Check::Signed,
+                               s + *addend as i128 - p)))
+        },
+        X86Reloc::Abs8 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 1, Check::Bitfield,
+                               s + *addend as i128)))
+        },
+        X86Reloc::PC8 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+            let p = place(env, offset);
+
+            Ok(Some(Patch::new(offset, 1, Check::Signed,
+                               s + *addend as i128 - p)))
+        },
+        X86Reloc::GOT32 { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let g = got_entry(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               g + *addend as i128)))
+        },
+        X86Reloc::PLTRel { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let l = plt_entry(&mut resolve, sym, offset)?;
+            let p = place(env, offset);
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               l + *addend as i128 - p)))
+        },
+        X86Reloc::Copy { .. } => Err(ApplyError::Unsupported),
+        X86Reloc::GlobalData { offset, sym } |
+        X86Reloc::JumpSlot { offset, sym } => {
+            let offset = *offset as u64;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::slot(offset, 4, s)))
+        },
+        X86Reloc::Relative { offset, addend } =>
+            Ok(Some(Patch::aligned(*offset as u64, 4,
+                                   env.base as i128 + *addend as i128))),
+        X86Reloc::GOTRel { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let got = got(env, offset)?;
+            let (s, _) = lookup(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               s + *addend as i128 - got)))
+        },
+        X86Reloc::GOTPC { offset, addend, .. } => {
+            let offset = *offset as u64;
+            let got = got(env, offset)?;
+            let p = place(env, offset);
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               got + *addend as i128 - p)))
+        },
+        X86Reloc::PLTAbs { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let l = plt_entry(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               l + *addend as i128)))
+        },
+        X86Reloc::Size { offset, sym, addend } => {
+            let offset = *offset as u64;
+            let (_, z) = lookup(&mut resolve, sym, offset)?;
+
+            Ok(Some(Patch::new(offset, 4, Check::Wrap,
+                               z + *addend as i128)))
+        }
+    }
+}
+
+impl<Name> Apply for X86_64Reloc<Name> {
+    type Name = Name;
+    type Addr = u64;
+
+    #[inline]
+    fn apply<F>(&self, section: &mut [u8], env: &ApplyEnv<u64>,
+                resolve: F) -> Result<(), ApplyError<u64>>
+        where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+        match x86_64_patch(self, env, resolve) {
+            Ok(Some(patch)) => write_patch(section, env.section, patch,
+                                           false),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+
+    #[inline]
+    fn apply_implicit<F>(&self, section: &mut [u8], env: &ApplyEnv<u64>,
+                         resolve: F) -> Result<(), ApplyError<u64>>
+        where F: FnMut(&Name) -> Option<SymAddr<u64>> {
+        match x86_64_patch(self, env, resolve) {
+            Ok(Some(patch)) => write_patch(section, env.section, patch,
+                                           true),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<Name> Apply for X86Reloc<Name> {
+    type Name = Name;
+    type Addr = u32;
+
+    #[inline]
+    fn apply<F>(&self, section: &mut [u8], env: &ApplyEnv<u32>,
+                mut resolve: F) -> Result<(), ApplyError<u32>>
+        where F: FnMut(&Name) -> Option<SymAddr<u32>> {
+        let wide = widen_env(env);
+        let resolve = |name: &Name| {
+            resolve(name).map(|SymAddr { addr, size, plt, got }| {
+                SymAddr { addr: addr.into(), size: size.into(),
+                          plt: plt.map(|plt| plt.into()),
+                          got: got.map(|got| got.into()) }
+            })
+        };
+
+        match x86_patch(self, &wide, resolve) {
+            Ok(Some(patch)) => match write_patch(section, wide.section,
+                                                 patch, false) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(narrow_err(err))
+            },
+            Ok(None) => Ok(()),
+            Err(err) => Err(narrow_err(err))
+        }
+    }
+
+    #[inline]
+    fn apply_implicit<F>(&self, section: &mut [u8], env: &ApplyEnv<u32>,
+                         mut resolve: F) -> Result<(), ApplyError<u32>>
+        where F: FnMut(&Name) -> Option<SymAddr<u32>> {
+        let wide = widen_env(env);
+        let resolve = |name: &Name| {
+            resolve(name).map(|SymAddr { addr, size, plt, got }| {
+                SymAddr { addr: addr.into(), size: size.into(),
+                          plt: plt.map(|plt| plt.into()),
+                          got: got.map(|got| got.into()) }
+            })
+        };
+
+        match x86_patch(self, &wide, resolve) {
+            Ok(Some(patch)) => match write_patch(section, wide.section,
+                                                 patch, true) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(narrow_err(err))
+            },
+            Ok(None) => Ok(()),
+            Err(err) => Err(narrow_err(err))
+        }
+    }
+}
+
+impl<Name> Apply for X32Reloc<Name> {
+    type Name = Name;
+    type Addr = u32;
+
+    #[inline]
+    fn apply<F>(&self, section: &mut [u8], env: &ApplyEnv<u32>,
+                mut resolve: F) -> Result<(), ApplyError<u32>>
+        where F: FnMut(&Name) -> Option<SymAddr<u32>> {
+        let wide = widen_env(env);
+        let resolve = |name: &Name| {
+            resolve(name).map(|SymAddr { addr, size, plt, got }| {
+                SymAddr { addr: addr.into(), size: size.into(),
+                          plt: plt.map(|plt| plt.into()),
+                          got: got.map(|got| got.into()) }
+            })
+        };
+
+        match x32_patch(&self.0, &wide, resolve) {
+            Ok(Some(patch)) => match write_patch(section, wide.section,
+                                                 patch, false) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(narrow_err(err))
+            },
+            Ok(None) => Ok(()),
+            Err(err) => Err(narrow_err(err))
+        }
+    }
+
+    #[inline]
+    fn apply_implicit<F>(&self, section: &mut [u8], env: &ApplyEnv<u32>,
+                         mut resolve: F) -> Result<(), ApplyError<u32>>
+        where F: FnMut(&Name) -> Option<SymAddr<u32>> {
+        let wide = widen_env(env);
+        let resolve = |name: &Name| {
+            resolve(name).map(|SymAddr { addr, size, plt, got }| {
+                SymAddr { addr: addr.into(), size: size.into(),
+                          plt: plt.map(|plt| plt.into()),
+                          got: got.map(|got| got.into()) }
+            })
+        };
+
+        match x32_patch(&self.0, &wide, resolve) {
+            Ok(Some(patch)) => match write_patch(section, wide.section,
+                                                 patch, true) {
+                Ok(()) => Ok(()),
+                Err(err) => Err(narrow_err(err))
+            },
+            Ok(None) => Ok(()),
+            Err(err) => Err(narrow_err(err))
+        }
+    }
+}
@@ -0,0 +1,7050 @@
+//! Relocation types for 32-bit ARM architecture.
+//!
+//! This module provides the `ArmReloc` type, which describes the
+//! relocation entries for the 32-bit ARM architecture, as defined by
+//! the ARM EABI.  These can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) as
+//! the [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf32;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for 32-bit ARM architectures (aka. AArch32, ARM
+/// EABI).
+///
+/// This datatype provides a semantic-level presentation of the ARM
+/// relocation entries, covering both ARM and Thumb instruction
+/// encodings as well as the thread-local storage and dynamic
+/// relocations.  These can be converted to and from
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) as
+/// the [ElfClass](crate::ElfClass) type argument using the
+/// [TryFrom](core::convert::TryFrom) instances for easier handling.
+///
+/// ARM objects typically use [Rels](crate::reloc::Rels), in which
+/// case the addend is stored in the relocated location itself.  Note
+/// that ARM is bi-endian; the [WithSymtab](crate::symtab::WithSymtab)
+/// instance works with any byte order.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum ArmReloc<Name> {
+    /// Null relocation.
+    None,
+    /// 24-bit PC-relative branch offset (deprecated).
+    ///
+    /// Set the offset field of the B or BL instruction at `offset` to
+    /// bits 2-25 of the relative address of `sym + addend`.  This has
+    /// been superseded by `Call` and `Jump24`.
+    PC24 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Abs32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the offset or address
+    /// of the target word from `sym + addend`).
+    Rel32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDR group 0 relocation for a PC-relative address.
+    ///
+    /// Set the offset field of the LDR, STR, LDRB or STRB instruction
+    /// at `offset` to group 0 of the relative address of
+    /// `sym + addend`.
+    LdrPCG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset` to `sym + addend`.
+    Abs16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 12-bit absolute offset.
+    ///
+    /// Set the 12-bit offset field of the LDR, STR, LDRB or STRB
+    /// instruction at `offset` to `sym + addend`.
+    Abs12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 5-bit absolute Thumb offset.
+    ///
+    /// Set the 5-bit offset field of the 16-bit Thumb LDR or STR
+    /// instruction at `offset` to bits 2-6 of `sym + addend`.
+    ThmAbs5 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 8-bit absolute offset.
+    ///
+    /// Set the 1-byte word at `offset` to `sym + addend`.
+    Abs8 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit static base-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` from the static base.
+    SBRel32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb BL or BLX call offset.
+    ///
+    /// Set the offset fields of the Thumb-2 BL or BLX instruction at
+    /// `offset` to bits 1-24 of the relative address of
+    /// `sym + addend`.
+    ThmCall {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 8-bit PC-relative Thumb offset.
+    ///
+    /// Set the 8-bit offset field of the 16-bit Thumb LDR or ADD
+    /// instruction at `offset` to bits 2-9 of the relative address of
+    /// `sym + addend`.
+    ThmPC8 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Base-relative adjustment.
+    ///
+    /// Adjust the 4-byte word at `offset` by the offset of the base
+    /// of the segment containing `sym` from the base of the segment
+    /// containing the section, plus `addend`.
+    BRelAdj {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// TLS descriptor fill.
+    ///
+    /// Set the 8-byte TLS descriptor at `offset` to describe the
+    /// thread-local `sym + addend`.
+    TLSDesc {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thread-local storage module index.
+    ///
+    /// Set the 4-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thread-local storage block offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    TLSDTPOff32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TLSTPOff32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Global Offset Table entry fill.
+    ///
+    /// Set a Global Offset Table entry to the address of
+    /// `sym + addend`.
+    GlobalData {
+        /// Offset in the GOT.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the PLT.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit offset relative to the image base.
+    ///
+    /// Set the 4-byte word at `offset` to the sum of the base address
+    /// and `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u32,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit offset from the Global Offset Table.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` from the Global Offset Table.
+    GOTOff32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative offset to the Global Offset Table.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Global Offset Table plus `addend`.
+    BasePRel {
+        /// Offset in the section.
+        offset: u32,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Global Offset Table entry offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the Global
+    /// Offset Table entry for `sym + addend` from the Global Offset
+    /// Table.
+    GOTBRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Procedure Linkage Table branch offset (deprecated).
+    ///
+    /// Set the offset field of the B or BL instruction at `offset` to
+    /// bits 2-25 of the relative address of `sym + addend`, which may
+    /// be resolved to a Procedure Linkage Table entry.  This has been
+    /// superseded by `Call` and `Jump24`.
+    PLT32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ARM BL or BLX call offset.
+    ///
+    /// Set the offset field of the BL or BLX instruction at `offset`
+    /// to bits 2-25 of the relative address of `sym + addend`.
+    Call {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ARM B or conditional BL jump offset.
+    ///
+    /// Set the offset field of the B or conditional BL instruction at
+    /// `offset` to bits 2-25 of the relative address of
+    /// `sym + addend`.
+    Jump24 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb B.W jump offset.
+    ///
+    /// Set the offset fields of the Thumb-2 B.W instruction at
+    /// `offset` to bits 1-24 of the relative address of
+    /// `sym + addend`.
+    ThmJump24 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Absolute segment base address.
+    ///
+    /// Set the 4-byte word at `offset` to the base address of the
+    /// segment containing `sym`, plus `addend`.
+    BaseAbs {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Platform-defined data relocation.
+    ///
+    /// Set the 4-byte word at `offset` according to the platform
+    /// interpretation of `sym + addend`; this is typically equivalent
+    /// to `Abs32` or `Rel32`.
+    Target1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 31-bit static base-relative offset.
+    ///
+    /// Set the low 31 bits of the 4-byte word at `offset` to the
+    /// offset of `sym + addend` from the static base.
+    SBRel31 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// BX instruction marker.
+    ///
+    /// Marks the BX instruction at `offset`, allowing it to be
+    /// rewritten for ARMv4 targets.
+    V4BX {
+        /// Offset in the section.
+        offset: u32
+    },
+    /// Platform-defined data relocation.
+    ///
+    /// Set the 4-byte word at `offset` according to the platform
+    /// interpretation of `sym + addend`; this is typically used in
+    /// exception tables.
+    Target2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 31-bit PC-relative offset.
+    ///
+    /// Set the low 31 bits of the 4-byte word at `offset` to the
+    /// relative address of `sym + addend`, preserving the top bit.
+    /// This is used primarily in exception index tables.
+    PRel31 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVW for the lower 16 bits of an absolute address.
+    ///
+    /// Set the immediate field of the MOVW instruction at `offset` to
+    /// the lower 16 bits of `sym + addend`, without checking for
+    /// overflow.
+    MovWAbsNC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVT for the upper 16 bits of an absolute address.
+    ///
+    /// Set the immediate field of the MOVT instruction at `offset` to
+    /// the upper 16 bits of `sym + addend`.
+    MovTAbs {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVW for the lower 16 bits of a PC-relative address.
+    ///
+    /// Set the immediate field of the MOVW instruction at `offset` to
+    /// the lower 16 bits of the relative address of `sym + addend`,
+    /// without checking for overflow.
+    MovWPRelNC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVT for the upper 16 bits of a PC-relative address.
+    ///
+    /// Set the immediate field of the MOVT instruction at `offset` to
+    /// the upper 16 bits of the relative address of `sym + addend`.
+    MovTPRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVW for the lower 16 bits of an absolute address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVW instruction at
+    /// `offset` to the lower 16 bits of `sym + addend`, without
+    /// checking for overflow.
+    ThmMovWAbsNC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVT for the upper 16 bits of an absolute address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVT instruction at
+    /// `offset` to the upper 16 bits of `sym + addend`.
+    ThmMovTAbs {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVW for the lower 16 bits of a PC-relative address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVW instruction at
+    /// `offset` to the lower 16 bits of the relative address of
+    /// `sym + addend`, without checking for overflow.
+    ThmMovWPRelNC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVT for the upper 16 bits of a PC-relative address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVT instruction at
+    /// `offset` to the upper 16 bits of the relative address of
+    /// `sym + addend`.
+    ThmMovTPRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb conditional B.W jump offset.
+    ///
+    /// Set the offset fields of the Thumb-2 conditional B.W
+    /// instruction at `offset` to bits 1-20 of the relative address
+    /// of `sym + addend`.
+    ThmJump19 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb CBZ or CBNZ jump offset.
+    ///
+    /// Set the offset fields of the 16-bit Thumb CBZ or CBNZ
+    /// instruction at `offset` to bits 1-6 of the relative address of
+    /// `sym + addend`.
+    ThmJump6 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb ADR 12-bit PC-relative offset.
+    ///
+    /// Set the immediate fields of the Thumb-2 ADR instruction at
+    /// `offset` to the relative address of `sym + addend`.
+    ThmAluPRel11_0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb LDR 12-bit PC-relative offset.
+    ///
+    /// Set the offset field of the Thumb-2 literal LDR instruction at
+    /// `offset` to the relative address of `sym + addend`.
+    ThmPC12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit absolute offset, no interworking.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`, ignoring
+    /// the Thumb bit of `sym`.
+    Abs32NoI {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit PC-relative offset, no interworking.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend`, ignoring the Thumb bit of `sym`.
+    Rel32NoI {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 0 relocation for a PC-relative address, no overflow
+    /// check.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 0 of the relative address of `sym + addend`,
+    /// without checking for overflow.
+    AluPCG0NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 0 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 0 of the relative address of `sym + addend`.
+    AluPCG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 1 relocation for a PC-relative address, no overflow
+    /// check.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 1 of the relative address of `sym + addend`,
+    /// without checking for overflow.
+    AluPCG1NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 1 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 1 of the relative address of `sym + addend`.
+    AluPCG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 2 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 2 of the relative address of `sym + addend`.
+    AluPCG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDR group 1 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDR, STR, LDRB or STRB
+    /// instruction at `offset` to group 1 of the relative address of
+    /// `sym + addend`.
+    LdrPCG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDR group 2 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDR, STR, LDRB or STRB
+    /// instruction at `offset` to group 2 of the relative address of
+    /// `sym + addend`.
+    LdrPCG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDRS group 0 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDRD, STRD, LDRH, STRH, LDRSH
+    /// or LDRSB instruction at `offset` to group 0 of the relative
+    /// address of `sym + addend`.
+    LdrsPCG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDRS group 1 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDRD, STRD, LDRH, STRH, LDRSH
+    /// or LDRSB instruction at `offset` to group 1 of the relative
+    /// address of `sym + addend`.
+    LdrsPCG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDRS group 2 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDRD, STRD, LDRH, STRH, LDRSH
+    /// or LDRSB instruction at `offset` to group 2 of the relative
+    /// address of `sym + addend`.
+    LdrsPCG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDC group 0 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDC or STC instruction at
+    /// `offset` to group 0 of the relative address of `sym + addend`.
+    LdcPCG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDC group 1 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDC or STC instruction at
+    /// `offset` to group 1 of the relative address of `sym + addend`.
+    LdcPCG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDC group 2 relocation for a PC-relative address.
+    ///
+    /// Set the immediate field of the LDC or STC instruction at
+    /// `offset` to group 2 of the relative address of `sym + addend`.
+    LdcPCG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 0 relocation for a static base-relative address, no
+    /// overflow check.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 0 of the offset of `sym + addend` from the
+    /// static base, without checking for overflow.
+    AluSBG0NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 0 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 0 of the offset of `sym + addend` from the
+    /// static base.
+    AluSBG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 1 relocation for a static base-relative address, no
+    /// overflow check.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 1 of the offset of `sym + addend` from the
+    /// static base, without checking for overflow.
+    AluSBG1NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 1 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 1 of the offset of `sym + addend` from the
+    /// static base.
+    AluSBG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// ALU group 2 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the ADD or SUB instruction at
+    /// `offset` to group 2 of the offset of `sym + addend` from the
+    /// static base.
+    AluSBG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDR group 0 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDR, STR, LDRB or STRB
+    /// instruction at `offset` to group 0 of the offset of
+    /// `sym + addend` from the static base.
+    LdrSBG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDR group 1 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDR, STR, LDRB or STRB
+    /// instruction at `offset` to group 1 of the offset of
+    /// `sym + addend` from the static base.
+    LdrSBG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDR group 2 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDR, STR, LDRB or STRB
+    /// instruction at `offset` to group 2 of the offset of
+    /// `sym + addend` from the static base.
+    LdrSBG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDRS group 0 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDRD, STRD, LDRH, STRH, LDRSH
+    /// or LDRSB instruction at `offset` to group 0 of the offset of
+    /// `sym + addend` from the static base.
+    LdrsSBG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDRS group 1 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDRD, STRD, LDRH, STRH, LDRSH
+    /// or LDRSB instruction at `offset` to group 1 of the offset of
+    /// `sym + addend` from the static base.
+    LdrsSBG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDRS group 2 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDRD, STRD, LDRH, STRH, LDRSH
+    /// or LDRSB instruction at `offset` to group 2 of the offset of
+    /// `sym + addend` from the static base.
+    LdrsSBG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDC group 0 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDC or STC instruction at
+    /// `offset` to group 0 of the offset of `sym + addend` from the
+    /// static base.
+    LdcSBG0 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDC group 1 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDC or STC instruction at
+    /// `offset` to group 1 of the offset of `sym + addend` from the
+    /// static base.
+    LdcSBG1 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// LDC group 2 relocation for a static base-relative address.
+    ///
+    /// Set the immediate field of the LDC or STC instruction at
+    /// `offset` to group 2 of the offset of `sym + addend` from the
+    /// static base.
+    LdcSBG2 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVW for the lower 16 bits of a base-relative address.
+    ///
+    /// Set the immediate field of the MOVW instruction at `offset` to
+    /// the lower 16 bits of the offset of `sym + addend` from the
+    /// base of its segment, without checking for overflow.
+    MovWBRelNC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVT for the upper 16 bits of a base-relative address.
+    ///
+    /// Set the immediate field of the MOVT instruction at `offset` to
+    /// the upper 16 bits of the offset of `sym + addend` from the
+    /// base of its segment.
+    MovTBRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// MOVW for a base-relative address.
+    ///
+    /// Set the immediate field of the MOVW instruction at `offset` to
+    /// the lower 16 bits of the offset of `sym + addend` from the
+    /// base of its segment, checking for overflow.
+    MovWBRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVW for the lower 16 bits of a base-relative address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVW instruction at
+    /// `offset` to the lower 16 bits of the offset of `sym + addend`
+    /// from the base of its segment, without checking for overflow.
+    ThmMovWBRelNC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVT for the upper 16 bits of a base-relative address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVT instruction at
+    /// `offset` to the upper 16 bits of the offset of `sym + addend`
+    /// from the base of its segment.
+    ThmMovTBRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb-2 MOVW for a base-relative address.
+    ///
+    /// Set the immediate field of the Thumb-2 MOVW instruction at
+    /// `offset` to the lower 16 bits of the offset of `sym + addend`
+    /// from the base of its segment, checking for overflow.
+    ThmMovWBRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// TLS descriptor Global Offset Table entry.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Global Offset Table entry for the TLS descriptor of
+    /// `sym + addend`.
+    TLSGOTDesc {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// TLS descriptor call marker.
+    ///
+    /// Marks the BL instruction at `offset` as calling the TLS
+    /// descriptor resolver for `sym`, allowing the linker to relax
+    /// the sequence.
+    TLSCall {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// TLS descriptor sequence marker.
+    ///
+    /// Marks the instruction at `offset` as part of a TLS descriptor
+    /// sequence for `sym`, allowing the linker to relax the sequence.
+    TLSDescSeq {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thumb TLS descriptor call marker.
+    ///
+    /// Marks the Thumb-2 BL instruction at `offset` as calling the
+    /// TLS descriptor resolver for `sym`, allowing the linker to
+    /// relax the sequence.
+    ThmTLSCall {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Absolute Procedure Linkage Table address.
+    ///
+    /// Set the 4-byte word at `offset` to the address of the
+    /// Procedure Linkage Table entry for `sym`, plus `addend`.
+    PLT32Abs {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Absolute Global Offset Table entry address.
+    ///
+    /// Set the 4-byte word at `offset` to the address of the Global
+    /// Offset Table entry for `sym + addend`.
+    GOTAbs {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative Global Offset Table entry address.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Global Offset Table entry for `sym + addend`.
+    GOTPRel {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 12-bit Global Offset Table entry offset.
+    ///
+    /// Set the 12-bit offset field of the LDR instruction at `offset`
+    /// to the offset of the Global Offset Table entry for
+    /// `sym + addend` from the Global Offset Table.
+    GOTBRel12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 12-bit offset from the Global Offset Table.
+    ///
+    /// Set the 12-bit offset field of the LDR or STR instruction at
+    /// `offset` to the offset of `sym + addend` from the Global
+    /// Offset Table.
+    GOTOff12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// C++ virtual table entry marker.
+    ///
+    /// Records a use of the virtual table entry for `sym` at
+    /// `addend`, for use in garbage collection of unused virtual
+    /// functions.
+    GNUVTEntry {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// C++ virtual table inheritance marker.
+    ///
+    /// Records that the virtual table at `offset` inherits from the
+    /// virtual table `sym`, for use in garbage collection of unused
+    /// virtual functions.
+    GNUVTInherit {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thumb B jump offset.
+    ///
+    /// Set the offset field of the 16-bit Thumb B instruction at
+    /// `offset` to bits 1-11 of the relative address of
+    /// `sym + addend`.
+    ThmJump11 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb conditional B jump offset.
+    ///
+    /// Set the offset field of the 16-bit Thumb conditional B
+    /// instruction at `offset` to bits 1-8 of the relative address of
+    /// `sym + addend`.
+    ThmJump8 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// General dynamic `tls_index` entry.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Global Offset Table entry for the general dynamic `tls_index`
+    /// of `sym + addend`.
+    TLSGD32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Local dynamic `tls_index` entry.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Global Offset Table entry for the local dynamic `tls_index` of
+    /// the module containing `sym`.
+    TLSLDM32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Local dynamic thread-local block offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    TLSLDO32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Initial execution GOT entry.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Global Offset Table entry for the thread-pointer offset of
+    /// `sym + addend`.
+    TLSIE32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Local execution thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TLSLE32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 12-bit local dynamic thread-local block offset.
+    ///
+    /// Set the 12-bit offset field of the LDR or STR instruction at
+    /// `offset` to the offset of `sym + addend` in its thread-local
+    /// storage block.
+    TLSLDO12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 12-bit local execution thread pointer offset.
+    ///
+    /// Set the 12-bit offset field of the LDR or STR instruction at
+    /// `offset` to the offset of `sym + addend` from the thread
+    /// pointer.
+    TLSLE12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 12-bit initial execution GOT entry offset.
+    ///
+    /// Set the 12-bit offset field of the LDR instruction at `offset`
+    /// to the offset of the Global Offset Table entry for the
+    /// thread-pointer offset of `sym + addend` from the Global Offset
+    /// Table.
+    TLSIE12GP {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb 16-bit TLS descriptor sequence marker.
+    ///
+    /// Marks the 16-bit Thumb instruction at `offset` as part of a
+    /// TLS descriptor sequence for `sym`, allowing the linker to
+    /// relax the sequence.
+    ThmTLSDescSeq16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thumb-2 TLS descriptor sequence marker.
+    ///
+    /// Marks the Thumb-2 instruction at `offset` as part of a TLS
+    /// descriptor sequence for `sym`, allowing the linker to relax
+    /// the sequence.
+    ThmTLSDescSeq32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thumb 12-bit Global Offset Table entry offset.
+    ///
+    /// Set the 12-bit offset field of the Thumb-2 LDR instruction at
+    /// `offset` to the offset of the Global Offset Table entry for
+    /// `sym + addend` from the Global Offset Table.
+    ThmGOTBRel12 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb ADD or MOV immediate for bits 0-7 of an absolute
+    /// address, no overflow check.
+    ///
+    /// Set the 8-bit immediate field of the 16-bit Thumb ADD or MOV
+    /// instruction at `offset` to bits 0-7 of `sym + addend`, without
+    /// checking for overflow.
+    ThmAluAbsG0NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb ADD or MOV immediate for bits 8-15 of an absolute
+    /// address, no overflow check.
+    ///
+    /// Set the 8-bit immediate field of the 16-bit Thumb ADD or MOV
+    /// instruction at `offset` to bits 8-15 of `sym + addend`,
+    /// without checking for overflow.
+    ThmAluAbsG1NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb ADD or MOV immediate for bits 16-23 of an absolute
+    /// address, no overflow check.
+    ///
+    /// Set the 8-bit immediate field of the 16-bit Thumb ADD or MOV
+    /// instruction at `offset` to bits 16-23 of `sym + addend`,
+    /// without checking for overflow.
+    ThmAluAbsG2NC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thumb ADD or MOV immediate for bits 24-31 of an absolute
+    /// address.
+    ///
+    /// Set the 8-bit immediate field of the 16-bit Thumb ADD or MOV
+    /// instruction at `offset` to bits 24-31 of `sym + addend`.
+    ThmAluAbsG3 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Indirect relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the result of calling the
+    /// resolver function at the sum of the base address and `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u32,
+        /// The addend argument.
+        addend: i32
+    }
+}
+
+/// Type synonym for [ArmReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type ArmRelocRaw = ArmReloc<u32>;
+
+/// Type synonym for [ArmReloc] with [SymDataRaw] as the symbol type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [ArmRelocRaw].
+pub type ArmRelocRawSym = ArmReloc<SymDataRaw<Elf32>>;
+
+/// Type synonym for [ArmReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [ArmRelocRawSym].
+pub type ArmRelocStrDataSym<'a> = ArmReloc<SymDataStrData<'a, Elf32>>;
+
+/// Type synonym for [ArmReloc] with [SymDataStr] as the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [ArmRelocStrDataSym].
+pub type ArmRelocStrData<'a> =
+    ArmReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [ArmReloc] with UTF-8 decoded string data as the
+/// symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [ArmRelocStrDataSym].
+pub type ArmRelocStrSym<'a> = ArmReloc<SymDataStr<'a, Elf32>>;
+
+/// Type synonym for [ArmReloc] with a `&'a str`s as the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [ArmRelocStrSym].
+pub type ArmRelocStr<'a> = ArmReloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [ArmReloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// At present, this can only happen with a non-zero addend.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ArmToRelError {
+    /// Non-zero addend.
+    BadAddend(i32)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [ArmReloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ArmRelocError {
+    /// Unknown tag value.
+    BadTag(u8)
+}
+
+impl<Name> Display for ArmReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ArmReloc::None => write!(f, "none"),
+            ArmReloc::PC24 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::Abs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::Rel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdrPCG0 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group0(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::Abs16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            ArmReloc::Abs12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmAbs5 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 2, sym, addend),
+            ArmReloc::Abs8 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- &{} + {}",
+                       offset, sym, addend),
+            ArmReloc::SBRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &sb",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmCall { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmPC8 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 2, sym, addend, offset),
+            ArmReloc::BRelAdj { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &base({}) - ",
+                                  "&base(.section) + {}"),
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSDesc { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- tls descriptor for {} + {}",
+                       offset, offset + 8, sym, addend),
+            ArmReloc::TLSDTPMod32 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 4, sym),
+            ArmReloc::TLSDTPOff32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSTPOff32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            ArmReloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            ArmReloc::GlobalData { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::Relative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 4, addend),
+            ArmReloc::GOTOff32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::BasePRel { offset, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got + {}) - (&.section + {})",
+                       offset, offset + 4, addend, offset),
+            ArmReloc::GOTBRel { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::PLT32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::Call { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::Jump24 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmJump24 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::BaseAbs { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &base({}) + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::Target1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- target1((&{} + {}))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::SBRel31 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &sb) & 0x7fffffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::V4BX { offset } =>
+                write!(f, ".section[{}..{}] <- armv4 bx",
+                       offset, offset + 4),
+            ArmReloc::Target2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- target2((&{} + {}))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::PRel31 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) & 0x7fffffff"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::MovWAbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::MovTAbs { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 16) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::MovWPRelNC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::MovTPRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmMovWAbsNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmMovTAbs { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 16) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmMovWPRelNC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmMovTPRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmJump19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmJump6 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            ArmReloc::ThmAluPRel11_0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::ThmPC12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::Abs32NoI { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::Rel32NoI { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::AluPCG0NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group0(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::AluPCG0 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group0(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::AluPCG1NC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group1(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::AluPCG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group1(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::AluPCG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group2(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdrPCG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group1(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdrPCG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group2(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdrsPCG0 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group0(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdrsPCG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group1(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdrsPCG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group2(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdcPCG0 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group0(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdcPCG1 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group1(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::LdcPCG2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- group2(((&{} + {}) - ",
+                                  "(&.section + {})))"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::AluSBG0NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group0(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::AluSBG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group0(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::AluSBG1NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group1(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::AluSBG1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group1(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::AluSBG2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group2(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdrSBG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group0(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdrSBG1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group1(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdrSBG2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group2(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdrsSBG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group0(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdrsSBG1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group1(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdrsSBG2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group2(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdcSBG0 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group0(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdcSBG1 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group1(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::LdcSBG2 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- group2(((&{} + {}) - &sb))",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::MovWBRelNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &base) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::MovTBRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - &base) ",
+                                  ">> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            ArmReloc::MovWBRel { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &base) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmMovWBRelNC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &base) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmMovTBRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - &base) ",
+                                  ">> 16) & 0xffff"),
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmMovWBRel { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &base) & 0xffff",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSGOTDesc { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tlsdesc(&{} + ",
+                                  "{})] - (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::TLSCall { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc call for {}",
+                       offset, offset + 4, sym),
+            ArmReloc::TLSDescSeq { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc sequence for {}",
+                       offset, offset + 4, sym),
+            ArmReloc::ThmTLSCall { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc call for {}",
+                       offset, offset + 4, sym),
+            ArmReloc::PLT32Abs { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::GOTAbs { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}]",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::GOTPRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[&{} + {}] - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::GOTBRel12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::GOTOff12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::GNUVTEntry { offset, sym, addend } =>
+                write!(f, ".section[{}] uses vtable entry {} + {}",
+                       offset, sym, addend),
+            ArmReloc::GNUVTInherit { offset, sym } =>
+                write!(f, ".section[{}] inherits vtable {}",
+                       offset, sym),
+            ArmReloc::ThmJump11 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            ArmReloc::ThmJump8 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            ArmReloc::TLSGD32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] - (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::TLSLDM32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] - (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::TLSLDO32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {})",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSIE32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tprel(&{} + {})] ",
+                                  "- (&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            ArmReloc::TLSLE32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {})",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSLDO12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {})",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSLE12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {})",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::TLSIE12GP { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tprel(&{} + {})] - &.got",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmTLSDescSeq16 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc sequence for {}",
+                       offset, offset + 2, sym),
+            ArmReloc::ThmTLSDescSeq32 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- tlsdesc sequence for {}",
+                       offset, offset + 4, sym),
+            ArmReloc::ThmGOTBRel12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 4, sym, addend),
+            ArmReloc::ThmAluAbsG0NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xff",
+                       offset, offset + 2, sym, addend),
+            ArmReloc::ThmAluAbsG1NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 8) & 0xff",
+                       offset, offset + 2, sym, addend),
+            ArmReloc::ThmAluAbsG2NC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 16) & 0xff",
+                       offset, offset + 2, sym, addend),
+            ArmReloc::ThmAluAbsG3 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 24) & 0xff",
+                       offset, offset + 2, sym, addend),
+            ArmReloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- (&base + {})()",
+                       offset, offset + 4, addend)
+        }
+    }
+}
+
+impl Display for ArmRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ArmRelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for ArmToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            ArmToRelError::BadAddend(addend) =>
+                write!(f, "non-zero addend value {}", addend)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u32, sym: Name, kind: u8, addend: i32) ->
+    Result<ArmReloc<Name>, ArmRelocError> {
+    match kind {
+        0 => Ok(ArmReloc::None),
+        1 => Ok(ArmReloc::PC24 { offset: offset, sym: sym, addend: addend }),
+        2 => Ok(ArmReloc::Abs32 { offset: offset, sym: sym, addend: addend }),
+        3 => Ok(ArmReloc::Rel32 { offset: offset, sym: sym, addend: addend }),
+        4 => Ok(ArmReloc::LdrPCG0 { offset: offset, sym: sym, addend: addend }),
+        5 => Ok(ArmReloc::Abs16 { offset: offset, sym: sym, addend: addend }),
+        6 => Ok(ArmReloc::Abs12 { offset: offset, sym: sym, addend: addend }),
+        7 => Ok(ArmReloc::ThmAbs5 { offset: offset, sym: sym, addend: addend }),
+        8 => Ok(ArmReloc::Abs8 { offset: offset, sym: sym, addend: addend }),
+        9 => Ok(ArmReloc::SBRel32 { offset: offset, sym: sym, addend: addend }),
+        10 => Ok(ArmReloc::ThmCall { offset: offset, sym: sym,
+                                     addend: addend }),
+        11 => Ok(ArmReloc::ThmPC8 { offset: offset, sym: sym, addend: addend }),
+        12 => Ok(ArmReloc::BRelAdj { offset: offset, sym: sym,
+                                     addend: addend }),
+        13 => Ok(ArmReloc::TLSDesc { offset: offset, sym: sym,
+                                     addend: addend }),
+        17 => Ok(ArmReloc::TLSDTPMod32 { offset: offset, sym: sym }),
+        18 => Ok(ArmReloc::TLSDTPOff32 { offset: offset, sym: sym,
+                                         addend: addend }),
+        19 => Ok(ArmReloc::TLSTPOff32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        20 => Ok(ArmReloc::Copy { offset: offset, sym: sym }),
+        21 => Ok(ArmReloc::GlobalData { offset: offset, sym: sym,
+                                        addend: addend }),
+        22 => Ok(ArmReloc::JumpSlot { offset: offset, sym: sym,
+                                      addend: addend }),
+        23 => Ok(ArmReloc::Relative { offset: offset, addend: addend }),
+        24 => Ok(ArmReloc::GOTOff32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        25 => Ok(ArmReloc::BasePRel { offset: offset, addend: addend }),
+        26 => Ok(ArmReloc::GOTBRel { offset: offset, sym: sym,
+                                     addend: addend }),
+        27 => Ok(ArmReloc::PLT32 { offset: offset, sym: sym, addend: addend }),
+        28 => Ok(ArmReloc::Call { offset: offset, sym: sym, addend: addend }),
+        29 => Ok(ArmReloc::Jump24 { offset: offset, sym: sym, addend: addend }),
+        30 => Ok(ArmReloc::ThmJump24 { offset: offset, sym: sym,
+                                       addend: addend }),
+        31 => Ok(ArmReloc::BaseAbs { offset: offset, sym: sym,
+                                     addend: addend }),
+        38 => Ok(ArmReloc::Target1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        39 => Ok(ArmReloc::SBRel31 { offset: offset, sym: sym,
+                                     addend: addend }),
+        40 => Ok(ArmReloc::V4BX { offset: offset }),
+        41 => Ok(ArmReloc::Target2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        42 => Ok(ArmReloc::PRel31 { offset: offset, sym: sym, addend: addend }),
+        43 => Ok(ArmReloc::MovWAbsNC { offset: offset, sym: sym,
+                                       addend: addend }),
+        44 => Ok(ArmReloc::MovTAbs { offset: offset, sym: sym,
+                                     addend: addend }),
+        45 => Ok(ArmReloc::MovWPRelNC { offset: offset, sym: sym,
+                                        addend: addend }),
+        46 => Ok(ArmReloc::MovTPRel { offset: offset, sym: sym,
+                                      addend: addend }),
+        47 => Ok(ArmReloc::ThmMovWAbsNC { offset: offset, sym: sym,
+                                          addend: addend }),
+        48 => Ok(ArmReloc::ThmMovTAbs { offset: offset, sym: sym,
+                                        addend: addend }),
+        49 => Ok(ArmReloc::ThmMovWPRelNC { offset: offset, sym: sym,
+                                           addend: addend }),
+        50 => Ok(ArmReloc::ThmMovTPRel { offset: offset, sym: sym,
+                                         addend: addend }),
+        51 => Ok(ArmReloc::ThmJump19 { offset: offset, sym: sym,
+                                       addend: addend }),
+        52 => Ok(ArmReloc::ThmJump6 { offset: offset, sym: sym,
+                                      addend: addend }),
+        53 => Ok(ArmReloc::ThmAluPRel11_0 { offset: offset, sym: sym,
+                                            addend: addend }),
+        54 => Ok(ArmReloc::ThmPC12 { offset: offset, sym: sym,
+                                     addend: addend }),
+        55 => Ok(ArmReloc::Abs32NoI { offset: offset, sym: sym,
+                                      addend: addend }),
+        56 => Ok(ArmReloc::Rel32NoI { offset: offset, sym: sym,
+                                      addend: addend }),
+        57 => Ok(ArmReloc::AluPCG0NC { offset: offset, sym: sym,
+                                       addend: addend }),
+        58 => Ok(ArmReloc::AluPCG0 { offset: offset, sym: sym,
+                                     addend: addend }),
+        59 => Ok(ArmReloc::AluPCG1NC { offset: offset, sym: sym,
+                                       addend: addend }),
+        60 => Ok(ArmReloc::AluPCG1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        61 => Ok(ArmReloc::AluPCG2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        62 => Ok(ArmReloc::LdrPCG1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        63 => Ok(ArmReloc::LdrPCG2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        64 => Ok(ArmReloc::LdrsPCG0 { offset: offset, sym: sym,
+                                      addend: addend }),
+        65 => Ok(ArmReloc::LdrsPCG1 { offset: offset, sym: sym,
+                                      addend: addend }),
+        66 => Ok(ArmReloc::LdrsPCG2 { offset: offset, sym: sym,
+                                      addend: addend }),
+        67 => Ok(ArmReloc::LdcPCG0 { offset: offset, sym: sym,
+                                     addend: addend }),
+        68 => Ok(ArmReloc::LdcPCG1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        69 => Ok(ArmReloc::LdcPCG2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        70 => Ok(ArmReloc::AluSBG0NC { offset: offset, sym: sym,
+                                       addend: addend }),
+        71 => Ok(ArmReloc::AluSBG0 { offset: offset, sym: sym,
+                                     addend: addend }),
+        72 => Ok(ArmReloc::AluSBG1NC { offset: offset, sym: sym,
+                                       addend: addend }),
+        73 => Ok(ArmReloc::AluSBG1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        74 => Ok(ArmReloc::AluSBG2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        75 => Ok(ArmReloc::LdrSBG0 { offset: offset, sym: sym,
+                                     addend: addend }),
+        76 => Ok(ArmReloc::LdrSBG1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        77 => Ok(ArmReloc::LdrSBG2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        78 => Ok(ArmReloc::LdrsSBG0 { offset: offset, sym: sym,
+                                      addend: addend }),
+        79 => Ok(ArmReloc::LdrsSBG1 { offset: offset, sym: sym,
+                                      addend: addend }),
+        80 => Ok(ArmReloc::LdrsSBG2 { offset: offset, sym: sym,
+                                      addend: addend }),
+        81 => Ok(ArmReloc::LdcSBG0 { offset: offset, sym: sym,
+                                     addend: addend }),
+        82 => Ok(ArmReloc::LdcSBG1 { offset: offset, sym: sym,
+                                     addend: addend }),
+        83 => Ok(ArmReloc::LdcSBG2 { offset: offset, sym: sym,
+                                     addend: addend }),
+        84 => Ok(ArmReloc::MovWBRelNC { offset: offset, sym: sym,
+                                        addend: addend }),
+        85 => Ok(ArmReloc::MovTBRel { offset: offset, sym: sym,
+                                      addend: addend }),
+        86 => Ok(ArmReloc::MovWBRel { offset: offset, sym: sym,
+                                      addend: addend }),
+        87 => Ok(ArmReloc::ThmMovWBRelNC { offset: offset, sym: sym,
+                                           addend: addend }),
+        88 => Ok(ArmReloc::ThmMovTBRel { offset: offset, sym: sym,
+                                         addend: addend }),
+        89 => Ok(ArmReloc::ThmMovWBRel { offset: offset, sym: sym,
+                                         addend: addend }),
+        90 => Ok(ArmReloc::TLSGOTDesc { offset: offset, sym: sym,
+                                        addend: addend }),
+        91 => Ok(ArmReloc::TLSCall { offset: offset, sym: sym }),
+        92 => Ok(ArmReloc::TLSDescSeq { offset: offset, sym: sym }),
+        93 => Ok(ArmReloc::ThmTLSCall { offset: offset, sym: sym }),
+        94 => Ok(ArmReloc::PLT32Abs { offset: offset, sym: sym,
+                                      addend: addend }),
+        95 => Ok(ArmReloc::GOTAbs { offset: offset, sym: sym, addend: addend }),
+        96 => Ok(ArmReloc::GOTPRel { offset: offset, sym: sym,
+                                     addend: addend }),
+        97 => Ok(ArmReloc::GOTBRel12 { offset: offset, sym: sym,
+                                       addend: addend }),
+        98 => Ok(ArmReloc::GOTOff12 { offset: offset, sym: sym,
+                                      addend: addend }),
+        100 => Ok(ArmReloc::GNUVTEntry { offset: offset, sym: sym,
+                                         addend: addend }),
+        101 => Ok(ArmReloc::GNUVTInherit { offset: offset, sym: sym }),
+        102 => Ok(ArmReloc::ThmJump11 { offset: offset, sym: sym,
+                                        addend: addend }),
+        103 => Ok(ArmReloc::ThmJump8 { offset: offset, sym: sym,
+                                       addend: addend }),
+        104 => Ok(ArmReloc::TLSGD32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        105 => Ok(ArmReloc::TLSLDM32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        106 => Ok(ArmReloc::TLSLDO32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        107 => Ok(ArmReloc::TLSIE32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        108 => Ok(ArmReloc::TLSLE32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        109 => Ok(ArmReloc::TLSLDO12 { offset: offset, sym: sym,
+                                       addend: addend }),
+        110 => Ok(ArmReloc::TLSLE12 { offset: offset, sym: sym,
+                                      addend: addend }),
+        111 => Ok(ArmReloc::TLSIE12GP { offset: offset, sym: sym,
+                                        addend: addend }),
+        129 => Ok(ArmReloc::ThmTLSDescSeq16 { offset: offset, sym: sym }),
+        130 => Ok(ArmReloc::ThmTLSDescSeq32 { offset: offset, sym: sym }),
+        131 => Ok(ArmReloc::ThmGOTBRel12 { offset: offset, sym: sym,
+                                           addend: addend }),
+        132 => Ok(ArmReloc::ThmAluAbsG0NC { offset: offset, sym: sym,
+                                            addend: addend }),
+        133 => Ok(ArmReloc::ThmAluAbsG1NC { offset: offset, sym: sym,
+                                            addend: addend }),
+        134 => Ok(ArmReloc::ThmAluAbsG2NC { offset: offset, sym: sym,
+                                            addend: addend }),
+        135 => Ok(ArmReloc::ThmAluAbsG3 { offset: offset, sym: sym,
+                                          addend: addend }),
+        160 => Ok(ArmReloc::IRelative { offset: offset, addend: addend }),
+        tag => Err(ArmRelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for ArmReloc<Name> {
+    type Error = ArmRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) -> Result<ArmReloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<ArmReloc<u32>> for RelData<u32, Elf32> {
+    type Error = ArmToRelError;
+
+    #[inline]
+    fn try_from(rel: ArmReloc<u32>) -> Result<RelData<u32, Elf32>,
+                                              Self::Error> {
+        match rel {
+            ArmReloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            ArmReloc::PC24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            ArmReloc::PC24 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Abs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            ArmReloc::Abs32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Rel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
+            ArmReloc::Rel32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrPCG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            ArmReloc::LdrPCG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Abs16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            ArmReloc::Abs16 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Abs12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            ArmReloc::Abs12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmAbs5 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            ArmReloc::ThmAbs5 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Abs8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            ArmReloc::Abs8 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::SBRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            ArmReloc::SBRel32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmCall { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            ArmReloc::ThmCall { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmPC8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            ArmReloc::ThmPC8 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::BRelAdj { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 12 }),
+            ArmReloc::BRelAdj { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSDesc { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 13 }),
+            ArmReloc::TLSDesc { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSDTPMod32 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 17 }),
+            ArmReloc::TLSDTPOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 18 }),
+            ArmReloc::TLSDTPOff32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSTPOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 19 }),
+            ArmReloc::TLSTPOff32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            ArmReloc::GlobalData { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            ArmReloc::GlobalData { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 22 }),
+            ArmReloc::JumpSlot { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 23 }),
+            ArmReloc::Relative { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GOTOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 24 }),
+            ArmReloc::GOTOff32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::BasePRel { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 25 }),
+            ArmReloc::BasePRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GOTBRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            ArmReloc::GOTBRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            ArmReloc::PLT32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Call { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            ArmReloc::Call { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Jump24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            ArmReloc::Jump24 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmJump24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 30 }),
+            ArmReloc::ThmJump24 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::BaseAbs { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 31 }),
+            ArmReloc::BaseAbs { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Target1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 38 }),
+            ArmReloc::Target1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::SBRel31 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 39 }),
+            ArmReloc::SBRel31 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::V4BX { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 40 }),
+            ArmReloc::Target2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 41 }),
+            ArmReloc::Target2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::PRel31 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 42 }),
+            ArmReloc::PRel31 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovWAbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 43 }),
+            ArmReloc::MovWAbsNC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovTAbs { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 44 }),
+            ArmReloc::MovTAbs { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovWPRelNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 45 }),
+            ArmReloc::MovWPRelNC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovTPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 46 }),
+            ArmReloc::MovTPRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovWAbsNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 47 }),
+            ArmReloc::ThmMovWAbsNC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovTAbs { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 48 }),
+            ArmReloc::ThmMovTAbs { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovWPRelNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 49 }),
+            ArmReloc::ThmMovWPRelNC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovTPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 50 }),
+            ArmReloc::ThmMovTPRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmJump19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 51 }),
+            ArmReloc::ThmJump19 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmJump6 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 52 }),
+            ArmReloc::ThmJump6 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmAluPRel11_0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 53 }),
+            ArmReloc::ThmAluPRel11_0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmPC12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 54 }),
+            ArmReloc::ThmPC12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Abs32NoI { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 55 }),
+            ArmReloc::Abs32NoI { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::Rel32NoI { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 56 }),
+            ArmReloc::Rel32NoI { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluPCG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 57 }),
+            ArmReloc::AluPCG0NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluPCG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 58 }),
+            ArmReloc::AluPCG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluPCG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 59 }),
+            ArmReloc::AluPCG1NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluPCG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 60 }),
+            ArmReloc::AluPCG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluPCG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 61 }),
+            ArmReloc::AluPCG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrPCG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 62 }),
+            ArmReloc::LdrPCG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrPCG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 63 }),
+            ArmReloc::LdrPCG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrsPCG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 64 }),
+            ArmReloc::LdrsPCG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrsPCG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 65 }),
+            ArmReloc::LdrsPCG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrsPCG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 66 }),
+            ArmReloc::LdrsPCG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdcPCG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 67 }),
+            ArmReloc::LdcPCG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdcPCG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 68 }),
+            ArmReloc::LdcPCG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdcPCG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 69 }),
+            ArmReloc::LdcPCG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluSBG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 70 }),
+            ArmReloc::AluSBG0NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluSBG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 71 }),
+            ArmReloc::AluSBG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluSBG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 72 }),
+            ArmReloc::AluSBG1NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluSBG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 73 }),
+            ArmReloc::AluSBG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::AluSBG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 74 }),
+            ArmReloc::AluSBG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrSBG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 75 }),
+            ArmReloc::LdrSBG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrSBG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 76 }),
+            ArmReloc::LdrSBG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrSBG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 77 }),
+            ArmReloc::LdrSBG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrsSBG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 78 }),
+            ArmReloc::LdrsSBG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrsSBG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 79 }),
+            ArmReloc::LdrsSBG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdrsSBG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 80 }),
+            ArmReloc::LdrsSBG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdcSBG0 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 81 }),
+            ArmReloc::LdcSBG0 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdcSBG1 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 82 }),
+            ArmReloc::LdcSBG1 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::LdcSBG2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 83 }),
+            ArmReloc::LdcSBG2 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovWBRelNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 84 }),
+            ArmReloc::MovWBRelNC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovTBRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 85 }),
+            ArmReloc::MovTBRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::MovWBRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 86 }),
+            ArmReloc::MovWBRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovWBRelNC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 87 }),
+            ArmReloc::ThmMovWBRelNC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovTBRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 88 }),
+            ArmReloc::ThmMovTBRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmMovWBRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 89 }),
+            ArmReloc::ThmMovWBRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSGOTDesc { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 90 }),
+            ArmReloc::TLSGOTDesc { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSCall { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 91 }),
+            ArmReloc::TLSDescSeq { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 92 }),
+            ArmReloc::ThmTLSCall { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 93 }),
+            ArmReloc::PLT32Abs { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 94 }),
+            ArmReloc::PLT32Abs { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GOTAbs { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 95 }),
+            ArmReloc::GOTAbs { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GOTPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 96 }),
+            ArmReloc::GOTPRel { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GOTBRel12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 97 }),
+            ArmReloc::GOTBRel12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GOTOff12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 98 }),
+            ArmReloc::GOTOff12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GNUVTEntry { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 100 }),
+            ArmReloc::GNUVTEntry { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::GNUVTInherit { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 101 }),
+            ArmReloc::ThmJump11 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 102 }),
+            ArmReloc::ThmJump11 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmJump8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 103 }),
+            ArmReloc::ThmJump8 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSGD32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 104 }),
+            ArmReloc::TLSGD32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSLDM32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 105 }),
+            ArmReloc::TLSLDM32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSLDO32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 106 }),
+            ArmReloc::TLSLDO32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSIE32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 107 }),
+            ArmReloc::TLSIE32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSLE32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 108 }),
+            ArmReloc::TLSLE32 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSLDO12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 109 }),
+            ArmReloc::TLSLDO12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSLE12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 110 }),
+            ArmReloc::TLSLE12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::TLSIE12GP { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 111 }),
+            ArmReloc::TLSIE12GP { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmTLSDescSeq16 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 129 }),
+            ArmReloc::ThmTLSDescSeq32 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 130 }),
+            ArmReloc::ThmGOTBRel12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 131 }),
+            ArmReloc::ThmGOTBRel12 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmAluAbsG0NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 132 }),
+            ArmReloc::ThmAluAbsG0NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmAluAbsG1NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 133 }),
+            ArmReloc::ThmAluAbsG1NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmAluAbsG2NC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 134 }),
+            ArmReloc::ThmAluAbsG2NC { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::ThmAluAbsG3 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 135 }),
+            ArmReloc::ThmAluAbsG3 { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend)),
+            ArmReloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 160 }),
+            ArmReloc::IRelative { addend, .. } =>
+                Err(ArmToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for ArmReloc<Name> {
+    type Error = ArmRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) -> Result<ArmReloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<ArmReloc<u32>> for RelaData<u32, Elf32> {
+    #[inline]
+    fn from(rel: ArmReloc<u32>) -> RelaData<u32, Elf32> {
+        match rel {
+            ArmReloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            ArmReloc::PC24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
+            ArmReloc::Abs32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
+            ArmReloc::Rel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 3, addend: addend },
+            ArmReloc::LdrPCG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 4, addend: addend },
+            ArmReloc::Abs16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 5, addend: addend },
+            ArmReloc::Abs12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 6, addend: addend },
+            ArmReloc::ThmAbs5 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 7, addend: addend },
+            ArmReloc::Abs8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 8, addend: addend },
+            ArmReloc::SBRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 9, addend: addend },
+            ArmReloc::ThmCall { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
+            ArmReloc::ThmPC8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
+            ArmReloc::BRelAdj { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 12, addend: addend },
+            ArmReloc::TLSDesc { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 13, addend: addend },
+            ArmReloc::TLSDTPMod32 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 17, addend: 0 },
+            ArmReloc::TLSDTPOff32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 18, addend: addend },
+            ArmReloc::TLSTPOff32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 19, addend: addend },
+            ArmReloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 20, addend: 0 },
+            ArmReloc::GlobalData { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 21, addend: addend },
+            ArmReloc::JumpSlot { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 22, addend: addend },
+            ArmReloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 23, addend: addend },
+            ArmReloc::GOTOff32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 24, addend: addend },
+            ArmReloc::BasePRel { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 25, addend: addend },
+            ArmReloc::GOTBRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 26, addend: addend },
+            ArmReloc::PLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 27, addend: addend },
+            ArmReloc::Call { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 28, addend: addend },
+            ArmReloc::Jump24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 29, addend: addend },
+            ArmReloc::ThmJump24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 30, addend: addend },
+            ArmReloc::BaseAbs { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 31, addend: addend },
+            ArmReloc::Target1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 38, addend: addend },
+            ArmReloc::SBRel31 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 39, addend: addend },
+            ArmReloc::V4BX { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 40, addend: 0 },
+            ArmReloc::Target2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 41, addend: addend },
+            ArmReloc::PRel31 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 42, addend: addend },
+            ArmReloc::MovWAbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 43, addend: addend },
+            ArmReloc::MovTAbs { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 44, addend: addend },
+            ArmReloc::MovWPRelNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 45, addend: addend },
+            ArmReloc::MovTPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 46, addend: addend },
+            ArmReloc::ThmMovWAbsNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 47, addend: addend },
+            ArmReloc::ThmMovTAbs { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 48, addend: addend },
+            ArmReloc::ThmMovWPRelNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 49, addend: addend },
+            ArmReloc::ThmMovTPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 50, addend: addend },
+            ArmReloc::ThmJump19 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 51, addend: addend },
+            ArmReloc::ThmJump6 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 52, addend: addend },
+            ArmReloc::ThmAluPRel11_0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 53, addend: addend },
+            ArmReloc::ThmPC12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 54, addend: addend },
+            ArmReloc::Abs32NoI { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 55, addend: addend },
+            ArmReloc::Rel32NoI { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 56, addend: addend },
+            ArmReloc::AluPCG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 57, addend: addend },
+            ArmReloc::AluPCG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 58, addend: addend },
+            ArmReloc::AluPCG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 59, addend: addend },
+            ArmReloc::AluPCG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 60, addend: addend },
+            ArmReloc::AluPCG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 61, addend: addend },
+            ArmReloc::LdrPCG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 62, addend: addend },
+            ArmReloc::LdrPCG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 63, addend: addend },
+            ArmReloc::LdrsPCG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 64, addend: addend },
+            ArmReloc::LdrsPCG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 65, addend: addend },
+            ArmReloc::LdrsPCG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 66, addend: addend },
+            ArmReloc::LdcPCG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 67, addend: addend },
+            ArmReloc::LdcPCG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 68, addend: addend },
+            ArmReloc::LdcPCG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 69, addend: addend },
+            ArmReloc::AluSBG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 70, addend: addend },
+            ArmReloc::AluSBG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 71, addend: addend },
+            ArmReloc::AluSBG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 72, addend: addend },
+            ArmReloc::AluSBG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 73, addend: addend },
+            ArmReloc::AluSBG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 74, addend: addend },
+            ArmReloc::LdrSBG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 75, addend: addend },
+            ArmReloc::LdrSBG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 76, addend: addend },
+            ArmReloc::LdrSBG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 77, addend: addend },
+            ArmReloc::LdrsSBG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 78, addend: addend },
+            ArmReloc::LdrsSBG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 79, addend: addend },
+            ArmReloc::LdrsSBG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 80, addend: addend },
+            ArmReloc::LdcSBG0 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 81, addend: addend },
+            ArmReloc::LdcSBG1 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 82, addend: addend },
+            ArmReloc::LdcSBG2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 83, addend: addend },
+            ArmReloc::MovWBRelNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 84, addend: addend },
+            ArmReloc::MovTBRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 85, addend: addend },
+            ArmReloc::MovWBRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 86, addend: addend },
+            ArmReloc::ThmMovWBRelNC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 87, addend: addend },
+            ArmReloc::ThmMovTBRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 88, addend: addend },
+            ArmReloc::ThmMovWBRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 89, addend: addend },
+            ArmReloc::TLSGOTDesc { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 90, addend: addend },
+            ArmReloc::TLSCall { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 91, addend: 0 },
+            ArmReloc::TLSDescSeq { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 92, addend: 0 },
+            ArmReloc::ThmTLSCall { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 93, addend: 0 },
+            ArmReloc::PLT32Abs { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 94, addend: addend },
+            ArmReloc::GOTAbs { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 95, addend: addend },
+            ArmReloc::GOTPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 96, addend: addend },
+            ArmReloc::GOTBRel12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 97, addend: addend },
+            ArmReloc::GOTOff12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 98, addend: addend },
+            ArmReloc::GNUVTEntry { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 100,
+                           addend: addend },
+            ArmReloc::GNUVTInherit { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 101, addend: 0 },
+            ArmReloc::ThmJump11 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 102,
+                           addend: addend },
+            ArmReloc::ThmJump8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 103,
+                           addend: addend },
+            ArmReloc::TLSGD32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 104,
+                           addend: addend },
+            ArmReloc::TLSLDM32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 105,
+                           addend: addend },
+            ArmReloc::TLSLDO32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 106,
+                           addend: addend },
+            ArmReloc::TLSIE32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 107,
+                           addend: addend },
+            ArmReloc::TLSLE32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 108,
+                           addend: addend },
+            ArmReloc::TLSLDO12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 109,
+                           addend: addend },
+            ArmReloc::TLSLE12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 110,
+                           addend: addend },
+            ArmReloc::TLSIE12GP { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 111,
+                           addend: addend },
+            ArmReloc::ThmTLSDescSeq16 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 129, addend: 0 },
+            ArmReloc::ThmTLSDescSeq32 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 130, addend: 0 },
+            ArmReloc::ThmGOTBRel12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 131,
+                           addend: addend },
+            ArmReloc::ThmAluAbsG0NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 132,
+                           addend: addend },
+            ArmReloc::ThmAluAbsG1NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 133,
+                           addend: addend },
+            ArmReloc::ThmAluAbsG2NC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 134,
+                           addend: addend },
+            ArmReloc::ThmAluAbsG3 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 135,
+                           addend: addend },
+            ArmReloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 160, addend: addend }
+        }
+    }
+}
+
+impl<'a, B> WithSymtab<'a, B, Elf32> for ArmRelocRaw
+    where B: ByteOrder {
+    type Result = ArmRelocRawSym;
+    type Error = RelocSymtabError<Elf32>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Elf32>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            ArmReloc::None => Ok(ArmReloc::None),
+            ArmReloc::PC24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::PC24 { offset: offset, sym: symdata,
+                                                addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Abs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Abs32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Rel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Rel32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrPCG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrPCG0 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Abs16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Abs16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Abs12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Abs12 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmAbs5 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmAbs5 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Abs8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Abs8 { offset: offset, sym: symdata,
+                                                addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::SBRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::SBRel32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmCall { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmPC8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmPC8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::BRelAdj { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::BRelAdj { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSDesc { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSDesc { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSDTPMod32 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSDTPMod32 { offset: offset,
+                                                       sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSDTPOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSDTPOff32 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSTPOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSTPOff32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Copy { offset: offset, sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GlobalData { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GlobalData { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::JumpSlot { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Relative { offset, addend } =>
+                Ok(ArmReloc::Relative { offset: offset, addend: addend }),
+            ArmReloc::GOTOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GOTOff32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::BasePRel { offset, addend } =>
+                Ok(ArmReloc::BasePRel { offset: offset, addend: addend }),
+            ArmReloc::GOTBRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GOTBRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::PLT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Call { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Call { offset: offset, sym: symdata,
+                                                addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Jump24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Jump24 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmJump24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmJump24 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::BaseAbs { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::BaseAbs { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Target1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Target1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::SBRel31 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::SBRel31 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::V4BX { offset } =>
+                Ok(ArmReloc::V4BX { offset: offset }),
+            ArmReloc::Target2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Target2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::PRel31 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::PRel31 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovWAbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovWAbsNC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovTAbs { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovTAbs { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovWPRelNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovWPRelNC { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovTPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovTPRel { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovWAbsNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovWAbsNC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovTAbs { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovTAbs { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovWPRelNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovWPRelNC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovTPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovTPRel { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmJump19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmJump19 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmJump6 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmJump6 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmAluPRel11_0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmAluPRel11_0 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmPC12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmPC12 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Abs32NoI { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Abs32NoI { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::Rel32NoI { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::Rel32NoI { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluPCG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluPCG0NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluPCG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluPCG0 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluPCG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluPCG1NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluPCG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluPCG1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluPCG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluPCG2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrPCG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrPCG1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrPCG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrPCG2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrsPCG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrsPCG0 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrsPCG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrsPCG1 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrsPCG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrsPCG2 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdcPCG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdcPCG0 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdcPCG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdcPCG1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdcPCG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdcPCG2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluSBG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluSBG0NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluSBG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluSBG0 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluSBG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluSBG1NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluSBG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluSBG1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::AluSBG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::AluSBG2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrSBG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrSBG0 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrSBG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrSBG1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrSBG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrSBG2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrsSBG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrsSBG0 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrsSBG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrsSBG1 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdrsSBG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdrsSBG2 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdcSBG0 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdcSBG0 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdcSBG1 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdcSBG1 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::LdcSBG2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::LdcSBG2 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovWBRelNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovWBRelNC { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovTBRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovTBRel { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::MovWBRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::MovWBRel { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovWBRelNC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovWBRelNC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovTBRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovTBRel { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmMovWBRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmMovWBRel { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSGOTDesc { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSGOTDesc { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSCall { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSCall { offset: offset,
+                                                   sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSDescSeq { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSDescSeq { offset: offset,
+                                                      sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmTLSCall { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmTLSCall { offset: offset,
+                                                      sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::PLT32Abs { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::PLT32Abs { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GOTAbs { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GOTAbs { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GOTPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GOTPRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GOTBRel12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GOTBRel12 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GOTOff12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GOTOff12 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GNUVTEntry { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GNUVTEntry { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::GNUVTInherit { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::GNUVTInherit { offset: offset,
+                                                        sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmJump11 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmJump11 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmJump8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmJump8 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSGD32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSGD32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSLDM32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSLDM32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSLDO32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSLDO32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSIE32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSIE32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSLE32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSLE32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSLDO12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSLDO12 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSLE12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSLE12 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::TLSIE12GP { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::TLSIE12GP { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmTLSDescSeq16 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmTLSDescSeq16 { offset: offset,
+                                                           sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmTLSDescSeq32 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmTLSDescSeq32 { offset: offset,
+                                                           sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmGOTBRel12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmGOTBRel12 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmAluAbsG0NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmAluAbsG0NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmAluAbsG1NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmAluAbsG1NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmAluAbsG2NC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmAluAbsG2NC { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::ThmAluAbsG3 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(ArmReloc::ThmAluAbsG3 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            ArmReloc::IRelative { offset, addend } =>
+                Ok(ArmReloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> WithStrtab<'a> for ArmRelocRawSym {
+    type Result = ArmRelocStrDataSym<'a>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            ArmReloc::None => Ok(ArmReloc::None),
+            ArmReloc::PC24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PC24 { offset: offset, sym: symdata,
+                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Rel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Rel32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrPCG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrPCG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs12 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAbs5 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAbs5 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs8 { offset: offset, sym: symdata,
+                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::SBRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::SBRel32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmCall { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmCall { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmPC8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmPC8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::BRelAdj { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::BRelAdj { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDesc { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDesc { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDTPMod32 { offset: offset,
+                                                   sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDTPOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDTPOff32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSTPOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSTPOff32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GlobalData { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GlobalData { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::JumpSlot { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Relative { offset, addend } =>
+                Ok(ArmReloc::Relative { offset: offset, addend: addend }),
+            ArmReloc::GOTOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTOff32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::BasePRel { offset, addend } =>
+                Ok(ArmReloc::BasePRel { offset: offset, addend: addend }),
+            ArmReloc::GOTBRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTBRel { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PLT32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Call { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Call { offset: offset, sym: symdata,
+                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Jump24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Jump24 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump24 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::BaseAbs { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::BaseAbs { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Target1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Target1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::SBRel31 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::SBRel31 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::V4BX { offset } =>
+                Ok(ArmReloc::V4BX { offset: offset }),
+            ArmReloc::Target2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Target2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::PRel31 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PRel31 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWAbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWAbsNC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovTAbs { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovTAbs { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWPRelNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWPRelNC { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovTPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovTPRel { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWAbsNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWAbsNC { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovTAbs { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovTAbs { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWPRelNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWPRelNC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovTPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovTPRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump19 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump6 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump6 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluPRel11_0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluPRel11_0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmPC12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmPC12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs32NoI { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs32NoI { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Rel32NoI { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Rel32NoI { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG0NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG1NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrPCG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrPCG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrPCG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrPCG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsPCG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsPCG0 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsPCG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsPCG1 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsPCG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsPCG2 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcPCG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcPCG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcPCG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcPCG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcPCG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcPCG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG0NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG1NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrSBG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrSBG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrSBG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrSBG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrSBG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrSBG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsSBG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsSBG0 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsSBG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsSBG1 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsSBG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsSBG2 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcSBG0 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcSBG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcSBG1 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcSBG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcSBG2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcSBG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWBRelNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWBRelNC { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovTBRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovTBRel { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWBRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWBRel { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWBRelNC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWBRelNC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovTBRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovTBRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWBRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWBRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSGOTDesc { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSGOTDesc { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSCall { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSCall { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDescSeq { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDescSeq { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmTLSCall { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmTLSCall { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::PLT32Abs { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PLT32Abs { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTAbs { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTAbs { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTPRel { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTBRel12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTBRel12 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTOff12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTOff12 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GNUVTEntry { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GNUVTInherit { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GNUVTInherit { offset: offset,
+                                                    sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump11 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump11 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump8 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSGD32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSGD32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLDM32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLDM32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLDO32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLDO32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSIE32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSIE32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLE32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLE32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLDO12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLDO12 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLE12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLE12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSIE12GP { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSIE12GP { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmTLSDescSeq16 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmTLSDescSeq16 { offset: offset,
+                                                       sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmTLSDescSeq32 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmTLSDescSeq32 { offset: offset,
+                                                       sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmGOTBRel12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmGOTBRel12 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG0NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG0NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG1NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG1NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG2NC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG2NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG3 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG3 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::IRelative { offset, addend } =>
+                Ok(ArmReloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> TryFrom<ArmRelocStrDataSym<'a>> for ArmRelocStrSym<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: ArmRelocStrDataSym<'a>) ->
+        Result<ArmRelocStrSym<'a>, Self::Error> {
+        match reloc {
+            ArmReloc::None => Ok(ArmReloc::None),
+            ArmReloc::PC24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PC24 { offset: offset, sym: symdata,
+                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Rel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Rel32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrPCG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrPCG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs12 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAbs5 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAbs5 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs8 { offset: offset, sym: symdata,
+                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::SBRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::SBRel32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmCall { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmCall { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmPC8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmPC8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::BRelAdj { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::BRelAdj { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDesc { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDesc { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDTPMod32 { offset: offset,
+                                                   sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDTPOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDTPOff32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSTPOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSTPOff32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GlobalData { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GlobalData { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::JumpSlot { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Relative { offset, addend } =>
+                Ok(ArmReloc::Relative { offset: offset, addend: addend }),
+            ArmReloc::GOTOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTOff32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::BasePRel { offset, addend } =>
+                Ok(ArmReloc::BasePRel { offset: offset, addend: addend }),
+            ArmReloc::GOTBRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTBRel { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PLT32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Call { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Call { offset: offset, sym: symdata,
+                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Jump24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Jump24 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump24 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::BaseAbs { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::BaseAbs { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Target1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Target1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::SBRel31 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::SBRel31 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::V4BX { offset } =>
+                Ok(ArmReloc::V4BX { offset: offset }),
+            ArmReloc::Target2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Target2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::PRel31 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PRel31 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWAbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWAbsNC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovTAbs { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovTAbs { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWPRelNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWPRelNC { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovTPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovTPRel { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWAbsNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWAbsNC { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovTAbs { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovTAbs { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWPRelNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWPRelNC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovTPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovTPRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump19 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump6 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump6 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluPRel11_0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluPRel11_0 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmPC12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmPC12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Abs32NoI { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Abs32NoI { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::Rel32NoI { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::Rel32NoI { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG0NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG1NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluPCG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluPCG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrPCG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrPCG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrPCG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrPCG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsPCG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsPCG0 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsPCG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsPCG1 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsPCG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsPCG2 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcPCG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcPCG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcPCG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcPCG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcPCG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcPCG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG0NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG1NC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::AluSBG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::AluSBG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrSBG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrSBG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrSBG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrSBG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrSBG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrSBG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsSBG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsSBG0 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsSBG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsSBG1 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdrsSBG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdrsSBG2 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcSBG0 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcSBG0 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcSBG1 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcSBG1 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::LdcSBG2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::LdcSBG2 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWBRelNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWBRelNC { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovTBRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovTBRel { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::MovWBRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::MovWBRel { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWBRelNC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWBRelNC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovTBRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovTBRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmMovWBRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmMovWBRel { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSGOTDesc { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSGOTDesc { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSCall { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSCall { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSDescSeq { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSDescSeq { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmTLSCall { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmTLSCall { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::PLT32Abs { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::PLT32Abs { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTAbs { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTAbs { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTPRel { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTBRel12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTBRel12 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GOTOff12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GOTOff12 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GNUVTEntry { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::GNUVTInherit { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::GNUVTInherit { offset: offset,
+                                                    sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump11 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump11 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmJump8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmJump8 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSGD32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSGD32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLDM32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLDM32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLDO32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLDO32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSIE32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSIE32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLE32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLE32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLDO12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLDO12 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSLE12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSLE12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::TLSIE12GP { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::TLSIE12GP { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmTLSDescSeq16 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmTLSDescSeq16 { offset: offset,
+                                                       sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmTLSDescSeq32 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmTLSDescSeq32 { offset: offset,
+                                                       sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmGOTBRel12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmGOTBRel12 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG0NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG0NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG1NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG1NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG2NC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG2NC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::ThmAluAbsG3 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(ArmReloc::ThmAluAbsG3 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            ArmReloc::IRelative { offset, addend } =>
+                Ok(ArmReloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> From<ArmRelocStrDataSym<'a>> for ArmRelocStrData<'a> {
+    #[inline]
+    fn from(reloc: ArmRelocStrDataSym<'a>) -> ArmRelocStrData<'a> {
+        match reloc {
+            ArmReloc::None => ArmReloc::None,
+            ArmReloc::PC24 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PC24 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Rel32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Rel32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrPCG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs16 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs12 { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmAbs5 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmAbs5 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs8 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs8 { offset: offset, sym: name, addend: addend },
+            ArmReloc::SBRel32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::SBRel32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmCall { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmCall { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmPC8 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmPC8 { offset: offset, sym: name, addend: addend },
+            ArmReloc::BRelAdj { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::BRelAdj { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSDesc { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSDesc { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                ArmReloc::TLSDTPMod32 { offset: offset, sym: name },
+            ArmReloc::TLSDTPOff32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::TLSDTPOff32 { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::TLSTPOff32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::TLSTPOff32 { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::Copy { sym: SymData { name, .. }, offset } =>
+                ArmReloc::Copy { offset: offset, sym: name },
+            ArmReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::GlobalData { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::JumpSlot { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::JumpSlot { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::Relative { offset, addend } =>
+                ArmReloc::Relative { offset: offset, addend: addend },
+            ArmReloc::GOTOff32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTOff32 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::BasePRel { offset, addend } =>
+                ArmReloc::BasePRel { offset: offset, addend: addend },
+            ArmReloc::GOTBRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTBRel { offset: offset, sym: name, addend: addend },
+            ArmReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Call { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Call { offset: offset, sym: name, addend: addend },
+            ArmReloc::Jump24 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Jump24 { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmJump24 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump24 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::BaseAbs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::BaseAbs { offset: offset, sym: name, addend: addend },
+            ArmReloc::Target1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Target1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::SBRel31 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::SBRel31 { offset: offset, sym: name, addend: addend },
+            ArmReloc::V4BX { offset } =>
+                ArmReloc::V4BX { offset: offset },
+            ArmReloc::Target2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Target2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::PRel31 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PRel31 { offset: offset, sym: name, addend: addend },
+            ArmReloc::MovWAbsNC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovWAbsNC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::MovTAbs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovTAbs { offset: offset, sym: name, addend: addend },
+            ArmReloc::MovWPRelNC { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::MovWPRelNC { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::MovTPRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovTPRel { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::ThmMovWAbsNC { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                ArmReloc::ThmMovWAbsNC { offset: offset, sym: name,
+                                         addend: addend },
+            ArmReloc::ThmMovTAbs { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::ThmMovTAbs { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::ThmMovWPRelNC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmMovWPRelNC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmMovTPRel { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmMovTPRel { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::ThmJump19 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump19 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::ThmJump6 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump6 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::ThmAluPRel11_0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                ArmReloc::ThmAluPRel11_0 { offset: offset, sym: name,
+                                           addend: addend },
+            ArmReloc::ThmPC12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmPC12 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs32NoI { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs32NoI { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::Rel32NoI { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Rel32NoI { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::AluPCG0NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG0NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluPCG1NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG1NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrPCG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrPCG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrsPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsPCG0 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsPCG1 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsPCG2 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdcPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcPCG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcPCG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcPCG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluSBG0NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG0NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluSBG1NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG1NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrSBG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrSBG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrSBG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrsSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsSBG0 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsSBG1 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsSBG2 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdcSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcSBG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcSBG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcSBG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::MovWBRelNC { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::MovWBRelNC { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::MovTBRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovTBRel { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::MovWBRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovWBRel { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::ThmMovWBRelNC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmMovWBRelNC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmMovTBRel { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmMovTBRel { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::ThmMovWBRel { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmMovWBRel { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::TLSGOTDesc { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::TLSGOTDesc { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::TLSCall { sym: SymData { name, .. }, offset } =>
+                ArmReloc::TLSCall { offset: offset, sym: name },
+            ArmReloc::TLSDescSeq { sym: SymData { name, .. }, offset } =>
+                ArmReloc::TLSDescSeq { offset: offset, sym: name },
+            ArmReloc::ThmTLSCall { sym: SymData { name, .. }, offset } =>
+                ArmReloc::ThmTLSCall { offset: offset, sym: name },
+            ArmReloc::PLT32Abs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PLT32Abs { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::GOTAbs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTAbs { offset: offset, sym: name, addend: addend },
+            ArmReloc::GOTPRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTPRel { offset: offset, sym: name, addend: addend },
+            ArmReloc::GOTBRel12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTBRel12 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::GOTOff12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTOff12 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::GNUVTEntry { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::GNUVTInherit { sym: SymData { name, .. }, offset } =>
+                ArmReloc::GNUVTInherit { offset: offset, sym: name },
+            ArmReloc::ThmJump11 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump11 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::ThmJump8 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump8 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSGD32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSGD32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSLDM32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLDM32 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSLDO32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLDO32 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSIE32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSIE32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSLE32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLE32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSLDO12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLDO12 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSLE12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLE12 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSIE12GP { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSIE12GP { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::ThmTLSDescSeq16 { sym: SymData { name, .. }, offset } =>
+                ArmReloc::ThmTLSDescSeq16 { offset: offset, sym: name },
+            ArmReloc::ThmTLSDescSeq32 { sym: SymData { name, .. }, offset } =>
+                ArmReloc::ThmTLSDescSeq32 { offset: offset, sym: name },
+            ArmReloc::ThmGOTBRel12 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                ArmReloc::ThmGOTBRel12 { offset: offset, sym: name,
+                                         addend: addend },
+            ArmReloc::ThmAluAbsG0NC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmAluAbsG0NC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmAluAbsG1NC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmAluAbsG1NC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmAluAbsG2NC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmAluAbsG2NC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmAluAbsG3 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmAluAbsG3 { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::IRelative { offset, addend } =>
+                ArmReloc::IRelative { offset: offset, addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<ArmRelocStrData<'a>> for ArmRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: ArmRelocStrData<'a>) ->
+        Result<ArmRelocStr<'a>, Self::Error> {
+        match reloc {
+            ArmReloc::None => Ok(ArmReloc::None),
+            ArmReloc::PC24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::PC24 { offset: offset, sym: Some(name),
+                                    addend: addend }),
+            ArmReloc::PC24 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::PC24 { sym: None, offset, addend } =>
+                Ok(ArmReloc::PC24 { offset: offset, sym: None,
+                                    addend: addend }),
+            ArmReloc::Abs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Abs32 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            ArmReloc::Abs32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Abs32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Abs32 { offset: offset, sym: None,
+                                     addend: addend }),
+            ArmReloc::Rel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Rel32 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            ArmReloc::Rel32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Rel32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Rel32 { offset: offset, sym: None,
+                                     addend: addend }),
+            ArmReloc::LdrPCG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrPCG0 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdrPCG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrPCG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrPCG0 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::Abs16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Abs16 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            ArmReloc::Abs16 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Abs16 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Abs16 { offset: offset, sym: None,
+                                     addend: addend }),
+            ArmReloc::Abs12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Abs12 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            ArmReloc::Abs12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Abs12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Abs12 { offset: offset, sym: None,
+                                     addend: addend }),
+            ArmReloc::ThmAbs5 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmAbs5 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::ThmAbs5 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmAbs5 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmAbs5 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::Abs8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Abs8 { offset: offset, sym: Some(name),
+                                    addend: addend }),
+            ArmReloc::Abs8 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Abs8 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Abs8 { offset: offset, sym: None,
+                                    addend: addend }),
+            ArmReloc::SBRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::SBRel32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::SBRel32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::SBRel32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::SBRel32 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::ThmCall { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmCall { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::ThmCall { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmCall { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmCall { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::ThmPC8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmPC8 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            ArmReloc::ThmPC8 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmPC8 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmPC8 { offset: offset, sym: None,
+                                      addend: addend }),
+            ArmReloc::BRelAdj { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::BRelAdj { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::BRelAdj { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::BRelAdj { sym: None, offset, addend } =>
+                Ok(ArmReloc::BRelAdj { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::TLSDesc { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSDesc { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::TLSDesc { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSDesc { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSDesc { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::TLSDTPMod32 { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::TLSDTPMod32 { offset: offset, sym: Some(name) }),
+            ArmReloc::TLSDTPMod32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSDTPMod32 { sym: None, offset } =>
+                Ok(ArmReloc::TLSDTPMod32 { offset: offset, sym: None }),
+            ArmReloc::TLSDTPOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSDTPOff32 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            ArmReloc::TLSDTPOff32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSDTPOff32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSDTPOff32 { offset: offset, sym: None,
+                                           addend: addend }),
+            ArmReloc::TLSTPOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSTPOff32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::TLSTPOff32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSTPOff32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSTPOff32 { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::Copy { offset: offset, sym: Some(name) }),
+            ArmReloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Copy { sym: None, offset } =>
+                Ok(ArmReloc::Copy { offset: offset, sym: None }),
+            ArmReloc::GlobalData { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GlobalData { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::GlobalData { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GlobalData { sym: None, offset, addend } =>
+                Ok(ArmReloc::GlobalData { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::JumpSlot { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(ArmReloc::JumpSlot { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::Relative { offset, addend } =>
+                Ok(ArmReloc::Relative { offset: offset, addend: addend }),
+            ArmReloc::GOTOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GOTOff32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::GOTOff32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GOTOff32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::GOTOff32 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::BasePRel { offset, addend } =>
+                Ok(ArmReloc::BasePRel { offset: offset, addend: addend }),
+            ArmReloc::GOTBRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GOTBRel { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::GOTBRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GOTBRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::GOTBRel { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::PLT32 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            ArmReloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::PLT32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::PLT32 { offset: offset, sym: None,
+                                     addend: addend }),
+            ArmReloc::Call { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Call { offset: offset, sym: Some(name),
+                                    addend: addend }),
+            ArmReloc::Call { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Call { sym: None, offset, addend } =>
+                Ok(ArmReloc::Call { offset: offset, sym: None,
+                                    addend: addend }),
+            ArmReloc::Jump24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Jump24 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            ArmReloc::Jump24 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Jump24 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Jump24 { offset: offset, sym: None,
+                                      addend: addend }),
+            ArmReloc::ThmJump24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmJump24 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::ThmJump24 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmJump24 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmJump24 { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::BaseAbs { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::BaseAbs { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::BaseAbs { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::BaseAbs { sym: None, offset, addend } =>
+                Ok(ArmReloc::BaseAbs { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::Target1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Target1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::Target1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Target1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Target1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::SBRel31 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::SBRel31 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::SBRel31 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::SBRel31 { sym: None, offset, addend } =>
+                Ok(ArmReloc::SBRel31 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::V4BX { offset } =>
+                Ok(ArmReloc::V4BX { offset: offset }),
+            ArmReloc::Target2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Target2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::Target2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Target2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::Target2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::PRel31 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::PRel31 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            ArmReloc::PRel31 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::PRel31 { sym: None, offset, addend } =>
+                Ok(ArmReloc::PRel31 { offset: offset, sym: None,
+                                      addend: addend }),
+            ArmReloc::MovWAbsNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovWAbsNC { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::MovWAbsNC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovWAbsNC { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovWAbsNC { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::MovTAbs { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovTAbs { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::MovTAbs { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovTAbs { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovTAbs { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::MovWPRelNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovWPRelNC { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::MovWPRelNC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovWPRelNC { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovWPRelNC { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::MovTPRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovTPRel { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::MovTPRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovTPRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovTPRel { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::ThmMovWAbsNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovWAbsNC { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            ArmReloc::ThmMovWAbsNC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovWAbsNC { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovWAbsNC { offset: offset, sym: None,
+                                            addend: addend }),
+            ArmReloc::ThmMovTAbs { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovTAbs { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::ThmMovTAbs { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovTAbs { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovTAbs { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::ThmMovWPRelNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovWPRelNC { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            ArmReloc::ThmMovWPRelNC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovWPRelNC { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovWPRelNC { offset: offset, sym: None,
+                                             addend: addend }),
+            ArmReloc::ThmMovTPRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovTPRel { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            ArmReloc::ThmMovTPRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovTPRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovTPRel { offset: offset, sym: None,
+                                           addend: addend }),
+            ArmReloc::ThmJump19 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmJump19 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::ThmJump19 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmJump19 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmJump19 { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::ThmJump6 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmJump6 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::ThmJump6 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmJump6 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmJump6 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::ThmAluPRel11_0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmAluPRel11_0 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            ArmReloc::ThmAluPRel11_0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmAluPRel11_0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmAluPRel11_0 { offset: offset, sym: None,
+                                              addend: addend }),
+            ArmReloc::ThmPC12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmPC12 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::ThmPC12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmPC12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmPC12 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::Abs32NoI { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Abs32NoI { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::Abs32NoI { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Abs32NoI { sym: None, offset, addend } =>
+                Ok(ArmReloc::Abs32NoI { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::Rel32NoI { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::Rel32NoI { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::Rel32NoI { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::Rel32NoI { sym: None, offset, addend } =>
+                Ok(ArmReloc::Rel32NoI { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::AluPCG0NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluPCG0NC { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::AluPCG0NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluPCG0NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluPCG0NC { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::AluPCG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluPCG0 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::AluPCG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluPCG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluPCG0 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::AluPCG1NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluPCG1NC { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::AluPCG1NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluPCG1NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluPCG1NC { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::AluPCG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluPCG1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::AluPCG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluPCG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluPCG1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::AluPCG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluPCG2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::AluPCG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluPCG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluPCG2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrPCG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrPCG1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdrPCG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrPCG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrPCG1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrPCG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrPCG2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdrPCG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrPCG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrPCG2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrsPCG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrsPCG0 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::LdrsPCG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrsPCG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrsPCG0 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::LdrsPCG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrsPCG1 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::LdrsPCG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrsPCG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrsPCG1 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::LdrsPCG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrsPCG2 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::LdrsPCG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrsPCG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrsPCG2 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::LdcPCG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdcPCG0 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdcPCG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdcPCG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdcPCG0 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdcPCG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdcPCG1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdcPCG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdcPCG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdcPCG1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdcPCG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdcPCG2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdcPCG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdcPCG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdcPCG2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::AluSBG0NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluSBG0NC { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::AluSBG0NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluSBG0NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluSBG0NC { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::AluSBG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluSBG0 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::AluSBG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluSBG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluSBG0 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::AluSBG1NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluSBG1NC { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::AluSBG1NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluSBG1NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluSBG1NC { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::AluSBG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluSBG1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::AluSBG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluSBG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluSBG1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::AluSBG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::AluSBG2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::AluSBG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::AluSBG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::AluSBG2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrSBG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrSBG0 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdrSBG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrSBG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrSBG0 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrSBG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrSBG1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdrSBG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrSBG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrSBG1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrSBG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrSBG2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdrSBG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrSBG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrSBG2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdrsSBG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrsSBG0 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::LdrsSBG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrsSBG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrsSBG0 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::LdrsSBG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrsSBG1 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::LdrsSBG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrsSBG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrsSBG1 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::LdrsSBG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdrsSBG2 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::LdrsSBG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdrsSBG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdrsSBG2 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::LdcSBG0 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdcSBG0 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdcSBG0 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdcSBG0 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdcSBG0 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdcSBG1 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdcSBG1 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdcSBG1 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdcSBG1 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdcSBG1 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::LdcSBG2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::LdcSBG2 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::LdcSBG2 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::LdcSBG2 { sym: None, offset, addend } =>
+                Ok(ArmReloc::LdcSBG2 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::MovWBRelNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovWBRelNC { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::MovWBRelNC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovWBRelNC { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovWBRelNC { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::MovTBRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovTBRel { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::MovTBRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovTBRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovTBRel { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::MovWBRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::MovWBRel { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::MovWBRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::MovWBRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::MovWBRel { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::ThmMovWBRelNC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovWBRelNC { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            ArmReloc::ThmMovWBRelNC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovWBRelNC { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovWBRelNC { offset: offset, sym: None,
+                                             addend: addend }),
+            ArmReloc::ThmMovTBRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovTBRel { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            ArmReloc::ThmMovTBRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovTBRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovTBRel { offset: offset, sym: None,
+                                           addend: addend }),
+            ArmReloc::ThmMovWBRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmMovWBRel { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            ArmReloc::ThmMovWBRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmMovWBRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmMovWBRel { offset: offset, sym: None,
+                                           addend: addend }),
+            ArmReloc::TLSGOTDesc { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSGOTDesc { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::TLSGOTDesc { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSGOTDesc { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSGOTDesc { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::TLSCall { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::TLSCall { offset: offset, sym: Some(name) }),
+            ArmReloc::TLSCall { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSCall { sym: None, offset } =>
+                Ok(ArmReloc::TLSCall { offset: offset, sym: None }),
+            ArmReloc::TLSDescSeq { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::TLSDescSeq { offset: offset, sym: Some(name) }),
+            ArmReloc::TLSDescSeq { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSDescSeq { sym: None, offset } =>
+                Ok(ArmReloc::TLSDescSeq { offset: offset, sym: None }),
+            ArmReloc::ThmTLSCall { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::ThmTLSCall { offset: offset, sym: Some(name) }),
+            ArmReloc::ThmTLSCall { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmTLSCall { sym: None, offset } =>
+                Ok(ArmReloc::ThmTLSCall { offset: offset, sym: None }),
+            ArmReloc::PLT32Abs { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::PLT32Abs { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::PLT32Abs { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::PLT32Abs { sym: None, offset, addend } =>
+                Ok(ArmReloc::PLT32Abs { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::GOTAbs { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GOTAbs { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            ArmReloc::GOTAbs { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GOTAbs { sym: None, offset, addend } =>
+                Ok(ArmReloc::GOTAbs { offset: offset, sym: None,
+                                      addend: addend }),
+            ArmReloc::GOTPRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GOTPRel { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::GOTPRel { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GOTPRel { sym: None, offset, addend } =>
+                Ok(ArmReloc::GOTPRel { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::GOTBRel12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GOTBRel12 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::GOTBRel12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GOTBRel12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::GOTBRel12 { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::GOTOff12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GOTOff12 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::GOTOff12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GOTOff12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::GOTOff12 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::GNUVTEntry { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::GNUVTEntry { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            ArmReloc::GNUVTEntry { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GNUVTEntry { sym: None, offset, addend } =>
+                Ok(ArmReloc::GNUVTEntry { offset: offset, sym: None,
+                                          addend: addend }),
+            ArmReloc::GNUVTInherit { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::GNUVTInherit { offset: offset, sym: Some(name) }),
+            ArmReloc::GNUVTInherit { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::GNUVTInherit { sym: None, offset } =>
+                Ok(ArmReloc::GNUVTInherit { offset: offset, sym: None }),
+            ArmReloc::ThmJump11 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmJump11 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::ThmJump11 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmJump11 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmJump11 { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::ThmJump8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmJump8 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::ThmJump8 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmJump8 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmJump8 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::TLSGD32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSGD32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::TLSGD32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSGD32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSGD32 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::TLSLDM32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSLDM32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::TLSLDM32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSLDM32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSLDM32 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::TLSLDO32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSLDO32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::TLSLDO32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSLDO32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSLDO32 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::TLSIE32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSIE32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::TLSIE32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSIE32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSIE32 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::TLSLE32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSLE32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::TLSLE32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSLE32 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSLE32 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::TLSLDO12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSLDO12 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            ArmReloc::TLSLDO12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSLDO12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSLDO12 { offset: offset, sym: None,
+                                        addend: addend }),
+            ArmReloc::TLSLE12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSLE12 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            ArmReloc::TLSLE12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSLE12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSLE12 { offset: offset, sym: None,
+                                       addend: addend }),
+            ArmReloc::TLSIE12GP { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::TLSIE12GP { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            ArmReloc::TLSIE12GP { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::TLSIE12GP { sym: None, offset, addend } =>
+                Ok(ArmReloc::TLSIE12GP { offset: offset, sym: None,
+                                         addend: addend }),
+            ArmReloc::ThmTLSDescSeq16 { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::ThmTLSDescSeq16 { offset: offset,
+                                               sym: Some(name) }),
+            ArmReloc::ThmTLSDescSeq16 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmTLSDescSeq16 { sym: None, offset } =>
+                Ok(ArmReloc::ThmTLSDescSeq16 { offset: offset, sym: None }),
+            ArmReloc::ThmTLSDescSeq32 { sym: Some(Ok(name)), offset } =>
+                Ok(ArmReloc::ThmTLSDescSeq32 { offset: offset,
+                                               sym: Some(name) }),
+            ArmReloc::ThmTLSDescSeq32 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmTLSDescSeq32 { sym: None, offset } =>
+                Ok(ArmReloc::ThmTLSDescSeq32 { offset: offset, sym: None }),
+            ArmReloc::ThmGOTBRel12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmGOTBRel12 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            ArmReloc::ThmGOTBRel12 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmGOTBRel12 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmGOTBRel12 { offset: offset, sym: None,
+                                            addend: addend }),
+            ArmReloc::ThmAluAbsG0NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG0NC { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            ArmReloc::ThmAluAbsG0NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmAluAbsG0NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG0NC { offset: offset, sym: None,
+                                             addend: addend }),
+            ArmReloc::ThmAluAbsG1NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG1NC { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            ArmReloc::ThmAluAbsG1NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmAluAbsG1NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG1NC { offset: offset, sym: None,
+                                             addend: addend }),
+            ArmReloc::ThmAluAbsG2NC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG2NC { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            ArmReloc::ThmAluAbsG2NC { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmAluAbsG2NC { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG2NC { offset: offset, sym: None,
+                                             addend: addend }),
+            ArmReloc::ThmAluAbsG3 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG3 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            ArmReloc::ThmAluAbsG3 { sym: Some(Err(err)), .. } => Err(err),
+            ArmReloc::ThmAluAbsG3 { sym: None, offset, addend } =>
+                Ok(ArmReloc::ThmAluAbsG3 { offset: offset, sym: None,
+                                           addend: addend }),
+            ArmReloc::IRelative { offset, addend } =>
+                Ok(ArmReloc::IRelative { offset: offset, addend: addend })
+        }
+    }
+}
+
+impl<'a> From<ArmRelocStrSym<'a>> for ArmRelocStr<'a> {
+    #[inline]
+    fn from(reloc: ArmRelocStrSym<'a>) -> ArmRelocStr<'a> {
+        match reloc {
+            ArmReloc::None => ArmReloc::None,
+            ArmReloc::PC24 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PC24 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Rel32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Rel32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrPCG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs16 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs12 { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmAbs5 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmAbs5 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs8 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs8 { offset: offset, sym: name, addend: addend },
+            ArmReloc::SBRel32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::SBRel32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmCall { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmCall { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmPC8 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmPC8 { offset: offset, sym: name, addend: addend },
+            ArmReloc::BRelAdj { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::BRelAdj { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSDesc { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSDesc { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                ArmReloc::TLSDTPMod32 { offset: offset, sym: name },
+            ArmReloc::TLSDTPOff32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::TLSDTPOff32 { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::TLSTPOff32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::TLSTPOff32 { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::Copy { sym: SymData { name, .. }, offset } =>
+                ArmReloc::Copy { offset: offset, sym: name },
+            ArmReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::GlobalData { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::JumpSlot { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::JumpSlot { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::Relative { offset, addend } =>
+                ArmReloc::Relative { offset: offset, addend: addend },
+            ArmReloc::GOTOff32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTOff32 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::BasePRel { offset, addend } =>
+                ArmReloc::BasePRel { offset: offset, addend: addend },
+            ArmReloc::GOTBRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTBRel { offset: offset, sym: name, addend: addend },
+            ArmReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Call { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Call { offset: offset, sym: name, addend: addend },
+            ArmReloc::Jump24 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Jump24 { offset: offset, sym: name, addend: addend },
+            ArmReloc::ThmJump24 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump24 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::BaseAbs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::BaseAbs { offset: offset, sym: name, addend: addend },
+            ArmReloc::Target1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Target1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::SBRel31 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::SBRel31 { offset: offset, sym: name, addend: addend },
+            ArmReloc::V4BX { offset } =>
+                ArmReloc::V4BX { offset: offset },
+            ArmReloc::Target2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Target2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::PRel31 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PRel31 { offset: offset, sym: name, addend: addend },
+            ArmReloc::MovWAbsNC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovWAbsNC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::MovTAbs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovTAbs { offset: offset, sym: name, addend: addend },
+            ArmReloc::MovWPRelNC { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::MovWPRelNC { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::MovTPRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovTPRel { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::ThmMovWAbsNC { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                ArmReloc::ThmMovWAbsNC { offset: offset, sym: name,
+                                         addend: addend },
+            ArmReloc::ThmMovTAbs { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::ThmMovTAbs { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::ThmMovWPRelNC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmMovWPRelNC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmMovTPRel { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmMovTPRel { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::ThmJump19 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump19 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::ThmJump6 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump6 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::ThmAluPRel11_0 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                ArmReloc::ThmAluPRel11_0 { offset: offset, sym: name,
+                                           addend: addend },
+            ArmReloc::ThmPC12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmPC12 { offset: offset, sym: name, addend: addend },
+            ArmReloc::Abs32NoI { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Abs32NoI { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::Rel32NoI { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::Rel32NoI { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::AluPCG0NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG0NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluPCG1NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG1NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluPCG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrPCG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrPCG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrsPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsPCG0 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsPCG1 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsPCG2 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdcPCG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcPCG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcPCG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcPCG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcPCG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcPCG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluSBG0NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG0NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluSBG1NC { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG1NC { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::AluSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::AluSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::AluSBG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrSBG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrSBG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrSBG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdrsSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsSBG0 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsSBG1 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdrsSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdrsSBG2 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::LdcSBG0 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcSBG0 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcSBG1 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcSBG1 { offset: offset, sym: name, addend: addend },
+            ArmReloc::LdcSBG2 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::LdcSBG2 { offset: offset, sym: name, addend: addend },
+            ArmReloc::MovWBRelNC { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::MovWBRelNC { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::MovTBRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovTBRel { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::MovWBRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::MovWBRel { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::ThmMovWBRelNC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmMovWBRelNC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmMovTBRel { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmMovTBRel { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::ThmMovWBRel { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmMovWBRel { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::TLSGOTDesc { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::TLSGOTDesc { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::TLSCall { sym: SymData { name, .. }, offset } =>
+                ArmReloc::TLSCall { offset: offset, sym: name },
+            ArmReloc::TLSDescSeq { sym: SymData { name, .. }, offset } =>
+                ArmReloc::TLSDescSeq { offset: offset, sym: name },
+            ArmReloc::ThmTLSCall { sym: SymData { name, .. }, offset } =>
+                ArmReloc::ThmTLSCall { offset: offset, sym: name },
+            ArmReloc::PLT32Abs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::PLT32Abs { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::GOTAbs { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTAbs { offset: offset, sym: name, addend: addend },
+            ArmReloc::GOTPRel { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTPRel { offset: offset, sym: name, addend: addend },
+            ArmReloc::GOTBRel12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTBRel12 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::GOTOff12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::GOTOff12 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                ArmReloc::GNUVTEntry { offset: offset, sym: name,
+                                       addend: addend },
+            ArmReloc::GNUVTInherit { sym: SymData { name, .. }, offset } =>
+                ArmReloc::GNUVTInherit { offset: offset, sym: name },
+            ArmReloc::ThmJump11 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump11 { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::ThmJump8 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::ThmJump8 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSGD32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSGD32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSLDM32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLDM32 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSLDO32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLDO32 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSIE32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSIE32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSLE32 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLE32 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSLDO12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLDO12 { offset: offset, sym: name,
+                                     addend: addend },
+            ArmReloc::TLSLE12 { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSLE12 { offset: offset, sym: name, addend: addend },
+            ArmReloc::TLSIE12GP { sym: SymData { name, .. }, offset, addend } =>
+                ArmReloc::TLSIE12GP { offset: offset, sym: name,
+                                      addend: addend },
+            ArmReloc::ThmTLSDescSeq16 { sym: SymData { name, .. }, offset } =>
+                ArmReloc::ThmTLSDescSeq16 { offset: offset, sym: name },
+            ArmReloc::ThmTLSDescSeq32 { sym: SymData { name, .. }, offset } =>
+                ArmReloc::ThmTLSDescSeq32 { offset: offset, sym: name },
+            ArmReloc::ThmGOTBRel12 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                ArmReloc::ThmGOTBRel12 { offset: offset, sym: name,
+                                         addend: addend },
+            ArmReloc::ThmAluAbsG0NC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmAluAbsG0NC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmAluAbsG1NC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmAluAbsG1NC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmAluAbsG2NC { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                ArmReloc::ThmAluAbsG2NC { offset: offset, sym: name,
+                                          addend: addend },
+            ArmReloc::ThmAluAbsG3 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                ArmReloc::ThmAluAbsG3 { offset: offset, sym: name,
+                                        addend: addend },
+            ArmReloc::IRelative { offset, addend } =>
+                ArmReloc::IRelative { offset: offset, addend: addend }
+        }
+    }
+}
@@ -0,0 +1,7374 @@
+//! Relocation types for LoongArch architecture.
+//!
+//! This module provides the `LoongArchReloc` type, which describes
+//! the relocation entries for the LoongArch architecture, as defined
+//! by the LoongArch ELF psABI.  The same relocation types are used by
+//! both LA32 and LA64, so these can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with either
+//! [Elf32](crate::Elf32) or [Elf64](crate::Elf64) as the
+//! [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+//!
+//! Older toolchains describe computations with a stack machine: the
+//! `SOPPush` relocations push values, the operator relocations
+//! combine them, and the `SOPPop` relocations write the result into
+//! an instruction field.  Newer toolchains instead use a single
+//! relocation per instruction, such as the
+//! [PCALAHi20](LoongArchReloc::PCALAHi20) and
+//! [GOTPCHi20](LoongArchReloc::GOTPCHi20) families.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf32;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::SymOffsets;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for LoongArch architectures.
+///
+/// This datatype provides a semantic-level presentation of the
+/// LoongArch relocation entries, covering both LA32 and LA64.  These
+/// can be converted to and from [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) or
+/// [Elf64](crate::Elf64) as the [ElfClass](crate::ElfClass) type
+/// argument using the [TryFrom](core::convert::TryFrom) instances for
+/// easier handling.  Offsets and addends are always stored at 64-bit
+/// width; converting back to a 32-bit relocation fails if they do not
+/// fit.
+///
+/// The stack-based relocations, such as
+/// [SOPPushPCRel](LoongArchReloc::SOPPushPCRel) and
+/// [SOPPop32S10W16S2](LoongArchReloc::SOPPop32S10W16S2), are named
+/// after their psABI counterparts, with the field widths spelled out;
+/// for example `R_LARCH_SOP_POP_32_S_10_16_S2` writes a signed 16-bit
+/// field at bit 10, shifted right by 2.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum LoongArchReloc<Name> {
+    /// Null relocation.
+    None,
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Abs32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`.
+    Abs64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Offset relative to the image base.
+    ///
+    /// Set the address-sized word at `offset` to the sum of the base
+    /// address and `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit thread-local storage module index.
+    ///
+    /// Set the 4-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 64-bit thread-local storage module index.
+    ///
+    /// Set the 8-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 32-bit thread-local storage block offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSDTPRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread-local storage block offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSDTPRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSTPRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSTPRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect offset relative to the image base.
+    ///
+    /// Set the address-sized word at `offset` to the address returned by
+    /// the resolver function at the base address plus `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit thread-local storage descriptor.
+    ///
+    /// Set the two 4-byte words at `offset` to a thread-local storage
+    /// descriptor for `sym + addend`.
+    TLSDesc32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread-local storage descriptor.
+    ///
+    /// Set the two 8-byte words at `offset` to a thread-local storage
+    /// descriptor for `sym + addend`.
+    TLSDesc64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Absolute address load marker.
+    ///
+    /// Marks the `la.abs` macro expansion at `offset` loading the address
+    /// of `sym + addend`.
+    MarkLA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative address load marker.
+    ///
+    /// Marks the `la.pcrel` macro expansion at `offset` loading the
+    /// address of `sym + addend`.
+    MarkPCRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Push a PC-relative offset.
+    ///
+    /// Push the relative address of `sym + addend` (computed by
+    /// subtracting the address of `offset`) onto the relocation stack.
+    /// This is a legacy stack-based relocation.
+    SOPPushPCRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Push an absolute address.
+    ///
+    /// Push `sym + addend` onto the relocation stack.  This is a legacy
+    /// stack-based relocation.
+    SOPPushAbsolute {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Duplicate the top of the stack.
+    ///
+    /// Push a copy of the top of the relocation stack.  This is a legacy
+    /// stack-based relocation.
+    SOPPushDup {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Push a Global Offset Table offset.
+    ///
+    /// Push the offset of the GOT entry for `sym + addend` onto the
+    /// relocation stack.  This is a legacy stack-based relocation.
+    SOPPushGPRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Push a thread pointer offset.
+    ///
+    /// Push the offset of `sym + addend` from the thread pointer onto the
+    /// relocation stack.  This is a legacy stack-based relocation.
+    SOPPushTLSTPRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Push an initial exec thread-local GOT offset.
+    ///
+    /// Push the offset of the GOT entry holding the thread pointer offset
+    /// for `sym + addend` onto the relocation stack.  This is a legacy
+    /// stack-based relocation.
+    SOPPushTLSGOT {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Push a general dynamic thread-local GOT offset.
+    ///
+    /// Push the offset of the GOT entry holding the `tls_index` for
+    /// `sym + addend` onto the relocation stack.  This is a legacy
+    /// stack-based relocation.
+    SOPPushTLSGD {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Push a PC-relative Procedure Linkage Table offset.
+    ///
+    /// Push the relative address of the PLT entry for `sym`, plus
+    /// `addend` onto the relocation stack.  This is a legacy stack-based
+    /// relocation.
+    SOPPushPLTPCRel {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Assert the top of the stack.
+    ///
+    /// Pop the top of the relocation stack, and fail if it is zero.  This
+    /// is a legacy stack-based relocation.
+    SOPAssert {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Logical negation.
+    ///
+    /// Pop the operands from the relocation stack, and push `!a`.  This
+    /// is a legacy stack-based relocation.
+    SOPNot {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Subtraction.
+    ///
+    /// Pop the operands from the relocation stack, and push `a - b`.
+    /// This is a legacy stack-based relocation.
+    SOPSub {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Left shift.
+    ///
+    /// Pop the operands from the relocation stack, and push `a << b`.
+    /// This is a legacy stack-based relocation.
+    SOPSL {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Right shift.
+    ///
+    /// Pop the operands from the relocation stack, and push `a >> b`.
+    /// This is a legacy stack-based relocation.
+    SOPSR {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Addition.
+    ///
+    /// Pop the operands from the relocation stack, and push `a + b`.
+    /// This is a legacy stack-based relocation.
+    SOPAdd {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Bitwise and.
+    ///
+    /// Pop the operands from the relocation stack, and push `a & b`.
+    /// This is a legacy stack-based relocation.
+    SOPAnd {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Conditional selection.
+    ///
+    /// Pop the operands from the relocation stack, and push `a ? b : c`.
+    /// This is a legacy stack-based relocation.
+    SOPIfElse {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 5-bit field at bit 10.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 5-bit field at bit 10.  This
+    /// is a legacy stack-based relocation.
+    SOPPop32S10W5 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into an unsigned 12-bit field at bit 10.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as an unsigned 12-bit field at bit 10.
+    /// This is a legacy stack-based relocation.
+    SOPPop32U10W12 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 12-bit field at bit 10.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 12-bit field at bit 10.  This
+    /// is a legacy stack-based relocation.
+    SOPPop32S10W12 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 16-bit field at bit 10.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 16-bit field at bit 10.  This
+    /// is a legacy stack-based relocation.
+    SOPPop32S10W16 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 16-bit field at bit 10, shifted right by 2.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 16-bit field at bit 10,
+    /// shifted right by 2.  This is a legacy stack-based relocation.
+    SOPPop32S10W16S2 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 20-bit field at bit 5.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 20-bit field at bit 5.  This
+    /// is a legacy stack-based relocation.
+    SOPPop32S5W20 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 21-bit split field.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 21-bit field split across bits
+    /// 0 and 10, shifted right by 2.  This is a legacy stack-based
+    /// relocation.
+    SOPPop32S0W5S10W16S2 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a signed 26-bit split field.
+    ///
+    /// Pop the top of the relocation stack and write it to the
+    /// instruction at `offset` as a signed 26-bit field split across bits
+    /// 0 and 10, shifted right by 2.  This is a legacy stack-based
+    /// relocation.
+    SOPPop32S0W10S10W16S2 {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Pop a value into a 32-bit word.
+    ///
+    /// Pop the top of the relocation stack and write it to the 4-byte
+    /// word at `offset`.  This is a legacy stack-based relocation.
+    SOPPop32U {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// 8-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 1-byte word at `offset`.
+    Add8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 2-byte word at `offset`.
+    Add16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 24-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 3-byte word at `offset`.
+    Add24 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 4-byte word at `offset`.
+    Add32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 8-byte word at `offset`.
+    Add64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 1-byte word at `offset`.
+    Sub8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 2-byte word at `offset`.
+    Sub16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 24-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 3-byte word at `offset`.
+    Sub24 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 4-byte word at `offset`.
+    Sub32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 8-byte word at `offset`.
+    Sub64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GNU C++ vtable hierarchy.
+    ///
+    /// Records that the vtable at `offset` inherits from `sym`, for use
+    /// by linker garbage collection.
+    GNUVTInherit {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// GNU C++ vtable member usage.
+    ///
+    /// Records that entry `addend` of the vtable `sym` is used at
+    /// `offset`, for use by linker garbage collection.
+    GNUVTEntry {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 18-bit PC-relative branch.
+    ///
+    /// Set the 16-bit immediate field of the instruction at `offset` to
+    /// the relative word address of `sym + addend`.
+    B16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 23-bit PC-relative branch.
+    ///
+    /// Set the 21-bit immediate field of the instruction at `offset` to
+    /// the relative word address of `sym + addend`.
+    B21 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 28-bit PC-relative branch.
+    ///
+    /// Set the 26-bit immediate field of the instruction at `offset` to
+    /// the relative word address of `sym + addend`.
+    B26 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of an absolute address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of `sym + addend`, for `lu12i.w`.
+    AbsHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an absolute address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of `sym + addend`.
+    AbsLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of an absolute address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of `sym + addend`, for `lu32i.d`.
+    Abs64Lo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of an absolute address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of `sym + addend`, for `lu52i.d`.
+    Abs64Hi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a page offset to an address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the difference between the 4KiB pages containing `sym + addend`
+    /// and `offset`, for `pcalau12i`.
+    PCALAHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of `sym + addend`.
+    PCALALo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a page offset to an address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the offset of `sym + addend` from the 4KiB
+    /// page containing `offset`, for `lu32i.d`.
+    PCALA64Lo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a page offset to an address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the offset of `sym + addend` from the 4KiB
+    /// page containing `offset`, for `lu52i.d`.
+    PCALA64Hi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a page offset to a GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the difference between the 4KiB pages containing the GOT entry for
+    /// `sym + addend` and `offset`, for `pcalau12i`.
+    GOTPCHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a GOT entry.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the GOT entry for `sym + addend`.
+    GOTPCLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a page offset to a GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the offset of the GOT entry for
+    /// `sym + addend` from the 4KiB page containing `offset`, for
+    /// `lu32i.d`.
+    GOT64PCLo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a page offset to a GOT entry.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the offset of the GOT entry for
+    /// `sym + addend` from the 4KiB page containing `offset`, for
+    /// `lu52i.d`.
+    GOT64PCHi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of a GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the address of the GOT entry for
+    /// `sym + addend`, for `lu12i.w`.
+    GOTHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a GOT entry address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the address of the GOT entry for
+    /// `sym + addend`.
+    GOTLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the address of the GOT entry for
+    /// `sym + addend`, for `lu32i.d`.
+    GOT64Lo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a GOT entry address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the address of the GOT entry for
+    /// `sym + addend`, for `lu52i.d`.
+    GOT64Hi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of a local exec thread pointer offset.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the thread pointer offset for
+    /// `sym + addend`, for `lu12i.w`.
+    TLSLEHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a local exec thread pointer offset.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the thread pointer offset for `sym + addend`.
+    TLSLELo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a local exec thread pointer offset.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the thread pointer offset for
+    /// `sym + addend`, for `lu32i.d`.
+    TLSLE64Lo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a local exec thread pointer offset.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the thread pointer offset for
+    /// `sym + addend`, for `lu52i.d`.
+    TLSLE64Hi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a page offset to an initial exec GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the difference between the 4KiB pages containing the GOT entry
+    /// holding the thread pointer offset for `sym + addend` and `offset`,
+    /// for `pcalau12i`.
+    TLSIEPCHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an initial exec GOT entry.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the GOT entry holding the thread pointer offset
+    /// for `sym + addend`.
+    TLSIEPCLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a page offset to an initial exec GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the offset of the GOT entry holding the
+    /// thread pointer offset for `sym + addend` from the 4KiB page
+    /// containing `offset`, for `lu32i.d`.
+    TLSIE64PCLo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a page offset to an initial exec GOT entry.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the offset of the GOT entry holding the
+    /// thread pointer offset for `sym + addend` from the 4KiB page
+    /// containing `offset`, for `lu52i.d`.
+    TLSIE64PCHi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of an initial exec GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the address of the GOT entry holding the
+    /// thread pointer offset for `sym + addend`, for `lu12i.w`.
+    TLSIEHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an initial exec GOT entry address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the address of the GOT entry holding the thread
+    /// pointer offset for `sym + addend`.
+    TLSIELo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of an initial exec GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the address of the GOT entry holding the
+    /// thread pointer offset for `sym + addend`, for `lu32i.d`.
+    TLSIE64Lo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of an initial exec GOT entry address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the address of the GOT entry holding the
+    /// thread pointer offset for `sym + addend`, for `lu52i.d`.
+    TLSIE64Hi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a page offset to a local dynamic GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the difference between the 4KiB pages containing the GOT entry
+    /// holding the module `tls_index` for `sym + addend` and `offset`,
+    /// for `pcalau12i`.
+    TLSLDPCHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of a local dynamic GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the address of the GOT entry holding the
+    /// module `tls_index` for `sym + addend`, for `lu12i.w`.
+    TLSLDHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a page offset to a general dynamic GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the difference between the 4KiB pages containing the GOT entry
+    /// holding the `tls_index` for `sym + addend` and `offset`, for
+    /// `pcalau12i`.
+    TLSGDPCHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of a general dynamic GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the address of the GOT entry holding the
+    /// `tls_index` for `sym + addend`, for `lu12i.w`.
+    TLSGDHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    PCRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Linker relaxation hint.
+    ///
+    /// Indicates that the instruction at `offset` may be relaxed by the
+    /// linker, together with the relocation preceding this one.
+    Relax {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// Alignment requirement.
+    ///
+    /// Indicates that `addend` bytes of padding at `offset` may be
+    /// deleted by the linker to satisfy an alignment requirement after
+    /// relaxation.
+    Align {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 22-bit PC-relative offset.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the relative word address of `sym + addend`, for `pcaddi`.
+    PCRel20S2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 6-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the low 6 bits of the byte at `offset`.
+    Add6 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 6-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the low 6 bits of the byte at
+    /// `offset`.
+    Sub6 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// In-place ULEB128 addition.
+    ///
+    /// Add `sym + addend` to the ULEB128-encoded value at `offset`,
+    /// keeping its encoded length.
+    AddULEB128 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// In-place ULEB128 subtraction.
+    ///
+    /// Subtract `sym + addend` from the ULEB128-encoded value at
+    /// `offset`, keeping its encoded length.
+    SubULEB128 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    PCRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 38-bit PC-relative call.
+    ///
+    /// Set the immediate fields of the `pcaddu18i` and `jirl` pair at
+    /// `offset` to the relative word address of `sym + addend`.
+    Call36 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a page offset to a TLS descriptor GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the difference between the 4KiB pages containing the GOT entry
+    /// holding the thread-local storage descriptor for `sym + addend` and
+    /// `offset`, for `pcalau12i`.
+    TLSDescPCHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a TLS descriptor GOT entry.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the GOT entry holding the thread-local storage
+    /// descriptor for `sym + addend`.
+    TLSDescPCLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a page offset to a TLS descriptor GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the offset of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend` from the 4KiB
+    /// page containing `offset`, for `lu32i.d`.
+    TLSDesc64PCLo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a page offset to a TLS descriptor GOT entry.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the offset of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend` from the 4KiB
+    /// page containing `offset`, for `lu52i.d`.
+    TLSDesc64PCHi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 31 of a TLS descriptor GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the address of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend`, for `lu12i.w`.
+    TLSDescHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a TLS descriptor GOT entry address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the address of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend`.
+    TLSDescLo12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 51 of a TLS descriptor GOT entry address.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 51 of the address of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend`, for `lu32i.d`.
+    TLSDesc64Lo20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 52 through 63 of a TLS descriptor GOT entry address.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// bits 52 through 63 of the address of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend`, for `lu52i.d`.
+    TLSDesc64Hi12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local descriptor load.
+    ///
+    /// Marks the `ld` instruction at `offset` loading the resolver
+    /// function from the descriptor for `sym + addend`, so that it can be
+    /// relaxed by the linker.
+    TLSDescLd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local descriptor call.
+    ///
+    /// Marks the `jirl` instruction at `offset` calling the resolver
+    /// function from the descriptor for `sym + addend`, so that it can be
+    /// relaxed by the linker.
+    TLSDescCall {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Rounded bits 12 through 31 of a local exec thread pointer offset.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 31 of the thread pointer offset for
+    /// `sym + addend`, rounded for a following signed low part, for
+    /// `lu12i.w`.
+    TLSLEHi20R {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local exec thread pointer add.
+    ///
+    /// Marks the `add` instruction at `offset` adding the thread pointer
+    /// to the offset for `sym + addend`, so that it can be relaxed by the
+    /// linker.
+    TLSLEAddR {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a local exec thread pointer offset.
+    ///
+    /// Set the 12-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of the thread pointer offset for `sym + addend`,
+    /// for use as a load or store offset.
+    TLSLELo12R {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 22-bit PC-relative offset of a local dynamic GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the relative word address of the GOT entry holding the module
+    /// `tls_index` for `sym + addend`, for `pcaddi`.
+    TLSLDPCRel20S2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 22-bit PC-relative offset of a general dynamic GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the relative word address of the GOT entry holding the `tls_index`
+    /// for `sym + addend`, for `pcaddi`.
+    TLSGDPCRel20S2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 22-bit PC-relative offset of a thread-local descriptor GOT entry.
+    ///
+    /// Set the 20-bit immediate field of the instruction at `offset` to
+    /// the relative word address of the GOT entry holding the
+    /// thread-local storage descriptor for `sym + addend`, for `pcaddi`.
+    TLSDescPCRel20S2 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    }
+}
+
+/// Type synonym for [LoongArchReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type LoongArchRelocRaw = LoongArchReloc<u32>;
+
+/// Type synonym for [LoongArchReloc] with [SymDataRaw] as the symbol
+/// type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [LoongArchRelocRaw].
+pub type LoongArchRelocRawSym<Class> = LoongArchReloc<SymDataRaw<Class>>;
+
+/// Type synonym for [LoongArchReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [LoongArchRelocRawSym].
+pub type LoongArchRelocStrDataSym<'a, Class> =
+    LoongArchReloc<SymDataStrData<'a, Class>>;
+
+/// Type synonym for [LoongArchReloc] with [SymDataStr] as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [LoongArchRelocStrDataSym].
+pub type LoongArchRelocStrData<'a> =
+    LoongArchReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [LoongArchReloc] with UTF-8 decoded string data as
+/// the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [LoongArchRelocStrDataSym].
+pub type LoongArchRelocStrSym<'a, Class> =
+    LoongArchReloc<SymDataStr<'a, Class>>;
+
+/// Type synonym for [LoongArchReloc] with a `&'a str`s as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [LoongArchRelocStrSym].
+pub type LoongArchRelocStr<'a> = LoongArchReloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [LoongArchReloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// This can happen with a non-zero addend, or when converting to a
+/// 32-bit relocation with an offset or addend that does not fit.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LoongArchToRelError {
+    /// Non-zero or out-of-range addend.
+    BadAddend(i64),
+    /// Out-of-range offset.
+    BadOffset(u64)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [LoongArchReloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum LoongArchRelocError {
+    /// Unknown tag value.
+    BadTag(u32)
+}
+
+impl<Name> Display for LoongArchReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            LoongArchReloc::None => write!(f, "none"),
+            LoongArchReloc::Abs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::Abs64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            LoongArchReloc::Relative { offset, addend } =>
+                write!(f, ".section[{}] <- &base + {}",
+                       offset, addend),
+            LoongArchReloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            LoongArchReloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}] <- &{} + {}",
+                       offset, sym, addend),
+            LoongArchReloc::TLSDTPMod32 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 4, sym),
+            LoongArchReloc::TLSDTPMod64 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 8, sym),
+            LoongArchReloc::TLSDTPRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSDTPRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 8, sym, addend),
+            LoongArchReloc::TLSTPRel32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSTPRel64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 8, sym, addend),
+            LoongArchReloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}] <- (&base + {})()",
+                       offset, addend),
+            LoongArchReloc::TLSDesc32 { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- tls descriptor for {} + {}",
+                       offset, offset + 8, sym, addend),
+            LoongArchReloc::TLSDesc64 { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- tls descriptor for {} + {}",
+                       offset, offset + 16, sym, addend),
+            LoongArchReloc::MarkLA { offset, sym, addend } =>
+                write!(f, "absolute load at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            LoongArchReloc::MarkPCRel { offset, sym, addend } =>
+                write!(f, "PC-relative load at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            LoongArchReloc::SOPPushPCRel { offset, sym, addend } =>
+                write!(f, "push (&{} + {}) - (&.section + {})",
+                       sym, addend, offset),
+            LoongArchReloc::SOPPushAbsolute { sym, addend, .. } =>
+                write!(f, "push &{} + {}",
+                       sym, addend),
+            LoongArchReloc::SOPPushDup { offset } =>
+                write!(f, "stack dup at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPPushGPRel { sym, addend, .. } =>
+                write!(f, "push &.got[&{} + {}] - &.got",
+                       sym, addend),
+            LoongArchReloc::SOPPushTLSTPRel { sym, addend, .. } =>
+                write!(f, "push thread pointer offset for {} + {}",
+                       sym, addend),
+            LoongArchReloc::SOPPushTLSGOT { sym, addend, .. } =>
+                write!(f, "push &.got[tp_offset(&{} + {})] - &.got",
+                       sym, addend),
+            LoongArchReloc::SOPPushTLSGD { sym, addend, .. } =>
+                write!(f, "push &.got[tls_index(&{} + {})] - &.got",
+                       sym, addend),
+            LoongArchReloc::SOPPushPLTPCRel { offset, sym, addend } =>
+                write!(f, "push (&.plt[{}] + {}) - (&.section + {})",
+                       sym, addend, offset),
+            LoongArchReloc::SOPAssert { offset } =>
+                write!(f, "stack assert at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPNot { offset } =>
+                write!(f, "stack !a at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPSub { offset } =>
+                write!(f, "stack a - b at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPSL { offset } =>
+                write!(f, "stack a << b at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPSR { offset } =>
+                write!(f, "stack a >> b at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPAdd { offset } =>
+                write!(f, "stack a + b at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPAnd { offset } =>
+                write!(f, "stack a & b at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPIfElse { offset } =>
+                write!(f, "stack a ? b : c at .section[{}]",
+                       offset),
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                write!(f, ".section[{}..{}] <- pop() as signed [10..15]",
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                write!(f, ".section[{}..{}] <- pop() as unsigned [10..22]",
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                write!(f, ".section[{}..{}] <- pop() as signed [10..22]",
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                write!(f, ".section[{}..{}] <- pop() as signed [10..26]",
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                write!(f, ".section[{}..{}] <- pop() as signed [10..26] >> 2",
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                write!(f, ".section[{}..{}] <- pop() as signed [5..25]",
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                write!(f, concat!(".section[{}..{}] <- pop() as signed ",
+                                  "[10..26, 0..5] >> 2"),
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                write!(f, concat!(".section[{}..{}] <- pop() as signed ",
+                                  "[10..26, 0..10] >> 2"),
+                       offset, offset + 4),
+            LoongArchReloc::SOPPop32U { offset } =>
+                write!(f, ".section[{}..{}] <- pop()",
+                       offset, offset + 4),
+            LoongArchReloc::Add8 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- .section[{}] + (&{} + {})",
+                       offset, offset, sym, addend),
+            LoongArchReloc::Add16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 2, offset, offset + 2, sym, addend),
+            LoongArchReloc::Add24 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 3, offset, offset + 3, sym, addend),
+            LoongArchReloc::Add32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 4, offset, offset + 4, sym, addend),
+            LoongArchReloc::Add64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 8, offset, offset + 8, sym, addend),
+            LoongArchReloc::Sub8 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- .section[{}] - (&{} + {})",
+                       offset, offset, sym, addend),
+            LoongArchReloc::Sub16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 2, offset, offset + 2, sym, addend),
+            LoongArchReloc::Sub24 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 3, offset, offset + 3, sym, addend),
+            LoongArchReloc::Sub32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 4, offset, offset + 4, sym, addend),
+            LoongArchReloc::Sub64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 8, offset, offset + 8, sym, addend),
+            LoongArchReloc::GNUVTInherit { offset, sym } =>
+                write!(f, "vtable at .section[{}] inherits {}",
+                       offset, sym),
+            LoongArchReloc::GNUVTEntry { offset, sym, addend } =>
+                write!(f, "vtable {} entry {} used at .section[{}]",
+                       sym, addend, offset),
+            LoongArchReloc::B16 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::B21 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::B26 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::AbsHi20 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 12) & 0xfffff",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::AbsLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::Abs64Lo20 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 32) & 0xfffff",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::Abs64Hi12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 52",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::PCALAHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (page(&{} + {}) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::PCALALo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::PCALA64Lo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "page(&.section + {})) >> 32) & 0xfffff"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::PCALA64Hi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "page(&.section + {})) >> 52"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::GOTPCHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (page(&.got[&{} + {}]) ",
+                                  "- page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::GOTPCLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}]) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::GOT64PCLo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&.got[&{} + {}]) - ",
+                                  "page(&.section + {})) >> 32) & 0xfffff"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::GOT64PCHi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}]) - ",
+                                  "page(&.section + {})) >> 52"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::GOTHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}]) >> ",
+                                  "12) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::GOTLo12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}]) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::GOT64Lo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[&{} + {}]) >> ",
+                                  "32) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::GOT64Hi12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}]) >> 52",
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLEHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((thread pointer ",
+                                  "offset for {} + {}) >> 12) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLELo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (thread pointer offset ",
+                                  "for {} + {}) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLE64Lo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((thread pointer ",
+                                  "offset for {} + {}) >> 32) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLE64Hi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (thread pointer offset ",
+                                  "for {} + {}) >> 52"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSIEPCHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tp_offset(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSIEPCLo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tp_offset(&{} + ",
+                                  "{})]) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSIE64PCLo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&.got[tp_offset(&{} ",
+                                  "+ {})]) - page(&.section + {})) >> 32) & ",
+                                  "0xfffff"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSIE64PCHi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tp_offset(&{} ",
+                                  "+ {})]) - page(&.section + {})) >> 52"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSIEHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tp_offset(&{} ",
+                                  "+ {})]) >> 12) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSIELo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tp_offset(&{} + ",
+                                  "{})]) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSIE64Lo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tp_offset(&{} ",
+                                  "+ {})]) >> 32) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSIE64Hi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tp_offset(&{} + ",
+                                  "{})]) >> 52"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLDPCHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tls_module(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSLDHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_module(&{} ",
+                                  "+ {})] >> 12) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSGDPCHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tls_index(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSGDHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] >> 12) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::PCRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::Relax { offset } =>
+                write!(f, "relax .section[{}]",
+                       offset),
+            LoongArchReloc::Align { offset, addend } =>
+                write!(f, "align .section[{}] with {} bytes of padding",
+                       offset, addend),
+            LoongArchReloc::PCRel20S2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::Add6 { offset, sym, addend } =>
+                write!(f, ".section[{}] += (&{} + {}) & 0x3f",
+                       offset, sym, addend),
+            LoongArchReloc::Sub6 { offset, sym, addend } =>
+                write!(f, ".section[{}] -= (&{} + {}) & 0x3f",
+                       offset, sym, addend),
+            LoongArchReloc::AddULEB128 { offset, sym, addend } =>
+                write!(f, ".section[{}..] += uleb128 (&{} + {})",
+                       offset, sym, addend),
+            LoongArchReloc::SubULEB128 { offset, sym, addend } =>
+                write!(f, ".section[{}..] -= uleb128 (&{} + {})",
+                       offset, sym, addend),
+            LoongArchReloc::PCRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 8, sym, addend, offset),
+            LoongArchReloc::Call36 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 8, sym, addend, offset),
+            LoongArchReloc::TLSDescPCHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ",
+                                  "(page(&.got[tls_desc(&{} + {})]) - ",
+                                  "page(&.section + {})) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSDescPCLo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_desc(&{} + ",
+                                  "{})]) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSDesc64PCLo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&.got[tls_desc(&{} ",
+                                  "+ {})]) - page(&.section + {})) >> 32) & ",
+                                  "0xfffff"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSDesc64PCHi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tls_desc(&{} + ",
+                                  "{})]) - page(&.section + {})) >> 52"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSDescHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tls_desc(&{} + ",
+                                  "{})]) >> 12) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSDescLo12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_desc(&{} + ",
+                                  "{})]) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSDesc64Lo20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[tls_desc(&{} + ",
+                                  "{})]) >> 32) & 0xfffff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSDesc64Hi12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_desc(&{} + ",
+                                  "{})]) >> 52"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSDescLd { offset, sym, addend } =>
+                write!(f, "tls descriptor load at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            LoongArchReloc::TLSDescCall { offset, sym, addend } =>
+                write!(f, "tls descriptor call at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            LoongArchReloc::TLSLEHi20R { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (thread pointer offset ",
+                                  "for {} + {} + 0x800) >> 12"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLEAddR { offset, sym, addend } =>
+                write!(f, "thread pointer add at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            LoongArchReloc::TLSLELo12R { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (thread pointer offset ",
+                                  "for {} + {}) & 0xfff"),
+                       offset, offset + 4, sym, addend),
+            LoongArchReloc::TLSLDPCRel20S2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_module(&{} ",
+                                  "+ {})] - (&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSGDPCRel20S2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] - (&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            LoongArchReloc::TLSDescPCRel20S2 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_desc(&{} + ",
+                                  "{})] - (&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset)
+        }
+    }
+}
+
+impl Display for LoongArchRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            LoongArchRelocError::BadTag(tag) =>
+                write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for LoongArchToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            LoongArchToRelError::BadAddend(addend) =>
+                write!(f, "bad addend value {}", addend),
+            LoongArchToRelError::BadOffset(offset) =>
+                write!(f, "bad offset value {}", offset)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<LoongArchReloc<Name>, LoongArchRelocError> {
+    match kind {
+        0 => Ok(LoongArchReloc::None),
+        1 => Ok(LoongArchReloc::Abs32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        2 => Ok(LoongArchReloc::Abs64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        3 => Ok(LoongArchReloc::Relative { offset: offset, addend: addend }),
+        4 => Ok(LoongArchReloc::Copy { offset: offset, sym: sym }),
+        5 => Ok(LoongArchReloc::JumpSlot { offset: offset, sym: sym,
+                                           addend: addend }),
+        6 => Ok(LoongArchReloc::TLSDTPMod32 { offset: offset, sym: sym }),
+        7 => Ok(LoongArchReloc::TLSDTPMod64 { offset: offset, sym: sym }),
+        8 => Ok(LoongArchReloc::TLSDTPRel32 { offset: offset, sym: sym,
+                                              addend: addend }),
+        9 => Ok(LoongArchReloc::TLSDTPRel64 { offset: offset, sym: sym,
+                                              addend: addend }),
+        10 => Ok(LoongArchReloc::TLSTPRel32 { offset: offset, sym: sym,
+                                              addend: addend }),
+        11 => Ok(LoongArchReloc::TLSTPRel64 { offset: offset, sym: sym,
+                                              addend: addend }),
+        12 => Ok(LoongArchReloc::IRelative { offset: offset, addend: addend }),
+        13 => Ok(LoongArchReloc::TLSDesc32 { offset: offset, sym: sym,
+                                             addend: addend }),
+        14 => Ok(LoongArchReloc::TLSDesc64 { offset: offset, sym: sym,
+                                             addend: addend }),
+        20 => Ok(LoongArchReloc::MarkLA { offset: offset, sym: sym,
+                                          addend: addend }),
+        21 => Ok(LoongArchReloc::MarkPCRel { offset: offset, sym: sym,
+                                             addend: addend }),
+        22 => Ok(LoongArchReloc::SOPPushPCRel { offset: offset, sym: sym,
+                                                addend: addend }),
+        23 => Ok(LoongArchReloc::SOPPushAbsolute { offset: offset, sym: sym,
+                                                   addend: addend }),
+        24 => Ok(LoongArchReloc::SOPPushDup { offset: offset }),
+        25 => Ok(LoongArchReloc::SOPPushGPRel { offset: offset, sym: sym,
+                                                addend: addend }),
+        26 => Ok(LoongArchReloc::SOPPushTLSTPRel { offset: offset, sym: sym,
+                                                   addend: addend }),
+        27 => Ok(LoongArchReloc::SOPPushTLSGOT { offset: offset, sym: sym,
+                                                 addend: addend }),
+        28 => Ok(LoongArchReloc::SOPPushTLSGD { offset: offset, sym: sym,
+                                                addend: addend }),
+        29 => Ok(LoongArchReloc::SOPPushPLTPCRel { offset: offset, sym: sym,
+                                                   addend: addend }),
+        30 => Ok(LoongArchReloc::SOPAssert { offset: offset }),
+        31 => Ok(LoongArchReloc::SOPNot { offset: offset }),
+        32 => Ok(LoongArchReloc::SOPSub { offset: offset }),
+        33 => Ok(LoongArchReloc::SOPSL { offset: offset }),
+        34 => Ok(LoongArchReloc::SOPSR { offset: offset }),
+        35 => Ok(LoongArchReloc::SOPAdd { offset: offset }),
+        36 => Ok(LoongArchReloc::SOPAnd { offset: offset }),
+        37 => Ok(LoongArchReloc::SOPIfElse { offset: offset }),
+        38 => Ok(LoongArchReloc::SOPPop32S10W5 { offset: offset }),
+        39 => Ok(LoongArchReloc::SOPPop32U10W12 { offset: offset }),
+        40 => Ok(LoongArchReloc::SOPPop32S10W12 { offset: offset }),
+        41 => Ok(LoongArchReloc::SOPPop32S10W16 { offset: offset }),
+        42 => Ok(LoongArchReloc::SOPPop32S10W16S2 { offset: offset }),
+        43 => Ok(LoongArchReloc::SOPPop32S5W20 { offset: offset }),
+        44 => Ok(LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset }),
+        45 => Ok(LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset }),
+        46 => Ok(LoongArchReloc::SOPPop32U { offset: offset }),
+        47 => Ok(LoongArchReloc::Add8 { offset: offset, sym: sym,
+                                        addend: addend }),
+        48 => Ok(LoongArchReloc::Add16 { offset: offset, sym: sym,
+                                         addend: addend }),
+        49 => Ok(LoongArchReloc::Add24 { offset: offset, sym: sym,
+                                         addend: addend }),
+        50 => Ok(LoongArchReloc::Add32 { offset: offset, sym: sym,
+                                         addend: addend }),
+        51 => Ok(LoongArchReloc::Add64 { offset: offset, sym: sym,
+                                         addend: addend }),
+        52 => Ok(LoongArchReloc::Sub8 { offset: offset, sym: sym,
+                                        addend: addend }),
+        53 => Ok(LoongArchReloc::Sub16 { offset: offset, sym: sym,
+                                         addend: addend }),
+        54 => Ok(LoongArchReloc::Sub24 { offset: offset, sym: sym,
+                                         addend: addend }),
+        55 => Ok(LoongArchReloc::Sub32 { offset: offset, sym: sym,
+                                         addend: addend }),
+        56 => Ok(LoongArchReloc::Sub64 { offset: offset, sym: sym,
+                                         addend: addend }),
+        57 => Ok(LoongArchReloc::GNUVTInherit { offset: offset, sym: sym }),
+        58 => Ok(LoongArchReloc::GNUVTEntry { offset: offset, sym: sym,
+                                              addend: addend }),
+        64 => Ok(LoongArchReloc::B16 { offset: offset, sym: sym,
+                                       addend: addend }),
+        65 => Ok(LoongArchReloc::B21 { offset: offset, sym: sym,
+                                       addend: addend }),
+        66 => Ok(LoongArchReloc::B26 { offset: offset, sym: sym,
+                                       addend: addend }),
+        67 => Ok(LoongArchReloc::AbsHi20 { offset: offset, sym: sym,
+                                           addend: addend }),
+        68 => Ok(LoongArchReloc::AbsLo12 { offset: offset, sym: sym,
+                                           addend: addend }),
+        69 => Ok(LoongArchReloc::Abs64Lo20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        70 => Ok(LoongArchReloc::Abs64Hi12 { offset: offset, sym: sym,
+                                             addend: addend }),
+        71 => Ok(LoongArchReloc::PCALAHi20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        72 => Ok(LoongArchReloc::PCALALo12 { offset: offset, sym: sym,
+                                             addend: addend }),
+        73 => Ok(LoongArchReloc::PCALA64Lo20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        74 => Ok(LoongArchReloc::PCALA64Hi12 { offset: offset, sym: sym,
+                                               addend: addend }),
+        75 => Ok(LoongArchReloc::GOTPCHi20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        76 => Ok(LoongArchReloc::GOTPCLo12 { offset: offset, sym: sym,
+                                             addend: addend }),
+        77 => Ok(LoongArchReloc::GOT64PCLo20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        78 => Ok(LoongArchReloc::GOT64PCHi12 { offset: offset, sym: sym,
+                                               addend: addend }),
+        79 => Ok(LoongArchReloc::GOTHi20 { offset: offset, sym: sym,
+                                           addend: addend }),
+        80 => Ok(LoongArchReloc::GOTLo12 { offset: offset, sym: sym,
+                                           addend: addend }),
+        81 => Ok(LoongArchReloc::GOT64Lo20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        82 => Ok(LoongArchReloc::GOT64Hi12 { offset: offset, sym: sym,
+                                             addend: addend }),
+        83 => Ok(LoongArchReloc::TLSLEHi20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        84 => Ok(LoongArchReloc::TLSLELo12 { offset: offset, sym: sym,
+                                             addend: addend }),
+        85 => Ok(LoongArchReloc::TLSLE64Lo20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        86 => Ok(LoongArchReloc::TLSLE64Hi12 { offset: offset, sym: sym,
+                                               addend: addend }),
+        87 => Ok(LoongArchReloc::TLSIEPCHi20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        88 => Ok(LoongArchReloc::TLSIEPCLo12 { offset: offset, sym: sym,
+                                               addend: addend }),
+        89 => Ok(LoongArchReloc::TLSIE64PCLo20 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        90 => Ok(LoongArchReloc::TLSIE64PCHi12 { offset: offset, sym: sym,
+                                                 addend: addend }),
+        91 => Ok(LoongArchReloc::TLSIEHi20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        92 => Ok(LoongArchReloc::TLSIELo12 { offset: offset, sym: sym,
+                                             addend: addend }),
+        93 => Ok(LoongArchReloc::TLSIE64Lo20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        94 => Ok(LoongArchReloc::TLSIE64Hi12 { offset: offset, sym: sym,
+                                               addend: addend }),
+        95 => Ok(LoongArchReloc::TLSLDPCHi20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        96 => Ok(LoongArchReloc::TLSLDHi20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        97 => Ok(LoongArchReloc::TLSGDPCHi20 { offset: offset, sym: sym,
+                                               addend: addend }),
+        98 => Ok(LoongArchReloc::TLSGDHi20 { offset: offset, sym: sym,
+                                             addend: addend }),
+        99 => Ok(LoongArchReloc::PCRel32 { offset: offset, sym: sym,
+                                           addend: addend }),
+        100 => Ok(LoongArchReloc::Relax { offset: offset }),
+        102 => Ok(LoongArchReloc::Align { offset: offset, addend: addend }),
+        103 => Ok(LoongArchReloc::PCRel20S2 { offset: offset, sym: sym,
+                                              addend: addend }),
+        105 => Ok(LoongArchReloc::Add6 { offset: offset, sym: sym,
+                                         addend: addend }),
+        106 => Ok(LoongArchReloc::Sub6 { offset: offset, sym: sym,
+                                         addend: addend }),
+        107 => Ok(LoongArchReloc::AddULEB128 { offset: offset, sym: sym,
+                                               addend: addend }),
+        108 => Ok(LoongArchReloc::SubULEB128 { offset: offset, sym: sym,
+                                               addend: addend }),
+        109 => Ok(LoongArchReloc::PCRel64 { offset: offset, sym: sym,
+                                            addend: addend }),
+        110 => Ok(LoongArchReloc::Call36 { offset: offset, sym: sym,
+                                           addend: addend }),
+        111 => Ok(LoongArchReloc::TLSDescPCHi20 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        112 => Ok(LoongArchReloc::TLSDescPCLo12 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        113 => Ok(LoongArchReloc::TLSDesc64PCLo20 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        114 => Ok(LoongArchReloc::TLSDesc64PCHi12 { offset: offset, sym: sym,
+                                                    addend: addend }),
+        115 => Ok(LoongArchReloc::TLSDescHi20 { offset: offset, sym: sym,
+                                                addend: addend }),
+        116 => Ok(LoongArchReloc::TLSDescLo12 { offset: offset, sym: sym,
+                                                addend: addend }),
+        117 => Ok(LoongArchReloc::TLSDesc64Lo20 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        118 => Ok(LoongArchReloc::TLSDesc64Hi12 { offset: offset, sym: sym,
+                                                  addend: addend }),
+        119 => Ok(LoongArchReloc::TLSDescLd { offset: offset, sym: sym,
+                                              addend: addend }),
+        120 => Ok(LoongArchReloc::TLSDescCall { offset: offset, sym: sym,
+                                                addend: addend }),
+        121 => Ok(LoongArchReloc::TLSLEHi20R { offset: offset, sym: sym,
+                                               addend: addend }),
+        122 => Ok(LoongArchReloc::TLSLEAddR { offset: offset, sym: sym,
+                                              addend: addend }),
+        123 => Ok(LoongArchReloc::TLSLELo12R { offset: offset, sym: sym,
+                                               addend: addend }),
+        124 => Ok(LoongArchReloc::TLSLDPCRel20S2 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        125 => Ok(LoongArchReloc::TLSGDPCRel20S2 { offset: offset, sym: sym,
+                                                   addend: addend }),
+        126 => Ok(LoongArchReloc::TLSDescPCRel20S2 { offset: offset, sym: sym,
+                                                     addend: addend }),
+        tag => Err(LoongArchRelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf64>> for LoongArchReloc<Name> {
+    type Error = LoongArchRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf64>) ->
+        Result<LoongArchReloc<Name>, Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<LoongArchReloc<u32>> for RelData<u32, Elf64> {
+    type Error = LoongArchToRelError;
+
+    #[inline]
+    fn try_from(rel: LoongArchReloc<u32>) -> Result<RelData<u32, Elf64>,
+                                                    Self::Error> {
+        match rel {
+            LoongArchReloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            LoongArchReloc::Abs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            LoongArchReloc::Abs32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Abs64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            LoongArchReloc::Abs64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 3 }),
+            LoongArchReloc::Relative { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            LoongArchReloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            LoongArchReloc::JumpSlot { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDTPMod32 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            LoongArchReloc::TLSDTPMod64 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            LoongArchReloc::TLSDTPRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            LoongArchReloc::TLSDTPRel32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDTPRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            LoongArchReloc::TLSDTPRel64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSTPRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            LoongArchReloc::TLSTPRel32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSTPRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            LoongArchReloc::TLSTPRel64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 12 }),
+            LoongArchReloc::IRelative { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDesc32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 13 }),
+            LoongArchReloc::TLSDesc32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDesc64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 14 }),
+            LoongArchReloc::TLSDesc64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::MarkLA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            LoongArchReloc::MarkLA { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::MarkPCRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            LoongArchReloc::MarkPCRel { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushPCRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 22 }),
+            LoongArchReloc::SOPPushPCRel { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushAbsolute { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 23 }),
+            LoongArchReloc::SOPPushAbsolute { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushDup { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 24 }),
+            LoongArchReloc::SOPPushGPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 25 }),
+            LoongArchReloc::SOPPushGPRel { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushTLSTPRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            LoongArchReloc::SOPPushTLSTPRel { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushTLSGOT { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            LoongArchReloc::SOPPushTLSGOT { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushTLSGD { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            LoongArchReloc::SOPPushTLSGD { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPPushPLTPCRel { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            LoongArchReloc::SOPPushPLTPCRel { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SOPAssert { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 30 }),
+            LoongArchReloc::SOPNot { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 31 }),
+            LoongArchReloc::SOPSub { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 32 }),
+            LoongArchReloc::SOPSL { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 33 }),
+            LoongArchReloc::SOPSR { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 34 }),
+            LoongArchReloc::SOPAdd { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 35 }),
+            LoongArchReloc::SOPAnd { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 36 }),
+            LoongArchReloc::SOPIfElse { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 37 }),
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 38 }),
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 39 }),
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 40 }),
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 41 }),
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 42 }),
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 43 }),
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 44 }),
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 45 }),
+            LoongArchReloc::SOPPop32U { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 46 }),
+            LoongArchReloc::Add8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 47 }),
+            LoongArchReloc::Add8 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Add16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 48 }),
+            LoongArchReloc::Add16 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Add24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 49 }),
+            LoongArchReloc::Add24 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Add32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 50 }),
+            LoongArchReloc::Add32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Add64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 51 }),
+            LoongArchReloc::Add64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Sub8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 52 }),
+            LoongArchReloc::Sub8 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Sub16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 53 }),
+            LoongArchReloc::Sub16 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Sub24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 54 }),
+            LoongArchReloc::Sub24 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Sub32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 55 }),
+            LoongArchReloc::Sub32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Sub64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 56 }),
+            LoongArchReloc::Sub64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GNUVTInherit { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 57 }),
+            LoongArchReloc::GNUVTEntry { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 58 }),
+            LoongArchReloc::GNUVTEntry { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::B16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 64 }),
+            LoongArchReloc::B16 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::B21 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 65 }),
+            LoongArchReloc::B21 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::B26 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 66 }),
+            LoongArchReloc::B26 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::AbsHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 67 }),
+            LoongArchReloc::AbsHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::AbsLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 68 }),
+            LoongArchReloc::AbsLo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Abs64Lo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 69 }),
+            LoongArchReloc::Abs64Lo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Abs64Hi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 70 }),
+            LoongArchReloc::Abs64Hi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCALAHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 71 }),
+            LoongArchReloc::PCALAHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCALALo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 72 }),
+            LoongArchReloc::PCALALo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCALA64Lo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 73 }),
+            LoongArchReloc::PCALA64Lo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCALA64Hi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 74 }),
+            LoongArchReloc::PCALA64Hi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOTPCHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 75 }),
+            LoongArchReloc::GOTPCHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOTPCLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 76 }),
+            LoongArchReloc::GOTPCLo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOT64PCLo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 77 }),
+            LoongArchReloc::GOT64PCLo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOT64PCHi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 78 }),
+            LoongArchReloc::GOT64PCHi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOTHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 79 }),
+            LoongArchReloc::GOTHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOTLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 80 }),
+            LoongArchReloc::GOTLo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOT64Lo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 81 }),
+            LoongArchReloc::GOT64Lo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::GOT64Hi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 82 }),
+            LoongArchReloc::GOT64Hi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLEHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 83 }),
+            LoongArchReloc::TLSLEHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLELo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 84 }),
+            LoongArchReloc::TLSLELo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLE64Lo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 85 }),
+            LoongArchReloc::TLSLE64Lo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLE64Hi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 86 }),
+            LoongArchReloc::TLSLE64Hi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIEPCHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 87 }),
+            LoongArchReloc::TLSIEPCHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIEPCLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 88 }),
+            LoongArchReloc::TLSIEPCLo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIE64PCLo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 89 }),
+            LoongArchReloc::TLSIE64PCLo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIE64PCHi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 90 }),
+            LoongArchReloc::TLSIE64PCHi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIEHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 91 }),
+            LoongArchReloc::TLSIEHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIELo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 92 }),
+            LoongArchReloc::TLSIELo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIE64Lo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 93 }),
+            LoongArchReloc::TLSIE64Lo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSIE64Hi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 94 }),
+            LoongArchReloc::TLSIE64Hi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLDPCHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 95 }),
+            LoongArchReloc::TLSLDPCHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLDHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 96 }),
+            LoongArchReloc::TLSLDHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSGDPCHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 97 }),
+            LoongArchReloc::TLSGDPCHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSGDHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 98 }),
+            LoongArchReloc::TLSGDHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 99 }),
+            LoongArchReloc::PCRel32 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Relax { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 100 }),
+            LoongArchReloc::Align { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 102 }),
+            LoongArchReloc::Align { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCRel20S2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 103 }),
+            LoongArchReloc::PCRel20S2 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Add6 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 105 }),
+            LoongArchReloc::Add6 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Sub6 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 106 }),
+            LoongArchReloc::Sub6 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::AddULEB128 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 107 }),
+            LoongArchReloc::AddULEB128 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::SubULEB128 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 108 }),
+            LoongArchReloc::SubULEB128 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::PCRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 109 }),
+            LoongArchReloc::PCRel64 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::Call36 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 110 }),
+            LoongArchReloc::Call36 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescPCHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 111 }),
+            LoongArchReloc::TLSDescPCHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescPCLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 112 }),
+            LoongArchReloc::TLSDescPCLo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDesc64PCLo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 113 }),
+            LoongArchReloc::TLSDesc64PCLo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDesc64PCHi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 114 }),
+            LoongArchReloc::TLSDesc64PCHi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 115 }),
+            LoongArchReloc::TLSDescHi20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescLo12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 116 }),
+            LoongArchReloc::TLSDescLo12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDesc64Lo20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 117 }),
+            LoongArchReloc::TLSDesc64Lo20 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDesc64Hi12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 118 }),
+            LoongArchReloc::TLSDesc64Hi12 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescLd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 119 }),
+            LoongArchReloc::TLSDescLd { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescCall { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 120 }),
+            LoongArchReloc::TLSDescCall { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLEHi20R { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 121 }),
+            LoongArchReloc::TLSLEHi20R { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLEAddR { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 122 }),
+            LoongArchReloc::TLSLEAddR { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLELo12R { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 123 }),
+            LoongArchReloc::TLSLELo12R { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSLDPCRel20S2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 124 }),
+            LoongArchReloc::TLSLDPCRel20S2 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSGDPCRel20S2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 125 }),
+            LoongArchReloc::TLSGDPCRel20S2 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend)),
+            LoongArchReloc::TLSDescPCRel20S2 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 126 }),
+            LoongArchReloc::TLSDescPCRel20S2 { addend, .. } =>
+                Err(LoongArchToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf64>> for LoongArchReloc<Name> {
+    type Error = LoongArchRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf64>) ->
+        Result<LoongArchReloc<Name>, Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<LoongArchReloc<u32>> for RelaData<u32, Elf64> {
+    #[inline]
+    fn from(rel: LoongArchReloc<u32>) -> RelaData<u32, Elf64> {
+        match rel {
+            LoongArchReloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            LoongArchReloc::Abs32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
+            LoongArchReloc::Abs64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
+            LoongArchReloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 3, addend: addend },
+            LoongArchReloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 4, addend: 0 },
+            LoongArchReloc::JumpSlot { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 5, addend: addend },
+            LoongArchReloc::TLSDTPMod32 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 6, addend: 0 },
+            LoongArchReloc::TLSDTPMod64 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 7, addend: 0 },
+            LoongArchReloc::TLSDTPRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 8, addend: addend },
+            LoongArchReloc::TLSDTPRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 9, addend: addend },
+            LoongArchReloc::TLSTPRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
+            LoongArchReloc::TLSTPRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
+            LoongArchReloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 12, addend: addend },
+            LoongArchReloc::TLSDesc32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 13, addend: addend },
+            LoongArchReloc::TLSDesc64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 14, addend: addend },
+            LoongArchReloc::MarkLA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 20, addend: addend },
+            LoongArchReloc::MarkPCRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 21, addend: addend },
+            LoongArchReloc::SOPPushPCRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 22, addend: addend },
+            LoongArchReloc::SOPPushAbsolute { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 23, addend: addend },
+            LoongArchReloc::SOPPushDup { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 24, addend: 0 },
+            LoongArchReloc::SOPPushGPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 25, addend: addend },
+            LoongArchReloc::SOPPushTLSTPRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 26, addend: addend },
+            LoongArchReloc::SOPPushTLSGOT { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 27, addend: addend },
+            LoongArchReloc::SOPPushTLSGD { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 28, addend: addend },
+            LoongArchReloc::SOPPushPLTPCRel { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 29, addend: addend },
+            LoongArchReloc::SOPAssert { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 30, addend: 0 },
+            LoongArchReloc::SOPNot { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 31, addend: 0 },
+            LoongArchReloc::SOPSub { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 32, addend: 0 },
+            LoongArchReloc::SOPSL { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 33, addend: 0 },
+            LoongArchReloc::SOPSR { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 34, addend: 0 },
+            LoongArchReloc::SOPAdd { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 35, addend: 0 },
+            LoongArchReloc::SOPAnd { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 36, addend: 0 },
+            LoongArchReloc::SOPIfElse { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 37, addend: 0 },
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 38, addend: 0 },
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 39, addend: 0 },
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 40, addend: 0 },
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 41, addend: 0 },
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 42, addend: 0 },
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 43, addend: 0 },
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 44, addend: 0 },
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 45, addend: 0 },
+            LoongArchReloc::SOPPop32U { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 46, addend: 0 },
+            LoongArchReloc::Add8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 47, addend: addend },
+            LoongArchReloc::Add16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 48, addend: addend },
+            LoongArchReloc::Add24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 49, addend: addend },
+            LoongArchReloc::Add32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 50, addend: addend },
+            LoongArchReloc::Add64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 51, addend: addend },
+            LoongArchReloc::Sub8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 52, addend: addend },
+            LoongArchReloc::Sub16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 53, addend: addend },
+            LoongArchReloc::Sub24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 54, addend: addend },
+            LoongArchReloc::Sub32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 55, addend: addend },
+            LoongArchReloc::Sub64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 56, addend: addend },
+            LoongArchReloc::GNUVTInherit { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 57, addend: 0 },
+            LoongArchReloc::GNUVTEntry { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 58, addend: addend },
+            LoongArchReloc::B16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 64, addend: addend },
+            LoongArchReloc::B21 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 65, addend: addend },
+            LoongArchReloc::B26 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 66, addend: addend },
+            LoongArchReloc::AbsHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 67, addend: addend },
+            LoongArchReloc::AbsLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 68, addend: addend },
+            LoongArchReloc::Abs64Lo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 69, addend: addend },
+            LoongArchReloc::Abs64Hi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 70, addend: addend },
+            LoongArchReloc::PCALAHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 71, addend: addend },
+            LoongArchReloc::PCALALo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 72, addend: addend },
+            LoongArchReloc::PCALA64Lo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 73, addend: addend },
+            LoongArchReloc::PCALA64Hi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 74, addend: addend },
+            LoongArchReloc::GOTPCHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 75, addend: addend },
+            LoongArchReloc::GOTPCLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 76, addend: addend },
+            LoongArchReloc::GOT64PCLo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 77, addend: addend },
+            LoongArchReloc::GOT64PCHi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 78, addend: addend },
+            LoongArchReloc::GOTHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 79, addend: addend },
+            LoongArchReloc::GOTLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 80, addend: addend },
+            LoongArchReloc::GOT64Lo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 81, addend: addend },
+            LoongArchReloc::GOT64Hi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 82, addend: addend },
+            LoongArchReloc::TLSLEHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 83, addend: addend },
+            LoongArchReloc::TLSLELo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 84, addend: addend },
+            LoongArchReloc::TLSLE64Lo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 85, addend: addend },
+            LoongArchReloc::TLSLE64Hi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 86, addend: addend },
+            LoongArchReloc::TLSIEPCHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 87, addend: addend },
+            LoongArchReloc::TLSIEPCLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 88, addend: addend },
+            LoongArchReloc::TLSIE64PCLo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 89, addend: addend },
+            LoongArchReloc::TLSIE64PCHi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 90, addend: addend },
+            LoongArchReloc::TLSIEHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 91, addend: addend },
+            LoongArchReloc::TLSIELo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 92, addend: addend },
+            LoongArchReloc::TLSIE64Lo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 93, addend: addend },
+            LoongArchReloc::TLSIE64Hi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 94, addend: addend },
+            LoongArchReloc::TLSLDPCHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 95, addend: addend },
+            LoongArchReloc::TLSLDHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 96, addend: addend },
+            LoongArchReloc::TLSGDPCHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 97, addend: addend },
+            LoongArchReloc::TLSGDHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 98, addend: addend },
+            LoongArchReloc::PCRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 99, addend: addend },
+            LoongArchReloc::Relax { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 100, addend: 0 },
+            LoongArchReloc::Align { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 102, addend: addend },
+            LoongArchReloc::PCRel20S2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 103,
+                           addend: addend },
+            LoongArchReloc::Add6 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 105,
+                           addend: addend },
+            LoongArchReloc::Sub6 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 106,
+                           addend: addend },
+            LoongArchReloc::AddULEB128 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 107,
+                           addend: addend },
+            LoongArchReloc::SubULEB128 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 108,
+                           addend: addend },
+            LoongArchReloc::PCRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 109,
+                           addend: addend },
+            LoongArchReloc::Call36 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 110,
+                           addend: addend },
+            LoongArchReloc::TLSDescPCHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 111,
+                           addend: addend },
+            LoongArchReloc::TLSDescPCLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 112,
+                           addend: addend },
+            LoongArchReloc::TLSDesc64PCLo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 113,
+                           addend: addend },
+            LoongArchReloc::TLSDesc64PCHi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 114,
+                           addend: addend },
+            LoongArchReloc::TLSDescHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 115,
+                           addend: addend },
+            LoongArchReloc::TLSDescLo12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 116,
+                           addend: addend },
+            LoongArchReloc::TLSDesc64Lo20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 117,
+                           addend: addend },
+            LoongArchReloc::TLSDesc64Hi12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 118,
+                           addend: addend },
+            LoongArchReloc::TLSDescLd { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 119,
+                           addend: addend },
+            LoongArchReloc::TLSDescCall { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 120,
+                           addend: addend },
+            LoongArchReloc::TLSLEHi20R { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 121,
+                           addend: addend },
+            LoongArchReloc::TLSLEAddR { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 122,
+                           addend: addend },
+            LoongArchReloc::TLSLELo12R { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 123,
+                           addend: addend },
+            LoongArchReloc::TLSLDPCRel20S2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 124,
+                           addend: addend },
+            LoongArchReloc::TLSGDPCRel20S2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 125,
+                           addend: addend },
+            LoongArchReloc::TLSDescPCRel20S2 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 126, addend: addend }
+        }
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for LoongArchReloc<Name> {
+    type Error = LoongArchRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) ->
+        Result<LoongArchReloc<Name>, Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset.into(), sym, kind.into(), 0)
+    }
+}
+
+impl TryFrom<LoongArchReloc<u32>> for RelData<u32, Elf32> {
+    type Error = LoongArchToRelError;
+
+    #[inline]
+    fn try_from(rel: LoongArchReloc<u32>) -> Result<RelData<u32, Elf32>,
+                                                    Self::Error> {
+        match RelData::<u32, Elf64>::try_from(rel) {
+            Ok(RelData { offset, sym, kind }) => match offset.try_into() {
+                Ok(offset) => Ok(RelData { offset: offset, sym: sym,
+                                           kind: kind as u8 }),
+                Err(_) => Err(LoongArchToRelError::BadOffset(offset))
+            },
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for LoongArchReloc<Name> {
+    type Error = LoongArchRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) ->
+        Result<LoongArchReloc<Name>, Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset.into(), sym, kind.into(), addend.into())
+    }
+}
+
+impl TryFrom<LoongArchReloc<u32>> for RelaData<u32, Elf32> {
+    type Error = LoongArchToRelError;
+
+    #[inline]
+    fn try_from(rel: LoongArchReloc<u32>) -> Result<RelaData<u32, Elf32>,
+                                                    Self::Error> {
+        let RelaData { offset, sym, kind, addend } =
+            RelaData::<u32, Elf64>::from(rel);
+
+        match (offset.try_into(), addend.try_into()) {
+            (Ok(offset), Ok(addend)) => Ok(RelaData { offset: offset,
+                                                      sym: sym,
+                                                      kind: kind as u8,
+                                                      addend: addend }),
+            (Err(_), _) => Err(LoongArchToRelError::BadOffset(offset)),
+            (_, Err(_)) => Err(LoongArchToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<'a, B, Class> WithSymtab<'a, B, Class> for LoongArchRelocRaw
+    where Class: SymOffsets,
+          B: ByteOrder {
+    type Result = LoongArchRelocRawSym<Class>;
+    type Error = RelocSymtabError<Class>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Class>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            LoongArchReloc::None => Ok(LoongArchReloc::None),
+            LoongArchReloc::Abs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Abs32 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Abs64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Abs64 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Relative { offset, addend } =>
+                Ok(LoongArchReloc::Relative { offset: offset, addend: addend }),
+            LoongArchReloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Copy { offset: offset,
+                                                      sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::JumpSlot { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDTPMod32 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDTPMod32 { offset: offset,
+                                                             sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDTPMod64 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDTPMod64 { offset: offset,
+                                                             sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDTPRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDTPRel32 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDTPRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDTPRel64 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSTPRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSTPRel32 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSTPRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSTPRel64 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::IRelative { offset, addend } =>
+                Ok(LoongArchReloc::IRelative { offset: offset,
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDesc32 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDesc64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDesc64 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::MarkLA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::MarkLA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::MarkPCRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::MarkPCRel { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushPCRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushPCRel { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushAbsolute { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushAbsolute {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushDup { offset } =>
+                Ok(LoongArchReloc::SOPPushDup { offset: offset }),
+            LoongArchReloc::SOPPushGPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushGPRel { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushTLSTPRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushTLSTPRel {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushTLSGOT { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushTLSGOT { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushTLSGD { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushTLSGD { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPPushPLTPCRel { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SOPPushPLTPCRel {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SOPAssert { offset } =>
+                Ok(LoongArchReloc::SOPAssert { offset: offset }),
+            LoongArchReloc::SOPNot { offset } =>
+                Ok(LoongArchReloc::SOPNot { offset: offset }),
+            LoongArchReloc::SOPSub { offset } =>
+                Ok(LoongArchReloc::SOPSub { offset: offset }),
+            LoongArchReloc::SOPSL { offset } =>
+                Ok(LoongArchReloc::SOPSL { offset: offset }),
+            LoongArchReloc::SOPSR { offset } =>
+                Ok(LoongArchReloc::SOPSR { offset: offset }),
+            LoongArchReloc::SOPAdd { offset } =>
+                Ok(LoongArchReloc::SOPAdd { offset: offset }),
+            LoongArchReloc::SOPAnd { offset } =>
+                Ok(LoongArchReloc::SOPAnd { offset: offset }),
+            LoongArchReloc::SOPIfElse { offset } =>
+                Ok(LoongArchReloc::SOPIfElse { offset: offset }),
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W5 { offset: offset }),
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32U10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S5W20 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32U { offset } =>
+                Ok(LoongArchReloc::SOPPop32U { offset: offset }),
+            LoongArchReloc::Add8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Add8 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Add16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Add16 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Add24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Add24 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Add32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Add32 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Add64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Add64 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Sub8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Sub8 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Sub16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Sub16 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Sub24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Sub24 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Sub32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Sub32 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Sub64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Sub64 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GNUVTInherit { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GNUVTInherit { offset: offset,
+                                                              sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GNUVTEntry { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GNUVTEntry { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::B16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::B16 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::B21 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::B21 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::B26 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::B26 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::AbsHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::AbsHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::AbsLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::AbsLo12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Abs64Lo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Abs64Lo20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Abs64Hi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Abs64Hi12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::PCALAHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCALAHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::PCALALo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCALALo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::PCALA64Lo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCALA64Lo20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::PCALA64Hi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCALA64Hi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOTPCHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOTPCHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOTPCLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOTPCLo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOT64PCLo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOT64PCLo20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOT64PCHi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOT64PCHi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOTHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOTHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOTLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOTLo12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOT64Lo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOT64Lo20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::GOT64Hi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::GOT64Hi12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLEHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLEHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLELo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLELo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLE64Lo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLE64Lo20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLE64Hi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLE64Hi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIEPCHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIEPCHi20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIEPCLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIEPCLo12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIE64PCLo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIE64PCLo20 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIE64PCHi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIE64PCHi12 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIEHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIEHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIELo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIELo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIE64Lo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIE64Lo20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSIE64Hi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSIE64Hi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLDPCHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLDPCHi20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLDHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLDHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSGDPCHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSGDPCHi20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSGDHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSGDHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::PCRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCRel32 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Relax { offset } =>
+                Ok(LoongArchReloc::Relax { offset: offset }),
+            LoongArchReloc::Align { offset, addend } =>
+                Ok(LoongArchReloc::Align { offset: offset, addend: addend }),
+            LoongArchReloc::PCRel20S2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCRel20S2 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Add6 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Add6 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Sub6 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Sub6 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::AddULEB128 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::AddULEB128 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::SubULEB128 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::SubULEB128 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::PCRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::PCRel64 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::Call36 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::Call36 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescPCHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescPCHi20 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescPCLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescPCLo12 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDesc64PCLo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDesc64PCLo20 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDesc64PCHi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDesc64PCHi12 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescHi20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescLo12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescLo12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDesc64Lo20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDesc64Lo20 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDesc64Hi12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDesc64Hi12 { offset: offset,
+                                                               sym: symdata,
+                                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescLd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescLd { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescCall { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescCall { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLEHi20R { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLEHi20R { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLEAddR { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLEAddR { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLELo12R { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLELo12R { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSLDPCRel20S2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSLDPCRel20S2 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSGDPCRel20S2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSGDPCRel20S2 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            LoongArchReloc::TLSDescPCRel20S2 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(LoongArchReloc::TLSDescPCRel20S2 {
+                                offset: offset, sym: symdata, addend: addend
+                            })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                }
+        }
+    }
+}
+
+impl<'a, Class> WithStrtab<'a> for LoongArchRelocRawSym<Class>
+    where Class: SymOffsets {
+    type Result = LoongArchRelocStrDataSym<'a, Class>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            LoongArchReloc::None => Ok(LoongArchReloc::None),
+            LoongArchReloc::Abs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Abs64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Relative { offset, addend } =>
+                Ok(LoongArchReloc::Relative { offset: offset, addend: addend }),
+            LoongArchReloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Copy { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::JumpSlot { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPMod32 { offset: offset,
+                                                         sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDTPMod64 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPMod64 { offset: offset,
+                                                         sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDTPRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPRel32 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDTPRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPRel64 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSTPRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSTPRel32 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSTPRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSTPRel64 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::IRelative { offset, addend } =>
+                Ok(LoongArchReloc::IRelative { offset: offset,
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc32 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDesc64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::MarkLA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::MarkLA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::MarkPCRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::MarkPCRel { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushPCRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushPCRel { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushAbsolute { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushAbsolute { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushDup { offset } =>
+                Ok(LoongArchReloc::SOPPushDup { offset: offset }),
+            LoongArchReloc::SOPPushGPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushGPRel { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushTLSTPRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushTLSTPRel { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushTLSGOT { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushTLSGOT { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushTLSGD { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushTLSGD { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPPushPLTPCRel { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushPLTPCRel { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SOPAssert { offset } =>
+                Ok(LoongArchReloc::SOPAssert { offset: offset }),
+            LoongArchReloc::SOPNot { offset } =>
+                Ok(LoongArchReloc::SOPNot { offset: offset }),
+            LoongArchReloc::SOPSub { offset } =>
+                Ok(LoongArchReloc::SOPSub { offset: offset }),
+            LoongArchReloc::SOPSL { offset } =>
+                Ok(LoongArchReloc::SOPSL { offset: offset }),
+            LoongArchReloc::SOPSR { offset } =>
+                Ok(LoongArchReloc::SOPSR { offset: offset }),
+            LoongArchReloc::SOPAdd { offset } =>
+                Ok(LoongArchReloc::SOPAdd { offset: offset }),
+            LoongArchReloc::SOPAnd { offset } =>
+                Ok(LoongArchReloc::SOPAnd { offset: offset }),
+            LoongArchReloc::SOPIfElse { offset } =>
+                Ok(LoongArchReloc::SOPIfElse { offset: offset }),
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W5 { offset: offset }),
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32U10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S5W20 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32U { offset } =>
+                Ok(LoongArchReloc::SOPPop32U { offset: offset }),
+            LoongArchReloc::Add8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Add16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Add24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add24 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Add32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Add64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Sub8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Sub16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Sub24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub24 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Sub32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Sub64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GNUVTInherit { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GNUVTInherit { offset: offset,
+                                                          sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GNUVTEntry { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::B16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::B16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::B21 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::B21 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::B26 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::B26 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::AbsHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::AbsHi20 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::AbsLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::AbsLo12 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Abs64Lo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs64Lo20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Abs64Hi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs64Hi12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::PCALAHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALAHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::PCALALo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALALo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::PCALA64Lo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALA64Lo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::PCALA64Hi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALA64Hi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOTPCHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTPCHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOTPCLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTPCLo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOT64PCLo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64PCLo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOT64PCHi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64PCHi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOTHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTHi20 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOTLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTLo12 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOT64Lo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64Lo20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::GOT64Hi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64Hi12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLEHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLEHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLELo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLELo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLE64Lo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLE64Lo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLE64Hi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLE64Hi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIEPCHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIEPCHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIEPCLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIEPCLo12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIE64PCLo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64PCLo20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIE64PCHi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64PCHi12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIEHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIEHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIELo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIELo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIE64Lo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64Lo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSIE64Hi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64Hi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLDPCHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLDPCHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLDHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLDHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSGDPCHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSGDPCHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSGDHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSGDHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::PCRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCRel32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Relax { offset } =>
+                Ok(LoongArchReloc::Relax { offset: offset }),
+            LoongArchReloc::Align { offset, addend } =>
+                Ok(LoongArchReloc::Align { offset: offset, addend: addend }),
+            LoongArchReloc::PCRel20S2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCRel20S2 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Add6 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Sub6 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::AddULEB128 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::AddULEB128 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::SubULEB128 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SubULEB128 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::PCRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCRel64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::Call36 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Call36 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescPCHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescPCHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescPCLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescPCLo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDesc64PCLo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64PCLo20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDesc64PCHi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64PCHi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescLo12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescLo12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDesc64Lo20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64Lo20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDesc64Hi12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64Hi12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescLd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescLd { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescCall { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescCall { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLEHi20R { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLEHi20R { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLEAddR { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLEAddR { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLELo12R { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLELo12R { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSLDPCRel20S2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLDPCRel20S2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSGDPCRel20S2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSGDPCRel20S2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            LoongArchReloc::TLSDescPCRel20S2 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescPCRel20S2 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                }
+        }
+    }
+}
+
+impl<'a, Class> TryFrom<LoongArchRelocStrDataSym<'a, Class>>
+    for LoongArchRelocStrSym<'a, Class>
+    where Class: SymOffsets {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: LoongArchRelocStrDataSym<'a, Class>) ->
+        Result<LoongArchRelocStrSym<'a, Class>, Self::Error> {
+        match reloc {
+            LoongArchReloc::None => Ok(LoongArchReloc::None),
+            LoongArchReloc::Abs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Abs64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Relative { offset, addend } =>
+                Ok(LoongArchReloc::Relative { offset: offset, addend: addend }),
+            LoongArchReloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Copy { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::JumpSlot { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPMod32 { offset: offset,
+                                                         sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDTPMod64 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPMod64 { offset: offset,
+                                                         sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDTPRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPRel32 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDTPRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDTPRel64 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSTPRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSTPRel32 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSTPRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSTPRel64 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::IRelative { offset, addend } =>
+                Ok(LoongArchReloc::IRelative { offset: offset,
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc32 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDesc64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::MarkLA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::MarkLA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::MarkPCRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::MarkPCRel { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushPCRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushPCRel { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushAbsolute { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushAbsolute { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushDup { offset } =>
+                Ok(LoongArchReloc::SOPPushDup { offset: offset }),
+            LoongArchReloc::SOPPushGPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushGPRel { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushTLSTPRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushTLSTPRel { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushTLSGOT { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushTLSGOT { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushTLSGD { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushTLSGD { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPPushPLTPCRel { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SOPPushPLTPCRel { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SOPAssert { offset } =>
+                Ok(LoongArchReloc::SOPAssert { offset: offset }),
+            LoongArchReloc::SOPNot { offset } =>
+                Ok(LoongArchReloc::SOPNot { offset: offset }),
+            LoongArchReloc::SOPSub { offset } =>
+                Ok(LoongArchReloc::SOPSub { offset: offset }),
+            LoongArchReloc::SOPSL { offset } =>
+                Ok(LoongArchReloc::SOPSL { offset: offset }),
+            LoongArchReloc::SOPSR { offset } =>
+                Ok(LoongArchReloc::SOPSR { offset: offset }),
+            LoongArchReloc::SOPAdd { offset } =>
+                Ok(LoongArchReloc::SOPAdd { offset: offset }),
+            LoongArchReloc::SOPAnd { offset } =>
+                Ok(LoongArchReloc::SOPAnd { offset: offset }),
+            LoongArchReloc::SOPIfElse { offset } =>
+                Ok(LoongArchReloc::SOPIfElse { offset: offset }),
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W5 { offset: offset }),
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32U10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S5W20 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32U { offset } =>
+                Ok(LoongArchReloc::SOPPop32U { offset: offset }),
+            LoongArchReloc::Add8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Add16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Add24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add24 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Add32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Add64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Sub8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Sub16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Sub24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub24 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Sub32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Sub64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GNUVTInherit { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GNUVTInherit { offset: offset,
+                                                          sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GNUVTEntry { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::B16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::B16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::B21 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::B21 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::B26 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::B26 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::AbsHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::AbsHi20 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::AbsLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::AbsLo12 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Abs64Lo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs64Lo20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Abs64Hi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Abs64Hi12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::PCALAHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALAHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::PCALALo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALALo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::PCALA64Lo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALA64Lo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::PCALA64Hi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCALA64Hi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOTPCHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTPCHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOTPCLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTPCLo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOT64PCLo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64PCLo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOT64PCHi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64PCHi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOTHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTHi20 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOTLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOTLo12 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOT64Lo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64Lo20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::GOT64Hi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::GOT64Hi12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLEHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLEHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLELo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLELo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLE64Lo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLE64Lo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLE64Hi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLE64Hi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIEPCHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIEPCHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIEPCLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIEPCLo12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIE64PCLo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64PCLo20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIE64PCHi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64PCHi12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIEHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIEHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIELo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIELo12 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIE64Lo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64Lo20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSIE64Hi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSIE64Hi12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLDPCHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLDPCHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLDHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLDHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSGDPCHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSGDPCHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSGDHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSGDHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::PCRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCRel32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Relax { offset } =>
+                Ok(LoongArchReloc::Relax { offset: offset }),
+            LoongArchReloc::Align { offset, addend } =>
+                Ok(LoongArchReloc::Align { offset: offset, addend: addend }),
+            LoongArchReloc::PCRel20S2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCRel20S2 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Add6 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Add6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Sub6 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Sub6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::AddULEB128 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::AddULEB128 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::SubULEB128 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::SubULEB128 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::PCRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::PCRel64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::Call36 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::Call36 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescPCHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescPCHi20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescPCLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescPCLo12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDesc64PCLo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64PCLo20 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDesc64PCHi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64PCHi12 { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescHi20 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescLo12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescLo12 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDesc64Lo20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64Lo20 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDesc64Hi12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDesc64Hi12 { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescLd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescLd { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescCall { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescCall { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLEHi20R { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLEHi20R { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLEAddR { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLEAddR { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLELo12R { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLELo12R { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSLDPCRel20S2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSLDPCRel20S2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSGDPCRel20S2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSGDPCRel20S2 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            LoongArchReloc::TLSDescPCRel20S2 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(LoongArchReloc::TLSDescPCRel20S2 { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a, Class> From<LoongArchRelocStrDataSym<'a, Class>>
+    for LoongArchRelocStrData<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: LoongArchRelocStrDataSym<'a, Class>) ->
+        LoongArchRelocStrData<'a> {
+        match reloc {
+            LoongArchReloc::None => LoongArchReloc::None,
+            LoongArchReloc::Abs32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Abs32 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Abs64 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Abs64 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Relative { offset, addend } =>
+                LoongArchReloc::Relative { offset: offset, addend: addend },
+            LoongArchReloc::Copy { sym: SymData { name, .. }, offset } =>
+                LoongArchReloc::Copy { offset: offset, sym: name },
+            LoongArchReloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                LoongArchReloc::JumpSlot { offset: offset, sym: name,
+                                           addend: addend },
+            LoongArchReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                LoongArchReloc::TLSDTPMod32 { offset: offset, sym: name },
+            LoongArchReloc::TLSDTPMod64 { sym: SymData { name, .. }, offset } =>
+                LoongArchReloc::TLSDTPMod64 { offset: offset, sym: name },
+            LoongArchReloc::TLSDTPRel32 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDTPRel32 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSDTPRel64 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDTPRel64 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSTPRel32 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSTPRel32 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::TLSTPRel64 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSTPRel64 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::IRelative { offset, addend } =>
+                LoongArchReloc::IRelative { offset: offset, addend: addend },
+            LoongArchReloc::TLSDesc32 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSDesc32 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSDesc64 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSDesc64 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::MarkLA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                LoongArchReloc::MarkLA { offset: offset, sym: name,
+                                         addend: addend },
+            LoongArchReloc::MarkPCRel { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::MarkPCRel { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::SOPPushPCRel { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                LoongArchReloc::SOPPushPCRel { offset: offset, sym: name,
+                                               addend: addend },
+            LoongArchReloc::SOPPushAbsolute { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::SOPPushAbsolute { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::SOPPushDup { offset } =>
+                LoongArchReloc::SOPPushDup { offset: offset },
+            LoongArchReloc::SOPPushGPRel { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                LoongArchReloc::SOPPushGPRel { offset: offset, sym: name,
+                                               addend: addend },
+            LoongArchReloc::SOPPushTLSTPRel { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::SOPPushTLSTPRel { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::SOPPushTLSGOT { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::SOPPushTLSGOT { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::SOPPushTLSGD { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                LoongArchReloc::SOPPushTLSGD { offset: offset, sym: name,
+                                               addend: addend },
+            LoongArchReloc::SOPPushPLTPCRel { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::SOPPushPLTPCRel { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::SOPAssert { offset } =>
+                LoongArchReloc::SOPAssert { offset: offset },
+            LoongArchReloc::SOPNot { offset } =>
+                LoongArchReloc::SOPNot { offset: offset },
+            LoongArchReloc::SOPSub { offset } =>
+                LoongArchReloc::SOPSub { offset: offset },
+            LoongArchReloc::SOPSL { offset } =>
+                LoongArchReloc::SOPSL { offset: offset },
+            LoongArchReloc::SOPSR { offset } =>
+                LoongArchReloc::SOPSR { offset: offset },
+            LoongArchReloc::SOPAdd { offset } =>
+                LoongArchReloc::SOPAdd { offset: offset },
+            LoongArchReloc::SOPAnd { offset } =>
+                LoongArchReloc::SOPAnd { offset: offset },
+            LoongArchReloc::SOPIfElse { offset } =>
+                LoongArchReloc::SOPIfElse { offset: offset },
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                LoongArchReloc::SOPPop32S10W5 { offset: offset },
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                LoongArchReloc::SOPPop32U10W12 { offset: offset },
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                LoongArchReloc::SOPPop32S10W12 { offset: offset },
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                LoongArchReloc::SOPPop32S10W16 { offset: offset },
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                LoongArchReloc::SOPPop32S10W16S2 { offset: offset },
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                LoongArchReloc::SOPPop32S5W20 { offset: offset },
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset },
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset },
+            LoongArchReloc::SOPPop32U { offset } =>
+                LoongArchReloc::SOPPop32U { offset: offset },
+            LoongArchReloc::Add8 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Add8 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::Add16 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add16 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Add24 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add24 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Add32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add32 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Add64 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add64 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub8 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Sub8 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::Sub16 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub16 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub24 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub24 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub32 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub64 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub64 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::GNUVTInherit { sym: SymData { name, .. },
+                                           offset } =>
+                LoongArchReloc::GNUVTInherit { offset: offset, sym: name },
+            LoongArchReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::GNUVTEntry { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::B16 { sym: SymData { name, .. }, offset, addend } =>
+                LoongArchReloc::B16 { offset: offset, sym: name,
+                                      addend: addend },
+            LoongArchReloc::B21 { sym: SymData { name, .. }, offset, addend } =>
+                LoongArchReloc::B21 { offset: offset, sym: name,
+                                      addend: addend },
+            LoongArchReloc::B26 { sym: SymData { name, .. }, offset, addend } =>
+                LoongArchReloc::B26 { offset: offset, sym: name,
+                                      addend: addend },
+            LoongArchReloc::AbsHi20 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::AbsHi20 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::AbsLo12 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::AbsLo12 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::Abs64Lo20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::Abs64Lo20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::Abs64Hi12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::Abs64Hi12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCALAHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::PCALAHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCALALo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::PCALALo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCALA64Lo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::PCALA64Lo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::PCALA64Hi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::PCALA64Hi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::GOTPCHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOTPCHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::GOTPCLo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOTPCLo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::GOT64PCLo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::GOT64PCLo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::GOT64PCHi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::GOT64PCHi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::GOTHi20 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::GOTHi20 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::GOTLo12 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::GOTLo12 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::GOT64Lo20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOT64Lo20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::GOT64Hi12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOT64Hi12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLEHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLEHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLELo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLELo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLE64Lo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSLE64Lo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLE64Hi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSLE64Hi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIEPCHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIEPCHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIEPCLo12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIEPCLo12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIE64PCLo20 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSIE64PCLo20 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSIE64PCHi12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSIE64PCHi12 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSIEHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSIEHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSIELo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSIELo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSIE64Lo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIE64Lo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIE64Hi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIE64Hi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLDPCHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSLDPCHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLDHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLDHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSGDPCHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSGDPCHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSGDHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSGDHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCRel32 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::PCRel32 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::Relax { offset } =>
+                LoongArchReloc::Relax { offset: offset },
+            LoongArchReloc::Align { offset, addend } =>
+                LoongArchReloc::Align { offset: offset, addend: addend },
+            LoongArchReloc::PCRel20S2 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::PCRel20S2 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::Add6 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Add6 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::Sub6 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Sub6 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::AddULEB128 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::AddULEB128 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::SubULEB128 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::SubULEB128 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::PCRel64 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::PCRel64 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::Call36 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                LoongArchReloc::Call36 { offset: offset, sym: name,
+                                         addend: addend },
+            LoongArchReloc::TLSDescPCHi20 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDescPCHi20 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDescPCLo12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDescPCLo12 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDesc64PCLo20 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::TLSDesc64PCLo20 { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::TLSDesc64PCHi12 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::TLSDesc64PCHi12 { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::TLSDescHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDescHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSDescLo12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDescLo12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSDesc64Lo20 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDesc64Lo20 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDesc64Hi12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDesc64Hi12 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDescLd { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSDescLd { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSDescCall { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDescCall { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLEHi20R { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSLEHi20R { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::TLSLEAddR { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLEAddR { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLELo12R { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSLELo12R { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::TLSLDPCRel20S2 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                LoongArchReloc::TLSLDPCRel20S2 { offset: offset, sym: name,
+                                                 addend: addend },
+            LoongArchReloc::TLSGDPCRel20S2 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                LoongArchReloc::TLSGDPCRel20S2 { offset: offset, sym: name,
+                                                 addend: addend },
+            LoongArchReloc::TLSDescPCRel20S2 { sym: SymData { name, .. },
+                                               offset, addend } =>
+                LoongArchReloc::TLSDescPCRel20S2 { offset: offset, sym: name,
+                                                   addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<LoongArchRelocStrData<'a>> for LoongArchRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: LoongArchRelocStrData<'a>) ->
+        Result<LoongArchRelocStr<'a>, Self::Error> {
+        match reloc {
+            LoongArchReloc::None => Ok(LoongArchReloc::None),
+            LoongArchReloc::Abs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Abs32 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Abs32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Abs32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Abs32 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Abs64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Abs64 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Abs64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Abs64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Abs64 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Relative { offset, addend } =>
+                Ok(LoongArchReloc::Relative { offset: offset, addend: addend }),
+            LoongArchReloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(LoongArchReloc::Copy { offset: offset, sym: Some(name) }),
+            LoongArchReloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Copy { sym: None, offset } =>
+                Ok(LoongArchReloc::Copy { offset: offset, sym: None }),
+            LoongArchReloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::JumpSlot { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            LoongArchReloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::JumpSlot { offset: offset, sym: None,
+                                              addend: addend }),
+            LoongArchReloc::TLSDTPMod32 { sym: Some(Ok(name)), offset } =>
+                Ok(LoongArchReloc::TLSDTPMod32 { offset: offset,
+                                                 sym: Some(name) }),
+            LoongArchReloc::TLSDTPMod32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDTPMod32 { sym: None, offset } =>
+                Ok(LoongArchReloc::TLSDTPMod32 { offset: offset, sym: None }),
+            LoongArchReloc::TLSDTPMod64 { sym: Some(Ok(name)), offset } =>
+                Ok(LoongArchReloc::TLSDTPMod64 { offset: offset,
+                                                 sym: Some(name) }),
+            LoongArchReloc::TLSDTPMod64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDTPMod64 { sym: None, offset } =>
+                Ok(LoongArchReloc::TLSDTPMod64 { offset: offset, sym: None }),
+            LoongArchReloc::TLSDTPRel32 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSDTPRel32 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSDTPRel32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDTPRel32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDTPRel32 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSDTPRel64 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSDTPRel64 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSDTPRel64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDTPRel64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDTPRel64 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSTPRel32 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::TLSTPRel32 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::TLSTPRel32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSTPRel32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSTPRel32 { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::TLSTPRel64 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::TLSTPRel64 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::TLSTPRel64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSTPRel64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSTPRel64 { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::IRelative { offset, addend } =>
+                Ok(LoongArchReloc::IRelative { offset: offset,
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc32 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDesc32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc32 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc64 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSDesc64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDesc64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc64 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::MarkLA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::MarkLA { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            LoongArchReloc::MarkLA { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::MarkLA { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::MarkLA { offset: offset, sym: None,
+                                            addend: addend }),
+            LoongArchReloc::MarkPCRel { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::MarkPCRel { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::MarkPCRel { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::MarkPCRel { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::MarkPCRel { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::SOPPushPCRel { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(LoongArchReloc::SOPPushPCRel { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            LoongArchReloc::SOPPushPCRel { sym: Some(Err(err)),
+                                           .. } => Err(err),
+            LoongArchReloc::SOPPushPCRel { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushPCRel { offset: offset, sym: None,
+                                                  addend: addend }),
+            LoongArchReloc::SOPPushAbsolute { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(LoongArchReloc::SOPPushAbsolute { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            LoongArchReloc::SOPPushAbsolute { sym: Some(Err(err)),
+                                              .. } => Err(err),
+            LoongArchReloc::SOPPushAbsolute { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushAbsolute { offset: offset, sym: None,
+                                                     addend: addend }),
+            LoongArchReloc::SOPPushDup { offset } =>
+                Ok(LoongArchReloc::SOPPushDup { offset: offset }),
+            LoongArchReloc::SOPPushGPRel { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(LoongArchReloc::SOPPushGPRel { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            LoongArchReloc::SOPPushGPRel { sym: Some(Err(err)),
+                                           .. } => Err(err),
+            LoongArchReloc::SOPPushGPRel { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushGPRel { offset: offset, sym: None,
+                                                  addend: addend }),
+            LoongArchReloc::SOPPushTLSTPRel { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(LoongArchReloc::SOPPushTLSTPRel { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            LoongArchReloc::SOPPushTLSTPRel { sym: Some(Err(err)),
+                                              .. } => Err(err),
+            LoongArchReloc::SOPPushTLSTPRel { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushTLSTPRel { offset: offset, sym: None,
+                                                     addend: addend }),
+            LoongArchReloc::SOPPushTLSGOT { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::SOPPushTLSGOT { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::SOPPushTLSGOT { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::SOPPushTLSGOT { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushTLSGOT { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::SOPPushTLSGD { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(LoongArchReloc::SOPPushTLSGD { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            LoongArchReloc::SOPPushTLSGD { sym: Some(Err(err)),
+                                           .. } => Err(err),
+            LoongArchReloc::SOPPushTLSGD { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushTLSGD { offset: offset, sym: None,
+                                                  addend: addend }),
+            LoongArchReloc::SOPPushPLTPCRel { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(LoongArchReloc::SOPPushPLTPCRel { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            LoongArchReloc::SOPPushPLTPCRel { sym: Some(Err(err)),
+                                              .. } => Err(err),
+            LoongArchReloc::SOPPushPLTPCRel { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SOPPushPLTPCRel { offset: offset, sym: None,
+                                                     addend: addend }),
+            LoongArchReloc::SOPAssert { offset } =>
+                Ok(LoongArchReloc::SOPAssert { offset: offset }),
+            LoongArchReloc::SOPNot { offset } =>
+                Ok(LoongArchReloc::SOPNot { offset: offset }),
+            LoongArchReloc::SOPSub { offset } =>
+                Ok(LoongArchReloc::SOPSub { offset: offset }),
+            LoongArchReloc::SOPSL { offset } =>
+                Ok(LoongArchReloc::SOPSL { offset: offset }),
+            LoongArchReloc::SOPSR { offset } =>
+                Ok(LoongArchReloc::SOPSR { offset: offset }),
+            LoongArchReloc::SOPAdd { offset } =>
+                Ok(LoongArchReloc::SOPAdd { offset: offset }),
+            LoongArchReloc::SOPAnd { offset } =>
+                Ok(LoongArchReloc::SOPAnd { offset: offset }),
+            LoongArchReloc::SOPIfElse { offset } =>
+                Ok(LoongArchReloc::SOPIfElse { offset: offset }),
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W5 { offset: offset }),
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32U10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W12 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16 { offset: offset }),
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S5W20 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                Ok(LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset }),
+            LoongArchReloc::SOPPop32U { offset } =>
+                Ok(LoongArchReloc::SOPPop32U { offset: offset }),
+            LoongArchReloc::Add8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Add8 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            LoongArchReloc::Add8 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Add8 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Add8 { offset: offset, sym: None,
+                                          addend: addend }),
+            LoongArchReloc::Add16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Add16 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Add16 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Add16 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Add16 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Add24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Add24 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Add24 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Add24 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Add24 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Add32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Add32 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Add32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Add32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Add32 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Add64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Add64 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Add64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Add64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Add64 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Sub8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Sub8 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            LoongArchReloc::Sub8 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Sub8 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Sub8 { offset: offset, sym: None,
+                                          addend: addend }),
+            LoongArchReloc::Sub16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Sub16 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Sub16 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Sub16 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Sub16 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Sub24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Sub24 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Sub24 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Sub24 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Sub24 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Sub32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Sub32 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Sub32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Sub32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Sub32 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::Sub64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Sub64 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            LoongArchReloc::Sub64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Sub64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Sub64 { offset: offset, sym: None,
+                                           addend: addend }),
+            LoongArchReloc::GNUVTInherit { sym: Some(Ok(name)), offset } =>
+                Ok(LoongArchReloc::GNUVTInherit { offset: offset,
+                                                  sym: Some(name) }),
+            LoongArchReloc::GNUVTInherit { sym: Some(Err(err)),
+                                           .. } => Err(err),
+            LoongArchReloc::GNUVTInherit { sym: None, offset } =>
+                Ok(LoongArchReloc::GNUVTInherit { offset: offset, sym: None }),
+            LoongArchReloc::GNUVTEntry { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::GNUVTEntry { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::GNUVTEntry { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GNUVTEntry { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GNUVTEntry { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::B16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::B16 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            LoongArchReloc::B16 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::B16 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::B16 { offset: offset, sym: None,
+                                         addend: addend }),
+            LoongArchReloc::B21 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::B21 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            LoongArchReloc::B21 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::B21 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::B21 { offset: offset, sym: None,
+                                         addend: addend }),
+            LoongArchReloc::B26 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::B26 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            LoongArchReloc::B26 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::B26 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::B26 { offset: offset, sym: None,
+                                         addend: addend }),
+            LoongArchReloc::AbsHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::AbsHi20 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            LoongArchReloc::AbsHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::AbsHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::AbsHi20 { offset: offset, sym: None,
+                                             addend: addend }),
+            LoongArchReloc::AbsLo12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::AbsLo12 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            LoongArchReloc::AbsLo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::AbsLo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::AbsLo12 { offset: offset, sym: None,
+                                             addend: addend }),
+            LoongArchReloc::Abs64Lo20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Abs64Lo20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::Abs64Lo20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Abs64Lo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Abs64Lo20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::Abs64Hi12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Abs64Hi12 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::Abs64Hi12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Abs64Hi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Abs64Hi12 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::PCALAHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::PCALAHi20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::PCALAHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCALAHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCALAHi20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::PCALALo12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::PCALALo12 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::PCALALo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCALALo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCALALo12 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::PCALA64Lo20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::PCALA64Lo20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::PCALA64Lo20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCALA64Lo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCALA64Lo20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::PCALA64Hi12 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::PCALA64Hi12 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::PCALA64Hi12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCALA64Hi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCALA64Hi12 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::GOTPCHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::GOTPCHi20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::GOTPCHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOTPCHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOTPCHi20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::GOTPCLo12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::GOTPCLo12 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::GOTPCLo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOTPCLo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOTPCLo12 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::GOT64PCLo20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::GOT64PCLo20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::GOT64PCLo20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOT64PCLo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOT64PCLo20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::GOT64PCHi12 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::GOT64PCHi12 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::GOT64PCHi12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOT64PCHi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOT64PCHi12 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::GOTHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::GOTHi20 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            LoongArchReloc::GOTHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOTHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOTHi20 { offset: offset, sym: None,
+                                             addend: addend }),
+            LoongArchReloc::GOTLo12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::GOTLo12 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            LoongArchReloc::GOTLo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOTLo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOTLo12 { offset: offset, sym: None,
+                                             addend: addend }),
+            LoongArchReloc::GOT64Lo20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::GOT64Lo20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::GOT64Lo20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOT64Lo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOT64Lo20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::GOT64Hi12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::GOT64Hi12 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::GOT64Hi12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::GOT64Hi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::GOT64Hi12 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSLEHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSLEHi20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSLEHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLEHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLEHi20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSLELo12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSLELo12 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSLELo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLELo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLELo12 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSLE64Lo20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSLE64Lo20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSLE64Lo20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLE64Lo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLE64Lo20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSLE64Hi12 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSLE64Hi12 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSLE64Hi12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLE64Hi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLE64Hi12 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSIEPCHi20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSIEPCHi20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSIEPCHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSIEPCHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIEPCHi20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSIEPCLo12 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSIEPCLo12 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSIEPCLo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSIEPCLo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIEPCLo12 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSIE64PCLo20 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::TLSIE64PCLo20 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::TLSIE64PCLo20 { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::TLSIE64PCLo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIE64PCLo20 { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::TLSIE64PCHi12 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::TLSIE64PCHi12 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::TLSIE64PCHi12 { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::TLSIE64PCHi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIE64PCHi12 { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::TLSIEHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSIEHi20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSIEHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSIEHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIEHi20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSIELo12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSIELo12 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSIELo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSIELo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIELo12 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSIE64Lo20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSIE64Lo20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSIE64Lo20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSIE64Lo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIE64Lo20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSIE64Hi12 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSIE64Hi12 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSIE64Hi12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSIE64Hi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSIE64Hi12 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSLDPCHi20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSLDPCHi20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSLDPCHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLDPCHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLDPCHi20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSLDHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSLDHi20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSLDHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLDHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLDHi20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSGDPCHi20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSGDPCHi20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSGDPCHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSGDPCHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSGDPCHi20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSGDHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSGDHi20 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSGDHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSGDHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSGDHi20 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::PCRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::PCRel32 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            LoongArchReloc::PCRel32 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCRel32 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCRel32 { offset: offset, sym: None,
+                                             addend: addend }),
+            LoongArchReloc::Relax { offset } =>
+                Ok(LoongArchReloc::Relax { offset: offset }),
+            LoongArchReloc::Align { offset, addend } =>
+                Ok(LoongArchReloc::Align { offset: offset, addend: addend }),
+            LoongArchReloc::PCRel20S2 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::PCRel20S2 { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::PCRel20S2 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCRel20S2 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCRel20S2 { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::Add6 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Add6 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            LoongArchReloc::Add6 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Add6 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Add6 { offset: offset, sym: None,
+                                          addend: addend }),
+            LoongArchReloc::Sub6 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Sub6 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            LoongArchReloc::Sub6 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Sub6 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Sub6 { offset: offset, sym: None,
+                                          addend: addend }),
+            LoongArchReloc::AddULEB128 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::AddULEB128 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::AddULEB128 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::AddULEB128 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::AddULEB128 { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::SubULEB128 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::SubULEB128 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::SubULEB128 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::SubULEB128 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::SubULEB128 { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::PCRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::PCRel64 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            LoongArchReloc::PCRel64 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::PCRel64 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::PCRel64 { offset: offset, sym: None,
+                                             addend: addend }),
+            LoongArchReloc::Call36 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::Call36 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            LoongArchReloc::Call36 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::Call36 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::Call36 { offset: offset, sym: None,
+                                            addend: addend }),
+            LoongArchReloc::TLSDescPCHi20 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::TLSDescPCHi20 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::TLSDescPCHi20 { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::TLSDescPCHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescPCHi20 { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::TLSDescPCLo12 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::TLSDescPCLo12 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::TLSDescPCLo12 { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::TLSDescPCLo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescPCLo12 { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::TLSDesc64PCLo20 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(LoongArchReloc::TLSDesc64PCLo20 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            LoongArchReloc::TLSDesc64PCLo20 { sym: Some(Err(err)),
+                                              .. } => Err(err),
+            LoongArchReloc::TLSDesc64PCLo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc64PCLo20 { offset: offset, sym: None,
+                                                     addend: addend }),
+            LoongArchReloc::TLSDesc64PCHi12 { sym: Some(Ok(name)), offset,
+                                              addend } =>
+                Ok(LoongArchReloc::TLSDesc64PCHi12 { offset: offset,
+                                                     sym: Some(name),
+                                                     addend: addend }),
+            LoongArchReloc::TLSDesc64PCHi12 { sym: Some(Err(err)),
+                                              .. } => Err(err),
+            LoongArchReloc::TLSDesc64PCHi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc64PCHi12 { offset: offset, sym: None,
+                                                     addend: addend }),
+            LoongArchReloc::TLSDescHi20 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSDescHi20 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSDescHi20 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDescHi20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescHi20 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSDescLo12 { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSDescLo12 { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSDescLo12 { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDescLo12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescLo12 { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSDesc64Lo20 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::TLSDesc64Lo20 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::TLSDesc64Lo20 { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::TLSDesc64Lo20 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc64Lo20 { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::TLSDesc64Hi12 { sym: Some(Ok(name)), offset,
+                                            addend } =>
+                Ok(LoongArchReloc::TLSDesc64Hi12 { offset: offset,
+                                                   sym: Some(name),
+                                                   addend: addend }),
+            LoongArchReloc::TLSDesc64Hi12 { sym: Some(Err(err)),
+                                            .. } => Err(err),
+            LoongArchReloc::TLSDesc64Hi12 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDesc64Hi12 { offset: offset, sym: None,
+                                                   addend: addend }),
+            LoongArchReloc::TLSDescLd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSDescLd { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSDescLd { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDescLd { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescLd { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSDescCall { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(LoongArchReloc::TLSDescCall { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            LoongArchReloc::TLSDescCall { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSDescCall { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescCall { offset: offset, sym: None,
+                                                 addend: addend }),
+            LoongArchReloc::TLSLEHi20R { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::TLSLEHi20R { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::TLSLEHi20R { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLEHi20R { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLEHi20R { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::TLSLEAddR { sym: Some(Ok(name)), offset, addend } =>
+                Ok(LoongArchReloc::TLSLEAddR { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            LoongArchReloc::TLSLEAddR { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLEAddR { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLEAddR { offset: offset, sym: None,
+                                               addend: addend }),
+            LoongArchReloc::TLSLELo12R { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(LoongArchReloc::TLSLELo12R { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            LoongArchReloc::TLSLELo12R { sym: Some(Err(err)), .. } => Err(err),
+            LoongArchReloc::TLSLELo12R { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLELo12R { offset: offset, sym: None,
+                                                addend: addend }),
+            LoongArchReloc::TLSLDPCRel20S2 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(LoongArchReloc::TLSLDPCRel20S2 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            LoongArchReloc::TLSLDPCRel20S2 { sym: Some(Err(err)),
+                                             .. } => Err(err),
+            LoongArchReloc::TLSLDPCRel20S2 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSLDPCRel20S2 { offset: offset, sym: None,
+                                                    addend: addend }),
+            LoongArchReloc::TLSGDPCRel20S2 { sym: Some(Ok(name)), offset,
+                                             addend } =>
+                Ok(LoongArchReloc::TLSGDPCRel20S2 { offset: offset,
+                                                    sym: Some(name),
+                                                    addend: addend }),
+            LoongArchReloc::TLSGDPCRel20S2 { sym: Some(Err(err)),
+                                             .. } => Err(err),
+            LoongArchReloc::TLSGDPCRel20S2 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSGDPCRel20S2 { offset: offset, sym: None,
+                                                    addend: addend }),
+            LoongArchReloc::TLSDescPCRel20S2 { sym: Some(Ok(name)), offset,
+                                               addend } =>
+                Ok(LoongArchReloc::TLSDescPCRel20S2 { offset: offset,
+                                                      sym: Some(name),
+                                                      addend: addend }),
+            LoongArchReloc::TLSDescPCRel20S2 { sym: Some(Err(err)),
+                                               .. } => Err(err),
+            LoongArchReloc::TLSDescPCRel20S2 { sym: None, offset, addend } =>
+                Ok(LoongArchReloc::TLSDescPCRel20S2 { offset: offset, sym: None,
+                                                      addend: addend })
+        }
+    }
+}
+
+impl<'a, Class> From<LoongArchRelocStrSym<'a, Class>> for LoongArchRelocStr<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: LoongArchRelocStrSym<'a, Class>) -> LoongArchRelocStr<'a> {
+        match reloc {
+            LoongArchReloc::None => LoongArchReloc::None,
+            LoongArchReloc::Abs32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Abs32 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Abs64 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Abs64 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Relative { offset, addend } =>
+                LoongArchReloc::Relative { offset: offset, addend: addend },
+            LoongArchReloc::Copy { sym: SymData { name, .. }, offset } =>
+                LoongArchReloc::Copy { offset: offset, sym: name },
+            LoongArchReloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                LoongArchReloc::JumpSlot { offset: offset, sym: name,
+                                           addend: addend },
+            LoongArchReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                LoongArchReloc::TLSDTPMod32 { offset: offset, sym: name },
+            LoongArchReloc::TLSDTPMod64 { sym: SymData { name, .. }, offset } =>
+                LoongArchReloc::TLSDTPMod64 { offset: offset, sym: name },
+            LoongArchReloc::TLSDTPRel32 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDTPRel32 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSDTPRel64 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDTPRel64 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSTPRel32 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSTPRel32 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::TLSTPRel64 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSTPRel64 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::IRelative { offset, addend } =>
+                LoongArchReloc::IRelative { offset: offset, addend: addend },
+            LoongArchReloc::TLSDesc32 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSDesc32 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSDesc64 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSDesc64 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::MarkLA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                LoongArchReloc::MarkLA { offset: offset, sym: name,
+                                         addend: addend },
+            LoongArchReloc::MarkPCRel { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::MarkPCRel { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::SOPPushPCRel { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                LoongArchReloc::SOPPushPCRel { offset: offset, sym: name,
+                                               addend: addend },
+            LoongArchReloc::SOPPushAbsolute { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::SOPPushAbsolute { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::SOPPushDup { offset } =>
+                LoongArchReloc::SOPPushDup { offset: offset },
+            LoongArchReloc::SOPPushGPRel { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                LoongArchReloc::SOPPushGPRel { offset: offset, sym: name,
+                                               addend: addend },
+            LoongArchReloc::SOPPushTLSTPRel { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::SOPPushTLSTPRel { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::SOPPushTLSGOT { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::SOPPushTLSGOT { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::SOPPushTLSGD { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                LoongArchReloc::SOPPushTLSGD { offset: offset, sym: name,
+                                               addend: addend },
+            LoongArchReloc::SOPPushPLTPCRel { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::SOPPushPLTPCRel { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::SOPAssert { offset } =>
+                LoongArchReloc::SOPAssert { offset: offset },
+            LoongArchReloc::SOPNot { offset } =>
+                LoongArchReloc::SOPNot { offset: offset },
+            LoongArchReloc::SOPSub { offset } =>
+                LoongArchReloc::SOPSub { offset: offset },
+            LoongArchReloc::SOPSL { offset } =>
+                LoongArchReloc::SOPSL { offset: offset },
+            LoongArchReloc::SOPSR { offset } =>
+                LoongArchReloc::SOPSR { offset: offset },
+            LoongArchReloc::SOPAdd { offset } =>
+                LoongArchReloc::SOPAdd { offset: offset },
+            LoongArchReloc::SOPAnd { offset } =>
+                LoongArchReloc::SOPAnd { offset: offset },
+            LoongArchReloc::SOPIfElse { offset } =>
+                LoongArchReloc::SOPIfElse { offset: offset },
+            LoongArchReloc::SOPPop32S10W5 { offset } =>
+                LoongArchReloc::SOPPop32S10W5 { offset: offset },
+            LoongArchReloc::SOPPop32U10W12 { offset } =>
+                LoongArchReloc::SOPPop32U10W12 { offset: offset },
+            LoongArchReloc::SOPPop32S10W12 { offset } =>
+                LoongArchReloc::SOPPop32S10W12 { offset: offset },
+            LoongArchReloc::SOPPop32S10W16 { offset } =>
+                LoongArchReloc::SOPPop32S10W16 { offset: offset },
+            LoongArchReloc::SOPPop32S10W16S2 { offset } =>
+                LoongArchReloc::SOPPop32S10W16S2 { offset: offset },
+            LoongArchReloc::SOPPop32S5W20 { offset } =>
+                LoongArchReloc::SOPPop32S5W20 { offset: offset },
+            LoongArchReloc::SOPPop32S0W5S10W16S2 { offset } =>
+                LoongArchReloc::SOPPop32S0W5S10W16S2 { offset: offset },
+            LoongArchReloc::SOPPop32S0W10S10W16S2 { offset } =>
+                LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: offset },
+            LoongArchReloc::SOPPop32U { offset } =>
+                LoongArchReloc::SOPPop32U { offset: offset },
+            LoongArchReloc::Add8 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Add8 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::Add16 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add16 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Add24 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add24 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Add32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add32 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Add64 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Add64 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub8 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Sub8 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::Sub16 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub16 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub24 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub24 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub32 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub32 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::Sub64 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                LoongArchReloc::Sub64 { offset: offset, sym: name,
+                                        addend: addend },
+            LoongArchReloc::GNUVTInherit { sym: SymData { name, .. },
+                                           offset } =>
+                LoongArchReloc::GNUVTInherit { offset: offset, sym: name },
+            LoongArchReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::GNUVTEntry { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::B16 { sym: SymData { name, .. }, offset, addend } =>
+                LoongArchReloc::B16 { offset: offset, sym: name,
+                                      addend: addend },
+            LoongArchReloc::B21 { sym: SymData { name, .. }, offset, addend } =>
+                LoongArchReloc::B21 { offset: offset, sym: name,
+                                      addend: addend },
+            LoongArchReloc::B26 { sym: SymData { name, .. }, offset, addend } =>
+                LoongArchReloc::B26 { offset: offset, sym: name,
+                                      addend: addend },
+            LoongArchReloc::AbsHi20 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::AbsHi20 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::AbsLo12 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::AbsLo12 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::Abs64Lo20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::Abs64Lo20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::Abs64Hi12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::Abs64Hi12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCALAHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::PCALAHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCALALo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::PCALALo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCALA64Lo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::PCALA64Lo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::PCALA64Hi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::PCALA64Hi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::GOTPCHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOTPCHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::GOTPCLo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOTPCLo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::GOT64PCLo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::GOT64PCLo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::GOT64PCHi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::GOT64PCHi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::GOTHi20 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::GOTHi20 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::GOTLo12 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::GOTLo12 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::GOT64Lo20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOT64Lo20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::GOT64Hi12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::GOT64Hi12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLEHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLEHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLELo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLELo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLE64Lo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSLE64Lo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLE64Hi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSLE64Hi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIEPCHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIEPCHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIEPCLo12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIEPCLo12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIE64PCLo20 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSIE64PCLo20 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSIE64PCHi12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSIE64PCHi12 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSIEHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSIEHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSIELo12 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSIELo12 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSIE64Lo20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIE64Lo20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSIE64Hi12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSIE64Hi12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLDPCHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSLDPCHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLDHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLDHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSGDPCHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSGDPCHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSGDHi20 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSGDHi20 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::PCRel32 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::PCRel32 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::Relax { offset } =>
+                LoongArchReloc::Relax { offset: offset },
+            LoongArchReloc::Align { offset, addend } =>
+                LoongArchReloc::Align { offset: offset, addend: addend },
+            LoongArchReloc::PCRel20S2 { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::PCRel20S2 { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::Add6 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Add6 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::Sub6 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                LoongArchReloc::Sub6 { offset: offset, sym: name,
+                                       addend: addend },
+            LoongArchReloc::AddULEB128 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::AddULEB128 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::SubULEB128 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::SubULEB128 { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::PCRel64 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                LoongArchReloc::PCRel64 { offset: offset, sym: name,
+                                          addend: addend },
+            LoongArchReloc::Call36 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                LoongArchReloc::Call36 { offset: offset, sym: name,
+                                         addend: addend },
+            LoongArchReloc::TLSDescPCHi20 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDescPCHi20 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDescPCLo12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDescPCLo12 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDesc64PCLo20 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::TLSDesc64PCLo20 { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::TLSDesc64PCHi12 { sym: SymData { name, .. }, offset,
+                                              addend } =>
+                LoongArchReloc::TLSDesc64PCHi12 { offset: offset, sym: name,
+                                                  addend: addend },
+            LoongArchReloc::TLSDescHi20 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDescHi20 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSDescLo12 { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDescLo12 { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSDesc64Lo20 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDesc64Lo20 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDesc64Hi12 { sym: SymData { name, .. }, offset,
+                                            addend } =>
+                LoongArchReloc::TLSDesc64Hi12 { offset: offset, sym: name,
+                                                addend: addend },
+            LoongArchReloc::TLSDescLd { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSDescLd { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSDescCall { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                LoongArchReloc::TLSDescCall { offset: offset, sym: name,
+                                              addend: addend },
+            LoongArchReloc::TLSLEHi20R { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSLEHi20R { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::TLSLEAddR { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                LoongArchReloc::TLSLEAddR { offset: offset, sym: name,
+                                            addend: addend },
+            LoongArchReloc::TLSLELo12R { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                LoongArchReloc::TLSLELo12R { offset: offset, sym: name,
+                                             addend: addend },
+            LoongArchReloc::TLSLDPCRel20S2 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                LoongArchReloc::TLSLDPCRel20S2 { offset: offset, sym: name,
+                                                 addend: addend },
+            LoongArchReloc::TLSGDPCRel20S2 { sym: SymData { name, .. }, offset,
+                                             addend } =>
+                LoongArchReloc::TLSGDPCRel20S2 { offset: offset, sym: name,
+                                                 addend: addend },
+            LoongArchReloc::TLSDescPCRel20S2 { sym: SymData { name, .. },
+                                               offset, addend } =>
+                LoongArchReloc::TLSDescPCRel20S2 { offset: offset, sym: name,
+                                                   addend: addend }
+        }
+    }
+}
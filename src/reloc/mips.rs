@@ -0,0 +1,1121 @@
+//! Relocation types for MIPS architecture.
+//!
+//! This module provides the `MipsReloc` type, which describes the
+//! relocation entries for the MIPS architecture, as defined by the
+//! MIPS System V ABI supplements and the MIPS64 ELF object file
+//! specification.  The same relocation types are used by both MIPS32
+//! and MIPS64, but the two encode the relocation info field
+//! differently.
+//!
+//! MIPS32 uses the standard [Elf32](crate::Elf32) encoding, so a
+//! `MipsReloc` can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) as
+//! the [ElfClass](crate::ElfClass) type argument.
+//!
+//! MIPS64 splits the info field into a 32-bit symbol index followed
+//! by a special symbol and three one-byte relocation types, which are
+//! applied in turn, each to the result of the previous one.  This
+//! does not match the [Elf64](crate::Elf64) encoding on little-endian
+//! targets, so MIPS64 relocation tables must be read with [Mips64] as
+//! the class type argument instead.
+//!
+//! Both forms can be converted using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf32;
+use crate::elf::Elf64;
+use crate::elf::ElfClass;
+use crate::reloc::RelClass;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelaOffsets;
+use crate::reloc::RelOffsets;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::SymOffsets;
+use crate::symtab::WithSymtab;
+
+/// Sizes for 64-bit MIPS ELF data.
+///
+/// This is identical to [Elf64](crate::Elf64) in every respect except
+/// for the layout of the relocation info field.  MIPS64 stores the
+/// symbol index as a 32-bit word in the file's byte order, followed
+/// by the special symbol and the third, second, and first relocation
+/// types as single bytes.  On big-endian targets this happens to
+/// coincide with the [Elf64](crate::Elf64) layout; on little-endian
+/// targets it does not.
+///
+/// # Examples
+///
+/// A little-endian MIPS64 relocation can be read using `Mips64` as
+/// the class type argument:
+///
+/// ```
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::reloc::Relas;
+/// use elf_utils::reloc::RelaData;
+/// use elf_utils::reloc::mips::Mips64;
+/// use elf_utils::reloc::mips::Mips64RelKind;
+///
+/// const RELAS: [u8; 24] = [
+///     0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x03,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+///
+/// let relas: Relas<'_, LittleEndian, Mips64> =
+///     Relas::try_from(&RELAS[0..]).expect("Expected success");
+/// let rela = RelaData::try_from(relas.idx(0).unwrap()).unwrap();
+///
+/// assert_eq!(rela.sym, 5);
+/// assert_eq!(rela.kind, Mips64RelKind { kind: 3, ssym: 0, kind2: 18,
+///                                       kind3: 0 });
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Mips64;
+
+/// Raw relocation kind for [Mips64].
+///
+/// This holds the individual bytes of the relocation info field other
+/// than the symbol index.
+#[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Mips64RelKind {
+    /// First relocation type.
+    pub kind: u8,
+    /// Special symbol used by the second relocation type.
+    pub ssym: u8,
+    /// Second relocation type.
+    pub kind2: u8,
+    /// Third relocation type.
+    pub kind3: u8
+}
+
+/// Relocation types for MIPS architectures.
+///
+/// These are the relocation type codes used in both MIPS32 and
+/// MIPS64 relocations.  The descriptions use the ABI notation, where
+/// `S` is the symbol value, `A` the addend, `P` the place being
+/// relocated, `GP` the final global pointer value, `GP0` the global
+/// pointer value used to create the object, and `G` the offset from
+/// `GP` of the Global Offset Table entry for the symbol.
+#[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum MipsRelType {
+    /// Null relocation.
+    None,
+    /// 16-bit absolute value `S + A`.
+    Abs16,
+    /// 32-bit absolute value `S + A`.
+    Abs32,
+    /// 32-bit value `S + A - EA`, relative to the load address.
+    Rel32,
+    /// 26-bit jump target `(A | ((P + 4) & 0xf0000000) + S) >> 2`.
+    Jump26,
+    /// High 16 bits of `S + A`, adjusted for the sign of the low half.
+    ///
+    /// The addend of a `Hi16` relocation in a `SHT_REL` table is split
+    /// between this instruction and the following [Lo16] relocation
+    /// against the same symbol; see [matching_lo16] and
+    /// [pair_addend].
+    ///
+    /// [Lo16]: MipsRelType::Lo16
+    Hi16,
+    /// Low 16 bits of `S + A`.
+    Lo16,
+    /// 16-bit offset from the global pointer, `S + A - GP`.
+    ///
+    /// For local symbols, `GP0` is also added to the addend.
+    GPRel16,
+    /// 16-bit offset of a literal pool entry from the global pointer.
+    Literal,
+    /// 16-bit Global Offset Table entry.
+    ///
+    /// For global symbols, this is `G`.  For local symbols, this
+    /// refers to the page entry for `S + A` and is paired with the
+    /// following [Lo16](MipsRelType::Lo16) like a
+    /// [Hi16](MipsRelType::Hi16).
+    GOT16,
+    /// 16-bit PC-relative offset `S + A - P`.
+    PC16,
+    /// 16-bit Global Offset Table entry for a function call, `G`.
+    ///
+    /// Calls through this entry initially reach a lazy-binding stub.
+    Call16,
+    /// 32-bit offset from the global pointer, `S + A + GP0 - GP`.
+    GPRel32,
+    /// 5-bit shift amount.
+    Shift5,
+    /// 6-bit shift amount.
+    Shift6,
+    /// 64-bit absolute value `S + A`.
+    Abs64,
+    /// 16-bit Global Offset Table entry for the symbol's address.
+    GOTDisp,
+    /// 16-bit Global Offset Table entry for the page of `S + A`.
+    GOTPage,
+    /// 16-bit offset of `S + A` within its Global Offset Table page.
+    GOTOfst,
+    /// High 16 bits of a Global Offset Table entry offset.
+    GOTHi16,
+    /// Low 16 bits of a Global Offset Table entry offset.
+    GOTLo16,
+    /// Subtraction, `S - A`.
+    Sub,
+    /// Insert a jump-table displacement (A form).
+    InsertA,
+    /// Insert a jump-table displacement (B form).
+    InsertB,
+    /// Delete an instruction.
+    Delete,
+    /// Bits 32 to 47 of `S + A`, adjusted for the sign of the lower
+    /// bits.
+    Higher,
+    /// Bits 48 to 63 of `S + A`, adjusted for the sign of the lower
+    /// bits.
+    Highest,
+    /// High 16 bits of a call Global Offset Table entry offset.
+    CallHi16,
+    /// Low 16 bits of a call Global Offset Table entry offset.
+    CallLo16,
+    /// Offset from the start of the section.
+    ScnDisp,
+    /// 16-bit value `S + A - EA`, relative to the load address.
+    Rel16,
+    /// Immediate addition.
+    AddImmediate,
+    /// Procedure jump.
+    PJump,
+    /// Global Offset Table entry relative to the load address.
+    RelGOT,
+    /// Hint for a jump through a register to `S`.
+    Jalr,
+    /// 32-bit thread-local storage module index.
+    TLSDTPMod32,
+    /// 32-bit thread-local storage block offset.
+    TLSDTPRel32,
+    /// 64-bit thread-local storage module index.
+    TLSDTPMod64,
+    /// 64-bit thread-local storage block offset.
+    TLSDTPRel64,
+    /// Global Offset Table entry for the general dynamic model.
+    TLSGD,
+    /// Global Offset Table entry for the local dynamic model.
+    TLSLDM,
+    /// High 16 bits of a thread-local storage block offset.
+    TLSDTPRelHi16,
+    /// Low 16 bits of a thread-local storage block offset.
+    TLSDTPRelLo16,
+    /// Global Offset Table entry for a thread pointer offset.
+    TLSGOTTPRel,
+    /// 32-bit thread pointer offset.
+    TLSTPRel32,
+    /// 64-bit thread pointer offset.
+    TLSTPRel64,
+    /// High 16 bits of a thread pointer offset.
+    TLSTPRelHi16,
+    /// Low 16 bits of a thread pointer offset.
+    TLSTPRelLo16,
+    /// Global Offset Table entry fill, `S`.
+    GlobData,
+    /// 21-bit PC-relative offset shifted by 2.
+    PC21S2,
+    /// 26-bit PC-relative offset shifted by 2.
+    PC26S2,
+    /// 18-bit PC-relative offset shifted by 3.
+    PC18S3,
+    /// 19-bit PC-relative offset shifted by 2.
+    PC19S2,
+    /// High 16 bits of `S + A - P`.
+    PCHi16,
+    /// Low 16 bits of `S + A - P`.
+    PCLo16,
+    /// Writable copy of `S`.
+    Copy,
+    /// Procedure Linkage Table jump-slot fill, `S`.
+    JumpSlot
+}
+
+/// Special symbols used by composed MIPS64 relocations.
+///
+/// These may be referenced by the second relocation type in a MIPS64
+/// relocation entry.
+#[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum MipsSpecialSym {
+    /// No special symbol.
+    Undef,
+    /// The global pointer value, `GP`.
+    GP,
+    /// The global pointer value used to create the object, `GP0`.
+    GP0,
+    /// The address of the location being relocated.
+    Loc
+}
+
+/// Relocation entries for MIPS architectures.
+///
+/// This datatype provides a semantic-level presentation of the MIPS
+/// relocation entries.  These can be converted to and from
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) or
+/// [Mips64] as the [ElfClass](crate::ElfClass) type argument using
+/// the [TryFrom](core::convert::TryFrom) instances for easier
+/// handling.  Offsets and addends are always stored at 64-bit width;
+/// converting back to a 32-bit relocation fails if they do not fit.
+///
+/// MIPS64 relocations are composed of up to three relocation types.
+/// The first is applied to `sym` and `addend`, the second to the
+/// result of the first and `ssym`, and the third to the result of the
+/// second.  For example, `%hi(%neg(%gp_rel(sym)))` is encoded as
+/// [GPRel32](MipsRelType::GPRel32), [Sub](MipsRelType::Sub),
+/// [Hi16](MipsRelType::Hi16).  MIPS32 relocations always have
+/// [None](MipsRelType::None) as the second and third types, and
+/// [Undef](MipsSpecialSym::Undef) as the special symbol.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct MipsReloc<Name> {
+    /// Offset in the section.
+    pub offset: u64,
+    /// Symbol reference.
+    pub sym: Name,
+    /// First relocation type, applied to `sym` and `addend`.
+    pub kind: MipsRelType,
+    /// Special symbol used by the second relocation type.
+    pub ssym: MipsSpecialSym,
+    /// Second relocation type, applied to the result of the first.
+    pub kind2: MipsRelType,
+    /// Third relocation type, applied to the result of the second.
+    pub kind3: MipsRelType,
+    /// The addend argument.
+    pub addend: i64
+}
+
+/// Type synonym for [MipsReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type MipsRelocRaw = MipsReloc<u32>;
+
+/// Type synonym for [MipsReloc] with [SymDataRaw] as the symbol type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [MipsRelocRaw].
+pub type MipsRelocRawSym<Class> = MipsReloc<SymDataRaw<Class>>;
+
+/// Type synonym for [MipsReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [MipsRelocRawSym].
+pub type MipsRelocStrDataSym<'a, Class> =
+    MipsReloc<SymDataStrData<'a, Class>>;
+
+/// Type synonym for [MipsReloc] with [SymDataStr] as the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [MipsRelocStrDataSym].
+pub type MipsRelocStrData<'a> =
+    MipsReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [MipsReloc] with UTF-8 decoded string data as the
+/// symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [MipsRelocStrDataSym].
+pub type MipsRelocStrSym<'a, Class> = MipsReloc<SymDataStr<'a, Class>>;
+
+/// Type synonym for [MipsReloc] with a `&'a str`s as the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [MipsRelocStrSym].
+pub type MipsRelocStr<'a> = MipsReloc<Option<&'a str>>;
+
+/// Errors that can occur converting a [MipsReloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// This can happen with a non-zero addend, when converting to a
+/// 32-bit relocation with an offset or addend that does not fit, or
+/// when converting a composed relocation to a 32-bit relocation.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MipsToRelError {
+    /// Non-zero or out-of-range addend.
+    BadAddend(i64),
+    /// Out-of-range offset.
+    BadOffset(u64),
+    /// Composed relocation cannot be represented.
+    Composed
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [MipsReloc].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum MipsRelocError {
+    /// Unknown tag value.
+    BadTag(u8),
+    /// Unknown special symbol value.
+    BadSpecialSym(u8)
+}
+
+/// Find the [Lo16](MipsRelType::Lo16) relocation paired with the
+/// relocation at `idx`.
+///
+/// A [Hi16](MipsRelType::Hi16) relocation, or a
+/// [GOT16](MipsRelType::GOT16) relocation against a local symbol,
+/// only holds the upper half of its addend; the lower half is held by
+/// the next [Lo16](MipsRelType::Lo16) relocation against the same
+/// symbol.  This returns the index of that relocation, or `None` if
+/// the relocation at `idx` is of another type or has no match.
+/// Whether a [GOT16](MipsRelType::GOT16) symbol is local must be
+/// checked by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::reloc::mips::MipsReloc;
+/// use elf_utils::reloc::mips::MipsRelType;
+/// use elf_utils::reloc::mips::MipsSpecialSym;
+/// use elf_utils::reloc::mips::matching_lo16;
+///
+/// let reloc = |offset, sym, kind| MipsReloc {
+///     offset: offset, sym: sym, kind: kind, ssym: MipsSpecialSym::Undef,
+///     kind2: MipsRelType::None, kind3: MipsRelType::None, addend: 0
+/// };
+/// let relocs = [
+///     reloc(0x0, 3, MipsRelType::Hi16),
+///     reloc(0x4, 4, MipsRelType::Lo16),
+///     reloc(0x8, 3, MipsRelType::Lo16)
+/// ];
+///
+/// assert_eq!(matching_lo16(&relocs, 0), Some(2));
+/// assert_eq!(matching_lo16(&relocs, 1), None);
+/// ```
+pub fn matching_lo16<Name: PartialEq>(relocs: &[MipsReloc<Name>],
+                                      idx: usize) -> Option<usize> {
+    match relocs.get(idx) {
+        Some(MipsReloc { kind: MipsRelType::Hi16, sym, .. }) |
+        Some(MipsReloc { kind: MipsRelType::GOT16, sym, .. }) =>
+            relocs[idx + 1 ..].iter()
+                              .position(|reloc| {
+                                  reloc.kind == MipsRelType::Lo16 &&
+                                  reloc.sym == *sym
+                              })
+                              .map(|pos| idx + 1 + pos),
+        _ => None
+    }
+}
+
+/// Combine the immediates from a paired [Hi16](MipsRelType::Hi16) and
+/// [Lo16](MipsRelType::Lo16) into a single addend.
+///
+/// The low half is sign-extended, so the high half is adjusted to
+/// compensate, as done by [split_hi_lo].  As specified by the ABI, the
+/// result is computed with 32-bit arithmetic.
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::reloc::mips::pair_addend;
+///
+/// assert_eq!(pair_addend(0x0001, 0x0010), 0x10010);
+/// assert_eq!(pair_addend(0x0001, 0xfff0), 0xfff0);
+/// ```
+#[inline]
+pub fn pair_addend(hi: u16, lo: u16) -> i64 {
+    ((hi as i32) << 16).wrapping_add(lo as i16 as i32) as i64
+}
+
+/// Split a value into [Hi16](MipsRelType::Hi16) and
+/// [Lo16](MipsRelType::Lo16) immediates.
+///
+/// The high half is rounded so that adding the sign-extended low half
+/// yields the original value.  This is the inverse of [pair_addend].
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::reloc::mips::split_hi_lo;
+///
+/// assert_eq!(split_hi_lo(0x10010), (0x0001, 0x0010));
+/// assert_eq!(split_hi_lo(0xfff0), (0x0001, 0xfff0));
+/// ```
+#[inline]
+pub fn split_hi_lo(value: i64) -> (u16, u16) {
+    let hi = (value.wrapping_add(0x8000) >> 16) as u16;
+    let lo = value as u16;
+
+    (hi, lo)
+}
+
+impl<Name> MipsReloc<Name> {
+    /// Check whether this relocation uses more than one relocation
+    /// type or a special symbol.
+    ///
+    /// Composed relocations can only be represented with [Mips64] as
+    /// the class type.
+    #[inline]
+    pub fn is_composed(&self) -> bool {
+        self.kind2 != MipsRelType::None || self.kind3 != MipsRelType::None ||
+        self.ssym != MipsSpecialSym::Undef
+    }
+}
+
+impl ElfClass for Mips64 {
+    type Half = <Elf64 as ElfClass>::Half;
+    type Word = <Elf64 as ElfClass>::Word;
+    type Addr = <Elf64 as ElfClass>::Addr;
+    type Offset = <Elf64 as ElfClass>::Offset;
+    type Addend = <Elf64 as ElfClass>::Addend;
+
+    const HALF_SIZE: usize = Elf64::HALF_SIZE;
+    const WORD_SIZE: usize = Elf64::WORD_SIZE;
+    const WORD_ALIGN: Self::Offset = Elf64::WORD_ALIGN;
+    const ADDR_SIZE: usize = Elf64::ADDR_SIZE;
+    const ADDR_ALIGN: Self::Offset = Elf64::ADDR_ALIGN;
+    const OFFSET_SIZE: usize = Elf64::OFFSET_SIZE;
+    const OFFSET_ALIGN: Self::Offset = Elf64::OFFSET_ALIGN;
+    const ADDEND_SIZE: usize = Elf64::ADDEND_SIZE;
+
+    const TYPE_CODE: u8 = Elf64::TYPE_CODE;
+
+    #[inline]
+    fn read_half<B: ByteOrder>(data: &[u8]) -> Self::Half {
+        Elf64::read_half::<B>(data)
+    }
+
+    #[inline]
+    fn read_word<B: ByteOrder>(data: &[u8]) -> Self::Word {
+        Elf64::read_word::<B>(data)
+    }
+
+    #[inline]
+    fn read_addr<B: ByteOrder>(data: &[u8]) -> Self::Addr {
+        Elf64::read_addr::<B>(data)
+    }
+
+    #[inline]
+    fn read_offset<B: ByteOrder>(data: &[u8]) -> Self::Offset {
+        Elf64::read_offset::<B>(data)
+    }
+
+    #[inline]
+    fn read_addend<B: ByteOrder>(data: &[u8]) -> Self::Addend {
+        Elf64::read_addend::<B>(data)
+    }
+
+    #[inline]
+    fn write_half<B: ByteOrder>(data: &mut [u8], val: Self::Half) {
+        Elf64::write_half::<B>(data, val)
+    }
+
+    #[inline]
+    fn write_word<B: ByteOrder>(data: &mut [u8], val: Self::Word) {
+        Elf64::write_word::<B>(data, val)
+    }
+
+    #[inline]
+    fn write_addr<B: ByteOrder>(data: &mut [u8], val: Self::Addr) {
+        Elf64::write_addr::<B>(data, val)
+    }
+
+    #[inline]
+    fn write_offset<B: ByteOrder>(data: &mut [u8], val: Self::Offset) {
+        Elf64::write_offset::<B>(data, val)
+    }
+
+    #[inline]
+    fn write_addend<B: ByteOrder>(data: &mut [u8], val: Self::Addend) {
+        Elf64::write_addend::<B>(data, val)
+    }
+}
+
+impl SymOffsets for Mips64 {
+    const ST_INFO_START: usize = Self::ST_NAME_END;
+    const ST_OTHER_START: usize = Self::ST_INFO_END;
+    const ST_SHIDX_START: usize = Self::ST_OTHER_END;
+    const ST_VALUE_START: usize = Self::ST_SHIDX_END;
+    const ST_SIZE_START: usize = Self::ST_VALUE_END;
+    const ST_ENT_SIZE: usize = Self::ST_SIZE_END;
+    const ST_ENT_SIZE_OFFSET: Self::Offset = Self::ST_ENT_SIZE as u64;
+}
+
+impl RelClass for Mips64 {
+    type RelKind = Mips64RelKind;
+
+    fn read_info<B: ByteOrder>(data: &[u8]) -> (Self::RelKind, Self::Word) {
+        let sym = B::read_u32(&data[0..4]);
+        let kind = Mips64RelKind { ssym: data[4], kind3: data[5],
+                                   kind2: data[6], kind: data[7] };
+
+        (kind, sym)
+    }
+
+    fn write_info<B: ByteOrder>(data: &mut [u8], kind: Self::RelKind,
+                                sym: Self::Word) {
+        B::write_u32(&mut data[0..4], sym);
+        data[4] = kind.ssym;
+        data[5] = kind.kind3;
+        data[6] = kind.kind2;
+        data[7] = kind.kind;
+    }
+}
+
+impl RelOffsets for Mips64 {
+    const REL_SIZE_OFFSET: Self::Offset = Self::REL_SIZE as u64;
+}
+
+impl RelaOffsets for Mips64 {
+    const RELA_SIZE_OFFSET: Self::Offset = Self::RELA_SIZE as u64;
+}
+
+impl Display for Mips64RelKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let Mips64RelKind { kind, ssym, kind2, kind3 } = self;
+
+        write!(f, "{}, {}, {} (special symbol {})", kind, kind2, kind3, ssym)
+    }
+}
+
+impl Display for MipsRelType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            MipsRelType::None => write!(f, "none"),
+            MipsRelType::Abs16 => write!(f, "16"),
+            MipsRelType::Abs32 => write!(f, "32"),
+            MipsRelType::Rel32 => write!(f, "rel32"),
+            MipsRelType::Jump26 => write!(f, "26"),
+            MipsRelType::Hi16 => write!(f, "hi16"),
+            MipsRelType::Lo16 => write!(f, "lo16"),
+            MipsRelType::GPRel16 => write!(f, "gprel16"),
+            MipsRelType::Literal => write!(f, "literal"),
+            MipsRelType::GOT16 => write!(f, "got16"),
+            MipsRelType::PC16 => write!(f, "pc16"),
+            MipsRelType::Call16 => write!(f, "call16"),
+            MipsRelType::GPRel32 => write!(f, "gprel32"),
+            MipsRelType::Shift5 => write!(f, "shift5"),
+            MipsRelType::Shift6 => write!(f, "shift6"),
+            MipsRelType::Abs64 => write!(f, "64"),
+            MipsRelType::GOTDisp => write!(f, "got_disp"),
+            MipsRelType::GOTPage => write!(f, "got_page"),
+            MipsRelType::GOTOfst => write!(f, "got_ofst"),
+            MipsRelType::GOTHi16 => write!(f, "got_hi16"),
+            MipsRelType::GOTLo16 => write!(f, "got_lo16"),
+            MipsRelType::Sub => write!(f, "sub"),
+            MipsRelType::InsertA => write!(f, "insert_a"),
+            MipsRelType::InsertB => write!(f, "insert_b"),
+            MipsRelType::Delete => write!(f, "delete"),
+            MipsRelType::Higher => write!(f, "higher"),
+            MipsRelType::Highest => write!(f, "highest"),
+            MipsRelType::CallHi16 => write!(f, "call_hi16"),
+            MipsRelType::CallLo16 => write!(f, "call_lo16"),
+            MipsRelType::ScnDisp => write!(f, "scn_disp"),
+            MipsRelType::Rel16 => write!(f, "rel16"),
+            MipsRelType::AddImmediate => write!(f, "add_immediate"),
+            MipsRelType::PJump => write!(f, "pjump"),
+            MipsRelType::RelGOT => write!(f, "relgot"),
+            MipsRelType::Jalr => write!(f, "jalr"),
+            MipsRelType::TLSDTPMod32 => write!(f, "tls_dtpmod32"),
+            MipsRelType::TLSDTPRel32 => write!(f, "tls_dtprel32"),
+            MipsRelType::TLSDTPMod64 => write!(f, "tls_dtpmod64"),
+            MipsRelType::TLSDTPRel64 => write!(f, "tls_dtprel64"),
+            MipsRelType::TLSGD => write!(f, "tls_gd"),
+            MipsRelType::TLSLDM => write!(f, "tls_ldm"),
+            MipsRelType::TLSDTPRelHi16 => write!(f, "tls_dtprel_hi16"),
+            MipsRelType::TLSDTPRelLo16 => write!(f, "tls_dtprel_lo16"),
+            MipsRelType::TLSGOTTPRel => write!(f, "tls_gottprel"),
+            MipsRelType::TLSTPRel32 => write!(f, "tls_tprel32"),
+            MipsRelType::TLSTPRel64 => write!(f, "tls_tprel64"),
+            MipsRelType::TLSTPRelHi16 => write!(f, "tls_tprel_hi16"),
+            MipsRelType::TLSTPRelLo16 => write!(f, "tls_tprel_lo16"),
+            MipsRelType::GlobData => write!(f, "glob_dat"),
+            MipsRelType::PC21S2 => write!(f, "pc21_s2"),
+            MipsRelType::PC26S2 => write!(f, "pc26_s2"),
+            MipsRelType::PC18S3 => write!(f, "pc18_s3"),
+            MipsRelType::PC19S2 => write!(f, "pc19_s2"),
+            MipsRelType::PCHi16 => write!(f, "pchi16"),
+            MipsRelType::PCLo16 => write!(f, "pclo16"),
+            MipsRelType::Copy => write!(f, "copy"),
+            MipsRelType::JumpSlot => write!(f, "jump_slot")
+        }
+    }
+}
+
+impl From<MipsRelType> for u8 {
+    #[inline]
+    fn from(kind: MipsRelType) -> u8 {
+        match kind {
+            MipsRelType::None => 0,
+            MipsRelType::Abs16 => 1,
+            MipsRelType::Abs32 => 2,
+            MipsRelType::Rel32 => 3,
+            MipsRelType::Jump26 => 4,
+            MipsRelType::Hi16 => 5,
+            MipsRelType::Lo16 => 6,
+            MipsRelType::GPRel16 => 7,
+            MipsRelType::Literal => 8,
+            MipsRelType::GOT16 => 9,
+            MipsRelType::PC16 => 10,
+            MipsRelType::Call16 => 11,
+            MipsRelType::GPRel32 => 12,
+            MipsRelType::Shift5 => 16,
+            MipsRelType::Shift6 => 17,
+            MipsRelType::Abs64 => 18,
+            MipsRelType::GOTDisp => 19,
+            MipsRelType::GOTPage => 20,
+            MipsRelType::GOTOfst => 21,
+            MipsRelType::GOTHi16 => 22,
+            MipsRelType::GOTLo16 => 23,
+            MipsRelType::Sub => 24,
+            MipsRelType::InsertA => 25,
+            MipsRelType::InsertB => 26,
+            MipsRelType::Delete => 27,
+            MipsRelType::Higher => 28,
+            MipsRelType::Highest => 29,
+            MipsRelType::CallHi16 => 30,
+            MipsRelType::CallLo16 => 31,
+            MipsRelType::ScnDisp => 32,
+            MipsRelType::Rel16 => 33,
+            MipsRelType::AddImmediate => 34,
+            MipsRelType::PJump => 35,
+            MipsRelType::RelGOT => 36,
+            MipsRelType::Jalr => 37,
+            MipsRelType::TLSDTPMod32 => 38,
+            MipsRelType::TLSDTPRel32 => 39,
+            MipsRelType::TLSDTPMod64 => 40,
+            MipsRelType::TLSDTPRel64 => 41,
+            MipsRelType::TLSGD => 42,
+            MipsRelType::TLSLDM => 43,
+            MipsRelType::TLSDTPRelHi16 => 44,
+            MipsRelType::TLSDTPRelLo16 => 45,
+            MipsRelType::TLSGOTTPRel => 46,
+            MipsRelType::TLSTPRel32 => 47,
+            MipsRelType::TLSTPRel64 => 48,
+            MipsRelType::TLSTPRelHi16 => 49,
+            MipsRelType::TLSTPRelLo16 => 50,
+            MipsRelType::GlobData => 51,
+            MipsRelType::PC21S2 => 60,
+            MipsRelType::PC26S2 => 61,
+            MipsRelType::PC18S3 => 62,
+            MipsRelType::PC19S2 => 63,
+            MipsRelType::PCHi16 => 64,
+            MipsRelType::PCLo16 => 65,
+            MipsRelType::Copy => 126,
+            MipsRelType::JumpSlot => 127
+        }
+    }
+}
+
+impl TryFrom<u8> for MipsRelType {
+    type Error = u8;
+
+    #[inline]
+    fn try_from(kind: u8) -> Result<MipsRelType, u8> {
+        match kind {
+            0 => Ok(MipsRelType::None),
+            1 => Ok(MipsRelType::Abs16),
+            2 => Ok(MipsRelType::Abs32),
+            3 => Ok(MipsRelType::Rel32),
+            4 => Ok(MipsRelType::Jump26),
+            5 => Ok(MipsRelType::Hi16),
+            6 => Ok(MipsRelType::Lo16),
+            7 => Ok(MipsRelType::GPRel16),
+            8 => Ok(MipsRelType::Literal),
+            9 => Ok(MipsRelType::GOT16),
+            10 => Ok(MipsRelType::PC16),
+            11 => Ok(MipsRelType::Call16),
+            12 => Ok(MipsRelType::GPRel32),
+            16 => Ok(MipsRelType::Shift5),
+            17 => Ok(MipsRelType::Shift6),
+            18 => Ok(MipsRelType::Abs64),
+            19 => Ok(MipsRelType::GOTDisp),
+            20 => Ok(MipsRelType::GOTPage),
+            21 => Ok(MipsRelType::GOTOfst),
+            22 => Ok(MipsRelType::GOTHi16),
+            23 => Ok(MipsRelType::GOTLo16),
+            24 => Ok(MipsRelType::Sub),
+            25 => Ok(MipsRelType::InsertA),
+            26 => Ok(MipsRelType::InsertB),
+            27 => Ok(MipsRelType::Delete),
+            28 => Ok(MipsRelType::Higher),
+            29 => Ok(MipsRelType::Highest),
+            30 => Ok(MipsRelType::CallHi16),
+            31 => Ok(MipsRelType::CallLo16),
+            32 => Ok(MipsRelType::ScnDisp),
+            33 => Ok(MipsRelType::Rel16),
+            34 => Ok(MipsRelType::AddImmediate),
+            35 => Ok(MipsRelType::PJump),
+            36 => Ok(MipsRelType::RelGOT),
+            37 => Ok(MipsRelType::Jalr),
+            38 => Ok(MipsRelType::TLSDTPMod32),
+            39 => Ok(MipsRelType::TLSDTPRel32),
+            40 => Ok(MipsRelType::TLSDTPMod64),
+            41 => Ok(MipsRelType::TLSDTPRel64),
+            42 => Ok(MipsRelType::TLSGD),
+            43 => Ok(MipsRelType::TLSLDM),
+            44 => Ok(MipsRelType::TLSDTPRelHi16),
+            45 => Ok(MipsRelType::TLSDTPRelLo16),
+            46 => Ok(MipsRelType::TLSGOTTPRel),
+            47 => Ok(MipsRelType::TLSTPRel32),
+            48 => Ok(MipsRelType::TLSTPRel64),
+            49 => Ok(MipsRelType::TLSTPRelHi16),
+            50 => Ok(MipsRelType::TLSTPRelLo16),
+            51 => Ok(MipsRelType::GlobData),
+            60 => Ok(MipsRelType::PC21S2),
+            61 => Ok(MipsRelType::PC26S2),
+            62 => Ok(MipsRelType::PC18S3),
+            63 => Ok(MipsRelType::PC19S2),
+            64 => Ok(MipsRelType::PCHi16),
+            65 => Ok(MipsRelType::PCLo16),
+            126 => Ok(MipsRelType::Copy),
+            127 => Ok(MipsRelType::JumpSlot),
+            bad => Err(bad)
+        }
+    }
+}
+
+impl Display for MipsSpecialSym {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            MipsSpecialSym::Undef => write!(f, "undefined"),
+            MipsSpecialSym::GP => write!(f, "gp"),
+            MipsSpecialSym::GP0 => write!(f, "gp0"),
+            MipsSpecialSym::Loc => write!(f, "loc")
+        }
+    }
+}
+
+impl From<MipsSpecialSym> for u8 {
+    #[inline]
+    fn from(ssym: MipsSpecialSym) -> u8 {
+        match ssym {
+            MipsSpecialSym::Undef => 0,
+            MipsSpecialSym::GP => 1,
+            MipsSpecialSym::GP0 => 2,
+            MipsSpecialSym::Loc => 3
+        }
+    }
+}
+
+impl TryFrom<u8> for MipsSpecialSym {
+    type Error = u8;
+
+    #[inline]
+    fn try_from(ssym: u8) -> Result<MipsSpecialSym, u8> {
+        match ssym {
+            0 => Ok(MipsSpecialSym::Undef),
+            1 => Ok(MipsSpecialSym::GP),
+            2 => Ok(MipsSpecialSym::GP0),
+            3 => Ok(MipsSpecialSym::Loc),
+            bad => Err(bad)
+        }
+    }
+}
+
+impl<Name> Display for MipsReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let MipsReloc { offset, sym, kind, ssym, kind2, kind3, addend } = self;
+
+        write!(f, ".section[{}] <- {}(&{} + {})", offset, kind, sym, addend)?;
+
+        if *kind2 != MipsRelType::None || *ssym != MipsSpecialSym::Undef {
+            write!(f, " then {}({})", kind2, ssym)?;
+        }
+
+        if *kind3 != MipsRelType::None {
+            write!(f, " then {}", kind3)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for MipsRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            MipsRelocError::BadTag(tag) => write!(f, "bad tag value {}", tag),
+            MipsRelocError::BadSpecialSym(ssym) =>
+                write!(f, "bad special symbol value {}", ssym)
+        }
+    }
+}
+
+impl Display for MipsToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            MipsToRelError::BadAddend(addend) =>
+                write!(f, "bad addend value {}", addend),
+            MipsToRelError::BadOffset(offset) =>
+                write!(f, "bad offset value {}", offset),
+            MipsToRelError::Composed =>
+                write!(f, "composed relocation requires MIPS64")
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: Mips64RelKind,
+                    addend: i64) -> Result<MipsReloc<Name>, MipsRelocError> {
+    let Mips64RelKind { kind, ssym, kind2, kind3 } = kind;
+
+    match (MipsRelType::try_from(kind), MipsSpecialSym::try_from(ssym),
+           MipsRelType::try_from(kind2), MipsRelType::try_from(kind3)) {
+        (Ok(kind), Ok(ssym), Ok(kind2), Ok(kind3)) =>
+            Ok(MipsReloc { offset: offset, sym: sym, kind: kind, ssym: ssym,
+                           kind2: kind2, kind3: kind3, addend: addend }),
+        (Err(tag), _, _, _) => Err(MipsRelocError::BadTag(tag)),
+        (_, Err(ssym), _, _) => Err(MipsRelocError::BadSpecialSym(ssym)),
+        (_, _, Err(tag), _) => Err(MipsRelocError::BadTag(tag)),
+        (_, _, _, Err(tag)) => Err(MipsRelocError::BadTag(tag))
+    }
+}
+
+fn convert_to32<Name>(offset: u64, sym: Name, kind: u8, addend: i64) ->
+    Result<MipsReloc<Name>, MipsRelocError> {
+    let kind = Mips64RelKind { kind: kind, ssym: 0, kind2: 0, kind3: 0 };
+
+    convert_to(offset, sym, kind, addend)
+}
+
+fn convert_from(rel: MipsReloc<u32>) -> RelaData<u32, Mips64> {
+    let MipsReloc { offset, sym, kind, ssym, kind2, kind3, addend } = rel;
+    let kind = Mips64RelKind { kind: kind.into(), ssym: ssym.into(),
+                               kind2: kind2.into(), kind3: kind3.into() };
+
+    RelaData { offset: offset, sym: sym, kind: kind, addend: addend }
+}
+
+impl<Name> TryFrom<RelData<Name, Mips64>> for MipsReloc<Name> {
+    type Error = MipsRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Mips64>) -> Result<MipsReloc<Name>,
+                                                      Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<MipsReloc<u32>> for RelData<u32, Mips64> {
+    type Error = MipsToRelError;
+
+    #[inline]
+    fn try_from(rel: MipsReloc<u32>) -> Result<RelData<u32, Mips64>,
+                                               Self::Error> {
+        match convert_from(rel) {
+            RelaData { offset, sym, kind, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: kind }),
+            RelaData { addend, .. } => Err(MipsToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Mips64>> for MipsReloc<Name> {
+    type Error = MipsRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Mips64>) -> Result<MipsReloc<Name>,
+                                                        Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<MipsReloc<u32>> for RelaData<u32, Mips64> {
+    #[inline]
+    fn from(rel: MipsReloc<u32>) -> RelaData<u32, Mips64> {
+        convert_from(rel)
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for MipsReloc<Name> {
+    type Error = MipsRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) -> Result<MipsReloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to32(offset.into(), sym, kind, 0)
+    }
+}
+
+impl TryFrom<MipsReloc<u32>> for RelData<u32, Elf32> {
+    type Error = MipsToRelError;
+
+    #[inline]
+    fn try_from(rel: MipsReloc<u32>) -> Result<RelData<u32, Elf32>,
+                                               Self::Error> {
+        if rel.is_composed() {
+            Err(MipsToRelError::Composed)
+        } else {
+            match RelData::<u32, Mips64>::try_from(rel) {
+                Ok(RelData { offset, sym, kind }) => match offset.try_into() {
+                    Ok(offset) => Ok(RelData { offset: offset, sym: sym,
+                                               kind: kind.kind }),
+                    Err(_) => Err(MipsToRelError::BadOffset(offset))
+                },
+                Err(err) => Err(err)
+            }
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for MipsReloc<Name> {
+    type Error = MipsRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) -> Result<MipsReloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to32(offset.into(), sym, kind, addend.into())
+    }
+}
+
+impl TryFrom<MipsReloc<u32>> for RelaData<u32, Elf32> {
+    type Error = MipsToRelError;
+
+    #[inline]
+    fn try_from(rel: MipsReloc<u32>) -> Result<RelaData<u32, Elf32>,
+                                               Self::Error> {
+        if rel.is_composed() {
+            Err(MipsToRelError::Composed)
+        } else {
+            let RelaData { offset, sym, kind, addend } = convert_from(rel);
+
+            match (offset.try_into(), addend.try_into()) {
+                (Ok(offset), Ok(addend)) => Ok(RelaData { offset: offset,
+                                                          sym: sym,
+                                                          kind: kind.kind,
+                                                          addend: addend }),
+                (Err(_), _) => Err(MipsToRelError::BadOffset(offset)),
+                (_, Err(_)) => Err(MipsToRelError::BadAddend(addend))
+            }
+        }
+    }
+}
+
+impl<'a, B, Class> WithSymtab<'a, B, Class> for MipsRelocRaw
+    where Class: SymOffsets,
+          B: ByteOrder {
+    type Result = MipsRelocRawSym<Class>;
+    type Error = RelocSymtabError<Class>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Class>) ->
+        Result<Self::Result, Self::Error> {
+        let MipsReloc { offset, sym, kind, ssym, kind2, kind3, addend } = self;
+
+        match symtab.idx(sym as usize) {
+            Some(sym) => match sym.try_into() {
+                Ok(symdata) => {
+                    Ok(MipsReloc { offset: offset, sym: symdata, kind: kind,
+                                   ssym: ssym, kind2: kind2, kind3: kind3,
+                                   addend: addend })
+                },
+                Err(err) => Err(RelocSymtabError::SymError(err))
+            },
+            None => Err(RelocSymtabError::BadIdx(sym.into()))
+        }
+    }
+}
+
+impl<'a, Class> WithStrtab<'a> for MipsRelocRawSym<Class>
+    where Class: SymOffsets {
+    type Result = MipsRelocStrDataSym<'a, Class>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        let MipsReloc { offset, sym, kind, ssym, kind2, kind3, addend } = self;
+
+        match sym.with_strtab(strtab) {
+            Ok(symdata) => {
+                Ok(MipsReloc { offset: offset, sym: symdata, kind: kind,
+                               ssym: ssym, kind2: kind2, kind3: kind3,
+                               addend: addend })
+            },
+            Err(err) => Err(err.into())
+        }
+    }
+}
+
+impl<'a, Class> TryFrom<MipsRelocStrDataSym<'a, Class>>
+    for MipsRelocStrSym<'a, Class>
+    where Class: SymOffsets {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: MipsRelocStrDataSym<'a, Class>) ->
+        Result<MipsRelocStrSym<'a, Class>, Self::Error> {
+        let MipsReloc { offset, sym, kind, ssym, kind2, kind3, addend } = reloc;
+
+        match sym.try_into() {
+            Ok(symdata) => {
+                Ok(MipsReloc { offset: offset, sym: symdata, kind: kind,
+                               ssym: ssym, kind2: kind2, kind3: kind3,
+                               addend: addend })
+            },
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a, Class> From<MipsRelocStrDataSym<'a, Class>> for MipsRelocStrData<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: MipsRelocStrDataSym<'a, Class>) -> MipsRelocStrData<'a> {
+        let MipsReloc { sym: SymData { name, .. }, offset, kind, ssym,
+                        kind2, kind3, addend } = reloc;
+
+        MipsReloc { offset: offset, sym: name, kind: kind, ssym: ssym,
+                    kind2: kind2, kind3: kind3, addend: addend }
+    }
+}
+
+impl<'a> TryFrom<MipsRelocStrData<'a>> for MipsRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: MipsRelocStrData<'a>) ->
+        Result<MipsRelocStr<'a>, Self::Error> {
+        let MipsReloc { offset, sym, kind, ssym, kind2, kind3, addend } = reloc;
+
+        match sym {
+            Some(Ok(name)) =>
+                Ok(MipsReloc { offset: offset, sym: Some(name), kind: kind,
+                               ssym: ssym, kind2: kind2, kind3: kind3,
+                               addend: addend }),
+            Some(Err(err)) => Err(err),
+            None =>
+                Ok(MipsReloc { offset: offset, sym: None, kind: kind,
+                               ssym: ssym, kind2: kind2, kind3: kind3,
+                               addend: addend })
+        }
+    }
+}
+
+impl<'a, Class> From<MipsRelocStrSym<'a, Class>> for MipsRelocStr<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: MipsRelocStrSym<'a, Class>) -> MipsRelocStr<'a> {
+        let MipsReloc { sym: SymData { name, .. }, offset, kind, ssym,
+                        kind2, kind3, addend } = reloc;
+
+        MipsReloc { offset: offset, sym: name, kind: kind, ssym: ssym,
+                    kind2: kind2, kind3: kind3, addend: addend }
+    }
+}
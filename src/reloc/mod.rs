@@ -1,7 +1,8 @@
 //! ELF relocation table functionality.
 //!
 //! This module provides the [Rels] and [Relas] types which act as a
-//! wrapper around ELF relocation table data.
+//! wrapper around ELF relocation table data, as well as the [Relrs]
+//! type for compressed relative relocation tables (`SHT_RELR`).
 //!
 //! # Examples
 //!
@@ -105,6 +106,17 @@
 //!
 //! assert_eq!(data, RelaData { offset: 0x2c, sym: 36, kind: 2, addend: -4 });
 //! ```
+pub mod aarch64;
+pub mod android;
+pub mod apply;
+pub mod arm;
+pub mod loongarch;
+pub mod mips;
+pub mod ppc;
+pub mod ppc64;
+pub mod riscv;
+pub mod s390x;
+pub mod sparc;
 pub mod x86;
 pub mod x86_64;
 
@@ -131,6 +143,11 @@ use crate::symtab::WithSymtab;
 
 /// Extension to [ElfClass](crate::ElfClass) providing formatting
 /// information specific to relocations.
+///
+/// The layout of the relocation info field is usually determined by
+/// the ELF class alone.  Architectures that lay it out differently
+/// provide their own class type with its own kind tag type, such as
+/// [Mips64](mips::Mips64).
 pub trait RelClass: ElfClass {
     /// Type used to hold relocation kind tags.
     type RelKind: Copy + Display;
@@ -186,6 +203,30 @@ pub trait RelaOffsets: RelOffsets {
     const RELA_SIZE_OFFSET: Self::Offset;
 }
 
+/// Offsets for compressed relative relocation table entries.
+///
+/// A relative relocation table entry (`Elf_Relr`) is a single
+/// address-sized word.  Decoding and encoding them requires some
+/// arithmetic on these, which is carried out at 64-bit width.
+pub trait RelrOffsets: ElfClass {
+    /// Size of a relative relocation table entry.
+    const RELR_SIZE: usize = Self::ADDR_SIZE;
+    /// Number of relocations described by a bitmap entry.
+    const RELR_BITMAP_BITS: usize = (Self::RELR_SIZE * 8) - 1;
+
+    /// Read a relative relocation table entry.
+    fn read_relr<B: ByteOrder>(data: &[u8]) -> u64;
+
+    /// Write a relative relocation table entry.
+    fn write_relr<B: ByteOrder>(data: &mut [u8], val: u64);
+
+    /// Convert an offset into a value used in a table entry.
+    fn relr_from_offset(offset: Self::Offset) -> u64;
+
+    /// Convert a value from a table entry into an offset.
+    fn relr_to_offset(val: u64) -> Self::Offset;
+}
+
 /// In-place read-only ELF relocation table.
 ///
 /// An ELF relocation table is an array of entries describing
@@ -313,6 +354,63 @@ pub struct Relas<'a, B: ByteOrder, Offsets: RelaOffsets> {
     data: &'a [u8]
 }
 
+/// In-place read-only ELF compressed relative relocation table.
+///
+/// A relative relocation table (`SHT_RELR`, referenced by `DT_RELR`)
+/// is a compact encoding of the offsets of relative relocations
+/// (such as `R_X86_64_RELATIVE`), which make up the bulk of the
+/// relocations in a position-independent executable.  Each entry is
+/// an address-sized word.  An even entry is the offset of a
+/// relocation, and sets the base for the following entries.  An odd
+/// entry is a bitmap: each bit above the lowest one indicates a
+/// relocation at the corresponding word past the base, after which
+/// the base advances past all of the words the bitmap covers.  The
+/// addend for each relocation is held at its offset.
+///
+/// A `Relrs` is essentially a 'handle' for raw ELF data.  It can be
+/// iterated over with [iter](Relrs::iter), which produces the decoded
+/// relocation offsets.
+///
+/// A `Relrs` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance.
+///
+/// New `Relrs` can be created from a sorted iterator over offsets with
+/// [create](Relrs::create) or [create_split](Relrs::create_split).
+///
+/// # Examples
+///
+/// ```
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::Elf64;
+/// use elf_utils::reloc::Relrs;
+///
+/// const RELRS: [u8; 24] = [
+///     0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+/// const OFFSETS: [u64; 5] = [
+///     0x10000, 0x10008, 0x10010, 0x10020, 0x20000
+/// ];
+///
+/// let relrs: Relrs<'_, LittleEndian, Elf64> =
+///     Relrs::try_from(&RELRS[0..]).unwrap();
+/// let mut iter = relrs.iter();
+///
+/// for i in 0 .. 5 {
+///     assert_eq!(iter.next(), Some(OFFSETS[i]));
+/// }
+///
+/// assert!(iter.next().is_none());
+/// ```
+#[derive(Copy, Clone)]
+pub struct Relrs<'a, B: ByteOrder, Offsets: RelrOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a [u8]
+}
+
 /// In-place read-only ELF relocation entry.
 ///
 /// An ELF relocation entry describes adjustments to be made to a
@@ -529,6 +627,24 @@ pub struct RelaIter<'a, B: ByteOrder, Offsets: RelaOffsets> {
     idx: usize
 }
 
+/// Iterator for [Relrs].
+///
+/// This iterator produces the offsets of the relocations described by
+/// an underlying `Relrs`, in the order in which they are encoded.
+#[derive(Clone)]
+pub struct RelrIter<'a, B: ByteOrder, Offsets: RelrOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    data: &'a [u8],
+    idx: usize,
+    /// Offset following the last address entry or bitmap.
+    base: u64,
+    /// Remaining bits of the current bitmap.
+    bitmap: u64,
+    /// Offset corresponding to the lowest bit of `bitmap`.
+    pos: u64
+}
+
 /// Projected ELF relocation data.
 ///
 /// This is a representation of an ELF relocation projected into a form
@@ -662,6 +778,18 @@ pub enum RelasError {
     BadSize(usize)
 }
 
+/// Errors that can occur creating a [Relrs].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RelrsError {
+    /// Size is not a multiple of the size of an entry.
+    BadSize(usize),
+    /// The buffer was too short.
+    TooShort,
+    /// An offset is not aligned to the entry size, or is not greater
+    /// than the previous one.
+    BadOffset(u64)
+}
+
 /// Errors that can occur when converting a relocation using a [Symtab].
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum RelocSymtabError<Class: ElfClass> {
@@ -732,6 +860,62 @@ fn create_rels<'a, 'b, B, I, Offsets>(buf: &'a mut [u8], rels: I) ->
     Ok(buf.split_at_mut(idx))
 }
 
+fn encode_relrs<I, Offsets, F>(offsets: I, mut emit: F) ->
+    Result<(), RelrsError>
+    where I: Iterator,
+          I::Item: Borrow<Offsets::Offset>,
+          Offsets: RelrOffsets,
+          F: FnMut(u64) -> Result<(), RelrsError> {
+    let size = Offsets::RELR_SIZE as u64;
+    let bits = Offsets::RELR_BITMAP_BITS as u64;
+    // Offset following the last address entry or bitmap, if there
+    // has been an address entry.
+    let mut base: Option<u64> = None;
+    let mut bitmap: u64 = 0;
+    let mut prev: Option<u64> = None;
+
+    for offset in offsets {
+        let offset = Offsets::relr_from_offset(*offset.borrow());
+
+        match prev {
+            Some(prev) if offset <= prev => {
+                return Err(RelrsError::BadOffset(offset))
+            },
+            _ if offset % size != 0 => {
+                return Err(RelrsError::BadOffset(offset))
+            },
+            _ => prev = Some(offset)
+        }
+
+        loop {
+            match base {
+                Some(next) if (offset - next) / size < bits => {
+                    bitmap |= 1 << ((offset - next) / size);
+
+                    break;
+                },
+                Some(next) if bitmap != 0 => {
+                    emit((bitmap << 1) | 1)?;
+                    base = Some(next.wrapping_add(bits * size));
+                    bitmap = 0;
+                },
+                _ => {
+                    emit(offset)?;
+                    base = Some(offset.wrapping_add(size));
+
+                    break;
+                }
+            }
+        }
+    }
+
+    if bitmap != 0 {
+        emit((bitmap << 1) | 1)?;
+    }
+
+    Ok(())
+}
+
 impl RelClass for Elf32 {
     type RelKind = u8;
 
@@ -788,6 +972,50 @@ impl RelaOffsets for Elf64 {
     const RELA_SIZE_OFFSET: Self::Offset = Self::RELA_SIZE as u64;
 }
 
+impl RelrOffsets for Elf32 {
+    #[inline]
+    fn read_relr<B: ByteOrder>(data: &[u8]) -> u64 {
+        B::read_u32(data) as u64
+    }
+
+    #[inline]
+    fn write_relr<B: ByteOrder>(data: &mut [u8], val: u64) {
+        B::write_u32(data, val as u32)
+    }
+
+    #[inline]
+    fn relr_from_offset(offset: Self::Offset) -> u64 {
+        offset as u64
+    }
+
+    #[inline]
+    fn relr_to_offset(val: u64) -> Self::Offset {
+        val as u32
+    }
+}
+
+impl RelrOffsets for Elf64 {
+    #[inline]
+    fn read_relr<B: ByteOrder>(data: &[u8]) -> u64 {
+        B::read_u64(data)
+    }
+
+    #[inline]
+    fn write_relr<B: ByteOrder>(data: &mut [u8], val: u64) {
+        B::write_u64(data, val)
+    }
+
+    #[inline]
+    fn relr_from_offset(offset: Self::Offset) -> u64 {
+        offset
+    }
+
+    #[inline]
+    fn relr_to_offset(val: u64) -> Self::Offset {
+        val
+    }
+}
+
 /// Calculate the number of bytes required to represent the relocation
 /// table containing `rels`.
 ///
@@ -849,6 +1077,42 @@ pub fn relas_required_bytes<'b, I, Offsets>(relas: I) -> usize
     relas.count() * Offsets::RELA_SIZE
 }
 
+/// Calculate the number of bytes required to represent the relative
+/// relocation table containing the relocations at `offsets`.
+///
+/// # Errors
+///
+/// An error will be returned if the offsets are not in ascending
+/// order, or are not aligned to the size of an entry.
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::Elf64;
+/// use elf_utils::reloc;
+///
+/// const OFFSETS: [u64; 5] = [
+///     0x10000, 0x10008, 0x10010, 0x10020, 0x20000
+/// ];
+///
+/// assert_eq!(reloc::relrs_required_bytes::<_, Elf64>(OFFSETS.iter()),
+///            Ok(24));
+/// ```
+pub fn relrs_required_bytes<I, Offsets>(offsets: I) -> Result<usize, RelrsError>
+    where I: Iterator,
+          I::Item: Borrow<Offsets::Offset>,
+          Offsets: RelrOffsets {
+    let mut size = 0;
+
+    encode_relrs::<I, Offsets, _>(offsets, |_| {
+        size += Offsets::RELR_SIZE;
+
+        Ok(())
+    })?;
+
+    Ok(size)
+}
+
 impl<'a, B, Offsets> Relas<'a, B, Offsets>
     where Offsets: RelaOffsets,
           B: ByteOrder {
@@ -1160,6 +1424,133 @@ impl<'a, B, Offsets> Rels<'a, B, Offsets>
     }
 }
 
+impl<'a, B, Offsets> Relrs<'a, B, Offsets>
+    where Offsets: RelrOffsets,
+          B: ByteOrder {
+    /// Attempt to create a `Relrs` in `buf` containing the relative
+    /// relocations at `offsets`.
+    ///
+    /// This will encode the offsets into address and bitmap entries,
+    /// and write them into the buffer in the proper format for the ELF
+    /// class and byte order.  Returns both the `Relrs` and the
+    /// remaining space if successful.
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the offsets are not in ascending
+    /// order, are not aligned to the size of an entry, or if the
+    /// table doesn't fit into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::reloc::Relrs;
+    /// use elf_utils::reloc::RelrsError;
+    ///
+    /// const OFFSETS: [u64; 5] = [
+    ///     0x10000, 0x10008, 0x10010, 0x10020, 0x20000
+    /// ];
+    ///
+    /// let mut buf = [0; 32];
+    /// let res: Result<(Relrs<'_, LittleEndian, Elf64>,
+    ///                  &'_ mut [u8]), RelrsError> =
+    ///     Relrs::create_split(&mut buf[0..], OFFSETS.iter());
+    /// let (relrs, rest) = res.unwrap();
+    ///
+    /// assert_eq!(rest.len(), 8);
+    /// assert_eq!(relrs.num_entries(), 3);
+    ///
+    /// let mut iter = relrs.iter();
+    ///
+    /// for i in 0 .. 5 {
+    ///     assert_eq!(iter.next(), Some(OFFSETS[i]));
+    /// }
+    ///
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn create_split<I>(buf: &'a mut [u8], offsets: I) ->
+        Result<(Self, &'a mut [u8]), RelrsError>
+        where I: Iterator,
+              I::Item: Borrow<Offsets::Offset> {
+        let len = buf.len();
+        let mut idx = 0;
+
+        encode_relrs::<I, Offsets, _>(offsets, |val| {
+            if idx + Offsets::RELR_SIZE <= len {
+                Offsets::write_relr::<B>(&mut buf[idx .. idx +
+                                                        Offsets::RELR_SIZE],
+                                         val);
+                idx += Offsets::RELR_SIZE;
+
+                Ok(())
+            } else {
+                Err(RelrsError::TooShort)
+            }
+        })?;
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((Relrs { byteorder: PhantomData, offsets: PhantomData,
+                    data: data }, rest))
+    }
+
+    /// Attempt to create a `Relrs` in `buf` containing the relative
+    /// relocations at `offsets` (see [create_split](Relrs::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// An error will be returned if the offsets are not in ascending
+    /// order, are not aligned to the size of an entry, or if the
+    /// table doesn't fit into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], offsets: I) -> Result<Self, RelrsError>
+        where I: Iterator,
+              I::Item: Borrow<Offsets::Offset> {
+        match Self::create_split(buf, offsets) {
+            Ok((out, _)) => Ok(out),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the number of entries in this `Relrs`.
+    ///
+    /// This is the number of address and bitmap entries in the
+    /// table, not the number of relocations they describe (see
+    /// [num_relocs](Relrs::num_relocs)).
+    #[inline]
+    pub fn num_entries(&self) -> usize {
+        self.data.len() / Offsets::RELR_SIZE
+    }
+
+    /// Get the number of relocations described by this `Relrs`.
+    pub fn num_relocs(&self) -> usize {
+        let mut count = 0;
+
+        for i in 0 .. self.num_entries() {
+            let start = i * Offsets::RELR_SIZE;
+            let end = start + Offsets::RELR_SIZE;
+            let ent = Offsets::read_relr::<B>(&self.data[start .. end]);
+
+            if ent & 1 == 0 {
+                count += 1;
+            } else {
+                count += (ent >> 1).count_ones() as usize;
+            }
+        }
+
+        count
+    }
+
+    /// Get an iterator over the relocation offsets in this `Relrs`.
+    #[inline]
+    pub fn iter(&self) -> RelrIter<'a, B, Offsets> {
+        RelrIter { byteorder: PhantomData, offsets: PhantomData,
+                   data: self.data, idx: 0, base: 0, bitmap: 0, pos: 0 }
+    }
+}
+
 impl<'a, B, Offsets: RelOffsets> TryFrom<&'a [u8]> for Rels<'a, B, Offsets>
     where B: ByteOrder {
     type Error = RelsError;
@@ -1240,6 +1631,40 @@ impl<'a, B, Offsets: RelaOffsets> TryFrom<&'a mut [u8]>
     }
 }
 
+impl<'a, B, Offsets: RelrOffsets> TryFrom<&'a [u8]>
+    for Relrs<'a, B, Offsets>
+    where B: ByteOrder {
+    type Error = RelrsError;
+
+    /// Create a `Relrs` from the data buffer.  This will check that
+    /// the data buffer is a multiple of the entry size.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<Relrs<'a, B, Offsets>, Self::Error> {
+        let len = data.len();
+
+        if data.len() % Offsets::RELR_SIZE == 0 {
+            Ok(Relrs { byteorder: PhantomData, offsets: PhantomData,
+                       data: data })
+        } else {
+            Err(RelrsError::BadSize(len))
+        }
+    }
+}
+
+impl<'a, B, Offsets: RelrOffsets> TryFrom<&'a mut [u8]>
+    for Relrs<'a, B, Offsets>
+    where B: ByteOrder {
+    type Error = RelrsError;
+
+    /// Create a `Relrs` from the data buffer.  This will check that
+    /// the data buffer is a multiple of the entry size.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<Relrs<'a, B, Offsets>,
+                                              Self::Error> {
+        Relrs::try_from(&*data)
+    }
+}
+
 impl<'a, B, Offsets> From<Rel<'a, B, Offsets>>
     for RelData<Offsets::Word, Offsets>
     where Offsets: RelOffsets,
@@ -1604,6 +2029,52 @@ impl<'a, B, Offsets: RelaOffsets> ExactSizeIterator for RelaIter<'a, B, Offsets>
     }
 }
 
+impl<'a, B, Offsets: RelrOffsets> Iterator for RelrIter<'a, B, Offsets>
+    where B: ByteOrder {
+    type Item = Offsets::Offset;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = Offsets::RELR_SIZE as u64;
+        let bits = Offsets::RELR_BITMAP_BITS as u64;
+
+        loop {
+            if self.bitmap != 0 {
+                let skip = self.bitmap.trailing_zeros() as u64;
+                let offset = self.pos.wrapping_add(skip * size);
+
+                self.bitmap = (self.bitmap >> skip) >> 1;
+                self.pos = offset.wrapping_add(size);
+
+                return Some(Offsets::relr_to_offset(offset))
+            }
+
+            let start = self.idx * Offsets::RELR_SIZE;
+
+            if start >= self.data.len() {
+                return None
+            }
+
+            let end = start + Offsets::RELR_SIZE;
+            let ent = Offsets::read_relr::<B>(&self.data[start .. end]);
+
+            self.idx += 1;
+
+            if ent & 1 == 0 {
+                self.base = ent.wrapping_add(size);
+
+                return Some(Offsets::relr_to_offset(ent))
+            } else {
+                self.bitmap = ent >> 1;
+                self.pos = self.base;
+                self.base = self.base.wrapping_add(bits * size);
+            }
+        }
+    }
+}
+
+impl<'a, B, Offsets: RelrOffsets> FusedIterator for RelrIter<'a, B, Offsets>
+    where B: ByteOrder {}
+
 impl Display for RelsError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
@@ -1621,3 +2092,15 @@ impl Display for RelasError {
         }
     }
 }
+
+impl Display for RelrsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            RelrsError::BadSize(size) =>
+                write!(f, "bad relative relocation table size {}", size),
+            RelrsError::TooShort => write!(f, "buffer too short"),
+            RelrsError::BadOffset(offset) =>
+                write!(f, "bad relative relocation offset {:x}", offset)
+        }
+    }
+}
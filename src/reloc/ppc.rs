@@ -0,0 +1,4854 @@
+//! Relocation types for 32-bit PowerPC architecture.
+//!
+//! This module provides the `PpcReloc` type, which describes the
+//! relocation entries for the 32-bit PowerPC architecture, as defined
+//! by the PowerPC ELF ABI.  These can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) as
+//! the [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf32;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for 32-bit PowerPC architectures.
+///
+/// This datatype provides a semantic-level presentation of the
+/// PowerPC relocation entries.  These can be converted to and from
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) as
+/// the [ElfClass](crate::ElfClass) type argument using the
+/// [TryFrom](core::convert::TryFrom) instances for easier handling.
+///
+/// The 16-bit relocations come in families that select different
+/// parts of the value: the `Lo` variants take the low 16 bits, the
+/// `Hi` variants take the high 16 bits, and the `HA` variants take
+/// the high 16 bits adjusted so that adding the sign-extended `Lo`
+/// part produces the full value.  PowerPC is bi-endian; the
+/// [WithSymtab](crate::symtab::WithSymtab) instance works with any
+/// byte order.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum PpcReloc<Name> {
+    /// Null relocation.
+    None,
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Addr32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Absolute 24-bit branch.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to
+    /// `sym + addend`, shifted right by 2.
+    Addr24 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to `sym + addend`, checking
+    /// for overflow.
+    Addr16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of
+    /// `sym + addend`.
+    Addr16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of
+    /// `sym + addend`.
+    Addr16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    Addr16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Absolute 14-bit conditional branch.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to `sym + addend`, shifted right by 2.
+    Addr14 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Absolute 14-bit conditional branch, predicted taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to `sym + addend`, shifted right by 2.  Also set the
+    /// branch prediction bit to indicate that the branch is likely
+    /// taken.
+    Addr14BrTaken {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Absolute 14-bit conditional branch, predicted not taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to `sym + addend`, shifted right by 2.  Also set the
+    /// branch prediction bit to indicate that the branch is likely
+    /// not taken.
+    Addr14BrNTaken {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative 24-bit branch.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to the
+    /// relative address of `sym + addend`, shifted right by 2.
+    Rel24 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative 14-bit conditional branch.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to the relative address of `sym + addend`, shifted
+    /// right by 2.
+    Rel14 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative 14-bit conditional branch, predicted taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to the relative address of `sym + addend`, shifted
+    /// right by 2.  Also set the branch prediction bit to indicate
+    /// that the branch is likely taken.
+    Rel14BrTaken {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative 14-bit conditional branch, predicted not taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to the relative address of `sym + addend`, shifted
+    /// right by 2.  Also set the branch prediction bit to indicate
+    /// that the branch is likely not taken.
+    Rel14BrNTaken {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the Global
+    /// Offset Table base of the entry for `sym + addend`, checking
+    /// for overflow.
+    GOT16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the Global Offset Table base of the entry for
+    /// `sym + addend`.
+    GOT16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the entry for
+    /// `sym + addend`.
+    GOT16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the entry for
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    GOT16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative 24-bit branch through the PLT.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to the
+    /// relative address of the Procedure Linkage Table entry for
+    /// `sym + addend`, shifted right by 2.
+    PLTRel24 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Global Offset Table entry fill.
+    ///
+    /// Set a Global Offset Table entry to the address of
+    /// `sym + addend`.
+    GlobalData {
+        /// Offset in the GOT.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the PLT.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit offset relative to the image base.
+    ///
+    /// Set the 4-byte word at `offset` to the sum of the base address
+    /// and `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u32,
+        /// The addend argument.
+        addend: i32
+    },
+    /// PC-relative 24-bit branch to a local symbol.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to the
+    /// relative address of the local symbol `sym + addend`, shifted
+    /// right by 2.
+    Local24PC {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Unaligned 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset`, which need not be aligned, to
+    /// `sym + addend`.
+    UAddr32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Unaligned 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset`, which need not be aligned, to
+    /// `sym + addend`.
+    UAddr16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend`.
+    Rel32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit absolute PLT entry address.
+    ///
+    /// Set the 4-byte word at `offset` to the address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLT32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit PC-relative PLT entry address.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLTRel32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a PLT entry address.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`.
+    PLT16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a PLT entry address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`.
+    PLT16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a PLT entry address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    PLT16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit small data area offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the base of the small data area, checking
+    /// for overflow.
+    SDARel16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit section offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the start of its section, checking for
+    /// overflow.
+    SectOff {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a section offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the start of its section.
+    SectOffLo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a section offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the start of its section.
+    SectOffHi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a section offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the start of its section,
+    /// adjusted to account for the sign extension of the low 16 bits.
+    SectOffHA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thread-local access marker.
+    ///
+    /// Marks the instruction at `offset` as the thread-local access
+    /// to `sym + addend` in an initial execution sequence, so that
+    /// the linker can optimize the access.  The instruction is not
+    /// modified.
+    TLS {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Thread-local storage module index.
+    ///
+    /// Set the 4-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    DTPMod32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 16-bit thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the thread pointer, checking for overflow.
+    TPRel16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the thread pointer.
+    TPRel16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the thread pointer.
+    TPRel16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the thread pointer, adjusted to
+    /// account for the sign extension of the low 16 bits.
+    TPRel16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TPRel32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block, checking for
+    /// overflow.
+    DTPRel16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    DTPRel16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    DTPRel16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` in its thread-local storage block,
+    /// adjusted to account for the sign extension of the low 16 bits.
+    DTPRel16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 32-bit thread-local storage block offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    DTPRel32 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit general dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the Global
+    /// Offset Table base of the pair of Global Offset Table entries
+    /// holding the TLS index for `sym + addend`, checking for
+    /// overflow.
+    GOTTLSGD16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a general dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the Global Offset Table base of the pair of Global
+    /// Offset Table entries holding the TLS index for `sym + addend`.
+    GOTTLSGD16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a general dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the pair of Global
+    /// Offset Table entries holding the TLS index for `sym + addend`.
+    GOTTLSGD16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a general dynamic TLS GOT entry
+    /// offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the pair of Global
+    /// Offset Table entries holding the TLS index for `sym + addend`,
+    /// adjusted to account for the sign extension of the low 16 bits.
+    GOTTLSGD16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the Global
+    /// Offset Table base of the pair of Global Offset Table entries
+    /// holding the TLS index for the module containing `sym`,
+    /// checking for overflow.
+    GOTTLSLD16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the Global Offset Table base of the pair of Global
+    /// Offset Table entries holding the TLS index for the module
+    /// containing `sym`.
+    GOTTLSLD16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the pair of Global
+    /// Offset Table entries holding the TLS index for the module
+    /// containing `sym`.
+    GOTTLSLD16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the pair of Global
+    /// Offset Table entries holding the TLS index for the module
+    /// containing `sym`, adjusted to account for the sign extension
+    /// of the low 16 bits.
+    GOTTLSLD16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit initial execution TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the Global
+    /// Offset Table base of the Global Offset Table entry holding the
+    /// thread pointer offset of `sym + addend`, checking for
+    /// overflow.
+    GOTTPRel16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of an initial execution TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the Global Offset Table base of the Global Offset
+    /// Table entry holding the thread pointer offset of
+    /// `sym + addend`.
+    GOTTPRel16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of an initial execution TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the Global Offset
+    /// Table entry holding the thread pointer offset of
+    /// `sym + addend`.
+    GOTTPRel16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of an initial execution TLS GOT entry
+    /// offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the Global Offset
+    /// Table entry holding the thread pointer offset of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    GOTTPRel16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit TLS block offset GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the Global
+    /// Offset Table base of the Global Offset Table entry holding the
+    /// thread-local storage block offset of `sym + addend`, checking
+    /// for overflow.
+    GOTDTPRel16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a TLS block offset GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the Global Offset Table base of the Global Offset
+    /// Table entry holding the thread-local storage block offset of
+    /// `sym + addend`.
+    GOTDTPRel16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a TLS block offset GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the Global Offset
+    /// Table entry holding the thread-local storage block offset of
+    /// `sym + addend`.
+    GOTDTPRel16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a TLS block offset GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the Global Offset Table base of the Global Offset
+    /// Table entry holding the thread-local storage block offset of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    GOTDTPRel16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// General dynamic TLS call marker.
+    ///
+    /// Marks the call to `__tls_get_addr` at `offset` as part of a
+    /// general dynamic access sequence for `sym + addend`, so that
+    /// the linker can optimize the access.  The instruction is not
+    /// modified.
+    TLSGD {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Local dynamic TLS call marker.
+    ///
+    /// Marks the call to `__tls_get_addr` at `offset` as part of a
+    /// local dynamic access sequence for `sym + addend`, so that the
+    /// linker can optimize the access.  The instruction is not
+    /// modified.
+    TLSLD {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Indirect relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the result of calling the
+    /// resolver function at the sum of the base address and `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u32,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to the relative address of
+    /// `sym + addend`, checking for overflow.
+    Rel16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Low 16 bits of a PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// relative address of `sym + addend`.
+    Rel16Lo {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// High 16 bits of a PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// relative address of `sym + addend`.
+    Rel16Hi {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// Adjusted high 16 bits of a PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// relative address of `sym + addend`, adjusted to account for
+    /// the sign extension of the low 16 bits.
+    Rel16HA {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    },
+    /// 16-bit TOC-relative offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the TOC base, checking for overflow.
+    TOC16 {
+        /// Offset in the section.
+        offset: u32,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i32
+    }
+}
+
+/// Type synonym for [PpcReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type PpcRelocRaw = PpcReloc<u32>;
+
+/// Type synonym for [PpcReloc] with [SymDataRaw] as the symbol type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [PpcRelocRaw].
+pub type PpcRelocRawSym = PpcReloc<SymDataRaw<Elf32>>;
+
+/// Type synonym for [PpcReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [PpcRelocRawSym].
+pub type PpcRelocStrDataSym<'a> = PpcReloc<SymDataStrData<'a, Elf32>>;
+
+/// Type synonym for [PpcReloc] with [SymDataStr] as the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [PpcRelocStrDataSym].
+pub type PpcRelocStrData<'a> =
+    PpcReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [PpcReloc] with UTF-8 decoded string data as the
+/// symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [PpcRelocStrDataSym].
+pub type PpcRelocStrSym<'a> = PpcReloc<SymDataStr<'a, Elf32>>;
+
+/// Type synonym for [PpcReloc] with a `&'a str`s as the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [PpcRelocStrSym].
+pub type PpcRelocStr<'a> = PpcReloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [PpcReloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// At present, this can only happen with a non-zero addend.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PpcToRelError {
+    /// Non-zero addend.
+    BadAddend(i32)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [PpcReloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum PpcRelocError {
+    /// Unknown tag value.
+    BadTag(u8)
+}
+
+impl<Name> Display for PpcReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            PpcReloc::None => write!(f, "none"),
+            PpcReloc::Addr32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::Addr24 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::Addr16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::Addr16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::Addr16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::Addr16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::Addr14 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::Addr14BrTaken { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::Addr14BrNTaken { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::Rel24 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::Rel14 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::Rel14BrTaken { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::Rel14BrNTaken { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::GOT16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[{}] + {} - &.got",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOT16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&.got[{}] + {} - &.got)",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOT16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&.got[{}] + {} - &.got)",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOT16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&.got[{}] + {} - &.got)",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::PLTRel24 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            PpcReloc::GlobalData { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::Relative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 4, addend),
+            PpcReloc::Local24PC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::UAddr32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::UAddr16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::Rel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::PLT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::PLTRel32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            PpcReloc::PLT16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::PLT16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::PLT16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::SDARel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &_SDA_BASE_",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::SectOff { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- sectoff({}) + {}",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::SectOffLo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::SectOffHi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::SectOffHA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::TLS { offset, sym, addend } =>
+                write!(f, "tls access to {} + {} at .section[{}]",
+                       sym, addend, offset),
+            PpcReloc::DTPMod32 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 4, sym),
+            PpcReloc::TPRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::TPRel16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::TPRel16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::TPRel16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::TPRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {})",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::DTPRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::DTPRel16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::DTPRel16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::DTPRel16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::DTPRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {})",
+                       offset, offset + 4, sym, addend),
+            PpcReloc::GOTTLSGD16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tlsgd(&{} + {})] - &.got",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo(&.got[tlsgd(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[tlsgd(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSGD16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[tlsgd(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSLD16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tlsld(&{} + {})] - &.got",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo(&.got[tlsld(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[tlsld(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTLSLD16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[tlsld(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTPRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tprel(&{} + {})] - &.got",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTPRel16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo(&.got[tprel(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTPRel16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[tprel(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTTPRel16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[tprel(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTDTPRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[dtprel(&{} + {})] - &.got",
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTDTPRel16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo(&.got[dtprel(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[dtprel(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::GOTDTPRel16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[dtprel(&{} + ",
+                                  "{})] - &.got)"),
+                       offset, offset + 2, sym, addend),
+            PpcReloc::TLSGD { offset, sym, addend } =>
+                write!(f, concat!("tls general dynamic call for {} + {} at ",
+                                  ".section[{}]"),
+                       sym, addend, offset),
+            PpcReloc::TLSLD { offset, sym, addend } =>
+                write!(f, "tls local dynamic call for {} + {} at .section[{}]",
+                       sym, addend, offset),
+            PpcReloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- (&base + {})()",
+                       offset, offset + 4, addend),
+            PpcReloc::Rel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            PpcReloc::Rel16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo((&{} + {}) - ",
+                                  "(&.section + {}))"),
+                       offset, offset + 2, sym, addend, offset),
+            PpcReloc::Rel16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi((&{} + {}) - ",
+                                  "(&.section + {}))"),
+                       offset, offset + 2, sym, addend, offset),
+            PpcReloc::Rel16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha((&{} + {}) - ",
+                                  "(&.section + {}))"),
+                       offset, offset + 2, sym, addend, offset),
+            PpcReloc::TOC16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.TOC.",
+                       offset, offset + 2, sym, addend)
+        }
+    }
+}
+
+impl Display for PpcRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            PpcRelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for PpcToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            PpcToRelError::BadAddend(addend) =>
+                write!(f, "non-zero addend value {}", addend)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u32, sym: Name, kind: u8, addend: i32) ->
+    Result<PpcReloc<Name>, PpcRelocError> {
+    match kind {
+        0 => Ok(PpcReloc::None),
+        1 => Ok(PpcReloc::Addr32 { offset: offset, sym: sym, addend: addend }),
+        2 => Ok(PpcReloc::Addr24 { offset: offset, sym: sym, addend: addend }),
+        3 => Ok(PpcReloc::Addr16 { offset: offset, sym: sym, addend: addend }),
+        4 => Ok(PpcReloc::Addr16Lo { offset: offset, sym: sym,
+                                     addend: addend }),
+        5 => Ok(PpcReloc::Addr16Hi { offset: offset, sym: sym,
+                                     addend: addend }),
+        6 => Ok(PpcReloc::Addr16HA { offset: offset, sym: sym,
+                                     addend: addend }),
+        7 => Ok(PpcReloc::Addr14 { offset: offset, sym: sym, addend: addend }),
+        8 => Ok(PpcReloc::Addr14BrTaken { offset: offset, sym: sym,
+                                          addend: addend }),
+        9 => Ok(PpcReloc::Addr14BrNTaken { offset: offset, sym: sym,
+                                           addend: addend }),
+        10 => Ok(PpcReloc::Rel24 { offset: offset, sym: sym, addend: addend }),
+        11 => Ok(PpcReloc::Rel14 { offset: offset, sym: sym, addend: addend }),
+        12 => Ok(PpcReloc::Rel14BrTaken { offset: offset, sym: sym,
+                                          addend: addend }),
+        13 => Ok(PpcReloc::Rel14BrNTaken { offset: offset, sym: sym,
+                                           addend: addend }),
+        14 => Ok(PpcReloc::GOT16 { offset: offset, sym: sym, addend: addend }),
+        15 => Ok(PpcReloc::GOT16Lo { offset: offset, sym: sym,
+                                     addend: addend }),
+        16 => Ok(PpcReloc::GOT16Hi { offset: offset, sym: sym,
+                                     addend: addend }),
+        17 => Ok(PpcReloc::GOT16HA { offset: offset, sym: sym,
+                                     addend: addend }),
+        18 => Ok(PpcReloc::PLTRel24 { offset: offset, sym: sym,
+                                      addend: addend }),
+        19 => Ok(PpcReloc::Copy { offset: offset, sym: sym }),
+        20 => Ok(PpcReloc::GlobalData { offset: offset, sym: sym,
+                                        addend: addend }),
+        21 => Ok(PpcReloc::JumpSlot { offset: offset, sym: sym,
+                                      addend: addend }),
+        22 => Ok(PpcReloc::Relative { offset: offset, addend: addend }),
+        23 => Ok(PpcReloc::Local24PC { offset: offset, sym: sym,
+                                       addend: addend }),
+        24 => Ok(PpcReloc::UAddr32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        25 => Ok(PpcReloc::UAddr16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        26 => Ok(PpcReloc::Rel32 { offset: offset, sym: sym, addend: addend }),
+        27 => Ok(PpcReloc::PLT32 { offset: offset, sym: sym, addend: addend }),
+        28 => Ok(PpcReloc::PLTRel32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        29 => Ok(PpcReloc::PLT16Lo { offset: offset, sym: sym,
+                                     addend: addend }),
+        30 => Ok(PpcReloc::PLT16Hi { offset: offset, sym: sym,
+                                     addend: addend }),
+        31 => Ok(PpcReloc::PLT16HA { offset: offset, sym: sym,
+                                     addend: addend }),
+        32 => Ok(PpcReloc::SDARel16 { offset: offset, sym: sym,
+                                      addend: addend }),
+        33 => Ok(PpcReloc::SectOff { offset: offset, sym: sym,
+                                     addend: addend }),
+        34 => Ok(PpcReloc::SectOffLo { offset: offset, sym: sym,
+                                       addend: addend }),
+        35 => Ok(PpcReloc::SectOffHi { offset: offset, sym: sym,
+                                       addend: addend }),
+        36 => Ok(PpcReloc::SectOffHA { offset: offset, sym: sym,
+                                       addend: addend }),
+        67 => Ok(PpcReloc::TLS { offset: offset, sym: sym, addend: addend }),
+        68 => Ok(PpcReloc::DTPMod32 { offset: offset, sym: sym }),
+        69 => Ok(PpcReloc::TPRel16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        70 => Ok(PpcReloc::TPRel16Lo { offset: offset, sym: sym,
+                                       addend: addend }),
+        71 => Ok(PpcReloc::TPRel16Hi { offset: offset, sym: sym,
+                                       addend: addend }),
+        72 => Ok(PpcReloc::TPRel16HA { offset: offset, sym: sym,
+                                       addend: addend }),
+        73 => Ok(PpcReloc::TPRel32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        74 => Ok(PpcReloc::DTPRel16 { offset: offset, sym: sym,
+                                      addend: addend }),
+        75 => Ok(PpcReloc::DTPRel16Lo { offset: offset, sym: sym,
+                                        addend: addend }),
+        76 => Ok(PpcReloc::DTPRel16Hi { offset: offset, sym: sym,
+                                        addend: addend }),
+        77 => Ok(PpcReloc::DTPRel16HA { offset: offset, sym: sym,
+                                        addend: addend }),
+        78 => Ok(PpcReloc::DTPRel32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        79 => Ok(PpcReloc::GOTTLSGD16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        80 => Ok(PpcReloc::GOTTLSGD16Lo { offset: offset, sym: sym,
+                                          addend: addend }),
+        81 => Ok(PpcReloc::GOTTLSGD16Hi { offset: offset, sym: sym,
+                                          addend: addend }),
+        82 => Ok(PpcReloc::GOTTLSGD16HA { offset: offset, sym: sym,
+                                          addend: addend }),
+        83 => Ok(PpcReloc::GOTTLSLD16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        84 => Ok(PpcReloc::GOTTLSLD16Lo { offset: offset, sym: sym,
+                                          addend: addend }),
+        85 => Ok(PpcReloc::GOTTLSLD16Hi { offset: offset, sym: sym,
+                                          addend: addend }),
+        86 => Ok(PpcReloc::GOTTLSLD16HA { offset: offset, sym: sym,
+                                          addend: addend }),
+        87 => Ok(PpcReloc::GOTTPRel16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        88 => Ok(PpcReloc::GOTTPRel16Lo { offset: offset, sym: sym,
+                                          addend: addend }),
+        89 => Ok(PpcReloc::GOTTPRel16Hi { offset: offset, sym: sym,
+                                          addend: addend }),
+        90 => Ok(PpcReloc::GOTTPRel16HA { offset: offset, sym: sym,
+                                          addend: addend }),
+        91 => Ok(PpcReloc::GOTDTPRel16 { offset: offset, sym: sym,
+                                         addend: addend }),
+        92 => Ok(PpcReloc::GOTDTPRel16Lo { offset: offset, sym: sym,
+                                           addend: addend }),
+        93 => Ok(PpcReloc::GOTDTPRel16Hi { offset: offset, sym: sym,
+                                           addend: addend }),
+        94 => Ok(PpcReloc::GOTDTPRel16HA { offset: offset, sym: sym,
+                                           addend: addend }),
+        95 => Ok(PpcReloc::TLSGD { offset: offset, sym: sym, addend: addend }),
+        96 => Ok(PpcReloc::TLSLD { offset: offset, sym: sym, addend: addend }),
+        248 => Ok(PpcReloc::IRelative { offset: offset, addend: addend }),
+        249 => Ok(PpcReloc::Rel16 { offset: offset, sym: sym, addend: addend }),
+        250 => Ok(PpcReloc::Rel16Lo { offset: offset, sym: sym,
+                                      addend: addend }),
+        251 => Ok(PpcReloc::Rel16Hi { offset: offset, sym: sym,
+                                      addend: addend }),
+        252 => Ok(PpcReloc::Rel16HA { offset: offset, sym: sym,
+                                      addend: addend }),
+        255 => Ok(PpcReloc::TOC16 { offset: offset, sym: sym, addend: addend }),
+        tag => Err(PpcRelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for PpcReloc<Name> {
+    type Error = PpcRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) -> Result<PpcReloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<PpcReloc<u32>> for RelData<u32, Elf32> {
+    type Error = PpcToRelError;
+
+    #[inline]
+    fn try_from(rel: PpcReloc<u32>) -> Result<RelData<u32, Elf32>,
+                                              Self::Error> {
+        match rel {
+            PpcReloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            PpcReloc::Addr32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            PpcReloc::Addr32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            PpcReloc::Addr24 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
+            PpcReloc::Addr16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            PpcReloc::Addr16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            PpcReloc::Addr16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            PpcReloc::Addr16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr14 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            PpcReloc::Addr14 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr14BrTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            PpcReloc::Addr14BrTaken { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Addr14BrNTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            PpcReloc::Addr14BrNTaken { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            PpcReloc::Rel24 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel14 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            PpcReloc::Rel14 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel14BrTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 12 }),
+            PpcReloc::Rel14BrTaken { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel14BrNTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 13 }),
+            PpcReloc::Rel14BrNTaken { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOT16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 14 }),
+            PpcReloc::GOT16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOT16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 15 }),
+            PpcReloc::GOT16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOT16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 16 }),
+            PpcReloc::GOT16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOT16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 17 }),
+            PpcReloc::GOT16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::PLTRel24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 18 }),
+            PpcReloc::PLTRel24 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 19 }),
+            PpcReloc::GlobalData { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            PpcReloc::GlobalData { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            PpcReloc::JumpSlot { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 22 }),
+            PpcReloc::Relative { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Local24PC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 23 }),
+            PpcReloc::Local24PC { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::UAddr32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 24 }),
+            PpcReloc::UAddr32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::UAddr16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 25 }),
+            PpcReloc::UAddr16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            PpcReloc::Rel32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            PpcReloc::PLT32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::PLTRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            PpcReloc::PLTRel32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::PLT16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            PpcReloc::PLT16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::PLT16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 30 }),
+            PpcReloc::PLT16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::PLT16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 31 }),
+            PpcReloc::PLT16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::SDARel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 32 }),
+            PpcReloc::SDARel16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::SectOff { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 33 }),
+            PpcReloc::SectOff { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::SectOffLo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 34 }),
+            PpcReloc::SectOffLo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::SectOffHi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 35 }),
+            PpcReloc::SectOffHi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::SectOffHA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 36 }),
+            PpcReloc::SectOffHA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TLS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 67 }),
+            PpcReloc::TLS { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::DTPMod32 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 68 }),
+            PpcReloc::TPRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 69 }),
+            PpcReloc::TPRel16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TPRel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 70 }),
+            PpcReloc::TPRel16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 71 }),
+            PpcReloc::TPRel16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 72 }),
+            PpcReloc::TPRel16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TPRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 73 }),
+            PpcReloc::TPRel32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::DTPRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 74 }),
+            PpcReloc::DTPRel16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::DTPRel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 75 }),
+            PpcReloc::DTPRel16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::DTPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 76 }),
+            PpcReloc::DTPRel16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::DTPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 77 }),
+            PpcReloc::DTPRel16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::DTPRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 78 }),
+            PpcReloc::DTPRel32 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSGD16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 79 }),
+            PpcReloc::GOTTLSGD16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSGD16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 80 }),
+            PpcReloc::GOTTLSGD16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSGD16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 81 }),
+            PpcReloc::GOTTLSGD16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSGD16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 82 }),
+            PpcReloc::GOTTLSGD16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSLD16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 83 }),
+            PpcReloc::GOTTLSLD16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSLD16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 84 }),
+            PpcReloc::GOTTLSLD16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSLD16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 85 }),
+            PpcReloc::GOTTLSLD16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTLSLD16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 86 }),
+            PpcReloc::GOTTLSLD16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTPRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 87 }),
+            PpcReloc::GOTTPRel16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTPRel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 88 }),
+            PpcReloc::GOTTPRel16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 89 }),
+            PpcReloc::GOTTPRel16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTTPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 90 }),
+            PpcReloc::GOTTPRel16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTDTPRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 91 }),
+            PpcReloc::GOTDTPRel16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTDTPRel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 92 }),
+            PpcReloc::GOTDTPRel16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTDTPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 93 }),
+            PpcReloc::GOTDTPRel16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::GOTDTPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 94 }),
+            PpcReloc::GOTDTPRel16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TLSGD { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 95 }),
+            PpcReloc::TLSGD { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TLSLD { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 96 }),
+            PpcReloc::TLSLD { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 248 }),
+            PpcReloc::IRelative { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 249 }),
+            PpcReloc::Rel16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 250 }),
+            PpcReloc::Rel16Lo { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 251 }),
+            PpcReloc::Rel16Hi { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::Rel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 252 }),
+            PpcReloc::Rel16HA { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend)),
+            PpcReloc::TOC16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 255 }),
+            PpcReloc::TOC16 { addend, .. } =>
+                Err(PpcToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for PpcReloc<Name> {
+    type Error = PpcRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) -> Result<PpcReloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<PpcReloc<u32>> for RelaData<u32, Elf32> {
+    #[inline]
+    fn from(rel: PpcReloc<u32>) -> RelaData<u32, Elf32> {
+        match rel {
+            PpcReloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            PpcReloc::Addr32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
+            PpcReloc::Addr24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
+            PpcReloc::Addr16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 3, addend: addend },
+            PpcReloc::Addr16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 4, addend: addend },
+            PpcReloc::Addr16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 5, addend: addend },
+            PpcReloc::Addr16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 6, addend: addend },
+            PpcReloc::Addr14 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 7, addend: addend },
+            PpcReloc::Addr14BrTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 8, addend: addend },
+            PpcReloc::Addr14BrNTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 9, addend: addend },
+            PpcReloc::Rel24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
+            PpcReloc::Rel14 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
+            PpcReloc::Rel14BrTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 12, addend: addend },
+            PpcReloc::Rel14BrNTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 13, addend: addend },
+            PpcReloc::GOT16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 14, addend: addend },
+            PpcReloc::GOT16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 15, addend: addend },
+            PpcReloc::GOT16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 16, addend: addend },
+            PpcReloc::GOT16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 17, addend: addend },
+            PpcReloc::PLTRel24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 18, addend: addend },
+            PpcReloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 19, addend: 0 },
+            PpcReloc::GlobalData { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 20, addend: addend },
+            PpcReloc::JumpSlot { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 21, addend: addend },
+            PpcReloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 22, addend: addend },
+            PpcReloc::Local24PC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 23, addend: addend },
+            PpcReloc::UAddr32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 24, addend: addend },
+            PpcReloc::UAddr16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 25, addend: addend },
+            PpcReloc::Rel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 26, addend: addend },
+            PpcReloc::PLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 27, addend: addend },
+            PpcReloc::PLTRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 28, addend: addend },
+            PpcReloc::PLT16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 29, addend: addend },
+            PpcReloc::PLT16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 30, addend: addend },
+            PpcReloc::PLT16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 31, addend: addend },
+            PpcReloc::SDARel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 32, addend: addend },
+            PpcReloc::SectOff { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 33, addend: addend },
+            PpcReloc::SectOffLo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 34, addend: addend },
+            PpcReloc::SectOffHi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 35, addend: addend },
+            PpcReloc::SectOffHA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 36, addend: addend },
+            PpcReloc::TLS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 67, addend: addend },
+            PpcReloc::DTPMod32 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 68, addend: 0 },
+            PpcReloc::TPRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 69, addend: addend },
+            PpcReloc::TPRel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 70, addend: addend },
+            PpcReloc::TPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 71, addend: addend },
+            PpcReloc::TPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 72, addend: addend },
+            PpcReloc::TPRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 73, addend: addend },
+            PpcReloc::DTPRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 74, addend: addend },
+            PpcReloc::DTPRel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 75, addend: addend },
+            PpcReloc::DTPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 76, addend: addend },
+            PpcReloc::DTPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 77, addend: addend },
+            PpcReloc::DTPRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 78, addend: addend },
+            PpcReloc::GOTTLSGD16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 79, addend: addend },
+            PpcReloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 80, addend: addend },
+            PpcReloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 81, addend: addend },
+            PpcReloc::GOTTLSGD16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 82, addend: addend },
+            PpcReloc::GOTTLSLD16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 83, addend: addend },
+            PpcReloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 84, addend: addend },
+            PpcReloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 85, addend: addend },
+            PpcReloc::GOTTLSLD16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 86, addend: addend },
+            PpcReloc::GOTTPRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 87, addend: addend },
+            PpcReloc::GOTTPRel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 88, addend: addend },
+            PpcReloc::GOTTPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 89, addend: addend },
+            PpcReloc::GOTTPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 90, addend: addend },
+            PpcReloc::GOTDTPRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 91, addend: addend },
+            PpcReloc::GOTDTPRel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 92, addend: addend },
+            PpcReloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 93, addend: addend },
+            PpcReloc::GOTDTPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 94, addend: addend },
+            PpcReloc::TLSGD { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 95, addend: addend },
+            PpcReloc::TLSLD { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 96, addend: addend },
+            PpcReloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 248, addend: addend },
+            PpcReloc::Rel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 249,
+                           addend: addend },
+            PpcReloc::Rel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 250,
+                           addend: addend },
+            PpcReloc::Rel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 251,
+                           addend: addend },
+            PpcReloc::Rel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 252,
+                           addend: addend },
+            PpcReloc::TOC16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 255, addend: addend }
+        }
+    }
+}
+
+impl<'a, B> WithSymtab<'a, B, Elf32> for PpcRelocRaw
+    where B: ByteOrder {
+    type Result = PpcRelocRawSym;
+    type Error = RelocSymtabError<Elf32>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Elf32>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            PpcReloc::None => Ok(PpcReloc::None),
+            PpcReloc::Addr32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr24 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr14 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr14 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr14BrTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr14BrTaken { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Addr14BrNTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Addr14BrNTaken { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel24 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel14 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel14 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel14BrTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel14BrTaken { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel14BrNTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel14BrNTaken { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOT16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOT16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOT16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOT16Lo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOT16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOT16Hi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOT16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOT16HA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::PLTRel24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::PLTRel24 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Copy { offset: offset, sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GlobalData { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GlobalData { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::JumpSlot { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Relative { offset, addend } =>
+                Ok(PpcReloc::Relative { offset: offset, addend: addend }),
+            PpcReloc::Local24PC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Local24PC { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::UAddr32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::UAddr32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::UAddr16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::UAddr16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::PLT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::PLTRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::PLTRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::PLT16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::PLT16Lo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::PLT16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::PLT16Hi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::PLT16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::PLT16HA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::SDARel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::SDARel16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::SectOff { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::SectOff { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::SectOffLo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::SectOffLo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::SectOffHi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::SectOffHi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::SectOffHA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::SectOffHA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TLS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TLS { offset: offset, sym: symdata,
+                                               addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::DTPMod32 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::DTPMod32 { offset: offset,
+                                                    sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TPRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TPRel16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TPRel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TPRel16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TPRel16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TPRel16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TPRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TPRel32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::DTPRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::DTPRel16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::DTPRel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::DTPRel16Lo { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::DTPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::DTPRel16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::DTPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::DTPRel16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::DTPRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::DTPRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSGD16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSGD16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSGD16Lo { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSGD16Hi { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSGD16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSGD16HA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSLD16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSLD16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSLD16Lo { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSLD16Hi { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTLSLD16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTLSLD16HA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTPRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTPRel16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTPRel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTPRel16Lo { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTPRel16Hi { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTTPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTTPRel16HA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTDTPRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTDTPRel16 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTDTPRel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTDTPRel16Lo { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTDTPRel16Hi { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::GOTDTPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::GOTDTPRel16HA { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TLSGD { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TLSGD { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TLSLD { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TLSLD { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::IRelative { offset, addend } =>
+                Ok(PpcReloc::IRelative { offset: offset, addend: addend }),
+            PpcReloc::Rel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel16Lo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel16Hi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::Rel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::Rel16HA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            PpcReloc::TOC16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(PpcReloc::TOC16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                }
+        }
+    }
+}
+
+impl<'a> WithStrtab<'a> for PpcRelocRawSym {
+    type Result = PpcRelocStrDataSym<'a>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            PpcReloc::None => Ok(PpcReloc::None),
+            PpcReloc::Addr32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr32 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr24 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16Lo { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16Hi { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16HA { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr14 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr14 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr14BrTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr14BrTaken { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr14BrNTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr14BrNTaken { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel24 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel14 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel14 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel14BrTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel14BrTaken { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel14BrNTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel14BrNTaken { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16Lo { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16Hi { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16HA { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLTRel24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLTRel24 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GlobalData { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GlobalData { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::JumpSlot { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Relative { offset, addend } =>
+                Ok(PpcReloc::Relative { offset: offset, addend: addend }),
+            PpcReloc::Local24PC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Local24PC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::UAddr32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::UAddr32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::UAddr16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::UAddr16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLTRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLTRel32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT16Lo { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT16Hi { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT16HA { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SDARel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SDARel16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOff { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOff { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOffLo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOffLo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOffHi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOffHi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOffHA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOffHA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TLS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TLS { offset: offset, sym: symdata,
+                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPMod32 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPMod32 { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16Lo { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16Hi { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16HA { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TLSGD { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TLSGD { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TLSLD { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TLSLD { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::IRelative { offset, addend } =>
+                Ok(PpcReloc::IRelative { offset: offset, addend: addend }),
+            PpcReloc::Rel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16Lo { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16Hi { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16HA { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TOC16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TOC16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a> TryFrom<PpcRelocStrDataSym<'a>> for PpcRelocStrSym<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: PpcRelocStrDataSym<'a>) ->
+        Result<PpcRelocStrSym<'a>, Self::Error> {
+        match reloc {
+            PpcReloc::None => Ok(PpcReloc::None),
+            PpcReloc::Addr32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr32 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr24 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16Lo { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16Hi { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr16HA { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr14 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr14 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr14BrTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr14BrTaken { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Addr14BrNTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Addr14BrNTaken { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel24 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel14 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel14 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel14BrTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel14BrTaken { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel14BrNTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel14BrNTaken { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16Lo { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16Hi { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOT16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOT16HA { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLTRel24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLTRel24 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GlobalData { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GlobalData { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::JumpSlot { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Relative { offset, addend } =>
+                Ok(PpcReloc::Relative { offset: offset, addend: addend }),
+            PpcReloc::Local24PC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Local24PC { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::UAddr32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::UAddr32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::UAddr16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::UAddr16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLTRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLTRel32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT16Lo { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT16Hi { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::PLT16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::PLT16HA { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SDARel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SDARel16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOff { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOff { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOffLo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOffLo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOffHi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOffHi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::SectOffHA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::SectOffHA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TLS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TLS { offset: offset, sym: symdata,
+                                           addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPMod32 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPMod32 { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TPRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TPRel32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16Lo { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16Hi { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel16HA { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::DTPRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::DTPRel32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSGD16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSGD16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTLSLD16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTLSLD16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTTPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTTPRel16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::GOTDTPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::GOTDTPRel16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TLSGD { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TLSGD { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TLSLD { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TLSLD { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::IRelative { offset, addend } =>
+                Ok(PpcReloc::IRelative { offset: offset, addend: addend }),
+            PpcReloc::Rel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16Lo { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16Hi { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::Rel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::Rel16HA { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            PpcReloc::TOC16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(PpcReloc::TOC16 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a> From<PpcRelocStrDataSym<'a>> for PpcRelocStrData<'a> {
+    #[inline]
+    fn from(reloc: PpcRelocStrDataSym<'a>) -> PpcRelocStrData<'a> {
+        match reloc {
+            PpcReloc::None => PpcReloc::None,
+            PpcReloc::Addr32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr24 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr24 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16Lo { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Addr16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16Hi { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Addr16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16HA { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Addr14 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr14 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr14BrTaken { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::Addr14BrTaken { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::Addr14BrNTaken { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                PpcReloc::Addr14BrNTaken { offset: offset, sym: name,
+                                           addend: addend },
+            PpcReloc::Rel24 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel24 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel14 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel14 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel14BrTaken { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::Rel14BrTaken { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::Rel14BrNTaken { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::Rel14BrNTaken { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::GOT16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::GOT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16Lo { offset: offset, sym: name, addend: addend },
+            PpcReloc::GOT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16Hi { offset: offset, sym: name, addend: addend },
+            PpcReloc::GOT16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16HA { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLTRel24 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLTRel24 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Copy { sym: SymData { name, .. }, offset } =>
+                PpcReloc::Copy { offset: offset, sym: name },
+            PpcReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GlobalData { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::JumpSlot { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::JumpSlot { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Relative { offset, addend } =>
+                PpcReloc::Relative { offset: offset, addend: addend },
+            PpcReloc::Local24PC { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Local24PC { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::UAddr32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::UAddr32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::UAddr16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::UAddr16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLTRel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLTRel32 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::PLT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT16Lo { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT16Hi { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLT16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT16HA { offset: offset, sym: name, addend: addend },
+            PpcReloc::SDARel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SDARel16 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::SectOff { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOff { offset: offset, sym: name, addend: addend },
+            PpcReloc::SectOffLo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOffLo { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::SectOffHi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOffHi { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::SectOffHA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOffHA { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TLS { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TLS { offset: offset, sym: name, addend: addend },
+            PpcReloc::DTPMod32 { sym: SymData { name, .. }, offset } =>
+                PpcReloc::DTPMod32 { offset: offset, sym: name },
+            PpcReloc::TPRel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::TPRel16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TPRel16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TPRel16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16HA { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TPRel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::DTPRel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::DTPRel16 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::DTPRel16Lo { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::DTPRel16Lo { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::DTPRel16Hi { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::DTPRel16Hi { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::DTPRel16HA { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::DTPRel16HA { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::DTPRel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::DTPRel32 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::GOTTLSGD16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GOTTLSGD16 { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::GOTTLSGD16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSGD16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSGD16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSGD16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSGD16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSGD16HA { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSLD16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GOTTLSLD16 { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::GOTTLSLD16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSLD16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSLD16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSLD16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSLD16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSLD16HA { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTPRel16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GOTTPRel16 { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::GOTTPRel16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTPRel16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTPRel16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTPRel16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTPRel16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTPRel16HA { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTDTPRel16 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                PpcReloc::GOTDTPRel16 { offset: offset, sym: name,
+                                        addend: addend },
+            PpcReloc::GOTDTPRel16Lo { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::GOTDTPRel16Lo { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::GOTDTPRel16Hi { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::GOTDTPRel16Hi { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::GOTDTPRel16HA { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::GOTDTPRel16HA { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::TLSGD { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TLSGD { offset: offset, sym: name, addend: addend },
+            PpcReloc::TLSLD { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TLSLD { offset: offset, sym: name, addend: addend },
+            PpcReloc::IRelative { offset, addend } =>
+                PpcReloc::IRelative { offset: offset, addend: addend },
+            PpcReloc::Rel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16Lo { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16Hi { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16HA { offset: offset, sym: name, addend: addend },
+            PpcReloc::TOC16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TOC16 { offset: offset, sym: name, addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<PpcRelocStrData<'a>> for PpcRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: PpcRelocStrData<'a>) ->
+        Result<PpcRelocStr<'a>, Self::Error> {
+        match reloc {
+            PpcReloc::None => Ok(PpcReloc::None),
+            PpcReloc::Addr32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr32 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            PpcReloc::Addr32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr32 { offset: offset, sym: None,
+                                      addend: addend }),
+            PpcReloc::Addr24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr24 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            PpcReloc::Addr24 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr24 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr24 { offset: offset, sym: None,
+                                      addend: addend }),
+            PpcReloc::Addr16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr16 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            PpcReloc::Addr16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr16 { offset: offset, sym: None,
+                                      addend: addend }),
+            PpcReloc::Addr16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr16Lo { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::Addr16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr16Lo { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::Addr16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr16Hi { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::Addr16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr16Hi { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::Addr16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr16HA { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::Addr16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr16HA { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::Addr14 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr14 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            PpcReloc::Addr14 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr14 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr14 { offset: offset, sym: None,
+                                      addend: addend }),
+            PpcReloc::Addr14BrTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr14BrTaken { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            PpcReloc::Addr14BrTaken { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr14BrTaken { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr14BrTaken { offset: offset, sym: None,
+                                             addend: addend }),
+            PpcReloc::Addr14BrNTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Addr14BrNTaken { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            PpcReloc::Addr14BrNTaken { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Addr14BrNTaken { sym: None, offset, addend } =>
+                Ok(PpcReloc::Addr14BrNTaken { offset: offset, sym: None,
+                                              addend: addend }),
+            PpcReloc::Rel24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel24 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::Rel24 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel24 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel24 { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::Rel14 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel14 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::Rel14 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel14 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel14 { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::Rel14BrTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel14BrTaken { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::Rel14BrTaken { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel14BrTaken { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel14BrTaken { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::Rel14BrNTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel14BrNTaken { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            PpcReloc::Rel14BrNTaken { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel14BrNTaken { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel14BrNTaken { offset: offset, sym: None,
+                                             addend: addend }),
+            PpcReloc::GOT16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOT16 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::GOT16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOT16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOT16 { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::GOT16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOT16Lo { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::GOT16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOT16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOT16Lo { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::GOT16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOT16Hi { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::GOT16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOT16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOT16Hi { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::GOT16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOT16HA { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::GOT16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOT16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOT16HA { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::PLTRel24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::PLTRel24 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::PLTRel24 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::PLTRel24 { sym: None, offset, addend } =>
+                Ok(PpcReloc::PLTRel24 { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(PpcReloc::Copy { offset: offset, sym: Some(name) }),
+            PpcReloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Copy { sym: None, offset } =>
+                Ok(PpcReloc::Copy { offset: offset, sym: None }),
+            PpcReloc::GlobalData { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GlobalData { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::GlobalData { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GlobalData { sym: None, offset, addend } =>
+                Ok(PpcReloc::GlobalData { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::JumpSlot { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(PpcReloc::JumpSlot { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::Relative { offset, addend } =>
+                Ok(PpcReloc::Relative { offset: offset, addend: addend }),
+            PpcReloc::Local24PC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Local24PC { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::Local24PC { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Local24PC { sym: None, offset, addend } =>
+                Ok(PpcReloc::Local24PC { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::UAddr32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::UAddr32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::UAddr32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::UAddr32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::UAddr32 { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::UAddr16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::UAddr16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::UAddr16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::UAddr16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::UAddr16 { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::Rel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel32 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::Rel32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel32 { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::PLT32 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::PLT32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::PLT32 { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::PLTRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::PLTRel32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::PLTRel32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::PLTRel32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::PLTRel32 { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::PLT16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::PLT16Lo { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::PLT16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::PLT16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::PLT16Lo { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::PLT16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::PLT16Hi { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::PLT16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::PLT16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::PLT16Hi { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::PLT16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::PLT16HA { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::PLT16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::PLT16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::PLT16HA { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::SDARel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::SDARel16 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::SDARel16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::SDARel16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::SDARel16 { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::SectOff { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::SectOff { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::SectOff { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::SectOff { sym: None, offset, addend } =>
+                Ok(PpcReloc::SectOff { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::SectOffLo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::SectOffLo { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::SectOffLo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::SectOffLo { sym: None, offset, addend } =>
+                Ok(PpcReloc::SectOffLo { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::SectOffHi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::SectOffHi { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::SectOffHi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::SectOffHi { sym: None, offset, addend } =>
+                Ok(PpcReloc::SectOffHi { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::SectOffHA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::SectOffHA { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::SectOffHA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::SectOffHA { sym: None, offset, addend } =>
+                Ok(PpcReloc::SectOffHA { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::TLS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TLS { offset: offset, sym: Some(name),
+                                   addend: addend }),
+            PpcReloc::TLS { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TLS { sym: None, offset, addend } =>
+                Ok(PpcReloc::TLS { offset: offset, sym: None, addend: addend }),
+            PpcReloc::DTPMod32 { sym: Some(Ok(name)), offset } =>
+                Ok(PpcReloc::DTPMod32 { offset: offset, sym: Some(name) }),
+            PpcReloc::DTPMod32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::DTPMod32 { sym: None, offset } =>
+                Ok(PpcReloc::DTPMod32 { offset: offset, sym: None }),
+            PpcReloc::TPRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TPRel16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::TPRel16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TPRel16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::TPRel16 { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::TPRel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TPRel16Lo { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::TPRel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TPRel16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::TPRel16Lo { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::TPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TPRel16Hi { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::TPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TPRel16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::TPRel16Hi { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::TPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TPRel16HA { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            PpcReloc::TPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TPRel16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::TPRel16HA { offset: offset, sym: None,
+                                         addend: addend }),
+            PpcReloc::TPRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TPRel32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::TPRel32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TPRel32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::TPRel32 { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::DTPRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::DTPRel16 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::DTPRel16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::DTPRel16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::DTPRel16 { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::DTPRel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::DTPRel16Lo { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::DTPRel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::DTPRel16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::DTPRel16Lo { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::DTPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::DTPRel16Hi { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::DTPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::DTPRel16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::DTPRel16Hi { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::DTPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::DTPRel16HA { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::DTPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::DTPRel16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::DTPRel16HA { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::DTPRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::DTPRel32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            PpcReloc::DTPRel32 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::DTPRel32 { sym: None, offset, addend } =>
+                Ok(PpcReloc::DTPRel32 { offset: offset, sym: None,
+                                        addend: addend }),
+            PpcReloc::GOTTLSGD16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::GOTTLSGD16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSGD16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16 { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::GOTTLSGD16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16Lo { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTLSGD16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSGD16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16Lo { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTLSGD16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16Hi { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTLSGD16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSGD16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16Hi { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTLSGD16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16HA { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTLSGD16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSGD16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSGD16HA { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTLSLD16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::GOTTLSLD16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSLD16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16 { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::GOTTLSLD16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16Lo { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTLSLD16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSLD16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16Lo { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTLSLD16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16Hi { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTLSLD16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSLD16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16Hi { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTLSLD16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16HA { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTLSLD16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTLSLD16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTLSLD16HA { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTPRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            PpcReloc::GOTTPRel16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTPRel16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16 { offset: offset, sym: None,
+                                          addend: addend }),
+            PpcReloc::GOTTPRel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16Lo { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTPRel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTPRel16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16Lo { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16Hi { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTPRel16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16Hi { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTTPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16HA { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            PpcReloc::GOTTPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTTPRel16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTTPRel16HA { offset: offset, sym: None,
+                                            addend: addend }),
+            PpcReloc::GOTDTPRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            PpcReloc::GOTDTPRel16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTDTPRel16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16 { offset: offset, sym: None,
+                                           addend: addend }),
+            PpcReloc::GOTDTPRel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16Lo { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            PpcReloc::GOTDTPRel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTDTPRel16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16Lo { offset: offset, sym: None,
+                                             addend: addend }),
+            PpcReloc::GOTDTPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16Hi { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            PpcReloc::GOTDTPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTDTPRel16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16Hi { offset: offset, sym: None,
+                                             addend: addend }),
+            PpcReloc::GOTDTPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16HA { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            PpcReloc::GOTDTPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::GOTDTPRel16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::GOTDTPRel16HA { offset: offset, sym: None,
+                                             addend: addend }),
+            PpcReloc::TLSGD { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TLSGD { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::TLSGD { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TLSGD { sym: None, offset, addend } =>
+                Ok(PpcReloc::TLSGD { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::TLSLD { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TLSLD { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::TLSLD { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TLSLD { sym: None, offset, addend } =>
+                Ok(PpcReloc::TLSLD { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::IRelative { offset, addend } =>
+                Ok(PpcReloc::IRelative { offset: offset, addend: addend }),
+            PpcReloc::Rel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel16 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::Rel16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel16 { offset: offset, sym: None,
+                                     addend: addend }),
+            PpcReloc::Rel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel16Lo { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::Rel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel16Lo { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel16Lo { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::Rel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel16Hi { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::Rel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel16Hi { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel16Hi { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::Rel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::Rel16HA { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            PpcReloc::Rel16HA { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::Rel16HA { sym: None, offset, addend } =>
+                Ok(PpcReloc::Rel16HA { offset: offset, sym: None,
+                                       addend: addend }),
+            PpcReloc::TOC16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(PpcReloc::TOC16 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            PpcReloc::TOC16 { sym: Some(Err(err)), .. } => Err(err),
+            PpcReloc::TOC16 { sym: None, offset, addend } =>
+                Ok(PpcReloc::TOC16 { offset: offset, sym: None,
+                                     addend: addend })
+        }
+    }
+}
+
+impl<'a> From<PpcRelocStrSym<'a>> for PpcRelocStr<'a> {
+    #[inline]
+    fn from(reloc: PpcRelocStrSym<'a>) -> PpcRelocStr<'a> {
+        match reloc {
+            PpcReloc::None => PpcReloc::None,
+            PpcReloc::Addr32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr24 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr24 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16Lo { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Addr16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16Hi { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Addr16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr16HA { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Addr14 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Addr14 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Addr14BrTaken { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::Addr14BrTaken { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::Addr14BrNTaken { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                PpcReloc::Addr14BrNTaken { offset: offset, sym: name,
+                                           addend: addend },
+            PpcReloc::Rel24 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel24 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel14 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel14 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel14BrTaken { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::Rel14BrTaken { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::Rel14BrNTaken { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::Rel14BrNTaken { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::GOT16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::GOT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16Lo { offset: offset, sym: name, addend: addend },
+            PpcReloc::GOT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16Hi { offset: offset, sym: name, addend: addend },
+            PpcReloc::GOT16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::GOT16HA { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLTRel24 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLTRel24 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Copy { sym: SymData { name, .. }, offset } =>
+                PpcReloc::Copy { offset: offset, sym: name },
+            PpcReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GlobalData { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::JumpSlot { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::JumpSlot { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::Relative { offset, addend } =>
+                PpcReloc::Relative { offset: offset, addend: addend },
+            PpcReloc::Local24PC { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Local24PC { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::UAddr32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::UAddr32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::UAddr16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::UAddr16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLTRel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLTRel32 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::PLT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT16Lo { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT16Hi { offset: offset, sym: name, addend: addend },
+            PpcReloc::PLT16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::PLT16HA { offset: offset, sym: name, addend: addend },
+            PpcReloc::SDARel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SDARel16 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::SectOff { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOff { offset: offset, sym: name, addend: addend },
+            PpcReloc::SectOffLo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOffLo { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::SectOffHi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOffHi { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::SectOffHA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::SectOffHA { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TLS { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TLS { offset: offset, sym: name, addend: addend },
+            PpcReloc::DTPMod32 { sym: SymData { name, .. }, offset } =>
+                PpcReloc::DTPMod32 { offset: offset, sym: name },
+            PpcReloc::TPRel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::TPRel16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TPRel16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TPRel16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel16HA { offset: offset, sym: name,
+                                      addend: addend },
+            PpcReloc::TPRel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TPRel32 { offset: offset, sym: name, addend: addend },
+            PpcReloc::DTPRel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::DTPRel16 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::DTPRel16Lo { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::DTPRel16Lo { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::DTPRel16Hi { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::DTPRel16Hi { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::DTPRel16HA { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::DTPRel16HA { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::DTPRel32 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::DTPRel32 { offset: offset, sym: name,
+                                     addend: addend },
+            PpcReloc::GOTTLSGD16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GOTTLSGD16 { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::GOTTLSGD16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSGD16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSGD16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSGD16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSGD16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSGD16HA { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSLD16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GOTTLSLD16 { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::GOTTLSLD16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSLD16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSLD16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSLD16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTLSLD16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTLSLD16HA { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTPRel16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                PpcReloc::GOTTPRel16 { offset: offset, sym: name,
+                                       addend: addend },
+            PpcReloc::GOTTPRel16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTPRel16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTPRel16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTPRel16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTTPRel16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                PpcReloc::GOTTPRel16HA { offset: offset, sym: name,
+                                         addend: addend },
+            PpcReloc::GOTDTPRel16 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                PpcReloc::GOTDTPRel16 { offset: offset, sym: name,
+                                        addend: addend },
+            PpcReloc::GOTDTPRel16Lo { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::GOTDTPRel16Lo { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::GOTDTPRel16Hi { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::GOTDTPRel16Hi { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::GOTDTPRel16HA { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                PpcReloc::GOTDTPRel16HA { offset: offset, sym: name,
+                                          addend: addend },
+            PpcReloc::TLSGD { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TLSGD { offset: offset, sym: name, addend: addend },
+            PpcReloc::TLSLD { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TLSLD { offset: offset, sym: name, addend: addend },
+            PpcReloc::IRelative { offset, addend } =>
+                PpcReloc::IRelative { offset: offset, addend: addend },
+            PpcReloc::Rel16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16 { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel16Lo { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16Lo { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel16Hi { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16Hi { offset: offset, sym: name, addend: addend },
+            PpcReloc::Rel16HA { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::Rel16HA { offset: offset, sym: name, addend: addend },
+            PpcReloc::TOC16 { sym: SymData { name, .. }, offset, addend } =>
+                PpcReloc::TOC16 { offset: offset, sym: name, addend: addend }
+        }
+    }
+}
@@ -0,0 +1,8265 @@
+//! Relocation types for 64-bit PowerPC architecture.
+//!
+//! This module provides the `Ppc64Reloc` type, which describes the
+//! relocation entries for the 64-bit PowerPC architecture, as defined
+//! by both the ELFv1 and ELFv2 ABIs.  These can be converted to and
+//! from [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
+//! the [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+//!
+//! The ELFv2 ABI gives functions separate global and local entry
+//! points, with the distance between them stored in the `st_other`
+//! field of the symbol.  The [local_entry_offset] function decodes
+//! this.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for 64-bit PowerPC architectures.
+///
+/// This datatype provides a semantic-level presentation of the
+/// PowerPC64 relocation entries, including the TOC-relative and
+/// thread-local storage relocations.  These can be converted to and
+/// from [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
+/// the [ElfClass](crate::ElfClass) type argument using the
+/// [TryFrom](core::convert::TryFrom) instances for easier handling.
+///
+/// The 16-bit relocations come in families that select different
+/// parts of the value: the `Lo` variants take the low 16 bits, the
+/// `Hi`, `Higher`, and `Highest` variants take successively higher
+/// 16-bit parts, and the variants ending in `A` adjust these so that
+/// adding the sign-extended lower parts produces the full value.  The
+/// `DS` variants apply to DS-form instructions, which keep the low
+/// two bits.  PowerPC64 is bi-endian; the
+/// [WithSymtab](crate::symtab::WithSymtab) instance works with any
+/// byte order.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Ppc64Reloc<Name> {
+    /// Null relocation.
+    None,
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Addr32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Absolute 24-bit branch.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to
+    /// `sym + addend`, shifted right by 2.
+    Addr24 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to `sym + addend`, checking
+    /// for overflow.
+    Addr16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of
+    /// `sym + addend`.
+    Addr16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of
+    /// `sym + addend`.
+    Addr16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    Addr16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Absolute 14-bit conditional branch.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to `sym + addend`, shifted right by 2.
+    Addr14 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Absolute 14-bit conditional branch, predicted taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to `sym + addend`, shifted right by 2.  Also set the
+    /// branch prediction bit to indicate that the branch is likely
+    /// taken.
+    Addr14BrTaken {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Absolute 14-bit conditional branch, predicted not taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to `sym + addend`, shifted right by 2.  Also set the
+    /// branch prediction bit to indicate that the branch is likely
+    /// not taken.
+    Addr14BrNTaken {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative 24-bit branch.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to the
+    /// relative address of `sym + addend`, shifted right by 2.  On
+    /// ELFv2, if `sym` is a function with a distinct local entry
+    /// point (see [local_entry_offset]) and the caller shares its
+    /// TOC, the branch targets the local entry point instead,
+    /// skipping the TOC pointer setup.
+    Rel24 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative 14-bit conditional branch.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to the relative address of `sym + addend`, shifted
+    /// right by 2.
+    Rel14 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative 14-bit conditional branch, predicted taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to the relative address of `sym + addend`, shifted
+    /// right by 2.  Also set the branch prediction bit to indicate
+    /// that the branch is likely taken.
+    Rel14BrTaken {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative 14-bit conditional branch, predicted not taken.
+    ///
+    /// Set the BD field of the conditional branch instruction at
+    /// `offset` to the relative address of `sym + addend`, shifted
+    /// right by 2.  Also set the branch prediction bit to indicate
+    /// that the branch is likely not taken.
+    Rel14BrNTaken {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the Global Offset Table entry for `sym + addend`,
+    /// checking for overflow.
+    GOT16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the Global Offset Table entry for
+    /// `sym + addend`.
+    GOT16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Global Offset Table entry for
+    /// `sym + addend`.
+    GOT16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Global Offset Table entry for
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    GOT16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Global Offset Table entry fill.
+    ///
+    /// Set a Global Offset Table entry to the address of
+    /// `sym + addend`.
+    GlobalData {
+        /// Offset in the GOT.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the PLT.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit offset relative to the image base.
+    ///
+    /// Set the 8-byte word at `offset` to the sum of the base address
+    /// and `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unaligned 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset`, which need not be aligned, to
+    /// `sym + addend`.
+    UAddr32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unaligned 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset`, which need not be aligned, to
+    /// `sym + addend`.
+    UAddr16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend`.
+    Rel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit absolute PLT entry address.
+    ///
+    /// Set the 4-byte word at `offset` to the address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative PLT entry address.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLTRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a PLT entry address.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`.
+    PLT16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a PLT entry address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`.
+    PLT16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a PLT entry address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    PLT16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit section offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the start of its section, checking for
+    /// overflow.
+    SectOff {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a section offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the start of its section.
+    SectOffLo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a section offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the start of its section.
+    SectOffHi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a section offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the start of its section,
+    /// adjusted to account for the sign extension of the low 16 bits.
+    SectOffHA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 30-bit PC-relative word offset.
+    ///
+    /// Set the high 30 bits of the 4-byte word at `offset` to the
+    /// relative address of `sym + addend`, shifted right by 2.
+    Addr30 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`.
+    Addr64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 47 of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 32 through 47 of
+    /// `sym + addend`.
+    Addr16Higher {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted bits 32 through 47 of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 32 through 47 of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the lower bits.
+    Addr16HigherA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 48 through 63 of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 48 through 63 of
+    /// `sym + addend`.
+    Addr16Highest {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted bits 48 through 63 of an absolute address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 48 through 63 of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the lower bits.
+    Addr16HighestA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unaligned 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset`, which need not be aligned, to
+    /// `sym + addend`.
+    UAddr64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of
+    /// `sym + addend`.
+    Rel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit absolute PLT entry address.
+    ///
+    /// Set the 8-byte word at `offset` to the address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLT64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative PLT entry address.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLTRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit TOC-relative offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the TOC base, checking for overflow.
+    TOC16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a TOC-relative offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the TOC base.
+    TOC16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a TOC-relative offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the TOC base.
+    TOC16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a TOC-relative offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the TOC base, adjusted to
+    /// account for the sign extension of the low 16 bits.
+    TOC16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// TOC base address.
+    ///
+    /// Set the 8-byte word at `offset` to the sum of the TOC base
+    /// address and `addend`.
+    TOC {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PLT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the Procedure Linkage Table entry for `sym + addend`,
+    /// checking for overflow.
+    PLTGOT16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a PLT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the Procedure Linkage Table entry
+    /// for `sym + addend`.
+    PLTGOT16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a PLT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Procedure Linkage Table entry
+    /// for `sym + addend`.
+    PLTGOT16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a PLT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Procedure Linkage Table entry
+    /// for `sym + addend`, adjusted to account for the sign extension
+    /// of the low 16 bits.
+    PLTGOT16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit absolute address (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to `sym + addend`, checking
+    /// for overflow.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    Addr16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of an absolute address (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of
+    /// `sym + addend`.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    Addr16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit GOT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the Global Offset Table entry for `sym + addend`,
+    /// checking for overflow.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    GOT16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a GOT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the Global Offset Table entry for
+    /// `sym + addend`.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    GOT16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a PLT entry address (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// address of the Procedure Linkage Table entry for
+    /// `sym + addend`.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    PLT16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit section offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the start of its section, checking for
+    /// overflow.  The field is the DS field of a DS-form instruction,
+    /// so the value must be a multiple of 4 and the low two bits of
+    /// the instruction are preserved.
+    SectOffDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a section offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the start of its section.  The
+    /// field is the DS field of a DS-form instruction, so the value
+    /// must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    SectOffLoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit TOC-relative offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the TOC base, checking for overflow.  The
+    /// field is the DS field of a DS-form instruction, so the value
+    /// must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    TOC16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a TOC-relative offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the TOC base.  The field is the
+    /// DS field of a DS-form instruction, so the value must be a
+    /// multiple of 4 and the low two bits of the instruction are
+    /// preserved.
+    TOC16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PLT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the Procedure Linkage Table entry for `sym + addend`,
+    /// checking for overflow.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    PLTGOT16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a PLT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the Procedure Linkage Table entry
+    /// for `sym + addend`.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    PLTGOT16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local access marker.
+    ///
+    /// Marks the instruction at `offset` as the thread-local access
+    /// to `sym + addend` in an initial execution sequence, so that
+    /// the linker can optimize the access.  The instruction is not
+    /// modified.
+    TLS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local storage module index.
+    ///
+    /// Set the 8-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    DTPMod64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 16-bit thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the thread pointer, checking for overflow.
+    TPRel16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the thread pointer.
+    TPRel16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the thread pointer.
+    TPRel16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the thread pointer, adjusted to
+    /// account for the sign extension of the low 16 bits.
+    TPRel16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TPRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block, checking for
+    /// overflow.
+    DTPRel16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    DTPRel16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    DTPRel16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` in its thread-local storage block,
+    /// adjusted to account for the sign extension of the low 16 bits.
+    DTPRel16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread-local storage block offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    DTPRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit general dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the pair of Global Offset Table entries holding the
+    /// TLS index for `sym + addend`, checking for overflow.
+    GOTTLSGD16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a general dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the pair of Global Offset Table
+    /// entries holding the TLS index for `sym + addend`.
+    GOTTLSGD16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a general dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the pair of Global Offset Table
+    /// entries holding the TLS index for `sym + addend`.
+    GOTTLSGD16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a general dynamic TLS GOT entry
+    /// offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the pair of Global Offset Table
+    /// entries holding the TLS index for `sym + addend`, adjusted to
+    /// account for the sign extension of the low 16 bits.
+    GOTTLSGD16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the pair of Global Offset Table entries holding the
+    /// TLS index for the module containing `sym`, checking for
+    /// overflow.
+    GOTTLSLD16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the pair of Global Offset Table
+    /// entries holding the TLS index for the module containing `sym`.
+    GOTTLSLD16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the pair of Global Offset Table
+    /// entries holding the TLS index for the module containing `sym`.
+    GOTTLSLD16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a local dynamic TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the pair of Global Offset Table
+    /// entries holding the TLS index for the module containing `sym`,
+    /// adjusted to account for the sign extension of the low 16 bits.
+    GOTTLSLD16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit initial execution TLS GOT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the Global Offset Table entry holding the thread
+    /// pointer offset of `sym + addend`, checking for overflow.  The
+    /// field is the DS field of a DS-form instruction, so the value
+    /// must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    GOTTPRel16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of an initial execution TLS GOT entry offset
+    /// (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the Global Offset Table entry
+    /// holding the thread pointer offset of `sym + addend`.  The
+    /// field is the DS field of a DS-form instruction, so the value
+    /// must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    GOTTPRel16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of an initial execution TLS GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Global Offset Table entry
+    /// holding the thread pointer offset of `sym + addend`.
+    GOTTPRel16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of an initial execution TLS GOT entry
+    /// offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Global Offset Table entry
+    /// holding the thread pointer offset of `sym + addend`, adjusted
+    /// to account for the sign extension of the low 16 bits.
+    GOTTPRel16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit TLS block offset GOT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset from the TOC
+    /// base of the Global Offset Table entry holding the thread-local
+    /// storage block offset of `sym + addend`, checking for overflow.
+    /// The field is the DS field of a DS-form instruction, so the
+    /// value must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    GOTDTPRel16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a TLS block offset GOT entry offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset from the TOC base of the Global Offset Table entry
+    /// holding the thread-local storage block offset of
+    /// `sym + addend`.  The field is the DS field of a DS-form
+    /// instruction, so the value must be a multiple of 4 and the low
+    /// two bits of the instruction are preserved.
+    GOTDTPRel16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a TLS block offset GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Global Offset Table entry
+    /// holding the thread-local storage block offset of
+    /// `sym + addend`.
+    GOTDTPRel16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a TLS block offset GOT entry offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset from the TOC base of the Global Offset Table entry
+    /// holding the thread-local storage block offset of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits.
+    GOTDTPRel16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit thread pointer offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` from the thread pointer, checking for overflow.
+    /// The field is the DS field of a DS-form instruction, so the
+    /// value must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    TPRel16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a thread pointer offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` from the thread pointer.  The field
+    /// is the DS field of a DS-form instruction, so the value must be
+    /// a multiple of 4 and the low two bits of the instruction are
+    /// preserved.
+    TPRel16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 47 of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 32 through 47 of the
+    /// offset of `sym + addend` from the thread pointer.
+    TPRel16Higher {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted bits 32 through 47 of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 32 through 47 of the
+    /// offset of `sym + addend` from the thread pointer, adjusted to
+    /// account for the sign extension of the lower bits.
+    TPRel16HigherA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 48 through 63 of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 48 through 63 of the
+    /// offset of `sym + addend` from the thread pointer.
+    TPRel16Highest {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted bits 48 through 63 of a thread pointer offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 48 through 63 of the
+    /// offset of `sym + addend` from the thread pointer, adjusted to
+    /// account for the sign extension of the lower bits.
+    TPRel16HighestA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit thread-local storage block offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block, checking for
+    /// overflow.  The field is the DS field of a DS-form instruction,
+    /// so the value must be a multiple of 4 and the low two bits of
+    /// the instruction are preserved.
+    DTPRel16DS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a thread-local storage block offset (DS-form).
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    /// The field is the DS field of a DS-form instruction, so the
+    /// value must be a multiple of 4 and the low two bits of the
+    /// instruction are preserved.
+    DTPRel16LoDS {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 47 of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 32 through 47 of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    DTPRel16Higher {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted bits 32 through 47 of a thread-local storage block
+    /// offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 32 through 47 of the
+    /// offset of `sym + addend` in its thread-local storage block,
+    /// adjusted to account for the sign extension of the lower bits.
+    DTPRel16HigherA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 48 through 63 of a thread-local storage block offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 48 through 63 of the
+    /// offset of `sym + addend` in its thread-local storage block.
+    DTPRel16Highest {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted bits 48 through 63 of a thread-local storage block
+    /// offset.
+    ///
+    /// Set the 2-byte field at `offset` to bits 48 through 63 of the
+    /// offset of `sym + addend` in its thread-local storage block,
+    /// adjusted to account for the sign extension of the lower bits.
+    DTPRel16HighestA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// General dynamic TLS call marker.
+    ///
+    /// Marks the call to `__tls_get_addr` at `offset` as part of a
+    /// general dynamic access sequence for `sym + addend`, so that
+    /// the linker can optimize the access.  The instruction is not
+    /// modified.
+    TLSGD {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic TLS call marker.
+    ///
+    /// Marks the call to `__tls_get_addr` at `offset` as part of a
+    /// local dynamic access sequence for `sym + addend`, so that the
+    /// linker can optimize the access.  The instruction is not
+    /// modified.
+    TLSLD {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// TOC save slot marker.
+    ///
+    /// Marks the call at `offset` as one whose following `nop` at
+    /// `sym + addend` may be replaced by the linker with a TOC
+    /// pointer save, allowing the save to be hoisted out of the call
+    /// site.
+    TOCSave {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of an absolute address, without overflow
+    /// checking.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of
+    /// `sym + addend`, without checking for overflow.
+    Addr16High {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of an absolute address, without overflow
+    /// checking.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of
+    /// `sym + addend`, adjusted to account for the sign extension of
+    /// the low 16 bits, without checking for overflow.
+    Addr16HighA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a thread pointer offset, without overflow
+    /// checking.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the thread pointer, without
+    /// checking for overflow.
+    TPRel16High {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a thread pointer offset, without
+    /// overflow checking.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` from the thread pointer, adjusted to
+    /// account for the sign extension of the low 16 bits, without
+    /// checking for overflow.
+    TPRel16HighA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a thread-local storage block offset, without
+    /// overflow checking.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` in its thread-local storage block,
+    /// without checking for overflow.
+    DTPRel16High {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a thread-local storage block offset,
+    /// without overflow checking.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// offset of `sym + addend` in its thread-local storage block,
+    /// adjusted to account for the sign extension of the low 16 bits,
+    /// without checking for overflow.
+    DTPRel16HighA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// PC-relative 24-bit branch from code without a TOC.
+    ///
+    /// Set the LI field of the branch instruction at `offset` to the
+    /// relative address of `sym + addend`, shifted right by 2.  This
+    /// is used by callers that do not maintain a TOC pointer, so the
+    /// linker must insert a stub if the callee requires one.
+    Rel24NoTOC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit local entry point address.
+    ///
+    /// Set the 8-byte word at `offset` to the address of the local
+    /// entry point of `sym`, plus `addend`.  See
+    /// [local_entry_offset].
+    Addr64Local {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Global entry point marker.
+    ///
+    /// Marks the TOC pointer setup sequence at the global entry point
+    /// of a function at `offset`, which the linker may optimize when
+    /// the TOC base is known.  The instructions are not modified.
+    Entry {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the result of calling the
+    /// resolver function at the sum of the base address and `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to the relative address of
+    /// `sym + addend`, checking for overflow.
+    Rel16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 16 bits of a PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to the low 16 bits of the
+    /// relative address of `sym + addend`.
+    Rel16Lo {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 16 bits of a PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// relative address of `sym + addend`.
+    Rel16Hi {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Adjusted high 16 bits of a PC-relative address.
+    ///
+    /// Set the 2-byte field at `offset` to bits 16 through 31 of the
+    /// relative address of `sym + addend`, adjusted to account for
+    /// the sign extension of the low 16 bits.
+    Rel16HA {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    }
+}
+
+/// Type synonym for [Ppc64Reloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type Ppc64RelocRaw = Ppc64Reloc<u32>;
+
+/// Type synonym for [Ppc64Reloc] with [SymDataRaw] as the symbol
+/// type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [Ppc64RelocRaw].
+pub type Ppc64RelocRawSym = Ppc64Reloc<SymDataRaw<Elf64>>;
+
+/// Type synonym for [Ppc64Reloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [Ppc64RelocRawSym].
+pub type Ppc64RelocStrDataSym<'a> = Ppc64Reloc<SymDataStrData<'a, Elf64>>;
+
+/// Type synonym for [Ppc64Reloc] with [SymDataStr] as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [Ppc64RelocStrDataSym].
+pub type Ppc64RelocStrData<'a> =
+    Ppc64Reloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [Ppc64Reloc] with UTF-8 decoded string data as
+/// the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [Ppc64RelocStrDataSym].
+pub type Ppc64RelocStrSym<'a> = Ppc64Reloc<SymDataStr<'a, Elf64>>;
+
+/// Type synonym for [Ppc64Reloc] with a `&'a str`s as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [Ppc64RelocStrSym].
+pub type Ppc64RelocStr<'a> = Ppc64Reloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [Ppc64Reloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// At present, this can only happen with a non-zero addend.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Ppc64ToRelError {
+    /// Non-zero addend.
+    BadAddend(i64)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [Ppc64Reloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Ppc64RelocError {
+    /// Unknown tag value.
+    BadTag(u32)
+}
+
+impl<Name> Display for Ppc64Reloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Ppc64Reloc::None => write!(f, "none"),
+            Ppc64Reloc::Addr32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::Addr24 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::Addr16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr14 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::Addr14BrTaken { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::Addr14BrNTaken { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 2",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::Rel24 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::Rel14 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::Rel14BrTaken { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::Rel14BrNTaken { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::GOT16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[{}] + {} - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOT16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&.got[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOT16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&.got[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOT16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&.got[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            Ppc64Reloc::GlobalData { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::Relative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 8, addend),
+            Ppc64Reloc::UAddr32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::UAddr16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Rel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::PLT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 4, sym, addend),
+            Ppc64Reloc::PLTRel32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::PLT16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLT16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLT16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::SectOff { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- sectoff({}) + {}",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::SectOffLo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::SectOffHi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::SectOffHA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr30 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::Addr64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::Addr16Higher { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #higher(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16HigherA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highera(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16Highest { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highest(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16HighestA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highesta(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::UAddr64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::Rel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 8, sym, addend, offset),
+            Ppc64Reloc::PLT64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::PLTRel64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 8, sym, addend, offset),
+            Ppc64Reloc::TOC16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TOC16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo((&{} + {}) - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TOC16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi((&{} + {}) - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TOC16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha((&{} + {}) - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TOC { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &.TOC. + {}",
+                       offset, offset + 8, addend),
+            Ppc64Reloc::PLTGOT16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {} - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLTGOT16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(&.plt[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLTGOT16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(&.plt[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLTGOT16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(&.plt[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16DS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds &{} + {}",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOT16DS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds &.got[{}] + {} - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOT16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(&.got[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLT16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(&.plt[{}] + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::SectOffDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds sectoff({}) + {}",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::SectOffLoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(sectoff({}) + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TOC16DS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds (&{} + {}) - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TOC16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo((&{} + {}) - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLTGOT16DS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds &.plt[{}] + {} - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::PLTGOT16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(&.plt[{}] + {} - &.TOC.)",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TLS { offset, sym, addend } =>
+                write!(f, "tls access to {} + {} at .section[{}]",
+                       sym, addend, offset),
+            Ppc64Reloc::DTPMod64 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 8, sym),
+            Ppc64Reloc::TPRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- tprel(&{} + {})",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::DTPRel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16Lo { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #lo(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16Hi { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #hi(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16HA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #ha(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- dtprel(&{} + {})",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::GOTTLSGD16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tlsgd(&{} + {})] - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo(&.got[tlsgd(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[tlsgd(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSGD16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[tlsgd(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSLD16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tlsld(&{} + {})] - &.TOC.",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo(&.got[tlsld(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[tlsld(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTLSLD16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[tlsld(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTPRel16DS { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ds &.got[tprel(&{} + ",
+                                  "{})] - &.TOC."),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTPRel16LoDS { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ds #lo(&.got[tprel(&{} ",
+                                  "+ {})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTPRel16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[tprel(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTTPRel16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[tprel(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTDTPRel16DS { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ds &.got[dtprel(&{} + ",
+                                  "{})] - &.TOC."),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTDTPRel16LoDS { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ds ",
+                                  "#lo(&.got[dtprel(&{} + {})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi(&.got[dtprel(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::GOTDTPRel16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha(&.got[dtprel(&{} + ",
+                                  "{})] - &.TOC.)"),
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16DS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds tprel(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16Higher { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #higher(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16HigherA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highera(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16Highest { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highest(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16HighestA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highesta(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16DS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds dtprel(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16LoDS { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ds #lo(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16Higher { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #higher(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16HigherA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highera(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16Highest { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highest(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16HighestA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #highesta(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TLSGD { offset, sym, addend } =>
+                write!(f, concat!("tls general dynamic call for {} + {} at ",
+                                  ".section[{}]"),
+                       sym, addend, offset),
+            Ppc64Reloc::TLSLD { offset, sym, addend } =>
+                write!(f, "tls local dynamic call for {} + {} at .section[{}]",
+                       sym, addend, offset),
+            Ppc64Reloc::TOCSave { offset, sym, addend } =>
+                write!(f, "toc save for call at .section[{}] at {} + {}",
+                       offset, sym, addend),
+            Ppc64Reloc::Addr16High { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #high(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Addr16HighA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #higha(&{} + {})",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16High { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #high(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::TPRel16HighA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #higha(tprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16High { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #high(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::DTPRel16HighA { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- #higha(dtprel(&{} + {}))",
+                       offset, offset + 2, sym, addend),
+            Ppc64Reloc::Rel24NoTOC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            Ppc64Reloc::Addr64Local { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- localentry(&{}) + {}",
+                       offset, offset + 8, sym, addend),
+            Ppc64Reloc::Entry { offset, addend } =>
+                write!(f, "global entry at .section[{}] + {}",
+                       offset, addend),
+            Ppc64Reloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- (&base + {})()",
+                       offset, offset + 8, addend),
+            Ppc64Reloc::Rel16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            Ppc64Reloc::Rel16Lo { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #lo((&{} + {}) - ",
+                                  "(&.section + {}))"),
+                       offset, offset + 2, sym, addend, offset),
+            Ppc64Reloc::Rel16Hi { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #hi((&{} + {}) - ",
+                                  "(&.section + {}))"),
+                       offset, offset + 2, sym, addend, offset),
+            Ppc64Reloc::Rel16HA { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- #ha((&{} + {}) - ",
+                                  "(&.section + {}))"),
+                       offset, offset + 2, sym, addend, offset)
+        }
+    }
+}
+
+impl Display for Ppc64RelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Ppc64RelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for Ppc64ToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            Ppc64ToRelError::BadAddend(addend) =>
+                write!(f, "non-zero addend value {}", addend)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<Ppc64Reloc<Name>, Ppc64RelocError> {
+    match kind {
+        0 => Ok(Ppc64Reloc::None),
+        1 => Ok(Ppc64Reloc::Addr32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        2 => Ok(Ppc64Reloc::Addr24 { offset: offset, sym: sym,
+                                     addend: addend }),
+        3 => Ok(Ppc64Reloc::Addr16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        4 => Ok(Ppc64Reloc::Addr16Lo { offset: offset, sym: sym,
+                                       addend: addend }),
+        5 => Ok(Ppc64Reloc::Addr16Hi { offset: offset, sym: sym,
+                                       addend: addend }),
+        6 => Ok(Ppc64Reloc::Addr16HA { offset: offset, sym: sym,
+                                       addend: addend }),
+        7 => Ok(Ppc64Reloc::Addr14 { offset: offset, sym: sym,
+                                     addend: addend }),
+        8 => Ok(Ppc64Reloc::Addr14BrTaken { offset: offset, sym: sym,
+                                            addend: addend }),
+        9 => Ok(Ppc64Reloc::Addr14BrNTaken { offset: offset, sym: sym,
+                                             addend: addend }),
+        10 => Ok(Ppc64Reloc::Rel24 { offset: offset, sym: sym,
+                                     addend: addend }),
+        11 => Ok(Ppc64Reloc::Rel14 { offset: offset, sym: sym,
+                                     addend: addend }),
+        12 => Ok(Ppc64Reloc::Rel14BrTaken { offset: offset, sym: sym,
+                                            addend: addend }),
+        13 => Ok(Ppc64Reloc::Rel14BrNTaken { offset: offset, sym: sym,
+                                             addend: addend }),
+        14 => Ok(Ppc64Reloc::GOT16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        15 => Ok(Ppc64Reloc::GOT16Lo { offset: offset, sym: sym,
+                                       addend: addend }),
+        16 => Ok(Ppc64Reloc::GOT16Hi { offset: offset, sym: sym,
+                                       addend: addend }),
+        17 => Ok(Ppc64Reloc::GOT16HA { offset: offset, sym: sym,
+                                       addend: addend }),
+        19 => Ok(Ppc64Reloc::Copy { offset: offset, sym: sym }),
+        20 => Ok(Ppc64Reloc::GlobalData { offset: offset, sym: sym,
+                                          addend: addend }),
+        21 => Ok(Ppc64Reloc::JumpSlot { offset: offset, sym: sym,
+                                        addend: addend }),
+        22 => Ok(Ppc64Reloc::Relative { offset: offset, addend: addend }),
+        24 => Ok(Ppc64Reloc::UAddr32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        25 => Ok(Ppc64Reloc::UAddr16 { offset: offset, sym: sym,
+                                       addend: addend }),
+        26 => Ok(Ppc64Reloc::Rel32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        27 => Ok(Ppc64Reloc::PLT32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        28 => Ok(Ppc64Reloc::PLTRel32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        29 => Ok(Ppc64Reloc::PLT16Lo { offset: offset, sym: sym,
+                                       addend: addend }),
+        30 => Ok(Ppc64Reloc::PLT16Hi { offset: offset, sym: sym,
+                                       addend: addend }),
+        31 => Ok(Ppc64Reloc::PLT16HA { offset: offset, sym: sym,
+                                       addend: addend }),
+        33 => Ok(Ppc64Reloc::SectOff { offset: offset, sym: sym,
+                                       addend: addend }),
+        34 => Ok(Ppc64Reloc::SectOffLo { offset: offset, sym: sym,
+                                         addend: addend }),
+        35 => Ok(Ppc64Reloc::SectOffHi { offset: offset, sym: sym,
+                                         addend: addend }),
+        36 => Ok(Ppc64Reloc::SectOffHA { offset: offset, sym: sym,
+                                         addend: addend }),
+        37 => Ok(Ppc64Reloc::Addr30 { offset: offset, sym: sym,
+                                      addend: addend }),
+        38 => Ok(Ppc64Reloc::Addr64 { offset: offset, sym: sym,
+                                      addend: addend }),
+        39 => Ok(Ppc64Reloc::Addr16Higher { offset: offset, sym: sym,
+                                            addend: addend }),
+        40 => Ok(Ppc64Reloc::Addr16HigherA { offset: offset, sym: sym,
+                                             addend: addend }),
+        41 => Ok(Ppc64Reloc::Addr16Highest { offset: offset, sym: sym,
+                                             addend: addend }),
+        42 => Ok(Ppc64Reloc::Addr16HighestA { offset: offset, sym: sym,
+                                              addend: addend }),
+        43 => Ok(Ppc64Reloc::UAddr64 { offset: offset, sym: sym,
+                                       addend: addend }),
+        44 => Ok(Ppc64Reloc::Rel64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        45 => Ok(Ppc64Reloc::PLT64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        46 => Ok(Ppc64Reloc::PLTRel64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        47 => Ok(Ppc64Reloc::TOC16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        48 => Ok(Ppc64Reloc::TOC16Lo { offset: offset, sym: sym,
+                                       addend: addend }),
+        49 => Ok(Ppc64Reloc::TOC16Hi { offset: offset, sym: sym,
+                                       addend: addend }),
+        50 => Ok(Ppc64Reloc::TOC16HA { offset: offset, sym: sym,
+                                       addend: addend }),
+        51 => Ok(Ppc64Reloc::TOC { offset: offset, addend: addend }),
+        52 => Ok(Ppc64Reloc::PLTGOT16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        53 => Ok(Ppc64Reloc::PLTGOT16Lo { offset: offset, sym: sym,
+                                          addend: addend }),
+        54 => Ok(Ppc64Reloc::PLTGOT16Hi { offset: offset, sym: sym,
+                                          addend: addend }),
+        55 => Ok(Ppc64Reloc::PLTGOT16HA { offset: offset, sym: sym,
+                                          addend: addend }),
+        56 => Ok(Ppc64Reloc::Addr16DS { offset: offset, sym: sym,
+                                        addend: addend }),
+        57 => Ok(Ppc64Reloc::Addr16LoDS { offset: offset, sym: sym,
+                                          addend: addend }),
+        58 => Ok(Ppc64Reloc::GOT16DS { offset: offset, sym: sym,
+                                       addend: addend }),
+        59 => Ok(Ppc64Reloc::GOT16LoDS { offset: offset, sym: sym,
+                                         addend: addend }),
+        60 => Ok(Ppc64Reloc::PLT16LoDS { offset: offset, sym: sym,
+                                         addend: addend }),
+        61 => Ok(Ppc64Reloc::SectOffDS { offset: offset, sym: sym,
+                                         addend: addend }),
+        62 => Ok(Ppc64Reloc::SectOffLoDS { offset: offset, sym: sym,
+                                           addend: addend }),
+        63 => Ok(Ppc64Reloc::TOC16DS { offset: offset, sym: sym,
+                                       addend: addend }),
+        64 => Ok(Ppc64Reloc::TOC16LoDS { offset: offset, sym: sym,
+                                         addend: addend }),
+        65 => Ok(Ppc64Reloc::PLTGOT16DS { offset: offset, sym: sym,
+                                          addend: addend }),
+        66 => Ok(Ppc64Reloc::PLTGOT16LoDS { offset: offset, sym: sym,
+                                            addend: addend }),
+        67 => Ok(Ppc64Reloc::TLS { offset: offset, sym: sym, addend: addend }),
+        68 => Ok(Ppc64Reloc::DTPMod64 { offset: offset, sym: sym }),
+        69 => Ok(Ppc64Reloc::TPRel16 { offset: offset, sym: sym,
+                                       addend: addend }),
+        70 => Ok(Ppc64Reloc::TPRel16Lo { offset: offset, sym: sym,
+                                         addend: addend }),
+        71 => Ok(Ppc64Reloc::TPRel16Hi { offset: offset, sym: sym,
+                                         addend: addend }),
+        72 => Ok(Ppc64Reloc::TPRel16HA { offset: offset, sym: sym,
+                                         addend: addend }),
+        73 => Ok(Ppc64Reloc::TPRel64 { offset: offset, sym: sym,
+                                       addend: addend }),
+        74 => Ok(Ppc64Reloc::DTPRel16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        75 => Ok(Ppc64Reloc::DTPRel16Lo { offset: offset, sym: sym,
+                                          addend: addend }),
+        76 => Ok(Ppc64Reloc::DTPRel16Hi { offset: offset, sym: sym,
+                                          addend: addend }),
+        77 => Ok(Ppc64Reloc::DTPRel16HA { offset: offset, sym: sym,
+                                          addend: addend }),
+        78 => Ok(Ppc64Reloc::DTPRel64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        79 => Ok(Ppc64Reloc::GOTTLSGD16 { offset: offset, sym: sym,
+                                          addend: addend }),
+        80 => Ok(Ppc64Reloc::GOTTLSGD16Lo { offset: offset, sym: sym,
+                                            addend: addend }),
+        81 => Ok(Ppc64Reloc::GOTTLSGD16Hi { offset: offset, sym: sym,
+                                            addend: addend }),
+        82 => Ok(Ppc64Reloc::GOTTLSGD16HA { offset: offset, sym: sym,
+                                            addend: addend }),
+        83 => Ok(Ppc64Reloc::GOTTLSLD16 { offset: offset, sym: sym,
+                                          addend: addend }),
+        84 => Ok(Ppc64Reloc::GOTTLSLD16Lo { offset: offset, sym: sym,
+                                            addend: addend }),
+        85 => Ok(Ppc64Reloc::GOTTLSLD16Hi { offset: offset, sym: sym,
+                                            addend: addend }),
+        86 => Ok(Ppc64Reloc::GOTTLSLD16HA { offset: offset, sym: sym,
+                                            addend: addend }),
+        87 => Ok(Ppc64Reloc::GOTTPRel16DS { offset: offset, sym: sym,
+                                            addend: addend }),
+        88 => Ok(Ppc64Reloc::GOTTPRel16LoDS { offset: offset, sym: sym,
+                                              addend: addend }),
+        89 => Ok(Ppc64Reloc::GOTTPRel16Hi { offset: offset, sym: sym,
+                                            addend: addend }),
+        90 => Ok(Ppc64Reloc::GOTTPRel16HA { offset: offset, sym: sym,
+                                            addend: addend }),
+        91 => Ok(Ppc64Reloc::GOTDTPRel16DS { offset: offset, sym: sym,
+                                             addend: addend }),
+        92 => Ok(Ppc64Reloc::GOTDTPRel16LoDS { offset: offset, sym: sym,
+                                               addend: addend }),
+        93 => Ok(Ppc64Reloc::GOTDTPRel16Hi { offset: offset, sym: sym,
+                                             addend: addend }),
+        94 => Ok(Ppc64Reloc::GOTDTPRel16HA { offset: offset, sym: sym,
+                                             addend: addend }),
+        95 => Ok(Ppc64Reloc::TPRel16DS { offset: offset, sym: sym,
+                                         addend: addend }),
+        96 => Ok(Ppc64Reloc::TPRel16LoDS { offset: offset, sym: sym,
+                                           addend: addend }),
+        97 => Ok(Ppc64Reloc::TPRel16Higher { offset: offset, sym: sym,
+                                             addend: addend }),
+        98 => Ok(Ppc64Reloc::TPRel16HigherA { offset: offset, sym: sym,
+                                              addend: addend }),
+        99 => Ok(Ppc64Reloc::TPRel16Highest { offset: offset, sym: sym,
+                                              addend: addend }),
+        100 => Ok(Ppc64Reloc::TPRel16HighestA { offset: offset, sym: sym,
+                                                addend: addend }),
+        101 => Ok(Ppc64Reloc::DTPRel16DS { offset: offset, sym: sym,
+                                           addend: addend }),
+        102 => Ok(Ppc64Reloc::DTPRel16LoDS { offset: offset, sym: sym,
+                                             addend: addend }),
+        103 => Ok(Ppc64Reloc::DTPRel16Higher { offset: offset, sym: sym,
+                                               addend: addend }),
+        104 => Ok(Ppc64Reloc::DTPRel16HigherA { offset: offset, sym: sym,
+                                                addend: addend }),
+        105 => Ok(Ppc64Reloc::DTPRel16Highest { offset: offset, sym: sym,
+                                                addend: addend }),
+        106 => Ok(Ppc64Reloc::DTPRel16HighestA { offset: offset, sym: sym,
+                                                 addend: addend }),
+        107 => Ok(Ppc64Reloc::TLSGD { offset: offset, sym: sym,
+                                      addend: addend }),
+        108 => Ok(Ppc64Reloc::TLSLD { offset: offset, sym: sym,
+                                      addend: addend }),
+        109 => Ok(Ppc64Reloc::TOCSave { offset: offset, sym: sym,
+                                        addend: addend }),
+        110 => Ok(Ppc64Reloc::Addr16High { offset: offset, sym: sym,
+                                           addend: addend }),
+        111 => Ok(Ppc64Reloc::Addr16HighA { offset: offset, sym: sym,
+                                            addend: addend }),
+        112 => Ok(Ppc64Reloc::TPRel16High { offset: offset, sym: sym,
+                                            addend: addend }),
+        113 => Ok(Ppc64Reloc::TPRel16HighA { offset: offset, sym: sym,
+                                             addend: addend }),
+        114 => Ok(Ppc64Reloc::DTPRel16High { offset: offset, sym: sym,
+                                             addend: addend }),
+        115 => Ok(Ppc64Reloc::DTPRel16HighA { offset: offset, sym: sym,
+                                              addend: addend }),
+        116 => Ok(Ppc64Reloc::Rel24NoTOC { offset: offset, sym: sym,
+                                           addend: addend }),
+        117 => Ok(Ppc64Reloc::Addr64Local { offset: offset, sym: sym,
+                                            addend: addend }),
+        118 => Ok(Ppc64Reloc::Entry { offset: offset, addend: addend }),
+        248 => Ok(Ppc64Reloc::IRelative { offset: offset, addend: addend }),
+        249 => Ok(Ppc64Reloc::Rel16 { offset: offset, sym: sym,
+                                      addend: addend }),
+        250 => Ok(Ppc64Reloc::Rel16Lo { offset: offset, sym: sym,
+                                        addend: addend }),
+        251 => Ok(Ppc64Reloc::Rel16Hi { offset: offset, sym: sym,
+                                        addend: addend }),
+        252 => Ok(Ppc64Reloc::Rel16HA { offset: offset, sym: sym,
+                                        addend: addend }),
+        tag => Err(Ppc64RelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf64>> for Ppc64Reloc<Name> {
+    type Error = Ppc64RelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf64>) -> Result<Ppc64Reloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<Ppc64Reloc<u32>> for RelData<u32, Elf64> {
+    type Error = Ppc64ToRelError;
+
+    #[inline]
+    fn try_from(rel: Ppc64Reloc<u32>) -> Result<RelData<u32, Elf64>,
+                                                Self::Error> {
+        match rel {
+            Ppc64Reloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            Ppc64Reloc::Addr32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            Ppc64Reloc::Addr32 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            Ppc64Reloc::Addr24 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
+            Ppc64Reloc::Addr16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            Ppc64Reloc::Addr16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            Ppc64Reloc::Addr16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            Ppc64Reloc::Addr16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr14 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            Ppc64Reloc::Addr14 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr14BrTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            Ppc64Reloc::Addr14BrTaken { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr14BrNTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            Ppc64Reloc::Addr14BrNTaken { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel24 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            Ppc64Reloc::Rel24 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel14 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            Ppc64Reloc::Rel14 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel14BrTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 12 }),
+            Ppc64Reloc::Rel14BrTaken { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel14BrNTaken { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 13 }),
+            Ppc64Reloc::Rel14BrNTaken { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOT16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 14 }),
+            Ppc64Reloc::GOT16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOT16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 15 }),
+            Ppc64Reloc::GOT16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOT16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 16 }),
+            Ppc64Reloc::GOT16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOT16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 17 }),
+            Ppc64Reloc::GOT16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 19 }),
+            Ppc64Reloc::GlobalData { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            Ppc64Reloc::GlobalData { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            Ppc64Reloc::JumpSlot { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 22 }),
+            Ppc64Reloc::Relative { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::UAddr32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 24 }),
+            Ppc64Reloc::UAddr32 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::UAddr16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 25 }),
+            Ppc64Reloc::UAddr16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            Ppc64Reloc::Rel32 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            Ppc64Reloc::PLT32 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            Ppc64Reloc::PLTRel32 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLT16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            Ppc64Reloc::PLT16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLT16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 30 }),
+            Ppc64Reloc::PLT16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLT16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 31 }),
+            Ppc64Reloc::PLT16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::SectOff { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 33 }),
+            Ppc64Reloc::SectOff { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::SectOffLo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 34 }),
+            Ppc64Reloc::SectOffLo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::SectOffHi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 35 }),
+            Ppc64Reloc::SectOffHi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::SectOffHA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 36 }),
+            Ppc64Reloc::SectOffHA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr30 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 37 }),
+            Ppc64Reloc::Addr30 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 38 }),
+            Ppc64Reloc::Addr64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16Higher { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 39 }),
+            Ppc64Reloc::Addr16Higher { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16HigherA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 40 }),
+            Ppc64Reloc::Addr16HigherA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16Highest { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 41 }),
+            Ppc64Reloc::Addr16Highest { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16HighestA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 42 }),
+            Ppc64Reloc::Addr16HighestA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::UAddr64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 43 }),
+            Ppc64Reloc::UAddr64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 44 }),
+            Ppc64Reloc::Rel64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLT64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 45 }),
+            Ppc64Reloc::PLT64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 46 }),
+            Ppc64Reloc::PLTRel64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 47 }),
+            Ppc64Reloc::TOC16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 48 }),
+            Ppc64Reloc::TOC16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 49 }),
+            Ppc64Reloc::TOC16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 50 }),
+            Ppc64Reloc::TOC16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 51 }),
+            Ppc64Reloc::TOC { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTGOT16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 52 }),
+            Ppc64Reloc::PLTGOT16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTGOT16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 53 }),
+            Ppc64Reloc::PLTGOT16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTGOT16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 54 }),
+            Ppc64Reloc::PLTGOT16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTGOT16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 55 }),
+            Ppc64Reloc::PLTGOT16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 56 }),
+            Ppc64Reloc::Addr16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 57 }),
+            Ppc64Reloc::Addr16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOT16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 58 }),
+            Ppc64Reloc::GOT16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOT16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 59 }),
+            Ppc64Reloc::GOT16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLT16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 60 }),
+            Ppc64Reloc::PLT16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::SectOffDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 61 }),
+            Ppc64Reloc::SectOffDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::SectOffLoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 62 }),
+            Ppc64Reloc::SectOffLoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 63 }),
+            Ppc64Reloc::TOC16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOC16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 64 }),
+            Ppc64Reloc::TOC16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTGOT16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 65 }),
+            Ppc64Reloc::PLTGOT16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::PLTGOT16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 66 }),
+            Ppc64Reloc::PLTGOT16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TLS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 67 }),
+            Ppc64Reloc::TLS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPMod64 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 68 }),
+            Ppc64Reloc::TPRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 69 }),
+            Ppc64Reloc::TPRel16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 70 }),
+            Ppc64Reloc::TPRel16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 71 }),
+            Ppc64Reloc::TPRel16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 72 }),
+            Ppc64Reloc::TPRel16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 73 }),
+            Ppc64Reloc::TPRel64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 74 }),
+            Ppc64Reloc::DTPRel16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 75 }),
+            Ppc64Reloc::DTPRel16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 76 }),
+            Ppc64Reloc::DTPRel16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 77 }),
+            Ppc64Reloc::DTPRel16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 78 }),
+            Ppc64Reloc::DTPRel64 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSGD16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 79 }),
+            Ppc64Reloc::GOTTLSGD16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSGD16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 80 }),
+            Ppc64Reloc::GOTTLSGD16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSGD16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 81 }),
+            Ppc64Reloc::GOTTLSGD16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSGD16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 82 }),
+            Ppc64Reloc::GOTTLSGD16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSLD16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 83 }),
+            Ppc64Reloc::GOTTLSLD16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSLD16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 84 }),
+            Ppc64Reloc::GOTTLSLD16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSLD16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 85 }),
+            Ppc64Reloc::GOTTLSLD16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTLSLD16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 86 }),
+            Ppc64Reloc::GOTTLSLD16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTPRel16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 87 }),
+            Ppc64Reloc::GOTTPRel16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTPRel16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 88 }),
+            Ppc64Reloc::GOTTPRel16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 89 }),
+            Ppc64Reloc::GOTTPRel16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTTPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 90 }),
+            Ppc64Reloc::GOTTPRel16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTDTPRel16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 91 }),
+            Ppc64Reloc::GOTDTPRel16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTDTPRel16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 92 }),
+            Ppc64Reloc::GOTDTPRel16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTDTPRel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 93 }),
+            Ppc64Reloc::GOTDTPRel16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::GOTDTPRel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 94 }),
+            Ppc64Reloc::GOTDTPRel16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 95 }),
+            Ppc64Reloc::TPRel16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 96 }),
+            Ppc64Reloc::TPRel16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16Higher { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 97 }),
+            Ppc64Reloc::TPRel16Higher { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16HigherA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 98 }),
+            Ppc64Reloc::TPRel16HigherA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16Highest { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 99 }),
+            Ppc64Reloc::TPRel16Highest { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16HighestA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 100 }),
+            Ppc64Reloc::TPRel16HighestA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16DS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 101 }),
+            Ppc64Reloc::DTPRel16DS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16LoDS { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 102 }),
+            Ppc64Reloc::DTPRel16LoDS { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16Higher { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 103 }),
+            Ppc64Reloc::DTPRel16Higher { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16HigherA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 104 }),
+            Ppc64Reloc::DTPRel16HigherA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16Highest { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 105 }),
+            Ppc64Reloc::DTPRel16Highest { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16HighestA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 106 }),
+            Ppc64Reloc::DTPRel16HighestA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TLSGD { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 107 }),
+            Ppc64Reloc::TLSGD { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TLSLD { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 108 }),
+            Ppc64Reloc::TLSLD { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TOCSave { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 109 }),
+            Ppc64Reloc::TOCSave { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16High { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 110 }),
+            Ppc64Reloc::Addr16High { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr16HighA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 111 }),
+            Ppc64Reloc::Addr16HighA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16High { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 112 }),
+            Ppc64Reloc::TPRel16High { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::TPRel16HighA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 113 }),
+            Ppc64Reloc::TPRel16HighA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16High { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 114 }),
+            Ppc64Reloc::DTPRel16High { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::DTPRel16HighA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 115 }),
+            Ppc64Reloc::DTPRel16HighA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel24NoTOC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 116 }),
+            Ppc64Reloc::Rel24NoTOC { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Addr64Local { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 117 }),
+            Ppc64Reloc::Addr64Local { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Entry { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 118 }),
+            Ppc64Reloc::Entry { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 248 }),
+            Ppc64Reloc::IRelative { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 249 }),
+            Ppc64Reloc::Rel16 { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel16Lo { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 250 }),
+            Ppc64Reloc::Rel16Lo { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel16Hi { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 251 }),
+            Ppc64Reloc::Rel16Hi { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend)),
+            Ppc64Reloc::Rel16HA { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 252 }),
+            Ppc64Reloc::Rel16HA { addend, .. } =>
+                Err(Ppc64ToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf64>> for Ppc64Reloc<Name> {
+    type Error = Ppc64RelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf64>) -> Result<Ppc64Reloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<Ppc64Reloc<u32>> for RelaData<u32, Elf64> {
+    #[inline]
+    fn from(rel: Ppc64Reloc<u32>) -> RelaData<u32, Elf64> {
+        match rel {
+            Ppc64Reloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            Ppc64Reloc::Addr32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
+            Ppc64Reloc::Addr24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
+            Ppc64Reloc::Addr16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 3, addend: addend },
+            Ppc64Reloc::Addr16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 4, addend: addend },
+            Ppc64Reloc::Addr16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 5, addend: addend },
+            Ppc64Reloc::Addr16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 6, addend: addend },
+            Ppc64Reloc::Addr14 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 7, addend: addend },
+            Ppc64Reloc::Addr14BrTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 8, addend: addend },
+            Ppc64Reloc::Addr14BrNTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 9, addend: addend },
+            Ppc64Reloc::Rel24 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
+            Ppc64Reloc::Rel14 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
+            Ppc64Reloc::Rel14BrTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 12, addend: addend },
+            Ppc64Reloc::Rel14BrNTaken { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 13, addend: addend },
+            Ppc64Reloc::GOT16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 14, addend: addend },
+            Ppc64Reloc::GOT16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 15, addend: addend },
+            Ppc64Reloc::GOT16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 16, addend: addend },
+            Ppc64Reloc::GOT16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 17, addend: addend },
+            Ppc64Reloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 19, addend: 0 },
+            Ppc64Reloc::GlobalData { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 20, addend: addend },
+            Ppc64Reloc::JumpSlot { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 21, addend: addend },
+            Ppc64Reloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 22, addend: addend },
+            Ppc64Reloc::UAddr32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 24, addend: addend },
+            Ppc64Reloc::UAddr16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 25, addend: addend },
+            Ppc64Reloc::Rel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 26, addend: addend },
+            Ppc64Reloc::PLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 27, addend: addend },
+            Ppc64Reloc::PLTRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 28, addend: addend },
+            Ppc64Reloc::PLT16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 29, addend: addend },
+            Ppc64Reloc::PLT16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 30, addend: addend },
+            Ppc64Reloc::PLT16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 31, addend: addend },
+            Ppc64Reloc::SectOff { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 33, addend: addend },
+            Ppc64Reloc::SectOffLo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 34, addend: addend },
+            Ppc64Reloc::SectOffHi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 35, addend: addend },
+            Ppc64Reloc::SectOffHA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 36, addend: addend },
+            Ppc64Reloc::Addr30 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 37, addend: addend },
+            Ppc64Reloc::Addr64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 38, addend: addend },
+            Ppc64Reloc::Addr16Higher { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 39, addend: addend },
+            Ppc64Reloc::Addr16HigherA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 40, addend: addend },
+            Ppc64Reloc::Addr16Highest { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 41, addend: addend },
+            Ppc64Reloc::Addr16HighestA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 42, addend: addend },
+            Ppc64Reloc::UAddr64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 43, addend: addend },
+            Ppc64Reloc::Rel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 44, addend: addend },
+            Ppc64Reloc::PLT64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 45, addend: addend },
+            Ppc64Reloc::PLTRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 46, addend: addend },
+            Ppc64Reloc::TOC16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 47, addend: addend },
+            Ppc64Reloc::TOC16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 48, addend: addend },
+            Ppc64Reloc::TOC16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 49, addend: addend },
+            Ppc64Reloc::TOC16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 50, addend: addend },
+            Ppc64Reloc::TOC { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 51, addend: addend },
+            Ppc64Reloc::PLTGOT16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 52, addend: addend },
+            Ppc64Reloc::PLTGOT16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 53, addend: addend },
+            Ppc64Reloc::PLTGOT16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 54, addend: addend },
+            Ppc64Reloc::PLTGOT16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 55, addend: addend },
+            Ppc64Reloc::Addr16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 56, addend: addend },
+            Ppc64Reloc::Addr16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 57, addend: addend },
+            Ppc64Reloc::GOT16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 58, addend: addend },
+            Ppc64Reloc::GOT16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 59, addend: addend },
+            Ppc64Reloc::PLT16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 60, addend: addend },
+            Ppc64Reloc::SectOffDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 61, addend: addend },
+            Ppc64Reloc::SectOffLoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 62, addend: addend },
+            Ppc64Reloc::TOC16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 63, addend: addend },
+            Ppc64Reloc::TOC16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 64, addend: addend },
+            Ppc64Reloc::PLTGOT16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 65, addend: addend },
+            Ppc64Reloc::PLTGOT16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 66, addend: addend },
+            Ppc64Reloc::TLS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 67, addend: addend },
+            Ppc64Reloc::DTPMod64 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 68, addend: 0 },
+            Ppc64Reloc::TPRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 69, addend: addend },
+            Ppc64Reloc::TPRel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 70, addend: addend },
+            Ppc64Reloc::TPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 71, addend: addend },
+            Ppc64Reloc::TPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 72, addend: addend },
+            Ppc64Reloc::TPRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 73, addend: addend },
+            Ppc64Reloc::DTPRel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 74, addend: addend },
+            Ppc64Reloc::DTPRel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 75, addend: addend },
+            Ppc64Reloc::DTPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 76, addend: addend },
+            Ppc64Reloc::DTPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 77, addend: addend },
+            Ppc64Reloc::DTPRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 78, addend: addend },
+            Ppc64Reloc::GOTTLSGD16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 79, addend: addend },
+            Ppc64Reloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 80, addend: addend },
+            Ppc64Reloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 81, addend: addend },
+            Ppc64Reloc::GOTTLSGD16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 82, addend: addend },
+            Ppc64Reloc::GOTTLSLD16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 83, addend: addend },
+            Ppc64Reloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 84, addend: addend },
+            Ppc64Reloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 85, addend: addend },
+            Ppc64Reloc::GOTTLSLD16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 86, addend: addend },
+            Ppc64Reloc::GOTTPRel16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 87, addend: addend },
+            Ppc64Reloc::GOTTPRel16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 88, addend: addend },
+            Ppc64Reloc::GOTTPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 89, addend: addend },
+            Ppc64Reloc::GOTTPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 90, addend: addend },
+            Ppc64Reloc::GOTDTPRel16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 91, addend: addend },
+            Ppc64Reloc::GOTDTPRel16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 92, addend: addend },
+            Ppc64Reloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 93, addend: addend },
+            Ppc64Reloc::GOTDTPRel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 94, addend: addend },
+            Ppc64Reloc::TPRel16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 95, addend: addend },
+            Ppc64Reloc::TPRel16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 96, addend: addend },
+            Ppc64Reloc::TPRel16Higher { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 97, addend: addend },
+            Ppc64Reloc::TPRel16HigherA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 98, addend: addend },
+            Ppc64Reloc::TPRel16Highest { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 99, addend: addend },
+            Ppc64Reloc::TPRel16HighestA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 100,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16DS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 101,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16LoDS { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 102,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16Higher { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 103,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16HigherA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 104,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16Highest { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 105,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16HighestA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 106,
+                           addend: addend },
+            Ppc64Reloc::TLSGD { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 107,
+                           addend: addend },
+            Ppc64Reloc::TLSLD { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 108,
+                           addend: addend },
+            Ppc64Reloc::TOCSave { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 109,
+                           addend: addend },
+            Ppc64Reloc::Addr16High { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 110,
+                           addend: addend },
+            Ppc64Reloc::Addr16HighA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 111,
+                           addend: addend },
+            Ppc64Reloc::TPRel16High { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 112,
+                           addend: addend },
+            Ppc64Reloc::TPRel16HighA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 113,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16High { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 114,
+                           addend: addend },
+            Ppc64Reloc::DTPRel16HighA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 115,
+                           addend: addend },
+            Ppc64Reloc::Rel24NoTOC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 116,
+                           addend: addend },
+            Ppc64Reloc::Addr64Local { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 117,
+                           addend: addend },
+            Ppc64Reloc::Entry { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 118, addend: addend },
+            Ppc64Reloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 248, addend: addend },
+            Ppc64Reloc::Rel16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 249,
+                           addend: addend },
+            Ppc64Reloc::Rel16Lo { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 250,
+                           addend: addend },
+            Ppc64Reloc::Rel16Hi { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 251,
+                           addend: addend },
+            Ppc64Reloc::Rel16HA { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 252, addend: addend }
+        }
+    }
+}
+
+impl<'a, B> WithSymtab<'a, B, Elf64> for Ppc64RelocRaw
+    where B: ByteOrder {
+    type Result = Ppc64RelocRawSym;
+    type Error = RelocSymtabError<Elf64>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Elf64>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            Ppc64Reloc::None => Ok(Ppc64Reloc::None),
+            Ppc64Reloc::Addr32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr24 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16Lo { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr14 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr14 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr14BrTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr14BrTaken { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr14BrNTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr14BrNTaken { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel24 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel24 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel14 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel14 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel14BrTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel14BrTaken { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel14BrNTaken { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel14BrNTaken { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOT16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOT16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOT16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOT16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOT16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOT16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOT16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOT16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Copy { offset: offset,
+                                                  sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GlobalData { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GlobalData { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::JumpSlot { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Relative { offset, addend } =>
+                Ok(Ppc64Reloc::Relative { offset: offset, addend: addend }),
+            Ppc64Reloc::UAddr32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::UAddr32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::UAddr16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::UAddr16 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLT32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTRel32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLT16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLT16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLT16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLT16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLT16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLT16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::SectOff { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::SectOff { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::SectOffLo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::SectOffLo { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::SectOffHi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::SectOffHi { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::SectOffHA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::SectOffHA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr30 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr30 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16Higher { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16Higher { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16HigherA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16HigherA { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16Highest { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16Highest { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16HighestA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16HighestA { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::UAddr64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::UAddr64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLT64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLT64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTRel64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOC16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOC16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOC16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOC16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC { offset, addend } =>
+                Ok(Ppc64Reloc::TOC { offset: offset, addend: addend }),
+            Ppc64Reloc::PLTGOT16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTGOT16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTGOT16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTGOT16Lo { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTGOT16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTGOT16Hi { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTGOT16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTGOT16HA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16DS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16LoDS { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOT16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOT16DS { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOT16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOT16LoDS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLT16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLT16LoDS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::SectOffDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::SectOffDS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::SectOffLoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::SectOffLoDS { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOC16DS { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOC16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOC16LoDS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTGOT16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTGOT16DS { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::PLTGOT16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::PLTGOT16LoDS { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TLS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TLS { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPMod64 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPMod64 { offset: offset,
+                                                      sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16Lo { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16Hi { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16HA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16Lo { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16Hi { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16HA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSGD16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSGD16 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSGD16Lo { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSGD16Hi { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSGD16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSGD16HA { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSLD16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSLD16 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSLD16Lo { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSLD16Hi { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTLSLD16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTLSLD16HA { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTPRel16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTPRel16DS { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTPRel16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTPRel16LoDS { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTPRel16Hi { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTTPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTTPRel16HA { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTDTPRel16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTDTPRel16DS { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTDTPRel16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTDTPRel16LoDS { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTDTPRel16Hi { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::GOTDTPRel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::GOTDTPRel16HA { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16DS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16LoDS { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16Higher { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16Higher { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16HigherA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16HigherA { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16Highest { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16Highest { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16HighestA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16HighestA { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16DS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16DS { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16LoDS { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16LoDS { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16Higher { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16Higher { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16HigherA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16HigherA { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16Highest { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16Highest { offset: offset,
+                                                             sym: symdata,
+                                                             addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16HighestA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16HighestA { offset: offset,
+                                                              sym: symdata,
+                                                              addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TLSGD { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TLSGD { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TLSLD { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TLSLD { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TOCSave { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TOCSave { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16High { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16High { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr16HighA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr16HighA { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16High { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16High { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::TPRel16HighA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::TPRel16HighA { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16High { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16High { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::DTPRel16HighA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::DTPRel16HighA { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel24NoTOC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel24NoTOC { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Addr64Local { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Addr64Local { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Entry { offset, addend } =>
+                Ok(Ppc64Reloc::Entry { offset: offset, addend: addend }),
+            Ppc64Reloc::IRelative { offset, addend } =>
+                Ok(Ppc64Reloc::IRelative { offset: offset, addend: addend }),
+            Ppc64Reloc::Rel16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel16Lo { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel16Lo { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel16Hi { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel16Hi { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
+            Ppc64Reloc::Rel16HA { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(Ppc64Reloc::Rel16HA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                }
+        }
+    }
+}
+
+impl<'a> WithStrtab<'a> for Ppc64RelocRawSym {
+    type Result = Ppc64RelocStrDataSym<'a>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            Ppc64Reloc::None => Ok(Ppc64Reloc::None),
+            Ppc64Reloc::Addr32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr24 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Lo { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Hi { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HA { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr14 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr14 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr14BrTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr14BrTaken { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr14BrNTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr14BrNTaken { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel24 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel24 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel14 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel14 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel14BrTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel14BrTaken { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel14BrNTaken { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel14BrNTaken { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GlobalData { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GlobalData { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::JumpSlot { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Relative { offset, addend } =>
+                Ok(Ppc64Reloc::Relative { offset: offset, addend: addend }),
+            Ppc64Reloc::UAddr32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::UAddr32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::UAddr16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::UAddr16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTRel32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOff { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOff { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffLo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffLo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffHi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffHi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffHA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffHA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr30 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr30 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Higher { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Higher { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HigherA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HigherA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Highest { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Highest { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HighestA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HighestA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::UAddr64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::UAddr64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTRel64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC { offset, addend } =>
+                Ok(Ppc64Reloc::TOC { offset: offset, addend: addend }),
+            Ppc64Reloc::PLTGOT16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16DS { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16LoDS { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16DS { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16LoDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16LoDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffLoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffLoDS { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16DS { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16LoDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16DS { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16LoDS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TLS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TLS { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPMod64 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPMod64 { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Lo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Hi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16Lo { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16Lo { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16DS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16LoDS { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16DS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16LoDS { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16Hi { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16HA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16DS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16LoDS { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Higher { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Higher { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HigherA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HigherA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Highest { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Highest { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HighestA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HighestA { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16DS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16DS { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16LoDS { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16LoDS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Higher { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Higher { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HigherA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HigherA { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Highest { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Highest { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HighestA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HighestA { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TLSGD { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TLSGD { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TLSLD { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TLSLD { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOCSave { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOCSave { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16High { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16High { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HighA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HighA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16High { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16High { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HighA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HighA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16High { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16High { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HighA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HighA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel24NoTOC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel24NoTOC { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr64Local { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr64Local { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Entry { offset, addend } =>
+                Ok(Ppc64Reloc::Entry { offset: offset, addend: addend }),
+            Ppc64Reloc::IRelative { offset, addend } =>
+                Ok(Ppc64Reloc::IRelative { offset: offset, addend: addend }),
+            Ppc64Reloc::Rel16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel16Lo { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel16Hi { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel16HA { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a> TryFrom<Ppc64RelocStrDataSym<'a>> for Ppc64RelocStrSym<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: Ppc64RelocStrDataSym<'a>) ->
+        Result<Ppc64RelocStrSym<'a>, Self::Error> {
+        match reloc {
+            Ppc64Reloc::None => Ok(Ppc64Reloc::None),
+            Ppc64Reloc::Addr32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr24 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Lo { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Hi { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HA { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr14 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr14 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr14BrTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr14BrTaken { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr14BrNTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr14BrNTaken { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel24 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel24 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel14 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel14 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel14BrTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel14BrTaken { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel14BrNTaken { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel14BrNTaken { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GlobalData { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GlobalData { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::JumpSlot { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Relative { offset, addend } =>
+                Ok(Ppc64Reloc::Relative { offset: offset, addend: addend }),
+            Ppc64Reloc::UAddr32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::UAddr32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::UAddr16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::UAddr16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTRel32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOff { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOff { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffLo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffLo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffHi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffHi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffHA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffHA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr30 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr30 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Higher { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Higher { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HigherA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HigherA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16Highest { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16Highest { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HighestA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HighestA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::UAddr64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::UAddr64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTRel64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC { offset, addend } =>
+                Ok(Ppc64Reloc::TOC { offset: offset, addend: addend }),
+            Ppc64Reloc::PLTGOT16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16DS { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16LoDS { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16DS { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOT16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOT16LoDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLT16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLT16LoDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::SectOffLoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::SectOffLoDS { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16DS { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOC16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOC16LoDS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16DS { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::PLTGOT16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::PLTGOT16LoDS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TLS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TLS { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPMod64 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPMod64 { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Lo { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Hi { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HA { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Lo { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Hi { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HA { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16Lo { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSGD16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSGD16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16Lo { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTLSLD16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTLSLD16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16DS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16LoDS { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16Hi { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTTPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTTPRel16HA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16DS { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16LoDS { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16Hi { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::GOTDTPRel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::GOTDTPRel16HA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16DS { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16LoDS { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Higher { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Higher { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HigherA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HigherA { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16Highest { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16Highest { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HighestA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HighestA { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16DS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16DS { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16LoDS { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16LoDS { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Higher { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Higher { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HigherA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HigherA { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16Highest { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16Highest { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HighestA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HighestA { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TLSGD { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TLSGD { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TLSLD { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TLSLD { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TOCSave { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TOCSave { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16High { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16High { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr16HighA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr16HighA { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16High { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16High { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::TPRel16HighA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::TPRel16HighA { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16High { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16High { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::DTPRel16HighA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::DTPRel16HighA { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel24NoTOC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel24NoTOC { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Addr64Local { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Addr64Local { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Entry { offset, addend } =>
+                Ok(Ppc64Reloc::Entry { offset: offset, addend: addend }),
+            Ppc64Reloc::IRelative { offset, addend } =>
+                Ok(Ppc64Reloc::IRelative { offset: offset, addend: addend }),
+            Ppc64Reloc::Rel16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel16Lo { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16Lo { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel16Hi { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16Hi { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            Ppc64Reloc::Rel16HA { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(Ppc64Reloc::Rel16HA { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a> From<Ppc64RelocStrDataSym<'a>> for Ppc64RelocStrData<'a> {
+    #[inline]
+    fn from(reloc: Ppc64RelocStrDataSym<'a>) -> Ppc64RelocStrData<'a> {
+        match reloc {
+            Ppc64Reloc::None => Ppc64Reloc::None,
+            Ppc64Reloc::Addr32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr32 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr24 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr24 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr16 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr16Lo { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16Lo { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr16Hi { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16Hi { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr16HA { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16HA { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr14 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr14 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr14BrTaken { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Addr14BrTaken { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Addr14BrNTaken { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::Addr14BrNTaken { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::Rel24 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel24 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::Rel14 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel14 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::Rel14BrTaken { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::Rel14BrTaken { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::Rel14BrNTaken { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Rel14BrNTaken { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::GOT16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::GOT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::GOT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::GOT16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16HA { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Copy { sym: SymData { name, .. }, offset } =>
+                Ppc64Reloc::Copy { offset: offset, sym: name },
+            Ppc64Reloc::GlobalData { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::GlobalData { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::JumpSlot { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Relative { offset, addend } =>
+                Ppc64Reloc::Relative { offset: offset, addend: addend },
+            Ppc64Reloc::UAddr32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::UAddr32 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::UAddr16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::UAddr16 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel32 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT32 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLTRel32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::PLTRel32 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::PLT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::PLT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::PLT16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT16HA { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::SectOff { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::SectOff { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::SectOffLo { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffLo { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffHi { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffHi { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffHA { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffHA { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::Addr30 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr30 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr64 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr16Higher { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::Addr16Higher { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::Addr16HigherA { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Addr16HigherA { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Addr16Highest { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Addr16Highest { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Addr16HighestA { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::Addr16HighestA { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::UAddr64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::UAddr64 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel64 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLT64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT64 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLTRel64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::PLTRel64 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::TOC16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::TOC16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16HA { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC { offset, addend } =>
+                Ppc64Reloc::TOC { offset: offset, addend: addend },
+            Ppc64Reloc::PLTGOT16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::PLTGOT16 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::PLTGOT16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::PLTGOT16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::PLTGOT16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16HA { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::Addr16DS { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16DS { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr16LoDS { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::Addr16LoDS { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::GOT16DS { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16DS { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::GOT16LoDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::GOT16LoDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::PLT16LoDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::PLT16LoDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffLoDS { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::SectOffLoDS { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TOC16DS { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16DS { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC16LoDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TOC16LoDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::PLTGOT16DS { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16DS { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::PLTGOT16LoDS { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::PLTGOT16LoDS { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::TLS { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TLS { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::DTPMod64 { sym: SymData { name, .. }, offset } =>
+                Ppc64Reloc::DTPMod64 { offset: offset, sym: name },
+            Ppc64Reloc::TPRel16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TPRel16 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TPRel16Lo { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16Lo { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel16Hi { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16Hi { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel16HA { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16HA { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TPRel64 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::DTPRel16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::DTPRel16 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::DTPRel16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16HA { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::DTPRel64 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::GOTTLSGD16 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::GOTTLSGD16 { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::GOTTLSGD16Lo { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSGD16Lo { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSGD16Hi { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSGD16Hi { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSGD16HA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSGD16HA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSLD16 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::GOTTLSLD16 { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::GOTTLSLD16Lo { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSLD16Lo { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSLD16Hi { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSLD16Hi { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSLD16HA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSLD16HA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTPRel16DS { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTPRel16DS { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTPRel16LoDS { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::GOTTPRel16LoDS { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::GOTTPRel16Hi { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTPRel16Hi { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTPRel16HA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTPRel16HA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTDTPRel16DS { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::GOTDTPRel16DS { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::GOTDTPRel16LoDS { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::GOTDTPRel16LoDS { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::GOTDTPRel16Hi { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::GOTDTPRel16Hi { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::GOTDTPRel16HA { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::GOTDTPRel16HA { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::TPRel16DS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16DS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel16LoDS { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::TPRel16LoDS { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TPRel16Higher { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::TPRel16Higher { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::TPRel16HigherA { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::TPRel16HigherA { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::TPRel16Highest { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::TPRel16Highest { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::TPRel16HighestA { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::TPRel16HighestA { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::DTPRel16DS { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16DS { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel16LoDS { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::DTPRel16LoDS { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::DTPRel16Higher { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::DTPRel16Higher { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::DTPRel16HigherA { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::DTPRel16HigherA { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::DTPRel16Highest { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::DTPRel16Highest { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::DTPRel16HighestA { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                Ppc64Reloc::DTPRel16HighestA { offset: offset, sym: name,
+                                               addend: addend },
+            Ppc64Reloc::TLSGD { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TLSGD { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::TLSLD { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TLSLD { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::TOCSave { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOCSave { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Addr16High { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::Addr16High { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::Addr16HighA { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::Addr16HighA { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TPRel16High { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::TPRel16High { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TPRel16HighA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::TPRel16HighA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::DTPRel16High { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::DTPRel16High { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::DTPRel16HighA { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::DTPRel16HighA { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Rel24NoTOC { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::Rel24NoTOC { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::Addr64Local { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::Addr64Local { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::Entry { offset, addend } =>
+                Ppc64Reloc::Entry { offset: offset, addend: addend },
+            Ppc64Reloc::IRelative { offset, addend } =>
+                Ppc64Reloc::IRelative { offset: offset, addend: addend },
+            Ppc64Reloc::Rel16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::Rel16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16HA { offset: offset, sym: name,
+                                      addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<Ppc64RelocStrData<'a>> for Ppc64RelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: Ppc64RelocStrData<'a>) ->
+        Result<Ppc64RelocStr<'a>, Self::Error> {
+        match reloc {
+            Ppc64Reloc::None => Ok(Ppc64Reloc::None),
+            Ppc64Reloc::Addr32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            Ppc64Reloc::Addr32 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr32 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr32 { offset: offset, sym: None,
+                                        addend: addend }),
+            Ppc64Reloc::Addr24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr24 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            Ppc64Reloc::Addr24 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr24 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr24 { offset: offset, sym: None,
+                                        addend: addend }),
+            Ppc64Reloc::Addr16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            Ppc64Reloc::Addr16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16 { offset: offset, sym: None,
+                                        addend: addend }),
+            Ppc64Reloc::Addr16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Lo { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::Addr16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Lo { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::Addr16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Hi { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::Addr16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Hi { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::Addr16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HA { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::Addr16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HA { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::Addr14 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr14 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            Ppc64Reloc::Addr14 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr14 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr14 { offset: offset, sym: None,
+                                        addend: addend }),
+            Ppc64Reloc::Addr14BrTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr14BrTaken { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::Addr14BrTaken { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr14BrTaken { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr14BrTaken { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::Addr14BrNTaken { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(Ppc64Reloc::Addr14BrNTaken { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            Ppc64Reloc::Addr14BrNTaken { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr14BrNTaken { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr14BrNTaken { offset: offset, sym: None,
+                                                addend: addend }),
+            Ppc64Reloc::Rel24 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel24 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::Rel24 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel24 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel24 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::Rel14 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel14 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::Rel14 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel14 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel14 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::Rel14BrTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel14BrTaken { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::Rel14BrTaken { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel14BrTaken { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel14BrTaken { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::Rel14BrNTaken { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel14BrNTaken { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::Rel14BrNTaken { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel14BrNTaken { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel14BrNTaken { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::GOT16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOT16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::GOT16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOT16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOT16 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::GOT16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOT16Lo { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::GOT16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOT16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOT16Lo { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::GOT16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOT16Hi { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::GOT16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOT16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOT16Hi { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::GOT16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOT16HA { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::GOT16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOT16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOT16HA { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(Ppc64Reloc::Copy { offset: offset, sym: Some(name) }),
+            Ppc64Reloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Copy { sym: None, offset } =>
+                Ok(Ppc64Reloc::Copy { offset: offset, sym: None }),
+            Ppc64Reloc::GlobalData { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GlobalData { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::GlobalData { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GlobalData { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GlobalData { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::JumpSlot { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::JumpSlot { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::Relative { offset, addend } =>
+                Ok(Ppc64Reloc::Relative { offset: offset, addend: addend }),
+            Ppc64Reloc::UAddr32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::UAddr32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::UAddr32 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::UAddr32 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::UAddr32 { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::UAddr16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::UAddr16 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::UAddr16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::UAddr16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::UAddr16 { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::Rel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::Rel32 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel32 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel32 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLT32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLT32 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLT32 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::PLTRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTRel32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::PLTRel32 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTRel32 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTRel32 { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::PLT16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLT16Lo { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::PLT16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLT16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLT16Lo { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::PLT16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLT16Hi { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::PLT16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLT16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLT16Hi { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::PLT16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLT16HA { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::PLT16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLT16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLT16HA { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::SectOff { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::SectOff { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::SectOff { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::SectOff { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::SectOff { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::SectOffLo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::SectOffLo { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::SectOffLo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::SectOffLo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::SectOffLo { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::SectOffHi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::SectOffHi { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::SectOffHi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::SectOffHi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::SectOffHi { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::SectOffHA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::SectOffHA { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::SectOffHA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::SectOffHA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::SectOffHA { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::Addr30 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr30 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            Ppc64Reloc::Addr30 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr30 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr30 { offset: offset, sym: None,
+                                        addend: addend }),
+            Ppc64Reloc::Addr64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr64 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            Ppc64Reloc::Addr64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr64 { offset: offset, sym: None,
+                                        addend: addend }),
+            Ppc64Reloc::Addr16Higher { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Higher { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::Addr16Higher { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16Higher { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Higher { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::Addr16HigherA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HigherA { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::Addr16HigherA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16HigherA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HigherA { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::Addr16Highest { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Highest { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::Addr16Highest { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16Highest { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16Highest { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::Addr16HighestA { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(Ppc64Reloc::Addr16HighestA { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            Ppc64Reloc::Addr16HighestA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16HighestA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HighestA { offset: offset, sym: None,
+                                                addend: addend }),
+            Ppc64Reloc::UAddr64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::UAddr64 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::UAddr64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::UAddr64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::UAddr64 { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::Rel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::Rel64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel64 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::PLT64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLT64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::PLT64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLT64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLT64 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::PLTRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTRel64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::PLTRel64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTRel64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTRel64 { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::TOC16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOC16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::TOC16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOC16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOC16 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::TOC16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOC16Lo { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TOC16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOC16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOC16Lo { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::TOC16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOC16Hi { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TOC16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOC16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOC16Hi { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::TOC16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOC16HA { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TOC16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOC16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOC16HA { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::TOC { offset, addend } =>
+                Ok(Ppc64Reloc::TOC { offset: offset, addend: addend }),
+            Ppc64Reloc::PLTGOT16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::PLTGOT16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTGOT16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16 { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::PLTGOT16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16Lo { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTGOT16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16Lo { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16Hi { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTGOT16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16Hi { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16HA { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTGOT16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16HA { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::Addr16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16DS { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::Addr16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16DS { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::Addr16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16LoDS { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::Addr16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16LoDS { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::GOT16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOT16DS { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::GOT16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOT16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOT16DS { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::GOT16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOT16LoDS { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::GOT16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOT16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOT16LoDS { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::PLT16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLT16LoDS { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::PLT16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLT16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLT16LoDS { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::SectOffDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::SectOffDS { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::SectOffDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::SectOffDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::SectOffDS { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::SectOffLoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::SectOffLoDS { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            Ppc64Reloc::SectOffLoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::SectOffLoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::SectOffLoDS { offset: offset, sym: None,
+                                             addend: addend }),
+            Ppc64Reloc::TOC16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOC16DS { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TOC16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOC16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOC16DS { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::TOC16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOC16LoDS { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::TOC16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOC16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOC16LoDS { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::PLTGOT16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16DS { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTGOT16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16DS { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::PLTGOT16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16LoDS { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::PLTGOT16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::PLTGOT16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::PLTGOT16LoDS { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::TLS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TLS { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            Ppc64Reloc::TLS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TLS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TLS { offset: offset, sym: None,
+                                     addend: addend }),
+            Ppc64Reloc::DTPMod64 { sym: Some(Ok(name)), offset } =>
+                Ok(Ppc64Reloc::DTPMod64 { offset: offset, sym: Some(name) }),
+            Ppc64Reloc::DTPMod64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPMod64 { sym: None, offset } =>
+                Ok(Ppc64Reloc::DTPMod64 { offset: offset, sym: None }),
+            Ppc64Reloc::TPRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TPRel16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16 { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::TPRel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Lo { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Lo { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Hi { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Hi { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16HA { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16HA { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::TPRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel64 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TPRel64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel64 { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::DTPRel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::DTPRel16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16 { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::DTPRel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16Lo { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16Lo { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16Hi { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16Hi { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16HA { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16HA { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            Ppc64Reloc::DTPRel64 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel64 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel64 { offset: offset, sym: None,
+                                          addend: addend }),
+            Ppc64Reloc::GOTTLSGD16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::GOTTLSGD16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSGD16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16 { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::GOTTLSGD16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16Lo { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSGD16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSGD16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16Lo { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSGD16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16Hi { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSGD16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSGD16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16Hi { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSGD16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16HA { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSGD16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSGD16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSGD16HA { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSLD16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::GOTTLSLD16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSLD16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16 { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::GOTTLSLD16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16Lo { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSLD16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSLD16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16Lo { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSLD16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16Hi { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSLD16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSLD16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16Hi { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSLD16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16HA { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTLSLD16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTLSLD16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTLSLD16HA { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTPRel16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16DS { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTPRel16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTPRel16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16DS { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTPRel16LoDS { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16LoDS { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            Ppc64Reloc::GOTTPRel16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTPRel16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16LoDS { offset: offset, sym: None,
+                                                addend: addend }),
+            Ppc64Reloc::GOTTPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16Hi { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTPRel16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16Hi { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTTPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16HA { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::GOTTPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTTPRel16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTTPRel16HA { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::GOTDTPRel16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16DS { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::GOTDTPRel16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTDTPRel16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16DS { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::GOTDTPRel16LoDS { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16LoDS { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            Ppc64Reloc::GOTDTPRel16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTDTPRel16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16LoDS { offset: offset, sym: None,
+                                                 addend: addend }),
+            Ppc64Reloc::GOTDTPRel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16Hi { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::GOTDTPRel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTDTPRel16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16Hi { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::GOTDTPRel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16HA { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::GOTDTPRel16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::GOTDTPRel16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::GOTDTPRel16HA { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::TPRel16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16DS { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16DS { offset: offset, sym: None,
+                                           addend: addend }),
+            Ppc64Reloc::TPRel16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16LoDS { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            Ppc64Reloc::TPRel16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16LoDS { offset: offset, sym: None,
+                                             addend: addend }),
+            Ppc64Reloc::TPRel16Higher { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Higher { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::TPRel16Higher { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16Higher { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Higher { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::TPRel16HigherA { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(Ppc64Reloc::TPRel16HigherA { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            Ppc64Reloc::TPRel16HigherA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16HigherA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16HigherA { offset: offset, sym: None,
+                                                addend: addend }),
+            Ppc64Reloc::TPRel16Highest { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(Ppc64Reloc::TPRel16Highest { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            Ppc64Reloc::TPRel16Highest { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16Highest { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16Highest { offset: offset, sym: None,
+                                                addend: addend }),
+            Ppc64Reloc::TPRel16HighestA { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(Ppc64Reloc::TPRel16HighestA { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            Ppc64Reloc::TPRel16HighestA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16HighestA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16HighestA { offset: offset, sym: None,
+                                                 addend: addend }),
+            Ppc64Reloc::DTPRel16DS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16DS { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16DS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16DS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16DS { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::DTPRel16LoDS { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16LoDS { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::DTPRel16LoDS { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16LoDS { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16LoDS { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::DTPRel16Higher { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(Ppc64Reloc::DTPRel16Higher { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            Ppc64Reloc::DTPRel16Higher { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16Higher { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16Higher { offset: offset, sym: None,
+                                                addend: addend }),
+            Ppc64Reloc::DTPRel16HigherA { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(Ppc64Reloc::DTPRel16HigherA { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            Ppc64Reloc::DTPRel16HigherA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16HigherA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16HigherA { offset: offset, sym: None,
+                                                 addend: addend }),
+            Ppc64Reloc::DTPRel16Highest { sym: Some(Ok(name)), offset,
+                                          addend } =>
+                Ok(Ppc64Reloc::DTPRel16Highest { offset: offset,
+                                                 sym: Some(name),
+                                                 addend: addend }),
+            Ppc64Reloc::DTPRel16Highest { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16Highest { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16Highest { offset: offset, sym: None,
+                                                 addend: addend }),
+            Ppc64Reloc::DTPRel16HighestA { sym: Some(Ok(name)), offset,
+                                           addend } =>
+                Ok(Ppc64Reloc::DTPRel16HighestA { offset: offset,
+                                                  sym: Some(name),
+                                                  addend: addend }),
+            Ppc64Reloc::DTPRel16HighestA { sym: Some(Err(err)), .. } =>
+                Err(err),
+            Ppc64Reloc::DTPRel16HighestA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16HighestA { offset: offset, sym: None,
+                                                  addend: addend }),
+            Ppc64Reloc::TLSGD { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TLSGD { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::TLSGD { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TLSGD { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TLSGD { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::TLSLD { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TLSLD { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::TLSLD { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TLSLD { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TLSLD { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::TOCSave { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TOCSave { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::TOCSave { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TOCSave { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TOCSave { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::Addr16High { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16High { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::Addr16High { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16High { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16High { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::Addr16HighA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HighA { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            Ppc64Reloc::Addr16HighA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr16HighA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr16HighA { offset: offset, sym: None,
+                                             addend: addend }),
+            Ppc64Reloc::TPRel16High { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16High { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            Ppc64Reloc::TPRel16High { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16High { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16High { offset: offset, sym: None,
+                                             addend: addend }),
+            Ppc64Reloc::TPRel16HighA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16HighA { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::TPRel16HighA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::TPRel16HighA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::TPRel16HighA { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::DTPRel16High { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16High { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            Ppc64Reloc::DTPRel16High { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16High { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16High { offset: offset, sym: None,
+                                              addend: addend }),
+            Ppc64Reloc::DTPRel16HighA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16HighA { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            Ppc64Reloc::DTPRel16HighA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::DTPRel16HighA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::DTPRel16HighA { offset: offset, sym: None,
+                                               addend: addend }),
+            Ppc64Reloc::Rel24NoTOC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel24NoTOC { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            Ppc64Reloc::Rel24NoTOC { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel24NoTOC { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel24NoTOC { offset: offset, sym: None,
+                                            addend: addend }),
+            Ppc64Reloc::Addr64Local { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Addr64Local { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            Ppc64Reloc::Addr64Local { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Addr64Local { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Addr64Local { offset: offset, sym: None,
+                                             addend: addend }),
+            Ppc64Reloc::Entry { offset, addend } =>
+                Ok(Ppc64Reloc::Entry { offset: offset, addend: addend }),
+            Ppc64Reloc::IRelative { offset, addend } =>
+                Ok(Ppc64Reloc::IRelative { offset: offset, addend: addend }),
+            Ppc64Reloc::Rel16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            Ppc64Reloc::Rel16 { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel16 { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel16 { offset: offset, sym: None,
+                                       addend: addend }),
+            Ppc64Reloc::Rel16Lo { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel16Lo { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::Rel16Lo { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel16Lo { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel16Lo { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::Rel16Hi { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel16Hi { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::Rel16Hi { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel16Hi { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel16Hi { offset: offset, sym: None,
+                                         addend: addend }),
+            Ppc64Reloc::Rel16HA { sym: Some(Ok(name)), offset, addend } =>
+                Ok(Ppc64Reloc::Rel16HA { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            Ppc64Reloc::Rel16HA { sym: Some(Err(err)), .. } => Err(err),
+            Ppc64Reloc::Rel16HA { sym: None, offset, addend } =>
+                Ok(Ppc64Reloc::Rel16HA { offset: offset, sym: None,
+                                         addend: addend })
+        }
+    }
+}
+
+impl<'a> From<Ppc64RelocStrSym<'a>> for Ppc64RelocStr<'a> {
+    #[inline]
+    fn from(reloc: Ppc64RelocStrSym<'a>) -> Ppc64RelocStr<'a> {
+        match reloc {
+            Ppc64Reloc::None => Ppc64Reloc::None,
+            Ppc64Reloc::Addr32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr32 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr24 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr24 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr16 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr16Lo { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16Lo { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr16Hi { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16Hi { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr16HA { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16HA { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr14 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr14 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr14BrTaken { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Addr14BrTaken { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Addr14BrNTaken { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::Addr14BrNTaken { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::Rel24 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel24 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::Rel14 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel14 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::Rel14BrTaken { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::Rel14BrTaken { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::Rel14BrNTaken { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Rel14BrNTaken { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::GOT16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::GOT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::GOT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::GOT16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16HA { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Copy { sym: SymData { name, .. }, offset } =>
+                Ppc64Reloc::Copy { offset: offset, sym: name },
+            Ppc64Reloc::GlobalData { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::GlobalData { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::JumpSlot { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Relative { offset, addend } =>
+                Ppc64Reloc::Relative { offset: offset, addend: addend },
+            Ppc64Reloc::UAddr32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::UAddr32 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::UAddr16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::UAddr16 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel32 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT32 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLTRel32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::PLTRel32 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::PLT16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::PLT16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::PLT16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT16HA { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::SectOff { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::SectOff { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::SectOffLo { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffLo { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffHi { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffHi { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffHA { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffHA { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::Addr30 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr30 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Addr64 { offset: offset, sym: name,
+                                     addend: addend },
+            Ppc64Reloc::Addr16Higher { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::Addr16Higher { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::Addr16HigherA { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Addr16HigherA { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Addr16Highest { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::Addr16Highest { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Addr16HighestA { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::Addr16HighestA { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::UAddr64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::UAddr64 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel64 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLT64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::PLT64 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::PLTRel64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::PLTRel64 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::TOC16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::TOC16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16HA { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC { offset, addend } =>
+                Ppc64Reloc::TOC { offset: offset, addend: addend },
+            Ppc64Reloc::PLTGOT16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::PLTGOT16 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::PLTGOT16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::PLTGOT16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::PLTGOT16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16HA { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::Addr16DS { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::Addr16DS { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::Addr16LoDS { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::Addr16LoDS { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::GOT16DS { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::GOT16DS { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::GOT16LoDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::GOT16LoDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::PLT16LoDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::PLT16LoDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::SectOffDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::SectOffLoDS { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::SectOffLoDS { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TOC16DS { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOC16DS { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TOC16LoDS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TOC16LoDS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::PLTGOT16DS { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::PLTGOT16DS { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::PLTGOT16LoDS { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::PLTGOT16LoDS { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::TLS { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TLS { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::DTPMod64 { sym: SymData { name, .. }, offset } =>
+                Ppc64Reloc::DTPMod64 { offset: offset, sym: name },
+            Ppc64Reloc::TPRel16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TPRel16 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::TPRel16Lo { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16Lo { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel16Hi { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16Hi { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel16HA { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16HA { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel64 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TPRel64 { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::DTPRel16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::DTPRel16 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::DTPRel16Lo { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16Lo { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel16Hi { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16Hi { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel16HA { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16HA { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                Ppc64Reloc::DTPRel64 { offset: offset, sym: name,
+                                       addend: addend },
+            Ppc64Reloc::GOTTLSGD16 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::GOTTLSGD16 { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::GOTTLSGD16Lo { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSGD16Lo { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSGD16Hi { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSGD16Hi { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSGD16HA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSGD16HA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSLD16 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::GOTTLSLD16 { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::GOTTLSLD16Lo { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSLD16Lo { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSLD16Hi { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSLD16Hi { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTLSLD16HA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTLSLD16HA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTPRel16DS { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTPRel16DS { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTPRel16LoDS { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::GOTTPRel16LoDS { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::GOTTPRel16Hi { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTPRel16Hi { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTTPRel16HA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::GOTTPRel16HA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::GOTDTPRel16DS { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::GOTDTPRel16DS { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::GOTDTPRel16LoDS { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::GOTDTPRel16LoDS { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::GOTDTPRel16Hi { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::GOTDTPRel16Hi { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::GOTDTPRel16HA { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::GOTDTPRel16HA { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::TPRel16DS { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                Ppc64Reloc::TPRel16DS { offset: offset, sym: name,
+                                        addend: addend },
+            Ppc64Reloc::TPRel16LoDS { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::TPRel16LoDS { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TPRel16Higher { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::TPRel16Higher { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::TPRel16HigherA { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::TPRel16HigherA { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::TPRel16Highest { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::TPRel16Highest { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::TPRel16HighestA { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::TPRel16HighestA { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::DTPRel16DS { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::DTPRel16DS { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::DTPRel16LoDS { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::DTPRel16LoDS { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::DTPRel16Higher { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                Ppc64Reloc::DTPRel16Higher { offset: offset, sym: name,
+                                             addend: addend },
+            Ppc64Reloc::DTPRel16HigherA { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::DTPRel16HigherA { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::DTPRel16Highest { sym: SymData { name, .. }, offset,
+                                          addend } =>
+                Ppc64Reloc::DTPRel16Highest { offset: offset, sym: name,
+                                              addend: addend },
+            Ppc64Reloc::DTPRel16HighestA { sym: SymData { name, .. }, offset,
+                                           addend } =>
+                Ppc64Reloc::DTPRel16HighestA { offset: offset, sym: name,
+                                               addend: addend },
+            Ppc64Reloc::TLSGD { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TLSGD { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::TLSLD { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TLSLD { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::TOCSave { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::TOCSave { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Addr16High { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::Addr16High { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::Addr16HighA { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::Addr16HighA { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TPRel16High { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::TPRel16High { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::TPRel16HighA { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::TPRel16HighA { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::DTPRel16High { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                Ppc64Reloc::DTPRel16High { offset: offset, sym: name,
+                                           addend: addend },
+            Ppc64Reloc::DTPRel16HighA { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                Ppc64Reloc::DTPRel16HighA { offset: offset, sym: name,
+                                            addend: addend },
+            Ppc64Reloc::Rel24NoTOC { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                Ppc64Reloc::Rel24NoTOC { offset: offset, sym: name,
+                                         addend: addend },
+            Ppc64Reloc::Addr64Local { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                Ppc64Reloc::Addr64Local { offset: offset, sym: name,
+                                          addend: addend },
+            Ppc64Reloc::Entry { offset, addend } =>
+                Ppc64Reloc::Entry { offset: offset, addend: addend },
+            Ppc64Reloc::IRelative { offset, addend } =>
+                Ppc64Reloc::IRelative { offset: offset, addend: addend },
+            Ppc64Reloc::Rel16 { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16 { offset: offset, sym: name, addend: addend },
+            Ppc64Reloc::Rel16Lo { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16Lo { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel16Hi { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16Hi { offset: offset, sym: name,
+                                      addend: addend },
+            Ppc64Reloc::Rel16HA { sym: SymData { name, .. }, offset, addend } =>
+                Ppc64Reloc::Rel16HA { offset: offset, sym: name,
+                                      addend: addend }
+        }
+    }
+}
+
+/// Mask for the local entry point bits in `st_other`.
+const STO_PPC64_LOCAL_MASK: u8 = 0xe0;
+
+/// Shift for the local entry point bits in `st_other`.
+const STO_PPC64_LOCAL_BIT: u8 = 5;
+
+/// Get the offset of an ELFv2 function's local entry point from its
+/// global entry point, given the symbol's `st_other` field.
+///
+/// Functions that set up a TOC pointer have a global entry point,
+/// which is the symbol value, followed by a local entry point that
+/// callers sharing the same TOC may branch to directly.  The
+/// distance between them is encoded in the top three bits of
+/// `st_other`.  Returns `None` for the reserved encoding.
+///
+/// # Examples
+///
+/// ```
+/// use elf_utils::reloc::ppc64::local_entry_offset;
+///
+/// assert_eq!(local_entry_offset(0x00), Some(0));
+/// assert_eq!(local_entry_offset(0x60), Some(8));
+/// assert_eq!(local_entry_offset(0xe0), None);
+/// ```
+#[inline]
+pub fn local_entry_offset(other: u8) -> Option<u64> {
+    match (other & STO_PPC64_LOCAL_MASK) >> STO_PPC64_LOCAL_BIT {
+        0 | 1 => Some(0),
+        7 => None,
+        bits => Some(1 << bits)
+    }
+}
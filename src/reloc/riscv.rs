@@ -0,0 +1,3582 @@
+//! Relocation types for RISC-V architecture.
+//!
+//! This module provides the `RiscVReloc` type, which describes the
+//! relocation entries for the RISC-V architecture, as defined by the
+//! RISC-V ELF psABI.  The same relocation types are used by both RV32
+//! and RV64, so these can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with either
+//! [Elf32](crate::Elf32) or [Elf64](crate::Elf64) as the
+//! [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf32;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::SymOffsets;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for RISC-V architectures.
+///
+/// This datatype provides a semantic-level presentation of the RISC-V
+/// relocation entries, covering both the RV32 and RV64 base ISAs as
+/// well as the compressed extension.  These can be converted to and
+/// from [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) or
+/// [Elf64](crate::Elf64) as the [ElfClass](crate::ElfClass) type
+/// argument using the [TryFrom](core::convert::TryFrom) instances for
+/// easier handling.  Offsets and addends are always stored at 64-bit
+/// width; converting back to a 32-bit relocation fails if they do not
+/// fit.
+///
+/// Many of these relocations, such as [Relax](RiscVReloc::Relax),
+/// [Align](RiscVReloc::Align), and the paired
+/// [Add32](RiscVReloc::Add32)/[Sub32](RiscVReloc::Sub32) relocations,
+/// exist to support linker relaxation, which may shrink code
+/// sequences after assembly.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum RiscVReloc<Name> {
+    /// Null relocation.
+    None,
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Abs32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`.
+    Abs64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Offset relative to the image base.
+    ///
+    /// Set the word at `offset` to the sum of the base address and
+    /// `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of `sym`.
+    JumpSlot {
+        /// Offset in the PLT.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 32-bit thread-local storage module index.
+    ///
+    /// Set the 4-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 64-bit thread-local storage module index.
+    ///
+    /// Set the 8-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 32-bit thread-local storage block offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    TLSDTPRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread-local storage block offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of
+    /// `sym + addend` in its thread-local storage block.
+    TLSDTPRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TLSTPRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of
+    /// `sym + addend` from the thread pointer.
+    TLSTPRel64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit PC-relative conditional branch.
+    ///
+    /// Set the offset field of the B-type conditional branch
+    /// instruction at `offset` to the relative address of
+    /// `sym + addend`.
+    Branch {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 20-bit PC-relative jump.
+    ///
+    /// Set the offset field of the J-type `jal` instruction at
+    /// `offset` to the relative address of `sym + addend`.
+    Jal {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative call.
+    ///
+    /// Set the offset fields of the `auipc` and `jalr` instruction
+    /// pair at `offset` to the relative address of `sym + addend`.
+    Call {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative call through the PLT.
+    ///
+    /// Set the offset fields of the `auipc` and `jalr` instruction
+    /// pair at `offset` to the relative address of the Procedure
+    /// Linkage Table entry for `sym + addend`.
+    CallPLT {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a PC-relative GOT entry address.
+    ///
+    /// Set the immediate field of the `auipc` instruction at `offset`
+    /// to the high 20 bits of the relative address of the Global
+    /// Offset Table entry for `sym + addend`.
+    GOTHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a PC-relative initial-exec TLS GOT entry.
+    ///
+    /// Set the immediate field of the `auipc` instruction at `offset`
+    /// to the high 20 bits of the relative address of the Global
+    /// Offset Table entry holding the thread pointer offset of
+    /// `sym + addend`.
+    TLSGOTHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a PC-relative general-dynamic TLS GOT entry.
+    ///
+    /// Set the immediate field of the `auipc` instruction at `offset`
+    /// to the high 20 bits of the relative address of the Global
+    /// Offset Table entry holding the TLS index for `sym + addend`.
+    TLSGDHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a PC-relative address.
+    ///
+    /// Set the immediate field of the `auipc` instruction at `offset`
+    /// to the high 20 bits of the relative address of `sym + addend`.
+    PCRelHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a PC-relative address (I-type).
+    ///
+    /// Set the immediate field of the I-type (load or add-immediate)
+    /// instruction at `offset` to the low 12 bits of a relative
+    /// address.  Note that `sym` refers to the `auipc` instruction
+    /// carrying the matching [PCRelHi20](RiscVReloc::PCRelHi20)
+    /// relocation, not the final target.
+    PCRelLo12I {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a PC-relative address (S-type).
+    ///
+    /// Set the immediate field of the S-type (store) instruction at
+    /// `offset` to the low 12 bits of a relative address.  Note that
+    /// `sym` refers to the `auipc` instruction carrying the matching
+    /// [PCRelHi20](RiscVReloc::PCRelHi20) relocation, not the final
+    /// target.
+    PCRelLo12S {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of an absolute address.
+    ///
+    /// Set the immediate field of the `lui` instruction at `offset`
+    /// to the high 20 bits of `sym + addend`.
+    Hi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an absolute address (I-type).
+    ///
+    /// Set the immediate field of the I-type (load or add-immediate)
+    /// instruction at `offset` to the low 12 bits of `sym + addend`.
+    Lo12I {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an absolute address (S-type).
+    ///
+    /// Set the immediate field of the S-type (store) instruction at
+    /// `offset` to the low 12 bits of `sym + addend`.
+    Lo12S {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of a thread pointer offset.
+    ///
+    /// Set the immediate field of the `lui` instruction at `offset`
+    /// to the high 20 bits of the offset of `sym + addend` from the
+    /// thread pointer.
+    TPRelHi20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a thread pointer offset (I-type).
+    ///
+    /// Set the immediate field of the I-type (load or add-immediate)
+    /// instruction at `offset` to the low 12 bits of the offset of
+    /// `sym + addend` from the thread pointer.
+    TPRelLo12I {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of a thread pointer offset (S-type).
+    ///
+    /// Set the immediate field of the S-type (store) instruction at
+    /// `offset` to the low 12 bits of the offset of `sym + addend`
+    /// from the thread pointer.
+    TPRelLo12S {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer addition marker.
+    ///
+    /// Marks the `add` instruction at `offset` that adds the thread
+    /// pointer to the offset of `sym + addend`, so that the linker
+    /// can relax the access sequence.  The instruction is not
+    /// modified.
+    TPRelAdd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 1-byte word at `offset`.  These are
+    /// used in pairs to compute label differences that must survive
+    /// linker relaxation.
+    Add8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 2-byte word at `offset`.  These are
+    /// used in pairs to compute label differences that must survive
+    /// linker relaxation.
+    Add16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 4-byte word at `offset`.  These are
+    /// used in pairs to compute label differences that must survive
+    /// linker relaxation.
+    Add32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit in-place addition.
+    ///
+    /// Add `sym + addend` to the 8-byte word at `offset`.  These are
+    /// used in pairs to compute label differences that must survive
+    /// linker relaxation.
+    Add64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 1-byte word at `offset`.
+    /// These are used in pairs to compute label differences that must
+    /// survive linker relaxation.
+    Sub8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 2-byte word at `offset`.
+    /// These are used in pairs to compute label differences that must
+    /// survive linker relaxation.
+    Sub16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 4-byte word at `offset`.
+    /// These are used in pairs to compute label differences that must
+    /// survive linker relaxation.
+    Sub32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the 8-byte word at `offset`.
+    /// These are used in pairs to compute label differences that must
+    /// survive linker relaxation.
+    Sub64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GNU C++ vtable hierarchy.
+    ///
+    /// Records that the vtable at `offset` inherits from the vtable
+    /// `sym`, for use in garbage collection of virtual functions.
+    GNUVTInherit {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// GNU C++ vtable member usage.
+    ///
+    /// Records that the vtable entry `addend` of `sym` is used at
+    /// `offset`, for use in garbage collection of virtual functions.
+    GNUVTEntry {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Alignment padding.
+    ///
+    /// Marks `addend` bytes of `nop` padding at `offset`, which the
+    /// linker may delete in part to satisfy an alignment requirement
+    /// after relaxation.
+    Align {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 9-bit PC-relative compressed branch.
+    ///
+    /// Set the offset field of the compressed `c.beqz` or `c.bnez`
+    /// instruction at `offset` to the relative address of
+    /// `sym + addend`.
+    RVCBranch {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit PC-relative compressed jump.
+    ///
+    /// Set the offset field of the compressed `c.j` or `c.jal`
+    /// instruction at `offset` to the relative address of
+    /// `sym + addend`.
+    RVCJump {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 20 bits of an absolute address (compressed).
+    ///
+    /// Set the immediate field of the compressed `c.lui` instruction
+    /// at `offset` to the high 20 bits of `sym + addend`, which must
+    /// fit in its 6-bit immediate.
+    RVCLui {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Relaxation marker.
+    ///
+    /// Marks the instruction at `offset`, which also carries another
+    /// relocation, as a candidate for linker relaxation.  This is
+    /// always paired with the preceding relocation at the same
+    /// offset.
+    Relax {
+        /// Offset in the section.
+        offset: u64
+    },
+    /// 6-bit in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the low 6 bits of the byte at
+    /// `offset`.
+    Sub6 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 6-bit set.
+    ///
+    /// Set the low 6 bits of the byte at `offset` to `sym + addend`.
+    Set6 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit set.
+    ///
+    /// Set the 1-byte word at `offset` to `sym + addend`.  This is
+    /// used together with the subtraction relocations to compute
+    /// label differences.
+    Set8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit set.
+    ///
+    /// Set the 2-byte word at `offset` to `sym + addend`.  This is
+    /// used together with the subtraction relocations to compute
+    /// label differences.
+    Set16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit set.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.  This is
+    /// used together with the subtraction relocations to compute
+    /// label differences.
+    Set32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend`.
+    PRel32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect relative offset.
+    ///
+    /// Set the word at `offset` to the result of calling the resolver
+    /// function at the sum of the base address and `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative PLT offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// Procedure Linkage Table entry for `sym + addend`.
+    PLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ULEB128 set.
+    ///
+    /// Set the ULEB128-encoded value at `offset` to `sym + addend`.
+    /// The encoding keeps its original length.  This is always paired
+    /// with a [SubULEB128](RiscVReloc::SubULEB128) relocation at the
+    /// same offset.
+    SetULEB128 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// ULEB128 in-place subtraction.
+    ///
+    /// Subtract `sym + addend` from the ULEB128-encoded value at
+    /// `offset`.  The encoding keeps its original length.  This
+    /// always follows a [SetULEB128](RiscVReloc::SetULEB128)
+    /// relocation at the same offset.
+    SubULEB128 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    }
+}
+
+/// Type synonym for [RiscVReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type RiscVRelocRaw = RiscVReloc<u32>;
+
+/// Type synonym for [RiscVReloc] with [SymDataRaw] as the symbol
+/// type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [RiscVRelocRaw].
+pub type RiscVRelocRawSym<Class> = RiscVReloc<SymDataRaw<Class>>;
+
+/// Type synonym for [RiscVReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [RiscVRelocRawSym].
+pub type RiscVRelocStrDataSym<'a, Class> =
+    RiscVReloc<SymDataStrData<'a, Class>>;
+
+/// Type synonym for [RiscVReloc] with [SymDataStr] as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [RiscVRelocStrDataSym].
+pub type RiscVRelocStrData<'a> =
+    RiscVReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [RiscVReloc] with UTF-8 decoded string data as
+/// the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [RiscVRelocStrDataSym].
+pub type RiscVRelocStrSym<'a, Class> = RiscVReloc<SymDataStr<'a, Class>>;
+
+/// Type synonym for [RiscVReloc] with a `&'a str`s as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [RiscVRelocStrSym].
+pub type RiscVRelocStr<'a> = RiscVReloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [RiscVReloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// This can happen with a non-zero addend, or when converting to a
+/// 32-bit relocation with an offset or addend that does not fit.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RiscVToRelError {
+    /// Non-zero or out-of-range addend.
+    BadAddend(i64),
+    /// Out-of-range offset.
+    BadOffset(u64)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [RiscVReloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum RiscVRelocError {
+    /// Unknown tag value.
+    BadTag(u32)
+}
+
+impl<Name> Display for RiscVReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            RiscVReloc::None => write!(f, "none"),
+            RiscVReloc::Abs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::Abs64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            RiscVReloc::Relative { offset, addend } =>
+                write!(f, ".section[{}] <- &base + {}",
+                       offset, addend),
+            RiscVReloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            RiscVReloc::JumpSlot { offset, sym } =>
+                write!(f, ".plt[{}] <- &{}",
+                       offset, sym),
+            RiscVReloc::TLSDTPMod32 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 4, sym),
+            RiscVReloc::TLSDTPMod64 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 8, sym),
+            RiscVReloc::TLSDTPRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TLSDTPRel64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 8, sym, addend),
+            RiscVReloc::TLSTPRel32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TLSTPRel64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 8, sym, addend),
+            RiscVReloc::Branch { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            RiscVReloc::Jal { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            RiscVReloc::Call { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 8, sym, addend, offset),
+            RiscVReloc::CallPLT { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 8, sym, addend, offset),
+            RiscVReloc::GOTHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got[{}] + {}) - ",
+                                  "(&.section + {}) + 0x800) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            RiscVReloc::TLSGOTHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- initial execution GOT ",
+                                  "entry for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TLSGDHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- general dynamic GOT ",
+                                  "entry for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::PCRelHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) + 0x800) >> 12"),
+                       offset, offset + 4, sym, addend, offset),
+            RiscVReloc::PCRelLo12I { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- low 12 bits of ",
+                                  "PC-relative offset at &{} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::PCRelLo12S { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- low 12 bits of ",
+                                  "PC-relative offset at &{} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::Hi20 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) + 0x800) >> 12",
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::Lo12I { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::Lo12S { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TPRelHi20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- high 20 bits of thread ",
+                                  "pointer offset for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TPRelLo12I { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- low 12 bits of thread ",
+                                  "pointer offset for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TPRelLo12S { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- low 12 bits of thread ",
+                                  "pointer offset for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::TPRelAdd { offset, sym, addend } =>
+                write!(f, "thread pointer add for {} + {} at .section[{}]",
+                       sym, addend, offset),
+            RiscVReloc::Add8 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 1, offset, offset + 1, sym, addend),
+            RiscVReloc::Add16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 2, offset, offset + 2, sym, addend),
+            RiscVReloc::Add32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 4, offset, offset + 4, sym, addend),
+            RiscVReloc::Add64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] + (&{} + {})",
+                       offset, offset + 8, offset, offset + 8, sym, addend),
+            RiscVReloc::Sub8 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 1, offset, offset + 1, sym, addend),
+            RiscVReloc::Sub16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 2, offset, offset + 2, sym, addend),
+            RiscVReloc::Sub32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 4, offset, offset + 4, sym, addend),
+            RiscVReloc::Sub64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- .section[{}..{}] - (&{} + {})",
+                       offset, offset + 8, offset, offset + 8, sym, addend),
+            RiscVReloc::GNUVTInherit { offset, sym } =>
+                write!(f, "vtable at .section[{}] inherits {}",
+                       offset, sym),
+            RiscVReloc::GNUVTEntry { offset, sym, addend } =>
+                write!(f, "vtable {} entry {} used at .section[{}]",
+                       sym, addend, offset),
+            RiscVReloc::Align { offset, addend } =>
+                write!(f, "align .section[{}] with {} bytes of padding",
+                       offset, addend),
+            RiscVReloc::RVCBranch { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            RiscVReloc::RVCJump { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            RiscVReloc::RVCLui { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) + 0x800) >> 12",
+                       offset, offset + 2, sym, addend),
+            RiscVReloc::Relax { offset } =>
+                write!(f, "relax .section[{}]",
+                       offset),
+            RiscVReloc::Sub6 { offset, sym, addend } =>
+                write!(f, ".section[{}] -= (&{} + {}) & 0x3f",
+                       offset, sym, addend),
+            RiscVReloc::Set6 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- (&{} + {}) & 0x3f",
+                       offset, sym, addend),
+            RiscVReloc::Set8 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {})",
+                       offset, offset + 1, sym, addend),
+            RiscVReloc::Set16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {})",
+                       offset, offset + 2, sym, addend),
+            RiscVReloc::Set32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {})",
+                       offset, offset + 4, sym, addend),
+            RiscVReloc::PRel32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            RiscVReloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}] <- (&base + {})()",
+                       offset, addend),
+            RiscVReloc::PLT32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            RiscVReloc::SetULEB128 { offset, sym, addend } =>
+                write!(f, ".section[{}..] <- uleb128 (&{} + {})",
+                       offset, sym, addend),
+            RiscVReloc::SubULEB128 { offset, sym, addend } =>
+                write!(f, ".section[{}..] -= uleb128 (&{} + {})",
+                       offset, sym, addend)
+        }
+    }
+}
+
+impl Display for RiscVRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            RiscVRelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for RiscVToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            RiscVToRelError::BadAddend(addend) =>
+                write!(f, "bad addend value {}", addend),
+            RiscVToRelError::BadOffset(offset) =>
+                write!(f, "bad offset value {}", offset)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<RiscVReloc<Name>, RiscVRelocError> {
+    match kind {
+        0 => Ok(RiscVReloc::None),
+        1 => Ok(RiscVReloc::Abs32 { offset: offset, sym: sym, addend: addend }),
+        2 => Ok(RiscVReloc::Abs64 { offset: offset, sym: sym, addend: addend }),
+        3 => Ok(RiscVReloc::Relative { offset: offset, addend: addend }),
+        4 => Ok(RiscVReloc::Copy { offset: offset, sym: sym }),
+        5 => Ok(RiscVReloc::JumpSlot { offset: offset, sym: sym }),
+        6 => Ok(RiscVReloc::TLSDTPMod32 { offset: offset, sym: sym }),
+        7 => Ok(RiscVReloc::TLSDTPMod64 { offset: offset, sym: sym }),
+        8 => Ok(RiscVReloc::TLSDTPRel32 { offset: offset, sym: sym,
+                                          addend: addend }),
+        9 => Ok(RiscVReloc::TLSDTPRel64 { offset: offset, sym: sym,
+                                          addend: addend }),
+        10 => Ok(RiscVReloc::TLSTPRel32 { offset: offset, sym: sym,
+                                          addend: addend }),
+        11 => Ok(RiscVReloc::TLSTPRel64 { offset: offset, sym: sym,
+                                          addend: addend }),
+        16 => Ok(RiscVReloc::Branch { offset: offset, sym: sym,
+                                      addend: addend }),
+        17 => Ok(RiscVReloc::Jal { offset: offset, sym: sym, addend: addend }),
+        18 => Ok(RiscVReloc::Call { offset: offset, sym: sym, addend: addend }),
+        19 => Ok(RiscVReloc::CallPLT { offset: offset, sym: sym,
+                                       addend: addend }),
+        20 => Ok(RiscVReloc::GOTHi20 { offset: offset, sym: sym,
+                                       addend: addend }),
+        21 => Ok(RiscVReloc::TLSGOTHi20 { offset: offset, sym: sym,
+                                          addend: addend }),
+        22 => Ok(RiscVReloc::TLSGDHi20 { offset: offset, sym: sym,
+                                         addend: addend }),
+        23 => Ok(RiscVReloc::PCRelHi20 { offset: offset, sym: sym,
+                                         addend: addend }),
+        24 => Ok(RiscVReloc::PCRelLo12I { offset: offset, sym: sym,
+                                          addend: addend }),
+        25 => Ok(RiscVReloc::PCRelLo12S { offset: offset, sym: sym,
+                                          addend: addend }),
+        26 => Ok(RiscVReloc::Hi20 { offset: offset, sym: sym, addend: addend }),
+        27 => Ok(RiscVReloc::Lo12I { offset: offset, sym: sym,
+                                     addend: addend }),
+        28 => Ok(RiscVReloc::Lo12S { offset: offset, sym: sym,
+                                     addend: addend }),
+        29 => Ok(RiscVReloc::TPRelHi20 { offset: offset, sym: sym,
+                                         addend: addend }),
+        30 => Ok(RiscVReloc::TPRelLo12I { offset: offset, sym: sym,
+                                          addend: addend }),
+        31 => Ok(RiscVReloc::TPRelLo12S { offset: offset, sym: sym,
+                                          addend: addend }),
+        32 => Ok(RiscVReloc::TPRelAdd { offset: offset, sym: sym,
+                                        addend: addend }),
+        33 => Ok(RiscVReloc::Add8 { offset: offset, sym: sym, addend: addend }),
+        34 => Ok(RiscVReloc::Add16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        35 => Ok(RiscVReloc::Add32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        36 => Ok(RiscVReloc::Add64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        37 => Ok(RiscVReloc::Sub8 { offset: offset, sym: sym, addend: addend }),
+        38 => Ok(RiscVReloc::Sub16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        39 => Ok(RiscVReloc::Sub32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        40 => Ok(RiscVReloc::Sub64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        41 => Ok(RiscVReloc::GNUVTInherit { offset: offset, sym: sym }),
+        42 => Ok(RiscVReloc::GNUVTEntry { offset: offset, sym: sym,
+                                          addend: addend }),
+        43 => Ok(RiscVReloc::Align { offset: offset, addend: addend }),
+        44 => Ok(RiscVReloc::RVCBranch { offset: offset, sym: sym,
+                                         addend: addend }),
+        45 => Ok(RiscVReloc::RVCJump { offset: offset, sym: sym,
+                                       addend: addend }),
+        46 => Ok(RiscVReloc::RVCLui { offset: offset, sym: sym,
+                                      addend: addend }),
+        51 => Ok(RiscVReloc::Relax { offset: offset }),
+        52 => Ok(RiscVReloc::Sub6 { offset: offset, sym: sym, addend: addend }),
+        53 => Ok(RiscVReloc::Set6 { offset: offset, sym: sym, addend: addend }),
+        54 => Ok(RiscVReloc::Set8 { offset: offset, sym: sym, addend: addend }),
+        55 => Ok(RiscVReloc::Set16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        56 => Ok(RiscVReloc::Set32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        57 => Ok(RiscVReloc::PRel32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        58 => Ok(RiscVReloc::IRelative { offset: offset, addend: addend }),
+        59 => Ok(RiscVReloc::PLT32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        60 => Ok(RiscVReloc::SetULEB128 { offset: offset, sym: sym,
+                                          addend: addend }),
+        61 => Ok(RiscVReloc::SubULEB128 { offset: offset, sym: sym,
+                                          addend: addend }),
+        tag => Err(RiscVRelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf64>> for RiscVReloc<Name> {
+    type Error = RiscVRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf64>) -> Result<RiscVReloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<RiscVReloc<u32>> for RelData<u32, Elf64> {
+    type Error = RiscVToRelError;
+
+    #[inline]
+    fn try_from(rel: RiscVReloc<u32>) -> Result<RelData<u32, Elf64>,
+                                                Self::Error> {
+        match rel {
+            RiscVReloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            RiscVReloc::Abs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            RiscVReloc::Abs32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Abs64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            RiscVReloc::Abs64 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 3 }),
+            RiscVReloc::Relative { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            RiscVReloc::JumpSlot { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            RiscVReloc::TLSDTPMod32 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            RiscVReloc::TLSDTPMod64 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            RiscVReloc::TLSDTPRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            RiscVReloc::TLSDTPRel32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TLSDTPRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            RiscVReloc::TLSDTPRel64 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TLSTPRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            RiscVReloc::TLSTPRel32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TLSTPRel64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            RiscVReloc::TLSTPRel64 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Branch { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 16 }),
+            RiscVReloc::Branch { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Jal { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 17 }),
+            RiscVReloc::Jal { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Call { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 18 }),
+            RiscVReloc::Call { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::CallPLT { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 19 }),
+            RiscVReloc::CallPLT { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::GOTHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            RiscVReloc::GOTHi20 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TLSGOTHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            RiscVReloc::TLSGOTHi20 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TLSGDHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 22 }),
+            RiscVReloc::TLSGDHi20 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::PCRelHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 23 }),
+            RiscVReloc::PCRelHi20 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::PCRelLo12I { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 24 }),
+            RiscVReloc::PCRelLo12I { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::PCRelLo12S { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 25 }),
+            RiscVReloc::PCRelLo12S { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Hi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            RiscVReloc::Hi20 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Lo12I { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            RiscVReloc::Lo12I { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Lo12S { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            RiscVReloc::Lo12S { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TPRelHi20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            RiscVReloc::TPRelHi20 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TPRelLo12I { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 30 }),
+            RiscVReloc::TPRelLo12I { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TPRelLo12S { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 31 }),
+            RiscVReloc::TPRelLo12S { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::TPRelAdd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 32 }),
+            RiscVReloc::TPRelAdd { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Add8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 33 }),
+            RiscVReloc::Add8 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Add16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 34 }),
+            RiscVReloc::Add16 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Add32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 35 }),
+            RiscVReloc::Add32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Add64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 36 }),
+            RiscVReloc::Add64 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Sub8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 37 }),
+            RiscVReloc::Sub8 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Sub16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 38 }),
+            RiscVReloc::Sub16 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Sub32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 39 }),
+            RiscVReloc::Sub32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Sub64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 40 }),
+            RiscVReloc::Sub64 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::GNUVTInherit { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 41 }),
+            RiscVReloc::GNUVTEntry { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 42 }),
+            RiscVReloc::GNUVTEntry { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Align { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 43 }),
+            RiscVReloc::Align { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::RVCBranch { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 44 }),
+            RiscVReloc::RVCBranch { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::RVCJump { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 45 }),
+            RiscVReloc::RVCJump { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::RVCLui { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 46 }),
+            RiscVReloc::RVCLui { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Relax { offset } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 51 }),
+            RiscVReloc::Sub6 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 52 }),
+            RiscVReloc::Sub6 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Set6 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 53 }),
+            RiscVReloc::Set6 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Set8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 54 }),
+            RiscVReloc::Set8 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Set16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 55 }),
+            RiscVReloc::Set16 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::Set32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 56 }),
+            RiscVReloc::Set32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::PRel32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 57 }),
+            RiscVReloc::PRel32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 58 }),
+            RiscVReloc::IRelative { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 59 }),
+            RiscVReloc::PLT32 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::SetULEB128 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 60 }),
+            RiscVReloc::SetULEB128 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend)),
+            RiscVReloc::SubULEB128 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 61 }),
+            RiscVReloc::SubULEB128 { addend, .. } =>
+                Err(RiscVToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf64>> for RiscVReloc<Name> {
+    type Error = RiscVRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf64>) -> Result<RiscVReloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<RiscVReloc<u32>> for RelaData<u32, Elf64> {
+    #[inline]
+    fn from(rel: RiscVReloc<u32>) -> RelaData<u32, Elf64> {
+        match rel {
+            RiscVReloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            RiscVReloc::Abs32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
+            RiscVReloc::Abs64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
+            RiscVReloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 3, addend: addend },
+            RiscVReloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 4, addend: 0 },
+            RiscVReloc::JumpSlot { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 5, addend: 0 },
+            RiscVReloc::TLSDTPMod32 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 6, addend: 0 },
+            RiscVReloc::TLSDTPMod64 { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 7, addend: 0 },
+            RiscVReloc::TLSDTPRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 8, addend: addend },
+            RiscVReloc::TLSDTPRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 9, addend: addend },
+            RiscVReloc::TLSTPRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
+            RiscVReloc::TLSTPRel64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
+            RiscVReloc::Branch { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 16, addend: addend },
+            RiscVReloc::Jal { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 17, addend: addend },
+            RiscVReloc::Call { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 18, addend: addend },
+            RiscVReloc::CallPLT { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 19, addend: addend },
+            RiscVReloc::GOTHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 20, addend: addend },
+            RiscVReloc::TLSGOTHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 21, addend: addend },
+            RiscVReloc::TLSGDHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 22, addend: addend },
+            RiscVReloc::PCRelHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 23, addend: addend },
+            RiscVReloc::PCRelLo12I { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 24, addend: addend },
+            RiscVReloc::PCRelLo12S { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 25, addend: addend },
+            RiscVReloc::Hi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 26, addend: addend },
+            RiscVReloc::Lo12I { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 27, addend: addend },
+            RiscVReloc::Lo12S { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 28, addend: addend },
+            RiscVReloc::TPRelHi20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 29, addend: addend },
+            RiscVReloc::TPRelLo12I { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 30, addend: addend },
+            RiscVReloc::TPRelLo12S { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 31, addend: addend },
+            RiscVReloc::TPRelAdd { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 32, addend: addend },
+            RiscVReloc::Add8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 33, addend: addend },
+            RiscVReloc::Add16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 34, addend: addend },
+            RiscVReloc::Add32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 35, addend: addend },
+            RiscVReloc::Add64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 36, addend: addend },
+            RiscVReloc::Sub8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 37, addend: addend },
+            RiscVReloc::Sub16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 38, addend: addend },
+            RiscVReloc::Sub32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 39, addend: addend },
+            RiscVReloc::Sub64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 40, addend: addend },
+            RiscVReloc::GNUVTInherit { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 41, addend: 0 },
+            RiscVReloc::GNUVTEntry { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 42, addend: addend },
+            RiscVReloc::Align { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 43, addend: addend },
+            RiscVReloc::RVCBranch { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 44, addend: addend },
+            RiscVReloc::RVCJump { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 45, addend: addend },
+            RiscVReloc::RVCLui { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 46, addend: addend },
+            RiscVReloc::Relax { offset } =>
+                RelaData { offset: offset, sym: 0, kind: 51, addend: 0 },
+            RiscVReloc::Sub6 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 52, addend: addend },
+            RiscVReloc::Set6 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 53, addend: addend },
+            RiscVReloc::Set8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 54, addend: addend },
+            RiscVReloc::Set16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 55, addend: addend },
+            RiscVReloc::Set32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 56, addend: addend },
+            RiscVReloc::PRel32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 57, addend: addend },
+            RiscVReloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 58, addend: addend },
+            RiscVReloc::PLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 59, addend: addend },
+            RiscVReloc::SetULEB128 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 60, addend: addend },
+            RiscVReloc::SubULEB128 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 61, addend: addend }
+        }
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for RiscVReloc<Name> {
+    type Error = RiscVRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) -> Result<RiscVReloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset.into(), sym, kind.into(), 0)
+    }
+}
+
+impl TryFrom<RiscVReloc<u32>> for RelData<u32, Elf32> {
+    type Error = RiscVToRelError;
+
+    #[inline]
+    fn try_from(rel: RiscVReloc<u32>) -> Result<RelData<u32, Elf32>,
+                                                Self::Error> {
+        match RelData::<u32, Elf64>::try_from(rel) {
+            Ok(RelData { offset, sym, kind }) => match offset.try_into() {
+                Ok(offset) => Ok(RelData { offset: offset, sym: sym,
+                                           kind: kind as u8 }),
+                Err(_) => Err(RiscVToRelError::BadOffset(offset))
+            },
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for RiscVReloc<Name> {
+    type Error = RiscVRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) -> Result<RiscVReloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset.into(), sym, kind.into(), addend.into())
+    }
+}
+
+impl TryFrom<RiscVReloc<u32>> for RelaData<u32, Elf32> {
+    type Error = RiscVToRelError;
+
+    #[inline]
+    fn try_from(rel: RiscVReloc<u32>) -> Result<RelaData<u32, Elf32>,
+                                                Self::Error> {
+        let RelaData { offset, sym, kind, addend } =
+            RelaData::<u32, Elf64>::from(rel);
+
+        match (offset.try_into(), addend.try_into()) {
+            (Ok(offset), Ok(addend)) => Ok(RelaData { offset: offset,
+                                                      sym: sym,
+                                                      kind: kind as u8,
+                                                      addend: addend }),
+            (Err(_), _) => Err(RiscVToRelError::BadOffset(offset)),
+            (_, Err(_)) => Err(RiscVToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<'a, B, Class> WithSymtab<'a, B, Class> for RiscVRelocRaw
+    where Class: SymOffsets,
+          B: ByteOrder {
+    type Result = RiscVRelocRawSym<Class>;
+    type Error = RelocSymtabError<Class>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Class>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            RiscVReloc::None => Ok(RiscVReloc::None),
+            RiscVReloc::Abs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Abs32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Abs64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Abs64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Relative { offset, addend } =>
+                Ok(RiscVReloc::Relative { offset: offset, addend: addend }),
+            RiscVReloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Copy { offset: offset,
+                                                  sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::JumpSlot { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::JumpSlot { offset: offset,
+                                                      sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSDTPMod32 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSDTPMod32 { offset: offset,
+                                                         sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSDTPMod64 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSDTPMod64 { offset: offset,
+                                                         sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSDTPRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSDTPRel32 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSDTPRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSDTPRel64 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSTPRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSTPRel32 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSTPRel64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSTPRel64 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Branch { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Branch { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Jal { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Jal { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Call { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Call { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::CallPLT { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::CallPLT { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::GOTHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::GOTHi20 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSGOTHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSGOTHi20 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TLSGDHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TLSGDHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::PCRelHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::PCRelHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::PCRelLo12I { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::PCRelLo12I { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::PCRelLo12S { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::PCRelLo12S { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Hi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Hi20 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Lo12I { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Lo12I { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Lo12S { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Lo12S { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TPRelHi20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TPRelHi20 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TPRelLo12I { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TPRelLo12I { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TPRelLo12S { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TPRelLo12S { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::TPRelAdd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::TPRelAdd { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Add8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Add8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Add16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Add16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Add32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Add32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Add64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Add64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Sub8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Sub8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Sub16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Sub16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Sub32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Sub32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Sub64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Sub64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::GNUVTInherit { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::GNUVTInherit { offset: offset,
+                                                          sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::GNUVTEntry { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::GNUVTEntry { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Align { offset, addend } =>
+                Ok(RiscVReloc::Align { offset: offset, addend: addend }),
+            RiscVReloc::RVCBranch { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::RVCBranch { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::RVCJump { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::RVCJump { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::RVCLui { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::RVCLui { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Relax { offset } =>
+                Ok(RiscVReloc::Relax { offset: offset }),
+            RiscVReloc::Sub6 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Sub6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Set6 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Set6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Set8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Set8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Set16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Set16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::Set32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::Set32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::PRel32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::PRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::IRelative { offset, addend } =>
+                Ok(RiscVReloc::IRelative { offset: offset, addend: addend }),
+            RiscVReloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::PLT32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::SetULEB128 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::SetULEB128 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            RiscVReloc::SubULEB128 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(RiscVReloc::SubULEB128 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                }
+        }
+    }
+}
+
+impl<'a, Class> WithStrtab<'a> for RiscVRelocRawSym<Class>
+    where Class: SymOffsets {
+    type Result = RiscVRelocStrDataSym<'a, Class>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            RiscVReloc::None => Ok(RiscVReloc::None),
+            RiscVReloc::Abs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Abs32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Abs64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Abs64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Relative { offset, addend } =>
+                Ok(RiscVReloc::Relative { offset: offset, addend: addend }),
+            RiscVReloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::JumpSlot { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::JumpSlot { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPMod32 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSDTPMod64 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPMod64 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSDTPRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPRel32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSDTPRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPRel64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSTPRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSTPRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSTPRel64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSTPRel64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Branch { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Branch { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Jal { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Jal { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Call { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Call { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::CallPLT { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::CallPLT { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::GOTHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::GOTHi20 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSGOTHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSGOTHi20 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TLSGDHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSGDHi20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::PCRelHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PCRelHi20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::PCRelLo12I { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PCRelLo12I { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::PCRelLo12S { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PCRelLo12S { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Hi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Hi20 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Lo12I { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Lo12I { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Lo12S { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Lo12S { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TPRelHi20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelHi20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TPRelLo12I { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelLo12I { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TPRelLo12S { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelLo12S { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::TPRelAdd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelAdd { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Add8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Add16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Add32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Add64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Sub8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Sub16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Sub32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Sub64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::GNUVTInherit { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::GNUVTInherit { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::GNUVTEntry { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Align { offset, addend } =>
+                Ok(RiscVReloc::Align { offset: offset, addend: addend }),
+            RiscVReloc::RVCBranch { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::RVCBranch { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::RVCJump { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::RVCJump { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::RVCLui { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::RVCLui { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Relax { offset } =>
+                Ok(RiscVReloc::Relax { offset: offset }),
+            RiscVReloc::Sub6 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub6 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Set6 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set6 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Set8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Set16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::Set32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::PRel32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PRel32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::IRelative { offset, addend } =>
+                Ok(RiscVReloc::IRelative { offset: offset, addend: addend }),
+            RiscVReloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::SetULEB128 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::SetULEB128 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            RiscVReloc::SubULEB128 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::SubULEB128 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                }
+        }
+    }
+}
+
+impl<'a, Class> TryFrom<RiscVRelocStrDataSym<'a, Class>>
+    for RiscVRelocStrSym<'a, Class>
+    where Class: SymOffsets {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: RiscVRelocStrDataSym<'a, Class>) ->
+        Result<RiscVRelocStrSym<'a, Class>, Self::Error> {
+        match reloc {
+            RiscVReloc::None => Ok(RiscVReloc::None),
+            RiscVReloc::Abs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Abs32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Abs64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Abs64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Relative { offset, addend } =>
+                Ok(RiscVReloc::Relative { offset: offset, addend: addend }),
+            RiscVReloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::JumpSlot { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::JumpSlot { offset: offset,
+                                                  sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPMod32 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSDTPMod64 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPMod64 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSDTPRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPRel32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSDTPRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSDTPRel64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSTPRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSTPRel32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSTPRel64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSTPRel64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Branch { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Branch { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Jal { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Jal { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Call { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Call { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::CallPLT { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::CallPLT { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::GOTHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::GOTHi20 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSGOTHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSGOTHi20 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TLSGDHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TLSGDHi20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::PCRelHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PCRelHi20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::PCRelLo12I { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PCRelLo12I { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::PCRelLo12S { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PCRelLo12S { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Hi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Hi20 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Lo12I { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Lo12I { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Lo12S { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Lo12S { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TPRelHi20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelHi20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TPRelLo12I { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelLo12I { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TPRelLo12S { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelLo12S { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::TPRelAdd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::TPRelAdd { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Add8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Add16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Add32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Add64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Add64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Sub8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Sub16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Sub32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Sub64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::GNUVTInherit { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::GNUVTInherit { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::GNUVTEntry { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Align { offset, addend } =>
+                Ok(RiscVReloc::Align { offset: offset, addend: addend }),
+            RiscVReloc::RVCBranch { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::RVCBranch { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::RVCJump { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::RVCJump { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::RVCLui { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::RVCLui { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Relax { offset } =>
+                Ok(RiscVReloc::Relax { offset: offset }),
+            RiscVReloc::Sub6 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Sub6 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Set6 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set6 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Set8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Set16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::Set32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::Set32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::PRel32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PRel32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::IRelative { offset, addend } =>
+                Ok(RiscVReloc::IRelative { offset: offset, addend: addend }),
+            RiscVReloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::SetULEB128 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::SetULEB128 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            RiscVReloc::SubULEB128 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(RiscVReloc::SubULEB128 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a, Class> From<RiscVRelocStrDataSym<'a, Class>> for RiscVRelocStrData<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: RiscVRelocStrDataSym<'a, Class>) -> RiscVRelocStrData<'a> {
+        match reloc {
+            RiscVReloc::None => RiscVReloc::None,
+            RiscVReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Abs64 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Relative { offset, addend } =>
+                RiscVReloc::Relative { offset: offset, addend: addend },
+            RiscVReloc::Copy { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::Copy { offset: offset, sym: name },
+            RiscVReloc::JumpSlot { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::JumpSlot { offset: offset, sym: name },
+            RiscVReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::TLSDTPMod32 { offset: offset, sym: name },
+            RiscVReloc::TLSDTPMod64 { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::TLSDTPMod64 { offset: offset, sym: name },
+            RiscVReloc::TLSDTPRel32 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                RiscVReloc::TLSDTPRel32 { offset: offset, sym: name,
+                                          addend: addend },
+            RiscVReloc::TLSDTPRel64 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                RiscVReloc::TLSDTPRel64 { offset: offset, sym: name,
+                                          addend: addend },
+            RiscVReloc::TLSTPRel32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TLSTPRel32 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TLSTPRel64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TLSTPRel64 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::Branch { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Branch { offset: offset, sym: name,
+                                     addend: addend },
+            RiscVReloc::Jal { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Jal { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Call { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Call { offset: offset, sym: name, addend: addend },
+            RiscVReloc::CallPLT { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::CallPLT { offset: offset, sym: name,
+                                      addend: addend },
+            RiscVReloc::GOTHi20 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::GOTHi20 { offset: offset, sym: name,
+                                      addend: addend },
+            RiscVReloc::TLSGOTHi20 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TLSGOTHi20 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TLSGDHi20 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::TLSGDHi20 { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::PCRelHi20 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::PCRelHi20 { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::PCRelLo12I { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::PCRelLo12I { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::PCRelLo12S { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::PCRelLo12S { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::Hi20 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Hi20 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Lo12I { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Lo12I { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Lo12S { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Lo12S { offset: offset, sym: name, addend: addend },
+            RiscVReloc::TPRelHi20 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::TPRelHi20 { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::TPRelLo12I { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TPRelLo12I { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TPRelLo12S { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TPRelLo12S { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TPRelAdd { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                RiscVReloc::TPRelAdd { offset: offset, sym: name,
+                                       addend: addend },
+            RiscVReloc::Add8 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add8 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Add16 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add16 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Add32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Add64 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add64 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub8 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub8 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub16 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub16 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub64 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub64 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::GNUVTInherit { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::GNUVTInherit { offset: offset, sym: name },
+            RiscVReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::GNUVTEntry { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::Align { offset, addend } =>
+                RiscVReloc::Align { offset: offset, addend: addend },
+            RiscVReloc::RVCBranch { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::RVCBranch { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::RVCJump { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::RVCJump { offset: offset, sym: name,
+                                      addend: addend },
+            RiscVReloc::RVCLui { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::RVCLui { offset: offset, sym: name,
+                                     addend: addend },
+            RiscVReloc::Relax { offset } =>
+                RiscVReloc::Relax { offset: offset },
+            RiscVReloc::Sub6 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub6 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set6 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set6 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set8 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set8 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set16 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set16 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::PRel32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::PRel32 { offset: offset, sym: name,
+                                     addend: addend },
+            RiscVReloc::IRelative { offset, addend } =>
+                RiscVReloc::IRelative { offset: offset, addend: addend },
+            RiscVReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::SetULEB128 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::SetULEB128 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::SubULEB128 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::SubULEB128 { offset: offset, sym: name,
+                                         addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<RiscVRelocStrData<'a>> for RiscVRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: RiscVRelocStrData<'a>) ->
+        Result<RiscVRelocStr<'a>, Self::Error> {
+        match reloc {
+            RiscVReloc::None => Ok(RiscVReloc::None),
+            RiscVReloc::Abs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Abs32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Abs32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Abs32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Abs32 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Abs64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Abs64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Abs64 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Abs64 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Abs64 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Relative { offset, addend } =>
+                Ok(RiscVReloc::Relative { offset: offset, addend: addend }),
+            RiscVReloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(RiscVReloc::Copy { offset: offset, sym: Some(name) }),
+            RiscVReloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Copy { sym: None, offset } =>
+                Ok(RiscVReloc::Copy { offset: offset, sym: None }),
+            RiscVReloc::JumpSlot { sym: Some(Ok(name)), offset } =>
+                Ok(RiscVReloc::JumpSlot { offset: offset, sym: Some(name) }),
+            RiscVReloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::JumpSlot { sym: None, offset } =>
+                Ok(RiscVReloc::JumpSlot { offset: offset, sym: None }),
+            RiscVReloc::TLSDTPMod32 { sym: Some(Ok(name)), offset } =>
+                Ok(RiscVReloc::TLSDTPMod32 { offset: offset, sym: Some(name) }),
+            RiscVReloc::TLSDTPMod32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSDTPMod32 { sym: None, offset } =>
+                Ok(RiscVReloc::TLSDTPMod32 { offset: offset, sym: None }),
+            RiscVReloc::TLSDTPMod64 { sym: Some(Ok(name)), offset } =>
+                Ok(RiscVReloc::TLSDTPMod64 { offset: offset, sym: Some(name) }),
+            RiscVReloc::TLSDTPMod64 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSDTPMod64 { sym: None, offset } =>
+                Ok(RiscVReloc::TLSDTPMod64 { offset: offset, sym: None }),
+            RiscVReloc::TLSDTPRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TLSDTPRel32 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            RiscVReloc::TLSDTPRel32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSDTPRel32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TLSDTPRel32 { offset: offset, sym: None,
+                                             addend: addend }),
+            RiscVReloc::TLSDTPRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TLSDTPRel64 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            RiscVReloc::TLSDTPRel64 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSDTPRel64 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TLSDTPRel64 { offset: offset, sym: None,
+                                             addend: addend }),
+            RiscVReloc::TLSTPRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TLSTPRel32 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::TLSTPRel32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSTPRel32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TLSTPRel32 { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::TLSTPRel64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TLSTPRel64 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::TLSTPRel64 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSTPRel64 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TLSTPRel64 { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::Branch { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Branch { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            RiscVReloc::Branch { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Branch { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Branch { offset: offset, sym: None,
+                                        addend: addend }),
+            RiscVReloc::Jal { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Jal { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            RiscVReloc::Jal { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Jal { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Jal { offset: offset, sym: None,
+                                     addend: addend }),
+            RiscVReloc::Call { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Call { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Call { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Call { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Call { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::CallPLT { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::CallPLT { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            RiscVReloc::CallPLT { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::CallPLT { sym: None, offset, addend } =>
+                Ok(RiscVReloc::CallPLT { offset: offset, sym: None,
+                                         addend: addend }),
+            RiscVReloc::GOTHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::GOTHi20 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            RiscVReloc::GOTHi20 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::GOTHi20 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::GOTHi20 { offset: offset, sym: None,
+                                         addend: addend }),
+            RiscVReloc::TLSGOTHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TLSGOTHi20 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::TLSGOTHi20 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSGOTHi20 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TLSGOTHi20 { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::TLSGDHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TLSGDHi20 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            RiscVReloc::TLSGDHi20 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TLSGDHi20 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TLSGDHi20 { offset: offset, sym: None,
+                                           addend: addend }),
+            RiscVReloc::PCRelHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::PCRelHi20 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            RiscVReloc::PCRelHi20 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::PCRelHi20 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::PCRelHi20 { offset: offset, sym: None,
+                                           addend: addend }),
+            RiscVReloc::PCRelLo12I { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::PCRelLo12I { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::PCRelLo12I { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::PCRelLo12I { sym: None, offset, addend } =>
+                Ok(RiscVReloc::PCRelLo12I { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::PCRelLo12S { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::PCRelLo12S { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::PCRelLo12S { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::PCRelLo12S { sym: None, offset, addend } =>
+                Ok(RiscVReloc::PCRelLo12S { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::Hi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Hi20 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Hi20 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Hi20 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Hi20 { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::Lo12I { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Lo12I { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Lo12I { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Lo12I { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Lo12I { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Lo12S { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Lo12S { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Lo12S { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Lo12S { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Lo12S { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::TPRelHi20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TPRelHi20 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            RiscVReloc::TPRelHi20 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TPRelHi20 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TPRelHi20 { offset: offset, sym: None,
+                                           addend: addend }),
+            RiscVReloc::TPRelLo12I { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TPRelLo12I { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::TPRelLo12I { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TPRelLo12I { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TPRelLo12I { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::TPRelLo12S { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TPRelLo12S { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::TPRelLo12S { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TPRelLo12S { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TPRelLo12S { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::TPRelAdd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::TPRelAdd { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            RiscVReloc::TPRelAdd { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::TPRelAdd { sym: None, offset, addend } =>
+                Ok(RiscVReloc::TPRelAdd { offset: offset, sym: None,
+                                          addend: addend }),
+            RiscVReloc::Add8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Add8 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Add8 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Add8 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Add8 { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::Add16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Add16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Add16 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Add16 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Add16 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Add32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Add32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Add32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Add32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Add32 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Add64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Add64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Add64 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Add64 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Add64 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Sub8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Sub8 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Sub8 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Sub8 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Sub8 { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::Sub16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Sub16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Sub16 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Sub16 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Sub16 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Sub32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Sub32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Sub32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Sub32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Sub32 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Sub64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Sub64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Sub64 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Sub64 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Sub64 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::GNUVTInherit { sym: Some(Ok(name)), offset } =>
+                Ok(RiscVReloc::GNUVTInherit { offset: offset,
+                                              sym: Some(name) }),
+            RiscVReloc::GNUVTInherit { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::GNUVTInherit { sym: None, offset } =>
+                Ok(RiscVReloc::GNUVTInherit { offset: offset, sym: None }),
+            RiscVReloc::GNUVTEntry { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::GNUVTEntry { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::GNUVTEntry { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::GNUVTEntry { sym: None, offset, addend } =>
+                Ok(RiscVReloc::GNUVTEntry { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::Align { offset, addend } =>
+                Ok(RiscVReloc::Align { offset: offset, addend: addend }),
+            RiscVReloc::RVCBranch { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::RVCBranch { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            RiscVReloc::RVCBranch { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::RVCBranch { sym: None, offset, addend } =>
+                Ok(RiscVReloc::RVCBranch { offset: offset, sym: None,
+                                           addend: addend }),
+            RiscVReloc::RVCJump { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::RVCJump { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            RiscVReloc::RVCJump { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::RVCJump { sym: None, offset, addend } =>
+                Ok(RiscVReloc::RVCJump { offset: offset, sym: None,
+                                         addend: addend }),
+            RiscVReloc::RVCLui { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::RVCLui { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            RiscVReloc::RVCLui { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::RVCLui { sym: None, offset, addend } =>
+                Ok(RiscVReloc::RVCLui { offset: offset, sym: None,
+                                        addend: addend }),
+            RiscVReloc::Relax { offset } =>
+                Ok(RiscVReloc::Relax { offset: offset }),
+            RiscVReloc::Sub6 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Sub6 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Sub6 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Sub6 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Sub6 { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::Set6 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Set6 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Set6 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Set6 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Set6 { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::Set8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Set8 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            RiscVReloc::Set8 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Set8 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Set8 { offset: offset, sym: None,
+                                      addend: addend }),
+            RiscVReloc::Set16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Set16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Set16 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Set16 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Set16 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::Set32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::Set32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::Set32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::Set32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::Set32 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::PRel32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::PRel32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            RiscVReloc::PRel32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::PRel32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::PRel32 { offset: offset, sym: None,
+                                        addend: addend }),
+            RiscVReloc::IRelative { offset, addend } =>
+                Ok(RiscVReloc::IRelative { offset: offset, addend: addend }),
+            RiscVReloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::PLT32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            RiscVReloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::PLT32 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::PLT32 { offset: offset, sym: None,
+                                       addend: addend }),
+            RiscVReloc::SetULEB128 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::SetULEB128 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::SetULEB128 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::SetULEB128 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::SetULEB128 { offset: offset, sym: None,
+                                            addend: addend }),
+            RiscVReloc::SubULEB128 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(RiscVReloc::SubULEB128 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            RiscVReloc::SubULEB128 { sym: Some(Err(err)), .. } => Err(err),
+            RiscVReloc::SubULEB128 { sym: None, offset, addend } =>
+                Ok(RiscVReloc::SubULEB128 { offset: offset, sym: None,
+                                            addend: addend })
+        }
+    }
+}
+
+impl<'a, Class> From<RiscVRelocStrSym<'a, Class>> for RiscVRelocStr<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: RiscVRelocStrSym<'a, Class>) -> RiscVRelocStr<'a> {
+        match reloc {
+            RiscVReloc::None => RiscVReloc::None,
+            RiscVReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Abs64 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Relative { offset, addend } =>
+                RiscVReloc::Relative { offset: offset, addend: addend },
+            RiscVReloc::Copy { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::Copy { offset: offset, sym: name },
+            RiscVReloc::JumpSlot { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::JumpSlot { offset: offset, sym: name },
+            RiscVReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::TLSDTPMod32 { offset: offset, sym: name },
+            RiscVReloc::TLSDTPMod64 { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::TLSDTPMod64 { offset: offset, sym: name },
+            RiscVReloc::TLSDTPRel32 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                RiscVReloc::TLSDTPRel32 { offset: offset, sym: name,
+                                          addend: addend },
+            RiscVReloc::TLSDTPRel64 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                RiscVReloc::TLSDTPRel64 { offset: offset, sym: name,
+                                          addend: addend },
+            RiscVReloc::TLSTPRel32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TLSTPRel32 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TLSTPRel64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TLSTPRel64 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::Branch { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Branch { offset: offset, sym: name,
+                                     addend: addend },
+            RiscVReloc::Jal { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Jal { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Call { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Call { offset: offset, sym: name, addend: addend },
+            RiscVReloc::CallPLT { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::CallPLT { offset: offset, sym: name,
+                                      addend: addend },
+            RiscVReloc::GOTHi20 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::GOTHi20 { offset: offset, sym: name,
+                                      addend: addend },
+            RiscVReloc::TLSGOTHi20 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TLSGOTHi20 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TLSGDHi20 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::TLSGDHi20 { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::PCRelHi20 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::PCRelHi20 { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::PCRelLo12I { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::PCRelLo12I { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::PCRelLo12S { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::PCRelLo12S { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::Hi20 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Hi20 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Lo12I { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Lo12I { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Lo12S { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Lo12S { offset: offset, sym: name, addend: addend },
+            RiscVReloc::TPRelHi20 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::TPRelHi20 { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::TPRelLo12I { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TPRelLo12I { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TPRelLo12S { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::TPRelLo12S { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::TPRelAdd { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                RiscVReloc::TPRelAdd { offset: offset, sym: name,
+                                       addend: addend },
+            RiscVReloc::Add8 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add8 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Add16 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add16 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Add32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Add64 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Add64 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub8 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub8 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub16 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub16 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Sub64 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub64 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::GNUVTInherit { sym: SymData { name, .. }, offset } =>
+                RiscVReloc::GNUVTInherit { offset: offset, sym: name },
+            RiscVReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::GNUVTEntry { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::Align { offset, addend } =>
+                RiscVReloc::Align { offset: offset, addend: addend },
+            RiscVReloc::RVCBranch { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                RiscVReloc::RVCBranch { offset: offset, sym: name,
+                                        addend: addend },
+            RiscVReloc::RVCJump { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::RVCJump { offset: offset, sym: name,
+                                      addend: addend },
+            RiscVReloc::RVCLui { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::RVCLui { offset: offset, sym: name,
+                                     addend: addend },
+            RiscVReloc::Relax { offset } =>
+                RiscVReloc::Relax { offset: offset },
+            RiscVReloc::Sub6 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Sub6 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set6 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set6 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set8 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set8 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set16 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set16 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::Set32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::Set32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::PRel32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::PRel32 { offset: offset, sym: name,
+                                     addend: addend },
+            RiscVReloc::IRelative { offset, addend } =>
+                RiscVReloc::IRelative { offset: offset, addend: addend },
+            RiscVReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                RiscVReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            RiscVReloc::SetULEB128 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::SetULEB128 { offset: offset, sym: name,
+                                         addend: addend },
+            RiscVReloc::SubULEB128 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                RiscVReloc::SubULEB128 { offset: offset, sym: name,
+                                         addend: addend }
+        }
+    }
+}
@@ -0,0 +1,4383 @@
+//! Relocation types for IBM z/Architecture.
+//!
+//! This module provides the `S390xReloc` type, which describes the
+//! relocation entries for the 64-bit IBM z/Architecture (aka. s390x),
+//! as defined by the s390x ELF ABI supplement.  These can be
+//! converted to and from [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
+//! the [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+//!
+//! The 31-bit s390 ABI uses the same relocation numbers, but is not
+//! covered here.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::SymOffsets;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for the 64-bit IBM z/Architecture.
+///
+/// This datatype provides a semantic-level presentation of the s390x
+/// relocation entries.  These can be converted to and from
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
+/// the [ElfClass](crate::ElfClass) type argument using the
+/// [TryFrom](core::convert::TryFrom) instances for easier handling.
+///
+/// Relative branch and load instructions on z/Architecture encode
+/// their targets in halfwords, so the PC-relative relocations used by
+/// code are the `DBL` forms, such as [PC32DBL](S390xReloc::PC32DBL),
+/// which store the offset shifted right by one.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum S390xReloc<Name> {
+    /// Null relocation.
+    None,
+    /// 8-bit absolute offset.
+    ///
+    /// Set the 1-byte word at `offset` to `sym + addend`.
+    Abs8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit absolute offset.
+    ///
+    /// Set the 12-bit field at `offset` to `sym + addend`.
+    Abs12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset` to `sym + addend`.
+    Abs16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Abs32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    PC32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit Global Offset Table offset.
+    ///
+    /// Set the 12-bit field at `offset` to the offset of the GOT entry
+    /// for `sym + addend`.
+    GOT12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit Global Offset Table offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the GOT entry for
+    /// `sym + addend`.
+    GOT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Procedure Linkage Table offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the PLT
+    /// entry for `sym`, plus `addend`.
+    PLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Global Offset Table entry fill.
+    ///
+    /// Set a Global Offset Table entry to the address of `sym + addend`.
+    GlobalData {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Offset relative to the image base.
+    ///
+    /// Set the 8-byte word at `offset` to the sum of the base address and
+    /// `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit offset from the Global Offset Table.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend`
+    /// from the GOT.
+    GOTOff32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Global Offset Table address.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// GOT, plus `addend`.  The symbol is normally
+    /// `_GLOBAL_OFFSET_TABLE_`.
+    GOTPC {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit Global Offset Table offset.
+    ///
+    /// Set the 2-byte word at `offset` to the offset of the GOT entry for
+    /// `sym + addend`.
+    GOT16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative offset.
+    ///
+    /// Set the 2-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    PC16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative halfword offset.
+    ///
+    /// Set the 16-bit field at `offset` to the relative address of
+    /// `sym + addend`, in halfwords.
+    PC16DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative Procedure Linkage Table halfword offset.
+    ///
+    /// Set the 16-bit field at `offset` to the relative address of the
+    /// PLT entry for `sym`, plus `addend`, in halfwords.
+    PLT16DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative halfword offset.
+    ///
+    /// Set the 32-bit field at `offset` to the relative address of
+    /// `sym + addend`, in halfwords.
+    PC32DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Procedure Linkage Table halfword offset.
+    ///
+    /// Set the 32-bit field at `offset` to the relative address of the
+    /// PLT entry for `sym`, plus `addend`, in halfwords.
+    PLT32DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Global Offset Table halfword offset.
+    ///
+    /// Set the 32-bit field at `offset` to the relative address of the
+    /// GOT, plus `addend`.  The symbol is normally
+    /// `_GLOBAL_OFFSET_TABLE_`, in halfwords.
+    GOTPCDBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`.
+    Abs64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    PC64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit Global Offset Table offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of the GOT entry for
+    /// `sym + addend`.
+    GOT64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative Procedure Linkage Table offset.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of the PLT
+    /// entry for `sym`, plus `addend`.
+    PLT64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Global Offset Table entry halfword offset.
+    ///
+    /// Set the 32-bit field at `offset` to the relative address of the
+    /// GOT entry for `sym + addend`, in halfwords.
+    GOTEnt {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit offset from the Global Offset Table.
+    ///
+    /// Set the 2-byte word at `offset` to the offset of `sym + addend`
+    /// from the GOT.
+    GOTOff16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit offset from the Global Offset Table.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend`
+    /// from the GOT.
+    GOTOff64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit Global Offset Table offset of a jump slot.
+    ///
+    /// Set the 12-bit field at `offset` to the offset of the GOT jump
+    /// slot for `sym`, plus `addend`.
+    GOTPLT12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit Global Offset Table offset of a jump slot.
+    ///
+    /// Set the 2-byte word at `offset` to the offset of the GOT jump slot
+    /// for `sym`, plus `addend`.
+    GOTPLT16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit Global Offset Table offset of a jump slot.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the GOT jump slot
+    /// for `sym`, plus `addend`.
+    GOTPLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit Global Offset Table offset of a jump slot.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of the GOT jump slot
+    /// for `sym`, plus `addend`.
+    GOTPLT64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Global Offset Table jump slot halfword offset.
+    ///
+    /// Set the 32-bit field at `offset` to the relative address of the
+    /// GOT jump slot for `sym`, plus `addend`, in halfwords.
+    GOTPLTEnt {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit Global Offset Table relative Procedure Linkage Table offset.
+    ///
+    /// Set the 2-byte word at `offset` to the offset of the PLT entry for
+    /// `sym`, plus `addend`, from the GOT.
+    PLTOff16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit Global Offset Table relative Procedure Linkage Table offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the PLT entry for
+    /// `sym`, plus `addend`, from the GOT.
+    PLTOff32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit Global Offset Table relative Procedure Linkage Table offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of the PLT entry for
+    /// `sym`, plus `addend`, from the GOT.
+    PLTOff64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Initial exec thread-local load.
+    ///
+    /// Marks the instruction at `offset` loading the thread pointer
+    /// offset for `sym + addend`, so that it can be relaxed by the
+    /// linker.
+    TLSLoad {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// General dynamic thread-local call.
+    ///
+    /// Marks the call to `__tls_get_offset` at `offset` for
+    /// `sym + addend`, so that it can be relaxed by the linker.
+    TLSGDCall {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic thread-local call.
+    ///
+    /// Marks the call to `__tls_get_offset` at `offset` for the module of
+    /// `sym + addend`, so that it can be relaxed by the linker.
+    TLSLDCall {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit general dynamic thread-local GOT offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the GOT entry
+    /// holding the `tls_index` for `sym + addend`.
+    TLSGD32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit general dynamic thread-local GOT offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of the GOT entry
+    /// holding the `tls_index` for `sym + addend`.
+    TLSGD64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit initial exec thread-local GOT offset.
+    ///
+    /// Set the 12-bit field at `offset` to the offset of the GOT entry
+    /// holding the thread pointer offset for `sym + addend`.
+    TLSGOTIE12 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit initial exec thread-local GOT offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the GOT entry
+    /// holding the thread pointer offset for `sym + addend`.
+    TLSGOTIE32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit initial exec thread-local GOT offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of the GOT entry
+    /// holding the thread pointer offset for `sym + addend`.
+    TLSGOTIE64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit local dynamic thread-local GOT offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of the GOT entry
+    /// holding the module `tls_index` for `sym + addend`.
+    TLSLDM32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit local dynamic thread-local GOT offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of the GOT entry
+    /// holding the module `tls_index` for `sym + addend`.
+    TLSLDM64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit initial exec thread-local GOT address.
+    ///
+    /// Set the 4-byte word at `offset` to the address of the GOT entry
+    /// holding the thread pointer offset for `sym + addend`.
+    TLSIE32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit initial exec thread-local GOT address.
+    ///
+    /// Set the 8-byte word at `offset` to the address of the GOT entry
+    /// holding the thread pointer offset for `sym + addend`.
+    TLSIE64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative initial exec thread-local GOT halfword offset.
+    ///
+    /// Set the 32-bit field at `offset` to the relative address of the
+    /// GOT entry holding the thread pointer offset for `sym + addend`, in
+    /// halfwords.
+    TLSIEEnt {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit local exec thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSLE32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit local exec thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSLE64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit local dynamic thread-local offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSLDO32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit local dynamic thread-local offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSLDO64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread-local storage module index.
+    ///
+    /// Set the 8-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Thread-local storage block offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSDTPOff {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSTPOff {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 20-bit absolute offset.
+    ///
+    /// Set the 20-bit field at `offset` to `sym + addend`, split across
+    /// the low and high parts of a long displacement.
+    Abs20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 20-bit Global Offset Table offset.
+    ///
+    /// Set the 20-bit field at `offset` to the offset of the GOT entry
+    /// for `sym + addend`, split across the low and high parts of a long
+    /// displacement.
+    GOT20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 20-bit Global Offset Table offset of a jump slot.
+    ///
+    /// Set the 20-bit field at `offset` to the offset of the GOT jump
+    /// slot for `sym`, plus `addend`, split across the low and high parts
+    /// of a long displacement.
+    GOTPLT20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 20-bit initial exec thread-local GOT offset.
+    ///
+    /// Set the 20-bit field at `offset` to the offset of the GOT entry
+    /// holding the thread pointer offset for `sym + addend`, split across
+    /// the low and high parts of a long displacement.
+    TLSGOTIE20 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect offset relative to the image base.
+    ///
+    /// Set the 8-byte word at `offset` to the address returned by the
+    /// resolver function at the base address plus `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit PC-relative halfword offset.
+    ///
+    /// Set the 12-bit field at `offset` to the relative address of
+    /// `sym + addend`, in halfwords.
+    PC12DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 12-bit PC-relative Procedure Linkage Table halfword offset.
+    ///
+    /// Set the 12-bit field at `offset` to the relative address of the
+    /// PLT entry for `sym`, plus `addend`, in halfwords.
+    PLT12DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 24-bit PC-relative halfword offset.
+    ///
+    /// Set the 24-bit field at `offset` to the relative address of
+    /// `sym + addend`, in halfwords.
+    PC24DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 24-bit PC-relative Procedure Linkage Table halfword offset.
+    ///
+    /// Set the 24-bit field at `offset` to the relative address of the
+    /// PLT entry for `sym`, plus `addend`, in halfwords.
+    PLT24DBL {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    }
+}
+
+/// Type synonym for [S390xReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type S390xRelocRaw = S390xReloc<u32>;
+
+/// Type synonym for [S390xReloc] with [SymDataRaw] as the symbol
+/// type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [S390xRelocRaw].
+pub type S390xRelocRawSym<Class> = S390xReloc<SymDataRaw<Class>>;
+
+/// Type synonym for [S390xReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [S390xRelocRawSym].
+pub type S390xRelocStrDataSym<'a, Class> =
+    S390xReloc<SymDataStrData<'a, Class>>;
+
+/// Type synonym for [S390xReloc] with [SymDataStr] as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [S390xRelocStrDataSym].
+pub type S390xRelocStrData<'a> =
+    S390xReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [S390xReloc] with UTF-8 decoded string data as
+/// the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [S390xRelocStrDataSym].
+pub type S390xRelocStrSym<'a, Class> = S390xReloc<SymDataStr<'a, Class>>;
+
+/// Type synonym for [S390xReloc] with a `&'a str`s as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [S390xRelocStrSym].
+pub type S390xRelocStr<'a> = S390xReloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [S390xReloc] to a
+/// [RelData](crate::reloc::RelData).
+///
+/// At present, this can only happen with a non-zero addend.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum S390xToRelError {
+    /// Non-zero addend.
+    BadAddend(i64)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [S390xReloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum S390xRelocError {
+    /// Unknown tag value.
+    BadTag(u32)
+}
+
+impl<Name> Display for S390xReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            S390xReloc::None => write!(f, "none"),
+            S390xReloc::Abs8 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- &{} + {}",
+                       offset, sym, addend),
+            S390xReloc::Abs12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::Abs16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::Abs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::PC32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::GOT12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::GOT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::PLT32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            S390xReloc::GlobalData { offset, sym, addend } =>
+                write!(f, ".got[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::Relative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 8, addend),
+            S390xReloc::GOTOff32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::GOTPC { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::GOT16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::PC16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            S390xReloc::PC16DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            S390xReloc::PLT16DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            S390xReloc::PC32DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::PLT32DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::GOTPCDBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::Abs64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::PC64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 8, sym, addend, offset),
+            S390xReloc::GOT64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::PLT64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 8, sym, addend, offset),
+            S390xReloc::GOTEnt { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::GOTOff16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::GOTOff64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - &.got",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::GOTPLT12 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got.plt[{}] + {}) - &.got",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::GOTPLT16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got.plt[{}] + {}) - &.got",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::GOTPLT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got.plt[{}] + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::GOTPLT64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got.plt[{}] + {}) - &.got",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::GOTPLTEnt { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.got.plt[{}] + {}) ",
+                                  "- (&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::PLTOff16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.plt[{}] + {}) - &.got",
+                       offset, offset + 2, sym, addend),
+            S390xReloc::PLTOff32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.plt[{}] + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::PLTOff64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.plt[{}] + {}) - &.got",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSLoad { offset, sym, addend } =>
+                write!(f, "initial exec load at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            S390xReloc::TLSGDCall { offset, sym, addend } =>
+                write!(f, "general dynamic call at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            S390xReloc::TLSLDCall { offset, sym, addend } =>
+                write!(f, "local dynamic call at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            S390xReloc::TLSGD32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSGD64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_index(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSGOTIE12 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tp_offset(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 2, sym, addend),
+            S390xReloc::TLSGOTIE32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tp_offset(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSGOTIE64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tp_offset(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSLDM32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_module(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSLDM64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tls_module(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSIE32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tp_offset(&{} + {})]",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSIE64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[tp_offset(&{} + {})]",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSIEEnt { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tp_offset(&{} + ",
+                                  "{})] - (&.section + {})) >> 1"),
+                       offset, offset + 4, sym, addend, offset),
+            S390xReloc::TLSLE32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSLE64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSLDO32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSLDO64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSDTPMod { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 8, sym),
+            S390xReloc::TLSDTPOff { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 8, sym, addend),
+            S390xReloc::TLSTPOff { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 8, sym, addend),
+            S390xReloc::Abs20 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::GOT20 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::GOTPLT20 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got.plt[{}] + {}) - &.got",
+                       offset, offset + 4, sym, addend),
+            S390xReloc::TLSGOTIE20 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- &.got[tp_offset(&{} + ",
+                                  "{})] - &.got"),
+                       offset, offset + 4, sym, addend),
+            S390xReloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- (&base + {})()",
+                       offset, offset + 8, addend),
+            S390xReloc::PC12DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            S390xReloc::PLT12DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 2, sym, addend, offset),
+            S390xReloc::PC24DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 3, sym, addend, offset),
+            S390xReloc::PLT24DBL { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 1"),
+                       offset, offset + 3, sym, addend, offset)
+        }
+    }
+}
+
+impl Display for S390xRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            S390xRelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for S390xToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            S390xToRelError::BadAddend(addend) =>
+                write!(f, "bad addend value {}", addend)
+        }
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<S390xReloc<Name>, S390xRelocError> {
+    match kind {
+        0 => Ok(S390xReloc::None),
+        1 => Ok(S390xReloc::Abs8 { offset: offset, sym: sym, addend: addend }),
+        2 => Ok(S390xReloc::Abs12 { offset: offset, sym: sym, addend: addend }),
+        3 => Ok(S390xReloc::Abs16 { offset: offset, sym: sym, addend: addend }),
+        4 => Ok(S390xReloc::Abs32 { offset: offset, sym: sym, addend: addend }),
+        5 => Ok(S390xReloc::PC32 { offset: offset, sym: sym, addend: addend }),
+        6 => Ok(S390xReloc::GOT12 { offset: offset, sym: sym, addend: addend }),
+        7 => Ok(S390xReloc::GOT32 { offset: offset, sym: sym, addend: addend }),
+        8 => Ok(S390xReloc::PLT32 { offset: offset, sym: sym, addend: addend }),
+        9 => Ok(S390xReloc::Copy { offset: offset, sym: sym }),
+        10 => Ok(S390xReloc::GlobalData { offset: offset, sym: sym,
+                                          addend: addend }),
+        11 => Ok(S390xReloc::JumpSlot { offset: offset, sym: sym,
+                                        addend: addend }),
+        12 => Ok(S390xReloc::Relative { offset: offset, addend: addend }),
+        13 => Ok(S390xReloc::GOTOff32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        14 => Ok(S390xReloc::GOTPC { offset: offset, sym: sym,
+                                     addend: addend }),
+        15 => Ok(S390xReloc::GOT16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        16 => Ok(S390xReloc::PC16 { offset: offset, sym: sym, addend: addend }),
+        17 => Ok(S390xReloc::PC16DBL { offset: offset, sym: sym,
+                                       addend: addend }),
+        18 => Ok(S390xReloc::PLT16DBL { offset: offset, sym: sym,
+                                        addend: addend }),
+        19 => Ok(S390xReloc::PC32DBL { offset: offset, sym: sym,
+                                       addend: addend }),
+        20 => Ok(S390xReloc::PLT32DBL { offset: offset, sym: sym,
+                                        addend: addend }),
+        21 => Ok(S390xReloc::GOTPCDBL { offset: offset, sym: sym,
+                                        addend: addend }),
+        22 => Ok(S390xReloc::Abs64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        23 => Ok(S390xReloc::PC64 { offset: offset, sym: sym, addend: addend }),
+        24 => Ok(S390xReloc::GOT64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        25 => Ok(S390xReloc::PLT64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        26 => Ok(S390xReloc::GOTEnt { offset: offset, sym: sym,
+                                      addend: addend }),
+        27 => Ok(S390xReloc::GOTOff16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        28 => Ok(S390xReloc::GOTOff64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        29 => Ok(S390xReloc::GOTPLT12 { offset: offset, sym: sym,
+                                        addend: addend }),
+        30 => Ok(S390xReloc::GOTPLT16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        31 => Ok(S390xReloc::GOTPLT32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        32 => Ok(S390xReloc::GOTPLT64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        33 => Ok(S390xReloc::GOTPLTEnt { offset: offset, sym: sym,
+                                         addend: addend }),
+        34 => Ok(S390xReloc::PLTOff16 { offset: offset, sym: sym,
+                                        addend: addend }),
+        35 => Ok(S390xReloc::PLTOff32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        36 => Ok(S390xReloc::PLTOff64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        37 => Ok(S390xReloc::TLSLoad { offset: offset, sym: sym,
+                                       addend: addend }),
+        38 => Ok(S390xReloc::TLSGDCall { offset: offset, sym: sym,
+                                         addend: addend }),
+        39 => Ok(S390xReloc::TLSLDCall { offset: offset, sym: sym,
+                                         addend: addend }),
+        40 => Ok(S390xReloc::TLSGD32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        41 => Ok(S390xReloc::TLSGD64 { offset: offset, sym: sym,
+                                       addend: addend }),
+        42 => Ok(S390xReloc::TLSGOTIE12 { offset: offset, sym: sym,
+                                          addend: addend }),
+        43 => Ok(S390xReloc::TLSGOTIE32 { offset: offset, sym: sym,
+                                          addend: addend }),
+        44 => Ok(S390xReloc::TLSGOTIE64 { offset: offset, sym: sym,
+                                          addend: addend }),
+        45 => Ok(S390xReloc::TLSLDM32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        46 => Ok(S390xReloc::TLSLDM64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        47 => Ok(S390xReloc::TLSIE32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        48 => Ok(S390xReloc::TLSIE64 { offset: offset, sym: sym,
+                                       addend: addend }),
+        49 => Ok(S390xReloc::TLSIEEnt { offset: offset, sym: sym,
+                                        addend: addend }),
+        50 => Ok(S390xReloc::TLSLE32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        51 => Ok(S390xReloc::TLSLE64 { offset: offset, sym: sym,
+                                       addend: addend }),
+        52 => Ok(S390xReloc::TLSLDO32 { offset: offset, sym: sym,
+                                        addend: addend }),
+        53 => Ok(S390xReloc::TLSLDO64 { offset: offset, sym: sym,
+                                        addend: addend }),
+        54 => Ok(S390xReloc::TLSDTPMod { offset: offset, sym: sym }),
+        55 => Ok(S390xReloc::TLSDTPOff { offset: offset, sym: sym,
+                                         addend: addend }),
+        56 => Ok(S390xReloc::TLSTPOff { offset: offset, sym: sym,
+                                        addend: addend }),
+        57 => Ok(S390xReloc::Abs20 { offset: offset, sym: sym,
+                                     addend: addend }),
+        58 => Ok(S390xReloc::GOT20 { offset: offset, sym: sym,
+                                     addend: addend }),
+        59 => Ok(S390xReloc::GOTPLT20 { offset: offset, sym: sym,
+                                        addend: addend }),
+        60 => Ok(S390xReloc::TLSGOTIE20 { offset: offset, sym: sym,
+                                          addend: addend }),
+        61 => Ok(S390xReloc::IRelative { offset: offset, addend: addend }),
+        62 => Ok(S390xReloc::PC12DBL { offset: offset, sym: sym,
+                                       addend: addend }),
+        63 => Ok(S390xReloc::PLT12DBL { offset: offset, sym: sym,
+                                        addend: addend }),
+        64 => Ok(S390xReloc::PC24DBL { offset: offset, sym: sym,
+                                       addend: addend }),
+        65 => Ok(S390xReloc::PLT24DBL { offset: offset, sym: sym,
+                                        addend: addend }),
+        tag => Err(S390xRelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf64>> for S390xReloc<Name> {
+    type Error = S390xRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf64>) -> Result<S390xReloc<Name>,
+                                                                Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<S390xReloc<u32>> for RelData<u32, Elf64> {
+    type Error = S390xToRelError;
+
+    #[inline]
+    fn try_from(rel: S390xReloc<u32>) -> Result<RelData<u32, Elf64>,
+                                                Self::Error> {
+        match rel {
+            S390xReloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            S390xReloc::Abs8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            S390xReloc::Abs8 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Abs12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            S390xReloc::Abs12 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Abs16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
+            S390xReloc::Abs16 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Abs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            S390xReloc::Abs32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            S390xReloc::PC32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOT12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            S390xReloc::GOT12 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            S390xReloc::GOT32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            S390xReloc::PLT32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            S390xReloc::GlobalData { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            S390xReloc::GlobalData { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            S390xReloc::JumpSlot { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 12 }),
+            S390xReloc::Relative { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 13 }),
+            S390xReloc::GOTOff32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPC { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 14 }),
+            S390xReloc::GOTPC { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOT16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 15 }),
+            S390xReloc::GOT16 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 16 }),
+            S390xReloc::PC16 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC16DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 17 }),
+            S390xReloc::PC16DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLT16DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 18 }),
+            S390xReloc::PLT16DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC32DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 19 }),
+            S390xReloc::PC32DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLT32DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            S390xReloc::PLT32DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPCDBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            S390xReloc::GOTPCDBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Abs64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 22 }),
+            S390xReloc::Abs64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 23 }),
+            S390xReloc::PC64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOT64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 24 }),
+            S390xReloc::GOT64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLT64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 25 }),
+            S390xReloc::PLT64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTEnt { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            S390xReloc::GOTEnt { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTOff16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            S390xReloc::GOTOff16 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTOff64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            S390xReloc::GOTOff64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPLT12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            S390xReloc::GOTPLT12 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPLT16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 30 }),
+            S390xReloc::GOTPLT16 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 31 }),
+            S390xReloc::GOTPLT32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPLT64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 32 }),
+            S390xReloc::GOTPLT64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPLTEnt { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 33 }),
+            S390xReloc::GOTPLTEnt { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLTOff16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 34 }),
+            S390xReloc::PLTOff16 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLTOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 35 }),
+            S390xReloc::PLTOff32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLTOff64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 36 }),
+            S390xReloc::PLTOff64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLoad { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 37 }),
+            S390xReloc::TLSLoad { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGDCall { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 38 }),
+            S390xReloc::TLSGDCall { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLDCall { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 39 }),
+            S390xReloc::TLSLDCall { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGD32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 40 }),
+            S390xReloc::TLSGD32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGD64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 41 }),
+            S390xReloc::TLSGD64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGOTIE12 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 42 }),
+            S390xReloc::TLSGOTIE12 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGOTIE32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 43 }),
+            S390xReloc::TLSGOTIE32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGOTIE64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 44 }),
+            S390xReloc::TLSGOTIE64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLDM32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 45 }),
+            S390xReloc::TLSLDM32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLDM64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 46 }),
+            S390xReloc::TLSLDM64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSIE32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 47 }),
+            S390xReloc::TLSIE32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSIE64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 48 }),
+            S390xReloc::TLSIE64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSIEEnt { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 49 }),
+            S390xReloc::TLSIEEnt { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLE32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 50 }),
+            S390xReloc::TLSLE32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLE64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 51 }),
+            S390xReloc::TLSLE64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLDO32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 52 }),
+            S390xReloc::TLSLDO32 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSLDO64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 53 }),
+            S390xReloc::TLSLDO64 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSDTPMod { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 54 }),
+            S390xReloc::TLSDTPOff { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 55 }),
+            S390xReloc::TLSDTPOff { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSTPOff { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 56 }),
+            S390xReloc::TLSTPOff { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::Abs20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 57 }),
+            S390xReloc::Abs20 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOT20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 58 }),
+            S390xReloc::GOT20 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::GOTPLT20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 59 }),
+            S390xReloc::GOTPLT20 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::TLSGOTIE20 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 60 }),
+            S390xReloc::TLSGOTIE20 { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 61 }),
+            S390xReloc::IRelative { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC12DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 62 }),
+            S390xReloc::PC12DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLT12DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 63 }),
+            S390xReloc::PLT12DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PC24DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 64 }),
+            S390xReloc::PC24DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend)),
+            S390xReloc::PLT24DBL { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 65 }),
+            S390xReloc::PLT24DBL { addend, .. } =>
+                Err(S390xToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf64>> for S390xReloc<Name> {
+    type Error = S390xRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf64>) -> Result<S390xReloc<Name>,
+                                                                  Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl From<S390xReloc<u32>> for RelaData<u32, Elf64> {
+    #[inline]
+    fn from(rel: S390xReloc<u32>) -> RelaData<u32, Elf64> {
+        match rel {
+            S390xReloc::None =>
+                RelaData { offset: 0, sym: 0, kind: 0, addend: 0 },
+            S390xReloc::Abs8 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
+            S390xReloc::Abs12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
+            S390xReloc::Abs16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 3, addend: addend },
+            S390xReloc::Abs32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 4, addend: addend },
+            S390xReloc::PC32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 5, addend: addend },
+            S390xReloc::GOT12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 6, addend: addend },
+            S390xReloc::GOT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 7, addend: addend },
+            S390xReloc::PLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 8, addend: addend },
+            S390xReloc::Copy { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 9, addend: 0 },
+            S390xReloc::GlobalData { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
+            S390xReloc::JumpSlot { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
+            S390xReloc::Relative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 12, addend: addend },
+            S390xReloc::GOTOff32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 13, addend: addend },
+            S390xReloc::GOTPC { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 14, addend: addend },
+            S390xReloc::GOT16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 15, addend: addend },
+            S390xReloc::PC16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 16, addend: addend },
+            S390xReloc::PC16DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 17, addend: addend },
+            S390xReloc::PLT16DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 18, addend: addend },
+            S390xReloc::PC32DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 19, addend: addend },
+            S390xReloc::PLT32DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 20, addend: addend },
+            S390xReloc::GOTPCDBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 21, addend: addend },
+            S390xReloc::Abs64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 22, addend: addend },
+            S390xReloc::PC64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 23, addend: addend },
+            S390xReloc::GOT64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 24, addend: addend },
+            S390xReloc::PLT64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 25, addend: addend },
+            S390xReloc::GOTEnt { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 26, addend: addend },
+            S390xReloc::GOTOff16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 27, addend: addend },
+            S390xReloc::GOTOff64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 28, addend: addend },
+            S390xReloc::GOTPLT12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 29, addend: addend },
+            S390xReloc::GOTPLT16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 30, addend: addend },
+            S390xReloc::GOTPLT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 31, addend: addend },
+            S390xReloc::GOTPLT64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 32, addend: addend },
+            S390xReloc::GOTPLTEnt { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 33, addend: addend },
+            S390xReloc::PLTOff16 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 34, addend: addend },
+            S390xReloc::PLTOff32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 35, addend: addend },
+            S390xReloc::PLTOff64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 36, addend: addend },
+            S390xReloc::TLSLoad { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 37, addend: addend },
+            S390xReloc::TLSGDCall { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 38, addend: addend },
+            S390xReloc::TLSLDCall { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 39, addend: addend },
+            S390xReloc::TLSGD32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 40, addend: addend },
+            S390xReloc::TLSGD64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 41, addend: addend },
+            S390xReloc::TLSGOTIE12 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 42, addend: addend },
+            S390xReloc::TLSGOTIE32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 43, addend: addend },
+            S390xReloc::TLSGOTIE64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 44, addend: addend },
+            S390xReloc::TLSLDM32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 45, addend: addend },
+            S390xReloc::TLSLDM64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 46, addend: addend },
+            S390xReloc::TLSIE32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 47, addend: addend },
+            S390xReloc::TLSIE64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 48, addend: addend },
+            S390xReloc::TLSIEEnt { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 49, addend: addend },
+            S390xReloc::TLSLE32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 50, addend: addend },
+            S390xReloc::TLSLE64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 51, addend: addend },
+            S390xReloc::TLSLDO32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 52, addend: addend },
+            S390xReloc::TLSLDO64 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 53, addend: addend },
+            S390xReloc::TLSDTPMod { offset, sym } =>
+                RelaData { offset: offset, sym: sym, kind: 54, addend: 0 },
+            S390xReloc::TLSDTPOff { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 55, addend: addend },
+            S390xReloc::TLSTPOff { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 56, addend: addend },
+            S390xReloc::Abs20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 57, addend: addend },
+            S390xReloc::GOT20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 58, addend: addend },
+            S390xReloc::GOTPLT20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 59, addend: addend },
+            S390xReloc::TLSGOTIE20 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 60, addend: addend },
+            S390xReloc::IRelative { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 61, addend: addend },
+            S390xReloc::PC12DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 62, addend: addend },
+            S390xReloc::PLT12DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 63, addend: addend },
+            S390xReloc::PC24DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 64, addend: addend },
+            S390xReloc::PLT24DBL { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 65, addend: addend }
+        }
+    }
+}
+
+impl<'a, B, Class> WithSymtab<'a, B, Class> for S390xRelocRaw
+    where Class: SymOffsets,
+          B: ByteOrder {
+    type Result = S390xRelocRawSym<Class>;
+    type Error = RelocSymtabError<Class>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Class>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            S390xReloc::None => Ok(S390xReloc::None),
+            S390xReloc::Abs8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Abs8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Abs12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Abs12 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Abs16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Abs16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Abs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Abs32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PC32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOT12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOT12 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOT32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLT32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Copy { offset: offset,
+                                                  sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GlobalData { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GlobalData { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::JumpSlot { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Relative { offset, addend } =>
+                Ok(S390xReloc::Relative { offset: offset, addend: addend }),
+            S390xReloc::GOTOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTOff32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPC { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPC { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOT16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOT16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PC16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PC16DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC16DBL { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLT16DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLT16DBL { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PC32DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC32DBL { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLT32DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLT32DBL { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPCDBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPCDBL { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Abs64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Abs64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PC64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOT64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOT64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLT64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLT64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTEnt { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTEnt { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTOff16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTOff16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTOff64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTOff64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPLT12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPLT12 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPLT16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPLT16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPLT32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPLT64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPLT64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPLTEnt { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPLTEnt { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLTOff16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLTOff16 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLTOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLTOff32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLTOff64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLTOff64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLoad { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLoad { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGDCall { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGDCall { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLDCall { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLDCall { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGD32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGD32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGD64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGD64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGOTIE12 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGOTIE12 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGOTIE32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGOTIE32 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGOTIE64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGOTIE64 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLDM32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLDM32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLDM64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLDM64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSIE32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSIE32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSIE64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSIE64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSIEEnt { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSIEEnt { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLE32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLE32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLE64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLE64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLDO32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLDO32 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSLDO64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSLDO64 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSDTPMod { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSDTPMod { offset: offset,
+                                                       sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSDTPOff { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSDTPOff { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSTPOff { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSTPOff { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::Abs20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::Abs20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOT20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOT20 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::GOTPLT20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::GOTPLT20 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::TLSGOTIE20 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::TLSGOTIE20 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::IRelative { offset, addend } =>
+                Ok(S390xReloc::IRelative { offset: offset, addend: addend }),
+            S390xReloc::PC12DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC12DBL { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLT12DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLT12DBL { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PC24DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PC24DBL { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            S390xReloc::PLT24DBL { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(S390xReloc::PLT24DBL { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                }
+        }
+    }
+}
+
+impl<'a, Class> WithStrtab<'a> for S390xRelocRawSym<Class>
+    where Class: SymOffsets {
+    type Result = S390xRelocStrDataSym<'a, Class>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            S390xReloc::None => Ok(S390xReloc::None),
+            S390xReloc::Abs8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Abs12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Abs16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Abs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PC32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC32 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOT12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GlobalData { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GlobalData { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::JumpSlot { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Relative { offset, addend } =>
+                Ok(S390xReloc::Relative { offset: offset, addend: addend }),
+            S390xReloc::GOTOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTOff32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPC { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPC { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOT16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PC16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC16 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PC16DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC16DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLT16DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT16DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PC32DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC32DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLT32DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT32DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPCDBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPCDBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Abs64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PC64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC64 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOT64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLT64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTEnt { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTEnt { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTOff16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTOff16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTOff64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTOff64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPLT12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT12 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPLT16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPLT64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPLTEnt { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLTEnt { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLTOff16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLTOff16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLTOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLTOff32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLTOff64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLTOff64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLoad { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLoad { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGDCall { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGDCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLDCall { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGD32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGD32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGD64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGD64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGOTIE12 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE12 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGOTIE32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGOTIE64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLDM32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDM32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLDM64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDM64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSIE32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSIE32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSIE64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSIE64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSIEEnt { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSIEEnt { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLE32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLE32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLE64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLE64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLDO32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDO32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSLDO64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDO64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSDTPMod { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSDTPMod { offset: offset,
+                                                   sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSDTPOff { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSDTPOff { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSTPOff { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSTPOff { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::Abs20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs20 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOT20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT20 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::GOTPLT20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT20 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::TLSGOTIE20 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE20 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::IRelative { offset, addend } =>
+                Ok(S390xReloc::IRelative { offset: offset, addend: addend }),
+            S390xReloc::PC12DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC12DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLT12DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT12DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PC24DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC24DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            S390xReloc::PLT24DBL { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT24DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                }
+        }
+    }
+}
+
+impl<'a, Class> TryFrom<S390xRelocStrDataSym<'a, Class>>
+    for S390xRelocStrSym<'a, Class>
+    where Class: SymOffsets {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: S390xRelocStrDataSym<'a, Class>) ->
+        Result<S390xRelocStrSym<'a, Class>, Self::Error> {
+        match reloc {
+            S390xReloc::None => Ok(S390xReloc::None),
+            S390xReloc::Abs8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Abs12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Abs16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Abs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PC32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC32 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOT12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT12 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GlobalData { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GlobalData { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::JumpSlot { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Relative { offset, addend } =>
+                Ok(S390xReloc::Relative { offset: offset, addend: addend }),
+            S390xReloc::GOTOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTOff32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPC { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPC { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOT16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PC16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC16 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PC16DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC16DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLT16DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT16DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PC32DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC32DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLT32DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT32DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPCDBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPCDBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Abs64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PC64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC64 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOT64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLT64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTEnt { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTEnt { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTOff16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTOff16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTOff64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTOff64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPLT12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT12 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPLT16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPLT64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPLTEnt { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLTEnt { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLTOff16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLTOff16 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLTOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLTOff32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLTOff64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLTOff64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLoad { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLoad { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGDCall { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGDCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLDCall { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGD32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGD32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGD64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGD64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGOTIE12 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE12 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGOTIE32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGOTIE64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLDM32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDM32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLDM64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDM64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSIE32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSIE32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSIE64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSIE64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSIEEnt { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSIEEnt { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLE32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLE32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLE64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLE64 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLDO32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDO32 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSLDO64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSLDO64 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSDTPMod { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSDTPMod { offset: offset,
+                                                   sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSDTPOff { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSDTPOff { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSTPOff { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSTPOff { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::Abs20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::Abs20 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOT20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOT20 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::GOTPLT20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::GOTPLT20 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::TLSGOTIE20 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::TLSGOTIE20 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::IRelative { offset, addend } =>
+                Ok(S390xReloc::IRelative { offset: offset, addend: addend }),
+            S390xReloc::PC12DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC12DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLT12DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT12DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PC24DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PC24DBL { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            S390xReloc::PLT24DBL { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(S390xReloc::PLT24DBL { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a, Class> From<S390xRelocStrDataSym<'a, Class>> for S390xRelocStrData<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: S390xRelocStrDataSym<'a, Class>) -> S390xRelocStrData<'a> {
+        match reloc {
+            S390xReloc::None => S390xReloc::None,
+            S390xReloc::Abs8 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs8 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Abs12 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs12 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs16 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT12 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT12 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Copy { sym: SymData { name, .. }, offset } =>
+                S390xReloc::Copy { offset: offset, sym: name },
+            S390xReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::GlobalData { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::JumpSlot { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::Relative { offset, addend } =>
+                S390xReloc::Relative { offset: offset, addend: addend },
+            S390xReloc::GOTOff32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTOff32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPC { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOTPC { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT16 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT16 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC16 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC16 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC16DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC16DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT16DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT16DBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PC32DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC32DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT32DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT32DBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPCDBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPCDBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PLT64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PLT64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOTEnt { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOTEnt { offset: offset, sym: name,
+                                     addend: addend },
+            S390xReloc::GOTOff16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTOff16 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTOff64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTOff64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT12 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT12 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT16 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLTEnt { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::GOTPLTEnt { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::PLTOff16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLTOff16 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PLTOff32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLTOff32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PLTOff64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLTOff64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLoad { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSLoad { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSGDCall { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::TLSGDCall { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::TLSLDCall { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::TLSLDCall { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::TLSGD32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSGD32 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSGD64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSGD64 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSGOTIE12 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE12 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::TLSGOTIE32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE32 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::TLSGOTIE64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE64 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::TLSLDM32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDM32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLDM64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDM64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSIE32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSIE32 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSIE64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSIE64 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSIEEnt { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSIEEnt { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLE32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSLE32 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSLE64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSLE64 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSLDO32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDO32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLDO64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDO64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSDTPMod { sym: SymData { name, .. }, offset } =>
+                S390xReloc::TLSDTPMod { offset: offset, sym: name },
+            S390xReloc::TLSDTPOff { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::TLSDTPOff { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::TLSTPOff { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSTPOff { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::Abs20 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs20 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT20 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT20 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOTPLT20 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT20 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSGOTIE20 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE20 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::IRelative { offset, addend } =>
+                S390xReloc::IRelative { offset: offset, addend: addend },
+            S390xReloc::PC12DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC12DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT12DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT12DBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PC24DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC24DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT24DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT24DBL { offset: offset, sym: name,
+                                       addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<S390xRelocStrData<'a>> for S390xRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: S390xRelocStrData<'a>) ->
+        Result<S390xRelocStr<'a>, Self::Error> {
+        match reloc {
+            S390xReloc::None => Ok(S390xReloc::None),
+            S390xReloc::Abs8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::Abs8 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            S390xReloc::Abs8 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Abs8 { sym: None, offset, addend } =>
+                Ok(S390xReloc::Abs8 { offset: offset, sym: None,
+                                      addend: addend }),
+            S390xReloc::Abs12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::Abs12 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::Abs12 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Abs12 { sym: None, offset, addend } =>
+                Ok(S390xReloc::Abs12 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::Abs16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::Abs16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::Abs16 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Abs16 { sym: None, offset, addend } =>
+                Ok(S390xReloc::Abs16 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::Abs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::Abs32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::Abs32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Abs32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::Abs32 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::PC32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC32 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            S390xReloc::PC32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC32 { offset: offset, sym: None,
+                                      addend: addend }),
+            S390xReloc::GOT12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOT12 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::GOT12 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOT12 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOT12 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::GOT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOT32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::GOT32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOT32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOT32 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLT32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLT32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLT32 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(S390xReloc::Copy { offset: offset, sym: Some(name) }),
+            S390xReloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Copy { sym: None, offset } =>
+                Ok(S390xReloc::Copy { offset: offset, sym: None }),
+            S390xReloc::GlobalData { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GlobalData { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            S390xReloc::GlobalData { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GlobalData { sym: None, offset, addend } =>
+                Ok(S390xReloc::GlobalData { offset: offset, sym: None,
+                                            addend: addend }),
+            S390xReloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::JumpSlot { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(S390xReloc::JumpSlot { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::Relative { offset, addend } =>
+                Ok(S390xReloc::Relative { offset: offset, addend: addend }),
+            S390xReloc::GOTOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTOff32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTOff32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTOff32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTOff32 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPC { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPC { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::GOTPC { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPC { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPC { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::GOT16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOT16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::GOT16 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOT16 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOT16 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::PC16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC16 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            S390xReloc::PC16 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC16 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC16 { offset: offset, sym: None,
+                                      addend: addend }),
+            S390xReloc::PC16DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC16DBL { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::PC16DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC16DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC16DBL { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::PLT16DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLT16DBL { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLT16DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLT16DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLT16DBL { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::PC32DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC32DBL { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::PC32DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC32DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC32DBL { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::PLT32DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLT32DBL { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLT32DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLT32DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLT32DBL { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPCDBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPCDBL { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTPCDBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPCDBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPCDBL { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::Abs64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::Abs64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::Abs64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Abs64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::Abs64 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::PC64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC64 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            S390xReloc::PC64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC64 { offset: offset, sym: None,
+                                      addend: addend }),
+            S390xReloc::GOT64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOT64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::GOT64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOT64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOT64 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::PLT64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLT64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::PLT64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLT64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLT64 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::GOTEnt { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTEnt { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            S390xReloc::GOTEnt { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTEnt { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTEnt { offset: offset, sym: None,
+                                        addend: addend }),
+            S390xReloc::GOTOff16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTOff16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTOff16 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTOff16 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTOff16 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTOff64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTOff64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTOff64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTOff64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTOff64 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPLT12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPLT12 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTPLT12 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPLT12 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPLT12 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPLT16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPLT16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTPLT16 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPLT16 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPLT16 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPLT32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTPLT32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPLT32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPLT32 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPLT64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPLT64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTPLT64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPLT64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPLT64 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::GOTPLTEnt { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPLTEnt { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            S390xReloc::GOTPLTEnt { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPLTEnt { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPLTEnt { offset: offset, sym: None,
+                                           addend: addend }),
+            S390xReloc::PLTOff16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLTOff16 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLTOff16 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLTOff16 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLTOff16 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::PLTOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLTOff32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLTOff32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLTOff32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLTOff32 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::PLTOff64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLTOff64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLTOff64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLTOff64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLTOff64 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSLoad { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLoad { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSLoad { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLoad { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLoad { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSGDCall { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGDCall { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            S390xReloc::TLSGDCall { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGDCall { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGDCall { offset: offset, sym: None,
+                                           addend: addend }),
+            S390xReloc::TLSLDCall { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLDCall { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            S390xReloc::TLSLDCall { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLDCall { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLDCall { offset: offset, sym: None,
+                                           addend: addend }),
+            S390xReloc::TLSGD32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGD32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSGD32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGD32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGD32 { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSGD64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGD64 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSGD64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGD64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGD64 { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSGOTIE12 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE12 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            S390xReloc::TLSGOTIE12 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGOTIE12 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE12 { offset: offset, sym: None,
+                                            addend: addend }),
+            S390xReloc::TLSGOTIE32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE32 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            S390xReloc::TLSGOTIE32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGOTIE32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE32 { offset: offset, sym: None,
+                                            addend: addend }),
+            S390xReloc::TLSGOTIE64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE64 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            S390xReloc::TLSGOTIE64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGOTIE64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE64 { offset: offset, sym: None,
+                                            addend: addend }),
+            S390xReloc::TLSLDM32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLDM32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::TLSLDM32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLDM32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLDM32 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSLDM64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLDM64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::TLSLDM64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLDM64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLDM64 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSIE32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSIE32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSIE32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSIE32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSIE32 { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSIE64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSIE64 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSIE64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSIE64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSIE64 { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSIEEnt { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSIEEnt { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::TLSIEEnt { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSIEEnt { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSIEEnt { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSLE32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLE32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSLE32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLE32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLE32 { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSLE64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLE64 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::TLSLE64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLE64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLE64 { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::TLSLDO32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLDO32 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::TLSLDO32 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLDO32 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLDO32 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSLDO64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSLDO64 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::TLSLDO64 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSLDO64 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSLDO64 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSDTPMod { sym: Some(Ok(name)), offset } =>
+                Ok(S390xReloc::TLSDTPMod { offset: offset, sym: Some(name) }),
+            S390xReloc::TLSDTPMod { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSDTPMod { sym: None, offset } =>
+                Ok(S390xReloc::TLSDTPMod { offset: offset, sym: None }),
+            S390xReloc::TLSDTPOff { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSDTPOff { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            S390xReloc::TLSDTPOff { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSDTPOff { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSDTPOff { offset: offset, sym: None,
+                                           addend: addend }),
+            S390xReloc::TLSTPOff { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSTPOff { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::TLSTPOff { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSTPOff { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSTPOff { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::Abs20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::Abs20 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::Abs20 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::Abs20 { sym: None, offset, addend } =>
+                Ok(S390xReloc::Abs20 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::GOT20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOT20 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            S390xReloc::GOT20 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOT20 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOT20 { offset: offset, sym: None,
+                                       addend: addend }),
+            S390xReloc::GOTPLT20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::GOTPLT20 { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::GOTPLT20 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::GOTPLT20 { sym: None, offset, addend } =>
+                Ok(S390xReloc::GOTPLT20 { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::TLSGOTIE20 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE20 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            S390xReloc::TLSGOTIE20 { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::TLSGOTIE20 { sym: None, offset, addend } =>
+                Ok(S390xReloc::TLSGOTIE20 { offset: offset, sym: None,
+                                            addend: addend }),
+            S390xReloc::IRelative { offset, addend } =>
+                Ok(S390xReloc::IRelative { offset: offset, addend: addend }),
+            S390xReloc::PC12DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC12DBL { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::PC12DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC12DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC12DBL { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::PLT12DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLT12DBL { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLT12DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLT12DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLT12DBL { offset: offset, sym: None,
+                                          addend: addend }),
+            S390xReloc::PC24DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PC24DBL { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            S390xReloc::PC24DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PC24DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PC24DBL { offset: offset, sym: None,
+                                         addend: addend }),
+            S390xReloc::PLT24DBL { sym: Some(Ok(name)), offset, addend } =>
+                Ok(S390xReloc::PLT24DBL { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            S390xReloc::PLT24DBL { sym: Some(Err(err)), .. } => Err(err),
+            S390xReloc::PLT24DBL { sym: None, offset, addend } =>
+                Ok(S390xReloc::PLT24DBL { offset: offset, sym: None,
+                                          addend: addend })
+        }
+    }
+}
+
+impl<'a, Class> From<S390xRelocStrSym<'a, Class>> for S390xRelocStr<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: S390xRelocStrSym<'a, Class>) -> S390xRelocStr<'a> {
+        match reloc {
+            S390xReloc::None => S390xReloc::None,
+            S390xReloc::Abs8 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs8 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Abs12 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs12 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs16 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT12 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT12 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            S390xReloc::Copy { sym: SymData { name, .. }, offset } =>
+                S390xReloc::Copy { offset: offset, sym: name },
+            S390xReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::GlobalData { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::JumpSlot { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::Relative { offset, addend } =>
+                S390xReloc::Relative { offset: offset, addend: addend },
+            S390xReloc::GOTOff32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTOff32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPC { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOTPC { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT16 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT16 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC16 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC16 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC16DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC16DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT16DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT16DBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PC32DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC32DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT32DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT32DBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPCDBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPCDBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PC64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::PLT64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PLT64 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOTEnt { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOTEnt { offset: offset, sym: name,
+                                     addend: addend },
+            S390xReloc::GOTOff16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTOff16 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTOff64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTOff64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT12 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT12 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT16 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLT64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::GOTPLTEnt { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::GOTPLTEnt { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::PLTOff16 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLTOff16 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PLTOff32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLTOff32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PLTOff64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLTOff64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLoad { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSLoad { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSGDCall { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::TLSGDCall { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::TLSLDCall { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::TLSLDCall { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::TLSGD32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSGD32 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSGD64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSGD64 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSGOTIE12 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE12 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::TLSGOTIE32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE32 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::TLSGOTIE64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE64 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::TLSLDM32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDM32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLDM64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDM64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSIE32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSIE32 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSIE64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSIE64 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSIEEnt { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSIEEnt { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLE32 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSLE32 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSLE64 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::TLSLE64 { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::TLSLDO32 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDO32 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSLDO64 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSLDO64 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSDTPMod { sym: SymData { name, .. }, offset } =>
+                S390xReloc::TLSDTPMod { offset: offset, sym: name },
+            S390xReloc::TLSDTPOff { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                S390xReloc::TLSDTPOff { offset: offset, sym: name,
+                                        addend: addend },
+            S390xReloc::TLSTPOff { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::TLSTPOff { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::Abs20 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::Abs20 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOT20 { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::GOT20 { offset: offset, sym: name, addend: addend },
+            S390xReloc::GOTPLT20 { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::GOTPLT20 { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::TLSGOTIE20 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                S390xReloc::TLSGOTIE20 { offset: offset, sym: name,
+                                         addend: addend },
+            S390xReloc::IRelative { offset, addend } =>
+                S390xReloc::IRelative { offset: offset, addend: addend },
+            S390xReloc::PC12DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC12DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT12DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT12DBL { offset: offset, sym: name,
+                                       addend: addend },
+            S390xReloc::PC24DBL { sym: SymData { name, .. }, offset, addend } =>
+                S390xReloc::PC24DBL { offset: offset, sym: name,
+                                      addend: addend },
+            S390xReloc::PLT24DBL { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                S390xReloc::PLT24DBL { offset: offset, sym: name,
+                                       addend: addend }
+        }
+    }
+}
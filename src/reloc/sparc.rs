@@ -0,0 +1,6268 @@
+//! Relocation types for SPARC architecture.
+//!
+//! This module provides the `SparcReloc` type, which describes the
+//! relocation entries for the SPARC architecture, as defined by the
+//! SPARC Compliance Definition and the SPARC V9 ELF ABI.  The same
+//! relocation types are used by both 32-bit SPARC and 64-bit SPARC
+//! V9, so these can be converted to and from
+//! [RelData](crate::reloc::RelData) or
+//! [RelaData](crate::reloc::RelaData) with either
+//! [Elf32](crate::Elf32) or [Elf64](crate::Elf64) as the
+//! [ElfClass](crate::ElfClass) type argument using the
+//! [TryFrom](core::convert::TryFrom) instances for easier handling.
+//!
+//! SPARC V9 splits the 64-bit type field in two: the low 8 bits hold
+//! the relocation type, and the upper 24 bits hold extra data.  At
+//! present, only [OLO10](SparcReloc::OLO10) uses this, to hold a
+//! second addend.
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use crate::elf::Elf32;
+use crate::elf::Elf64;
+use crate::reloc::RelData;
+use crate::reloc::RelaData;
+use crate::reloc::RelocSymtabError;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::Symtab;
+use crate::symtab::SymData;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymDataStr;
+use crate::symtab::SymDataStrData;
+use crate::symtab::SymOffsets;
+use crate::symtab::WithSymtab;
+
+/// Relocation entries for SPARC architectures.
+///
+/// This datatype provides a semantic-level presentation of the SPARC
+/// relocation entries, covering both 32-bit SPARC and SPARC V9.
+/// These can be converted to and from
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) with [Elf32](crate::Elf32) or
+/// [Elf64](crate::Elf64) as the [ElfClass](crate::ElfClass) type
+/// argument using the [TryFrom](core::convert::TryFrom) instances for
+/// easier handling.  Offsets and addends are always stored at 64-bit
+/// width; converting back to a 32-bit relocation fails if they do not
+/// fit.
+///
+/// Most SPARC instructions carry a 13-bit immediate field, so
+/// addresses are typically built from a 22-bit `sethi` immediate
+/// combined with a 10-bit low part, as with the paired
+/// [Hi22](SparcReloc::Hi22)/[Lo10](SparcReloc::Lo10) relocations.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SparcReloc<Name> {
+    /// Null relocation.
+    None,
+    /// 8-bit absolute offset.
+    ///
+    /// Set the 1-byte word at `offset` to `sym + addend`.
+    Abs8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset` to `sym + addend`.
+    Abs16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`.
+    Abs32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 8-bit PC-relative offset.
+    ///
+    /// Set the 1-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    Disp8 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative offset.
+    ///
+    /// Set the 2-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    Disp16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    Disp32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 30-bit PC-relative word displacement.
+    ///
+    /// Set the 30-bit immediate field of the call at `offset` to the
+    /// relative word address of `sym + addend`.
+    WDisp30 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 22-bit PC-relative word displacement.
+    ///
+    /// Set the 22-bit immediate field of the branch at `offset` to the
+    /// relative word address of `sym + addend`.
+    WDisp22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of an absolute address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of `sym + addend`.
+    Hi22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 22-bit absolute offset.
+    ///
+    /// Set the 22-bit immediate field of the instruction at `offset` to
+    /// `sym + addend`.
+    Abs22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 13-bit absolute offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// `sym + addend`.
+    Abs13 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of an absolute address.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of `sym + addend`.
+    Lo10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a Global Offset Table offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of the GOT entry for `sym + addend`.
+    GOT10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 13-bit Global Offset Table offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the offset of the GOT entry for `sym + addend`.
+    GOT13 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a Global Offset Table offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of the GOT entry for `sym + addend`.
+    GOT22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a PC-relative offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the relative address of `sym + addend`.
+    PC10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a PC-relative offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the relative address of `sym + addend`.
+    PC22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 30-bit PC-relative Procedure Linkage Table call.
+    ///
+    /// Set the 30-bit immediate field of the call at `offset` to the
+    /// relative word address of the PLT entry for `sym`, plus `addend`.
+    WPLT30 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Writable copy.
+    ///
+    /// Create a copy of the symbol `sym` in a writable segment at
+    /// `offset`.
+    Copy {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// Global Offset Table entry fill.
+    ///
+    /// Set a Global Offset Table entry to the address of `sym + addend`.
+    /// The entry is the size of an address.
+    GlobalData {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address of
+    /// `sym + addend`.
+    JumpSlot {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Offset relative to the image base.
+    ///
+    /// Set the address-sized word at `offset` to the sum of the base
+    /// address and `addend`.
+    Relative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unaligned 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend`. The word need
+    /// not be aligned.
+    UAbs32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit Procedure Linkage Table address.
+    ///
+    /// Set the 4-byte word at `offset` to the address of the PLT entry
+    /// for `sym`, plus `addend`.
+    PLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a Procedure Linkage Table address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the address of the PLT entry for `sym`, plus
+    /// `addend`.
+    HiPLT22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a Procedure Linkage Table address.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the address of the PLT entry for `sym`, plus
+    /// `addend`.
+    LoPLT10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit PC-relative Procedure Linkage Table offset.
+    ///
+    /// Set the 4-byte word at `offset` to the relative address of the PLT
+    /// entry for `sym`, plus `addend`.
+    PCPLT32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a PC-relative Procedure Linkage Table offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the relative address of the PLT entry for `sym`,
+    /// plus `addend`.
+    PCPLT22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a PC-relative Procedure Linkage Table offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the relative address of the PLT entry for
+    /// `sym`, plus `addend`.
+    PCPLT10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 10-bit absolute offset.
+    ///
+    /// Set the 10-bit immediate field of the instruction at `offset` to
+    /// `sym + addend`.
+    Abs10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 11-bit absolute offset.
+    ///
+    /// Set the 11-bit immediate field of the instruction at `offset` to
+    /// `sym + addend`.
+    Abs11 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`.
+    Abs64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of an address plus a second addend.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of `sym + addend`, plus `secondary`.  This is only
+    /// used by SPARC V9, where `secondary` is encoded in the upper 24
+    /// bits of the relocation type.
+    OLO10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64,
+        /// Secondary addend, stored in the upper 24 bits of
+        /// the type field.
+        secondary: i32
+    },
+    /// Bits 42 through 63 of an absolute address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 42 through 63 of `sym + addend`.
+    HH22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 41 of an absolute address.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 41 of `sym + addend`.
+    HM10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 10 through 31 of an absolute address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of `sym + addend`.
+    LM22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 42 through 63 of a PC-relative offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 42 through 63 of the relative address of `sym + addend`.
+    PCHH22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 32 through 41 of a PC-relative offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// bits 32 through 41 of the relative address of `sym + addend`.
+    PCHM10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 10 through 31 of a PC-relative offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the relative address of `sym + addend`.
+    PCLM22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 16-bit PC-relative word displacement.
+    ///
+    /// Set the 16-bit immediate field of the branch-on-register at
+    /// `offset` to the relative word address of `sym + addend`.
+    WDisp16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 19-bit PC-relative word displacement.
+    ///
+    /// Set the 19-bit immediate field of the branch at `offset` to the
+    /// relative word address of `sym + addend`.
+    WDisp19 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 7-bit absolute offset.
+    ///
+    /// Set the 7-bit immediate field of the software trap at `offset` to
+    /// `sym + addend`.
+    Abs7 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 5-bit absolute offset.
+    ///
+    /// Set the 5-bit immediate field of the shift at `offset` to
+    /// `sym + addend`.
+    Abs5 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 6-bit absolute offset.
+    ///
+    /// Set the 6-bit immediate field of the shift at `offset` to
+    /// `sym + addend`.
+    Abs6 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit PC-relative offset.
+    ///
+    /// Set the 8-byte word at `offset` to the relative address of
+    /// `sym + addend` (computed by subtracting the address of `offset`).
+    Disp64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit Procedure Linkage Table address.
+    ///
+    /// Set the 8-byte word at `offset` to the address of the PLT entry
+    /// for `sym`, plus `addend`.
+    PLT64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of an inverted absolute address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the one's complement of `sym + addend`. This is
+    /// paired with [LoX10](SparcReloc::LoX10) to load addresses in the
+    /// upper or lower 4GiB of the address space.
+    HiX22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of an inverted absolute address.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of `sym + addend`, with the upper bits set to 1.
+    LoX10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 22 through 43 of an absolute address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 22 through 43 of `sym + addend`.
+    H44 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 21 of an absolute address.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// bits 12 through 21 of `sym + addend`.
+    M44 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 12 bits of an absolute address.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 12 bits of `sym + addend`.
+    L44 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Global register initialization.
+    ///
+    /// Initialize the application register `offset` to `sym + addend`.
+    /// This is used by `STT_REGISTER` symbols.
+    Register {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unaligned 64-bit absolute offset.
+    ///
+    /// Set the 8-byte word at `offset` to `sym + addend`. The word need
+    /// not be aligned.
+    UAbs64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Unaligned 16-bit absolute offset.
+    ///
+    /// Set the 2-byte word at `offset` to `sym + addend`. The word need
+    /// not be aligned.
+    UAbs16 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a general dynamic thread-local GOT offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of the GOT entry holding the
+    /// `tls_index` for `sym + addend`.
+    TLSGDHi22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a general dynamic thread-local GOT offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of the GOT entry holding the
+    /// `tls_index` for `sym + addend`.
+    TLSGDLo10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// General dynamic thread-local address add.
+    ///
+    /// Marks the `add` instruction at `offset` computing the address of
+    /// the `tls_index` for `sym + addend`, so that it can be relaxed by
+    /// the linker.
+    TLSGDAdd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// General dynamic thread-local call.
+    ///
+    /// Marks the call to `__tls_get_addr` at `offset` for `sym + addend`,
+    /// so that it can be relaxed by the linker.
+    TLSGDCall {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a local dynamic thread-local GOT offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of the GOT entry holding the module
+    /// `tls_index` for `sym + addend`.
+    TLSLDMHi22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a local dynamic thread-local GOT offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of the GOT entry holding the module
+    /// `tls_index` for `sym + addend`.
+    TLSLDMLo10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic thread-local address add.
+    ///
+    /// Marks the `add` instruction at `offset` computing the address of
+    /// the module `tls_index` for `sym + addend`, so that it can be
+    /// relaxed by the linker.
+    TLSLDMAdd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic thread-local call.
+    ///
+    /// Marks the call to `__tls_get_addr` at `offset` for the module of
+    /// `sym + addend`, so that it can be relaxed by the linker.
+    TLSLDMCall {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a local dynamic thread-local offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of `sym + addend` in its thread-local
+    /// storage block.
+    TLSLDOHiX22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a local dynamic thread-local offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of `sym + addend` in its
+    /// thread-local storage block.
+    TLSLDOLoX10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Local dynamic thread-local offset add.
+    ///
+    /// Marks the `add` instruction at `offset` adding the offset of
+    /// `sym + addend` to its module base, so that it can be relaxed by
+    /// the linker.
+    TLSLDOAdd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of an initial exec thread-local GOT offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of the GOT entry holding the thread
+    /// pointer offset for `sym + addend`.
+    TLSIEHi22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of an initial exec thread-local GOT offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of the GOT entry holding the thread
+    /// pointer offset for `sym + addend`.
+    TLSIELo10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Initial exec thread-local 32-bit load.
+    ///
+    /// Marks the `ld` instruction at `offset` loading the thread pointer
+    /// offset for `sym + addend`, so that it can be relaxed by the
+    /// linker.
+    TLSIELd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Initial exec thread-local 64-bit load.
+    ///
+    /// Marks the `ldx` instruction at `offset` loading the thread pointer
+    /// offset for `sym + addend`, so that it can be relaxed by the
+    /// linker.
+    TLSIELdX {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Initial exec thread-local address add.
+    ///
+    /// Marks the `add` instruction at `offset` adding the thread pointer
+    /// to the offset for `sym + addend`, so that it can be relaxed by the
+    /// linker.
+    TLSIEAdd {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of an inverted local exec thread pointer offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the one's complement of the thread pointer offset
+    /// for `sym + addend`.
+    TLSLEHiX22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of an inverted local exec thread pointer offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the thread pointer offset for `sym + addend`,
+    /// with the upper bits set to 1.
+    TLSLELoX10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit thread-local storage module index.
+    ///
+    /// Set the 4-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 64-bit thread-local storage module index.
+    ///
+    /// Set the 8-byte word at `offset` to the index of the module
+    /// containing the thread-local storage block for `sym`.
+    TLSDTPMod64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// 32-bit thread-local storage block offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSDTPOff32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread-local storage block offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend` in
+    /// its thread-local storage block.
+    TLSDTPOff64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit thread pointer offset.
+    ///
+    /// Set the 4-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSTPOff32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit thread pointer offset.
+    ///
+    /// Set the 8-byte word at `offset` to the offset of `sym + addend`
+    /// from the thread pointer.
+    TLSTPOff64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of an inverted Global Offset Table relative offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of `sym + addend` from the GOT,
+    /// inverted if the offset is negative.
+    GOTDataHiX22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of an inverted Global Offset Table relative offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of `sym + addend` from the GOT, with
+    /// the upper bits set if the offset is negative.
+    GOTDataLoX10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// High 22 bits of a relaxable Global Offset Table offset.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 10 through 31 of the offset of the GOT entry for `sym + addend`.
+    /// The linker may relax this to
+    /// [GOTDataHiX22](SparcReloc::GOTDataHiX22).
+    GOTDataOpHiX22 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Low 10 bits of a relaxable Global Offset Table offset.
+    ///
+    /// Set the 13-bit immediate field of the instruction at `offset` to
+    /// the low 10 bits of the offset of the GOT entry for `sym + addend`.
+    /// The linker may relax this to
+    /// [GOTDataLoX10](SparcReloc::GOTDataLoX10).
+    GOTDataOpLoX10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Relaxable Global Offset Table load.
+    ///
+    /// Marks the `ld` instruction at `offset` loading the GOT entry for
+    /// `sym + addend`, so that it can be relaxed by the linker.
+    GOTDataOp {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Bits 12 through 33 of an absolute address.
+    ///
+    /// Set the 22-bit immediate field of the `sethi` at `offset` to bits
+    /// 12 through 33 of `sym + addend`.
+    H34 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 32-bit symbol size.
+    ///
+    /// Set the 4-byte word at `offset` to the size of `sym` plus
+    /// `addend`.
+    Size32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 64-bit symbol size.
+    ///
+    /// Set the 8-byte word at `offset` to the size of `sym` plus
+    /// `addend`.
+    Size64 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// 10-bit PC-relative word displacement.
+    ///
+    /// Set the 10-bit immediate field of the compare-and-branch at
+    /// `offset` to the relative word address of `sym + addend`.
+    WDisp10 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect Procedure Linkage Table jump-slot fill.
+    ///
+    /// Set a Procedure Linkage Table entry to the address returned by the
+    /// resolver function at the base address plus `addend`.
+    JmpIRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Indirect offset relative to the image base.
+    ///
+    /// Set the address-sized word at `offset` to the address returned by
+    /// the resolver function at the base address plus `addend`.
+    IRelative {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// GNU C++ vtable hierarchy.
+    ///
+    /// Records that the vtable at `offset` inherits from `sym`, for use
+    /// by linker garbage collection.
+    GNUVTInherit {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name
+    },
+    /// GNU C++ vtable member usage.
+    ///
+    /// Records that entry `addend` of the vtable `sym` is used at
+    /// `offset`, for use by linker garbage collection.
+    GNUVTEntry {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Byte-reversed 32-bit absolute offset.
+    ///
+    /// Set the 4-byte word at `offset` to `sym + addend` with its bytes
+    /// in reversed order.
+    Rev32 {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    }
+}
+
+/// Type synonym for [SparcReloc] as projected from a
+/// [Rela](crate::reloc::Rela).
+///
+/// This is obtained directly from the [TryFrom] insance acting on a
+/// [Rela](crate::reloc::Rela).
+pub type SparcRelocRaw = SparcReloc<u32>;
+
+/// Type synonym for [SparcReloc] with [SymDataRaw] as the symbol
+/// type.
+///
+/// This is obtained directly from the [WithSymtab] instance acting on
+/// a [SparcRelocRaw].
+pub type SparcRelocRawSym<Class> = SparcReloc<SymDataRaw<Class>>;
+
+/// Type synonym for [SparcReloc] with [SymDataStrData] as the symbol
+/// type.
+///
+/// This is obtained directly from the
+/// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
+/// [SparcRelocRawSym].
+pub type SparcRelocStrDataSym<'a, Class> =
+    SparcReloc<SymDataStrData<'a, Class>>;
+
+/// Type synonym for [SparcReloc] with [SymDataStr] as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [SparcRelocStrDataSym].
+pub type SparcRelocStrData<'a> =
+    SparcReloc<Option<Result<&'a str, &'a [u8]>>>;
+
+/// Type synonym for [SparcReloc] with UTF-8 decoded string data as
+/// the symbol type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [SparcRelocStrDataSym].
+pub type SparcRelocStrSym<'a, Class> = SparcReloc<SymDataStr<'a, Class>>;
+
+/// Type synonym for [SparcReloc] with a `&'a str`s as the symbol
+/// type.
+///
+/// This is obtained directly from the [TryFrom] instance acting on a
+/// [SparcRelocStrSym].
+pub type SparcRelocStr<'a> = SparcReloc<Option<&'a str>>;
+
+/// Errors that can occur converting an [SparcReloc] to a
+/// [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData).
+///
+/// This can happen with a non-zero addend, when converting to a
+/// 32-bit relocation with an offset or addend that does not fit, or
+/// with a secondary addend that does not fit in the type field.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SparcToRelError {
+    /// Non-zero or out-of-range addend.
+    BadAddend(i64),
+    /// Out-of-range offset.
+    BadOffset(u64),
+    /// Out-of-range secondary addend.
+    BadSecondary(i32)
+}
+
+/// Errors that can occur converting a [RelData](crate::reloc::RelData) or
+/// [RelaData](crate::reloc::RelaData) to a [SparcReloc].
+///
+/// At present, this can only happen with a bad tag value.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SparcRelocError {
+    /// Unknown tag value.
+    BadTag(u32)
+}
+
+impl<Name> Display for SparcReloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SparcReloc::None => write!(f, "none"),
+            SparcReloc::Abs8 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- &{} + {}",
+                       offset, sym, addend),
+            SparcReloc::Abs16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            SparcReloc::Abs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Disp8 { offset, sym, addend } =>
+                write!(f, ".section[{}] <- (&{} + {}) - (&.section + {})",
+                       offset, sym, addend, offset),
+            SparcReloc::Disp16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 2, sym, addend, offset),
+            SparcReloc::Disp32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::WDisp30 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::WDisp22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::Hi22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 10",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Abs22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Abs13 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Lo10 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0x3ff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOT10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "&.got) & 0x3ff"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOT13 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[&{} + {}] - &.got",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOT22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}] - &.got) >> 10",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::PC10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) & 0x3ff"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::PC22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 10"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::WPLT30 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::Copy { sym, .. } =>
+                write!(f, "copy {}",
+                       sym),
+            SparcReloc::GlobalData { offset, sym, addend } =>
+                write!(f, ".got[{}] <- &{} + {}",
+                       offset, sym, addend),
+            SparcReloc::JumpSlot { offset, sym, addend } =>
+                write!(f, ".plt[{}] <- &{} + {}",
+                       offset, sym, addend),
+            SparcReloc::Relative { offset, addend } =>
+                write!(f, ".section[{}] <- &base + {}",
+                       offset, addend),
+            SparcReloc::UAbs32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::PLT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::HiPLT22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.plt[{}] + {}) >> 10",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::LoPLT10 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.plt[{}] + {}) & 0x3ff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::PCPLT32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::PCPLT22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) >> 10"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::PCPLT10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&.plt[{}] + {}) - ",
+                                  "(&.section + {})) & 0x3ff"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::Abs10 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Abs11 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Abs64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            SparcReloc::OLO10 { offset, sym, addend, secondary } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) & 0x3ff) + {}",
+                       offset, offset + 4, sym, addend, secondary),
+            SparcReloc::HH22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 42",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::HM10 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 32) & 0x3ff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::LM22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 10) & 0x3fffff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::PCHH22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 42"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::PCHM10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 32) & 0x3ff"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::PCLM22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (((&{} + {}) - ",
+                                  "(&.section + {})) >> 10) & 0x3fffff"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::WDisp16 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::WDisp19 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::Abs7 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Abs5 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Abs6 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Disp64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
+                       offset, offset + 8, sym, addend, offset),
+            SparcReloc::PLT64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 8, sym, addend),
+            SparcReloc::HiX22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- !(&{} + {}) >> 10",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::LoX10 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) & 0x3ff) | 0x1c00",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::H44 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 22",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::M44 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) >> 12) & 0x3ff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::L44 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) & 0xfff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Register { offset, sym, addend } =>
+                write!(f, "register %g{} <- &{} + {}",
+                       offset, sym, addend),
+            SparcReloc::UAbs64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 8, sym, addend),
+            SparcReloc::UAbs16 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &{} + {}",
+                       offset, offset + 2, sym, addend),
+            SparcReloc::TLSGDHi22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] - &.got) >> 10"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSGDLo10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_index(&{} + ",
+                                  "{})] - &.got) & 0x3ff"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSGDAdd { offset, sym, addend } =>
+                write!(f, "general dynamic add at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::TLSGDCall { offset, sym, addend } =>
+                write!(f, "general dynamic call at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::TLSLDMHi22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_module(&{} ",
+                                  "+ {})] - &.got) >> 10"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSLDMLo10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tls_module(&{} ",
+                                  "+ {})] - &.got) & 0x3ff"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSLDMAdd { offset, sym, addend } =>
+                write!(f, "local dynamic add at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::TLSLDMCall { offset, sym, addend } =>
+                write!(f, "local dynamic call at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::TLSLDOHiX22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread-local offset ",
+                                  "for ({} + {}) >> 10"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSLDOLoX10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread-local offset ",
+                                  "for ({} + {}) & 0x3ff"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSLDOAdd { offset, sym, addend } =>
+                write!(f, concat!("local dynamic offset add at .section[{}] ",
+                                  "for {} + {}"),
+                       offset, sym, addend),
+            SparcReloc::TLSIEHi22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tp_offset(&{} + ",
+                                  "{})] - &.got) >> 10"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSIELo10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[tp_offset(&{} + ",
+                                  "{})] - &.got) & 0x3ff"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSIELd { offset, sym, addend } =>
+                write!(f, "initial exec load at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::TLSIELdX { offset, sym, addend } =>
+                write!(f, concat!("initial exec 64-bit load at .section[{}] ",
+                                  "for {} + {}"),
+                       offset, sym, addend),
+            SparcReloc::TLSIEAdd { offset, sym, addend } =>
+                write!(f, "initial exec add at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::TLSLEHiX22 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- !(thread pointer ",
+                                  "offset for {} + {}) >> 10"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSLELoX10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((thread pointer ",
+                                  "offset for {} + {}) & 0x3ff) | 0x1c00"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSDTPMod32 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 4, sym),
+            SparcReloc::TLSDTPMod64 { offset, sym } =>
+                write!(f, ".section[{}..{}] <- thread-local module for {}",
+                       offset, offset + 8, sym),
+            SparcReloc::TLSDTPOff32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSDTPOff64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- thread-local offset for {} + {}",
+                       offset, offset + 8, sym, addend),
+            SparcReloc::TLSTPOff32 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::TLSTPOff64 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- thread pointer offset ",
+                                  "for {} + {}"),
+                       offset, offset + 8, sym, addend),
+            SparcReloc::GOTDataHiX22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &.got) >> 10",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOTDataLoX10 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- ((&{} + {}) - &.got) & 0x3ff",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOTDataOpHiX22 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&.got[&{} + {}] - &.got) >> 10",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOTDataOpLoX10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[&{} + {}] - ",
+                                  "&.got) & 0x3ff"),
+                       offset, offset + 4, sym, addend),
+            SparcReloc::GOTDataOp { offset, sym, addend } =>
+                write!(f, "GOT load at .section[{}] for {} + {}",
+                       offset, sym, addend),
+            SparcReloc::H34 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- (&{} + {}) >> 12",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Size32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- sizeof({}) + {}",
+                       offset, offset + 4, sym, addend),
+            SparcReloc::Size64 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- sizeof({}) + {}",
+                       offset, offset + 8, sym, addend),
+            SparcReloc::WDisp10 { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- ((&{} + {}) - ",
+                                  "(&.section + {})) >> 2"),
+                       offset, offset + 4, sym, addend, offset),
+            SparcReloc::JmpIRelative { offset, addend } =>
+                write!(f, ".plt[{}] <- (&base + {})()",
+                       offset, addend),
+            SparcReloc::IRelative { offset, addend } =>
+                write!(f, ".section[{}] <- (&base + {})()",
+                       offset, addend),
+            SparcReloc::GNUVTInherit { offset, sym } =>
+                write!(f, "vtable at .section[{}] inherits {}",
+                       offset, sym),
+            SparcReloc::GNUVTEntry { offset, sym, addend } =>
+                write!(f, "vtable {} entry {} used at .section[{}]",
+                       sym, addend, offset),
+            SparcReloc::Rev32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- bswap(&{} + {})",
+                       offset, offset + 4, sym, addend)
+        }
+    }
+}
+
+impl Display for SparcRelocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SparcRelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+        }
+    }
+}
+
+impl Display for SparcToRelError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SparcToRelError::BadAddend(addend) =>
+                write!(f, "bad addend value {}", addend),
+            SparcToRelError::BadOffset(offset) =>
+                write!(f, "bad offset value {}", offset),
+            SparcToRelError::BadSecondary(secondary) =>
+                write!(f, "bad secondary addend value {}", secondary)
+        }
+    }
+}
+
+/// Combine the secondary addend of an [OLO10](SparcReloc::OLO10)
+/// with its tag, checking that it fits in 24 bits.
+#[inline]
+fn olo10_kind(secondary: i32) -> Result<u32, SparcToRelError> {
+    if (-0x80_0000 .. 0x80_0000).contains(&secondary) {
+        Ok(((secondary as u32) << 8) | 33)
+    } else {
+        Err(SparcToRelError::BadSecondary(secondary))
+    }
+}
+
+fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<SparcReloc<Name>, SparcRelocError> {
+    match kind {
+        0 => Ok(SparcReloc::None),
+        1 => Ok(SparcReloc::Abs8 { offset: offset, sym: sym, addend: addend }),
+        2 => Ok(SparcReloc::Abs16 { offset: offset, sym: sym, addend: addend }),
+        3 => Ok(SparcReloc::Abs32 { offset: offset, sym: sym, addend: addend }),
+        4 => Ok(SparcReloc::Disp8 { offset: offset, sym: sym, addend: addend }),
+        5 => Ok(SparcReloc::Disp16 { offset: offset, sym: sym,
+                                     addend: addend }),
+        6 => Ok(SparcReloc::Disp32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        7 => Ok(SparcReloc::WDisp30 { offset: offset, sym: sym,
+                                      addend: addend }),
+        8 => Ok(SparcReloc::WDisp22 { offset: offset, sym: sym,
+                                      addend: addend }),
+        9 => Ok(SparcReloc::Hi22 { offset: offset, sym: sym, addend: addend }),
+        10 => Ok(SparcReloc::Abs22 { offset: offset, sym: sym,
+                                     addend: addend }),
+        11 => Ok(SparcReloc::Abs13 { offset: offset, sym: sym,
+                                     addend: addend }),
+        12 => Ok(SparcReloc::Lo10 { offset: offset, sym: sym, addend: addend }),
+        13 => Ok(SparcReloc::GOT10 { offset: offset, sym: sym,
+                                     addend: addend }),
+        14 => Ok(SparcReloc::GOT13 { offset: offset, sym: sym,
+                                     addend: addend }),
+        15 => Ok(SparcReloc::GOT22 { offset: offset, sym: sym,
+                                     addend: addend }),
+        16 => Ok(SparcReloc::PC10 { offset: offset, sym: sym, addend: addend }),
+        17 => Ok(SparcReloc::PC22 { offset: offset, sym: sym, addend: addend }),
+        18 => Ok(SparcReloc::WPLT30 { offset: offset, sym: sym,
+                                      addend: addend }),
+        19 => Ok(SparcReloc::Copy { offset: offset, sym: sym }),
+        20 => Ok(SparcReloc::GlobalData { offset: offset, sym: sym,
+                                          addend: addend }),
+        21 => Ok(SparcReloc::JumpSlot { offset: offset, sym: sym,
+                                        addend: addend }),
+        22 => Ok(SparcReloc::Relative { offset: offset, addend: addend }),
+        23 => Ok(SparcReloc::UAbs32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        24 => Ok(SparcReloc::PLT32 { offset: offset, sym: sym,
+                                     addend: addend }),
+        25 => Ok(SparcReloc::HiPLT22 { offset: offset, sym: sym,
+                                       addend: addend }),
+        26 => Ok(SparcReloc::LoPLT10 { offset: offset, sym: sym,
+                                       addend: addend }),
+        27 => Ok(SparcReloc::PCPLT32 { offset: offset, sym: sym,
+                                       addend: addend }),
+        28 => Ok(SparcReloc::PCPLT22 { offset: offset, sym: sym,
+                                       addend: addend }),
+        29 => Ok(SparcReloc::PCPLT10 { offset: offset, sym: sym,
+                                       addend: addend }),
+        30 => Ok(SparcReloc::Abs10 { offset: offset, sym: sym,
+                                     addend: addend }),
+        31 => Ok(SparcReloc::Abs11 { offset: offset, sym: sym,
+                                     addend: addend }),
+        32 => Ok(SparcReloc::Abs64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        tag if tag & 0xff == 33 =>
+            Ok(SparcReloc::OLO10 { offset: offset, sym: sym, addend: addend,
+                                   secondary: (tag as i32) >> 8 }),
+        34 => Ok(SparcReloc::HH22 { offset: offset, sym: sym, addend: addend }),
+        35 => Ok(SparcReloc::HM10 { offset: offset, sym: sym, addend: addend }),
+        36 => Ok(SparcReloc::LM22 { offset: offset, sym: sym, addend: addend }),
+        37 => Ok(SparcReloc::PCHH22 { offset: offset, sym: sym,
+                                      addend: addend }),
+        38 => Ok(SparcReloc::PCHM10 { offset: offset, sym: sym,
+                                      addend: addend }),
+        39 => Ok(SparcReloc::PCLM22 { offset: offset, sym: sym,
+                                      addend: addend }),
+        40 => Ok(SparcReloc::WDisp16 { offset: offset, sym: sym,
+                                       addend: addend }),
+        41 => Ok(SparcReloc::WDisp19 { offset: offset, sym: sym,
+                                       addend: addend }),
+        43 => Ok(SparcReloc::Abs7 { offset: offset, sym: sym, addend: addend }),
+        44 => Ok(SparcReloc::Abs5 { offset: offset, sym: sym, addend: addend }),
+        45 => Ok(SparcReloc::Abs6 { offset: offset, sym: sym, addend: addend }),
+        46 => Ok(SparcReloc::Disp64 { offset: offset, sym: sym,
+                                      addend: addend }),
+        47 => Ok(SparcReloc::PLT64 { offset: offset, sym: sym,
+                                     addend: addend }),
+        48 => Ok(SparcReloc::HiX22 { offset: offset, sym: sym,
+                                     addend: addend }),
+        49 => Ok(SparcReloc::LoX10 { offset: offset, sym: sym,
+                                     addend: addend }),
+        50 => Ok(SparcReloc::H44 { offset: offset, sym: sym, addend: addend }),
+        51 => Ok(SparcReloc::M44 { offset: offset, sym: sym, addend: addend }),
+        52 => Ok(SparcReloc::L44 { offset: offset, sym: sym, addend: addend }),
+        53 => Ok(SparcReloc::Register { offset: offset, sym: sym,
+                                        addend: addend }),
+        54 => Ok(SparcReloc::UAbs64 { offset: offset, sym: sym,
+                                      addend: addend }),
+        55 => Ok(SparcReloc::UAbs16 { offset: offset, sym: sym,
+                                      addend: addend }),
+        56 => Ok(SparcReloc::TLSGDHi22 { offset: offset, sym: sym,
+                                         addend: addend }),
+        57 => Ok(SparcReloc::TLSGDLo10 { offset: offset, sym: sym,
+                                         addend: addend }),
+        58 => Ok(SparcReloc::TLSGDAdd { offset: offset, sym: sym,
+                                        addend: addend }),
+        59 => Ok(SparcReloc::TLSGDCall { offset: offset, sym: sym,
+                                         addend: addend }),
+        60 => Ok(SparcReloc::TLSLDMHi22 { offset: offset, sym: sym,
+                                          addend: addend }),
+        61 => Ok(SparcReloc::TLSLDMLo10 { offset: offset, sym: sym,
+                                          addend: addend }),
+        62 => Ok(SparcReloc::TLSLDMAdd { offset: offset, sym: sym,
+                                         addend: addend }),
+        63 => Ok(SparcReloc::TLSLDMCall { offset: offset, sym: sym,
+                                          addend: addend }),
+        64 => Ok(SparcReloc::TLSLDOHiX22 { offset: offset, sym: sym,
+                                           addend: addend }),
+        65 => Ok(SparcReloc::TLSLDOLoX10 { offset: offset, sym: sym,
+                                           addend: addend }),
+        66 => Ok(SparcReloc::TLSLDOAdd { offset: offset, sym: sym,
+                                         addend: addend }),
+        67 => Ok(SparcReloc::TLSIEHi22 { offset: offset, sym: sym,
+                                         addend: addend }),
+        68 => Ok(SparcReloc::TLSIELo10 { offset: offset, sym: sym,
+                                         addend: addend }),
+        69 => Ok(SparcReloc::TLSIELd { offset: offset, sym: sym,
+                                       addend: addend }),
+        70 => Ok(SparcReloc::TLSIELdX { offset: offset, sym: sym,
+                                        addend: addend }),
+        71 => Ok(SparcReloc::TLSIEAdd { offset: offset, sym: sym,
+                                        addend: addend }),
+        72 => Ok(SparcReloc::TLSLEHiX22 { offset: offset, sym: sym,
+                                          addend: addend }),
+        73 => Ok(SparcReloc::TLSLELoX10 { offset: offset, sym: sym,
+                                          addend: addend }),
+        74 => Ok(SparcReloc::TLSDTPMod32 { offset: offset, sym: sym }),
+        75 => Ok(SparcReloc::TLSDTPMod64 { offset: offset, sym: sym }),
+        76 => Ok(SparcReloc::TLSDTPOff32 { offset: offset, sym: sym,
+                                           addend: addend }),
+        77 => Ok(SparcReloc::TLSDTPOff64 { offset: offset, sym: sym,
+                                           addend: addend }),
+        78 => Ok(SparcReloc::TLSTPOff32 { offset: offset, sym: sym,
+                                          addend: addend }),
+        79 => Ok(SparcReloc::TLSTPOff64 { offset: offset, sym: sym,
+                                          addend: addend }),
+        80 => Ok(SparcReloc::GOTDataHiX22 { offset: offset, sym: sym,
+                                            addend: addend }),
+        81 => Ok(SparcReloc::GOTDataLoX10 { offset: offset, sym: sym,
+                                            addend: addend }),
+        82 => Ok(SparcReloc::GOTDataOpHiX22 { offset: offset, sym: sym,
+                                              addend: addend }),
+        83 => Ok(SparcReloc::GOTDataOpLoX10 { offset: offset, sym: sym,
+                                              addend: addend }),
+        84 => Ok(SparcReloc::GOTDataOp { offset: offset, sym: sym,
+                                         addend: addend }),
+        85 => Ok(SparcReloc::H34 { offset: offset, sym: sym, addend: addend }),
+        86 => Ok(SparcReloc::Size32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        87 => Ok(SparcReloc::Size64 { offset: offset, sym: sym,
+                                      addend: addend }),
+        88 => Ok(SparcReloc::WDisp10 { offset: offset, sym: sym,
+                                       addend: addend }),
+        248 => Ok(SparcReloc::JmpIRelative { offset: offset, addend: addend }),
+        249 => Ok(SparcReloc::IRelative { offset: offset, addend: addend }),
+        250 => Ok(SparcReloc::GNUVTInherit { offset: offset, sym: sym }),
+        251 => Ok(SparcReloc::GNUVTEntry { offset: offset, sym: sym,
+                                           addend: addend }),
+        252 => Ok(SparcReloc::Rev32 { offset: offset, sym: sym,
+                                      addend: addend }),
+        tag => Err(SparcRelocError::BadTag(tag))
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf64>> for SparcReloc<Name> {
+    type Error = SparcRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf64>) -> Result<SparcReloc<Name>,
+                                                                Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset, sym, kind, 0)
+    }
+}
+
+impl TryFrom<SparcReloc<u32>> for RelData<u32, Elf64> {
+    type Error = SparcToRelError;
+
+    #[inline]
+    fn try_from(rel: SparcReloc<u32>) -> Result<RelData<u32, Elf64>,
+                                                Self::Error> {
+        match rel {
+            SparcReloc::None =>
+                Ok(RelData { offset: 0, sym: 0, kind: 0 }),
+            SparcReloc::Abs8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 1 }),
+            SparcReloc::Abs8 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 2 }),
+            SparcReloc::Abs16 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
+            SparcReloc::Abs32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Disp8 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 4 }),
+            SparcReloc::Disp8 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Disp16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 5 }),
+            SparcReloc::Disp16 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Disp32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 6 }),
+            SparcReloc::Disp32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::WDisp30 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 7 }),
+            SparcReloc::WDisp30 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::WDisp22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 8 }),
+            SparcReloc::WDisp22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Hi22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 9 }),
+            SparcReloc::Hi22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 10 }),
+            SparcReloc::Abs22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs13 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
+            SparcReloc::Abs13 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Lo10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 12 }),
+            SparcReloc::Lo10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOT10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 13 }),
+            SparcReloc::GOT10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOT13 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 14 }),
+            SparcReloc::GOT13 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOT22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 15 }),
+            SparcReloc::GOT22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PC10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 16 }),
+            SparcReloc::PC10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PC22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 17 }),
+            SparcReloc::PC22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::WPLT30 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 18 }),
+            SparcReloc::WPLT30 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Copy { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 19 }),
+            SparcReloc::GlobalData { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 20 }),
+            SparcReloc::GlobalData { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::JumpSlot { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 21 }),
+            SparcReloc::JumpSlot { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Relative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 22 }),
+            SparcReloc::Relative { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::UAbs32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 23 }),
+            SparcReloc::UAbs32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 24 }),
+            SparcReloc::PLT32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::HiPLT22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 25 }),
+            SparcReloc::HiPLT22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::LoPLT10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 26 }),
+            SparcReloc::LoPLT10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PCPLT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 27 }),
+            SparcReloc::PCPLT32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PCPLT22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 28 }),
+            SparcReloc::PCPLT22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PCPLT10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 29 }),
+            SparcReloc::PCPLT10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 30 }),
+            SparcReloc::Abs10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs11 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 31 }),
+            SparcReloc::Abs11 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 32 }),
+            SparcReloc::Abs64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::OLO10 { offset, sym, addend: 0, secondary } =>
+                match olo10_kind(secondary) {
+                    Ok(kind) => Ok(RelData { offset: offset, sym: sym,
+                                             kind: kind }),
+                    Err(err) => Err(err)
+                },
+            SparcReloc::OLO10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::HH22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 34 }),
+            SparcReloc::HH22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::HM10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 35 }),
+            SparcReloc::HM10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::LM22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 36 }),
+            SparcReloc::LM22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PCHH22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 37 }),
+            SparcReloc::PCHH22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PCHM10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 38 }),
+            SparcReloc::PCHM10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PCLM22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 39 }),
+            SparcReloc::PCLM22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::WDisp16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 40 }),
+            SparcReloc::WDisp16 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::WDisp19 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 41 }),
+            SparcReloc::WDisp19 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs7 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 43 }),
+            SparcReloc::Abs7 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs5 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 44 }),
+            SparcReloc::Abs5 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Abs6 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 45 }),
+            SparcReloc::Abs6 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Disp64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 46 }),
+            SparcReloc::Disp64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::PLT64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 47 }),
+            SparcReloc::PLT64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::HiX22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 48 }),
+            SparcReloc::HiX22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::LoX10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 49 }),
+            SparcReloc::LoX10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::H44 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 50 }),
+            SparcReloc::H44 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::M44 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 51 }),
+            SparcReloc::M44 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::L44 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 52 }),
+            SparcReloc::L44 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Register { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 53 }),
+            SparcReloc::Register { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::UAbs64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 54 }),
+            SparcReloc::UAbs64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::UAbs16 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 55 }),
+            SparcReloc::UAbs16 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSGDHi22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 56 }),
+            SparcReloc::TLSGDHi22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSGDLo10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 57 }),
+            SparcReloc::TLSGDLo10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSGDAdd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 58 }),
+            SparcReloc::TLSGDAdd { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSGDCall { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 59 }),
+            SparcReloc::TLSGDCall { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDMHi22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 60 }),
+            SparcReloc::TLSLDMHi22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDMLo10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 61 }),
+            SparcReloc::TLSLDMLo10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDMAdd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 62 }),
+            SparcReloc::TLSLDMAdd { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDMCall { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 63 }),
+            SparcReloc::TLSLDMCall { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDOHiX22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 64 }),
+            SparcReloc::TLSLDOHiX22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDOLoX10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 65 }),
+            SparcReloc::TLSLDOLoX10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLDOAdd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 66 }),
+            SparcReloc::TLSLDOAdd { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSIEHi22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 67 }),
+            SparcReloc::TLSIEHi22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSIELo10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 68 }),
+            SparcReloc::TLSIELo10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSIELd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 69 }),
+            SparcReloc::TLSIELd { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSIELdX { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 70 }),
+            SparcReloc::TLSIELdX { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSIEAdd { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 71 }),
+            SparcReloc::TLSIEAdd { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLEHiX22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 72 }),
+            SparcReloc::TLSLEHiX22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSLELoX10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 73 }),
+            SparcReloc::TLSLELoX10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSDTPMod32 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 74 }),
+            SparcReloc::TLSDTPMod64 { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 75 }),
+            SparcReloc::TLSDTPOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 76 }),
+            SparcReloc::TLSDTPOff32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSDTPOff64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 77 }),
+            SparcReloc::TLSDTPOff64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSTPOff32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 78 }),
+            SparcReloc::TLSTPOff32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::TLSTPOff64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 79 }),
+            SparcReloc::TLSTPOff64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOTDataHiX22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 80 }),
+            SparcReloc::GOTDataHiX22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOTDataLoX10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 81 }),
+            SparcReloc::GOTDataLoX10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOTDataOpHiX22 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 82 }),
+            SparcReloc::GOTDataOpHiX22 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOTDataOpLoX10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 83 }),
+            SparcReloc::GOTDataOpLoX10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GOTDataOp { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 84 }),
+            SparcReloc::GOTDataOp { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::H34 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 85 }),
+            SparcReloc::H34 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Size32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 86 }),
+            SparcReloc::Size32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Size64 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 87 }),
+            SparcReloc::Size64 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::WDisp10 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 88 }),
+            SparcReloc::WDisp10 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::JmpIRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 248 }),
+            SparcReloc::JmpIRelative { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::IRelative { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 249 }),
+            SparcReloc::IRelative { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::GNUVTInherit { offset, sym } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 250 }),
+            SparcReloc::GNUVTEntry { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 251 }),
+            SparcReloc::GNUVTEntry { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend)),
+            SparcReloc::Rev32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 252 }),
+            SparcReloc::Rev32 { addend, .. } =>
+                Err(SparcToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf64>> for SparcReloc<Name> {
+    type Error = SparcRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf64>) -> Result<SparcReloc<Name>,
+                                                                  Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset, sym, kind, addend)
+    }
+}
+
+impl TryFrom<SparcReloc<u32>> for RelaData<u32, Elf64> {
+    type Error = SparcToRelError;
+
+    #[inline]
+    fn try_from(rel: SparcReloc<u32>) -> Result<RelaData<u32, Elf64>,
+                                                Self::Error> {
+        match rel {
+            SparcReloc::None =>
+                Ok(RelaData { offset: 0, sym: 0, kind: 0, addend: 0 }),
+            SparcReloc::Abs8 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 1,
+                              addend: addend }),
+            SparcReloc::Abs16 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 2,
+                              addend: addend }),
+            SparcReloc::Abs32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 3,
+                              addend: addend }),
+            SparcReloc::Disp8 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 4,
+                              addend: addend }),
+            SparcReloc::Disp16 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 5,
+                              addend: addend }),
+            SparcReloc::Disp32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 6,
+                              addend: addend }),
+            SparcReloc::WDisp30 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 7,
+                              addend: addend }),
+            SparcReloc::WDisp22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 8,
+                              addend: addend }),
+            SparcReloc::Hi22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 9,
+                              addend: addend }),
+            SparcReloc::Abs22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 10,
+                              addend: addend }),
+            SparcReloc::Abs13 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 11,
+                              addend: addend }),
+            SparcReloc::Lo10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 12,
+                              addend: addend }),
+            SparcReloc::GOT10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 13,
+                              addend: addend }),
+            SparcReloc::GOT13 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 14,
+                              addend: addend }),
+            SparcReloc::GOT22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 15,
+                              addend: addend }),
+            SparcReloc::PC10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 16,
+                              addend: addend }),
+            SparcReloc::PC22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 17,
+                              addend: addend }),
+            SparcReloc::WPLT30 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 18,
+                              addend: addend }),
+            SparcReloc::Copy { offset, sym } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 19, addend: 0 }),
+            SparcReloc::GlobalData { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 20,
+                              addend: addend }),
+            SparcReloc::JumpSlot { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 21,
+                              addend: addend }),
+            SparcReloc::Relative { offset, addend } =>
+                Ok(RelaData { offset: offset, sym: 0, kind: 22,
+                              addend: addend }),
+            SparcReloc::UAbs32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 23,
+                              addend: addend }),
+            SparcReloc::PLT32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 24,
+                              addend: addend }),
+            SparcReloc::HiPLT22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 25,
+                              addend: addend }),
+            SparcReloc::LoPLT10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 26,
+                              addend: addend }),
+            SparcReloc::PCPLT32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 27,
+                              addend: addend }),
+            SparcReloc::PCPLT22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 28,
+                              addend: addend }),
+            SparcReloc::PCPLT10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 29,
+                              addend: addend }),
+            SparcReloc::Abs10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 30,
+                              addend: addend }),
+            SparcReloc::Abs11 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 31,
+                              addend: addend }),
+            SparcReloc::Abs64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 32,
+                              addend: addend }),
+            SparcReloc::OLO10 { offset, sym, addend, secondary } =>
+                match olo10_kind(secondary) {
+                    Ok(kind) => Ok(RelaData { offset: offset, sym: sym,
+                                              kind: kind,
+                                              addend: addend }),
+                    Err(err) => Err(err)
+                },
+            SparcReloc::HH22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 34,
+                              addend: addend }),
+            SparcReloc::HM10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 35,
+                              addend: addend }),
+            SparcReloc::LM22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 36,
+                              addend: addend }),
+            SparcReloc::PCHH22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 37,
+                              addend: addend }),
+            SparcReloc::PCHM10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 38,
+                              addend: addend }),
+            SparcReloc::PCLM22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 39,
+                              addend: addend }),
+            SparcReloc::WDisp16 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 40,
+                              addend: addend }),
+            SparcReloc::WDisp19 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 41,
+                              addend: addend }),
+            SparcReloc::Abs7 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 43,
+                              addend: addend }),
+            SparcReloc::Abs5 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 44,
+                              addend: addend }),
+            SparcReloc::Abs6 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 45,
+                              addend: addend }),
+            SparcReloc::Disp64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 46,
+                              addend: addend }),
+            SparcReloc::PLT64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 47,
+                              addend: addend }),
+            SparcReloc::HiX22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 48,
+                              addend: addend }),
+            SparcReloc::LoX10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 49,
+                              addend: addend }),
+            SparcReloc::H44 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 50,
+                              addend: addend }),
+            SparcReloc::M44 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 51,
+                              addend: addend }),
+            SparcReloc::L44 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 52,
+                              addend: addend }),
+            SparcReloc::Register { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 53,
+                              addend: addend }),
+            SparcReloc::UAbs64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 54,
+                              addend: addend }),
+            SparcReloc::UAbs16 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 55,
+                              addend: addend }),
+            SparcReloc::TLSGDHi22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 56,
+                              addend: addend }),
+            SparcReloc::TLSGDLo10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 57,
+                              addend: addend }),
+            SparcReloc::TLSGDAdd { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 58,
+                              addend: addend }),
+            SparcReloc::TLSGDCall { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 59,
+                              addend: addend }),
+            SparcReloc::TLSLDMHi22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 60,
+                              addend: addend }),
+            SparcReloc::TLSLDMLo10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 61,
+                              addend: addend }),
+            SparcReloc::TLSLDMAdd { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 62,
+                              addend: addend }),
+            SparcReloc::TLSLDMCall { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 63,
+                              addend: addend }),
+            SparcReloc::TLSLDOHiX22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 64,
+                              addend: addend }),
+            SparcReloc::TLSLDOLoX10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 65,
+                              addend: addend }),
+            SparcReloc::TLSLDOAdd { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 66,
+                              addend: addend }),
+            SparcReloc::TLSIEHi22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 67,
+                              addend: addend }),
+            SparcReloc::TLSIELo10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 68,
+                              addend: addend }),
+            SparcReloc::TLSIELd { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 69,
+                              addend: addend }),
+            SparcReloc::TLSIELdX { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 70,
+                              addend: addend }),
+            SparcReloc::TLSIEAdd { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 71,
+                              addend: addend }),
+            SparcReloc::TLSLEHiX22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 72,
+                              addend: addend }),
+            SparcReloc::TLSLELoX10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 73,
+                              addend: addend }),
+            SparcReloc::TLSDTPMod32 { offset, sym } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 74, addend: 0 }),
+            SparcReloc::TLSDTPMod64 { offset, sym } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 75, addend: 0 }),
+            SparcReloc::TLSDTPOff32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 76,
+                              addend: addend }),
+            SparcReloc::TLSDTPOff64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 77,
+                              addend: addend }),
+            SparcReloc::TLSTPOff32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 78,
+                              addend: addend }),
+            SparcReloc::TLSTPOff64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 79,
+                              addend: addend }),
+            SparcReloc::GOTDataHiX22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 80,
+                              addend: addend }),
+            SparcReloc::GOTDataLoX10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 81,
+                              addend: addend }),
+            SparcReloc::GOTDataOpHiX22 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 82,
+                              addend: addend }),
+            SparcReloc::GOTDataOpLoX10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 83,
+                              addend: addend }),
+            SparcReloc::GOTDataOp { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 84,
+                              addend: addend }),
+            SparcReloc::H34 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 85,
+                              addend: addend }),
+            SparcReloc::Size32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 86,
+                              addend: addend }),
+            SparcReloc::Size64 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 87,
+                              addend: addend }),
+            SparcReloc::WDisp10 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 88,
+                              addend: addend }),
+            SparcReloc::JmpIRelative { offset, addend } =>
+                Ok(RelaData { offset: offset, sym: 0, kind: 248,
+                              addend: addend }),
+            SparcReloc::IRelative { offset, addend } =>
+                Ok(RelaData { offset: offset, sym: 0, kind: 249,
+                              addend: addend }),
+            SparcReloc::GNUVTInherit { offset, sym } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 250, addend: 0 }),
+            SparcReloc::GNUVTEntry { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 251,
+                              addend: addend }),
+            SparcReloc::Rev32 { offset, sym, addend } =>
+                Ok(RelaData { offset: offset, sym: sym, kind: 252,
+                              addend: addend })
+        }
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for SparcReloc<Name> {
+    type Error = SparcRelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) -> Result<SparcReloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to(offset.into(), sym, kind.into(), 0)
+    }
+}
+
+impl TryFrom<SparcReloc<u32>> for RelData<u32, Elf32> {
+    type Error = SparcToRelError;
+
+    #[inline]
+    fn try_from(rel: SparcReloc<u32>) -> Result<RelData<u32, Elf32>,
+                                                Self::Error> {
+        match RelData::<u32, Elf64>::try_from(rel) {
+            Ok(RelData { offset, sym, kind }) =>
+                match (offset.try_into(), kind.try_into()) {
+                    (Ok(offset), Ok(kind)) => Ok(RelData { offset: offset,
+                                                           sym: sym,
+                                                           kind: kind }),
+                    (Err(_), _) => Err(SparcToRelError::BadOffset(offset)),
+                    (_, Err(_)) =>
+                        Err(SparcToRelError::BadSecondary((kind as i32) >> 8))
+                },
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for SparcReloc<Name> {
+    type Error = SparcRelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) -> Result<SparcReloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to(offset.into(), sym, kind.into(), addend.into())
+    }
+}
+
+impl TryFrom<SparcReloc<u32>> for RelaData<u32, Elf32> {
+    type Error = SparcToRelError;
+
+    #[inline]
+    fn try_from(rel: SparcReloc<u32>) -> Result<RelaData<u32, Elf32>,
+                                                Self::Error> {
+        let RelaData { offset, sym, kind, addend } =
+            RelaData::<u32, Elf64>::try_from(rel)?;
+
+        match (offset.try_into(), kind.try_into(), addend.try_into()) {
+            (Ok(offset), Ok(kind), Ok(addend)) =>
+                Ok(RelaData { offset: offset, sym: sym, kind: kind,
+                              addend: addend }),
+            (Err(_), _, _) => Err(SparcToRelError::BadOffset(offset)),
+            (_, Err(_), _) =>
+                Err(SparcToRelError::BadSecondary((kind as i32) >> 8)),
+            (_, _, Err(_)) => Err(SparcToRelError::BadAddend(addend))
+        }
+    }
+}
+
+impl<'a, B, Class> WithSymtab<'a, B, Class> for SparcRelocRaw
+    where Class: SymOffsets,
+          B: ByteOrder {
+    type Result = SparcRelocRawSym<Class>;
+    type Error = RelocSymtabError<Class>;
+
+    #[inline]
+    fn with_symtab(self, symtab: Symtab<'a, B, Class>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            SparcReloc::None => Ok(SparcReloc::None),
+            SparcReloc::Abs8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs8 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs16 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Disp8 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Disp8 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Disp16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Disp16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Disp32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Disp32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::WDisp30 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::WDisp30 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::WDisp22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::WDisp22 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Hi22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Hi22 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs13 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs13 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Lo10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Lo10 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOT10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOT10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOT13 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOT13 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOT22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOT22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PC10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PC10 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PC22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PC22 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::WPLT30 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::WPLT30 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Copy { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Copy { offset: offset,
+                                                  sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GlobalData { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GlobalData { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::JumpSlot { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::JumpSlot { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Relative { offset, addend } =>
+                Ok(SparcReloc::Relative { offset: offset, addend: addend }),
+            SparcReloc::UAbs32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::UAbs32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PLT32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::HiPLT22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::HiPLT22 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::LoPLT10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::LoPLT10 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PCPLT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PCPLT32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PCPLT22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PCPLT22 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PCPLT10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PCPLT10 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs11 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs11 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::OLO10 { offset, sym, addend, secondary } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::OLO10 { offset: offset, sym: symdata,
+                                                   addend: addend,
+                                                   secondary: secondary })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::HH22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::HH22 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::HM10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::HM10 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::LM22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::LM22 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PCHH22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PCHH22 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PCHM10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PCHM10 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PCLM22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PCLM22 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::WDisp16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::WDisp16 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::WDisp19 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::WDisp19 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs7 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs7 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs5 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs5 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Abs6 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Abs6 { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Disp64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Disp64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::PLT64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::PLT64 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::HiX22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::HiX22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::LoX10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::LoX10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::H44 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::H44 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::M44 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::M44 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::L44 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::L44 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Register { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Register { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::UAbs64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::UAbs64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::UAbs16 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::UAbs16 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSGDHi22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSGDHi22 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSGDLo10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSGDLo10 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSGDAdd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSGDAdd { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSGDCall { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSGDCall { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDMHi22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDMHi22 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDMLo10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDMLo10 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDMAdd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDMAdd { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDMCall { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDMCall { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDOHiX22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDOHiX22 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDOLoX10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDOLoX10 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLDOAdd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLDOAdd { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSIEHi22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSIEHi22 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSIELo10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSIELo10 { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSIELd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSIELd { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSIELdX { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSIELdX { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSIEAdd { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSIEAdd { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLEHiX22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLEHiX22 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSLELoX10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSLELoX10 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSDTPMod32 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSDTPMod32 { offset: offset,
+                                                         sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSDTPMod64 { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSDTPMod64 { offset: offset,
+                                                         sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSDTPOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSDTPOff32 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSDTPOff64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSDTPOff64 { offset: offset,
+                                                         sym: symdata,
+                                                         addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSTPOff32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSTPOff32 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::TLSTPOff64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::TLSTPOff64 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOTDataHiX22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOTDataHiX22 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOTDataLoX10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOTDataLoX10 { offset: offset,
+                                                          sym: symdata,
+                                                          addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOTDataOpHiX22 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOTDataOpHiX22 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOTDataOpLoX10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOTDataOpLoX10 { offset: offset,
+                                                            sym: symdata,
+                                                            addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GOTDataOp { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GOTDataOp { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::H34 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::H34 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Size32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Size32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Size64 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Size64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::WDisp10 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::WDisp10 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::JmpIRelative { offset, addend } =>
+                Ok(SparcReloc::JmpIRelative { offset: offset, addend: addend }),
+            SparcReloc::IRelative { offset, addend } =>
+                Ok(SparcReloc::IRelative { offset: offset, addend: addend }),
+            SparcReloc::GNUVTInherit { offset, sym } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GNUVTInherit { offset: offset,
+                                                          sym: symdata })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::GNUVTEntry { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::GNUVTEntry { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            SparcReloc::Rev32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(SparcReloc::Rev32 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                }
+        }
+    }
+}
+
+impl<'a, Class> WithStrtab<'a> for SparcRelocRawSym<Class>
+    where Class: SymOffsets {
+    type Result = SparcRelocStrDataSym<'a, Class>;
+    type Error = u32;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            SparcReloc::None => Ok(SparcReloc::None),
+            SparcReloc::Abs8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Disp8 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp8 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Disp16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Disp32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::WDisp30 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp30 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::WDisp22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp22 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Hi22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Hi22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs22 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs13 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs13 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Lo10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Lo10 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOT10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOT10 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOT13 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOT13 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOT22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOT22 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PC10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PC10 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PC22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PC22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::WPLT30 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WPLT30 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Copy { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GlobalData { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GlobalData { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::JumpSlot { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::JumpSlot { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Relative { offset, addend } =>
+                Ok(SparcReloc::Relative { offset: offset, addend: addend }),
+            SparcReloc::UAbs32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::UAbs32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::HiPLT22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HiPLT22 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::LoPLT10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::LoPLT10 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PCPLT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCPLT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PCPLT22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCPLT22 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PCPLT10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCPLT10 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs10 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs11 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs11 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::OLO10 { offset, sym, addend, secondary } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::OLO10 { offset: offset, sym: symdata,
+                                               addend: addend,
+                                               secondary: secondary })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::HH22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HH22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::HM10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HM10 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::LM22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::LM22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PCHH22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCHH22 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PCHM10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCHM10 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PCLM22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCLM22 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::WDisp16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::WDisp19 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp19 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs7 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs7 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs5 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs5 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Abs6 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs6 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Disp64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::PLT64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PLT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::HiX22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HiX22 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::LoX10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::LoX10 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::H44 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::H44 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::M44 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::M44 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::L44 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::L44 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Register { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Register { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::UAbs64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::UAbs64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::UAbs16 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::UAbs16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSGDHi22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDHi22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSGDLo10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDLo10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSGDAdd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDAdd { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSGDCall { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDMHi22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMHi22 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDMLo10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMLo10 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDMAdd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMAdd { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDMCall { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMCall { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDOHiX22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDOHiX22 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDOLoX10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDOLoX10 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLDOAdd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDOAdd { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSIEHi22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIEHi22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSIELo10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIELo10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSIELd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIELd { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSIELdX { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIELdX { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSIEAdd { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIEAdd { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLEHiX22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLEHiX22 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSLELoX10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLELoX10 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPMod32 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSDTPMod64 { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPMod64 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSDTPOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPOff32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSDTPOff64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPOff64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSTPOff32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSTPOff32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::TLSTPOff64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSTPOff64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOTDataHiX22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataHiX22 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOTDataLoX10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataLoX10 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOTDataOpHiX22 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataOpHiX22 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOTDataOpLoX10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataOpLoX10 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GOTDataOp { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataOp { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::H34 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::H34 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Size32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Size32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Size64 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Size64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::WDisp10 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp10 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::JmpIRelative { offset, addend } =>
+                Ok(SparcReloc::JmpIRelative { offset: offset, addend: addend }),
+            SparcReloc::IRelative { offset, addend } =>
+                Ok(SparcReloc::IRelative { offset: offset, addend: addend }),
+            SparcReloc::GNUVTInherit { offset, sym } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GNUVTInherit { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GNUVTEntry { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                },
+            SparcReloc::Rev32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Rev32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                }
+        }
+    }
+}
+
+impl<'a, Class> TryFrom<SparcRelocStrDataSym<'a, Class>>
+    for SparcRelocStrSym<'a, Class>
+    where Class: SymOffsets {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: SparcRelocStrDataSym<'a, Class>) ->
+        Result<SparcRelocStrSym<'a, Class>, Self::Error> {
+        match reloc {
+            SparcReloc::None => Ok(SparcReloc::None),
+            SparcReloc::Abs8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs8 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs16 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Disp8 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp8 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Disp16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Disp32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::WDisp30 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp30 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::WDisp22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp22 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Hi22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Hi22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs22 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs13 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs13 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Lo10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Lo10 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOT10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOT10 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOT13 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOT13 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOT22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOT22 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PC10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PC10 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PC22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PC22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::WPLT30 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WPLT30 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Copy { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Copy { offset: offset, sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GlobalData { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GlobalData { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::JumpSlot { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::JumpSlot { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Relative { offset, addend } =>
+                Ok(SparcReloc::Relative { offset: offset, addend: addend }),
+            SparcReloc::UAbs32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::UAbs32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PLT32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::HiPLT22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HiPLT22 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::LoPLT10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::LoPLT10 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PCPLT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCPLT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PCPLT22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCPLT22 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PCPLT10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCPLT10 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs10 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs11 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs11 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::OLO10 { offset, sym, addend, secondary } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::OLO10 { offset: offset, sym: symdata,
+                                               addend: addend,
+                                               secondary: secondary })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::HH22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HH22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::HM10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HM10 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::LM22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::LM22 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PCHH22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCHH22 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PCHM10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCHM10 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PCLM22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PCLM22 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::WDisp16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp16 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::WDisp19 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp19 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs7 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs7 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs5 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs5 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Abs6 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Abs6 { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Disp64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Disp64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::PLT64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::PLT64 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::HiX22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::HiX22 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::LoX10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::LoX10 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::H44 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::H44 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::M44 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::M44 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::L44 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::L44 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Register { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Register { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::UAbs64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::UAbs64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::UAbs16 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::UAbs16 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSGDHi22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDHi22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSGDLo10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDLo10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSGDAdd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDAdd { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSGDCall { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSGDCall { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDMHi22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMHi22 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDMLo10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMLo10 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDMAdd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMAdd { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDMCall { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDMCall { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDOHiX22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDOHiX22 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDOLoX10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDOLoX10 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLDOAdd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLDOAdd { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSIEHi22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIEHi22 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSIELo10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIELo10 { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSIELd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIELd { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSIELdX { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIELdX { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSIEAdd { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSIEAdd { offset: offset, sym: symdata,
+                                                  addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLEHiX22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLEHiX22 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSLELoX10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSLELoX10 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSDTPMod32 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPMod32 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSDTPMod64 { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPMod64 { offset: offset,
+                                                     sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSDTPOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPOff32 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSDTPOff64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSDTPOff64 { offset: offset,
+                                                     sym: symdata,
+                                                     addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSTPOff32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSTPOff32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::TLSTPOff64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::TLSTPOff64 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOTDataHiX22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataHiX22 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOTDataLoX10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataLoX10 { offset: offset,
+                                                      sym: symdata,
+                                                      addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOTDataOpHiX22 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataOpHiX22 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOTDataOpLoX10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataOpLoX10 { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GOTDataOp { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GOTDataOp { offset: offset, sym: symdata,
+                                                   addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::H34 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::H34 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Size32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Size32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Size64 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Size64 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::WDisp10 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::WDisp10 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::JmpIRelative { offset, addend } =>
+                Ok(SparcReloc::JmpIRelative { offset: offset, addend: addend }),
+            SparcReloc::IRelative { offset, addend } =>
+                Ok(SparcReloc::IRelative { offset: offset, addend: addend }),
+            SparcReloc::GNUVTInherit { offset, sym } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GNUVTInherit { offset: offset,
+                                                      sym: symdata })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::GNUVTEntry { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::GNUVTEntry { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            SparcReloc::Rev32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(SparcReloc::Rev32 { offset: offset, sym: symdata,
+                                               addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
+        }
+    }
+}
+
+impl<'a, Class> From<SparcRelocStrDataSym<'a, Class>> for SparcRelocStrData<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: SparcRelocStrDataSym<'a, Class>) -> SparcRelocStrData<'a> {
+        match reloc {
+            SparcReloc::None => SparcReloc::None,
+            SparcReloc::Abs8 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs8 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs16 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Disp8 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp8 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Disp16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp16 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::Disp32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp32 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::WDisp30 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp30 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::WDisp22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp22 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::Hi22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Hi22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs13 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs13 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Lo10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Lo10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::GOT10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::GOT10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::GOT13 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::GOT13 { offset: offset, sym: name, addend: addend },
+            SparcReloc::GOT22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::GOT22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::PC10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PC10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::PC22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PC22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::WPLT30 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WPLT30 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::Copy { sym: SymData { name, .. }, offset } =>
+                SparcReloc::Copy { offset: offset, sym: name },
+            SparcReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::GlobalData { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::JumpSlot { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::Relative { offset, addend } =>
+                SparcReloc::Relative { offset: offset, addend: addend },
+            SparcReloc::UAbs32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::UAbs32 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            SparcReloc::HiPLT22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HiPLT22 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::LoPLT10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::LoPLT10 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::PCPLT32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCPLT32 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::PCPLT22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCPLT22 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::PCPLT10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCPLT10 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::Abs10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs11 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs11 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs64 { offset: offset, sym: name, addend: addend },
+            SparcReloc::OLO10 { sym: SymData { name, .. }, offset, addend,
+                                secondary } =>
+                SparcReloc::OLO10 { offset: offset, sym: name, addend: addend,
+                                    secondary: secondary },
+            SparcReloc::HH22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HH22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::HM10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HM10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::LM22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::LM22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::PCHH22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCHH22 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PCHM10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCHM10 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PCLM22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCLM22 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::WDisp16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp16 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::WDisp19 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp19 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::Abs7 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs7 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs5 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs5 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs6 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs6 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Disp64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp64 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PLT64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PLT64 { offset: offset, sym: name, addend: addend },
+            SparcReloc::HiX22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HiX22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::LoX10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::LoX10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::H44 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::H44 { offset: offset, sym: name, addend: addend },
+            SparcReloc::M44 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::M44 { offset: offset, sym: name, addend: addend },
+            SparcReloc::L44 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::L44 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Register { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::Register { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::UAbs64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::UAbs64 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::UAbs16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::UAbs16 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::TLSGDHi22 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSGDHi22 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSGDLo10 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSGDLo10 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSGDAdd { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::TLSGDAdd { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::TLSGDCall { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSGDCall { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSLDMHi22 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLDMHi22 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLDMLo10 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLDMLo10 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLDMAdd { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSLDMAdd { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSLDMCall { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLDMCall { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLDOHiX22 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSLDOHiX22 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSLDOLoX10 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSLDOLoX10 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSLDOAdd { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSLDOAdd { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSIEHi22 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSIEHi22 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSIELo10 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSIELo10 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSIELd { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::TLSIELd { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::TLSIELdX { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::TLSIELdX { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::TLSIEAdd { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::TLSIEAdd { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::TLSLEHiX22 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLEHiX22 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLELoX10 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLELoX10 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                SparcReloc::TLSDTPMod32 { offset: offset, sym: name },
+            SparcReloc::TLSDTPMod64 { sym: SymData { name, .. }, offset } =>
+                SparcReloc::TLSDTPMod64 { offset: offset, sym: name },
+            SparcReloc::TLSDTPOff32 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSDTPOff32 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSDTPOff64 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSDTPOff64 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSTPOff32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSTPOff32 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSTPOff64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSTPOff64 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::GOTDataHiX22 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                SparcReloc::GOTDataHiX22 { offset: offset, sym: name,
+                                           addend: addend },
+            SparcReloc::GOTDataLoX10 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                SparcReloc::GOTDataLoX10 { offset: offset, sym: name,
+                                           addend: addend },
+            SparcReloc::GOTDataOpHiX22 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                SparcReloc::GOTDataOpHiX22 { offset: offset, sym: name,
+                                             addend: addend },
+            SparcReloc::GOTDataOpLoX10 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                SparcReloc::GOTDataOpLoX10 { offset: offset, sym: name,
+                                             addend: addend },
+            SparcReloc::GOTDataOp { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::GOTDataOp { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::H34 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::H34 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Size32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Size32 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::Size64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Size64 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::WDisp10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp10 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::JmpIRelative { offset, addend } =>
+                SparcReloc::JmpIRelative { offset: offset, addend: addend },
+            SparcReloc::IRelative { offset, addend } =>
+                SparcReloc::IRelative { offset: offset, addend: addend },
+            SparcReloc::GNUVTInherit { sym: SymData { name, .. }, offset } =>
+                SparcReloc::GNUVTInherit { offset: offset, sym: name },
+            SparcReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::GNUVTEntry { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::Rev32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Rev32 { offset: offset, sym: name, addend: addend }
+        }
+    }
+}
+
+impl<'a> TryFrom<SparcRelocStrData<'a>> for SparcRelocStr<'a> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(reloc: SparcRelocStrData<'a>) ->
+        Result<SparcRelocStr<'a>, Self::Error> {
+        match reloc {
+            SparcReloc::None => Ok(SparcReloc::None),
+            SparcReloc::Abs8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs8 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::Abs8 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs8 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs8 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::Abs16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs16 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs16 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs16 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs16 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Abs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs32 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Disp8 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Disp8 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Disp8 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Disp8 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Disp8 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Disp16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Disp16 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::Disp16 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Disp16 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Disp16 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::Disp32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Disp32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::Disp32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Disp32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Disp32 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::WDisp30 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::WDisp30 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::WDisp30 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::WDisp30 { sym: None, offset, addend } =>
+                Ok(SparcReloc::WDisp30 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::WDisp22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::WDisp22 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::WDisp22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::WDisp22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::WDisp22 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::Hi22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Hi22 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::Hi22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Hi22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Hi22 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::Abs22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs22 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs22 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Abs13 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs13 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs13 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs13 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs13 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Lo10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Lo10 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::Lo10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Lo10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Lo10 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::GOT10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GOT10 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::GOT10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOT10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOT10 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::GOT13 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GOT13 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::GOT13 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOT13 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOT13 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::GOT22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GOT22 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::GOT22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOT22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOT22 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::PC10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PC10 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::PC10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PC10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PC10 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::PC22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PC22 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::PC22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PC22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PC22 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::WPLT30 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::WPLT30 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::WPLT30 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::WPLT30 { sym: None, offset, addend } =>
+                Ok(SparcReloc::WPLT30 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::Copy { sym: Some(Ok(name)), offset } =>
+                Ok(SparcReloc::Copy { offset: offset, sym: Some(name) }),
+            SparcReloc::Copy { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Copy { sym: None, offset } =>
+                Ok(SparcReloc::Copy { offset: offset, sym: None }),
+            SparcReloc::GlobalData { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GlobalData { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::GlobalData { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GlobalData { sym: None, offset, addend } =>
+                Ok(SparcReloc::GlobalData { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::JumpSlot { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::JumpSlot { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            SparcReloc::JumpSlot { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::JumpSlot { sym: None, offset, addend } =>
+                Ok(SparcReloc::JumpSlot { offset: offset, sym: None,
+                                          addend: addend }),
+            SparcReloc::Relative { offset, addend } =>
+                Ok(SparcReloc::Relative { offset: offset, addend: addend }),
+            SparcReloc::UAbs32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::UAbs32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::UAbs32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::UAbs32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::UAbs32 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::PLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PLT32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::PLT32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PLT32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PLT32 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::HiPLT22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::HiPLT22 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::HiPLT22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::HiPLT22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::HiPLT22 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::LoPLT10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::LoPLT10 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::LoPLT10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::LoPLT10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::LoPLT10 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::PCPLT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PCPLT32 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::PCPLT32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PCPLT32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PCPLT32 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::PCPLT22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PCPLT22 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::PCPLT22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PCPLT22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PCPLT22 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::PCPLT10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PCPLT10 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::PCPLT10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PCPLT10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PCPLT10 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::Abs10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs10 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs10 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Abs11 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs11 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs11 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs11 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs11 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::Abs64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Abs64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs64 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::OLO10 { sym: Some(Ok(name)), offset, addend,
+                                secondary } =>
+                Ok(SparcReloc::OLO10 { offset: offset, sym: Some(name),
+                                       addend: addend, secondary: secondary }),
+            SparcReloc::OLO10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::OLO10 { sym: None, offset, addend, secondary } =>
+                Ok(SparcReloc::OLO10 { offset: offset, sym: None,
+                                       addend: addend, secondary: secondary }),
+            SparcReloc::HH22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::HH22 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::HH22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::HH22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::HH22 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::HM10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::HM10 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::HM10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::HM10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::HM10 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::LM22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::LM22 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::LM22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::LM22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::LM22 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::PCHH22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PCHH22 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::PCHH22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PCHH22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PCHH22 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::PCHM10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PCHM10 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::PCHM10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PCHM10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PCHM10 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::PCLM22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PCLM22 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::PCLM22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PCLM22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PCLM22 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::WDisp16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::WDisp16 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::WDisp16 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::WDisp16 { sym: None, offset, addend } =>
+                Ok(SparcReloc::WDisp16 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::WDisp19 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::WDisp19 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::WDisp19 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::WDisp19 { sym: None, offset, addend } =>
+                Ok(SparcReloc::WDisp19 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::Abs7 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs7 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::Abs7 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs7 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs7 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::Abs5 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs5 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::Abs5 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs5 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs5 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::Abs6 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Abs6 { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            SparcReloc::Abs6 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Abs6 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Abs6 { offset: offset, sym: None,
+                                      addend: addend }),
+            SparcReloc::Disp64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Disp64 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::Disp64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Disp64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Disp64 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::PLT64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::PLT64 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::PLT64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::PLT64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::PLT64 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::HiX22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::HiX22 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::HiX22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::HiX22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::HiX22 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::LoX10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::LoX10 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::LoX10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::LoX10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::LoX10 { offset: offset, sym: None,
+                                       addend: addend }),
+            SparcReloc::H44 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::H44 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            SparcReloc::H44 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::H44 { sym: None, offset, addend } =>
+                Ok(SparcReloc::H44 { offset: offset, sym: None,
+                                     addend: addend }),
+            SparcReloc::M44 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::M44 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            SparcReloc::M44 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::M44 { sym: None, offset, addend } =>
+                Ok(SparcReloc::M44 { offset: offset, sym: None,
+                                     addend: addend }),
+            SparcReloc::L44 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::L44 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            SparcReloc::L44 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::L44 { sym: None, offset, addend } =>
+                Ok(SparcReloc::L44 { offset: offset, sym: None,
+                                     addend: addend }),
+            SparcReloc::Register { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Register { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            SparcReloc::Register { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Register { sym: None, offset, addend } =>
+                Ok(SparcReloc::Register { offset: offset, sym: None,
+                                          addend: addend }),
+            SparcReloc::UAbs64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::UAbs64 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::UAbs64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::UAbs64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::UAbs64 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::UAbs16 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::UAbs16 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::UAbs16 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::UAbs16 { sym: None, offset, addend } =>
+                Ok(SparcReloc::UAbs16 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::TLSGDHi22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSGDHi22 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSGDHi22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSGDHi22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSGDHi22 { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSGDLo10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSGDLo10 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSGDLo10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSGDLo10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSGDLo10 { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSGDAdd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSGDAdd { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            SparcReloc::TLSGDAdd { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSGDAdd { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSGDAdd { offset: offset, sym: None,
+                                          addend: addend }),
+            SparcReloc::TLSGDCall { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSGDCall { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSGDCall { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSGDCall { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSGDCall { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSLDMHi22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDMHi22 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSLDMHi22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDMHi22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDMHi22 { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::TLSLDMLo10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDMLo10 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSLDMLo10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDMLo10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDMLo10 { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::TLSLDMAdd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDMAdd { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSLDMAdd { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDMAdd { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDMAdd { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSLDMCall { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDMCall { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSLDMCall { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDMCall { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDMCall { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::TLSLDOHiX22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDOHiX22 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            SparcReloc::TLSLDOHiX22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDOHiX22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDOHiX22 { offset: offset, sym: None,
+                                             addend: addend }),
+            SparcReloc::TLSLDOLoX10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDOLoX10 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            SparcReloc::TLSLDOLoX10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDOLoX10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDOLoX10 { offset: offset, sym: None,
+                                             addend: addend }),
+            SparcReloc::TLSLDOAdd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLDOAdd { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSLDOAdd { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLDOAdd { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLDOAdd { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSIEHi22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSIEHi22 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSIEHi22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSIEHi22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSIEHi22 { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSIELo10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSIELo10 { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::TLSIELo10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSIELo10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSIELo10 { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::TLSIELd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSIELd { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::TLSIELd { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSIELd { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSIELd { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::TLSIELdX { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSIELdX { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            SparcReloc::TLSIELdX { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSIELdX { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSIELdX { offset: offset, sym: None,
+                                          addend: addend }),
+            SparcReloc::TLSIEAdd { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSIEAdd { offset: offset, sym: Some(name),
+                                          addend: addend }),
+            SparcReloc::TLSIEAdd { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSIEAdd { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSIEAdd { offset: offset, sym: None,
+                                          addend: addend }),
+            SparcReloc::TLSLEHiX22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLEHiX22 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSLEHiX22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLEHiX22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLEHiX22 { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::TLSLELoX10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSLELoX10 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSLELoX10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSLELoX10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSLELoX10 { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::TLSDTPMod32 { sym: Some(Ok(name)), offset } =>
+                Ok(SparcReloc::TLSDTPMod32 { offset: offset, sym: Some(name) }),
+            SparcReloc::TLSDTPMod32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSDTPMod32 { sym: None, offset } =>
+                Ok(SparcReloc::TLSDTPMod32 { offset: offset, sym: None }),
+            SparcReloc::TLSDTPMod64 { sym: Some(Ok(name)), offset } =>
+                Ok(SparcReloc::TLSDTPMod64 { offset: offset, sym: Some(name) }),
+            SparcReloc::TLSDTPMod64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSDTPMod64 { sym: None, offset } =>
+                Ok(SparcReloc::TLSDTPMod64 { offset: offset, sym: None }),
+            SparcReloc::TLSDTPOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSDTPOff32 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            SparcReloc::TLSDTPOff32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSDTPOff32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSDTPOff32 { offset: offset, sym: None,
+                                             addend: addend }),
+            SparcReloc::TLSDTPOff64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSDTPOff64 { offset: offset, sym: Some(name),
+                                             addend: addend }),
+            SparcReloc::TLSDTPOff64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSDTPOff64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSDTPOff64 { offset: offset, sym: None,
+                                             addend: addend }),
+            SparcReloc::TLSTPOff32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSTPOff32 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSTPOff32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSTPOff32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSTPOff32 { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::TLSTPOff64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::TLSTPOff64 { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::TLSTPOff64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::TLSTPOff64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::TLSTPOff64 { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::GOTDataHiX22 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GOTDataHiX22 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            SparcReloc::GOTDataHiX22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOTDataHiX22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOTDataHiX22 { offset: offset, sym: None,
+                                              addend: addend }),
+            SparcReloc::GOTDataLoX10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GOTDataLoX10 { offset: offset, sym: Some(name),
+                                              addend: addend }),
+            SparcReloc::GOTDataLoX10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOTDataLoX10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOTDataLoX10 { offset: offset, sym: None,
+                                              addend: addend }),
+            SparcReloc::GOTDataOpHiX22 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(SparcReloc::GOTDataOpHiX22 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            SparcReloc::GOTDataOpHiX22 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOTDataOpHiX22 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOTDataOpHiX22 { offset: offset, sym: None,
+                                                addend: addend }),
+            SparcReloc::GOTDataOpLoX10 { sym: Some(Ok(name)), offset,
+                                         addend } =>
+                Ok(SparcReloc::GOTDataOpLoX10 { offset: offset, sym: Some(name),
+                                                addend: addend }),
+            SparcReloc::GOTDataOpLoX10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOTDataOpLoX10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOTDataOpLoX10 { offset: offset, sym: None,
+                                                addend: addend }),
+            SparcReloc::GOTDataOp { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GOTDataOp { offset: offset, sym: Some(name),
+                                           addend: addend }),
+            SparcReloc::GOTDataOp { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GOTDataOp { sym: None, offset, addend } =>
+                Ok(SparcReloc::GOTDataOp { offset: offset, sym: None,
+                                           addend: addend }),
+            SparcReloc::H34 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::H34 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            SparcReloc::H34 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::H34 { sym: None, offset, addend } =>
+                Ok(SparcReloc::H34 { offset: offset, sym: None,
+                                     addend: addend }),
+            SparcReloc::Size32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Size32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::Size32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Size32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Size32 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::Size64 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Size64 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            SparcReloc::Size64 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Size64 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Size64 { offset: offset, sym: None,
+                                        addend: addend }),
+            SparcReloc::WDisp10 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::WDisp10 { offset: offset, sym: Some(name),
+                                         addend: addend }),
+            SparcReloc::WDisp10 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::WDisp10 { sym: None, offset, addend } =>
+                Ok(SparcReloc::WDisp10 { offset: offset, sym: None,
+                                         addend: addend }),
+            SparcReloc::JmpIRelative { offset, addend } =>
+                Ok(SparcReloc::JmpIRelative { offset: offset, addend: addend }),
+            SparcReloc::IRelative { offset, addend } =>
+                Ok(SparcReloc::IRelative { offset: offset, addend: addend }),
+            SparcReloc::GNUVTInherit { sym: Some(Ok(name)), offset } =>
+                Ok(SparcReloc::GNUVTInherit { offset: offset,
+                                              sym: Some(name) }),
+            SparcReloc::GNUVTInherit { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GNUVTInherit { sym: None, offset } =>
+                Ok(SparcReloc::GNUVTInherit { offset: offset, sym: None }),
+            SparcReloc::GNUVTEntry { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::GNUVTEntry { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            SparcReloc::GNUVTEntry { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::GNUVTEntry { sym: None, offset, addend } =>
+                Ok(SparcReloc::GNUVTEntry { offset: offset, sym: None,
+                                            addend: addend }),
+            SparcReloc::Rev32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(SparcReloc::Rev32 { offset: offset, sym: Some(name),
+                                       addend: addend }),
+            SparcReloc::Rev32 { sym: Some(Err(err)), .. } => Err(err),
+            SparcReloc::Rev32 { sym: None, offset, addend } =>
+                Ok(SparcReloc::Rev32 { offset: offset, sym: None,
+                                       addend: addend })
+        }
+    }
+}
+
+impl<'a, Class> From<SparcRelocStrSym<'a, Class>> for SparcRelocStr<'a>
+    where Class: SymOffsets {
+    #[inline]
+    fn from(reloc: SparcRelocStrSym<'a, Class>) -> SparcRelocStr<'a> {
+        match reloc {
+            SparcReloc::None => SparcReloc::None,
+            SparcReloc::Abs8 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs8 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs16 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs32 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Disp8 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp8 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Disp16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp16 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::Disp32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp32 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::WDisp30 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp30 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::WDisp22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp22 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::Hi22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Hi22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs13 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs13 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Lo10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Lo10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::GOT10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::GOT10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::GOT13 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::GOT13 { offset: offset, sym: name, addend: addend },
+            SparcReloc::GOT22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::GOT22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::PC10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PC10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::PC22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PC22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::WPLT30 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WPLT30 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::Copy { sym: SymData { name, .. }, offset } =>
+                SparcReloc::Copy { offset: offset, sym: name },
+            SparcReloc::GlobalData { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::GlobalData { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::JumpSlot { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::JumpSlot { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::Relative { offset, addend } =>
+                SparcReloc::Relative { offset: offset, addend: addend },
+            SparcReloc::UAbs32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::UAbs32 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PLT32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PLT32 { offset: offset, sym: name, addend: addend },
+            SparcReloc::HiPLT22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HiPLT22 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::LoPLT10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::LoPLT10 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::PCPLT32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCPLT32 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::PCPLT22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCPLT22 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::PCPLT10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCPLT10 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::Abs10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs11 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs11 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs64 { offset: offset, sym: name, addend: addend },
+            SparcReloc::OLO10 { sym: SymData { name, .. }, offset, addend,
+                                secondary } =>
+                SparcReloc::OLO10 { offset: offset, sym: name, addend: addend,
+                                    secondary: secondary },
+            SparcReloc::HH22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HH22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::HM10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HM10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::LM22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::LM22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::PCHH22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCHH22 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PCHM10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCHM10 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PCLM22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PCLM22 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::WDisp16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp16 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::WDisp19 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp19 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::Abs7 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs7 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs5 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs5 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Abs6 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Abs6 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Disp64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Disp64 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::PLT64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::PLT64 { offset: offset, sym: name, addend: addend },
+            SparcReloc::HiX22 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::HiX22 { offset: offset, sym: name, addend: addend },
+            SparcReloc::LoX10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::LoX10 { offset: offset, sym: name, addend: addend },
+            SparcReloc::H44 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::H44 { offset: offset, sym: name, addend: addend },
+            SparcReloc::M44 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::M44 { offset: offset, sym: name, addend: addend },
+            SparcReloc::L44 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::L44 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Register { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::Register { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::UAbs64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::UAbs64 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::UAbs16 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::UAbs16 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::TLSGDHi22 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSGDHi22 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSGDLo10 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSGDLo10 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSGDAdd { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::TLSGDAdd { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::TLSGDCall { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSGDCall { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSLDMHi22 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLDMHi22 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLDMLo10 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLDMLo10 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLDMAdd { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSLDMAdd { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSLDMCall { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLDMCall { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLDOHiX22 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSLDOHiX22 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSLDOLoX10 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSLDOLoX10 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSLDOAdd { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSLDOAdd { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSIEHi22 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSIEHi22 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSIELo10 { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::TLSIELo10 { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::TLSIELd { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::TLSIELd { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::TLSIELdX { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::TLSIELdX { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::TLSIEAdd { sym: SymData { name, .. }, offset,
+                                   addend } =>
+                SparcReloc::TLSIEAdd { offset: offset, sym: name,
+                                       addend: addend },
+            SparcReloc::TLSLEHiX22 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLEHiX22 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSLELoX10 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSLELoX10 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSDTPMod32 { sym: SymData { name, .. }, offset } =>
+                SparcReloc::TLSDTPMod32 { offset: offset, sym: name },
+            SparcReloc::TLSDTPMod64 { sym: SymData { name, .. }, offset } =>
+                SparcReloc::TLSDTPMod64 { offset: offset, sym: name },
+            SparcReloc::TLSDTPOff32 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSDTPOff32 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSDTPOff64 { sym: SymData { name, .. }, offset,
+                                      addend } =>
+                SparcReloc::TLSDTPOff64 { offset: offset, sym: name,
+                                          addend: addend },
+            SparcReloc::TLSTPOff32 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSTPOff32 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::TLSTPOff64 { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::TLSTPOff64 { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::GOTDataHiX22 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                SparcReloc::GOTDataHiX22 { offset: offset, sym: name,
+                                           addend: addend },
+            SparcReloc::GOTDataLoX10 { sym: SymData { name, .. }, offset,
+                                       addend } =>
+                SparcReloc::GOTDataLoX10 { offset: offset, sym: name,
+                                           addend: addend },
+            SparcReloc::GOTDataOpHiX22 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                SparcReloc::GOTDataOpHiX22 { offset: offset, sym: name,
+                                             addend: addend },
+            SparcReloc::GOTDataOpLoX10 { sym: SymData { name, .. }, offset,
+                                         addend } =>
+                SparcReloc::GOTDataOpLoX10 { offset: offset, sym: name,
+                                             addend: addend },
+            SparcReloc::GOTDataOp { sym: SymData { name, .. }, offset,
+                                    addend } =>
+                SparcReloc::GOTDataOp { offset: offset, sym: name,
+                                        addend: addend },
+            SparcReloc::H34 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::H34 { offset: offset, sym: name, addend: addend },
+            SparcReloc::Size32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Size32 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::Size64 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Size64 { offset: offset, sym: name,
+                                     addend: addend },
+            SparcReloc::WDisp10 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::WDisp10 { offset: offset, sym: name,
+                                      addend: addend },
+            SparcReloc::JmpIRelative { offset, addend } =>
+                SparcReloc::JmpIRelative { offset: offset, addend: addend },
+            SparcReloc::IRelative { offset, addend } =>
+                SparcReloc::IRelative { offset: offset, addend: addend },
+            SparcReloc::GNUVTInherit { sym: SymData { name, .. }, offset } =>
+                SparcReloc::GNUVTInherit { offset: offset, sym: name },
+            SparcReloc::GNUVTEntry { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                SparcReloc::GNUVTEntry { offset: offset, sym: name,
+                                         addend: addend },
+            SparcReloc::Rev32 { sym: SymData { name, .. }, offset, addend } =>
+                SparcReloc::Rev32 { offset: offset, sym: name, addend: addend }
+        }
+    }
+}
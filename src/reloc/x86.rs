@@ -111,21 +111,23 @@ pub enum X86Reloc<Name> {
     },
     /// 32-bit Global Offset Table index.
     ///
-    /// Set the 4-byte word at `offset` to the sum of the address of
-    /// the Global Offset Table and `addend`.
+    /// Set the 4-byte word at `offset` to the sum of the offset of
+    /// the symbol's Global Offset Table entry and `addend`.
     GOT32 {
         /// Offset in the section.
         offset: u32,
+        /// Symbol reference.
+        sym: Name,
         /// The addend argument.
         addend: i32
     },
     /// Procedure Linkage Table index.
     ///
     /// Set the 4-byte word at `offset` to the relative address of the
-    /// sum of the address of the Procedure Linkage Table and `addend`
-    /// (computed by subtracting the offset or address of the target
-    /// word from the sum of the address of the Procedure Linkage
-    /// Table and `addend`).
+    /// sum of the address of the symbol's Procedure Linkage Table
+    /// entry and `addend` (computed by subtracting the offset or
+    /// address of the target word from the sum of the address of the
+    /// symbol's Procedure Linkage Table entry and `addend`).
     PLTRel {
         /// Offset in the section.
         offset: u32,
@@ -197,10 +199,12 @@ pub enum X86Reloc<Name> {
     /// 32-bit absolute offset to a Procedure Linkage Table entry.
     ///
     /// Set the 4-byte word at `offset` to the sum of the address of
-    /// the Procedure Linkage Table and `addend`.
+    /// the symbol's Procedure Linkage Table entry and `addend`.
     PLTAbs {
         /// Offset in the section.
         offset: u32,
+        /// Symbol reference.
+        sym: Name,
         /// The addend argument.
         addend: i32
     },
@@ -299,12 +303,13 @@ impl<Name> Display for X86Reloc<Name>
             X86Reloc::PC8 { offset, sym, addend } =>
                 write!(f, ".section[{}] <- (&{} + {}) - (&.section + {})",
                        offset, sym, addend, offset),
-            X86Reloc::GOT32 { offset, addend } =>
-                write!(f, ".section[{}..{}] <- &.got + {}",
-                       offset, offset + 4, addend),
-            X86Reloc::PLTRel { offset, addend, .. } =>
-                write!(f, ".section[{}..{}] <- (&.plt + {}) - (&.section + {})",
-                       offset, offset + 4, addend, offset),
+            X86Reloc::GOT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[{}] - &.got + {}",
+                       offset, offset + 4, sym, addend),
+            X86Reloc::PLTRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
             X86Reloc::Copy { sym } => write!(f, "copy {}", sym),
             X86Reloc::GlobalData { offset, sym } =>
                 write!(f, ".got[{}..{}] <- &{}", offset, offset + 4, sym),
@@ -319,9 +324,9 @@ impl<Name> Display for X86Reloc<Name>
             X86Reloc::GOTPC { offset, addend, .. } =>
                 write!(f, ".section[{}..{}] <- (&.got + {}) - (&.section + {})",
                        offset, offset + 4, addend, offset),
-            X86Reloc::PLTAbs { offset, addend } =>
-                write!(f, ".section[{}..{}] <- &.plt + {}",
-                       offset, offset + 4, addend),
+            X86Reloc::PLTAbs { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.plt[{}] + {}",
+                       offset, offset + 4, sym, addend),
             X86Reloc::Size { offset, sym, addend } =>
                 write!(f, ".section[{}..{}] <- sizeof({}) + {}",
                        offset, offset + 4, sym, addend),
@@ -352,7 +357,7 @@ fn convert_to<Name>(offset: u32, sym: Name, kind: u8, addend: i32) ->
         0 => Ok(X86Reloc::None),
         1 => Ok(X86Reloc::Abs32 { offset: offset, sym: sym, addend: addend }),
         2 => Ok(X86Reloc::PC32 { offset: offset, sym: sym, addend: addend }),
-        3 => Ok(X86Reloc::GOT32 { offset: offset, addend: addend }),
+        3 => Ok(X86Reloc::GOT32 { offset: offset, sym: sym, addend: addend }),
         4 => Ok(X86Reloc::PLTRel { offset: offset, sym: sym, addend: addend }),
         5 => Ok(X86Reloc::Copy { sym: sym }),
         6 => Ok(X86Reloc::GlobalData { offset: offset, sym: sym }),
@@ -360,7 +365,7 @@ fn convert_to<Name>(offset: u32, sym: Name, kind: u8, addend: i32) ->
         8 => Ok(X86Reloc::Relative { offset: offset, addend: addend }),
         9 => Ok(X86Reloc::GOTRel { offset: offset, sym: sym, addend: addend }),
         10 => Ok(X86Reloc::GOTPC { offset: offset, sym: sym, addend: addend }),
-        11 => Ok(X86Reloc::PLTAbs { offset: offset, addend: addend }),
+        11 => Ok(X86Reloc::PLTAbs { offset: offset, sym: sym, addend: addend }),
         20 => Ok(X86Reloc::Abs16 { offset: offset, sym: sym, addend: addend }),
         21 => Ok(X86Reloc::PC16 { offset: offset, sym: sym, addend: addend }),
         22 => Ok(X86Reloc::Abs8 { offset: offset, sym: sym, addend: addend }),
@@ -398,8 +403,8 @@ impl TryFrom<X86Reloc<u32>> for RelData<u32, Elf32> {
                 Ok(RelData { offset: offset, sym: sym, kind: 2 }),
             X86Reloc::PC32 { addend, .. } =>
                 Err(X86ToRelError::BadAddend(addend)),
-            X86Reloc::GOT32 { offset, addend: 0 } =>
-                Ok(RelData { offset: offset, sym: 0, kind: 3 }),
+            X86Reloc::GOT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
             X86Reloc::GOT32 { addend, .. } =>
                 Err(X86ToRelError::BadAddend(addend)),
             X86Reloc::PLTRel { offset, sym, addend: 0 } =>
@@ -424,8 +429,8 @@ impl TryFrom<X86Reloc<u32>> for RelData<u32, Elf32> {
                 Ok(RelData { offset: offset, sym: sym, kind: 10 }),
             X86Reloc::GOTPC { addend, .. } =>
                 Err(X86ToRelError::BadAddend(addend)),
-            X86Reloc::PLTAbs { offset, addend: 0 } =>
-                Ok(RelData { offset: offset, sym: 0, kind: 11 }),
+            X86Reloc::PLTAbs { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 11 }),
             X86Reloc::PLTAbs { addend, .. } =>
                 Err(X86ToRelError::BadAddend(addend)),
             X86Reloc::Abs16 { offset, sym, addend: 0 } =>
@@ -474,8 +479,8 @@ impl From<X86Reloc<u32>> for RelaData<u32, Elf32> {
                 RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
             X86Reloc::PC32 { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
-            X86Reloc::GOT32 { offset, addend } =>
-                RelaData { offset: offset, sym: 0, kind: 3, addend: addend },
+            X86Reloc::GOT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 3, addend: addend },
             X86Reloc::PLTRel { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 4, addend: addend },
             X86Reloc::Copy { sym } =>
@@ -490,8 +495,8 @@ impl From<X86Reloc<u32>> for RelaData<u32, Elf32> {
                 RelaData { offset: offset, sym: sym, kind: 9, addend: addend },
             X86Reloc::GOTPC { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 10, addend: addend },
-            X86Reloc::PLTAbs { offset, addend } =>
-                RelaData { offset: offset, sym: 0, kind: 11, addend: addend },
+            X86Reloc::PLTAbs { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 11, addend: addend },
             X86Reloc::Abs16 { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 20, addend: addend },
             X86Reloc::PC16 { offset, sym, addend } =>
@@ -537,8 +542,17 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf32> for X86RelocRaw {
                     },
                     None => Err(RelocSymtabError::BadIdx(sym))
                 },
-            X86Reloc::GOT32 { offset, addend } =>
-                Ok(X86Reloc::GOT32 { offset: offset, addend: addend }),
+            X86Reloc::GOT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(X86Reloc::GOT32 { offset: offset, sym: symdata,
+                                                 addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
             X86Reloc::PLTRel { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
                     Some(sym) => match sym.try_into() {
@@ -605,8 +619,17 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf32> for X86RelocRaw {
                     },
                     None => Err(RelocSymtabError::BadIdx(sym))
                 },
-            X86Reloc::PLTAbs { offset, addend } =>
-                Ok(X86Reloc::PLTAbs { offset: offset, addend: addend }),
+            X86Reloc::PLTAbs { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(X86Reloc::PLTAbs { offset: offset, sym: symdata,
+                                                  addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym))
+                },
             X86Reloc::Abs16 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
                     Some(sym) => match sym.try_into() {
@@ -691,8 +714,14 @@ impl<'a> WithStrtab<'a> for X86RelocRawSym {
                     },
                     Err(err) => Err(err)
                 },
-            X86Reloc::GOT32 { offset, addend } =>
-                Ok(X86Reloc::GOT32 { offset: offset, addend: addend }),
+            X86Reloc::GOT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(X86Reloc::GOT32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
             X86Reloc::PLTRel { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
@@ -740,8 +769,14 @@ impl<'a> WithStrtab<'a> for X86RelocRawSym {
                     },
                     Err(err) => Err(err)
                 },
-            X86Reloc::PLTAbs { offset, addend } =>
-                Ok(X86Reloc::PLTAbs { offset: offset, addend: addend }),
+            X86Reloc::PLTAbs { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(X86Reloc::PLTAbs { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
             X86Reloc::Abs16 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
@@ -810,8 +845,14 @@ impl<'a> TryFrom<X86RelocStrDataSym<'a>> for X86RelocStrSym<'a> {
                     },
                     Err(err) => Err(err)
                 },
-            X86Reloc::GOT32 { offset, addend } =>
-                Ok(X86Reloc::GOT32 { offset: offset, addend: addend }),
+            X86Reloc::GOT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(X86Reloc::GOT32 { offset: offset, sym: symdata,
+                                             addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
             X86Reloc::PLTRel { offset, sym, addend } =>
                 match sym.try_into() {
                     Ok(symdata) => {
@@ -859,8 +900,14 @@ impl<'a> TryFrom<X86RelocStrDataSym<'a>> for X86RelocStrSym<'a> {
                     },
                     Err(err) => Err(err)
                 },
-            X86Reloc::PLTAbs { offset, addend } =>
-                Ok(X86Reloc::PLTAbs { offset: offset, addend: addend }),
+            X86Reloc::PLTAbs { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(X86Reloc::PLTAbs { offset: offset, sym: symdata,
+                                              addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
             X86Reloc::Abs16 { offset, sym, addend } =>
                 match sym.try_into() {
                     Ok(symdata) => {
@@ -914,8 +961,8 @@ impl<'a> From<X86RelocStrDataSym<'a>> for X86RelocStrData<'a> {
                 X86Reloc::Abs32 { offset: offset, sym: name, addend: addend },
             X86Reloc::PC32 { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::PC32 { offset: offset, sym: name, addend: addend },
-            X86Reloc::GOT32 { offset, addend } =>
-                X86Reloc::GOT32 { offset: offset, addend: addend },
+            X86Reloc::GOT32 { sym: SymData { name, .. }, offset, addend } =>
+                X86Reloc::GOT32 { offset: offset, sym: name, addend: addend },
             X86Reloc::PLTRel { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::PLTRel { offset: offset, sym: name, addend: addend },
             X86Reloc::Copy { sym: SymData { name, .. } } =>
@@ -930,8 +977,8 @@ impl<'a> From<X86RelocStrDataSym<'a>> for X86RelocStrData<'a> {
                 X86Reloc::GOTRel { offset: offset, sym: name, addend: addend },
             X86Reloc::GOTPC { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::GOTPC { offset: offset, sym: name, addend: addend },
-            X86Reloc::PLTAbs { offset, addend } =>
-                X86Reloc::PLTAbs { offset: offset, addend: addend },
+            X86Reloc::PLTAbs { sym: SymData { name, .. }, offset, addend } =>
+                X86Reloc::PLTAbs { offset: offset, sym: name, addend: addend },
             X86Reloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::Abs16 { offset: offset, sym: name, addend: addend },
             X86Reloc::PC16 { sym: SymData { name, .. }, offset, addend } =>
@@ -968,8 +1015,13 @@ impl<'a> TryFrom<X86RelocStrData<'a>> for X86RelocStr<'a> {
             X86Reloc::PC32 { sym: None, offset, addend } =>
                 Ok(X86Reloc::PC32 { offset: offset, sym: None,
                                     addend: addend }),
-            X86Reloc::GOT32 { offset, addend } =>
-                Ok(X86Reloc::GOT32 { offset: offset, addend: addend }),
+            X86Reloc::GOT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(X86Reloc::GOT32 { offset: offset, sym: Some(name),
+                                     addend: addend }),
+            X86Reloc::GOT32 { sym: Some(Err(err)), .. } => Err(err),
+            X86Reloc::GOT32 { sym: None, offset, addend } =>
+                Ok(X86Reloc::GOT32 { offset: offset, sym: None,
+                                     addend: addend }),
             X86Reloc::PLTRel { sym: Some(Ok(name)), offset, addend } =>
                 Ok(X86Reloc::PLTRel { offset: offset, sym: Some(name),
                                       addend: addend }),
@@ -1007,8 +1059,13 @@ impl<'a> TryFrom<X86RelocStrData<'a>> for X86RelocStr<'a> {
             X86Reloc::GOTPC { sym: None, offset, addend } =>
                 Ok(X86Reloc::GOTPC { offset: offset, sym: None,
                                      addend: addend }),
-            X86Reloc::PLTAbs { offset, addend } =>
-                Ok(X86Reloc::PLTAbs { offset: offset, addend: addend }),
+            X86Reloc::PLTAbs { sym: Some(Ok(name)), offset, addend } =>
+                Ok(X86Reloc::PLTAbs { offset: offset, sym: Some(name),
+                                      addend: addend }),
+            X86Reloc::PLTAbs { sym: Some(Err(err)), .. } => Err(err),
+            X86Reloc::PLTAbs { sym: None, offset, addend } =>
+                Ok(X86Reloc::PLTAbs { offset: offset, sym: None,
+                                      addend: addend }),
             X86Reloc::Abs16 { sym: Some(Ok(name)), offset, addend } =>
                 Ok(X86Reloc::Abs16 { offset: offset, sym: Some(name),
                                      addend: addend }),
@@ -1057,8 +1114,8 @@ impl<'a> From<X86RelocStrSym<'a>> for X86RelocStr<'a> {
                 X86Reloc::Abs32 { offset: offset, sym: name, addend: addend },
             X86Reloc::PC32 { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::PC32 { offset: offset, sym: name, addend: addend },
-            X86Reloc::GOT32 { offset, addend } =>
-                X86Reloc::GOT32 { offset: offset, addend: addend },
+            X86Reloc::GOT32 { sym: SymData { name, .. }, offset, addend } =>
+                X86Reloc::GOT32 { offset: offset, sym: name, addend: addend },
             X86Reloc::PLTRel { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::PLTRel { offset: offset, sym: name, addend: addend },
             X86Reloc::Copy { sym: SymData { name, .. } } =>
@@ -1073,8 +1130,8 @@ impl<'a> From<X86RelocStrSym<'a>> for X86RelocStr<'a> {
                 X86Reloc::GOTRel { offset: offset, sym: name, addend: addend },
             X86Reloc::GOTPC { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::GOTPC { offset: offset, sym: name, addend: addend },
-            X86Reloc::PLTAbs { offset, addend } =>
-                X86Reloc::PLTAbs { offset: offset, addend: addend },
+            X86Reloc::PLTAbs { sym: SymData { name, .. }, offset, addend } =>
+                X86Reloc::PLTAbs { offset: offset, sym: name, addend: addend },
             X86Reloc::Abs16 { sym: SymData { name, .. }, offset, addend } =>
                 X86Reloc::Abs16 { offset: offset, sym: name, addend: addend },
             X86Reloc::PC16 { sym: SymData { name, .. }, offset, addend } =>
@@ -7,11 +7,43 @@
 //! [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
 //! the [ElfClass](crate::ElfClass) type argument using the
 //! [TryFrom](core::convert::TryFrom) instances for easier handling.
+//!
+//! # x32
+//!
+//! The x32 ABI runs x86-64 code with 32-bit pointers, and uses
+//! [Elf32](crate::Elf32) containers with the x86-64 architecture and
+//! relocation types.  The same conversions are therefore also
+//! provided with [Elf32](crate::Elf32) as the class type argument,
+//! with the following differences:
+//!
+//! * Offsets and addends must fit in 32 bits when converting back to
+//!   a [RelData](crate::reloc::RelData) or
+//!   [RelaData](crate::reloc::RelaData).
+//! * The large-model relocations [PC64](X86_64Reloc::PC64) and
+//!   [GOTRel](X86_64Reloc::GOTRel) are not supported in x32 mode, and
+//!   are rejected in both directions.
+//! * [Abs64](X86_64Reloc::Abs64) remains legal, and always relocates
+//!   a full 8-byte field.  Pointer-sized dynamic relocations such as
+//!   [GlobalData](X86_64Reloc::GlobalData),
+//!   [JumpSlot](X86_64Reloc::JumpSlot), and
+//!   [Relative](X86_64Reloc::Relative), on the other hand, relocate a
+//!   4-byte field in x32 mode; the 8-byte equivalent of
+//!   [Relative](X86_64Reloc::Relative) is
+//!   [Relative64](X86_64Reloc::Relative64).  The same holds for the
+//!   thread-local storage relocations [DTPMod](X86_64Reloc::DTPMod),
+//!   [DTPOff](X86_64Reloc::DTPOff), and [TPOff](X86_64Reloc::TPOff).
+//!   Wrapping a relocation in an [X32Reloc] gives it the `Display`
+//!   and [Apply](crate::reloc::apply::Apply) instances for x32 mode.
+//! * Global Offset Table entries are 4 bytes in x32 mode, which
+//!   affects the instructions that a linker may rewrite when relaxing
+//!   [GOTPCRelX](X86_64Reloc::GOTPCRelX) and
+//!   [RexGOTPCRelX](X86_64Reloc::RexGOTPCRelX).
 use byteorder::LittleEndian;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use core::fmt::Display;
 use core::fmt::Formatter;
+use crate::elf::Elf32;
 use crate::elf::Elf64;
 use crate::reloc::RelData;
 use crate::reloc::RelaData;
@@ -23,6 +55,7 @@ use crate::symtab::SymData;
 use crate::symtab::SymDataRaw;
 use crate::symtab::SymDataStr;
 use crate::symtab::SymDataStrData;
+use crate::symtab::SymOffsets;
 use crate::symtab::WithSymtab;
 
 /// Relocation entries for 64-bit x86 architectures (aka. AA-64, x86-64).
@@ -30,8 +63,9 @@ use crate::symtab::WithSymtab;
 /// This datatype provides a semantic-level presentation of the x86
 /// relocation entries.  These can be converted to and from
 /// [RelData](crate::reloc::RelData) or
-/// [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64) as
-/// the [ElfClass](crate::ElfClass) type argument using the
+/// [RelaData](crate::reloc::RelaData) with [Elf64](crate::Elf64), or
+/// [Elf32](crate::Elf32) for the x32 ABI, as the
+/// [ElfClass](crate::ElfClass) type argument using the
 /// [TryFrom](core::convert::TryFrom) instances for easier handling.
 #[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
 pub enum X86_64Reloc<Name> {
@@ -62,21 +96,23 @@ pub enum X86_64Reloc<Name> {
     },
     /// 32-bit Global Offset Table index.
     ///
-    /// Set the 4-byte word at `offset` to the sum of the address of
-    /// the Global Offset Table and `addend`.
+    /// Set the 4-byte word at `offset` to the sum of the offset of
+    /// the symbol's Global Offset Table entry and `addend`.
     GOT32 {
         /// Offset in the section.
         offset: u64,
+        /// Symbol reference.
+        sym: Name,
         /// The addend argument.
         addend: i64
     },
     /// Procedure Linkage Table index.
     ///
     /// Set the 4-byte word at `offset` to the relative address of the
-    /// sum of the address of the Procedure Linkage Table and `addend`
-    /// (computed by subtracting the offset or address of the target
-    /// word from the sum of the address of the Procedure Linkage
-    /// Table and `addend`).
+    /// sum of the address of the symbol's Procedure Linkage Table
+    /// entry and `addend` (computed by subtracting the offset or
+    /// address of the target word from the sum of the address of the
+    /// symbol's Procedure Linkage Table entry and `addend`).
     PLTRel {
         /// Offset in the section.
         offset: u64,
@@ -120,11 +156,11 @@ pub enum X86_64Reloc<Name> {
         /// The addend argument.
         addend: i64
     },
-    /// 64-bit PC-relative offset to a Global Offset Table entry.
+    /// 32-bit PC-relative offset to a Global Offset Table entry.
     ///
-    /// Set the 8-byte word at `offset` relative address of Global
-    /// Offset Table address added to `addend` from the address of the
-    /// word at `offset`.
+    /// Set the 4-byte word at `offset` to the relative address of the
+    /// symbol's Global Offset Table entry added to `addend` from the
+    /// address of the word at `offset`.
     GOTPC {
         /// Offset in the section.
         offset: u64,
@@ -296,6 +332,45 @@ pub enum X86_64Reloc<Name> {
         sym: Name,
         /// The addend argument.
         addend: i64
+    },
+    /// 64-bit offset relative to the image base, for x32.
+    ///
+    /// Set the 8-byte word at `offset` to the sum of the base address
+    /// and `addend`.  This is only used by the x32 ABI, where
+    /// [Relative](X86_64Reloc::Relative) relocates a 4-byte word.
+    Relative64 {
+        /// Offset in the section.
+        offset: u64,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Relaxable 32-bit PC-relative offset to a Global Offset Table
+    /// entry.
+    ///
+    /// This has the same effect as [GOTPC](X86_64Reloc::GOTPC), but
+    /// marks an instruction without a REX prefix that the linker may
+    /// rewrite to avoid the Global Offset Table entry.
+    GOTPCRelX {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
+    },
+    /// Relaxable 32-bit PC-relative offset to a Global Offset Table
+    /// entry, with a REX prefix.
+    ///
+    /// This has the same effect as [GOTPC](X86_64Reloc::GOTPC), but
+    /// marks an instruction with a REX prefix that the linker may
+    /// rewrite to avoid the Global Offset Table entry.
+    RexGOTPCRelX {
+        /// Offset in the section.
+        offset: u64,
+        /// Symbol reference.
+        sym: Name,
+        /// The addend argument.
+        addend: i64
     }
 }
 
@@ -310,14 +385,15 @@ pub type X86_64RelocRaw = X86_64Reloc<u32>;
 ///
 /// This is obtained directly from the [WithSymtab] instance acting on a
 /// [X86_64RelocRaw].
-pub type X86_64RelocRawSym = X86_64Reloc<SymDataRaw<Elf64>>;
+pub type X86_64RelocRawSym<Class = Elf64> = X86_64Reloc<SymDataRaw<Class>>;
 
 /// Type synonym for [X86_64Reloc] with [SymDataStrData] as the symbol type.
 ///
 /// This is obtained directly from the
 /// [WithStrtab](crate::strtab::WithStrtab) instance acting on a
 /// [X86_64RelocRawSym].
-pub type X86_64RelocStrDataSym<'a> = X86_64Reloc<SymDataStrData<'a, Elf64>>;
+pub type X86_64RelocStrDataSym<'a, Class = Elf64> =
+    X86_64Reloc<SymDataStrData<'a, Class>>;
 
 /// Type synonym for [X86_64Reloc] with [SymDataStr] as the symbol
 /// type.
@@ -332,7 +408,8 @@ pub type X86_64RelocStrData<'a> =
 ///
 /// This is obtained directly from the [TryFrom] instance acting on
 /// a [X86_64RelocStrDataSym].
-pub type X86_64RelocStrSym<'a> = X86_64Reloc<SymDataStr<'a, Elf64>>;
+pub type X86_64RelocStrSym<'a, Class = Elf64> =
+    X86_64Reloc<SymDataStr<'a, Class>>;
 
 /// Type synonym for [X86_64Reloc] with a `&'a str`s as the symbol type.
 ///
@@ -340,24 +417,41 @@ pub type X86_64RelocStrSym<'a> = X86_64Reloc<SymDataStr<'a, Elf64>>;
 /// a [X86_64RelocStrSym].
 pub type X86_64RelocStr<'a> = X86_64Reloc<Option<&'a str>>;
 
+/// An [X86_64Reloc] from an x32 object.
+///
+/// This provides the `Display` and
+/// [Apply](crate::reloc::apply::Apply) instances for the x32 ABI, in
+/// which pointer-sized relocations relocate a 4-byte field.
+#[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct X32Reloc<Name>(pub X86_64Reloc<Name>);
+
 /// Errors that can occur converting an [X86_64Reloc] to a
 /// [RelData](crate::reloc::RelData).
 ///
-/// At present, this can only happen with a non-zero addend.
+/// This can happen with a non-zero addend, or when converting to an
+/// x32 relocation with an offset or addend that does not fit or a
+/// relocation type that is not supported in x32 mode.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum X86_64ToRelError {
-    /// Non-zero addend.
-    BadAddend(i64)
+    /// Non-zero or out-of-range addend.
+    BadAddend(i64),
+    /// Out-of-range offset.
+    BadOffset(u64),
+    /// Relocation type not supported in x32 mode.
+    BadX32Tag(u32)
 }
 
 /// Errors that can occur converting a [RelData](crate::reloc::RelData) or
 /// [RelaData](crate::reloc::RelaData) to a [X86_64Reloc].
 ///
-/// At present, this can only happen with a bad tag value.
+/// This can happen with a bad tag value, or with a tag value that is
+/// not supported in x32 mode when converting from an x32 relocation.
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum X86_64RelocError {
     /// Unknown tag value.
-    BadTag(u32)
+    BadTag(u32),
+    /// Tag value not supported in x32 mode.
+    BadX32Tag(u32)
 }
 
 impl<Name> Display for X86_64Reloc<Name>
@@ -367,16 +461,17 @@ impl<Name> Display for X86_64Reloc<Name>
             X86_64Reloc::None => write!(f, "none"),
             X86_64Reloc::Abs64 { offset, sym, addend } =>
                 write!(f, ".section[{}..{}] <- &{} + {}",
-                       offset, offset + 4, sym, addend),
+                       offset, offset + 8, sym, addend),
             X86_64Reloc::PC32 { offset, sym, addend } =>
                 write!(f, ".section[{}..{}] <- (&{} + {}) - (&.section + {})",
                        offset, offset + 4, sym, addend, offset),
-            X86_64Reloc::GOT32 { offset, addend } =>
-                write!(f, ".section[{}..{}] <- &.got + {}",
-                       offset, offset + 4, addend),
-            X86_64Reloc::PLTRel { offset, addend, .. } =>
-                write!(f, ".section[{}..{}] <- (&.plt + {}) - (&.section + {})",
-                       offset, offset + 4, addend, offset),
+            X86_64Reloc::GOT32 { offset, sym, addend } =>
+                write!(f, ".section[{}..{}] <- &.got[{}] - &.got + {}",
+                       offset, offset + 4, sym, addend),
+            X86_64Reloc::PLTRel { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.plt[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
             X86_64Reloc::Copy { sym } => write!(f, "copy {}", sym),
             X86_64Reloc::GlobalData { offset, sym } =>
                 write!(f, ".got[{}..{}] <- &{}", offset, offset + 8, sym),
@@ -385,9 +480,10 @@ impl<Name> Display for X86_64Reloc<Name>
             X86_64Reloc::Relative { offset, addend } =>
                 write!(f, ".section[{}..{}] <- &base + {}",
                        offset, offset + 8, addend),
-            X86_64Reloc::GOTPC { offset, addend, .. } =>
-                write!(f, ".section[{}..{}] <- (&.got + {}) - (&.section + {})",
-                       offset, offset + 4, addend, offset),
+            X86_64Reloc::GOTPC { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
             X86_64Reloc::Abs32 { offset, sym, addend } =>
                 write!(f, ".section[{}..{}] <- &{} + {}",
                        offset, offset + 4, sym, addend),
@@ -453,6 +549,45 @@ impl<Name> Display for X86_64Reloc<Name>
             X86_64Reloc::Size { offset, sym, addend } =>
                 write!(f, ".section[{}..{}] <- sizeof({}) + {}",
                        offset, offset + 8, sym, addend),
+            X86_64Reloc::Relative64 { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 8, addend),
+            X86_64Reloc::GOTPCRelX { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset),
+            X86_64Reloc::RexGOTPCRelX { offset, sym, addend } =>
+                write!(f, concat!(".section[{}..{}] <- (&.got[{}] + {}) - ",
+                                  "(&.section + {})"),
+                       offset, offset + 4, sym, addend, offset)
+        }
+    }
+}
+
+impl<Name> Display for X32Reloc<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match &self.0 {
+            X86_64Reloc::GlobalData { offset, sym } =>
+                write!(f, ".got[{}..{}] <- &{}", offset, offset + 4, sym),
+            X86_64Reloc::JumpSlot { offset, sym } =>
+                write!(f, ".plt[{}..{}] <- &{}", offset, offset + 4, sym),
+            X86_64Reloc::Relative { offset, addend } =>
+                write!(f, ".section[{}..{}] <- &base + {}",
+                       offset, offset + 4, addend),
+            X86_64Reloc::DTPMod { offset, sym } =>
+                write!(f, concat!(".section[{}..{}] <- general dynamic ",
+                                  "thread-local module for {}"),
+                       offset, offset + 4, sym),
+            X86_64Reloc::DTPOff { offset, sym } =>
+                write!(f, concat!(".section[{}..{}] <- general dynamic ",
+                                  "thread-local offset for {}"),
+                       offset, offset + 4, sym),
+            X86_64Reloc::TPOff { offset, sym } =>
+                write!(f, concat!(".section[{}..{}] <- initial execution ",
+                                  "thread-local offset for {}"),
+                       offset, offset + 4, sym),
+            reloc => reloc.fmt(f)
         }
     }
 }
@@ -460,7 +595,9 @@ impl<Name> Display for X86_64Reloc<Name>
 impl Display for X86_64RelocError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
-            X86_64RelocError::BadTag(tag) => write!(f, "bad tag value {}", tag)
+            X86_64RelocError::BadTag(tag) => write!(f, "bad tag value {}", tag),
+            X86_64RelocError::BadX32Tag(tag) =>
+                write!(f, "tag value {} not supported in x32 mode", tag)
         }
     }
 }
@@ -469,7 +606,11 @@ impl Display for X86_64ToRelError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             X86_64ToRelError::BadAddend(addend) =>
-                write!(f, "non-zero addend value {}", addend)
+                write!(f, "bad addend value {}", addend),
+            X86_64ToRelError::BadOffset(offset) =>
+                write!(f, "bad offset value {}", offset),
+            X86_64ToRelError::BadX32Tag(tag) =>
+                write!(f, "tag value {} not supported in x32 mode", tag)
         }
     }
 }
@@ -480,7 +621,7 @@ fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
         0 => Ok(X86_64Reloc::None),
         1 => Ok(X86_64Reloc::Abs64 { offset, sym, addend }),
         2 => Ok(X86_64Reloc::PC32 { offset, sym, addend }),
-        3 => Ok(X86_64Reloc::GOT32 { offset, addend }),
+        3 => Ok(X86_64Reloc::GOT32 { offset, sym, addend }),
         4 => Ok(X86_64Reloc::PLTRel { offset, sym, addend }),
         5 => Ok(X86_64Reloc::Copy { sym }),
         6 => Ok(X86_64Reloc::GlobalData { offset, sym }),
@@ -506,10 +647,21 @@ fn convert_to<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
         26 => Ok(X86_64Reloc::GOTPC32 { offset, sym, addend }),
         32 => Ok(X86_64Reloc::Size32 { offset, sym, addend }),
         33 => Ok(X86_64Reloc::Size { offset, sym, addend }),
+        38 => Ok(X86_64Reloc::Relative64 { offset, addend }),
+        41 => Ok(X86_64Reloc::GOTPCRelX { offset, sym, addend }),
+        42 => Ok(X86_64Reloc::RexGOTPCRelX { offset, sym, addend }),
         tag => Err(X86_64RelocError::BadTag(tag))
     }
 }
 
+fn convert_to_x32<Name>(offset: u64, sym: Name, kind: u32, addend: i64) ->
+    Result<X86_64Reloc<Name>, X86_64RelocError> {
+    match kind {
+        24 | 25 => Err(X86_64RelocError::BadX32Tag(kind)),
+        _ => convert_to(offset, sym, kind, addend)
+    }
+}
+
 impl<Name> TryFrom<RelData<Name, Elf64>> for X86_64Reloc<Name> {
     type Error = X86_64RelocError;
 
@@ -539,8 +691,8 @@ impl TryFrom<X86_64Reloc<u32>> for RelData<u32, Elf64> {
                 Ok(RelData { offset: offset, sym: sym, kind: 2 }),
             X86_64Reloc::PC32 { addend, .. } =>
                 Err(X86_64ToRelError::BadAddend(addend)),
-            X86_64Reloc::GOT32 { offset, addend: 0 } =>
-                Ok(RelData { offset: offset, sym: 0, kind: 3 }),
+            X86_64Reloc::GOT32 { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 3 }),
             X86_64Reloc::GOT32 { addend, .. } =>
                 Err(X86_64ToRelError::BadAddend(addend)),
             X86_64Reloc::PLTRel { offset, sym, addend: 0 } =>
@@ -620,6 +772,18 @@ impl TryFrom<X86_64Reloc<u32>> for RelData<u32, Elf64> {
             X86_64Reloc::Size { offset, sym, addend: 0 } =>
                 Ok(RelData { offset: offset, sym: sym, kind: 33 }),
             X86_64Reloc::Size { addend, .. } =>
+                Err(X86_64ToRelError::BadAddend(addend)),
+            X86_64Reloc::Relative64 { offset, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: 0, kind: 38 }),
+            X86_64Reloc::Relative64 { addend, .. } =>
+                Err(X86_64ToRelError::BadAddend(addend)),
+            X86_64Reloc::GOTPCRelX { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 41 }),
+            X86_64Reloc::GOTPCRelX { addend, .. } =>
+                Err(X86_64ToRelError::BadAddend(addend)),
+            X86_64Reloc::RexGOTPCRelX { offset, sym, addend: 0 } =>
+                Ok(RelData { offset: offset, sym: sym, kind: 42 }),
+            X86_64Reloc::RexGOTPCRelX { addend, .. } =>
                 Err(X86_64ToRelError::BadAddend(addend))
         }
     }
@@ -647,8 +811,8 @@ impl From<X86_64Reloc<u32>> for RelaData<u32, Elf64> {
                 RelaData { offset: offset, sym: sym, kind: 1, addend: addend },
             X86_64Reloc::PC32 { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 2, addend: addend },
-            X86_64Reloc::GOT32 { offset, addend } =>
-                RelaData { offset: offset, sym: 0, kind: 3, addend: addend },
+            X86_64Reloc::GOT32 { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 3, addend: addend },
             X86_64Reloc::PLTRel { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 4, addend: addend },
             X86_64Reloc::Copy { sym } =>
@@ -699,16 +863,89 @@ impl From<X86_64Reloc<u32>> for RelaData<u32, Elf64> {
                 RelaData { offset: offset, sym: sym, kind: 32, addend: addend },
             X86_64Reloc::Size { offset, sym, addend } =>
                 RelaData { offset: offset, sym: sym, kind: 33, addend: addend },
+            X86_64Reloc::Relative64 { offset, addend } =>
+                RelaData { offset: offset, sym: 0, kind: 38, addend: addend },
+            X86_64Reloc::GOTPCRelX { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 41, addend: addend },
+            X86_64Reloc::RexGOTPCRelX { offset, sym, addend } =>
+                RelaData { offset: offset, sym: sym, kind: 42, addend: addend }
+        }
+    }
+}
+
+impl<Name> TryFrom<RelData<Name, Elf32>> for X86_64Reloc<Name> {
+    type Error = X86_64RelocError;
+
+    #[inline]
+    fn try_from(rel: RelData<Name, Elf32>) -> Result<X86_64Reloc<Name>,
+                                                     Self::Error> {
+        let RelData { offset, sym, kind } = rel;
+
+        convert_to_x32(offset.into(), sym, kind.into(), 0)
+    }
+}
+
+impl TryFrom<X86_64Reloc<u32>> for RelData<u32, Elf32> {
+    type Error = X86_64ToRelError;
+
+    #[inline]
+    fn try_from(rel: X86_64Reloc<u32>) -> Result<RelData<u32, Elf32>,
+                                                 Self::Error> {
+        match RelData::<u32, Elf64>::try_from(rel) {
+            Ok(RelData { kind: kind @ 24, .. }) |
+            Ok(RelData { kind: kind @ 25, .. }) =>
+                Err(X86_64ToRelError::BadX32Tag(kind)),
+            Ok(RelData { offset, sym, kind }) => match offset.try_into() {
+                Ok(offset) => Ok(RelData { offset: offset, sym: sym,
+                                           kind: kind as u8 }),
+                Err(_) => Err(X86_64ToRelError::BadOffset(offset))
+            },
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<Name> TryFrom<RelaData<Name, Elf32>> for X86_64Reloc<Name> {
+    type Error = X86_64RelocError;
+
+    #[inline]
+    fn try_from(rela: RelaData<Name, Elf32>) -> Result<X86_64Reloc<Name>,
+                                                       Self::Error> {
+        let RelaData { offset, sym, kind, addend } = rela;
+
+        convert_to_x32(offset.into(), sym, kind.into(), addend.into())
+    }
+}
+
+impl TryFrom<X86_64Reloc<u32>> for RelaData<u32, Elf32> {
+    type Error = X86_64ToRelError;
+
+    #[inline]
+    fn try_from(rel: X86_64Reloc<u32>) -> Result<RelaData<u32, Elf32>,
+                                                 Self::Error> {
+        match RelaData::<u32, Elf64>::from(rel) {
+            RelaData { kind: kind @ 24, .. } |
+            RelaData { kind: kind @ 25, .. } =>
+                Err(X86_64ToRelError::BadX32Tag(kind)),
+            RelaData { offset, sym, kind, addend } =>
+                match (offset.try_into(), addend.try_into()) {
+                    (Ok(offset), Ok(addend)) =>
+                        Ok(RelaData { offset: offset, sym: sym,
+                                      kind: kind as u8, addend: addend }),
+                    (Err(_), _) => Err(X86_64ToRelError::BadOffset(offset)),
+                    (_, Err(_)) => Err(X86_64ToRelError::BadAddend(addend))
+                }
         }
     }
 }
 
-impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
-    type Result = X86_64Reloc<SymDataRaw<Elf64>>;
-    type Error = RelocSymtabError<Elf64>;
+impl<'a, Class> WithSymtab<'a, LittleEndian, Class> for X86_64RelocRaw
+    where Class: SymOffsets {
+    type Result = X86_64RelocRawSym<Class>;
+    type Error = RelocSymtabError<Class>;
 
     #[inline]
-    fn with_symtab(self, symtab: Symtab<'a, LittleEndian, Elf64>) ->
+    fn with_symtab(self, symtab: Symtab<'a, LittleEndian, Class>) ->
         Result<Self::Result, Self::Error> {
         match self {
             X86_64Reloc::None => Ok(X86_64Reloc::None),
@@ -722,7 +959,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::PC32 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -733,10 +970,20 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            X86_64Reloc::GOT32 { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(X86_64Reloc::GOT32 { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
-            X86_64Reloc::GOT32 { offset, addend } =>
-                Ok(X86_64Reloc::GOT32 { offset: offset, addend: addend }),
             X86_64Reloc::PLTRel { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
                     Some(sym) => match sym.try_into() {
@@ -747,7 +994,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Copy { sym } => match symtab.idx(sym as usize) {
                     Some(sym) => match sym.try_into() {
@@ -756,7 +1003,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::GlobalData { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -767,7 +1014,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::JumpSlot { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -778,7 +1025,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Relative { offset, addend } =>
                 Ok(X86_64Reloc::Relative { offset: offset, addend: addend }),
@@ -792,7 +1039,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Abs32 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -804,7 +1051,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Abs32Signed { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -816,7 +1063,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Abs16 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -828,7 +1075,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::PC16 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -839,7 +1086,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Abs8 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -851,7 +1098,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::PC8 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -862,7 +1109,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::DTPMod { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -873,7 +1120,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::DTPOff { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -884,7 +1131,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::TPOff { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -895,7 +1142,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::TLSGD { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -906,7 +1153,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::TLSLD { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -917,7 +1164,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::DTPOff32 { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -928,7 +1175,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::GOTTPOff { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -939,7 +1186,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::TPOff32 { offset, sym } =>
                 match symtab.idx(sym as usize) {
@@ -950,7 +1197,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::PC64 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -961,7 +1208,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::GOTRel { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -973,7 +1220,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::GOTPC32 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -985,7 +1232,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Size32 { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -997,7 +1244,7 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 },
             X86_64Reloc::Size { offset, sym, addend } =>
                 match symtab.idx(sym as usize) {
@@ -1008,14 +1255,42 @@ impl<'a> WithSymtab<'a, LittleEndian, Elf64> for X86_64RelocRaw {
                         },
                         Err(err) => Err(RelocSymtabError::SymError(err))
                     },
-                    None => Err(RelocSymtabError::BadIdx(sym))
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            X86_64Reloc::Relative64 { offset, addend } =>
+                Ok(X86_64Reloc::Relative64 { offset: offset,
+                                             addend: addend }),
+            X86_64Reloc::GOTPCRelX { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(X86_64Reloc::GOTPCRelX { offset: offset,
+                                                        sym: symdata,
+                                                        addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
+                },
+            X86_64Reloc::RexGOTPCRelX { offset, sym, addend } =>
+                match symtab.idx(sym as usize) {
+                    Some(sym) => match sym.try_into() {
+                        Ok(symdata) => {
+                            Ok(X86_64Reloc::RexGOTPCRelX { offset: offset,
+                                                           sym: symdata,
+                                                           addend: addend })
+                        },
+                        Err(err) => Err(RelocSymtabError::SymError(err))
+                    },
+                    None => Err(RelocSymtabError::BadIdx(sym.into()))
                 }
         }
     }
 }
 
-impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
-    type Result = X86_64RelocStrDataSym<'a>;
+impl<'a, Class> WithStrtab<'a> for X86_64RelocRawSym<Class>
+    where Class: SymOffsets {
+    type Result = X86_64RelocStrDataSym<'a, Class>;
     type Error = u32;
 
     #[inline]
@@ -1029,7 +1304,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::Abs64 { offset: offset, sym: symdata,
                                                 addend: addend })
                         },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                     },
             X86_64Reloc::PC32 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1037,23 +1312,29 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::PC32 { offset: offset, sym: symdata,
                                                addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
+                },
+            X86_64Reloc::GOT32 { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(X86_64Reloc::GOT32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err.into())
                 },
-            X86_64Reloc::GOT32 { offset, addend } =>
-                Ok(X86_64Reloc::GOT32 { offset: offset, addend: addend }),
             X86_64Reloc::PLTRel { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
                         Ok(X86_64Reloc::PLTRel { offset: offset, sym: symdata,
                                                  addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Copy { sym } => match sym.with_strtab(strtab) {
                 Ok(symdata) => {
                     Ok(X86_64Reloc::Copy { sym: symdata })
                 },
-                Err(err) => Err(err)
+                Err(err) => Err(err.into())
             },
             X86_64Reloc::GlobalData { offset, sym } =>
                 match sym.with_strtab(strtab) {
@@ -1061,7 +1342,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::GlobalData { offset: offset,
                                                      sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::JumpSlot { offset, sym } =>
                 match sym.with_strtab(strtab) {
@@ -1069,7 +1350,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::JumpSlot { offset: offset,
                                                    sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Relative { offset, addend } =>
                 Ok(X86_64Reloc::Relative { offset: offset, addend: addend }),
@@ -1079,7 +1360,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::GOTPC { offset: offset, sym: symdata,
                                                 addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Abs32 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1087,7 +1368,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::Abs32 { offset: offset, sym: symdata,
                                                 addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Abs32Signed { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1096,7 +1377,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                                                       sym: symdata,
                                                       addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Abs16 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1104,7 +1385,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::Abs16 { offset: offset, sym: symdata,
                                                 addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::PC16 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1112,7 +1393,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::PC16 { offset: offset, sym: symdata,
                                                addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Abs8 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1120,7 +1401,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::Abs8 { offset: offset, sym: symdata,
                                                addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::PC8 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1128,42 +1409,42 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::PC8 { offset: offset, sym: symdata,
                                               addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::DTPMod { offset, sym } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
                         Ok(X86_64Reloc::DTPMod { offset: offset, sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::DTPOff { offset, sym } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
                         Ok(X86_64Reloc::DTPOff { offset: offset, sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::TPOff { offset, sym } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
                         Ok(X86_64Reloc::TPOff { offset: offset, sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::TLSGD { offset, sym } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
                         Ok(X86_64Reloc::TLSGD { offset: offset, sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::TLSLD { offset, sym } =>
                 match sym.with_strtab(strtab) {
                     Ok(symdata) => {
                         Ok(X86_64Reloc::TLSLD { offset: offset, sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::DTPOff32 { offset, sym } =>
                 match sym.with_strtab(strtab) {
@@ -1171,7 +1452,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::DTPOff32 { offset: offset,
                                                    sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::GOTTPOff { offset, sym } =>
                 match sym.with_strtab(strtab) {
@@ -1179,7 +1460,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::GOTTPOff { offset: offset,
                                                    sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::TPOff32 { offset, sym } =>
                 match sym.with_strtab(strtab) {
@@ -1187,7 +1468,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::TPOff32 { offset: offset,
                                                   sym: symdata })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::PC64 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1195,7 +1476,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::PC64 { offset: offset, sym: symdata,
                                                addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::GOTRel { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1203,7 +1484,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::GOTRel { offset: offset, sym: symdata,
                                                  addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::GOTPC32 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1211,7 +1492,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::GOTPC32 { offset: offset, sym: symdata,
                                                   addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Size32 { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1219,7 +1500,7 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::Size32 { offset: offset, sym: symdata,
                                                  addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
                 },
             X86_64Reloc::Size { offset, sym, addend } =>
                 match sym.with_strtab(strtab) {
@@ -1227,18 +1508,41 @@ impl<'a> WithStrtab<'a> for X86_64RelocRawSym {
                         Ok(X86_64Reloc::Size { offset: offset, sym: symdata,
                                                addend: addend })
                     },
-                    Err(err) => Err(err)
+                    Err(err) => Err(err.into())
+                },
+            X86_64Reloc::Relative64 { offset, addend } =>
+                Ok(X86_64Reloc::Relative64 { offset: offset,
+                                             addend: addend }),
+            X86_64Reloc::GOTPCRelX { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(X86_64Reloc::GOTPCRelX { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err.into())
                 },
+            X86_64Reloc::RexGOTPCRelX { offset, sym, addend } =>
+                match sym.with_strtab(strtab) {
+                    Ok(symdata) => {
+                        Ok(X86_64Reloc::RexGOTPCRelX { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err.into())
+                }
         }
     }
 }
 
-impl<'a> TryFrom<X86_64RelocStrDataSym<'a>> for X86_64RelocStrSym<'a> {
+impl<'a, Class> TryFrom<X86_64RelocStrDataSym<'a, Class>>
+    for X86_64RelocStrSym<'a, Class>
+    where Class: SymOffsets {
     type Error = &'a [u8];
 
     #[inline]
-    fn try_from(reloc: X86_64RelocStrDataSym<'a>) ->
-        Result<X86_64RelocStrSym<'a>, Self::Error> {
+    fn try_from(reloc: X86_64RelocStrDataSym<'a, Class>) ->
+        Result<X86_64RelocStrSym<'a, Class>, Self::Error> {
         match reloc {
             X86_64Reloc::None => Ok(X86_64Reloc::None),
             X86_64Reloc::Abs64 { offset, sym, addend } =>
@@ -1257,8 +1561,14 @@ impl<'a> TryFrom<X86_64RelocStrDataSym<'a>> for X86_64RelocStrSym<'a> {
                     },
                     Err(err) => Err(err)
                 },
-            X86_64Reloc::GOT32 { offset, addend } =>
-                Ok(X86_64Reloc::GOT32 { offset: offset, addend: addend }),
+            X86_64Reloc::GOT32 { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(X86_64Reloc::GOT32 { offset: offset, sym: symdata,
+                                                addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
             X86_64Reloc::PLTRel { offset, sym, addend } =>
                 match sym.try_into() {
                     Ok(symdata) => {
@@ -1447,13 +1757,37 @@ impl<'a> TryFrom<X86_64RelocStrDataSym<'a>> for X86_64RelocStrSym<'a> {
                     },
                     Err(err) => Err(err)
                 },
+            X86_64Reloc::Relative64 { offset, addend } =>
+                Ok(X86_64Reloc::Relative64 { offset: offset,
+                                             addend: addend }),
+            X86_64Reloc::GOTPCRelX { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(X86_64Reloc::GOTPCRelX { offset: offset,
+                                                    sym: symdata,
+                                                    addend: addend })
+                    },
+                    Err(err) => Err(err)
+                },
+            X86_64Reloc::RexGOTPCRelX { offset, sym, addend } =>
+                match sym.try_into() {
+                    Ok(symdata) => {
+                        Ok(X86_64Reloc::RexGOTPCRelX { offset: offset,
+                                                       sym: symdata,
+                                                       addend: addend })
+                    },
+                    Err(err) => Err(err)
+                }
         }
     }
 }
 
-impl<'a> From<X86_64RelocStrDataSym<'a>> for X86_64RelocStrData<'a> {
+impl<'a, Class> From<X86_64RelocStrDataSym<'a, Class>>
+    for X86_64RelocStrData<'a>
+    where Class: SymOffsets {
     #[inline]
-    fn from(reloc: X86_64RelocStrDataSym<'a>) -> X86_64RelocStrData<'a> {
+    fn from(reloc: X86_64RelocStrDataSym<'a, Class>) ->
+        X86_64RelocStrData<'a> {
         match reloc {
             X86_64Reloc::None => X86_64Reloc::None,
             X86_64Reloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
@@ -1461,8 +1795,9 @@ impl<'a> From<X86_64RelocStrDataSym<'a>> for X86_64RelocStrData<'a> {
                                      addend: addend },
             X86_64Reloc::PC32 { sym: SymData { name, .. }, offset, addend } =>
                 X86_64Reloc::PC32 { offset: offset, sym: name, addend: addend },
-            X86_64Reloc::GOT32 { offset, addend } =>
-                X86_64Reloc::GOT32 { offset: offset, addend: addend },
+            X86_64Reloc::GOT32 { sym: SymData { name, .. }, offset, addend } =>
+                X86_64Reloc::GOT32 { offset: offset, sym: name,
+                                     addend: addend },
             X86_64Reloc::PLTRel { sym: SymData { name, .. }, offset, addend } =>
                 X86_64Reloc::PLTRel { offset: offset, sym: name,
                                       addend: addend },
@@ -1522,7 +1857,17 @@ impl<'a> From<X86_64RelocStrDataSym<'a>> for X86_64RelocStrData<'a> {
                 X86_64Reloc::Size32 { offset: offset, sym: name,
                                       addend: addend },
             X86_64Reloc::Size { sym: SymData { name, .. }, offset, addend } =>
-                X86_64Reloc::Size { offset: offset, sym: name, addend: addend }
+                X86_64Reloc::Size { offset: offset, sym: name, addend: addend },
+            X86_64Reloc::Relative64 { offset, addend } =>
+                X86_64Reloc::Relative64 { offset: offset, addend: addend },
+            X86_64Reloc::GOTPCRelX { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                X86_64Reloc::GOTPCRelX { offset: offset, sym: name,
+                                         addend: addend },
+            X86_64Reloc::RexGOTPCRelX { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                X86_64Reloc::RexGOTPCRelX { offset: offset, sym: name,
+                                            addend: addend }
         }
     }
 }
@@ -1549,8 +1894,13 @@ impl<'a> TryFrom<X86_64RelocStrData<'a>> for X86_64RelocStr<'a> {
             X86_64Reloc::PC32 { sym: None, offset, addend } =>
                 Ok(X86_64Reloc::PC32 { offset: offset, sym: None,
                                        addend: addend }),
-            X86_64Reloc::GOT32 { offset, addend } =>
-                Ok(X86_64Reloc::GOT32 { offset: offset, addend: addend }),
+            X86_64Reloc::GOT32 { sym: Some(Ok(name)), offset, addend } =>
+                Ok(X86_64Reloc::GOT32 { offset: offset, sym: Some(name),
+                                        addend: addend }),
+            X86_64Reloc::GOT32 { sym: Some(Err(err)), .. } => Err(err),
+            X86_64Reloc::GOT32 { sym: None, offset, addend } =>
+                Ok(X86_64Reloc::GOT32 { offset: offset, sym: None,
+                                        addend: addend }),
             X86_64Reloc::PLTRel { sym: Some(Ok(name)), offset, addend } =>
                 Ok(X86_64Reloc::PLTRel { offset: offset, sym: Some(name),
                                          addend: addend }),
@@ -1698,14 +2048,32 @@ impl<'a> TryFrom<X86_64RelocStrData<'a>> for X86_64RelocStr<'a> {
             X86_64Reloc::Size { sym: Some(Err(err)), .. } => Err(err),
             X86_64Reloc::Size { sym: None, offset, addend } =>
                 Ok(X86_64Reloc::Size { offset: offset, sym: None,
-                                       addend: addend })
+                                       addend: addend }),
+            X86_64Reloc::Relative64 { offset, addend } =>
+                Ok(X86_64Reloc::Relative64 { offset: offset,
+                                             addend: addend }),
+            X86_64Reloc::GOTPCRelX { sym: Some(Ok(name)), offset, addend } =>
+                Ok(X86_64Reloc::GOTPCRelX { offset: offset, sym: Some(name),
+                                            addend: addend }),
+            X86_64Reloc::GOTPCRelX { sym: Some(Err(err)), .. } => Err(err),
+            X86_64Reloc::GOTPCRelX { sym: None, offset, addend } =>
+                Ok(X86_64Reloc::GOTPCRelX { offset: offset, sym: None,
+                                            addend: addend }),
+            X86_64Reloc::RexGOTPCRelX { sym: Some(Ok(name)), offset, addend } =>
+                Ok(X86_64Reloc::RexGOTPCRelX { offset: offset, sym: Some(name),
+                                               addend: addend }),
+            X86_64Reloc::RexGOTPCRelX { sym: Some(Err(err)), .. } => Err(err),
+            X86_64Reloc::RexGOTPCRelX { sym: None, offset, addend } =>
+                Ok(X86_64Reloc::RexGOTPCRelX { offset: offset, sym: None,
+                                               addend: addend })
         }
     }
 }
 
-impl<'a> From<X86_64RelocStrSym<'a>> for X86_64RelocStr<'a> {
+impl<'a, Class> From<X86_64RelocStrSym<'a, Class>> for X86_64RelocStr<'a>
+    where Class: SymOffsets {
     #[inline]
-    fn from(reloc: X86_64RelocStrSym<'a>) -> X86_64RelocStr<'a> {
+    fn from(reloc: X86_64RelocStrSym<'a, Class>) -> X86_64RelocStr<'a> {
         match reloc {
             X86_64Reloc::None => X86_64Reloc::None,
             X86_64Reloc::Abs64 { sym: SymData { name, .. }, offset, addend } =>
@@ -1713,8 +2081,9 @@ impl<'a> From<X86_64RelocStrSym<'a>> for X86_64RelocStr<'a> {
                                      addend: addend },
             X86_64Reloc::PC32 { sym: SymData { name, .. }, offset, addend } =>
                 X86_64Reloc::PC32 { offset: offset, sym: name, addend: addend },
-            X86_64Reloc::GOT32 { offset, addend } =>
-                X86_64Reloc::GOT32 { offset: offset, addend: addend },
+            X86_64Reloc::GOT32 { sym: SymData { name, .. }, offset, addend } =>
+                X86_64Reloc::GOT32 { offset: offset, sym: name,
+                                     addend: addend },
             X86_64Reloc::PLTRel { sym: SymData { name, .. }, offset, addend } =>
                 X86_64Reloc::PLTRel { offset: offset, sym: name,
                                       addend: addend },
@@ -1774,7 +2143,17 @@ impl<'a> From<X86_64RelocStrSym<'a>> for X86_64RelocStr<'a> {
                 X86_64Reloc::Size32 { offset: offset, sym: name,
                                       addend: addend },
             X86_64Reloc::Size { sym: SymData { name, .. }, offset, addend } =>
-                X86_64Reloc::Size { offset: offset, sym: name, addend: addend }
+                X86_64Reloc::Size { offset: offset, sym: name, addend: addend },
+            X86_64Reloc::Relative64 { offset, addend } =>
+                X86_64Reloc::Relative64 { offset: offset, addend: addend },
+            X86_64Reloc::GOTPCRelX { sym: SymData { name, .. }, offset,
+                                     addend } =>
+                X86_64Reloc::GOTPCRelX { offset: offset, sym: name,
+                                         addend: addend },
+            X86_64Reloc::RexGOTPCRelX { sym: SymData { name, .. }, offset,
+                                        addend } =>
+                X86_64Reloc::RexGOTPCRelX { offset: offset, sym: name,
+                                            addend: addend }
         }
     }
 }
@@ -107,6 +107,7 @@
 //! use core::convert::TryFrom;
 //! use core::convert::TryInto;
 //! use elf_utils::Elf32;
+//! use elf_utils::section_hdr::SectionFlags;
 //! use elf_utils::section_hdr::SectionHdrs;
 //! use elf_utils::section_hdr::SectionHdrData;
 //! use elf_utils::section_hdr::SectionHdrDataRaw;
@@ -149,8 +150,9 @@
 //!                                           syms: SectionPos { offset: 0x18c,
 //!                                                              size: 0x1b0 },
 //!                                           strtab: 7, local_end: 1,
-//!                                           alloc: true, write: false,
-//!                                           exec: false });
+//!                                           flags: SectionFlags {
+//!                                               bits: 0x2
+//!                                           } });
 //! ```
 use byteorder::ByteOrder;
 use core::convert::TryFrom;
@@ -160,30 +162,85 @@ use core::fmt::Formatter;
 use core::fmt::LowerHex;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
+use crate::array::AddrArray;
+use crate::array::AddrArrayError;
 use crate::dynamic::Dynamic;
 use crate::dynamic::DynamicError;
 use crate::dynamic::DynamicOffsets;
 use crate::elf::Elf32;
 use crate::elf::Elf64;
 use crate::elf::ElfClass;
+use crate::elf::PN_XNUM;
 use crate::elf::WithElfData;
+use crate::group::Group;
+use crate::group::GroupError;
+use crate::hash::GnuHashtab;
+use crate::hash::GnuHashtabError;
 use crate::hash::Hashtab;
 use crate::hash::HashtabError;
 use crate::note::Notes;
 use crate::note::NotesError;
+use crate::reloc::android::AndroidRelOffsets;
+use crate::reloc::android::AndroidRelas;
+use crate::reloc::android::AndroidRelocsError;
+use crate::reloc::android::AndroidRels;
 use crate::reloc::RelaOffsets;
 use crate::reloc::Relas;
 use crate::reloc::RelasError;
+use crate::reloc::RelrOffsets;
+use crate::reloc::Relrs;
+use crate::reloc::RelrsError;
 use crate::reloc::Rels;
 use crate::reloc::RelsError;
+use crate::shndx::SymtabShndx;
+use crate::shndx::SymtabShndxError;
 use crate::symtab::Symtab;
 use crate::symtab::SymtabError;
 use crate::strtab::Strtab;
 use crate::strtab::StrtabError;
 use crate::strtab::StrtabIdxError;
+use crate::version::Verdefs;
+use crate::version::VerdefsError;
+use crate::version::Verneeds;
+use crate::version::VerneedsError;
+use crate::version::Versyms;
+use crate::version::VersymsError;
 use crate::strtab::WithStrtab;
 use crate::symtab::SymOffsets;
 
+const SHN_LORESERVE: usize = 0xff00;
+const SHF_WRITE: u32 = 0x1;
+const SHF_ALLOC: u32 = 0x2;
+const SHF_EXECINSTR: u32 = 0x4;
+const SHF_MERGE: u32 = 0x10;
+const SHF_STRINGS: u32 = 0x20;
+const SHF_INFO_LINK: u32 = 0x40;
+const SHF_LINK_ORDER: u32 = 0x80;
+const SHF_OS_NONCONFORMING: u32 = 0x100;
+const SHF_GROUP: u32 = 0x200;
+const SHF_TLS: u32 = 0x400;
+const SHF_COMPRESSED: u32 = 0x800;
+const SHF_GNU_RETAIN: u32 = 0x200000;
+const SHF_EXCLUDE: u32 = 0x80000000;
+const SHF_MASKOS: u32 = 0x0ff00000;
+const SHF_MASKPROC: u32 = 0xf0000000;
+
+const SECTION_FLAGS: [SectionFlag; 13] = [
+    SectionFlag::Write,
+    SectionFlag::Alloc,
+    SectionFlag::Exec,
+    SectionFlag::Merge,
+    SectionFlag::Strings,
+    SectionFlag::InfoLink,
+    SectionFlag::LinkOrder,
+    SectionFlag::OSNonConforming,
+    SectionFlag::Group,
+    SectionFlag::TLS,
+    SectionFlag::Compressed,
+    SectionFlag::GNURetain,
+    SectionFlag::Exclude
+];
+
 /// Offsets for ELF section headers.
 ///
 /// This contains the various offsets for fields in an ELF section
@@ -311,6 +368,7 @@ pub trait WithSectionHdrs<'a, B: ByteOrder, Offsets: SectionHdrOffsets> {
 /// use core::convert::TryFrom;
 /// use core::convert::TryInto;
 /// use elf_utils::Elf32;
+/// use elf_utils::section_hdr::SectionFlags;
 /// use elf_utils::section_hdr::SectionHdrs;
 /// use elf_utils::section_hdr::SectionHdrData;
 /// use elf_utils::section_hdr::SectionHdrDataRaw;
@@ -348,19 +406,19 @@ pub trait WithSectionHdrs<'a, B: ByteOrder, Offsets: SectionHdrOffsets> {
 ///     SectionHdrData::Null,
 ///     SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
 ///                            note: SectionPos { offset: 0x174, size: 0x18 },
-///                            alloc: true, write: false, exec: false },
+///                            flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
 ///                              syms: SectionPos { offset: 0x18c,
 ///                                                 size: 0x1b0 },
 ///                              strtab: 7, local_end: 1,
-///                              alloc: true, write: false, exec: false },
+///                              flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
 ///                            hash: SectionPos { offset: 0x540, size: 0xe0 },
-///                            symtab: 2, alloc: true, write: false,
-///                            exec: false },
+///                            symtab: 2, flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
 ///                              strs: SectionPos { offset: 0x620,
-///                                                 size: 0x1d2 } }
+///                                                 size: 0x1d2 },
+///                              flags: SectionFlags { bits: 0x2 } }
 /// ];
 ///
 /// let hdrs: SectionHdrs<'_, LittleEndian, Elf32> =
@@ -401,6 +459,7 @@ pub struct SectionHdrs<'a, B, Offsets: SectionHdrOffsets> {
 /// use core::convert::TryFrom;
 /// use core::convert::TryInto;
 /// use elf_utils::Elf32;
+/// use elf_utils::section_hdr::SectionFlags;
 /// use elf_utils::section_hdr::SectionHdrs;
 /// use elf_utils::section_hdr::SectionHdrData;
 /// use elf_utils::section_hdr::SectionHdrDataRaw;
@@ -443,20 +502,84 @@ pub struct SectionHdrs<'a, B, Offsets: SectionHdrOffsets> {
 ///                                           syms: SectionPos { offset: 0x18c,
 ///                                                              size: 0x1b0 },
 ///                                           strtab: 7, local_end: 1,
-///                                           alloc: true, write: false,
-///                                           exec: false });
+///                                           flags: SectionFlags {
+///                                               bits: 0x2
+///                                           } });
 /// ```
 #[derive(Copy, Clone)]
 pub struct SectionHdr<'a, B: ByteOrder, Offsets: SectionHdrOffsets> {
     byteorder: PhantomData<B>,
     offsets: PhantomData<Offsets>,
-    ent: &'a [u8]
+    ent: &'a [u8],
+    first: bool
+}
+
+/// In-place mutable ELF section header table.
+///
+/// This is the mutable counterpart to [SectionHdrs].  It can be
+/// created from raw data using the [TryFrom](core::convert::TryFrom)
+/// instance, and provides [SectionHdrMut]s through
+/// [idx_mut](SectionHdrsMut::idx_mut), which can be used to patch
+/// section headers in place.
+pub struct SectionHdrsMut<'a, B, Offsets: SectionHdrOffsets> {
+    byteorder: PhantomData<B>,
+    offsets: PhantomData<Offsets>,
+    hdrs: &'a mut [u8]
 }
 
+/// In-place mutable ELF section header.
+///
+/// This is obtained from a [SectionHdrsMut] using
+/// [idx_mut](SectionHdrsMut::idx_mut).  Individual fields can be
+/// changed with the setter functions, or the entire section header
+/// can be replaced with [set](SectionHdrMut::set).  All values are
+/// written in the byte order of the underlying data.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::Elf32;
+/// use elf_utils::section_hdr::SectionFlag;
+/// use elf_utils::section_hdr::SectionFlags;
+/// use elf_utils::section_hdr::SectionHdrData;
+/// use elf_utils::section_hdr::SectionHdrDataRaw;
+/// use elf_utils::section_hdr::SectionHdrsMut;
+/// use elf_utils::section_hdr::SectionPos;
+///
+/// let mut buf: [u8; 40] = [
+///     0x1b, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+///     0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x40, 0x00, 0x00, 0x00, 0x2a, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+/// let mut hdrs: SectionHdrsMut<'_, LittleEndian, Elf32> =
+///     SectionHdrsMut::try_from(&mut buf[0..]).unwrap();
+/// let mut hdr = hdrs.idx_mut(0).unwrap();
+/// let mut flags = SectionFlags { bits: 0x4 };
+///
+/// flags.insert(SectionFlag::Alloc);
+/// hdr.set_flags(flags);
+/// hdr.set_addr(0x1000);
+///
+/// let data: SectionHdrDataRaw<Elf32> = hdr.try_into().unwrap();
+///
+/// assert_eq!(data, SectionHdrData::ProgBits {
+///                      name: 0x1b, addr: 0x1000, align: 16, ent_size: 0,
+///                      data: SectionPos { offset: 0x40, size: 0x2a },
+///                      flags: flags
+///                  });
+/// ```
 pub struct SectionHdrMut<'a, B: ByteOrder, Offsets: SectionHdrOffsets> {
     byteorder: PhantomData<B>,
     offsets: PhantomData<Offsets>,
-    ent: &'a mut [u8]
+    ent: &'a mut [u8],
+    first: bool
 }
 
 /// Iterator for [SectionHdrs].
@@ -473,6 +596,7 @@ pub struct SectionHdrMut<'a, B: ByteOrder, Offsets: SectionHdrOffsets> {
 /// use core::convert::TryFrom;
 /// use core::convert::TryInto;
 /// use elf_utils::Elf32;
+/// use elf_utils::section_hdr::SectionFlags;
 /// use elf_utils::section_hdr::SectionHdrs;
 /// use elf_utils::section_hdr::SectionHdrData;
 /// use elf_utils::section_hdr::SectionHdrDataRaw;
@@ -511,19 +635,19 @@ pub struct SectionHdrMut<'a, B: ByteOrder, Offsets: SectionHdrOffsets> {
 ///     SectionHdrData::Null,
 ///     SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
 ///                            note: SectionPos { offset: 0x174, size: 0x18 },
-///                            alloc: true, write: false, exec: false },
+///                            flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
 ///                              syms: SectionPos { offset: 0x18c,
 ///                                                 size: 0x1b0 },
 ///                              strtab: 7, local_end: 1,
-///                              alloc: true, write: false, exec: false },
+///                              flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
 ///                            hash: SectionPos { offset: 0x540, size: 0xe0 },
-///                            symtab: 2, alloc: true, write: false,
-///                            exec: false },
+///                            symtab: 2, flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
 ///                              strs: SectionPos { offset: 0x620,
-///                                                 size: 0x1d2 } }
+///                                                 size: 0x1d2 },
+///                              flags: SectionFlags { bits: 0x2 } }
 /// ];
 ///
 /// let hdrs: SectionHdrs<'_, LittleEndian, Elf32> =
@@ -608,6 +732,163 @@ pub enum SectionHdrsError {
     BadSize(usize)
 }
 
+/// Individual flags that can appear in a section header.
+///
+/// These are the values recognized by [SectionFlags].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SectionFlag {
+    /// The section is writable during execution (`SHF_WRITE`).
+    Write,
+    /// The section occupies memory during execution (`SHF_ALLOC`).
+    Alloc,
+    /// The section contains executable code (`SHF_EXECINSTR`).
+    Exec,
+    /// The section contains entries that may be merged to eliminate
+    /// duplicates (`SHF_MERGE`).
+    Merge,
+    /// The section contains null-terminated strings (`SHF_STRINGS`).
+    Strings,
+    /// The info field holds a section header index (`SHF_INFO_LINK`).
+    InfoLink,
+    /// The section must be ordered with respect to the section given
+    /// by the link field (`SHF_LINK_ORDER`).
+    LinkOrder,
+    /// The section requires OS-specific processing
+    /// (`SHF_OS_NONCONFORMING`).
+    OSNonConforming,
+    /// The section is a member of a section group (`SHF_GROUP`).
+    Group,
+    /// The section holds thread-local storage (`SHF_TLS`).
+    TLS,
+    /// The section data is compressed (`SHF_COMPRESSED`).
+    Compressed,
+    /// The section must not be garbage-collected by the linker
+    /// (`SHF_GNU_RETAIN`).
+    GNURetain,
+    /// The section is excluded from executables and shared objects
+    /// (`SHF_EXCLUDE`).
+    Exclude
+}
+
+/// Decoded contents of a section header flags field.
+///
+/// This is a bitset over the [SectionFlag] values.  All bits of the
+/// original field are kept, including OS- and processor-specific bits
+/// and any that do not correspond to a known flag, so that projecting
+/// a [SectionHdr] and then writing it out again with
+/// [create](SectionHdrs::create) produces the same header.
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use elf_utils::Elf64;
+/// use elf_utils::section_hdr::SectionFlag;
+/// use elf_utils::section_hdr::SectionFlags;
+///
+/// let mut flags: SectionFlags<Elf64> = SectionFlags { bits: 0x32 };
+///
+/// assert!(flags.contains(SectionFlag::Alloc));
+/// assert!(flags.contains(SectionFlag::Merge));
+/// assert!(flags.contains(SectionFlag::Strings));
+/// assert!(!flags.contains(SectionFlag::Write));
+///
+/// flags.remove(SectionFlag::Strings);
+/// flags.insert(SectionFlag::Exec);
+///
+/// assert_eq!(flags.bits, 0x16);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SectionFlags<Class: ElfClass> {
+    /// The raw flag bits.
+    pub bits: Class::Offset
+}
+
+/// Representations of packed relocation table contents.
+///
+/// This groups the types used for the contents of the
+/// [Relr](SectionHdrData::Relr), [AndroidRel](SectionHdrData::AndroidRel)
+/// and [AndroidRela](SectionHdrData::AndroidRela) variants of
+/// [SectionHdrData] into a single type parameter.  An instance is
+/// provided by [PackedRelocs].
+pub trait PackedRelocTypes {
+    /// Representation of a relative relocation table.
+    type Relr;
+    /// Representation of an Android packed relocation table with
+    /// implicit addends.
+    type AndroidRel;
+    /// Representation of an Android packed relocation table with
+    /// explicit addends.
+    type AndroidRela;
+}
+
+/// Instance of [PackedRelocTypes] for any three representations.
+///
+/// This type is never constructed; it only names the representations
+/// used for each kind of packed relocation table in a
+/// [SectionHdrData].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PackedRelocs<Relr, AndroidRel, AndroidRela> {
+    tabs: PhantomData<(Relr, AndroidRel, AndroidRela)>
+}
+
+/// Representations of symbol version table contents.
+///
+/// This groups the types used for the contents of the
+/// [Versym](SectionHdrData::Versym), [Verdef](SectionHdrData::Verdef)
+/// and [Verneed](SectionHdrData::Verneed) variants of
+/// [SectionHdrData] into a single type parameter.  An instance is
+/// provided by [VersionTables].
+pub trait VersionTableTypes {
+    /// Representation of a symbol version table.
+    type Versym;
+    /// Representation of a version definition table.
+    type Verdef;
+    /// Representation of a version requirement table.
+    type Verneed;
+}
+
+/// Instance of [VersionTableTypes] for any three representations.
+///
+/// This type is never constructed; it only names the representations
+/// used for each kind of symbol version table in a [SectionHdrData].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VersionTables<Versym, Verdef, Verneed> {
+    tabs: PhantomData<(Versym, Verdef, Verneed)>
+}
+
+/// Representations of auxiliary section contents.
+///
+/// This groups the types used for the contents of the
+/// [GnuHash](SectionHdrData::GnuHash),
+/// [InitArray](SectionHdrData::InitArray),
+/// [FiniArray](SectionHdrData::FiniArray),
+/// [PreInitArray](SectionHdrData::PreInitArray),
+/// [Group](SectionHdrData::Group) and
+/// [SymtabShndx](SectionHdrData::SymtabShndx) variants of
+/// [SectionHdrData] into a single type parameter.  An instance is
+/// provided by [AuxSections].
+pub trait AuxSectionTypes {
+    /// Representation of a GNU hash table.
+    type GnuHash;
+    /// Representation of an array of addresses.
+    type Array;
+    /// Representation of a section group.
+    type Group;
+    /// Representation of an extended section index table.
+    type Shndx;
+}
+
+/// Instance of [AuxSectionTypes] for any four representations.
+///
+/// This type is never constructed; it only names the representations
+/// used for each kind of auxiliary section in a [SectionHdrData].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AuxSections<GnuHash, Array, Group, Shndx> {
+    tabs: PhantomData<(GnuHash, Array, Group, Shndx)>
+}
+
 /// Projected ELF section header data.
 ///
 /// This is a representation of an ELF section header table entry
@@ -619,12 +900,43 @@ pub enum SectionHdrsError {
 /// The instance of [WithSectionHdrs] allows a `SectionHdrData` to be
 /// interpreted using its own host `SectionHdrs` to convert associated
 /// sections references from an index into a `SectionHdr`.
+///
+/// The contents of the packed relocation sections are grouped into
+/// the single `Packed` parameter (see [PackedRelocTypes]).  This
+/// defaults to representing all of them the same way as `Data`.  The
+/// `Aux` parameter (see [AuxSectionTypes]) and the `Versions`
+/// parameter (see [VersionTableTypes]) likewise group the contents of
+/// the GNU hash table, address array, section group, and extended
+/// section index sections, and of the symbol version sections.
 #[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
 pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
                         StrsRef, Data, Syms, Strs, Rels, Relas, Hash,
-                        Dynamic, Note> {
+                        Dynamic, Note,
+                        Packed: PackedRelocTypes =
+                            PackedRelocs<Data, Data, Data>,
+                        Aux: AuxSectionTypes =
+                            AuxSections<Data, Data, Data, Data>,
+                        Versions: VersionTableTypes =
+                            VersionTables<Data, Data, Data>> {
     /// Null section.
     Null,
+    /// Null section holding extended ELF header values.
+    ///
+    /// This can only appear as the first section header.  When the
+    /// number of section headers, the section header string table
+    /// index, or the number of program headers is too large to fit in
+    /// the ELF header, the actual value is stored here instead.  A
+    /// value of zero indicates that the ELF header holds the actual
+    /// value.
+    ExtNull {
+        /// Number of section headers (held in `sh_size`).
+        num_section_hdrs: Class::Offset,
+        /// Index of the section header string table (held in
+        /// `sh_link`).
+        section_hdr_strtab: Class::Word,
+        /// Number of program headers (held in `sh_info`).
+        num_prog_hdrs: Class::Word
+    },
     /// Section containing raw program data.
     ///
     /// This typically consists of program data.
@@ -637,12 +949,11 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         align: Class::Offset,
         /// Data for the section.
         data: Data,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Size of the entries in the section, if it holds a table of
+        /// fixed-size entries (such as a mergeable section).
+        ent_size: Class::Offset,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing a symbol table.
     Symtab {
@@ -658,12 +969,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         strtab: StrsRef,
         /// Index of the last local symbol in the symbol table.
         local_end: Class::Word,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing a string table.
     Strtab {
@@ -674,7 +981,9 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         /// Alignment of the section.
         align: Class::Offset,
         /// String table information.
-        strs: Strs
+        strs: Strs,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing relocations with explicit addends.
     Rela {
@@ -690,12 +999,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         symtab: SymsRef,
         /// Section header of the target section.
         target: HdrRef,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing a symbol hash table.
     Hash {
@@ -709,12 +1014,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         hash: Hash,
         /// Section header of the target symbol table.
         symtab: SymsRef,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing dynamic loading information.
     Dynamic {
@@ -728,12 +1029,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         dynamic: Dynamic,
         /// Section header of the associated string table.
         strtab: StrsRef,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing notes.
     Note {
@@ -745,12 +1042,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         align: Class::Offset,
         /// Note information.
         note: Note,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section to be allocated at runtime, but which has no file contents.
     ///
@@ -767,12 +1060,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         offset: Class::Offset,
         /// Size of data to be allocated.
         size: Class::Offset,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing relocations with implicit addends.
     Rel {
@@ -788,12 +1077,8 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         symtab: SymsRef,
         /// Section header of the target section.
         target: HdrRef,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Section containing a dynamic symbol table.
     ///
@@ -811,12 +1096,205 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         strtab: StrsRef,
         /// Index of the last local symbol in the symbol table.
         local_end: Class::Word,
-        /// Whether this section should occupy memory during execution.
-        alloc: bool,
-        /// Whether this section is writable.
-        write: bool,
-        /// Whether this section is executable.
-        exec: bool
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing an array of initialization function
+    /// addresses.
+    InitArray {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Function addresses.
+        addrs: Aux::Array,
+        /// Size of the entries in the section, as recorded in the
+        /// header.  Linkers commonly leave this as 0.
+        ent_size: Class::Offset,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing an array of finalization function
+    /// addresses.
+    FiniArray {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Function addresses.
+        addrs: Aux::Array,
+        /// Size of the entries in the section, as recorded in the
+        /// header.  Linkers commonly leave this as 0.
+        ent_size: Class::Offset,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing an array of pre-initialization
+    /// function addresses.
+    PreInitArray {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Function addresses.
+        addrs: Aux::Array,
+        /// Size of the entries in the section, as recorded in the
+        /// header.  Linkers commonly leave this as 0.
+        ent_size: Class::Offset,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section group, typically a COMDAT group.
+    Group {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Group flags and member section indexes.
+        group: Aux::Group,
+        /// Section header of the symbol table holding the signature.
+        symtab: SymsRef,
+        /// Index of the signature symbol in the symbol table.
+        sym: Class::Word,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing extended section indexes for a symbol
+    /// table.
+    SymtabShndx {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Extended section indexes.
+        shndxs: Aux::Shndx,
+        /// Section header of the associated symbol table.
+        symtab: SymsRef,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing a compressed table of relative relocations.
+    Relr {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Relative relocation table.
+        relrs: Packed::Relr,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing an Android packed relocation table with
+    /// implicit addends.
+    AndroidRel {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Packed relocation information.
+        rels: Packed::AndroidRel,
+        /// Section header of the associated symbol table.
+        symtab: SymsRef,
+        /// Section header of the target section.
+        target: HdrRef,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing an Android packed relocation table with
+    /// explicit addends.
+    AndroidRela {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Packed relocation information.
+        relas: Packed::AndroidRela,
+        /// Section header of the associated symbol table.
+        symtab: SymsRef,
+        /// Section header of the target section.
+        target: HdrRef,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing a GNU-style symbol hash table.
+    GnuHash {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// GNU hash table information.
+        hash: Aux::GnuHash,
+        /// Section header of the target symbol table.
+        symtab: SymsRef,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing symbol version indexes.
+    Versym {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Symbol version indexes.
+        versyms: Versions::Versym,
+        /// Section header of the target symbol table.
+        symtab: SymsRef,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing symbol version definitions.
+    Verdef {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Version definitions.
+        verdefs: Versions::Verdef,
+        /// Section header of the associated string table.
+        strtab: StrsRef,
+        /// Number of version definitions.
+        count: Class::Word,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
+    },
+    /// Section containing symbol version requirements.
+    Verneed {
+        /// Name of the section.
+        name: Str,
+        /// Starting address of the section.
+        addr: Class::Addr,
+        /// Alignment of the section.
+        align: Class::Offset,
+        /// Version requirements.
+        verneeds: Versions::Verneed,
+        /// Section header of the associated string table.
+        strtab: StrsRef,
+        /// Number of version requirement entries.
+        count: Class::Word,
+        /// Flags for the section.
+        flags: SectionFlags<Class>
     },
     /// Unknown section type.
     Unknown {
@@ -833,13 +1311,13 @@ pub enum SectionHdrData<Class: ElfClass, Str, HdrRef, SymsRef,
         /// Size of the section.
         size: Class::Offset,
         /// Entry size of the section.
-        ent_size: Class::Word,
+        ent_size: Class::Offset,
         /// Link field for the section header.
         link: Class::Word,
         /// Info field for the section header.
         info: Class::Word,
         /// Flags, including access.
-        flags: Class::Offset,
+        flags: SectionFlags<Class>,
     }
 }
 
@@ -985,7 +1463,16 @@ pub type SectionHdrDataResolved<'a, B, Class> =
                    SymsStrs<Symtab<'a, B, Class>, Strtab<'a>>, Strtab<'a>,
                    &'a [u8], Symtab<'a, B, Class>, Strtab<'a>,
                    Rels<'a, B, Class>, Relas<'a, B, Class>,
-                   Hashtab<'a, B, Class>, Dynamic<'a, B, Class>, Notes<'a, B>>;
+                   Hashtab<'a, B, Class>,
+                   Dynamic<'a, B, Class>, Notes<'a, B>,
+                   PackedRelocs<Relrs<'a, B, Class>,
+                                AndroidRels<'a, B, Class>,
+                                AndroidRelas<'a, B, Class>>,
+                   AuxSections<GnuHashtab<'a, B, Class>,
+                               AddrArray<'a, B, Class>, Group<'a, B>,
+                               SymtabShndx<'a, B>>,
+                   VersionTables<Versyms<'a, B>, Verdefs<'a, B>,
+                                 Verneeds<'a, B>>>;
 
 /// Type alias for [SectionHdrDataResolved] with the section names
 /// resolved to UTF-8 decoding results.
@@ -997,7 +1484,16 @@ pub type SectionHdrDataResolvedStrData<'a, B, Class> =
                    SymsStrs<Symtab<'a, B, Class>, Strtab<'a>>, Strtab<'a>,
                    &'a [u8], Symtab<'a, B, Class>, Strtab<'a>,
                    Rels<'a, B, Class>, Relas<'a, B, Class>,
-                   Hashtab<'a, B, Class>, Dynamic<'a, B, Class>, Notes<'a, B>>;
+                   Hashtab<'a, B, Class>,
+                   Dynamic<'a, B, Class>, Notes<'a, B>,
+                   PackedRelocs<Relrs<'a, B, Class>,
+                                AndroidRels<'a, B, Class>,
+                                AndroidRelas<'a, B, Class>>,
+                   AuxSections<GnuHashtab<'a, B, Class>,
+                               AddrArray<'a, B, Class>, Group<'a, B>,
+                               SymtabShndx<'a, B>>,
+                   VersionTables<Versyms<'a, B>, Verdefs<'a, B>,
+                                 Verneeds<'a, B>>>;
 
 /// Type alias for [SectionHdrDataResolved] with the section names
 /// fully resolved to `&'a str`s.
@@ -1009,7 +1505,16 @@ pub type SectionHdrDataResolvedStrs<'a, B, Class> =
                    SymsStrs<Symtab<'a, B, Class>, Strtab<'a>>, Strtab<'a>,
                    &'a [u8], Symtab<'a, B, Class>, Strtab<'a>,
                    Rels<'a, B, Class>, Relas<'a, B, Class>,
-                   Hashtab<'a, B, Class>, Dynamic<'a, B, Class>, Notes<'a, B>>;
+                   Hashtab<'a, B, Class>,
+                   Dynamic<'a, B, Class>, Notes<'a, B>,
+                   PackedRelocs<Relrs<'a, B, Class>,
+                                AndroidRels<'a, B, Class>,
+                                AndroidRelas<'a, B, Class>>,
+                   AuxSections<GnuHashtab<'a, B, Class>,
+                               AddrArray<'a, B, Class>, Group<'a, B>,
+                               SymtabShndx<'a, B>>,
+                   VersionTables<Versyms<'a, B>, Verdefs<'a, B>,
+                                 Verneeds<'a, B>>>;
 
 /// Errors that can occur when creating a [SectionHdrData].
 #[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
@@ -1029,7 +1534,7 @@ pub enum SectionHdrError<Class: ElfClass> {
     /// Entry size does not match expectation.
     BadEntSize {
         expected: usize,
-        actual: Class::Word
+        actual: Class::Offset
     },
     /// Bad link reference.
     BadLink,
@@ -1047,6 +1552,32 @@ pub enum SectionHdrError<Class: ElfClass> {
     DynamicErr(DynamicError),
     /// An error occurred creating the [Hashtab](crate::hash::Hashtab).
     HashErr(HashtabError),
+    /// An error occurred creating the
+    /// [GnuHashtab](crate::hash::GnuHashtab).
+    GnuHashErr(GnuHashtabError),
+    /// An error occurred creating the
+    /// [Versyms](crate::version::Versyms).
+    VersymsErr(VersymsError),
+    /// An error occurred creating the
+    /// [Verdefs](crate::version::Verdefs).
+    VerdefsErr(VerdefsError),
+    /// An error occurred creating the
+    /// [Verneeds](crate::version::Verneeds).
+    VerneedsErr(VerneedsError),
+    /// An error occurred creating the
+    /// [AddrArray](crate::array::AddrArray).
+    AddrArrayErr(AddrArrayError),
+    /// An error occurred creating the [Group](crate::group::Group).
+    GroupErr(GroupError),
+    /// An error occurred creating the
+    /// [SymtabShndx](crate::shndx::SymtabShndx).
+    SymtabShndxErr(SymtabShndxError),
+    /// An error occurred creating the [Relrs](crate::reloc::Relrs).
+    RelrsErr(RelrsError),
+    /// An error occurred creating the
+    /// [AndroidRels](crate::reloc::android::AndroidRels) or
+    /// [AndroidRelas](crate::reloc::android::AndroidRelas).
+    AndroidRelocsErr(AndroidRelocsError),
     /// An error occurred creating the [Notes](crate::note::Notes).
     NoteErr(NotesError),
 }
@@ -1078,15 +1609,97 @@ pub struct SymsStrs<Syms, Strs> {
     pub strs: Strs
 }
 
-fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
-                                                    SectionHdrError<Offsets>>
+impl SectionFlag {
+    /// Get the bit in a section header flags field corresponding to
+    /// this flag.
+    #[inline]
+    pub fn mask(&self) -> u32 {
+        match self {
+            SectionFlag::Write => SHF_WRITE,
+            SectionFlag::Alloc => SHF_ALLOC,
+            SectionFlag::Exec => SHF_EXECINSTR,
+            SectionFlag::Merge => SHF_MERGE,
+            SectionFlag::Strings => SHF_STRINGS,
+            SectionFlag::InfoLink => SHF_INFO_LINK,
+            SectionFlag::LinkOrder => SHF_LINK_ORDER,
+            SectionFlag::OSNonConforming => SHF_OS_NONCONFORMING,
+            SectionFlag::Group => SHF_GROUP,
+            SectionFlag::TLS => SHF_TLS,
+            SectionFlag::Compressed => SHF_COMPRESSED,
+            SectionFlag::GNURetain => SHF_GNU_RETAIN,
+            SectionFlag::Exclude => SHF_EXCLUDE
+        }
+    }
+}
+
+impl<Class: ElfClass> SectionFlags<Class> {
+    /// Check whether `flag` is set.
+    #[inline]
+    pub fn contains(&self, flag: SectionFlag) -> bool {
+        let mask: Class::Offset = flag.mask().into();
+
+        self.bits & mask == mask
+    }
+
+    /// Set `flag`.
+    #[inline]
+    pub fn insert(&mut self, flag: SectionFlag) {
+        self.bits = self.bits | flag.mask().into();
+    }
+
+    /// Clear `flag`.
+    #[inline]
+    pub fn remove(&mut self, flag: SectionFlag) {
+        self.bits = self.bits & !Class::Offset::from(flag.mask());
+    }
+
+    /// Get the OS-specific bits (`SHF_MASKOS`).
+    #[inline]
+    pub fn os(&self) -> Class::Offset {
+        self.bits & SHF_MASKOS.into()
+    }
+
+    /// Get the processor-specific bits (`SHF_MASKPROC`).
+    #[inline]
+    pub fn proc(&self) -> Class::Offset {
+        self.bits & SHF_MASKPROC.into()
+    }
+
+    /// Get any set bits that do not correspond to a [SectionFlag].
+    #[inline]
+    pub fn unknown(&self) -> Class::Offset {
+        let known = SECTION_FLAGS.iter().fold(0, |acc, flag| acc | flag.mask());
+
+        self.bits & !Class::Offset::from(known)
+    }
+}
+
+fn project<'a, B, Offsets>(ent: &'a [u8], first: bool) ->
+    Result<SectionHdrDataRaw<Offsets>, SectionHdrError<Offsets>>
     where Offsets: SectionHdrOffsets,
           B: ByteOrder {
     let kind = Offsets::read_word::<B>(&ent[Offsets::SH_KIND_START ..
                                             Offsets::SH_KIND_END]);
 
     match kind.into() {
-        0 => Ok(SectionHdrData::Null),
+        0 if !first => Ok(SectionHdrData::Null),
+        0 => {
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let link = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                    Offsets::SH_LINK_END]);
+            let info = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                    Offsets::SH_INFO_END]);
+
+            if size == (0 as u8).into() && link == (0 as u8).into() &&
+               info == (0 as u8).into() {
+                Ok(SectionHdrData::Null)
+            } else {
+                Ok(SectionHdrData::ExtNull { num_section_hdrs: size,
+                                             section_hdr_strtab: link,
+                                             num_prog_hdrs: info })
+            }
+        },
         1 => {
             let offset = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
@@ -1100,17 +1713,19 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             let align = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
-            let flags = Offsets::read_offset::<B>(
-                &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END],
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let ent_size = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
             );
             let pos = SectionPos { offset: offset, size: size };
-            let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-            let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-            let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
             Ok(SectionHdrData::ProgBits { name: name, addr: addr, align: align,
-                                          data: pos, alloc: alloc, write: write,
-                                          exec: exec })
+                                          data: pos, ent_size: ent_size,
+                                          flags: flags })
         },
         2 => {
             let offset = Offsets::read_offset::<B>(
@@ -1119,7 +1734,7 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                       Offsets::SH_SIZE_END],
             );
-            let ent_size = Offsets::read_word::<B>(
+            let ent_size = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
             );
             let strtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
@@ -1136,22 +1751,21 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
                     let align = Offsets::read_offset::<B>(
                         &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END]
                     );
-                    let flags = Offsets::read_offset::<B>(
-                        &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
-                    );
+                    let flags = SectionFlags {
+                        bits: Offsets::read_offset::<B>(
+                            &ent[Offsets::SH_FLAGS_START ..
+                                 Offsets::SH_FLAGS_END]
+                        )
+                    };
                     let info = Offsets::read_word::<B>(
                         &ent[Offsets::SH_INFO_START .. Offsets::SH_INFO_END],
                     );
                     let pos = SectionPos { offset: offset, size: size };
-                    let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-                    let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-                    let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
                     Ok(SectionHdrData::Symtab { name: name, local_end: info,
                                                 addr: addr, strtab: strtab,
                                                 align: align, syms: pos,
-                                                write: write, alloc: alloc,
-                                                exec: exec })
+                                                flags: flags })
                 },
                 _ => Err(SectionHdrError::BadEntSize {
                     expected: Offsets::ST_ENT_SIZE, actual: ent_size
@@ -1171,10 +1785,15 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             let align = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
             let pos = SectionPos { offset: offset, size: size };
 
-            Ok(SectionHdrData::Strtab { name: name, addr: addr,
-                                        align: align, strs: pos })
+            Ok(SectionHdrData::Strtab { name: name, addr: addr, align: align,
+                                        strs: pos, flags: flags })
         },
         4 => {
             let offset = Offsets::read_offset::<B>(
@@ -1182,7 +1801,7 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             );
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                       Offsets::SH_SIZE_END]);
-            let ent_size = Offsets::read_word::<B>(
+            let ent_size = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
             );
             let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
@@ -1201,19 +1820,18 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
                     let align = Offsets::read_offset::<B>(
                         &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END]
                      );
-                    let flags = Offsets::read_offset::<B>(
-                        &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
-                    );
+                    let flags = SectionFlags {
+                        bits: Offsets::read_offset::<B>(
+                            &ent[Offsets::SH_FLAGS_START ..
+                                 Offsets::SH_FLAGS_END]
+                        )
+                    };
                     let pos = SectionPos { offset: offset, size: size };
-                    let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-                    let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-                    let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
                     Ok(SectionHdrData::Rela { name: name, addr: addr,
                                               align: align, target: target,
                                               symtab: symtab, relas: pos,
-                                              write: write, alloc: alloc,
-                                              exec: exec })
+                                              flags: flags })
                 },
                 _ => Err(SectionHdrError::BadEntSize {
                     expected: Offsets::RELA_SIZE, actual: ent_size
@@ -1235,17 +1853,15 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             let align = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
-            let flags = Offsets::read_offset::<B>(
-                &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END],
-            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
             let pos = SectionPos { offset: offset, size: size };
-            let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-            let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-            let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
             Ok(SectionHdrData::Hash { name: name, addr: addr, align: align,
-                                      hash: pos, symtab: symtab, write: write,
-                                      alloc: alloc, exec: exec })
+                                      hash: pos, symtab: symtab, flags: flags })
         },
         6 => {
             let offset = Offsets::read_offset::<B>(
@@ -1253,7 +1869,7 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             );
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                  Offsets::SH_SIZE_END]);
-            let ent_size = Offsets::read_word::<B>(
+            let ent_size = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
             );
             let strtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
@@ -1270,18 +1886,17 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
                     let align = Offsets::read_offset::<B>(
                         &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END]
                     );
-                    let flags = Offsets::read_offset::<B>(
-                        &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
-                    );
+                    let flags = SectionFlags {
+                        bits: Offsets::read_offset::<B>(
+                            &ent[Offsets::SH_FLAGS_START ..
+                                 Offsets::SH_FLAGS_END]
+                        )
+                    };
                     let pos = SectionPos { offset: offset, size: size };
-                    let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-                    let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-                    let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
                     Ok(SectionHdrData::Dynamic { name: name, align: align,
                                                  addr: addr, strtab: strtab,
-                                                 dynamic: pos, write: write,
-                                                 alloc: alloc, exec: exec })
+                                                 dynamic: pos, flags: flags })
                 },
                 _ => Err(SectionHdrError::BadEntSize {
                     expected: Offsets::DYNAMIC_SIZE, actual: ent_size
@@ -1301,17 +1916,15 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             let align = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
-            let flags = Offsets::read_offset::<B>(
-                &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END],
-            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
             let pos = SectionPos { offset: offset, size: size };
-            let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-            let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-            let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
             Ok(SectionHdrData::Note { name: name, addr: addr, align: align,
-                                      note: pos, write: write, alloc: alloc,
-                                      exec: exec })
+                                      note: pos, flags: flags })
         },
         8 => {
             let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
@@ -1321,21 +1934,20 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             let align = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
-            let flags = Offsets::read_offset::<B>(
-                &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END],
-            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
             let offset = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
             );
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                  Offsets::SH_SIZE_END]);
-            let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-            let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-            let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
             Ok(SectionHdrData::Nobits { name: name, addr: addr, align: align,
-                                        size: size, offset: offset, exec: exec,
-                                        write: write, alloc: alloc })
+                                        size: size, offset: offset,
+                                        flags: flags })
         },
         9 => {
             let offset = Offsets::read_offset::<B>(
@@ -1343,7 +1955,7 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             );
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                       Offsets::SH_SIZE_END]);
-            let ent_size = Offsets::read_word::<B>(
+            let ent_size = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END]
             );
             let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
@@ -1362,19 +1974,18 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
                     let align = Offsets::read_offset::<B>(
                         &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END]
                     );
-                    let flags = Offsets::read_offset::<B>(
-                        &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
-                    );
+                    let flags = SectionFlags {
+                        bits: Offsets::read_offset::<B>(
+                            &ent[Offsets::SH_FLAGS_START ..
+                                 Offsets::SH_FLAGS_END]
+                        )
+                    };
                     let pos = SectionPos { offset: offset, size: size };
-                    let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-                    let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-                    let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
                     Ok(SectionHdrData::Rel { name: name, addr: addr,
                                              align: align, target: target,
                                              symtab: symtab, rels: pos,
-                                             write: write, alloc: alloc,
-                                             exec: exec })
+                                             flags: flags })
                 },
                 _ => Err(SectionHdrError::BadEntSize {
                     expected: Offsets::REL_SIZE, actual: ent_size
@@ -1387,7 +1998,7 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
             );
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                       Offsets::SH_SIZE_END]);
-            let ent_size = Offsets::read_word::<B>(
+            let ent_size = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END]
             );
             let strtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
@@ -1404,671 +2015,1613 @@ fn project<'a, B, Offsets>(ent: &'a [u8]) -> Result<SectionHdrDataRaw<Offsets>,
                     let align = Offsets::read_offset::<B>(
                         &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END]
                     );
-                    let flags = Offsets::read_offset::<B>(
-                        &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
-                    );
+                    let flags = SectionFlags {
+                        bits: Offsets::read_offset::<B>(
+                            &ent[Offsets::SH_FLAGS_START ..
+                                 Offsets::SH_FLAGS_END]
+                        )
+                    };
                     let info = Offsets::read_word::<B>(
                         &ent[Offsets::SH_INFO_START .. Offsets::SH_INFO_END],
                     );
                     let pos = SectionPos { offset: offset, size: size };
-                    let exec = flags & (0x4 as u8).into() != (0 as u8).into();
-                    let alloc = flags & (0x2 as u8).into() != (0 as u8).into();
-                    let write = flags & (0x1 as u8).into() != (0 as u8).into();
 
                     Ok(SectionHdrData::Dynsym { name: name, local_end: info,
                                                 addr: addr, strtab: strtab,
                                                 align: align, syms: pos,
-                                                write: write, alloc: alloc,
-                                                exec: exec })
+                                                flags: flags })
                 },
                 _ => Err(SectionHdrError::BadEntSize {
                     expected: Offsets::ST_ENT_SIZE, actual: ent_size
                 })
             }
         },
-        _ => {
+        14 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
             let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
                                                     Offsets::SH_NAME_END]);
             let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
-                                               Offsets::SH_ADDR_END]);
+                                                    Offsets::SH_ADDR_END]);
             let align = Offsets::read_offset::<B>(
                 &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
-            let flags = Offsets::read_offset::<B>(
-                &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let ent_size = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
             );
-            let link = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
-                                                    Offsets::SH_LINK_END]);
-            let info = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
-                                                    Offsets::SH_INFO_END]);
+            let pos = SectionPos { offset: offset, size: size };
+
+            Ok(SectionHdrData::InitArray { name: name, addr: addr, align: align,
+                                           addrs: pos, ent_size: ent_size,
+                                           flags: flags })
+        },
+        15 => {
             let offset = Offsets::read_offset::<B>(
-                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END]
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
             );
             let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
                                                       Offsets::SH_SIZE_END]);
-            let ent_size = Offsets::read_word::<B>(
-                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END]
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
             );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let ent_size = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
+            );
+            let pos = SectionPos { offset: offset, size: size };
 
-            Ok(SectionHdrData::Unknown { name: name, tag: kind, addr: addr,
-                                         align: align, offset: offset,
-                                         size: size, link: link, info: info,
-                                         ent_size: ent_size, flags: flags })
-        }
-    }
-}
-
-fn create<'a, B, I, Offsets>(buf: &'a mut [u8], hdrs: I) ->
-    Result<(&'a mut [u8], &'a mut [u8]), ()>
-    where I: Iterator<Item = SectionHdrDataRaw<Offsets>>,
-          Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    let len = buf.len();
-    let mut idx = 0;
+            Ok(SectionHdrData::FiniArray { name: name, addr: addr, align: align,
+                                           addrs: pos, ent_size: ent_size,
+                                           flags: flags })
+        },
+        16 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let ent_size = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END],
+            );
+            let pos = SectionPos { offset: offset, size: size };
 
-    for hdr in hdrs {
-        if idx + Offsets::SECTION_HDR_SIZE <= len {
-            let ent = &mut buf[idx .. idx + Offsets::SECTION_HDR_SIZE];
+            Ok(SectionHdrData::PreInitArray { name: name, addr: addr,
+                                              align: align, addrs: pos,
+                                              ent_size: ent_size,
+                                              flags: flags })
+        },
+        17 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let sym = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                   Offsets::SH_INFO_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let pos = SectionPos { offset: offset, size: size };
 
-            match hdr {
-                SectionHdrData::Null => {
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                 Offsets::SH_NAME_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                 Offsets::SH_KIND_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                 Offsets::SH_ADDR_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        (0 as u8).into()
-                    );
-                },
-                SectionHdrData::ProgBits { name, addr, align, data, alloc,
-                                           write, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (1 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        data.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        data.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        (0 as u8).into()
-                    );
-                },
-                SectionHdrData::Symtab { name, local_end, addr, strtab, align,
-                                         syms, write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (2 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        syms.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        syms.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             strtab);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             local_end);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        Offsets::ST_ENT_SIZE_OFFSET,
-                    );
-                },
-                SectionHdrData::Strtab { name, addr, align, strs } => {
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (3 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        (0 as u8).into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        strs.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        strs.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        (0 as u8).into()
-                    );
-                },
-                SectionHdrData::Rela { name, addr, align, target, symtab,
-                                       relas, write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (4 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        relas.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        relas.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             symtab);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             target);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        Offsets::RELA_SIZE_OFFSET
-                    );
-                },
-                SectionHdrData::Hash { name, addr, align, hash, symtab,
-                                       write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (5 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        hash.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        hash.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             symtab);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_word::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        (0 as u8).into()
-                    );
-                },
-                SectionHdrData::Dynamic { name, align, addr, strtab, dynamic,
-                                          write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (6 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        dynamic.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        dynamic.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             strtab);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        Offsets::DYNAMIC_SIZE_OFFSET,
-                    );
-                },
-                SectionHdrData::Note { name, addr, align, note, write,
-                                       alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (7 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        note.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        note.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        (0 as u8).into()
-                    );
-                },
-                SectionHdrData::Nobits { name, addr, align, offset, size,
-                                         write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (8 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             (0 as u8).into());
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             (0 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        (0 as u8).into()
-                    );
-                },
-                SectionHdrData::Rel { name, addr, align, target, symtab,
-                                      rels, write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (9 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        rels.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        rels.size
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             symtab);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             target);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        Offsets::REL_SIZE_OFFSET
-                    );
-                },
-                SectionHdrData::Dynsym { name, local_end, addr, strtab, align,
-                                         syms, write, alloc, exec } => {
-                    let flags: u8 = if exec { 0x4 } else { 0 } |
-                                    if alloc { 0x2 } else { 0 } |
-                                    if write { 0x1 } else { 0 };
-
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             (11 as u8).into());
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags.into()
-                    );
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        syms.offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        syms.size
+            Ok(SectionHdrData::Group { name: name, addr: addr, align: align,
+                                       group: pos, symtab: symtab, sym: sym,
+                                       flags: flags })
+        },
+        18 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let pos = SectionPos { offset: offset, size: size };
+
+            Ok(SectionHdrData::SymtabShndx { name: name, addr: addr,
+                                             align: align, shndxs: pos,
+                                             symtab: symtab, flags: flags })
+        },
+        19 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let ent_size = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END]
+            );
+
+            // Relative relocation entries are always address-sized.
+            match ent_size.try_into() {
+                Ok(ent_size) if ent_size == Offsets::ADDR_SIZE => {
+                    let name = Offsets::read_word::<B>(
+                        &ent[Offsets::SH_NAME_START .. Offsets::SH_NAME_END],
                     );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             strtab);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             local_end);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
+                    let addr = Offsets::read_addr::<B>(
+                        &ent[Offsets::SH_ADDR_START .. Offsets::SH_ADDR_END],
                     );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        Offsets::ST_ENT_SIZE_OFFSET,
+                    let align = Offsets::read_offset::<B>(
+                        &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END]
                     );
+                    let flags = SectionFlags {
+                        bits: Offsets::read_offset::<B>(
+                            &ent[Offsets::SH_FLAGS_START ..
+                                 Offsets::SH_FLAGS_END]
+                        )
+                    };
+                    let pos = SectionPos { offset: offset, size: size };
+
+                    Ok(SectionHdrData::Relr { name: name, addr: addr,
+                                              align: align, relrs: pos,
+                                              flags: flags })
                 },
-                SectionHdrData::Unknown { name, tag, addr, align, offset, size,
-                                          link, info, ent_size, flags } => {
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
-                                                      Offsets::SH_NAME_END],
-                                             name);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
-                                                      Offsets::SH_KIND_END],
-                                             tag);
-                    Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
-                                                      Offsets::SH_ADDR_END],
-                                             addr);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_ALIGN_START ..
-                                 Offsets::SH_ALIGN_END],
-                        align
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_FLAGS_START ..
-                                 Offsets::SH_FLAGS_END],
-                        flags
-                    );
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
-                                                      Offsets::SH_LINK_END],
-                                             link);
-                    Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
-                                                      Offsets::SH_INFO_END],
-                                             info);
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_OFFSET_START ..
-                                 Offsets::SH_OFFSET_END],
-                        offset
-                    );
-                    Offsets::write_offset::<B>(
-                        &mut ent[Offsets::SH_SIZE_START ..
-                                 Offsets::SH_SIZE_END],
-                        size
-                    );
-                    Offsets::write_word::<B>(
-                        &mut ent[Offsets::SH_ENT_SIZE_START ..
-                                 Offsets::SH_ENT_SIZE_END],
-                        ent_size
-                    );
-                }
+                _ => Err(SectionHdrError::BadEntSize {
+                    expected: Offsets::ADDR_SIZE, actual: ent_size
+                })
             }
+        },
+        0x60000001 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let target = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                      Offsets::SH_INFO_END]);
+            let pos = SectionPos { offset: offset, size: size };
 
-            idx += Offsets::SECTION_HDR_SIZE;
-        } else {
-            return Err(())
-        }
-    }
-
-    Ok(buf.split_at_mut(idx))
-}
-
-impl SectionHdrOffsets for Elf32 {
-    const SECTION_HDR_SIZE_HALF: Self::Half = Self::SECTION_HDR_SIZE as u16;
-}
-
-impl SectionHdrOffsets for Elf64 {
-    const SECTION_HDR_SIZE_HALF: Self::Half = Self::SECTION_HDR_SIZE as u16;
-}
-
-fn get_target<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
-                              idx: Offsets::Word) ->
-    Result<SectionHdr<'a, B, Offsets>, SectionHdrDataError<Offsets>>
-    where Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    match idx.try_into() {
-        Ok(target) => {
-            let target_start = target * Offsets::SECTION_HDR_SIZE;
-            let target_end = target_start + Offsets::SECTION_HDR_SIZE;
+            Ok(SectionHdrData::AndroidRel { name: name, addr: addr,
+                                            align: align, target: target,
+                                            symtab: symtab, rels: pos,
+                                            flags: flags })
+        },
+        0x60000002 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let target = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                      Offsets::SH_INFO_END]);
+            let pos = SectionPos { offset: offset, size: size };
 
-            if target_end > hdrs.hdrs.len() {
-                Err(SectionHdrDataError::IdxOutOfBounds(idx))
-            } else {
-                let data = &hdrs.hdrs[target_start .. target_end];
+            Ok(SectionHdrData::AndroidRela { name: name, addr: addr,
+                                             align: align, target: target,
+                                             symtab: symtab, relas: pos,
+                                             flags: flags })
+        },
+        0x6ffffff6 => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let pos = SectionPos { offset: offset, size: size };
 
-                Ok(SectionHdr { byteorder: PhantomData, offsets: PhantomData,
-                                ent: data })
-            }
+            Ok(SectionHdrData::GnuHash { name: name, addr: addr, align: align,
+                                         hash: pos, symtab: symtab,
+                                         flags: flags })
         },
-        _ => Err(SectionHdrDataError::IdxOutOfBounds(idx))
-    }
-}
+        0x6ffffffd => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let strtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let count = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                     Offsets::SH_INFO_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let pos = SectionPos { offset: offset, size: size };
 
-fn get_strtab<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
-                              idx: Offsets::Word) ->
-    Result<SectionHdr<'a, B, Offsets>, SectionHdrDataError<Offsets>>
-    where Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    match idx.try_into() {
+            Ok(SectionHdrData::Verdef { name: name, addr: addr, align: align,
+                                        verdefs: pos, strtab: strtab,
+                                        count: count, flags: flags })
+        },
+        0x6ffffffe => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let strtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let count = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                     Offsets::SH_INFO_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let pos = SectionPos { offset: offset, size: size };
+
+            Ok(SectionHdrData::Verneed { name: name, addr: addr, align: align,
+                                         verneeds: pos, strtab: strtab,
+                                         count: count, flags: flags })
+        },
+        0x6fffffff => {
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END],
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let symtab = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                      Offsets::SH_LINK_END]);
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                                    Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let pos = SectionPos { offset: offset, size: size };
+
+            Ok(SectionHdrData::Versym { name: name, addr: addr, align: align,
+                                        versyms: pos, symtab: symtab,
+                                        flags: flags })
+        },
+        _ => {
+            let name = Offsets::read_word::<B>(&ent[Offsets::SH_NAME_START ..
+                                                    Offsets::SH_NAME_END]);
+            let addr = Offsets::read_addr::<B>(&ent[Offsets::SH_ADDR_START ..
+                                               Offsets::SH_ADDR_END]);
+            let align = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ALIGN_START .. Offsets::SH_ALIGN_END],
+            );
+            let flags = SectionFlags {
+                bits: Offsets::read_offset::<B>(
+                    &ent[Offsets::SH_FLAGS_START .. Offsets::SH_FLAGS_END]
+                )
+            };
+            let link = Offsets::read_word::<B>(&ent[Offsets::SH_LINK_START ..
+                                                    Offsets::SH_LINK_END]);
+            let info = Offsets::read_word::<B>(&ent[Offsets::SH_INFO_START ..
+                                                    Offsets::SH_INFO_END]);
+            let offset = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_OFFSET_START .. Offsets::SH_OFFSET_END]
+            );
+            let size = Offsets::read_offset::<B>(&ent[Offsets::SH_SIZE_START ..
+                                                      Offsets::SH_SIZE_END]);
+            let ent_size = Offsets::read_offset::<B>(
+                &ent[Offsets::SH_ENT_SIZE_START .. Offsets::SH_ENT_SIZE_END]
+            );
+
+            Ok(SectionHdrData::Unknown { name: name, tag: kind, addr: addr,
+                                         align: align, offset: offset,
+                                         size: size, link: link, info: info,
+                                         ent_size: ent_size, flags: flags })
+        }
+    }
+}
+
+#[inline]
+fn write<B, Offsets>(ent: &mut [u8], hdr: SectionHdrDataRaw<Offsets>)
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    match hdr {
+        SectionHdrData::Null => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                         Offsets::SH_NAME_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                         Offsets::SH_KIND_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                (0 as u8).into()
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                         Offsets::SH_ADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                (0 as u8).into()
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                  num_prog_hdrs } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                         Offsets::SH_NAME_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                         Offsets::SH_KIND_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                (0 as u8).into()
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                         Offsets::SH_ADDR_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                num_section_hdrs
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     section_hdr_strtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     num_prog_hdrs);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                (0 as u8).into()
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::ProgBits { name, addr, align, data, ent_size,
+                                   flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (1 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                data.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                data.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                ent_size
+            );
+        },
+        SectionHdrData::Symtab { name, local_end, addr, strtab, align,
+                                 syms, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (2 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                syms.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                syms.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     strtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     local_end);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                Offsets::ST_ENT_SIZE_OFFSET,
+            );
+        },
+        SectionHdrData::Strtab { name, addr, align, strs, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (3 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                strs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                strs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Rela { name, addr, align, target, symtab, relas,
+                               flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (4 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                relas.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                relas.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     target);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                Offsets::RELA_SIZE_OFFSET
+            );
+        },
+        SectionHdrData::Hash { name, addr, align, hash, symtab,
+                               flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (5 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                hash.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                hash.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Dynamic { name, align, addr, strtab, dynamic,
+                                  flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (6 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                dynamic.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                dynamic.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     strtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                Offsets::DYNAMIC_SIZE_OFFSET,
+            );
+        },
+        SectionHdrData::Note { name, addr, align, note, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (7 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                note.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                note.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Nobits { name, addr, align, offset, size,
+                                 flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (8 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Rel { name, addr, align, target, symtab, rels,
+                              flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (9 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                rels.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                rels.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     target);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                Offsets::REL_SIZE_OFFSET
+            );
+        },
+        SectionHdrData::Dynsym { name, local_end, addr, strtab, align,
+                                 syms, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (11 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                syms.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                syms.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     strtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     local_end);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                Offsets::ST_ENT_SIZE_OFFSET,
+            );
+        },
+        SectionHdrData::InitArray { name, addr, align, addrs,
+                                    ent_size, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (14 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                addrs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                addrs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                ent_size
+            );
+        },
+        SectionHdrData::FiniArray { name, addr, align, addrs,
+                                    ent_size, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (15 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                addrs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                addrs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                ent_size
+            );
+        },
+        SectionHdrData::PreInitArray { name, addr, align, addrs,
+                                       ent_size, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (16 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                addrs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                addrs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                ent_size
+            );
+        },
+        SectionHdrData::Group { name, addr, align, group, symtab, sym,
+                                flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (17 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                group.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                group.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     sym);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (4 as u8).into()
+            );
+        },
+        SectionHdrData::SymtabShndx { name, addr, align, shndxs, symtab,
+                                      flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (18 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                shndxs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                shndxs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (4 as u8).into()
+            );
+        },
+        SectionHdrData::Relr { name, addr, align, relrs, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (19 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                relrs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                relrs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     (0 as u8).into());
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (Offsets::ADDR_SIZE as u8).into()
+            );
+        },
+        SectionHdrData::AndroidRel { name, addr, align, target, symtab, rels,
+                                     flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (0x60000001 as u32).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                rels.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                rels.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     target);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (1 as u8).into()
+            );
+        },
+        SectionHdrData::AndroidRela { name, addr, align, target, symtab, relas,
+                                      flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (0x60000002 as u32).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                relas.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                relas.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     target);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (1 as u8).into()
+            );
+        },
+        SectionHdrData::GnuHash { name, addr, align, hash, symtab,
+                                  flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (0x6ffffff6 as u32).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                hash.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                hash.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Versym { name, addr, align, versyms, symtab,
+                                 flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (0x6fffffff as u32).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                versyms.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                versyms.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     symtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     (0 as u8).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (2 as u8).into()
+            );
+        },
+        SectionHdrData::Verdef { name, addr, align, verdefs, strtab,
+                                 count, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (0x6ffffffd as u32).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                verdefs.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                verdefs.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     strtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     count);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Verneed { name, addr, align, verneeds, strtab,
+                                  count, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     (0x6ffffffe as u32).into());
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                verneeds.offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                verneeds.size
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     strtab);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     count);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                (0 as u8).into()
+            );
+        },
+        SectionHdrData::Unknown { name, tag, addr, align, offset, size,
+                                  link, info, ent_size, flags } => {
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_NAME_START ..
+                                              Offsets::SH_NAME_END],
+                                     name);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_KIND_START ..
+                                              Offsets::SH_KIND_END],
+                                     tag);
+            Offsets::write_addr::<B>(&mut ent[Offsets::SH_ADDR_START ..
+                                              Offsets::SH_ADDR_END],
+                                     addr);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ALIGN_START ..
+                         Offsets::SH_ALIGN_END],
+                align
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_FLAGS_START ..
+                         Offsets::SH_FLAGS_END],
+                flags.bits
+            );
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                              Offsets::SH_LINK_END],
+                                     link);
+            Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                              Offsets::SH_INFO_END],
+                                     info);
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_OFFSET_START ..
+                         Offsets::SH_OFFSET_END],
+                offset
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_SIZE_START ..
+                         Offsets::SH_SIZE_END],
+                size
+            );
+            Offsets::write_offset::<B>(
+                &mut ent[Offsets::SH_ENT_SIZE_START ..
+                         Offsets::SH_ENT_SIZE_END],
+                ent_size
+            );
+        }
+    }
+}
+
+fn create<'a, B, I, Offsets>(buf: &'a mut [u8], hdrs: I) ->
+    Result<(&'a mut [u8], &'a mut [u8]), ()>
+    where I: Iterator<Item = SectionHdrDataRaw<Offsets>>,
+          Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    let len = buf.len();
+    let mut idx = 0;
+
+    for hdr in hdrs {
+        if idx + Offsets::SECTION_HDR_SIZE <= len {
+            let ent = &mut buf[idx .. idx + Offsets::SECTION_HDR_SIZE];
+
+            write::<B, Offsets>(ent, hdr);
+
+            idx += Offsets::SECTION_HDR_SIZE;
+        } else {
+            return Err(())
+        }
+    }
+
+    Ok(buf.split_at_mut(idx))
+}
+
+// Store any of the number of section headers, the section header
+// string table index, or the number of program headers that is too
+// large for the ELF header in the first section header.
+fn escape_ext<B, Offsets>(hdrs: &mut [u8], strtab: usize,
+                          num_prog_hdrs: usize) -> Result<(), ()>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    let num_hdrs = hdrs.len() / Offsets::SECTION_HDR_SIZE;
+
+    if num_hdrs < SHN_LORESERVE && strtab < SHN_LORESERVE &&
+       num_prog_hdrs < PN_XNUM as usize {
+        return Ok(())
+    }
+
+    if num_hdrs == 0 {
+        return Err(())
+    }
+
+    let ent = &mut hdrs[0 .. Offsets::SECTION_HDR_SIZE];
+    let kind = Offsets::read_word::<B>(&ent[Offsets::SH_KIND_START ..
+                                            Offsets::SH_KIND_END]);
+
+    if kind.into() != 0 {
+        return Err(())
+    }
+
+    if num_hdrs >= SHN_LORESERVE {
+        let size = Offsets::Offset::try_from(num_hdrs).map_err(|_| ())?;
+
+        Offsets::write_offset::<B>(&mut ent[Offsets::SH_SIZE_START ..
+                                            Offsets::SH_SIZE_END], size);
+    }
+
+    if strtab >= SHN_LORESERVE {
+        let link = Offsets::Word::try_from(strtab).map_err(|_| ())?;
+
+        Offsets::write_word::<B>(&mut ent[Offsets::SH_LINK_START ..
+                                          Offsets::SH_LINK_END], link);
+    }
+
+    if num_prog_hdrs >= PN_XNUM as usize {
+        let info = Offsets::Word::try_from(num_prog_hdrs).map_err(|_| ())?;
+
+        Offsets::write_word::<B>(&mut ent[Offsets::SH_INFO_START ..
+                                          Offsets::SH_INFO_END], info);
+    }
+
+    Ok(())
+}
+
+impl SectionHdrOffsets for Elf32 {
+    const SECTION_HDR_SIZE_HALF: Self::Half = Self::SECTION_HDR_SIZE as u16;
+}
+
+impl SectionHdrOffsets for Elf64 {
+    const SECTION_HDR_SIZE_HALF: Self::Half = Self::SECTION_HDR_SIZE as u16;
+}
+
+impl<Relr, AndroidRel, AndroidRela> PackedRelocTypes
+    for PackedRelocs<Relr, AndroidRel, AndroidRela> {
+    type Relr = Relr;
+    type AndroidRel = AndroidRel;
+    type AndroidRela = AndroidRela;
+}
+
+impl<Versym, Verdef, Verneed> VersionTableTypes
+    for VersionTables<Versym, Verdef, Verneed> {
+    type Versym = Versym;
+    type Verdef = Verdef;
+    type Verneed = Verneed;
+}
+
+impl<GnuHash, Array, Group, Shndx> AuxSectionTypes
+    for AuxSections<GnuHash, Array, Group, Shndx> {
+    type GnuHash = GnuHash;
+    type Array = Array;
+    type Group = Group;
+    type Shndx = Shndx;
+}
+
+fn get_target<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
+                              idx: Offsets::Word) ->
+    Result<SectionHdr<'a, B, Offsets>, SectionHdrDataError<Offsets>>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    match idx.try_into() {
+        Ok(target) => {
+            let target_start = target * Offsets::SECTION_HDR_SIZE;
+            let target_end = target_start + Offsets::SECTION_HDR_SIZE;
+
+            if target_end > hdrs.hdrs.len() {
+                Err(SectionHdrDataError::IdxOutOfBounds(idx))
+            } else {
+                let data = &hdrs.hdrs[target_start .. target_end];
+
+                Ok(SectionHdr { byteorder: PhantomData, offsets: PhantomData,
+                                ent: data, first: target == 0 })
+            }
+        },
+        _ => Err(SectionHdrDataError::IdxOutOfBounds(idx))
+    }
+}
+
+fn get_strtab<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
+                              idx: Offsets::Word) ->
+    Result<SectionHdr<'a, B, Offsets>, SectionHdrDataError<Offsets>>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    match idx.try_into() {
         Ok(strtab) => {
             let strtab_start = strtab * Offsets::SECTION_HDR_SIZE;
             let strtab_end = strtab_start + Offsets::SECTION_HDR_SIZE;
@@ -2079,7 +3632,7 @@ fn get_strtab<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
                 let data = &hdrs.hdrs[strtab_start .. strtab_end];
                 let hdr = SectionHdr { byteorder: PhantomData,
                                        offsets: PhantomData,
-                                       ent: data };
+                                       ent: data, first: strtab == 0 };
 
                 match hdr.try_into() {
                     Ok(SectionHdrData::Strtab { .. }) => Ok(hdr),
@@ -2109,7 +3662,8 @@ fn get_symtab<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
                 let symtab_data = &hdrs.hdrs[symtab_start .. symtab_end];
                 let symtab = SectionHdr { byteorder: PhantomData,
                                           offsets: PhantomData,
-                                          ent: symtab_data };
+                                          ent: symtab_data,
+                                          first: symtab == 0 };
 
                 match symtab.try_into() {
                     Ok(SectionHdrData::Symtab { strtab, .. }) =>
@@ -2136,12 +3690,17 @@ fn get_symtab<'a, B, Offsets>(hdrs: SectionHdrs<'a, B, Offsets>,
 
 }
 
-impl<'a, B, Offsets, Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
+impl<'a, B, Offsets, Syms, Strs, Rels, Relas, Hash, Dynamic, Note,
+     Packed, Aux, Versions>
     WithSectionHdrs<'a, B, Offsets>
     for SectionHdrData<Offsets, Offsets::Word, Offsets::Word, Offsets::Word,
                        Offsets::Word, SectionPos<Offsets::Offset>,
-                       Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
+                       Syms, Strs, Rels, Relas, Hash, Dynamic, Note,
+                       Packed, Aux, Versions>
     where Offsets: SectionHdrOffsets,
+          Packed: PackedRelocTypes,
+          Aux: AuxSectionTypes,
+          Versions: VersionTableTypes,
           B: ByteOrder {
     type Result = SectionHdrData<Offsets, Offsets::Word,
                                  SectionHdr<'a, B, Offsets>,
@@ -2150,7 +3709,8 @@ impl<'a, B, Offsets, Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
                                  SectionHdr<'a, B, Offsets>,
                                  SectionPos<Offsets::Offset>,
                                  Syms, Strs, Rels, Relas,
-                                 Hash, Dynamic, Note>;
+                                 Hash, Dynamic, Note, Packed, Aux,
+                                 Versions>;
     type Error = SectionHdrDataError<Offsets>;
 
     #[inline]
@@ -2158,108 +3718,241 @@ impl<'a, B, Offsets, Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
         Result<Self::Result, Self::Error> {
         match self {
             SectionHdrData::Null => Ok(SectionHdrData::Null),
-            SectionHdrData::ProgBits { name, addr, align, alloc, write,
-                                       exec, data } => {
+            SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                      num_prog_hdrs } =>
+                Ok(SectionHdrData::ExtNull {
+                    num_section_hdrs: num_section_hdrs,
+                    section_hdr_strtab: section_hdr_strtab,
+                    num_prog_hdrs: num_prog_hdrs
+                }),
+            SectionHdrData::ProgBits { name, addr, align, ent_size, flags,
+                                       data } => {
                 Ok(SectionHdrData::ProgBits {
                     name: name, addr: addr, align: align, data: data,
-                    alloc: alloc, write: write, exec: exec
+                    ent_size: ent_size, flags: flags
                 })
             },
-            SectionHdrData::Symtab { name, local_end, addr, align, syms, write,
-                                     alloc, exec, strtab: strtab_idx } => {
+            SectionHdrData::Symtab { name, local_end, addr, align, syms, flags,
+                                     strtab: strtab_idx } => {
                 match get_strtab(section_hdrs, strtab_idx) {
                     Ok(strtab) => {
                         Ok(SectionHdrData::Symtab {
-                            name: name, addr: addr, syms: syms,
-                            strtab: strtab, align: align, write: write,
-                            alloc: alloc, exec: exec, local_end: local_end
+                            name: name, addr: addr, syms: syms, strtab: strtab,
+                            align: align, flags: flags, local_end: local_end
+                        })
+                    },
+                    Err(err) => Err(err)
+                }
+            },
+            SectionHdrData::Strtab { name, addr, align, strs, flags } => {
+                Ok(SectionHdrData::Strtab {
+                    name: name, addr: addr, align: align, strs: strs,
+                    flags: flags
+                })
+            },
+            SectionHdrData::Rela { name, addr, align, relas, flags,
+                                   target: target_idx, symtab: symtab_idx } => {
+                match (get_symtab(section_hdrs, symtab_idx),
+                       get_target(section_hdrs, target_idx)) {
+                    (Ok(symtab), Ok(target)) => {
+                        Ok(SectionHdrData::Rela {
+                            name: name, align: align, addr: addr,
+                            target: target, symtab: symtab, relas: relas,
+                            flags: flags
+                        })
+                    },
+                    (Ok(_), Err(err)) => Err(err),
+                    (Err(err), _) => Err(err)
+                }
+            },
+            SectionHdrData::Hash { name, addr, align, hash, flags,
+                                   symtab: symtab_idx } => {
+                match get_symtab(section_hdrs, symtab_idx) {
+                    Ok(symtab) => {
+                        Ok(SectionHdrData::Hash {
+                            name: name, addr: addr, align: align, hash: hash,
+                            flags: flags, symtab: symtab
+                        })
+                    },
+                    Err(err) => Err(err)
+                }
+            },
+            SectionHdrData::Dynamic { name, align, addr, dynamic, flags,
+                                      strtab: strtab_idx } => {
+                match get_strtab(section_hdrs, strtab_idx) {
+                    Ok(strtab) => {
+                        Ok(SectionHdrData::Dynamic {
+                            name: name, align: align, addr: addr,
+                            strtab: strtab, dynamic: dynamic, flags: flags
+                        })
+                    },
+                    Err(err) => Err(err)
+                }
+            },
+            SectionHdrData::Note { name, addr, align, note, flags } => {
+                Ok(SectionHdrData::Note { name: name, addr: addr, align: align,
+                                          note: note, flags: flags })
+            },
+            SectionHdrData::Nobits { name, addr, align, offset, size,
+                                     flags } => {
+                Ok(SectionHdrData::Nobits { name: name, addr: addr, size: size,
+                                            offset: offset, align: align,
+                                            flags: flags })
+            },
+            SectionHdrData::Rel { name, addr, align, rels, flags,
+                                  symtab: symtab_idx, target: target_idx } => {
+                match (get_symtab(section_hdrs, symtab_idx),
+                       get_target(section_hdrs, target_idx)) {
+                    (Ok(symtab), Ok(target)) => {
+                        Ok(SectionHdrData::Rel {
+                            name: name, align: align, addr: addr,
+                            target: target, symtab: symtab, rels: rels,
+                            flags: flags
+                        })
+                    },
+                    (Ok(_), Err(err)) => Err(err),
+                    (Err(err), _) => Err(err)
+                }
+            },
+            SectionHdrData::Dynsym { name, local_end, addr, align, syms, flags,
+                                     strtab: strtab_idx } => {
+                match get_strtab(section_hdrs, strtab_idx) {
+                    Ok(strtab) => {
+                        Ok(SectionHdrData::Dynsym {
+                            name: name, addr: addr, syms: syms, strtab: strtab,
+                            align: align, flags: flags, local_end: local_end
                         })
                     },
                     Err(err) => Err(err)
                 }
             },
-            SectionHdrData::Strtab { name, addr, align, strs } => {
-                Ok(SectionHdrData::Strtab {
-                    name: name, addr: addr, align: align, strs: strs
-                })
-            },
-            SectionHdrData::Rela { name, addr, align, relas, write, alloc, exec,
-                                   target: target_idx, symtab: symtab_idx} => {
+            SectionHdrData::InitArray { name, addr, align, addrs, ent_size,
+                                        flags } => {
+                Ok(SectionHdrData::InitArray { name: name, addr: addr,
+                                               align: align, addrs: addrs,
+                                               ent_size: ent_size,
+                                               flags: flags })
+            },
+            SectionHdrData::FiniArray { name, addr, align, addrs, ent_size,
+                                        flags } => {
+                Ok(SectionHdrData::FiniArray { name: name, addr: addr,
+                                               align: align, addrs: addrs,
+                                               ent_size: ent_size,
+                                               flags: flags })
+            },
+            SectionHdrData::PreInitArray { name, addr, align, addrs,
+                                           ent_size, flags } => {
+                Ok(SectionHdrData::PreInitArray { name: name, addr: addr,
+                                                  align: align, addrs: addrs,
+                                                  ent_size: ent_size,
+                                                  flags: flags })
+            },
+            SectionHdrData::Group { name, addr, align, group, sym, flags,
+                                    symtab: symtab_idx } => {
+                match get_symtab(section_hdrs, symtab_idx) {
+                    Ok(symtab) => {
+                        Ok(SectionHdrData::Group {
+                            name: name, addr: addr, align: align, group: group,
+                            flags: flags, symtab: symtab, sym: sym
+                        })
+                    },
+                    Err(err) => Err(err)
+                }
+            },
+            SectionHdrData::SymtabShndx { name, addr, align, shndxs, flags,
+                                          symtab: symtab_idx } => {
+                match get_symtab(section_hdrs, symtab_idx) {
+                    Ok(symtab) => {
+                        Ok(SectionHdrData::SymtabShndx {
+                            name: name, addr: addr, align: align,
+                            shndxs: shndxs, flags: flags, symtab: symtab
+                        })
+                    },
+                    Err(err) => Err(err)
+                }
+            },
+            SectionHdrData::Relr { name, addr, align, relrs, flags } => {
+                Ok(SectionHdrData::Relr { name: name, addr: addr,
+                                          align: align, relrs: relrs,
+                                          flags: flags })
+            },
+            SectionHdrData::AndroidRel { name, addr, align, rels, flags,
+                                         symtab: symtab_idx,
+                                         target: target_idx } => {
+                match (get_symtab(section_hdrs, symtab_idx),
+                       get_target(section_hdrs, target_idx)) {
+                    (Ok(symtab), Ok(target)) => {
+                        Ok(SectionHdrData::AndroidRel {
+                            name: name, align: align, addr: addr,
+                            target: target, symtab: symtab, rels: rels,
+                            flags: flags
+                        })
+                    },
+                    (Ok(_), Err(err)) => Err(err),
+                    (Err(err), _) => Err(err)
+                }
+            },
+            SectionHdrData::AndroidRela { name, addr, align, relas, flags,
+                                          target: target_idx,
+                                          symtab: symtab_idx } => {
                 match (get_symtab(section_hdrs, symtab_idx),
                        get_target(section_hdrs, target_idx)) {
                     (Ok(symtab), Ok(target)) => {
-                        Ok(SectionHdrData::Rela {
+                        Ok(SectionHdrData::AndroidRela {
                             name: name, align: align, addr: addr,
                             target: target, symtab: symtab, relas: relas,
-                            write: write, alloc: alloc, exec: exec,
+                            flags: flags
                         })
                     },
                     (Ok(_), Err(err)) => Err(err),
                     (Err(err), _) => Err(err)
                 }
             },
-            SectionHdrData::Hash { name, addr, align, hash, write, alloc, exec,
-                                   symtab: symtab_idx } => {
+            SectionHdrData::GnuHash { name, addr, align, hash, flags,
+                                      symtab: symtab_idx } => {
                 match get_symtab(section_hdrs, symtab_idx) {
                     Ok(symtab) => {
-                        Ok(SectionHdrData::Hash {
-                            name: name, addr: addr, align: align,
-                            hash: hash, write: write, exec: exec,
-                            alloc: alloc, symtab: symtab
+                        Ok(SectionHdrData::GnuHash {
+                            name: name, addr: addr, align: align, hash: hash,
+                            flags: flags, symtab: symtab
                         })
                     },
                     Err(err) => Err(err)
                 }
             },
-            SectionHdrData::Dynamic { name, align, addr, dynamic, write, alloc,
-                                      exec, strtab: strtab_idx } => {
-                match get_strtab(section_hdrs, strtab_idx) {
-                    Ok(strtab) => {
-                        Ok(SectionHdrData::Dynamic {
-                            name: name, align: align, addr: addr,
-                            strtab: strtab, dynamic: dynamic, write: write,
-                            alloc: alloc, exec: exec
+            SectionHdrData::Versym { name, addr, align, versyms, flags,
+                                     symtab: symtab_idx } => {
+                match get_symtab(section_hdrs, symtab_idx) {
+                    Ok(symtab) => {
+                        Ok(SectionHdrData::Versym {
+                            name: name, addr: addr, align: align,
+                            versyms: versyms, flags: flags, symtab: symtab
                         })
                     },
                     Err(err) => Err(err)
                 }
             },
-            SectionHdrData::Note { name, addr, align, note,
-                                   write, alloc, exec } => {
-                Ok(SectionHdrData::Note { name: name, addr: addr, align: align,
-                                          note: note, write: write,
-                                          alloc: alloc, exec: exec })
-            },
-            SectionHdrData::Nobits { name, addr, align, offset, size,
-                                     write, alloc, exec } => {
-                Ok(SectionHdrData::Nobits { name: name, addr: addr, size: size,
-                                            offset: offset, align: align,
-                                            write: write, alloc: alloc,
-                                            exec: exec })
-            },
-            SectionHdrData::Rel { name, addr, align, rels, write, alloc, exec,
-                                  symtab: symtab_idx, target: target_idx } => {
-                match (get_symtab(section_hdrs, symtab_idx),
-                       get_target(section_hdrs, target_idx)) {
-                    (Ok(symtab), Ok(target)) => {
-                        Ok(SectionHdrData::Rel {
-                            name: name, align: align, addr: addr,
-                            target: target, symtab: symtab, rels: rels,
-                            write: write, alloc: alloc, exec: exec,
+            SectionHdrData::Verdef { name, addr, align, verdefs, count, flags,
+                                     strtab: strtab_idx } => {
+                match get_strtab(section_hdrs, strtab_idx) {
+                    Ok(strtab) => {
+                        Ok(SectionHdrData::Verdef {
+                            name: name, addr: addr, align: align,
+                            verdefs: verdefs, count: count, flags: flags,
+                            strtab: strtab
                         })
                     },
-                    (Ok(_), Err(err)) => Err(err),
-                    (Err(err), _) => Err(err)
+                    Err(err) => Err(err)
                 }
             },
-            SectionHdrData::Dynsym { name, local_end, addr, align, syms, write,
-                                     alloc, exec, strtab: strtab_idx } => {
+            SectionHdrData::Verneed { name, addr, align, verneeds, count, flags,
+                                      strtab: strtab_idx } => {
                 match get_strtab(section_hdrs, strtab_idx) {
                     Ok(strtab) => {
-                        Ok(SectionHdrData::Dynsym {
-                            name: name, addr: addr, syms: syms,
-                            strtab: strtab, align: align, write: write,
-                            alloc: alloc, exec: exec, local_end: local_end
+                        Ok(SectionHdrData::Verneed {
+                            name: name, addr: addr, align: align,
+                            verneeds: verneeds, count: count, flags: flags,
+                            strtab: strtab
                         })
                     },
                     Err(err) => Err(err)
@@ -2286,6 +3979,7 @@ impl<'a, B, Offsets, Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
 ///
 /// use byteorder::LittleEndian;
 /// use elf_utils::Elf32;
+/// use elf_utils::section_hdr::SectionFlags;
 /// use elf_utils::section_hdr::SectionHdrData;
 /// use elf_utils::section_hdr::SectionPos;
 /// use elf_utils::section_hdr;
@@ -2302,19 +3996,19 @@ impl<'a, B, Offsets, Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
 ///     SectionHdrData::Null,
 ///     SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
 ///                            note: SectionPos { offset: 0x174, size: 0x18 },
-///                            alloc: true, write: false, exec: false },
+///                            flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
 ///                              syms: SectionPos { offset: 0x18c,
 ///                                                 size: 0x1b0 },
 ///                              strtab: 7, local_end: 1,
-///                              alloc: true, write: false, exec: false },
+///                              flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
 ///                            hash: SectionPos { offset: 0x540, size: 0xe0 },
-///                            symtab: 2, alloc: true, write: false,
-///                            exec: false },
+///                            symtab: 2, flags: SectionFlags { bits: 0x2 } },
 ///     SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
 ///                              strs: SectionPos { offset: 0x620,
-///                                                 size: 0x1d2 } }
+///                                                 size: 0x1d2 },
+///                              flags: SectionFlags { bits: 0x2 } }
 /// ];
 ///
 /// assert_eq!(section_hdr::required_bytes(SECTION_HDR_CONTENTS
@@ -2338,10 +4032,15 @@ impl<'a, B, Offsets> SectionHdrs<'a, B, Offsets>
     /// Returns both the `SectionHdrs` and the remaining space if
     /// successful.
     ///
+    /// If there are too many section headers for the count to fit in
+    /// the ELF header, the count will be stored in the first section
+    /// header (see [ExtNull](SectionHdrData::ExtNull)).
+    ///
     /// # Errors
     ///
-    /// The only error that can occur is if the section header table doesn't
-    /// fit into the provided memory.
+    /// An error will occur if the section header table doesn't fit
+    /// into the provided memory, or if the count must be stored in the
+    /// first section header and it is not a null section.
     ///
     /// # Examples
     ///
@@ -2352,6 +4051,7 @@ impl<'a, B, Offsets> SectionHdrs<'a, B, Offsets>
     /// use core::convert::TryFrom;
     /// use core::convert::TryInto;
     /// use elf_utils::Elf32;
+    /// use elf_utils::section_hdr::SectionFlags;
     /// use elf_utils::section_hdr::SectionHdrs;
     /// use elf_utils::section_hdr::SectionHdrData;
     /// use elf_utils::section_hdr::SectionHdrDataRaw;
@@ -2362,20 +4062,21 @@ impl<'a, B, Offsets> SectionHdrs<'a, B, Offsets>
     ///     SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
     ///                            note: SectionPos { offset: 0x174,
     ///                                               size: 0x18 },
-    ///                            alloc: true, write: false, exec: false },
+    ///                            flags: SectionFlags { bits: 0x2 } },
     ///     SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
     ///                              syms: SectionPos { offset: 0x18c,
     ///                                                 size: 0x1b0 },
     ///                              strtab: 7, local_end: 1,
-    ///                              alloc: true, write: false, exec: false },
+    ///                              flags: SectionFlags { bits: 0x2 } },
     ///     SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
     ///                            hash: SectionPos { offset: 0x540,
     ///                                               size: 0xe0 },
-    ///                            symtab: 2, alloc: true, write: false,
-    ///                            exec: false },
+    ///                            symtab: 2,
+    ///                            flags: SectionFlags { bits: 0x2 } },
     ///     SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
     ///                              strs: SectionPos { offset: 0x620,
-    ///                                                 size: 0x1d2 } }
+    ///                                                 size: 0x1d2 },
+    ///                              flags: SectionFlags { bits: 0x2 } }
     /// ];
     ///
     /// let mut buf = [0; 208];
@@ -2397,247 +4098,914 @@ impl<'a, B, Offsets> SectionHdrs<'a, B, Offsets>
     /// }
     /// ```
     #[inline]
-    pub fn create_split<I>(buf: &'a mut [u8], ents: I) ->
-        Result<(Self, &'a mut [u8]), ()>
-        where I: Iterator<Item = SectionHdrDataRaw<Offsets>> {
-        let byteorder: PhantomData<B> = PhantomData;
-        let offsets: PhantomData<Offsets> = PhantomData;
-        let (data, out) = create::<B, I, Offsets>(buf, ents)?;
+    pub fn create_split<I>(buf: &'a mut [u8], ents: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator<Item = SectionHdrDataRaw<Offsets>> {
+        Self::create_ext_split(buf, ents, 0, 0)
+    }
+
+    /// Attempt to create a `SectionHdrs` in `buf` containing the section
+    /// header table entries in `hdrs`.
+    ///
+    /// This will write the section header table data into the buffer
+    /// in the proper format for the ELF class and byte order.
+    ///
+    /// If there are too many section headers for the count to fit in
+    /// the ELF header, the count will be stored in the first section
+    /// header (see [ExtNull](SectionHdrData::ExtNull)).
+    ///
+    /// # Errors
+    ///
+    /// An error will occur if the section header table doesn't fit
+    /// into the provided memory, or if the count must be stored in the
+    /// first section header and it is not a null section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryFrom;
+    /// use core::convert::TryInto;
+    /// use elf_utils::Elf32;
+    /// use elf_utils::section_hdr::SectionFlags;
+    /// use elf_utils::section_hdr::SectionHdrs;
+    /// use elf_utils::section_hdr::SectionHdrData;
+    /// use elf_utils::section_hdr::SectionHdrDataRaw;
+    /// use elf_utils::section_hdr::SectionPos;
+    ///
+    /// const SECTION_HDR_CONTENTS: [SectionHdrDataRaw<Elf32>; 5] = [
+    ///     SectionHdrData::Null,
+    ///     SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
+    ///                            note: SectionPos { offset: 0x174,
+    ///                                               size: 0x18 },
+    ///                            flags: SectionFlags { bits: 0x2 } },
+    ///     SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
+    ///                              syms: SectionPos { offset: 0x18c,
+    ///                                                 size: 0x1b0 },
+    ///                              strtab: 7, local_end: 1,
+    ///                              flags: SectionFlags { bits: 0x2 } },
+    ///     SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
+    ///                            hash: SectionPos { offset: 0x540,
+    ///                                               size: 0xe0 },
+    ///                            symtab: 2,
+    ///                            flags: SectionFlags { bits: 0x2 } },
+    ///     SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
+    ///                              strs: SectionPos { offset: 0x620,
+    ///                                                 size: 0x1d2 },
+    ///                              flags: SectionFlags { bits: 0x2 } }
+    /// ];
+    ///
+    /// let mut buf = [0; 208];
+    /// let res: Result<SectionHdrs<'_, LittleEndian, Elf32>, ()> =
+    ///     SectionHdrs::create(&mut buf[0..],
+    ///                         SECTION_HDR_CONTENTS.iter().map(|x| *x));
+    /// let hdrs = res.unwrap();
+    ///
+    /// let mut iter = hdrs.iter();
+    ///
+    /// for i in 0 .. 5 {
+    ///     let ent = iter.next().unwrap();
+    ///     let data: SectionHdrDataRaw<Elf32> = ent.try_into().unwrap();
+    ///
+    ///     assert_eq!(data, SECTION_HDR_CONTENTS[i]);
+    /// }
+    /// ```
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8],ents: I) -> Result<Self, ()>
+        where I: Iterator<Item = SectionHdrDataRaw<Offsets>>,
+              Self: Sized {
+        match Self::create_split(buf, ents) {
+            Ok((out, _)) => Ok(out),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Attempt to create a `SectionHdrs` in `buf` containing the
+    /// section header table entries in `hdrs`, along with the
+    /// extended ELF header values.
+    ///
+    /// This is the same as [create_split](SectionHdrs::create_split),
+    /// except that the section header string table index
+    /// `section_hdr_strtab` and the number of program headers
+    /// `num_prog_hdrs` will also be stored in the first section
+    /// header if they are too large to fit in the ELF header.  This
+    /// matches the escaping done by
+    /// [Elf::create_split](crate::elf::Elf::create_split).
+    ///
+    /// # Errors
+    ///
+    /// An error will occur if the section header table doesn't fit
+    /// into the provided memory, or if a value must be stored in the
+    /// first section header and it is not a null section.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryInto;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::section_hdr::SectionHdrs;
+    /// use elf_utils::section_hdr::SectionHdrData;
+    /// use elf_utils::section_hdr::SectionHdrDataRaw;
+    ///
+    /// const SECTION_HDR_CONTENTS: [SectionHdrDataRaw<Elf64>; 2] = [
+    ///     SectionHdrData::Null,
+    ///     SectionHdrData::Null
+    /// ];
+    ///
+    /// let mut buf = [0; 128];
+    /// let (hdrs, rest): (SectionHdrs<'_, LittleEndian, Elf64>, _) =
+    ///     SectionHdrs::create_ext_split(&mut buf[0..],
+    ///                                   SECTION_HDR_CONTENTS.iter()
+    ///                                   .map(|x| *x), 1, 0x10000)
+    ///     .expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 0);
+    ///
+    /// let first: SectionHdrDataRaw<Elf64> =
+    ///     hdrs.idx(0).unwrap().try_into().unwrap();
+    ///
+    /// assert_eq!(first, SectionHdrData::ExtNull { num_section_hdrs: 0,
+    ///                                             section_hdr_strtab: 0,
+    ///                                             num_prog_hdrs: 0x10000 });
+    /// ```
+    #[inline]
+    pub fn create_ext_split<I>(buf: &'a mut [u8], ents: I,
+                               section_hdr_strtab: usize,
+                               num_prog_hdrs: usize) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator<Item = SectionHdrDataRaw<Offsets>> {
+        let byteorder: PhantomData<B> = PhantomData;
+        let offsets: PhantomData<Offsets> = PhantomData;
+        let (data, out) = create::<B, I, Offsets>(buf, ents)?;
+
+        escape_ext::<B, Offsets>(data, section_hdr_strtab, num_prog_hdrs)?;
+
+        Ok((SectionHdrs { byteorder: byteorder, offsets: offsets, hdrs: data },
+            out))
+    }
+
+    /// Attempt to create a `SectionHdrs` in `buf` containing the
+    /// section header table entries in `hdrs`, along with the
+    /// extended ELF header values.
+    ///
+    /// This is the same as [create](SectionHdrs::create), except that
+    /// the section header string table index `section_hdr_strtab` and
+    /// the number of program headers `num_prog_hdrs` will also be
+    /// stored in the first section header if they are too large to fit
+    /// in the ELF header.
+    ///
+    /// # Errors
+    ///
+    /// An error will occur if the section header table doesn't fit
+    /// into the provided memory, or if a value must be stored in the
+    /// first section header and it is not a null section.
+    #[inline]
+    pub fn create_ext<I>(buf: &'a mut [u8], ents: I,
+                         section_hdr_strtab: usize,
+                         num_prog_hdrs: usize) -> Result<Self, ()>
+        where I: Iterator<Item = SectionHdrDataRaw<Offsets>>,
+              Self: Sized {
+        match Self::create_ext_split(buf, ents, section_hdr_strtab,
+                                     num_prog_hdrs) {
+            Ok((out, _)) => Ok(out),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get a [SectionHdr] for the section header table entry at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<SectionHdr<'a, B, Offsets>> {
+        let len = self.hdrs.len();
+        let start = idx * Offsets::SECTION_HDR_SIZE;
+
+        if start < len {
+            let end = start + Offsets::SECTION_HDR_SIZE;
+
+            Some(SectionHdr { byteorder: PhantomData, offsets: PhantomData,
+                              ent: &self.hdrs[start .. end ],
+                              first: idx == 0 })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of program header table entries in this `SectionHdrs`.
+    #[inline]
+    pub fn num_hdrs(&self) -> usize {
+        self.hdrs.len() / Offsets::SECTION_HDR_SIZE
+    }
+
+    /// Get an iterator over this `SectionHdrs`.
+    #[inline]
+    pub fn iter(&self) -> SectionHdrIter<'a, B, Offsets> {
+        SectionHdrIter { byteorder: PhantomData, offsets: PhantomData,
+                         hdrs: self.hdrs, idx: 0 }
+    }
+
+    /// Get the raw data for this `SectionHdrs`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.hdrs
+    }
+}
+
+impl<'a, B, Offsets> SectionHdrsMut<'a, B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    /// Get a [SectionHdr] for the section header at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<SectionHdr<'_, B, Offsets>> {
+        let len = self.hdrs.len();
+        let start = idx * Offsets::SECTION_HDR_SIZE;
+
+        if start < len {
+            let end = start + Offsets::SECTION_HDR_SIZE;
+
+            Some(SectionHdr { byteorder: PhantomData, offsets: PhantomData,
+                              ent: &self.hdrs[start .. end ],
+                              first: idx == 0 })
+        } else {
+            None
+        }
+    }
+
+    /// Get a [SectionHdrMut] for the section header at `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx_mut(&mut self, idx: usize) ->
+        Option<SectionHdrMut<'_, B, Offsets>> {
+        let len = self.hdrs.len();
+        let start = idx * Offsets::SECTION_HDR_SIZE;
+
+        if start < len {
+            let end = start + Offsets::SECTION_HDR_SIZE;
+
+            Some(SectionHdrMut { byteorder: PhantomData, offsets: PhantomData,
+                                 ent: &mut self.hdrs[start .. end ],
+                                 first: idx == 0 })
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of section headers.
+    #[inline]
+    pub fn num_hdrs(&self) -> usize {
+        self.hdrs.len() / Offsets::SECTION_HDR_SIZE
+    }
+}
+
+impl<'a, B, Offsets> SectionHdrMut<'a, B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    /// Replace this section header with `data`.
+    #[inline]
+    pub fn set(&mut self, data: SectionHdrDataRaw<Offsets>) {
+        write::<B, Offsets>(self.ent, data)
+    }
+
+    /// Set the section name (an index into the section header string
+    /// table) to `name`.
+    #[inline]
+    pub fn set_name(&mut self, name: Offsets::Word) {
+        Offsets::write_word::<B>(&mut self.ent[Offsets::SH_NAME_START ..
+                                               Offsets::SH_NAME_END],
+                                 name)
+    }
+
+    /// Set the type tag (`sh_type`) of this section to `kind`.
+    #[inline]
+    pub fn set_kind(&mut self, kind: Offsets::Word) {
+        Offsets::write_word::<B>(&mut self.ent[Offsets::SH_KIND_START ..
+                                               Offsets::SH_KIND_END],
+                                 kind)
+    }
+
+    /// Set the starting address of this section to `addr`.
+    #[inline]
+    pub fn set_addr(&mut self, addr: Offsets::Addr) {
+        Offsets::write_addr::<B>(&mut self.ent[Offsets::SH_ADDR_START ..
+                                               Offsets::SH_ADDR_END],
+                                 addr)
+    }
+
+    /// Set the offset of this section's data in the file to `offset`.
+    #[inline]
+    pub fn set_offset(&mut self, offset: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::SH_OFFSET_START ..
+                                                 Offsets::SH_OFFSET_END],
+                                   offset)
+    }
+
+    /// Set the size of this section to `size`.
+    #[inline]
+    pub fn set_size(&mut self, size: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::SH_SIZE_START ..
+                                                 Offsets::SH_SIZE_END],
+                                   size)
+    }
+
+    /// Set the link field (`sh_link`) of this section to `link`.
+    #[inline]
+    pub fn set_link(&mut self, link: Offsets::Word) {
+        Offsets::write_word::<B>(&mut self.ent[Offsets::SH_LINK_START ..
+                                               Offsets::SH_LINK_END],
+                                 link)
+    }
+
+    /// Set the info field (`sh_info`) of this section to `info`.
+    #[inline]
+    pub fn set_info(&mut self, info: Offsets::Word) {
+        Offsets::write_word::<B>(&mut self.ent[Offsets::SH_INFO_START ..
+                                               Offsets::SH_INFO_END],
+                                 info)
+    }
+
+    /// Set the alignment of this section to `align`.
+    #[inline]
+    pub fn set_align(&mut self, align: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::SH_ALIGN_START ..
+                                                 Offsets::SH_ALIGN_END],
+                                   align)
+    }
 
-        Ok((SectionHdrs { byteorder: byteorder, offsets: offsets, hdrs: data },
-            out))
+    /// Set the entry size of this section to `ent_size`.
+    #[inline]
+    pub fn set_ent_size(&mut self, ent_size: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::SH_ENT_SIZE_START ..
+                                                 Offsets::SH_ENT_SIZE_END],
+                                   ent_size)
     }
 
-    /// Attempt to create a `SectionHdrs` in `buf` containing the section
-    /// header table entries in `hdrs`.
-    ///
-    /// This will write the section header table data into the buffer
-    /// in the proper format for the ELF class and byte order.
-    ///
-    /// # Errors
-    ///
-    /// The only error that can occur is if the section header table doesn't
-    /// fit into the provided memory.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// extern crate elf_utils;
-    ///
-    /// use byteorder::LittleEndian;
-    /// use core::convert::TryFrom;
-    /// use core::convert::TryInto;
-    /// use elf_utils::Elf32;
-    /// use elf_utils::section_hdr::SectionHdrs;
-    /// use elf_utils::section_hdr::SectionHdrData;
-    /// use elf_utils::section_hdr::SectionHdrDataRaw;
-    /// use elf_utils::section_hdr::SectionPos;
-    ///
-    /// const SECTION_HDR_CONTENTS: [SectionHdrDataRaw<Elf32>; 5] = [
-    ///     SectionHdrData::Null,
-    ///     SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
-    ///                            note: SectionPos { offset: 0x174,
-    ///                                               size: 0x18 },
-    ///                            alloc: true, write: false, exec: false },
-    ///     SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
-    ///                              syms: SectionPos { offset: 0x18c,
-    ///                                                 size: 0x1b0 },
-    ///                              strtab: 7, local_end: 1,
-    ///                              alloc: true, write: false, exec: false },
-    ///     SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
-    ///                            hash: SectionPos { offset: 0x540,
-    ///                                               size: 0xe0 },
-    ///                            symtab: 2, alloc: true, write: false,
-    ///                            exec: false },
-    ///     SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
-    ///                              strs: SectionPos { offset: 0x620,
-    ///                                                 size: 0x1d2 } }
-    /// ];
-    ///
-    /// let mut buf = [0; 208];
-    /// let res: Result<SectionHdrs<'_, LittleEndian, Elf32>, ()> =
-    ///     SectionHdrs::create(&mut buf[0..],
-    ///                         SECTION_HDR_CONTENTS.iter().map(|x| *x));
-    /// let hdrs = res.unwrap();
-    ///
-    /// let mut iter = hdrs.iter();
-    ///
-    /// for i in 0 .. 5 {
-    ///     let ent = iter.next().unwrap();
-    ///     let data: SectionHdrDataRaw<Elf32> = ent.try_into().unwrap();
-    ///
-    ///     assert_eq!(data, SECTION_HDR_CONTENTS[i]);
-    /// }
-    /// ```
+    /// Set the flags for this section to `flags`.
     #[inline]
-    pub fn create<I>(buf: &'a mut [u8],ents: I) -> Result<Self, ()>
-        where I: Iterator<Item = SectionHdrDataRaw<Offsets>>,
-              Self: Sized {
-        match Self::create_split(buf, ents) {
-            Ok((out, _)) => Ok(out),
-            Err(err) => Err(err)
-        }
+    pub fn set_flags(&mut self, flags: SectionFlags<Offsets>) {
+        Offsets::write_offset::<B>(&mut self.ent[Offsets::SH_FLAGS_START ..
+                                                 Offsets::SH_FLAGS_END],
+                                   flags.bits)
     }
+}
 
-    /// Get a [SectionHdr] for the section header table entry at `idx`.
+impl<'a, B, Offsets> SectionHdrsWithData<'a, B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    /// Get a `SectionHdrWithData` for the section header table entry
+    /// at `idx`.
     ///
     /// # Errors
     ///
     /// `None` will be returned if `idx` is out of bounds.
     #[inline]
-    pub fn idx(&self, idx: usize) -> Option<SectionHdr<'a, B, Offsets>> {
+    pub fn idx(&self, idx: usize) ->
+        Option<SectionHdrWithData<'a, B, Offsets>> {
         let len = self.hdrs.len();
         let start = idx * Offsets::SECTION_HDR_SIZE;
 
         if start < len {
             let end = start + Offsets::SECTION_HDR_SIZE;
 
-            Some(SectionHdr { byteorder: PhantomData, offsets: PhantomData,
-                              ent: &self.hdrs[start .. end ] })
+            Some(SectionHdrWithData {
+                byteorder: PhantomData, offsets: PhantomData,
+                ent: &self.hdrs[start .. end ], data: self.data
+            })
         } else {
             None
         }
     }
 
-    /// Get the number of program header table entries in this `SectionHdrs`.
+    /// Get the number of program header table entries in this
+    /// `SectionHdrsWithData`.
     #[inline]
     pub fn num_hdrs(&self) -> usize {
         self.hdrs.len() / Offsets::SECTION_HDR_SIZE
     }
 
-    /// Get an iterator over this `SectionHdrs`.
+    /// Get an iterator over this `SectionHdrsWithData`.
     #[inline]
-    pub fn iter(&self) -> SectionHdrIter<'a, B, Offsets> {
-        SectionHdrIter { byteorder: PhantomData, offsets: PhantomData,
-                         hdrs: self.hdrs, idx: 0 }
+    pub fn iter(&self) -> SectionHdrWithDataIter<'a, B, Offsets> {
+        SectionHdrWithDataIter { byteorder: PhantomData, offsets: PhantomData,
+                                 data: self.data, hdrs: self.hdrs, idx: 0 }
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a [u8]> for SectionHdrs<'a, B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    type Error = SectionHdrsError;
+
+    #[inline]
+    fn try_from(hdrs: &'a [u8]) -> Result<SectionHdrs<'a, B, Offsets>,
+                                          Self::Error> {
+        let len = hdrs.len();
+
+        if hdrs.len() % Offsets::SECTION_HDR_SIZE == 0 {
+            Ok(SectionHdrs { byteorder: PhantomData, offsets: PhantomData,
+                             hdrs: hdrs })
+        } else {
+            Err(SectionHdrsError::BadSize(len))
+        }
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a mut [u8]> for SectionHdrs<'a, B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    type Error = SectionHdrsError;
+
+    #[inline]
+    fn try_from(hdrs: &'a mut [u8]) -> Result<SectionHdrs<'a, B, Offsets>,
+                                              Self::Error> {
+        let len = hdrs.len();
+
+        if hdrs.len() % Offsets::SECTION_HDR_SIZE == 0 {
+            Ok(SectionHdrs { byteorder: PhantomData, offsets: PhantomData,
+                             hdrs: hdrs })
+        } else {
+            Err(SectionHdrsError::BadSize(len))
+        }
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<&'a mut [u8]>
+    for SectionHdrsMut<'a, B, Offsets>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    type Error = SectionHdrsError;
+
+    #[inline]
+    fn try_from(hdrs: &'a mut [u8]) ->
+        Result<SectionHdrsMut<'a, B, Offsets>, Self::Error> {
+        let len = hdrs.len();
+
+        if hdrs.len() % Offsets::SECTION_HDR_SIZE == 0 {
+            Ok(SectionHdrsMut { byteorder: PhantomData, offsets: PhantomData,
+                                hdrs: hdrs })
+        } else {
+            Err(SectionHdrsError::BadSize(len))
+        }
     }
 }
 
-impl<'a, B, Offsets> SectionHdrsWithData<'a, B, Offsets>
-    where Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    /// Get a `SectionHdrWithData` for the section header table entry
-    /// at `idx`.
-    ///
-    /// # Errors
-    ///
-    /// `None` will be returned if `idx` is out of bounds.
-    #[inline]
-    pub fn idx(&self, idx: usize) ->
-        Option<SectionHdrWithData<'a, B, Offsets>> {
-        let len = self.hdrs.len();
-        let start = idx * Offsets::SECTION_HDR_SIZE;
+impl<'a, B, Str, Offsets> WithElfData<'a>
+        for SectionHdrData<Offsets, Str, SectionHdr<'a, B, Offsets>,
+                           SymsStrs<SectionHdr<'a, B, Offsets>,
+                                    SectionHdr<'a, B, Offsets>>,
+                           SectionHdr<'a, B, Offsets>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>,
+                           SectionPos<Offsets::Offset>>
+    where Offsets: SectionHdrOffsets,
+          B: ByteOrder {
+    type Result = SectionHdrData<Offsets, Str, SectionHdr<'a, B, Offsets>,
+                                 SymsStrs<&'a [u8], &'a [u8]>, &'a [u8],
+                                 &'a [u8], &'a [u8], &'a [u8], &'a [u8],
+                                 &'a [u8], &'a [u8], &'a [u8], &'a [u8]>;
+    type Error = SectionHdrError<Offsets>;
+
+    #[inline]
+    fn with_elf_data(self, data: &'a [u8]) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            SectionHdrData::Null => Ok(SectionHdrData::Null),
+            SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                      num_prog_hdrs } =>
+                Ok(SectionHdrData::ExtNull {
+                    num_section_hdrs: num_section_hdrs,
+                    section_hdr_strtab: section_hdr_strtab,
+                    num_prog_hdrs: num_prog_hdrs
+                }),
+            SectionHdrData::ProgBits { data: SectionPos { offset, size }, name,
+                                       addr, align, ent_size, flags } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::ProgBits {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags,
+                            data: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Symtab { syms: SectionPos { offset, size }, name,
+                                     local_end, addr, align, flags,
+                                     strtab } => {
+                // Convert the strtab into data first.
+                let strtab = match strtab.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
+
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Symtab {
+                            name: name, addr: addr, align: align, flags: flags,
+                            strtab: strtab?, local_end: local_end,
+                            syms: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Strtab { strs: SectionPos { offset, size }, name,
+                                     addr, align, flags } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Strtab {
+                            name: name, addr: addr, align: align,
+                            strs: &data[offset .. offset + size], flags: flags
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Rela { relas: SectionPos { offset, size },
+                                   symtab: SymsStrs { syms, strs }, name, addr,
+                                   align, flags, target } => {
+                let symtab = match syms.try_into() {
+                    Ok(SectionHdrData::Symtab {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(SectionHdrData::Dynsym {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
+
+                let strtab = match strs.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
+
+                let symtab = SymsStrs { syms: symtab?, strs: strtab? };
 
-        if start < len {
-            let end = start + Offsets::SECTION_HDR_SIZE;
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Rela {
+                            name: name, align: align, addr: addr, flags: flags,
+                            target: target, symtab: symtab,
+                            relas: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Hash { hash: SectionPos { offset, size },
+                                   symtab: SymsStrs { syms, strs }, name, addr,
+                                   align, flags } => {
+                let symtab = match syms.try_into() {
+                    Ok(SectionHdrData::Symtab {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(SectionHdrData::Dynsym {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-            Some(SectionHdrWithData {
-                byteorder: PhantomData, offsets: PhantomData,
-                ent: &self.hdrs[start .. end ], data: self.data
-            })
-        } else {
-            None
-        }
-    }
+                let strtab = match strs.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-    /// Get the number of program header table entries in this
-    /// `SectionHdrsWithData`.
-    #[inline]
-    pub fn num_hdrs(&self) -> usize {
-        self.hdrs.len() / Offsets::SECTION_HDR_SIZE
-    }
+                let symtab = SymsStrs { syms: symtab?, strs: strtab? };
 
-    /// Get an iterator over this `SectionHdrsWithData`.
-    #[inline]
-    pub fn iter(&self) -> SectionHdrWithDataIter<'a, B, Offsets> {
-        SectionHdrWithDataIter { byteorder: PhantomData, offsets: PhantomData,
-                                 data: self.data, hdrs: self.hdrs, idx: 0 }
-    }
-}
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Hash {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: symtab, hash: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Dynamic { dynamic: SectionPos { offset, size },
+                                      name, align, addr, flags, strtab } => {
+                // Convert the strtab into data first.
+                let strtab = match strtab.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-impl<'a, B, Offsets> TryFrom<&'a [u8]> for SectionHdrs<'a, B, Offsets>
-    where Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    type Error = SectionHdrsError;
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Dynamic {
+                            name: name, align: align, addr: addr, flags: flags,
+                            strtab: strtab?,
+                            dynamic: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Note { note: SectionPos { offset, size }, name,
+                                   addr, align, flags } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Note {
+                            name: name, addr: addr, align: align, flags: flags,
+                            note: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Nobits { name, addr, align, offset, size,
+                                     flags } => {
+                Ok(SectionHdrData::Nobits {
+                    name: name, addr: addr, size: size, offset: offset,
+                    align: align, flags: flags
+                })
+            },
+            SectionHdrData::Rel { rels: SectionPos { offset, size },
+                                  symtab: SymsStrs { syms, strs }, name, addr,
+                                  align, flags, target } => {
+                let symtab = match syms.try_into() {
+                    Ok(SectionHdrData::Symtab {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(SectionHdrData::Dynsym {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-    #[inline]
-    fn try_from(hdrs: &'a [u8]) -> Result<SectionHdrs<'a, B, Offsets>,
-                                          Self::Error> {
-        let len = hdrs.len();
+                let strtab = match strs.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-        if hdrs.len() % Offsets::SECTION_HDR_SIZE == 0 {
-            Ok(SectionHdrs { byteorder: PhantomData, offsets: PhantomData,
-                             hdrs: hdrs })
-        } else {
-            Err(SectionHdrsError::BadSize(len))
-        }
-    }
-}
+                let symtab = SymsStrs { syms: symtab?, strs: strtab? };
 
-impl<'a, B, Offsets> TryFrom<&'a mut [u8]> for SectionHdrs<'a, B, Offsets>
-    where Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    type Error = SectionHdrsError;
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Rel {
+                            name: name, align: align, addr: addr, flags: flags,
+                            target: target, symtab: symtab,
+                            rels: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Dynsym { syms: SectionPos { offset, size }, name,
+                                     local_end, addr, align, flags,
+                                     strtab } => {
+                // Convert the strtab into data first.
+                let strtab = match strtab.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-    #[inline]
-    fn try_from(hdrs: &'a mut [u8]) -> Result<SectionHdrs<'a, B, Offsets>,
-                                              Self::Error> {
-        let len = hdrs.len();
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Dynsym {
+                            name: name, addr: addr, align: align, flags: flags,
+                            strtab: strtab?, local_end: local_end,
+                            syms: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::InitArray { addrs: SectionPos { offset, size },
+                                        name, addr, align, ent_size,
+                                        flags } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::InitArray {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags,
+                            addrs: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::FiniArray { addrs: SectionPos { offset, size },
+                                        name, addr, align, ent_size,
+                                        flags } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::FiniArray {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags,
+                            addrs: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::PreInitArray { addrs: SectionPos { offset, size },
+                                           name, addr, align, ent_size,
+                                           flags } => {
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::PreInitArray {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags,
+                            addrs: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Group { group: SectionPos { offset, size },
+                                    symtab: SymsStrs { syms, strs }, name, addr,
+                                    align, sym, flags } => {
+                let symtab = match syms.try_into() {
+                    Ok(SectionHdrData::Symtab {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(SectionHdrData::Dynsym {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-        if hdrs.len() % Offsets::SECTION_HDR_SIZE == 0 {
-            Ok(SectionHdrs { byteorder: PhantomData, offsets: PhantomData,
-                             hdrs: hdrs })
-        } else {
-            Err(SectionHdrsError::BadSize(len))
-        }
-    }
-}
+                let strtab = match strs.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
 
-impl<'a, B, Str, Offsets> WithElfData<'a>
-        for SectionHdrData<Offsets, Str, SectionHdr<'a, B, Offsets>,
-                           SymsStrs<SectionHdr<'a, B, Offsets>,
-                                    SectionHdr<'a, B, Offsets>>,
-                           SectionHdr<'a, B, Offsets>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>,
-                           SectionPos<Offsets::Offset>>
-    where Offsets: SectionHdrOffsets,
-          B: ByteOrder {
-    type Result = SectionHdrData<Offsets, Str, SectionHdr<'a, B, Offsets>,
-                                 SymsStrs<&'a [u8], &'a [u8]>, &'a [u8],
-                                 &'a [u8], &'a [u8], &'a [u8], &'a [u8],
-                                 &'a [u8], &'a [u8], &'a [u8], &'a [u8]>;
-    type Error = SectionHdrError<Offsets>;
+                let symtab = SymsStrs { syms: symtab?, strs: strtab? };
 
-    #[inline]
-    fn with_elf_data(self, data: &'a [u8]) ->
-        Result<Self::Result, Self::Error> {
-        match self {
-            SectionHdrData::Null => Ok(SectionHdrData::Null),
-            SectionHdrData::ProgBits { data: SectionPos { offset, size },
-                                       name, addr, align, alloc,
-                                       write, exec } => {
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::ProgBits {
-                            name: name, addr: addr, align: align,
-                            alloc: alloc, write: write, exec: exec,
-                            data: &data[offset .. offset + size]
+                        Ok(SectionHdrData::Group {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: symtab, sym: sym,
+                            group: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Symtab { syms: SectionPos { offset, size },
-                                     name, local_end, addr, align, write,
-                                     alloc, exec, strtab } => {
-                // Convert the strtab into data first.
-                let strtab = match strtab.try_into() {
+            SectionHdrData::SymtabShndx { shndxs: SectionPos { offset, size },
+                                          symtab: SymsStrs { syms, strs }, name,
+                                          addr, align, flags } => {
+                let symtab = match syms.try_into() {
+                    Ok(SectionHdrData::Symtab {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(SectionHdrData::Dynsym {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
+
+                let strtab = match strs.try_into() {
                     Ok(SectionHdrData::Strtab {
                         strs: SectionPos { offset, size }, ..
                     }) => match (offset.try_into(), size.try_into()) {
@@ -2651,33 +5019,34 @@ impl<'a, B, Str, Offsets> WithElfData<'a>
                     Err(err) => Err(err)
                 };
 
+                let symtab = SymsStrs { syms: symtab?, strs: strtab? };
+
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Symtab {
-                            name: name, addr: addr, align: align, write: write,
-                            strtab: strtab?, exec: exec, local_end: local_end,
-                            alloc: alloc, syms: &data[offset .. offset + size]
+                        Ok(SectionHdrData::SymtabShndx {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: symtab,
+                            shndxs: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Strtab { strs: SectionPos { offset, size },
-                                     name, addr, align } => {
+            SectionHdrData::Relr { relrs: SectionPos { offset, size },
+                                   name, addr, align, flags } => {
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Strtab {
-                            name: name, addr: addr, align: align,
-                            strs: &data[offset .. offset + size]
+                        Ok(SectionHdrData::Relr {
+                            name: name, addr: addr, align: align, flags: flags,
+                            relrs: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Rela { relas: SectionPos { offset, size },
-                                   symtab: SymsStrs { syms, strs },
-                                   name, addr, align, write, alloc,
-                                   exec, target } => {
+            SectionHdrData::AndroidRel { rels: SectionPos { offset, size },
+                                         symtab: SymsStrs { syms, strs }, name,
+                                         addr, align, flags, target } => {
                 let symtab = match syms.try_into() {
                     Ok(SectionHdrData::Symtab {
                         syms: SectionPos { offset, size }, ..
@@ -2719,19 +5088,18 @@ impl<'a, B, Str, Offsets> WithElfData<'a>
 
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Rela {
-                            name: name, align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
+                        Ok(SectionHdrData::AndroidRel {
+                            name: name, align: align, addr: addr, flags: flags,
                             target: target, symtab: symtab,
-                            relas: &data[offset .. offset + size]
+                            rels: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Hash { hash: SectionPos { offset, size },
-                                   symtab: SymsStrs { syms, strs },
-                                   name, addr, align, write, alloc, exec } => {
+            SectionHdrData::AndroidRela { relas: SectionPos { offset, size },
+                                          symtab: SymsStrs { syms, strs }, name,
+                                          addr, align, flags, target } => {
                 let symtab = match syms.try_into() {
                     Ok(SectionHdrData::Symtab {
                         syms: SectionPos { offset, size }, ..
@@ -2773,20 +5141,42 @@ impl<'a, B, Str, Offsets> WithElfData<'a>
 
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Hash {
-                            name: name, addr: addr, align: align, write: write,
-                            exec: exec, alloc: alloc, symtab: symtab,
-                            hash: &data[offset .. offset + size]
+                        Ok(SectionHdrData::AndroidRela {
+                            name: name, align: align, addr: addr, flags: flags,
+                            target: target, symtab: symtab,
+                            relas: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Dynamic { dynamic: SectionPos { offset, size },
-                                      name, align, addr, write, alloc,
-                                      exec, strtab } => {
-                // Convert the strtab into data first.
-                let strtab = match strtab.try_into() {
+            SectionHdrData::GnuHash { hash: SectionPos { offset, size },
+                                      symtab: SymsStrs { syms, strs }, name,
+                                      addr, align, flags } => {
+                let symtab = match syms.try_into() {
+                    Ok(SectionHdrData::Symtab {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(SectionHdrData::Dynsym {
+                        syms: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
+
+                let strtab = match strs.try_into() {
                     Ok(SectionHdrData::Strtab {
                         strs: SectionPos { offset, size }, ..
                     }) => match (offset.try_into(), size.try_into()) {
@@ -2800,41 +5190,21 @@ impl<'a, B, Str, Offsets> WithElfData<'a>
                     Err(err) => Err(err)
                 };
 
+                let symtab = SymsStrs { syms: symtab?, strs: strtab? };
+
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Dynamic {
-                            name: name, align: align, addr: addr, write: write,
-                            alloc: alloc, exec: exec, strtab: strtab?,
-                            dynamic: &data[offset .. offset + size]
-                        }),
-                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
-                                                                size: size })
-                }
-            },
-            SectionHdrData::Note { note: SectionPos { offset, size },
-                                   name, addr, align, write, alloc, exec } => {
-                match (offset.try_into(), size.try_into()) {
-                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Note {
-                            name: name, addr: addr, align: align,
-                            write: write, alloc: alloc, exec: exec,
-                            note: &data[offset .. offset + size]
+                        Ok(SectionHdrData::GnuHash {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: symtab, hash: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Nobits { name, addr, align, offset, size,
-                                     write, alloc, exec } => {
-                Ok(SectionHdrData::Nobits {
-                    name: name, addr: addr, size: size, offset: offset,
-                    align: align, write: write, alloc: alloc, exec: exec
-                })
-            },
-            SectionHdrData::Rel { rels: SectionPos { offset, size },
-                                  symtab: SymsStrs { syms, strs },
-                                  name, addr, align, write, alloc,
-                                  exec, target } => {
+            SectionHdrData::Versym { versyms: SectionPos { offset, size },
+                                     symtab: SymsStrs { syms, strs }, name,
+                                     addr, align, flags } => {
                 let symtab = match syms.try_into() {
                     Ok(SectionHdrData::Symtab {
                         syms: SectionPos { offset, size }, ..
@@ -2876,20 +5246,17 @@ impl<'a, B, Str, Offsets> WithElfData<'a>
 
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Rel {
-                            name: name, align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            target: target, symtab: symtab,
-                            rels: &data[offset .. offset + size]
+                        Ok(SectionHdrData::Versym {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: symtab,
+                            versyms: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
                 }
             },
-            SectionHdrData::Dynsym { syms: SectionPos { offset, size },
-                                     name, local_end, addr, align, write,
-                                     alloc, exec, strtab } => {
-                // Convert the strtab into data first.
+            SectionHdrData::Verdef { verdefs: SectionPos { offset, size }, name,
+                                     addr, align, count, flags, strtab } => {
                 let strtab = match strtab.try_into() {
                     Ok(SectionHdrData::Strtab {
                         strs: SectionPos { offset, size }, ..
@@ -2906,10 +5273,38 @@ impl<'a, B, Str, Offsets> WithElfData<'a>
 
                 match (offset.try_into(), size.try_into()) {
                     (Ok(offset), Ok(size)) if offset + size <= data.len() =>
-                        Ok(SectionHdrData::Dynsym {
-                            name: name, addr: addr, align: align, write: write,
-                            strtab: strtab?, exec: exec, local_end: local_end,
-                            alloc: alloc, syms: &data[offset .. offset + size]
+                        Ok(SectionHdrData::Verdef {
+                            name: name, align: align, addr: addr, flags: flags,
+                            strtab: strtab?, count: count,
+                            verdefs: &data[offset .. offset + size]
+                        }),
+                    _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
+                                                                size: size })
+                }
+            },
+            SectionHdrData::Verneed { verneeds: SectionPos { offset, size },
+                                      name, addr, align, count, flags,
+                                      strtab } => {
+                let strtab = match strtab.try_into() {
+                    Ok(SectionHdrData::Strtab {
+                        strs: SectionPos { offset, size }, ..
+                    }) => match (offset.try_into(), size.try_into()) {
+                        (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                            Ok(&data[offset .. offset + size]),
+                        _ => Err(SectionHdrError::DataOutOfBounds {
+                            offset: offset, size: size
+                        })
+                    },
+                    Ok(_) => Err(SectionHdrError::BadLink),
+                    Err(err) => Err(err)
+                };
+
+                match (offset.try_into(), size.try_into()) {
+                    (Ok(offset), Ok(size)) if offset + size <= data.len() =>
+                        Ok(SectionHdrData::Verneed {
+                            name: name, align: align, addr: addr, flags: flags,
+                            strtab: strtab?, count: count,
+                            verneeds: &data[offset .. offset + size]
                         }),
                     _ => Err(SectionHdrError::DataOutOfBounds { offset: offset,
                                                                 size: size })
@@ -2967,9 +5362,17 @@ impl<'a, B, Str, Offsets>
                        Strtab<'a>, &'a [u8],
                        Symtab<'a, B, Offsets>, Strtab<'a>,
                        Rels<'a, B, Offsets>, Relas<'a, B, Offsets>,
-                       Hashtab<'a, B, Offsets>, Dynamic<'a, B, Offsets>,
-                       Notes<'a, B>>
-    where Offsets: 'a + SectionHdrOffsets,
+                       Hashtab<'a, B, Offsets>,
+                       Dynamic<'a, B, Offsets>, Notes<'a, B>,
+                       PackedRelocs<Relrs<'a, B, Offsets>,
+                                    AndroidRels<'a, B, Offsets>,
+                                    AndroidRelas<'a, B, Offsets>>,
+                       AuxSections<GnuHashtab<'a, B, Offsets>,
+                                   AddrArray<'a, B, Offsets>, Group<'a, B>,
+                                   SymtabShndx<'a, B>>,
+                       VersionTables<Versyms<'a, B>, Verdefs<'a, B>,
+                                     Verneeds<'a, B>>>
+    where Offsets: 'a + SectionHdrOffsets + RelrOffsets + AndroidRelOffsets,
           B: 'a + ByteOrder {
     type Error = SectionHdrError<Offsets>;
 
@@ -2983,48 +5386,61 @@ impl<'a, B, Str, Offsets>
                        Strtab<'a>, &'a [u8],
                        Symtab<'a, B, Offsets>, Strtab<'a>,
                        Rels<'a, B, Offsets>, Relas<'a, B, Offsets>,
-                       Hashtab<'a, B, Offsets>, Dynamic<'a, B, Offsets>,
-                       Notes<'a, B>>,
+                       Hashtab<'a, B, Offsets>,
+                       Dynamic<'a, B, Offsets>, Notes<'a, B>,
+                       PackedRelocs<Relrs<'a, B, Offsets>,
+                                    AndroidRels<'a, B, Offsets>,
+                                    AndroidRelas<'a, B, Offsets>>,
+                       AuxSections<GnuHashtab<'a, B, Offsets>,
+                                   AddrArray<'a, B, Offsets>, Group<'a, B>,
+                                   SymtabShndx<'a, B>>,
+                       VersionTables<Versyms<'a, B>, Verdefs<'a, B>,
+                                     Verneeds<'a, B>>>,
                SectionHdrError<Offsets>> {
         match data {
             SectionHdrData::Null => Ok(SectionHdrData::Null),
-            SectionHdrData::ProgBits { name, addr, align, alloc,
-                                       write, exec, data } => {
+            SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                      num_prog_hdrs } =>
+                Ok(SectionHdrData::ExtNull {
+                    num_section_hdrs: num_section_hdrs,
+                    section_hdr_strtab: section_hdr_strtab,
+                    num_prog_hdrs: num_prog_hdrs
+                }),
+            SectionHdrData::ProgBits { name, addr, align, ent_size, flags,
+                                       data } => {
                 Ok(SectionHdrData::ProgBits {
-                    name: name, addr: addr, align: align,  alloc: alloc,
-                    write: write, exec: exec, data: data
+                    name: name, addr: addr, align: align, ent_size: ent_size,
+                    flags: flags, data: data
                 })
             },
-            SectionHdrData::Symtab { name, local_end, addr, align, write,
-                                     alloc, exec, strtab, syms } => {
+            SectionHdrData::Symtab { name, local_end, addr, align, flags,
+                                     strtab, syms } => {
                 match (Symtab::try_from(syms), Strtab::try_from(strtab)) {
                     (Ok(syms), Ok(strtab)) =>
                         Ok(SectionHdrData::Symtab {
-                            name: name, addr: addr, align: align, write: write,
-                            strtab: strtab, exec: exec, local_end: local_end,
-                            alloc: alloc, syms: syms
+                            name: name, addr: addr, align: align, flags: flags,
+                            strtab: strtab, local_end: local_end, syms: syms
                         }),
                     (_, Err(err)) => Err(SectionHdrError::StrtabErr(err)),
                     (Err(err), _) => Err(SectionHdrError::SymtabErr(err))
                 }
             },
-            SectionHdrData::Strtab { name, addr, align, strs } => {
+            SectionHdrData::Strtab { name, addr, align, strs, flags } => {
                 match Strtab::try_from(strs) {
                     Ok(strs) =>
                         Ok(SectionHdrData::Strtab { name: name, addr: addr,
-                                                    align: align, strs: strs }),
+                                                    align: align, strs: strs,
+                                                    flags: flags }),
                     Err(err) => Err(SectionHdrError::StrtabErr(err))
                 }
             },
-            SectionHdrData::Rela { symtab: SymsStrs { syms, strs },
-                                   name, addr, align, write, alloc,
-                                   exec, target, relas } => {
+            SectionHdrData::Rela { symtab: SymsStrs { syms, strs }, name, addr,
+                                   align, flags, target, relas } => {
                 match (Relas::try_from(relas), Symtab::try_from(syms),
                        Strtab::try_from(strs)) {
                     (Ok(relas), Ok(syms), Ok(strs)) =>
                         Ok(SectionHdrData::Rela {
-                            name: name, align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
+                            name: name, align: align, addr: addr, flags: flags,
                             symtab: SymsStrs { syms: syms, strs: strs },
                             target: target, relas: relas
                         }),
@@ -3033,7 +5449,7 @@ impl<'a, B, Str, Offsets>
                     (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
                 }
             },
-            SectionHdrData::Hash { name, addr, align, write, alloc, exec, hash,
+            SectionHdrData::Hash { name, addr, align, flags, hash,
                                    symtab: SymsStrs { syms, strs } } => {
                 match (Symtab::try_from(syms), Strtab::try_from(strs)) {
                     (Ok(syms), Ok(strs)) =>
@@ -3041,7 +5457,7 @@ impl<'a, B, Str, Offsets>
                             Ok(hash) =>
                                 Ok(SectionHdrData::Hash {
                                     name: name, addr: addr, align: align,
-                                    write: write, exec: exec, alloc: alloc,
+                                    flags: flags,
                                     symtab: SymsStrs { syms: syms, strs: strs },
                                     hash: hash
                                 }),
@@ -3051,46 +5467,42 @@ impl<'a, B, Str, Offsets>
                     (_, Err(err)) => Err(SectionHdrError::StrtabErr(err))
                 }
             },
-            SectionHdrData::Dynamic { name, align, addr, write, alloc,
-                                      exec, strtab, dynamic } => {
+            SectionHdrData::Dynamic { name, align, addr, flags, strtab,
+                                      dynamic } => {
                 match (Dynamic::try_from(dynamic), Strtab::try_from(strtab)) {
                     (Ok(dynamic), Ok(strtab)) =>
                         Ok(SectionHdrData::Dynamic {
-                            name: name, align: align, addr: addr, write: write,
-                            alloc: alloc, exec: exec, strtab: strtab,
-                            dynamic: dynamic,
+                            name: name, align: align, addr: addr, flags: flags,
+                            strtab: strtab, dynamic: dynamic
                         }),
                     (Err(err), _) => Err(SectionHdrError::DynamicErr(err)),
                     (_, Err(err)) => Err(SectionHdrError::StrtabErr(err))
                 }
             },
-            SectionHdrData::Note { name, addr, align, write,
-                                   alloc, exec, note } => {
+            SectionHdrData::Note { name, addr, align, flags, note } => {
                 match Notes::try_from(note) {
                     Ok(note) =>
                         Ok(SectionHdrData::Note {
-                            name: name, addr: addr, align: align, write: write,
-                            alloc: alloc, exec: exec, note: note
+                            name: name, addr: addr, align: align, flags: flags,
+                            note: note
                         }),
                     Err(err) => Err(SectionHdrError::NoteErr(err))
                 }
             },
             SectionHdrData::Nobits { name, addr, align, offset, size,
-                                     write, alloc, exec } => {
+                                     flags } => {
                 Ok(SectionHdrData::Nobits {
                     name: name, addr: addr, size: size, offset: offset,
-                    align: align, write: write, alloc: alloc, exec: exec
+                    align: align, flags: flags
                 })
             },
-            SectionHdrData::Rel { symtab: SymsStrs { syms, strs },
-                                  name, addr, align, write, alloc,
-                                  exec, target, rels } => {
+            SectionHdrData::Rel { symtab: SymsStrs { syms, strs }, name, addr,
+                                  align, flags, target, rels } => {
                 match (Rels::try_from(rels), Symtab::try_from(syms),
                        Strtab::try_from(strs)) {
                     (Ok(rels), Ok(syms), Ok(strs)) =>
                         Ok(SectionHdrData::Rel {
-                            name: name, align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
+                            name: name, align: align, addr: addr, flags: flags,
                             symtab: SymsStrs { syms: syms, strs: strs },
                             target: target, rels: rels
                         }),
@@ -3099,19 +5511,182 @@ impl<'a, B, Str, Offsets>
                     (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
                 }
             },
-            SectionHdrData::Dynsym { name, local_end, addr, align, write,
-                                     alloc, exec, strtab, syms } => {
+            SectionHdrData::Dynsym { name, local_end, addr, align, flags,
+                                     strtab, syms } => {
                 match (Symtab::try_from(syms), Strtab::try_from(strtab)) {
                     (Ok(syms), Ok(strtab)) =>
                         Ok(SectionHdrData::Dynsym {
-                            name: name, addr: addr, align: align, write: write,
-                            strtab: strtab, exec: exec, local_end: local_end,
-                            alloc: alloc, syms: syms
+                            name: name, addr: addr, align: align, flags: flags,
+                            strtab: strtab, local_end: local_end, syms: syms
                         }),
                     (_, Err(err)) => Err(SectionHdrError::StrtabErr(err)),
                     (Err(err), _) => Err(SectionHdrError::SymtabErr(err))
                 }
             },
+            SectionHdrData::InitArray { name, addr, align, ent_size, flags,
+                                        addrs } => {
+                match AddrArray::try_from(addrs) {
+                    Ok(addrs) =>
+                        Ok(SectionHdrData::InitArray {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags, addrs: addrs
+                        }),
+                    Err(err) => Err(SectionHdrError::AddrArrayErr(err))
+                }
+            },
+            SectionHdrData::FiniArray { name, addr, align, ent_size, flags,
+                                        addrs } => {
+                match AddrArray::try_from(addrs) {
+                    Ok(addrs) =>
+                        Ok(SectionHdrData::FiniArray {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags, addrs: addrs
+                        }),
+                    Err(err) => Err(SectionHdrError::AddrArrayErr(err))
+                }
+            },
+            SectionHdrData::PreInitArray { name, addr, align, ent_size,
+                                           flags, addrs } => {
+                match AddrArray::try_from(addrs) {
+                    Ok(addrs) =>
+                        Ok(SectionHdrData::PreInitArray {
+                            name: name, addr: addr, align: align,
+                            ent_size: ent_size, flags: flags, addrs: addrs
+                        }),
+                    Err(err) => Err(SectionHdrError::AddrArrayErr(err))
+                }
+            },
+            SectionHdrData::Group { name, addr, align, flags, group, sym,
+                                    symtab: SymsStrs { syms, strs } } => {
+                match (Group::try_from(group), Symtab::try_from(syms),
+                       Strtab::try_from(strs)) {
+                    (Ok(group), Ok(syms), Ok(strs)) =>
+                        Ok(SectionHdrData::Group {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: SymsStrs { syms: syms, strs: strs },
+                            sym: sym, group: group
+                        }),
+                    (Err(err), _, _) => Err(SectionHdrError::GroupErr(err)),
+                    (_, Err(err), _) => Err(SectionHdrError::SymtabErr(err)),
+                    (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::SymtabShndx { name, addr, align, flags, shndxs,
+                                          symtab: SymsStrs { syms, strs } } => {
+                match (SymtabShndx::try_from(shndxs), Symtab::try_from(syms),
+                       Strtab::try_from(strs)) {
+                    (Ok(shndxs), Ok(syms), Ok(strs)) =>
+                        Ok(SectionHdrData::SymtabShndx {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: SymsStrs { syms: syms, strs: strs },
+                            shndxs: shndxs
+                        }),
+                    (Err(err), _, _) =>
+                        Err(SectionHdrError::SymtabShndxErr(err)),
+                    (_, Err(err), _) => Err(SectionHdrError::SymtabErr(err)),
+                    (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::Relr { name, addr, align, flags, relrs } => {
+                match Relrs::try_from(relrs) {
+                    Ok(relrs) =>
+                        Ok(SectionHdrData::Relr {
+                            name: name, addr: addr, align: align, flags: flags,
+                            relrs: relrs
+                        }),
+                    Err(err) => Err(SectionHdrError::RelrsErr(err))
+                }
+            },
+            SectionHdrData::AndroidRel { symtab: SymsStrs { syms, strs }, name,
+                                         addr, align, flags, target, rels } => {
+                match (AndroidRels::try_from(rels), Symtab::try_from(syms),
+                       Strtab::try_from(strs)) {
+                    (Ok(rels), Ok(syms), Ok(strs)) =>
+                        Ok(SectionHdrData::AndroidRel {
+                            name: name, align: align, addr: addr, flags: flags,
+                            symtab: SymsStrs { syms: syms, strs: strs },
+                            target: target, rels: rels
+                        }),
+                    (Err(err), _, _) =>
+                        Err(SectionHdrError::AndroidRelocsErr(err)),
+                    (_, Err(err), _) => Err(SectionHdrError::SymtabErr(err)),
+                    (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::AndroidRela { symtab: SymsStrs { syms, strs }, name,
+                                          addr, align, flags, target,
+                                          relas } => {
+                match (AndroidRelas::try_from(relas), Symtab::try_from(syms),
+                       Strtab::try_from(strs)) {
+                    (Ok(relas), Ok(syms), Ok(strs)) =>
+                        Ok(SectionHdrData::AndroidRela {
+                            name: name, align: align, addr: addr, flags: flags,
+                            symtab: SymsStrs { syms: syms, strs: strs },
+                            target: target, relas: relas
+                        }),
+                    (Err(err), _, _) =>
+                        Err(SectionHdrError::AndroidRelocsErr(err)),
+                    (_, Err(err), _) => Err(SectionHdrError::SymtabErr(err)),
+                    (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::GnuHash { name, addr, align, flags, hash,
+                                      symtab: SymsStrs { syms, strs } } => {
+                match (Symtab::try_from(syms), Strtab::try_from(strs)) {
+                    (Ok(syms), Ok(strs)) =>
+                        match GnuHashtab::from_slice(hash, strs, syms) {
+                            Ok(hash) =>
+                                Ok(SectionHdrData::GnuHash {
+                                    name: name, addr: addr, align: align,
+                                    flags: flags,
+                                    symtab: SymsStrs { syms: syms, strs: strs },
+                                    hash: hash
+                                }),
+                            Err(err) => Err(SectionHdrError::GnuHashErr(err))
+                        },
+                    (Err(err), _) => Err(SectionHdrError::SymtabErr(err)),
+                    (_, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::Versym { name, addr, align, flags, versyms,
+                                     symtab: SymsStrs { syms, strs } } => {
+                match (Versyms::try_from(versyms), Symtab::try_from(syms),
+                       Strtab::try_from(strs)) {
+                    (Ok(versyms), Ok(syms), Ok(strs)) =>
+                        Ok(SectionHdrData::Versym {
+                            name: name, addr: addr, align: align, flags: flags,
+                            symtab: SymsStrs { syms: syms, strs: strs },
+                            versyms: versyms
+                        }),
+                    (Err(err), _, _) => Err(SectionHdrError::VersymsErr(err)),
+                    (_, Err(err), _) => Err(SectionHdrError::SymtabErr(err)),
+                    (_, _, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::Verdef { name, addr, align, flags, count, strtab,
+                                     verdefs } => {
+                match (Verdefs::try_from(verdefs), Strtab::try_from(strtab)) {
+                    (Ok(verdefs), Ok(strtab)) =>
+                        Ok(SectionHdrData::Verdef {
+                            name: name, addr: addr, align: align, flags: flags,
+                            count: count, strtab: strtab, verdefs: verdefs
+                        }),
+                    (Err(err), _) => Err(SectionHdrError::VerdefsErr(err)),
+                    (_, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
+            SectionHdrData::Verneed { name, addr, align, flags, count, strtab,
+                                      verneeds } => {
+                match (Verneeds::try_from(verneeds), Strtab::try_from(strtab)) {
+                    (Ok(verneeds), Ok(strtab)) =>
+                        Ok(SectionHdrData::Verneed {
+                            name: name, addr: addr, align: align, flags: flags,
+                            count: count, strtab: strtab, verneeds: verneeds
+                        }),
+                    (Err(err), _) => Err(SectionHdrError::VerneedsErr(err)),
+                    (_, Err(err)) => Err(SectionHdrError::StrtabErr(err))
+                }
+            },
             SectionHdrData::Unknown { name, tag, addr, align, offset, size,
                                       link, info, ent_size, flags } => {
                 Ok(SectionHdrData::Unknown { name: name, tag: tag, addr: addr,
@@ -3123,109 +5698,215 @@ impl<'a, B, Str, Offsets>
     }
 }
 
-impl<'a, Offsets, HdrRef, SymsRef, StrsRef, Data,
-     Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
+impl<'a, Offsets, HdrRef, SymsRef, StrsRef, Data, Syms, Strs, Rels,
+     Relas, Hash, Dynamic, Note, Packed, Aux, Versions>
     TryFrom<SectionHdrData<Offsets, Result<&'a str, &'a [u8]>, HdrRef,
                            SymsRef, StrsRef, Data, Syms, Strs, Rels,
-                           Relas, Hash, Dynamic, Note>>
+                           Relas, Hash, Dynamic, Note, Packed, Aux,
+                           Versions>>
     for SectionHdrData<Offsets, &'a str, HdrRef, SymsRef, StrsRef, Data,
-                       Syms, Strs, Rels, Relas, Hash, Dynamic, Note>
-    where Offsets: 'a + SectionHdrOffsets {
+                       Syms, Strs, Rels, Relas, Hash,
+                       Dynamic, Note, Packed, Aux, Versions>
+    where Offsets: 'a + SectionHdrOffsets,
+          Packed: PackedRelocTypes,
+          Aux: AuxSectionTypes,
+          Versions: VersionTableTypes {
     type Error = &'a [u8];
 
     #[inline]
     fn try_from(data: SectionHdrData<Offsets, Result<&'a str, &'a [u8]>, HdrRef,
                                      SymsRef, StrsRef, Data, Syms, Strs, Rels,
-                                     Relas, Hash, Dynamic, Note>) ->
+                                     Relas, Hash, Dynamic, Note, Packed, Aux,
+                                     Versions>) ->
         Result<SectionHdrData<Offsets, &'a str, HdrRef, SymsRef, StrsRef, Data,
-                              Syms, Strs, Rels, Relas, Hash, Dynamic, Note>,
+                              Syms, Strs, Rels, Relas, Hash, Dynamic, Note,
+                              Packed, Aux, Versions>,
                &'a [u8]> {
         match data {
             SectionHdrData::Null => Ok(SectionHdrData::Null),
-            SectionHdrData::ProgBits { name: Ok(name), addr, align, alloc,
-                                       write, exec, data } => {
+            SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                      num_prog_hdrs } =>
+                Ok(SectionHdrData::ExtNull {
+                    num_section_hdrs: num_section_hdrs,
+                    section_hdr_strtab: section_hdr_strtab,
+                    num_prog_hdrs: num_prog_hdrs
+                }),
+            SectionHdrData::ProgBits { name: Ok(name), addr, align, ent_size,
+                                       flags, data } => {
                 Ok(SectionHdrData::ProgBits {
-                    name: name, addr: addr, align: align,  alloc: alloc,
-                    write: write, exec: exec, data: data
+                    name: name, addr: addr, align: align, ent_size: ent_size,
+                    flags: flags, data: data
                 })
             },
             SectionHdrData::ProgBits { name: Err(err), .. } => Err(err),
             SectionHdrData::Symtab { name: Ok(name), local_end, addr, align,
-                                     write, alloc, exec, strtab, syms } => {
+                                     flags, strtab, syms } => {
                 Ok(SectionHdrData::Symtab {
-                    name: name, addr: addr, align: align, write: write,
-                    strtab: strtab, exec: exec, local_end: local_end,
-                    alloc: alloc, syms: syms
+                    name: name, addr: addr, align: align, flags: flags,
+                    strtab: strtab, local_end: local_end, syms: syms
                 })
             },
             SectionHdrData::Symtab { name: Err(err), .. } => Err(err),
-            SectionHdrData::Strtab { name: Ok(name), addr, align, strs } => {
+            SectionHdrData::Strtab { name: Ok(name), addr, align, strs,
+                                     flags } => {
                 Ok(SectionHdrData::Strtab {
-                    name: name, addr: addr, align: align, strs: strs
+                    name: name, addr: addr, align: align, strs: strs,
+                    flags: flags
                 })
             },
             SectionHdrData::Strtab { name: Err(err), .. } => Err(err),
-            SectionHdrData::Rela { name: Ok(name), symtab, addr, align, write,
-                                   alloc, exec, target, relas } => {
+            SectionHdrData::Rela { name: Ok(name), symtab, addr, align, flags,
+                                   target, relas } => {
                 Ok(SectionHdrData::Rela {
-                    name: name, align: align, addr: addr,
-                    write: write, alloc: alloc, exec: exec,
+                    name: name, align: align, addr: addr, flags: flags,
                     symtab: symtab, target: target, relas: relas
                 })
             },
             SectionHdrData::Rela { name: Err(err), .. } => Err(err),
-            SectionHdrData::Hash { name: Ok(name), addr, align, write, alloc,
-                                   symtab, exec, hash } => {
+            SectionHdrData::Hash { name: Ok(name), addr, align, flags, symtab,
+                                   hash } => {
                 Ok(SectionHdrData::Hash {
-                    name: name, addr: addr, align: align,
-                    write: write, exec: exec, alloc: alloc,
+                    name: name, addr: addr, align: align, flags: flags,
                     symtab: symtab, hash: hash
                 })
             },
             SectionHdrData::Hash { name: Err(err), ..} => Err(err),
-            SectionHdrData::Dynamic { name: Ok(name), align, addr, write, alloc,
-                                      exec, strtab, dynamic } => {
+            SectionHdrData::Dynamic { name: Ok(name), align, addr, flags,
+                                      strtab, dynamic } => {
                 Ok(SectionHdrData::Dynamic {
-                    name: name, align: align, addr: addr, write: write,
-                    alloc: alloc, exec: exec, strtab: strtab,
-                    dynamic: dynamic,
+                    name: name, align: align, addr: addr, flags: flags,
+                    strtab: strtab, dynamic: dynamic
                 })
             },
             SectionHdrData::Dynamic { name: Err(err), .. } => Err(err),
-            SectionHdrData::Note { name: Ok(name), addr, align, write,
-                                   alloc, exec, note } => {
+            SectionHdrData::Note { name: Ok(name), addr, align, flags,
+                                   note } => {
                 Ok(SectionHdrData::Note {
-                    name: name, addr: addr, align: align, write: write,
-                    alloc: alloc, exec: exec, note: note
+                    name: name, addr: addr, align: align, flags: flags,
+                    note: note
                 })
             },
             SectionHdrData::Note { name: Err(err), .. } => Err(err),
             SectionHdrData::Nobits { name: Ok(name), addr, align, offset, size,
-                                     write, alloc, exec } => {
+                                     flags } => {
                 Ok(SectionHdrData::Nobits {
                     name: name, addr: addr, size: size, offset: offset,
-                    align: align, write: write, alloc: alloc, exec: exec
+                    align: align, flags: flags
                 })
             },
             SectionHdrData::Nobits { name: Err(err), .. } => Err(err),
-            SectionHdrData::Rel { name: Ok(name), symtab, addr, align, write,
-                                  alloc, exec, target, rels } => {
+            SectionHdrData::Rel { name: Ok(name), symtab, addr, align, flags,
+                                  target, rels } => {
                 Ok(SectionHdrData::Rel {
-                    name: name, align: align, addr: addr,
-                    write: write, alloc: alloc, exec: exec,
+                    name: name, align: align, addr: addr, flags: flags,
                     symtab: symtab, target: target, rels: rels
                 })
             },
             SectionHdrData::Rel { name: Err(err), .. } => Err(err),
             SectionHdrData::Dynsym { name: Ok(name), local_end, addr, align,
-                                     write, alloc, exec, strtab, syms } => {
+                                     flags, strtab, syms } => {
                 Ok(SectionHdrData::Dynsym {
-                    name: name, addr: addr, align: align, write: write,
-                    strtab: strtab, exec: exec, local_end: local_end,
-                    alloc: alloc, syms: syms
+                    name: name, addr: addr, align: align, flags: flags,
+                    strtab: strtab, local_end: local_end, syms: syms
                 })
             },
             SectionHdrData::Dynsym { name: Err(err), .. } => Err(err),
+            SectionHdrData::InitArray { name: Ok(name), addr, align, flags,
+                                        addrs, ent_size } => {
+                Ok(SectionHdrData::InitArray {
+                    name: name, addr: addr, align: align, flags: flags,
+                    addrs: addrs, ent_size: ent_size
+                })
+            },
+            SectionHdrData::InitArray { name: Err(err), .. } => Err(err),
+            SectionHdrData::FiniArray { name: Ok(name), addr, align, flags,
+                                        addrs, ent_size } => {
+                Ok(SectionHdrData::FiniArray {
+                    name: name, addr: addr, align: align, flags: flags,
+                    addrs: addrs, ent_size: ent_size
+                })
+            },
+            SectionHdrData::FiniArray { name: Err(err), .. } => Err(err),
+            SectionHdrData::PreInitArray { name: Ok(name), addr, align, flags,
+                                           addrs, ent_size } => {
+                Ok(SectionHdrData::PreInitArray {
+                    name: name, addr: addr, align: align, flags: flags,
+                    addrs: addrs, ent_size: ent_size
+                })
+            },
+            SectionHdrData::PreInitArray { name: Err(err), .. } => Err(err),
+            SectionHdrData::Group { name: Ok(name), addr, align, flags, symtab,
+                                    sym, group } => {
+                Ok(SectionHdrData::Group {
+                    name: name, addr: addr, align: align, flags: flags,
+                    symtab: symtab, sym: sym, group: group
+                })
+            },
+            SectionHdrData::Group { name: Err(err), .. } => Err(err),
+            SectionHdrData::SymtabShndx { name: Ok(name), addr, align, flags,
+                                          symtab, shndxs } => {
+                Ok(SectionHdrData::SymtabShndx {
+                    name: name, addr: addr, align: align, flags: flags,
+                    symtab: symtab, shndxs: shndxs
+                })
+            },
+            SectionHdrData::SymtabShndx { name: Err(err), .. } => Err(err),
+            SectionHdrData::Relr { name: Ok(name), addr, align, flags,
+                                   relrs } => {
+                Ok(SectionHdrData::Relr {
+                    name: name, addr: addr, align: align, flags: flags,
+                    relrs: relrs
+                })
+            },
+            SectionHdrData::Relr { name: Err(err), .. } => Err(err),
+            SectionHdrData::AndroidRel { name: Ok(name), symtab, addr, align,
+                                         flags, target, rels } => {
+                Ok(SectionHdrData::AndroidRel {
+                    name: name, align: align, addr: addr, flags: flags,
+                    symtab: symtab, target: target, rels: rels
+                })
+            },
+            SectionHdrData::AndroidRel { name: Err(err), .. } => Err(err),
+            SectionHdrData::AndroidRela { name: Ok(name), symtab, addr, align,
+                                          flags, target, relas } => {
+                Ok(SectionHdrData::AndroidRela {
+                    name: name, align: align, addr: addr, flags: flags,
+                    symtab: symtab, target: target, relas: relas
+                })
+            },
+            SectionHdrData::AndroidRela { name: Err(err), .. } => Err(err),
+            SectionHdrData::GnuHash { name: Ok(name), addr, align, flags,
+                                      symtab, hash } => {
+                Ok(SectionHdrData::GnuHash {
+                    name: name, addr: addr, align: align, flags: flags,
+                    symtab: symtab, hash: hash
+                })
+            },
+            SectionHdrData::GnuHash { name: Err(err), ..} => Err(err),
+            SectionHdrData::Versym { name: Ok(name), addr, align, flags, symtab,
+                                     versyms } => {
+                Ok(SectionHdrData::Versym {
+                    name: name, addr: addr, align: align, flags: flags,
+                    symtab: symtab, versyms: versyms
+                })
+            },
+            SectionHdrData::Versym { name: Err(err), .. } => Err(err),
+            SectionHdrData::Verdef { name: Ok(name), addr, align, flags, strtab,
+                                     count, verdefs } => {
+                Ok(SectionHdrData::Verdef {
+                    name: name, addr: addr, align: align, flags: flags,
+                    strtab: strtab, count: count, verdefs: verdefs
+                })
+            },
+            SectionHdrData::Verdef { name: Err(err), .. } => Err(err),
+            SectionHdrData::Verneed { name: Ok(name), addr, align, flags,
+                                      strtab, count, verneeds } => {
+                Ok(SectionHdrData::Verneed {
+                    name: name, addr: addr, align: align, flags: flags,
+                    strtab: strtab, count: count, verneeds: verneeds
+                })
+            },
+            SectionHdrData::Verneed { name: Err(err), .. } => Err(err),
             SectionHdrData::Unknown { name: Ok(name), tag, addr, align, offset,
                                       size, link, info, ent_size, flags } => {
                 Ok(SectionHdrData::Unknown { name: name, tag: tag, addr: addr,
@@ -3238,15 +5919,20 @@ impl<'a, Offsets, HdrRef, SymsRef, StrsRef, Data,
     }
 }
 
-impl<'a, Offsets, HdrRef, SymsRef, StrsRef, Data,
-     Syms, Strs, Rels, Relas, Hash, Dynamic, Note> WithStrtab<'a>
+impl<'a, Offsets, HdrRef, SymsRef, StrsRef, Data, Syms, Strs, Rels,
+     Relas, Hash, Dynamic, Note, Packed, Aux, Versions>
+    WithStrtab<'a>
     for SectionHdrData<Offsets, Offsets::Word, HdrRef, SymsRef,
                        StrsRef, Data, Syms, Strs, Rels, Relas, Hash,
-                       Dynamic, Note>
-    where Offsets: 'a + SectionHdrOffsets {
+                       Dynamic, Note, Packed, Aux, Versions>
+    where Offsets: 'a + SectionHdrOffsets,
+          Packed: PackedRelocTypes,
+          Aux: AuxSectionTypes,
+          Versions: VersionTableTypes {
     type Result = SectionHdrData<Offsets, Result<&'a str, &'a [u8]>, HdrRef,
                                  SymsRef, StrsRef, Data, Syms, Strs, Rels,
-                                 Relas, Hash, Dynamic, Note>;
+                                 Relas, Hash, Dynamic, Note, Packed, Aux,
+                                 Versions>;
     type Error = Offsets::Word;
 
     #[inline]
@@ -3254,171 +5940,371 @@ impl<'a, Offsets, HdrRef, SymsRef, StrsRef, Data,
         Result<Self::Result, Self::Error> {
         match self {
             SectionHdrData::Null => Ok(SectionHdrData::Null),
-            SectionHdrData::ProgBits { name, addr, align, alloc,
-                                       write, exec, data } => {
+            SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                      num_prog_hdrs } =>
+                Ok(SectionHdrData::ExtNull {
+                    num_section_hdrs: num_section_hdrs,
+                    section_hdr_strtab: section_hdr_strtab,
+                    num_prog_hdrs: num_prog_hdrs
+                }),
+            SectionHdrData::ProgBits { name, addr, align, ent_size, flags,
+                                       data } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::ProgBits {
                             name: Ok(name), addr: addr, align: align,
-                            alloc: alloc, write: write, exec: exec, data: data
+                            ent_size: ent_size, flags: flags, data: data
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::ProgBits {
                             name: Err(name), addr: addr, align: align,
-                            alloc: alloc, write: write, exec: exec, data: data
+                            ent_size: ent_size, flags: flags, data: data
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Symtab { name, local_end, addr, align, write,
-                                     alloc, exec, strtab, syms } => {
+            SectionHdrData::Symtab { name, local_end, addr, align, flags,
+                                     strtab, syms } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Symtab {
                             name: Ok(name), addr: addr, align: align,
-                            write: write, strtab: strtab, exec: exec,
-                            local_end: local_end, alloc: alloc, syms: syms
+                            flags: flags, strtab: strtab, local_end: local_end,
+                            syms: syms
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Symtab {
                             name: Err(name), addr: addr, align: align,
-                            write: write, strtab: strtab, exec: exec,
-                            local_end: local_end, alloc: alloc, syms: syms
+                            flags: flags, strtab: strtab, local_end: local_end,
+                            syms: syms
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Strtab { name, addr, align, strs } => {
+            SectionHdrData::Strtab { name, addr, align, strs, flags } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Strtab { name: Ok(name), addr: addr,
-                                                    align: align, strs: strs }),
+                                                    align: align, strs: strs,
+                                                    flags: flags }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Strtab { name: Err(name), addr: addr,
-                                                    align: align, strs: strs }),
+                                                    align: align, strs: strs,
+                                                    flags: flags }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Rela { name, addr, align, write, alloc,
-                                   exec, target, symtab, relas } => {
+            SectionHdrData::Rela { name, addr, align, flags, target, symtab,
+                                   relas } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Rela {
                             name: Ok(name), align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            symtab: symtab, target: target, relas: relas
+                            flags: flags, symtab: symtab, target: target,
+                            relas: relas
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Rela {
                             name: Err(name), align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            symtab: symtab, target: target, relas: relas
+                            flags: flags, symtab: symtab, target: target,
+                            relas: relas
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Hash { name, addr, align, write, alloc, exec,
-                                   hash, symtab } => {
+            SectionHdrData::Hash { name, addr, align, flags, hash, symtab } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Hash {
                             name: Ok(name), addr: addr, align: align,
-                            write: write, exec: exec, alloc: alloc,
-                            symtab: symtab, hash: hash
+                            flags: flags, symtab: symtab, hash: hash
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Hash {
                             name: Err(name), addr: addr, align: align,
-                            write: write, exec: exec, alloc: alloc,
-                            symtab: symtab, hash: hash
+                            flags: flags, symtab: symtab, hash: hash
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Dynamic { name, align, addr, write, alloc,
-                                      exec, strtab, dynamic } => {
+            SectionHdrData::Dynamic { name, align, addr, flags, strtab,
+                                      dynamic } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Dynamic {
                             name: Ok(name), align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            strtab: strtab, dynamic: dynamic,
+                            flags: flags, strtab: strtab, dynamic: dynamic
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Dynamic {
                             name: Err(name), align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            strtab: strtab, dynamic: dynamic,
+                            flags: flags, strtab: strtab, dynamic: dynamic
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Note { name, addr, align, write,
-                                   alloc, exec, note } => {
+            SectionHdrData::Note { name, addr, align, flags, note } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Note {
                             name: Ok(name), addr: addr, align: align,
-                            write: write, alloc: alloc, exec: exec, note: note
+                            flags: flags, note: note
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Note {
                             name: Err(name), addr: addr, align: align,
-                            write: write, alloc: alloc, exec: exec, note: note
+                            flags: flags, note: note
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
             SectionHdrData::Nobits { name, addr, align, offset, size,
-                                     write, alloc, exec } => {
+                                     flags } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Nobits {
                             name: Ok(name), addr: addr, size: size,
-                            offset: offset, align: align, write: write,
-                            alloc: alloc, exec: exec
+                            offset: offset, align: align, flags: flags
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Nobits {
                             name: Err(name), addr: addr, size: size,
-                            offset: offset, align: align, write: write,
-                            alloc: alloc, exec: exec
+                            offset: offset, align: align, flags: flags
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Rel { name, addr, align, write, alloc,
-                                  exec, target, symtab, rels } => {
+            SectionHdrData::Rel { name, addr, align, flags, target, symtab,
+                                  rels } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Rel {
                             name: Ok(name), align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            symtab: symtab, target: target, rels: rels
+                            flags: flags, symtab: symtab, target: target,
+                            rels: rels
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Rel {
                             name: Err(name), align: align, addr: addr,
-                            write: write, alloc: alloc, exec: exec,
-                            symtab: symtab, target: target, rels: rels
+                            flags: flags, symtab: symtab, target: target,
+                            rels: rels
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
             },
-            SectionHdrData::Dynsym { name, local_end, addr, align, write,
-                                     alloc, exec, strtab, syms } => {
+            SectionHdrData::Dynsym { name, local_end, addr, align, flags,
+                                     strtab, syms } => {
                 match tab.idx(name) {
                     Ok(name) =>
                         Ok(SectionHdrData::Dynsym {
                             name: Ok(name), addr: addr, align: align,
-                            write: write, strtab: strtab, exec: exec,
-                            local_end: local_end, alloc: alloc, syms: syms
+                            flags: flags, strtab: strtab, local_end: local_end,
+                            syms: syms
                         }),
                     Err(StrtabIdxError::UTF8Decode(name)) =>
                         Ok(SectionHdrData::Dynsym {
                             name: Err(name), addr: addr, align: align,
-                            write: write, strtab: strtab, exec: exec,
-                            local_end: local_end, alloc: alloc, syms: syms
+                            flags: flags, strtab: strtab, local_end: local_end,
+                            syms: syms
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::InitArray { name, addr, align, flags, addrs,
+                                        ent_size } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::InitArray {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, addrs: addrs, ent_size: ent_size
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::InitArray {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, addrs: addrs, ent_size: ent_size
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::FiniArray { name, addr, align, flags, addrs,
+                                        ent_size } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::FiniArray {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, addrs: addrs, ent_size: ent_size
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::FiniArray {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, addrs: addrs, ent_size: ent_size
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::PreInitArray { name, addr, align, flags,
+                                           addrs, ent_size } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::PreInitArray {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, addrs: addrs, ent_size: ent_size
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::PreInitArray {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, addrs: addrs, ent_size: ent_size
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::Group { name, addr, align, flags, group, symtab,
+                                    sym } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::Group {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, group: group, symtab: symtab, sym: sym
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::Group {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, group: group, symtab: symtab, sym: sym
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::SymtabShndx { name, addr, align, flags, shndxs,
+                                          symtab } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::SymtabShndx {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, shndxs: shndxs, symtab: symtab
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::SymtabShndx {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, shndxs: shndxs, symtab: symtab
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::Relr { name, addr, align, flags, relrs } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::Relr {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, relrs: relrs
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::Relr {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, relrs: relrs
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::AndroidRel { name, addr, align, flags, target,
+                                         symtab, rels } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::AndroidRel {
+                            name: Ok(name), align: align, addr: addr,
+                            flags: flags, symtab: symtab, target: target,
+                            rels: rels
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::AndroidRel {
+                            name: Err(name), align: align, addr: addr,
+                            flags: flags, symtab: symtab, target: target,
+                            rels: rels
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::AndroidRela { name, addr, align, flags, target,
+                                          symtab, relas } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::AndroidRela {
+                            name: Ok(name), align: align, addr: addr,
+                            flags: flags, symtab: symtab, target: target,
+                            relas: relas
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::AndroidRela {
+                            name: Err(name), align: align, addr: addr,
+                            flags: flags, symtab: symtab, target: target,
+                            relas: relas
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::GnuHash { name, addr, align, flags, hash,
+                                      symtab } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::GnuHash {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, symtab: symtab, hash: hash
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::GnuHash {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, symtab: symtab, hash: hash
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::Versym { name, addr, align, flags, versyms,
+                                     symtab } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::Versym {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, versyms: versyms, symtab: symtab
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::Versym {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, versyms: versyms, symtab: symtab
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::Verdef { name, addr, align, flags, verdefs, strtab,
+                                     count } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::Verdef {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, verdefs: verdefs, strtab: strtab,
+                            count: count
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::Verdef {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, verdefs: verdefs, strtab: strtab,
+                            count: count
+                        }),
+                    Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
+                }
+            },
+            SectionHdrData::Verneed { name, addr, align, flags, verneeds,
+                                      strtab, count } => {
+                match tab.idx(name) {
+                    Ok(name) =>
+                        Ok(SectionHdrData::Verneed {
+                            name: Ok(name), addr: addr, align: align,
+                            flags: flags, verneeds: verneeds, strtab: strtab,
+                            count: count
+                        }),
+                    Err(StrtabIdxError::UTF8Decode(name)) =>
+                        Ok(SectionHdrData::Verneed {
+                            name: Err(name), addr: addr, align: align,
+                            flags: flags, verneeds: verneeds, strtab: strtab,
+                            count: count
                         }),
                     Err(StrtabIdxError::OutOfBounds(idx)) => Err(idx)
                 }
@@ -3453,7 +6339,7 @@ impl<'a, B, Offsets> TryFrom<SectionHdr<'a, B, Offsets>>
     fn try_from(ent: SectionHdr<'a, B, Offsets>) ->
         Result<SectionHdrDataRaw<Offsets>,
                SectionHdrError<Offsets>> {
-        project::<B, Offsets>(ent.ent)
+        project::<B, Offsets>(ent.ent, ent.first)
     }
 }
 
@@ -3467,7 +6353,7 @@ impl<'a, B, Offsets> TryFrom<SectionHdrMut<'a, B, Offsets>>
     fn try_from(ent: SectionHdrMut<'a, B, Offsets>) ->
         Result<SectionHdrDataRaw<Offsets>,
                SectionHdrError<Offsets>> {
-        project::<B, Offsets>(ent.ent)
+        project::<B, Offsets>(ent.ent, ent.first)
     }
 }
 
@@ -3503,7 +6389,8 @@ impl<'a, B, Offsets: SectionHdrOffsets> Iterator
             self.idx += n + 1;
 
             Some(SectionHdr { byteorder: PhantomData, offsets: PhantomData,
-                              ent: &self.hdrs[start .. end ] })
+                              ent: &self.hdrs[start .. end ],
+                              first: start == 0 })
         } else {
             None
         }
@@ -3594,11 +6481,64 @@ impl<Offset> Display for SectionPos<Offset>
     }
 }
 
-impl<'a, Offsets, Str, HdrRef, SymsRef, StrsRef, Data,
-     Syms, Strs, Rels, Relas, Hash, Dynamic, Note> Display
+impl Display for SectionFlag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SectionFlag::Write => write!(f, "write"),
+            SectionFlag::Alloc => write!(f, "alloc"),
+            SectionFlag::Exec => write!(f, "exec"),
+            SectionFlag::Merge => write!(f, "merge"),
+            SectionFlag::Strings => write!(f, "strings"),
+            SectionFlag::InfoLink => write!(f, "info link"),
+            SectionFlag::LinkOrder => write!(f, "link order"),
+            SectionFlag::OSNonConforming => write!(f, "OS nonconforming"),
+            SectionFlag::Group => write!(f, "group"),
+            SectionFlag::TLS => write!(f, "TLS"),
+            SectionFlag::Compressed => write!(f, "compressed"),
+            SectionFlag::GNURetain => write!(f, "GNU retain"),
+            SectionFlag::Exclude => write!(f, "exclude")
+        }
+    }
+}
+
+impl<Class> Display for SectionFlags<Class>
+    where Class: ElfClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        let mut first = true;
+
+        for flag in SECTION_FLAGS.iter().filter(|flag| self.contains(**flag)) {
+            if !first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", flag)?;
+            first = false;
+        }
+
+        let unknown = self.unknown();
+
+        if unknown != Class::Offset::from(0 as u8) {
+            if !first {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "0x{:x}", unknown)?;
+            first = false;
+        }
+
+        if first {
+            write!(f, "none")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, Offsets, Str, HdrRef, SymsRef, StrsRef, Data, Syms, Strs, Rels,
+     Relas, Hash, Dynamic, Note, Packed, Aux, Versions> Display
     for SectionHdrData<Offsets, Str, HdrRef, SymsRef,
                        StrsRef, Data, Syms, Strs, Rels, Relas, Hash,
-                       Dynamic, Note>
+                       Dynamic, Note, Packed, Aux, Versions>
     where Offsets: SectionHdrOffsets,
           Str: Display,
           HdrRef: Display,
@@ -3611,129 +6551,258 @@ impl<'a, Offsets, Str, HdrRef, SymsRef, StrsRef, Data,
           Relas: Display,
           Hash: Display,
           Dynamic: Display,
-          Note: Display {
+          Note: Display,
+          Packed: PackedRelocTypes,
+          Packed::Relr: Display,
+          Packed::AndroidRel: Display,
+          Packed::AndroidRela: Display,
+          Aux: AuxSectionTypes,
+          Aux::GnuHash: Display,
+          Aux::Array: Display,
+          Aux::Group: Display,
+          Aux::Shndx: Display,
+          Versions: VersionTableTypes,
+          Versions::Versym: Display,
+          Versions::Verdef: Display,
+          Versions::Verneed: Display {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
             SectionHdrData::Null => write!(f, "  Null"),
-            SectionHdrData::ProgBits { name, addr, align, alloc,
-                                       write, exec, data } =>
+            SectionHdrData::ExtNull { num_section_hdrs, section_hdr_strtab,
+                                      num_prog_hdrs } =>
+                write!(f, concat!("  Null (extended)\n",
+                                  "    Section headers: {}\n",
+                                  "    Section header string table: {}\n",
+                                  "    Program headers: {}"),
+                       num_section_hdrs, section_hdr_strtab, num_prog_hdrs),
+            SectionHdrData::ProgBits { name, addr, align, ent_size, flags,
+                                       data } =>
                 write!(f, concat!("  Program data\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Entry size: 0x{:x}\n",
+                                  "    Flags: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec, data),
-            SectionHdrData::Symtab { name, local_end, addr, align, write,
-                                     alloc, exec, strtab, syms } =>
+                       name, addr, align, ent_size, flags, data),
+            SectionHdrData::Symtab { name, local_end, addr, align, flags,
+                                     strtab, syms } =>
                 write!(f, concat!("  Symbol table\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    Last local symbol: {}\n",
                                   "    String table: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec,
+                       name, addr, align, flags,
                        local_end, strtab, syms),
-            SectionHdrData::Strtab { name, addr, align, strs } =>
+            SectionHdrData::Strtab { name, addr, align, strs, flags } =>
                 write!(f, concat!("  String table\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, strs),
-            SectionHdrData::Rela { name, addr, align, write, alloc,
-                                   exec, target, symtab, relas } =>
+                       name, addr, align, flags, strs),
+            SectionHdrData::Rela { name, addr, align, flags, target, symtab,
+                                   relas } =>
                 write!(f, concat!("  Relocations (with addends)\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    Target section: {}\n",
                                   "    Symbol table: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec,
+                       name, addr, align, flags,
                        target, symtab, relas),
-            SectionHdrData::Hash { name, addr, align, write, alloc, exec,
-                                   hash, symtab } =>
+            SectionHdrData::Hash { name, addr, align, flags, hash, symtab } =>
                 write!(f, concat!("  Hash table\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    Symbol table: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec, symtab, hash),
-            SectionHdrData::Dynamic { name, align, addr, write, alloc,
-                                      exec, strtab, dynamic } =>
+                       name, addr, align, flags, symtab, hash),
+            SectionHdrData::Dynamic { name, align, addr, flags, strtab,
+                                      dynamic } =>
                 write!(f, concat!("  Dynamic linking information\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    String table: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec, strtab, dynamic),
-            SectionHdrData::Note { name, addr, align, write,
-                                   alloc, exec, note } =>
+                       name, addr, align, flags, strtab, dynamic),
+            SectionHdrData::Note { name, addr, align, flags, note } =>
                 write!(f, concat!("  Notes\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec, note),
-            SectionHdrData::Nobits { name, addr, align, write,
-                                     alloc, exec, .. } =>
+                       name, addr, align, flags, note),
+            SectionHdrData::Nobits { name, addr, align, flags, .. } =>
                 write!(f, concat!("  Notes\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}"),
-                       name, addr, align, alloc, write, exec),
-            SectionHdrData::Rel { name, addr, align, write, alloc,
-                                  exec, target, symtab, rels } =>
+                                  "    Flags: {}"),
+                       name, addr, align, flags),
+            SectionHdrData::Rel { name, addr, align, flags, target, symtab,
+                                  rels } =>
                 write!(f, concat!("  Relocations (no addends)\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    Target section: {}\n",
                                   "    Symbol table: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec,
+                       name, addr, align, flags,
                        target, symtab, rels),
-            SectionHdrData::Dynsym { name, local_end, addr, align, write,
-                                     alloc, exec, strtab, syms } =>
+            SectionHdrData::Dynsym { name, local_end, addr, align, flags,
+                                     strtab, syms } =>
                 write!(f, concat!("  Dynamic linking symbol table\n",
                                   "    Name: {}\n",
                                   "    Address: 0x{:x}\n",
                                   "    Alignment: 0x{:x}\n",
-                                  "    Allocated: {}\n",
-                                  "    Writable: {}\n",
-                                  "    Executable: {}\n",
+                                  "    Flags: {}\n",
                                   "    Last local symbol: {}\n",
                                   "    String table: {}\n",
                                   "    Data: {}"),
-                       name, addr, align, alloc, write, exec,
+                       name, addr, align, flags,
                        local_end, strtab, syms),
+            SectionHdrData::InitArray { name, addr, align, ent_size, flags,
+                                        addrs } =>
+                write!(f, concat!("  Initialization functions\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Entry size: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, ent_size, flags, addrs),
+            SectionHdrData::FiniArray { name, addr, align, ent_size, flags,
+                                        addrs } =>
+                write!(f, concat!("  Finalization functions\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Entry size: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, ent_size, flags, addrs),
+            SectionHdrData::PreInitArray { name, addr, align, ent_size, flags,
+                                           addrs } =>
+                write!(f, concat!("  Pre-initialization functions\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Entry size: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, ent_size, flags, addrs),
+            SectionHdrData::Group { name, addr, align, flags, group, symtab,
+                                    sym } =>
+                write!(f, concat!("  Section group\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Symbol table: {}\n",
+                                  "    Signature symbol: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, symtab, sym,
+                       group),
+            SectionHdrData::SymtabShndx { name, addr, align, flags, shndxs,
+                                          symtab } =>
+                write!(f, concat!("  Extended section indexes\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Symbol table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, symtab, shndxs),
+            SectionHdrData::Relr { name, addr, align, flags, relrs } =>
+                write!(f, concat!("  Relative relocations\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, relrs),
+            SectionHdrData::AndroidRel { name, addr, align, flags, target,
+                                         symtab, rels } =>
+                write!(f, concat!("  Android relocations (no addends)\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Target section: {}\n",
+                                  "    Symbol table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags,
+                       target, symtab, rels),
+            SectionHdrData::AndroidRela { name, addr, align, flags, target,
+                                          symtab, relas } =>
+                write!(f, concat!("  Android relocations (with addends)\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Target section: {}\n",
+                                  "    Symbol table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags,
+                       target, symtab, relas),
+            SectionHdrData::GnuHash { name, addr, align, flags, hash,
+                                      symtab } =>
+                write!(f, concat!("  GNU hash table\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Symbol table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, symtab, hash),
+            SectionHdrData::Versym { name, addr, align, flags, versyms,
+                                     symtab } =>
+                write!(f, concat!("  Symbol versions\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Symbol table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, symtab, versyms),
+            SectionHdrData::Verdef { name, addr, align, flags, verdefs, strtab,
+                                     count } =>
+                write!(f, concat!("  Version definitions\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Count: {}\n",
+                                  "    String table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, count, strtab,
+                       verdefs),
+            SectionHdrData::Verneed { name, addr, align, flags, verneeds,
+                                      strtab, count } =>
+                write!(f, concat!("  Version requirements\n",
+                                  "    Name: {}\n",
+                                  "    Address: 0x{:x}\n",
+                                  "    Alignment: 0x{:x}\n",
+                                  "    Flags: {}\n",
+                                  "    Count: {}\n",
+                                  "    String table: {}\n",
+                                  "    Data: {}"),
+                       name, addr, align, flags, count, strtab,
+                       verneeds),
             SectionHdrData::Unknown { name, tag, addr, align, offset, size,
                                       link, info, ent_size, flags } =>
                 write!(f, concat!("  Unknown type 0x{:x}\n",
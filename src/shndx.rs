@@ -0,0 +1,587 @@
+//! ELF extended section index functionality.
+//!
+//! This module provides the [SymtabShndx] type, which acts as a
+//! wrapper around ELF extended section index tables (the
+//! `SHT_SYMTAB_SHNDX` sections, usually named `.symtab_shndx`).
+//!
+//! An extended section index table runs parallel to a symbol table,
+//! holding one section index for each symbol.  This is used when an
+//! object contains too many sections for the section index to fit in
+//! the symbol table entry itself.
+//!
+//! Symbols whose section is
+//! [SymBase::Escape](crate::symtab::SymBase::Escape) can be resolved
+//! using [with_shndx](crate::symtab::SymData::with_shndx), and
+//! the entries for a new table can be obtained with
+//! [shndx](crate::symtab::SymData::shndx).  A whole
+//! [Symtab](crate::symtab::Symtab) can be paired with its extended
+//! section index table using the [WithShndx] instance, which resolves
+//! the section index of each symbol automatically.
+//!
+//! # Examples
+//!
+//! A `SymtabShndx` can be created from any slice containing binary
+//! data that contains a whole number of section indexes:
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use elf_utils::shndx::SymtabShndx;
+//!
+//! const SHNDX: [u8; 12] = [
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+//!     0x01, 0x00, 0x01, 0x00
+//! ];
+//!
+//! let shndxs: SymtabShndx<'_, LittleEndian> =
+//!     SymtabShndx::try_from(&SHNDX[0..]).expect("Expected success");
+//! let mut iter = shndxs.iter();
+//!
+//! assert_eq!(iter.next(), Some(0));
+//! assert_eq!(iter.next(), Some(0x10000));
+//! assert_eq!(iter.next(), Some(0x10001));
+//! assert_eq!(iter.next(), None);
+//! ```
+//!
+//! Pairing a symbol table with its extended section index table:
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use core::convert::TryInto;
+//! use elf_utils::Elf64;
+//! use elf_utils::shndx::SymtabShndx;
+//! use elf_utils::shndx::SymtabWithShndx;
+//! use elf_utils::shndx::WithShndx;
+//! use elf_utils::symtab::Symtab;
+//! use elf_utils::symtab::SymBase;
+//! use elf_utils::symtab::SymDataExt;
+//!
+//! const SYMTAB: [u8; 48] = [
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x01, 0x00, 0x00, 0x00, 0x12, 0x00, 0xff, 0xff,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+//! ];
+//! const SHNDX: [u8; 8] = [
+//!     0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00
+//! ];
+//!
+//! let symtab: Symtab<'_, LittleEndian, Elf64> =
+//!     Symtab::try_from(&SYMTAB[0..]).expect("Expected success");
+//! let shndxs: SymtabShndx<'_, LittleEndian> =
+//!     SymtabShndx::try_from(&SHNDX[0..]).expect("Expected success");
+//! let syms: SymtabWithShndx<'_, LittleEndian, Elf64> =
+//!     symtab.with_shndx(shndxs).expect("Expected success");
+//! let sym: SymDataExt<Elf64> =
+//!     syms.idx(1).unwrap().try_into().expect("Expected success");
+//!
+//! assert_eq!(sym.section, SymBase::Index(0x10002));
+//! ```
+
+use byteorder::ByteOrder;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::iter::Iterator;
+use core::marker::PhantomData;
+use crate::elf::ElfClass;
+use crate::strtab::Strtab;
+use crate::strtab::WithStrtab;
+use crate::symtab::StrSymError;
+use crate::symtab::Sym;
+use crate::symtab::SymData;
+use crate::symtab::SymDataExt;
+use crate::symtab::SymDataRaw;
+use crate::symtab::SymError;
+use crate::symtab::SymOffsets;
+use crate::symtab::Symtab;
+use crate::symtab::SymtabIter;
+
+const SHNDX_SIZE: usize = 4;
+
+/// In-place read-only ELF extended section index table.
+///
+/// An extended section index table contains a section index for each
+/// symbol in the associated symbol table.  The entry for a symbol is
+/// only meaningful when the section index in the symbol table entry
+/// is `SHN_XINDEX`; otherwise it is zero.
+///
+/// A `SymtabShndx` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance.  New `SymtabShndx`s
+/// can be created from an iterator over section indexes with
+/// [create](SymtabShndx::create) or
+/// [create_split](SymtabShndx::create_split).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::shndx::SymtabShndx;
+///
+/// const SHNDX: [u8; 8] = [
+///     0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00
+/// ];
+///
+/// let shndxs: SymtabShndx<'_, LittleEndian> =
+///     SymtabShndx::try_from(&SHNDX[0..]).expect("Expected success");
+///
+/// assert_eq!(shndxs.num_shndxs(), 2);
+/// assert_eq!(shndxs.idx(1), Some(0x10002));
+/// assert_eq!(shndxs.idx(2), None);
+/// ```
+#[derive(Copy, Clone)]
+pub struct SymtabShndx<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8]
+}
+
+/// Trait for things that can be converted from one type to another
+/// with the use of a [SymtabShndx].
+///
+/// This is used to pair a [Symtab] with its extended section index
+/// table, so that symbols with a section index of
+/// [SymBase::Escape](crate::symtab::SymBase::Escape) are resolved
+/// without having to index the table by hand.
+pub trait WithShndx<'a, B: ByteOrder> {
+    /// Result of conversion.
+    type Result;
+    /// Errors that can occur.
+    type Error;
+
+    /// Consume the caller to convert it using `shndxs`.
+    fn with_shndx(self, shndxs: SymtabShndx<'a, B>) ->
+        Result<Self::Result, Self::Error>;
+}
+
+/// A [Symtab] paired with its extended section index table.
+///
+/// This is obtained from the [WithShndx] instance on a [Symtab].
+/// Symbols obtained from this will have their extended section
+/// indexes resolved when projected to a [SymDataExt].
+#[derive(Copy, Clone)]
+pub struct SymtabWithShndx<'a, B: ByteOrder, Offsets: SymOffsets> {
+    symtab: Symtab<'a, B, Offsets>,
+    shndxs: SymtabShndx<'a, B>
+}
+
+/// A [Sym] together with its entry in the extended section index
+/// table.
+///
+/// This can be projected to a [SymDataExt] using the
+/// [TryFrom](core::convert::TryFrom) instance, or to a [SymData] with
+/// resolved names using the [WithStrtab] instance.
+#[derive(Copy, Clone)]
+pub struct SymWithShndx<'a, B: ByteOrder, Offsets: SymOffsets> {
+    sym: Sym<'a, B, Offsets>,
+    shndx: u32
+}
+
+/// Iterator over the symbols of a [SymtabWithShndx].
+#[derive(Clone)]
+pub struct SymtabWithShndxIter<'a, B: ByteOrder, Offsets: SymOffsets> {
+    syms: SymtabIter<'a, B, Offsets>,
+    shndxs: SymtabShndxIter<'a, B>
+}
+
+/// Errors that can occur pairing a [Symtab] with a [SymtabShndx].
+///
+/// The only error that can occur is if the number of section indexes
+/// doesn't match the number of symbols.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SymtabWithShndxError {
+    /// The number of section indexes, which differs from the number
+    /// of symbols.
+    BadLen(usize)
+}
+
+/// Iterator over the entries of a [SymtabShndx].
+#[derive(Copy, Clone)]
+pub struct SymtabShndxIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    idx: usize
+}
+
+/// Errors that can occur creating a [SymtabShndx].
+///
+/// The only error that can occur is if the data is not a multiple of
+/// the size of a section index.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SymtabShndxError {
+    BadSize(usize)
+}
+
+/// Calculate the number of bytes required to represent all of the
+/// section indexes in `shndxs`.
+#[inline]
+pub fn required_bytes<I>(shndxs: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<u32> {
+    shndxs.count() * SHNDX_SIZE
+}
+
+impl<'a, B> SymtabShndx<'a, B>
+    where B: ByteOrder {
+    /// Attempt to create a `SymtabShndx` in `buf` containing the
+    /// section indexes in `shndxs`.
+    ///
+    /// This will write the section indexes into the buffer in the
+    /// ELF format.  Returns both the `SymtabShndx` and the remaining
+    /// space if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the section indexes don't
+    /// fit into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::shndx::SymtabShndx;
+    ///
+    /// const SHNDXS: [u32; 2] = [ 0, 0x10000 ];
+    ///
+    /// let mut buf = [0; 12];
+    /// let (shndxs, rest): (SymtabShndx<'_, LittleEndian>, _) =
+    ///     SymtabShndx::create_split(&mut buf[0..], SHNDXS.iter())
+    ///     .expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 4);
+    ///
+    /// let mut iter = shndxs.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(SHNDXS[0]));
+    /// assert_eq!(iter.next(), Some(SHNDXS[1]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn create_split<I>(buf: &'a mut [u8], shndxs: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<u32> {
+        let mut idx = 0;
+
+        for shndx in shndxs {
+            if buf.len() - idx < SHNDX_SIZE {
+                return Err(())
+            }
+
+            B::write_u32(&mut buf[idx .. idx + SHNDX_SIZE], *shndx.borrow());
+            idx += SHNDX_SIZE;
+        }
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((SymtabShndx { byteorder: PhantomData, data: data }, rest))
+    }
+
+    /// Attempt to create a `SymtabShndx` in `buf` containing the
+    /// section indexes in `shndxs` (see
+    /// [create_split](SymtabShndx::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the section indexes don't
+    /// fit into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], shndxs: I) -> Result<Self, ()>
+        where I: Iterator,
+              I::Item: Borrow<u32> {
+        match Self::create_split(buf, shndxs) {
+            Ok((shndxs, _)) => Ok(shndxs),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the extended section index for the symbol at index `idx`.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<u32> {
+        let start = idx * SHNDX_SIZE;
+
+        if start < self.data.len() {
+            Some(B::read_u32(&self.data[start .. start + SHNDX_SIZE]))
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of section indexes in this `SymtabShndx`.
+    #[inline]
+    pub fn num_shndxs(&self) -> usize {
+        self.data.len() / SHNDX_SIZE
+    }
+
+    /// Get an iterator over this `SymtabShndx`.
+    #[inline]
+    pub fn iter(&self) -> SymtabShndxIter<'a, B> {
+        SymtabShndxIter { byteorder: PhantomData, data: self.data, idx: 0 }
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for SymtabShndx<'a, B>
+    where B: ByteOrder {
+    type Error = SymtabShndxError;
+
+    /// Create a `SymtabShndx` from the data buffer.  This will check
+    /// that the data buffer is a multiple of the section index size.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<SymtabShndx<'a, B>, Self::Error> {
+        if data.len() % SHNDX_SIZE == 0 {
+            Ok(SymtabShndx { byteorder: PhantomData, data: data })
+        } else {
+            Err(SymtabShndxError::BadSize(data.len()))
+        }
+    }
+}
+
+impl<'a, B> TryFrom<&'a mut [u8]> for SymtabShndx<'a, B>
+    where B: ByteOrder {
+    type Error = SymtabShndxError;
+
+    /// Create a `SymtabShndx` from the data buffer.  This will check
+    /// that the data buffer is a multiple of the section index size.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) ->
+        Result<SymtabShndx<'a, B>, Self::Error> {
+        SymtabShndx::try_from(&*data)
+    }
+}
+
+impl<'a, B> Iterator for SymtabShndxIter<'a, B>
+    where B: ByteOrder {
+    type Item = u32;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.len();
+
+        (size, Some(size))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let start = (self.idx + n) * SHNDX_SIZE;
+
+        if start < self.data.len() {
+            let value = B::read_u32(&self.data[start .. start + SHNDX_SIZE]);
+
+            self.idx += n + 1;
+
+            Some(value)
+        } else {
+            self.idx = self.data.len() / SHNDX_SIZE;
+
+            None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for SymtabShndxIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> ExactSizeIterator for SymtabShndxIter<'a, B>
+    where B: ByteOrder {
+    #[inline]
+    fn len(&self) -> usize {
+        (self.data.len() / SHNDX_SIZE) - self.idx
+    }
+}
+
+impl<'a, B, Offsets> WithShndx<'a, B> for Symtab<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    type Result = SymtabWithShndx<'a, B, Offsets>;
+    type Error = SymtabWithShndxError;
+
+    /// Pair this symbol table with `shndxs`.  This will check that
+    /// `shndxs` has an entry for every symbol.
+    #[inline]
+    fn with_shndx(self, shndxs: SymtabShndx<'a, B>) ->
+        Result<Self::Result, Self::Error> {
+        if self.num_syms() == shndxs.num_shndxs() {
+            Ok(SymtabWithShndx { symtab: self, shndxs: shndxs })
+        } else {
+            Err(SymtabWithShndxError::BadLen(shndxs.num_shndxs()))
+        }
+    }
+}
+
+impl<'a, B, Offsets> SymtabWithShndx<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    /// Get the symbol at index `idx`, together with its extended
+    /// section index.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<SymWithShndx<'a, B, Offsets>> {
+        match (self.symtab.idx(idx), self.shndxs.idx(idx)) {
+            (Some(sym), Some(shndx)) => Some(SymWithShndx { sym: sym,
+                                                            shndx: shndx }),
+            _ => None
+        }
+    }
+
+    /// Get the number of symbols in this `SymtabWithShndx`.
+    #[inline]
+    pub fn num_syms(&self) -> usize {
+        self.symtab.num_syms()
+    }
+
+    /// Get the underlying [Symtab].
+    #[inline]
+    pub fn symtab(&self) -> Symtab<'a, B, Offsets> {
+        self.symtab
+    }
+
+    /// Get the underlying [SymtabShndx].
+    #[inline]
+    pub fn shndxs(&self) -> SymtabShndx<'a, B> {
+        self.shndxs
+    }
+
+    /// Get an iterator over this `SymtabWithShndx`.
+    #[inline]
+    pub fn iter(&self) -> SymtabWithShndxIter<'a, B, Offsets> {
+        SymtabWithShndxIter { syms: self.symtab.iter(),
+                              shndxs: self.shndxs.iter() }
+    }
+}
+
+impl<'a, B, Offsets> SymWithShndx<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    /// Get the underlying [Sym].
+    #[inline]
+    pub fn sym(&self) -> Sym<'a, B, Offsets> {
+        self.sym
+    }
+
+    /// Get the entry for this symbol in the extended section index
+    /// table.
+    #[inline]
+    pub fn shndx(&self) -> u32 {
+        self.shndx
+    }
+}
+
+impl<'a, B, Offsets> TryFrom<SymWithShndx<'a, B, Offsets>>
+    for SymDataExt<Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    type Error = SymError;
+
+    #[inline]
+    fn try_from(sym: SymWithShndx<'a, B, Offsets>) ->
+        Result<SymDataExt<Offsets>, Self::Error> {
+        let data: SymDataRaw<Offsets> = SymDataRaw::try_from(sym.sym)?;
+
+        Ok(data.with_shndx(Some(sym.shndx)))
+    }
+}
+
+impl<'a, B, Offsets> WithStrtab<'a> for SymWithShndx<'a, B, Offsets>
+    where Offsets: 'a + SymOffsets,
+          B: ByteOrder {
+    type Result = SymData<Result<&'a str, &'a [u8]>,
+                          <Offsets as ElfClass>::Word, Offsets>;
+    type Error = StrSymError;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        let data = self.sym.with_strtab(strtab)?;
+
+        Ok(data.with_shndx(Some(self.shndx)))
+    }
+}
+
+impl<'a, B, Offsets> Iterator for SymtabWithShndxIter<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    type Item = SymWithShndx<'a, B, Offsets>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.len();
+
+        (size, Some(size))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match (self.syms.nth(n), self.shndxs.nth(n)) {
+            (Some(sym), Some(shndx)) => Some(SymWithShndx { sym: sym,
+                                                            shndx: shndx }),
+            _ => None
+        }
+    }
+}
+
+impl<'a, B, Offsets> FusedIterator for SymtabWithShndxIter<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {}
+
+impl<'a, B, Offsets> ExactSizeIterator for SymtabWithShndxIter<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn len(&self) -> usize {
+        self.syms.len().min(self.shndxs.len())
+    }
+}
+
+impl Display for SymtabWithShndxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SymtabWithShndxError::BadLen(len) =>
+                write!(f, "extended section index table has {} entries, \
+                           which doesn't match symbol table", len)
+        }
+    }
+}
+
+impl Display for SymtabShndxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SymtabShndxError::BadSize(size) =>
+                write!(f, "bad extended section index table size {}", size)
+        }
+    }
+}
@@ -486,6 +486,13 @@ impl<'a> Strtab<'a> {
         self.data.len()
     }
 
+    /// Get the raw data for this `Strtab`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
     /// Get the string at index `idx` in this `Strtab`.
     ///
     /// This is capable of indexing any suffix of a string in the
@@ -88,6 +88,7 @@
 //! use elf_utils::symtab::SymData;
 //! use elf_utils::symtab::SymDataRaw;
 //! use elf_utils::symtab::SymKind;
+//! use elf_utils::symtab::SymVisibility;
 //!
 //! const SYMTAB: [u8; 120] = [
 //!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -114,6 +115,7 @@
 //!
 //! assert_eq!(data, SymData { name: Some(1), value: 0, size: 0,
 //!                            kind: SymKind::File, bind: SymBind::Local,
+//!                            visibility: SymVisibility::Default, other: 0,
 //!                            section: SymBase::Absolute });
 //! ```
 //!
@@ -133,6 +135,7 @@
 //! use elf_utils::symtab::SymData;
 //! use elf_utils::symtab::SymDataRaw;
 //! use elf_utils::symtab::SymKind;
+//! use elf_utils::symtab::SymVisibility;
 //!
 //! const SYMTAB: [u8; 120] = [
 //!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -171,6 +174,7 @@
 //! assert_eq!(with_str, SymData { name: Some(Ok("finalizer")), value: 560,
 //!                                size: 90, kind: SymKind::Function,
 //!                                bind: SymBind::Local,
+//!                                visibility: SymVisibility::Default, other: 0,
 //!                                section: SymBase::Index(1) });
 //! ```
 
@@ -190,6 +194,9 @@ use crate::strtab::Strtab;
 use crate::strtab::StrtabIdxError;
 use crate::strtab::WithStrtab;
 
+const ST_VISIBILITY_MASK: u8 = 0x3;
+const SHN_LORESERVE: u32 = 0xff00;
+
 /// Trait for things that can be converted from one type to another
 /// with the use of a [Symtab].
 ///
@@ -335,6 +342,7 @@ pub trait SymtabMutOps<'a, B: ByteOrder, Offsets: SymOffsets> {
 /// use elf_utils::symtab::SymData;
 /// use elf_utils::symtab::SymDataRaw;
 /// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
 ///
 /// const SYMTAB: [u8; 120] = [
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -355,15 +363,20 @@ pub trait SymtabMutOps<'a, B: ByteOrder, Offsets: SymOffsets> {
 /// ];
 /// const SYMTAB_CONTENTS: [SymDataRaw<Elf64>; 5] = [
 ///    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-///              bind: SymBind::Local, section: SymBase::Undef },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Undef },
 ///    SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-///              bind: SymBind::Local, section: SymBase::Absolute },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Absolute },
 ///    SymData { name: Some(10), value: 560, size: 90, kind: SymKind::Function,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 ///    SymData { name: Some(20), value: 272, size: 282, kind: SymKind::Function,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 ///    SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 /// ];
 ///
 /// let symtab: Symtab<'_, LittleEndian, Elf64> =
@@ -383,6 +396,12 @@ pub struct Symtab<'a, B: ByteOrder, Offsets: SymOffsets> {
     symtab: &'a [u8]
 }
 
+/// In-place mutable ELF symbol table.
+///
+/// This is the mutable counterpart to [Symtab].  In addition to the
+/// operations in [SymtabMutOps], it provides [SymMut]s through
+/// [idx_mut](SymtabMut::idx_mut), which can be used to patch symbols
+/// in place.
 pub struct SymtabMut<'a, B: ByteOrder, Offsets: SymOffsets> {
     byteorder: PhantomData<B>,
     offsets: PhantomData<Offsets>,
@@ -415,6 +434,7 @@ pub struct SymtabMut<'a, B: ByteOrder, Offsets: SymOffsets> {
 /// use elf_utils::symtab::SymData;
 /// use elf_utils::symtab::SymDataRaw;
 /// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
 ///
 /// const SYMTAB: [u8; 120] = [
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -441,6 +461,7 @@ pub struct SymtabMut<'a, B: ByteOrder, Offsets: SymOffsets> {
 ///
 /// assert_eq!(data, SymData { name: Some(1), value: 0, size: 0,
 ///                            kind: SymKind::File, bind: SymBind::Local,
+///                            visibility: SymVisibility::Default, other: 0,
 ///                            section: SymBase::Absolute });
 /// ```
 #[derive(Copy, Clone)]
@@ -450,6 +471,49 @@ pub struct Sym<'a, B: ByteOrder, Offsets: SymOffsets> {
     sym: &'a [u8]
 }
 
+/// In-place mutable ELF symbol.
+///
+/// This is obtained from a [SymtabMut] using
+/// [idx_mut](SymtabMut::idx_mut).  Individual fields can be changed
+/// with the setter functions, or the entire symbol can be replaced
+/// with [set](SymMut::set).  All values are written in the byte order
+/// of the underlying data.
+///
+/// # Examples
+///
+/// ```
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::Elf64;
+/// use elf_utils::symtab::SymtabMut;
+/// use elf_utils::symtab::SymBase;
+/// use elf_utils::symtab::SymBind;
+/// use elf_utils::symtab::SymData;
+/// use elf_utils::symtab::SymDataRaw;
+/// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
+///
+/// let mut buf: [u8; 24] = [
+///     0x0a, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00,
+///     0x30, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x5a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+/// let mut symtab: SymtabMut<'_, LittleEndian, Elf64> =
+///     SymtabMut::try_from(&mut buf[0..]).unwrap();
+/// let mut sym = symtab.idx_mut(0).unwrap();
+///
+/// sym.set_bind(SymBind::Global);
+/// sym.set_visibility(SymVisibility::Hidden);
+/// sym.set_value(0x1230);
+///
+/// let data: SymDataRaw<Elf64> = sym.try_into().unwrap();
+///
+/// assert_eq!(data, SymData { name: Some(10), value: 0x1230, size: 90,
+///                            kind: SymKind::Function, bind: SymBind::Global,
+///                            visibility: SymVisibility::Hidden, other: 0,
+///                            section: SymBase::Index(1) });
+/// ```
 pub struct SymMut<'a, B: ByteOrder, Offsets: SymOffsets> {
     byteorder: PhantomData<B>,
     offsets: PhantomData<Offsets>,
@@ -474,6 +538,7 @@ pub struct SymMut<'a, B: ByteOrder, Offsets: SymOffsets> {
 /// use elf_utils::symtab::SymData;
 /// use elf_utils::symtab::SymDataRaw;
 /// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
 ///
 /// const SYMTAB: [u8; 120] = [
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -494,15 +559,20 @@ pub struct SymMut<'a, B: ByteOrder, Offsets: SymOffsets> {
 /// ];
 /// const SYMTAB_CONTENTS: [SymDataRaw<Elf64>; 5] = [
 ///    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-///              bind: SymBind::Local, section: SymBase::Undef },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Undef },
 ///    SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-///              bind: SymBind::Local, section: SymBase::Absolute },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Absolute },
 ///    SymData { name: Some(10), value: 560, size: 90, kind: SymKind::Function,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 ///    SymData { name: Some(20), value: 272, size: 282, kind: SymKind::Function,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 ///    SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 /// ];
 ///
 /// let symtab: Symtab<'_, LittleEndian, Elf64> =
@@ -568,6 +638,23 @@ pub enum SymBind {
     ArchSpecific(u8)
 }
 
+/// ELF symbol visibility.
+///
+/// These are held in the low bits of the `st_other` field of an ELF
+/// symbol, and define how the symbol can be seen by other components
+/// once it has been combined into an executable or shared object.
+#[derive(Copy, Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum SymVisibility {
+    /// Visibility is given by the symbol's binding.
+    Default,
+    /// Processor-specific hidden visibility.
+    Internal,
+    /// Not visible to other components.
+    Hidden,
+    /// Visible to other components, but cannot be preempted.
+    Protected
+}
+
 /// Base of an ELF symbol.
 ///
 /// This is information held in the `st_section` field of an ELF
@@ -617,6 +704,7 @@ pub enum SymBase<Section, Half> {
 /// use elf_utils::symtab::SymData;
 /// use elf_utils::symtab::SymDataRaw;
 /// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
 ///
 /// const SYMTAB: [u8; 120] = [
 ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
@@ -653,6 +741,7 @@ pub enum SymBase<Section, Half> {
 ///
 /// assert_eq!(data, SymData { name: Some(10), value: 560, size: 90,
 ///                            kind: SymKind::Function, bind: SymBind::Local,
+///                            visibility: SymVisibility::Default, other: 0,
 ///                            section: SymBase::Index(1) });
 ///
 /// let with_str = data.with_strtab(strtab).unwrap();
@@ -660,6 +749,7 @@ pub enum SymBase<Section, Half> {
 /// assert_eq!(with_str, SymData { name: Some(Ok("finalizer")), value: 560,
 ///                                size: 90, kind: SymKind::Function,
 ///                                bind: SymBind::Local,
+///                                visibility: SymVisibility::Default, other: 0,
 ///                                section: SymBase::Index(1) });
 /// ```
 #[derive(Clone, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
@@ -674,6 +764,12 @@ pub struct SymData<Name, Section, Class: ElfClass> {
     pub kind: SymKind,
     /// Symbol binding.
     pub bind: SymBind,
+    /// Symbol visibility.
+    pub visibility: SymVisibility,
+    /// Remaining bits of the `st_other` field, with the visibility
+    /// masked out.  These are used by some architectures (for
+    /// example, the PPC64 local entry point offset).
+    pub other: u8,
     /// Section for this symbol.
     pub section: SymBase<Section, Class::Half>
 }
@@ -686,6 +782,14 @@ pub struct SymData<Name, Section, Class: ElfClass> {
 pub type SymDataRaw<Class> =
     SymData<<Class as ElfClass>::Word, <Class as ElfClass>::Half, Class>;
 
+/// Type synonym for [SymData] with extended section indexes resolved.
+///
+/// This is obtained from a [SymDataRaw] using
+/// [with_shndx](SymData::with_shndx), and can be converted back into
+/// a [SymDataRaw] using [escape_shndx](SymData::escape_shndx).
+pub type SymDataExt<Class> =
+    SymData<<Class as ElfClass>::Word, <Class as ElfClass>::Word, Class>;
+
 /// Type synonym for [SymData] as projected from a [Sym], with symbol
 /// names represented as the results of UTF-8 decoding.
 ///
@@ -736,25 +840,28 @@ fn name_lookup<'a, Name, Section, Class>(sym: SymData<Name, Section, Class>,
     Result<SymData<Result<&'a str, &'a [u8]>, Section, Class>, ()>
     where Class: ElfClass,
           Name: Clone + TryInto<usize> {
-    let SymData { name, value, size, bind, kind, section } = sym;
+    let SymData { name, value, size, bind, visibility, other, kind,
+                  section } = sym;
 
     match name {
         Some(name) => {
             match strtab.idx(name) {
                 Ok(name) => Ok(SymData { name: Some(Ok(name)), value: value,
-                                         size: size, bind: bind, kind: kind,
-                                         section: section }),
+                                         size: size, bind: bind,
+                                         visibility: visibility, other: other,
+                                         kind: kind, section: section }),
                 Err(StrtabIdxError::UTF8Decode(data)) => {
                     Ok(SymData { name: Some(Err(data)), value: value,
-                                 size: size, bind: bind, kind: kind,
-                                 section: section })
+                                 size: size, bind: bind, visibility: visibility,
+                                 other: other, kind: kind, section: section })
                 },
                 _ => Err(())
             }
         },
         None => {
-            Ok(SymData { name: None, value: value, size: size,
-                         bind: bind, kind: kind, section: section })
+            Ok(SymData { name: None, value: value, size: size, bind: bind,
+                         visibility: visibility, other: other, kind: kind,
+                         section: section })
         }
     }
 }
@@ -771,10 +878,13 @@ fn project<'a, B, Offsets>(data: &'a [u8]) -> Result<SymDataRaw<Offsets>,
     let size = Offsets::read_offset::<B>(&data[Offsets::ST_SIZE_START ..
                                                Offsets::ST_SIZE_END]);
     let info = data[Offsets::ST_INFO_START];
+    let st_other = data[Offsets::ST_OTHER_START];
     let section = Offsets::read_half::<B>(&data[Offsets::ST_SHIDX_START ..
                                                 Offsets::ST_SHIDX_END]);
     let bind = info >> 4;
     let kind = info & 0xf;
+    let visibility = SymVisibility::from(st_other);
+    let other = st_other & !ST_VISIBILITY_MASK;
 
     match (bind.try_into(), kind.try_into()) {
         (Ok(bind), Ok(kind)) => {
@@ -785,13 +895,53 @@ fn project<'a, B, Offsets>(data: &'a [u8]) -> Result<SymDataRaw<Offsets>,
             };
 
             Ok(SymData { name: name, value: value, size: size, bind: bind,
-                         kind: kind, section: SymBase::from(section) })
+                         visibility: visibility, other: other, kind: kind,
+                         section: SymBase::from(section) })
         },
         (Err(bad), _) => Err(SymError::BadBind(bad)),
         (_, Err(bad)) => Err(SymError::BadType(bad))
     }
 }
 
+#[inline]
+fn write<B, Offsets>(symbuf: &mut [u8], sym: &SymDataRaw<Offsets>)
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    let bind: u8 = sym.bind.into();
+    let kind: u8 = sym.kind.into();
+    let info = (bind << 4) | kind;
+    let visibility: u8 = sym.visibility.into();
+    let st_other = (sym.other & !ST_VISIBILITY_MASK) | visibility;
+
+    match sym.name {
+        Some(name) => {
+            Offsets::write_word::<B>(
+                &mut symbuf[Offsets::ST_NAME_START ..
+                            Offsets::ST_NAME_END], name
+            )
+        }
+        None => {
+            Offsets::write_word::<B>(
+                &mut symbuf[Offsets::ST_NAME_START ..
+                            Offsets::ST_NAME_END],
+                (0 as u8).into()
+            )
+        }
+    }
+
+    Offsets::write_addr::<B>(&mut symbuf[Offsets::ST_VALUE_START ..
+                                         Offsets::ST_VALUE_END],
+                             sym.value);
+    Offsets::write_offset::<B>(&mut symbuf[Offsets::ST_SIZE_START ..
+                                           Offsets::ST_SIZE_END],
+                               sym.size);
+    symbuf[Offsets::ST_INFO_START] = info;
+    symbuf[Offsets::ST_OTHER_START] = st_other;
+    Offsets::write_half::<B>(&mut symbuf[Offsets::ST_SHIDX_START ..
+                                         Offsets::ST_SHIDX_END],
+                             sym.section.encode());
+}
+
 fn create<'a, 'b, B, I, Offsets>(buf: &'a mut [u8], syms: I) ->
     Result<(&'a mut [u8], &'a mut [u8]), ()>
     where I: Iterator,
@@ -805,36 +955,7 @@ fn create<'a, 'b, B, I, Offsets>(buf: &'a mut [u8], syms: I) ->
         let sym = sym.borrow();
         if idx + Offsets::ST_ENT_SIZE <= len {
             let symbuf = &mut buf[idx .. idx + Offsets::ST_ENT_SIZE];
-            let bind: u8 = sym.bind.into();
-            let kind: u8 = sym.kind.into();
-            let info = (bind << 4) | kind;
-
-            match sym.name {
-                Some(name) => {
-                    Offsets::write_word::<B>(
-                        &mut symbuf[Offsets::ST_NAME_START ..
-                                    Offsets::ST_NAME_END], name
-                    )
-                }
-                None => {
-                    Offsets::write_word::<B>(
-                        &mut symbuf[Offsets::ST_NAME_START ..
-                                    Offsets::ST_NAME_END],
-                        (0 as u8).into()
-                    )
-                }
-            }
-
-            Offsets::write_addr::<B>(&mut symbuf[Offsets::ST_VALUE_START ..
-                                                 Offsets::ST_VALUE_END],
-                                     sym.value);
-            Offsets::write_offset::<B>(&mut symbuf[Offsets::ST_SIZE_START ..
-                                                   Offsets::ST_SIZE_END],
-                                       sym.size);
-            symbuf[Offsets::ST_INFO_START] = info;
-            Offsets::write_half::<B>(&mut symbuf[Offsets::ST_SHIDX_START ..
-                                                 Offsets::ST_SHIDX_END],
-                                     sym.section.encode());
+            write::<B, Offsets>(symbuf, sym);
             idx += Offsets::ST_ENT_SIZE;
         } else {
             return Err(())
@@ -878,21 +999,25 @@ impl SymOffsets for Elf64 {
 /// use elf_utils::symtab::SymData;
 /// use elf_utils::symtab::SymDataRaw;
 /// use elf_utils::symtab::SymKind;
+/// use elf_utils::symtab::SymVisibility;
 /// use elf_utils::symtab;
 ///
 /// const SYMTAB_CONTENTS: [SymDataRaw<Elf64>; 5] = [
 ///    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-///              bind: SymBind::Local, section: SymBase::Undef },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Undef },
 ///    SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-///              bind: SymBind::Local, section: SymBase::Absolute },
-///    SymData { name: Some(10), value: 560, size: 90,
-///              kind: SymKind::Function, bind: SymBind::Local,
-///              section: SymBase::Index(1) },
-///    SymData { name: Some(20), value: 272, size: 282,
-///              kind: SymKind::Function, bind: SymBind::Local,
-///              section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Absolute },
+///    SymData { name: Some(10), value: 560, size: 90, kind: SymKind::Function,
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
+///    SymData { name: Some(20), value: 272, size: 282, kind: SymKind::Function,
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 ///    SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-///              bind: SymBind::Local, section: SymBase::Index(1) },
+///              bind: SymBind::Local, visibility: SymVisibility::Default,
+///              other: 0, section: SymBase::Index(1) },
 /// ];
 ///
 /// assert_eq!(symtab::required_bytes(SYMTAB_CONTENTS.iter()), 120);
@@ -932,20 +1057,26 @@ impl<'a, B, Offsets> Symtab<'a, B, Offsets>
     /// use elf_utils::symtab::SymData;
     /// use elf_utils::symtab::SymDataRaw;
     /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
     ///
     /// const SYMTAB_CONTENTS: [SymDataRaw<Elf64>; 5] = [
     ///    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-    ///              bind: SymBind::Local, section: SymBase::Undef },
+    ///              bind: SymBind::Local, visibility: SymVisibility::Default,
+    ///              other: 0, section: SymBase::Undef },
     ///    SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-    ///              bind: SymBind::Local, section: SymBase::Absolute },
+    ///              bind: SymBind::Local, visibility: SymVisibility::Default,
+    ///              other: 0, section: SymBase::Absolute },
     ///    SymData { name: Some(10), value: 560, size: 90,
     ///              kind: SymKind::Function, bind: SymBind::Local,
+    ///              visibility: SymVisibility::Default, other: 0,
     ///              section: SymBase::Index(1) },
     ///    SymData { name: Some(20), value: 272, size: 282,
     ///              kind: SymKind::Function, bind: SymBind::Local,
+    ///              visibility: SymVisibility::Default, other: 0,
     ///              section: SymBase::Index(1) },
     ///    SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-    ///              bind: SymBind::Local, section: SymBase::Index(1) },
+    ///              bind: SymBind::Local, visibility: SymVisibility::Default,
+    ///              other: 0, section: SymBase::Index(1) },
     /// ];
     ///
     /// let mut buf = [0; 128];
@@ -1005,20 +1136,26 @@ impl<'a, B, Offsets> Symtab<'a, B, Offsets>
     /// use elf_utils::symtab::SymData;
     /// use elf_utils::symtab::SymDataRaw;
     /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
     ///
     /// const SYMTAB_CONTENTS: [SymDataRaw<Elf64>; 5] = [
     ///    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-    ///              bind: SymBind::Local, section: SymBase::Undef },
+    ///              bind: SymBind::Local, visibility: SymVisibility::Default,
+    ///              other: 0, section: SymBase::Undef },
     ///    SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-    ///              bind: SymBind::Local, section: SymBase::Absolute },
+    ///              bind: SymBind::Local, visibility: SymVisibility::Default,
+    ///              other: 0, section: SymBase::Absolute },
     ///    SymData { name: Some(10), value: 560, size: 90,
     ///              kind: SymKind::Function, bind: SymBind::Local,
+    ///              visibility: SymVisibility::Default, other: 0,
     ///              section: SymBase::Index(1) },
     ///    SymData { name: Some(20), value: 272, size: 282,
     ///              kind: SymKind::Function, bind: SymBind::Local,
+    ///              visibility: SymVisibility::Default, other: 0,
     ///              section: SymBase::Index(1) },
     ///    SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-    ///              bind: SymBind::Local, section: SymBase::Index(1) },
+    ///              bind: SymBind::Local, visibility: SymVisibility::Default,
+    ///              other: 0, section: SymBase::Index(1) },
     /// ];
     ///
     /// let mut buf = [0; 128];
@@ -1073,6 +1210,13 @@ impl<'a, B, Offsets> Symtab<'a, B, Offsets>
         self.symtab.len() / Offsets::ST_ENT_SIZE
     }
 
+    /// Get the raw data for this `Symtab`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.symtab
+    }
+
     /// Get an iterator over this `Symtab`.
     #[inline]
     pub fn iter(&self) -> SymtabIter<'a, B, Offsets> {
@@ -1175,6 +1319,120 @@ impl<'a, B, Offsets> SymtabMutOps<'a, B, Offsets> for SymtabMut<'a, B, Offsets>
     }
 }
 
+impl<'a, B, Offsets> SymtabMut<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    /// Get a [SymMut] for the symbol at index `idx`.
+    ///
+    /// # Errors
+    ///
+    /// `None` will be returned if `idx` is out of bounds.
+    #[inline]
+    pub fn idx_mut(&mut self, idx: usize) -> Option<SymMut<'_, B, Offsets>> {
+        let len = self.symtab.len();
+        let start = idx * Offsets::ST_ENT_SIZE;
+
+        if start < len {
+            let end = start + Offsets::ST_ENT_SIZE;
+
+            Some(SymMut { byteorder: PhantomData, offsets: PhantomData,
+                          sym: &mut self.symtab[start .. end ] })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, B, Offsets> SymMut<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    /// Replace this symbol with `sym`.
+    #[inline]
+    pub fn set(&mut self, sym: &SymDataRaw<Offsets>) {
+        write::<B, Offsets>(self.sym, sym)
+    }
+
+    /// Set the name of this symbol (an index into the associated
+    /// string table) to `name`.  `None` indicates no name.
+    #[inline]
+    pub fn set_name(&mut self, name: Option<Offsets::Word>) {
+        let name = match name {
+            Some(name) => name,
+            None => (0 as u8).into()
+        };
+
+        Offsets::write_word::<B>(&mut self.sym[Offsets::ST_NAME_START ..
+                                               Offsets::ST_NAME_END],
+                                 name)
+    }
+
+    /// Set the value of this symbol to `value`.
+    #[inline]
+    pub fn set_value(&mut self, value: Offsets::Addr) {
+        Offsets::write_addr::<B>(&mut self.sym[Offsets::ST_VALUE_START ..
+                                               Offsets::ST_VALUE_END],
+                                 value)
+    }
+
+    /// Set the size of this symbol to `size`.
+    #[inline]
+    pub fn set_size(&mut self, size: Offsets::Offset) {
+        Offsets::write_offset::<B>(&mut self.sym[Offsets::ST_SIZE_START ..
+                                                 Offsets::ST_SIZE_END],
+                                   size)
+    }
+
+    /// Set the type of this symbol to `kind`, leaving the binding
+    /// unchanged.
+    #[inline]
+    pub fn set_kind(&mut self, kind: SymKind) {
+        let kind: u8 = kind.into();
+        let info = self.sym[Offsets::ST_INFO_START];
+
+        self.sym[Offsets::ST_INFO_START] = (info & 0xf0) | (kind & 0xf);
+    }
+
+    /// Set the binding of this symbol to `bind`, leaving the type
+    /// unchanged.
+    #[inline]
+    pub fn set_bind(&mut self, bind: SymBind) {
+        let bind: u8 = bind.into();
+        let info = self.sym[Offsets::ST_INFO_START];
+
+        self.sym[Offsets::ST_INFO_START] = (bind << 4) | (info & 0xf);
+    }
+
+    /// Set the visibility of this symbol to `visibility`, leaving the
+    /// remaining bits of `st_other` unchanged.
+    #[inline]
+    pub fn set_visibility(&mut self, visibility: SymVisibility) {
+        let visibility: u8 = visibility.into();
+        let other = self.sym[Offsets::ST_OTHER_START];
+
+        self.sym[Offsets::ST_OTHER_START] =
+            (other & !ST_VISIBILITY_MASK) | visibility;
+    }
+
+    /// Set the non-visibility bits of `st_other` to `other`, leaving
+    /// the visibility unchanged.
+    #[inline]
+    pub fn set_other(&mut self, other: u8) {
+        let st_other = self.sym[Offsets::ST_OTHER_START];
+
+        self.sym[Offsets::ST_OTHER_START] =
+            (other & !ST_VISIBILITY_MASK) | (st_other & ST_VISIBILITY_MASK);
+    }
+
+    /// Set the section of this symbol to `section`.
+    #[inline]
+    pub fn set_section(&mut self, section: SymBase<Offsets::Half,
+                                                   Offsets::Half>) {
+        Offsets::write_half::<B>(&mut self.sym[Offsets::ST_SHIDX_START ..
+                                               Offsets::ST_SHIDX_END],
+                                 section.encode())
+    }
+}
+
 impl<'a, B, Offsets> SymtabCreate<'a, B, Offsets> for SymtabMut<'a, B, Offsets>
     where Offsets: SymOffsets,
           B: ByteOrder {
@@ -1199,22 +1457,180 @@ impl<'a, B, Offsets> SymtabCreate<'a, B, Offsets> for SymtabMut<'a, B, Offsets>
     }
 }
 
+impl<Name, Class> SymData<Name, Class::Half, Class>
+    where Class: ElfClass {
+    /// Resolve the section index of this symbol using `shndx`, the
+    /// entry for this symbol in the extended section index table (see
+    /// [SymtabShndx](crate::shndx::SymtabShndx)).
+    ///
+    /// A section of [SymBase::Escape] will be replaced with
+    /// `SymBase::Index(shndx)`.  If `shndx` is `None`, it will be
+    /// left as `SymBase::Escape`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use elf_utils::Elf64;
+    /// use elf_utils::symtab::SymBase;
+    /// use elf_utils::symtab::SymBind;
+    /// use elf_utils::symtab::SymData;
+    /// use elf_utils::symtab::SymDataExt;
+    /// use elf_utils::symtab::SymDataRaw;
+    /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
+    ///
+    /// let sym: SymDataRaw<Elf64> =
+    ///     SymData { name: Some(1), value: 0, size: 0,
+    ///               kind: SymKind::Function, bind: SymBind::Global,
+    ///               visibility: SymVisibility::Default, other: 0,
+    ///               section: SymBase::Escape };
+    /// let ext: SymDataExt<Elf64> = sym.with_shndx(Some(0x10000));
+    ///
+    /// assert_eq!(ext.section, SymBase::Index(0x10000));
+    /// ```
+    pub fn with_shndx(self, shndx: Option<u32>) ->
+        SymData<Name, Class::Word, Class> {
+        let SymData { name, value, size, bind, visibility, other, kind,
+                      section } = self;
+        let section = match section {
+            SymBase::Undef => SymBase::Undef,
+            SymBase::Absolute => SymBase::Absolute,
+            SymBase::Common => SymBase::Common,
+            SymBase::Escape => match shndx {
+                Some(idx) => SymBase::Index(Class::Word::from(idx)),
+                None => SymBase::Escape
+            },
+            SymBase::Index(idx) => {
+                let idx: u16 = idx.into();
+
+                SymBase::Index(Class::Word::from(idx as u32))
+            },
+            SymBase::ArchSpecific(code) => SymBase::ArchSpecific(code),
+            SymBase::OSSpecific(code) => SymBase::OSSpecific(code)
+        };
+
+        SymData { name: name, value: value, size: size, bind: bind,
+                  visibility: visibility, other: other, kind: kind,
+                  section: section }
+    }
+}
+
+impl<Name, Class> SymData<Name, Class::Word, Class>
+    where Class: ElfClass {
+    /// Get the entry for this symbol in an extended section index
+    /// table.
+    ///
+    /// This is the section index if it is too large to be stored in
+    /// the symbol table entry itself, and zero otherwise.
+    #[inline]
+    pub fn shndx(&self) -> u32 {
+        match self.section {
+            SymBase::Index(idx) if idx.into() >= SHN_LORESERVE => idx.into(),
+            _ => 0
+        }
+    }
+
+    /// Convert this symbol into a form that can be written into a
+    /// [Symtab].
+    ///
+    /// Section indexes that are too large to be stored in the symbol
+    /// table entry will be replaced with [SymBase::Escape].  The
+    /// actual index must then be stored in an extended section index
+    /// table using the value obtained from [shndx](SymData::shndx).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use byteorder::LittleEndian;
+    /// use core::convert::TryInto;
+    /// use elf_utils::Elf64;
+    /// use elf_utils::shndx::SymtabShndx;
+    /// use elf_utils::symtab::Symtab;
+    /// use elf_utils::symtab::SymBase;
+    /// use elf_utils::symtab::SymBind;
+    /// use elf_utils::symtab::SymData;
+    /// use elf_utils::symtab::SymDataExt;
+    /// use elf_utils::symtab::SymDataRaw;
+    /// use elf_utils::symtab::SymKind;
+    /// use elf_utils::symtab::SymVisibility;
+    ///
+    /// const SYMS: [SymDataExt<Elf64>; 2] = [
+    ///     SymData { name: Some(1), value: 0, size: 0,
+    ///               kind: SymKind::Function, bind: SymBind::Global,
+    ///               visibility: SymVisibility::Default, other: 0,
+    ///               section: SymBase::Index(2) },
+    ///     SymData { name: Some(1), value: 0, size: 0,
+    ///               kind: SymKind::Function, bind: SymBind::Global,
+    ///               visibility: SymVisibility::Default, other: 0,
+    ///               section: SymBase::Index(0x10000) }
+    /// ];
+    ///
+    /// let mut symbuf = [0; 48];
+    /// let mut shndxbuf = [0; 8];
+    /// let symtab: Symtab<'_, LittleEndian, Elf64> =
+    ///     Symtab::create(&mut symbuf[0..],
+    ///                    SYMS.iter().map(|sym| sym.clone().escape_shndx()))
+    ///     .expect("Expected success");
+    /// let shndxs: SymtabShndx<'_, LittleEndian> =
+    ///     SymtabShndx::create(&mut shndxbuf[0..],
+    ///                         SYMS.iter().map(|sym| sym.shndx()))
+    ///     .expect("Expected success");
+    ///
+    /// for i in 0 .. 2 {
+    ///     let sym: SymDataRaw<Elf64> =
+    ///         symtab.idx(i).unwrap().try_into().unwrap();
+    ///
+    ///     assert_eq!(sym.with_shndx(shndxs.idx(i)), SYMS[i]);
+    /// }
+    /// ```
+    pub fn escape_shndx(self) -> SymData<Name, Class::Half, Class> {
+        let SymData { name, value, size, bind, visibility, other, kind,
+                      section } = self;
+        let section = match section {
+            SymBase::Undef => SymBase::Undef,
+            SymBase::Absolute => SymBase::Absolute,
+            SymBase::Common => SymBase::Common,
+            SymBase::Escape => SymBase::Escape,
+            SymBase::Index(idx) => {
+                let idx: u32 = idx.into();
+
+                if idx >= SHN_LORESERVE {
+                    SymBase::Escape
+                } else {
+                    SymBase::Index(Class::Half::from(idx as u16))
+                }
+            },
+            SymBase::ArchSpecific(code) => SymBase::ArchSpecific(code),
+            SymBase::OSSpecific(code) => SymBase::OSSpecific(code)
+        };
+
+        SymData { name: name, value: value, size: size, bind: bind,
+                  visibility: visibility, other: other, kind: kind,
+                  section: section }
+    }
+}
+
 impl<Name, Section, Class> Display for SymData<Name, Section, Class>
     where Class: ElfClass,
           Section: Display,
           Name: Display {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
-        let SymData { name, value, size, bind, kind, section } = self;
+        let SymData { name, value, size, bind, visibility, other,
+                      kind, section } = self;
 
         match name {
             Some(name) => write!(f, concat!("  name: {}\n  kind: {}\n  ",
-                                            "bind: {}\n  value: {:x}\n  ",
+                                            "bind: {}\n  visibility: {}\n  ",
+                                            "other: {:x}\n  value: {:x}\n  ",
                                             "size: {:x}\n  section: {}"),
-                       name, kind, bind, value, size, section),
+                       name, kind, bind, visibility, other, value, size,
+                       section),
             None => write!(f, concat!("  kind: {}\n  bind: {}\n  ",
+                                      "visibility: {}\n  other: {:x}\n  ",
                                       "value: {:x}\n  size: {:x}\n  ",
                                       "section: {}"),
-                           kind, bind, value, size, section)
+                           kind, bind, visibility, other, value, size,
+                           section)
         }
     }
 }
@@ -1363,6 +1779,57 @@ impl<'a> TryFrom<&'a mut u8> for SymBind {
     }
 }
 
+impl Display for SymVisibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SymVisibility::Default => write!(f, "default"),
+            SymVisibility::Internal => write!(f, "internal"),
+            SymVisibility::Hidden => write!(f, "hidden"),
+            SymVisibility::Protected => write!(f, "protected")
+        }
+    }
+}
+
+impl From<SymVisibility> for u8 {
+    #[inline]
+    fn from(visibility: SymVisibility) -> u8 {
+        match visibility {
+            SymVisibility::Default => 0,
+            SymVisibility::Internal => 1,
+            SymVisibility::Hidden => 2,
+            SymVisibility::Protected => 3
+        }
+    }
+}
+
+impl<'a> From<&'a SymVisibility> for u8 {
+    #[inline]
+    fn from(visibility: &'a SymVisibility) -> u8 {
+        (*visibility).into()
+    }
+}
+
+impl From<u8> for SymVisibility {
+    /// Get the visibility from an `st_other` value.  Only the low
+    /// bits are considered; all other bits are ignored.
+    #[inline]
+    fn from(other: u8) -> SymVisibility {
+        match other & ST_VISIBILITY_MASK {
+            0 => SymVisibility::Default,
+            1 => SymVisibility::Internal,
+            2 => SymVisibility::Hidden,
+            _ => SymVisibility::Protected
+        }
+    }
+}
+
+impl<'a> From<&'a u8> for SymVisibility {
+    #[inline]
+    fn from(other: &'a u8) -> SymVisibility {
+        SymVisibility::from(*other)
+    }
+}
+
 impl<Section: Display, Half: LowerHex> Display for SymBase<Section, Half> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         match self {
@@ -1458,13 +1925,16 @@ impl<'a, Offsets> TryFrom<SymDataStrData<'a, Offsets>>
     fn try_from(sym: SymDataStrData<'a, Offsets>) ->
         Result<SymDataStr<'a, Offsets>, &'a [u8]> {
         match sym {
-            SymData { name: Some(Ok(name)), value, size,
-                      bind, kind, section } =>
+            SymData { name: Some(Ok(name)), value, size, bind, visibility,
+                      other, kind, section } =>
                 Ok(SymData { name: Some(name), value: value, size: size,
-                             bind: bind, kind: kind, section: section }),
-            SymData { name: None, value, size, bind, kind, section } =>
-                Ok(SymData { name: None, value: value, size: size,
-                             bind: bind, kind: kind, section: section }),
+                             bind: bind, visibility: visibility, other: other,
+                             kind: kind, section: section }),
+            SymData { name: None, value, size, bind, visibility, other, kind,
+                      section } =>
+                Ok(SymData { name: None, value: value, size: size, bind: bind,
+                             visibility: visibility, other: other, kind: kind,
+                             section: section }),
             SymData { name: Some(Err(data)), .. } => Err(data)
         }
     }
@@ -1482,6 +1952,18 @@ impl<'a, B, Offsets> TryFrom<Sym<'a, B, Offsets>> for SymDataRaw<Offsets>
     }
 }
 
+impl<'a, B, Offsets> TryFrom<SymMut<'a, B, Offsets>> for SymDataRaw<Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    type Error = SymError;
+
+    #[inline]
+    fn try_from(sym: SymMut<'a, B, Offsets>) ->
+        Result<SymDataRaw<Offsets>, Self::Error> {
+        project::<B, Offsets>(sym.sym)
+    }
+}
+
 impl<'a, Name, Section, Class> WithStrtab<'a>
     for SymData<Name, Section, Class>
     where Class: ElfClass,
@@ -1494,25 +1976,30 @@ impl<'a, Name, Section, Class> WithStrtab<'a>
     #[inline]
     fn with_strtab(self, strtab: Strtab<'a>) ->
         Result<Self::Result, Self::Error> {
-        let SymData { name, value, size, bind, kind, section } = self;
+        let SymData { name, value, size, bind, visibility, other, kind,
+                      section } = self;
 
         match name {
             Some(name) => {
                 match strtab.idx(name) {
                     Ok(name) => Ok(SymData { name: Some(Ok(name)), value: value,
-                                             size: size, bind: bind, kind: kind,
+                                             size: size, bind: bind,
+                                             visibility: visibility,
+                                             other: other, kind: kind,
                                              section: section }),
                     Err(StrtabIdxError::UTF8Decode(data)) => {
                         Ok(SymData { name: Some(Err(data)), value: value,
-                                     size: size, bind: bind, kind: kind,
-                                     section: section })
+                                     size: size, bind: bind,
+                                     visibility: visibility, other: other,
+                                     kind: kind, section: section })
                     },
                     _ => Err(name)
                 }
             },
             None => {
-                Ok(SymData { name: None, value: value, size: size,
-                             bind: bind, kind: kind, section: section })
+                Ok(SymData { name: None, value: value, size: size, bind: bind,
+                             visibility: visibility, other: other, kind: kind,
+                             section: section })
             }
         }
     }
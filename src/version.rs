@@ -0,0 +1,1914 @@
+//! ELF symbol versioning functionality.
+//!
+//! This module provides the [Versyms], [Verdefs], and [Verneeds]
+//! types, which act as wrappers around the GNU symbol versioning
+//! sections (`.gnu.version`, `.gnu.version_d`, and `.gnu.version_r`
+//! respectively).
+//!
+//! A `Versyms` holds a version index for each symbol in the dynamic
+//! symbol table.  A `Verdefs` holds the versions defined by an
+//! object, and a `Verneeds` holds the versions it requires from the
+//! objects it depends on.  Each symbol can be paired with its
+//! resolved version using [VersionedSyms].
+//!
+//! # Examples
+//!
+//! The version of each symbol in a dynamic symbol table can be
+//! obtained by combining the tables into a `VersionedSyms`:
+//!
+//! ```
+//! extern crate elf_utils;
+//!
+//! use byteorder::LittleEndian;
+//! use core::convert::TryFrom;
+//! use core::convert::TryInto;
+//! use elf_utils::Elf64;
+//! use elf_utils::strtab::Strtab;
+//! use elf_utils::strtab::WithStrtab;
+//! use elf_utils::symtab::SymBase;
+//! use elf_utils::symtab::SymBind;
+//! use elf_utils::symtab::SymData;
+//! use elf_utils::symtab::SymDataRaw;
+//! use elf_utils::symtab::SymKind;
+//! use elf_utils::symtab::Symtab;
+//! use elf_utils::symtab::SymVisibility;
+//! use elf_utils::version::SymVersion;
+//! use elf_utils::version::Verdefs;
+//! use elf_utils::version::Verneeds;
+//! use elf_utils::version::VersionedSyms;
+//! use elf_utils::version::Versyms;
+//!
+//! const STRTAB: [u8; 57] = [
+//!     0x00, 0x6c, 0x69, 0x62, 0x66, 0x6f, 0x6f, 0x2e,
+//!     0x73, 0x6f, 0x2e, 0x31, 0x00, 0x46, 0x4f, 0x4f,
+//!     0x5f, 0x31, 0x2e, 0x30, 0x00, 0x46, 0x4f, 0x4f,
+//!     0x5f, 0x31, 0x2e, 0x31, 0x00, 0x6c, 0x69, 0x62,
+//!     0x62, 0x61, 0x72, 0x2e, 0x73, 0x6f, 0x2e, 0x32,
+//!     0x00, 0x42, 0x41, 0x52, 0x5f, 0x32, 0x2e, 0x30,
+//!     0x00, 0x42, 0x41, 0x52, 0x5f, 0x32, 0x2e, 0x31,
+//!     0x00
+//! ];
+//! const VERSYMS: [u8; 8] = [
+//!     0x00, 0x00, 0x02, 0x00, 0x03, 0x80, 0x05, 0x00
+//! ];
+//! const VERDEFS: [u8; 92] = [
+//!     0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+//!     0xc1, 0x7a, 0x77, 0x06, 0x14, 0x00, 0x00, 0x00,
+//!     0x1c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+//!     0x02, 0x00, 0x01, 0x00, 0x50, 0x24, 0x45, 0x0b,
+//!     0x14, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+//!     0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00,
+//!     0x51, 0x24, 0x45, 0x0b, 0x14, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+//!     0x08, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00
+//! ];
+//! const VERNEEDS: [u8; 48] = [
+//!     0x01, 0x00, 0x02, 0x00, 0x1d, 0x00, 0x00, 0x00,
+//!     0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+//!     0x50, 0x25, 0x68, 0x06, 0x00, 0x00, 0x04, 0x00,
+//!     0x29, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+//!     0x51, 0x25, 0x68, 0x06, 0x00, 0x00, 0x05, 0x00,
+//!     0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+//! ];
+//! const SYMS: [SymDataRaw<Elf64>; 4] = [
+//!     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
+//!               bind: SymBind::Local, visibility: SymVisibility::Default,
+//!               other: 0, section: SymBase::Undef },
+//!     SymData { name: Some(1), value: 0x1000, size: 0x10,
+//!               kind: SymKind::Function, bind: SymBind::Global,
+//!               visibility: SymVisibility::Default, other: 0,
+//!               section: SymBase::Index(1) },
+//!     SymData { name: Some(1), value: 0x1010, size: 0x10,
+//!               kind: SymKind::Function, bind: SymBind::Global,
+//!               visibility: SymVisibility::Default, other: 0,
+//!               section: SymBase::Index(1) },
+//!     SymData { name: Some(29), value: 0, size: 0, kind: SymKind::Function,
+//!               bind: SymBind::Global, visibility: SymVisibility::Default,
+//!               other: 0, section: SymBase::Undef }
+//! ];
+//!
+//! let strtab: Strtab<'_> =
+//!     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+//! let mut buf = [0; 96];
+//! let syms: Symtab<'_, LittleEndian, Elf64> =
+//!     Symtab::create(&mut buf[0..], SYMS.iter()).expect("Expected success");
+//! let versyms: Versyms<'_, LittleEndian> =
+//!     Versyms::try_from(&VERSYMS[0..]).expect("Expected success");
+//! let verdefs: Verdefs<'_, LittleEndian> =
+//!     Verdefs::try_from(&VERDEFS[0..]).expect("Expected success");
+//! let verneeds: Verneeds<'_, LittleEndian> =
+//!     Verneeds::try_from(&VERNEEDS[0..]).expect("Expected success");
+//! let versioned = VersionedSyms::new(syms, versyms, Some(verdefs),
+//!                                    Some(verneeds))
+//!     .expect("Expected success");
+//! let mut iter = versioned.iter().map(|(_, version)| {
+//!     let version: SymVersion<&str> = version.with_strtab(strtab)
+//!         .expect("Expected success")
+//!         .try_into().expect("Expected success");
+//!
+//!     version
+//! });
+//!
+//! assert_eq!(iter.next(), Some(SymVersion::Local));
+//! assert_eq!(iter.next(), Some(SymVersion::Defined { name: "FOO_1.0",
+//!                                                    hidden: false }));
+//! assert_eq!(iter.next(), Some(SymVersion::Defined { name: "FOO_1.1",
+//!                                                    hidden: true }));
+//! assert_eq!(iter.next(), Some(SymVersion::Needed { file: "libbar.so.2",
+//!                                                   name: "BAR_2.1",
+//!                                                   hidden: false }));
+//! assert_eq!(iter.next(), None);
+//! ```
+
+use byteorder::ByteOrder;
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Display;
+use core::fmt::Formatter;
+use core::iter::FusedIterator;
+use core::iter::Iterator;
+use core::marker::PhantomData;
+use crate::strtab::Strtab;
+use crate::strtab::StrtabIdxError;
+use crate::strtab::WithStrtab;
+use crate::symtab::Sym;
+use crate::symtab::SymOffsets;
+use crate::symtab::Symtab;
+
+const VERSYM_SIZE: usize = 2;
+
+const VERSYM_HIDDEN: u16 = 0x8000;
+const VERSYM_IDX_MASK: u16 = 0x7fff;
+
+const VER_NDX_LOCAL: u16 = 0;
+const VER_NDX_GLOBAL: u16 = 1;
+
+const VER_CURRENT: u16 = 1;
+
+const VERDEF_VERSION_START: usize = 0;
+const VERDEF_VERSION_SIZE: usize = 2;
+const VERDEF_VERSION_END: usize = VERDEF_VERSION_START + VERDEF_VERSION_SIZE;
+
+const VERDEF_FLAGS_START: usize = VERDEF_VERSION_END;
+const VERDEF_FLAGS_SIZE: usize = 2;
+const VERDEF_FLAGS_END: usize = VERDEF_FLAGS_START + VERDEF_FLAGS_SIZE;
+
+const VERDEF_IDX_START: usize = VERDEF_FLAGS_END;
+const VERDEF_IDX_SIZE: usize = 2;
+const VERDEF_IDX_END: usize = VERDEF_IDX_START + VERDEF_IDX_SIZE;
+
+const VERDEF_CNT_START: usize = VERDEF_IDX_END;
+const VERDEF_CNT_SIZE: usize = 2;
+const VERDEF_CNT_END: usize = VERDEF_CNT_START + VERDEF_CNT_SIZE;
+
+const VERDEF_HASH_START: usize = VERDEF_CNT_END;
+const VERDEF_HASH_SIZE: usize = 4;
+const VERDEF_HASH_END: usize = VERDEF_HASH_START + VERDEF_HASH_SIZE;
+
+const VERDEF_AUX_START: usize = VERDEF_HASH_END;
+const VERDEF_AUX_SIZE: usize = 4;
+const VERDEF_AUX_END: usize = VERDEF_AUX_START + VERDEF_AUX_SIZE;
+
+const VERDEF_NEXT_START: usize = VERDEF_AUX_END;
+const VERDEF_NEXT_SIZE: usize = 4;
+const VERDEF_NEXT_END: usize = VERDEF_NEXT_START + VERDEF_NEXT_SIZE;
+
+const VERDEF_SIZE: usize = VERDEF_NEXT_END;
+
+const VERDAUX_NAME_START: usize = 0;
+const VERDAUX_NAME_SIZE: usize = 4;
+const VERDAUX_NAME_END: usize = VERDAUX_NAME_START + VERDAUX_NAME_SIZE;
+
+const VERDAUX_NEXT_START: usize = VERDAUX_NAME_END;
+const VERDAUX_NEXT_SIZE: usize = 4;
+const VERDAUX_NEXT_END: usize = VERDAUX_NEXT_START + VERDAUX_NEXT_SIZE;
+
+const VERDAUX_SIZE: usize = VERDAUX_NEXT_END;
+
+const VERNEED_VERSION_START: usize = 0;
+const VERNEED_VERSION_SIZE: usize = 2;
+const VERNEED_VERSION_END: usize = VERNEED_VERSION_START +
+                                   VERNEED_VERSION_SIZE;
+
+const VERNEED_CNT_START: usize = VERNEED_VERSION_END;
+const VERNEED_CNT_SIZE: usize = 2;
+const VERNEED_CNT_END: usize = VERNEED_CNT_START + VERNEED_CNT_SIZE;
+
+const VERNEED_FILE_START: usize = VERNEED_CNT_END;
+const VERNEED_FILE_SIZE: usize = 4;
+const VERNEED_FILE_END: usize = VERNEED_FILE_START + VERNEED_FILE_SIZE;
+
+const VERNEED_AUX_START: usize = VERNEED_FILE_END;
+const VERNEED_AUX_SIZE: usize = 4;
+const VERNEED_AUX_END: usize = VERNEED_AUX_START + VERNEED_AUX_SIZE;
+
+const VERNEED_NEXT_START: usize = VERNEED_AUX_END;
+const VERNEED_NEXT_SIZE: usize = 4;
+const VERNEED_NEXT_END: usize = VERNEED_NEXT_START + VERNEED_NEXT_SIZE;
+
+const VERNEED_SIZE: usize = VERNEED_NEXT_END;
+
+const VERNAUX_HASH_START: usize = 0;
+const VERNAUX_HASH_SIZE: usize = 4;
+const VERNAUX_HASH_END: usize = VERNAUX_HASH_START + VERNAUX_HASH_SIZE;
+
+const VERNAUX_FLAGS_START: usize = VERNAUX_HASH_END;
+const VERNAUX_FLAGS_SIZE: usize = 2;
+const VERNAUX_FLAGS_END: usize = VERNAUX_FLAGS_START + VERNAUX_FLAGS_SIZE;
+
+const VERNAUX_IDX_START: usize = VERNAUX_FLAGS_END;
+const VERNAUX_IDX_SIZE: usize = 2;
+const VERNAUX_IDX_END: usize = VERNAUX_IDX_START + VERNAUX_IDX_SIZE;
+
+const VERNAUX_NAME_START: usize = VERNAUX_IDX_END;
+const VERNAUX_NAME_SIZE: usize = 4;
+const VERNAUX_NAME_END: usize = VERNAUX_NAME_START + VERNAUX_NAME_SIZE;
+
+const VERNAUX_NEXT_START: usize = VERNAUX_NAME_END;
+const VERNAUX_NEXT_SIZE: usize = 4;
+const VERNAUX_NEXT_END: usize = VERNAUX_NEXT_START + VERNAUX_NEXT_SIZE;
+
+const VERNAUX_SIZE: usize = VERNAUX_NEXT_END;
+
+/// In-place read-only ELF symbol version table.
+///
+/// A symbol version table (the `.gnu.version` section) contains a
+/// version index for each symbol in the associated dynamic symbol
+/// table.  The indexes refer to entries in the [Verdefs] and
+/// [Verneeds] for the same object.
+///
+/// A `Versyms` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance.  New `Versyms` can be
+/// created from an iterator over [VersymData] with
+/// [create](Versyms::create) or [create_split](Versyms::create_split).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use elf_utils::version::Versyms;
+/// use elf_utils::version::VersymData;
+///
+/// const VERSYMS: [u8; 8] = [
+///     0x00, 0x00, 0x02, 0x00, 0x03, 0x80, 0x05, 0x00
+/// ];
+///
+/// let versyms: Versyms<'_, LittleEndian> =
+///     Versyms::try_from(&VERSYMS[0..]).expect("Expected success");
+///
+/// assert_eq!(versyms.num_versyms(), 4);
+/// assert_eq!(versyms.idx(2), Some(VersymData { idx: 3, hidden: true }));
+/// assert_eq!(versyms.idx(4), None);
+/// ```
+#[derive(Copy, Clone)]
+pub struct Versyms<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8]
+}
+
+/// Iterator over the entries of a [Versyms].
+#[derive(Copy, Clone)]
+pub struct VersymsIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    idx: usize
+}
+
+/// Version index for a single symbol.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VersymData {
+    /// Index of the version.  This is `0` for local symbols, `1` for
+    /// unversioned global symbols, and otherwise refers to an entry
+    /// in the [Verdefs] or [Verneeds].
+    pub idx: u16,
+    /// Whether this is a hidden (non-default) version of the symbol.
+    pub hidden: bool
+}
+
+/// Errors that can occur creating a [Versyms].
+///
+/// The only error that can occur is if the data is not a multiple of
+/// the size of a version index.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VersymsError {
+    BadSize(usize)
+}
+
+/// In-place read-only ELF version definition table.
+///
+/// A version definition table (the `.gnu.version_d` section) is a
+/// chain of version definitions, each of which carries one or more
+/// names.  The first name is the name of the version itself; any
+/// subsequent names are the versions it inherits from.
+///
+/// A `Verdefs` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance, which will check the
+/// structure of the chains.  It can be iterated over with
+/// [iter](Verdefs::iter), which produces a [VerdefData] for each
+/// definition.  New `Verdefs` can be created with
+/// [create](Verdefs::create) or [create_split](Verdefs::create_split).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::strtab::Strtab;
+/// use elf_utils::strtab::WithStrtab;
+/// use elf_utils::version::VerdauxData;
+/// use elf_utils::version::Verdefs;
+///
+/// const STRTAB: [u8; 29] = [
+///     0x00, 0x6c, 0x69, 0x62, 0x66, 0x6f, 0x6f, 0x2e,
+///     0x73, 0x6f, 0x2e, 0x31, 0x00, 0x46, 0x4f, 0x4f,
+///     0x5f, 0x31, 0x2e, 0x30, 0x00, 0x46, 0x4f, 0x4f,
+///     0x5f, 0x31, 0x2e, 0x31, 0x00
+/// ];
+/// const VERDEFS: [u8; 92] = [
+///     0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+///     0xc1, 0x7a, 0x77, 0x06, 0x14, 0x00, 0x00, 0x00,
+///     0x1c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+///     0x02, 0x00, 0x01, 0x00, 0x50, 0x24, 0x45, 0x0b,
+///     0x14, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+///     0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00,
+///     0x51, 0x24, 0x45, 0x0b, 0x14, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+///     0x08, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00
+/// ];
+///
+/// let strtab: Strtab<'_> =
+///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+/// let verdefs: Verdefs<'_, LittleEndian> =
+///     Verdefs::try_from(&VERDEFS[0..]).expect("Expected success");
+/// let verdef = verdefs.iter().nth(2).expect("Expected some");
+///
+/// assert_eq!(verdef.idx, 3);
+///
+/// let mut names = verdef.names.map(|name| {
+///     let name: VerdauxData<&str> = name.with_strtab(strtab)
+///         .expect("Expected success")
+///         .try_into().expect("Expected success");
+///
+///     name
+/// });
+///
+/// assert_eq!(names.next(), Some(VerdauxData { name: "FOO_1.1" }));
+/// assert_eq!(names.next(), Some(VerdauxData { name: "FOO_1.0" }));
+/// assert_eq!(names.next(), None);
+/// ```
+#[derive(Copy, Clone)]
+pub struct Verdefs<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8]
+}
+
+/// Iterator over the definitions in a [Verdefs].
+///
+/// This produces a [VerdefData] for each definition, whose names
+/// are given by a [VerdauxIter].
+#[derive(Copy, Clone)]
+pub struct VerdefsIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    offset: Option<usize>
+}
+
+/// Iterator over the names in a single version definition.
+///
+/// This produces a [VerdauxData] for each name.
+#[derive(Copy, Clone)]
+pub struct VerdauxIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    offset: usize,
+    remaining: usize
+}
+
+/// Projected ELF version definition.
+///
+/// This is a representation of a single version definition in a form
+/// that can be manipulated by native code.  When obtained from a
+/// [Verdefs], `Names` is a [VerdauxIter].  Any type that can be
+/// cloned and iterated to produce [VerdauxData] (such as a slice) can
+/// be used to create a `Verdefs` with [create](Verdefs::create).
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VerdefData<Names> {
+    /// Version flags.  The `0x1` bit marks the definition of the
+    /// object itself, and the `0x2` bit marks a weak definition.
+    pub flags: u16,
+    /// Version index, as referenced by [VersymData].
+    pub idx: u16,
+    /// ELF hash of the version name (see
+    /// [ElfName](crate::hash::ElfName)).
+    pub hash: u32,
+    /// Names of the version and its predecessors.
+    pub names: Names
+}
+
+/// Name entry in a version definition.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VerdauxData<Name> {
+    /// Name of the version.
+    pub name: Name
+}
+
+/// Errors that can occur creating a [Verdefs].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VerdefsError {
+    /// Unknown structure version.
+    BadVersion(u16),
+    /// An entry at the given offset is out of bounds.
+    OutOfBounds(usize)
+}
+
+/// In-place read-only ELF version requirement table.
+///
+/// A version requirement table (the `.gnu.version_r` section) is a
+/// chain of entries, one for each object from which versioned
+/// symbols are imported.  Each of these contains a chain of the
+/// versions required from that object.
+///
+/// A `Verneeds` can be created from raw data using the
+/// [TryFrom](core::convert::TryFrom) instance, which will check the
+/// structure of the chains.  It can be iterated over with
+/// [iter](Verneeds::iter), which produces a [VerneedData] for each
+/// object.  New `Verneeds` can be created with
+/// [create](Verneeds::create) or
+/// [create_split](Verneeds::create_split).
+///
+/// # Examples
+///
+/// ```
+/// extern crate elf_utils;
+///
+/// use byteorder::LittleEndian;
+/// use core::convert::TryFrom;
+/// use core::convert::TryInto;
+/// use elf_utils::strtab::Strtab;
+/// use elf_utils::strtab::WithStrtab;
+/// use elf_utils::version::VernauxData;
+/// use elf_utils::version::VerneedData;
+/// use elf_utils::version::Verneeds;
+///
+/// const STRTAB: [u8; 57] = [
+///     0x00, 0x6c, 0x69, 0x62, 0x66, 0x6f, 0x6f, 0x2e,
+///     0x73, 0x6f, 0x2e, 0x31, 0x00, 0x46, 0x4f, 0x4f,
+///     0x5f, 0x31, 0x2e, 0x30, 0x00, 0x46, 0x4f, 0x4f,
+///     0x5f, 0x31, 0x2e, 0x31, 0x00, 0x6c, 0x69, 0x62,
+///     0x62, 0x61, 0x72, 0x2e, 0x73, 0x6f, 0x2e, 0x32,
+///     0x00, 0x42, 0x41, 0x52, 0x5f, 0x32, 0x2e, 0x30,
+///     0x00, 0x42, 0x41, 0x52, 0x5f, 0x32, 0x2e, 0x31,
+///     0x00
+/// ];
+/// const VERNEEDS: [u8; 48] = [
+///     0x01, 0x00, 0x02, 0x00, 0x1d, 0x00, 0x00, 0x00,
+///     0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x50, 0x25, 0x68, 0x06, 0x00, 0x00, 0x04, 0x00,
+///     0x29, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+///     0x51, 0x25, 0x68, 0x06, 0x00, 0x00, 0x05, 0x00,
+///     0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+/// ];
+///
+/// let strtab: Strtab<'_> =
+///     Strtab::try_from(&STRTAB[0..]).expect("Expected success");
+/// let verneeds: Verneeds<'_, LittleEndian> =
+///     Verneeds::try_from(&VERNEEDS[0..]).expect("Expected success");
+/// let mut iter = verneeds.iter();
+/// let verneed = iter.next().expect("Expected some");
+/// let verneed: VerneedData<&str, _> = verneed.with_strtab(strtab)
+///     .expect("Expected success")
+///     .try_into().expect("Expected success");
+///
+/// assert_eq!(verneed.file, "libbar.so.2");
+///
+/// let mut versions = verneed.versions.map(|version| {
+///     let version: VernauxData<&str> = version.with_strtab(strtab)
+///         .expect("Expected success")
+///         .try_into().expect("Expected success");
+///
+///     version
+/// });
+///
+/// assert_eq!(versions.next(), Some(VernauxData { hash: 0x6682550,
+///                                                flags: 0, idx: 4,
+///                                                name: "BAR_2.0" }));
+/// assert_eq!(versions.next(), Some(VernauxData { hash: 0x6682551,
+///                                                flags: 0, idx: 5,
+///                                                name: "BAR_2.1" }));
+/// assert_eq!(versions.next(), None);
+/// assert!(iter.next().is_none());
+/// ```
+#[derive(Copy, Clone)]
+pub struct Verneeds<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8]
+}
+
+/// Iterator over the entries in a [Verneeds].
+///
+/// This produces a [VerneedData] for each required object, whose
+/// versions are given by a [VernauxIter].
+#[derive(Copy, Clone)]
+pub struct VerneedsIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    offset: Option<usize>
+}
+
+/// Iterator over the versions required from a single object.
+///
+/// This produces a [VernauxData] for each version.
+#[derive(Copy, Clone)]
+pub struct VernauxIter<'a, B: ByteOrder> {
+    byteorder: PhantomData<B>,
+    data: &'a [u8],
+    offset: usize,
+    remaining: usize
+}
+
+/// Projected ELF version requirement.
+///
+/// This is a representation of the versions required from a single
+/// object in a form that can be manipulated by native code.  When
+/// obtained from a [Verneeds], `Versions` is a [VernauxIter].  Any
+/// type that can be cloned and iterated to produce [VernauxData]
+/// (such as a slice) can be used to create a `Verneeds` with
+/// [create](Verneeds::create).
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VerneedData<Name, Versions> {
+    /// Name of the required object.
+    pub file: Name,
+    /// Versions required from the object.
+    pub versions: Versions
+}
+
+/// Version entry in a version requirement.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VernauxData<Name> {
+    /// ELF hash of the version name (see
+    /// [ElfName](crate::hash::ElfName)).
+    pub hash: u32,
+    /// Version flags.  The `0x2` bit marks a weak requirement.
+    pub flags: u16,
+    /// Version index, as referenced by [VersymData].
+    pub idx: u16,
+    /// Name of the version.
+    pub name: Name
+}
+
+/// Errors that can occur creating a [Verneeds].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum VerneedsError {
+    /// Unknown structure version.
+    BadVersion(u16),
+    /// An entry at the given offset is out of bounds.
+    OutOfBounds(usize)
+}
+
+/// Version of a symbol, resolved using the version tables.
+///
+/// This is obtained from a [VersionedSyms], and can have its names
+/// resolved using the [WithStrtab] instance.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SymVersion<Name> {
+    /// The symbol is local to the object.
+    Local,
+    /// The symbol is global, but has no version.
+    Global,
+    /// The symbol is defined by this object with a version.
+    Defined {
+        /// Name of the version.
+        name: Name,
+        /// Whether this is a hidden (non-default) version.
+        hidden: bool
+    },
+    /// The symbol is required from another object with a version.
+    Needed {
+        /// Name of the object defining the symbol.
+        file: Name,
+        /// Name of the version.
+        name: Name,
+        /// Whether this is a hidden (non-default) version.
+        hidden: bool
+    },
+    /// The version index doesn't appear in the version tables.
+    Unknown {
+        /// Version index.
+        idx: u16,
+        /// Whether this is a hidden (non-default) version.
+        hidden: bool
+    }
+}
+
+/// Dynamic symbol table paired with its version tables.
+///
+/// This combines a [Symtab] with the [Versyms] giving the version of
+/// each symbol, as well as the [Verdefs] and [Verneeds] (if present)
+/// that the version indexes refer to.  The versions of symbols can
+/// then be obtained with [idx](VersionedSyms::idx) or
+/// [iter](VersionedSyms::iter).
+#[derive(Copy, Clone)]
+pub struct VersionedSyms<'a, B: ByteOrder, Offsets: SymOffsets> {
+    syms: Symtab<'a, B, Offsets>,
+    versyms: Versyms<'a, B>,
+    verdefs: Option<Verdefs<'a, B>>,
+    verneeds: Option<Verneeds<'a, B>>
+}
+
+/// Iterator over the symbols in a [VersionedSyms].
+///
+/// This produces each [Sym] along with its [SymVersion].
+#[derive(Copy, Clone)]
+pub struct VersionedSymsIter<'a, B: ByteOrder, Offsets: SymOffsets> {
+    versioned: VersionedSyms<'a, B, Offsets>,
+    idx: usize
+}
+
+/// Check the chains in a version definition table.
+fn check_verdefs<B>(data: &[u8]) -> Result<(), VerdefsError>
+    where B: ByteOrder {
+    let len = data.len();
+    let mut offset = 0;
+
+    while offset < len {
+        if len - offset < VERDEF_SIZE {
+            return Err(VerdefsError::OutOfBounds(offset))
+        }
+
+        let ent = &data[offset .. offset + VERDEF_SIZE];
+        let version = B::read_u16(&ent[VERDEF_VERSION_START ..
+                                       VERDEF_VERSION_END]);
+
+        if version != VER_CURRENT {
+            return Err(VerdefsError::BadVersion(version))
+        }
+
+        let cnt = B::read_u16(&ent[VERDEF_CNT_START .. VERDEF_CNT_END]);
+        let aux = B::read_u32(&ent[VERDEF_AUX_START .. VERDEF_AUX_END]);
+        let next = B::read_u32(&ent[VERDEF_NEXT_START .. VERDEF_NEXT_END]);
+        let mut aux_offset = offset + aux as usize;
+
+        for i in 0 .. cnt {
+            if aux_offset > len || len - aux_offset < VERDAUX_SIZE {
+                return Err(VerdefsError::OutOfBounds(aux_offset))
+            }
+
+            let aux_next = B::read_u32(&data[aux_offset + VERDAUX_NEXT_START ..
+                                             aux_offset + VERDAUX_NEXT_END]);
+
+            if i + 1 < cnt {
+                if aux_next == 0 {
+                    return Err(VerdefsError::OutOfBounds(aux_offset))
+                }
+
+                aux_offset += aux_next as usize;
+            }
+        }
+
+        if next == 0 {
+            return Ok(())
+        }
+
+        offset += next as usize;
+    }
+
+    // The next offset for the last entry pointed past the end.
+    if len == 0 {
+        Ok(())
+    } else {
+        Err(VerdefsError::OutOfBounds(offset))
+    }
+}
+
+/// Check the chains in a version requirement table.
+fn check_verneeds<B>(data: &[u8]) -> Result<(), VerneedsError>
+    where B: ByteOrder {
+    let len = data.len();
+    let mut offset = 0;
+
+    while offset < len {
+        if len - offset < VERNEED_SIZE {
+            return Err(VerneedsError::OutOfBounds(offset))
+        }
+
+        let ent = &data[offset .. offset + VERNEED_SIZE];
+        let version = B::read_u16(&ent[VERNEED_VERSION_START ..
+                                       VERNEED_VERSION_END]);
+
+        if version != VER_CURRENT {
+            return Err(VerneedsError::BadVersion(version))
+        }
+
+        let cnt = B::read_u16(&ent[VERNEED_CNT_START .. VERNEED_CNT_END]);
+        let aux = B::read_u32(&ent[VERNEED_AUX_START .. VERNEED_AUX_END]);
+        let next = B::read_u32(&ent[VERNEED_NEXT_START .. VERNEED_NEXT_END]);
+        let mut aux_offset = offset + aux as usize;
+
+        for i in 0 .. cnt {
+            if aux_offset > len || len - aux_offset < VERNAUX_SIZE {
+                return Err(VerneedsError::OutOfBounds(aux_offset))
+            }
+
+            let aux_next = B::read_u32(&data[aux_offset + VERNAUX_NEXT_START ..
+                                             aux_offset + VERNAUX_NEXT_END]);
+
+            if i + 1 < cnt {
+                if aux_next == 0 {
+                    return Err(VerneedsError::OutOfBounds(aux_offset))
+                }
+
+                aux_offset += aux_next as usize;
+            }
+        }
+
+        if next == 0 {
+            return Ok(())
+        }
+
+        offset += next as usize;
+    }
+
+    if len == 0 {
+        Ok(())
+    } else {
+        Err(VerneedsError::OutOfBounds(offset))
+    }
+}
+
+/// Get the symbol version for `versym` from the version tables.
+fn resolve_version<'a, B>(versym: VersymData,
+                          verdefs: Option<Verdefs<'a, B>>,
+                          verneeds: Option<Verneeds<'a, B>>) ->
+    SymVersion<u32>
+    where B: ByteOrder {
+    let VersymData { idx, hidden } = versym;
+
+    match idx {
+        VER_NDX_LOCAL => SymVersion::Local,
+        VER_NDX_GLOBAL => SymVersion::Global,
+        _ => match verdefs.and_then(|verdefs| verdefs.version(idx)) {
+            Some(VerdefData { mut names, .. }) => match names.next() {
+                Some(VerdauxData { name }) =>
+                    SymVersion::Defined { name: name, hidden: hidden },
+                None => SymVersion::Unknown { idx: idx, hidden: hidden }
+            },
+            None => match verneeds.and_then(|verneeds| verneeds.version(idx)) {
+                Some(VerneedData { file,
+                                   versions: VernauxData { name, .. } }) =>
+                    SymVersion::Needed { file: file, name: name,
+                                         hidden: hidden },
+                None => SymVersion::Unknown { idx: idx, hidden: hidden }
+            }
+        }
+    }
+}
+
+/// Calculate the number of bytes required to represent all of the
+/// version indexes in `versyms`.
+#[inline]
+pub fn versyms_required_bytes<I>(versyms: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<VersymData> {
+    versyms.count() * VERSYM_SIZE
+}
+
+/// Calculate the number of bytes required to represent all of the
+/// version definitions in `verdefs`.
+#[inline]
+pub fn verdefs_required_bytes<I, Names>(verdefs: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<VerdefData<Names>>,
+          Names: Clone + IntoIterator,
+          Names::Item: Borrow<VerdauxData<u32>> {
+    let mut size = 0;
+
+    for verdef in verdefs {
+        let nnames = verdef.borrow().names.clone().into_iter().count();
+
+        size += VERDEF_SIZE + (nnames * VERDAUX_SIZE);
+    }
+
+    size
+}
+
+/// Calculate the number of bytes required to represent all of the
+/// version requirements in `verneeds`.
+#[inline]
+pub fn verneeds_required_bytes<I, Versions>(verneeds: I) -> usize
+    where I: Iterator,
+          I::Item: Borrow<VerneedData<u32, Versions>>,
+          Versions: Clone + IntoIterator,
+          Versions::Item: Borrow<VernauxData<u32>> {
+    let mut size = 0;
+
+    for verneed in verneeds {
+        let nversions = verneed.borrow().versions.clone().into_iter().count();
+
+        size += VERNEED_SIZE + (nversions * VERNAUX_SIZE);
+    }
+
+    size
+}
+
+impl<'a, B> Versyms<'a, B>
+    where B: ByteOrder {
+    /// Attempt to create a `Versyms` in `buf` containing the version
+    /// indexes in `versyms`.
+    ///
+    /// This will write the version indexes into the buffer in the
+    /// ELF format.  Returns both the `Versyms` and the remaining
+    /// space if successful.
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the version indexes don't
+    /// fit into the provided memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::version::VersymData;
+    /// use elf_utils::version::Versyms;
+    ///
+    /// const VERSYMS: [VersymData; 3] = [
+    ///     VersymData { idx: 0, hidden: false },
+    ///     VersymData { idx: 2, hidden: false },
+    ///     VersymData { idx: 3, hidden: true }
+    /// ];
+    ///
+    /// let mut buf = [0; 8];
+    /// let (versyms, rest): (Versyms<'_, LittleEndian>, _) =
+    ///     Versyms::create_split(&mut buf[0..], VERSYMS.iter())
+    ///     .expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 2);
+    ///
+    /// let mut iter = versyms.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(VERSYMS[0]));
+    /// assert_eq!(iter.next(), Some(VERSYMS[1]));
+    /// assert_eq!(iter.next(), Some(VERSYMS[2]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[inline]
+    pub fn create_split<I>(buf: &'a mut [u8], versyms: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<VersymData> {
+        let mut idx = 0;
+
+        for versym in versyms {
+            let VersymData { idx: ver, hidden } = versym.borrow();
+
+            if ver & VERSYM_HIDDEN != 0 || buf.len() - idx < VERSYM_SIZE {
+                return Err(())
+            }
+
+            let value = if *hidden { ver | VERSYM_HIDDEN } else { *ver };
+
+            B::write_u16(&mut buf[idx .. idx + VERSYM_SIZE], value);
+            idx += VERSYM_SIZE;
+        }
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((Versyms { byteorder: PhantomData, data: data }, rest))
+    }
+
+    /// Attempt to create a `Versyms` in `buf` containing the version
+    /// indexes in `versyms` (see [create_split](Versyms::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// The only error that can occur is if the version indexes don't
+    /// fit into the provided memory.
+    #[inline]
+    pub fn create<I>(buf: &'a mut [u8], versyms: I) -> Result<Self, ()>
+        where I: Iterator,
+              I::Item: Borrow<VersymData> {
+        match Self::create_split(buf, versyms) {
+            Ok((versyms, _)) => Ok(versyms),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the version index for the symbol at index `idx`.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) -> Option<VersymData> {
+        let start = idx * VERSYM_SIZE;
+
+        if start < self.data.len() {
+            let value = B::read_u16(&self.data[start .. start + VERSYM_SIZE]);
+
+            Some(VersymData::from(value))
+        } else {
+            None
+        }
+    }
+
+    /// Get the number of version indexes in this `Versyms`.
+    #[inline]
+    pub fn num_versyms(&self) -> usize {
+        self.data.len() / VERSYM_SIZE
+    }
+
+    /// Get an iterator over this `Versyms`.
+    #[inline]
+    pub fn iter(&self) -> VersymsIter<'a, B> {
+        VersymsIter { byteorder: PhantomData, data: self.data, idx: 0 }
+    }
+}
+
+impl<'a, B> Verdefs<'a, B>
+    where B: ByteOrder {
+    /// Attempt to create a `Verdefs` in `buf` containing the version
+    /// definitions in `verdefs`.
+    ///
+    /// This will write the version definitions into the buffer in
+    /// the ELF format, with each definition followed by its names.
+    /// Returns both the `Verdefs` and the remaining space if
+    /// successful.
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if the version definitions don't fit into the
+    /// provided memory, or if a definition has more names than can
+    /// be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::version::VerdauxData;
+    /// use elf_utils::version::VerdefData;
+    /// use elf_utils::version::Verdefs;
+    ///
+    /// const BASE_NAMES: [VerdauxData<u32>; 1] = [
+    ///     VerdauxData { name: 1 }
+    /// ];
+    /// const FOO_1_0_NAMES: [VerdauxData<u32>; 1] = [
+    ///     VerdauxData { name: 13 }
+    /// ];
+    /// const FOO_1_1_NAMES: [VerdauxData<u32>; 2] = [
+    ///     VerdauxData { name: 21 },
+    ///     VerdauxData { name: 13 }
+    /// ];
+    /// const VERDEFS: [VerdefData<&'static [VerdauxData<u32>]>; 3] = [
+    ///     VerdefData { flags: 1, idx: 1, hash: 0x6777ac1,
+    ///                  names: &BASE_NAMES },
+    ///     VerdefData { flags: 0, idx: 2, hash: 0xb452450,
+    ///                  names: &FOO_1_0_NAMES },
+    ///     VerdefData { flags: 0, idx: 3, hash: 0xb452451,
+    ///                  names: &FOO_1_1_NAMES }
+    /// ];
+    ///
+    /// let mut buf = [0; 96];
+    /// let (verdefs, rest): (Verdefs<'_, LittleEndian>, _) =
+    ///     Verdefs::create_split(&mut buf[0..], VERDEFS.iter())
+    ///     .expect("Expected success");
+    ///
+    /// assert_eq!(rest.len(), 4);
+    ///
+    /// let mut iter = verdefs.iter();
+    ///
+    /// for expected in VERDEFS.iter() {
+    ///     let verdef = iter.next().expect("Expected some");
+    ///
+    ///     assert_eq!(verdef.flags, expected.flags);
+    ///     assert_eq!(verdef.idx, expected.idx);
+    ///     assert_eq!(verdef.hash, expected.hash);
+    ///     assert!(verdef.names.eq(expected.names.iter().cloned()));
+    /// }
+    ///
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn create_split<I, Names>(buf: &'a mut [u8], verdefs: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<VerdefData<Names>>,
+              Names: Clone + IntoIterator,
+              Names::Item: Borrow<VerdauxData<u32>> {
+        let mut idx = 0;
+        let mut prev: Option<usize> = None;
+
+        for verdef in verdefs {
+            let VerdefData { flags, idx: ver, hash, names } = verdef.borrow();
+            let nnames = names.clone().into_iter().count();
+            let size = VERDEF_SIZE + (nnames * VERDAUX_SIZE);
+
+            if nnames > u16::MAX as usize || buf.len() - idx < size {
+                return Err(())
+            }
+
+            // Link the previous entry to this one.
+            if let Some(prev) = prev {
+                B::write_u32(&mut buf[prev + VERDEF_NEXT_START ..
+                                      prev + VERDEF_NEXT_END],
+                             (idx - prev) as u32);
+            }
+
+            let ent = &mut buf[idx .. idx + VERDEF_SIZE];
+
+            B::write_u16(&mut ent[VERDEF_VERSION_START .. VERDEF_VERSION_END],
+                         VER_CURRENT);
+            B::write_u16(&mut ent[VERDEF_FLAGS_START .. VERDEF_FLAGS_END],
+                         *flags);
+            B::write_u16(&mut ent[VERDEF_IDX_START .. VERDEF_IDX_END], *ver);
+            B::write_u16(&mut ent[VERDEF_CNT_START .. VERDEF_CNT_END],
+                         nnames as u16);
+            B::write_u32(&mut ent[VERDEF_HASH_START .. VERDEF_HASH_END],
+                         *hash);
+            B::write_u32(&mut ent[VERDEF_AUX_START .. VERDEF_AUX_END],
+                         VERDEF_SIZE as u32);
+            B::write_u32(&mut ent[VERDEF_NEXT_START .. VERDEF_NEXT_END], 0);
+
+            let mut aux = idx + VERDEF_SIZE;
+
+            for (i, name) in names.clone().into_iter().enumerate() {
+                let VerdauxData { name } = name.borrow();
+                let next = if i + 1 < nnames { VERDAUX_SIZE as u32 }
+                           else { 0 };
+                let ent = &mut buf[aux .. aux + VERDAUX_SIZE];
+
+                B::write_u32(&mut ent[VERDAUX_NAME_START .. VERDAUX_NAME_END],
+                             *name);
+                B::write_u32(&mut ent[VERDAUX_NEXT_START .. VERDAUX_NEXT_END],
+                             next);
+                aux += VERDAUX_SIZE;
+            }
+
+            prev = Some(idx);
+            idx += size;
+        }
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((Verdefs { byteorder: PhantomData, data: data }, rest))
+    }
+
+    /// Attempt to create a `Verdefs` in `buf` containing the version
+    /// definitions in `verdefs` (see
+    /// [create_split](Verdefs::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if the version definitions don't fit into the
+    /// provided memory, or if a definition has more names than can
+    /// be represented.
+    #[inline]
+    pub fn create<I, Names>(buf: &'a mut [u8], verdefs: I) ->
+        Result<Self, ()>
+        where I: Iterator,
+              I::Item: Borrow<VerdefData<Names>>,
+              Names: Clone + IntoIterator,
+              Names::Item: Borrow<VerdauxData<u32>> {
+        match Self::create_split(buf, verdefs) {
+            Ok((verdefs, _)) => Ok(verdefs),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the version definition with the version index `idx`.
+    ///
+    /// Returns `None` if there is no such definition.
+    #[inline]
+    pub fn version(&self, idx: u16) -> Option<VerdefData<VerdauxIter<'a, B>>> {
+        self.iter().find(|verdef| verdef.idx == idx)
+    }
+
+    /// Get an iterator over this `Verdefs`.
+    #[inline]
+    pub fn iter(&self) -> VerdefsIter<'a, B> {
+        let offset = if self.data.is_empty() { None } else { Some(0) };
+
+        VerdefsIter { byteorder: PhantomData, data: self.data,
+                      offset: offset }
+    }
+}
+
+impl<'a, B> Verneeds<'a, B>
+    where B: ByteOrder {
+    /// Attempt to create a `Verneeds` in `buf` containing the version
+    /// requirements in `verneeds`.
+    ///
+    /// This will write the version requirements into the buffer in
+    /// the ELF format, with each entry followed by its versions.
+    /// Returns both the `Verneeds` and the remaining space if
+    /// successful.
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if the version requirements don't fit into
+    /// the provided memory, or if an entry has more versions than can
+    /// be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate elf_utils;
+    ///
+    /// use byteorder::LittleEndian;
+    /// use elf_utils::version::VernauxData;
+    /// use elf_utils::version::VerneedData;
+    /// use elf_utils::version::Verneeds;
+    ///
+    /// const LIBBAR_VERSIONS: [VernauxData<u32>; 2] = [
+    ///     VernauxData { hash: 0x6682550, flags: 0, idx: 4, name: 41 },
+    ///     VernauxData { hash: 0x6682551, flags: 0, idx: 5, name: 49 }
+    /// ];
+    /// const VERNEEDS: [VerneedData<u32, &'static [VernauxData<u32>]>; 1] = [
+    ///     VerneedData { file: 29, versions: &LIBBAR_VERSIONS }
+    /// ];
+    ///
+    /// let mut buf = [0; 48];
+    /// let verneeds: Verneeds<'_, LittleEndian> =
+    ///     Verneeds::create(&mut buf[0..], VERNEEDS.iter())
+    ///     .expect("Expected success");
+    /// let verneed = verneeds.version(5).expect("Expected some");
+    ///
+    /// assert_eq!(verneed, VerneedData { file: 29,
+    ///                                   versions: LIBBAR_VERSIONS[1] });
+    /// ```
+    pub fn create_split<I, Versions>(buf: &'a mut [u8], verneeds: I) ->
+        Result<(Self, &'a mut [u8]), ()>
+        where I: Iterator,
+              I::Item: Borrow<VerneedData<u32, Versions>>,
+              Versions: Clone + IntoIterator,
+              Versions::Item: Borrow<VernauxData<u32>> {
+        let mut idx = 0;
+        let mut prev: Option<usize> = None;
+
+        for verneed in verneeds {
+            let VerneedData { file, versions } = verneed.borrow();
+            let nversions = versions.clone().into_iter().count();
+            let size = VERNEED_SIZE + (nversions * VERNAUX_SIZE);
+
+            if nversions > u16::MAX as usize || buf.len() - idx < size {
+                return Err(())
+            }
+
+            // Link the previous entry to this one.
+            if let Some(prev) = prev {
+                B::write_u32(&mut buf[prev + VERNEED_NEXT_START ..
+                                      prev + VERNEED_NEXT_END],
+                             (idx - prev) as u32);
+            }
+
+            let ent = &mut buf[idx .. idx + VERNEED_SIZE];
+
+            B::write_u16(&mut ent[VERNEED_VERSION_START ..
+                                  VERNEED_VERSION_END],
+                         VER_CURRENT);
+            B::write_u16(&mut ent[VERNEED_CNT_START .. VERNEED_CNT_END],
+                         nversions as u16);
+            B::write_u32(&mut ent[VERNEED_FILE_START .. VERNEED_FILE_END],
+                         *file);
+            B::write_u32(&mut ent[VERNEED_AUX_START .. VERNEED_AUX_END],
+                         VERNEED_SIZE as u32);
+            B::write_u32(&mut ent[VERNEED_NEXT_START .. VERNEED_NEXT_END], 0);
+
+            let mut aux = idx + VERNEED_SIZE;
+
+            for (i, version) in versions.clone().into_iter().enumerate() {
+                let VernauxData { hash, flags, idx: ver, name } =
+                    version.borrow();
+                let next = if i + 1 < nversions { VERNAUX_SIZE as u32 }
+                           else { 0 };
+                let ent = &mut buf[aux .. aux + VERNAUX_SIZE];
+
+                B::write_u32(&mut ent[VERNAUX_HASH_START .. VERNAUX_HASH_END],
+                             *hash);
+                B::write_u16(&mut ent[VERNAUX_FLAGS_START ..
+                                      VERNAUX_FLAGS_END],
+                             *flags);
+                B::write_u16(&mut ent[VERNAUX_IDX_START .. VERNAUX_IDX_END],
+                             *ver);
+                B::write_u32(&mut ent[VERNAUX_NAME_START .. VERNAUX_NAME_END],
+                             *name);
+                B::write_u32(&mut ent[VERNAUX_NEXT_START .. VERNAUX_NEXT_END],
+                             next);
+                aux += VERNAUX_SIZE;
+            }
+
+            prev = Some(idx);
+            idx += size;
+        }
+
+        let (data, rest) = buf.split_at_mut(idx);
+
+        Ok((Verneeds { byteorder: PhantomData, data: data }, rest))
+    }
+
+    /// Attempt to create a `Verneeds` in `buf` containing the version
+    /// requirements in `verneeds` (see
+    /// [create_split](Verneeds::create_split)).
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if the version requirements don't fit into
+    /// the provided memory, or if an entry has more versions than can
+    /// be represented.
+    #[inline]
+    pub fn create<I, Versions>(buf: &'a mut [u8], verneeds: I) ->
+        Result<Self, ()>
+        where I: Iterator,
+              I::Item: Borrow<VerneedData<u32, Versions>>,
+              Versions: Clone + IntoIterator,
+              Versions::Item: Borrow<VernauxData<u32>> {
+        match Self::create_split(buf, verneeds) {
+            Ok((verneeds, _)) => Ok(verneeds),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Get the version requirement with the version index `idx`,
+    /// along with the name of the object it is required from.
+    ///
+    /// Returns `None` if there is no such requirement.
+    pub fn version(&self, idx: u16) ->
+        Option<VerneedData<u32, VernauxData<u32>>> {
+        for VerneedData { file, versions } in self.iter() {
+            for version in versions {
+                if version.idx == idx {
+                    return Some(VerneedData { file: file, versions: version })
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Get an iterator over this `Verneeds`.
+    #[inline]
+    pub fn iter(&self) -> VerneedsIter<'a, B> {
+        let offset = if self.data.is_empty() { None } else { Some(0) };
+
+        VerneedsIter { byteorder: PhantomData, data: self.data,
+                       offset: offset }
+    }
+}
+
+impl<'a, B, Offsets> VersionedSyms<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    /// Create a `VersionedSyms` from the symbol table `syms` and
+    /// its version indexes `versyms`, with the version definitions
+    /// and requirements `verdefs` and `verneeds`.
+    ///
+    /// # Errors
+    ///
+    /// An error occurs if `versyms` does not have the same number of
+    /// entries as `syms`.
+    #[inline]
+    pub fn new(syms: Symtab<'a, B, Offsets>, versyms: Versyms<'a, B>,
+               verdefs: Option<Verdefs<'a, B>>,
+               verneeds: Option<Verneeds<'a, B>>) -> Result<Self, ()> {
+        if syms.num_syms() == versyms.num_versyms() {
+            Ok(VersionedSyms { syms: syms, versyms: versyms,
+                               verdefs: verdefs, verneeds: verneeds })
+        } else {
+            Err(())
+        }
+    }
+
+    /// Get the symbol at index `idx`, along with its version.
+    ///
+    /// Returns `None` if `idx` is out of bounds.
+    #[inline]
+    pub fn idx(&self, idx: usize) ->
+        Option<(Sym<'a, B, Offsets>, SymVersion<u32>)> {
+        match (self.syms.idx(idx), self.versyms.idx(idx)) {
+            (Some(sym), Some(versym)) => {
+                let version = resolve_version(versym, self.verdefs,
+                                              self.verneeds);
+
+                Some((sym, version))
+            },
+            _ => None
+        }
+    }
+
+    /// Get the number of symbols in this `VersionedSyms`.
+    #[inline]
+    pub fn num_syms(&self) -> usize {
+        self.syms.num_syms()
+    }
+
+    /// Get an iterator over this `VersionedSyms`.
+    #[inline]
+    pub fn iter(&self) -> VersionedSymsIter<'a, B, Offsets> {
+        VersionedSymsIter { versioned: *self, idx: 0 }
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for Versyms<'a, B>
+    where B: ByteOrder {
+    type Error = VersymsError;
+
+    /// Create a `Versyms` from the data buffer.  This will check that
+    /// the data buffer is a multiple of the version index size.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<Versyms<'a, B>, Self::Error> {
+        if data.len() % VERSYM_SIZE == 0 {
+            Ok(Versyms { byteorder: PhantomData, data: data })
+        } else {
+            Err(VersymsError::BadSize(data.len()))
+        }
+    }
+}
+
+impl<'a, B> TryFrom<&'a mut [u8]> for Versyms<'a, B>
+    where B: ByteOrder {
+    type Error = VersymsError;
+
+    /// Create a `Versyms` from the data buffer.  This will check that
+    /// the data buffer is a multiple of the version index size.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<Versyms<'a, B>, Self::Error> {
+        Versyms::try_from(&*data)
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for Verdefs<'a, B>
+    where B: ByteOrder {
+    type Error = VerdefsError;
+
+    /// Create a `Verdefs` from the data buffer.  This will check that
+    /// all the entries in the chains are in bounds.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<Verdefs<'a, B>, Self::Error> {
+        match check_verdefs::<B>(data) {
+            Ok(()) => Ok(Verdefs { byteorder: PhantomData, data: data }),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a, B> TryFrom<&'a mut [u8]> for Verdefs<'a, B>
+    where B: ByteOrder {
+    type Error = VerdefsError;
+
+    /// Create a `Verdefs` from the data buffer.  This will check that
+    /// all the entries in the chains are in bounds.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<Verdefs<'a, B>, Self::Error> {
+        Verdefs::try_from(&*data)
+    }
+}
+
+impl<'a, B> TryFrom<&'a [u8]> for Verneeds<'a, B>
+    where B: ByteOrder {
+    type Error = VerneedsError;
+
+    /// Create a `Verneeds` from the data buffer.  This will check
+    /// that all the entries in the chains are in bounds.
+    #[inline]
+    fn try_from(data: &'a [u8]) -> Result<Verneeds<'a, B>, Self::Error> {
+        match check_verneeds::<B>(data) {
+            Ok(()) => Ok(Verneeds { byteorder: PhantomData, data: data }),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a, B> TryFrom<&'a mut [u8]> for Verneeds<'a, B>
+    where B: ByteOrder {
+    type Error = VerneedsError;
+
+    /// Create a `Verneeds` from the data buffer.  This will check
+    /// that all the entries in the chains are in bounds.
+    #[inline]
+    fn try_from(data: &'a mut [u8]) -> Result<Verneeds<'a, B>, Self::Error> {
+        Verneeds::try_from(&*data)
+    }
+}
+
+impl From<u16> for VersymData {
+    #[inline]
+    fn from(value: u16) -> VersymData {
+        VersymData { idx: value & VERSYM_IDX_MASK,
+                     hidden: value & VERSYM_HIDDEN != 0 }
+    }
+}
+
+impl From<VersymData> for u16 {
+    #[inline]
+    fn from(versym: VersymData) -> u16 {
+        let VersymData { idx, hidden } = versym;
+
+        if hidden {
+            (idx & VERSYM_IDX_MASK) | VERSYM_HIDDEN
+        } else {
+            idx & VERSYM_IDX_MASK
+        }
+    }
+}
+
+impl<'a, B> Iterator for VersymsIter<'a, B>
+    where B: ByteOrder {
+    type Item = VersymData;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.len();
+
+        (size, Some(size))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let start = (self.idx + n) * VERSYM_SIZE;
+
+        if start < self.data.len() {
+            let value = B::read_u16(&self.data[start .. start + VERSYM_SIZE]);
+
+            self.idx += n + 1;
+
+            Some(VersymData::from(value))
+        } else {
+            self.idx = self.data.len() / VERSYM_SIZE;
+
+            None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for VersymsIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> ExactSizeIterator for VersymsIter<'a, B>
+    where B: ByteOrder {
+    #[inline]
+    fn len(&self) -> usize {
+        (self.data.len() / VERSYM_SIZE) - self.idx
+    }
+}
+
+impl<'a, B> Iterator for VerdefsIter<'a, B>
+    where B: ByteOrder {
+    type Item = VerdefData<VerdauxIter<'a, B>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.offset {
+            Some(offset) => {
+                let ent = &self.data[offset .. offset + VERDEF_SIZE];
+                let flags = B::read_u16(&ent[VERDEF_FLAGS_START ..
+                                             VERDEF_FLAGS_END]);
+                let idx = B::read_u16(&ent[VERDEF_IDX_START ..
+                                           VERDEF_IDX_END]);
+                let cnt = B::read_u16(&ent[VERDEF_CNT_START ..
+                                           VERDEF_CNT_END]);
+                let hash = B::read_u32(&ent[VERDEF_HASH_START ..
+                                            VERDEF_HASH_END]);
+                let aux = B::read_u32(&ent[VERDEF_AUX_START ..
+                                           VERDEF_AUX_END]);
+                let next = B::read_u32(&ent[VERDEF_NEXT_START ..
+                                            VERDEF_NEXT_END]);
+                let names = VerdauxIter { byteorder: PhantomData,
+                                          data: self.data,
+                                          offset: offset + aux as usize,
+                                          remaining: cnt as usize };
+
+                if next == 0 {
+                    self.offset = None
+                } else {
+                    self.offset = Some(offset + next as usize)
+                }
+
+                Some(VerdefData { flags: flags, idx: idx, hash: hash,
+                                  names: names })
+            },
+            None => None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for VerdefsIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> Iterator for VerdauxIter<'a, B>
+    where B: ByteOrder {
+    type Item = VerdauxData<u32>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            let ent = &self.data[self.offset .. self.offset + VERDAUX_SIZE];
+            let name = B::read_u32(&ent[VERDAUX_NAME_START ..
+                                        VERDAUX_NAME_END]);
+            let next = B::read_u32(&ent[VERDAUX_NEXT_START ..
+                                        VERDAUX_NEXT_END]);
+
+            self.offset += next as usize;
+            self.remaining -= 1;
+
+            Some(VerdauxData { name: name })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for VerdauxIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> ExactSizeIterator for VerdauxIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> Iterator for VerneedsIter<'a, B>
+    where B: ByteOrder {
+    type Item = VerneedData<u32, VernauxIter<'a, B>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.offset {
+            Some(offset) => {
+                let ent = &self.data[offset .. offset + VERNEED_SIZE];
+                let cnt = B::read_u16(&ent[VERNEED_CNT_START ..
+                                           VERNEED_CNT_END]);
+                let file = B::read_u32(&ent[VERNEED_FILE_START ..
+                                            VERNEED_FILE_END]);
+                let aux = B::read_u32(&ent[VERNEED_AUX_START ..
+                                           VERNEED_AUX_END]);
+                let next = B::read_u32(&ent[VERNEED_NEXT_START ..
+                                            VERNEED_NEXT_END]);
+                let versions = VernauxIter { byteorder: PhantomData,
+                                             data: self.data,
+                                             offset: offset + aux as usize,
+                                             remaining: cnt as usize };
+
+                if next == 0 {
+                    self.offset = None
+                } else {
+                    self.offset = Some(offset + next as usize)
+                }
+
+                Some(VerneedData { file: file, versions: versions })
+            },
+            None => None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for VerneedsIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> Iterator for VernauxIter<'a, B>
+    where B: ByteOrder {
+    type Item = VernauxData<u32>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            let ent = &self.data[self.offset .. self.offset + VERNAUX_SIZE];
+            let hash = B::read_u32(&ent[VERNAUX_HASH_START ..
+                                        VERNAUX_HASH_END]);
+            let flags = B::read_u16(&ent[VERNAUX_FLAGS_START ..
+                                         VERNAUX_FLAGS_END]);
+            let idx = B::read_u16(&ent[VERNAUX_IDX_START ..
+                                       VERNAUX_IDX_END]);
+            let name = B::read_u32(&ent[VERNAUX_NAME_START ..
+                                        VERNAUX_NAME_END]);
+            let next = B::read_u32(&ent[VERNAUX_NEXT_START ..
+                                        VERNAUX_NEXT_END]);
+
+            self.offset += next as usize;
+            self.remaining -= 1;
+
+            Some(VernauxData { hash: hash, flags: flags, idx: idx,
+                               name: name })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, B> FusedIterator for VernauxIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B> ExactSizeIterator for VernauxIter<'a, B>
+    where B: ByteOrder {}
+
+impl<'a, B, Offsets> Iterator for VersionedSymsIter<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    type Item = (Sym<'a, B, Offsets>, SymVersion<u32>);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.len();
+
+        (size, Some(size))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.versioned.idx(self.idx) {
+            Some(out) => {
+                self.idx += 1;
+
+                Some(out)
+            },
+            None => None
+        }
+    }
+}
+
+impl<'a, B, Offsets> FusedIterator for VersionedSymsIter<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {}
+
+impl<'a, B, Offsets> ExactSizeIterator for VersionedSymsIter<'a, B, Offsets>
+    where Offsets: SymOffsets,
+          B: ByteOrder {
+    #[inline]
+    fn len(&self) -> usize {
+        self.versioned.num_syms() - self.idx
+    }
+}
+
+impl<'a, Name> WithStrtab<'a> for VerdauxData<Name>
+    where Name: Copy + TryInto<usize> {
+    type Result = VerdauxData<Result<&'a str, &'a [u8]>>;
+    type Error = Name;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match strtab.idx(self.name) {
+            Ok(name) => Ok(VerdauxData { name: Ok(name) }),
+            Err(StrtabIdxError::UTF8Decode(data)) =>
+                Ok(VerdauxData { name: Err(data) }),
+            Err(_) => Err(self.name)
+        }
+    }
+}
+
+impl<'a, Name> WithStrtab<'a> for VernauxData<Name>
+    where Name: Copy + TryInto<usize> {
+    type Result = VernauxData<Result<&'a str, &'a [u8]>>;
+    type Error = Name;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        let VernauxData { hash, flags, idx, name } = self;
+
+        match strtab.idx(name) {
+            Ok(name) => Ok(VernauxData { hash: hash, flags: flags, idx: idx,
+                                         name: Ok(name) }),
+            Err(StrtabIdxError::UTF8Decode(data)) =>
+                Ok(VernauxData { hash: hash, flags: flags, idx: idx,
+                                 name: Err(data) }),
+            Err(_) => Err(name)
+        }
+    }
+}
+
+impl<'a, Name, Versions> WithStrtab<'a> for VerneedData<Name, Versions>
+    where Name: Copy + TryInto<usize> {
+    type Result = VerneedData<Result<&'a str, &'a [u8]>, Versions>;
+    type Error = Name;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        let VerneedData { file, versions } = self;
+
+        match strtab.idx(file) {
+            Ok(file) => Ok(VerneedData { file: Ok(file),
+                                         versions: versions }),
+            Err(StrtabIdxError::UTF8Decode(data)) =>
+                Ok(VerneedData { file: Err(data), versions: versions }),
+            Err(_) => Err(file)
+        }
+    }
+}
+
+impl<'a, Name> WithStrtab<'a> for SymVersion<Name>
+    where Name: Copy + TryInto<usize> {
+    type Result = SymVersion<Result<&'a str, &'a [u8]>>;
+    type Error = Name;
+
+    #[inline]
+    fn with_strtab(self, strtab: Strtab<'a>) ->
+        Result<Self::Result, Self::Error> {
+        match self {
+            SymVersion::Local => Ok(SymVersion::Local),
+            SymVersion::Global => Ok(SymVersion::Global),
+            SymVersion::Defined { name, hidden } => match strtab.idx(name) {
+                Ok(name) => Ok(SymVersion::Defined { name: Ok(name),
+                                                     hidden: hidden }),
+                Err(StrtabIdxError::UTF8Decode(data)) =>
+                    Ok(SymVersion::Defined { name: Err(data),
+                                             hidden: hidden }),
+                Err(_) => Err(name)
+            },
+            SymVersion::Needed { file, name, hidden } => {
+                let file = match strtab.idx(file) {
+                    Ok(file) => Ok(file),
+                    Err(StrtabIdxError::UTF8Decode(data)) => Err(data),
+                    Err(_) => return Err(file)
+                };
+
+                match strtab.idx(name) {
+                    Ok(name) => Ok(SymVersion::Needed { file: file,
+                                                        name: Ok(name),
+                                                        hidden: hidden }),
+                    Err(StrtabIdxError::UTF8Decode(data)) =>
+                        Ok(SymVersion::Needed { file: file, name: Err(data),
+                                                hidden: hidden }),
+                    Err(_) => Err(name)
+                }
+            },
+            SymVersion::Unknown { idx, hidden } =>
+                Ok(SymVersion::Unknown { idx: idx, hidden: hidden })
+        }
+    }
+}
+
+impl<'a> TryFrom<VerdauxData<Result<&'a str, &'a [u8]>>>
+    for VerdauxData<&'a str> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(data: VerdauxData<Result<&'a str, &'a [u8]>>) ->
+        Result<VerdauxData<&'a str>, Self::Error> {
+        match data.name {
+            Ok(name) => Ok(VerdauxData { name: name }),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a> TryFrom<VernauxData<Result<&'a str, &'a [u8]>>>
+    for VernauxData<&'a str> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(data: VernauxData<Result<&'a str, &'a [u8]>>) ->
+        Result<VernauxData<&'a str>, Self::Error> {
+        let VernauxData { hash, flags, idx, name } = data;
+
+        match name {
+            Ok(name) => Ok(VernauxData { hash: hash, flags: flags, idx: idx,
+                                         name: name }),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a, Versions> TryFrom<VerneedData<Result<&'a str, &'a [u8]>, Versions>>
+    for VerneedData<&'a str, Versions> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(data: VerneedData<Result<&'a str, &'a [u8]>, Versions>) ->
+        Result<VerneedData<&'a str, Versions>, Self::Error> {
+        let VerneedData { file, versions } = data;
+
+        match file {
+            Ok(file) => Ok(VerneedData { file: file, versions: versions }),
+            Err(err) => Err(err)
+        }
+    }
+}
+
+impl<'a> TryFrom<SymVersion<Result<&'a str, &'a [u8]>>>
+    for SymVersion<&'a str> {
+    type Error = &'a [u8];
+
+    #[inline]
+    fn try_from(data: SymVersion<Result<&'a str, &'a [u8]>>) ->
+        Result<SymVersion<&'a str>, Self::Error> {
+        match data {
+            SymVersion::Local => Ok(SymVersion::Local),
+            SymVersion::Global => Ok(SymVersion::Global),
+            SymVersion::Defined { name: Ok(name), hidden } =>
+                Ok(SymVersion::Defined { name: name, hidden: hidden }),
+            SymVersion::Needed { file: Ok(file), name: Ok(name), hidden } =>
+                Ok(SymVersion::Needed { file: file, name: name,
+                                        hidden: hidden }),
+            SymVersion::Defined { name: Err(err), .. } => Err(err),
+            SymVersion::Needed { file: Err(err), .. } => Err(err),
+            SymVersion::Needed { name: Err(err), .. } => Err(err),
+            SymVersion::Unknown { idx, hidden } =>
+                Ok(SymVersion::Unknown { idx: idx, hidden: hidden })
+        }
+    }
+}
+
+impl Display for VersymData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        if self.hidden {
+            write!(f, "{} (hidden)", self.idx)
+        } else {
+            write!(f, "{}", self.idx)
+        }
+    }
+}
+
+impl<Name> Display for VerdauxData<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{}", self.name)
+    }
+}
+
+impl<Name> Display for VernauxData<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(f, "{} (index {})", self.name, self.idx)
+    }
+}
+
+impl<Name> Display for SymVersion<Name>
+    where Name: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            SymVersion::Local => write!(f, "local"),
+            SymVersion::Global => write!(f, "global"),
+            SymVersion::Defined { name, hidden: false } =>
+                write!(f, "{}", name),
+            SymVersion::Defined { name, hidden: true } =>
+                write!(f, "{} (hidden)", name),
+            SymVersion::Needed { file, name, hidden: false } =>
+                write!(f, "{} from {}", name, file),
+            SymVersion::Needed { file, name, hidden: true } =>
+                write!(f, "{} from {} (hidden)", name, file),
+            SymVersion::Unknown { idx, hidden: false } =>
+                write!(f, "unknown version {}", idx),
+            SymVersion::Unknown { idx, hidden: true } =>
+                write!(f, "unknown version {} (hidden)", idx)
+        }
+    }
+}
+
+impl Display for VersymsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            VersymsError::BadSize(size) =>
+                write!(f, "bad symbol version table size {}", size)
+        }
+    }
+}
+
+impl Display for VerdefsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            VerdefsError::BadVersion(version) =>
+                write!(f, "bad version definition version {}", version),
+            VerdefsError::OutOfBounds(offset) =>
+                write!(f, "version definition entry at 0x{:x} out of bounds",
+                       offset)
+        }
+    }
+}
+
+impl Display for VerneedsError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
+        match self {
+            VerneedsError::BadVersion(version) =>
+                write!(f, "bad version requirement version {}", version),
+            VerneedsError::OutOfBounds(offset) =>
+                write!(f, "version requirement entry at 0x{:x} out of bounds",
+                       offset)
+        }
+    }
+}
@@ -7,9 +7,11 @@ use elf_utils::ElfKind;
 use elf_utils::ElfTable;
 use elf_utils::note::NoteData;
 use elf_utils::dynamic::DynamicEntData;
+use elf_utils::dynamic::DynamicFlags;
 use elf_utils::prog_hdr::ProgHdrData;
 use elf_utils::prog_hdr::Segment;
 use elf_utils::reloc::x86::X86Reloc;
+use elf_utils::section_hdr::SectionFlags;
 use elf_utils::section_hdr::SectionHdrData;
 use elf_utils::section_hdr::SectionPos;
 use elf_utils::section_hdr::SymsStrs;
@@ -17,6 +19,7 @@ use elf_utils::symtab::SymBase;
 use elf_utils::symtab::SymBind;
 use elf_utils::symtab::SymData;
 use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
 use std::marker::PhantomData;
 
 pub const PATH: &'static str = "tests/data/dynamic/i386/ld-elf32.so.1";
@@ -46,15 +49,15 @@ pub const PROG_HDR_CONTENTS: [ProgHdrData<Elf32, Segment<u32>,
                         content: Segment { offset: 0x19710, size: 0x64 } },
     ProgHdrData::Dynamic { virt_addr: 0x1b628, phys_addr: 0x1b628,
                            content: Segment { offset: 0x19628, size: 0x88 } },
-    ProgHdrData::Unknown { tag: 0x6474e552, flags: 4, offset: 0x193c8,
-                           file_size: 0x348, mem_size: 0x348,
-                           phys_addr: 0x1b3c8, virt_addr: 0x1b3c8, align: 1 },
-    ProgHdrData::Unknown { tag: 0x6474e550, flags: 4, offset: 0x428c,
-                           file_size: 0xbc, mem_size: 0xbc,
-                           phys_addr: 0x428c, virt_addr: 0x428c, align: 4 },
-    ProgHdrData::Unknown { tag: 0x6474e551, flags: 6, offset: 0,
-                           file_size: 0, mem_size: 0,
-                           phys_addr: 0, virt_addr: 0, align: 0 },
+    ProgHdrData::GnuRelro { virt_addr: 0x1b3c8, phys_addr: 0x1b3c8,
+                            mem_size: 0x348, align: 1,
+                            read: true, write: false, exec: false,
+                            content: Segment { offset: 0x193c8, size: 0x348 } },
+    ProgHdrData::GnuEhFrame { virt_addr: 0x428c, phys_addr: 0x428c,
+                              align: 4, read: true, write: false, exec: false,
+                              content: Segment { offset: 0x428c, size: 0xbc } },
+    ProgHdrData::GnuStack { size: 0, align: 0, read: true, write: true,
+                            exec: false },
     ProgHdrData::Note { virt_addr: 0x174, phys_addr: 0x174,
                         content: Segment { offset: 0x174, size: 0x18 } },
 ];
@@ -72,76 +75,73 @@ pub const SECTION_HDR_CONTENTS_BARE: [SectionHdrData<Elf32, u32, u32, u32, u32,
                                                      SectionPos<u32>>;
                                       NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
-                           note: SectionPos { offset: 0x174, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
-                             syms: SectionPos { offset: 0x18c, size: 0x1b0 },
-                             strtab: 7, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: 30, tag: 0x6fffffff, addr: 0x33c,
-                              align: 2, offset: 0x33c, size: 0x36, ent_size: 2,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: 43, tag: 0x6ffffffd, addr: 0x374,
-                              align: 4, offset: 0x374, size: 0xfc, ent_size: 0,
-                              link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: 58, tag: 0x6ffffff6, addr: 0x470,
-                              align: 4, offset: 0x470, size: 0xd0, ent_size: 0,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
-                           hash: SectionPos { offset: 0x540, size: 0xe0 },
-                           symtab: 2, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
-                             strs: SectionPos { offset: 0x620, size: 0x1d2 } },
-    SectionHdrData::Rel { name: 82, addr: 0x7f4, align: 4,
-                          rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
-                          symtab: 2, target: 0, alloc: true, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: 91, addr: 0xddc, align: 4,
-                               data: SectionPos { offset: 0xddc, size: 0x34b0 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 99, addr: 0x428c, align: 4,
-                               data: SectionPos { offset: 0x428c, size: 0xbc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 113, addr: 0x4348, align: 4,
-                               data: SectionPos { offset: 0x4348, size: 0x374 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 123, addr: 0x56c0, align: 16,
-                               data: SectionPos { offset: 0x46c0,
-                                                  size: 0x14d05 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: 129, tag: 15, addr: 0x1b3c8, align: 4,
-                              offset: 0x193c8, size: 4, ent_size: 0, link: 0,
-                              info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: 141, addr: 0x1b3cc, align: 4,
-                               data: SectionPos { offset: 0x193cc,
-                                                  size: 0x25c },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: 154, addr: 0x1b628, align: 4, strtab: 7,
-                              dynamic: SectionPos { offset: 0x19628,
-                                                    size: 0x88 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 163, addr: 0x1b6b0, align: 4,
-                               data: SectionPos { offset: 0x196b0, size: 0x60 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 168, addr: 0x1c710, align: 4,
-                               data: SectionPos { offset: 0x19710, size: 0x58 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 174, addr: 0x1c768, align: 4,
-                               data: SectionPos { offset: 0x19768, size: 0xc },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: 183, addr: 0x1c774, align: 4,
-                             offset: 0x19774, size: 0xb04, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: 188, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x19774,
-                                                  size: 0x11de },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 197, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1a952, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x1a96a, size: 0xd4 } }
+SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
+                       note: SectionPos { offset: 0x174, size: 0x18 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
+                         syms: SectionPos { offset: 0x18c, size: 0x1b0 },
+                         strtab: 7, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: 30, addr: 0x33c, align: 2,
+                         versyms: SectionPos { offset: 0x33c, size: 0x36 },
+                         symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: 43, addr: 0x374, align: 4,
+                         verdefs: SectionPos { offset: 0x374, size: 0xfc },
+                         strtab: 7, count: 9, flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: 58, addr: 0x470, align: 4,
+                          hash: SectionPos { offset: 0x470, size: 0xd0 },
+                          symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
+                       hash: SectionPos { offset: 0x540, size: 0xe0 },
+                       symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
+                         strs: SectionPos { offset: 0x620, size: 0x1d2 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: 82, addr: 0x7f4, align: 4,
+                      rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
+                      symtab: 2, target: 0,
+                      flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: 91, addr: 0xddc, align: 4,
+                           data: SectionPos { offset: 0xddc, size: 0x34b0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: 99, addr: 0x428c, align: 4,
+                           data: SectionPos { offset: 0x428c, size: 0xbc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 113, addr: 0x4348, align: 4,
+                           data: SectionPos { offset: 0x4348, size: 0x374 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 123, addr: 0x56c0, align: 16,
+                           data: SectionPos { offset: 0x46c0, size: 0x14d05 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: 129, addr: 0x1b3c8, align: 4,
+                            addrs: SectionPos { offset: 0x193c8, size: 4 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 141, addr: 0x1b3cc, align: 4,
+                           data: SectionPos { offset: 0x193cc, size: 0x25c },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: 154, addr: 0x1b628, align: 4, strtab: 7,
+                          dynamic: SectionPos { offset: 0x19628, size: 0x88 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 163, addr: 0x1b6b0, align: 4,
+                           data: SectionPos { offset: 0x196b0, size: 0x60 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 168, addr: 0x1c710, align: 4,
+                           data: SectionPos { offset: 0x19710, size: 0x58 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 174, addr: 0x1c768, align: 4,
+                           data: SectionPos { offset: 0x19768, size: 0xc },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: 183, addr: 0x1c774, align: 4, offset: 0x19774,
+                         size: 0xb04, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 188, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x19774, size: 0x11de },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: 197, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1a952, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x1a96a, size: 0xd4 },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf32, &'static str,
@@ -156,80 +156,77 @@ pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf32, &'static str,
                                                      SectionPos<u32>>;
                                       NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: ".note.tag", addr: 0x174, align: 4,
-                           note: SectionPos { offset: 0x174, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: ".dynsym", addr: 0x18c, align: 4,
-                             syms: SectionPos { offset: 0x18c, size: 0x1b0 },
-                             strtab: 7, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: ".gnu.version", tag: 0x6fffffff,
-                              addr: 0x33c, align: 2, offset: 0x33c, size: 0x36,
-                              ent_size: 2, link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.version_d", tag: 0x6ffffffd,
-                              addr: 0x374, align: 4, offset: 0x374, size: 0xfc,
-                              ent_size: 0, link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.hash", tag: 0x6ffffff6, addr: 0x470,
-                              align: 4, offset: 0x470, size: 0xd0, ent_size: 0,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: ".hash", addr: 0x540, align: 4,
-                           hash: SectionPos { offset: 0x540, size: 0xe0 },
-                           symtab: 2, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".dynstr", addr: 0x620, align: 1,
-                             strs: SectionPos { offset: 0x620, size: 0x1d2 } },
-    SectionHdrData::Rel { name: ".rel.dyn", addr: 0x7f4, align: 4,
-                          rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
-                          symtab: 2, target: 0, alloc: true, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: ".rodata", addr: 0xddc, align: 4,
-                               data: SectionPos { offset: 0xddc, size: 0x34b0 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x428c, align: 4,
-                               data: SectionPos { offset: 0x428c, size: 0xbc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4348, align: 4,
-                               data: SectionPos { offset: 0x4348, size: 0x374 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".text", addr: 0x56c0, align: 16,
-                               data: SectionPos { offset: 0x46c0,
-                                                  size: 0x14d05 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: ".fini_array", tag: 15, addr: 0x1b3c8,
-                              align: 4, offset: 0x193c8, size: 4, ent_size: 0,
-                              link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1b3cc, align: 4,
-                               data: SectionPos { offset: 0x193cc,
-                                                  size: 0x25c },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1b628, align: 4,
-                              strtab: 7,dynamic: SectionPos { offset: 0x19628,
-                                                              size: 0x88 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".got", addr: 0x1b6b0, align: 4,
-                               data: SectionPos { offset: 0x196b0, size: 0x60 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".data", addr: 0x1c710, align: 4,
-                               data: SectionPos { offset: 0x19710, size: 0x58 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".got.plt", addr: 0x1c768, align: 4,
-                               data: SectionPos { offset: 0x19768, size: 0xc },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: ".bss", addr: 0x1c774, align: 4,
-                             offset: 0x19774, size: 0xb04, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x19774,
-                                                  size: 0x11de },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1a952, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x1a96a, size: 0xd4 } }
+SectionHdrData::Note { name: ".note.tag", addr: 0x174, align: 4,
+                       note: SectionPos { offset: 0x174, size: 0x18 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: ".dynsym", addr: 0x18c, align: 4,
+                         syms: SectionPos { offset: 0x18c, size: 0x1b0 },
+                         strtab: 7, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: ".gnu.version", addr: 0x33c, align: 2,
+                         versyms: SectionPos { offset: 0x33c, size: 0x36 },
+                         symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: ".gnu.version_d", addr: 0x374, align: 4,
+                         verdefs: SectionPos { offset: 0x374, size: 0xfc },
+                         strtab: 7, count: 9, flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: ".gnu.hash", addr: 0x470, align: 4,
+                          hash: SectionPos { offset: 0x470, size: 0xd0 },
+                          symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: ".hash", addr: 0x540, align: 4,
+                       hash: SectionPos { offset: 0x540, size: 0xe0 },
+                       symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: ".dynstr", addr: 0x620, align: 1,
+                         strs: SectionPos { offset: 0x620, size: 0x1d2 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: ".rel.dyn", addr: 0x7f4, align: 4,
+                      rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
+                      symtab: 2, target: 0,
+                      flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: ".rodata", addr: 0xddc, align: 4,
+                           data: SectionPos { offset: 0xddc, size: 0x34b0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x428c, align: 4,
+                           data: SectionPos { offset: 0x428c, size: 0xbc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4348, align: 4,
+                           data: SectionPos { offset: 0x4348, size: 0x374 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".text", addr: 0x56c0, align: 16,
+                           data: SectionPos { offset: 0x46c0, size: 0x14d05 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: ".fini_array", addr: 0x1b3c8, align: 4,
+                            addrs: SectionPos { offset: 0x193c8, size: 4 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1b3cc, align: 4,
+                           data: SectionPos { offset: 0x193cc, size: 0x25c },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1b628, align: 4, strtab: 7,
+                          dynamic: SectionPos { offset: 0x19628, size: 0x88 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".got", addr: 0x1b6b0, align: 4,
+                           data: SectionPos { offset: 0x196b0, size: 0x60 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0x1c710, align: 4,
+                           data: SectionPos { offset: 0x19710, size: 0x58 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".got.plt", addr: 0x1c768, align: 4,
+                           data: SectionPos { offset: 0x19768, size: 0xc },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0x1c774, align: 4, offset: 0x19774,
+                         size: 0xb04, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x19774, size: 0x11de },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1a952, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x1a96a, size: 0xd4 },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 pub const HEADER_DATA: ElfHdrData<LittleEndian, Elf32, ElfTable<Elf32>,
-                                  ElfTable<Elf32>, u16> =
+                                  ElfTable<Elf32>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Dynamic, arch: ElfArch::I386,
@@ -256,84 +253,111 @@ const DYNSYM_NUM_SYMS: usize = 27;
 pub const DYNSYM_CONTENTS: [SymData<&'static str, u16, Elf32>;
                             DYNSYM_NUM_SYMS] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some("_rtld_allocate_tls"), value: 0xbdf0, size: 138,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_get_stack_prot"), value: 0xbf50, size: 23,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dllockinit"), value: 0x9ed0, size: 62,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlsym"), value: 0xa110, size: 38,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlvsym"), value: 0xa9d0, size: 121,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_addr_phdr"), value: 0xaa50, size: 213,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_version__FreeBSD_version"), value: 0x1c738,
               size: 4, kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(17) },
-    SymData { name: Some("_rtld_version_laddr_offset"), value: 0x1cba4,
-              size: 1, kind: SymKind::Object, bind: SymBind::Global,
+    SymData { name: Some("_rtld_version_laddr_offset"), value: 0x1cba4, size: 1,
+              kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(19) },
     SymData { name: Some("dl_iterate_phdr"), value: 0xb010, size: 743,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlerror"), value: 0x9650, size: 33,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlopen"), value: 0x9f10, size: 38,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("r_debug_state"), value: 0x8a50, size: 5,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dladdr"), value: 0xab30, size: 299,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_thread_init"), value: 0x10960, size: 497,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("__tls_get_addr"), value: 0x6130, size: 46,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_r_debug_postinit"), value: 0x9130, size: 5,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_is_dlopened"), value: 0xbf70, size: 217,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlclose"), value: 0x9c20, size: 77,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("fdlopen"), value: 0xa0e0, size: 35,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("___tls_get_addr"), value: 0x6100, size: 43,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_atfork_pre"), value: 0x10b60, size: 153,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_error"), value: 0x8330, size: 224,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_free_tls"), value: 0xbe80, size: 197,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlfunc"), value: 0xa9a0, size: 38,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlinfo"), value: 0xac60, size: 938,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_atfork_post"), value: 0x10c00, size: 170,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
 ];
 
@@ -606,22 +630,22 @@ const NUM_DYNAMIC_ENTS: usize = 17;
 
 const DYNAMIC_ENTS: [DynamicEntData<&'static str, u32, Elf32>;
                      NUM_DYNAMIC_ENTS] = [
-    DynamicEntData::Flags { flags: 0x2 },
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
     DynamicEntData::Rel { tab: 0x7f4 },
     DynamicEntData::RelSize { size: 1512 },
     DynamicEntData::RelEntSize { size: 8 },
-    DynamicEntData::Unknown { tag: 0x6ffffffa, info: 189 },
+    DynamicEntData::RelCount { count: 189 },
     DynamicEntData::Symtab { tab: 0x18c },
     DynamicEntData::SymtabEntSize { size: 16 },
     DynamicEntData::Strtab { tab: 0x620 },
     DynamicEntData::StrtabSize { size: 466 },
-    DynamicEntData::Unknown { tag: 0x6ffffef5, info: 0x470 },
+    DynamicEntData::GnuHash { tab: 0x470 },
     DynamicEntData::Hash { tab: 0x540 },
     DynamicEntData::FiniArray { arr: 0x1b3c8 },
     DynamicEntData::FiniArraySize { size: 4 },
-    DynamicEntData::Unknown { tag: 0x6ffffff0, info: 0x33c },
-    DynamicEntData::Unknown { tag: 0x6ffffffc, info: 0x374 },
-    DynamicEntData::Unknown { tag: 0x6ffffffd, info: 0x9 },
+    DynamicEntData::Versym { tab: 0x33c },
+    DynamicEntData::Verdef { tab: 0x374 },
+    DynamicEntData::VerdefNum { num: 0x9 },
     DynamicEntData::None
 ];
 
@@ -645,86 +669,77 @@ pub const EXPECTED: [SectionHdrData<Elf32, &'static str,
                                     &'static [NoteData<'static>]>;
                      NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: ".note.tag", addr: 0x174, align: 4,
-                           note: &NOTES_CONTENTS, alloc: true,
-                           write: false, exec: false },
-    SectionHdrData::Dynsym { name: ".dynsym", addr: 0x18c, align: 4,
-                             syms: &DYNSYM_CONTENTS,
-                             strtab: &STRTAB_CONTENTS, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: ".gnu.version", tag: 0x6fffffff,
-                              addr: 0x33c, align: 2, offset: 0x33c,
-                              size: 0x36, ent_size: 2, link: 2, info: 0,
-                              flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.version_d", tag: 0x6ffffffd,
-                              addr: 0x374, align: 4, offset: 0x374,
-                              size: 0xfc, ent_size: 0, link: 7, info: 9,
-                              flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.hash", tag: 0x6ffffff6,
-                              addr: 0x470, align: 4, offset: 0x470,
-                              size: 0xd0, ent_size: 0, link: 2,
-                              info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: ".hash", addr: 0x540, align: 4,
-                           hash: SectionPos { offset: 0x540, size: 0xe0 },
-                           symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
-                                              strs: &STRTAB_CONTENTS },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".dynstr", addr: 0x620, align: 1,
-                             strs: &STRTAB_CONTENTS },
-    SectionHdrData::Rel { name: ".rel.dyn", addr: 0x7f4, align: 4,
-                          rels: &REL_DYN_RELS_CONTENTS_X86,
+SectionHdrData::Note { name: ".note.tag", addr: 0x174, align: 4,
+                       note: &NOTES_CONTENTS, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: ".dynsym", addr: 0x18c, align: 4,
+                         syms: &DYNSYM_CONTENTS, strtab: &STRTAB_CONTENTS,
+                         local_end: 1, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: ".gnu.version", addr: 0x33c, align: 2,
+                         versyms: SectionPos { offset: 0x33c, size: 0x36 },
+                         symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                            strs: &STRTAB_CONTENTS },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: ".gnu.version_d", addr: 0x374, align: 4,
+                         verdefs: SectionPos { offset: 0x374, size: 0xfc },
+                         strtab: &STRTAB_CONTENTS, count: 9,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: ".gnu.hash", addr: 0x470, align: 4,
+                          hash: SectionPos { offset: 0x470, size: 0xd0 },
                           symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
                                              strs: &STRTAB_CONTENTS },
-                          target: 0, alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".rodata", addr: 0xddc, align: 4,
-                               data: SectionPos { offset: 0xddc,
-                                                  size: 0x34b0 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x428c,
-                               align: 4, data: SectionPos { offset: 0x428c,
-                                                            size: 0xbc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4348, align: 4,
-                               data: SectionPos { offset: 0x4348,
-                                                  size: 0x374 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".text", addr: 0x56c0, align: 16,
-                               data: SectionPos { offset: 0x46c0,
-                                                  size: 0x14d05 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: ".fini_array", tag: 15, addr: 0x1b3c8,
-                              align: 4, offset: 0x193c8, size: 4,
-                              ent_size: 0, link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1b3cc,
-                               align: 4, data: SectionPos { offset: 0x193cc,
-                                                            size: 0x25c },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1b628, align: 4,
-                              strtab: &STRTAB_CONTENTS, dynamic: &DYNAMIC_ENTS,
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".got", addr: 0x1b6b0, align: 4,
-                               data: SectionPos { offset: 0x196b0,
-                                                  size: 0x60 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".data", addr: 0x1c710, align: 4,
-                               data: SectionPos { offset: 0x19710,
-                                                  size: 0x58 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".got.plt", addr: 0x1c768, align: 4,
-                               data: SectionPos { offset: 0x19768,
-                                                  size: 0xc },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: ".bss", addr: 0x1c774, align: 4,
-                             offset: 0x19774, size: 0xb04, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x19774,
-                                                  size: 0x11de },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1a952,
-                                                  size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
-                             strs: &SHSTRTAB_CONTENTS }
+                          flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: ".hash", addr: 0x540, align: 4,
+                       hash: SectionPos { offset: 0x540, size: 0xe0 },
+                       symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                          strs: &STRTAB_CONTENTS },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: ".dynstr", addr: 0x620, align: 1,
+                         strs: &STRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: ".rel.dyn", addr: 0x7f4, align: 4,
+                      rels: &REL_DYN_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 0, flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: ".rodata", addr: 0xddc, align: 4,
+                           data: SectionPos { offset: 0xddc, size: 0x34b0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x428c, align: 4,
+                           data: SectionPos { offset: 0x428c, size: 0xbc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4348, align: 4,
+                           data: SectionPos { offset: 0x4348, size: 0x374 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".text", addr: 0x56c0, align: 16,
+                           data: SectionPos { offset: 0x46c0, size: 0x14d05 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: ".fini_array", addr: 0x1b3c8, align: 4,
+                            addrs: SectionPos { offset: 0x193c8, size: 4 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1b3cc, align: 4,
+                           data: SectionPos { offset: 0x193cc, size: 0x25c },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1b628, align: 4,
+                          strtab: &STRTAB_CONTENTS, dynamic: &DYNAMIC_ENTS,
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".got", addr: 0x1b6b0, align: 4,
+                           data: SectionPos { offset: 0x196b0, size: 0x60 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0x1c710, align: 4,
+                           data: SectionPos { offset: 0x19710, size: 0x58 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".got.plt", addr: 0x1c768, align: 4,
+                           data: SectionPos { offset: 0x19768, size: 0xc },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0x1c774, align: 4, offset: 0x19774,
+                         size: 0xb04, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x19774, size: 0x11de },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1a952, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
+                         strs: &SHSTRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 0 } }
 ];
@@ -7,9 +7,11 @@ use elf_utils::ElfKind;
 use elf_utils::ElfTable;
 use elf_utils::note::NoteData;
 use elf_utils::dynamic::DynamicEntData;
+use elf_utils::dynamic::DynamicFlags;
 use elf_utils::prog_hdr::ProgHdrData;
 use elf_utils::prog_hdr::Segment;
 use elf_utils::reloc::x86_64::X86_64Reloc;
+use elf_utils::section_hdr::SectionFlags;
 use elf_utils::section_hdr::SectionHdrData;
 use elf_utils::section_hdr::SectionPos;
 use elf_utils::section_hdr::SymsStrs;
@@ -17,6 +19,7 @@ use elf_utils::symtab::SymBase;
 use elf_utils::symtab::SymBind;
 use elf_utils::symtab::SymData;
 use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
 use std::marker::PhantomData;
 
 pub const PATH: &'static str = "tests/data/dynamic/x86_64/ld-elf.so.1";
@@ -46,15 +49,16 @@ pub const PROG_HDR_CONTENTS: [ProgHdrData<Elf64, Segment<u64>,
                         content: Segment { offset: 0x1b6d0, size: 0xb0 } },
     ProgHdrData::Dynamic { virt_addr: 0x1d508, phys_addr: 0x1d508,
                            content: Segment { offset: 0x1b508, size: 0x110 } },
-    ProgHdrData::Unknown { tag: 0x6474e552, flags: 4, offset: 0x1b048,
-                           file_size: 0x688, mem_size: 0x688,
-                           phys_addr: 0x1d048, virt_addr: 0x1d048, align: 1 },
-    ProgHdrData::Unknown { tag: 0x6474e550, flags: 4, offset: 0x49dc,
-                           file_size: 0x224, mem_size: 0x224,
-                           phys_addr: 0x49dc, virt_addr: 0x49dc, align: 4 },
-    ProgHdrData::Unknown { tag: 0x6474e551, flags: 6, offset: 0,
-                           file_size: 0, mem_size: 0,
-                           phys_addr: 0, virt_addr: 0, align: 0 },
+    ProgHdrData::GnuRelro { virt_addr: 0x1d048, phys_addr: 0x1d048,
+                            mem_size: 0x688, align: 1,
+                            read: true, write: false, exec: false,
+                            content: Segment { offset: 0x1b048, size: 0x688 } },
+    ProgHdrData::GnuEhFrame { virt_addr: 0x49dc, phys_addr: 0x49dc,
+                              align: 4, read: true, write: false, exec: false,
+                              content: Segment { offset: 0x49dc,
+                                                 size: 0x224 } },
+    ProgHdrData::GnuStack { size: 0, align: 0, read: true, write: true,
+                            exec: false },
     ProgHdrData::Note { virt_addr: 0x270, phys_addr: 0x270,
                         content: Segment { offset: 0x270, size: 0x18 } },
 ];
@@ -72,74 +76,70 @@ pub const SECTION_HDR_CONTENTS_BARE: [SectionHdrData<Elf64, u32, u32, u32, u32,
                                                      SectionPos<u64>>;
                                       NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: 12, addr: 0x270, align: 4,
-                           note: SectionPos { offset: 0x270, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: 22, addr: 0x288, align: 8,
-                             syms: SectionPos { offset: 0x288, size: 0x270 },
-                             strtab: 7, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: 30, tag: 0x6fffffff,
-                              addr: 0x4f8, align: 2, offset: 0x4f8, size: 0x34,
-                              ent_size: 2, link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: 43, tag: 0x6ffffffd,
-                              addr: 0x52c, align: 4, offset: 0x52c, size: 0xfc,
-                              ent_size: 0, link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: 58, tag: 0x6ffffff6, addr: 0x628,
-                              align: 8, offset: 0x628, size: 0xcc, ent_size: 0,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: 68, addr: 0x6f4, align: 4,
-                           hash: SectionPos { offset: 0x6f4, size: 0xd8 },
-                           symtab: 2, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: 74, addr: 0x7cc, align: 1,
-                             strs: SectionPos { offset: 0x7cc, size: 0x1c0 } },
-    SectionHdrData::Rela { name: 82, addr: 0x990, align: 8,
-                           relas: SectionPos { offset: 0x990, size: 0x11a0 },
-                           symtab: 2, target: 0, alloc: true, write: false,
-                           exec: false },
-    SectionHdrData::ProgBits { name: 92, addr: 0x1b30, align: 16,
-                               data: SectionPos { offset: 0x1b30,
-                                                  size: 0x2eac },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 100, addr: 0x49dc, align: 4,
-                               data: SectionPos { offset: 0x49dc, size: 0x224 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 114, addr: 0x4c00, align: 8,
-                               data: SectionPos { offset: 0x4c00, size: 0x824 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 124, addr: 0x6430, align: 16,
-                               data: SectionPos { offset: 0x5430,
-                                                  size: 0x15c14 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: 130, tag: 15, addr: 0x1d048,
-                              align: 8, offset: 0x1b048, size: 8, ent_size: 0,
-                              link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: 142, addr: 0x1d050, align: 16,
-                               data: SectionPos { offset: 0x1b050,
-                                                  size: 0x4b8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: 155, addr: 0x1d508, align: 8,
-                              strtab: 7, dynamic: SectionPos { offset: 0x1b508,
-                                                               size: 0x110 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 164, addr: 0x1d618, align: 8,
-                               data: SectionPos { offset: 0x1b618, size: 0xb8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 169, addr: 0x1e6d0, align: 8,
-                               data: SectionPos { offset: 0x1b6d0, size: 0xb0 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: 175, addr: 0x1e780, align: 16,
-                             offset: 0x1b780, size: 0xe00, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: 180, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1b780,
-                                                  size: 0x1091 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 189, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1c811, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x1c829, size: 0xcc } }
+SectionHdrData::Note { name: 12, addr: 0x270, align: 4,
+                       note: SectionPos { offset: 0x270, size: 0x18 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: 22, addr: 0x288, align: 8,
+                         syms: SectionPos { offset: 0x288, size: 0x270 },
+                         strtab: 7, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: 30, addr: 0x4f8, align: 2,
+                         versyms: SectionPos { offset: 0x4f8, size: 0x34 },
+                         symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: 43, addr: 0x52c, align: 4,
+                         verdefs: SectionPos { offset: 0x52c, size: 0xfc },
+                         strtab: 7, count: 9, flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: 58, addr: 0x628, align: 8,
+                          hash: SectionPos { offset: 0x628, size: 0xcc },
+                          symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: 68, addr: 0x6f4, align: 4,
+                       hash: SectionPos { offset: 0x6f4, size: 0xd8 },
+                       symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: 74, addr: 0x7cc, align: 1,
+                         strs: SectionPos { offset: 0x7cc, size: 0x1c0 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: 82, addr: 0x990, align: 8,
+                       relas: SectionPos { offset: 0x990, size: 0x11a0 },
+                       symtab: 2, target: 0,
+                       flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: 92, addr: 0x1b30, align: 16,
+                           data: SectionPos { offset: 0x1b30, size: 0x2eac },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: 100, addr: 0x49dc, align: 4,
+                           data: SectionPos { offset: 0x49dc, size: 0x224 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 114, addr: 0x4c00, align: 8,
+                           data: SectionPos { offset: 0x4c00, size: 0x824 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 124, addr: 0x6430, align: 16,
+                           data: SectionPos { offset: 0x5430, size: 0x15c14 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: 130, addr: 0x1d048, align: 8,
+                            addrs: SectionPos { offset: 0x1b048, size: 8 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 142, addr: 0x1d050, align: 16,
+                           data: SectionPos { offset: 0x1b050, size: 0x4b8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: 155, addr: 0x1d508, align: 8, strtab: 7,
+                          dynamic: SectionPos { offset: 0x1b508, size: 0x110 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 164, addr: 0x1d618, align: 8,
+                           data: SectionPos { offset: 0x1b618, size: 0xb8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 169, addr: 0x1e6d0, align: 8,
+                           data: SectionPos { offset: 0x1b6d0, size: 0xb0 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: 175, addr: 0x1e780, align: 16, offset: 0x1b780,
+                         size: 0xe00, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 180, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1b780, size: 0x1091 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: 189, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1c811, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x1c829, size: 0xcc },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf64, &'static str,
@@ -154,78 +154,75 @@ pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf64, &'static str,
                                                      SectionPos<u64>>;
                                       NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: ".note.tag", addr: 0x270, align: 4,
-                           note: SectionPos { offset: 0x270, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: ".dynsym", addr: 0x288, align: 8,
-                             syms: SectionPos { offset: 0x288, size: 0x270 },
-                             strtab: 7, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: ".gnu.version", tag: 0x6fffffff,
-                              addr: 0x4f8, align: 2, offset: 0x4f8, size: 0x34,
-                              ent_size: 2, link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.version_d", tag: 0x6ffffffd,
-                              addr: 0x52c, align: 4, offset: 0x52c, size: 0xfc,
-                              ent_size: 0, link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.hash", tag: 0x6ffffff6, addr: 0x628,
-                              align: 8, offset: 0x628, size: 0xcc, ent_size: 0,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: ".hash", addr: 0x6f4, align: 4,
-                           hash: SectionPos { offset: 0x6f4, size: 0xd8 },
-                           symtab: 2, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".dynstr", addr: 0x7cc, align: 1,
-                             strs: SectionPos { offset: 0x7cc, size: 0x1c0 } },
-    SectionHdrData::Rela { name: ".rela.dyn", addr: 0x990, align: 8,
-                           relas: SectionPos { offset: 0x990, size: 0x11a0 },
-                           symtab: 2, target: 0, alloc: true, write: false,
-                           exec: false },
-    SectionHdrData::ProgBits { name: ".rodata", addr: 0x1b30, align: 16,
-                               data: SectionPos { offset: 0x1b30,
-                                                  size: 0x2eac },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x49dc, align: 4,
-                               data: SectionPos { offset: 0x49dc, size: 0x224 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4c00, align: 8,
-                               data: SectionPos { offset: 0x4c00, size: 0x824 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".text", addr: 0x6430, align: 16,
-                               data: SectionPos { offset: 0x5430,
-                                                  size: 0x15c14 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: ".fini_array", tag: 15, addr: 0x1d048,
-                              align: 8, offset: 0x1b048, size: 8, ent_size: 0,
-                              link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1d050, align: 16,
-                               data: SectionPos { offset: 0x1b050,
-                                                  size: 0x4b8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1d508, align: 8,
-                              strtab: 7, dynamic: SectionPos { offset: 0x1b508,
-                                                               size: 0x110 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".got", addr: 0x1d618, align: 8,
-                               data: SectionPos { offset: 0x1b618, size: 0xb8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".data", addr: 0x1e6d0, align: 8,
-                               data: SectionPos { offset: 0x1b6d0, size: 0xb0 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: ".bss", addr: 0x1e780, align: 16,
-                             offset: 0x1b780, size: 0xe00, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1b780,
-                                                  size: 0x1091 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1c811, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x1c829, size: 0xcc } }
+SectionHdrData::Note { name: ".note.tag", addr: 0x270, align: 4,
+                       note: SectionPos { offset: 0x270, size: 0x18 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: ".dynsym", addr: 0x288, align: 8,
+                         syms: SectionPos { offset: 0x288, size: 0x270 },
+                         strtab: 7, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: ".gnu.version", addr: 0x4f8, align: 2,
+                         versyms: SectionPos { offset: 0x4f8, size: 0x34 },
+                         symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: ".gnu.version_d", addr: 0x52c, align: 4,
+                         verdefs: SectionPos { offset: 0x52c, size: 0xfc },
+                         strtab: 7, count: 9, flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: ".gnu.hash", addr: 0x628, align: 8,
+                          hash: SectionPos { offset: 0x628, size: 0xcc },
+                          symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: ".hash", addr: 0x6f4, align: 4,
+                       hash: SectionPos { offset: 0x6f4, size: 0xd8 },
+                       symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: ".dynstr", addr: 0x7cc, align: 1,
+                         strs: SectionPos { offset: 0x7cc, size: 0x1c0 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: ".rela.dyn", addr: 0x990, align: 8,
+                       relas: SectionPos { offset: 0x990, size: 0x11a0 },
+                       symtab: 2, target: 0,
+                       flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: ".rodata", addr: 0x1b30, align: 16,
+                           data: SectionPos { offset: 0x1b30, size: 0x2eac },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x49dc, align: 4,
+                           data: SectionPos { offset: 0x49dc, size: 0x224 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4c00, align: 8,
+                           data: SectionPos { offset: 0x4c00, size: 0x824 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".text", addr: 0x6430, align: 16,
+                           data: SectionPos { offset: 0x5430, size: 0x15c14 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: ".fini_array", addr: 0x1d048, align: 8,
+                            addrs: SectionPos { offset: 0x1b048, size: 8 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1d050, align: 16,
+                           data: SectionPos { offset: 0x1b050, size: 0x4b8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1d508, align: 8, strtab: 7,
+                          dynamic: SectionPos { offset: 0x1b508, size: 0x110 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".got", addr: 0x1d618, align: 8,
+                           data: SectionPos { offset: 0x1b618, size: 0xb8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0x1e6d0, align: 8,
+                           data: SectionPos { offset: 0x1b6d0, size: 0xb0 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0x1e780, align: 16,
+                         offset: 0x1b780, size: 0xe00,
+                         flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1b780, size: 0x1091 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1c811, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x1c829, size: 0xcc },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 pub const HEADER_DATA: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                                  ElfTable<Elf64>, u16> =
+                                  ElfTable<Elf64>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Dynamic, arch: ElfArch::X86_64,
@@ -251,81 +248,107 @@ const DYNSYM_NUM_SYMS: usize = 26;
 pub const DYNSYM_CONTENTS: [SymData<&'static str, u16, Elf64>;
                             DYNSYM_NUM_SYMS] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some("_rtld_allocate_tls"), value: 0xd110, size: 140,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_get_stack_prot"), value: 0xd290, size: 12,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dllockinit"), value: 0xb0d0, size: 54,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlsym"), value: 0xb2d0, size: 13,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlvsym"), value: 0xbaf0, size: 110,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_addr_phdr"), value: 0xbb60, size: 231,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_version__FreeBSD_version"), value: 0x1e720,
               size: 4, kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(17) },
-    SymData { name: Some("_rtld_version_laddr_offset"), value: 0x1ed88,
-              size: 1, kind: SymKind::Object, bind: SymBind::Global,
+    SymData { name: Some("_rtld_version_laddr_offset"), value: 0x1ed88, size: 1,
+              kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(18) },
     SymData { name: Some("dl_iterate_phdr"), value: 0xc1c0, size: 838,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlerror"), value: 0xa810, size: 24,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlopen"), value: 0xb110, size: 14,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("r_debug_state"), value: 0x9b70, size: 6,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dladdr"), value: 0xbc50, size: 297,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_thread_init"), value: 0x11dc0, size: 496,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("__tls_get_addr"), value: 0x6ee0, size: 32,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_r_debug_postinit"), value: 0xa260, size: 6,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_is_dlopened"), value: 0xd2a0, size: 222,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlclose"), value: 0xae20, size: 75,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("fdlopen"), value: 0xb2c0, size: 16,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_atfork_pre"), value: 0x11fb0, size: 151,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_error"), value: 0x9410, size: 269,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_free_tls"), value: 0xd1a0, size: 232,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlfunc"), value: 0xbae0, size: 16,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("dlinfo"), value: 0xbd80, size: 1073,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
     SymData { name: Some("_rtld_atfork_post"), value: 0x12050, size: 167,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(12) },
 ];
 
@@ -596,22 +619,22 @@ const NUM_DYNAMIC_ENTS: usize = 17;
 
 const DYNAMIC_ENTS: [DynamicEntData<&'static str, u64, Elf64>;
                      NUM_DYNAMIC_ENTS] = [
-    DynamicEntData::Flags { flags: 0x2 },
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
     DynamicEntData::Rela { tab: 0x990 },
     DynamicEntData::RelaSize { size: 4512 },
     DynamicEntData::RelaEntSize { size: 24 },
-    DynamicEntData::Unknown { tag: 0x6ffffff9, info: 188 },
+    DynamicEntData::RelaCount { count: 188 },
     DynamicEntData::Symtab { tab: 0x288 },
     DynamicEntData::SymtabEntSize { size: 24 },
     DynamicEntData::Strtab { tab: 0x7cc },
     DynamicEntData::StrtabSize { size: 448 },
-    DynamicEntData::Unknown { tag: 0x6ffffef5, info: 0x628 },
+    DynamicEntData::GnuHash { tab: 0x628 },
     DynamicEntData::Hash { tab: 0x6f4 },
     DynamicEntData::FiniArray { arr: 0x1d048 },
     DynamicEntData::FiniArraySize { size: 8 },
-    DynamicEntData::Unknown { tag: 0x6ffffff0, info: 0x4f8 },
-    DynamicEntData::Unknown { tag: 0x6ffffffc, info: 0x52c },
-    DynamicEntData::Unknown { tag: 0x6ffffffd, info: 0x9 },
+    DynamicEntData::Versym { tab: 0x4f8 },
+    DynamicEntData::Verdef { tab: 0x52c },
+    DynamicEntData::VerdefNum { num: 0x9 },
     DynamicEntData::None
 ];
 
@@ -635,74 +658,75 @@ pub const EXPECTED: [SectionHdrData<Elf64, &'static str,
                                     &'static [NoteData<'static>]>;
                      NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: ".note.tag", addr: 0x270, align: 4,
-                           note: &NOTES_CONTENTS, alloc: true,
-                           write: false, exec: false },
-    SectionHdrData::Dynsym { name: ".dynsym", addr: 0x288, align: 8,
-                             syms: &DYNSYM_CONTENTS,
-                             strtab: &STRTAB_CONTENTS, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: ".gnu.version", tag: 0x6fffffff,
-                              addr: 0x4f8, align: 2, offset: 0x4f8, size: 0x34,
-                              ent_size: 2, link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.version_d", tag: 0x6ffffffd,
-                              addr: 0x52c, align: 4, offset: 0x52c, size: 0xfc,
-                              ent_size: 0, link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: ".gnu.hash", tag: 0x6ffffff6, addr: 0x628,
-                              align: 8, offset: 0x628, size: 0xcc, ent_size: 0,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: ".hash", addr: 0x6f4, align: 8,
-                           hash: SectionPos { offset: 0x6f4, size: 0xd8 },
-                           symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
-                                              strs: &STRTAB_CONTENTS },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".dynstr", addr: 0x7cc, align: 1,
-                             strs: &STRTAB_CONTENTS },
-    SectionHdrData::Rela { name: ".rela.dyn", addr: 0x990, align: 8,
-                           relas: &REL_DYN_RELS_CONTENTS_X86,
-                           symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
-                                              strs: &STRTAB_CONTENTS },
-                           target: 0, alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".rodata", addr: 0x1b30, align: 16,
-                               data: SectionPos { offset: 0x1b30,
-                                                  size: 0x2eac },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x49dc, align: 4,
-                               data: SectionPos { offset: 0x49dc, size: 0x224 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4c00, align: 8,
-                               data: SectionPos { offset: 0x4c00, size: 0x824 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".text", addr: 0x6430, align: 16,
-                               data: SectionPos { offset: 0x5430,
-                                                  size: 0x15c14 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: ".fini_array", tag: 15, addr: 0x1d048,
-                              align: 8, offset: 0x1b048, size: 4, ent_size: 0,
-                              link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1d050, align: 16,
-                               data: SectionPos { offset: 0x1b050,
-                                                  size: 0x4b8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1d508, align: 16,
-                              strtab: &STRTAB_CONTENTS, dynamic: &DYNAMIC_ENTS,
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".got", addr: 0x1d618, align: 8,
-                               data: SectionPos { offset: 0x1b618, size: 0xb8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".data", addr: 0x1e6d0, align: 8,
-                               data: SectionPos { offset: 0x1b6d0, size: 0xb0 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: ".bss", addr: 0x1e780, align: 16,
-                             offset: 0x1b780, size: 0xe00, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1b780,
-                                                  size: 0x1091 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1c811, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
-                             strs: &SHSTRTAB_CONTENTS }
+SectionHdrData::Note { name: ".note.tag", addr: 0x270, align: 4,
+                       note: &NOTES_CONTENTS, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: ".dynsym", addr: 0x288, align: 8,
+                         syms: &DYNSYM_CONTENTS, strtab: &STRTAB_CONTENTS,
+                         local_end: 1, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: ".gnu.version", addr: 0x4f8, align: 2,
+                         versyms: SectionPos { offset: 0x4f8, size: 0x34 },
+                         symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                            strs: &STRTAB_CONTENTS },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: ".gnu.version_d", addr: 0x52c, align: 4,
+                         verdefs: SectionPos { offset: 0x52c, size: 0xfc },
+                         strtab: &STRTAB_CONTENTS, count: 9,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: ".gnu.hash", addr: 0x628, align: 8,
+                          hash: SectionPos { offset: 0x628, size: 0xcc },
+                          symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                             strs: &STRTAB_CONTENTS },
+                          flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: ".hash", addr: 0x6f4, align: 8,
+                       hash: SectionPos { offset: 0x6f4, size: 0xd8 },
+                       symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                          strs: &STRTAB_CONTENTS },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: ".dynstr", addr: 0x7cc, align: 1,
+                         strs: &STRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: ".rela.dyn", addr: 0x990, align: 8,
+                       relas: &REL_DYN_RELS_CONTENTS_X86,
+                       symtab: SymsStrs { syms: &DYNSYM_CONTENTS,
+                                          strs: &STRTAB_CONTENTS },
+                       target: 0, flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: ".rodata", addr: 0x1b30, align: 16,
+                           data: SectionPos { offset: 0x1b30, size: 0x2eac },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: ".eh_frame_hdr", addr: 0x49dc, align: 4,
+                           data: SectionPos { offset: 0x49dc, size: 0x224 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0x4c00, align: 8,
+                           data: SectionPos { offset: 0x4c00, size: 0x824 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".text", addr: 0x6430, align: 16,
+                           data: SectionPos { offset: 0x5430, size: 0x15c14 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: ".fini_array", addr: 0x1d048, align: 8,
+                            addrs: SectionPos { offset: 0x1b048, size: 8 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data.rel.ro", addr: 0x1d050, align: 16,
+                           data: SectionPos { offset: 0x1b050, size: 0x4b8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: ".dynamic", addr: 0x1d508, align: 16,
+                          strtab: &STRTAB_CONTENTS, dynamic: &DYNAMIC_ENTS,
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".got", addr: 0x1d618, align: 8,
+                           data: SectionPos { offset: 0x1b618, size: 0xb8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0x1e6d0, align: 8,
+                           data: SectionPos { offset: 0x1b6d0, size: 0xb0 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0x1e780, align: 16,
+                         offset: 0x1b780, size: 0xe00,
+                         flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1b780, size: 0x1091 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".gnu_debuglink", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1c811, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
+                         strs: &SHSTRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 0 } }
 ];
@@ -0,0 +1,384 @@
+use byteorder::LittleEndian;
+use elf_utils::Elf64;
+use elf_utils::ElfArch;
+use elf_utils::ElfABI;
+use elf_utils::ElfHdrData;
+use elf_utils::ElfKind;
+use elf_utils::ElfTable;
+use elf_utils::dynamic::DynamicEntData;
+use elf_utils::note::NoteData;
+use elf_utils::reloc::aarch64::AArch64Reloc;
+use elf_utils::section_hdr::SectionFlags;
+use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionPos;
+use elf_utils::section_hdr::SymsStrs;
+use elf_utils::symtab::SymBase;
+use elf_utils::symtab::SymBind;
+use elf_utils::symtab::SymData;
+use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
+use std::marker::PhantomData;
+
+type AArch64RelocSym = AArch64Reloc<SymData<&'static str, u16, Elf64>>;
+
+pub const PATH: &'static str = "tests/data/relocatable/aarch64/reloc.o";
+
+const NUM_SECTION_HDRS: usize = 11;
+
+pub const SECTION_HDR_CONTENTS_BARE: [SectionHdrData<Elf64, u32, u32, u32, u32,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>>;
+                                      NUM_SECTION_HDRS] = [
+    SectionHdrData::Null,
+SectionHdrData::Strtab { name: 92, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x388, size: 0x8b },
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: 6, addr: 0, align: 4,
+                           data: SectionPos { offset: 0x40, size: 0x48 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::Rela { name: 1, addr: 0, align: 8,
+                       relas: SectionPos { offset: 0x220, size: 0xf0 },
+                       symtab: 10, target: 2,
+                       flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Nobits { name: 25, addr: 0, align: 4, offset: 0x88,
+                         size: 0x4, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 113, addr: 0, align: 8,
+                           data: SectionPos { offset: 0x88, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Rela { name: 108, addr: 0, align: 8,
+                       relas: SectionPos { offset: 0x310, size: 0x48 },
+                       symtab: 10, target: 5,
+                       flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: 47, addr: 0, align: 1,
+                           data: SectionPos { offset: 0xa0, size: 0x0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: 68, addr: 0, align: 8,
+                           data: SectionPos { offset: 0xa0, size: 0x48 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: 63, addr: 0, align: 8,
+                       relas: SectionPos { offset: 0x358, size: 0x30 },
+                       symtab: 10, target: 8,
+                       flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Symtab { name: 100, addr: 0, align: 8,
+                         syms: SectionPos { offset: 0xe8, size: 0x138 },
+                         strtab: 1, local_end: 7,
+                         flags: SectionFlags { bits: 0 } }
+];
+
+pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf64, &'static str,
+                                                     u32, u32, u32,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>,
+                                                     SectionPos<u64>>;
+                                      NUM_SECTION_HDRS] = [
+    SectionHdrData::Null,
+SectionHdrData::Strtab { name: ".strtab", addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x388, size: 0x8b },
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".text", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x40, size: 0x48 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::Rela { name: ".rela.text", addr: 0, align: 8,
+                       relas: SectionPos { offset: 0x220, size: 0xf0 },
+                       symtab: 10, target: 2,
+                       flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0, align: 4, offset: 0x88,
+                         size: 0x4, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0, align: 8,
+                           data: SectionPos { offset: 0x88, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Rela { name: ".rela.data", addr: 0, align: 8,
+                       relas: SectionPos { offset: 0x310, size: 0x48 },
+                       symtab: 10, target: 5,
+                       flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".note.GNU-stack", addr: 0, align: 1,
+                           data: SectionPos { offset: 0xa0, size: 0x0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0, align: 8,
+                           data: SectionPos { offset: 0xa0, size: 0x48 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: ".rela.eh_frame", addr: 0, align: 8,
+                       relas: SectionPos { offset: 0x358, size: 0x30 },
+                       symtab: 10, target: 8,
+                       flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Symtab { name: ".symtab", addr: 0, align: 8,
+                         syms: SectionPos { offset: 0xe8, size: 0x138 },
+                         strtab: 1, local_end: 7,
+                         flags: SectionFlags { bits: 0 } }
+];
+
+pub const HEADER_DATA: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
+                                  ElfTable<Elf64>, u32> =
+    ElfHdrData {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::AArch64,
+        entry: 0, flags: 0, section_hdr_strtab: 1, prog_hdrs: None,
+        section_hdrs: ElfTable { offset: 1048, num_ents: 11 }
+    };
+
+const STRTAB_NUM_STRS: usize = 18;
+
+pub const STRTAB_CONTENTS: [(&'static str, usize); STRTAB_NUM_STRS] = [
+    ("", 0),
+    (".rela.text", 1),
+    ("report", 12),
+    ("limit", 19),
+    (".bss", 25),
+    ("get_counter", 30),
+    ("bump", 42),
+    (".note.GNU-stack", 47),
+    (".rela.eh_frame", 63),
+    ("table", 78),
+    ("reloc.c", 84),
+    (".strtab", 92),
+    (".symtab", 100),
+    (".rela.data", 108),
+    ("$d.3", 119),
+    ("$d.2", 124),
+    ("$d.1", 129),
+    ("$x.0", 134),
+];
+
+const SYMTAB_NUM_SYMS: usize = 13;
+
+pub const SYMTAB_CONTENTS: [SymData<&'static str, u16, Elf64>;
+                            SYMTAB_NUM_SYMS] = [
+    SymData { name: None, value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Undef },
+    SymData { name: Some("reloc.c"), value: 0, size: 0,
+              kind: SymKind::File, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Absolute },
+    SymData { name: None, value: 0, size: 0,
+              kind: SymKind::Section, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(2) },
+    SymData { name: Some("$x.0"), value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(2) },
+    SymData { name: Some("$d.1"), value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(4) },
+    SymData { name: Some("$d.2"), value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(5) },
+    SymData { name: Some("$d.3"), value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(8) },
+    SymData { name: Some("get_counter"), value: 0, size: 12,
+              kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(2) },
+    SymData { name: Some("counter"), value: 0, size: 4,
+              kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(4) },
+    SymData { name: Some("bump"), value: 12, size: 60,
+              kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(2) },
+    SymData { name: Some("limit"), value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Undef },
+    SymData { name: Some("table"), value: 0, size: 24,
+              kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(5) },
+    SymData { name: Some("report"), value: 0, size: 0,
+              kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Undef }
+];
+
+const RELA_TEXT_NUM_RELS: usize = 10;
+
+const RELA_TEXT_RELS_CONTENTS: [AArch64RelocSym; RELA_TEXT_NUM_RELS] = [
+    AArch64Reloc::AdrPRelPgHi21 {
+        offset: 0x0, addend: 0,
+        sym: SymData { name: Some("counter"), value: 0, size: 4,
+                       kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(4) }
+    },
+    AArch64Reloc::LdSt32AbsNC {
+        offset: 0x4, addend: 0,
+        sym: SymData { name: Some("counter"), value: 0, size: 4,
+                       kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(4) }
+    },
+    AArch64Reloc::AdrGOTPage {
+        offset: 0x14, addend: 0,
+        sym: SymData { name: Some("limit"), value: 0, size: 0,
+                       kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Undef }
+    },
+    AArch64Reloc::Ld64GOTLo12NC {
+        offset: 0x18, addend: 0,
+        sym: SymData { name: Some("limit"), value: 0, size: 0,
+                       kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Undef }
+    },
+    AArch64Reloc::AdrPRelPgHi21 {
+        offset: 0x20, addend: 0,
+        sym: SymData { name: Some("counter"), value: 0, size: 4,
+                       kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(4) }
+    },
+    AArch64Reloc::AddAbsLo12NC {
+        offset: 0x24, addend: 0,
+        sym: SymData { name: Some("counter"), value: 0, size: 4,
+                       kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(4) }
+    },
+    AArch64Reloc::AdrPRelPgHi21 {
+        offset: 0x34, addend: 0,
+        sym: SymData { name: Some("table"), value: 0, size: 24,
+                       kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(5) }
+    },
+    AArch64Reloc::LdSt64AbsNC {
+        offset: 0x38, addend: 0,
+        sym: SymData { name: Some("table"), value: 0, size: 24,
+                       kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(5) }
+    },
+    AArch64Reloc::Call26 {
+        offset: 0x3c, addend: 0,
+        sym: SymData { name: Some("report"), value: 0, size: 0,
+                       kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Undef }
+    },
+    AArch64Reloc::Jump26 {
+        offset: 0x44, addend: 0,
+        sym: SymData { name: Some("get_counter"), value: 0, size: 12,
+                       kind: SymKind::Function, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(2) }
+    }
+];
+
+const RELA_DATA_NUM_RELS: usize = 3;
+
+const RELA_DATA_RELS_CONTENTS: [AArch64RelocSym; RELA_DATA_NUM_RELS] = [
+    AArch64Reloc::Abs64 {
+        offset: 0x0, addend: 0,
+        sym: SymData { name: Some("get_counter"), value: 0, size: 12,
+                       kind: SymKind::Function, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(2) }
+    },
+    AArch64Reloc::Abs64 {
+        offset: 0x8, addend: 0,
+        sym: SymData { name: Some("bump"), value: 12, size: 60,
+                       kind: SymKind::Function, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(2) }
+    },
+    AArch64Reloc::Abs64 {
+        offset: 0x10, addend: 8,
+        sym: SymData { name: Some("limit"), value: 0, size: 0,
+                       kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Undef }
+    }
+];
+
+const RELA_EH_FRAME_NUM_RELS: usize = 2;
+
+const RELA_EH_FRAME_RELS_CONTENTS: [AArch64RelocSym; RELA_EH_FRAME_NUM_RELS] = [
+    AArch64Reloc::PRel32 {
+        offset: 0x1c, addend: 0,
+        sym: SymData { name: None, value: 0, size: 0,
+                       kind: SymKind::Section, bind: SymBind::Local,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(2) }
+    },
+    AArch64Reloc::PRel32 {
+        offset: 0x30, addend: 12,
+        sym: SymData { name: None, value: 0, size: 0,
+                       kind: SymKind::Section, bind: SymBind::Local,
+                       visibility: SymVisibility::Default, other: 0,
+                       section: SymBase::Index(2) }
+    }
+];
+
+pub const EXPECTED: [SectionHdrData<Elf64, &'static str,
+                                    u32,
+                                    SymsStrs<&'static [SymData<&'static str,
+                                                               u16, Elf64>],
+                                             &'static [(&'static str, usize)]>,
+                                    &'static [(&'static str, usize)],
+                                    SectionPos<u64>,
+                                    &'static [SymData<&'static str,
+                                                      u16, Elf64>],
+                                    &'static [(&'static str, usize)],
+                                    &'static [AArch64RelocSym],
+                                    &'static [AArch64RelocSym],
+                                    SectionPos<u64>,
+                                    &'static [DynamicEntData<&'static str,
+                                                             u64, Elf64>],
+                                    &'static [NoteData<'static>]>;
+                     NUM_SECTION_HDRS] = [
+    SectionHdrData::Null,
+SectionHdrData::Strtab { name: ".strtab", addr: 0, align: 1,
+                         strs: &STRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".text", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x40, size: 0x48 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::Rela { name: ".rela.text", addr: 0, align: 8,
+                       relas: &RELA_TEXT_RELS_CONTENTS,
+                       symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                          strs: &STRTAB_CONTENTS },
+                       target: 2, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0, align: 4, offset: 0x88,
+                         size: 0x4, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0, align: 8,
+                           data: SectionPos { offset: 0x88, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Rela { name: ".rela.data", addr: 0, align: 8,
+                       relas: &RELA_DATA_RELS_CONTENTS,
+                       symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                          strs: &STRTAB_CONTENTS },
+                       target: 5, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".note.GNU-stack", addr: 0, align: 1,
+                           data: SectionPos { offset: 0xa0, size: 0x0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0, align: 8,
+                           data: SectionPos { offset: 0xa0, size: 0x48 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: ".rela.eh_frame", addr: 0, align: 8,
+                       relas: &RELA_EH_FRAME_RELS_CONTENTS,
+                       symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                          strs: &STRTAB_CONTENTS },
+                       target: 8, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Symtab { name: ".symtab", addr: 0, align: 8,
+                         syms: &SYMTAB_CONTENTS, strtab: &STRTAB_CONTENTS,
+                         local_end: 7, flags: SectionFlags { bits: 0 } }
+];
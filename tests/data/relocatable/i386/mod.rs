@@ -10,6 +10,7 @@ use elf_utils::note::NoteData;
 use elf_utils::prog_hdr::ProgHdrData;
 use elf_utils::prog_hdr::Segment;
 use elf_utils::reloc::x86::X86Reloc;
+use elf_utils::section_hdr::SectionFlags;
 use elf_utils::section_hdr::SectionHdrData;
 use elf_utils::section_hdr::SectionPos;
 use elf_utils::section_hdr::SymsStrs;
@@ -17,6 +18,7 @@ use elf_utils::symtab::SymBase;
 use elf_utils::symtab::SymBind;
 use elf_utils::symtab::SymData;
 use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
 use std::marker::PhantomData;
 
 pub const PATH: &'static str = "tests/data/relocatable/i386/crt1.o";
@@ -34,99 +36,99 @@ pub const SECTION_HDR_CONTENTS_BARE: [SectionHdrData<Elf32, u32, u32, u32, u32,
                                                      SectionPos<u32>>;
                                       NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::ProgBits { name: 28, addr: 0, align: 16,
-                               data: SectionPos { offset: 0x40, size: 0x284 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Rel { name: 34, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x2c4, size: 0xd0 },
-                          symtab: 28, target: 1, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: 44, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x394, size: 0x279 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 53, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x60d, size: 0x1 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 68, addr: 0, align: 4,
-                               data: SectionPos { offset: 0x610, size: 0x4 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Rel { name: 74, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x614, size: 0x8 },
-                          symtab: 28, target: 5, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::Nobits { name: 84, addr: 0, align: 4,
-                             offset: 0x61c, size: 0x4, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: 89, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x61c, size: 0x295 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 100, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x8b1, size: 0x1f4 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 114, addr: 0, align: 1,
-                               data: SectionPos { offset: 0xaa5, size: 0x60b },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: 126, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x10b0, size: 0x3d8 },
-                          symtab: 28, target: 10, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: 142, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1488, size: 0x30 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 156, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x14b8, size: 0x2a1 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 167, addr: 0, align: 4,
-                               data: SectionPos { offset: 0x175c, size: 0x7c },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: 180, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x17d8, size: 0x30 },
-                          symtab: 28, target: 14, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: 197, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1808, size: 0x46c },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: 209, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x1c74, size: 0x10 },
-                          symtab: 28, target: 16, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::Unknown { name: 225, tag: 0x6fff4c03, addr: 0,
-                              align: 1, offset: 0x1c84, size: 0xa, ent_size: 0,
-                              link: 0, info: 0, flags: 0x80000000 },
-    SectionHdrData::ProgBits { name: 239, addr: 0, align: 4,
-                               data: SectionPos { offset: 0x1c90, size: 0x34 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::Rel { name: 249, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x1cc4, size: 0x8 },
-                          symtab: 28, target: 19, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: 263, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1ccc, size: 0x20 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: 278, addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x1cec, size: 0x10 },
-                          symtab: 28, target: 21, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::Unknown { name: 297, tag: 0x11, addr: 0,
-                              align: 4, offset: 0x1cfc, size: 0x8, ent_size: 4,
-                              link: 28, info: 72, flags: 0 },
-    SectionHdrData::Note { name: 304, addr: 0, align: 4,
-                           note: SectionPos { offset: 0x1d04, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Note { name: 304, addr: 0, align: 4,
-                           note: SectionPos { offset: 0x1d1c, size: 0x30 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 314, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1d4c, size: 0x0 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: 18, addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x239c, size: 0x14a } },
-    SectionHdrData::Symtab { name: 2, addr: 0, align: 4,
-                             syms: SectionPos { offset: 0x1d4c, size: 0x650 },
-                             strtab: 29, local_end: 83,
-                             alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: 10, addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x24e6, size: 0x121 } }
+SectionHdrData::ProgBits { name: 28, addr: 0, align: 16,
+                           data: SectionPos { offset: 0x40, size: 0x284 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::Rel { name: 34, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x2c4, size: 0xd0 },
+                      symtab: 28, target: 1,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: 44, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x394, size: 0x279 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: 53, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x60d, size: 0x1 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: 68, addr: 0, align: 4,
+                           data: SectionPos { offset: 0x610, size: 0x4 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Rel { name: 74, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x614, size: 0x8 }, symtab: 28,
+                      target: 5, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Nobits { name: 84, addr: 0, align: 4, offset: 0x61c, size: 0x4,
+                         flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 89, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x61c, size: 0x295 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: 100, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x8b1, size: 0x1f4 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: 114, addr: 0, align: 1,
+                           data: SectionPos { offset: 0xaa5, size: 0x60b },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: 126, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x10b0, size: 0x3d8 },
+                      symtab: 28, target: 10,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: 142, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1488, size: 0x30 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: 156, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x14b8, size: 0x2a1 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: 167, addr: 0, align: 4,
+                           data: SectionPos { offset: 0x175c, size: 0x7c },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: 180, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x17d8, size: 0x30 },
+                      symtab: 28, target: 14,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: 197, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1808, size: 0x46c },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: 209, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x1c74, size: 0x10 },
+                      symtab: 28, target: 16,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Unknown { name: 225, tag: 0x6fff4c03, addr: 0, align: 1,
+                          offset: 0x1c84, size: 0xa, ent_size: 0, link: 0,
+                          info: 0, flags: SectionFlags { bits: 0x80000000 } },
+SectionHdrData::ProgBits { name: 239, addr: 0, align: 4,
+                           data: SectionPos { offset: 0x1c90, size: 0x34 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: 249, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x1cc4, size: 0x8 },
+                      symtab: 28, target: 19,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: 263, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1ccc, size: 0x20 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: 278, addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x1cec, size: 0x10 },
+                      symtab: 28, target: 21,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Group { name: 297, addr: 0, align: 4,
+                        group: SectionPos { offset: 0x1cfc, size: 0x8 },
+                        symtab: 28, sym: 72, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Note { name: 304, addr: 0, align: 4,
+                       note: SectionPos { offset: 0x1d04, size: 0x18 },
+                       flags: SectionFlags { bits: 0x202 } },
+SectionHdrData::Note { name: 304, addr: 0, align: 4,
+                       note: SectionPos { offset: 0x1d1c, size: 0x30 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 314, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1d4c, size: 0x0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: 18, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x239c, size: 0x14a },
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::Symtab { name: 2, addr: 0, align: 4,
+                         syms: SectionPos { offset: 0x1d4c, size: 0x650 },
+                         strtab: 29, local_end: 83,
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: 10, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x24e6, size: 0x121 },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf32, &'static str,
@@ -141,103 +143,104 @@ pub const SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf32, &'static str,
                                                      SectionPos<u32>>;
                                       NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::ProgBits { name: ".text", addr: 0, align: 16,
-                               data: SectionPos { offset: 0x40, size: 0x284 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Rel { name: ".rel.text", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x2c4, size: 0xd0 },
-                          symtab: 28, target: 1, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x394, size: 0x279 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".rodata.str1.1", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x60d, size: 0x1 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".data", addr: 0, align: 4,
-                               data: SectionPos { offset: 0x610, size: 0x4 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Rel { name: ".rel.data", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x614, size: 0x8 },
-                          symtab: 28, target: 5, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::Nobits { name: ".bss", addr: 0, align: 4,
-                             offset: 0x61c, size: 0x4, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_loc", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x61c, size: 0x295 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_abbrev", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x8b1, size: 0x1f4 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_info", addr: 0, align: 1,
-                               data: SectionPos { offset: 0xaa5, size: 0x60b },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_info", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x10b0, size: 0x3d8 },
-                          symtab: 28, target: 10, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: ".debug_ranges", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1488, size: 0x30 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_str", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x14b8, size: 0x2a1 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_frame", addr: 0, align: 4,
-                               data: SectionPos { offset: 0x175c, size: 0x7c },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_frame", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x17d8, size: 0x30 },
-                          symtab: 28, target: 14, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: ".debug_line", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1808, size: 0x46c },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_line", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x1c74, size: 0x10 },
-                          symtab: 28, target: 16, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::Unknown { name: ".llvm_addrsig", tag: 0x6fff4c03, addr: 0,
-                              align: 1, offset: 0x1c84, size: 0xa, ent_size: 0,
-                              link: 0, info: 0, flags: 0x80000000 },
-    SectionHdrData::ProgBits { name: ".eh_frame", addr: 0, align: 4,
-                               data: SectionPos { offset: 0x1c90, size: 0x34 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.eh_frame", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x1cc4, size: 0x8 },
-                          symtab: 28, target: 19, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: ".debug_aranges", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1ccc, size: 0x20 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_aranges", addr: 0, align: 4,
-                          rels: SectionPos { offset: 0x1cec, size: 0x10 },
-                          symtab: 28, target: 21, alloc: false, write: false,
-                          exec: false },
-    SectionHdrData::Unknown { name: ".group", tag: 0x11, addr: 0,
-                              align: 4, offset: 0x1cfc, size: 0x8, ent_size: 4,
-                              link: 28, info: 72, flags: 0 },
-    SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
-                           note: SectionPos { offset: 0x1d04, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
-                           note: SectionPos { offset: 0x1d1c, size: 0x30 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".note.GNU-stack", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1d4c, size: 0x0 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x239c, size: 0x14a } },
-    SectionHdrData::Symtab { name: ".symtab", addr: 0, align: 4,
-                             syms: SectionPos { offset: 0x1d4c, size: 0x650 },
-                             strtab: 29, local_end: 83,
-                             alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".strtab", addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x24e6, size: 0x121 } }
+SectionHdrData::ProgBits { name: ".text", addr: 0, align: 16,
+                           data: SectionPos { offset: 0x40, size: 0x284 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::Rel { name: ".rel.text", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x2c4, size: 0xd0 },
+                      symtab: 28, target: 1,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x394, size: 0x279 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".rodata.str1.1", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x60d, size: 0x1 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x610, size: 0x4 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Rel { name: ".rel.data", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x614, size: 0x8 }, symtab: 28,
+                      target: 5, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0, align: 4, offset: 0x61c,
+                         size: 0x4, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".debug_loc", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x61c, size: 0x295 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".debug_abbrev", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x8b1, size: 0x1f4 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".debug_info", addr: 0, align: 1,
+                           data: SectionPos { offset: 0xaa5, size: 0x60b },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_info", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x10b0, size: 0x3d8 },
+                      symtab: 28, target: 10,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".debug_ranges", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1488, size: 0x30 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".debug_str", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x14b8, size: 0x2a1 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".debug_frame", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x175c, size: 0x7c },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_frame", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x17d8, size: 0x30 },
+                      symtab: 28, target: 14,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".debug_line", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1808, size: 0x46c },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_line", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x1c74, size: 0x10 },
+                      symtab: 28, target: 16,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Unknown { name: ".llvm_addrsig", tag: 0x6fff4c03, addr: 0,
+                          align: 1, offset: 0x1c84, size: 0xa, ent_size: 0,
+                          link: 0, info: 0,
+                          flags: SectionFlags { bits: 0x80000000 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x1c90, size: 0x34 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: ".rel.eh_frame", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x1cc4, size: 0x8 },
+                      symtab: 28, target: 19,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".debug_aranges", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1ccc, size: 0x20 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_aranges", addr: 0, align: 4,
+                      rels: SectionPos { offset: 0x1cec, size: 0x10 },
+                      symtab: 28, target: 21,
+                      flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Group { name: ".group", addr: 0, align: 4,
+                        group: SectionPos { offset: 0x1cfc, size: 0x8 },
+                        symtab: 28, sym: 72, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
+                       note: SectionPos { offset: 0x1d04, size: 0x18 },
+                       flags: SectionFlags { bits: 0x202 } },
+SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
+                       note: SectionPos { offset: 0x1d1c, size: 0x30 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".note.GNU-stack", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1d4c, size: 0x0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x239c, size: 0x14a },
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::Symtab { name: ".symtab", addr: 0, align: 4,
+                         syms: SectionPos { offset: 0x1d4c, size: 0x650 },
+                         strtab: 29, local_end: 83,
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".strtab", addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x24e6, size: 0x121 },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 pub const HEADER_DATA: ElfHdrData<LittleEndian, Elf32, ElfTable<Elf32>,
-                                  ElfTable<Elf32>, u16> =
+                                  ElfTable<Elf32>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Relocatable, arch: ElfArch::I386,
@@ -346,225 +349,321 @@ const SYMTAB_NUM_SYMS: usize = 101;
 pub const SYMTAB_CONTENTS: [SymData<&'static str, u16, Elf32>;
                             SYMTAB_NUM_SYMS] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
-    SymData { name: Some("crt1_c.c"), value: 0, size: 0,
-              kind: SymKind::File, bind: SymBind::Local,
-              section: SymBase::Absolute },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
+    SymData { name: Some("crt1_c.c"), value: 0, size: 0, kind: SymKind::File,
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Absolute },
     SymData { name: None, value: 504, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 485, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 68, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 480, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 275, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 6, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 442, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 447, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 132, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 512, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 166, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 16, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 155, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 338, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 496, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 141, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 356, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 102, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 419, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 317, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 343, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 467, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 398, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 71, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 620, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 281, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 286, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 197, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 235, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 638, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 456, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 365, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 328, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 410, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 113, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 428, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 239, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 199, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 654, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 458, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 625, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 298, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 255, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 614, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 460, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 373, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 215, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 379, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 477, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 120, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 439, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 290, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 666, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 388, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 83, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 454, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
-    SymData { name: Some(".L.str"), value: 0, size: 1,
-              kind: SymKind::Object, bind: SymBind::Local,
-              section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
+    SymData { name: Some(".L.str"), value: 0, size: 1, kind: SymKind::Object,
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: Some("finalizer"), value: 544, size: 69,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: Some("handle_static_init"), value: 304, size: 238,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(5) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(7) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(8) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(8) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(9) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(9) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(10) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(10) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(12) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(12) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(14) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(16) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(16) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(18) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(18) },
     SymData { name: Some(".freebsd.noteG"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(23) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(23) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(23) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(24) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(24) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(3) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(3) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(19) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(21) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(21) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(25) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(25) },
     SymData { name: Some("_start1"), value: 0, size: 303,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(26) },
-    SymData { name: Some("_DYNAMIC"), value: 0, size: 0,
-              kind: SymKind::None, bind: SymBind::Weak,
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(26) },
+    SymData { name: Some("_DYNAMIC"), value: 0, size: 0, kind: SymKind::None,
+              bind: SymBind::Weak, visibility: SymVisibility::Default, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__fini_array_end"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__fini_array_start"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__init_array_end"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__init_array_start"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__preinit_array_end"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__preinit_array_start"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__progname"), value: 0, size: 4,
               kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(5) },
     SymData { name: Some("__rel_iplt_end"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("__rel_iplt_start"), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some("_fini"), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some("_init"), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
-    SymData { name: Some("_init_tls"), value: 0, size: 0,
-              kind: SymKind::None, bind: SymBind::Global,
-              section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
+    SymData { name: Some("_init_tls"), value: 0, size: 0, kind: SymKind::None,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some("atexit"), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
-    SymData { name: Some("environ"), value: 0, size: 4,
-              kind: SymKind::Object, bind: SymBind::Global,
-              section: SymBase::Index(7) },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
+    SymData { name: Some("environ"), value: 0, size: 4, kind: SymKind::Object,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: Some("exit"), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some("main"), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some("_start"), value: 616, size: 25,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
 ];
 
@@ -575,18 +674,21 @@ const REL_TEXT_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str, u16, Elf32>>;
     X86Reloc::Abs32 {
         sym: SymData { name: Some("environ"), value: 0, size: 4,
                        kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Index(7) },
         offset: 0x15, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("environ"), value: 0, size: 4,
                        kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Index(7) },
         offset: 0x1e, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("_DYNAMIC"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Weak,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x2d, addend: 0
 
@@ -594,137 +696,160 @@ const REL_TEXT_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str, u16, Elf32>>;
     X86Reloc::PC32 {
         sym: SymData { name: Some("atexit"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x39, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__progname"), value: 0, size: 4,
                        kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Index(5) },
         offset: 0x51, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__rel_iplt_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Weak,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x69, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__rel_iplt_end"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Weak,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x6f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__rel_iplt_end"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Weak,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0xfc, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("_init_tls"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x107, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("main"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x122, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("exit"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x12b, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("_DYNAMIC"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Weak,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x140, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x14d, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("atexit"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Undef },
         offset: 0x152, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__preinit_array_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x15a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__preinit_array_end"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x15f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__preinit_array_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x198, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("_init"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x1b7, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__init_array_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x1bc, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__init_array_end"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x1c1, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__init_array_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x1f8, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__fini_array_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x225, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__fini_array_end"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x22a, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("_fini"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x242, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__fini_array_start"), value: 0, size: 0,
                        kind: SymKind::None, bind: SymBind::Global,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Undef },
         offset: 0x257, addend: 0
     },
     X86Reloc::PC32 {
         sym: SymData { name: Some("_start1"), value: 0, size: 303,
                        kind: SymKind::Function, bind: SymBind::Local,
+                       visibility: SymVisibility::Hidden, other: 0,
                        section: SymBase::Index(1) },
         offset: 0x27c, addend: 0
     },
@@ -737,6 +862,7 @@ const REL_DATA_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str, u16, Elf32>>;
     X86Reloc::Abs32 {
         sym: SymData { name: Some(".L.str"), value: 0, size: 1,
                        kind: SymKind::Object, bind: SymBind::Local,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Index(4) },
         offset: 0, addend: 0
     },
@@ -749,619 +875,742 @@ const REL_DEBUG_INFO_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str,
                                          REL_DEBUG_INFO_NUM_RELS] = [
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(9) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(9) },
         offset: 0x6, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 512, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xc, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 166, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x12, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(16) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(16) },
         offset: 0x16, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 16, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x1e, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 155, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x27, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("__progname"), value: 0, size: 4,
                        kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Index(5) },
         offset: 0x33, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 338, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x42, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 496, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x49, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: Some("environ"), value: 0, size: 4,
                        kind: SymKind::Object, bind: SymBind::Global,
+                       visibility: SymVisibility::Default, other: 0,
                        section: SymBase::Index(7) },
         offset: 0x55, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 141, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x65, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 356, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x75, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 102, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x80, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 419, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x8b, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 317, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x96, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 343, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x9d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 467, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xc7, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 398, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xd2, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 71, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xd9, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 620, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xe1, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 281, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xec, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 286, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0xf7, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 197, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x102, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 235, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x10e, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 638, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x115, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 456, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x11d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 365, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x137, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 328, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x142, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 410, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x14d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 113, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x159, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 428, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x16a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 239, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x171, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 456, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x179, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 199, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x184, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 654, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x18f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 458, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x19a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 625, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1a5, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 298, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1b0, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 255, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1bb, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 614, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1c6, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 460, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1d1, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 373, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1e1, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 215, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1f4, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 379, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x1fb, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 477, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x203, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 458, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x20e, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 120, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x21f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 477, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x227, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 439, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x232, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 458, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x23d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x249, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 290, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x253, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 504, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x25d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 620, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x26b, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 281, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x279, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x284, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 286, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x288, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(12) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(12) },
         offset: 0x297, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x29f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x2a8, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x2b1, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x2c4, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x2d0, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x2dd, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x2e9, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x2f2, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x2fb, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x304, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x30d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x316, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x31f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x328, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x331, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x33e, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x35a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x366, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(12) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(12) },
         offset: 0x379, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x381, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x38a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x39f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x3a6, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x3af, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x3b8, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x3ce, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 666, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x3d4, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 388, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x3eb, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x3f2, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 83, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x3fc, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x403, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 620, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x407, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x412, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 281, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x416, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 286, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x424, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x42f, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 454, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x433, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 485, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x43e, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x449, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 68, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x44d, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x45c, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x463, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x46c, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x473, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 480, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x479, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 275, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x494, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x49b, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 6, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4a5, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x4ac, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 454, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4b0, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 485, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4bb, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(8) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(8) },
         offset: 0x4c6, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 68, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4ca, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x4d9, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x4e0, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4e6, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 442, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4f1, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 447, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x4fc, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 132, size: 0, kind: SymKind::None,
-                       bind: SymBind::Local, section: SymBase::Index(13) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(13) },
         offset: 0x507, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(9) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(9) },
         offset: 0x52a, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(16) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(16) },
         offset: 0x530, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x534, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x538, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x606, addend: 0
     }
 ];
@@ -1373,32 +1622,38 @@ const REL_DEBUG_FRAME_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str,
                                           REL_DEBUG_FRAME_NUM_RELS] = [
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(14) },
         offset: 0x18, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x1c, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(14) },
         offset: 0x38, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x3c, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(14) },
         offset: 0x5c, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x60, addend: 0
     }
 ];
@@ -1410,12 +1665,14 @@ const REL_DEBUG_LINE_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str,
                                          REL_DEBUG_LINE_NUM_RELS] = [
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x1b8, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x37e, addend: 0
     },
 ];
@@ -1427,7 +1684,8 @@ const REL_EH_FRAME_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str,
                                        REL_EH_FRAME_NUM_RELS] = [
     X86Reloc::PC32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x20, addend: 0
     },
 ];
@@ -1439,12 +1697,14 @@ const REL_DEBUG_ARANGES_RELS_CONTENTS_X86: [X86Reloc<SymData<&'static str,
                                             REL_DEBUG_ARANGES_NUM_RELS] = [
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(10) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(10) },
         offset: 0x6, addend: 0
     },
     X86Reloc::Abs32 {
         sym: SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-                       bind: SymBind::Local, section: SymBase::Index(1) },
+                       bind: SymBind::Local, visibility: SymVisibility::Default,
+                       other: 0, section: SymBase::Index(1) },
         offset: 0x10, addend: 0
     },
 ];
@@ -1469,119 +1729,107 @@ pub const EXPECTED: [SectionHdrData<Elf32, &'static str,
                                     &'static [NoteData<'static>]>;
                      NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::ProgBits { name: ".text", addr: 0, align: 16,
-                               data: SectionPos { offset: 0x40,
-                                                  size: 0x284 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Rel { name: ".rel.text", addr: 0, align: 4,
-                          rels: &REL_TEXT_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 1, alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x394,
-                                                  size: 0x279 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".rodata.str1.1", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x60d,
-                                                  size: 0x1 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".data", addr: 0, align: 4,
-                               data: SectionPos { offset: 0x610,
-                                                  size: 0x4 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Rel { name: ".rel.data", addr: 0, align: 4,
-                          rels: &REL_DATA_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 5, alloc: false, write: false, exec: false },
-    SectionHdrData::Nobits { name: ".bss", addr: 0, align: 4,
-                             offset: 0x61c, size: 0x4, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_loc", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x61c,
-                                                  size: 0x295 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_abbrev", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x8b1,
-                                                  size: 0x1f4 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_info", addr: 0, align: 1,
-                               data: SectionPos { offset: 0xaa5,
-                                                  size: 0x60b },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_info", addr: 0, align: 4,
-                          rels: &REL_DEBUG_INFO_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 10, alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_ranges", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1488,
-                                                  size: 0x30 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_str", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x14b8,
-                                                  size: 0x2a1 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_frame", addr: 0, align: 4,
-                               data: SectionPos { offset: 0x175c,
-                                                  size: 0x7c },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_frame", addr: 0, align: 4,
-                          rels: &REL_DEBUG_FRAME_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 14, alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_line", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1808,
-                                                  size: 0x46c },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_line", addr: 0, align: 4,
-                          rels: &REL_DEBUG_LINE_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 16, alloc: false, write: false, exec: false },
-    SectionHdrData::Unknown { name: ".llvm_addrsig", tag: 0x6fff4c03,
-                              addr: 0, align: 1, offset: 0x1c84, size: 0xa,
-                              ent_size: 0, link: 0, info: 0,
-                              flags: 0x80000000 },
-    SectionHdrData::ProgBits { name: ".eh_frame", addr: 0, align: 4,
-                               data: SectionPos { offset: 0x1c90,
-                                                  size: 0x34 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.eh_frame", addr: 0, align: 4,
-                          rels: &REL_EH_FRAME_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 19, alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".debug_aranges", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1ccc,
-                                                  size: 0x20 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Rel { name: ".rel.debug_aranges", addr: 0, align: 4,
-                          rels: &REL_DEBUG_ARANGES_RELS_CONTENTS_X86,
-                          symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
-                                             strs: &STRTAB_CONTENTS },
-                          target: 21, alloc: false, write: false, exec: false },
-    SectionHdrData::Unknown { name: ".group", tag: 0x11, addr: 0,
-                              align: 4, offset: 0x1cfc, size: 0x8,
-                              ent_size: 4, link: 28, info: 72, flags: 0 },
-    SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
-                           note: &NOTES1_CONTENTS,
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
-                           note: &NOTES2_CONTENTS,
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: ".note.GNU-stack", addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1d4c,
-                                                  size: 0x0 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
-                             strs: &SHSTRTAB_CONTENTS },
-    SectionHdrData::Symtab { name: ".symtab", addr: 0, align: 4,
-                             syms: &SYMTAB_CONTENTS,
-                             strtab: &STRTAB_CONTENTS, local_end: 83,
-                             alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: ".strtab", addr: 0, align: 1,
-                             strs: &STRTAB_CONTENTS }
+SectionHdrData::ProgBits { name: ".text", addr: 0, align: 16,
+                           data: SectionPos { offset: 0x40, size: 0x284 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::Rel { name: ".rel.text", addr: 0, align: 4,
+                      rels: &REL_TEXT_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 1, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".comment", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x394, size: 0x279 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".rodata.str1.1", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x60d, size: 0x1 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: ".data", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x610, size: 0x4 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Rel { name: ".rel.data", addr: 0, align: 4,
+                      rels: &REL_DATA_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 5, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Nobits { name: ".bss", addr: 0, align: 4, offset: 0x61c,
+                         size: 0x4, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: ".debug_loc", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x61c, size: 0x295 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".debug_abbrev", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x8b1, size: 0x1f4 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".debug_info", addr: 0, align: 1,
+                           data: SectionPos { offset: 0xaa5, size: 0x60b },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_info", addr: 0, align: 4,
+                      rels: &REL_DEBUG_INFO_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 10, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".debug_ranges", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1488, size: 0x30 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::ProgBits { name: ".debug_str", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x14b8, size: 0x2a1 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: ".debug_frame", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x175c, size: 0x7c },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_frame", addr: 0, align: 4,
+                      rels: &REL_DEBUG_FRAME_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 14, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".debug_line", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1808, size: 0x46c },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_line", addr: 0, align: 4,
+                      rels: &REL_DEBUG_LINE_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 16, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Unknown { name: ".llvm_addrsig", tag: 0x6fff4c03, addr: 0,
+                          align: 1, offset: 0x1c84, size: 0xa, ent_size: 0,
+                          link: 0, info: 0,
+                          flags: SectionFlags { bits: 0x80000000 } },
+SectionHdrData::ProgBits { name: ".eh_frame", addr: 0, align: 4,
+                           data: SectionPos { offset: 0x1c90, size: 0x34 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: ".rel.eh_frame", addr: 0, align: 4,
+                      rels: &REL_EH_FRAME_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 19, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::ProgBits { name: ".debug_aranges", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1ccc, size: 0x20 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Rel { name: ".rel.debug_aranges", addr: 0, align: 4,
+                      rels: &REL_DEBUG_ARANGES_RELS_CONTENTS_X86,
+                      symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                         strs: &STRTAB_CONTENTS },
+                      target: 21, flags: SectionFlags { bits: 0x40 } },
+SectionHdrData::Group { name: ".group", addr: 0, align: 4,
+                        group: SectionPos { offset: 0x1cfc, size: 0x8 },
+                        symtab: SymsStrs { syms: &SYMTAB_CONTENTS,
+                                           strs: &STRTAB_CONTENTS },
+                        sym: 72, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
+                       note: &NOTES1_CONTENTS,
+                       flags: SectionFlags { bits: 0x202 } },
+SectionHdrData::Note { name: ".note.tag", addr: 0, align: 4,
+                       note: &NOTES2_CONTENTS,
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: ".note.GNU-stack", addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1d4c, size: 0x0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".shstrtab", addr: 0, align: 1,
+                         strs: &SHSTRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 0 } },
+SectionHdrData::Symtab { name: ".symtab", addr: 0, align: 4,
+                         syms: &SYMTAB_CONTENTS, strtab: &STRTAB_CONTENTS,
+                         local_end: 83, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: ".strtab", addr: 0, align: 1,
+                         strs: &STRTAB_CONTENTS,
+                         flags: SectionFlags { bits: 0 } }
 ];
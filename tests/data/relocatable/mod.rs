@@ -1,2 +1,3 @@
+pub mod aarch64;
 pub mod i386;
 pub mod x86_64;
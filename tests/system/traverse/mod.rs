@@ -10,6 +10,7 @@ use elf_utils::dynamic::Dynamic;
 use elf_utils::dynamic::DynamicEntData;
 use elf_utils::dynamic::DynamicEntDataStr;
 use elf_utils::dynamic::DynamicOffsets;
+use elf_utils::hash::GnuHashtab;
 use elf_utils::hash::Hashtab;
 use elf_utils::note::NoteData;
 use elf_utils::note::Notes;
@@ -24,6 +25,8 @@ use elf_utils::reloc::RelaData;
 use elf_utils::reloc::RelaDataStrSym;
 use elf_utils::reloc::RelaOffsets;
 use elf_utils::reloc::Relas;
+use elf_utils::reloc::RelrOffsets;
+use elf_utils::reloc::android::AndroidRelOffsets;
 use elf_utils::section_hdr::SectionHdrs;
 use elf_utils::section_hdr::SectionHdrData;
 use elf_utils::section_hdr::SectionHdrDataBufs;
@@ -44,6 +47,12 @@ use elf_utils::symtab::SymDataRaw;
 use elf_utils::symtab::SymDataStr;
 use elf_utils::symtab::SymOffsets;
 use elf_utils::symtab::WithSymtab;
+use elf_utils::version::VerdauxData;
+use elf_utils::version::Verdefs;
+use elf_utils::version::VernauxData;
+use elf_utils::version::VerneedData;
+use elf_utils::version::Verneeds;
+use elf_utils::version::Versyms;
 use std::convert::TryFrom;
 use std::convert::TryInto;
 use std::fmt::Display;
@@ -96,7 +105,8 @@ fn traverse_section_hdrs<'a, B, Offsets>(
         data: &'a [u8], strtab: usize,
         bare: &[SectionHdrDataRaw<Offsets>],
         strs: &[SectionHdrDataRawStr<'_, Offsets>]
-    ) where Offsets: 'a + Debug + SectionHdrOffsets,
+    ) where Offsets: 'a + Debug + SectionHdrOffsets + RelrOffsets +
+                     AndroidRelOffsets,
             B: 'a + ByteOrder {
     assert_eq!(hdrs.num_hdrs(), bare.len());
 
@@ -217,6 +227,118 @@ fn traverse_hash<'a, 'b, B, Offsets>(
     assert!(iter.next().is_none());
 }
 
+fn traverse_gnu_hash<'a, 'b, B, Offsets>(
+        hash: GnuHashtab<'a, B, Offsets>,
+        syms: Symtab<'a, B, Offsets>,
+        strtab: Strtab<'a>,
+        expected: &'b [SymData<&'b str, Offsets::Half, Offsets>]
+    ) where Offsets: Debug + SectionHdrOffsets,
+            B: ByteOrder {
+    let mut iter = syms.iter();
+
+    for i in 0 .. expected.len() {
+        let expected: SymData<Offsets::Word, Offsets::Half, Offsets> =
+            iter.next().expect("expected some")
+            .try_into().expect("expected success");
+        let expected: SymData<&'a str, Offsets::Half, Offsets> =
+            expected.clone().with_strtab(strtab).expect("expected success")
+            .try_into().expect("expected success");
+
+        // Only the symbols after the offset are in the hash table.
+        if i >= hash.symoffset() {
+            match expected.name {
+                Some(name) => {
+                    let sym = hash.lookup(name).expect("expected success")
+                        .expect("expected some");
+                    let actual: SymData<Offsets::Word, Offsets::Half,
+                                        Offsets> =
+                        sym.try_into().expect("expected success");
+                    let actual: SymData<&'a str, Offsets::Half, Offsets> =
+                        actual.clone().with_strtab(strtab)
+                        .expect("expected success")
+                        .try_into().expect("expected success");
+
+                    println!(" Symbol for \"{}\":\n{}\n", name, actual);
+
+                    assert_eq!(expected, actual);
+                },
+                None => {}
+            }
+        }
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn traverse_versyms<'a, 'b, B, Offsets>(
+        versyms: Versyms<'a, B>,
+        syms: Symtab<'a, B, Offsets>,
+        expected: &'b [SymData<&'b str, Offsets::Half, Offsets>]
+    ) where Offsets: SymOffsets,
+            B: ByteOrder {
+    assert_eq!(versyms.num_versyms(), syms.num_syms());
+    assert_eq!(versyms.num_versyms(), expected.len());
+
+    for versym in versyms.iter() {
+        println!("  Version index: {}", versym);
+    }
+}
+
+fn traverse_verdefs<'a, B, Offsets>(
+        verdefs: Verdefs<'a, B>,
+        strtab: Strtab<'a>,
+        expected: Offsets::Word
+    ) where Offsets: SectionHdrOffsets,
+            B: ByteOrder {
+    let mut count: u32 = 0;
+
+    for verdef in verdefs.iter() {
+        println!("  Version definition {} (flags 0x{:x}, hash 0x{:x}):",
+                 verdef.idx, verdef.flags, verdef.hash);
+
+        for name in verdef.names {
+            let name: VerdauxData<&'a str> = name.with_strtab(strtab)
+                .expect("expected success")
+                .try_into().expect("expected success");
+
+            println!("    {}", name);
+        }
+
+        count += 1;
+    }
+
+    assert_eq!(Offsets::Word::from(count), expected);
+}
+
+fn traverse_verneeds<'a, B, Offsets>(
+        verneeds: Verneeds<'a, B>,
+        strtab: Strtab<'a>,
+        expected: Offsets::Word
+    ) where Offsets: SectionHdrOffsets,
+            B: ByteOrder {
+    let mut count: u32 = 0;
+
+    for verneed in verneeds.iter() {
+        let verneed: VerneedData<&'a str, _> = verneed.with_strtab(strtab)
+            .expect("expected success")
+            .try_into().expect("expected success");
+
+        println!("  Version requirements for {}:", verneed.file);
+
+        for version in verneed.versions {
+            let version: VernauxData<&'a str> = version.with_strtab(strtab)
+                .expect("expected success")
+                .try_into().expect("expected success");
+
+            println!("    {}", version);
+        }
+
+        count += 1;
+    }
+
+    assert_eq!(Offsets::Word::from(count), expected);
+}
+
 fn traverse_notes<'a, 'b, B>(note: Notes<'a, B>, expected: &'b [NoteData<'b>])
     where B: ByteOrder {
     let mut iter = note.iter();
@@ -336,7 +458,8 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                        TryFrom<RelaDataStrSym<'a, Offsets>, Error = RelaError>,
             ArchRels: Display + Debug + Eq +
                       TryFrom<RelDataStrSym<'a, Offsets>, Error = RelError>,
-            Offsets: 'a + Debug + SectionHdrOffsets,
+            Offsets: 'a + Debug + SectionHdrOffsets + RelrOffsets +
+                     AndroidRelOffsets,
             B: 'a + ByteOrder,
             RelaError: Debug,
             RelError: Debug,
@@ -375,6 +498,7 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
 
         match expected[i] {
             SectionHdrData::Null => {},
+            SectionHdrData::ExtNull { .. } => {},
             SectionHdrData::ProgBits { .. } => {},
             SectionHdrData::Symtab { strtab: expected_strs,
                                      syms: expected, .. } => match hdr {
@@ -389,6 +513,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected symtab section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected symtab section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected symtab section {}, got progbits instead",
                            name);
@@ -425,6 +552,64 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected symtab section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected symtab section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected symtab section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected symtab section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected symtab section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected symtab section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected symtab section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected symtab section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected symtab section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected symtab section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected symtab section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected symtab section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected symtab section {}, got android rela instead",
+                        name
+                    );
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected symtab section {}, got unknown instead",
                            name);
@@ -439,6 +624,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected strtab section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected strtab section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected strtab section {}, got progbits instead",
                            name);
@@ -475,6 +663,64 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected strtab section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected strtab section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected strtab section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected strtab section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected strtab section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected strtab section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected strtab section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected strtab section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected strtab section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected strtab section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected strtab section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected strtab section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected strtab section {}, got android rela instead",
+                        name
+                    );
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected strtab section {}, got unknown instead",
                            name);
@@ -502,6 +748,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected rela section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected rela section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected rela section {}, got progbits instead",
                            name);
@@ -538,6 +787,56 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected rela section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected rela section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected rela section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected rela section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected rela section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected rela section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected rela section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected rela section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected rela section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!("Expected rela section {}, got symtab shndx instead",
+                           name);
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!("Expected rela section {}, got relr instead",
+                           name);
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!("Expected rela section {}, got android rel instead",
+                           name);
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!("Expected rela section {}, got android rela instead",
+                           name);
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected rela section {}, got unknown instead",
                            name);
@@ -554,6 +853,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected hash section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected hash section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected hash section {}, got progbits instead",
                            name);
@@ -590,11 +892,517 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected hash section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected hash section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected hash section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected hash section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected hash section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected hash section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected hash section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected hash section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected hash section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!("Expected hash section {}, got symtab shndx instead",
+                           name);
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!("Expected hash section {}, got relr instead",
+                           name);
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!("Expected hash section {}, got android rel instead",
+                           name);
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!("Expected hash section {}, got android rela instead",
+                           name);
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected hash section {}, got unknown instead",
                            name);
                 }
             },
+            SectionHdrData::GnuHash { symtab: SymsStrs { syms: expected, .. },
+                                   .. } => match hdr {
+                SectionHdrData::GnuHash { name, symtab: SymsStrs { syms, strs },
+                                       hash, .. } => {
+                    println!("GNU hash section {}:\n", name);
+                    traverse_gnu_hash(hash, syms, strs, expected);
+                    println!();
+                },
+                SectionHdrData::Null => {
+                    panic!("Expected gnu hash section, got null instead");
+                },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected gnu hash section, got null instead");
+                },
+                SectionHdrData::ProgBits { name, .. } => {
+                    panic!("Expected gnu hash section {}, got progbits instead",
+                           name);
+                },
+                SectionHdrData::Symtab { name, .. } => {
+                    panic!("Expected gnu hash section {}, got symtab instead",
+                           name);
+                },
+                SectionHdrData::Strtab { name, .. } => {
+                    panic!("Expected gnu hash section {}, got strtab instead",
+                           name);
+                },
+                SectionHdrData::Rela { name, .. } => {
+                    panic!("Expected gnu hash section {}, got rela instead",
+                           name);
+                },
+                SectionHdrData::Dynamic { name, .. } => {
+                    panic!("Expected gnu hash section {}, got dynamic instead",
+                           name);
+                },
+                SectionHdrData::Note { name, .. } => {
+                    panic!("Expected gnu hash section {}, got note instead",
+                           name);
+                },
+                SectionHdrData::Nobits { name, .. } => {
+                    panic!("Expected gnu hash section {}, got nobits instead",
+                           name);
+                },
+                SectionHdrData::Rel { name, .. } => {
+                    panic!("Expected gnu hash section {}, got rela instead",
+                           name);
+                },
+                SectionHdrData::Dynsym { name, .. } => {
+                    panic!("Expected gnu hash section {}, got dynsym instead",
+                           name);
+                },
+                SectionHdrData::Hash { name, .. } => {
+                    panic!("Expected gnu hash section {}, got hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected gnu hash section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected gnu hash section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected gnu hash section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got init array instead",
+                        name
+                    );
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got fini array instead",
+                        name
+                    );
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected gnu hash section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected gnu hash section {}, got android rela instead",
+                        name
+                    );
+                },
+                SectionHdrData::Unknown { name, .. } => {
+                    panic!("Expected gnu hash section {}, got unknown instead",
+                           name);
+                }
+            },
+            SectionHdrData::Versym { symtab: SymsStrs { syms: expected, .. },
+                                     .. } => match hdr {
+                SectionHdrData::Versym { name, symtab: SymsStrs { syms, .. },
+                                         versyms, .. } => {
+                    println!("Symbol version section {}:\n", name);
+                    traverse_versyms(versyms, syms, expected);
+                    println!();
+                },
+                SectionHdrData::Null => {
+                    panic!("Expected versym section, got null instead");
+                },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected versym section, got null instead");
+                },
+                SectionHdrData::ProgBits { name, .. } => {
+                    panic!("Expected versym section {}, got progbits instead",
+                           name);
+                },
+                SectionHdrData::Symtab { name, .. } => {
+                    panic!("Expected versym section {}, got symtab instead",
+                           name);
+                },
+                SectionHdrData::Strtab { name, .. } => {
+                    panic!("Expected versym section {}, got strtab instead",
+                           name);
+                },
+                SectionHdrData::Rela { name, .. } => {
+                    panic!("Expected versym section {}, got rela instead",
+                           name);
+                },
+                SectionHdrData::Hash { name, .. } => {
+                    panic!("Expected versym section {}, got hash instead",
+                           name);
+                },
+                SectionHdrData::Dynamic { name, .. } => {
+                    panic!("Expected versym section {}, got dynamic instead",
+                           name);
+                },
+                SectionHdrData::Note { name, .. } => {
+                    panic!("Expected versym section {}, got note instead",
+                           name);
+                },
+                SectionHdrData::Nobits { name, .. } => {
+                    panic!("Expected versym section {}, got nobits instead",
+                           name);
+                },
+                SectionHdrData::Rel { name, .. } => {
+                    panic!("Expected versym section {}, got rel instead",
+                           name);
+                },
+                SectionHdrData::Dynsym { name, .. } => {
+                    panic!("Expected versym section {}, got dynsym instead",
+                           name);
+                },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected versym section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected versym section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected versym section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected versym section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected versym section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected versym section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected versym section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected versym section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected versym section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected versym section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected versym section {}, got android rela instead",
+                        name
+                    );
+                },
+                SectionHdrData::Unknown { name, .. } => {
+                    panic!("Expected versym section {}, got unknown instead",
+                           name);
+                }
+            },
+            SectionHdrData::Verdef { count: expected, .. } => match hdr {
+                SectionHdrData::Verdef { name, strtab, verdefs, .. } => {
+                    println!("Version definition section {}:\n", name);
+                    traverse_verdefs::<B, Offsets>(verdefs, strtab, expected);
+                    println!();
+                },
+                SectionHdrData::Null => {
+                    panic!("Expected verdef section, got null instead");
+                },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected verdef section, got null instead");
+                },
+                SectionHdrData::ProgBits { name, .. } => {
+                    panic!("Expected verdef section {}, got progbits instead",
+                           name);
+                },
+                SectionHdrData::Symtab { name, .. } => {
+                    panic!("Expected verdef section {}, got symtab instead",
+                           name);
+                },
+                SectionHdrData::Strtab { name, .. } => {
+                    panic!("Expected verdef section {}, got strtab instead",
+                           name);
+                },
+                SectionHdrData::Rela { name, .. } => {
+                    panic!("Expected verdef section {}, got rela instead",
+                           name);
+                },
+                SectionHdrData::Hash { name, .. } => {
+                    panic!("Expected verdef section {}, got hash instead",
+                           name);
+                },
+                SectionHdrData::Dynamic { name, .. } => {
+                    panic!("Expected verdef section {}, got dynamic instead",
+                           name);
+                },
+                SectionHdrData::Note { name, .. } => {
+                    panic!("Expected verdef section {}, got note instead",
+                           name);
+                },
+                SectionHdrData::Nobits { name, .. } => {
+                    panic!("Expected verdef section {}, got nobits instead",
+                           name);
+                },
+                SectionHdrData::Rel { name, .. } => {
+                    panic!("Expected verdef section {}, got rel instead",
+                           name);
+                },
+                SectionHdrData::Dynsym { name, .. } => {
+                    panic!("Expected verdef section {}, got dynsym instead",
+                           name);
+                },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected verdef section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected verdef section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected verdef section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected verdef section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected verdef section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected verdef section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected verdef section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected verdef section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected verdef section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected verdef section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected verdef section {}, got android rela instead",
+                        name
+                    );
+                },
+                SectionHdrData::Unknown { name, .. } => {
+                    panic!("Expected verdef section {}, got unknown instead",
+                           name);
+                }
+            },
+            SectionHdrData::Verneed { count: expected, .. } => match hdr {
+                SectionHdrData::Verneed { name, strtab, verneeds, .. } => {
+                    println!("Version requirement section {}:\n", name);
+                    traverse_verneeds::<B, Offsets>(verneeds, strtab, expected);
+                    println!();
+                },
+                SectionHdrData::Null => {
+                    panic!("Expected verneed section, got null instead");
+                },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected verneed section, got null instead");
+                },
+                SectionHdrData::ProgBits { name, .. } => {
+                    panic!("Expected verneed section {}, got progbits instead",
+                           name);
+                },
+                SectionHdrData::Symtab { name, .. } => {
+                    panic!("Expected verneed section {}, got symtab instead",
+                           name);
+                },
+                SectionHdrData::Strtab { name, .. } => {
+                    panic!("Expected verneed section {}, got strtab instead",
+                           name);
+                },
+                SectionHdrData::Rela { name, .. } => {
+                    panic!("Expected verneed section {}, got rela instead",
+                           name);
+                },
+                SectionHdrData::Hash { name, .. } => {
+                    panic!("Expected verneed section {}, got hash instead",
+                           name);
+                },
+                SectionHdrData::Dynamic { name, .. } => {
+                    panic!("Expected verneed section {}, got dynamic instead",
+                           name);
+                },
+                SectionHdrData::Note { name, .. } => {
+                    panic!("Expected verneed section {}, got note instead",
+                           name);
+                },
+                SectionHdrData::Nobits { name, .. } => {
+                    panic!("Expected verneed section {}, got nobits instead",
+                           name);
+                },
+                SectionHdrData::Rel { name, .. } => {
+                    panic!("Expected verneed section {}, got rel instead",
+                           name);
+                },
+                SectionHdrData::Dynsym { name, .. } => {
+                    panic!("Expected verneed section {}, got dynsym instead",
+                           name);
+                },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected verneed section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected verneed section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected verneed section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got init array instead",
+                        name
+                    );
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got fini array instead",
+                        name
+                    );
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected verneed section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected verneed section {}, got android rela instead",
+                        name
+                    );
+                },
+                SectionHdrData::Unknown { name, .. } => {
+                    panic!("Expected verneed section {}, got unknown instead",
+                           name);
+                }
+            },
             SectionHdrData::Dynamic { strtab: expected_strs,
                                       dynamic: expected, .. } => match hdr {
                 SectionHdrData::Dynamic { name, strtab: actual_strs,
@@ -608,6 +1416,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected dynamic section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected dynamic section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected dynamic section {}, got progbits instead",
                            name);
@@ -644,6 +1455,68 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected dynamic section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected dynamic section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected dynamic section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected dynamic section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected dynamic section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got init array instead",
+                        name
+                    );
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got fini array instead",
+                        name
+                    );
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected dynamic section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected dynamic section {}, got android rela instead",
+                        name
+                    );
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected dynamic section {}, got unknown instead",
                            name);
@@ -657,6 +1530,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected note section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected note section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected note section {}, got progbits instead",
                            name);
@@ -693,6 +1569,56 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected note section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected note section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected note section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected note section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected note section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected note section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected note section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected note section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected note section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!("Expected note section {}, got symtab shndx instead",
+                           name);
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!("Expected note section {}, got relr instead",
+                           name);
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!("Expected note section {}, got android rel instead",
+                           name);
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!("Expected note section {}, got android rela instead",
+                           name);
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected note section {}, got unknown instead",
                            name);
@@ -721,6 +1647,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected rel section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected rel section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected rel section {}, got progbits instead",
                            name);
@@ -757,6 +1686,54 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected rel section {}, got dynsym instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected rel section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected rel section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected rel section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected rel section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected rel section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected rel section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!("Expected rel section {}, got preinit array instead",
+                           name);
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected rel section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!("Expected rel section {}, got symtab shndx instead",
+                           name);
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!("Expected rel section {}, got relr instead",
+                           name);
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!("Expected rel section {}, got android rel instead",
+                           name);
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!("Expected rel section {}, got android rela instead",
+                           name);
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected rel section {}, got unknown instead",
                            name);
@@ -775,6 +1752,9 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                 SectionHdrData::Null => {
                     panic!("Expected dynsym section, got null instead");
                 },
+                SectionHdrData::ExtNull { .. } => {
+                    panic!("Expected dynsym section, got null instead");
+                },
                 SectionHdrData::ProgBits { name, .. } => {
                     panic!("Expected dynsym section {}, got progbits instead",
                            name);
@@ -811,11 +1791,77 @@ fn traverse_section_contents<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                     panic!("Expected dynsym section {}, got rela instead",
                            name);
                 },
+                SectionHdrData::GnuHash { name, .. } => {
+                    panic!("Expected dynsym section {}, got gnu hash instead",
+                           name);
+                },
+                SectionHdrData::Versym { name, .. } => {
+                    panic!("Expected dynsym section {}, got versym instead",
+                           name);
+                },
+                SectionHdrData::Verdef { name, .. } => {
+                    panic!("Expected dynsym section {}, got verdef instead",
+                           name);
+                },
+                SectionHdrData::Verneed { name, .. } => {
+                    panic!("Expected dynsym section {}, got verneed instead",
+                           name);
+                },
+                SectionHdrData::InitArray { name, .. } => {
+                    panic!("Expected dynsym section {}, got init array instead",
+                           name);
+                },
+                SectionHdrData::FiniArray { name, .. } => {
+                    panic!("Expected dynsym section {}, got fini array instead",
+                           name);
+                },
+                SectionHdrData::PreInitArray { name, .. } => {
+                    panic!(
+                        "Expected dynsym section {}, got preinit array instead",
+                        name
+                    );
+                },
+                SectionHdrData::Group { name, .. } => {
+                    panic!("Expected dynsym section {}, got group instead",
+                           name);
+                },
+                SectionHdrData::SymtabShndx { name, .. } => {
+                    panic!(
+                        "Expected dynsym section {}, got symtab shndx instead",
+                        name
+                    );
+                },
+                SectionHdrData::Relr { name, .. } => {
+                    panic!(
+                        "Expected dynsym section {}, got relr instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRel { name, .. } => {
+                    panic!(
+                        "Expected dynsym section {}, got android rel instead",
+                        name
+                    );
+                },
+                SectionHdrData::AndroidRela { name, .. } => {
+                    panic!(
+                        "Expected dynsym section {}, got android rela instead",
+                        name
+                    );
+                },
                 SectionHdrData::Unknown { name, .. } => {
                     panic!("Expected dynsym section {}, got unknown instead",
                            name);
                 }
             },
+            SectionHdrData::InitArray { .. } => {},
+            SectionHdrData::FiniArray { .. } => {},
+            SectionHdrData::PreInitArray { .. } => {},
+            SectionHdrData::Group { .. } => {},
+            SectionHdrData::SymtabShndx { .. } => {},
+            SectionHdrData::Relr { .. } => {},
+            SectionHdrData::AndroidRel { .. } => {},
+            SectionHdrData::AndroidRela { .. } => {},
             SectionHdrData::Unknown { .. } => {},
         }
     }
@@ -846,7 +1892,8 @@ pub fn traverse_elf_file<'a, 'b, B, Offsets, ArchRels, ArchRelas,
                        TryFrom<RelaDataStrSym<'a, Offsets>, Error = RelaError>,
             ArchRels: Display + Debug + Eq +
                       TryFrom<RelDataStrSym<'a, Offsets>, Error = RelError>,
-            Offsets: 'a + Debug + ElfHdrOffsets,
+            Offsets: 'a + Debug + ElfHdrOffsets + RelrOffsets +
+                     AndroidRelOffsets,
             B: 'a + ElfByteOrder,
             RelaError: Debug,
             RelError: Debug {
@@ -1,2 +1,3 @@
+mod aarch64;
 mod elf32;
 mod elf64;
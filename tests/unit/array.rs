@@ -0,0 +1,174 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::array;
+use elf_utils::array::AddrArray;
+use elf_utils::array::AddrArrayError;
+
+const ELF32_ADDRS_BYTES: usize = 12;
+
+const ELF32_ADDRS: [u8; ELF32_ADDRS_BYTES] = [
+    0x40, 0x12, 0x00, 0x08, 0xa0, 0x13, 0x00, 0x08,
+    0xff, 0xff, 0xff, 0xff
+];
+
+const ELF32_ADDRS_CONTENTS: [u32; 3] = [ 0x08001240, 0x080013a0, 0xffffffff ];
+
+const ELF64_ADDRS_BYTES: usize = 16;
+
+const ELF64_ADDRS: [u8; ELF64_ADDRS_BYTES] = [
+    0x30, 0x11, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x90, 0x12, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF64_ADDRS_CONTENTS: [u64; 2] = [ 0x201130, 0x201290 ];
+
+#[test]
+fn test_AddrArray_elf32_from_slice_ok() {
+    let addrs: Result<AddrArray<'_, LittleEndian, Elf32>, AddrArrayError> =
+        AddrArray::try_from(&ELF32_ADDRS[0..]);
+
+    assert!(addrs.is_ok());
+    assert_eq!(addrs.unwrap().num_addrs(), ELF32_ADDRS_CONTENTS.len());
+}
+
+#[test]
+fn test_AddrArray_elf32_from_slice_bad_size() {
+    let addrs: Result<AddrArray<'_, LittleEndian, Elf32>, AddrArrayError> =
+        AddrArray::try_from(&ELF32_ADDRS[0 .. ELF32_ADDRS_BYTES - 1]);
+
+    assert_eq!(addrs.err(), Some(AddrArrayError::BadSize(11)));
+}
+
+#[test]
+fn test_AddrArray_elf32_from_slice_iter_len() {
+    let addrs: AddrArray<'_, LittleEndian, Elf32> =
+        AddrArray::try_from(&ELF32_ADDRS[0..]).expect("Expected success");
+
+    assert_eq!(addrs.iter().len(), ELF32_ADDRS_CONTENTS.len());
+}
+
+#[test]
+fn test_AddrArray_elf32_from_slice_iter() {
+    let addrs: AddrArray<'_, LittleEndian, Elf32> =
+        AddrArray::try_from(&ELF32_ADDRS[0..]).expect("Expected success");
+    let mut iter = addrs.iter();
+
+    for expected in ELF32_ADDRS_CONTENTS.iter() {
+        assert_eq!(iter.next(), Some(*expected));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_AddrArray_elf32_from_slice_idx() {
+    let addrs: AddrArray<'_, LittleEndian, Elf32> =
+        AddrArray::try_from(&ELF32_ADDRS[0..]).expect("Expected success");
+
+    for i in 0 .. ELF32_ADDRS_CONTENTS.len() {
+        assert_eq!(addrs.idx(i), Some(ELF32_ADDRS_CONTENTS[i]));
+    }
+
+    assert!(addrs.idx(ELF32_ADDRS_CONTENTS.len()).is_none());
+}
+
+#[test]
+fn test_AddrArray_elf32_create_just_right() {
+    let mut buf = [0; ELF32_ADDRS_BYTES];
+    let res: Result<(AddrArray<'_, LittleEndian, Elf32>, &'_ mut [u8]), ()> =
+        AddrArray::create_split(&mut buf[0..], ELF32_ADDRS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF32_ADDRS);
+}
+
+#[test]
+fn test_AddrArray_elf32_create_too_small() {
+    let mut buf = [0; ELF32_ADDRS_BYTES - 1];
+    let res: Result<AddrArray<'_, LittleEndian, Elf32>, ()> =
+        AddrArray::create(&mut buf[0..], ELF32_ADDRS_CONTENTS.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_AddrArray_elf32_required_bytes() {
+    let size = array::required_bytes::<_, Elf32>(ELF32_ADDRS_CONTENTS.iter());
+
+    assert_eq!(size, ELF32_ADDRS_BYTES);
+}
+
+#[test]
+fn test_AddrArray_elf64_from_slice_ok() {
+    let addrs: Result<AddrArray<'_, LittleEndian, Elf64>, AddrArrayError> =
+        AddrArray::try_from(&ELF64_ADDRS[0..]);
+
+    assert!(addrs.is_ok());
+    assert_eq!(addrs.unwrap().num_addrs(), ELF64_ADDRS_CONTENTS.len());
+}
+
+#[test]
+fn test_AddrArray_elf64_from_slice_bad_size() {
+    let addrs: Result<AddrArray<'_, LittleEndian, Elf64>, AddrArrayError> =
+        AddrArray::try_from(&ELF64_ADDRS[0 .. ELF64_ADDRS_BYTES - 4]);
+
+    assert_eq!(addrs.err(), Some(AddrArrayError::BadSize(12)));
+}
+
+#[test]
+fn test_AddrArray_elf64_from_slice_iter() {
+    let addrs: AddrArray<'_, LittleEndian, Elf64> =
+        AddrArray::try_from(&ELF64_ADDRS[0..]).expect("Expected success");
+    let mut iter = addrs.iter();
+
+    for expected in ELF64_ADDRS_CONTENTS.iter() {
+        assert_eq!(iter.next(), Some(*expected));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_AddrArray_elf64_from_slice_idx() {
+    let addrs: AddrArray<'_, LittleEndian, Elf64> =
+        AddrArray::try_from(&ELF64_ADDRS[0..]).expect("Expected success");
+
+    for i in 0 .. ELF64_ADDRS_CONTENTS.len() {
+        assert_eq!(addrs.idx(i), Some(ELF64_ADDRS_CONTENTS[i]));
+    }
+
+    assert!(addrs.idx(ELF64_ADDRS_CONTENTS.len()).is_none());
+}
+
+#[test]
+fn test_AddrArray_elf64_create_just_right() {
+    let mut buf = [0; ELF64_ADDRS_BYTES];
+    let res: Result<(AddrArray<'_, LittleEndian, Elf64>, &'_ mut [u8]), ()> =
+        AddrArray::create_split(&mut buf[0..], ELF64_ADDRS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF64_ADDRS);
+}
+
+#[test]
+fn test_AddrArray_elf64_create_too_big() {
+    let mut buf = [0; ELF64_ADDRS_BYTES + 1];
+    let res: Result<(AddrArray<'_, LittleEndian, Elf64>, &'_ mut [u8]), ()> =
+        AddrArray::create_split(&mut buf[0..], ELF64_ADDRS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_AddrArray_elf64_create_too_small() {
+    let mut buf = [0; ELF64_ADDRS_BYTES - 1];
+    let res: Result<AddrArray<'_, LittleEndian, Elf64>, ()> =
+        AddrArray::create(&mut buf[0..], ELF64_ADDRS_CONTENTS.iter());
+
+    assert!(res.is_err());
+}
@@ -0,0 +1,528 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::fmt::Debug;
+use core::marker::PhantomData;
+use elf_utils::Elf;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::ElfABI;
+use elf_utils::ElfArch;
+use elf_utils::ElfClass;
+use elf_utils::ElfHdrDataRaw;
+use elf_utils::ElfHdrOffsets;
+use elf_utils::ElfKind;
+use elf_utils::ElfTable;
+use elf_utils::builder::BuilderContent;
+use elf_utils::builder::BuilderSection;
+use elf_utils::builder::BuilderSegment;
+use elf_utils::builder::BuilderSegmentKind;
+use elf_utils::builder::BuilderSym;
+use elf_utils::builder::ElfBuilder;
+use elf_utils::builder::ElfBuilderError;
+use elf_utils::prog_hdr::ProgHdrData;
+use elf_utils::prog_hdr::ProgHdrDataRaw;
+use elf_utils::prog_hdr::ProgHdrs;
+use elf_utils::prog_hdr::Segment;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelDataRaw;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::RelaDataRaw;
+use elf_utils::reloc::Rels;
+use elf_utils::reloc::Relas;
+use elf_utils::section_hdr::SectionFlags;
+use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionHdrDataRaw;
+use elf_utils::section_hdr::SectionHdrs;
+use elf_utils::section_hdr::SectionPos;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::WithStrtab;
+use elf_utils::symtab::SymBase;
+use elf_utils::symtab::SymBind;
+use elf_utils::symtab::SymData;
+use elf_utils::symtab::SymDataRaw;
+use elf_utils::symtab::SymDataStr;
+use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
+use elf_utils::symtab::Symtab;
+
+const TEXT: [u8; 16] = [
+    0xe8, 0x00, 0x00, 0x00, 0x00, 0xe8, 0x00, 0x00,
+    0x00, 0x00, 0x31, 0xc0, 0xc3, 0x90, 0x90, 0x90
+];
+const DATA: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+
+fn sym<'a, Class>(name: &'a str, bind: SymBind,
+                  section: SymBase<usize, Class::Half>) ->
+    BuilderSym<'a, Class>
+    where Class: ElfClass {
+    SymData { name: Some(name), value: (0 as u8).into(),
+              size: (0 as u8).into(), kind: SymKind::Function, bind: bind,
+              visibility: SymVisibility::Default, other: 0,
+              section: section }
+}
+
+fn section_hdrs<'a, Class>(data: &'a [u8]) ->
+    (ElfHdrDataRaw<LittleEndian, Class>, SectionHdrs<'a, LittleEndian, Class>)
+    where Class: ElfHdrOffsets + Debug {
+    let elf: Elf<'_, LittleEndian, Class> =
+        Elf::try_from(data).expect("Expected success");
+    let hdr: ElfHdrDataRaw<LittleEndian, Class> =
+        elf.try_into().expect("Expected success");
+    let start: usize = hdr.section_hdrs.offset.try_into().ok().unwrap();
+    let num: u32 = hdr.section_hdrs.num_ents.into();
+    let end = start + (num as usize * Class::SECTION_HDR_SIZE);
+    let hdrs = SectionHdrs::try_from(&data[start .. end])
+        .expect("Expected success");
+
+    (hdr, hdrs)
+}
+
+fn section_hdr<Class>(hdrs: &SectionHdrs<'_, LittleEndian, Class>,
+                      idx: usize) -> SectionHdrDataRaw<Class>
+    where Class: ElfHdrOffsets + Debug {
+    hdrs.idx(idx).expect("Expected some").try_into()
+        .expect("Expected success")
+}
+
+fn prog_hdrs<'a, Class>(data: &'a [u8],
+                        hdr: &ElfHdrDataRaw<LittleEndian, Class>) ->
+    ProgHdrs<'a, LittleEndian, Class>
+    where Class: ElfHdrOffsets + Debug {
+    let ElfTable { offset, num_ents } = hdr.prog_hdrs.expect("Expected some");
+    let start: usize = offset.try_into().ok().unwrap();
+    let num: u32 = num_ents.into();
+    let end = start + (num as usize * Class::PROG_HDR_SIZE);
+
+    ProgHdrs::try_from(&data[start .. end]).expect("Expected success")
+}
+
+#[test]
+fn test_ElfBuilder_relocatable() {
+    let relas: [RelaDataRaw<Elf64>; 2] = [
+        RelaData { offset: 1, sym: 1, kind: 4, addend: -4 },
+        RelaData { offset: 6, sym: 3, kind: 4, addend: -4 }
+    ];
+    let sections: [BuilderSection<'_, Elf64>; 3] = [
+        BuilderSection {
+            name: ".text", addr: 0, align: 16,
+            flags: SectionFlags { bits: 0x6 },
+            content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".data", addr: 0, align: 8,
+            flags: SectionFlags { bits: 0x3 },
+            content: BuilderContent::ProgBits { data: &DATA, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".rela.text", addr: 0, align: 8,
+            flags: SectionFlags { bits: 0x40 },
+            content: BuilderContent::Rela { relas: &relas, target: 0 }
+        }
+    ];
+    let syms: [BuilderSym<'_, Elf64>; 3] = [
+        sym("main", SymBind::Global, SymBase::Index(0)),
+        sym("helper", SymBind::Local, SymBase::Index(0)),
+        sym("puts", SymBind::Global, SymBase::Undef)
+    ];
+    let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+        flags: 0, sections: &sections, segments: &[], syms: &syms
+    };
+    let size = builder.required_bytes().expect("Expected success");
+    let mut buf = vec![0xff; size];
+
+    builder.create(&mut buf[0..]).expect("Expected success");
+
+    let (hdr, hdrs) = section_hdrs::<Elf64>(&buf);
+
+    assert_eq!(hdr.prog_hdrs, None);
+    assert_eq!(hdrs.num_hdrs(), 7);
+    assert_eq!(hdr.section_hdr_strtab, 6);
+    assert_eq!(section_hdr(&hdrs, 0), SectionHdrData::Null);
+
+    let shstrtab = match section_hdr(&hdrs, 6) {
+        SectionHdrData::Strtab { strs: SectionPos { offset, size }, .. } =>
+            Strtab::try_from(&buf[offset as usize ..
+                                  (offset + size) as usize])
+            .expect("Expected success"),
+        hdr => panic!("Expected string table, got {:?}", hdr)
+    };
+    let names = [
+        "", ".text", ".data", ".rela.text", ".symtab", ".strtab", ".shstrtab"
+    ];
+
+    for (i, name) in names.iter().enumerate() {
+        let hdr = section_hdr(&hdrs, i).with_strtab(shstrtab)
+            .expect("Expected success");
+        let hdr_name = match hdr {
+            SectionHdrData::Null => "",
+            SectionHdrData::ProgBits { name, .. } |
+            SectionHdrData::Rela { name, .. } |
+            SectionHdrData::Symtab { name, .. } |
+            SectionHdrData::Strtab { name, .. } =>
+                name.expect("Expected success"),
+            hdr => panic!("Unexpected section header {:?}", hdr)
+        };
+
+        assert_eq!(hdr_name, *name);
+    }
+
+    match section_hdr(&hdrs, 1) {
+        SectionHdrData::ProgBits { data: SectionPos { offset, size },
+                                   align, .. } => {
+            assert_eq!(offset % 16, 0);
+            assert_eq!(align, 16);
+            assert_eq!(&buf[offset as usize .. (offset + size) as usize],
+                       &TEXT[0..]);
+        },
+        hdr => panic!("Expected program data, got {:?}", hdr)
+    }
+
+    match section_hdr(&hdrs, 2) {
+        SectionHdrData::ProgBits { data: SectionPos { offset, size }, .. } =>
+            assert_eq!(&buf[offset as usize .. (offset + size) as usize],
+                       &DATA[0..]),
+        hdr => panic!("Expected program data, got {:?}", hdr)
+    }
+
+    let (strtab, local_end, syms_pos) = match section_hdr(&hdrs, 4) {
+        SectionHdrData::Symtab { syms, strtab, local_end, .. } =>
+            (strtab, local_end, syms),
+        hdr => panic!("Expected symbol table, got {:?}", hdr)
+    };
+
+    assert_eq!(strtab, 5);
+    assert_eq!(local_end, 2);
+
+    let strs = match section_hdr(&hdrs, 5) {
+        SectionHdrData::Strtab { strs: SectionPos { offset, size }, .. } =>
+            Strtab::try_from(&buf[offset as usize ..
+                                  (offset + size) as usize])
+            .expect("Expected success"),
+        hdr => panic!("Expected string table, got {:?}", hdr)
+    };
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&buf[syms_pos.offset as usize ..
+                              (syms_pos.offset + syms_pos.size) as usize])
+        .expect("Expected success");
+    let expected: [(Option<&str>, SymBind, SymBase<u16, u16>); 4] = [
+        (None, SymBind::Local, SymBase::Undef),
+        (Some("helper"), SymBind::Local, SymBase::Index(1)),
+        (Some("main"), SymBind::Global, SymBase::Index(1)),
+        (Some("puts"), SymBind::Global, SymBase::Undef)
+    ];
+
+    assert_eq!(symtab.num_syms(), 4);
+
+    for (i, (name, bind, section)) in expected.iter().enumerate() {
+        let sym: SymDataRaw<Elf64> = symtab.idx(i).expect("Expected some")
+            .try_into().expect("Expected success");
+
+        assert_eq!(sym.bind, *bind);
+        assert_eq!(sym.section, *section);
+
+        match name {
+            Some(name) => {
+                let sym: SymDataStr<'_, Elf64> = sym.with_strtab(strs)
+                    .expect("Expected success").try_into()
+                    .expect("Expected success");
+
+                assert_eq!(sym.name, Some(*name));
+            },
+            None => assert_eq!(sym.name, None)
+        }
+    }
+
+    match section_hdr(&hdrs, 3) {
+        SectionHdrData::Rela { relas: SectionPos { offset, size }, symtab,
+                               target, .. } => {
+            let relas: Relas<'_, LittleEndian, Elf64> =
+                Relas::try_from(&buf[offset as usize ..
+                                     (offset + size) as usize])
+                .expect("Expected success");
+            let mut iter = relas.iter();
+            let rela: RelaDataRaw<Elf64> = iter.next()
+                .expect("Expected some").into();
+
+            assert_eq!(symtab, 4);
+            assert_eq!(target, 1);
+            assert_eq!(rela, RelaData { offset: 1, sym: 2, kind: 4,
+                                        addend: -4 });
+
+            let rela: RelaDataRaw<Elf64> = iter.next()
+                .expect("Expected some").into();
+
+            assert_eq!(rela, RelaData { offset: 6, sym: 3, kind: 4,
+                                        addend: -4 });
+        },
+        hdr => panic!("Expected relocations, got {:?}", hdr)
+    }
+}
+
+#[test]
+fn test_ElfBuilder_executable() {
+    let sections: [BuilderSection<'_, Elf64>; 3] = [
+        BuilderSection {
+            name: ".text", addr: 0x401000, align: 16,
+            flags: SectionFlags { bits: 0x6 },
+            content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".data", addr: 0x402010, align: 8,
+            flags: SectionFlags { bits: 0x3 },
+            content: BuilderContent::ProgBits { data: &DATA, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".bss", addr: 0x402020, align: 16,
+            flags: SectionFlags { bits: 0x3 },
+            content: BuilderContent::Nobits { size: 0x100 }
+        }
+    ];
+    let segments: [BuilderSegment<Elf64>; 3] = [
+        BuilderSegment {
+            kind: BuilderSegmentKind::Load { align: 0x1000, read: true,
+                                             write: false, exec: true },
+            first: 0, num: 1
+        },
+        BuilderSegment {
+            kind: BuilderSegmentKind::Load { align: 0x1000, read: true,
+                                             write: true, exec: false },
+            first: 1, num: 2
+        },
+        BuilderSegment {
+            kind: BuilderSegmentKind::GnuStack { read: true, write: true,
+                                                 exec: false },
+            first: 0, num: 0
+        }
+    ];
+    let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Executable, arch: ElfArch::X86_64, entry: 0x401000,
+        flags: 0, sections: &sections, segments: &segments, syms: &[]
+    };
+    let size = builder.required_bytes().expect("Expected success");
+    let mut buf = vec![0; size];
+
+    builder.create(&mut buf[0..]).expect("Expected success");
+
+    let (hdr, hdrs) = section_hdrs::<Elf64>(&buf);
+    let phdrs = prog_hdrs(&buf, &hdr);
+
+    assert_eq!(hdr.entry, 0x401000);
+    assert_eq!(hdr.prog_hdrs, Some(ElfTable { offset: 64, num_ents: 3 }));
+    assert_eq!(hdrs.num_hdrs(), 5);
+
+    let expected: [ProgHdrDataRaw<Elf64>; 3] = [
+        ProgHdrData::Load { virt_addr: 0x401000, phys_addr: 0x401000,
+                            mem_size: 16, align: 0x1000, read: true,
+                            write: false, exec: true,
+                            content: Segment { offset: 0x1000, size: 16 } },
+        ProgHdrData::Load { virt_addr: 0x402010, phys_addr: 0x402010,
+                            mem_size: 0x110, align: 0x1000, read: true,
+                            write: true, exec: false,
+                            content: Segment { offset: 0x1010, size: 8 } },
+        ProgHdrData::GnuStack { size: 0, align: 0, read: true, write: true,
+                                exec: false }
+    ];
+
+    for (i, expected) in expected.iter().enumerate() {
+        let phdr: ProgHdrDataRaw<Elf64> = phdrs.idx(i).expect("Expected some")
+            .try_into().expect("Expected success");
+
+        assert_eq!(phdr, *expected);
+    }
+
+    assert_eq!(&buf[0x1000 .. 0x1010], &TEXT[0..]);
+    assert_eq!(&buf[0x1010 .. 0x1018], &DATA[0..]);
+
+    match section_hdr(&hdrs, 3) {
+        SectionHdrData::Nobits { addr, offset, size, .. } => {
+            assert_eq!(addr, 0x402020);
+            assert_eq!(offset, 0x1020);
+            assert_eq!(size, 0x100);
+        },
+        hdr => panic!("Expected nobits section, got {:?}", hdr)
+    }
+}
+
+#[test]
+fn test_ElfBuilder_elf32_rel() {
+    let rels: [RelDataRaw<Elf32>; 1] = [
+        RelData { offset: 1, sym: 1, kind: 2 }
+    ];
+    let sections: [BuilderSection<'_, Elf32>; 2] = [
+        BuilderSection {
+            name: ".text", addr: 0, align: 4,
+            flags: SectionFlags { bits: 0x6 },
+            content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".rel.text", addr: 0, align: 0,
+            flags: SectionFlags { bits: 0x40 },
+            content: BuilderContent::Rel { rels: &rels, target: 0 }
+        }
+    ];
+    let builder: ElfBuilder<'_, LittleEndian, Elf32> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::I386, entry: 0,
+        flags: 0, sections: &sections, segments: &[],
+        syms: &[sym("f", SymBind::Global, SymBase::Undef)]
+    };
+    let size = builder.required_bytes().expect("Expected success");
+    let mut buf = vec![0; size];
+
+    builder.create(&mut buf[0..]).expect("Expected success");
+
+    let (_, hdrs) = section_hdrs::<Elf32>(&buf);
+
+    match section_hdr(&hdrs, 2) {
+        SectionHdrData::Rel { rels: SectionPos { offset, size }, symtab,
+                              target, .. } => {
+            let rels: Rels<'_, LittleEndian, Elf32> =
+                Rels::try_from(&buf[offset as usize ..
+                                    (offset + size) as usize])
+                .expect("Expected success");
+            let rel: RelDataRaw<Elf32> = rels.iter().next()
+                .expect("Expected some").into();
+
+            assert_eq!(offset % 4, 0);
+            assert_eq!(symtab, 3);
+            assert_eq!(target, 1);
+            assert_eq!(rel, RelData { offset: 1, sym: 1, kind: 2 });
+        },
+        hdr => panic!("Expected relocations, got {:?}", hdr)
+    }
+}
+
+#[test]
+fn test_ElfBuilder_no_sections() {
+    let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+        flags: 0, sections: &[], segments: &[], syms: &[]
+    };
+
+    // ELF header, ".shstrtab", padding, and two section headers.
+    assert_eq!(builder.required_bytes(), Ok(64 + 11 + 5 + 128));
+
+    let mut buf = [0; 208];
+
+    builder.create(&mut buf[0..]).expect("Expected success");
+
+    let (hdr, hdrs) = section_hdrs::<Elf64>(&buf);
+
+    assert_eq!(hdr.section_hdr_strtab, 1);
+    assert_eq!(hdrs.num_hdrs(), 2);
+}
+
+#[test]
+fn test_ElfBuilder_too_small() {
+    let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+        flags: 0, sections: &[], segments: &[], syms: &[]
+    };
+    let mut buf = [0; 207];
+
+    assert!(matches!(builder.create(&mut buf[0..]),
+                     Err(ElfBuilderError::TooSmall)));
+}
+
+fn create_err(builder: &ElfBuilder<'_, LittleEndian, Elf64>) ->
+    ElfBuilderError {
+    let mut buf = vec![0; 0x4000];
+
+    match builder.create(&mut buf[0..]) {
+        Err(err) => err,
+        Ok(_) => panic!("Expected error")
+    }
+}
+
+#[test]
+fn test_ElfBuilder_bad_refs() {
+    let relas: [RelaDataRaw<Elf64>; 1] = [
+        RelaData { offset: 1, sym: 2, kind: 4, addend: -4 }
+    ];
+    let sections: [BuilderSection<'_, Elf64>; 2] = [
+        BuilderSection {
+            name: ".text", addr: 0x1000, align: 16,
+            flags: SectionFlags { bits: 0x6 },
+            content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".rela.text", addr: 0, align: 8,
+            flags: SectionFlags { bits: 0x40 },
+            content: BuilderContent::Rela { relas: &relas, target: 0 }
+        }
+    ];
+    let syms = [sym("main", SymBind::Global, SymBase::Index(0))];
+    let mut builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+        flags: 0, sections: &sections, segments: &[], syms: &syms
+    };
+
+    assert_eq!(create_err(&builder), ElfBuilderError::BadRelocSym(1));
+
+    let bad_target = [
+        sections[0],
+        BuilderSection {
+            content: BuilderContent::Rela { relas: &relas[0 .. 0], target: 2 },
+            ..sections[1]
+        }
+    ];
+
+    builder.sections = &bad_target;
+
+    assert_eq!(create_err(&builder), ElfBuilderError::BadTarget(1));
+
+    let bad_syms = [sym("main", SymBind::Global, SymBase::Index(5))];
+
+    builder.sections = &sections[0 .. 1];
+    builder.syms = &bad_syms;
+
+    assert_eq!(create_err(&builder), ElfBuilderError::BadSymSection(0));
+
+    let bad_segment = [
+        BuilderSegment { kind: BuilderSegmentKind::Note, first: 0, num: 2 }
+    ];
+
+    builder.syms = &syms;
+    builder.segments = &bad_segment;
+
+    assert!(builder.required_bytes().is_ok());
+    assert_eq!(create_err(&builder), ElfBuilderError::BadSegment(0));
+}
+
+#[test]
+fn test_ElfBuilder_bad_section_addr() {
+    let sections: [BuilderSection<'_, Elf64>; 2] = [
+        BuilderSection {
+            name: ".text", addr: 0x401000, align: 16,
+            flags: SectionFlags { bits: 0x6 },
+            content: BuilderContent::ProgBits { data: &TEXT, ent_size: 0 }
+        },
+        BuilderSection {
+            name: ".rodata", addr: 0x401008, align: 8,
+            flags: SectionFlags { bits: 0x2 },
+            content: BuilderContent::ProgBits { data: &DATA, ent_size: 0 }
+        }
+    ];
+    let segments = [
+        BuilderSegment {
+            kind: BuilderSegmentKind::Load { align: 0x1000, read: true,
+                                             write: false, exec: true },
+            first: 0, num: 2
+        }
+    ];
+    let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Executable, arch: ElfArch::X86_64, entry: 0x401000,
+        flags: 0, sections: &sections, segments: &segments, syms: &[]
+    };
+
+    assert_eq!(builder.required_bytes(),
+               Err(ElfBuilderError::BadSectionAddr(1)));
+}
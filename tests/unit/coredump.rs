@@ -0,0 +1,678 @@
+use byteorder::ByteOrder;
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::ElfArch;
+use elf_utils::ElfKind;
+use elf_utils::coredump::AArch64Regs;
+use elf_utils::coredump::AuxvEnt;
+use elf_utils::coredump::Core;
+use elf_utils::coredump::CoreError;
+use elf_utils::coredump::CoreMemData;
+use elf_utils::coredump::CoreNote;
+use elf_utils::coredump::CoreNoteError;
+use elf_utils::coredump::CoreNoteRaw;
+use elf_utils::coredump::CoreNoteRefs;
+use elf_utils::coredump::CoreRegs;
+use elf_utils::coredump::MappedFileData;
+use elf_utils::coredump::MappedFilesError;
+use elf_utils::coredump::PrPsInfo;
+use elf_utils::coredump::PrStatus;
+use elf_utils::coredump::SigFields;
+use elf_utils::coredump::SigInfo;
+use elf_utils::coredump::TimeVal;
+use elf_utils::coredump::required_bytes;
+use elf_utils::note::NoteData;
+use elf_utils::note::Notes;
+
+const CORE_NAME: [u8; 5] = [0x43, 0x4f, 0x52, 0x45, 0x00];
+
+/// Build a little-endian 64-bit ELF with one `PT_NOTE` segment
+/// holding `notes`, followed by a `PT_LOAD` segment at `0x401000`
+/// holding `mem`.
+fn build_elf64(kind: u16, arch: u16, notes: &[NoteData<'_>],
+               mem: &[u8]) -> Vec<u8> {
+    let mut notebuf = vec![0; 1024];
+    let size = {
+        let res: Result<(Notes<'_, LittleEndian>, &'_ mut [u8]), ()> =
+            Notes::create_split(&mut notebuf[0..], notes.iter());
+        let (_, rest) = res.expect("Expected success");
+
+        1024 - rest.len()
+    };
+    let note_off = 64 + (2 * 56);
+    let load_off = note_off + size;
+    let mut buf = vec![0; load_off + mem.len()];
+
+    buf[0 .. 8].clone_from_slice(&[0x7f, 0x45, 0x4c, 0x46, 2, 1, 1, 0]);
+    LittleEndian::write_u16(&mut buf[16 .. 18], kind);
+    LittleEndian::write_u16(&mut buf[18 .. 20], arch);
+    LittleEndian::write_u32(&mut buf[20 .. 24], 1);
+    LittleEndian::write_u64(&mut buf[32 .. 40], 64);
+    LittleEndian::write_u16(&mut buf[52 .. 54], 64);
+    LittleEndian::write_u16(&mut buf[54 .. 56], 56);
+    LittleEndian::write_u16(&mut buf[56 .. 58], 2);
+    LittleEndian::write_u16(&mut buf[58 .. 60], 64);
+
+    LittleEndian::write_u32(&mut buf[64 .. 68], 4);
+    LittleEndian::write_u64(&mut buf[72 .. 80], note_off as u64);
+    LittleEndian::write_u64(&mut buf[96 .. 104], size as u64);
+    LittleEndian::write_u64(&mut buf[112 .. 120], 4);
+
+    LittleEndian::write_u32(&mut buf[120 .. 124], 1);
+    LittleEndian::write_u32(&mut buf[124 .. 128], 5);
+    LittleEndian::write_u64(&mut buf[128 .. 136], load_off as u64);
+    LittleEndian::write_u64(&mut buf[136 .. 144], 0x401000);
+    LittleEndian::write_u64(&mut buf[152 .. 160], mem.len() as u64);
+    LittleEndian::write_u64(&mut buf[160 .. 168], 0x2000);
+    LittleEndian::write_u64(&mut buf[168 .. 176], 0x1000);
+
+    buf[note_off .. load_off].clone_from_slice(&notebuf[0 .. size]);
+    buf[load_off ..].clone_from_slice(mem);
+
+    buf
+}
+
+fn prstatus64(pid: i32, num_regs: usize) -> Vec<u8> {
+    let mut desc = vec![0; 112 + (num_regs * 8) + 8];
+
+    LittleEndian::write_i32(&mut desc[0 .. 4], 11);
+    LittleEndian::write_u16(&mut desc[12 .. 14], 11);
+    LittleEndian::write_u64(&mut desc[16 .. 24], 0x100);
+    LittleEndian::write_i32(&mut desc[32 .. 36], pid);
+    LittleEndian::write_i32(&mut desc[36 .. 40], 1);
+    LittleEndian::write_i32(&mut desc[40 .. 44], pid);
+    LittleEndian::write_i32(&mut desc[44 .. 48], 1);
+    LittleEndian::write_u64(&mut desc[48 .. 56], 3);
+    LittleEndian::write_u64(&mut desc[56 .. 64], 500);
+
+    for i in 0 .. num_regs {
+        let start = 112 + (i * 8);
+
+        LittleEndian::write_u64(&mut desc[start .. start + 8],
+                                0x1000 + i as u64);
+    }
+
+    let fpvalid = 112 + (num_regs * 8);
+
+    LittleEndian::write_u32(&mut desc[fpvalid .. fpvalid + 4], 1);
+
+    desc
+}
+
+#[test]
+fn test_Core_x86_64_prstatus() {
+    let desc = prstatus64(42, 27);
+    let notes = [NoteData { kind: 1, name: &CORE_NAME, desc: &desc }];
+    let data = build_elf64(4, 0x3e, &notes, &[0; 16]);
+    let core: Core<'_, LittleEndian, Elf64> =
+        Core::try_from(&data[0..]).expect("Expected success");
+
+    assert_eq!(core.arch(), ElfArch::X86_64);
+
+    let mut iter = core.notes();
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    assert!(iter.next().is_none());
+
+    match note {
+        CoreNote::PrStatus(status) => {
+            assert_eq!(status.signo, 11);
+            assert_eq!(status.cursig, 11);
+            assert_eq!(status.sigpend, 0x100);
+            assert_eq!(status.pid, 42);
+            assert_eq!(status.ppid, 1);
+            assert_eq!(status.pgrp, 42);
+            assert_eq!(status.sid, 1);
+            assert_eq!(status.utime, TimeVal { sec: 3, usec: 500 });
+            assert!(status.fp_valid);
+
+            match status.regs.regs(core.arch()) {
+                CoreRegs::X86_64(regs) => {
+                    assert_eq!(regs.r15, 0x1000);
+                    assert_eq!(regs.rax, 0x100a);
+                    assert_eq!(regs.rip, 0x1010);
+                    assert_eq!(regs.rsp, 0x1013);
+                    assert_eq!(regs.gs, 0x101a);
+                },
+                regs => panic!("Expected x86-64 registers, got {:?}", regs)
+            }
+
+            assert_eq!(status.regs.regs(ElfArch::AArch64),
+                       CoreRegs::Other(status.regs.data()));
+        },
+        _ => panic!("Expected prstatus note")
+    }
+}
+
+#[test]
+fn test_PrStatusRegs_aarch64() {
+    let desc = prstatus64(7, 34);
+    let note = NoteData { kind: 1, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::PrStatus(status) => match status.regs.regs(ElfArch::AArch64) {
+            CoreRegs::AArch64(regs) => {
+                assert_eq!(regs.regs[0], 0x1000);
+                assert_eq!(regs.regs[30], 0x101e);
+                assert_eq!(regs.sp, 0x101f);
+                assert_eq!(regs.pc, 0x1020);
+                assert_eq!(regs.pstate, 0x1021);
+            },
+            regs => panic!("Expected AArch64 registers, got {:?}", regs)
+        },
+        _ => panic!("Expected prstatus note")
+    }
+}
+
+#[test]
+fn test_CoreNote_prstatus_bad_size() {
+    const DESC: [u8; 64] = [0; 64];
+    let note = NoteData { kind: 1, name: &CORE_NAME, desc: &DESC };
+    let note: Result<CoreNoteRaw<'_, LittleEndian, Elf64>, CoreNoteError> =
+        note.try_into();
+
+    assert_eq!(note, Err(CoreNoteError::BadDescSize { kind: 1, size: 64 }));
+}
+
+#[test]
+fn test_CoreNote_prpsinfo_elf64() {
+    let mut desc = [0; 136];
+
+    desc[0] = 0;
+    desc[1] = b'R';
+    desc[3] = 0xfb;
+    LittleEndian::write_u64(&mut desc[8 .. 16], 0x400);
+    LittleEndian::write_u32(&mut desc[16 .. 20], 1000);
+    LittleEndian::write_u32(&mut desc[20 .. 24], 100);
+    LittleEndian::write_i32(&mut desc[24 .. 28], 42);
+    LittleEndian::write_i32(&mut desc[28 .. 32], 1);
+    desc[40 .. 44].clone_from_slice(b"prog");
+    desc[56 .. 67].clone_from_slice(b"prog --test");
+
+    let note = NoteData { kind: 3, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::PrPsInfo(info) => {
+            assert_eq!(info.sname, b'R');
+            assert!(!info.zombie);
+            assert_eq!(info.nice, -5);
+            assert_eq!(info.flags, 0x400);
+            assert_eq!(info.uid, 1000);
+            assert_eq!(info.gid, 100);
+            assert_eq!(info.pid, 42);
+            assert_eq!(info.ppid, 1);
+            assert_eq!(info.fname, "prog");
+            assert_eq!(info.psargs, "prog --test");
+        },
+        _ => panic!("Expected prpsinfo note")
+    }
+}
+
+#[test]
+fn test_CoreNote_prpsinfo_elf32_short_ids() {
+    let mut desc = [0; 124];
+
+    desc[1] = b'S';
+    LittleEndian::write_u32(&mut desc[4 .. 8], 0x40);
+    LittleEndian::write_u16(&mut desc[8 .. 10], 1000);
+    LittleEndian::write_u16(&mut desc[10 .. 12], 100);
+    LittleEndian::write_i32(&mut desc[12 .. 16], 42);
+    desc[28 .. 32].clone_from_slice(b"prog");
+    desc[44 .. 48].clone_from_slice(b"prog");
+
+    let note = NoteData { kind: 3, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf32> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::PrPsInfo(info) => {
+            assert_eq!(info.sname, b'S');
+            assert_eq!(info.flags, 0x40);
+            assert_eq!(info.uid, 1000);
+            assert_eq!(info.gid, 100);
+            assert_eq!(info.pid, 42);
+            assert_eq!(info.fname, "prog");
+            assert_eq!(info.psargs, "prog");
+        },
+        _ => panic!("Expected prpsinfo note")
+    }
+}
+
+#[test]
+fn test_CoreNote_auxv() {
+    const ENTS: [(u64, u64); 5] = [
+        (3, 0x400040), (6, 0x1000), (23, 1), (0x1234, 5), (0, 0)
+    ];
+    let mut desc = [0; 80];
+
+    for (i, (tag, val)) in ENTS.iter().enumerate() {
+        LittleEndian::write_u64(&mut desc[i * 16 .. (i * 16) + 8], *tag);
+        LittleEndian::write_u64(&mut desc[(i * 16) + 8 .. (i + 1) * 16],
+                                *val);
+    }
+
+    let note = NoteData { kind: 6, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::Auxv { auxv } => {
+            let ents: Vec<AuxvEnt<Elf64>> = auxv.iter().collect();
+
+            assert_eq!(auxv.num_ents(), 5);
+            assert_eq!(ents, vec![
+                AuxvEnt::Phdr { addr: 0x400040 },
+                AuxvEnt::PageSize { size: 0x1000 },
+                AuxvEnt::Secure { secure: true },
+                AuxvEnt::Unknown { tag: 0x1234, val: 5 },
+                AuxvEnt::Null
+            ]);
+        },
+        _ => panic!("Expected auxv note")
+    }
+}
+
+#[test]
+fn test_CoreNote_auxv_bad_size() {
+    const DESC: [u8; 12] = [0; 12];
+    let note = NoteData { kind: 6, name: &CORE_NAME, desc: &DESC };
+    let note: Result<CoreNoteRaw<'_, LittleEndian, Elf64>, CoreNoteError> =
+        note.try_into();
+
+    assert_eq!(note, Err(CoreNoteError::BadDescSize { kind: 6, size: 12 }));
+}
+
+fn file_note64(names: &[&str]) -> Vec<u8> {
+    let mut desc = vec![0; 16 + (names.len() * 24)];
+
+    LittleEndian::write_u64(&mut desc[0 .. 8], names.len() as u64);
+    LittleEndian::write_u64(&mut desc[8 .. 16], 0x1000);
+
+    for i in 0 .. names.len() {
+        let start = 16 + (i * 24);
+        let addr = 0x400000 + (i as u64 * 0x1000);
+
+        LittleEndian::write_u64(&mut desc[start .. start + 8], addr);
+        LittleEndian::write_u64(&mut desc[start + 8 .. start + 16],
+                                addr + 0x1000);
+        LittleEndian::write_u64(&mut desc[start + 16 .. start + 24],
+                                i as u64);
+    }
+
+    for name in names {
+        desc.extend_from_slice(name.as_bytes());
+        desc.push(0);
+    }
+
+    desc
+}
+
+#[test]
+fn test_CoreNote_file() {
+    let desc = file_note64(&["/bin/prog", "/lib/libc.so.6"]);
+    let note = NoteData { kind: 0x46494c45, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::File { page_size, files } => {
+            let ents: Vec<MappedFileData<'_, Elf64>> = files.iter().collect();
+
+            assert_eq!(page_size, 0x1000);
+            assert_eq!(files.num_files(), 2);
+            assert_eq!(ents, vec![
+                MappedFileData { start: 0x400000, end: 0x401000,
+                                 page_offset: 0, name: "/bin/prog" },
+                MappedFileData { start: 0x401000, end: 0x402000,
+                                 page_offset: 1, name: "/lib/libc.so.6" }
+            ]);
+        },
+        _ => panic!("Expected file note")
+    }
+}
+
+#[test]
+fn test_CoreNote_file_truncated() {
+    let desc = file_note64(&["/bin/prog"]);
+    let desc = &desc[0 .. desc.len() - 1];
+    let note = NoteData { kind: 0x46494c45, name: &CORE_NAME, desc: desc };
+    let note: Result<CoreNoteRaw<'_, LittleEndian, Elf64>, CoreNoteError> =
+        note.try_into();
+
+    assert_eq!(note,
+               Err(CoreNoteError::BadFiles(MappedFilesError::TooShort)));
+}
+
+#[test]
+fn test_CoreNote_siginfo_fault() {
+    let mut desc = [0; 128];
+
+    LittleEndian::write_i32(&mut desc[0 .. 4], 11);
+    LittleEndian::write_i32(&mut desc[8 .. 12], 1);
+    LittleEndian::write_u64(&mut desc[16 .. 24], 0xdeadbeef);
+
+    let note = NoteData { kind: 0x53494749, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::SigInfo(info) => {
+            assert_eq!(info.signo, 11);
+            assert_eq!(info.code, 1);
+            assert_eq!(info.fields, SigFields::Fault { addr: 0xdeadbeef });
+        },
+        _ => panic!("Expected siginfo note")
+    }
+}
+
+#[test]
+fn test_CoreNote_siginfo_kill() {
+    let mut desc = [0; 128];
+
+    LittleEndian::write_i32(&mut desc[0 .. 4], 6);
+    LittleEndian::write_i32(&mut desc[8 .. 12], -6);
+    LittleEndian::write_i32(&mut desc[12 .. 16], 42);
+    LittleEndian::write_u32(&mut desc[16 .. 20], 1000);
+
+    let note = NoteData { kind: 0x53494749, name: &CORE_NAME, desc: &desc };
+    let note: CoreNoteRaw<'_, LittleEndian, Elf32> =
+        note.try_into().expect("Expected success");
+
+    match note {
+        CoreNote::SigInfo(info) =>
+            assert_eq!(info.fields, SigFields::Kill { pid: 42, uid: 1000 }),
+        _ => panic!("Expected siginfo note")
+    }
+}
+
+#[test]
+fn test_CoreNote_other() {
+    const LINUX_NAME: [u8; 6] = [0x4c, 0x49, 0x4e, 0x55, 0x58, 0x00];
+    const DESC: [u8; 4] = [0; 4];
+    let note = NoteData { kind: 1, name: &LINUX_NAME, desc: &DESC };
+    let kind: Result<CoreNoteRaw<'_, LittleEndian, Elf64>, CoreNoteError> =
+        note.try_into();
+
+    assert_eq!(kind, Ok(CoreNote::Other(note)));
+}
+
+#[test]
+fn test_Core_mem_ranges_read() {
+    const MEM: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    let desc = prstatus64(1, 27);
+    let notes = [
+        NoteData { kind: 1, name: &CORE_NAME, desc: &desc },
+        NoteData { kind: 1, name: &CORE_NAME, desc: &desc }
+    ];
+    let data = build_elf64(4, 0x3e, &notes, &MEM);
+    let core: Core<'_, LittleEndian, Elf64> =
+        Core::try_from(&data[0..]).expect("Expected success");
+    let mem: Vec<CoreMemData<'_, Elf64>> = core.mem_ranges().collect();
+
+    assert_eq!(core.notes().count(), 2);
+    assert_eq!(mem, vec![CoreMemData { addr: 0x401000, size: 0x2000,
+                                       read: true, write: false, exec: true,
+                                       data: &MEM }]);
+    assert_eq!(core.read(0x401000, 8), Some(&MEM[0..]));
+    assert_eq!(core.read(0x401004, 2), Some(&MEM[4 .. 6]));
+    assert_eq!(core.read(0x401004, 8), None);
+    assert_eq!(core.read(0x400000, 1), None);
+}
+
+#[test]
+fn test_Core_not_core() {
+    let data = build_elf64(2, 0x3e, &[], &[]);
+    let core: Result<Core<'_, LittleEndian, Elf64>, CoreError<Elf64>> =
+        Core::try_from(&data[0..]);
+
+    assert!(matches!(core, Err(CoreError::NotCore(ElfKind::Executable))));
+}
+
+#[test]
+fn test_Core_bad_notes() {
+    let desc = prstatus64(1, 27);
+    let notes = [NoteData { kind: 1, name: &CORE_NAME, desc: &desc }];
+    let mut data = build_elf64(4, 0x3e, &notes, &[]);
+
+    LittleEndian::write_u32(&mut data[180 .. 184], 0x1000);
+
+    let core: Result<Core<'_, LittleEndian, Elf64>, CoreError<Elf64>> =
+        Core::try_from(&data[0..]);
+
+    assert!(matches!(core, Err(CoreError::BadNotes(_))));
+}
+
+#[test]
+fn test_Core_note_offset_overflow() {
+    let desc = prstatus64(1, 27);
+    let notes = [NoteData { kind: 1, name: &CORE_NAME, desc: &desc }];
+    let mut data = build_elf64(4, 0x3e, &notes, &[]);
+
+    LittleEndian::write_u64(&mut data[72 .. 80], u64::MAX);
+
+    let core: Result<Core<'_, LittleEndian, Elf64>, CoreError<Elf64>> =
+        Core::try_from(&data[0..]);
+
+    assert!(matches!(core, Err(CoreError::BadProgHdr(_))));
+}
+
+#[test]
+fn test_Core_load_offset_overflow() {
+    const MEM: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+    let mut data = build_elf64(4, 0x3e, &[], &MEM);
+
+    LittleEndian::write_u64(&mut data[128 .. 136], u64::MAX);
+
+    let core: Result<Core<'_, LittleEndian, Elf64>, CoreError<Elf64>> =
+        Core::try_from(&data[0..]);
+
+    assert!(matches!(core, Err(CoreError::BadProgHdr(_))));
+}
+
+#[test]
+fn test_Core_prog_hdrs_overflow() {
+    let mut data = build_elf64(4, 0x3e, &[], &[]);
+
+    LittleEndian::write_u64(&mut data[32 .. 40], u64::MAX - 8);
+
+    let core: Result<Core<'_, LittleEndian, Elf64>, CoreError<Elf64>> =
+        Core::try_from(&data[0..]);
+
+    assert!(matches!(core, Err(CoreError::ProgHdrsOutOfBounds(_))));
+}
+
+#[test]
+fn test_Core_create_elf64() {
+    const AUXV: [AuxvEnt<Elf64>; 4] = [
+        AuxvEnt::Phdr { addr: 0x400040 }, AuxvEnt::PageSize { size: 0x1000 },
+        AuxvEnt::Secure { secure: true }, AuxvEnt::Null
+    ];
+    const FILES: [MappedFileData<'static, Elf64>; 2] = [
+        MappedFileData { start: 0x400000, end: 0x401000, page_offset: 0,
+                         name: "/bin/prog" },
+        MappedFileData { start: 0x401000, end: 0x403000, page_offset: 1,
+                         name: "/bin/prog" }
+    ];
+    const MEM: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    let regs = AArch64Regs { regs: [7; 31], sp: 0x7ff0, pc: 0x401234,
+                             pstate: 0x60000000 };
+    let status = PrStatus {
+        signo: 11, code: 0, errno: 0, cursig: 11, sigpend: 0, sighold: 0x100,
+        pid: 42, ppid: 1, pgrp: 42, sid: 1,
+        utime: TimeVal { sec: 3, usec: 500 },
+        stime: TimeVal { sec: 0, usec: 0 },
+        cutime: TimeVal { sec: 0, usec: 0 },
+        cstime: TimeVal { sec: 0, usec: 0 },
+        regs: CoreRegs::AArch64(regs), fp_valid: true
+    };
+    let info = PrPsInfo {
+        state: 0, sname: b'R', zombie: false, nice: -5, flags: 0x400,
+        uid: 1000, gid: 100, pid: 42, ppid: 1, pgrp: 42, sid: 1,
+        fname: "prog", psargs: "prog --test"
+    };
+    let siginfo = SigInfo { signo: 11, errno: 0, code: 1,
+                            fields: SigFields::Fault { addr: 0xdead } };
+    let notes: [CoreNoteRefs<'_, Elf64>; 5] = [
+        CoreNote::PrStatus(status), CoreNote::PrPsInfo(info),
+        CoreNote::SigInfo(siginfo), CoreNote::Auxv { auxv: &AUXV },
+        CoreNote::File { page_size: 0x1000, files: &FILES }
+    ];
+    let mem = [
+        CoreMemData { addr: 0x401000, size: 0x2000, read: true, write: false,
+                      exec: true, data: &MEM[0 .. 5] },
+        CoreMemData { addr: 0x7f000, size: 0x1000, read: true, write: true,
+                      exec: false, data: &MEM[5 ..] }
+    ];
+    let size = required_bytes(notes.iter(), mem.iter());
+    let mut buf = vec![0xff; size + 8];
+    let res: Result<(Core<'_, LittleEndian, Elf64>, &'_ mut [u8]), ()> =
+        Core::create_split(&mut buf[0..], ElfArch::AArch64, notes.iter(),
+                           mem.iter());
+    let (core, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 8);
+    assert_eq!(core.arch(), ElfArch::AArch64);
+
+    let mut iter = core.notes();
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    match note {
+        CoreNote::PrStatus(decoded) => {
+            assert_eq!(decoded.signo, 11);
+            assert_eq!(decoded.cursig, 11);
+            assert_eq!(decoded.sighold, 0x100);
+            assert_eq!(decoded.pid, 42);
+            assert_eq!(decoded.sid, 1);
+            assert_eq!(decoded.utime, TimeVal { sec: 3, usec: 500 });
+            assert!(decoded.fp_valid);
+            assert_eq!(decoded.regs.regs(core.arch()),
+                       CoreRegs::AArch64(regs));
+        },
+        _ => panic!("Expected prstatus note")
+    }
+
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    assert_eq!(note, CoreNote::PrPsInfo(info));
+
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    assert_eq!(note, CoreNote::SigInfo(siginfo));
+
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    match note {
+        CoreNote::Auxv { auxv } =>
+            assert!(auxv.iter().eq(AUXV.iter().cloned())),
+        _ => panic!("Expected auxv note")
+    }
+
+    let note: CoreNoteRaw<'_, LittleEndian, Elf64> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    match note {
+        CoreNote::File { page_size, files } => {
+            assert_eq!(page_size, 0x1000);
+            assert!(files.iter().eq(FILES.iter().cloned()));
+        },
+        _ => panic!("Expected file note")
+    }
+
+    assert!(iter.next().is_none());
+
+    let ranges: Vec<CoreMemData<'_, Elf64>> = core.mem_ranges().collect();
+
+    assert_eq!(ranges, mem.to_vec());
+    assert_eq!(core.read(0x401003, 2), Some(&MEM[3 .. 5]));
+    assert_eq!(core.read(0x7f000, 7), Some(&MEM[5 ..]));
+}
+
+#[test]
+fn test_Core_create_elf32() {
+    let info = PrPsInfo {
+        state: 1, sname: b'S', zombie: false, nice: 0, flags: 0x40,
+        uid: 1000, gid: 100, pid: 42, ppid: 1, pgrp: 42, sid: 1,
+        fname: "a-very-long-program-name", psargs: "prog"
+    };
+    let siginfo = SigInfo { signo: 6, errno: 0, code: -6,
+                            fields: SigFields::Kill { pid: 42, uid: 1000 } };
+    let notes: [CoreNoteRefs<'_, Elf32>; 2] = [
+        CoreNote::PrPsInfo(info), CoreNote::SigInfo(siginfo)
+    ];
+    let mut buf = [0; 512];
+    let core: Core<'_, LittleEndian, Elf32> =
+        Core::create(&mut buf[0..], ElfArch::AArch32, notes.iter(),
+                     [].iter()).expect("Expected success");
+    let mut iter = core.notes();
+    let note = iter.next().expect("Expected note");
+
+    assert_eq!(note.desc.len(), 124);
+
+    let note: CoreNoteRaw<'_, LittleEndian, Elf32> =
+        note.try_into().expect("Expected success");
+
+    assert_eq!(note, CoreNote::PrPsInfo(PrPsInfo { fname: "a-very-long-prog",
+                                                   ..info }));
+
+    let note: CoreNoteRaw<'_, LittleEndian, Elf32> =
+        iter.next().expect("Expected note").try_into()
+        .expect("Expected success");
+
+    assert_eq!(note, CoreNote::SigInfo(siginfo));
+    assert_eq!(core.mem_ranges().count(), 0);
+}
+
+#[test]
+fn test_Core_create_many_ranges() {
+    let mem = CoreMemData { addr: 0x1000, size: 0x1000, read: true,
+                            write: false, exec: false, data: &[] };
+    let mems = std::iter::repeat_n(mem, 0xffff);
+    let notes: [CoreNoteRefs<'_, Elf64>; 0] = [];
+    let size = required_bytes(notes.iter(), mems.clone());
+
+    assert_eq!(size, 64 + (0x10000 * 56) + 64);
+
+    let mut buf = vec![0; size];
+    let core: Core<'_, LittleEndian, Elf64> =
+        Core::create(&mut buf[0..], ElfArch::X86_64, notes.iter(), mems)
+        .expect("Expected success");
+
+    assert_eq!(core.prog_hdrs().num_hdrs(), 0x10000);
+    assert_eq!(core.mem_ranges().count(), 0xffff);
+    assert_eq!(LittleEndian::read_u16(&buf[56 .. 58]), 0xffff);
+}
+
+#[test]
+fn test_Core_create_too_small() {
+    const AUXV: [AuxvEnt<Elf64>; 1] = [AuxvEnt::Null];
+    let notes: [CoreNoteRefs<'_, Elf64>; 1] = [
+        CoreNote::Auxv { auxv: &AUXV }
+    ];
+    let mem = [CoreMemData { addr: 0x1000, size: 0x1000, read: true,
+                             write: false, exec: false, data: &[0; 8] }];
+    let size = required_bytes(notes.iter(), mem.iter());
+    let mut buf = vec![0; size - 1];
+    let core: Result<Core<'_, LittleEndian, Elf64>, ()> =
+        Core::create(&mut buf[0..], ElfArch::X86_64, notes.iter(),
+                     mem.iter());
+
+    assert!(core.is_err());
+}
@@ -5,7 +5,14 @@ use core::convert::TryInto;
 use elf_utils::Elf32;
 use elf_utils::dynamic::Dynamic;
 use elf_utils::dynamic::DynamicEntData;
+use elf_utils::dynamic::DynamicEntDataStr;
 use elf_utils::dynamic::DynamicError;
+use elf_utils::dynamic::DynamicFlag;
+use elf_utils::dynamic::DynamicFlag1;
+use elf_utils::dynamic::DynamicFlags;
+use elf_utils::dynamic::DynamicFlags1;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::WithStrtab;
 
 const ELF32_DYNAMIC_SIZE: usize = 136;
 
@@ -33,22 +40,22 @@ const ELF32_NUM_DYNAMIC_ENTS: usize = 17;
 
 const ELF32_DYNAMIC_ENTS: [DynamicEntData<u32, u32, Elf32>;
                            ELF32_NUM_DYNAMIC_ENTS] = [
-    DynamicEntData::Flags { flags: 0x2 },
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
     DynamicEntData::Rel { tab: 0x7f4 },
     DynamicEntData::RelSize { size: 1512 },
     DynamicEntData::RelEntSize { size: 8 },
-    DynamicEntData::Unknown { tag: 0x6ffffffa, info: 189 },
+    DynamicEntData::RelCount { count: 189 },
     DynamicEntData::Symtab { tab: 0x18c },
     DynamicEntData::SymtabEntSize { size: 16 },
     DynamicEntData::Strtab { tab: 0x620 },
     DynamicEntData::StrtabSize { size: 466 },
-    DynamicEntData::Unknown { tag: 0x6ffffef5, info: 0x470 },
+    DynamicEntData::GnuHash { tab: 0x470 },
     DynamicEntData::Hash { tab: 0x540 },
     DynamicEntData::FiniArray { arr: 0x1b3a8 },
     DynamicEntData::FiniArraySize { size: 4 },
-    DynamicEntData::Unknown { tag: 0x6ffffff0, info: 0x33c },
-    DynamicEntData::Unknown { tag: 0x6ffffffc, info: 0x374 },
-    DynamicEntData::Unknown { tag: 0x6ffffffd, info: 0x9 },
+    DynamicEntData::Versym { tab: 0x33c },
+    DynamicEntData::Verdef { tab: 0x374 },
+    DynamicEntData::VerdefNum { num: 0x9 },
     DynamicEntData::None
 ];
 
@@ -274,3 +281,205 @@ fn test_Dynamic_create_idx() {
 
     assert!(dynamic.idx(ELF32_DYNAMIC_ENTS.len()).is_none());
 }
+
+const ELF32_EXT_DYNAMIC_ENTS: [DynamicEntData<u32, u32, Elf32>; 19] = [
+    DynamicEntData::RPath { path: 1 },
+    DynamicEntData::RunPath { path: 7 },
+    DynamicEntData::BindNow,
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x109 } },
+    DynamicEntData::Flags1 {
+        flags: DynamicFlags1 { bits: 0x80000000 | 0x8000001 }
+    },
+    DynamicEntData::GnuHash { tab: 0x470 },
+    DynamicEntData::Versym { tab: 0x33c },
+    DynamicEntData::RelaCount { count: 188 },
+    DynamicEntData::RelCount { count: 189 },
+    DynamicEntData::Verdef { tab: 0x374 },
+    DynamicEntData::VerdefNum { num: 9 },
+    DynamicEntData::Verneed { tab: 0x3a0 },
+    DynamicEntData::VerneedNum { num: 2 },
+    DynamicEntData::Relr { tab: 0x5e8 },
+    DynamicEntData::RelrSize { size: 0x20 },
+    DynamicEntData::RelrEntSize { size: 8 },
+    DynamicEntData::SymtabIdx { idx: 4 },
+    DynamicEntData::Unknown { tag: 0x6ffffef4, info: 0x10 },
+    DynamicEntData::None
+];
+
+const ELF32_EXT_STRTAB: [u8; 15] = [
+    0x00, 0x2f, 0x6c, 0x69, 0x62, 0x00, 0x2f, 0x75,
+    0x73, 0x72, 0x2f, 0x6c, 0x69, 0x62, 0x00
+];
+
+#[test]
+fn test_Dynamic_create_ext_round_trip() {
+    let mut buf = [0; 8 * ELF32_EXT_DYNAMIC_ENTS.len()];
+    let dynamic: Dynamic<'_, LittleEndian, Elf32> =
+        Dynamic::create(&mut buf[0..], ELF32_EXT_DYNAMIC_ENTS.iter())
+        .expect("Expected success");
+
+    assert_eq!(dynamic.num_ents(), ELF32_EXT_DYNAMIC_ENTS.len());
+
+    for (ent, expect) in dynamic.iter().zip(ELF32_EXT_DYNAMIC_ENTS.iter()) {
+        let actual: DynamicEntData<u32, u32, Elf32> =
+            ent.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+}
+
+#[test]
+fn test_Dynamic_create_ext_tags() {
+    let mut buf = [0; 8 * ELF32_EXT_DYNAMIC_ENTS.len()];
+
+    Dynamic::<'_, LittleEndian, Elf32>::create(&mut buf[0..],
+                                             ELF32_EXT_DYNAMIC_ENTS.iter())
+        .expect("Expected success");
+
+    let tags: Vec<u32> = buf.chunks(8)
+                           .map(|ent| LittleEndian::read_u32(&ent[0 .. 4]))
+                           .collect();
+
+    assert_eq!(tags, [15, 29, 24, 30, 0x6ffffffb, 0x6ffffef5, 0x6ffffff0,
+                      0x6ffffff9, 0x6ffffffa, 0x6ffffffc, 0x6ffffffd,
+                      0x6ffffffe, 0x6fffffff, 36, 35, 37, 34, 0x6ffffef4, 0]);
+}
+
+// DT_RPATH (15), DT_TEXTREL (22), DT_BIND_NOW (24), DT_RUNPATH (29)
+// and DT_FLAGS (30), whose value is held in d_val.
+const ELF32_TAG_DYNAMIC: [u8; 48] = [
+    0x0f, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1d, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x1e, 0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF32_TAG_DYNAMIC_ENTS: [DynamicEntData<u32, u32, Elf32>; 6] = [
+    DynamicEntData::RPath { path: 1 },
+    DynamicEntData::TextRel,
+    DynamicEntData::BindNow,
+    DynamicEntData::RunPath { path: 7 },
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x9 } },
+    DynamicEntData::None
+];
+
+#[test]
+fn test_Dynamic_from_bytes_tags() {
+    let dynamic: Dynamic<'_, LittleEndian, Elf32> =
+        Dynamic::try_from(&ELF32_TAG_DYNAMIC[0..]).expect("Expected success");
+
+    for (ent, expect) in dynamic.iter().zip(ELF32_TAG_DYNAMIC_ENTS.iter()) {
+        let actual: DynamicEntData<u32, u32, Elf32> =
+            ent.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+}
+
+#[test]
+fn test_Dynamic_create_tags() {
+    let mut buf = [0; 48];
+
+    Dynamic::<'_, LittleEndian, Elf32>::create(&mut buf[0..],
+                                             ELF32_TAG_DYNAMIC_ENTS.iter())
+        .expect("Expected success");
+
+    let tags: Vec<u32> = buf.chunks(8)
+                           .map(|ent| LittleEndian::read_u32(&ent[0 .. 4]))
+                           .collect();
+    let flags = LittleEndian::read_u32(&buf[4 * 8 + 4 .. 5 * 8]);
+
+    assert_eq!(tags, [15, 22, 24, 29, 30, 0]);
+    assert_eq!(flags, 0x9);
+    assert_eq!(buf, ELF32_TAG_DYNAMIC);
+}
+
+#[test]
+fn test_Dynamic_with_strtab_runpath() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF32_EXT_STRTAB[0..]).expect("Expected success");
+    let rpath: DynamicEntData<u32, u32, Elf32> =
+        DynamicEntData::RPath { path: 1 };
+    let runpath: DynamicEntData<u32, u32, Elf32> =
+        DynamicEntData::RunPath { path: 6 };
+    let rpath: DynamicEntDataStr<'_, Elf32> =
+        rpath.with_strtab(strtab).expect("Expected success")
+             .try_into().expect("Expected success");
+    let runpath: DynamicEntDataStr<'_, Elf32> =
+        runpath.with_strtab(strtab).expect("Expected success")
+               .try_into().expect("Expected success");
+
+    assert_eq!(rpath, DynamicEntData::RPath { path: "/lib" });
+    assert_eq!(runpath, DynamicEntData::RunPath { path: "/usr/lib" });
+}
+
+#[test]
+fn test_Dynamic_with_strtab_runpath_bad_idx() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF32_EXT_STRTAB[0..]).expect("Expected success");
+    let runpath: DynamicEntData<u32, u32, Elf32> =
+        DynamicEntData::RunPath { path: 20 };
+
+    assert_eq!(runpath.with_strtab(strtab), Err(20));
+}
+
+#[test]
+fn test_DynamicFlags_contains() {
+    let flags: DynamicFlags<Elf32> = DynamicFlags { bits: 0x109 };
+
+    assert!(flags.contains(DynamicFlag::Origin));
+    assert!(!flags.contains(DynamicFlag::Symbolic));
+    assert!(!flags.contains(DynamicFlag::TextRel));
+    assert!(flags.contains(DynamicFlag::BindNow));
+    assert!(!flags.contains(DynamicFlag::StaticTLS));
+    assert_eq!(flags.unknown(), 0x100);
+}
+
+#[test]
+fn test_DynamicFlags_insert_remove() {
+    let mut flags: DynamicFlags<Elf32> = DynamicFlags { bits: 0x100 };
+
+    flags.insert(DynamicFlag::StaticTLS);
+    flags.insert(DynamicFlag::Symbolic);
+
+    assert_eq!(flags.bits, 0x112);
+
+    flags.remove(DynamicFlag::Symbolic);
+    flags.remove(DynamicFlag::Origin);
+
+    assert_eq!(flags.bits, 0x110);
+}
+
+#[test]
+fn test_DynamicFlags1_contains() {
+    let flags: DynamicFlags1<Elf32> =
+        DynamicFlags1 { bits: 0x80000000 | 0x8000001 };
+
+    assert!(flags.contains(DynamicFlag1::Now));
+    assert!(flags.contains(DynamicFlag1::PIE));
+    assert!(!flags.contains(DynamicFlag1::NoDelete));
+    assert_eq!(flags.unknown(), 0x80000000);
+}
+
+#[test]
+fn test_DynamicFlags1_mask() {
+    assert_eq!(DynamicFlag1::Now.mask(), 0x1);
+    assert_eq!(DynamicFlag1::NoDelete.mask(), 0x8);
+    assert_eq!(DynamicFlag1::Origin.mask(), 0x80);
+    assert_eq!(DynamicFlag1::NoDefaultLib.mask(), 0x800);
+    assert_eq!(DynamicFlag1::NoDirect.mask(), 0x20000);
+    assert_eq!(DynamicFlag1::PIE.mask(), 0x8000000);
+}
+
+#[test]
+fn test_DynamicFlags_display() {
+    let none: DynamicFlags<Elf32> = DynamicFlags { bits: 0 };
+    let flags: DynamicFlags<Elf32> = DynamicFlags { bits: 0x10a };
+    let flags1: DynamicFlags1<Elf32> = DynamicFlags1 { bits: 0x8000009 };
+
+    assert_eq!(format!("{}", none), "none");
+    assert_eq!(format!("{}", flags), "symbolic, bind now, 0x100");
+    assert_eq!(format!("{}", flags1), "now, no delete, PIE");
+}
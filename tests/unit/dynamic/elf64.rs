@@ -5,7 +5,15 @@ use core::convert::TryInto;
 use elf_utils::Elf64;
 use elf_utils::dynamic::Dynamic;
 use elf_utils::dynamic::DynamicEntData;
+use elf_utils::dynamic::DynamicEntDataStr;
 use elf_utils::dynamic::DynamicError;
+use elf_utils::dynamic::DynamicFlag;
+use elf_utils::dynamic::DynamicFlag1;
+use elf_utils::dynamic::DynamicFlags;
+use elf_utils::dynamic::DynamicFlags1;
+use elf_utils::dynamic::DynamicMut;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::WithStrtab;
 
 const ELF64_DYNAMIC_SIZE: usize = 272;
 
@@ -50,22 +58,22 @@ const ELF64_NUM_DYNAMIC_ENTS: usize = 17;
 
 const ELF64_DYNAMIC_ENTS: [DynamicEntData<u64, u64, Elf64>;
                            ELF64_NUM_DYNAMIC_ENTS] = [
-    DynamicEntData::Flags { flags: 0x2 },
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x2 } },
     DynamicEntData::Rela { tab: 0x990 },
     DynamicEntData::RelaSize { size: 4512 },
     DynamicEntData::RelaEntSize { size: 24 },
-    DynamicEntData::Unknown { tag: 0x6ffffff9, info: 188 },
+    DynamicEntData::RelaCount { count: 188 },
     DynamicEntData::Symtab { tab: 0x288 },
     DynamicEntData::SymtabEntSize { size: 24 },
     DynamicEntData::Strtab { tab: 0x7cc },
     DynamicEntData::StrtabSize { size: 448 },
-    DynamicEntData::Unknown { tag: 0x6ffffef5, info: 0x628 },
+    DynamicEntData::GnuHash { tab: 0x628 },
     DynamicEntData::Hash { tab: 0x6f4 },
     DynamicEntData::FiniArray { arr: 0x1d018 },
     DynamicEntData::FiniArraySize { size: 8 },
-    DynamicEntData::Unknown { tag: 0x6ffffff0, info: 0x4f8 },
-    DynamicEntData::Unknown { tag: 0x6ffffffc, info: 0x52c },
-    DynamicEntData::Unknown { tag: 0x6ffffffd, info: 0x9 },
+    DynamicEntData::Versym { tab: 0x4f8 },
+    DynamicEntData::Verdef { tab: 0x52c },
+    DynamicEntData::VerdefNum { num: 0x9 },
     DynamicEntData::None
 ];
 
@@ -286,3 +294,253 @@ fn test_Dynamic_create_idx() {
 
     assert!(dynamic.idx(ELF64_DYNAMIC_ENTS.len()).is_none());
 }
+
+const ELF64_EXT_DYNAMIC_ENTS: [DynamicEntData<u64, u64, Elf64>; 19] = [
+    DynamicEntData::RPath { path: 1 },
+    DynamicEntData::RunPath { path: 7 },
+    DynamicEntData::BindNow,
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x109 } },
+    DynamicEntData::Flags1 {
+        flags: DynamicFlags1 { bits: 0x100000000 | 0x8000001 }
+    },
+    DynamicEntData::GnuHash { tab: 0x470 },
+    DynamicEntData::Versym { tab: 0x33c },
+    DynamicEntData::RelaCount { count: 188 },
+    DynamicEntData::RelCount { count: 189 },
+    DynamicEntData::Verdef { tab: 0x374 },
+    DynamicEntData::VerdefNum { num: 9 },
+    DynamicEntData::Verneed { tab: 0x3a0 },
+    DynamicEntData::VerneedNum { num: 2 },
+    DynamicEntData::Relr { tab: 0x5e8 },
+    DynamicEntData::RelrSize { size: 0x20 },
+    DynamicEntData::RelrEntSize { size: 8 },
+    DynamicEntData::SymtabIdx { idx: 4 },
+    DynamicEntData::Unknown { tag: 0x6ffffef4, info: 0x10 },
+    DynamicEntData::None
+];
+
+const ELF64_EXT_STRTAB: [u8; 15] = [
+    0x00, 0x2f, 0x6c, 0x69, 0x62, 0x00, 0x2f, 0x75,
+    0x73, 0x72, 0x2f, 0x6c, 0x69, 0x62, 0x00
+];
+
+#[test]
+fn test_Dynamic_create_ext_round_trip() {
+    let mut buf = [0; 16 * ELF64_EXT_DYNAMIC_ENTS.len()];
+    let dynamic: Dynamic<'_, LittleEndian, Elf64> =
+        Dynamic::create(&mut buf[0..], ELF64_EXT_DYNAMIC_ENTS.iter())
+        .expect("Expected success");
+
+    assert_eq!(dynamic.num_ents(), ELF64_EXT_DYNAMIC_ENTS.len());
+
+    for (ent, expect) in dynamic.iter().zip(ELF64_EXT_DYNAMIC_ENTS.iter()) {
+        let actual: DynamicEntData<u64, u64, Elf64> =
+            ent.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+}
+
+#[test]
+fn test_Dynamic_create_ext_tags() {
+    let mut buf = [0; 16 * ELF64_EXT_DYNAMIC_ENTS.len()];
+
+    Dynamic::<'_, LittleEndian, Elf64>::create(&mut buf[0..],
+                                             ELF64_EXT_DYNAMIC_ENTS.iter())
+        .expect("Expected success");
+
+    let tags: Vec<u64> = buf.chunks(16)
+                           .map(|ent| LittleEndian::read_u64(&ent[0 .. 8]))
+                           .collect();
+
+    assert_eq!(tags, [15, 29, 24, 30, 0x6ffffffb, 0x6ffffef5, 0x6ffffff0,
+                      0x6ffffff9, 0x6ffffffa, 0x6ffffffc, 0x6ffffffd,
+                      0x6ffffffe, 0x6fffffff, 36, 35, 37, 34, 0x6ffffef4, 0]);
+}
+
+// DT_RPATH (15), DT_TEXTREL (22), DT_BIND_NOW (24), DT_RUNPATH (29)
+// and DT_FLAGS (30), whose value is held in d_val.
+const ELF64_TAG_DYNAMIC: [u8; 96] = [
+    0x0f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x16, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1e, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF64_TAG_DYNAMIC_ENTS: [DynamicEntData<u64, u64, Elf64>; 6] = [
+    DynamicEntData::RPath { path: 1 },
+    DynamicEntData::TextRel,
+    DynamicEntData::BindNow,
+    DynamicEntData::RunPath { path: 7 },
+    DynamicEntData::Flags { flags: DynamicFlags { bits: 0x9 } },
+    DynamicEntData::None
+];
+
+#[test]
+fn test_Dynamic_from_bytes_tags() {
+    let dynamic: Dynamic<'_, LittleEndian, Elf64> =
+        Dynamic::try_from(&ELF64_TAG_DYNAMIC[0..]).expect("Expected success");
+
+    for (ent, expect) in dynamic.iter().zip(ELF64_TAG_DYNAMIC_ENTS.iter()) {
+        let actual: DynamicEntData<u64, u64, Elf64> =
+            ent.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+}
+
+#[test]
+fn test_Dynamic_create_tags() {
+    let mut buf = [0; 96];
+
+    Dynamic::<'_, LittleEndian, Elf64>::create(&mut buf[0..],
+                                             ELF64_TAG_DYNAMIC_ENTS.iter())
+        .expect("Expected success");
+
+    let tags: Vec<u64> = buf.chunks(16)
+                           .map(|ent| LittleEndian::read_u64(&ent[0 .. 8]))
+                           .collect();
+    let flags = LittleEndian::read_u64(&buf[4 * 16 + 8 .. 5 * 16]);
+
+    assert_eq!(tags, [15, 22, 24, 29, 30, 0]);
+    assert_eq!(flags, 0x9);
+    assert_eq!(buf, ELF64_TAG_DYNAMIC);
+}
+
+#[test]
+fn test_Dynamic_with_strtab_runpath() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF64_EXT_STRTAB[0..]).expect("Expected success");
+    let rpath: DynamicEntData<u64, u64, Elf64> =
+        DynamicEntData::RPath { path: 1 };
+    let runpath: DynamicEntData<u64, u64, Elf64> =
+        DynamicEntData::RunPath { path: 6 };
+    let rpath: DynamicEntDataStr<'_, Elf64> =
+        rpath.with_strtab(strtab).expect("Expected success")
+             .try_into().expect("Expected success");
+    let runpath: DynamicEntDataStr<'_, Elf64> =
+        runpath.with_strtab(strtab).expect("Expected success")
+               .try_into().expect("Expected success");
+
+    assert_eq!(rpath, DynamicEntData::RPath { path: "/lib" });
+    assert_eq!(runpath, DynamicEntData::RunPath { path: "/usr/lib" });
+}
+
+#[test]
+fn test_Dynamic_with_strtab_runpath_bad_idx() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF64_EXT_STRTAB[0..]).expect("Expected success");
+    let runpath: DynamicEntData<u64, u64, Elf64> =
+        DynamicEntData::RunPath { path: 20 };
+
+    assert_eq!(runpath.with_strtab(strtab), Err(20));
+}
+
+#[test]
+fn test_DynamicFlags_contains() {
+    let flags: DynamicFlags<Elf64> = DynamicFlags { bits: 0x109 };
+
+    assert!(flags.contains(DynamicFlag::Origin));
+    assert!(!flags.contains(DynamicFlag::Symbolic));
+    assert!(!flags.contains(DynamicFlag::TextRel));
+    assert!(flags.contains(DynamicFlag::BindNow));
+    assert!(!flags.contains(DynamicFlag::StaticTLS));
+    assert_eq!(flags.unknown(), 0x100);
+}
+
+#[test]
+fn test_DynamicFlags_insert_remove() {
+    let mut flags: DynamicFlags<Elf64> = DynamicFlags { bits: 0x100 };
+
+    flags.insert(DynamicFlag::StaticTLS);
+    flags.insert(DynamicFlag::Symbolic);
+
+    assert_eq!(flags.bits, 0x112);
+
+    flags.remove(DynamicFlag::Symbolic);
+    flags.remove(DynamicFlag::Origin);
+
+    assert_eq!(flags.bits, 0x110);
+}
+
+#[test]
+fn test_DynamicFlags1_contains() {
+    let flags: DynamicFlags1<Elf64> =
+        DynamicFlags1 { bits: 0x100000000 | 0x8000001 };
+
+    assert!(flags.contains(DynamicFlag1::Now));
+    assert!(flags.contains(DynamicFlag1::PIE));
+    assert!(!flags.contains(DynamicFlag1::NoDelete));
+    assert_eq!(flags.unknown(), 0x100000000);
+}
+
+#[test]
+fn test_DynamicFlags1_mask() {
+    assert_eq!(DynamicFlag1::Now.mask(), 0x1);
+    assert_eq!(DynamicFlag1::NoDelete.mask(), 0x8);
+    assert_eq!(DynamicFlag1::Origin.mask(), 0x80);
+    assert_eq!(DynamicFlag1::NoDefaultLib.mask(), 0x800);
+    assert_eq!(DynamicFlag1::NoDirect.mask(), 0x20000);
+    assert_eq!(DynamicFlag1::PIE.mask(), 0x8000000);
+}
+
+#[test]
+fn test_DynamicFlags_display() {
+    let none: DynamicFlags<Elf64> = DynamicFlags { bits: 0 };
+    let flags: DynamicFlags<Elf64> = DynamicFlags { bits: 0x10a };
+    let flags1: DynamicFlags1<Elf64> = DynamicFlags1 { bits: 0x8000009 };
+
+    assert_eq!(format!("{}", none), "none");
+    assert_eq!(format!("{}", flags), "symbolic, bind now, 0x100");
+    assert_eq!(format!("{}", flags1), "now, no delete, PIE");
+}
+
+#[test]
+fn test_DynamicMut_set_round_trip() {
+    let mut buf = [0; ELF64_DYNAMIC_SIZE];
+    let mut dynamic: DynamicMut<'_, LittleEndian, Elf64> =
+        DynamicMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+
+    assert_eq!(dynamic.num_ents(), ELF64_NUM_DYNAMIC_ENTS);
+
+    for i in 0 .. ELF64_DYNAMIC_ENTS.len() {
+        let ent = dynamic.idx_mut(i);
+
+        assert!(ent.is_some());
+
+        ent.unwrap().set(&ELF64_DYNAMIC_ENTS[i]);
+    }
+
+    assert!(dynamic.idx_mut(ELF64_NUM_DYNAMIC_ENTS).is_none());
+    assert_eq!(&buf[0..], &ELF64_DYNAMIC[0..]);
+}
+
+#[test]
+fn test_DynamicMut_set_tag_val() {
+    let mut buf = ELF64_DYNAMIC.clone();
+    let mut dynamic: DynamicMut<'_, LittleEndian, Elf64> =
+        DynamicMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+    let mut ent = dynamic.idx_mut(0).expect("Expected success");
+
+    ent.set_tag(0x1d);
+    ent.set_val(0x21);
+
+    let data = ent.try_into();
+
+    assert!(data.is_ok());
+
+    let actual: DynamicEntData<u64, u64, Elf64> = data.unwrap();
+
+    assert_eq!(DynamicEntData::RunPath { path: 0x21 }, actual);
+    assert_eq!(LittleEndian::read_u64(&buf[0..8]), 0x1d);
+}
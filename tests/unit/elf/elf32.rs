@@ -107,7 +107,7 @@ const ELF32_DYNAMIC_ELF_HDR_BAD_CLASS: [u8; 52] = [
 
 const ELF32_DYNAMIC_HEADER_DATA: ElfHdrData<LittleEndian, Elf32,
                                             ElfTable<Elf32>,
-                                            ElfTable<Elf32>, u16> =
+                                            ElfTable<Elf32>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Dynamic, arch: ElfArch::I386,
@@ -118,7 +118,7 @@ const ELF32_DYNAMIC_HEADER_DATA: ElfHdrData<LittleEndian, Elf32,
 
 const ELF32_REL_HEADER_DATA: ElfHdrData<LittleEndian, Elf32,
                                         ElfTable<Elf32>,
-                                        ElfTable<Elf32>, u16> =
+                                        ElfTable<Elf32>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Relocatable, arch: ElfArch::I386,
@@ -155,7 +155,7 @@ fn test_ElfHdr_from_rel_hdr() {
     };
 
     let hdr: ElfHdrData<LittleEndian, Elf32, ElfTable<Elf32>,
-                        ElfTable<Elf32>, u16> =
+                        ElfTable<Elf32>, u32> =
         hdr.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF32_REL_HEADER_DATA);
@@ -174,7 +174,7 @@ fn test_ElfHdr_from_dynamic_hdr() {
     };
 
     let hdr: ElfHdrData<LittleEndian, Elf32, ElfTable<Elf32>,
-                        ElfTable<Elf32>, u16> =
+                        ElfTable<Elf32>, u32> =
         hdr.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF32_DYNAMIC_HEADER_DATA);
@@ -226,7 +226,7 @@ fn test_ElfHdr_create_rel_hdr() {
     let (elf, _) = res.unwrap();
 
     let hdr: ElfHdrData<LittleEndian, Elf32, ElfTable<Elf32>,
-                        ElfTable<Elf32>, u16> =
+                        ElfTable<Elf32>, u32> =
         elf.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF32_REL_HEADER_DATA);
@@ -243,7 +243,7 @@ fn test_ElfHdr_create_dynamic_hdr() {
     let (elf, _) = res.unwrap();
 
     let hdr: ElfHdrData<LittleEndian, Elf32, ElfTable<Elf32>,
-                        ElfTable<Elf32>, u16> =
+                        ElfTable<Elf32>, u32> =
         elf.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF32_DYNAMIC_HEADER_DATA);
@@ -14,6 +14,9 @@ use elf_utils::ElfKind;
 use elf_utils::ElfMut;
 use elf_utils::ElfMux;
 use elf_utils::ElfTable;
+use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionHdrDataRaw;
+use elf_utils::section_hdr::SectionHdrs;
 
 const ELF64_EXEC_ELF_HDR: [u8; 64] = [
     0x7f, 0x45, 0x4c, 0x46, 0x02, 0x01, 0x01, 0x09,
@@ -127,7 +130,7 @@ const ELF64_EXEC_ELF_HDR_BAD_CLASS: [u8; 64] = [
 
 const ELF64_EXEC_HEADER_DATA: ElfHdrData<LittleEndian, Elf64,
                                          ElfTable<Elf64>,
-                                         ElfTable<Elf64>, u16> =
+                                         ElfTable<Elf64>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Executable, arch: ElfArch::X86_64,
@@ -138,7 +141,7 @@ const ELF64_EXEC_HEADER_DATA: ElfHdrData<LittleEndian, Elf64,
 
 const ELF64_DYNAMIC_HEADER_DATA: ElfHdrData<LittleEndian, Elf64,
                                             ElfTable<Elf64>,
-                                            ElfTable<Elf64>, u16> =
+                                            ElfTable<Elf64>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Dynamic, arch: ElfArch::X86_64,
@@ -149,7 +152,7 @@ const ELF64_DYNAMIC_HEADER_DATA: ElfHdrData<LittleEndian, Elf64,
 
 const ELF64_REL_HEADER_DATA: ElfHdrData<LittleEndian, Elf64,
                                         ElfTable<Elf64>,
-                                        ElfTable<Elf64>, u16> =
+                                        ElfTable<Elf64>, u32> =
     ElfHdrData {
         byteorder: PhantomData, abi: ElfABI::FreeBSD, abi_version: 0,
         kind: ElfKind::Relocatable, arch: ElfArch::X86_64,
@@ -197,7 +200,7 @@ fn test_ElfMux_from_exec_hdr() {
     };
 
     let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                        ElfTable<Elf64>, u16> =
+                        ElfTable<Elf64>, u32> =
         hdr.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF64_EXEC_HEADER_DATA);
@@ -219,7 +222,7 @@ fn test_ElfMux_from_rel_hdr() {
     };
 
     let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                        ElfTable<Elf64>, u16> =
+                        ElfTable<Elf64>, u32> =
         hdr.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF64_REL_HEADER_DATA);
@@ -241,7 +244,7 @@ fn test_ElfMux_from_dynamic_hdr() {
     };
 
     let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                        ElfTable<Elf64>, u16> =
+                        ElfTable<Elf64>, u32> =
         hdr.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF64_DYNAMIC_HEADER_DATA);
@@ -293,7 +296,7 @@ fn test_ElfMux_create_exec_hdr() {
     let (elf, _) = res.unwrap();
 
     let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                        ElfTable<Elf64>, u16> =
+                        ElfTable<Elf64>, u32> =
         elf.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF64_EXEC_HEADER_DATA);
@@ -310,7 +313,7 @@ fn test_ElfMux_create_rel_hdr() {
     let (elf, _) = res.unwrap();
 
     let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                        ElfTable<Elf64>, u16> =
+                        ElfTable<Elf64>, u32> =
         elf.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF64_REL_HEADER_DATA);
@@ -327,7 +330,7 @@ fn test_ElfMux_create_dynamic_hdr() {
     let (elf, _) = res.unwrap();
 
     let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
-                        ElfTable<Elf64>, u16> =
+                        ElfTable<Elf64>, u32> =
         elf.try_into().expect("expected success");
 
     assert_eq!(hdr, ELF64_DYNAMIC_HEADER_DATA);
@@ -876,3 +879,82 @@ fn test_ElfMut_from_hdr_bad_class() {
     assert!(elf.is_err());
     assert_eq!(elf.err(), Some(ElfError::BadClass(0x05)));
 }
+
+#[test]
+fn test_ElfHdr_create_extended_hdr() {
+    const HEADER_DATA: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
+                                  ElfTable<Elf64>, u32> = ElfHdrData {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64,
+        entry: 0, flags: 0, section_hdr_strtab: 0x10002, prog_hdrs: None,
+        section_hdrs: ElfTable { offset: 64, num_ents: 0x10003 }
+    };
+    const EXT_HDR: [SectionHdrDataRaw<Elf64>; 1] = [
+        SectionHdrData::ExtNull { num_section_hdrs: 0x10003,
+                                  section_hdr_strtab: 0x10002,
+                                  num_prog_hdrs: 0 }
+    ];
+    let mut buf: [u8; 128] = [0; 128];
+    let (_, rest) = Elf::<LittleEndian, Elf64>::create_split(&mut buf[0..],
+                                                             HEADER_DATA)
+        .expect("expected success");
+    let _: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create(rest, EXT_HDR.iter().map(|x| *x))
+        .expect("expected success");
+
+    assert_eq!(&buf[60..64], &[0x00, 0x00, 0xff, 0xff]);
+
+    let elf: Elf<'_, LittleEndian, Elf64> =
+        Elf::try_from(&buf[0..]).expect("expected success");
+    let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
+                        ElfTable<Elf64>, u32> =
+        elf.try_into().expect("expected success");
+
+    assert_eq!(hdr, HEADER_DATA);
+}
+
+#[test]
+fn test_ElfHdr_create_extended_hdr_round_trip() {
+    const NUM_HDRS: usize = 0xff01;
+    const HEADER_DATA: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
+                                  ElfTable<Elf64>, u32> = ElfHdrData {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64,
+        entry: 0, flags: 0, section_hdr_strtab: 0xff00, prog_hdrs: None,
+        section_hdrs: ElfTable { offset: 64, num_ents: NUM_HDRS as u32 }
+    };
+    let mut buf = vec![0; 64 + (NUM_HDRS * 64)];
+    let (_, rest) = Elf::<LittleEndian, Elf64>::create_split(&mut buf[0..],
+                                                             HEADER_DATA)
+        .expect("expected success");
+    let _: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create_ext(rest,
+                                (0 .. NUM_HDRS).map(|_| SectionHdrData::Null),
+                                0xff00, 0)
+        .expect("expected success");
+
+    assert_eq!(&buf[60..64], &[0x00, 0x00, 0xff, 0xff]);
+
+    let elf: Elf<'_, LittleEndian, Elf64> =
+        Elf::try_from(&buf[0..]).expect("expected success");
+    let hdr: ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
+                        ElfTable<Elf64>, u32> =
+        elf.try_into().expect("expected success");
+
+    assert_eq!(hdr, HEADER_DATA);
+}
+
+#[test]
+fn test_ElfHdr_from_extended_hdr_out_of_bounds() {
+    let mut buf: [u8; 64] = ELF64_REL_ELF_HDR;
+
+    buf[60] = 0;
+    buf[61] = 0;
+
+    let elf: Elf<'_, LittleEndian, Elf64> =
+        Elf::try_from(&buf[0..]).expect("expected success");
+    let hdr: Result<ElfHdrData<LittleEndian, Elf64, ElfTable<Elf64>,
+                               ElfTable<Elf64>, u32>, _> = elf.try_into();
+
+    assert!(matches!(hdr, Err(ElfHdrDataError::ExtHdrOutOfBounds(_))));
+}
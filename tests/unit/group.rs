@@ -0,0 +1,152 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use elf_utils::group;
+use elf_utils::group::Group;
+use elf_utils::group::GroupError;
+
+const ELF_GROUP_BYTES: usize = 12;
+
+const ELF_GROUP: [u8; ELF_GROUP_BYTES] = [
+    0x01, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x06, 0x00, 0x00, 0x00
+];
+
+const ELF_GROUP_MEMBERS: [u32; 2] = [ 5, 6 ];
+
+const ELF_GROUP_NOT_COMDAT: [u8; 8] = [
+    0x00, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00
+];
+
+#[test]
+fn test_Group_from_slice_ok() {
+    let group: Result<Group<'_, LittleEndian>, GroupError> =
+        Group::try_from(&ELF_GROUP[0..]);
+
+    assert!(group.is_ok());
+    assert_eq!(group.unwrap().num_members(), ELF_GROUP_MEMBERS.len());
+}
+
+#[test]
+fn test_Group_from_slice_too_short() {
+    let group: Result<Group<'_, LittleEndian>, GroupError> =
+        Group::try_from(&ELF_GROUP[0 .. 3]);
+
+    assert_eq!(group.err(), Some(GroupError::TooShort));
+}
+
+#[test]
+fn test_Group_from_slice_bad_size() {
+    let group: Result<Group<'_, LittleEndian>, GroupError> =
+        Group::try_from(&ELF_GROUP[0 .. ELF_GROUP_BYTES - 1]);
+
+    assert_eq!(group.err(), Some(GroupError::BadSize(11)));
+}
+
+#[test]
+fn test_Group_from_slice_empty() {
+    let group: Group<'_, LittleEndian> =
+        Group::try_from(&ELF_GROUP[0 .. 4]).expect("Expected success");
+
+    assert!(group.comdat());
+    assert_eq!(group.num_members(), 0);
+    assert!(group.iter().next().is_none());
+}
+
+#[test]
+fn test_Group_from_slice_flags() {
+    let group: Group<'_, LittleEndian> =
+        Group::try_from(&ELF_GROUP[0..]).expect("Expected success");
+
+    assert_eq!(group.flags(), group::GRP_COMDAT);
+    assert!(group.comdat());
+}
+
+#[test]
+fn test_Group_from_slice_not_comdat() {
+    let group: Group<'_, LittleEndian> =
+        Group::try_from(&ELF_GROUP_NOT_COMDAT[0..])
+        .expect("Expected success");
+
+    assert_eq!(group.flags(), 0);
+    assert!(!group.comdat());
+    assert_eq!(group.idx(0), Some(7));
+}
+
+#[test]
+fn test_Group_from_slice_iter_len() {
+    let group: Group<'_, LittleEndian> =
+        Group::try_from(&ELF_GROUP[0..]).expect("Expected success");
+
+    assert_eq!(group.iter().len(), ELF_GROUP_MEMBERS.len());
+}
+
+#[test]
+fn test_Group_from_slice_iter() {
+    let group: Group<'_, LittleEndian> =
+        Group::try_from(&ELF_GROUP[0..]).expect("Expected success");
+    let mut iter = group.iter();
+
+    for expected in ELF_GROUP_MEMBERS.iter() {
+        assert_eq!(iter.next(), Some(*expected));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Group_from_slice_idx() {
+    let group: Group<'_, LittleEndian> =
+        Group::try_from(&ELF_GROUP[0..]).expect("Expected success");
+
+    for i in 0 .. ELF_GROUP_MEMBERS.len() {
+        assert_eq!(group.idx(i), Some(ELF_GROUP_MEMBERS[i]));
+    }
+
+    assert!(group.idx(ELF_GROUP_MEMBERS.len()).is_none());
+}
+
+#[test]
+fn test_Group_create_just_right() {
+    let mut buf = [0; ELF_GROUP_BYTES];
+    let res: Result<(Group<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Group::create_split(&mut buf[0..], true, ELF_GROUP_MEMBERS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_GROUP);
+}
+
+#[test]
+fn test_Group_create_too_big() {
+    let mut buf = [0; ELF_GROUP_BYTES + 1];
+    let res: Result<(Group<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Group::create_split(&mut buf[0..], true, ELF_GROUP_MEMBERS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_Group_create_too_small() {
+    let mut buf = [0; ELF_GROUP_BYTES - 1];
+    let res: Result<Group<'_, LittleEndian>, ()> =
+        Group::create(&mut buf[0..], true, ELF_GROUP_MEMBERS.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_Group_create_no_flags_room() {
+    let mut buf = [0; 3];
+    let res: Result<Group<'_, LittleEndian>, ()> =
+        Group::create(&mut buf[0..], false, ELF_GROUP_MEMBERS[0..0].iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_Group_required_bytes() {
+    let size = group::required_bytes(ELF_GROUP_MEMBERS.iter());
+
+    assert_eq!(size, ELF_GROUP_BYTES);
+}
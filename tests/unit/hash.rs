@@ -2,12 +2,18 @@ use byteorder::LittleEndian;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use elf_utils::Elf64;
+use elf_utils::hash;
+use elf_utils::hash::ElfName;
+use elf_utils::hash::GnuHashtab;
+use elf_utils::hash::GnuHashtabMut;
+use elf_utils::hash::GnuHashtabError;
 use elf_utils::hash::Hashtab;
 use elf_utils::hash::HashtabMut;
 use elf_utils::hash::HashtabError;
 use elf_utils::strtab::Strtab;
 use elf_utils::strtab::WithStrtab;
 use elf_utils::symtab::SymData;
+use elf_utils::symtab::SymDataRaw;
 use elf_utils::symtab::Symtab;
 
 const ELF_SYMTAB_BYTES: usize = 384;
@@ -150,6 +156,82 @@ const ELF_HASH_BAD_HASHES: [u8; ELF_HASH_BYTES] = [
     0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00
 ];
 
+const ELF_GNU_SYMTAB: [u8; ELF_SYMTAB_BYTES] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x16, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x61, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x11, 0x00, 0x17, 0x00,
+    0x80, 0x3e, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6f, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x82, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2a, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x48, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x4c, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x53, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x74, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1d, 0x00, 0x00, 0x00, 0x11, 0x00, 0x19, 0x00,
+    0xf8, 0x3e, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x25, 0x00, 0x00, 0x00, 0x12, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3e, 0x00, 0x00, 0x00, 0x11, 0x00, 0x19, 0x00,
+    0x10, 0x3f, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x68, 0x00, 0x00, 0x00, 0x11, 0x00, 0x19, 0x00,
+    0x08, 0x3f, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_GNU_HASH_BYTES: usize = 124;
+
+const ELF_GNU_HASH_SYMOFFSET: usize = 1;
+
+const ELF_GNU_HASH_NBUCKETS: usize = 4;
+
+const ELF_GNU_HASH: [u8; ELF_GNU_HASH_BYTES] = [
+    0x04, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x42, 0x80, 0x00, 0x00, 0x08, 0xd0,
+    0x00, 0x04, 0x00, 0x00, 0x00, 0x04, 0x00, 0x81,
+    0x84, 0x06, 0x00, 0x24, 0xc0, 0x40, 0x00, 0x10,
+    0x00, 0x00, 0x40, 0x00, 0x81, 0x00, 0x10, 0x10,
+    0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x06, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00,
+    0xf4, 0xf2, 0x8f, 0xf2, 0x78, 0x8b, 0x87, 0xff,
+    0xbd, 0x50, 0x76, 0x9e, 0x10, 0x7b, 0x9c, 0x7c,
+    0x9d, 0xbb, 0x93, 0x1c, 0x8a, 0xbd, 0x27, 0x24,
+    0xd6, 0xd5, 0xd0, 0x9f, 0xae, 0x6f, 0x88, 0x0b,
+    0x16, 0xea, 0x76, 0xfe, 0x3e, 0xa8, 0x1e, 0xf0,
+    0x4a, 0x1a, 0x93, 0xa8, 0xa7, 0xdd, 0xa3, 0x6b,
+    0x3e, 0x7e, 0x96, 0x7c, 0xe6, 0x15, 0x03, 0x83,
+    0x33, 0xc4, 0xf7, 0x12
+];
+
 #[test]
 fn test_Hashtab_from_slice_ok() {
     let strtab: Strtab<'_> =
@@ -600,3 +682,688 @@ fn test_HashtabMut_create_lookup_syms() {
         }
     }
 }
+
+#[test]
+fn test_GnuHashtab_from_slice_ok() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0..], strtab, symtab);
+
+    assert!(hash.is_ok());
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_too_short_15() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0..15], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::TooShort));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_too_short() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0 .. ELF_GNU_HASH_BYTES - 1],
+                               strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::TooShort));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_bad_buckets() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[0] = 0;
+
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadBuckets));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_bad_bloom_size() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[8] = 3;
+
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadBloom));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_bad_bloom_shift() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[12] = 32;
+
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadBloom));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_bad_symoffset() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[4] = 0x11;
+
+    let hash: Result<GnuHashtab<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtab::from_slice(&data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadSymOffset {
+        nsyms: 0x10, symoffset: 0x11
+    }));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_lookup_syms() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: GnuHashtab<'_, LittleEndian, Elf64> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0..], strtab, symtab)
+        .expect("Expected success");
+
+    for sym in symtab.iter() {
+        let expected: SymData<u32, u16, Elf64> = sym.try_into().unwrap();
+        let named: SymData<Result<&'static str, &'static [u8]>, u16, Elf64> =
+            expected.clone().with_strtab(strtab).unwrap();
+
+        match named.name {
+            Some(name) => {
+                let lookup = hash.lookup(name);
+
+                assert!(lookup.is_ok());
+
+                let res = lookup.unwrap();
+
+                assert!(res.is_some());
+
+                let actual: SymData<u32, u16, Elf64> =
+                    res.unwrap().try_into().unwrap();
+
+                assert_eq!(actual, expected);
+            },
+            _ => {}
+        }
+    }
+}
+
+/// A name type that only implements the required `ElfName` methods,
+/// as an implementation predating GNU hash tables would.
+struct OpaqueName<'a>(&'a str);
+
+impl<'a> ElfName for OpaqueName<'a> {
+    fn hash_name(&self) -> u32 {
+        self.0.hash_name()
+    }
+
+    fn cmp_name(&self, other: &Result<&str, &[u8]>) -> bool {
+        self.0.cmp_name(other)
+    }
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_lookup_opaque_names() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: GnuHashtab<'_, LittleEndian, Elf64> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0..], strtab, symtab)
+        .expect("Expected success");
+
+    for sym in symtab.iter().skip(hash.symoffset()) {
+        let expected: SymData<u32, u16, Elf64> = sym.try_into().unwrap();
+        let named: SymData<Result<&'static str, &'static [u8]>, u16, Elf64> =
+            expected.clone().with_strtab(strtab).unwrap();
+
+        match named.name {
+            Some(Ok(name)) => {
+                let res = hash.lookup(OpaqueName(name))
+                    .expect("Expected success");
+                let actual: SymData<u32, u16, Elf64> =
+                    res.expect("Expected some").try_into().unwrap();
+
+                assert_eq!(actual, expected);
+            },
+            _ => {}
+        }
+    }
+
+    let res = hash.lookup(OpaqueName("SIR NOT APPEARING IN THIS OBJECT"));
+
+    assert_eq!(res.map(|sym| sym.is_none()), Ok(true));
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_bad_lookup() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: GnuHashtab<'_, LittleEndian, Elf64> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0..], strtab, symtab)
+        .expect("Expected success");
+
+    let lookup = hash.lookup("SIR NOT APPEARING IN THIS OBJECT");
+
+    assert!(lookup.is_ok());
+
+    let res = lookup.unwrap();
+
+    assert!(res.is_none());
+}
+
+#[test]
+fn test_GnuHashtab_from_slice_empty_str_lookup() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let hash: GnuHashtab<'_, LittleEndian, Elf64> =
+        GnuHashtab::from_slice(&ELF_GNU_HASH[0..], strtab, symtab)
+        .expect("Expected success");
+
+    let lookup = hash.lookup("");
+
+    assert!(lookup.is_ok());
+
+    let res = lookup.unwrap();
+
+    assert!(res.is_none());
+}
+
+#[test]
+fn test_GnuHashtab_create_is_ok() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let result: Result<(GnuHashtab<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtab::create_split(&mut buf[0..], strtab, symtab,
+                                 ELF_GNU_HASH_SYMOFFSET,
+                                 ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_ok());
+
+    let (_, rest) = result.unwrap();
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_GNU_HASH);
+}
+
+#[test]
+fn test_GnuHashtab_create_too_small() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES - 1];
+    let result: Result<(GnuHashtab<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtab::create_split(&mut buf[0..], strtab, symtab,
+                                 ELF_GNU_HASH_SYMOFFSET,
+                                 ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_GnuHashtab_create_too_big() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES + 1];
+    let result: Result<(GnuHashtab<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtab::create_split(&mut buf[0..], strtab, symtab,
+                                 ELF_GNU_HASH_SYMOFFSET,
+                                 ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_ok());
+
+    let (_, rest) = result.unwrap();
+
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_GnuHashtab_create_unsorted() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let result: Result<(GnuHashtab<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtab::create_split(&mut buf[0..], strtab, symtab,
+                                 ELF_GNU_HASH_SYMOFFSET,
+                                 ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_GnuHashtab_create_lookup_syms() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let hash: GnuHashtab<'_, LittleEndian, Elf64> =
+        GnuHashtab::create(&mut buf[0..], strtab, symtab,
+                           ELF_GNU_HASH_SYMOFFSET,
+                           ELF_GNU_HASH_NBUCKETS).unwrap();
+
+    for sym in symtab.iter() {
+        let expected: SymData<u32, u16, Elf64> = sym.try_into().unwrap();
+        let named: SymData<Result<&'static str, &'static [u8]>, u16, Elf64> =
+            expected.clone().with_strtab(strtab).unwrap();
+
+        match named.name {
+            Some(name) => {
+                let lookup = hash.lookup(name);
+
+                assert!(lookup.is_ok());
+
+                let res = lookup.unwrap();
+
+                assert!(res.is_some());
+
+                let actual: SymData<u32, u16, Elf64> =
+                    res.unwrap().try_into().unwrap();
+
+                assert_eq!(actual, expected);
+            },
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_ok() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab);
+
+    assert!(hash.is_ok());
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_too_short_15() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0..15], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::TooShort));
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_too_short() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0 .. ELF_GNU_HASH_BYTES - 1],
+                                  strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::TooShort));
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_bad_buckets() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[0] = 0;
+
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadBuckets));
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_bad_bloom_size() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[8] = 3;
+
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadBloom));
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_bad_bloom_shift() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[12] = 32;
+
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadBloom));
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_bad_symoffset() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+
+    data[4] = 0x11;
+
+    let hash: Result<GnuHashtabMut<'_, LittleEndian, Elf64>, GnuHashtabError> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab);
+
+    assert_eq!(hash.err(), Some(GnuHashtabError::BadSymOffset {
+        nsyms: 0x10, symoffset: 0x11
+    }));
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_lookup_syms() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+    let hash: GnuHashtabMut<'_, LittleEndian, Elf64> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab)
+        .expect("Expected success");
+
+    for sym in symtab.iter() {
+        let expected: SymData<u32, u16, Elf64> = sym.try_into().unwrap();
+        let named: SymData<Result<&'static str, &'static [u8]>, u16, Elf64> =
+            expected.clone().with_strtab(strtab).unwrap();
+
+        match named.name {
+            Some(name) => {
+                let lookup = hash.lookup(name);
+
+                assert!(lookup.is_ok());
+
+                let res = lookup.unwrap();
+
+                assert!(res.is_some());
+
+                let actual: SymData<u32, u16, Elf64> =
+                    res.unwrap().try_into().unwrap();
+
+                assert_eq!(actual, expected);
+            },
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_bad_lookup() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+    let hash: GnuHashtabMut<'_, LittleEndian, Elf64> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab)
+        .expect("Expected success");
+
+    let lookup = hash.lookup("SIR NOT APPEARING IN THIS OBJECT");
+
+    assert!(lookup.is_ok());
+
+    let res = lookup.unwrap();
+
+    assert!(res.is_none());
+}
+
+#[test]
+fn test_GnuHashtabMut_from_slice_empty_str_lookup() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut data = ELF_GNU_HASH;
+    let hash: GnuHashtabMut<'_, LittleEndian, Elf64> =
+        GnuHashtabMut::from_slice(&mut data[0..], strtab, symtab)
+        .expect("Expected success");
+
+    let lookup = hash.lookup("");
+
+    assert!(lookup.is_ok());
+
+    let res = lookup.unwrap();
+
+    assert!(res.is_none());
+}
+
+#[test]
+fn test_GnuHashtabMut_create_is_ok() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let result: Result<(GnuHashtabMut<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtabMut::create_split(&mut buf[0..], strtab, symtab,
+                                    ELF_GNU_HASH_SYMOFFSET,
+                                    ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_ok());
+
+    let (_, rest) = result.unwrap();
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_GNU_HASH);
+}
+
+#[test]
+fn test_GnuHashtabMut_create_too_small() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES - 1];
+    let result: Result<(GnuHashtabMut<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtabMut::create_split(&mut buf[0..], strtab, symtab,
+                                    ELF_GNU_HASH_SYMOFFSET,
+                                    ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_GnuHashtabMut_create_too_big() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES + 1];
+    let result: Result<(GnuHashtabMut<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtabMut::create_split(&mut buf[0..], strtab, symtab,
+                                    ELF_GNU_HASH_SYMOFFSET,
+                                    ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_ok());
+
+    let (_, rest) = result.unwrap();
+
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_GnuHashtabMut_create_unsorted() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let result: Result<(GnuHashtabMut<'_, LittleEndian, Elf64>,
+                        &'_ mut [u8]), ()> =
+        GnuHashtabMut::create_split(&mut buf[0..], strtab, symtab,
+                                    ELF_GNU_HASH_SYMOFFSET,
+                                    ELF_GNU_HASH_NBUCKETS);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_GnuHashtabMut_create_lookup_syms() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_GNU_SYMTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let hash: GnuHashtabMut<'_, LittleEndian, Elf64> =
+        GnuHashtabMut::create(&mut buf[0..], strtab, symtab,
+                              ELF_GNU_HASH_SYMOFFSET,
+                              ELF_GNU_HASH_NBUCKETS).unwrap();
+
+    for sym in symtab.iter() {
+        let expected: SymData<u32, u16, Elf64> = sym.try_into().unwrap();
+        let named: SymData<Result<&'static str, &'static [u8]>, u16, Elf64> =
+            expected.clone().with_strtab(strtab).unwrap();
+
+        match named.name {
+            Some(name) => {
+                let lookup = hash.lookup(name);
+
+                assert!(lookup.is_ok());
+
+                let res = lookup.unwrap();
+
+                assert!(res.is_some());
+
+                let actual: SymData<u32, u16, Elf64> =
+                    res.unwrap().try_into().unwrap();
+
+                assert_eq!(actual, expected);
+            },
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_sort_gnu_syms() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SYMTAB[0..]).expect("Expected success");
+    let mut syms: Vec<SymDataRaw<Elf64>> =
+        symtab.iter().map(|sym| sym.try_into().unwrap()).collect();
+
+    assert!(hash::sort_gnu_syms(&mut syms[0..], strtab,
+                                ELF_GNU_HASH_SYMOFFSET,
+                                ELF_GNU_HASH_NBUCKETS).is_ok());
+
+    let mut symbuf = [0; ELF_SYMTAB_BYTES];
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::create(&mut symbuf[0..], syms.iter()).unwrap();
+    let mut buf = [0; ELF_GNU_HASH_BYTES];
+    let hash: GnuHashtab<'_, LittleEndian, Elf64> =
+        GnuHashtab::create(&mut buf[0..], strtab, symtab,
+                           ELF_GNU_HASH_SYMOFFSET,
+                           ELF_GNU_HASH_NBUCKETS).unwrap();
+
+    for sym in symtab.iter() {
+        let expected: SymData<u32, u16, Elf64> = sym.try_into().unwrap();
+        let named: SymData<Result<&'static str, &'static [u8]>, u16, Elf64> =
+            expected.clone().with_strtab(strtab).unwrap();
+
+        match named.name {
+            Some(name) => {
+                let lookup = hash.lookup(name);
+
+                assert!(lookup.is_ok());
+
+                let res = lookup.unwrap();
+
+                assert!(res.is_some());
+
+                let actual: SymData<u32, u16, Elf64> =
+                    res.unwrap().try_into().unwrap();
+
+                assert_eq!(actual, expected);
+            },
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_sort_gnu_syms_bad_symoffset() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SYMTAB[0..]).expect("Expected success");
+    let mut syms: Vec<SymDataRaw<Elf64>> =
+        symtab.iter().map(|sym| sym.try_into().unwrap()).collect();
+
+    assert!(hash::sort_gnu_syms(&mut syms[0..], strtab, 17,
+                                ELF_GNU_HASH_NBUCKETS).is_err());
+}
@@ -1,9 +1,17 @@
+mod array;
+mod builder;
+mod coredump;
 mod dynamic;
 mod elf;
+mod group;
 mod hash;
 mod note;
+#[cfg(feature = "alloc")]
+mod owned;
 mod prog_hdr;
 mod reloc;
 mod section_hdr;
+mod shndx;
 mod strtab;
 mod symtab;
+mod version;
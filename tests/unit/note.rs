@@ -1,11 +1,30 @@
 use byteorder::ByteOrder;
 use byteorder::LittleEndian;
 use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
 use elf_utils::note::required_bytes;
+use elf_utils::note::required_bytes_kinds;
+use elf_utils::note::AArch64Feature;
+use elf_utils::note::AArch64Features;
+use elf_utils::note::FreeBSDFeatures;
+use elf_utils::note::GnuAbiOS;
+use elf_utils::note::GnuProperties;
+use elf_utils::note::GnuPropertiesError;
+use elf_utils::note::GnuPropertyData;
 use elf_utils::note::NoteData;
+use elf_utils::note::NoteKind;
+use elf_utils::note::NoteKindError;
+use elf_utils::note::NoteKindProps;
+use elf_utils::note::NoteKindRaw;
 use elf_utils::note::Notes;
 use elf_utils::note::NotesError;
 use elf_utils::note::NotesMut;
+use elf_utils::note::X86Feature;
+use elf_utils::note::X86Features;
+use elf_utils::note::X86IsaLevel;
+use elf_utils::note::X86IsaLevels;
 
 const ELF_NOTES_SIZE: usize = 72;
 
@@ -252,3 +271,233 @@ fn test_NoteMut_create_iter() {
 
     assert!(iter.next().is_none());
 }
+
+const ELF_NOTES_KINDS: [NoteKindProps<'static, Elf64>; ELF_NOTES_COUNT] = [
+    NoteKind::FreeBSDAbiTag { version: 1300114 },
+    NoteKind::FreeBSDFeatureCtl { features: FreeBSDFeatures { bits: 0 } },
+    NoteKind::FreeBSDNoInit
+];
+
+const GNU_NOTES_SIZE: usize = 144;
+
+const GNU_NOTES: [u8; GNU_NOTES_SIZE] = [
+    0x04, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00,
+    0x1b, 0x50, 0x4a, 0x6c, 0x8d, 0x6a, 0x2f, 0x4b,
+    0x6b, 0x6c, 0x02, 0x0f, 0x33, 0x30, 0xd7, 0x05,
+    0x1e, 0x95, 0x0a, 0x38, 0x04, 0x00, 0x00, 0x00,
+    0x0a, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x47, 0x4e, 0x55, 0x00, 0x67, 0x6f, 0x6c, 0x64,
+    0x20, 0x31, 0x2e, 0x31, 0x36, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+    0x05, 0x00, 0x00, 0x00, 0x47, 0x4e, 0x55, 0x00,
+    0x02, 0x00, 0x00, 0xc0, 0x04, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x80, 0x00, 0xc0, 0x04, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const GNU_BUILD_ID: [u8; 20] = [
+    0x1b, 0x50, 0x4a, 0x6c, 0x8d, 0x6a, 0x2f, 0x4b,
+    0x6b, 0x6c, 0x02, 0x0f, 0x33, 0x30, 0xd7, 0x05,
+    0x1e, 0x95, 0x0a, 0x38
+];
+
+const GNU_PROPS: [GnuPropertyData<'static, Elf64>; 2] = [
+    GnuPropertyData::X86Feature1And { features: X86Features { bits: 0x3 } },
+    GnuPropertyData::X86IsaNeeded { isa: X86IsaLevels { bits: 0x1 } }
+];
+
+const GNU_NOTES_KINDS: [NoteKindProps<'static, Elf64>; 4] = [
+    NoteKind::GnuAbiTag { os: GnuAbiOS::Linux, major: 3, minor: 2,
+                          subminor: 0 },
+    NoteKind::GnuBuildId { id: &GNU_BUILD_ID },
+    NoteKind::GnuGoldVersion { version: "gold 1.16" },
+    NoteKind::GnuProperty { props: &GNU_PROPS }
+];
+
+#[test]
+fn test_NoteKind_from_notes_freebsd() {
+    let notes: Notes<'_, LittleEndian> =
+        Notes::try_from(&ELF_NOTES[0..])
+        .expect("Expected success");
+    let mut iter = notes.iter();
+
+    for expected in ELF_NOTES_KINDS.iter() {
+        let note = iter.next();
+
+        assert!(note.is_some());
+
+        let kind: Result<NoteKindRaw<'_, LittleEndian, Elf64>,
+                         NoteKindError> = note.unwrap().try_into();
+
+        assert!(kind.is_ok());
+
+        match (expected, kind.unwrap()) {
+            (NoteKind::FreeBSDAbiTag { version: a },
+             NoteKind::FreeBSDAbiTag { version: b }) => assert_eq!(*a, b),
+            (NoteKind::FreeBSDFeatureCtl { features: a },
+             NoteKind::FreeBSDFeatureCtl { features: b }) => assert_eq!(*a, b),
+            (NoteKind::FreeBSDNoInit, NoteKind::FreeBSDNoInit) => {},
+            (_, actual) => panic!("Unexpected note {:?}", actual)
+        }
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_NoteKind_create_freebsd() {
+    let mut buf = [0; ELF_NOTES_SIZE];
+    let notes: Result<(Notes<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Notes::create_kinds_split(&mut buf[0..], ELF_NOTES_KINDS.iter());
+
+    assert!(notes.is_ok());
+
+    let (_, rest) = notes.unwrap();
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_NOTES);
+}
+
+#[test]
+fn test_NoteKind_from_notes_gnu() {
+    let notes: Notes<'_, LittleEndian> =
+        Notes::try_from(&GNU_NOTES[0..])
+        .expect("Expected success");
+    let mut iter = notes.iter();
+    let kinds: Result<[NoteKindRaw<'_, LittleEndian, Elf64>; 4],
+                      NoteKindError> =
+        (|| Ok([iter.next().unwrap().try_into()?,
+                iter.next().unwrap().try_into()?,
+                iter.next().unwrap().try_into()?,
+                iter.next().unwrap().try_into()?]))();
+
+    assert!(iter.next().is_none());
+    assert!(kinds.is_ok());
+
+    let kinds = kinds.unwrap();
+
+    assert_eq!(kinds[0], NoteKind::GnuAbiTag { os: GnuAbiOS::Linux, major: 3,
+                                               minor: 2, subminor: 0 });
+    assert_eq!(kinds[1], NoteKind::GnuBuildId { id: &GNU_BUILD_ID });
+    assert_eq!(kinds[2], NoteKind::GnuGoldVersion { version: "gold 1.16" });
+
+    match kinds[3] {
+        NoteKind::GnuProperty { props } => {
+            let mut iter = props.iter();
+
+            for expected in GNU_PROPS.iter() {
+                assert_eq!(iter.next().as_ref(), Some(expected));
+            }
+
+            assert!(iter.next().is_none());
+        },
+        actual => panic!("Unexpected note {:?}", actual)
+    }
+}
+
+#[test]
+fn test_NoteKind_create_gnu() {
+    let mut buf = [0xff; GNU_NOTES_SIZE];
+
+    assert_eq!(required_bytes_kinds(GNU_NOTES_KINDS.iter()),
+               GNU_NOTES_SIZE);
+
+    let notes: Result<Notes<'_, LittleEndian>, ()> =
+        Notes::create_kinds(&mut buf[0..], GNU_NOTES_KINDS.iter());
+
+    assert!(notes.is_ok());
+    assert_eq!(&buf[0..], &GNU_NOTES[0..]);
+}
+
+#[test]
+fn test_NoteKind_create_too_small() {
+    let mut buf = [0; GNU_NOTES_SIZE - 1];
+    let notes: Result<Notes<'_, LittleEndian>, ()> =
+        Notes::create_kinds(&mut buf[0..], GNU_NOTES_KINDS.iter());
+
+    assert!(notes.is_err());
+}
+
+#[test]
+fn test_NoteKind_bad_desc_size() {
+    const NAME: [u8; 8] = [0x46, 0x72, 0x65, 0x65, 0x42, 0x53, 0x44, 0x00];
+    const DESC: [u8; 2] = [0x01, 0x00];
+    let note = NoteData { kind: 1, name: &NAME, desc: &DESC };
+    let kind: Result<NoteKindRaw<'_, LittleEndian, Elf64>, NoteKindError> =
+        note.try_into();
+
+    assert_eq!(kind, Err(NoteKindError::BadDescSize { kind: 1, size: 2 }));
+}
+
+#[test]
+fn test_NoteKind_other() {
+    const NAME: [u8; 8] = [0x4e, 0x65, 0x74, 0x42, 0x53, 0x44, 0x00, 0x00];
+    const DESC: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+    let note = NoteData { kind: 1, name: &NAME, desc: &DESC };
+    let kind: Result<NoteKindRaw<'_, LittleEndian, Elf64>, NoteKindError> =
+        note.try_into();
+
+    assert_eq!(kind, Ok(NoteKind::Other(note)));
+}
+
+#[test]
+fn test_GnuProperties_elf32_aarch64() {
+    const PROPS: [u8; 12] = [
+        0x00, 0x00, 0x00, 0xc0, 0x04, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00
+    ];
+    let props: GnuProperties<'_, LittleEndian, Elf32> =
+        GnuProperties::try_from(&PROPS[0..])
+        .expect("Expected success");
+    let mut iter = props.iter();
+
+    match iter.next() {
+        Some(GnuPropertyData::AArch64Feature1And { features }) => {
+            assert!(features.contains(AArch64Feature::BTI));
+            assert!(features.contains(AArch64Feature::PAC));
+            assert_eq!(features.unknown(), 0);
+            assert_eq!(features, AArch64Features { bits: 0x3 });
+        },
+        actual => panic!("Unexpected property {:?}", actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_GnuProperties_bad_data_size() {
+    const PROPS: [u8; 16] = [
+        0x02, 0x00, 0x00, 0xc0, 0x02, 0x00, 0x00, 0x00,
+        0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+    ];
+    let props: Result<GnuProperties<'_, LittleEndian, Elf64>,
+                      GnuPropertiesError> =
+        GnuProperties::try_from(&PROPS[0..]);
+
+    assert_eq!(props, Err(GnuPropertiesError::BadDataSize { kind: 0xc0000002,
+                                                            size: 2 }));
+}
+
+#[test]
+fn test_X86Features_insert_remove() {
+    let mut features = X86Features { bits: 0x10 };
+    let mut isa = X86IsaLevels { bits: 0 };
+
+    features.insert(X86Feature::SHSTK);
+    isa.insert(X86IsaLevel::V3);
+
+    assert!(features.contains(X86Feature::SHSTK));
+    assert!(!features.contains(X86Feature::IBT));
+    assert_eq!(features.unknown(), 0x10);
+    assert!(isa.contains(X86IsaLevel::V3));
+
+    features.remove(X86Feature::SHSTK);
+
+    assert_eq!(features.bits, 0x10);
+}
@@ -0,0 +1,397 @@
+use byteorder::BigEndian;
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use core::marker::PhantomData;
+use elf_utils::Elf;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::ElfABI;
+use elf_utils::ElfArch;
+use elf_utils::ElfError;
+use elf_utils::ElfHdrDataRaw;
+use elf_utils::ElfKind;
+use elf_utils::builder::BuilderContent;
+use elf_utils::builder::BuilderSection;
+use elf_utils::builder::ElfBuilder;
+use elf_utils::owned::ElfFile;
+use elf_utils::owned::OwnedSectionHdrs;
+use elf_utils::owned::OwnedStrtab;
+use elf_utils::owned::OwnedSymtab;
+use elf_utils::owned::StrtabBuilder;
+use elf_utils::section_hdr::SectionFlags;
+use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionHdrDataRaw;
+use elf_utils::section_hdr::SectionHdrs;
+use elf_utils::section_hdr::SectionHdrsError;
+use elf_utils::section_hdr::SectionHdrsMut;
+use elf_utils::section_hdr::SectionPos;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::StrtabError;
+use elf_utils::symtab::SymBase;
+use elf_utils::symtab::SymBind;
+use elf_utils::symtab::SymData;
+use elf_utils::symtab::SymDataRaw;
+use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
+use elf_utils::symtab::Symtab;
+use elf_utils::symtab::SymtabError;
+
+const ELF_SPEC_STRTAB: [u8; 25] = [
+    0,
+    'n' as u8, 'a' as u8, 'm' as u8, 'e' as u8, '.' as u8, 0,
+    'V' as u8, 'a' as u8, 'r' as u8, 'i' as u8,
+    'a' as u8, 'b' as u8, 'l' as u8, 'e' as u8, 0,
+    'a' as u8, 'b' as u8, 'l' as u8, 'e' as u8, 0,
+    0,
+    'x' as u8, 'x' as u8, 0
+];
+
+const STRTAB_STRS: [&'static str; 5] = [
+    "name.",
+    "Variable",
+    "able",
+    "",
+    "xx"
+];
+
+const SYMTAB_CONTENTS: [SymDataRaw<Elf32>; 3] = [
+    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
+    SymData { name: Some(1), value: 0x1000, size: 0x10,
+              kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(1) },
+    SymData { name: Some(7), value: 0x2000, size: 0x8,
+              kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
+              section: SymBase::Index(2) }
+];
+
+const SECTION_HDR_CONTENTS: [SectionHdrDataRaw<Elf64>; 2] = [
+    SectionHdrData::Null,
+    SectionHdrData::ProgBits { name: 1, addr: 0x1000, align: 16,
+                               data: SectionPos { offset: 0x40, size: 0x20 },
+                               ent_size: 0, flags: SectionFlags { bits: 6 } }
+];
+
+#[test]
+fn test_OwnedStrtab_push() {
+    let mut strtab = OwnedStrtab::new();
+
+    for str in STRTAB_STRS.iter() {
+        strtab.push(str);
+    }
+
+    assert_eq!(strtab.as_bytes(), &ELF_SPEC_STRTAB[0..]);
+    assert_eq!(strtab.strtab().idx(7), Ok("Variable"));
+    assert_eq!(strtab.strtab().idx(11), Ok("able"));
+    assert_eq!(strtab.strtab().idx(22), Ok("xx"));
+}
+
+#[test]
+fn test_OwnedStrtab_from_iter() {
+    let strtab: OwnedStrtab = STRTAB_STRS.iter().map(|x| *x).collect();
+    let bytes: Vec<u8> = strtab.into();
+
+    assert_eq!(&bytes[0..], &ELF_SPEC_STRTAB[0..]);
+}
+
+#[test]
+fn test_OwnedStrtab_from_strtab() {
+    let borrowed: Strtab<'_> =
+        Strtab::try_from(&ELF_SPEC_STRTAB[0..]).expect("Expected success");
+    let mut strtab = OwnedStrtab::from(borrowed);
+
+    assert_eq!(strtab.len(), 25);
+    assert!(!strtab.is_empty());
+    assert_eq!(strtab.push("new"), 25);
+    assert_eq!(strtab.strtab().idx(1), Ok("name."));
+    assert_eq!(strtab.strtab().idx(25), Ok("new"));
+    assert_eq!(strtab.into_bytes().len(), 29);
+}
+
+#[test]
+fn test_OwnedStrtab_from_vec() {
+    let strtab = OwnedStrtab::try_from(ELF_SPEC_STRTAB.to_vec())
+        .expect("Expected success");
+
+    assert_eq!(strtab.strtab().idx(16), Ok("able"));
+    assert_eq!(OwnedStrtab::try_from(Vec::new()),
+               Err(StrtabError::BadFirst));
+    assert_eq!(OwnedStrtab::try_from(ELF_SPEC_STRTAB[1..].to_vec()),
+               Err(StrtabError::BadFirst));
+    assert_eq!(OwnedStrtab::try_from(ELF_SPEC_STRTAB[0 .. 24].to_vec()),
+               Err(StrtabError::BadLast));
+}
+
+#[test]
+fn test_StrtabBuilder_empty() {
+    let builder = StrtabBuilder::new();
+    let (strtab, offsets) = builder.build();
+
+    assert_eq!(strtab.as_bytes(), &[0]);
+    assert!(offsets.is_empty());
+}
+
+#[test]
+fn test_StrtabBuilder_spec_strs() {
+    let builder: StrtabBuilder<'_> = STRTAB_STRS.iter().map(|x| *x).collect();
+    let (strtab, offsets) = builder.build();
+
+    assert_eq!(builder.num_strs(), 5);
+    // "able" is merged into "Variable", and "" is at index 0.
+    assert_eq!(strtab.len(), 19);
+    assert!(strtab.len() < Strtab::required_bytes(STRTAB_STRS.iter()
+                                                  .map(|x| *x)));
+    assert_eq!(offsets[3], 0);
+
+    for (str, offset) in STRTAB_STRS.iter().zip(offsets.iter()) {
+        assert_eq!(strtab.strtab().idx(*offset), Ok(*str));
+    }
+}
+
+#[test]
+fn test_StrtabBuilder_dedup() {
+    let mut builder = StrtabBuilder::new();
+    let a = builder.insert("main");
+    let b = builder.insert("puts");
+    let c = builder.insert("main");
+    let d = builder.insert("puts");
+    let (strtab, offsets) = builder.build();
+
+    assert_eq!(strtab.len(), 11);
+    assert_eq!(offsets[a], offsets[c]);
+    assert_eq!(offsets[b], offsets[d]);
+    assert_ne!(offsets[a], offsets[b]);
+    assert_eq!(strtab.strtab().idx(offsets[a]), Ok("main"));
+    assert_eq!(strtab.strtab().idx(offsets[b]), Ok("puts"));
+}
+
+#[test]
+fn test_StrtabBuilder_suffix_chain() {
+    let strs = [
+        "text", ".text", ".rela.text", "xt", ".data", ".rela.data", "", "t"
+    ];
+    let builder: StrtabBuilder<'_> = strs.iter().map(|x| *x).collect();
+    let (strtab, offsets) = builder.build();
+
+    // Only ".rela.text" and ".rela.data" need to be stored.
+    assert_eq!(strtab.len(), 23);
+
+    for (str, offset) in strs.iter().zip(offsets.iter()) {
+        assert_eq!(strtab.strtab().idx(*offset), Ok(*str));
+    }
+}
+
+#[test]
+fn test_OwnedSymtab_push() {
+    let mut symtab: OwnedSymtab<LittleEndian, Elf32> = OwnedSymtab::new();
+
+    for (i, sym) in SYMTAB_CONTENTS.iter().enumerate() {
+        assert_eq!(symtab.push(sym), i);
+    }
+
+    assert_eq!(symtab.num_syms(), 3);
+    assert_eq!(symtab.as_bytes().len(), 48);
+
+    let view = symtab.symtab();
+
+    for (i, expected) in SYMTAB_CONTENTS.iter().enumerate() {
+        let sym: SymDataRaw<Elf32> = view.idx(i).expect("Expected some")
+            .try_into().expect("Expected success");
+
+        assert_eq!(sym, *expected);
+    }
+}
+
+#[test]
+fn test_OwnedSymtab_roundtrip() {
+    let symtab: OwnedSymtab<BigEndian, Elf32> =
+        SYMTAB_CONTENTS.iter().collect();
+    let bytes: Vec<u8> = symtab.clone().into();
+    let borrowed: Symtab<'_, BigEndian, Elf32> =
+        Symtab::try_from(&bytes[0..]).expect("Expected success");
+
+    assert_eq!(OwnedSymtab::from(borrowed), symtab);
+
+    let owned: OwnedSymtab<BigEndian, Elf32> =
+        OwnedSymtab::try_from(bytes).expect("Expected success");
+
+    assert_eq!(owned, symtab);
+}
+
+#[test]
+fn test_OwnedSymtab_modify() {
+    let mut symtab: OwnedSymtab<LittleEndian, Elf32> =
+        SYMTAB_CONTENTS.iter().collect();
+
+    symtab.symtab_mut().idx_mut(2).expect("Expected some").set_value(0x3000);
+
+    let sym: SymDataRaw<Elf32> = symtab.symtab().idx(2)
+        .expect("Expected some").try_into().expect("Expected success");
+
+    assert_eq!(sym.value, 0x3000);
+    assert_eq!(sym.size, 0x8);
+}
+
+#[test]
+fn test_OwnedSymtab_from_vec_bad_size() {
+    let res: Result<OwnedSymtab<LittleEndian, Elf64>, SymtabError> =
+        OwnedSymtab::try_from(vec![0; 25]);
+
+    assert_eq!(res, Err(SymtabError::BadSize(25)));
+}
+
+#[test]
+fn test_OwnedSectionHdrs_push() {
+    let mut hdrs: OwnedSectionHdrs<LittleEndian, Elf64> =
+        OwnedSectionHdrs::new();
+
+    for (i, hdr) in SECTION_HDR_CONTENTS.iter().enumerate() {
+        assert_eq!(hdrs.push(*hdr), i);
+    }
+
+    assert_eq!(hdrs.num_hdrs(), 2);
+
+    let view = hdrs.section_hdrs();
+
+    for (i, expected) in SECTION_HDR_CONTENTS.iter().enumerate() {
+        let hdr: SectionHdrDataRaw<Elf64> = view.idx(i)
+            .expect("Expected some").try_into().expect("Expected success");
+
+        assert_eq!(hdr, *expected);
+    }
+}
+
+#[test]
+fn test_OwnedSectionHdrs_roundtrip() {
+    let mut hdrs: OwnedSectionHdrs<BigEndian, Elf64> =
+        SECTION_HDR_CONTENTS.iter().map(|x| *x).collect();
+    let bytes = hdrs.clone().into_bytes();
+    let borrowed: SectionHdrs<'_, BigEndian, Elf64> =
+        SectionHdrs::try_from(&bytes[0..]).expect("Expected success");
+
+    assert_eq!(OwnedSectionHdrs::from(borrowed), hdrs);
+
+    hdrs.section_hdrs_mut().idx_mut(1).expect("Expected some")
+        .set_addr(0x2000);
+
+    match hdrs.section_hdrs().idx(1).expect("Expected some").try_into() {
+        Ok(SectionHdrData::ProgBits { addr, .. }) => assert_eq!(addr, 0x2000),
+        _ => panic!("Expected program data")
+    }
+}
+
+#[test]
+fn test_OwnedSectionHdrs_from_vec_bad_size() {
+    let res: Result<OwnedSectionHdrs<LittleEndian, Elf64>,
+                    SectionHdrsError> =
+        OwnedSectionHdrs::try_from(vec![0; 65]);
+
+    assert_eq!(res, Err(SectionHdrsError::BadSize(65)));
+}
+
+fn build_elf() -> ElfFile<LittleEndian, Elf64> {
+    let text = [0x31, 0xc0, 0xc3, 0x90];
+    let sections: [BuilderSection<'_, Elf64>; 1] = [
+        BuilderSection {
+            name: ".text", addr: 0, align: 16,
+            flags: SectionFlags { bits: 0x6 },
+            content: BuilderContent::ProgBits { data: &text, ent_size: 0 }
+        }
+    ];
+    let builder: ElfBuilder<'_, LittleEndian, Elf64> = ElfBuilder {
+        byteorder: PhantomData, abi: ElfABI::SysV, abi_version: 0,
+        kind: ElfKind::Relocatable, arch: ElfArch::X86_64, entry: 0,
+        flags: 0, sections: &sections, segments: &[], syms: &[]
+    };
+
+    ElfFile::build(&builder).expect("Expected success")
+}
+
+#[test]
+fn test_ElfFile_build() {
+    let file = build_elf();
+    let hdr: ElfHdrDataRaw<LittleEndian, Elf64> =
+        file.elf().expect("Expected success")
+        .try_into().expect("Expected success");
+
+    assert_eq!(hdr.arch, ElfArch::X86_64);
+    assert_eq!(hdr.section_hdrs.num_ents, 3);
+    assert_eq!(hdr.section_hdr_strtab, 2);
+}
+
+#[test]
+fn test_ElfFile_roundtrip() {
+    let file = build_elf();
+    let bytes: Vec<u8> = file.clone().into();
+    let elf: Elf<'_, LittleEndian, Elf64> =
+        Elf::try_from(&bytes[0..]).expect("Expected success");
+
+    assert_eq!(ElfFile::from(elf), file);
+
+    let owned: ElfFile<LittleEndian, Elf64> =
+        ElfFile::try_from(bytes).expect("Expected success");
+
+    assert_eq!(owned.len(), file.len());
+    assert_eq!(owned, file);
+}
+
+#[test]
+fn test_ElfFile_modify() {
+    let mut file = build_elf();
+    let hdr: ElfHdrDataRaw<LittleEndian, Elf64> =
+        file.elf().expect("Expected success")
+        .try_into().expect("Expected success");
+    let start = hdr.section_hdrs.offset as usize;
+    let end = start + (3 * 64);
+    let mut hdrs: SectionHdrsMut<'_, LittleEndian, Elf64> =
+        SectionHdrsMut::try_from(&mut file.as_bytes_mut()[start .. end])
+        .expect("Expected success");
+
+    hdrs.idx_mut(1).expect("Expected some").set_addr(0x401000);
+
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::try_from(&file.as_bytes()[start .. end])
+        .expect("Expected success");
+
+    match hdrs.idx(1).expect("Expected some").try_into() {
+        Ok(SectionHdrData::ProgBits { addr, .. }) =>
+            assert_eq!(addr, 0x401000),
+        _ => panic!("Expected program data")
+    }
+}
+
+#[test]
+fn test_ElfFile_corrupt_header() {
+    let mut file = build_elf();
+
+    assert!(!file.is_empty());
+    assert!(file.elf().is_ok());
+    assert!(file.elf_mut().is_ok());
+
+    // Clobber the ELF magic.
+    file.as_bytes_mut()[0] = 0;
+
+    assert!(file.elf().is_err());
+    assert!(file.elf_mut().is_err());
+}
+
+#[test]
+fn test_ElfFile_from_vec_bad() {
+    let res: Result<ElfFile<LittleEndian, Elf64>, ElfError> =
+        ElfFile::try_from(vec![0; 64]);
+
+    assert!(res.is_err());
+
+    let mut bytes = build_elf().into_bytes();
+
+    bytes.truncate(32);
+
+    let res: Result<ElfFile<LittleEndian, Elf64>, ElfError> =
+        ElfFile::try_from(bytes);
+
+    assert!(res.is_err());
+}
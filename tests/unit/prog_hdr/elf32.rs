@@ -78,15 +78,15 @@ const ELF32_PROG_HDR_CONTENTS_BARE: [ProgHdrData<Elf32, Segment<u32>,
                         content: Segment { offset: 0x19710, size: 0x64 } },
     ProgHdrData::Dynamic { virt_addr: 0x1b628, phys_addr: 0x1b628,
                            content: Segment { offset: 0x19628, size: 0x88 } },
-    ProgHdrData::Unknown { tag: 0x6474e552, flags: 4, offset: 0x193c8,
-                           file_size: 0x348, mem_size: 0x348,
-                           phys_addr: 0x1b3c8, virt_addr: 0x1b3c8, align: 1 },
-    ProgHdrData::Unknown { tag: 0x6474e550, flags: 4, offset: 0x428c,
-                           file_size: 0xbc, mem_size: 0xbc,
-                           phys_addr: 0x428c, virt_addr: 0x428c, align: 4 },
-    ProgHdrData::Unknown { tag: 0x6474e551, flags: 6, offset: 0,
-                           file_size: 0, mem_size: 0,
-                           phys_addr: 0, virt_addr: 0, align: 0 },
+    ProgHdrData::GnuRelro { virt_addr: 0x1b3c8, phys_addr: 0x1b3c8,
+                            mem_size: 0x348, align: 1,
+                            read: true, write: false, exec: false,
+                            content: Segment { offset: 0x193c8, size: 0x348 } },
+    ProgHdrData::GnuEhFrame { virt_addr: 0x428c, phys_addr: 0x428c,
+                              align: 4, read: true, write: false, exec: false,
+                              content: Segment { offset: 0x428c, size: 0xbc } },
+    ProgHdrData::GnuStack { size: 0, align: 0, read: true, write: true,
+                            exec: false },
     ProgHdrData::Note { virt_addr: 0x174, phys_addr: 0x174,
                         content: Segment { offset: 0x174, size: 0x18 } },
 ];
@@ -320,3 +320,65 @@ fn test_ProgHdrs_create_idx() {
 
     assert!(dynamic.idx(ELF32_PROG_HDR_CONTENTS_BARE.len()).is_none());
 }
+
+#[test]
+fn test_ProgHdrs_create_gnu_bytes() {
+    let mut buf = [0; ELF32_PROG_HDR_BYTES];
+    let hdrs: ProgHdrs<'_, LittleEndian, Elf32> =
+        ProgHdrs::create(&mut buf[0..],
+                         ELF32_PROG_HDR_CONTENTS_BARE.iter().map(|x| *x))
+        .expect("Expected success");
+
+    assert_eq!(hdrs.num_hdrs(), ELF32_NUM_PROG_HDRS);
+
+    for (i, hdr) in ELF32_PROG_HDR_CONTENTS_BARE.iter().enumerate() {
+        match hdr {
+            ProgHdrData::GnuEhFrame { .. } |
+            ProgHdrData::GnuStack { .. } |
+            ProgHdrData::GnuRelro { .. } => {
+                let start = i * 32;
+                let end = start + 32;
+
+                assert_eq!(buf[start .. end], ELF32_PROG_HDR[start .. end])
+            },
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_ProgHdrs_create_tls_property() {
+    let hdrs: [ProgHdrData<Elf32, Segment<u32>, Segment<u32>,
+                           Segment<u32>>; 3] = [
+        ProgHdrData::Tls { virt_addr: 0x3e00, phys_addr: 0x3e00,
+                           mem_size: 0x48, align: 0x10,
+                           read: true, write: false, exec: false,
+                           content: Segment { offset: 0x2e00, size: 0x10 } },
+        ProgHdrData::GnuProperty { virt_addr: 0x338, phys_addr: 0x338,
+                                   align: 4, read: true, write: false,
+                                   exec: false,
+                                   content: Segment { offset: 0x338,
+                                                      size: 0x20 } },
+        ProgHdrData::GnuStack { size: 0x100000, align: 0x10, read: true,
+                                write: true, exec: true }
+    ];
+    let mut buf = [0; 3 * 32];
+    let created: ProgHdrs<'_, LittleEndian, Elf32> =
+        ProgHdrs::create(&mut buf[0..], hdrs.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for (hdr, expect) in created.iter().zip(hdrs.iter()) {
+        let actual: ProgHdrData<Elf32, Segment<u32>, Segment<u32>,
+                                Segment<u32>> =
+            hdr.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert_eq!(buf[0 .. 4], [0x07, 0x00, 0x00, 0x00]);
+    assert_eq!(buf[24 .. 24 + 4], [0x04, 0x00, 0x00, 0x00]);
+    assert_eq!(buf[32 .. 32 + 4], [0x53, 0xe5, 0x74, 0x64]);
+    assert_eq!(buf[32 + 28], 4);
+    assert_eq!(buf[2 * 32 .. 2 * 32 + 4], [0x51, 0xe5, 0x74, 0x64]);
+    assert_eq!(buf[2 * 32 + 24], 0x7);
+}
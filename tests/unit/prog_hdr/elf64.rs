@@ -2,10 +2,13 @@ use byteorder::LittleEndian;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use elf_utils::Elf64;
+use elf_utils::WithElfData;
 use elf_utils::prog_hdr::ProgHdr;
 use elf_utils::prog_hdr::ProgHdrData;
+use elf_utils::prog_hdr::ProgHdrError;
 use elf_utils::prog_hdr::ProgHdrs;
 use elf_utils::prog_hdr::ProgHdrsError;
+use elf_utils::prog_hdr::ProgHdrsMut;
 use elf_utils::prog_hdr::Segment;
 
 const ELF64_PROG_HDR_BYTES: usize = 560;
@@ -108,15 +111,16 @@ const ELF64_PROG_HDR_CONTENTS_BARE: [ProgHdrData<Elf64, Segment<u64>,
                         content: Segment { offset: 0x1b6c0, size: 0xb0 } },
     ProgHdrData::Dynamic { virt_addr: 0x1d4f8, phys_addr: 0x1d4f8,
                            content: Segment { offset: 0x1b4f8, size: 0x110 } },
-    ProgHdrData::Unknown { tag: 0x6474e552, flags: 4, offset: 0x1b038,
-                           file_size: 0x688, mem_size: 0x688,
-                           phys_addr: 0x1d038, virt_addr: 0x1d038, align: 1 },
-    ProgHdrData::Unknown { tag: 0x6474e550, flags: 4, offset: 0x49dc,
-                           file_size: 0x224, mem_size: 0x224,
-                           phys_addr: 0x49dc, virt_addr: 0x49dc, align: 4 },
-    ProgHdrData::Unknown { tag: 0x6474e551, flags: 6, offset: 0,
-                           file_size: 0, mem_size: 0,
-                           phys_addr: 0, virt_addr: 0, align: 0 },
+    ProgHdrData::GnuRelro { virt_addr: 0x1d038, phys_addr: 0x1d038,
+                            mem_size: 0x688, align: 1,
+                            read: true, write: false, exec: false,
+                            content: Segment { offset: 0x1b038, size: 0x688 } },
+    ProgHdrData::GnuEhFrame { virt_addr: 0x49dc, phys_addr: 0x49dc,
+                              align: 4, read: true, write: false, exec: false,
+                              content: Segment { offset: 0x49dc,
+                                                 size: 0x224 } },
+    ProgHdrData::GnuStack { size: 0, align: 0, read: true, write: true,
+                            exec: false },
     ProgHdrData::Note { virt_addr: 0x270, phys_addr: 0x270,
                         content: Segment { offset: 0x270, size: 0x18 } },
 ];
@@ -350,3 +354,288 @@ fn test_ProgHdrs_create_idx() {
 
     assert!(dynamic.idx(ELF64_PROG_HDR_CONTENTS_BARE.len()).is_none());
 }
+
+#[test]
+fn test_ProgHdrs_create_gnu_bytes() {
+    let mut buf = [0; ELF64_PROG_HDR_BYTES];
+    let hdrs: ProgHdrs<'_, LittleEndian, Elf64> =
+        ProgHdrs::create(&mut buf[0..],
+                         ELF64_PROG_HDR_CONTENTS_BARE.iter().map(|x| *x))
+        .expect("Expected success");
+
+    assert_eq!(hdrs.num_hdrs(), ELF64_NUM_PROG_HDRS);
+
+    for (i, hdr) in ELF64_PROG_HDR_CONTENTS_BARE.iter().enumerate() {
+        match hdr {
+            ProgHdrData::GnuEhFrame { .. } |
+            ProgHdrData::GnuStack { .. } |
+            ProgHdrData::GnuRelro { .. } => {
+                let start = i * 56;
+                let end = start + 56;
+
+                assert_eq!(buf[start .. end], ELF64_PROG_HDR[start .. end])
+            },
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_ProgHdrs_create_tls_property() {
+    let hdrs: [ProgHdrData<Elf64, Segment<u64>, Segment<u64>,
+                           Segment<u64>>; 3] = [
+        ProgHdrData::Tls { virt_addr: 0x3e00, phys_addr: 0x3e00,
+                           mem_size: 0x48, align: 0x10,
+                           read: true, write: false, exec: false,
+                           content: Segment { offset: 0x2e00, size: 0x10 } },
+        ProgHdrData::GnuProperty { virt_addr: 0x338, phys_addr: 0x338,
+                                   align: 8, read: true, write: false,
+                                   exec: false,
+                                   content: Segment { offset: 0x338,
+                                                      size: 0x20 } },
+        ProgHdrData::GnuStack { size: 0x100000, align: 0x10, read: true,
+                                write: true, exec: true }
+    ];
+    let mut buf = [0; 3 * 56];
+    let created: ProgHdrs<'_, LittleEndian, Elf64> =
+        ProgHdrs::create(&mut buf[0..], hdrs.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for (hdr, expect) in created.iter().zip(hdrs.iter()) {
+        let actual: ProgHdrData<Elf64, Segment<u64>, Segment<u64>,
+                                Segment<u64>> =
+            hdr.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert_eq!(buf[0 .. 4], [0x07, 0x00, 0x00, 0x00]);
+    assert_eq!(buf[4 .. 4 + 4], [0x04, 0x00, 0x00, 0x00]);
+    assert_eq!(buf[56 .. 56 + 4], [0x53, 0xe5, 0x74, 0x64]);
+    assert_eq!(buf[56 + 48], 8);
+    assert_eq!(buf[2 * 56 .. 2 * 56 + 4], [0x51, 0xe5, 0x74, 0x64]);
+    assert_eq!(buf[2 * 56 + 4], 0x7);
+}
+
+// TLS and GNU program headers from a GCC-built executable, whose
+// alignments and sizes differ from those in the other test data.
+const ELF64_GNU_PROG_HDR: [u8; 280] = [
+    0x07, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0xe0, 0x2d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xe0, 0x3d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xe0, 0x3d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x53, 0xe5, 0x74, 0x64, 0x04, 0x00, 0x00, 0x00,
+    0x70, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x70, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x70, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x50, 0xe5, 0x74, 0x64, 0x04, 0x00, 0x00, 0x00,
+    0x04, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x51, 0xe5, 0x74, 0x64, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x52, 0xe5, 0x74, 0x64, 0x04, 0x00, 0x00, 0x00,
+    0xe0, 0x2d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xe0, 0x3d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xe0, 0x3d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF64_GNU_PROG_HDR_CONTENTS: [ProgHdrData<Elf64, Segment<u64>,
+                                                Segment<u64>, Segment<u64>>;
+                                    5] = [
+    ProgHdrData::Tls { virt_addr: 0x3de0, phys_addr: 0x3de0,
+                       mem_size: 0x50, align: 0x10,
+                       read: true, write: false, exec: false,
+                       content: Segment { offset: 0x2de0, size: 0x4 } },
+    ProgHdrData::GnuProperty { virt_addr: 0x370, phys_addr: 0x370,
+                               align: 8, read: true, write: false,
+                               exec: false,
+                               content: Segment { offset: 0x370,
+                                                  size: 0x20 } },
+    ProgHdrData::GnuEhFrame { virt_addr: 0x2004, phys_addr: 0x2004,
+                              align: 4, read: true, write: false, exec: false,
+                              content: Segment { offset: 0x2004,
+                                                 size: 0x2c } },
+    ProgHdrData::GnuStack { size: 0, align: 0x10, read: true, write: true,
+                            exec: false },
+    ProgHdrData::GnuRelro { virt_addr: 0x3de0, phys_addr: 0x3de0,
+                            mem_size: 0x220, align: 1,
+                            read: true, write: false, exec: false,
+                            content: Segment { offset: 0x2de0, size: 0x220 } }
+];
+
+#[test]
+fn test_ProgHdrs_gnu_round_trip() {
+    let hdrs: ProgHdrs<'_, LittleEndian, Elf64> =
+        ProgHdrs::try_from(&ELF64_GNU_PROG_HDR[0..])
+        .expect("Expected success");
+    let mut buf = [0; 280];
+    let created: ProgHdrs<'_, LittleEndian, Elf64> =
+        ProgHdrs::create(&mut buf[0..],
+                         hdrs.iter().map(|hdr| hdr.try_into()
+                                              .expect("Expected success")))
+        .expect("Expected success");
+
+    for (hdr, expect) in created.iter()
+                                .zip(ELF64_GNU_PROG_HDR_CONTENTS.iter()) {
+        let actual: ProgHdrData<Elf64, Segment<u64>, Segment<u64>,
+                                Segment<u64>> =
+            hdr.try_into().expect("Expected success");
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert_eq!(buf, ELF64_GNU_PROG_HDR);
+}
+
+#[test]
+fn test_ProgHdrs_gnu_relro_mem_size_round_trip() {
+    let mut data = ELF64_GNU_PROG_HDR;
+
+    // Some linkers round the GNU_RELRO memory size up to a page.
+    data[4 * 56 + 40] = 0x00;
+    data[4 * 56 + 41] = 0x10;
+
+    let hdrs: ProgHdrs<'_, LittleEndian, Elf64> =
+        ProgHdrs::try_from(&data[0..]).expect("Expected success");
+    let relro: ProgHdrData<Elf64, Segment<u64>, Segment<u64>,
+                           Segment<u64>> =
+        hdrs.idx(4).unwrap().try_into().expect("Expected success");
+
+    match relro {
+        ProgHdrData::GnuRelro { mem_size, content, .. } => {
+            assert_eq!(mem_size, 0x1000);
+            assert_eq!(content.size, 0x220);
+        },
+        _ => panic!("Expected GnuRelro")
+    }
+
+    let mut buf = [0; 280];
+    let _: ProgHdrs<'_, LittleEndian, Elf64> =
+        ProgHdrs::create(&mut buf[0..],
+                         hdrs.iter().map(|hdr| hdr.try_into()
+                                              .expect("Expected success")))
+        .expect("Expected success");
+
+    assert_eq!(buf, data);
+}
+
+#[test]
+fn test_ProgHdrData_gnu_with_elf_data_overflow() {
+    let data = [0; 0x3000];
+
+    for hdr in ELF64_GNU_PROG_HDR_CONTENTS.iter() {
+        let hdr: ProgHdrData<Elf64, Segment<u64>, Segment<u64>,
+                             Segment<u64>> = match *hdr {
+            ProgHdrData::Tls { virt_addr, phys_addr, mem_size, align,
+                               read, write, exec, .. } =>
+                ProgHdrData::Tls { virt_addr: virt_addr,
+                                   phys_addr: phys_addr, mem_size: mem_size,
+                                   align: align, read: read, write: write,
+                                   exec: exec,
+                                   content: Segment { offset: u64::MAX,
+                                                      size: 4 } },
+            ProgHdrData::GnuProperty { virt_addr, phys_addr, align, read,
+                                       write, exec, .. } =>
+                ProgHdrData::GnuProperty { virt_addr: virt_addr,
+                                           phys_addr: phys_addr,
+                                           align: align, read: read,
+                                           write: write, exec: exec,
+                                           content: Segment {
+                                               offset: u64::MAX, size: 4
+                                           } },
+            ProgHdrData::GnuEhFrame { virt_addr, phys_addr, align, read,
+                                      write, exec, .. } =>
+                ProgHdrData::GnuEhFrame { virt_addr: virt_addr,
+                                          phys_addr: phys_addr,
+                                          align: align, read: read,
+                                          write: write, exec: exec,
+                                          content: Segment {
+                                              offset: u64::MAX, size: 4
+                                          } },
+            ProgHdrData::GnuRelro { virt_addr, phys_addr, mem_size, align,
+                                    read, write, exec, .. } =>
+                ProgHdrData::GnuRelro { virt_addr: virt_addr,
+                                        phys_addr: phys_addr,
+                                        mem_size: mem_size, align: align,
+                                        read: read, write: write,
+                                        exec: exec,
+                                        content: Segment {
+                                            offset: u64::MAX, size: 4
+                                        } },
+            _ => continue
+        };
+
+        assert_eq!(hdr.with_elf_data(&data[0..]),
+                   Err(ProgHdrError::DataOutOfBounds { offset: u64::MAX,
+                                                       size: 4 }));
+    }
+}
+
+#[test]
+fn test_ProgHdrsMut_set_round_trip() {
+    let mut buf = [0; ELF64_PROG_HDR_BYTES];
+    let mut prog_hdr: ProgHdrsMut<'_, LittleEndian, Elf64> =
+        ProgHdrsMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+
+    assert_eq!(prog_hdr.num_hdrs(), ELF64_NUM_PROG_HDRS);
+
+    for i in 0 .. ELF64_PROG_HDR_CONTENTS_BARE.len() {
+        let ent = prog_hdr.idx_mut(i);
+
+        assert!(ent.is_some());
+
+        ent.unwrap().set(ELF64_PROG_HDR_CONTENTS_BARE[i]);
+    }
+
+    assert!(prog_hdr.idx_mut(ELF64_NUM_PROG_HDRS).is_none());
+    assert_eq!(&buf[0..], &ELF64_PROG_HDR[0..]);
+}
+
+#[test]
+fn test_ProgHdrsMut_set_fields() {
+    let mut buf = ELF64_PROG_HDR.clone();
+    let mut prog_hdr: ProgHdrsMut<'_, LittleEndian, Elf64> =
+        ProgHdrsMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+    let mut ent = prog_hdr.idx_mut(1).expect("Expected success");
+
+    ent.set_virt_addr(0x400000);
+    ent.set_phys_addr(0x400000);
+    ent.set_mem_size(0x6000);
+    ent.set_align(0x200000);
+    ent.set_exec(true);
+
+    let data = ent.try_into();
+
+    assert!(data.is_ok());
+
+    let actual: ProgHdrData<Elf64, Segment<u64>, Segment<u64>,
+                            Segment<u64>> = data.unwrap();
+    let expect = ProgHdrData::Load { virt_addr: 0x400000,
+                                     phys_addr: 0x400000,
+                                     mem_size: 0x6000, align: 0x200000,
+                                     read: true, write: false, exec: true,
+                                     content: Segment { offset: 0,
+                                                        size: 0x5424 } };
+
+    assert_eq!(expect, actual);
+}
@@ -0,0 +1,160 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf64;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::aarch64::AArch64Reloc;
+use elf_utils::reloc::aarch64::AArch64RelocError;
+
+const AARCH64_RELAS_SIZE: usize = 336;
+
+const AARCH64_RELAS: [u8; AARCH64_RELAS_SIZE] = [
+    0x18, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0d, 0x01, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x01, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0a, 0x01, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x01, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0d, 0x01, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x01, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0a, 0x01, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x34, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x01, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x38, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0d, 0x01, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x01, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0a, 0x01, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x01, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1b, 0x01, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x4c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1b, 0x01, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const AARCH64_NUM_RELAS: usize = 14;
+
+const AARCH64_RELAS_CONTENTS: [AArch64Reloc<u32>; AARCH64_NUM_RELAS] = [
+    AArch64Reloc::MovWUAbsG3 { offset: 0x18, sym: 5, addend: 0 },
+    AArch64Reloc::MovWUAbsG2NC { offset: 0x1c, sym: 5, addend: 0 },
+    AArch64Reloc::MovWUAbsG1NC { offset: 0x20, sym: 5, addend: 0 },
+    AArch64Reloc::MovWUAbsG0NC { offset: 0x24, sym: 5, addend: 0 },
+    AArch64Reloc::MovWUAbsG3 { offset: 0x28, sym: 6, addend: 0 },
+    AArch64Reloc::MovWUAbsG2NC { offset: 0x2c, sym: 6, addend: 0 },
+    AArch64Reloc::MovWUAbsG1NC { offset: 0x30, sym: 6, addend: 0 },
+    AArch64Reloc::MovWUAbsG0NC { offset: 0x34, sym: 6, addend: 0 },
+    AArch64Reloc::MovWUAbsG3 { offset: 0x38, sym: 7, addend: 0 },
+    AArch64Reloc::MovWUAbsG2NC { offset: 0x3c, sym: 7, addend: 0 },
+    AArch64Reloc::MovWUAbsG1NC { offset: 0x40, sym: 7, addend: 0 },
+    AArch64Reloc::MovWUAbsG0NC { offset: 0x44, sym: 7, addend: 0 },
+    AArch64Reloc::Call26 { offset: 0x48, sym: 8, addend: 0 },
+    AArch64Reloc::Call26 { offset: 0x4c, sym: 9, addend: 0 }
+];
+
+const AARCH64_DYN_RELAS_CONTENTS: [AArch64Reloc<u32>; 9] = [
+    AArch64Reloc::Copy { offset: 0x11040, sym: 3 },
+    AArch64Reloc::GlobalData { offset: 0x10fd8, sym: 4, addend: 0 },
+    AArch64Reloc::JumpSlot { offset: 0x11000, sym: 5, addend: 0 },
+    AArch64Reloc::Relative { offset: 0x10d90, addend: 0x7d4 },
+    AArch64Reloc::TLSDTPMod { offset: 0x10fe0, sym: 6 },
+    AArch64Reloc::TLSDTPRel { offset: 0x10fe8, sym: 6, addend: 8 },
+    AArch64Reloc::TLSTPRel { offset: 0x10ff0, sym: 7, addend: 0 },
+    AArch64Reloc::TLSDesc { offset: 0x11010, sym: 0, addend: 16 },
+    AArch64Reloc::IRelative { offset: 0x11020, addend: 0x810 }
+];
+
+#[test]
+fn test_Relas_from_bytes_iter() {
+    let rels: Relas<'_, LittleEndian, Elf64> =
+        Relas::try_from(&AARCH64_RELAS[0..]).expect("Expected success");
+    let mut iter = rels.iter();
+
+    for expect in AARCH64_RELAS_CONTENTS.iter() {
+        let rel = iter.next();
+
+        assert!(rel.is_some());
+
+        let raw: RelaData<u32, Elf64> = rel.unwrap().into();
+        let data: Result<AArch64Reloc<u32>, AArch64RelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Relas_from_bytes_idx() {
+    let rels: Relas<'_, LittleEndian, Elf64> =
+        Relas::try_from(&AARCH64_RELAS[0..]).expect("Expected success");
+
+    for i in 0 .. AARCH64_NUM_RELAS {
+        let expect = &AARCH64_RELAS_CONTENTS[i];
+        let rel = rels.idx(i);
+
+        assert!(rel.is_some());
+
+        let raw: RelaData<u32, Elf64> = rel.unwrap().into();
+        let data: Result<AArch64Reloc<u32>, AArch64RelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(rels.idx(AARCH64_RELAS_CONTENTS.len()).is_none());
+}
+
+#[test]
+fn test_RelaData_round_trip() {
+    for expect in AARCH64_DYN_RELAS_CONTENTS.iter() {
+        let raw: RelaData<u32, Elf64> = expect.clone().into();
+        let data: Result<AArch64Reloc<u32>, AArch64RelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf64> =
+        RelaData { offset: 0x18, sym: 5, kind: 281, addend: 0 };
+    let data: Result<AArch64Reloc<u32>, AArch64RelocError> = raw.try_into();
+
+    assert_eq!(data, Err(AArch64RelocError::BadTag(281)));
+}
@@ -0,0 +1,213 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use elf_utils::Elf32;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelDataRaw;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::RelaDataRaw;
+use elf_utils::reloc::android;
+use elf_utils::reloc::android::AndroidRelas;
+use elf_utils::reloc::android::AndroidRelocsError;
+use elf_utils::reloc::android::AndroidRels;
+
+const ELF32_ANDROID_RELS_SIZE: usize = 27;
+
+const ELF32_ANDROID_RELS: [u8; ELF32_ANDROID_RELS_SIZE] = [
+    0x41, 0x50, 0x53, 0x32, 0x07, 0x00, 0x01, 0x00,
+    0x80, 0x3e, 0x08, 0x03, 0x03, 0x04, 0x08, 0x03,
+    0x00, 0xe4, 0x01, 0x86, 0x06, 0x10, 0x81, 0x0a,
+    0x10, 0x81, 0x0a
+];
+
+const ELF32_NUM_ANDROID_RELS: usize = 7;
+
+const ELF32_ANDROID_RELS_CONTENTS: [RelDataRaw<Elf32>;
+                                   ELF32_NUM_ANDROID_RELS] = [
+    RelData { offset: 0x1f00, sym: 0, kind: 8 },
+    RelData { offset: 0x1f04, sym: 0, kind: 8 },
+    RelData { offset: 0x1f08, sym: 0, kind: 8 },
+    RelData { offset: 0x1f0c, sym: 0, kind: 8 },
+    RelData { offset: 0x1ff0, sym: 3, kind: 6 },
+    RelData { offset: 0x2000, sym: 5, kind: 1 },
+    RelData { offset: 0x2010, sym: 5, kind: 1 }
+];
+
+const ELF32_ANDROID_RELAS_SIZE: usize = 32;
+
+const ELF32_ANDROID_RELAS: [u8; ELF32_ANDROID_RELAS_SIZE] = [
+    0x41, 0x50, 0x53, 0x32, 0x05, 0x00, 0x05, 0x08,
+    0x80, 0x20, 0x08, 0x7c, 0x04, 0x08, 0x00, 0x04,
+    0x08, 0x00, 0xf8, 0x1f, 0x81, 0x04, 0x7c, 0x80,
+    0x02, 0x81, 0x06, 0x87, 0x80, 0x80, 0x80, 0x78
+];
+
+const ELF32_NUM_ANDROID_RELAS: usize = 5;
+
+const ELF32_ANDROID_RELAS_CONTENTS: [RelaDataRaw<Elf32>;
+                                    ELF32_NUM_ANDROID_RELAS] = [
+    RelaData { offset: 0x1000, sym: 0, kind: 8, addend: -4 },
+    RelaData { offset: 0x1004, sym: 0, kind: 8, addend: -4 },
+    RelaData { offset: 0x1008, sym: 0, kind: 8, addend: -4 },
+    RelaData { offset: 0x2000, sym: 2, kind: 1, addend: -8 },
+    RelaData { offset: 0x2100, sym: 3, kind: 1, addend: 0x7fffffff }
+];
+
+#[test]
+fn test_AndroidRels_from_bytes_just_right() {
+    let rels: Result<AndroidRels<'_, LittleEndian, Elf32>,
+                     AndroidRelocsError> =
+        AndroidRels::try_from(&ELF32_ANDROID_RELS[0..]);
+
+    assert!(rels.is_ok());
+}
+
+#[test]
+fn test_AndroidRels_from_bytes_too_small() {
+    let rels: Result<AndroidRels<'_, LittleEndian, Elf32>,
+                     AndroidRelocsError> =
+        AndroidRels::try_from(&ELF32_ANDROID_RELS[0 ..
+                                                  ELF32_ANDROID_RELS_SIZE - 1]);
+
+    assert_eq!(rels.err(), Some(AndroidRelocsError::TooShort));
+}
+
+#[test]
+fn test_AndroidRels_from_bytes_bad_magic() {
+    let mut buf = ELF32_ANDROID_RELS.clone();
+
+    buf[3] = b'1';
+
+    let rels: Result<AndroidRels<'_, LittleEndian, Elf32>,
+                     AndroidRelocsError> =
+        AndroidRels::try_from(&mut buf[0..]);
+
+    assert_eq!(rels.err(), Some(AndroidRelocsError::BadMagic));
+}
+
+#[test]
+fn test_AndroidRels_from_bytes_addend() {
+    let rels: Result<AndroidRels<'_, LittleEndian, Elf32>,
+                     AndroidRelocsError> =
+        AndroidRels::try_from(&ELF32_ANDROID_RELAS[0..]);
+
+    assert_eq!(rels.err(), Some(AndroidRelocsError::BadFlags(8)));
+}
+
+#[test]
+fn test_AndroidRels_from_bytes_num_relocs() {
+    let rels: AndroidRels<'_, LittleEndian, Elf32> =
+        AndroidRels::try_from(&ELF32_ANDROID_RELS[0..])
+        .expect("Expected success");
+
+    assert_eq!(rels.num_relocs(), ELF32_NUM_ANDROID_RELS);
+}
+
+#[test]
+fn test_AndroidRels_from_bytes_iter() {
+    let rels: AndroidRels<'_, LittleEndian, Elf32> =
+        AndroidRels::try_from(&ELF32_ANDROID_RELS[0..])
+        .expect("Expected success");
+    let mut iter = rels.iter();
+
+    assert_eq!(iter.len(), ELF32_NUM_ANDROID_RELS);
+
+    for expect in ELF32_ANDROID_RELS_CONTENTS.iter() {
+        let rel = iter.next();
+
+        assert!(rel.is_some());
+        assert_eq!(expect, &rel.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_AndroidRels_required_bytes() {
+    let size = android::rels_required_bytes::<_, Elf32>(
+        ELF32_ANDROID_RELS_CONTENTS.iter()
+    );
+
+    assert_eq!(size, ELF32_ANDROID_RELS_SIZE);
+}
+
+#[test]
+fn test_AndroidRels_create_too_big() {
+    let mut buf = [0; ELF32_ANDROID_RELS_SIZE + 1];
+    let rels: Result<(AndroidRels<'_, LittleEndian, Elf32>, &'_ mut [u8]),
+                     ()> =
+        AndroidRels::create_split(&mut buf[0..],
+                                  ELF32_ANDROID_RELS_CONTENTS.iter());
+
+    assert!(rels.is_ok());
+
+    let (_, buf) = rels.expect("Expected success");
+
+    assert_eq!(buf.len(), 1);
+}
+
+#[test]
+fn test_AndroidRels_create_too_small() {
+    let mut buf = [0; ELF32_ANDROID_RELS_SIZE - 1];
+    let rels: Result<(AndroidRels<'_, LittleEndian, Elf32>, &'_ mut [u8]),
+                     ()> =
+        AndroidRels::create_split(&mut buf[0..],
+                                  ELF32_ANDROID_RELS_CONTENTS.iter());
+
+    assert!(rels.is_err());
+}
+
+#[test]
+fn test_AndroidRels_create_bytes() {
+    let mut buf = [0; ELF32_ANDROID_RELS_SIZE];
+    let rels: Result<AndroidRels<'_, LittleEndian, Elf32>, ()> =
+        AndroidRels::create(&mut buf[0..], ELF32_ANDROID_RELS_CONTENTS.iter());
+
+    assert!(rels.is_ok());
+    assert_eq!(buf, ELF32_ANDROID_RELS);
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_num_relocs() {
+    let relas: AndroidRelas<'_, LittleEndian, Elf32> =
+        AndroidRelas::try_from(&ELF32_ANDROID_RELAS[0..])
+        .expect("Expected success");
+
+    assert_eq!(relas.num_relocs(), ELF32_NUM_ANDROID_RELAS);
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_iter() {
+    let relas: AndroidRelas<'_, LittleEndian, Elf32> =
+        AndroidRelas::try_from(&ELF32_ANDROID_RELAS[0..])
+        .expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in ELF32_ANDROID_RELAS_CONTENTS.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+        assert_eq!(expect, &rela.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_AndroidRelas_required_bytes() {
+    let size = android::relas_required_bytes::<_, Elf32>(
+        ELF32_ANDROID_RELAS_CONTENTS.iter()
+    );
+
+    assert_eq!(size, ELF32_ANDROID_RELAS_SIZE);
+}
+
+#[test]
+fn test_AndroidRelas_create_bytes() {
+    let mut buf = [0; ELF32_ANDROID_RELAS_SIZE];
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf32>, ()> =
+        AndroidRelas::create(&mut buf[0..],
+                             ELF32_ANDROID_RELAS_CONTENTS.iter());
+
+    assert!(relas.is_ok());
+    assert_eq!(buf, ELF32_ANDROID_RELAS);
+}
@@ -0,0 +1,234 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use elf_utils::Elf64;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::RelaDataRaw;
+use elf_utils::reloc::android;
+use elf_utils::reloc::android::AndroidRelas;
+use elf_utils::reloc::android::AndroidRelocsError;
+
+const ELF64_ANDROID_RELAS_SIZE: usize = 45;
+
+const ELF64_ANDROID_RELAS: [u8; ELF64_ANDROID_RELAS_SIZE] = [
+    0x41, 0x50, 0x53, 0x32, 0x07, 0x00, 0x01, 0x0c,
+    0xb0, 0x22, 0xe0, 0xfb, 0x00, 0x08, 0x03, 0x0b,
+    0x08, 0x08, 0x10, 0x10, 0x10, 0x03, 0x08, 0xe0,
+    0x03, 0x86, 0x80, 0x80, 0x80, 0x30, 0xa0, 0x5d,
+    0x08, 0x86, 0x80, 0x80, 0x80, 0xc0, 0x00, 0x00,
+    0x28, 0x08, 0x88, 0x80, 0x01
+];
+
+const ELF64_NUM_ANDROID_RELAS: usize = 7;
+
+const ELF64_ANDROID_RELAS_CONTENTS: [RelaDataRaw<Elf64>;
+                                    ELF64_NUM_ANDROID_RELAS] = [
+    RelaData { offset: 0x3de0, sym: 0, kind: 8, addend: 0x1130 },
+    RelaData { offset: 0x3de8, sym: 0, kind: 8, addend: 0x1140 },
+    RelaData { offset: 0x3df0, sym: 0, kind: 8, addend: 0x1150 },
+    RelaData { offset: 0x3df8, sym: 0, kind: 8, addend: 0x1160 },
+    RelaData { offset: 0x3fd8, sym: 3, kind: 6, addend: 0 },
+    RelaData { offset: 0x3fe0, sym: 4, kind: 6, addend: 0 },
+    RelaData { offset: 0x4008, sym: 0, kind: 8, addend: 0x4008 }
+];
+
+#[test]
+fn test_AndroidRelas_from_bytes_just_right() {
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&ELF64_ANDROID_RELAS[0..]);
+
+    assert!(relas.is_ok());
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_too_small() {
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&ELF64_ANDROID_RELAS[0 ..
+                                                    ELF64_ANDROID_RELAS_SIZE -
+                                                    1]);
+
+    assert_eq!(relas.err(), Some(AndroidRelocsError::TooShort));
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_bad_magic() {
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&ELF64_ANDROID_RELAS[1..]);
+
+    assert_eq!(relas.err(), Some(AndroidRelocsError::BadMagic));
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_bad_group_size() {
+    let mut buf = ELF64_ANDROID_RELAS.clone();
+
+    // Make the first group larger than the whole table.
+    buf[6] = 0x08;
+
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&mut buf[0..]);
+
+    assert_eq!(relas.err(), Some(AndroidRelocsError::BadGroupSize(8)));
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_bad_count() {
+    // A count of 2^62, and a single group of that size sharing its
+    // offset delta and info value.
+    const BUF: [u8; 28] = [
+        0x41, 0x50, 0x53, 0x32, 0x80, 0x80, 0x80, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0xc0, 0x00, 0x00, 0x80,
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0xc0,
+        0x00, 0x03, 0x08, 0x08
+    ];
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&BUF[0..]);
+
+    assert_eq!(relas.err(), Some(AndroidRelocsError::BadCount(1 << 62)));
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_grouped_too_big() {
+    // A count of 2^21, and a single group of that size sharing its
+    // offset delta and info value.
+    const BUF: [u8; 16] = [
+        0x41, 0x50, 0x53, 0x32, 0x80, 0x80, 0x80, 0x01,
+        0x00, 0x80, 0x80, 0x80, 0x01, 0x03, 0x08, 0x08
+    ];
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&BUF[0..]);
+
+    assert_eq!(relas.err(), Some(AndroidRelocsError::BadGroupSize(1 << 21)));
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_ungrouped_too_big() {
+    // Four relocations in a group that needs a byte per relocation,
+    // with only two bytes left.
+    const BUF: [u8; 10] = [
+        0x41, 0x50, 0x53, 0x32, 0x04, 0x00, 0x04, 0x00,
+        0x08, 0x08
+    ];
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>,
+                      AndroidRelocsError> =
+        AndroidRelas::try_from(&BUF[0..]);
+
+    assert_eq!(relas.err(), Some(AndroidRelocsError::BadGroupSize(4)));
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_num_relocs() {
+    let relas: AndroidRelas<'_, LittleEndian, Elf64> =
+        AndroidRelas::try_from(&ELF64_ANDROID_RELAS[0..])
+        .expect("Expected success");
+
+    assert_eq!(relas.num_relocs(), ELF64_NUM_ANDROID_RELAS);
+}
+
+#[test]
+fn test_AndroidRelas_from_bytes_iter() {
+    let relas: AndroidRelas<'_, LittleEndian, Elf64> =
+        AndroidRelas::try_from(&ELF64_ANDROID_RELAS[0..])
+        .expect("Expected success");
+    let mut iter = relas.iter();
+
+    assert_eq!(iter.len(), ELF64_NUM_ANDROID_RELAS);
+
+    for expect in ELF64_ANDROID_RELAS_CONTENTS.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+        assert_eq!(expect, &rela.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_AndroidRelas_required_bytes() {
+    let size = android::relas_required_bytes::<_, Elf64>(
+        ELF64_ANDROID_RELAS_CONTENTS.iter()
+    );
+
+    assert_eq!(size, ELF64_ANDROID_RELAS_SIZE);
+}
+
+#[test]
+fn test_AndroidRelas_create_just_right() {
+    let mut buf = [0; ELF64_ANDROID_RELAS_SIZE];
+    let relas: Result<(AndroidRelas<'_, LittleEndian, Elf64>, &'_ mut [u8]),
+                      ()> =
+        AndroidRelas::create_split(&mut buf[0..],
+                                   ELF64_ANDROID_RELAS_CONTENTS.iter());
+
+    assert!(relas.is_ok());
+
+    let (_, buf) = relas.expect("Expected success");
+
+    assert_eq!(buf.len(), 0);
+}
+
+#[test]
+fn test_AndroidRelas_create_too_small() {
+    let mut buf = [0; ELF64_ANDROID_RELAS_SIZE - 1];
+    let relas: Result<(AndroidRelas<'_, LittleEndian, Elf64>, &'_ mut [u8]),
+                      ()> =
+        AndroidRelas::create_split(&mut buf[0..],
+                                   ELF64_ANDROID_RELAS_CONTENTS.iter());
+
+    assert!(relas.is_err());
+}
+
+#[test]
+fn test_AndroidRelas_create_bytes() {
+    let mut buf = [0; ELF64_ANDROID_RELAS_SIZE];
+    let relas: Result<AndroidRelas<'_, LittleEndian, Elf64>, ()> =
+        AndroidRelas::create(&mut buf[0..],
+                             ELF64_ANDROID_RELAS_CONTENTS.iter());
+
+    assert!(relas.is_ok());
+    assert_eq!(buf, ELF64_ANDROID_RELAS);
+}
+
+#[test]
+fn test_AndroidRelas_create_iter() {
+    let mut buf = [0; ELF64_ANDROID_RELAS_SIZE];
+    let relas: AndroidRelas<'_, LittleEndian, Elf64> =
+        AndroidRelas::create(&mut buf[0..],
+                             ELF64_ANDROID_RELAS_CONTENTS.iter())
+        .expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in ELF64_ANDROID_RELAS_CONTENTS.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+        assert_eq!(expect, &rela.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_AndroidRelas_create_long_run() {
+    // A run of relative relocations longer than a single group can
+    // hold.
+    const NUM: u64 = (1 << 20) + 5;
+    let relas = (0 .. NUM).map(|i| {
+        RelaData { offset: 0x10000 + (i * 8), sym: 0, kind: 8, addend: 0 }
+    });
+    let size = android::relas_required_bytes::<_, Elf64>(relas.clone());
+    let mut buf = vec![0; size];
+    let _: AndroidRelas<'_, LittleEndian, Elf64> =
+        AndroidRelas::create(&mut buf[0..], relas)
+        .expect("Expected success");
+    let relas: AndroidRelas<'_, LittleEndian, Elf64> =
+        AndroidRelas::try_from(&buf[0..]).expect("Expected success");
+
+    assert_eq!(relas.num_relocs(), NUM as usize);
+}
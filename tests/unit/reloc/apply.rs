@@ -0,0 +1,496 @@
+use elf_utils::reloc::apply::Apply;
+use elf_utils::reloc::apply::ApplyEnv;
+use elf_utils::reloc::apply::ApplyError;
+use elf_utils::reloc::apply::SymAddr;
+use elf_utils::reloc::apply::TLSBases;
+use elf_utils::reloc::x86::X86Reloc;
+use elf_utils::reloc::x86_64::X32Reloc;
+use elf_utils::reloc::x86_64::X86_64Reloc;
+
+const X86_64_ENV: ApplyEnv<u64> = ApplyEnv {
+    section: 0x401000,
+    base: 0x400000,
+    got: Some(0x404000),
+    plt: Some(0x402000),
+    tls: Some(TLSBases { module: 1, block: 0x7f0000001000,
+                         thread_ptr: 0x7f0000002000 })
+};
+
+const X86_ENV: ApplyEnv<u32> = ApplyEnv {
+    section: 0x8049000,
+    base: 0x8048000,
+    got: Some(0x804c000),
+    plt: Some(0x804a000),
+    tls: None
+};
+
+const X32_ENV: ApplyEnv<u32> = ApplyEnv {
+    section: 0x401000,
+    base: 0x400000,
+    got: Some(0x404000),
+    plt: Some(0x402000),
+    tls: None
+};
+
+fn resolve64(name: &&str) -> Option<SymAddr<u64>> {
+    match *name {
+        "main" => Some(SymAddr { addr: 0x401100, size: 0x20, plt: None,
+                                 got: None }),
+        "data" => Some(SymAddr { addr: 0x405000, size: 0x100, plt: None,
+                                 got: None }),
+        "tvar" => Some(SymAddr { addr: 0x7f0000001010, size: 8, plt: None,
+                                 got: None }),
+        "far" => Some(SymAddr { addr: 0x100000000000, size: 0, plt: None,
+                                got: None }),
+        "puts" => Some(SymAddr { addr: 0, size: 0, plt: Some(0x402010),
+                                 got: Some(0x18) }),
+        "printf" => Some(SymAddr { addr: 0, size: 0, plt: Some(0x402020),
+                                   got: Some(0x20) }),
+        _ => None
+    }
+}
+
+fn resolve32(name: &&str) -> Option<SymAddr<u32>> {
+    match *name {
+        "main" => Some(SymAddr { addr: 0x8049100, size: 0x20, plt: None,
+                                 got: None }),
+        "data" => Some(SymAddr { addr: 0x804d000, size: 0x40, plt: None,
+                                 got: None }),
+        "puts" => Some(SymAddr { addr: 0, size: 0, plt: Some(0x804a010),
+                                 got: Some(0x0c) }),
+        "printf" => Some(SymAddr { addr: 0, size: 0, plt: Some(0x804a020),
+                                   got: Some(0x10) }),
+        _ => None
+    }
+}
+
+#[test]
+fn test_X86_64_apply_PC32() {
+    let mut buf = [0xe8, 0x00, 0x00, 0x00, 0x00];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::PC32 { offset: 1, sym: "main", addend: -4 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0xe8, 0xfb, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_Abs64() {
+    let mut buf = [0; 8];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::Abs64 { offset: 0, sym: "data", addend: 0x10 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x10, 0x50, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_Relative() {
+    let mut buf = [0; 16];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::Relative { offset: 8, addend: 0x1234 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf[8..], [0x34, 0x12, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_GOTPC() {
+    let mut buf = [0; 8];
+    let puts: X86_64Reloc<&str> =
+        X86_64Reloc::GOTPC { offset: 0, sym: "puts", addend: -4 };
+    let printf: X86_64Reloc<&str> =
+        X86_64Reloc::GOTPC { offset: 4, sym: "printf", addend: -4 };
+
+    assert!(puts.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert!(printf.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x14, 0x30, 0x00, 0x00, 0x18, 0x30, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_GOTPCRelX() {
+    let mut buf = [0; 8];
+    let puts: X86_64Reloc<&str> =
+        X86_64Reloc::GOTPCRelX { offset: 0, sym: "puts", addend: -4 };
+    let printf: X86_64Reloc<&str> =
+        X86_64Reloc::RexGOTPCRelX { offset: 4, sym: "printf", addend: -4 };
+
+    assert!(puts.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert!(printf.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x14, 0x30, 0x00, 0x00, 0x18, 0x30, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_GOTPC32() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::GOTPC32 { offset: 0, sym: "_GLOBAL_OFFSET_TABLE_",
+                               addend: 0 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x00, 0x30, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_GOT32() {
+    let mut buf = [0; 8];
+    let puts: X86_64Reloc<&str> =
+        X86_64Reloc::GOT32 { offset: 0, sym: "puts", addend: 0 };
+    let printf: X86_64Reloc<&str> =
+        X86_64Reloc::GOT32 { offset: 4, sym: "printf", addend: 0 };
+
+    assert!(puts.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert!(printf.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x18, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_PLTRel() {
+    let mut buf = [0; 8];
+    let puts: X86_64Reloc<&str> =
+        X86_64Reloc::PLTRel { offset: 0, sym: "puts", addend: -4 };
+    let printf: X86_64Reloc<&str> =
+        X86_64Reloc::PLTRel { offset: 4, sym: "printf", addend: -4 };
+
+    assert!(puts.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert!(printf.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x0c, 0x10, 0x00, 0x00, 0x18, 0x10, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_TPOff32() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::TPOff32 { offset: 0, sym: "tvar" };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x10, 0xf0, 0xff, 0xff]);
+}
+
+#[test]
+fn test_X86_64_apply_Size32() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::Size32 { offset: 0, sym: "data", addend: 4 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64).is_ok());
+    assert_eq!(buf, [0x04, 0x01, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_implicit() {
+    let mut buf = [0x08, 0x00, 0x00, 0x00];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::Abs32 { offset: 0, sym: "data", addend: 0 };
+
+    assert!(reloc.apply_implicit(&mut buf[0..], &X86_64_ENV,
+                                 resolve64).is_ok());
+    assert_eq!(buf, [0x08, 0x50, 0x40, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_implicit_slots() {
+    let mut buf = [0x36, 0x10, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+                   0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let slot: X86_64Reloc<&str> =
+        X86_64Reloc::JumpSlot { offset: 0, sym: "main" };
+    let data: X86_64Reloc<&str> =
+        X86_64Reloc::GlobalData { offset: 8, sym: "data" };
+
+    assert!(slot.apply_implicit(&mut buf[0..], &X86_64_ENV,
+                                resolve64).is_ok());
+    assert!(data.apply_implicit(&mut buf[0..], &X86_64_ENV,
+                                resolve64).is_ok());
+    assert_eq!(buf, [0x00, 0x11, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00,
+                     0x00, 0x50, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_64_apply_overflow() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::PC32 { offset: 0, sym: "far", addend: -4 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::Overflow(0)));
+    assert_eq!(buf, [0; 4]);
+}
+
+#[test]
+fn test_X86_64_apply_unsigned_overflow() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::Abs32 { offset: 0, sym: "main", addend: -0x500000 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::Overflow(0)));
+}
+
+#[test]
+fn test_X86_64_apply_misaligned() {
+    let mut buf = [0; 16];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::GlobalData { offset: 4, sym: "data" };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::Misaligned(4)));
+}
+
+#[test]
+fn test_X86_64_apply_out_of_bounds() {
+    let mut buf = [0; 6];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::PC32 { offset: 4, sym: "main", addend: -4 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::OutOfBounds(4)));
+}
+
+#[test]
+fn test_X86_64_apply_unresolved() {
+    let mut buf = [0; 8];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::Abs64 { offset: 0, sym: "missing", addend: 0 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::Unresolved(0)));
+}
+
+#[test]
+fn test_X86_64_apply_no_GOT() {
+    let mut buf = [0; 4];
+    let env = ApplyEnv { got: None, ..X86_64_ENV };
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::GOTPC { offset: 0, sym: "puts", addend: -4 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &env, resolve64),
+               Err(ApplyError::NoGOT(0)));
+}
+
+#[test]
+fn test_X86_64_apply_no_GOT_entry() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::GOT32 { offset: 0, sym: "main", addend: 0 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::NoGOT(0)));
+}
+
+#[test]
+fn test_X86_64_apply_no_PLT_entry() {
+    let mut buf = [0; 4];
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::PLTRel { offset: 0, sym: "main", addend: -4 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::NoPLT(0)));
+}
+
+#[test]
+fn test_X86_64_apply_no_TLS() {
+    let mut buf = [0; 8];
+    let env = ApplyEnv { tls: None, ..X86_64_ENV };
+    let reloc: X86_64Reloc<&str> =
+        X86_64Reloc::DTPMod { offset: 0, sym: "tvar" };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &env, resolve64),
+               Err(ApplyError::NoTLS(0)));
+}
+
+#[test]
+fn test_X86_64_apply_unsupported() {
+    let mut buf = [0; 8];
+    let reloc: X86_64Reloc<&str> = X86_64Reloc::Copy { sym: "data" };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_64_ENV, resolve64),
+               Err(ApplyError::Unsupported));
+}
+
+#[test]
+fn test_X32_apply_GlobalData() {
+    let mut buf = [0xaa; 12];
+    let reloc: X32Reloc<&str> =
+        X32Reloc(X86_64Reloc::GlobalData { offset: 4, sym: "main" });
+
+    assert!(reloc.apply(&mut buf[0..], &X32_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x91, 0x04, 0x08,
+                     0xaa, 0xaa, 0xaa, 0xaa]);
+}
+
+#[test]
+fn test_X32_apply_Relative() {
+    let mut buf = [0xaa; 12];
+    let reloc: X32Reloc<&str> =
+        X32Reloc(X86_64Reloc::Relative { offset: 4, addend: 0x1234 });
+
+    assert!(reloc.apply(&mut buf[0..], &X32_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0xaa, 0xaa, 0xaa, 0xaa, 0x34, 0x12, 0x40, 0x00,
+                     0xaa, 0xaa, 0xaa, 0xaa]);
+}
+
+#[test]
+fn test_X32_apply_DTPMod() {
+    let mut buf = [0xaa; 12];
+    let env = ApplyEnv { tls: Some(TLSBases { module: 1, block: 0,
+                                              thread_ptr: 0 }),
+                         ..X32_ENV };
+    let reloc: X32Reloc<&str> =
+        X32Reloc(X86_64Reloc::DTPMod { offset: 4, sym: "main" });
+
+    assert!(reloc.apply(&mut buf[0..], &env, resolve32).is_ok());
+    assert_eq!(buf, [0xaa, 0xaa, 0xaa, 0xaa, 0x01, 0x00, 0x00, 0x00,
+                     0xaa, 0xaa, 0xaa, 0xaa]);
+}
+
+#[test]
+fn test_X32_apply_Abs64() {
+    let mut buf = [0xaa; 12];
+    let reloc: X32Reloc<&str> =
+        X32Reloc(X86_64Reloc::Abs64 { offset: 4, sym: "main", addend: 0 });
+
+    assert!(reloc.apply(&mut buf[0..], &X32_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0xaa, 0xaa, 0xaa, 0xaa, 0x00, 0x91, 0x04, 0x08,
+                     0x00, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X32_apply_unsupported() {
+    let mut buf = [0; 8];
+    let reloc: X32Reloc<&str> =
+        X32Reloc(X86_64Reloc::PC64 { offset: 0, sym: "main", addend: 0 });
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X32_ENV, resolve32),
+               Err(ApplyError::Unsupported));
+}
+
+#[test]
+fn test_X86_apply_PC32() {
+    let mut buf = [0xe8, 0xfc, 0xff, 0xff, 0xff];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::PC32 { offset: 1, sym: "main", addend: 0 };
+
+    assert!(reloc.apply_implicit(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0xe8, 0xfb, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_apply_implicit_slots() {
+    // A lazy PLT slot and a GOT slot with nonzero contents, which are
+    // replaced rather than added to.
+    let mut buf = [0x36, 0x83, 0x04, 0x08, 0x10, 0x00, 0x00, 0x00];
+    let slot: X86Reloc<&str> =
+        X86Reloc::JumpSlot { offset: 0, sym: "main" };
+    let data: X86Reloc<&str> =
+        X86Reloc::GlobalData { offset: 4, sym: "data" };
+
+    assert!(slot.apply_implicit(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert!(data.apply_implicit(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0x00, 0x91, 0x04, 0x08, 0x00, 0xd0, 0x04, 0x08]);
+}
+
+#[test]
+fn test_X86_apply_implicit_Relative() {
+    let mut buf = [0x10, 0x00, 0x00, 0x00];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::Relative { offset: 0, addend: 0 };
+
+    assert!(reloc.apply_implicit(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0x10, 0x80, 0x04, 0x08]);
+}
+
+#[test]
+fn test_X86_apply_Abs32() {
+    let mut buf = [0; 4];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::Abs32 { offset: 0, sym: "data", addend: 8 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0x08, 0xd0, 0x04, 0x08]);
+}
+
+#[test]
+fn test_X86_apply_GOTRel() {
+    let mut buf = [0; 4];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::GOTRel { offset: 0, sym: "main", addend: 0 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0x00, 0xd1, 0xff, 0xff]);
+}
+
+#[test]
+fn test_X86_apply_Abs16_overflow() {
+    let mut buf = [0; 2];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::Abs16 { offset: 0, sym: "main", addend: 0 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32),
+               Err(ApplyError::Overflow(0)));
+}
+
+#[test]
+fn test_X86_apply_PC8() {
+    let mut buf = [0; 0x100];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::PC8 { offset: 0xf9, sym: "main", addend: -1 };
+
+    assert!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf[0xf9], 0x06);
+}
+
+#[test]
+fn test_X86_apply_PC8_overflow() {
+    let mut buf = [0; 2];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::PC8 { offset: 0, sym: "main", addend: -1 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32),
+               Err(ApplyError::Overflow(0)));
+}
+
+#[test]
+fn test_X86_apply_misaligned() {
+    let mut buf = [0; 8];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::Relative { offset: 2, addend: 0x100 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32),
+               Err(ApplyError::Misaligned(2)));
+}
+
+#[test]
+fn test_X86_apply_PLTRel() {
+    let mut buf = [0; 8];
+    let puts: X86Reloc<&str> =
+        X86Reloc::PLTRel { offset: 0, sym: "puts", addend: -4 };
+    let printf: X86Reloc<&str> =
+        X86Reloc::PLTRel { offset: 4, sym: "printf", addend: -4 };
+
+    assert!(puts.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert!(printf.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0x0c, 0x10, 0x00, 0x00, 0x18, 0x10, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_apply_GOT32() {
+    let mut buf = [0; 8];
+    let puts: X86Reloc<&str> =
+        X86Reloc::GOT32 { offset: 0, sym: "puts", addend: 0 };
+    let printf: X86Reloc<&str> =
+        X86Reloc::GOT32 { offset: 4, sym: "printf", addend: 0 };
+
+    assert!(puts.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert!(printf.apply(&mut buf[0..], &X86_ENV, resolve32).is_ok());
+    assert_eq!(buf, [0x0c, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn test_X86_apply_no_PLT() {
+    let mut buf = [0; 4];
+    let reloc: X86Reloc<&str> =
+        X86Reloc::PLTRel { offset: 0, sym: "main", addend: -4 };
+
+    assert_eq!(reloc.apply(&mut buf[0..], &X86_ENV, resolve32),
+               Err(ApplyError::NoPLT(0)));
+}
@@ -0,0 +1,181 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Rels;
+use elf_utils::reloc::arm::ArmReloc;
+use elf_utils::reloc::arm::ArmRelocError;
+use elf_utils::reloc::arm::ArmToRelError;
+
+const ARM_RELS_SIZE: usize = 40;
+
+const ARM_RELS: [u8; ARM_RELS_SIZE] = [
+    0x28, 0x00, 0x00, 0x00, 0x1c, 0x04, 0x00, 0x00,
+    0x2c, 0x00, 0x00, 0x00, 0x1c, 0x05, 0x00, 0x00,
+    0x30, 0x00, 0x00, 0x00, 0x02, 0x06, 0x00, 0x00,
+    0x34, 0x00, 0x00, 0x00, 0x02, 0x07, 0x00, 0x00,
+    0x38, 0x00, 0x00, 0x00, 0x02, 0x08, 0x00, 0x00
+];
+
+const ARM_NUM_RELS: usize = 5;
+
+const ARM_RELS_CONTENTS: [ArmReloc<u32>; ARM_NUM_RELS] = [
+    ArmReloc::Call { offset: 0x28, sym: 4, addend: 0 },
+    ArmReloc::Call { offset: 0x2c, sym: 5, addend: 0 },
+    ArmReloc::Abs32 { offset: 0x30, sym: 6, addend: 0 },
+    ArmReloc::Abs32 { offset: 0x34, sym: 7, addend: 0 },
+    ArmReloc::Abs32 { offset: 0x38, sym: 8, addend: 0 }
+];
+
+const THUMB_RELS_SIZE: usize = 56;
+
+const THUMB_RELS: [u8; THUMB_RELS_SIZE] = [
+    0x02, 0x00, 0x00, 0x00, 0x2f, 0x04, 0x00, 0x00,
+    0x06, 0x00, 0x00, 0x00, 0x30, 0x04, 0x00, 0x00,
+    0x0a, 0x00, 0x00, 0x00, 0x2f, 0x04, 0x00, 0x00,
+    0x0e, 0x00, 0x00, 0x00, 0x30, 0x04, 0x00, 0x00,
+    0x12, 0x00, 0x00, 0x00, 0x0a, 0x05, 0x00, 0x00,
+    0x16, 0x00, 0x00, 0x00, 0x0a, 0x06, 0x00, 0x00,
+    0x1e, 0x00, 0x00, 0x00, 0x1e, 0x07, 0x00, 0x00
+];
+
+const THUMB_NUM_RELS: usize = 7;
+
+const THUMB_RELS_CONTENTS: [ArmReloc<u32>; THUMB_NUM_RELS] = [
+    ArmReloc::ThmMovWAbsNC { offset: 0x02, sym: 4, addend: 0 },
+    ArmReloc::ThmMovTAbs { offset: 0x06, sym: 4, addend: 0 },
+    ArmReloc::ThmMovWAbsNC { offset: 0x0a, sym: 4, addend: 0 },
+    ArmReloc::ThmMovTAbs { offset: 0x0e, sym: 4, addend: 0 },
+    ArmReloc::ThmCall { offset: 0x12, sym: 5, addend: 0 },
+    ArmReloc::ThmCall { offset: 0x16, sym: 6, addend: 0 },
+    ArmReloc::ThmJump24 { offset: 0x1e, sym: 7, addend: 0 }
+];
+
+const EXIDX_RELS_SIZE: usize = 16;
+
+const EXIDX_RELS: [u8; EXIDX_RELS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x2a, 0x01, 0x00, 0x00
+];
+
+const EXIDX_NUM_RELS: usize = 2;
+
+const EXIDX_RELS_CONTENTS: [ArmReloc<u32>; EXIDX_NUM_RELS] = [
+    ArmReloc::None,
+    ArmReloc::PRel31 { offset: 0x00, sym: 1, addend: 0 }
+];
+
+fn check_rels_iter(bytes: &[u8], expected: &[ArmReloc<u32>]) {
+    let rels: Rels<'_, LittleEndian, Elf32> =
+        Rels::try_from(bytes).expect("Expected success");
+    let mut iter = rels.iter();
+
+    for expect in expected.iter() {
+        let rel = iter.next();
+
+        assert!(rel.is_some());
+
+        let raw: RelData<u32, Elf32> = rel.unwrap().into();
+        let data: Result<ArmReloc<u32>, ArmRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_rels_idx(bytes: &[u8], expected: &[ArmReloc<u32>]) {
+    let rels: Rels<'_, LittleEndian, Elf32> =
+        Rels::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rel = rels.idx(i);
+
+        assert!(rel.is_some());
+
+        let raw: RelData<u32, Elf32> = rel.unwrap().into();
+        let data: Result<ArmReloc<u32>, ArmRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(rels.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_Rels_from_bytes_iter() {
+    check_rels_iter(&ARM_RELS[0..], &ARM_RELS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_Rels_from_bytes_idx() {
+    check_rels_idx(&ARM_RELS[0..], &ARM_RELS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_thumb_Rels_from_bytes_iter() {
+    check_rels_iter(&THUMB_RELS[0..], &THUMB_RELS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_thumb_Rels_from_bytes_idx() {
+    check_rels_idx(&THUMB_RELS[0..], &THUMB_RELS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_exidx_Rels_from_bytes_iter() {
+    check_rels_iter(&EXIDX_RELS[0..], &EXIDX_RELS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_exidx_Rels_from_bytes_idx() {
+    check_rels_idx(&EXIDX_RELS[0..], &EXIDX_RELS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_RelData_round_trip() {
+    for expect in THUMB_RELS_CONTENTS.iter() {
+        let raw: Result<RelData<u32, Elf32>, ArmToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let data: Result<ArmReloc<u32>, ArmRelocError> =
+            raw.unwrap().try_into();
+
+        assert_eq!(Ok(expect.clone()), data)
+    }
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: ArmReloc<u32> =
+        ArmReloc::ThmMovWAbsNC { offset: 0x02, sym: 4, addend: 8 };
+    let raw: Result<RelData<u32, Elf32>, ArmToRelError> = reloc.try_into();
+
+    assert_eq!(raw, Err(ArmToRelError::BadAddend(8)));
+}
+
+#[test]
+fn test_RelaData_round_trip() {
+    let expect: ArmReloc<u32> =
+        ArmReloc::ThmMovTAbs { offset: 0x0e, sym: 4, addend: -8 };
+    let raw: RelaData<u32, Elf32> = expect.clone().into();
+
+    assert_eq!(raw, RelaData { offset: 0x0e, sym: 4, kind: 48, addend: -8 });
+
+    let data: Result<ArmReloc<u32>, ArmRelocError> = raw.try_into();
+
+    assert_eq!(data, Ok(expect));
+}
@@ -0,0 +1,356 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::loongarch::LoongArchReloc;
+use elf_utils::reloc::loongarch::LoongArchRelocError;
+use elf_utils::reloc::loongarch::LoongArchToRelError;
+
+const LA64_TEXT_RELAS_SIZE: usize = 240;
+
+const LA64_TEXT_RELAS: [u8; LA64_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x47, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x48, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x4b, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x4c, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x6e, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const LA64_TEXT_NUM_RELAS: usize = 10;
+
+const LA64_TEXT_RELAS_CONTENTS: [LoongArchReloc<u32>; LA64_TEXT_NUM_RELAS] = [
+    LoongArchReloc::PCALAHi20 { offset: 0x00, sym: 4, addend: 0 },
+    LoongArchReloc::Relax { offset: 0x00 },
+    LoongArchReloc::PCALALo12 { offset: 0x04, sym: 4, addend: 0 },
+    LoongArchReloc::Relax { offset: 0x04 },
+    LoongArchReloc::GOTPCHi20 { offset: 0x08, sym: 5, addend: 0 },
+    LoongArchReloc::Relax { offset: 0x08 },
+    LoongArchReloc::GOTPCLo12 { offset: 0x0c, sym: 5, addend: 0 },
+    LoongArchReloc::Relax { offset: 0x0c },
+    LoongArchReloc::Call36 { offset: 0x14, sym: 6, addend: 0 },
+    LoongArchReloc::Relax { offset: 0x14 }
+];
+
+const LA64_DATA_RELAS_SIZE: usize = 72;
+
+const LA64_DATA_RELAS: [u8; LA64_DATA_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x32, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x37, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const LA64_DATA_NUM_RELAS: usize = 3;
+
+const LA64_DATA_RELAS_CONTENTS: [LoongArchReloc<u32>; LA64_DATA_NUM_RELAS] = [
+    LoongArchReloc::Abs64 { offset: 0x00, sym: 4, addend: 8 },
+    LoongArchReloc::Add32 { offset: 0x08, sym: 4, addend: 0 },
+    LoongArchReloc::Sub32 { offset: 0x08, sym: 5, addend: 0 }
+];
+
+const LA64_SOP_RELAS_SIZE: usize = 144;
+
+const LA64_SOP_RELAS: [u8; LA64_SOP_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x16, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0xfc, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x17, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x17, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const LA64_SOP_NUM_RELAS: usize = 6;
+
+const LA64_SOP_RELAS_CONTENTS: [LoongArchReloc<u32>; LA64_SOP_NUM_RELAS] = [
+    LoongArchReloc::SOPPushPCRel { offset: 0x00, sym: 6, addend: -4 },
+    LoongArchReloc::SOPPop32S0W10S10W16S2 { offset: 0x00 },
+    LoongArchReloc::SOPPushAbsolute { offset: 0x04, sym: 4, addend: 0 },
+    LoongArchReloc::SOPPushAbsolute { offset: 0x04, sym: 0, addend: 12 },
+    LoongArchReloc::SOPSR { offset: 0x04 },
+    LoongArchReloc::SOPPop32S5W20 { offset: 0x04 }
+];
+
+const LA32_TEXT_RELAS_SIZE: usize = 48;
+
+const LA32_TEXT_RELAS: [u8; LA32_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x47, 0x04, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x48, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x42, 0x06, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+    0x01, 0x05, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00
+];
+
+const LA32_TEXT_NUM_RELAS: usize = 4;
+
+const LA32_TEXT_RELAS_CONTENTS: [LoongArchReloc<u32>; LA32_TEXT_NUM_RELAS] = [
+    LoongArchReloc::PCALAHi20 { offset: 0x00, sym: 4, addend: 0 },
+    LoongArchReloc::PCALALo12 { offset: 0x04, sym: 4, addend: 0 },
+    LoongArchReloc::B26 { offset: 0x08, sym: 6, addend: 0 },
+    LoongArchReloc::Abs32 { offset: 0x0c, sym: 5, addend: 4 }
+];
+
+fn check_relas64_iter(bytes: &[u8], expected: &[LoongArchReloc<u32>]) {
+    let relas: Relas<'_, LittleEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas64_idx(bytes: &[u8], expected: &[LoongArchReloc<u32>]) {
+    let relas: Relas<'_, LittleEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_la64_text_Relas_from_bytes_iter() {
+    check_relas64_iter(&LA64_TEXT_RELAS[0..], &LA64_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_la64_text_Relas_from_bytes_idx() {
+    check_relas64_idx(&LA64_TEXT_RELAS[0..], &LA64_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_la64_data_Relas_from_bytes_iter() {
+    check_relas64_iter(&LA64_DATA_RELAS[0..], &LA64_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_la64_data_Relas_from_bytes_idx() {
+    check_relas64_idx(&LA64_DATA_RELAS[0..], &LA64_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_la64_sop_Relas_from_bytes_iter() {
+    check_relas64_iter(&LA64_SOP_RELAS[0..], &LA64_SOP_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_la64_sop_Relas_from_bytes_idx() {
+    check_relas64_idx(&LA64_SOP_RELAS[0..], &LA64_SOP_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_la32_text_Relas_from_bytes_iter() {
+    let relas: Relas<'_, LittleEndian, Elf32> =
+        Relas::try_from(&LA32_TEXT_RELAS[0..]).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in LA32_TEXT_RELAS_CONTENTS.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_la32_text_Relas_from_bytes_idx() {
+    let relas: Relas<'_, LittleEndian, Elf32> =
+        Relas::try_from(&LA32_TEXT_RELAS[0..]).expect("Expected success");
+
+    for i in 0 .. LA32_TEXT_NUM_RELAS {
+        let expect = &LA32_TEXT_RELAS_CONTENTS[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+            raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(LA32_TEXT_NUM_RELAS).is_none());
+}
+
+#[test]
+fn test_RelaData_round_trip() {
+    let text = LA64_TEXT_RELAS_CONTENTS.iter();
+    let data = LA64_DATA_RELAS_CONTENTS.iter();
+    let sop = LA64_SOP_RELAS_CONTENTS.iter();
+
+    for expect in text.chain(data).chain(sop) {
+        let raw: RelaData<u32, Elf64> = expect.clone().into();
+        let actual: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+            raw.try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_Elf32_RelaData_round_trip() {
+    for expect in LA32_TEXT_RELAS_CONTENTS.iter() {
+        let raw: Result<RelaData<u32, Elf32>, LoongArchToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelData_round_trip() {
+    let expect: LoongArchReloc<u32> = LoongArchReloc::Sub32 {
+        offset: 0x08, sym: 5, addend: 0
+    };
+    let raw: Result<RelData<u32, Elf64>, LoongArchToRelError> =
+        expect.clone().try_into();
+
+    assert_eq!(raw, Ok(RelData { offset: 0x08, sym: 5, kind: 55 }));
+
+    let actual: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+        raw.unwrap().try_into();
+
+    assert_eq!(actual, Ok(expect));
+}
+
+#[test]
+fn test_Elf32_RelaData_bad_offset() {
+    let reloc: LoongArchReloc<u32> = LoongArchReloc::Abs64 {
+        offset: 0x1_0000_0000, sym: 1, addend: 0
+    };
+    let raw: Result<RelaData<u32, Elf32>, LoongArchToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(LoongArchToRelError::BadOffset(0x1_0000_0000)));
+}
+
+#[test]
+fn test_Elf32_RelaData_bad_addend() {
+    let reloc: LoongArchReloc<u32> = LoongArchReloc::PCALAHi20 {
+        offset: 0x10, sym: 1, addend: -0x1_0000_0000
+    };
+    let raw: Result<RelaData<u32, Elf32>, LoongArchToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(LoongArchToRelError::BadAddend(-0x1_0000_0000)));
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: LoongArchReloc<u32> = LoongArchReloc::SOPPushPCRel {
+        offset: 0x00, sym: 6, addend: -4
+    };
+    let raw: Result<RelData<u32, Elf64>, LoongArchToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(LoongArchToRelError::BadAddend(-4)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0, sym: 0, kind: 101,
+                                               addend: 0 };
+    let data: Result<LoongArchReloc<u32>, LoongArchRelocError> =
+        raw.try_into();
+
+    assert_eq!(data, Err(LoongArchRelocError::BadTag(101)));
+}
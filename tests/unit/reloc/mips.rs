@@ -0,0 +1,306 @@
+use byteorder::BigEndian;
+use byteorder::ByteOrder;
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::reloc::RelClass;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Rels;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::mips::Mips64;
+use elf_utils::reloc::mips::Mips64RelKind;
+use elf_utils::reloc::mips::MipsReloc;
+use elf_utils::reloc::mips::MipsRelocError;
+use elf_utils::reloc::mips::MipsRelType;
+use elf_utils::reloc::mips::MipsSpecialSym;
+use elf_utils::reloc::mips::MipsToRelError;
+use elf_utils::reloc::mips::matching_lo16;
+use elf_utils::reloc::mips::pair_addend;
+use elf_utils::reloc::mips::split_hi_lo;
+
+const MIPS64_RELAS_SIZE: usize = 120;
+
+const MIPS64EL_RELAS: [u8; MIPS64_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x00, 0x00, 0x00, 0x00, 0x05, 0x18, 0x0c,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x07, 0x00, 0x00, 0x00, 0x00, 0x06, 0x18, 0x0c,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0b,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x25,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x12, 0x03,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const MIPS64EB_RELAS: [u8; MIPS64_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x07, 0x00, 0x05, 0x18, 0x0c,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x07, 0x00, 0x06, 0x18, 0x0c,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x0b,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x25,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+    0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x12, 0x03,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10
+];
+
+const MIPS64_NUM_RELAS: usize = 5;
+
+const MIPS64_RELAS_CONTENTS: [MipsReloc<u32>; MIPS64_NUM_RELAS] = [
+    MipsReloc { offset: 0x00, sym: 7, kind: MipsRelType::GPRel32,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::Sub,
+                kind3: MipsRelType::Hi16, addend: 0 },
+    MipsReloc { offset: 0x04, sym: 7, kind: MipsRelType::GPRel32,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::Sub,
+                kind3: MipsRelType::Lo16, addend: 0 },
+    MipsReloc { offset: 0x08, sym: 3, kind: MipsRelType::Call16,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::None,
+                kind3: MipsRelType::None, addend: 0 },
+    MipsReloc { offset: 0x0c, sym: 3, kind: MipsRelType::Jalr,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::None,
+                kind3: MipsRelType::None, addend: 0 },
+    MipsReloc { offset: 0x20, sym: 5, kind: MipsRelType::Rel32,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::Abs64,
+                kind3: MipsRelType::None, addend: 0x10 }
+];
+
+const MIPS32_RELS_SIZE: usize = 32;
+
+const MIPS32_RELS: [u8; MIPS32_RELS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x05, 0x04, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x06, 0x04, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x0b, 0x06, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00
+];
+
+const MIPS32_NUM_RELS: usize = 4;
+
+const MIPS32_RELS_CONTENTS: [MipsReloc<u32>; MIPS32_NUM_RELS] = [
+    MipsReloc { offset: 0x00, sym: 4, kind: MipsRelType::Hi16,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::None,
+                kind3: MipsRelType::None, addend: 0 },
+    MipsReloc { offset: 0x04, sym: 4, kind: MipsRelType::Lo16,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::None,
+                kind3: MipsRelType::None, addend: 0 },
+    MipsReloc { offset: 0x08, sym: 6, kind: MipsRelType::Call16,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::None,
+                kind3: MipsRelType::None, addend: 0 },
+    MipsReloc { offset: 0x10, sym: 2, kind: MipsRelType::Abs32,
+                ssym: MipsSpecialSym::Undef, kind2: MipsRelType::None,
+                kind3: MipsRelType::None, addend: 0 }
+];
+
+fn check_relas64_iter<B: ByteOrder>(bytes: &[u8],
+                                    expected: &[MipsReloc<u32>]) {
+    let relas: Relas<'_, B, Mips64> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Mips64> = rela.unwrap().into();
+        let data: Result<MipsReloc<u32>, MipsRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas64_idx<B: ByteOrder>(bytes: &[u8],
+                                   expected: &[MipsReloc<u32>]) {
+    let relas: Relas<'_, B, Mips64> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Mips64> = rela.unwrap().into();
+        let data: Result<MipsReloc<u32>, MipsRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_mips64el_Relas_from_bytes_iter() {
+    check_relas64_iter::<LittleEndian>(&MIPS64EL_RELAS[0..],
+                                       &MIPS64_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_mips64el_Relas_from_bytes_idx() {
+    check_relas64_idx::<LittleEndian>(&MIPS64EL_RELAS[0..],
+                                      &MIPS64_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_mips64eb_Relas_from_bytes_iter() {
+    check_relas64_iter::<BigEndian>(&MIPS64EB_RELAS[0..],
+                                    &MIPS64_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_mips64eb_Relas_from_bytes_idx() {
+    check_relas64_idx::<BigEndian>(&MIPS64EB_RELAS[0..],
+                                   &MIPS64_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_mips32_Rels_from_bytes_iter() {
+    let rels: Rels<'_, LittleEndian, Elf32> =
+        Rels::try_from(&MIPS32_RELS[0..]).expect("Expected success");
+    let mut iter = rels.iter();
+
+    for expect in MIPS32_RELS_CONTENTS.iter() {
+        let rel = iter.next();
+
+        assert!(rel.is_some());
+
+        let raw: RelData<u32, Elf32> = rel.unwrap().into();
+        let data: Result<MipsReloc<u32>, MipsRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Mips64_write_info() {
+    for (bytes, expect) in MIPS64EL_RELAS.chunks(24)
+                                         .zip(MIPS64_RELAS_CONTENTS.iter()) {
+        let raw: RelaData<u32, Mips64> = expect.clone().into();
+        let mut info = [0; 8];
+
+        Mips64::write_info::<LittleEndian>(&mut info, raw.kind, raw.sym);
+
+        assert_eq!(&bytes[8..16], &info[0..]);
+    }
+}
+
+#[test]
+fn test_Mips64_RelaData_round_trip() {
+    for expect in MIPS64_RELAS_CONTENTS.iter() {
+        let raw: RelaData<u32, Mips64> = expect.clone().into();
+        let actual: Result<MipsReloc<u32>, MipsRelocError> = raw.try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_Elf32_RelData_round_trip() {
+    for expect in MIPS32_RELS_CONTENTS.iter() {
+        let raw: Result<RelData<u32, Elf32>, MipsToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<MipsReloc<u32>, MipsRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_Elf32_RelaData_composed() {
+    let raw: Result<RelaData<u32, Elf32>, MipsToRelError> =
+        MIPS64_RELAS_CONTENTS[0].clone().try_into();
+
+    assert_eq!(raw, Err(MipsToRelError::Composed));
+}
+
+#[test]
+fn test_Elf32_RelData_bad_offset() {
+    let mut reloc = MIPS32_RELS_CONTENTS[3].clone();
+
+    reloc.offset = 0x1_0000_0000;
+
+    let raw: Result<RelData<u32, Elf32>, MipsToRelError> = reloc.try_into();
+
+    assert_eq!(raw, Err(MipsToRelError::BadOffset(0x1_0000_0000)));
+}
+
+#[test]
+fn test_Mips64_RelData_bad_addend() {
+    let raw: Result<RelData<u32, Mips64>, MipsToRelError> =
+        MIPS64_RELAS_CONTENTS[4].clone().try_into();
+
+    assert_eq!(raw, Err(MipsToRelError::BadAddend(0x10)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let kind = Mips64RelKind { kind: 2, ssym: 0, kind2: 13, kind3: 0 };
+    let raw: RelaData<u32, Mips64> = RelaData { offset: 0, sym: 0,
+                                                kind: kind, addend: 0 };
+    let data: Result<MipsReloc<u32>, MipsRelocError> = raw.try_into();
+
+    assert_eq!(data, Err(MipsRelocError::BadTag(13)));
+}
+
+#[test]
+fn test_RelaData_bad_special_sym() {
+    let kind = Mips64RelKind { kind: 7, ssym: 4, kind2: 24, kind3: 0 };
+    let raw: RelaData<u32, Mips64> = RelaData { offset: 0, sym: 0,
+                                                kind: kind, addend: 0 };
+    let data: Result<MipsReloc<u32>, MipsRelocError> = raw.try_into();
+
+    assert_eq!(data, Err(MipsRelocError::BadSpecialSym(4)));
+}
+
+#[test]
+fn test_matching_lo16() {
+    assert_eq!(matching_lo16(&MIPS32_RELS_CONTENTS[0..], 0), Some(1));
+    assert_eq!(matching_lo16(&MIPS32_RELS_CONTENTS[0..], 2), None);
+    assert_eq!(matching_lo16(&MIPS32_RELS_CONTENTS[0..1], 0), None);
+    assert_eq!(matching_lo16(&MIPS32_RELS_CONTENTS[0..], 4), None);
+}
+
+#[test]
+fn test_split_hi_lo_round_trip() {
+    for value in [0, 0x7fff, 0x8000, 0x1234_5678, 0x7fff_8000,
+                  -0x8000, -0x1234_5678].iter() {
+        let (hi, lo) = split_hi_lo(*value);
+
+        assert_eq!(pair_addend(hi, lo), *value);
+    }
+}
@@ -1,4 +1,16 @@
+mod aarch64;
+mod android;
+mod apply;
+mod arm;
+mod loongarch;
+mod mips;
+mod ppc;
+mod ppc64;
 mod rel;
 mod rela;
+mod relr;
+mod riscv;
+mod s390x;
+mod sparc;
 mod x86;
 mod x86_64;
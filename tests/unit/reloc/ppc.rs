@@ -0,0 +1,169 @@
+use byteorder::BigEndian;
+use byteorder::ByteOrder;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::ppc::PpcReloc;
+use elf_utils::reloc::ppc::PpcRelocError;
+use elf_utils::reloc::ppc::PpcToRelError;
+
+const PPC_TEXT_RELAS_SIZE: usize = 96;
+
+const PPC_TEXT_RELAS: [u8; PPC_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x02, 0x06,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06,
+    0x00, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x02, 0x05,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c,
+    0x00, 0x00, 0x03, 0x0a, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x04, 0x12,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x16,
+    0x00, 0x00, 0x05, 0x0e, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x1a, 0x00, 0x00, 0x06, 0x48,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1e,
+    0x00, 0x00, 0x06, 0x46, 0x00, 0x00, 0x00, 0x00
+];
+
+const PPC_TEXT_NUM_RELAS: usize = 8;
+
+const PPC_TEXT_RELAS_CONTENTS: [PpcReloc<u32>; PPC_TEXT_NUM_RELAS] = [
+    PpcReloc::Addr16HA { offset: 0x02, sym: 2, addend: 0 },
+    PpcReloc::Addr16Lo { offset: 0x06, sym: 2, addend: 0 },
+    PpcReloc::Addr16Hi { offset: 0x0a, sym: 2, addend: 0 },
+    PpcReloc::Rel24 { offset: 0x0c, sym: 3, addend: 0 },
+    PpcReloc::PLTRel24 { offset: 0x10, sym: 4, addend: 0 },
+    PpcReloc::GOT16 { offset: 0x16, sym: 5, addend: 0 },
+    PpcReloc::TPRel16HA { offset: 0x1a, sym: 6, addend: 0 },
+    PpcReloc::TPRel16Lo { offset: 0x1e, sym: 6, addend: 0 }
+];
+
+const PPC_DATA_RELAS_SIZE: usize = 12;
+
+const PPC_DATA_RELAS: [u8; PPC_DATA_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x01,
+    0x00, 0x00, 0x00, 0x00
+];
+
+const PPC_DATA_NUM_RELAS: usize = 1;
+
+const PPC_DATA_RELAS_CONTENTS: [PpcReloc<u32>; PPC_DATA_NUM_RELAS] = [
+    PpcReloc::Addr32 { offset: 0x00, sym: 5, addend: 0 }
+];
+
+fn check_relas_iter<B: ByteOrder>(bytes: &[u8], expected: &[PpcReloc<u32>]) {
+    let relas: Relas<'_, B, Elf32> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<PpcReloc<u32>, PpcRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas_idx<B: ByteOrder>(bytes: &[u8], expected: &[PpcReloc<u32>]) {
+    let relas: Relas<'_, B, Elf32> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<PpcReloc<u32>, PpcRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_text_Relas_from_bytes_iter() {
+    check_relas_iter::<BigEndian>(&PPC_TEXT_RELAS[0..],
+                                  &PPC_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_text_Relas_from_bytes_idx() {
+    check_relas_idx::<BigEndian>(&PPC_TEXT_RELAS[0..],
+                                 &PPC_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_data_Relas_from_bytes_iter() {
+    check_relas_iter::<BigEndian>(&PPC_DATA_RELAS[0..],
+                                  &PPC_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_data_Relas_from_bytes_idx() {
+    check_relas_idx::<BigEndian>(&PPC_DATA_RELAS[0..],
+                                 &PPC_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_RelaData_round_trip() {
+    for expect in PPC_TEXT_RELAS_CONTENTS.iter() {
+        let raw: RelaData<u32, Elf32> = expect.clone().into();
+        let actual: Result<PpcReloc<u32>, PpcRelocError> = raw.try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelData_round_trip() {
+    for expect in PPC_TEXT_RELAS_CONTENTS.iter() {
+        let raw: Result<RelData<u32, Elf32>, PpcToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<PpcReloc<u32>, PpcRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: PpcReloc<u32> = PpcReloc::Addr16Lo { offset: 0x6, sym: 2,
+                                                    addend: 4 };
+    let raw: Result<RelData<u32, Elf32>, PpcToRelError> = reloc.try_into();
+
+    assert_eq!(raw, Err(PpcToRelError::BadAddend(4)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf32> = RelaData { offset: 0, sym: 0, kind: 37,
+                                               addend: 0 };
+    let data: Result<PpcReloc<u32>, PpcRelocError> = raw.try_into();
+
+    assert_eq!(data, Err(PpcRelocError::BadTag(37)));
+}
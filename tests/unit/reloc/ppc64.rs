@@ -0,0 +1,292 @@
+use byteorder::BigEndian;
+use byteorder::ByteOrder;
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf;
+use elf_utils::Elf64;
+use elf_utils::ElfByteOrder;
+use elf_utils::ElfHdrDataRaw;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::ppc64::Ppc64Reloc;
+use elf_utils::reloc::ppc64::Ppc64RelocError;
+use elf_utils::reloc::ppc64::Ppc64ToRelError;
+use elf_utils::reloc::ppc64::local_entry_offset;
+use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionHdrDataRaw;
+use elf_utils::section_hdr::SectionHdrOffsets;
+use elf_utils::section_hdr::SectionHdrs;
+use elf_utils::section_hdr::SectionPos;
+use std::fs::read;
+
+const PPC64_BE_PATH: &'static str = "tests/data/relocatable/ppc64/reloc.o";
+
+const PPC64_LE_PATH: &'static str = "tests/data/relocatable/ppc64le/reloc.o";
+
+const PPC64_BE_TEXT_RELAS_IDX: usize = 3;
+
+const PPC64_BE_OPD_RELAS_IDX: usize = 5;
+
+const PPC64_BE_DATA_RELAS_IDX: usize = 7;
+
+const PPC64_LE_TEXT_RELAS_IDX: usize = 3;
+
+const PPC64_LE_DATA_RELAS_IDX: usize = 5;
+
+const PPC64_BE_TEXT_NUM_RELAS: usize = 16;
+
+const PPC64_BE_TEXT_RELAS_CONTENTS: [Ppc64Reloc<u32>;
+                                     PPC64_BE_TEXT_NUM_RELAS] = [
+    Ppc64Reloc::TOC16HA { offset: 0x02, sym: 4, addend: 0 },
+    Ppc64Reloc::TOC16Lo { offset: 0x06, sym: 4, addend: 0 },
+    Ppc64Reloc::TOC16LoDS { offset: 0x0a, sym: 4, addend: 0 },
+    Ppc64Reloc::Addr16HA { offset: 0x0e, sym: 4, addend: 0 },
+    Ppc64Reloc::Addr16Lo { offset: 0x12, sym: 4, addend: 0 },
+    Ppc64Reloc::Addr16Hi { offset: 0x16, sym: 4, addend: 0 },
+    Ppc64Reloc::Rel24 { offset: 0x18, sym: 7, addend: 0 },
+    Ppc64Reloc::GOTTLSGD16HA { offset: 0x22, sym: 8, addend: 0 },
+    Ppc64Reloc::GOTTLSGD16Lo { offset: 0x26, sym: 8, addend: 0 },
+    Ppc64Reloc::TLSGD { offset: 0x28, sym: 8, addend: 0 },
+    Ppc64Reloc::Rel24 { offset: 0x28, sym: 9, addend: 0 },
+    Ppc64Reloc::TPRel16HA { offset: 0x32, sym: 3, addend: 0 },
+    Ppc64Reloc::TPRel16Lo { offset: 0x36, sym: 3, addend: 0 },
+    Ppc64Reloc::GOTTPRel16HA { offset: 0x3a, sym: 10, addend: 0 },
+    Ppc64Reloc::GOTTPRel16LoDS { offset: 0x3e, sym: 10, addend: 0 },
+    Ppc64Reloc::TLS { offset: 0x40, sym: 10, addend: 0 }
+];
+
+const PPC64_BE_OPD_NUM_RELAS: usize = 2;
+
+const PPC64_BE_OPD_RELAS_CONTENTS: [Ppc64Reloc<u32>; PPC64_BE_OPD_NUM_RELAS] = [
+    Ppc64Reloc::Addr64 { offset: 0x00, sym: 1, addend: 0 },
+    Ppc64Reloc::TOC { offset: 0x08, addend: 0 }
+];
+
+const PPC64_BE_DATA_NUM_RELAS: usize = 3;
+
+const PPC64_BE_DATA_RELAS_CONTENTS: [Ppc64Reloc<u32>;
+                                     PPC64_BE_DATA_NUM_RELAS] = [
+    Ppc64Reloc::Addr64 { offset: 0x00, sym: 5, addend: 0 },
+    Ppc64Reloc::Addr64 { offset: 0x08, sym: 7, addend: 16 },
+    Ppc64Reloc::Rel32 { offset: 0x10, sym: 7, addend: 0 }
+];
+
+const PPC64_LE_TEXT_NUM_RELAS: usize = 18;
+
+const PPC64_LE_TEXT_RELAS_CONTENTS: [Ppc64Reloc<u32>;
+                                     PPC64_LE_TEXT_NUM_RELAS] = [
+    Ppc64Reloc::Rel16HA { offset: 0x00, sym: 5, addend: 0 },
+    Ppc64Reloc::Rel16Lo { offset: 0x04, sym: 5, addend: 4 },
+    Ppc64Reloc::TOC16HA { offset: 0x08, sym: 3, addend: 0 },
+    Ppc64Reloc::TOC16Lo { offset: 0x0c, sym: 3, addend: 0 },
+    Ppc64Reloc::TOC16LoDS { offset: 0x10, sym: 3, addend: 0 },
+    Ppc64Reloc::Addr16HA { offset: 0x14, sym: 3, addend: 0 },
+    Ppc64Reloc::Addr16Lo { offset: 0x18, sym: 3, addend: 0 },
+    Ppc64Reloc::Addr16Hi { offset: 0x1c, sym: 3, addend: 0 },
+    Ppc64Reloc::Rel24 { offset: 0x20, sym: 6, addend: 0 },
+    Ppc64Reloc::GOTTLSGD16HA { offset: 0x28, sym: 7, addend: 0 },
+    Ppc64Reloc::GOTTLSGD16Lo { offset: 0x2c, sym: 7, addend: 0 },
+    Ppc64Reloc::TLSGD { offset: 0x30, sym: 7, addend: 0 },
+    Ppc64Reloc::Rel24 { offset: 0x30, sym: 8, addend: 0 },
+    Ppc64Reloc::TPRel16HA { offset: 0x38, sym: 2, addend: 0 },
+    Ppc64Reloc::TPRel16Lo { offset: 0x3c, sym: 2, addend: 0 },
+    Ppc64Reloc::GOTTPRel16HA { offset: 0x40, sym: 9, addend: 0 },
+    Ppc64Reloc::GOTTPRel16LoDS { offset: 0x44, sym: 9, addend: 0 },
+    Ppc64Reloc::TLS { offset: 0x48, sym: 9, addend: 0 }
+];
+
+const PPC64_LE_DATA_NUM_RELAS: usize = 3;
+
+const PPC64_LE_DATA_RELAS_CONTENTS: [Ppc64Reloc<u32>;
+                                     PPC64_LE_DATA_NUM_RELAS] = [
+    Ppc64Reloc::Addr64 { offset: 0x00, sym: 4, addend: 0 },
+    Ppc64Reloc::Addr64 { offset: 0x08, sym: 6, addend: 16 },
+    Ppc64Reloc::Rel32 { offset: 0x10, sym: 6, addend: 0 }
+];
+
+fn rela_section<B: ElfByteOrder>(data: &[u8], idx: usize) -> &[u8] {
+    let elf: Elf<'_, B, Elf64> =
+        Elf::try_from(data).expect("Expected success");
+    let hdr: ElfHdrDataRaw<B, Elf64> =
+        elf.try_into().expect("Expected success");
+    let start = hdr.section_hdrs.offset as usize;
+    let end = start + (hdr.section_hdrs.num_ents as usize *
+                       Elf64::SECTION_HDR_SIZE);
+    let hdrs: SectionHdrs<'_, B, Elf64> =
+        SectionHdrs::try_from(&data[start .. end])
+        .expect("Expected success");
+    let hdr: SectionHdrDataRaw<Elf64> =
+        hdrs.idx(idx).expect("Expected some").try_into()
+        .expect("Expected success");
+
+    match hdr {
+        SectionHdrData::Rela { relas: SectionPos { offset, size }, .. } =>
+            &data[offset as usize .. (offset + size) as usize],
+        _ => panic!("Expected Rela section, got {:?}", hdr)
+    }
+}
+
+fn check_relas_iter<B: ByteOrder>(bytes: &[u8], expected: &[Ppc64Reloc<u32>]) {
+    let relas: Relas<'_, B, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<Ppc64Reloc<u32>, Ppc64RelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas_idx<B: ByteOrder>(bytes: &[u8], expected: &[Ppc64Reloc<u32>]) {
+    let relas: Relas<'_, B, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<Ppc64Reloc<u32>, Ppc64RelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_big_endian_Relas_from_bytes_iter() {
+    let data = read(PPC64_BE_PATH).expect("Expected success");
+
+    check_relas_iter::<BigEndian>(
+        rela_section::<BigEndian>(&data, PPC64_BE_TEXT_RELAS_IDX),
+        &PPC64_BE_TEXT_RELAS_CONTENTS[0..]);
+    check_relas_iter::<BigEndian>(
+        rela_section::<BigEndian>(&data, PPC64_BE_OPD_RELAS_IDX),
+        &PPC64_BE_OPD_RELAS_CONTENTS[0..]);
+    check_relas_iter::<BigEndian>(
+        rela_section::<BigEndian>(&data, PPC64_BE_DATA_RELAS_IDX),
+        &PPC64_BE_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_big_endian_Relas_from_bytes_idx() {
+    let data = read(PPC64_BE_PATH).expect("Expected success");
+
+    check_relas_idx::<BigEndian>(
+        rela_section::<BigEndian>(&data, PPC64_BE_TEXT_RELAS_IDX),
+        &PPC64_BE_TEXT_RELAS_CONTENTS[0..]);
+    check_relas_idx::<BigEndian>(
+        rela_section::<BigEndian>(&data, PPC64_BE_OPD_RELAS_IDX),
+        &PPC64_BE_OPD_RELAS_CONTENTS[0..]);
+    check_relas_idx::<BigEndian>(
+        rela_section::<BigEndian>(&data, PPC64_BE_DATA_RELAS_IDX),
+        &PPC64_BE_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_little_endian_Relas_from_bytes_iter() {
+    let data = read(PPC64_LE_PATH).expect("Expected success");
+
+    check_relas_iter::<LittleEndian>(
+        rela_section::<LittleEndian>(&data, PPC64_LE_TEXT_RELAS_IDX),
+        &PPC64_LE_TEXT_RELAS_CONTENTS[0..]);
+    check_relas_iter::<LittleEndian>(
+        rela_section::<LittleEndian>(&data, PPC64_LE_DATA_RELAS_IDX),
+        &PPC64_LE_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_little_endian_Relas_from_bytes_idx() {
+    let data = read(PPC64_LE_PATH).expect("Expected success");
+
+    check_relas_idx::<LittleEndian>(
+        rela_section::<LittleEndian>(&data, PPC64_LE_TEXT_RELAS_IDX),
+        &PPC64_LE_TEXT_RELAS_CONTENTS[0..]);
+    check_relas_idx::<LittleEndian>(
+        rela_section::<LittleEndian>(&data, PPC64_LE_DATA_RELAS_IDX),
+        &PPC64_LE_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_RelaData_round_trip() {
+    let relocs = PPC64_LE_TEXT_RELAS_CONTENTS.iter()
+                 .chain(PPC64_LE_DATA_RELAS_CONTENTS.iter());
+
+    for expect in relocs {
+        let raw: RelaData<u32, Elf64> = expect.clone().into();
+        let actual: Result<Ppc64Reloc<u32>, Ppc64RelocError> = raw.try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelData_round_trip() {
+    let relocs = PPC64_BE_TEXT_RELAS_CONTENTS.iter()
+                 .chain(PPC64_BE_OPD_RELAS_CONTENTS.iter());
+
+    for expect in relocs {
+        let raw: Result<RelData<u32, Elf64>, Ppc64ToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<Ppc64Reloc<u32>, Ppc64RelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: Ppc64Reloc<u32> = Ppc64Reloc::Addr16Lo { offset: 0x6, sym: 2,
+                                                        addend: 4 };
+    let raw: Result<RelData<u32, Elf64>, Ppc64ToRelError> = reloc.try_into();
+
+    assert_eq!(raw, Err(Ppc64ToRelError::BadAddend(4)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0, sym: 0, kind: 119,
+                                               addend: 0 };
+    let data: Result<Ppc64Reloc<u32>, Ppc64RelocError> = raw.try_into();
+
+    assert_eq!(data, Err(Ppc64RelocError::BadTag(119)));
+}
+
+#[test]
+fn test_local_entry_offset() {
+    // st_other for a function with a two-instruction global entry.
+    assert_eq!(local_entry_offset(0x60), Some(8));
+    assert_eq!(local_entry_offset(0x00), Some(0));
+    assert_eq!(local_entry_offset(0x20), Some(0));
+    assert_eq!(local_entry_offset(0x40), Some(4));
+    assert_eq!(local_entry_offset(0xc0), Some(64));
+    assert_eq!(local_entry_offset(0xe0), None);
+    assert_eq!(local_entry_offset(0x63), Some(8));
+}
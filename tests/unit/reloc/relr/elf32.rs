@@ -0,0 +1,193 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use elf_utils::Elf32;
+use elf_utils::reloc;
+use elf_utils::reloc::Relrs;
+use elf_utils::reloc::RelrsError;
+
+const ELF32_RELRS_SIZE: usize = 32;
+
+const ELF32_RELRS: [u8; ELF32_RELRS_SIZE] = [
+    0x00, 0x20, 0x00, 0x00, 0x17, 0x00, 0x00, 0x00,
+    0x00, 0x21, 0x00, 0x00, 0x6d, 0xdb, 0xb6, 0x6d,
+    0xb7, 0x6d, 0xdb, 0xb6, 0xdb, 0xb6, 0x01, 0x00,
+    0x00, 0x30, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00
+];
+
+const ELF32_NUM_RELR_ENTS: usize = 8;
+
+const ELF32_NUM_RELRS: usize = 59;
+
+const ELF32_RELRS_CONTENTS: [u32; ELF32_NUM_RELRS] = [
+    0x2000, 0x2004, 0x2008, 0x2010, 0x2100, 0x2108,
+    0x210c, 0x2114, 0x2118, 0x2120, 0x2124, 0x212c,
+    0x2130, 0x2138, 0x213c, 0x2144, 0x2148, 0x2150,
+    0x2154, 0x215c, 0x2160, 0x2168, 0x216c, 0x2174,
+    0x2178, 0x2180, 0x2184, 0x218c, 0x2190, 0x2198,
+    0x219c, 0x21a4, 0x21a8, 0x21b0, 0x21b4, 0x21bc,
+    0x21c0, 0x21c8, 0x21cc, 0x21d4, 0x21d8, 0x21e0,
+    0x21e4, 0x21ec, 0x21f0, 0x21f8, 0x21fc, 0x2204,
+    0x2208, 0x2210, 0x2214, 0x221c, 0x2220, 0x2228,
+    0x222c, 0x2234, 0x2238, 0x3000, 0x300c
+];
+
+#[test]
+fn test_Relrs_from_bytes_just_right() {
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::try_from(&ELF32_RELRS[0..]);
+
+    assert!(relrs.is_ok());
+}
+
+#[test]
+fn test_Relrs_from_bytes_too_small() {
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::try_from(&ELF32_RELRS[0 .. ELF32_RELRS.len() - 1]);
+
+    assert!(relrs.is_err());
+}
+
+#[test]
+fn test_Relrs_from_bytes_num_entries() {
+    let relrs: Relrs<'_, LittleEndian, Elf32> =
+        Relrs::try_from(&ELF32_RELRS[0..]).expect("Expected success");
+
+    assert_eq!(relrs.num_entries(), ELF32_NUM_RELR_ENTS);
+}
+
+#[test]
+fn test_Relrs_from_bytes_num_relocs() {
+    let relrs: Relrs<'_, LittleEndian, Elf32> =
+        Relrs::try_from(&ELF32_RELRS[0..]).expect("Expected success");
+
+    assert_eq!(relrs.num_relocs(), ELF32_NUM_RELRS);
+}
+
+#[test]
+fn test_Relrs_from_bytes_just_right_mut() {
+    let mut buf = ELF32_RELRS.clone();
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::try_from(&mut buf[0..]);
+
+    assert!(relrs.is_ok());
+}
+
+#[test]
+fn test_Relrs_from_bytes_too_small_mut() {
+    let mut buf = ELF32_RELRS.clone();
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::try_from(&mut buf[0 .. ELF32_RELRS.len() - 1]);
+
+    assert!(relrs.is_err());
+}
+
+#[test]
+fn test_Relrs_from_bytes_iter() {
+    let relrs: Relrs<'_, LittleEndian, Elf32> =
+        Relrs::try_from(&ELF32_RELRS[0..]).expect("Expected success");
+    let mut iter = relrs.iter();
+
+    for expect in ELF32_RELRS_CONTENTS.iter() {
+        let relr = iter.next();
+
+        assert!(relr.is_some());
+        assert_eq!(expect, &relr.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Relrs_required_bytes() {
+    let size = reloc::relrs_required_bytes::<_, Elf32>(
+        ELF32_RELRS_CONTENTS.iter()
+    );
+
+    assert_eq!(size, Ok(ELF32_RELRS_SIZE));
+}
+
+#[test]
+fn test_Relrs_create_just_right() {
+    let mut buf = [0; ELF32_RELRS_SIZE];
+    let relrs: Result<(Relrs<'_, LittleEndian, Elf32>, &'_ mut [u8]),
+                      RelrsError> =
+        Relrs::create_split(&mut buf[0..], ELF32_RELRS_CONTENTS.iter());
+
+    assert!(relrs.is_ok());
+
+    let (_, buf) = relrs.expect("Expected success");
+
+    assert_eq!(buf.len(), 0);
+}
+
+#[test]
+fn test_Relrs_create_too_big() {
+    let mut buf = [0; ELF32_RELRS_SIZE + 1];
+    let relrs: Result<(Relrs<'_, LittleEndian, Elf32>, &'_ mut [u8]),
+                      RelrsError> =
+        Relrs::create_split(&mut buf[0..], ELF32_RELRS_CONTENTS.iter());
+
+    assert!(relrs.is_ok());
+
+    let (_, buf) = relrs.expect("Expected success");
+
+    assert_eq!(buf.len(), 1);
+}
+
+#[test]
+fn test_Relrs_create_too_small() {
+    let mut buf = [0; ELF32_RELRS_SIZE - 1];
+    let relrs: Result<(Relrs<'_, LittleEndian, Elf32>, &'_ mut [u8]),
+                      RelrsError> =
+        Relrs::create_split(&mut buf[0..], ELF32_RELRS_CONTENTS.iter());
+
+    assert_eq!(relrs.err(), Some(RelrsError::TooShort));
+}
+
+#[test]
+fn test_Relrs_create_bytes() {
+    let mut buf = [0; ELF32_RELRS_SIZE];
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::create(&mut buf[0..], ELF32_RELRS_CONTENTS.iter());
+
+    assert!(relrs.is_ok());
+    assert_eq!(buf, ELF32_RELRS);
+}
+
+#[test]
+fn test_Relrs_create_iter() {
+    let mut buf = [0; ELF32_RELRS_SIZE];
+    let relrs: Relrs<'_, LittleEndian, Elf32> =
+        Relrs::create(&mut buf[0..], ELF32_RELRS_CONTENTS.iter())
+        .expect("Expected success");
+    let mut iter = relrs.iter();
+
+    for expect in ELF32_RELRS_CONTENTS.iter() {
+        let relr = iter.next();
+
+        assert!(relr.is_some());
+        assert_eq!(expect, &relr.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Relrs_create_unsorted() {
+    let mut buf = [0; ELF32_RELRS_SIZE];
+    let offsets: [u32; 3] = [0xA000, 0xB000, 0xA000 + 4];
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::create(&mut buf[0..], offsets.iter());
+
+    assert_eq!(relrs.err(), Some(RelrsError::BadOffset(0xA000 + 4)));
+}
+
+#[test]
+fn test_Relrs_create_misaligned() {
+    let mut buf = [0; ELF32_RELRS_SIZE];
+    let offsets: [u32; 2] = [0xA000, 0xA001];
+    let relrs: Result<Relrs<'_, LittleEndian, Elf32>, RelrsError> =
+        Relrs::create(&mut buf[0..], offsets.iter());
+
+    assert_eq!(relrs.err(), Some(RelrsError::BadOffset(0xA001)));
+}
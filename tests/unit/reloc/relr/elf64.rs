@@ -0,0 +1,194 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use elf_utils::Elf64;
+use elf_utils::reloc;
+use elf_utils::reloc::Relrs;
+use elf_utils::reloc::RelrsError;
+
+const ELF64_RELRS_SIZE: usize = 40;
+
+const ELF64_RELRS: [u8; ELF64_RELRS_SIZE] = [
+    0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x17, 0x00, 0x00, 0x00, 0x6d, 0xdb, 0xb6, 0x6d,
+    0xb7, 0x6d, 0xdb, 0xb6, 0x6d, 0xdb, 0x00, 0x00,
+    0x00, 0x10, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF64_NUM_RELR_ENTS: usize = 5;
+
+const ELF64_NUM_RELRS: usize = 59;
+
+const ELF64_RELRS_CONTENTS: [u64; ELF64_NUM_RELRS] = [
+    0x10000, 0x10008, 0x10010, 0x10020, 0x10100, 0x10110,
+    0x10118, 0x10128, 0x10130, 0x10140, 0x10148, 0x10158,
+    0x10160, 0x10170, 0x10178, 0x10188, 0x10190, 0x101a0,
+    0x101a8, 0x101b8, 0x101c0, 0x101d0, 0x101d8, 0x101e8,
+    0x101f0, 0x10200, 0x10208, 0x10218, 0x10220, 0x10230,
+    0x10238, 0x10248, 0x10250, 0x10260, 0x10268, 0x10278,
+    0x10280, 0x10290, 0x10298, 0x102a8, 0x102b0, 0x102c0,
+    0x102c8, 0x102d8, 0x102e0, 0x102f0, 0x102f8, 0x10308,
+    0x10310, 0x10320, 0x10328, 0x10338, 0x10340, 0x10350,
+    0x10358, 0x10368, 0x10370, 0x11000, 0x11018
+];
+
+#[test]
+fn test_Relrs_from_bytes_just_right() {
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::try_from(&ELF64_RELRS[0..]);
+
+    assert!(relrs.is_ok());
+}
+
+#[test]
+fn test_Relrs_from_bytes_too_small() {
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::try_from(&ELF64_RELRS[0 .. ELF64_RELRS.len() - 1]);
+
+    assert!(relrs.is_err());
+}
+
+#[test]
+fn test_Relrs_from_bytes_num_entries() {
+    let relrs: Relrs<'_, LittleEndian, Elf64> =
+        Relrs::try_from(&ELF64_RELRS[0..]).expect("Expected success");
+
+    assert_eq!(relrs.num_entries(), ELF64_NUM_RELR_ENTS);
+}
+
+#[test]
+fn test_Relrs_from_bytes_num_relocs() {
+    let relrs: Relrs<'_, LittleEndian, Elf64> =
+        Relrs::try_from(&ELF64_RELRS[0..]).expect("Expected success");
+
+    assert_eq!(relrs.num_relocs(), ELF64_NUM_RELRS);
+}
+
+#[test]
+fn test_Relrs_from_bytes_just_right_mut() {
+    let mut buf = ELF64_RELRS.clone();
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::try_from(&mut buf[0..]);
+
+    assert!(relrs.is_ok());
+}
+
+#[test]
+fn test_Relrs_from_bytes_too_small_mut() {
+    let mut buf = ELF64_RELRS.clone();
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::try_from(&mut buf[0 .. ELF64_RELRS.len() - 1]);
+
+    assert!(relrs.is_err());
+}
+
+#[test]
+fn test_Relrs_from_bytes_iter() {
+    let relrs: Relrs<'_, LittleEndian, Elf64> =
+        Relrs::try_from(&ELF64_RELRS[0..]).expect("Expected success");
+    let mut iter = relrs.iter();
+
+    for expect in ELF64_RELRS_CONTENTS.iter() {
+        let relr = iter.next();
+
+        assert!(relr.is_some());
+        assert_eq!(expect, &relr.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Relrs_required_bytes() {
+    let size = reloc::relrs_required_bytes::<_, Elf64>(
+        ELF64_RELRS_CONTENTS.iter()
+    );
+
+    assert_eq!(size, Ok(ELF64_RELRS_SIZE));
+}
+
+#[test]
+fn test_Relrs_create_just_right() {
+    let mut buf = [0; ELF64_RELRS_SIZE];
+    let relrs: Result<(Relrs<'_, LittleEndian, Elf64>, &'_ mut [u8]),
+                      RelrsError> =
+        Relrs::create_split(&mut buf[0..], ELF64_RELRS_CONTENTS.iter());
+
+    assert!(relrs.is_ok());
+
+    let (_, buf) = relrs.expect("Expected success");
+
+    assert_eq!(buf.len(), 0);
+}
+
+#[test]
+fn test_Relrs_create_too_big() {
+    let mut buf = [0; ELF64_RELRS_SIZE + 1];
+    let relrs: Result<(Relrs<'_, LittleEndian, Elf64>, &'_ mut [u8]),
+                      RelrsError> =
+        Relrs::create_split(&mut buf[0..], ELF64_RELRS_CONTENTS.iter());
+
+    assert!(relrs.is_ok());
+
+    let (_, buf) = relrs.expect("Expected success");
+
+    assert_eq!(buf.len(), 1);
+}
+
+#[test]
+fn test_Relrs_create_too_small() {
+    let mut buf = [0; ELF64_RELRS_SIZE - 1];
+    let relrs: Result<(Relrs<'_, LittleEndian, Elf64>, &'_ mut [u8]),
+                      RelrsError> =
+        Relrs::create_split(&mut buf[0..], ELF64_RELRS_CONTENTS.iter());
+
+    assert_eq!(relrs.err(), Some(RelrsError::TooShort));
+}
+
+#[test]
+fn test_Relrs_create_bytes() {
+    let mut buf = [0; ELF64_RELRS_SIZE];
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::create(&mut buf[0..], ELF64_RELRS_CONTENTS.iter());
+
+    assert!(relrs.is_ok());
+    assert_eq!(buf, ELF64_RELRS);
+}
+
+#[test]
+fn test_Relrs_create_iter() {
+    let mut buf = [0; ELF64_RELRS_SIZE];
+    let relrs: Relrs<'_, LittleEndian, Elf64> =
+        Relrs::create(&mut buf[0..], ELF64_RELRS_CONTENTS.iter())
+        .expect("Expected success");
+    let mut iter = relrs.iter();
+
+    for expect in ELF64_RELRS_CONTENTS.iter() {
+        let relr = iter.next();
+
+        assert!(relr.is_some());
+        assert_eq!(expect, &relr.unwrap())
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Relrs_create_unsorted() {
+    let mut buf = [0; ELF64_RELRS_SIZE];
+    let offsets: [u64; 3] = [0xA000, 0xB000, 0xA000 + 8];
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::create(&mut buf[0..], offsets.iter());
+
+    assert_eq!(relrs.err(), Some(RelrsError::BadOffset(0xA000 + 8)));
+}
+
+#[test]
+fn test_Relrs_create_misaligned() {
+    let mut buf = [0; ELF64_RELRS_SIZE];
+    let offsets: [u64; 2] = [0xA000, 0xA001];
+    let relrs: Result<Relrs<'_, LittleEndian, Elf64>, RelrsError> =
+        Relrs::create(&mut buf[0..], offsets.iter());
+
+    assert_eq!(relrs.err(), Some(RelrsError::BadOffset(0xA001)));
+}
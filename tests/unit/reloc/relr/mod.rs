@@ -0,0 +1,2 @@
+mod elf32;
+mod elf64;
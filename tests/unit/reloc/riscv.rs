@@ -0,0 +1,374 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::riscv::RiscVReloc;
+use elf_utils::reloc::riscv::RiscVRelocError;
+use elf_utils::reloc::riscv::RiscVToRelError;
+
+const RV64_TEXT_RELAS_SIZE: usize = 312;
+
+const RV64_TEXT_RELAS: [u8; RV64_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1a, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1b, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1c, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x17, 0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x18, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x12, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x2b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const RV64_TEXT_NUM_RELAS: usize = 13;
+
+const RV64_TEXT_RELAS_CONTENTS: [RiscVReloc<u32>; RV64_TEXT_NUM_RELAS] = [
+    RiscVReloc::Hi20 { offset: 0x00, sym: 4, addend: 0 },
+    RiscVReloc::Relax { offset: 0x00 },
+    RiscVReloc::Lo12I { offset: 0x04, sym: 4, addend: 0 },
+    RiscVReloc::Relax { offset: 0x04 },
+    RiscVReloc::Lo12S { offset: 0x08, sym: 5, addend: 0 },
+    RiscVReloc::Relax { offset: 0x08 },
+    RiscVReloc::PCRelHi20 { offset: 0x0c, sym: 6, addend: 0 },
+    RiscVReloc::Relax { offset: 0x0c },
+    RiscVReloc::PCRelLo12I { offset: 0x10, sym: 1, addend: 0 },
+    RiscVReloc::Relax { offset: 0x10 },
+    RiscVReloc::Call { offset: 0x14, sym: 7, addend: 0 },
+    RiscVReloc::Relax { offset: 0x14 },
+    RiscVReloc::Align { offset: 0x1c, addend: 4 }
+];
+
+const RV64_DATA_RELAS_SIZE: usize = 96;
+
+const RV64_DATA_RELAS: [u8; RV64_DATA_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x23, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x27, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x21, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x25, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const RV64_DATA_NUM_RELAS: usize = 4;
+
+const RV64_DATA_RELAS_CONTENTS: [RiscVReloc<u32>; RV64_DATA_NUM_RELAS] = [
+    RiscVReloc::Add32 { offset: 0x00, sym: 2, addend: 0 },
+    RiscVReloc::Sub32 { offset: 0x00, sym: 3, addend: 0 },
+    RiscVReloc::Add8 { offset: 0x04, sym: 2, addend: 0 },
+    RiscVReloc::Sub8 { offset: 0x04, sym: 3, addend: 0 }
+];
+
+const RV64_SET_RELAS_SIZE: usize = 144;
+
+const RV64_SET_RELAS: [u8; RV64_SET_RELAS_SIZE] = [
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x35, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x34, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x36, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x25, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3c, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x12, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x3d, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const RV64_SET_NUM_RELAS: usize = 6;
+
+const RV64_SET_RELAS_CONTENTS: [RiscVReloc<u32>; RV64_SET_NUM_RELAS] = [
+    RiscVReloc::Set6 { offset: 0x10, sym: 2, addend: 0 },
+    RiscVReloc::Sub6 { offset: 0x10, sym: 3, addend: 0 },
+    RiscVReloc::Set8 { offset: 0x11, sym: 2, addend: 0 },
+    RiscVReloc::Sub8 { offset: 0x11, sym: 3, addend: 0 },
+    RiscVReloc::SetULEB128 { offset: 0x12, sym: 2, addend: 0 },
+    RiscVReloc::SubULEB128 { offset: 0x12, sym: 3, addend: 0 }
+];
+
+const RV32_TEXT_RELAS_SIZE: usize = 156;
+
+const RV32_TEXT_RELAS: [u8; RV32_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x1a, 0x04, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x1b, 0x04, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x1c, 0x05, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x0c, 0x00, 0x00, 0x00, 0x17, 0x06, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x18, 0x01, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x14, 0x00, 0x00, 0x00, 0x12, 0x07, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00,
+    0x33, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x1c, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00
+];
+
+const RV32_TEXT_NUM_RELAS: usize = 13;
+
+const RV32_TEXT_RELAS_CONTENTS: [RiscVReloc<u32>; RV32_TEXT_NUM_RELAS] = [
+    RiscVReloc::Hi20 { offset: 0x00, sym: 4, addend: 0 },
+    RiscVReloc::Relax { offset: 0x00 },
+    RiscVReloc::Lo12I { offset: 0x04, sym: 4, addend: 0 },
+    RiscVReloc::Relax { offset: 0x04 },
+    RiscVReloc::Lo12S { offset: 0x08, sym: 5, addend: 0 },
+    RiscVReloc::Relax { offset: 0x08 },
+    RiscVReloc::PCRelHi20 { offset: 0x0c, sym: 6, addend: 0 },
+    RiscVReloc::Relax { offset: 0x0c },
+    RiscVReloc::PCRelLo12I { offset: 0x10, sym: 1, addend: 0 },
+    RiscVReloc::Relax { offset: 0x10 },
+    RiscVReloc::Call { offset: 0x14, sym: 7, addend: 0 },
+    RiscVReloc::Relax { offset: 0x14 },
+    RiscVReloc::Align { offset: 0x1c, addend: 4 }
+];
+
+fn check_relas64_iter(bytes: &[u8], expected: &[RiscVReloc<u32>]) {
+    let relas: Relas<'_, LittleEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<RiscVReloc<u32>, RiscVRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas64_idx(bytes: &[u8], expected: &[RiscVReloc<u32>]) {
+    let relas: Relas<'_, LittleEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<RiscVReloc<u32>, RiscVRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_rv64_text_Relas_from_bytes_iter() {
+    check_relas64_iter(&RV64_TEXT_RELAS[0..], &RV64_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_rv64_text_Relas_from_bytes_idx() {
+    check_relas64_idx(&RV64_TEXT_RELAS[0..], &RV64_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_rv64_data_Relas_from_bytes_iter() {
+    check_relas64_iter(&RV64_DATA_RELAS[0..], &RV64_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_rv64_data_Relas_from_bytes_idx() {
+    check_relas64_idx(&RV64_DATA_RELAS[0..], &RV64_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_rv64_set_Relas_from_bytes_iter() {
+    check_relas64_iter(&RV64_SET_RELAS[0..], &RV64_SET_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_rv64_set_Relas_from_bytes_idx() {
+    check_relas64_idx(&RV64_SET_RELAS[0..], &RV64_SET_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_rv32_text_Relas_from_bytes_iter() {
+    let relas: Relas<'_, LittleEndian, Elf32> =
+        Relas::try_from(&RV32_TEXT_RELAS[0..]).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in RV32_TEXT_RELAS_CONTENTS.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<RiscVReloc<u32>, RiscVRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_rv32_text_Relas_from_bytes_idx() {
+    let relas: Relas<'_, LittleEndian, Elf32> =
+        Relas::try_from(&RV32_TEXT_RELAS[0..]).expect("Expected success");
+
+    for i in 0 .. RV32_TEXT_NUM_RELAS {
+        let expect = &RV32_TEXT_RELAS_CONTENTS[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<RiscVReloc<u32>, RiscVRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(RV32_TEXT_NUM_RELAS).is_none());
+}
+
+#[test]
+fn test_Elf32_RelaData_round_trip() {
+    for expect in RV32_TEXT_RELAS_CONTENTS.iter() {
+        let raw: Result<RelaData<u32, Elf32>, RiscVToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<RiscVReloc<u32>, RiscVRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_Elf32_RelData_round_trip() {
+    for expect in RV64_SET_RELAS_CONTENTS.iter() {
+        let raw: Result<RelData<u32, Elf32>, RiscVToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<RiscVReloc<u32>, RiscVRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_Elf32_RelaData_bad_offset() {
+    let reloc: RiscVReloc<u32> = RiscVReloc::Abs64 {
+        offset: 0x1_0000_0000, sym: 1, addend: 0
+    };
+    let raw: Result<RelaData<u32, Elf32>, RiscVToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(RiscVToRelError::BadOffset(0x1_0000_0000)));
+}
+
+#[test]
+fn test_Elf32_RelaData_bad_addend() {
+    let reloc: RiscVReloc<u32> = RiscVReloc::Hi20 {
+        offset: 0x10, sym: 1, addend: -0x1_0000_0000
+    };
+    let raw: Result<RelaData<u32, Elf32>, RiscVToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(RiscVToRelError::BadAddend(-0x1_0000_0000)));
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: RiscVReloc<u32> = RiscVReloc::Add32 {
+        offset: 0x4, sym: 2, addend: 8
+    };
+    let raw: Result<RelData<u32, Elf64>, RiscVToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(RiscVToRelError::BadAddend(8)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0, sym: 0, kind: 48,
+                                               addend: 0 };
+    let data: Result<RiscVReloc<u32>, RiscVRelocError> = raw.try_into();
+
+    assert_eq!(data, Err(RiscVRelocError::BadTag(48)));
+}
@@ -0,0 +1,165 @@
+use byteorder::BigEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf64;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::s390x::S390xReloc;
+use elf_utils::reloc::s390x::S390xRelocError;
+use elf_utils::reloc::s390x::S390xToRelError;
+
+const TEXT_RELAS_SIZE: usize = 72;
+
+const TEXT_RELAS: [u8; TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x13,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0e,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x1a,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02
+];
+
+const TEXT_NUM_RELAS: usize = 3;
+
+const TEXT_RELAS_CONTENTS: [S390xReloc<u32>; TEXT_NUM_RELAS] = [
+    S390xReloc::PC32DBL { offset: 0x02, sym: 2, addend: 2 },
+    S390xReloc::PLT32DBL { offset: 0x08, sym: 3, addend: 2 },
+    S390xReloc::GOTEnt { offset: 0x0e, sym: 3, addend: 2 }
+];
+
+const DATA_RELAS_SIZE: usize = 48;
+
+const DATA_RELAS: [u8; DATA_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x16,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const DATA_NUM_RELAS: usize = 2;
+
+const DATA_RELAS_CONTENTS: [S390xReloc<u32>; DATA_NUM_RELAS] = [
+    S390xReloc::Abs64 { offset: 0x00, sym: 3, addend: 16 },
+    S390xReloc::Abs32 { offset: 0x08, sym: 1, addend: 0 }
+];
+
+fn check_relas_iter(bytes: &[u8], expected: &[S390xReloc<u32>]) {
+    let relas: Relas<'_, BigEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<S390xReloc<u32>, S390xRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas_idx(bytes: &[u8], expected: &[S390xReloc<u32>]) {
+    let relas: Relas<'_, BigEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<S390xReloc<u32>, S390xRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_text_Relas_from_bytes_iter() {
+    check_relas_iter(&TEXT_RELAS[0..], &TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_text_Relas_from_bytes_idx() {
+    check_relas_idx(&TEXT_RELAS[0..], &TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_data_Relas_from_bytes_iter() {
+    check_relas_iter(&DATA_RELAS[0..], &DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_data_Relas_from_bytes_idx() {
+    check_relas_idx(&DATA_RELAS[0..], &DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_RelaData_round_trip() {
+    for expect in TEXT_RELAS_CONTENTS.iter()
+                                     .chain(DATA_RELAS_CONTENTS.iter()) {
+        let raw: RelaData<u32, Elf64> = expect.clone().into();
+        let actual: Result<S390xReloc<u32>, S390xRelocError> =
+            raw.try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelData_round_trip() {
+    let expect: S390xReloc<u32> = S390xReloc::Abs32 {
+        offset: 0x08, sym: 1, addend: 0
+    };
+    let raw: Result<RelData<u32, Elf64>, S390xToRelError> =
+        expect.clone().try_into();
+
+    assert_eq!(raw, Ok(RelData { offset: 0x08, sym: 1, kind: 4 }));
+
+    let actual: Result<S390xReloc<u32>, S390xRelocError> =
+        raw.unwrap().try_into();
+
+    assert_eq!(actual, Ok(expect));
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: S390xReloc<u32> = S390xReloc::PC32DBL {
+        offset: 0x02, sym: 2, addend: 2
+    };
+    let raw: Result<RelData<u32, Elf64>, S390xToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(S390xToRelError::BadAddend(2)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0, sym: 0, kind: 66,
+                                               addend: 0 };
+    let data: Result<S390xReloc<u32>, S390xRelocError> = raw.try_into();
+
+    assert_eq!(data, Err(S390xRelocError::BadTag(66)));
+}
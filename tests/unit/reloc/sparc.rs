@@ -0,0 +1,295 @@
+use byteorder::BigEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf32;
+use elf_utils::Elf64;
+use elf_utils::reloc::RelData;
+use elf_utils::reloc::RelaData;
+use elf_utils::reloc::Relas;
+use elf_utils::reloc::sparc::SparcReloc;
+use elf_utils::reloc::sparc::SparcRelocError;
+use elf_utils::reloc::sparc::SparcToRelError;
+
+const SPARC64_TEXT_RELAS_SIZE: usize = 216;
+
+const SPARC64_TEXT_RELAS: [u8; SPARC64_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x09,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0c,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x07,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x22,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x14,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x23,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x24,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1c,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x32,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x33,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24,
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x34,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const SPARC64_TEXT_NUM_RELAS: usize = 9;
+
+const SPARC64_TEXT_RELAS_CONTENTS: [SparcReloc<u32>; SPARC64_TEXT_NUM_RELAS] = [
+    SparcReloc::Hi22 { offset: 0x00, sym: 2, addend: 0 },
+    SparcReloc::Lo10 { offset: 0x04, sym: 2, addend: 0 },
+    SparcReloc::WDisp30 { offset: 0x08, sym: 3, addend: 0 },
+    SparcReloc::HH22 { offset: 0x10, sym: 2, addend: 0 },
+    SparcReloc::HM10 { offset: 0x14, sym: 2, addend: 0 },
+    SparcReloc::LM22 { offset: 0x18, sym: 2, addend: 0 },
+    SparcReloc::H44 { offset: 0x1c, sym: 2, addend: 0 },
+    SparcReloc::M44 { offset: 0x20, sym: 2, addend: 0 },
+    SparcReloc::L44 { offset: 0x24, sym: 2, addend: 0 }
+];
+
+const SPARC64_DATA_RELAS_SIZE: usize = 48;
+
+const SPARC64_DATA_RELAS: [u8; SPARC64_DATA_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x36,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x08,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0c,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x03,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const SPARC64_DATA_NUM_RELAS: usize = 2;
+
+const SPARC64_DATA_RELAS_CONTENTS: [SparcReloc<u32>; SPARC64_DATA_NUM_RELAS] = [
+    SparcReloc::UAbs64 { offset: 0x04, sym: 3, addend: 8 },
+    SparcReloc::Abs32 { offset: 0x0c, sym: 1, addend: 0 }
+];
+
+const SPARC32_TEXT_RELAS_SIZE: usize = 60;
+
+const SPARC32_TEXT_RELAS: [u8; SPARC32_TEXT_RELAS_SIZE] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x09,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+    0x00, 0x00, 0x02, 0x0c, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x08, 0x00, 0x00, 0x03, 0x07,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+    0x00, 0x00, 0x02, 0x38, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x02, 0x39,
+    0x00, 0x00, 0x00, 0x00
+];
+
+const SPARC32_TEXT_NUM_RELAS: usize = 5;
+
+const SPARC32_TEXT_RELAS_CONTENTS: [SparcReloc<u32>; SPARC32_TEXT_NUM_RELAS] = [
+    SparcReloc::Hi22 { offset: 0x00, sym: 2, addend: 0 },
+    SparcReloc::Lo10 { offset: 0x04, sym: 2, addend: 0 },
+    SparcReloc::WDisp30 { offset: 0x08, sym: 3, addend: 0 },
+    SparcReloc::TLSGDHi22 { offset: 0x10, sym: 2, addend: 0 },
+    SparcReloc::TLSGDLo10 { offset: 0x14, sym: 2, addend: 0 }
+];
+
+fn check_relas64_iter(bytes: &[u8], expected: &[SparcReloc<u32>]) {
+    let relas: Relas<'_, BigEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<SparcReloc<u32>, SparcRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+fn check_relas64_idx(bytes: &[u8], expected: &[SparcReloc<u32>]) {
+    let relas: Relas<'_, BigEndian, Elf64> =
+        Relas::try_from(bytes).expect("Expected success");
+
+    for i in 0 .. expected.len() {
+        let expect = &expected[i];
+        let rela = relas.idx(i);
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf64> = rela.unwrap().into();
+        let data: Result<SparcReloc<u32>, SparcRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(relas.idx(expected.len()).is_none());
+}
+
+#[test]
+fn test_sparc64_text_Relas_from_bytes_iter() {
+    check_relas64_iter(&SPARC64_TEXT_RELAS[0..],
+                       &SPARC64_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_sparc64_text_Relas_from_bytes_idx() {
+    check_relas64_idx(&SPARC64_TEXT_RELAS[0..],
+                      &SPARC64_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_sparc64_data_Relas_from_bytes_iter() {
+    check_relas64_iter(&SPARC64_DATA_RELAS[0..],
+                       &SPARC64_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_sparc64_data_Relas_from_bytes_idx() {
+    check_relas64_idx(&SPARC64_DATA_RELAS[0..],
+                      &SPARC64_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_sparc32_text_Relas_from_bytes_iter() {
+    let relas: Relas<'_, BigEndian, Elf32> =
+        Relas::try_from(&SPARC32_TEXT_RELAS[0..]).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in SPARC32_TEXT_RELAS_CONTENTS.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<SparcReloc<u32>, SparcRelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Elf32_RelaData_round_trip() {
+    for expect in SPARC32_TEXT_RELAS_CONTENTS.iter() {
+        let raw: Result<RelaData<u32, Elf32>, SparcToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<SparcReloc<u32>, SparcRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_Elf32_RelData_round_trip() {
+    for expect in SPARC64_TEXT_RELAS_CONTENTS.iter() {
+        let raw: Result<RelData<u32, Elf32>, SparcToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<SparcReloc<u32>, SparcRelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_RelaData_OLO10() {
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0x8, sym: 2,
+                                               kind: 0xffff_f821,
+                                               addend: 0x10 };
+    let data: Result<SparcReloc<u32>, SparcRelocError> = raw.try_into();
+
+    assert_eq!(data, Ok(SparcReloc::OLO10 { offset: 0x8, sym: 2,
+                                            addend: 0x10,
+                                            secondary: -8 }));
+
+    let raw: Result<RelaData<u32, Elf64>, SparcToRelError> =
+        data.unwrap().try_into();
+
+    assert_eq!(raw, Ok(RelaData { offset: 0x8, sym: 2, kind: 0xffff_f821,
+                                  addend: 0x10 }));
+}
+
+#[test]
+fn test_RelaData_OLO10_bad_secondary() {
+    let reloc: SparcReloc<u32> = SparcReloc::OLO10 {
+        offset: 0x8, sym: 2, addend: 0, secondary: 0x80_0000
+    };
+    let raw: Result<RelaData<u32, Elf64>, SparcToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(SparcToRelError::BadSecondary(0x80_0000)));
+}
+
+#[test]
+fn test_Elf32_RelaData_OLO10_bad_secondary() {
+    let reloc: SparcReloc<u32> = SparcReloc::OLO10 {
+        offset: 0x8, sym: 2, addend: 0, secondary: 4
+    };
+    let raw: Result<RelaData<u32, Elf32>, SparcToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(SparcToRelError::BadSecondary(4)));
+}
+
+#[test]
+fn test_Elf32_RelaData_bad_offset() {
+    let reloc: SparcReloc<u32> = SparcReloc::Abs64 {
+        offset: 0x1_0000_0000, sym: 1, addend: 0
+    };
+    let raw: Result<RelaData<u32, Elf32>, SparcToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(SparcToRelError::BadOffset(0x1_0000_0000)));
+}
+
+#[test]
+fn test_RelData_bad_addend() {
+    let reloc: SparcReloc<u32> = SparcReloc::UAbs64 {
+        offset: 0x4, sym: 3, addend: 8
+    };
+    let raw: Result<RelData<u32, Elf64>, SparcToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(SparcToRelError::BadAddend(8)));
+}
+
+#[test]
+fn test_RelaData_bad_tag() {
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0, sym: 0,
+                                               kind: 0x109, addend: 0 };
+    let data: Result<SparcReloc<u32>, SparcRelocError> = raw.try_into();
+
+    assert_eq!(data, Err(SparcRelocError::BadTag(0x109)));
+}
@@ -1,11 +1,29 @@
 use byteorder::LittleEndian;
 use core::convert::TryFrom;
 use core::convert::TryInto;
+use elf_utils::Elf;
+use elf_utils::Elf32;
 use elf_utils::Elf64;
+use elf_utils::ElfHdrDataRaw;
+use elf_utils::reloc::RelData;
 use elf_utils::reloc::RelaData;
 use elf_utils::reloc::Relas;
+use elf_utils::reloc::x86_64::X32Reloc;
 use elf_utils::reloc::x86_64::X86_64Reloc;
 use elf_utils::reloc::x86_64::X86_64RelocError;
+use elf_utils::reloc::x86_64::X86_64RelocStr;
+use elf_utils::reloc::x86_64::X86_64RelocStrSym;
+use elf_utils::reloc::x86_64::X86_64ToRelError;
+use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionHdrDataRaw;
+use elf_utils::section_hdr::SectionHdrOffsets;
+use elf_utils::section_hdr::SectionHdrs;
+use elf_utils::section_hdr::SectionPos;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::WithStrtab;
+use elf_utils::symtab::Symtab;
+use elf_utils::symtab::WithSymtab;
+use std::fs::read;
 
 const X86_64_RELAS_SIZE: usize = 600;
 
@@ -117,6 +135,94 @@ const X86_64_RELAS_CONTENTS: [X86_64Reloc<u32>; X86_64_NUM_RELAS] = [
     X86_64Reloc::Abs32Signed { offset: 0x279, sym: 23, addend: -8 }
 ];
 
+const X32_PATH: &'static str = "tests/data/relocatable/x32/reloc.o";
+
+const X32_STRTAB_IDX: usize = 1;
+
+const X32_TEXT_RELAS_IDX: usize = 3;
+
+const X32_DATA_RELAS_IDX: usize = 5;
+
+const X32_SYMTAB_IDX: usize = 6;
+
+const X32_TEXT_NUM_RELAS: usize = 7;
+
+const X32_TEXT_RELAS_CONTENTS: [X86_64Reloc<u32>; X32_TEXT_NUM_RELAS] = [
+    X86_64Reloc::PLTRel { offset: 0x01, sym: 4, addend: -4 },
+    X86_64Reloc::GOTPCRelX { offset: 0x07, sym: 5, addend: -4 },
+    X86_64Reloc::RexGOTPCRelX { offset: 0x0e, sym: 5, addend: -4 },
+    X86_64Reloc::PC32 { offset: 0x14, sym: 2, addend: -4 },
+    X86_64Reloc::RexGOTPCRelX { offset: 0x1b, sym: 5, addend: -4 },
+    X86_64Reloc::Abs32 { offset: 0x20, sym: 5, addend: 0 },
+    X86_64Reloc::Abs32 { offset: 0x25, sym: 2, addend: 0 }
+];
+
+const X32_DATA_NUM_RELAS: usize = 4;
+
+const X32_DATA_RELAS_CONTENTS: [X86_64Reloc<u32>; X32_DATA_NUM_RELAS] = [
+    X86_64Reloc::Abs32 { offset: 0x00, sym: 2, addend: 0 },
+    X86_64Reloc::Abs64 { offset: 0x04, sym: 5, addend: 0 },
+    X86_64Reloc::Abs64 { offset: 0x0c, sym: 2, addend: 8 },
+    X86_64Reloc::PC32 { offset: 0x14, sym: 3, addend: 0 }
+];
+
+const X32_TEXT_RELAS_STRS: [X86_64RelocStr<'static>; X32_TEXT_NUM_RELAS] = [
+    X86_64Reloc::PLTRel { offset: 0x01, sym: Some("g"), addend: -4 },
+    X86_64Reloc::GOTPCRelX { offset: 0x07, sym: Some("ext"), addend: -4 },
+    X86_64Reloc::RexGOTPCRelX { offset: 0x0e, sym: Some("ext"), addend: -4 },
+    X86_64Reloc::PC32 { offset: 0x14, sym: None, addend: -4 },
+    X86_64Reloc::RexGOTPCRelX { offset: 0x1b, sym: Some("ext"), addend: -4 },
+    X86_64Reloc::Abs32 { offset: 0x20, sym: Some("ext"), addend: 0 },
+    X86_64Reloc::Abs32 { offset: 0x25, sym: None, addend: 0 }
+];
+
+fn x32_section(data: &[u8], idx: usize) -> &[u8] {
+    let elf: Elf<'_, LittleEndian, Elf32> =
+        Elf::try_from(data).expect("Expected success");
+    let hdr: ElfHdrDataRaw<LittleEndian, Elf32> =
+        elf.try_into().expect("Expected success");
+    let start = hdr.section_hdrs.offset as usize;
+    let end = start + (hdr.section_hdrs.num_ents as usize *
+                       Elf32::SECTION_HDR_SIZE);
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf32> =
+        SectionHdrs::try_from(&data[start .. end])
+        .expect("Expected success");
+    let hdr: SectionHdrDataRaw<Elf32> =
+        hdrs.idx(idx).expect("Expected some").try_into()
+        .expect("Expected success");
+    let SectionPos { offset, size } = match hdr {
+        SectionHdrData::Rela { relas, .. } => relas,
+        SectionHdrData::Symtab { syms, .. } => syms,
+        SectionHdrData::Strtab { strs, .. } => strs,
+        _ => panic!("Unexpected section header {:?}", hdr)
+    };
+
+    &data[offset as usize .. (offset + size) as usize]
+}
+
+fn check_x32_relas(bytes: &[u8], expected: &[X86_64Reloc<u32>]) {
+    let relas: Relas<'_, LittleEndian, Elf32> =
+        Relas::try_from(bytes).expect("Expected success");
+    let mut iter = relas.iter();
+
+    for expect in expected.iter() {
+        let rela = iter.next();
+
+        assert!(rela.is_some());
+
+        let raw: RelaData<u32, Elf32> = rela.unwrap().into();
+        let data: Result<X86_64Reloc<u32>, X86_64RelocError> = raw.try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+
+    assert!(iter.next().is_none());
+}
+
 #[test]
 fn test_Relas_from_bytes_iter() {
     let rels: Relas<'_, LittleEndian, Elf64> =
@@ -165,3 +271,152 @@ fn test_Relas_from_bytes_idx() {
 
     assert!(rels.idx(X86_64_RELAS_CONTENTS.len()).is_none());
 }
+
+#[test]
+fn test_x32_text_Relas_from_bytes_iter() {
+    let data = read(X32_PATH).expect("Expected success");
+
+    check_x32_relas(x32_section(&data, X32_TEXT_RELAS_IDX),
+                    &X32_TEXT_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_x32_data_Relas_from_bytes_iter() {
+    let data = read(X32_PATH).expect("Expected success");
+
+    check_x32_relas(x32_section(&data, X32_DATA_RELAS_IDX),
+                    &X32_DATA_RELAS_CONTENTS[0..]);
+}
+
+#[test]
+fn test_x32_Relas_with_symtab() {
+    let data = read(X32_PATH).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf32> =
+        Symtab::try_from(x32_section(&data, X32_SYMTAB_IDX))
+        .expect("Expected success");
+    let strtab = Strtab::try_from(x32_section(&data, X32_STRTAB_IDX))
+        .expect("Expected success");
+
+    for (reloc, expect) in X32_TEXT_RELAS_CONTENTS.iter()
+                                                   .zip(X32_TEXT_RELAS_STRS
+                                                        .iter()) {
+        let data: X86_64RelocStrSym<'_, Elf32> =
+            reloc.clone()
+                 .with_symtab(symtab).expect("Expected success")
+                 .with_strtab(strtab).expect("Expected success")
+                 .try_into().expect("Expected success");
+        let actual: X86_64RelocStr<'_> = data.into();
+
+        assert_eq!(expect, &actual);
+    }
+}
+
+#[test]
+fn test_x32_RelaData_round_trip() {
+    for expect in X32_TEXT_RELAS_CONTENTS.iter()
+                                         .chain(X32_DATA_RELAS_CONTENTS
+                                                .iter()) {
+        let raw: Result<RelaData<u32, Elf32>, X86_64ToRelError> =
+            expect.clone().try_into();
+
+        assert!(raw.is_ok());
+
+        let actual: Result<X86_64Reloc<u32>, X86_64RelocError> =
+            raw.unwrap().try_into();
+
+        assert!(actual.is_ok());
+        assert_eq!(expect, &actual.unwrap());
+    }
+}
+
+#[test]
+fn test_x32_RelaData_relative64() {
+    let raw: RelaData<u32, Elf32> = RelaData { offset: 0x3014, sym: 0,
+                                               kind: 38, addend: 0x3008 };
+    let data: Result<X86_64Reloc<u32>, X86_64RelocError> = raw.try_into();
+
+    assert_eq!(data, Ok(X86_64Reloc::Relative64 { offset: 0x3014,
+                                                  addend: 0x3008 }));
+}
+
+#[test]
+fn test_x32_RelaData_bad_x32_tag() {
+    let raw: RelaData<u32, Elf32> = RelaData { offset: 0, sym: 1, kind: 24,
+                                               addend: 0 };
+    let data: Result<X86_64Reloc<u32>, X86_64RelocError> = raw.try_into();
+
+    assert_eq!(data, Err(X86_64RelocError::BadX32Tag(24)));
+
+    let raw: RelaData<u32, Elf64> = RelaData { offset: 0, sym: 1, kind: 24,
+                                               addend: 0 };
+    let data: Result<X86_64Reloc<u32>, X86_64RelocError> = raw.try_into();
+
+    assert!(data.is_ok());
+}
+
+#[test]
+fn test_x32_RelaData_from_unsupported() {
+    let reloc: X86_64Reloc<u32> = X86_64Reloc::GOTRel {
+        offset: 0x10, sym: 1, addend: 0
+    };
+    let raw: Result<RelaData<u32, Elf32>, X86_64ToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(X86_64ToRelError::BadX32Tag(25)));
+}
+
+#[test]
+fn test_x32_RelaData_bad_offset() {
+    let reloc: X86_64Reloc<u32> = X86_64Reloc::Abs64 {
+        offset: 0x1_0000_0000, sym: 1, addend: 0
+    };
+    let raw: Result<RelaData<u32, Elf32>, X86_64ToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(X86_64ToRelError::BadOffset(0x1_0000_0000)));
+}
+
+#[test]
+fn test_x32_RelaData_bad_addend() {
+    let reloc: X86_64Reloc<u32> = X86_64Reloc::Abs64 {
+        offset: 0x10, sym: 1, addend: 0x1_0000_0000
+    };
+    let raw: Result<RelaData<u32, Elf32>, X86_64ToRelError> =
+        reloc.try_into();
+
+    assert_eq!(raw, Err(X86_64ToRelError::BadAddend(0x1_0000_0000)));
+}
+
+#[test]
+fn test_x32_RelData_round_trip() {
+    let expect: X86_64Reloc<u32> = X86_64Reloc::Abs64 {
+        offset: 0x0c, sym: 1, addend: 0
+    };
+    let raw: Result<RelData<u32, Elf32>, X86_64ToRelError> =
+        expect.clone().try_into();
+
+    assert_eq!(raw, Ok(RelData { offset: 0x0c, sym: 1, kind: 1 }));
+
+    let actual: Result<X86_64Reloc<u32>, X86_64RelocError> =
+        raw.unwrap().try_into();
+
+    assert_eq!(actual, Ok(expect));
+}
+
+#[test]
+fn test_x32_X32Reloc_display() {
+    let data: X86_64Reloc<&str> =
+        X86_64Reloc::GlobalData { offset: 0x10, sym: "data" };
+    let relative: X86_64Reloc<&str> =
+        X86_64Reloc::Relative { offset: 0x14, addend: 0x20 };
+    let abs: X86_64Reloc<&str> =
+        X86_64Reloc::Abs64 { offset: 0x18, sym: "data", addend: 0 };
+
+    assert_eq!(format!("{}", data), ".got[16..24] <- &data");
+    assert_eq!(format!("{}", X32Reloc(data)), ".got[16..20] <- &data");
+    assert_eq!(format!("{}", X32Reloc(relative)),
+               ".section[20..24] <- &base + 32");
+    assert_eq!(format!("{}", abs), ".section[24..32] <- &data + 0");
+    assert_eq!(format!("{}", X32Reloc(abs)),
+               ".section[24..32] <- &data + 0");
+}
@@ -2,6 +2,8 @@ use byteorder::LittleEndian;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use elf_utils::Elf32;
+use elf_utils::section_hdr::SectionFlag;
+use elf_utils::section_hdr::SectionFlags;
 use elf_utils::section_hdr::SectionHdr;
 use elf_utils::section_hdr::SectionHdrData;
 use elf_utils::section_hdr::SectionHdrs;
@@ -176,76 +178,73 @@ const ELF32_SECTION_HDR_CONTENTS_BARE: [SectionHdrData<Elf32, u32, u32,
                                                        SectionPos<u32>>;
                                         ELF32_NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
-                           note: SectionPos { offset: 0x174, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
-                             syms: SectionPos { offset: 0x18c, size: 0x1b0 },
-                             strtab: 7, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: 30, tag: 0x6fffffff, addr: 0x33c,
-                              align: 2, offset: 0x33c, size: 0x36, ent_size: 2,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: 43, tag: 0x6ffffffd, addr: 0x374,
-                              align: 4, offset: 0x374, size: 0xfc, ent_size: 0,
-                              link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: 58, tag: 0x6ffffff6, addr: 0x470,
-                              align: 4, offset: 0x470, size: 0xd0, ent_size: 0,
-                              link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
-                           hash: SectionPos { offset: 0x540, size: 0xe0 },
-                           symtab: 2, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
-                             strs: SectionPos { offset: 0x620, size: 0x1d2 } },
-    SectionHdrData::Rel { name: 82, addr: 0x7f4, align: 4,
-                          rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
-                          symtab: 2, target: 0, alloc: true, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: 91, addr: 0xddc, align: 4,
-                               data: SectionPos { offset: 0xddc, size: 0x34b0 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 99, addr: 0x428c, align: 4,
-                               data: SectionPos { offset: 0x428c, size: 0xbc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 113, addr: 0x4348, align: 4,
-                               data: SectionPos { offset: 0x4348, size: 0x374 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 123, addr: 0x56c0, align: 16,
-                               data: SectionPos { offset: 0x46c0,
-                                                  size: 0x14d05 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: 129, tag: 15, addr: 0x1b3c8, align: 4,
-                              offset: 0x193c8, size: 4, ent_size: 0, link: 0,
-                              info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: 141, addr: 0x1b3cc, align: 4,
-                               data: SectionPos { offset: 0x193cc,
-                                                  size: 0x25c },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: 154, addr: 0x1b628, align: 4, strtab: 7,
-                              dynamic: SectionPos { offset: 0x19628,
-                                                    size: 0x88 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 163, addr: 0x1b6b0, align: 4,
-                               data: SectionPos { offset: 0x196b0, size: 0x60 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 168, addr: 0x1c710, align: 4,
-                               data: SectionPos { offset: 0x19710, size: 0x58 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 174, addr: 0x1c768, align: 4,
-                               data: SectionPos { offset: 0x19768, size: 0xc },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: 183, addr: 0x1c774, align: 4,
-                             offset: 0x19774, size: 0xb04, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: 188, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x19774,
-                                                  size: 0x11de },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 197, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1a952, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x1a96a, size: 0xd4 } }
+SectionHdrData::Note { name: 12, addr: 0x174, align: 4,
+                       note: SectionPos { offset: 0x174, size: 0x18 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: 22, addr: 0x18c, align: 4,
+                         syms: SectionPos { offset: 0x18c, size: 0x1b0 },
+                         strtab: 7, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: 30, addr: 0x33c, align: 2,
+                         versyms: SectionPos { offset: 0x33c, size: 0x36 },
+                         symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: 43, addr: 0x374, align: 4,
+                         verdefs: SectionPos { offset: 0x374, size: 0xfc },
+                         strtab: 7, count: 9, flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: 58, addr: 0x470, align: 4,
+                          hash: SectionPos { offset: 0x470, size: 0xd0 },
+                          symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: 68, addr: 0x540, align: 4,
+                       hash: SectionPos { offset: 0x540, size: 0xe0 },
+                       symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: 74, addr: 0x620, align: 1,
+                         strs: SectionPos { offset: 0x620, size: 0x1d2 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: 82, addr: 0x7f4, align: 4,
+                      rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
+                      symtab: 2, target: 0,
+                      flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: 91, addr: 0xddc, align: 4,
+                           data: SectionPos { offset: 0xddc, size: 0x34b0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: 99, addr: 0x428c, align: 4,
+                           data: SectionPos { offset: 0x428c, size: 0xbc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 113, addr: 0x4348, align: 4,
+                           data: SectionPos { offset: 0x4348, size: 0x374 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 123, addr: 0x56c0, align: 16,
+                           data: SectionPos { offset: 0x46c0, size: 0x14d05 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: 129, addr: 0x1b3c8, align: 4,
+                            addrs: SectionPos { offset: 0x193c8, size: 4 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 141, addr: 0x1b3cc, align: 4,
+                           data: SectionPos { offset: 0x193cc, size: 0x25c },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: 154, addr: 0x1b628, align: 4, strtab: 7,
+                          dynamic: SectionPos { offset: 0x19628, size: 0x88 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 163, addr: 0x1b6b0, align: 4,
+                           data: SectionPos { offset: 0x196b0, size: 0x60 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 168, addr: 0x1c710, align: 4,
+                           data: SectionPos { offset: 0x19710, size: 0x58 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 174, addr: 0x1c768, align: 4,
+                           data: SectionPos { offset: 0x19768, size: 0xc },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: 183, addr: 0x1c774, align: 4, offset: 0x19774,
+                         size: 0xb04, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 188, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x19774, size: 0x11de },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: 197, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1a952, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x1a96a, size: 0xd4 },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 const ELF32_SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf32,
@@ -262,77 +261,75 @@ const ELF32_SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf32,
                                                        SectionPos<u32>>;
                                         ELF32_NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::Note { name: Ok(".note.tag"), addr: 0x174, align: 4,
-                           note: SectionPos { offset: 0x174, size: 0x18 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: Ok(".dynsym"), addr: 0x18c, align: 4,
-                             syms: SectionPos { offset: 0x18c, size: 0x1b0 },
-                             strtab: 7, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: Ok(".gnu.version"), tag: 0x6fffffff,
-                              addr: 0x33c, align: 2, offset: 0x33c, size: 0x36,
-                              ent_size: 2, link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: Ok(".gnu.version_d"), tag: 0x6ffffffd,
-                              addr: 0x374, align: 4, offset: 0x374, size: 0xfc,
-                              ent_size: 0, link: 7, info: 9, flags: 0x2 },
-    SectionHdrData::Unknown { name: Ok(".gnu.hash"), tag: 0x6ffffff6,
-                              addr: 0x470, align: 4, offset: 0x470, size: 0xd0,
-                              ent_size: 0, link: 2, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: Ok(".hash"), addr: 0x540, align: 4,
-                           hash: SectionPos { offset: 0x540, size: 0xe0 },
-                           symtab: 2, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: Ok(".dynstr"), addr: 0x620, align: 1,
-                             strs: SectionPos { offset: 0x620, size: 0x1d2 } },
-    SectionHdrData::Rel { name: Ok(".rel.dyn"), addr: 0x7f4, align: 4,
-                          rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
-                          symtab: 2, target: 0, alloc: true, write: false,
-                          exec: false },
-    SectionHdrData::ProgBits { name: Ok(".rodata"), addr: 0xddc, align: 4,
-                               data: SectionPos { offset: 0xddc, size: 0x34b0 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".eh_frame_hdr"), addr: 0x428c,
-                               align: 4, data: SectionPos { offset: 0x428c,
-                                                            size: 0xbc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".eh_frame"), addr: 0x4348, align: 4,
-                               data: SectionPos { offset: 0x4348, size: 0x374 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".text"), addr: 0x56c0, align: 16,
-                               data: SectionPos { offset: 0x46c0,
-                                                  size: 0x14d05 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::Unknown { name: Ok(".fini_array"), tag: 15, addr: 0x1b3c8,
-                              align: 4, offset: 0x193c8, size: 4, ent_size: 0,
-                              link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::ProgBits { name: Ok(".data.rel.ro"), addr: 0x1b3cc,
-                               align: 4, data: SectionPos { offset: 0x193cc,
-                                                            size: 0x25c },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Dynamic { name: Ok(".dynamic"), addr: 0x1b628, align: 4,
-                              strtab: 7, dynamic: SectionPos { offset: 0x19628,
-                                                               size: 0x88 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".got"), addr: 0x1b6b0, align: 4,
-                               data: SectionPos { offset: 0x196b0, size: 0x60 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".data"), addr: 0x1c710, align: 4,
-                               data: SectionPos { offset: 0x19710, size: 0x58 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".got.plt"), addr: 0x1c768, align: 4,
-                               data: SectionPos { offset: 0x19768, size: 0xc },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: Ok(".bss"), addr: 0x1c774, align: 4,
-                             offset: 0x19774, size: 0xb04, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".comment"), addr: 0, align: 1,
-                               data: SectionPos { offset: 0x19774,
-                                                  size: 0x11de },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".gnu_debuglink"), addr: 0, align: 1,
-                               data: SectionPos { offset: 0x1a952, size: 0x18 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: Ok(".shstrtab"), addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x1a96a, size: 0xd4 } }
+SectionHdrData::Note { name: Ok(".note.tag"), addr: 0x174, align: 4,
+                       note: SectionPos { offset: 0x174, size: 0x18 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: Ok(".dynsym"), addr: 0x18c, align: 4,
+                         syms: SectionPos { offset: 0x18c, size: 0x1b0 },
+                         strtab: 7, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: Ok(".gnu.version"), addr: 0x33c, align: 2,
+                         versyms: SectionPos { offset: 0x33c, size: 0x36 },
+                         symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verdef { name: Ok(".gnu.version_d"), addr: 0x374, align: 4,
+                         verdefs: SectionPos { offset: 0x374, size: 0xfc },
+                         strtab: 7, count: 9, flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: Ok(".gnu.hash"), addr: 0x470, align: 4,
+                          hash: SectionPos { offset: 0x470, size: 0xd0 },
+                          symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: Ok(".hash"), addr: 0x540, align: 4,
+                       hash: SectionPos { offset: 0x540, size: 0xe0 },
+                       symtab: 2, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: Ok(".dynstr"), addr: 0x620, align: 1,
+                         strs: SectionPos { offset: 0x620, size: 0x1d2 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rel { name: Ok(".rel.dyn"), addr: 0x7f4, align: 4,
+                      rels: SectionPos { offset: 0x7f4, size: 0x5e8 },
+                      symtab: 2, target: 0,
+                      flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: Ok(".rodata"), addr: 0xddc, align: 4,
+                           data: SectionPos { offset: 0xddc, size: 0x34b0 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: Ok(".eh_frame_hdr"), addr: 0x428c, align: 4,
+                           data: SectionPos { offset: 0x428c, size: 0xbc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: Ok(".eh_frame"), addr: 0x4348, align: 4,
+                           data: SectionPos { offset: 0x4348, size: 0x374 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: Ok(".text"), addr: 0x56c0, align: 16,
+                           data: SectionPos { offset: 0x46c0, size: 0x14d05 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::FiniArray { name: Ok(".fini_array"), addr: 0x1b3c8, align: 4,
+                            addrs: SectionPos { offset: 0x193c8, size: 4 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".data.rel.ro"), addr: 0x1b3cc, align: 4,
+                           data: SectionPos { offset: 0x193cc, size: 0x25c },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: Ok(".dynamic"), addr: 0x1b628, align: 4,
+                          strtab: 7,
+                          dynamic: SectionPos { offset: 0x19628, size: 0x88 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".got"), addr: 0x1b6b0, align: 4,
+                           data: SectionPos { offset: 0x196b0, size: 0x60 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".data"), addr: 0x1c710, align: 4,
+                           data: SectionPos { offset: 0x19710, size: 0x58 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".got.plt"), addr: 0x1c768, align: 4,
+                           data: SectionPos { offset: 0x19768, size: 0xc },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: Ok(".bss"), addr: 0x1c774, align: 4,
+                         offset: 0x19774, size: 0xb04,
+                         flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".comment"), addr: 0, align: 1,
+                           data: SectionPos { offset: 0x19774, size: 0x11de },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: Ok(".gnu_debuglink"), addr: 0, align: 1,
+                           data: SectionPos { offset: 0x1a952, size: 0x18 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: Ok(".shstrtab"), addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x1a96a, size: 0xd4 },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 #[test]
@@ -653,3 +650,53 @@ fn test_SectionHdrs_create_idx() {
 
     assert!(dynamic.idx(ELF32_SECTION_HDR_CONTENTS_BARE.len()).is_none());
 }
+
+#[test]
+fn test_SectionFlags_contains() {
+    let flags: SectionFlags<Elf32> = SectionFlags { bits: 0x1000042 };
+
+    assert!(flags.contains(SectionFlag::Alloc));
+    assert!(flags.contains(SectionFlag::InfoLink));
+    assert!(!flags.contains(SectionFlag::Write));
+    assert!(!flags.contains(SectionFlag::Merge));
+    assert_eq!(flags.unknown(), 0x1000000);
+    assert_eq!(flags.os(), 0x1000000);
+    assert_eq!(flags.proc(), 0);
+}
+
+#[test]
+fn test_SectionFlags_insert_remove() {
+    let mut flags: SectionFlags<Elf32> = SectionFlags { bits: 0x2 };
+
+    flags.insert(SectionFlag::Merge);
+    flags.insert(SectionFlag::Strings);
+    flags.insert(SectionFlag::Exclude);
+
+    assert_eq!(flags.bits, 0x80000032);
+    assert_eq!(flags.proc(), 0x80000000);
+
+    flags.remove(SectionFlag::Alloc);
+    flags.remove(SectionFlag::Write);
+
+    assert_eq!(flags.bits, 0x80000030);
+}
+
+#[test]
+fn test_SectionFlag_mask() {
+    assert_eq!(SectionFlag::Write.mask(), 0x1);
+    assert_eq!(SectionFlag::Exec.mask(), 0x4);
+    assert_eq!(SectionFlag::LinkOrder.mask(), 0x80);
+    assert_eq!(SectionFlag::Group.mask(), 0x200);
+    assert_eq!(SectionFlag::TLS.mask(), 0x400);
+    assert_eq!(SectionFlag::Compressed.mask(), 0x800);
+    assert_eq!(SectionFlag::GNURetain.mask(), 0x200000);
+}
+
+#[test]
+fn test_SectionFlags_display() {
+    let none: SectionFlags<Elf32> = SectionFlags { bits: 0 };
+    let flags: SectionFlags<Elf32> = SectionFlags { bits: 0x1000432 };
+
+    assert_eq!(format!("{}", none), "none");
+    assert_eq!(format!("{}", flags), "alloc, merge, strings, TLS, 0x1000000");
+}
@@ -1,11 +1,16 @@
+use byteorder::BigEndian;
 use byteorder::LittleEndian;
 use core::convert::TryFrom;
 use core::convert::TryInto;
 use elf_utils::Elf64;
+use elf_utils::section_hdr::SectionFlag;
+use elf_utils::section_hdr::SectionFlags;
 use elf_utils::section_hdr::SectionHdr;
 use elf_utils::section_hdr::SectionHdrData;
+use elf_utils::section_hdr::SectionHdrDataRaw;
 use elf_utils::section_hdr::SectionHdrs;
 use elf_utils::section_hdr::SectionHdrsError;
+use elf_utils::section_hdr::SectionHdrsMut;
 use elf_utils::section_hdr::SectionPos;
 use elf_utils::strtab::Strtab;
 use elf_utils::strtab::WithStrtab;
@@ -316,111 +321,98 @@ const ELF64_SECTION_HDR_CONTENTS_BARE: [SectionHdrData<Elf64, u32, u32,
                                                        SectionPos<u64>>;
                                         ELF64_NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::ProgBits { name: 12, addr: 0x2002a8, align: 1,
-                               data: SectionPos { offset: 0x2a8, size: 0x15 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::Note { name: 20, addr: 0x2002c0, align: 4,
-                           note: SectionPos { offset: 0x2c0, size: 0x48 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: 30, addr: 0x200308, align: 8,
-                             syms: SectionPos { offset: 0x308, size: 0xcc0 },
-                             strtab: 8, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: 38, tag: 0x6fffffff, addr: 0x200fc8,
-                              align: 2, offset: 0xfc8, size: 0x110, ent_size: 2,
-                              link: 3, info: 0, flags: 0x2 },
-    SectionHdrData::Unknown { name: 51, tag: 0x6ffffffe, addr: 0x2010d8,
-                              align: 4, offset: 0x10d8, size: 0x40, ent_size: 0,
-                              link: 8, info: 1, flags: 0x2 },
-    SectionHdrData::Unknown { name: 66, tag: 0x6ffffff6, addr: 0x201118,
-                              align: 8, offset: 0x1118, size: 0x4c, ent_size: 0,
-                              link: 3, info: 0, flags: 0x2 },
-    SectionHdrData::Hash { name: 76, addr: 0x201164, align: 4,
-                           hash: SectionPos { offset: 0x1164, size: 0x448 },
-                           symtab: 3, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: 82, addr: 0x2015ac, align: 1,
-                             strs: SectionPos { offset: 0x15ac, size: 0x4a8 } },
-    SectionHdrData::Rela { name: 90, addr: 0x201a58, align: 8,
-                           relas: SectionPos { offset: 0x1a58, size: 0x90 },
-                           symtab: 3, target: 0, alloc: true, write: false,
-                           exec: false },
-    SectionHdrData::Rela { name: 100, addr: 0x201ae8, align: 8,
-                           relas: SectionPos { offset: 0x1ae8, size: 0xbe8 },
-                           symtab: 3, target: 26, alloc: true, write: false,
-                           exec: false },
-    SectionHdrData::ProgBits { name: 110, addr: 0x2026d0, align: 16,
-                               data: SectionPos { offset: 0x26d0,
-                                                  size: 0x4d31 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 118, addr: 0x207404, align: 4,
-                               data: SectionPos { offset: 0x7404, size: 0x86c },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 132, addr: 0x207c70, align: 8,
-                               data: SectionPos { offset: 0x7c70,
-                                                  size: 0x2ccc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 142, addr: 0x20b940, align: 16,
-                               data: SectionPos { offset: 0xa940,
-                                                  size: 0x1b7bc },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: 148, addr: 0x2270fc, align: 4,
-                               data: SectionPos { offset: 0x260fc, size: 0xe },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: 154, addr: 0x22710c, align: 4,
-                               data: SectionPos { offset: 0x2610c, size: 0xe },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: 160, addr: 0x227120, align: 16,
-                               data: SectionPos { offset: 0x26120,
-                                                  size: 0x800 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: 165, addr: 0x228920, align: 8,
-                               data: SectionPos { offset: 0x26920,
-                                                  size: 0x10 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 172, addr: 0x228930, align: 8,
-                               data: SectionPos { offset: 0x26930,
-                                                  size: 0x10 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 179, addr: 0x228940, align: 8,
-                               data: SectionPos { offset: 0x26940,
-                                                  size: 0x8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Unknown { name: 184, tag: 14, addr: 0x228948, align: 8,
-                              offset: 0x26948, size: 8, ent_size: 0, link: 0,
-                              info: 0, flags: 0x3 },
-    SectionHdrData::Dynamic { name: 196, addr: 0x228950, align: 8, strtab: 8,
-                              dynamic: SectionPos { offset: 0x26950,
-                                                    size: 0x190 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 205, addr: 0x228ae0, align: 8,
-                               data: SectionPos { offset: 0x26ae0,
-                                                  size: 0x8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: 210, addr: 0x228af0, align: 16,
-                             offset: 0x26ae8, size: 0x110, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: 222, addr: 0x229c00, align: 8,
-                               data: SectionPos { offset: 0x26c00,
-                                                  size: 0xe8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: 228, addr: 0x229ce8, align: 8,
-                               data: SectionPos { offset: 0x26ce8,
-                                                  size: 0x410 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: 237, addr: 0x22a100, align: 32,
-                             offset: 0x270f8, size: 0x13d8, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: 242, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x270f8,
-                                                  size: 0xa76 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: 251, addr: 0, align: 1,
-                               data: SectionPos { offset: 0x27b6e,
-                                                  size: 0x10 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x27b7e,
-                                                size: 0x10a } }
+SectionHdrData::ProgBits { name: 12, addr: 0x2002a8, align: 1,
+                           data: SectionPos { offset: 0x2a8, size: 0x15 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Note { name: 20, addr: 0x2002c0, align: 4,
+                       note: SectionPos { offset: 0x2c0, size: 0x48 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: 30, addr: 0x200308, align: 8,
+                         syms: SectionPos { offset: 0x308, size: 0xcc0 },
+                         strtab: 8, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: 38, addr: 0x200fc8, align: 2,
+                         versyms: SectionPos { offset: 0xfc8, size: 0x110 },
+                         symtab: 3, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verneed { name: 51, addr: 0x2010d8, align: 4,
+                          verneeds: SectionPos { offset: 0x10d8, size: 0x40 },
+                          strtab: 8, count: 1,
+                          flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: 66, addr: 0x201118, align: 8,
+                          hash: SectionPos { offset: 0x1118, size: 0x4c },
+                          symtab: 3, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: 76, addr: 0x201164, align: 4,
+                       hash: SectionPos { offset: 0x1164, size: 0x448 },
+                       symtab: 3, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: 82, addr: 0x2015ac, align: 1,
+                         strs: SectionPos { offset: 0x15ac, size: 0x4a8 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: 90, addr: 0x201a58, align: 8,
+                       relas: SectionPos { offset: 0x1a58, size: 0x90 },
+                       symtab: 3, target: 0,
+                       flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::Rela { name: 100, addr: 0x201ae8, align: 8,
+                       relas: SectionPos { offset: 0x1ae8, size: 0xbe8 },
+                       symtab: 3, target: 26,
+                       flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: 110, addr: 0x2026d0, align: 16,
+                           data: SectionPos { offset: 0x26d0, size: 0x4d31 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: 118, addr: 0x207404, align: 4,
+                           data: SectionPos { offset: 0x7404, size: 0x86c },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 132, addr: 0x207c70, align: 8,
+                           data: SectionPos { offset: 0x7c70, size: 0x2ccc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: 142, addr: 0x20b940, align: 16,
+                           data: SectionPos { offset: 0xa940, size: 0x1b7bc },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: 148, addr: 0x2270fc, align: 4,
+                           data: SectionPos { offset: 0x260fc, size: 0xe },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: 154, addr: 0x22710c, align: 4,
+                           data: SectionPos { offset: 0x2610c, size: 0xe },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: 160, addr: 0x227120, align: 16,
+                           data: SectionPos { offset: 0x26120, size: 0x800 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: 165, addr: 0x228920, align: 8,
+                           data: SectionPos { offset: 0x26920, size: 0x10 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 172, addr: 0x228930, align: 8,
+                           data: SectionPos { offset: 0x26930, size: 0x10 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 179, addr: 0x228940, align: 8,
+                           data: SectionPos { offset: 0x26940, size: 0x8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::InitArray { name: 184, addr: 0x228948, align: 8,
+                            addrs: SectionPos { offset: 0x26948, size: 8 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: 196, addr: 0x228950, align: 8, strtab: 8,
+                          dynamic: SectionPos { offset: 0x26950, size: 0x190 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 205, addr: 0x228ae0, align: 8,
+                           data: SectionPos { offset: 0x26ae0, size: 0x8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: 210, addr: 0x228af0, align: 16, offset: 0x26ae8,
+                         size: 0x110, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 222, addr: 0x229c00, align: 8,
+                           data: SectionPos { offset: 0x26c00, size: 0xe8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 228, addr: 0x229ce8, align: 8,
+                           data: SectionPos { offset: 0x26ce8, size: 0x410 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: 237, addr: 0x22a100, align: 32, offset: 0x270f8,
+                         size: 0x13d8, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: 242, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x270f8, size: 0xa76 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: 251, addr: 0, align: 1,
+                           data: SectionPos { offset: 0x27b6e, size: 0x10 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: 2, addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x27b7e, size: 0x10a },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 const ELF64_SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf64,
@@ -437,115 +429,101 @@ const ELF64_SECTION_HDR_CONTENTS_STRS: [SectionHdrData<Elf64,
                                                        SectionPos<u64>>;
                                         ELF64_NUM_SECTION_HDRS] = [
     SectionHdrData::Null,
-    SectionHdrData::ProgBits { name: Ok(".interp"), addr: 0x2002a8, align: 1,
-                               data: SectionPos { offset: 0x2a8, size: 0x15 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::Note { name: Ok(".note.tag"), addr: 0x2002c0, align: 4,
-                           note: SectionPos { offset: 0x2c0, size: 0x48 },
-                           alloc: true, write: false, exec: false },
-    SectionHdrData::Dynsym { name: Ok(".dynsym"), addr: 0x200308, align: 8,
-                             syms: SectionPos { offset: 0x308, size: 0xcc0 },
-                             strtab: 8, local_end: 1,
-                             alloc: true, write: false, exec: false },
-    SectionHdrData::Unknown { name: Ok(".gnu.version"), tag: 0x6fffffff,
-                              addr: 0x200fc8, align: 2, offset: 0xfc8,
-                              size: 0x110, ent_size: 2, link: 3, info: 0,
-                              flags: 0x2 },
-    SectionHdrData::Unknown { name: Ok(".gnu.version_r"), tag: 0x6ffffffe,
-                              addr: 0x2010d8, align: 4, offset: 0x10d8,
-                              size: 0x40, ent_size: 0, link: 8, info: 1,
-                              flags: 0x2 },
-    SectionHdrData::Unknown { name: Ok(".gnu.hash"), tag: 0x6ffffff6,
-                              addr: 0x201118, align: 8, offset: 0x1118,
-                              size: 0x4c, ent_size: 0, link: 3, info: 0,
-                              flags: 0x2 },
-    SectionHdrData::Hash { name: Ok(".hash"), addr: 0x201164, align: 4,
-                           hash: SectionPos { offset: 0x1164, size: 0x448 },
-                           symtab: 3, alloc: true, write: false, exec: false },
-    SectionHdrData::Strtab { name: Ok(".dynstr"), addr: 0x2015ac, align: 1,
-                             strs: SectionPos { offset: 0x15ac, size: 0x4a8 } },
-    SectionHdrData::Rela { name: Ok(".rela.dyn"), addr: 0x201a58, align: 8,
-                           relas: SectionPos { offset: 0x1a58, size: 0x90 },
-                           symtab: 3, target: 0, alloc: true, write: false,
-                           exec: false },
-    SectionHdrData::Rela { name: Ok(".rela.plt"), addr: 0x201ae8, align: 8,
-                           relas: SectionPos { offset: 0x1ae8, size: 0xbe8 },
-                           symtab: 3, target: 26, alloc: true, write: false,
-                           exec: false },
-    SectionHdrData::ProgBits { name: Ok(".rodata"), addr: 0x2026d0, align: 16,
-                               data: SectionPos { offset: 0x26d0,
-                                                  size: 0x4d31 },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".eh_frame_hdr"), addr: 0x207404,
-                               align: 4, data: SectionPos { offset: 0x7404,
-                                                            size: 0x86c },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".eh_frame"), addr: 0x207c70, align: 8,
-                               data: SectionPos { offset: 0x7c70,
-                                                  size: 0x2ccc },
-                               alloc: true, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".text"), addr: 0x20b940, align: 16,
-                               data: SectionPos { offset: 0xa940,
-                                                  size: 0x1b7bc },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: Ok(".init"), addr: 0x2270fc, align: 4,
-                               data: SectionPos { offset: 0x260fc, size: 0xe },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: Ok(".fini"), addr: 0x22710c, align: 4,
-                               data: SectionPos { offset: 0x2610c, size: 0xe },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: Ok(".plt"), addr: 0x227120, align: 16,
-                               data: SectionPos { offset: 0x26120,
-                                                  size: 0x800 },
-                               alloc: true, write: false, exec: true },
-    SectionHdrData::ProgBits { name: Ok(".ctors"), addr: 0x228920, align: 8,
-                               data: SectionPos { offset: 0x26920,
-                                                  size: 0x10 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".dtors"), addr: 0x228930, align: 8,
-                               data: SectionPos { offset: 0x26930,
-                                                  size: 0x10 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".jcr"), addr: 0x228940, align: 8,
-                               data: SectionPos { offset: 0x26940,
-                                                  size: 0x8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Unknown { name: Ok(".init_array"), tag: 14, addr: 0x228948,
-                              align: 8, offset: 0x26948, size: 8, ent_size: 0,
-                              link: 0, info: 0, flags: 0x3 },
-    SectionHdrData::Dynamic { name: Ok(".dynamic"), addr: 0x228950, align: 8,
-                              strtab: 8, dynamic: SectionPos { offset: 0x26950,
-                                                               size: 0x190 },
-                              alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".got"), addr: 0x228ae0, align: 8,
-                               data: SectionPos { offset: 0x26ae0,
-                                                  size: 0x8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: Ok(".bss.rel.ro"), addr: 0x228af0, align: 16,
-                             offset: 0x26ae8, size: 0x110, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".data"), addr: 0x229c00, align: 8,
-                               data: SectionPos { offset: 0x26c00,
-                                                  size: 0xe8 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".got.plt"), addr: 0x229ce8, align: 8,
-                               data: SectionPos { offset: 0x26ce8,
-                                                  size: 0x410 },
-                               alloc: true, write: true, exec: false },
-    SectionHdrData::Nobits { name: Ok(".bss"), addr: 0x22a100, align: 32,
-                             offset: 0x270f8, size: 0x13d8, alloc: true,
-                             write: true, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".comment"), addr: 0, align: 1,
-                               data: SectionPos { offset: 0x270f8,
-                                                  size: 0xa76 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::ProgBits { name: Ok(".gnu_debuglink"), addr: 0, align: 1,
-                               data: SectionPos { offset: 0x27b6e,
-                                                  size: 0x10 },
-                               alloc: false, write: false, exec: false },
-    SectionHdrData::Strtab { name: Ok(".shstrtab"), addr: 0, align: 1,
-                             strs: SectionPos { offset: 0x27b7e,
-                                                size: 0x10a } }
+SectionHdrData::ProgBits { name: Ok(".interp"), addr: 0x2002a8, align: 1,
+                           data: SectionPos { offset: 0x2a8, size: 0x15 },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Note { name: Ok(".note.tag"), addr: 0x2002c0, align: 4,
+                       note: SectionPos { offset: 0x2c0, size: 0x48 },
+                       flags: SectionFlags { bits: 2 } },
+SectionHdrData::Dynsym { name: Ok(".dynsym"), addr: 0x200308, align: 8,
+                         syms: SectionPos { offset: 0x308, size: 0xcc0 },
+                         strtab: 8, local_end: 1,
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Versym { name: Ok(".gnu.version"), addr: 0x200fc8, align: 2,
+                         versyms: SectionPos { offset: 0xfc8, size: 0x110 },
+                         symtab: 3, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Verneed { name: Ok(".gnu.version_r"), addr: 0x2010d8, align: 4,
+                          verneeds: SectionPos { offset: 0x10d8, size: 0x40 },
+                          strtab: 8, count: 1,
+                          flags: SectionFlags { bits: 2 } },
+SectionHdrData::GnuHash { name: Ok(".gnu.hash"), addr: 0x201118, align: 8,
+                          hash: SectionPos { offset: 0x1118, size: 0x4c },
+                          symtab: 3, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Hash { name: Ok(".hash"), addr: 0x201164, align: 4,
+                       hash: SectionPos { offset: 0x1164, size: 0x448 },
+                       symtab: 3, flags: SectionFlags { bits: 2 } },
+SectionHdrData::Strtab { name: Ok(".dynstr"), addr: 0x2015ac, align: 1,
+                         strs: SectionPos { offset: 0x15ac, size: 0x4a8 },
+                         flags: SectionFlags { bits: 2 } },
+SectionHdrData::Rela { name: Ok(".rela.dyn"), addr: 0x201a58, align: 8,
+                       relas: SectionPos { offset: 0x1a58, size: 0x90 },
+                       symtab: 3, target: 0,
+                       flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::Rela { name: Ok(".rela.plt"), addr: 0x201ae8, align: 8,
+                       relas: SectionPos { offset: 0x1ae8, size: 0xbe8 },
+                       symtab: 3, target: 26,
+                       flags: SectionFlags { bits: 0x42 } },
+SectionHdrData::ProgBits { name: Ok(".rodata"), addr: 0x2026d0, align: 16,
+                           data: SectionPos { offset: 0x26d0, size: 0x4d31 },
+                           ent_size: 0, flags: SectionFlags { bits: 0x32 } },
+SectionHdrData::ProgBits { name: Ok(".eh_frame_hdr"), addr: 0x207404, align: 4,
+                           data: SectionPos { offset: 0x7404, size: 0x86c },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: Ok(".eh_frame"), addr: 0x207c70, align: 8,
+                           data: SectionPos { offset: 0x7c70, size: 0x2ccc },
+                           ent_size: 0, flags: SectionFlags { bits: 2 } },
+SectionHdrData::ProgBits { name: Ok(".text"), addr: 0x20b940, align: 16,
+                           data: SectionPos { offset: 0xa940, size: 0x1b7bc },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: Ok(".init"), addr: 0x2270fc, align: 4,
+                           data: SectionPos { offset: 0x260fc, size: 0xe },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: Ok(".fini"), addr: 0x22710c, align: 4,
+                           data: SectionPos { offset: 0x2610c, size: 0xe },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: Ok(".plt"), addr: 0x227120, align: 16,
+                           data: SectionPos { offset: 0x26120, size: 0x800 },
+                           ent_size: 0, flags: SectionFlags { bits: 6 } },
+SectionHdrData::ProgBits { name: Ok(".ctors"), addr: 0x228920, align: 8,
+                           data: SectionPos { offset: 0x26920, size: 0x10 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".dtors"), addr: 0x228930, align: 8,
+                           data: SectionPos { offset: 0x26930, size: 0x10 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".jcr"), addr: 0x228940, align: 8,
+                           data: SectionPos { offset: 0x26940, size: 0x8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::InitArray { name: Ok(".init_array"), addr: 0x228948, align: 8,
+                            addrs: SectionPos { offset: 0x26948, size: 8 },
+                            ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Dynamic { name: Ok(".dynamic"), addr: 0x228950, align: 8,
+                          strtab: 8,
+                          dynamic: SectionPos { offset: 0x26950, size: 0x190 },
+                          flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".got"), addr: 0x228ae0, align: 8,
+                           data: SectionPos { offset: 0x26ae0, size: 0x8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: Ok(".bss.rel.ro"), addr: 0x228af0, align: 16,
+                         offset: 0x26ae8, size: 0x110,
+                         flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".data"), addr: 0x229c00, align: 8,
+                           data: SectionPos { offset: 0x26c00, size: 0xe8 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".got.plt"), addr: 0x229ce8, align: 8,
+                           data: SectionPos { offset: 0x26ce8, size: 0x410 },
+                           ent_size: 0, flags: SectionFlags { bits: 3 } },
+SectionHdrData::Nobits { name: Ok(".bss"), addr: 0x22a100, align: 32,
+                         offset: 0x270f8, size: 0x13d8,
+                         flags: SectionFlags { bits: 3 } },
+SectionHdrData::ProgBits { name: Ok(".comment"), addr: 0, align: 1,
+                           data: SectionPos { offset: 0x270f8, size: 0xa76 },
+                           ent_size: 1, flags: SectionFlags { bits: 0x30 } },
+SectionHdrData::ProgBits { name: Ok(".gnu_debuglink"), addr: 0, align: 1,
+                           data: SectionPos { offset: 0x27b6e, size: 0x10 },
+                           ent_size: 0, flags: SectionFlags { bits: 0 } },
+SectionHdrData::Strtab { name: Ok(".shstrtab"), addr: 0, align: 1,
+                         strs: SectionPos { offset: 0x27b7e, size: 0x10a },
+                         flags: SectionFlags { bits: 0 } }
 ];
 
 #[test]
@@ -862,3 +840,306 @@ fn test_SectionHdrs_create_idx() {
 
     assert!(dynamic.idx(ELF64_SECTION_HDR_CONTENTS_BARE.len()).is_none());
 }
+
+#[test]
+fn test_SectionFlags_unknown_high() {
+    let flags: SectionFlags<Elf64> = SectionFlags { bits: 0x100000000200003 };
+
+    assert!(flags.contains(SectionFlag::Write));
+    assert!(flags.contains(SectionFlag::Alloc));
+    assert!(flags.contains(SectionFlag::GNURetain));
+    assert_eq!(flags.unknown(), 0x100000000000000);
+    assert_eq!(flags.os(), 0x200000);
+    assert_eq!(flags.proc(), 0);
+}
+
+#[test]
+fn test_SectionHdrs_create_ext_null() {
+    const EXT_HDR: [SectionHdrDataRaw<Elf64>; 2] = [
+        SectionHdrData::ExtNull { num_section_hdrs: 0x12345,
+                                  section_hdr_strtab: 0x12344,
+                                  num_prog_hdrs: 0x10000 },
+        SectionHdrData::Null
+    ];
+    let mut buf = [0; 128];
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create(&mut buf[0..], EXT_HDR.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for i in 0 .. EXT_HDR.len() {
+        let data: SectionHdrDataRaw<Elf64> =
+            hdrs.idx(i).unwrap().try_into().expect("Expected success");
+
+        assert_eq!(EXT_HDR[i], data);
+    }
+}
+
+#[test]
+fn test_SectionHdrs_from_slice_null_not_first() {
+    let mut buf = [0; 128];
+
+    // Only the first section header may hold extended values.
+    buf[96] = 0x05;
+
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::try_from(&buf[0..]).expect("Expected success");
+    let data: SectionHdrDataRaw<Elf64> =
+        hdrs.idx(1).unwrap().try_into().expect("Expected success");
+
+    assert_eq!(data, SectionHdrData::Null);
+
+    let mut iter = hdrs.iter();
+
+    iter.next();
+
+    let data: SectionHdrDataRaw<Elf64> =
+        iter.next().unwrap().try_into().expect("Expected success");
+
+    assert_eq!(data, SectionHdrData::Null);
+}
+
+#[test]
+fn test_SectionHdrs_create_ext_num_hdrs() {
+    const NUM_HDRS: usize = 0xff00;
+    let mut buf = vec![0; NUM_HDRS * 64];
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create(&mut buf[0..],
+                            (0 .. NUM_HDRS).map(|_| SectionHdrData::Null))
+        .expect("Expected success");
+    let first: SectionHdrDataRaw<Elf64> =
+        hdrs.idx(0).unwrap().try_into().expect("Expected success");
+    let last: SectionHdrDataRaw<Elf64> =
+        hdrs.idx(NUM_HDRS - 1).unwrap().try_into().expect("Expected success");
+
+    assert_eq!(first, SectionHdrData::ExtNull { num_section_hdrs: 0xff00,
+                                                section_hdr_strtab: 0,
+                                                num_prog_hdrs: 0 });
+    assert_eq!(last, SectionHdrData::Null);
+}
+
+#[test]
+fn test_SectionHdrs_create_ext_strtab() {
+    const HDRS: [SectionHdrDataRaw<Elf64>; 2] = [
+        SectionHdrData::Null,
+        SectionHdrData::Null
+    ];
+    let mut buf = [0; 128];
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create_ext(&mut buf[0..], HDRS.iter().map(|x| *x),
+                                0xff00, 0xffff)
+        .expect("Expected success");
+    let first: SectionHdrDataRaw<Elf64> =
+        hdrs.idx(0).unwrap().try_into().expect("Expected success");
+
+    assert_eq!(first, SectionHdrData::ExtNull { num_section_hdrs: 0,
+                                                section_hdr_strtab: 0xff00,
+                                                num_prog_hdrs: 0xffff });
+}
+
+#[test]
+fn test_SectionHdrs_create_ext_not_null() {
+    let mut buf = [0; 128];
+    let res: Result<SectionHdrs<'_, LittleEndian, Elf64>, ()> =
+        SectionHdrs::create_ext(&mut buf[0..],
+                                ELF64_SECTION_HDR_CONTENTS_BARE[1 .. 3]
+                                .iter().map(|x| *x), 0x10000, 0);
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_SectionHdrs_create_relr() {
+    const RELR_HDR: [SectionHdrDataRaw<Elf64>; 2] = [
+        SectionHdrData::Null,
+        SectionHdrData::Relr { name: 1, addr: 0x3a0, align: 8,
+                               relrs: SectionPos { offset: 0x3a0,
+                                                   size: 0x28 },
+                               flags: SectionFlags { bits: 2 } }
+    ];
+    let mut buf = [0; 128];
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create(&mut buf[0..], RELR_HDR.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for i in 0 .. RELR_HDR.len() {
+        let data: SectionHdrDataRaw<Elf64> =
+            hdrs.idx(i).unwrap().try_into().expect("Expected success");
+
+        assert_eq!(RELR_HDR[i], data);
+    }
+
+    // Corrupt the entry size.
+    buf[64 + 56] = 4;
+
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::try_from(&buf[0..]).expect("Expected success");
+    let data: Result<SectionHdrDataRaw<Elf64>, _> =
+        hdrs.idx(1).unwrap().try_into();
+
+    assert!(data.is_err());
+}
+
+#[test]
+fn test_SectionHdrs_create_init_arrays() {
+    const ARRAY_HDR: [SectionHdrDataRaw<Elf64>; 4] = [
+        SectionHdrData::Null,
+        SectionHdrData::InitArray { name: 1, addr: 0x20a6f8, align: 8,
+                                    addrs: SectionPos { offset: 0x86f8,
+                                                        size: 8 },
+                                    ent_size: 0,
+                                    flags: SectionFlags { bits: 3 } },
+        SectionHdrData::FiniArray { name: 13, addr: 0x20a700, align: 8,
+                                    addrs: SectionPos { offset: 0x8700,
+                                                        size: 8 },
+                                    ent_size: 8,
+                                    flags: SectionFlags { bits: 3 } },
+        SectionHdrData::PreInitArray { name: 25, addr: 0x20a708, align: 8,
+                                       addrs: SectionPos { offset: 0x8708,
+                                                           size: 16 },
+                                       ent_size: 0,
+                                       flags: SectionFlags { bits: 3 } }
+    ];
+    let mut buf = [0; 256];
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create(&mut buf[0..], ARRAY_HDR.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for i in 0 .. ARRAY_HDR.len() {
+        let data: SectionHdrDataRaw<Elf64> =
+            hdrs.idx(i).unwrap().try_into().expect("Expected success");
+
+        assert_eq!(ARRAY_HDR[i], data);
+    }
+
+    assert_eq!(buf[64 + 56], 0);
+    assert_eq!(buf[128 + 56], 8);
+    assert_eq!(buf[192 + 56], 0);
+}
+
+#[test]
+fn test_SectionHdrs_create_android_rela() {
+    const ANDROID_RELA_HDR: [SectionHdrDataRaw<Elf64>; 3] = [
+        SectionHdrData::Null,
+        SectionHdrData::AndroidRel { name: 1, addr: 0x3a0, align: 1,
+                                     rels: SectionPos { offset: 0x3a0,
+                                                        size: 0x20 },
+                                     symtab: 3, target: 0,
+                                     flags: SectionFlags { bits: 2 } },
+        SectionHdrData::AndroidRela { name: 13, addr: 0x3c0, align: 1,
+                                      relas: SectionPos { offset: 0x3c0,
+                                                          size: 0x2d },
+                                      symtab: 3, target: 0,
+                                      flags: SectionFlags { bits: 2 } }
+    ];
+    let mut buf = [0; 192];
+    let hdrs: SectionHdrs<'_, LittleEndian, Elf64> =
+        SectionHdrs::create(&mut buf[0..],
+                            ANDROID_RELA_HDR.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for i in 0 .. ANDROID_RELA_HDR.len() {
+        let data: SectionHdrDataRaw<Elf64> =
+            hdrs.idx(i).unwrap().try_into().expect("Expected success");
+
+        assert_eq!(ANDROID_RELA_HDR[i], data);
+    }
+}
+
+#[test]
+fn test_SectionHdrs_create_big_endian() {
+    const BIG_ENDIAN_HDR: [SectionHdrDataRaw<Elf64>; 3] = [
+        SectionHdrData::Null,
+        SectionHdrData::Rela { name: 1, addr: 0, align: 8,
+                               relas: SectionPos { offset: 0x1c0,
+                                                   size: 0x180 },
+                               symtab: 2, target: 0,
+                               flags: SectionFlags { bits: 0x40 } },
+        SectionHdrData::Relr { name: 12, addr: 0x3a0, align: 8,
+                               relrs: SectionPos { offset: 0x3a0,
+                                                   size: 0x28 },
+                               flags: SectionFlags { bits: 2 } }
+    ];
+    let mut buf = [0; 192];
+    let hdrs: SectionHdrs<'_, BigEndian, Elf64> =
+        SectionHdrs::create(&mut buf[0..], BIG_ENDIAN_HDR.iter().map(|x| *x))
+        .expect("Expected success");
+
+    for i in 0 .. BIG_ENDIAN_HDR.len() {
+        let data: SectionHdrDataRaw<Elf64> =
+            hdrs.idx(i).unwrap().try_into().expect("Expected success");
+
+        assert_eq!(BIG_ENDIAN_HDR[i], data);
+    }
+
+    // The entry size is a full 8-byte field.
+    assert_eq!(buf[64 + 56 .. 64 + 64], [0, 0, 0, 0, 0, 0, 0, 0x18]);
+    assert_eq!(buf[128 + 56 .. 128 + 64], [0, 0, 0, 0, 0, 0, 0, 0x08]);
+}
+
+#[test]
+fn test_SectionHdrsMut_set_round_trip() {
+    let mut buf = [0; ELF64_SECTION_HDR_BYTES];
+    let mut section_hdrs: SectionHdrsMut<'_, LittleEndian, Elf64> =
+        SectionHdrsMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+
+    assert_eq!(section_hdrs.num_hdrs(), ELF64_NUM_SECTION_HDRS);
+
+    for i in 0 .. ELF64_SECTION_HDR_CONTENTS_BARE.len() {
+        let ent = section_hdrs.idx_mut(i);
+
+        assert!(ent.is_some());
+
+        ent.unwrap().set(ELF64_SECTION_HDR_CONTENTS_BARE[i]);
+    }
+
+    assert!(section_hdrs.idx_mut(ELF64_NUM_SECTION_HDRS).is_none());
+
+    for i in 0 .. ELF64_SECTION_HDR_CONTENTS_BARE.len() {
+        let expect = &ELF64_SECTION_HDR_CONTENTS_BARE[i];
+        let ent = section_hdrs.idx(i);
+
+        assert!(ent.is_some());
+
+        let data = ent.unwrap().try_into();
+
+        assert!(data.is_ok());
+
+        let actual = data.unwrap();
+
+        assert_eq!(expect, &actual)
+    }
+}
+
+#[test]
+fn test_SectionHdrsMut_set_fields() {
+    let mut buf = [0; 128];
+    let mut section_hdrs: SectionHdrsMut<'_, LittleEndian, Elf64> =
+        SectionHdrsMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+    let mut ent = section_hdrs.idx_mut(1).expect("Expected success");
+    let mut flags = SectionFlags { bits: 0 };
+
+    flags.insert(SectionFlag::Alloc);
+    flags.insert(SectionFlag::Exec);
+    ent.set_name(0x1b);
+    ent.set_kind(1);
+    ent.set_flags(flags);
+    ent.set_addr(0x1000);
+    ent.set_offset(0x1000);
+    ent.set_size(0x200);
+    ent.set_align(0x10);
+
+    let data = ent.try_into();
+
+    assert!(data.is_ok());
+
+    let actual: SectionHdrDataRaw<Elf64> = data.unwrap();
+    let expect = SectionHdrData::ProgBits { name: 0x1b, addr: 0x1000,
+                                            align: 0x10, ent_size: 0,
+                                            flags: flags,
+                                            data: SectionPos { offset: 0x1000,
+                                                               size: 0x200 } };
+
+    assert_eq!(expect, actual);
+}
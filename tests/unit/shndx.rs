@@ -0,0 +1,228 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf64;
+use elf_utils::shndx;
+use elf_utils::shndx::SymtabShndx;
+use elf_utils::shndx::SymtabShndxError;
+use elf_utils::shndx::SymtabWithShndx;
+use elf_utils::shndx::SymtabWithShndxError;
+use elf_utils::shndx::WithShndx;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::WithStrtab;
+use elf_utils::symtab::Symtab;
+use elf_utils::symtab::SymBase;
+use elf_utils::symtab::SymBind;
+use elf_utils::symtab::SymData;
+use elf_utils::symtab::SymDataExt;
+use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
+
+const ELF_SHNDX_BYTES: usize = 16;
+
+const ELF_SHNDX: [u8; ELF_SHNDX_BYTES] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x05, 0x00, 0x01, 0x00
+];
+
+const ELF_SHNDX_CONTENTS: [u32; 4] = [ 0, 0x10000, 0, 0x10005 ];
+
+const ELF_SHNDX_SYMTAB: [u8; 96] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x12, 0x00, 0xff, 0xff,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x05, 0x00, 0x00, 0x00, 0x11, 0x00, 0x03, 0x00,
+    0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x09, 0x00, 0x00, 0x00, 0x12, 0x00, 0xff, 0xff,
+    0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_SHNDX_STRTAB: [u8; 13] = [
+    0x00, 0x66, 0x6f, 0x6f, 0x00, 0x62, 0x61, 0x72,
+    0x00, 0x62, 0x61, 0x7a, 0x00
+];
+
+const ELF_SHNDX_SYMS: [SymDataExt<Elf64>; 4] = [
+    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
+    SymData { name: Some(1), value: 0x10, size: 8, kind: SymKind::Function,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(0x10000) },
+    SymData { name: Some(5), value: 0x20, size: 4, kind: SymKind::Object,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(3) },
+    SymData { name: Some(9), value: 0x30, size: 16, kind: SymKind::Function,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(0x10005) }
+];
+
+#[test]
+fn test_SymtabShndx_from_slice_ok() {
+    let shndxs: Result<SymtabShndx<'_, LittleEndian>, SymtabShndxError> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]);
+
+    assert!(shndxs.is_ok());
+    assert_eq!(shndxs.unwrap().num_shndxs(), ELF_SHNDX_CONTENTS.len());
+}
+
+#[test]
+fn test_SymtabShndx_from_slice_bad_size() {
+    let shndxs: Result<SymtabShndx<'_, LittleEndian>, SymtabShndxError> =
+        SymtabShndx::try_from(&ELF_SHNDX[0 .. ELF_SHNDX_BYTES - 1]);
+
+    assert_eq!(shndxs.err(), Some(SymtabShndxError::BadSize(15)));
+}
+
+#[test]
+fn test_SymtabShndx_from_slice_iter_len() {
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]).expect("Expected success");
+
+    assert_eq!(shndxs.iter().len(), ELF_SHNDX_CONTENTS.len());
+}
+
+#[test]
+fn test_SymtabShndx_from_slice_iter() {
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]).expect("Expected success");
+    let mut iter = shndxs.iter();
+
+    for expected in ELF_SHNDX_CONTENTS.iter() {
+        assert_eq!(iter.next(), Some(*expected));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_SymtabShndx_from_slice_idx() {
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]).expect("Expected success");
+
+    for i in 0 .. ELF_SHNDX_CONTENTS.len() {
+        assert_eq!(shndxs.idx(i), Some(ELF_SHNDX_CONTENTS[i]));
+    }
+
+    assert!(shndxs.idx(ELF_SHNDX_CONTENTS.len()).is_none());
+}
+
+#[test]
+fn test_SymtabShndx_create_just_right() {
+    let mut buf = [0; ELF_SHNDX_BYTES];
+    let res: Result<(SymtabShndx<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        SymtabShndx::create_split(&mut buf[0..], ELF_SHNDX_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_SHNDX);
+}
+
+#[test]
+fn test_SymtabShndx_create_too_big() {
+    let mut buf = [0; ELF_SHNDX_BYTES + 1];
+    let res: Result<(SymtabShndx<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        SymtabShndx::create_split(&mut buf[0..], ELF_SHNDX_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_SymtabShndx_create_too_small() {
+    let mut buf = [0; ELF_SHNDX_BYTES - 1];
+    let res: Result<SymtabShndx<'_, LittleEndian>, ()> =
+        SymtabShndx::create(&mut buf[0..], ELF_SHNDX_CONTENTS.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_SymtabShndx_required_bytes() {
+    let size = shndx::required_bytes(ELF_SHNDX_CONTENTS.iter());
+
+    assert_eq!(size, ELF_SHNDX_BYTES);
+}
+
+#[test]
+fn test_SymtabWithShndx_bad_len() {
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SHNDX_SYMTAB[0..]).expect("Expected success");
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0 .. 12]).expect("Expected success");
+    let res: Result<SymtabWithShndx<'_, LittleEndian, Elf64>,
+                    SymtabWithShndxError> = symtab.with_shndx(shndxs);
+
+    assert_eq!(res.err(), Some(SymtabWithShndxError::BadLen(3)));
+}
+
+#[test]
+fn test_SymtabWithShndx_idx() {
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SHNDX_SYMTAB[0..]).expect("Expected success");
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]).expect("Expected success");
+    let syms: SymtabWithShndx<'_, LittleEndian, Elf64> =
+        symtab.with_shndx(shndxs).expect("Expected success");
+
+    assert_eq!(syms.num_syms(), ELF_SHNDX_SYMS.len());
+
+    for i in 0 .. ELF_SHNDX_SYMS.len() {
+        let sym = syms.idx(i).expect("Expected some");
+        let data: SymDataExt<Elf64> =
+            sym.try_into().expect("Expected success");
+
+        assert_eq!(sym.shndx(), ELF_SHNDX_CONTENTS[i]);
+        assert_eq!(data, ELF_SHNDX_SYMS[i]);
+    }
+
+    assert!(syms.idx(ELF_SHNDX_SYMS.len()).is_none());
+}
+
+#[test]
+fn test_SymtabWithShndx_iter() {
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SHNDX_SYMTAB[0..]).expect("Expected success");
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]).expect("Expected success");
+    let syms: SymtabWithShndx<'_, LittleEndian, Elf64> =
+        symtab.with_shndx(shndxs).expect("Expected success");
+    let mut iter = syms.iter();
+
+    assert_eq!(iter.len(), ELF_SHNDX_SYMS.len());
+
+    for expected in ELF_SHNDX_SYMS.iter() {
+        let sym = iter.next().expect("Expected some");
+        let data: SymDataExt<Elf64> =
+            sym.try_into().expect("Expected success");
+
+        assert_eq!(&data, expected);
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_SymtabWithShndx_with_strtab() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_SHNDX_STRTAB[0..]).expect("Expected success");
+    let symtab: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::try_from(&ELF_SHNDX_SYMTAB[0..]).expect("Expected success");
+    let shndxs: SymtabShndx<'_, LittleEndian> =
+        SymtabShndx::try_from(&ELF_SHNDX[0..]).expect("Expected success");
+    let syms: SymtabWithShndx<'_, LittleEndian, Elf64> =
+        symtab.with_shndx(shndxs).expect("Expected success");
+    let names = [None, Some("foo"), Some("bar"), Some("baz")];
+
+    for (i, sym) in syms.iter().enumerate() {
+        let data = sym.with_strtab(strtab).expect("Expected success");
+
+        assert_eq!(data.name.map(|name| name.unwrap()), names[i]);
+        assert_eq!(data.section, ELF_SHNDX_SYMS[i].section);
+    }
+}
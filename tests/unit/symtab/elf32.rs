@@ -8,6 +8,7 @@ use elf_utils::symtab::SymBase;
 use elf_utils::symtab::SymBind;
 use elf_utils::symtab::SymData;
 use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
 use elf_utils::symtab::Symtab;
 use elf_utils::symtab::SymtabCreate;
 use elf_utils::symtab::SymtabError;
@@ -264,431 +265,633 @@ const ELF32_STRTAB: [u8; 289] = [
 
 const ELF32_SYMTAB_CONTENTS_BARE: [SymData<u32, u16, Elf32>; 101] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-              bind: SymBind::Local, section: SymBase::Absolute },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Absolute },
     SymData { name: None, value: 504, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 485, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 68, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 480, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 275, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 6, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 442, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 447, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 132, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 512, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 166, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 16, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 155, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 338, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 496, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 141, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 356, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 102, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 419, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 317, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 343, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 467, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 398, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 71, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 620, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 281, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 286, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 197, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 235, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 638, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 456, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 365, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 328, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 410, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 113, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 428, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 239, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 199, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 654, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 458, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 625, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 298, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 255, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 614, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 460, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 373, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 215, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 379, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 477, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 120, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 439, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 290, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 666, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 388, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 83, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 454, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: Some(10), value: 0, size: 1, kind: SymKind::Object,
-              bind: SymBind::Local, section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: Some(17), value: 544, size: 69, kind: SymKind::Function,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: Some(27), value: 304, size: 238, kind: SymKind::Function,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(5) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(7) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(8) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(8) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(9) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(9) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(10) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(10) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(12) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(12) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(14) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(16) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(16) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(18) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(18) },
     SymData { name: Some(46), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(23) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(23) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(23) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(23) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(24) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(24) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(3) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(3) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(19) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(21) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(21) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(25) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(25) },
     SymData { name: Some(61), value: 0, size: 303, kind: SymKind::Function,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(26) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(26) },
     SymData { name: Some(69), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Weak, section: SymBase::Undef },
+              bind: SymBind::Weak, visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Undef },
     SymData { name: Some(78), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(95), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(114), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(131), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(150), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(170), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(192), value: 0, size: 4, kind: SymKind::Object,
-              bind: SymBind::Global, section: SymBase::Index(5) },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: Some(203), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Weak, section: SymBase::Undef },
+              bind: SymBind::Weak, visibility: SymVisibility::Hidden, other: 0,
+              section: SymBase::Undef },
     SymData { name: Some(218), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Weak, section: SymBase::Undef },
+              bind: SymBind::Weak, visibility: SymVisibility::Hidden, other: 0,
+              section: SymBase::Undef },
     SymData { name: Some(235), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(241), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(247), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(257), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(264), value: 0, size: 4, kind: SymKind::Object,
-              bind: SymBind::Global, section: SymBase::Index(7) },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: Some(272), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(277), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(282), value: 616, size: 25, kind: SymKind::Function,
-              bind: SymBind::Global, section: SymBase::Index(1) },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
 ];
 
 const ELF32_SYMTAB_CONTENTS: [SymData<Result<&'static str, &'static [u8]>,
                                              u16, Elf32>; 101] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
               kind: SymKind::File, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Absolute },
     SymData { name: None, value: 504, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 485, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 68, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 480, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 275, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 6, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 442, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 447, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 132, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 512, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 166, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 16, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 155, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 338, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 496, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 141, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 356, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 102, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 419, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 317, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 343, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 467, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 398, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 71, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 620, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 281, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 286, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 197, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 235, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 638, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 456, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 365, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 328, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 410, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 113, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 428, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 239, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 199, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 654, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 458, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 625, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 298, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 255, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 614, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 460, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 373, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 215, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 379, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 477, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 120, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 439, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 290, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 666, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 388, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 83, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 454, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: Some(Ok(".L.str")), value: 0, size: 1,
               kind: SymKind::Object, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(4) },
     SymData { name: Some(Ok("finalizer")), value: 544, size: 69,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: Some(Ok("handle_static_init")), value: 304, size: 238,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(5) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(7) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(8) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(8) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(9) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(9) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(10) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(10) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(12) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(12) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(14) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(16) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(16) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(18) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(18) },
     SymData { name: Some(Ok(".freebsd.noteG")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(23) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(23) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(23) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(24) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(24) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(3) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(3) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(19) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(21) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(21) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(25) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(25) },
     SymData { name: Some(Ok("_start1")), value: 0, size: 303,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(26) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(26) },
     SymData { name: Some(Ok("_DYNAMIC")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__fini_array_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__fini_array_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__init_array_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__init_array_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__preinit_array_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__preinit_array_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__progname")), value: 0, size: 4,
               kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(5) },
     SymData { name: Some(Ok("__rel_iplt_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__rel_iplt_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("_fini")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("_init")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("_init_tls")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("atexit")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("environ")), value: 0, size: 4,
               kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(7) },
     SymData { name: Some(Ok("exit")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("main")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("_start")), value: 616, size: 25,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
 ];
 
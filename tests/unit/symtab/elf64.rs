@@ -8,6 +8,7 @@ use elf_utils::symtab::SymBase;
 use elf_utils::symtab::SymBind;
 use elf_utils::symtab::SymData;
 use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
 use elf_utils::symtab::Symtab;
 use elf_utils::symtab::SymtabCreate;
 use elf_utils::symtab::SymtabError;
@@ -177,182 +178,260 @@ const ELF64_STRTAB: [u8; 276] = [
 
 const ELF64_SYMTAB_CONTENTS_BARE: [SymData<u32, u16, Elf64>; 39] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(1), value: 0, size: 0, kind: SymKind::File,
-              bind: SymBind::Local, section: SymBase::Absolute },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Absolute },
     SymData { name: Some(10), value: 560, size: 90, kind: SymKind::Function,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: Some(20), value: 272, size: 282, kind: SymKind::Function,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(5) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(7) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(8) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(8) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(9) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(9) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(10) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(10) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(12) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(12) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(14) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(16) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(16) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(18) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(18) },
     SymData { name: Some(39), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Index(19) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(19) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(20) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(20) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(3) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(3) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(21) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(21) },
     SymData { name: Some(54), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Weak, section: SymBase::Undef },
+              bind: SymBind::Weak, visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Undef },
     SymData { name: Some(63), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(80), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(99), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(116), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(135), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(155), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(177), value: 0, size: 8, kind: SymKind::Object,
-              bind: SymBind::Global, section: SymBase::Index(5)},
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: Some(188), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Weak, section: SymBase::Undef },
+              bind: SymBind::Weak, visibility: SymVisibility::Hidden, other: 0,
+              section: SymBase::Undef },
     SymData { name: Some(204), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Weak, section: SymBase::Undef },
+              bind: SymBind::Weak, visibility: SymVisibility::Hidden, other: 0,
+              section: SymBase::Undef },
     SymData { name: Some(222), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(228), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(234), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(244), value: 0, size: 262, kind: SymKind::Function,
-              bind: SymBind::Global, section: SymBase::Index(1) },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: Some(251), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(258), value: 0, size: 8, kind: SymKind::Object,
-              bind: SymBind::Global, section: SymBase::Index(7) },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: Some(266), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(271), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef }
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef }
 ];
 
 const ELF64_SYMTAB_CONTENTS: [SymData<Result<&'static str, &'static [u8]>,
                                               u16, Elf64>; 39] = [
     SymData { name: None, value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Local, section: SymBase::Undef },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("crt1_c.c")), value: 0, size: 0,
               kind: SymKind::File, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Absolute },
     SymData { name: Some(Ok("finalizer")), value: 560, size: 90,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: Some(Ok("handle_static_init")), value: 272, size: 282,
               kind: SymKind::Function, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(1) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(5) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(5) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(7) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(7) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(8) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(8) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(9) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(9) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(10) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(10) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(12) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(12) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(14) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(14) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(16) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(16) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(18) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(18) },
     SymData { name: Some(Ok(".freebsd.noteG")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Local,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(19) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(19) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(20) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(20) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(3) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(3) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(4) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(4) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(13) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(13) },
     SymData { name: None, value: 0, size: 0, kind: SymKind::Section,
-              bind: SymBind::Local, section: SymBase::Index(21) },
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(21) },
     SymData { name: Some(Ok("_DYNAMIC")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__fini_array_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__fini_array_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__init_array_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__init_array_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__preinit_array_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__preinit_array_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__progname")), value: 0, size: 8,
               kind: SymKind::Object, bind: SymBind::Global,
-              section: SymBase::Index(5)},
+              visibility: SymVisibility::Default, other: 0,
+              section: SymBase::Index(5) },
     SymData { name: Some(Ok("__rela_iplt_end")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("__rela_iplt_start")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Weak,
+              visibility: SymVisibility::Hidden, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("_fini")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("_init")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Hidden,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("_init_tls")), value: 0, size: 0,
               kind: SymKind::None, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Undef },
     SymData { name: Some(Ok("_start")), value: 0, size: 262,
               kind: SymKind::Function, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(1) },
     SymData { name: Some(Ok("atexit")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("environ")), value: 0, size: 8,
               kind: SymKind::Object, bind: SymBind::Global,
+              visibility: SymVisibility::Default, other: 0,
               section: SymBase::Index(7) },
     SymData { name: Some(Ok("exit")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef },
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
     SymData { name: Some(Ok("main")), value: 0, size: 0, kind: SymKind::None,
-              bind: SymBind::Global, section: SymBase::Undef }
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef }
 ];
 
 #[test]
@@ -877,3 +956,85 @@ fn test_SymtabMut_create_idx() {
 
     assert!(symtab.idx(ELF64_SYMTAB_CONTENTS_BARE.len()).is_none());
 }
+
+#[test]
+fn test_SymtabMut_create_visibility_other() {
+    let contents: [SymData<u32, u16, Elf64>; 2] = [
+        SymData { name: None, value: 0, size: 0, kind: SymKind::None,
+                  bind: SymBind::Local, visibility: SymVisibility::Default,
+                  other: 0, section: SymBase::Undef },
+        SymData { name: Some(1), value: 0x1000, size: 0x10,
+                  kind: SymKind::Function, bind: SymBind::Global,
+                  visibility: SymVisibility::Hidden, other: 0x60,
+                  section: SymBase::Index(1) }
+    ];
+    let mut buf = [0; 48];
+    let symtab: Result<(SymtabMut<'_, LittleEndian, Elf64>, &'_ mut [u8]), ()> =
+        SymtabMut::create_split(&mut buf[0..], contents.iter());
+
+    assert!(symtab.is_ok());
+
+    let (symtab, _) = symtab.expect("Expected success");
+    let sym = symtab.idx(1);
+
+    assert!(sym.is_some());
+
+    let data = sym.unwrap().try_into();
+
+    assert!(data.is_ok());
+
+    let actual: SymData<u32, u16, Elf64> = data.unwrap();
+
+    assert_eq!(contents[1], actual);
+    assert_eq!(buf[29], 0x62);
+}
+
+#[test]
+fn test_SymtabMut_set_round_trip() {
+    let mut buf = [0; ELF64_SYMTAB_BYTES];
+    let mut symtab: SymtabMut<'_, LittleEndian, Elf64> =
+        SymtabMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+
+    for i in 0 .. ELF64_SYMTAB_CONTENTS_BARE.len() {
+        let sym = symtab.idx_mut(i);
+
+        assert!(sym.is_some());
+
+        sym.unwrap().set(&ELF64_SYMTAB_CONTENTS_BARE[i]);
+    }
+
+    assert!(symtab.idx_mut(ELF64_SYMTAB_CONTENTS_BARE.len()).is_none());
+    assert_eq!(&buf[0..], &ELF64_SYMTAB[0..]);
+}
+
+#[test]
+fn test_SymtabMut_set_fields() {
+    let mut buf = ELF64_SYMTAB.clone();
+    let mut symtab: SymtabMut<'_, LittleEndian, Elf64> =
+        SymtabMut::try_from(&mut buf[0..])
+        .expect("Expected success");
+    let mut sym = symtab.idx_mut(2).expect("Expected success");
+
+    sym.set_name(None);
+    sym.set_value(0x4000);
+    sym.set_size(0x20);
+    sym.set_kind(SymKind::Object);
+    sym.set_bind(SymBind::Weak);
+    sym.set_other(0x60);
+    sym.set_visibility(SymVisibility::Protected);
+    sym.set_section(SymBase::Index(3));
+
+    let data = sym.try_into();
+
+    assert!(data.is_ok());
+
+    let actual: SymData<u32, u16, Elf64> = data.unwrap();
+    let expect = SymData { name: None, value: 0x4000, size: 0x20,
+                           kind: SymKind::Object, bind: SymBind::Weak,
+                           visibility: SymVisibility::Protected, other: 0x60,
+                           section: SymBase::Index(3) };
+
+    assert_eq!(expect, actual);
+    assert_eq!(buf[48 + 5], 0x63);
+}
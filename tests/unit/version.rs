@@ -0,0 +1,561 @@
+use byteorder::LittleEndian;
+use core::convert::TryFrom;
+use core::convert::TryInto;
+use elf_utils::Elf64;
+use elf_utils::strtab::Strtab;
+use elf_utils::strtab::WithStrtab;
+use elf_utils::symtab::SymBase;
+use elf_utils::symtab::SymBind;
+use elf_utils::symtab::SymData;
+use elf_utils::symtab::SymDataRaw;
+use elf_utils::symtab::SymKind;
+use elf_utils::symtab::SymVisibility;
+use elf_utils::symtab::Symtab;
+use elf_utils::version;
+use elf_utils::version::SymVersion;
+use elf_utils::version::VerdauxData;
+use elf_utils::version::VerdefData;
+use elf_utils::version::Verdefs;
+use elf_utils::version::VerdefsError;
+use elf_utils::version::VernauxData;
+use elf_utils::version::VerneedData;
+use elf_utils::version::Verneeds;
+use elf_utils::version::VerneedsError;
+use elf_utils::version::VersionedSyms;
+use elf_utils::version::VersymData;
+use elf_utils::version::Versyms;
+use elf_utils::version::VersymsError;
+
+const ELF_STRTAB_BYTES: usize = 57;
+
+const ELF_STRTAB: [u8; ELF_STRTAB_BYTES] = [
+    0x00, 0x6c, 0x69, 0x62, 0x66, 0x6f, 0x6f, 0x2e,
+    0x73, 0x6f, 0x2e, 0x31, 0x00, 0x46, 0x4f, 0x4f,
+    0x5f, 0x31, 0x2e, 0x30, 0x00, 0x46, 0x4f, 0x4f,
+    0x5f, 0x31, 0x2e, 0x31, 0x00, 0x6c, 0x69, 0x62,
+    0x62, 0x61, 0x72, 0x2e, 0x73, 0x6f, 0x2e, 0x32,
+    0x00, 0x42, 0x41, 0x52, 0x5f, 0x32, 0x2e, 0x30,
+    0x00, 0x42, 0x41, 0x52, 0x5f, 0x32, 0x2e, 0x31,
+    0x00
+];
+
+const ELF_VERSYMS_BYTES: usize = 8;
+
+const ELF_VERSYMS: [u8; ELF_VERSYMS_BYTES] = [
+    0x00, 0x00, 0x02, 0x00, 0x03, 0x80, 0x05, 0x00
+];
+
+const ELF_VERSYMS_CONTENTS: [VersymData; 4] = [
+    VersymData { idx: 0, hidden: false },
+    VersymData { idx: 2, hidden: false },
+    VersymData { idx: 3, hidden: true },
+    VersymData { idx: 5, hidden: false }
+];
+
+const ELF_VERDEFS_BYTES: usize = 92;
+
+const ELF_VERDEFS: [u8; ELF_VERDEFS_BYTES] = [
+    0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    0xc1, 0x7a, 0x77, 0x06, 0x14, 0x00, 0x00, 0x00,
+    0x1c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x02, 0x00, 0x01, 0x00, 0x50, 0x24, 0x45, 0x0b,
+    0x14, 0x00, 0x00, 0x00, 0x1c, 0x00, 0x00, 0x00,
+    0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, 0x00, 0x00, 0x00, 0x03, 0x00, 0x02, 0x00,
+    0x51, 0x24, 0x45, 0x0b, 0x14, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x15, 0x00, 0x00, 0x00,
+    0x08, 0x00, 0x00, 0x00, 0x0d, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_VERDEFS_BAD_VERSION: [u8; 28] = [
+    0x02, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    0xc1, 0x7a, 0x77, 0x06, 0x14, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_VERDEFS_BAD_AUX: [u8; 28] = [
+    0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    0xc1, 0x7a, 0x77, 0x06, 0x18, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_VERDEFS_BAD_NEXT: [u8; 28] = [
+    0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00,
+    0xc1, 0x7a, 0x77, 0x06, 0x14, 0x00, 0x00, 0x00,
+    0x1c, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_VERDEF_BASE_NAMES: [VerdauxData<u32>; 1] = [
+    VerdauxData { name: 1 }
+];
+
+const ELF_VERDEF_FOO_1_0_NAMES: [VerdauxData<u32>; 1] = [
+    VerdauxData { name: 13 }
+];
+
+const ELF_VERDEF_FOO_1_1_NAMES: [VerdauxData<u32>; 2] = [
+    VerdauxData { name: 21 },
+    VerdauxData { name: 13 }
+];
+
+const ELF_VERDEFS_CONTENTS: [VerdefData<&'static [VerdauxData<u32>]>; 3] = [
+    VerdefData { flags: 1, idx: 1, hash: 0x6777ac1,
+                 names: &ELF_VERDEF_BASE_NAMES },
+    VerdefData { flags: 0, idx: 2, hash: 0xb452450,
+                 names: &ELF_VERDEF_FOO_1_0_NAMES },
+    VerdefData { flags: 0, idx: 3, hash: 0xb452451,
+                 names: &ELF_VERDEF_FOO_1_1_NAMES }
+];
+
+const ELF_VERNEEDS_BYTES: usize = 48;
+
+const ELF_VERNEEDS: [u8; ELF_VERNEEDS_BYTES] = [
+    0x01, 0x00, 0x02, 0x00, 0x1d, 0x00, 0x00, 0x00,
+    0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x50, 0x25, 0x68, 0x06, 0x00, 0x00, 0x04, 0x00,
+    0x29, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+    0x51, 0x25, 0x68, 0x06, 0x00, 0x00, 0x05, 0x00,
+    0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+];
+
+const ELF_VERNEED_LIBBAR_VERSIONS: [VernauxData<u32>; 2] = [
+    VernauxData { hash: 0x6682550, flags: 0, idx: 4, name: 41 },
+    VernauxData { hash: 0x6682551, flags: 0, idx: 5, name: 49 }
+];
+
+const ELF_VERNEEDS_CONTENTS: [VerneedData<u32,
+                                          &'static [VernauxData<u32>]>; 1] = [
+    VerneedData { file: 29, versions: &ELF_VERNEED_LIBBAR_VERSIONS }
+];
+
+const ELF_SYMTAB_BYTES: usize = 96;
+
+const ELF_SYMS: [SymDataRaw<Elf64>; 4] = [
+    SymData { name: None, value: 0, size: 0, kind: SymKind::None,
+              bind: SymBind::Local, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef },
+    SymData { name: Some(1), value: 0x1000, size: 0x10, kind: SymKind::Function,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
+    SymData { name: Some(1), value: 0x1010, size: 0x10, kind: SymKind::Function,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Index(1) },
+    SymData { name: Some(29), value: 0, size: 0, kind: SymKind::Function,
+              bind: SymBind::Global, visibility: SymVisibility::Default,
+              other: 0, section: SymBase::Undef }
+];
+
+#[test]
+fn test_Versyms_from_slice_ok() {
+    let versyms: Result<Versyms<'_, LittleEndian>, VersymsError> =
+        Versyms::try_from(&ELF_VERSYMS[0..]);
+
+    assert!(versyms.is_ok());
+    assert_eq!(versyms.unwrap().num_versyms(), ELF_VERSYMS_CONTENTS.len());
+}
+
+#[test]
+fn test_Versyms_from_slice_bad_size() {
+    let versyms: Result<Versyms<'_, LittleEndian>, VersymsError> =
+        Versyms::try_from(&ELF_VERSYMS[0 .. ELF_VERSYMS_BYTES - 1]);
+
+    assert_eq!(versyms.err(), Some(VersymsError::BadSize(7)));
+}
+
+#[test]
+fn test_Versyms_from_slice_iter_len() {
+    let versyms: Versyms<'_, LittleEndian> =
+        Versyms::try_from(&ELF_VERSYMS[0..]).expect("Expected success");
+
+    assert_eq!(versyms.iter().len(), ELF_VERSYMS_CONTENTS.len());
+}
+
+#[test]
+fn test_Versyms_from_slice_iter() {
+    let versyms: Versyms<'_, LittleEndian> =
+        Versyms::try_from(&ELF_VERSYMS[0..]).expect("Expected success");
+    let mut iter = versyms.iter();
+
+    for expected in ELF_VERSYMS_CONTENTS.iter() {
+        assert_eq!(iter.next(), Some(*expected));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Versyms_from_slice_idx() {
+    let versyms: Versyms<'_, LittleEndian> =
+        Versyms::try_from(&ELF_VERSYMS[0..]).expect("Expected success");
+
+    for i in 0 .. ELF_VERSYMS_CONTENTS.len() {
+        assert_eq!(versyms.idx(i), Some(ELF_VERSYMS_CONTENTS[i]));
+    }
+
+    assert!(versyms.idx(ELF_VERSYMS_CONTENTS.len()).is_none());
+}
+
+#[test]
+fn test_VersymData_u16_round_trip() {
+    for expected in ELF_VERSYMS_CONTENTS.iter() {
+        let value: u16 = (*expected).into();
+
+        assert_eq!(VersymData::from(value), *expected);
+    }
+
+    assert_eq!(u16::from(VersymData { idx: 3, hidden: true }), 0x8003);
+}
+
+#[test]
+fn test_Versyms_create_just_right() {
+    let mut buf = [0; ELF_VERSYMS_BYTES];
+    let res: Result<(Versyms<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Versyms::create_split(&mut buf[0..], ELF_VERSYMS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_VERSYMS);
+}
+
+#[test]
+fn test_Versyms_create_too_big() {
+    let mut buf = [0; ELF_VERSYMS_BYTES + 1];
+    let res: Result<(Versyms<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Versyms::create_split(&mut buf[0..], ELF_VERSYMS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 1);
+}
+
+#[test]
+fn test_Versyms_create_too_small() {
+    let mut buf = [0; ELF_VERSYMS_BYTES - 1];
+    let res: Result<Versyms<'_, LittleEndian>, ()> =
+        Versyms::create(&mut buf[0..], ELF_VERSYMS_CONTENTS.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_Versyms_create_bad_idx() {
+    let mut buf = [0; 2];
+    let versyms = [VersymData { idx: 0x8001, hidden: false }];
+    let res: Result<Versyms<'_, LittleEndian>, ()> =
+        Versyms::create(&mut buf[0..], versyms.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_versyms_required_bytes() {
+    assert_eq!(version::versyms_required_bytes(ELF_VERSYMS_CONTENTS.iter()),
+               ELF_VERSYMS_BYTES);
+}
+
+#[test]
+fn test_Verdefs_from_slice_ok() {
+    let verdefs: Result<Verdefs<'_, LittleEndian>, VerdefsError> =
+        Verdefs::try_from(&ELF_VERDEFS[0..]);
+
+    assert!(verdefs.is_ok());
+}
+
+#[test]
+fn test_Verdefs_from_slice_empty() {
+    let verdefs: Verdefs<'_, LittleEndian> =
+        Verdefs::try_from(&ELF_VERDEFS[0..0]).expect("Expected success");
+
+    assert!(verdefs.iter().next().is_none());
+}
+
+#[test]
+fn test_Verdefs_from_slice_too_short() {
+    let verdefs: Result<Verdefs<'_, LittleEndian>, VerdefsError> =
+        Verdefs::try_from(&ELF_VERDEFS[0 .. ELF_VERDEFS_BYTES - 1]);
+
+    assert_eq!(verdefs.err(), Some(VerdefsError::OutOfBounds(84)));
+}
+
+#[test]
+fn test_Verdefs_from_slice_bad_version() {
+    let verdefs: Result<Verdefs<'_, LittleEndian>, VerdefsError> =
+        Verdefs::try_from(&ELF_VERDEFS_BAD_VERSION[0..]);
+
+    assert_eq!(verdefs.err(), Some(VerdefsError::BadVersion(2)));
+}
+
+#[test]
+fn test_Verdefs_from_slice_bad_aux() {
+    let verdefs: Result<Verdefs<'_, LittleEndian>, VerdefsError> =
+        Verdefs::try_from(&ELF_VERDEFS_BAD_AUX[0..]);
+
+    assert_eq!(verdefs.err(), Some(VerdefsError::OutOfBounds(24)));
+}
+
+#[test]
+fn test_Verdefs_from_slice_bad_next() {
+    let verdefs: Result<Verdefs<'_, LittleEndian>, VerdefsError> =
+        Verdefs::try_from(&ELF_VERDEFS_BAD_NEXT[0..]);
+
+    assert_eq!(verdefs.err(), Some(VerdefsError::OutOfBounds(28)));
+}
+
+#[test]
+fn test_Verdefs_from_slice_iter() {
+    let verdefs: Verdefs<'_, LittleEndian> =
+        Verdefs::try_from(&ELF_VERDEFS[0..]).expect("Expected success");
+    let mut iter = verdefs.iter();
+
+    for expected in ELF_VERDEFS_CONTENTS.iter() {
+        let verdef = iter.next().expect("Expected some");
+
+        assert_eq!(verdef.flags, expected.flags);
+        assert_eq!(verdef.idx, expected.idx);
+        assert_eq!(verdef.hash, expected.hash);
+        assert_eq!(verdef.names.len(), expected.names.len());
+        assert!(verdef.names.eq(expected.names.iter().cloned()));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Verdefs_from_slice_with_strtab() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let verdefs: Verdefs<'_, LittleEndian> =
+        Verdefs::try_from(&ELF_VERDEFS[0..]).expect("Expected success");
+    let mut iter = verdefs.iter().map(|verdef| {
+        let name: VerdauxData<&str> = verdef.names.clone().next()
+            .expect("Expected some")
+            .with_strtab(strtab).expect("Expected success")
+            .try_into().expect("Expected success");
+
+        name.name
+    });
+
+    assert_eq!(iter.next(), Some("libfoo.so.1"));
+    assert_eq!(iter.next(), Some("FOO_1.0"));
+    assert_eq!(iter.next(), Some("FOO_1.1"));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_Verdefs_from_slice_version() {
+    let verdefs: Verdefs<'_, LittleEndian> =
+        Verdefs::try_from(&ELF_VERDEFS[0..]).expect("Expected success");
+    let verdef = verdefs.version(3).expect("Expected some");
+
+    assert_eq!(verdef.hash, 0xb452451);
+    assert!(verdef.names.eq(ELF_VERDEF_FOO_1_1_NAMES.iter().cloned()));
+    assert!(verdefs.version(4).is_none());
+}
+
+#[test]
+fn test_Verdefs_create_just_right() {
+    let mut buf = [0; ELF_VERDEFS_BYTES];
+    let res: Result<(Verdefs<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Verdefs::create_split(&mut buf[0..], ELF_VERDEFS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_VERDEFS);
+}
+
+#[test]
+fn test_Verdefs_create_too_big() {
+    let mut buf = [0; ELF_VERDEFS_BYTES + 1];
+    let res: Result<(Verdefs<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Verdefs::create_split(&mut buf[0..], ELF_VERDEFS_CONTENTS.iter());
+    let (verdefs, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 1);
+    assert_eq!(verdefs.iter().count(), ELF_VERDEFS_CONTENTS.len());
+}
+
+#[test]
+fn test_Verdefs_create_too_small() {
+    let mut buf = [0; ELF_VERDEFS_BYTES - 1];
+    let res: Result<Verdefs<'_, LittleEndian>, ()> =
+        Verdefs::create(&mut buf[0..], ELF_VERDEFS_CONTENTS.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_verdefs_required_bytes() {
+    assert_eq!(version::verdefs_required_bytes(ELF_VERDEFS_CONTENTS.iter()),
+               ELF_VERDEFS_BYTES);
+}
+
+#[test]
+fn test_Verneeds_from_slice_ok() {
+    let verneeds: Result<Verneeds<'_, LittleEndian>, VerneedsError> =
+        Verneeds::try_from(&ELF_VERNEEDS[0..]);
+
+    assert!(verneeds.is_ok());
+}
+
+#[test]
+fn test_Verneeds_from_slice_too_short() {
+    let verneeds: Result<Verneeds<'_, LittleEndian>, VerneedsError> =
+        Verneeds::try_from(&ELF_VERNEEDS[0 .. ELF_VERNEEDS_BYTES - 1]);
+
+    assert_eq!(verneeds.err(), Some(VerneedsError::OutOfBounds(32)));
+}
+
+#[test]
+fn test_Verneeds_from_slice_iter() {
+    let verneeds: Verneeds<'_, LittleEndian> =
+        Verneeds::try_from(&ELF_VERNEEDS[0..]).expect("Expected success");
+    let mut iter = verneeds.iter();
+
+    for expected in ELF_VERNEEDS_CONTENTS.iter() {
+        let verneed = iter.next().expect("Expected some");
+
+        assert_eq!(verneed.file, expected.file);
+        assert!(verneed.versions.eq(expected.versions.iter().cloned()));
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_Verneeds_from_slice_with_strtab() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let verneeds: Verneeds<'_, LittleEndian> =
+        Verneeds::try_from(&ELF_VERNEEDS[0..]).expect("Expected success");
+    let verneed = verneeds.iter().next().expect("Expected some");
+    let verneed: VerneedData<&str, _> = verneed.with_strtab(strtab)
+        .expect("Expected success")
+        .try_into().expect("Expected success");
+
+    assert_eq!(verneed.file, "libbar.so.2");
+
+    let mut iter = verneed.versions.map(|version| {
+        let version: VernauxData<&str> = version.with_strtab(strtab)
+            .expect("Expected success")
+            .try_into().expect("Expected success");
+
+        version.name
+    });
+
+    assert_eq!(iter.next(), Some("BAR_2.0"));
+    assert_eq!(iter.next(), Some("BAR_2.1"));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_Verneeds_from_slice_version() {
+    let verneeds: Verneeds<'_, LittleEndian> =
+        Verneeds::try_from(&ELF_VERNEEDS[0..]).expect("Expected success");
+
+    assert_eq!(verneeds.version(4),
+               Some(VerneedData { file: 29,
+                                  versions: ELF_VERNEED_LIBBAR_VERSIONS[0] }));
+    assert!(verneeds.version(2).is_none());
+}
+
+#[test]
+fn test_Verneeds_create_just_right() {
+    let mut buf = [0; ELF_VERNEEDS_BYTES];
+    let res: Result<(Verneeds<'_, LittleEndian>, &'_ mut [u8]), ()> =
+        Verneeds::create_split(&mut buf[0..], ELF_VERNEEDS_CONTENTS.iter());
+    let (_, rest) = res.expect("Expected success");
+
+    assert_eq!(rest.len(), 0);
+    assert_eq!(buf, ELF_VERNEEDS);
+}
+
+#[test]
+fn test_Verneeds_create_too_small() {
+    let mut buf = [0; ELF_VERNEEDS_BYTES - 1];
+    let res: Result<Verneeds<'_, LittleEndian>, ()> =
+        Verneeds::create(&mut buf[0..], ELF_VERNEEDS_CONTENTS.iter());
+
+    assert!(res.is_err());
+}
+
+#[test]
+fn test_verneeds_required_bytes() {
+    assert_eq!(version::verneeds_required_bytes(ELF_VERNEEDS_CONTENTS.iter()),
+               ELF_VERNEEDS_BYTES);
+}
+
+#[test]
+fn test_VersionedSyms_new_mismatch() {
+    let mut buf = [0; ELF_SYMTAB_BYTES];
+    let syms: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::create(&mut buf[0..], ELF_SYMS.iter())
+        .expect("Expected success");
+    let versyms: Versyms<'_, LittleEndian> =
+        Versyms::try_from(&ELF_VERSYMS[0 .. ELF_VERSYMS_BYTES - 2])
+        .expect("Expected success");
+
+    assert!(VersionedSyms::new(syms, versyms, None, None).is_err());
+}
+
+#[test]
+fn test_VersionedSyms_iter() {
+    let strtab: Strtab<'_> =
+        Strtab::try_from(&ELF_STRTAB[0..]).expect("Expected success");
+    let mut buf = [0; ELF_SYMTAB_BYTES];
+    let syms: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::create(&mut buf[0..], ELF_SYMS.iter())
+        .expect("Expected success");
+    let versyms: Versyms<'_, LittleEndian> =
+        Versyms::try_from(&ELF_VERSYMS[0..]).expect("Expected success");
+    let verdefs: Verdefs<'_, LittleEndian> =
+        Verdefs::try_from(&ELF_VERDEFS[0..]).expect("Expected success");
+    let verneeds: Verneeds<'_, LittleEndian> =
+        Verneeds::try_from(&ELF_VERNEEDS[0..]).expect("Expected success");
+    let versioned = VersionedSyms::new(syms, versyms, Some(verdefs),
+                                       Some(verneeds))
+        .expect("Expected success");
+    let mut iter = versioned.iter();
+
+    assert_eq!(iter.len(), ELF_SYMS.len());
+
+    let expected = [
+        SymVersion::Local,
+        SymVersion::Defined { name: "FOO_1.0", hidden: false },
+        SymVersion::Defined { name: "FOO_1.1", hidden: true },
+        SymVersion::Needed { file: "libbar.so.2", name: "BAR_2.1",
+                             hidden: false }
+    ];
+
+    for i in 0 .. expected.len() {
+        let (sym, version) = iter.next().expect("Expected some");
+        let sym: SymDataRaw<Elf64> = sym.try_into().expect("Expected success");
+        let version: SymVersion<&str> = version.with_strtab(strtab)
+            .expect("Expected success")
+            .try_into().expect("Expected success");
+
+        assert_eq!(sym, ELF_SYMS[i]);
+        assert_eq!(version, expected[i]);
+    }
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_VersionedSyms_idx_unknown() {
+    let mut buf = [0; ELF_SYMTAB_BYTES];
+    let syms: Symtab<'_, LittleEndian, Elf64> =
+        Symtab::create(&mut buf[0..], ELF_SYMS.iter())
+        .expect("Expected success");
+    let versyms: Versyms<'_, LittleEndian> =
+        Versyms::try_from(&ELF_VERSYMS[0..]).expect("Expected success");
+    let versioned = VersionedSyms::new(syms, versyms, None, None)
+        .expect("Expected success");
+    let (_, version) = versioned.idx(2).expect("Expected some");
+
+    assert_eq!(version, SymVersion::Unknown { idx: 3, hidden: true });
+    assert!(versioned.idx(ELF_SYMS.len()).is_none());
+}